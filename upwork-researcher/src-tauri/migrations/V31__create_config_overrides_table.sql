@@ -0,0 +1,8 @@
+-- Local overrides pinned on top of the fetched remote config
+-- Migration V31: Remote config overrides (key_path is a dotted path into the config JSON)
+
+CREATE TABLE config_overrides (
+    key_path TEXT PRIMARY KEY,
+    value_json TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
@@ -0,0 +1,4 @@
+-- Track which trusted key verified the cached remote config
+-- Migration V32: Remote config key rotation support
+
+ALTER TABLE remote_config ADD COLUMN signing_key_id TEXT;
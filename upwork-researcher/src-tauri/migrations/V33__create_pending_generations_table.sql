@@ -0,0 +1,17 @@
+-- Generation requests queued while the cooldown is active
+-- Migration V33: Queued generation (drained FIFO by a single background worker)
+
+CREATE TABLE pending_generations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    job_content TEXT NOT NULL,
+    hook_strategy_id TEXT,
+    status TEXT NOT NULL DEFAULT 'queued'
+        CHECK (status IN ('queued', 'running', 'completed', 'failed', 'cancelled')),
+    error TEXT,
+    proposal_id INTEGER REFERENCES proposals(id) ON DELETE SET NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    started_at TEXT,
+    completed_at TEXT
+);
+
+CREATE INDEX idx_pending_generations_status ON pending_generations(status, id);
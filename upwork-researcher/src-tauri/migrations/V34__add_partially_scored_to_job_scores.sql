@@ -0,0 +1,8 @@
+-- Bulk score backfill: flag rows scored without the client quality component
+--
+-- score_all_unscored_jobs scores previously imported jobs locally (skills match +
+-- budget alignment) without calling the API, so client quality is missing and the
+-- overall score uses its default. The flag is cleared once a full analysis stores
+-- a client quality score.
+
+ALTER TABLE job_scores ADD COLUMN partially_scored INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,7 @@
+-- Job requirement checklist extracted by analyze_job_post
+--
+-- JSON object: {"required": [...], "niceToHave": [...]}
+-- "required" holds must-have deliverables; "niceToHave" holds items the client
+-- marked as optional/bonus. NULL = not analyzed yet.
+
+ALTER TABLE job_posts ADD COLUMN deliverables TEXT;
@@ -0,0 +1,14 @@
+-- Job post freshness tracking
+--
+-- last_seen_at: updated whenever an RSS refresh encounters the same URL again.
+-- NULL for pasted jobs and RSS jobs not seen since import; freshness then
+-- falls back to created_at.
+-- job_status: 'active' (default), 'stale' (not seen for job_stale_days, set at
+-- startup), 'applied' (mark_job_applied), or 'dismissed' (dismiss_job_post).
+-- The job queue hides stale and dismissed jobs unless asked to include them.
+
+ALTER TABLE job_posts ADD COLUMN last_seen_at TEXT;
+ALTER TABLE job_posts ADD COLUMN job_status TEXT NOT NULL DEFAULT 'active'
+    CHECK (job_status IN ('active', 'stale', 'applied', 'dismissed'));
+
+CREATE INDEX IF NOT EXISTS idx_job_posts_job_status ON job_posts(job_status);
@@ -0,0 +1,9 @@
+-- Cached perplexity analysis for saved proposals
+--
+-- Written by analyze_stored_proposal_perplexity when asked to store the result,
+-- so the history view can show a risk badge without calling the API again.
+-- Cleared by update_proposal_text: a score for old content is never shown.
+
+ALTER TABLE proposals ADD COLUMN perplexity_score REAL;
+ALTER TABLE proposals ADD COLUMN perplexity_flagged_count INTEGER;
+ALTER TABLE proposals ADD COLUMN perplexity_analyzed_at TEXT;
@@ -0,0 +1,15 @@
+-- Migration: V38 - Backfill proposals.job_post_id
+-- proposals.job_post_id (V27: nullable, indexed, ON DELETE SET NULL) was never set by
+-- save_proposal, so existing proposals have no navigable link to their job post.
+-- Best-effort: link each unlinked proposal to the newest job post whose raw_content
+-- exactly matches the proposal's job_content. Proposals with no exact match stay NULL.
+
+UPDATE proposals
+SET job_post_id = (
+    SELECT jp.id FROM job_posts jp
+    WHERE jp.raw_content = proposals.job_content
+    ORDER BY jp.id DESC
+    LIMIT 1
+)
+WHERE job_post_id IS NULL
+  AND EXISTS (SELECT 1 FROM job_posts jp WHERE jp.raw_content = proposals.job_content);
@@ -0,0 +1,20 @@
+-- Migration: V39 - Create generation_metadata table
+-- Purpose: Record how each proposal was generated (model, humanization intensity,
+-- hook strategy, voice profile version) so a change in proposal quality can be traced
+-- back to what changed. Pure metadata: no prompt text is stored.
+-- Written in the same transaction as the proposal insert. Proposals saved before this
+-- migration have no row and report "unknown".
+
+CREATE TABLE IF NOT EXISTS generation_metadata (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    proposal_id INTEGER NOT NULL UNIQUE REFERENCES proposals(id) ON DELETE CASCADE,
+    model TEXT NOT NULL,
+    humanization_intensity TEXT NOT NULL,
+    -- Hook strategy key; NULL when no hook strategy was used
+    hook_strategy_id TEXT,
+    -- voice_profiles.updated_at at generation time; NULL = default voice (not calibrated)
+    voice_profile_version TEXT,
+    -- Target word count from the length preference, if any
+    target_words INTEGER,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
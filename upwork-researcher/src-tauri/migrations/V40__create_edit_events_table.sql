@@ -0,0 +1,19 @@
+-- Migration: V40 - Create edit_events table
+-- Purpose: Fine-grained, append-only snapshots of proposal text for in-session undo
+-- that survives a crash. Complements proposal_revisions (coarse, archived after 5).
+-- Pruned aggressively on every insert (last few hours, last N, per-proposal byte budget;
+-- see db::queries::edit_events). Not included in encrypted archive exports by default
+-- and never imported from archives.
+
+CREATE TABLE IF NOT EXISTS edit_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    proposal_id INTEGER NOT NULL REFERENCES proposals(id) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    -- UTF-8 length of content, summed for the per-proposal byte budget
+    content_bytes INTEGER NOT NULL,
+    -- Millisecond precision: several snapshots can land in the same second
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_edit_events_proposal ON edit_events(proposal_id, id);
+CREATE INDEX IF NOT EXISTS idx_edit_events_created_at ON edit_events(created_at);
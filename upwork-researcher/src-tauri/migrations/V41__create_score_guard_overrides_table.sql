@@ -0,0 +1,14 @@
+-- Migration: V41 - Create score_guard_overrides table
+-- Purpose: Records each generation the user forced past the `min_score_to_generate`
+-- floor, so analytics can later check whether low-score jobs ever convert
+-- (join job_post_id to proposals and their outcomes). Same pattern as safety_overrides.
+
+CREATE TABLE IF NOT EXISTS score_guard_overrides (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    job_post_id INTEGER NOT NULL REFERENCES job_posts(id) ON DELETE CASCADE,
+    overall_score REAL NOT NULL,
+    floor_at_override REAL NOT NULL,
+    timestamp TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_score_guard_overrides_job_post ON score_guard_overrides(job_post_id);
@@ -0,0 +1,7 @@
+-- Migration: V42 - Add is_favorite to proposals
+-- Purpose: Single-bit pin for the few proposals the user keeps as templates.
+-- Independent of status (draft/completed) and outcome_status; goes away with the row.
+
+ALTER TABLE proposals ADD COLUMN is_favorite INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX IF NOT EXISTS idx_proposals_favorite ON proposals(is_favorite, created_at DESC);
@@ -0,0 +1,9 @@
+-- Migration: V43 - Add budget currency and USD-normalized budget to job_posts
+-- Purpose: Jobs posted in EUR/GBP/etc. were compared raw against the user's USD rate.
+-- budget_min/budget_max keep the amounts as posted; budget_currency is the detected
+-- ISO code (NULL = no marker, treated as USD); the _usd columns hold the converted
+-- amounts used for alignment (NULL when the currency has no exchange rate).
+
+ALTER TABLE job_posts ADD COLUMN budget_currency TEXT;
+ALTER TABLE job_posts ADD COLUMN budget_min_usd REAL;
+ALTER TABLE job_posts ADD COLUMN budget_max_usd REAL;
@@ -0,0 +1,68 @@
+-- Migration: V44 - Client name normalization and the clients view
+-- Purpose: The same client was extracted under slightly different names ("Acme Inc."
+-- vs "Acme Inc"), so repeat clients went unnoticed. client_name keeps the display
+-- casing; client_name_normalized is the matching key (whitespace collapsed, trailing
+-- punctuation stripped, casefolded), set by the Rust layer on every write. Existing
+-- rows are backfilled by startup maintenance. Jobs without a client name stay NULL and
+-- are never grouped together.
+
+ALTER TABLE job_posts ADD COLUMN client_name_normalized TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_job_posts_client_name_normalized
+    ON job_posts(client_name_normalized);
+
+-- One row per known client with job and proposal aggregates. Budgets are averaged in
+-- USD per budget type (rows from before V43 have no _usd amounts; their raw amounts
+-- were always treated as USD). Drafts don't count as sent proposals.
+CREATE VIEW IF NOT EXISTS clients AS
+WITH jobs AS (
+    SELECT
+        client_name_normalized AS normalized_name,
+        COUNT(*) AS job_count,
+        MIN(created_at) AS first_seen,
+        MAX(created_at) AS last_seen,
+        AVG(CASE WHEN budget_type = 'hourly' THEN
+            (COALESCE(budget_min_usd, CASE WHEN budget_currency IS NULL THEN budget_min END)
+             + COALESCE(budget_max_usd, CASE WHEN budget_currency IS NULL THEN budget_max END)) / 2.0
+        END) AS avg_hourly_budget_usd,
+        AVG(CASE WHEN budget_type = 'fixed' THEN
+            (COALESCE(budget_min_usd, CASE WHEN budget_currency IS NULL THEN budget_min END)
+             + COALESCE(budget_max_usd, CASE WHEN budget_currency IS NULL THEN budget_max END)) / 2.0
+        END) AS avg_fixed_budget_usd
+    FROM job_posts
+    WHERE client_name_normalized IS NOT NULL
+    GROUP BY client_name_normalized
+),
+sent AS (
+    SELECT
+        jp.client_name_normalized AS normalized_name,
+        COUNT(*) AS proposals_sent,
+        SUM(p.outcome_status IN ('response_received', 'interview', 'hired')) AS responses,
+        SUM(p.outcome_status = 'interview') AS interviews,
+        SUM(p.outcome_status = 'hired') AS hired,
+        SUM(p.outcome_status = 'rejected') AS rejected,
+        MAX(p.created_at) AS last_proposal_at
+    FROM proposals p
+    JOIN job_posts jp ON jp.id = p.job_post_id
+    WHERE jp.client_name_normalized IS NOT NULL AND p.status != 'draft'
+    GROUP BY jp.client_name_normalized
+)
+SELECT
+    jobs.normalized_name,
+    (SELECT latest.client_name FROM job_posts latest
+     WHERE latest.client_name_normalized = jobs.normalized_name
+     ORDER BY latest.created_at DESC, latest.id DESC
+     LIMIT 1) AS display_name,
+    jobs.job_count,
+    COALESCE(sent.proposals_sent, 0) AS proposals_sent,
+    COALESCE(sent.responses, 0) AS responses,
+    COALESCE(sent.interviews, 0) AS interviews,
+    COALESCE(sent.hired, 0) AS hired,
+    COALESCE(sent.rejected, 0) AS rejected,
+    jobs.avg_hourly_budget_usd,
+    jobs.avg_fixed_budget_usd,
+    jobs.first_seen,
+    jobs.last_seen,
+    sent.last_proposal_at
+FROM jobs
+LEFT JOIN sent ON sent.normalized_name = jobs.normalized_name;
@@ -0,0 +1,34 @@
+-- V45: Allow 'partial_regen' revisions (regenerate_selection)
+-- SQLite can't alter a CHECK constraint, so proposal_revisions is rebuilt.
+-- The new table's self-reference names proposal_revisions_new so dropping the
+-- old table doesn't trip restored_from_id; the rename rewrites it.
+
+CREATE TABLE proposal_revisions_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    proposal_id INTEGER NOT NULL,
+    content TEXT NOT NULL,
+    revision_number INTEGER NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    revision_type TEXT NOT NULL DEFAULT 'edit'
+        CHECK (revision_type IN ('generation', 'edit', 'restore', 'partial_regen')),
+    restored_from_id INTEGER REFERENCES proposal_revisions_new(id),
+    FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE
+);
+
+INSERT INTO proposal_revisions_new
+    (id, proposal_id, content, revision_number, created_at, revision_type, restored_from_id)
+SELECT id, proposal_id, content, revision_number, created_at, revision_type, restored_from_id
+FROM proposal_revisions;
+
+DROP TABLE proposal_revisions;
+
+ALTER TABLE proposal_revisions_new RENAME TO proposal_revisions;
+
+CREATE INDEX IF NOT EXISTS idx_proposal_revisions_proposal_id
+    ON proposal_revisions(proposal_id);
+
+CREATE INDEX IF NOT EXISTS idx_proposal_revisions_revision_number
+    ON proposal_revisions(proposal_id, revision_number DESC);
+
+CREATE INDEX IF NOT EXISTS idx_proposal_revisions_proposal_created
+    ON proposal_revisions(proposal_id, created_at DESC);
@@ -0,0 +1,7 @@
+-- Migration: V46 - A/B rotation membership for hook strategies
+-- Purpose: Restrict automatic A/B selection to a chosen subset of strategies (e.g. only
+-- Contrarian vs Question-Based) without zeroing the others' weights. Every existing
+-- strategy starts in rotation, so selection behaves as before until the user narrows it.
+-- Remote config sync leaves this column alone; it is a local preference.
+
+ALTER TABLE hook_strategies ADD COLUMN in_ab_rotation INTEGER NOT NULL DEFAULT 1;
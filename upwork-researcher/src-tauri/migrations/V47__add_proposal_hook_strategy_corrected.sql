@@ -0,0 +1,6 @@
+-- Migration: V47 - Manual hook strategy corrections
+-- Purpose: update_proposal_hook_strategy relabels a proposal whose hook strategy was
+-- recorded wrong. A corrected proposal is no longer an A/B observation (ab_assigned is
+-- cleared), and this flag lets strategy effectiveness count or exclude corrections.
+
+ALTER TABLE proposals ADD COLUMN hook_strategy_corrected INTEGER NOT NULL DEFAULT 0;
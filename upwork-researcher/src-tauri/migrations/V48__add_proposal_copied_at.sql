@@ -0,0 +1,7 @@
+-- Migration: V48 - Proposal copy tracking
+-- Purpose: record_proposal_copied stamps the last time a proposal was copied for
+-- sending. Copying is the start of the outcome funnel: a proposal that was never
+-- copied was never sent, so analytics report copied counts and copy→outcome
+-- conversion from this column. NULL means never copied.
+
+ALTER TABLE proposals ADD COLUMN copied_at TEXT;
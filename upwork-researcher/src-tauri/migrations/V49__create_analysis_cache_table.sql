@@ -0,0 +1,14 @@
+-- Migration: V49 - Job analysis cache
+-- Purpose: analyze_job_post caches the Haiku analysis and extracted budget keyed by a
+-- whitespace-normalized SHA-256 of the job text, so analyzing the same job again
+-- (after a restart, or when it arrives via RSS and paste) skips both API calls.
+-- Entries older than analysis_cache_days are ignored and pruned at startup.
+
+CREATE TABLE IF NOT EXISTS analysis_cache (
+    content_hash TEXT PRIMARY KEY,
+    analysis_json TEXT NOT NULL,
+    budget_json TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_analysis_cache_created_at ON analysis_cache(created_at);
@@ -0,0 +1,7 @@
+-- Migration: V50 - Voice profile name in generation metadata
+-- Purpose: users can keep several named voice profiles and pick one per proposal,
+-- so record which profile generated each proposal alongside its version.
+-- NULL = default voice (not calibrated) or a proposal generated before profiles
+-- had names. rename_voice_profile keeps this column in step with the profile.
+
+ALTER TABLE generation_metadata ADD COLUMN voice_profile_name TEXT;
@@ -0,0 +1,20 @@
+-- Migration: V51 - Revision deduplication and delta storage
+-- Purpose: every autosave used to store a full copy of the proposal, so an hour
+-- of editing left dozens of near-identical rows. content_hash (SHA-256 of the
+-- full text) lets create_revision skip a save identical to the latest revision.
+-- Revisions are stored either as full text ('full', a keyframe) or as a delta
+-- against keyframe_id ('delta'); readers reconstruct deltas transparently.
+-- Existing rows are full text. Their hashes are backfilled at startup
+-- (SQLite has no SHA-256), and a NULL hash is computed on demand until then.
+
+ALTER TABLE proposal_revisions ADD COLUMN content_hash TEXT;
+
+ALTER TABLE proposal_revisions ADD COLUMN storage TEXT NOT NULL DEFAULT 'full'
+    CHECK (storage IN ('full', 'delta'));
+
+-- No foreign key: archiving rewrites deltas as full text before their keyframe
+-- leaves the table
+ALTER TABLE proposal_revisions ADD COLUMN keyframe_id INTEGER;
+
+CREATE INDEX IF NOT EXISTS idx_proposal_revisions_keyframe_id
+    ON proposal_revisions(keyframe_id);
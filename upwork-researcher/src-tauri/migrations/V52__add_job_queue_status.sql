@@ -0,0 +1,13 @@
+-- Job queue archive/snooze
+--
+-- queue_status is the user's own triage of a job, separate from job_status
+-- (freshness and applied tracking, which the app sets itself): 'active'
+-- (default), 'archived' (kept but out of the queue) or 'snoozed' (hidden until
+-- snoozed_until, UTC 'YYYY-MM-DD HH:MM:SS'). A snooze whose snoozed_until has
+-- passed counts as active at query time; nothing rewrites the row when it ends.
+
+ALTER TABLE job_posts ADD COLUMN queue_status TEXT NOT NULL DEFAULT 'active'
+    CHECK (queue_status IN ('active', 'archived', 'snoozed'));
+ALTER TABLE job_posts ADD COLUMN snoozed_until TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_job_posts_queue_status ON job_posts(queue_status, snoozed_until);
@@ -0,0 +1,7 @@
+-- Migration: V53 - Prompt context in generation metadata
+-- Purpose: trace a change in generation quality to a prompt change. JSON
+-- (claude::GenerationContext): prompt template version, SHA-256 of the assembled
+-- system prompt and the parameter values used. No prompt text or job content.
+-- NULL = proposal generated before prompt versioning.
+
+ALTER TABLE generation_metadata ADD COLUMN generation_context TEXT;
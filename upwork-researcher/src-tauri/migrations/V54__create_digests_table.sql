@@ -0,0 +1,16 @@
+-- Weekly digests (digest::generate_weekly_digest)
+--
+-- One row per completed ISO week, keyed by week_label ('YYYY-Www'). week_start
+-- is the Monday 00:00:00 UTC that opens the week, in the same
+-- 'YYYY-MM-DD HH:MM:SS' form as other timestamps. digest_json holds the
+-- serialized digest::WeeklyDigest and markdown its pre-rendered text; both are
+-- replaced when a digest is regenerated.
+
+CREATE TABLE IF NOT EXISTS digests (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    week_label TEXT NOT NULL UNIQUE,
+    week_start TEXT NOT NULL,
+    digest_json TEXT NOT NULL,
+    markdown TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
@@ -0,0 +1,21 @@
+-- Milestone notification log (notifications module)
+--
+-- One row per milestone alert. dedupe_key identifies the milestone
+-- ('hired:<proposal id>', 'strategy_threshold:<strategy>:<pct>') so each fires
+-- once. desktop_status is 'sent' or 'failed'; webhook_status is 'sent',
+-- 'failed', 'blocked' (URL not allowlisted), 'not_configured', or 'pending'
+-- while the POST is in flight. error holds the last delivery error, if any.
+
+CREATE TABLE IF NOT EXISTS notification_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    rule TEXT NOT NULL,
+    dedupe_key TEXT NOT NULL,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL,
+    desktop_status TEXT NOT NULL,
+    webhook_status TEXT NOT NULL,
+    error TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_notification_log_dedupe_key ON notification_log(dedupe_key);
@@ -0,0 +1,6 @@
+-- Migration: V56 - API key profile in generation metadata
+-- Purpose: users can keep several named API keys (e.g. personal and client-billed),
+-- so record which profile's key paid for each generation and spend can be
+-- attributed per key. NULL = generated before profiles were recorded, or imported.
+
+ALTER TABLE generation_metadata ADD COLUMN api_key_profile TEXT;
@@ -0,0 +1,11 @@
+-- Migration: V57 - Proposal variant groups
+-- Purpose: "try 3 variants" generates several proposals for one job in a single
+-- request. Each variant is its own proposals row sharing a variant_group_id (UUID);
+-- promote_variant keeps one and marks the others status = 'discarded'.
+-- NULL = an ordinary single generation.
+
+ALTER TABLE proposals ADD COLUMN variant_group_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_proposals_variant_group
+    ON proposals(variant_group_id)
+    WHERE variant_group_id IS NOT NULL;
@@ -0,0 +1,42 @@
+-- V58: Allow 'rehumanize' revisions (rehumanize_proposal)
+-- Same rebuild as V45, now carrying V51's content_hash / storage / keyframe_id
+-- columns. The self-reference names proposal_revisions_new so dropping the old
+-- table doesn't trip restored_from_id; the rename rewrites it.
+
+CREATE TABLE proposal_revisions_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    proposal_id INTEGER NOT NULL,
+    content TEXT NOT NULL,
+    revision_number INTEGER NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    revision_type TEXT NOT NULL DEFAULT 'edit'
+        CHECK (revision_type IN ('generation', 'edit', 'restore', 'partial_regen', 'rehumanize')),
+    restored_from_id INTEGER REFERENCES proposal_revisions_new(id),
+    content_hash TEXT,
+    storage TEXT NOT NULL DEFAULT 'full' CHECK (storage IN ('full', 'delta')),
+    keyframe_id INTEGER,
+    FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE
+);
+
+INSERT INTO proposal_revisions_new
+    (id, proposal_id, content, revision_number, created_at, revision_type, restored_from_id,
+     content_hash, storage, keyframe_id)
+SELECT id, proposal_id, content, revision_number, created_at, revision_type, restored_from_id,
+       content_hash, storage, keyframe_id
+FROM proposal_revisions;
+
+DROP TABLE proposal_revisions;
+
+ALTER TABLE proposal_revisions_new RENAME TO proposal_revisions;
+
+CREATE INDEX IF NOT EXISTS idx_proposal_revisions_proposal_id
+    ON proposal_revisions(proposal_id);
+
+CREATE INDEX IF NOT EXISTS idx_proposal_revisions_revision_number
+    ON proposal_revisions(proposal_id, revision_number DESC);
+
+CREATE INDEX IF NOT EXISTS idx_proposal_revisions_proposal_created
+    ON proposal_revisions(proposal_id, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_proposal_revisions_keyframe_id
+    ON proposal_revisions(keyframe_id);
@@ -2,11 +2,21 @@
 //!
 //! Implements weighted random assignment for proposal hook strategies (Story 10.4).
 //! Strategies with ab_weight > 0.0 are eligible for A/B testing; weight 0.0 = inactive.
+//! Selection is further limited to strategies flagged `in_ab_rotation`, so a test can be
+//! narrowed to a subset (e.g. Contrarian vs Question-Based) without touching weights.
 
 use crate::db::queries::hook_strategies::HookStrategy;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// Fewest proposals with a recorded outcome a strategy needs before its
+/// response rate is compared against others
+pub const MIN_SIGNIFICANCE_SAMPLES: i64 = 10;
+
+/// p-value below which a difference in response rate is reported as real
+pub const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
 /// Errors returned by A/B testing selection (Story 10.4: Task 2.9)
 #[derive(Debug, Error, PartialEq)]
 pub enum ABTestingError {
@@ -14,17 +24,35 @@ pub enum ABTestingError {
     NoActiveWeights,
 }
 
+/// Strategies selection may pick from: those in A/B rotation with a nonzero weight.
+/// When no strategy is flagged in rotation, the rotation doesn't restrict anything
+/// and every strategy with a nonzero weight is eligible.
+fn eligible_strategies(strategies: &[HookStrategy]) -> Vec<&HookStrategy> {
+    let any_in_rotation = strategies.iter().any(|s| s.in_ab_rotation);
+    strategies
+        .iter()
+        .filter(|s| s.in_ab_rotation || !any_in_rotation)
+        .filter(|s| s.ab_weight > 0.0)
+        .collect()
+}
+
+/// True when selection has at most one candidate, so it always picks the same
+/// strategy: that is a fixed choice, not an A/B assignment.
+pub fn is_fixed_selection(strategies: &[HookStrategy]) -> bool {
+    eligible_strategies(strategies).len() < 2
+}
+
 /// Select a hook strategy via weighted random assignment (Story 10.4: AC-1).
 ///
-/// Filters strategies with ab_weight == 0.0, normalizes remaining weights,
-/// then picks one using cumulative probability.
+/// Considers only strategies in A/B rotation, filters those with ab_weight == 0.0,
+/// normalizes remaining weights, then picks one using cumulative probability.
 ///
 /// # Arguments
 /// * `strategies` - Slice of HookStrategy from the database (includes ab_weight)
 ///
 /// # Returns
 /// * `Ok((hook_strategy_name, ab_weight_at_assignment))` - selected strategy key and original weight
-/// * `Err(ABTestingError::NoActiveWeights)` - all weights are 0.0 (AC-6 fallback)
+/// * `Err(ABTestingError::NoActiveWeights)` - all in-rotation weights are 0.0 (AC-6 fallback)
 ///
 /// # Example
 /// ```
@@ -43,11 +71,8 @@ pub fn select_hook_strategy_ab_with_rng<R: Rng>(
     strategies: &[HookStrategy],
     rng: &mut R,
 ) -> Result<(String, f32), ABTestingError> {
-    // Task 2.4: Filter out strategies with ab_weight == 0.0
-    let active: Vec<&HookStrategy> = strategies
-        .iter()
-        .filter(|s| s.ab_weight > 0.0)
-        .collect();
+    // Task 2.4: Filter out strategies with ab_weight == 0.0 (and those out of rotation)
+    let active = eligible_strategies(strategies);
 
     // Task 2.9: All weights are 0.0 → NoActiveWeights error (AC-6 trigger)
     if active.is_empty() {
@@ -76,6 +101,87 @@ pub fn select_hook_strategy_ab_with_rng<R: Rng>(
     Ok((last.name.clone(), last.ab_weight as f32))
 }
 
+/// Hook strategies for `count` variants of one proposal: the eligible strategies
+/// (as for `select_hook_strategy_ab`) by descending weight, then name, repeating
+/// from the top when there are fewer strategies than variants. Deterministic, so
+/// the same settings always produce the same variant line-up.
+pub fn variant_hook_strategies(
+    strategies: &[HookStrategy],
+    count: usize,
+) -> Result<Vec<String>, ABTestingError> {
+    let mut active = eligible_strategies(strategies);
+    if active.is_empty() {
+        return Err(ABTestingError::NoActiveWeights);
+    }
+    active.sort_by(|a, b| {
+        b.ab_weight
+            .total_cmp(&a.ab_weight)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    Ok(active
+        .iter()
+        .cycle()
+        .take(count)
+        .map(|s| s.name.clone())
+        .collect())
+}
+
+/// How a strategy's response rate compares with the best-performing strategy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Significance {
+    /// The best strategy with enough data; others are compared against it
+    Baseline,
+    /// Differs from the baseline at `SIGNIFICANCE_LEVEL`
+    Significant,
+    /// The difference from the baseline could be noise
+    NotSignificant,
+    /// Fewer than `MIN_SIGNIFICANCE_SAMPLES` outcomes, or nothing to compare against
+    InsufficientData,
+}
+
+/// Classify a p-value from `two_proportion_p_value`; None means insufficient data
+pub fn classify_p_value(p_value: Option<f64>) -> Significance {
+    match p_value {
+        Some(p) if p < SIGNIFICANCE_LEVEL => Significance::Significant,
+        Some(_) => Significance::NotSignificant,
+        None => Significance::InsufficientData,
+    }
+}
+
+/// Two-sided p-value of a two-proportion z-test: does `won_a / n_a` differ from `won_b / n_b`?
+///
+/// None when either sample has fewer than two observations. When both samples are
+/// all wins or all losses there is no variance and no evidence of a difference, so 1.0.
+pub fn two_proportion_p_value(won_a: i64, n_a: i64, won_b: i64, n_b: i64) -> Option<f64> {
+    if n_a < 2 || n_b < 2 || won_a < 0 || won_b < 0 || won_a > n_a || won_b > n_b {
+        return None;
+    }
+    let (n_a, n_b) = (n_a as f64, n_b as f64);
+    let pooled = (won_a + won_b) as f64 / (n_a + n_b);
+    let std_err = (pooled * (1.0 - pooled) * (1.0 / n_a + 1.0 / n_b)).sqrt();
+    if std_err == 0.0 {
+        return Some(1.0);
+    }
+    let z = (won_a as f64 / n_a - won_b as f64 / n_b) / std_err;
+    Some((2.0 * (1.0 - standard_normal_cdf(z.abs()))).clamp(0.0, 1.0))
+}
+
+/// Standard normal CDF via the Abramowitz & Stegun 7.1.26 erf approximation (error < 1.5e-7)
+fn standard_normal_cdf(z: f64) -> f64 {
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.3275911 * x);
+    let poly = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1.0 - poly * (-x * x).exp();
+    if z >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,9 +198,91 @@ mod tests {
             status: "active".to_string(),
             remote_id: None,
             ab_weight,
+            in_ab_rotation: true,
+        }
+    }
+
+    fn out_of_rotation(name: &str, ab_weight: f64) -> HookStrategy {
+        HookStrategy {
+            in_ab_rotation: false,
+            ..make_strategy(name, ab_weight)
+        }
+    }
+
+    #[test]
+    fn test_only_rotation_members_selected() {
+        let strategies = vec![
+            out_of_rotation("social_proof", 0.9),
+            make_strategy("contrarian", 0.3),
+            make_strategy("question_based", 0.3),
+        ];
+        assert!(!is_fixed_selection(&strategies));
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..200 {
+            let (name, _) = select_hook_strategy_ab_with_rng(&strategies, &mut rng).unwrap();
+            assert_ne!(name, "social_proof");
         }
     }
 
+    #[test]
+    fn test_variant_strategies_rotate_by_weight() {
+        let strategies = vec![
+            make_strategy("question_based", 0.2),
+            out_of_rotation("social_proof", 0.9),
+            make_strategy("contrarian", 0.5),
+            make_strategy("data_driven", 0.2),
+            make_strategy("story", 0.0),
+        ];
+        assert_eq!(
+            variant_hook_strategies(&strategies, 3).unwrap(),
+            vec!["contrarian", "data_driven", "question_based"]
+        );
+        // Fewer eligible strategies than variants: repeat from the top
+        assert_eq!(
+            variant_hook_strategies(&strategies[..2], 3).unwrap(),
+            vec!["question_based"; 3]
+        );
+        assert_eq!(
+            variant_hook_strategies(&[make_strategy("story", 0.0)], 3),
+            Err(ABTestingError::NoActiveWeights)
+        );
+    }
+
+    #[test]
+    fn test_single_rotation_member_is_fixed_selection() {
+        let strategies = vec![
+            out_of_rotation("social_proof", 0.5),
+            make_strategy("contrarian", 0.2),
+            make_strategy("question_based", 0.0),
+        ];
+        assert!(is_fixed_selection(&strategies));
+        for _ in 0..50 {
+            assert_eq!(
+                select_hook_strategy_ab(&strategies).unwrap().0,
+                "contrarian"
+            );
+        }
+
+        // Nothing flagged: rotation doesn't restrict, all weighted strategies compete
+        let none_flagged = vec![out_of_rotation("a", 0.5), out_of_rotation("b", 0.5)];
+        assert!(!is_fixed_selection(&none_flagged));
+        assert!(select_hook_strategy_ab(&none_flagged).is_ok());
+    }
+
+    #[test]
+    fn test_zero_weight_rotation_returns_no_active_error() {
+        // Out-of-rotation weights don't rescue an all-zero rotation
+        let strategies = vec![
+            out_of_rotation("social_proof", 1.0),
+            make_strategy("contrarian", 0.0),
+            make_strategy("question_based", 0.0),
+        ];
+        assert_eq!(
+            select_hook_strategy_ab(&strategies).unwrap_err(),
+            ABTestingError::NoActiveWeights
+        );
+    }
+
     // Task 2.10: 8+ unit tests
 
     #[test]
@@ -318,4 +506,41 @@ mod tests {
             rate_a
         );
     }
+
+    #[test]
+    fn test_two_proportion_p_value_known_inputs() {
+        // Reference values from scipy (two-sided, pooled standard error)
+        let p = two_proportion_p_value(30, 100, 40, 100).unwrap();
+        assert!((p - 0.138208).abs() < 1e-4, "p = {}", p);
+        let p = two_proportion_p_value(50, 100, 30, 100).unwrap();
+        assert!((p - 0.003892).abs() < 1e-4, "p = {}", p);
+        let p = two_proportion_p_value(4, 10, 3, 10).unwrap();
+        assert!((p - 0.639207).abs() < 1e-4, "p = {}", p);
+
+        // Symmetric, and equal rates are indistinguishable
+        assert_eq!(
+            two_proportion_p_value(30, 100, 40, 100),
+            two_proportion_p_value(40, 100, 30, 100)
+        );
+        assert!((two_proportion_p_value(5, 10, 10, 20).unwrap() - 1.0).abs() < 1e-6);
+
+        assert_eq!(classify_p_value(Some(0.0039)), Significance::Significant);
+        assert_eq!(classify_p_value(Some(0.138)), Significance::NotSignificant);
+    }
+
+    #[test]
+    fn test_two_proportion_p_value_degenerate_samples() {
+        // Zero or one outcome: nothing to test
+        assert_eq!(two_proportion_p_value(0, 0, 3, 10), None);
+        assert_eq!(two_proportion_p_value(1, 1, 3, 10), None);
+        assert_eq!(two_proportion_p_value(3, 10, 0, 0), None);
+        assert_eq!(classify_p_value(None), Significance::InsufficientData);
+
+        // No variance (all lost / all won on both sides) must not divide by zero
+        assert_eq!(two_proportion_p_value(0, 10, 0, 12), Some(1.0));
+        assert_eq!(two_proportion_p_value(10, 10, 12, 12), Some(1.0));
+
+        // Inconsistent counts are rejected rather than producing NaN
+        assert_eq!(two_proportion_p_value(11, 10, 3, 10), None);
+    }
 }
@@ -2,14 +2,21 @@
 /// Story 4a.2: Client Name Extraction
 /// Extensible design for future analysis fields (4a-3: skills, 4a-4: hidden needs)
 /// Story 4a.9: Prompt injection defense via input sanitization
+use crate::api_quota;
+use crate::http::TrackedSend;
+use crate::logs::api_debug;
 use crate::sanitization::sanitize_job_content;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
-const ANTHROPIC_API_URL: &str = "https://api.anthropic.com/v1/messages";
+mod response_parsing;
+
 const ANTHROPIC_VERSION: &str = "2023-06-01";
 const HAIKU_MODEL: &str = "claude-3-5-haiku-20241022";
 const PROMPT_CACHING_BETA: &str = "prompt-caching-2024-07-31";
+/// Whole-request cap for the non-streaming Haiku calls behind `analyze_job_post`.
+/// Deliberately much shorter than the generation idle timeout (`generation_timeout_secs`).
+const ANALYSIS_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Hidden need detected from job post language patterns
 /// Story 4a.4: Structured insight with supporting evidence
@@ -27,6 +34,9 @@ pub struct HiddenNeed {
 /// Story 4a.9: was_truncated flag for input sanitization
 /// Story 4b.3: client_quality_score (0-100 integer)
 /// Story 4b.4: budget_min, budget_max, budget_type (budget alignment)
+/// required_deliverables / nice_to_have_deliverables: explicit requirement checklist
+/// prior_history: earlier jobs/proposals for the same (normalized) client
+/// parse_warnings: fields of the Haiku response that were missing or unusable
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct JobAnalysis {
@@ -54,6 +64,42 @@ pub struct JobAnalysis {
     /// Story 4b.4: Budget alignment status: "green", "yellow", "red", "gray", "mismatch" (AC-2)
     #[serde(default = "default_alignment_status")]
     pub budget_alignment_status: String,
+    /// Must-have deliverables/requirements the client explicitly listed
+    #[serde(default)]
+    pub required_deliverables: Vec<String>,
+    /// Requirements the client marked as optional ("nice to have", "bonus", "plus")
+    #[serde(default)]
+    pub nice_to_have_deliverables: Vec<String>,
+    /// Set by `analyze_job_post` when the client has earlier job posts (repeat client)
+    #[serde(default)]
+    pub prior_history: Option<crate::db::queries::clients::ClientPriorHistory>,
+    /// True when `analyze_job_post` served this from the analysis cache (no API call)
+    #[serde(default)]
+    pub cached: bool,
+    /// Fields of the analysis response that were missing or had the wrong type and
+    /// were left empty; the rest of the analysis is still used
+    #[serde(default)]
+    pub parse_warnings: Vec<String>,
+}
+
+/// Deliverables checklist as persisted in `job_posts.deliverables`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobDeliverables {
+    #[serde(default)]
+    pub required: Vec<String>,
+    #[serde(default)]
+    pub nice_to_have: Vec<String>,
+}
+
+impl JobAnalysis {
+    /// Deliverables in their persisted shape
+    pub fn deliverables(&self) -> JobDeliverables {
+        JobDeliverables {
+            required: self.required_deliverables.clone(),
+            nice_to_have: self.nice_to_have_deliverables.clone(),
+        }
+    }
 }
 
 /// Default budget type when field is missing
@@ -131,6 +177,10 @@ struct AnalysisResponse {
     hidden_needs: Vec<HiddenNeed>,
     /// Story 4b.3: Client quality score (0-100)
     client_quality_score: Option<i32>,
+    #[serde(default)]
+    required_deliverables: Vec<String>,
+    #[serde(default)]
+    nice_to_have_deliverables: Vec<String>,
     // Note: Budget fields removed in code review - budget extraction is handled
     // separately by extract_budget() which uses a dedicated API call (Story 4b.4)
 }
@@ -143,6 +193,9 @@ pub struct BudgetInfo {
     pub min: Option<f64>,
     pub max: Option<f64>,
     pub budget_type: String, // "hourly", "fixed", "unknown"
+    /// ISO currency code detected in the job text; None = no marker (USD assumed)
+    #[serde(default)]
+    pub currency: Option<String>,
 }
 
 /// Budget alignment result (Story 4b.4, Task 5)
@@ -150,6 +203,20 @@ pub struct BudgetInfo {
 pub struct BudgetAlignment {
     pub percentage: Option<i32>, // 0-100+ or null
     pub status: String,          // "green", "yellow", "red", "gray", "mismatch"
+    /// Budget converted to USD (None if not compared, e.g. unknown currency)
+    pub normalized_min: Option<f64>,
+    pub normalized_max: Option<f64>,
+}
+
+impl BudgetAlignment {
+    fn without_percentage(status: &str) -> Self {
+        Self {
+            percentage: None,
+            status: status.to_string(),
+            normalized_min: None,
+            normalized_max: None,
+        }
+    }
 }
 
 /// Extract budget information from job post using Claude Haiku (Story 4b.4, Task 4)
@@ -242,18 +309,26 @@ Return ONLY valid JSON, no other text."#;
         }],
     };
 
+    // AR-14: configurable base URL (api_base_url) must pass the allowlist
+    let api_url = crate::network::messages_url();
+    crate::network::validate_url(&api_url).map_err(|e| format!("Network security: {}", e))?;
+
+    api_debug::log_request("extract_budget", api_key, &request_body);
+
     let response = client
-        .post(ANTHROPIC_API_URL)
-        .timeout(Duration::from_secs(5))
+        .post(&api_url)
+        .timeout(ANALYSIS_REQUEST_TIMEOUT)
         .header("x-api-key", api_key)
         .header("anthropic-version", ANTHROPIC_VERSION)
         .header("anthropic-beta", PROMPT_CACHING_BETA) // AR-5: Enable caching
         .header("content-type", "application/json")
         .json(&request_body)
-        .send()
+        .send_tracked()
         .await
         .map_err(|e| {
-            let error_msg = if e.is_timeout() {
+            let error_msg = if e.is_locally_rate_limited() {
+                e.to_string()
+            } else if e.is_timeout() {
                 "Budget extraction timed out".to_string()
             } else if e.is_connect() {
                 "Unable to reach AI service for budget extraction".to_string()
@@ -268,6 +343,10 @@ Return ONLY valid JSON, no other text."#;
 
     if !status.is_success() {
         let error_text = response.text().await.unwrap_or_default();
+        api_debug::log_response("extract_budget", api_key, status.as_u16(), &error_text);
+        if let Some(overloaded) = api_quota::overloaded_error(status.as_u16(), &error_text) {
+            return Err(overloaded);
+        }
         if let Ok(error) = serde_json::from_str::<ClaudeError>(&error_text) {
             tracing::error!("Budget extraction API error: {}", error.error.message);
             return Err(format!("API error: {}", error.error.message));
@@ -288,10 +367,11 @@ Return ONLY valid JSON, no other text."#;
         .first()
         .and_then(|block| block.text.as_ref())
         .ok_or_else(|| "No content in API response".to_string())?;
+    api_debug::log_response("extract_budget", api_key, status.as_u16(), response_text);
 
     // Parse JSON response (handle potential code block wrapping)
     let json_str = extract_json_from_response(response_text);
-    let budget_info: BudgetInfo = match serde_json::from_str(json_str) {
+    let mut budget_info: BudgetInfo = match serde_json::from_str(json_str) {
         Ok(info) => info,
         Err(e) => {
             tracing::warn!(
@@ -304,15 +384,19 @@ Return ONLY valid JSON, no other text."#;
                 min: None,
                 max: None,
                 budget_type: "unknown".to_string(),
+                currency: None,
             }
         }
     };
+    // Currency comes from the job text itself, not the model (no network dependency)
+    budget_info.currency = crate::currency::detect_currency(raw_content);
 
     tracing::info!(
-        "Budget extraction complete: type={}, min={:?}, max={:?}",
+        "Budget extraction complete: type={}, min={:?}, max={:?}, currency={:?}",
         budget_info.budget_type,
         budget_info.min,
-        budget_info.max
+        budget_info.max,
+        budget_info.currency
     );
 
     Ok(budget_info)
@@ -320,68 +404,60 @@ Return ONLY valid JSON, no other text."#;
 
 /// Calculate budget alignment between job budget and user rate configuration (Story 4b.4, Task 5)
 /// AC-1, AC-2, AC-4: Calculates alignment percentage and determines color status
+/// The budget is converted to USD with `fx` first; a currency without a rate is
+/// treated like an unknown budget (gray) instead of comparing raw amounts.
 /// Returns None for percentage if type mismatch, unknown budget, or invalid rates
 pub fn calculate_budget_alignment(
     budget: &BudgetInfo,
     user_rate: &crate::RateConfig,
+    fx: &crate::currency::FxRates,
 ) -> BudgetAlignment {
     // AC-4: Type mismatch detection (Subtask 5.3)
     if budget.budget_type == "hourly" && user_rate.hourly_rate.is_none() {
-        return BudgetAlignment {
-            percentage: None,
-            status: "mismatch".to_string(),
-        };
+        return BudgetAlignment::without_percentage("mismatch");
     }
     if budget.budget_type == "fixed" && user_rate.project_rate_min.is_none() {
-        return BudgetAlignment {
-            percentage: None,
-            status: "mismatch".to_string(),
-        };
+        return BudgetAlignment::without_percentage("mismatch");
     }
     if budget.budget_type == "unknown" {
-        return BudgetAlignment {
-            percentage: None,
-            status: "gray".to_string(),
-        };
+        return BudgetAlignment::without_percentage("gray");
     }
 
+    // Normalize to USD; unknown currency degrades to unknown alignment
+    let currency = budget.currency.as_deref();
+    if fx.to_usd(0.0, currency).is_none() {
+        tracing::debug!(?currency, "No exchange rate for budget currency");
+        return BudgetAlignment::without_percentage("gray");
+    }
+    let normalized_min = budget.min.and_then(|v| fx.to_usd(v, currency));
+    let normalized_max = budget.max.and_then(|v| fx.to_usd(v, currency));
+
     // AC-1: Calculate alignment percentage (Subtask 5.4)
     // Use budget_min for conservative estimate
     let percentage = match budget.budget_type.as_str() {
         "hourly" => {
-            let job_rate = budget.min.unwrap_or(0.0);
+            let job_rate = normalized_min.unwrap_or(0.0);
             let user_rate_val = user_rate.hourly_rate.unwrap_or(0.0);
 
             // Subtask 5.6: Handle edge cases
             if user_rate_val == 0.0 || job_rate < 0.0 {
-                return BudgetAlignment {
-                    percentage: None,
-                    status: "gray".to_string(),
-                };
+                return BudgetAlignment::without_percentage("gray");
             }
 
             ((job_rate / user_rate_val) * 100.0) as i32
         }
         "fixed" => {
-            let job_budget = budget.min.unwrap_or(0.0);
+            let job_budget = normalized_min.unwrap_or(0.0);
             let user_min = user_rate.project_rate_min.unwrap_or(0.0);
 
             // Subtask 5.6: Handle edge cases
             if user_min == 0.0 || job_budget < 0.0 {
-                return BudgetAlignment {
-                    percentage: None,
-                    status: "gray".to_string(),
-                };
+                return BudgetAlignment::without_percentage("gray");
             }
 
             ((job_budget / user_min) * 100.0) as i32
         }
-        _ => {
-            return BudgetAlignment {
-                percentage: None,
-                status: "gray".to_string(),
-            }
-        }
+        _ => return BudgetAlignment::without_percentage("gray"),
     };
 
     // AC-2: Color status based on percentage (Subtask 5.5)
@@ -397,9 +473,30 @@ pub fn calculate_budget_alignment(
     BudgetAlignment {
         percentage: Some(percentage),
         status,
+        normalized_min,
+        normalized_max,
     }
 }
 
+/// Clean up extracted deliverables: trim, drop empties and duplicates, and keep an
+/// item only in the must-have bucket if the model put it in both.
+fn normalize_deliverables(
+    required: Vec<String>,
+    nice_to_have: Vec<String>,
+) -> (Vec<String>, Vec<String>) {
+    let mut seen = std::collections::HashSet::new();
+    let mut clean = |items: Vec<String>| -> Vec<String> {
+        items
+            .into_iter()
+            .map(|item| item.trim().to_string())
+            .filter(|item| !item.is_empty() && seen.insert(item.to_lowercase()))
+            .collect()
+    };
+    let required = clean(required);
+    let nice_to_have = clean(nice_to_have);
+    (required, nice_to_have)
+}
+
 /// Analyze job post to extract client name and key skills using Claude Haiku
 /// Story 4a.2: client_name extraction
 /// Story 4a.3: key_skills extraction (3-7 skills)
@@ -435,10 +532,12 @@ Return JSON in this exact format:
     {"need": "Client is stressed", "evidence": "They mention 'urgent' and 'ASAP'"},
     {"need": "Budget-conscious", "evidence": "They emphasize 'cost-effective solution'"}
   ],
-  "client_quality_score": 85
+  "client_quality_score": 85,
+  "required_deliverables": ["Responsive dashboard with 3 chart views", "REST API integration", "Unit tests for core components"],
+  "nice_to_have_deliverables": ["Dark mode"]
 }
 
-If no name is found, set client_name to null. If no skills found, return empty array. If no hidden needs identifiable, return empty array.
+If no name is found, set client_name to null. If no skills found, return empty array. If no hidden needs identifiable, return empty array. If no deliverables are listed, return empty arrays.
 
 Guidelines - Client Name:
 - Look for: greeting signatures ("Hi, I'm..."), company names, profile references, sign-offs
@@ -468,6 +567,13 @@ Guidelines - Hidden Needs:
 - Avoid duplicating similar concepts (e.g., "urgent" and "time-pressured" should be one need)
 - Keep evidence concise (1 sentence max, quote key phrases)
 
+Guidelines - Deliverables:
+- List the concrete deliverables and requirements the client EXPLICITLY asks for (features, outputs, qualifications, constraints like deadlines or tools)
+- One short checklist item each (under 12 words), in the order they appear; do not infer unstated requirements
+- Put items in nice_to_have_deliverables only when the post signals they are optional: "nice to have", "bonus", "a plus", "preferred", "ideally", "optional"
+- Everything else the client asks for goes in required_deliverables
+- Do not repeat the same requirement in both lists
+
 Guidelines - Client Quality Score (0-100 integer):
 Estimate the client's quality based on signals in the job post. Return an integer from 0-100.
 
@@ -499,7 +605,7 @@ Examples:
 
 Example 1 - Technical job with explicit client (high quality):
 Job post: "Hi! I'm Sarah Chen, founder of TechStartup Inc. Looking for a React developer to build our dashboard. Must have experience with TypeScript, REST APIs, and unit testing. We've completed 15 projects on Upwork with a 100% hire rate and verified payment."
-Response: {"client_name": "Sarah Chen", "key_skills": ["React", "TypeScript", "REST APIs", "Unit Testing"], "hidden_needs": [], "client_quality_score": 90}
+Response: {"client_name": "Sarah Chen", "key_skills": ["React", "TypeScript", "REST APIs", "Unit Testing"], "hidden_needs": [], "client_quality_score": 90, "required_deliverables": ["Build the dashboard in React", "TypeScript experience", "REST API experience", "Unit testing experience"], "nice_to_have_deliverables": []}
 
 Example 2 - Company name only, non-technical skills (medium quality):
 Job post: "Acme Corporation is seeking a freelance content writer for our blog. SEO experience required. Must be able to research topics and write engaging articles."
@@ -507,7 +613,7 @@ Response: {"client_name": "Acme Corporation", "key_skills": ["Content Writing",
 
 Example 3 - No name, technical skills from implied requirements (medium quality):
 Job post: "Need help with Python automation. Must build scripts to scrape websites and process data. Budget $500."
-Response: {"client_name": null, "key_skills": ["Python", "Web Scraping", "Data Processing", "Automation"], "hidden_needs": [], "client_quality_score": 68}
+Response: {"client_name": null, "key_skills": ["Python", "Web Scraping", "Data Processing", "Automation"], "hidden_needs": [], "client_quality_score": 68, "required_deliverables": ["Python scripts that scrape websites", "Process the scraped data"], "nice_to_have_deliverables": []}
 
 Example 4 - Name in sign-off, vague requirements (medium quality):
 Job post: "Looking for general help with website updates. Thanks, Michael Rodriguez"
@@ -538,14 +644,14 @@ Return ONLY valid JSON, no other text."#;
     // Story 4a.9 AC-2: Wrap sanitized content in XML delimiters (AR-13)
     // Story 4b.3: Extended to request client quality score
     let user_message = format!(
-        "Analyze this job post and extract the client name, key skills, hidden needs, and client quality score:\n\n<job_post>\n{}\n</job_post>",
+        "Analyze this job post and extract the client name, key skills, hidden needs, client quality score, and deliverables:\n\n<job_post>\n{}\n</job_post>",
         sanitization_result.content
     );
 
     // AR-5: Enable prompt caching on system prompt with few-shot examples
     let request_body = ClaudeRequest {
         model: HAIKU_MODEL.to_string(),
-        max_tokens: 600, // Client name + skills + hidden needs + client quality score
+        max_tokens: 900, // Client name + skills + hidden needs + client quality score + deliverables
         system: vec![ContentBlock::Text {
             text: system_prompt.to_string(),
             cache_control: Some(CacheControl {
@@ -561,18 +667,26 @@ Return ONLY valid JSON, no other text."#;
         }],
     };
 
+    // AR-14: configurable base URL (api_base_url) must pass the allowlist
+    let api_url = crate::network::messages_url();
+    crate::network::validate_url(&api_url).map_err(|e| format!("Network security: {}", e))?;
+
+    api_debug::log_request("analyze_job", api_key, &request_body);
+
     let response = client
-        .post(ANTHROPIC_API_URL)
-        .timeout(Duration::from_secs(5)) // AC-4: <3 seconds target, 5s allows for network latency
+        .post(&api_url)
+        .timeout(ANALYSIS_REQUEST_TIMEOUT) // AC-4: <3 seconds target, 5s allows for network latency
         .header("x-api-key", api_key)
         .header("anthropic-version", ANTHROPIC_VERSION)
         .header("anthropic-beta", PROMPT_CACHING_BETA) // AR-5: Enable caching
         .header("content-type", "application/json")
         .json(&request_body)
-        .send()
+        .send_tracked()
         .await
         .map_err(|e| {
-            let error_msg = if e.is_timeout() {
+            let error_msg = if e.is_locally_rate_limited() {
+                e.to_string()
+            } else if e.is_timeout() {
                 "Job analysis timed out".to_string()
             } else if e.is_connect() {
                 "Unable to reach AI service for analysis".to_string()
@@ -587,6 +701,10 @@ Return ONLY valid JSON, no other text."#;
 
     if !status.is_success() {
         let error_text = response.text().await.unwrap_or_default();
+        api_debug::log_response("analyze_job", api_key, status.as_u16(), &error_text);
+        if let Some(overloaded) = api_quota::overloaded_error(status.as_u16(), &error_text) {
+            return Err(overloaded);
+        }
         if let Ok(error) = serde_json::from_str::<ClaudeError>(&error_text) {
             tracing::error!("Job analysis API error: {}", error.error.message);
             return Err(format!("API error: {}", error.error.message));
@@ -607,17 +725,32 @@ Return ONLY valid JSON, no other text."#;
         .first()
         .and_then(|block| block.text.as_ref())
         .ok_or_else(|| "No content in API response".to_string())?;
+    api_debug::log_response("analyze_job", api_key, status.as_u16(), response_text);
 
-    // Parse JSON response (handle potential code block wrapping)
-    let json_str = extract_json_from_response(response_text);
-    let analysis_response: AnalysisResponse = serde_json::from_str(json_str).map_err(|e| {
+    // Parse JSON response (prose, code fences and common JSON slips are tolerated)
+    let parsed = response_parsing::parse_analysis_response(response_text).map_err(|e| {
         tracing::warn!(
-            "Failed to parse job analysis JSON: {}. Response: {}",
-            e,
-            response_text
+            response_len = response_text.len(),
+            "Failed to parse job analysis JSON: {}",
+            e
+        );
+        tracing::debug!(
+            "Unparsable job analysis response: {}",
+            redacted_response_excerpt(response_text)
         );
         format!("Failed to parse analysis response: {}", e)
     })?;
+    if parsed.repaired {
+        tracing::info!("Job analysis JSON parsed after repair");
+    }
+    if !parsed.warnings.is_empty() {
+        tracing::warn!(warnings = ?parsed.warnings, "Job analysis response incomplete");
+        tracing::debug!(
+            "Incomplete job analysis response: {}",
+            redacted_response_excerpt(response_text)
+        );
+    }
+    let analysis_response = parsed.response;
 
     // Story 4b.3 AC-3: Apply hard rules for client quality score
     let client_quality_score = apply_client_quality_hard_rules(
@@ -625,6 +758,11 @@ Return ONLY valid JSON, no other text."#;
         &sanitization_result.content,
     );
 
+    let (required_deliverables, nice_to_have_deliverables) = normalize_deliverables(
+        analysis_response.required_deliverables,
+        analysis_response.nice_to_have_deliverables,
+    );
+
     let result = JobAnalysis {
         client_name: analysis_response.client_name,
         key_skills: analysis_response.key_skills,
@@ -638,6 +776,11 @@ Return ONLY valid JSON, no other text."#;
         // Story 4b.4: Alignment fields populated by calculate_budget_alignment()
         budget_alignment_pct: None,
         budget_alignment_status: "gray".to_string(),
+        required_deliverables,
+        nice_to_have_deliverables,
+        prior_history: None,
+        cached: false,
+        parse_warnings: parsed.warnings,
     };
 
     tracing::info!(
@@ -652,6 +795,214 @@ Return ONLY valid JSON, no other text."#;
     Ok(result)
 }
 
+/// How requirement coverage was determined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CoverageMethod {
+    /// Semantic match by Claude Haiku
+    Llm,
+    /// Offline fallback: shared significant words
+    Keyword,
+}
+
+/// Which of a job's required deliverables a proposal addresses
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalCoverage {
+    pub addressed: Vec<String>,
+    /// Unaddressed requirements, in checklist order
+    pub missing: Vec<String>,
+    /// Share of requirements addressed (0-100; 100 when there are none)
+    pub coverage_percentage: u8,
+    pub method: CoverageMethod,
+}
+
+impl ProposalCoverage {
+    fn from_flags(requirements: &[String], addressed: &[bool], method: CoverageMethod) -> Self {
+        let (hit, miss): (Vec<_>, Vec<_>) = requirements
+            .iter()
+            .zip(addressed)
+            .partition(|(_, &is_addressed)| is_addressed);
+        let coverage_percentage = if requirements.is_empty() {
+            100
+        } else {
+            ((hit.len() as f64 / requirements.len() as f64) * 100.0).round() as u8
+        };
+        Self {
+            addressed: hit.into_iter().map(|(r, _)| r.clone()).collect(),
+            missing: miss.into_iter().map(|(r, _)| r.clone()).collect(),
+            coverage_percentage,
+            method,
+        }
+    }
+}
+
+/// Claude's coverage answer: 1-based indices of addressed requirements
+#[derive(Debug, Deserialize)]
+struct CoverageResponse {
+    #[serde(default)]
+    addressed: Vec<usize>,
+}
+
+/// Check which requirements a proposal addresses.
+/// Prefers the semantic Haiku check; falls back to keyword matching when no
+/// API key is available or the call fails (offline, timeout, bad JSON).
+pub async fn check_proposal_coverage(
+    proposal_text: &str,
+    requirements: &[String],
+    api_key: Option<&str>,
+) -> ProposalCoverage {
+    if requirements.is_empty() {
+        return ProposalCoverage::from_flags(requirements, &[], CoverageMethod::Keyword);
+    }
+    if let Some(api_key) = api_key {
+        match check_coverage_with_llm(proposal_text, requirements, api_key).await {
+            Ok(addressed) => {
+                return ProposalCoverage::from_flags(requirements, &addressed, CoverageMethod::Llm)
+            }
+            Err(e) => tracing::warn!("Coverage check fell back to keyword matching: {}", e),
+        }
+    }
+    keyword_coverage(proposal_text, requirements)
+}
+
+async fn check_coverage_with_llm(
+    proposal_text: &str,
+    requirements: &[String],
+    api_key: &str,
+) -> Result<Vec<bool>, String> {
+    let system_prompt = r#"You check whether a freelancer's Upwork proposal addresses each of the client's requirements.
+
+Return JSON in this exact format:
+{"addressed": [1, 3]}
+
+Rules:
+- List the numbers of the requirements the proposal clearly addresses. Omit the rest.
+- Match meaning, not wording: "I'll connect your backend to the payment gateway" addresses "API integration".
+- A vague claim ("I can do anything you need") does not address a specific requirement.
+- Only use numbers from the requirement list."#;
+
+    let numbered: Vec<String> = requirements
+        .iter()
+        .enumerate()
+        .map(|(i, r)| format!("{}. {}", i + 1, r))
+        .collect();
+    // AR-13: Sanitized, XML-escaped proposal inside delimiters
+    let proposal = sanitize_job_content(proposal_text);
+    let user_message = format!(
+        "<requirements>\n{}\n</requirements>\n\n<proposal>\n{}\n</proposal>\n\nWhich requirements does the proposal address?",
+        numbered.join("\n"),
+        proposal.content
+    );
+
+    let request_body = ClaudeRequest {
+        model: HAIKU_MODEL.to_string(),
+        max_tokens: 200,
+        system: vec![ContentBlock::Text {
+            text: system_prompt.to_string(),
+            cache_control: Some(CacheControl {
+                control_type: "ephemeral".to_string(),
+            }),
+        }],
+        messages: vec![Message {
+            role: "user".to_string(),
+            content: vec![ContentBlock::Text {
+                text: user_message,
+                cache_control: None,
+            }],
+        }],
+    };
+
+    // AR-14: configurable base URL (api_base_url) must pass the allowlist
+    let api_url = crate::network::messages_url();
+    crate::network::validate_url(&api_url).map_err(|e| format!("Network security: {}", e))?;
+
+    api_debug::log_request("check_proposal_coverage", api_key, &request_body);
+
+    let response = crate::http::client()
+        .post(&api_url)
+        .timeout(ANALYSIS_REQUEST_TIMEOUT)
+        .header("x-api-key", api_key)
+        .header("anthropic-version", ANTHROPIC_VERSION)
+        .header("anthropic-beta", PROMPT_CACHING_BETA) // AR-5: Enable caching
+        .header("content-type", "application/json")
+        .json(&request_body)
+        .send_tracked()
+        .await
+        .map_err(|e| format!("Network error during coverage check: {}", e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        if let Some(overloaded) = api_quota::overloaded_error(status.as_u16(), "") {
+            return Err(overloaded);
+        }
+        return Err(format!("API error ({})", status));
+    }
+
+    let response_json: ClaudeResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse API response: {}", e))?;
+    let response_text = response_json
+        .content
+        .first()
+        .and_then(|block| block.text.as_ref())
+        .ok_or_else(|| "No content in API response".to_string())?;
+    api_debug::log_response(
+        "check_proposal_coverage",
+        api_key,
+        status.as_u16(),
+        response_text,
+    );
+
+    let parsed: CoverageResponse = serde_json::from_str(extract_json_from_response(response_text))
+        .map_err(|e| format!("Failed to parse coverage JSON: {}", e))?;
+
+    let mut addressed = vec![false; requirements.len()];
+    // Out-of-range numbers are ignored
+    for n in parsed.addressed {
+        if let Some(flag) = n.checked_sub(1).and_then(|i| addressed.get_mut(i)) {
+            *flag = true;
+        }
+    }
+    Ok(addressed)
+}
+
+const COVERAGE_STOPWORDS: &[&str] = &[
+    "and", "the", "for", "with", "from", "into", "your", "our", "their", "that", "this", "will",
+    "must", "should", "able", "have", "has", "are", "all", "any", "using", "use", "new", "etc",
+];
+
+/// Lowercased significant words, crudely stemmed so "integrations" ~ "integrating"
+fn coverage_terms(text: &str) -> std::collections::HashSet<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.len() >= 3 && !COVERAGE_STOPWORDS.contains(w))
+        .map(|w| w.chars().take(6).collect())
+        .collect()
+}
+
+/// Offline fallback: a requirement counts as addressed when at least half of
+/// its significant words appear in the proposal. Literal only, so it
+/// under-reports paraphrased coverage.
+pub fn keyword_coverage(proposal_text: &str, requirements: &[String]) -> ProposalCoverage {
+    let proposal_terms = coverage_terms(proposal_text);
+    let addressed: Vec<bool> = requirements
+        .iter()
+        .map(|requirement| {
+            let terms = coverage_terms(requirement);
+            if terms.is_empty() {
+                return proposal_text
+                    .to_lowercase()
+                    .contains(&requirement.trim().to_lowercase());
+            }
+            let hits = terms.iter().filter(|t| proposal_terms.contains(*t)).count();
+            hits * 2 >= terms.len()
+        })
+        .collect();
+    ProposalCoverage::from_flags(requirements, &addressed, CoverageMethod::Keyword)
+}
+
 /// Story 4b.3 AC-3: Apply hard rules for client quality score
 /// - "0 hires" explicitly mentioned → force score to 45
 /// - No score from LLM → default to 65
@@ -669,6 +1020,22 @@ fn apply_client_quality_hard_rules(llm_score: Option<i32>, job_post_text: &str)
     Some(score.clamp(0, 100))
 }
 
+/// Longest response excerpt written to the debug log
+const RESPONSE_LOG_CHARS: usize = 2000;
+
+/// Response text for debug logs: secrets redacted and capped at `RESPONSE_LOG_CHARS`
+fn redacted_response_excerpt(text: &str) -> String {
+    let redacted = crate::logs::redaction::redact_secrets(text);
+    match redacted.char_indices().nth(RESPONSE_LOG_CHARS) {
+        Some((cut, _)) => format!(
+            "{}... ({} chars total)",
+            &redacted[..cut],
+            redacted.chars().count()
+        ),
+        None => redacted,
+    }
+}
+
 /// Extract JSON from response text (handles code block wrapping)
 /// Reused pattern from claude.rs
 fn extract_json_from_response(text: &str) -> &str {
@@ -684,6 +1051,7 @@ fn extract_json_from_response(text: &str) -> &str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::currency::FxRates;
 
     #[test]
     fn test_extract_json_from_response_clean() {
@@ -721,6 +1089,11 @@ mod tests {
             budget_type: "unknown".to_string(),
             budget_alignment_pct: None,
             budget_alignment_status: "gray".to_string(),
+            required_deliverables: vec![],
+            nice_to_have_deliverables: vec![],
+            prior_history: None,
+            cached: false,
+            parse_warnings: vec![],
         };
         let json = serde_json::to_string(&analysis).unwrap();
         assert!(json.contains("clientName")); // camelCase serialization
@@ -823,9 +1196,14 @@ mod tests {
             client_quality_score: Some(75),
             budget_alignment_pct: None,
             budget_alignment_status: "gray".to_string(),
+            required_deliverables: vec![],
+            nice_to_have_deliverables: vec![],
+            prior_history: None,
             budget_min: None,
             budget_max: None,
             budget_type: "unknown".to_string(),
+            cached: false,
+            parse_warnings: vec![],
         };
         let json = serde_json::to_string(&analysis).unwrap();
         assert!(json.contains("hiddenNeeds")); // camelCase serialization
@@ -914,6 +1292,38 @@ mod tests {
         assert_eq!(response.client_quality_score, None);
     }
 
+    #[test]
+    fn test_analysis_response_with_deliverables() {
+        let json = r#"{"client_name": null, "key_skills": [], "required_deliverables": ["Landing page", "Stripe checkout"], "nice_to_have_deliverables": ["Blog"]}"#;
+        let response: AnalysisResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            response.required_deliverables,
+            vec!["Landing page", "Stripe checkout"]
+        );
+        assert_eq!(response.nice_to_have_deliverables, vec!["Blog"]);
+
+        // Older responses without the fields still parse
+        let json = r#"{"client_name": null, "key_skills": []}"#;
+        let response: AnalysisResponse = serde_json::from_str(json).unwrap();
+        assert!(response.required_deliverables.is_empty());
+        assert!(response.nice_to_have_deliverables.is_empty());
+    }
+
+    #[test]
+    fn test_normalize_deliverables_keeps_buckets_disjoint() {
+        let (required, nice) = normalize_deliverables(
+            vec![
+                " Landing page ".into(),
+                "".into(),
+                "landing page".into(),
+                "Stripe checkout".into(),
+            ],
+            vec!["Blog".into(), "Stripe Checkout".into()],
+        );
+        assert_eq!(required, vec!["Landing page", "Stripe checkout"]);
+        assert_eq!(nice, vec!["Blog"]);
+    }
+
     #[test]
     fn test_job_analysis_serialization_with_quality_score() {
         // Subtask 5.1: Test serialization includes clientQualityScore
@@ -928,6 +1338,11 @@ mod tests {
             budget_type: "unknown".to_string(),
             budget_alignment_pct: None,
             budget_alignment_status: "gray".to_string(),
+            required_deliverables: vec![],
+            nice_to_have_deliverables: vec![],
+            prior_history: None,
+            cached: false,
+            parse_warnings: vec![],
         };
         let json = serde_json::to_string(&analysis).unwrap();
         assert!(json.contains("clientQualityScore"));
@@ -1033,6 +1448,7 @@ mod tests {
             min: Some(50.0),
             max: Some(75.0),
             budget_type: "hourly".to_string(),
+            currency: None,
         };
         let json = serde_json::to_string(&budget).unwrap();
         assert!(json.contains("\"min\":50"));
@@ -1047,12 +1463,13 @@ mod tests {
             min: Some(50.0),
             max: Some(50.0),
             budget_type: "hourly".to_string(),
+            currency: None,
         };
         let user_rate = crate::RateConfig {
             hourly_rate: Some(50.0),
             project_rate_min: None,
         };
-        let alignment = calculate_budget_alignment(&budget, &user_rate);
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
         assert_eq!(alignment.percentage, Some(100));
         assert_eq!(alignment.status, "green");
     }
@@ -1064,12 +1481,13 @@ mod tests {
             min: Some(50.0),
             max: Some(50.0),
             budget_type: "hourly".to_string(),
+            currency: None,
         };
         let user_rate = crate::RateConfig {
             hourly_rate: Some(75.0),
             project_rate_min: None,
         };
-        let alignment = calculate_budget_alignment(&budget, &user_rate);
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
         assert_eq!(alignment.percentage, Some(66)); // 50/75 = 66.66% → 66
         assert_eq!(alignment.status, "red");
     }
@@ -1081,12 +1499,13 @@ mod tests {
             min: Some(50.0),
             max: Some(50.0),
             budget_type: "hourly".to_string(),
+            currency: None,
         };
         let user_rate = crate::RateConfig {
             hourly_rate: Some(40.0),
             project_rate_min: None,
         };
-        let alignment = calculate_budget_alignment(&budget, &user_rate);
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
         assert_eq!(alignment.percentage, Some(125));
         assert_eq!(alignment.status, "green");
     }
@@ -1098,12 +1517,13 @@ mod tests {
             min: Some(2000.0),
             max: Some(2000.0),
             budget_type: "fixed".to_string(),
+            currency: None,
         };
         let user_rate = crate::RateConfig {
             hourly_rate: None,
             project_rate_min: Some(2000.0),
         };
-        let alignment = calculate_budget_alignment(&budget, &user_rate);
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
         assert_eq!(alignment.percentage, Some(100));
         assert_eq!(alignment.status, "green");
     }
@@ -1115,12 +1535,13 @@ mod tests {
             min: Some(50.0),
             max: Some(50.0),
             budget_type: "hourly".to_string(),
+            currency: None,
         };
         let user_rate = crate::RateConfig {
             hourly_rate: None,
             project_rate_min: Some(2000.0),
         };
-        let alignment = calculate_budget_alignment(&budget, &user_rate);
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
         assert_eq!(alignment.percentage, None);
         assert_eq!(alignment.status, "mismatch");
     }
@@ -1132,12 +1553,13 @@ mod tests {
             min: Some(2000.0),
             max: Some(2000.0),
             budget_type: "fixed".to_string(),
+            currency: None,
         };
         let user_rate = crate::RateConfig {
             hourly_rate: Some(75.0),
             project_rate_min: None,
         };
-        let alignment = calculate_budget_alignment(&budget, &user_rate);
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
         assert_eq!(alignment.percentage, None);
         assert_eq!(alignment.status, "mismatch");
     }
@@ -1149,12 +1571,13 @@ mod tests {
             min: None,
             max: None,
             budget_type: "unknown".to_string(),
+            currency: None,
         };
         let user_rate = crate::RateConfig {
             hourly_rate: Some(75.0),
             project_rate_min: Some(2000.0),
         };
-        let alignment = calculate_budget_alignment(&budget, &user_rate);
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
         assert_eq!(alignment.percentage, None);
         assert_eq!(alignment.status, "gray");
     }
@@ -1166,12 +1589,13 @@ mod tests {
             min: Some(50.0),
             max: Some(50.0),
             budget_type: "hourly".to_string(),
+            currency: None,
         };
         let user_rate = crate::RateConfig {
             hourly_rate: Some(0.0),
             project_rate_min: None,
         };
-        let alignment = calculate_budget_alignment(&budget, &user_rate);
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
         assert_eq!(alignment.percentage, None);
         assert_eq!(alignment.status, "gray");
     }
@@ -1183,12 +1607,13 @@ mod tests {
             min: Some(60.0),
             max: Some(60.0),
             budget_type: "hourly".to_string(),
+            currency: None,
         };
         let user_rate = crate::RateConfig {
             hourly_rate: Some(75.0),
             project_rate_min: None,
         };
-        let alignment = calculate_budget_alignment(&budget, &user_rate);
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
         assert_eq!(alignment.percentage, Some(80)); // 60/75 = 80%
         assert_eq!(alignment.status, "yellow");
     }
@@ -1207,6 +1632,11 @@ mod tests {
             budget_type: "hourly".to_string(),
             budget_alignment_pct: Some(80),
             budget_alignment_status: "yellow".to_string(),
+            required_deliverables: vec![],
+            nice_to_have_deliverables: vec![],
+            prior_history: None,
+            cached: false,
+            parse_warnings: vec![],
         };
         let json = serde_json::to_string(&analysis).unwrap();
         assert!(json.contains("\"budgetMin\":50"));
@@ -1224,12 +1654,13 @@ mod tests {
             min: Some(-50.0), // Invalid negative budget
             max: Some(-50.0),
             budget_type: "hourly".to_string(),
+            currency: None,
         };
         let user_rate = crate::RateConfig {
             hourly_rate: Some(75.0),
             project_rate_min: None,
         };
-        let alignment = calculate_budget_alignment(&budget, &user_rate);
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
         assert_eq!(alignment.percentage, None);
         assert_eq!(alignment.status, "gray");
     }
@@ -1241,15 +1672,78 @@ mod tests {
             min: Some(-2000.0), // Invalid negative budget
             max: Some(-2000.0),
             budget_type: "fixed".to_string(),
+            currency: None,
         };
         let user_rate = crate::RateConfig {
             hourly_rate: None,
             project_rate_min: Some(1500.0),
         };
-        let alignment = calculate_budget_alignment(&budget, &user_rate);
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
         assert_eq!(alignment.percentage, None);
         assert_eq!(alignment.status, "gray");
     }
 
+    #[test]
+    fn test_budget_alignment_normalizes_currency() {
+        // €50/hr at 1.08 USD/EUR = $54/hr vs $50/hr → 108%, green
+        let budget = BudgetInfo {
+            min: Some(50.0),
+            max: Some(60.0),
+            budget_type: "hourly".to_string(),
+            currency: Some("EUR".to_string()),
+        };
+        let user_rate = crate::RateConfig {
+            hourly_rate: Some(50.0),
+            project_rate_min: None,
+        };
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
+        assert_eq!(alignment.percentage, Some(108));
+        assert_eq!(alignment.status, "green");
+        assert_eq!(alignment.normalized_max.map(|v| v.round()), Some(65.0));
+
+        // No rate for PLN: gray instead of comparing 200 PLN against $50
+        let budget = BudgetInfo {
+            currency: Some("PLN".to_string()),
+            min: Some(200.0),
+            ..budget
+        };
+        let alignment = calculate_budget_alignment(&budget, &user_rate, &FxRates::default());
+        assert_eq!(alignment.percentage, None);
+        assert_eq!(alignment.status, "gray");
+        assert_eq!(alignment.normalized_min, None);
+    }
+
+    #[test]
+    fn test_keyword_coverage_reports_missing_items() {
+        let requirements = vec![
+            "REST API integration".to_string(),
+            "Unit tests for core components".to_string(),
+            "Deploy to AWS".to_string(),
+        ];
+        let proposal = "I've built several REST integrations with Stripe and will add unit tests \
+            covering every core component.";
+
+        let coverage = keyword_coverage(proposal, &requirements);
+        assert_eq!(coverage.method, CoverageMethod::Keyword);
+        assert_eq!(coverage.addressed, requirements[..2].to_vec());
+        assert_eq!(coverage.missing, vec!["Deploy to AWS".to_string()]);
+        assert_eq!(coverage.coverage_percentage, 67);
+    }
+
+    #[tokio::test]
+    async fn test_coverage_without_api_key_or_requirements() {
+        // No requirements: nothing to miss
+        let coverage = check_proposal_coverage("Hi there", &[], None).await;
+        assert_eq!(coverage.coverage_percentage, 100);
+        assert!(coverage.missing.is_empty());
+
+        // No API key: keyword fallback, no network call
+        let requirements = vec!["Figma mockups".to_string()];
+        let coverage =
+            check_proposal_coverage("I'll start with Figma mockups.", &requirements, None).await;
+        assert_eq!(coverage.method, CoverageMethod::Keyword);
+        assert_eq!(coverage.coverage_percentage, 100);
+    }
+
     // Integration tests with mocked API responses will be added below
 }
@@ -0,0 +1,424 @@
+//! Lenient parsing of the job analysis JSON returned by Haiku
+//!
+//! The model sometimes wraps the object in prose or code fences, leaves trailing
+//! commas, or quotes with single quotes. Parsing takes the first balanced JSON
+//! object in the text that parses, trying each object once as-is and once after
+//! `repair_json`. Fields are then read one by one: optional lists default to empty,
+//! and a required field (`client_name`, `client_quality_score`) that is missing or
+//! has the wrong type becomes None with a warning instead of failing the analysis.
+
+use super::{AnalysisResponse, HiddenNeed};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+/// Balanced objects tried before giving up (bounds work on long prose)
+const MAX_CANDIDATES: usize = 8;
+
+/// A parsed analysis with what had to be worked around
+#[derive(Debug)]
+pub(super) struct ParsedAnalysis {
+    pub response: AnalysisResponse,
+    /// One entry per missing or unusable field
+    pub warnings: Vec<String>,
+    /// True when the object only parsed after `repair_json`
+    pub repaired: bool,
+}
+
+/// Parse a Haiku analysis response. Errors only when no usable object is found.
+pub(super) fn parse_analysis_response(text: &str) -> Result<ParsedAnalysis, String> {
+    let mut last_error = "no JSON object found".to_string();
+
+    for candidate in balanced_objects(text).take(MAX_CANDIDATES) {
+        let (object, repaired) = match serde_json::from_str::<Value>(candidate) {
+            Ok(value) => (value, false),
+            Err(e) => match serde_json::from_str::<Value>(&repair_json(candidate)) {
+                Ok(value) => (value, true),
+                Err(_) => {
+                    last_error = e.to_string();
+                    continue;
+                }
+            },
+        };
+        let Value::Object(object) = object else {
+            continue;
+        };
+        match read_fields(&object) {
+            Some((response, warnings)) => {
+                return Ok(ParsedAnalysis {
+                    response,
+                    warnings,
+                    repaired,
+                })
+            }
+            None => last_error = "JSON object has no analysis fields".to_string(),
+        }
+    }
+
+    Err(last_error)
+}
+
+/// Read the analysis fields from a parsed object. None if it has none of them.
+fn read_fields(object: &Map<String, Value>) -> Option<(AnalysisResponse, Vec<String>)> {
+    const FIELDS: [&str; 6] = [
+        "client_name",
+        "key_skills",
+        "hidden_needs",
+        "client_quality_score",
+        "required_deliverables",
+        "nice_to_have_deliverables",
+    ];
+    if !FIELDS.iter().any(|field| object.contains_key(*field)) {
+        return None;
+    }
+
+    let mut warnings = Vec::new();
+    let client_name = required_field::<Option<String>>(object, "client_name", &mut warnings)
+        .flatten()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+    let client_quality_score =
+        required_field::<Option<f64>>(object, "client_quality_score", &mut warnings)
+            .flatten()
+            .map(|score| score.round() as i32);
+
+    let response = AnalysisResponse {
+        client_name,
+        key_skills: optional_field(object, "key_skills", &mut warnings),
+        hidden_needs: optional_field::<Vec<HiddenNeed>>(object, "hidden_needs", &mut warnings),
+        client_quality_score,
+        required_deliverables: optional_field(object, "required_deliverables", &mut warnings),
+        nice_to_have_deliverables: optional_field(
+            object,
+            "nice_to_have_deliverables",
+            &mut warnings,
+        ),
+    };
+    Some((response, warnings))
+}
+
+/// A field the prompt always asks for; missing or mistyped gives None and a warning
+fn required_field<T: DeserializeOwned>(
+    object: &Map<String, Value>,
+    key: &str,
+    warnings: &mut Vec<String>,
+) -> Option<T> {
+    let Some(value) = object.get(key) else {
+        warnings.push(format!("{} missing from analysis response", key));
+        return None;
+    };
+    match serde_json::from_value(value.clone()) {
+        Ok(parsed) => Some(parsed),
+        Err(e) => {
+            warnings.push(format!("{} ignored: {}", key, e));
+            None
+        }
+    }
+}
+
+/// A list field; missing is the empty default, mistyped gives a warning
+fn optional_field<T: DeserializeOwned + Default>(
+    object: &Map<String, Value>,
+    key: &str,
+    warnings: &mut Vec<String>,
+) -> T {
+    match object.get(key) {
+        None | Some(Value::Null) => T::default(),
+        Some(value) => serde_json::from_value(value.clone()).unwrap_or_else(|e| {
+            warnings.push(format!("{} ignored: {}", key, e));
+            T::default()
+        }),
+    }
+}
+
+/// Every balanced `{...}` in `text`, starting at each `{` in order. Quoted braces
+/// (double or single quotes) do not count; an object that never closes is skipped.
+fn balanced_objects(text: &str) -> impl Iterator<Item = &str> {
+    text.char_indices()
+        .filter(|&(_, c)| c == '{')
+        .filter_map(move |(start, _)| balanced_from(text, start))
+}
+
+fn balanced_from(text: &str, start: usize) -> Option<&str> {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for (offset, c) in text[start..].char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&text[start..=start + offset]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// One repair pass over a JSON-ish object: single-quoted strings become
+/// double-quoted, trailing commas before `}` / `]` are dropped, and the Python
+/// literals `None`, `True`, `False` become `null`, `true`, `false`.
+/// Text inside double-quoted strings is left alone.
+fn repair_json(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut out = String::with_capacity(input.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '"' => {
+                // Copy a double-quoted string verbatim
+                out.push(c);
+                i += 1;
+                while i < chars.len() {
+                    let s = chars[i];
+                    out.push(s);
+                    i += 1;
+                    if s == '\\' && i < chars.len() {
+                        out.push(chars[i]);
+                        i += 1;
+                    } else if s == '"' {
+                        break;
+                    }
+                }
+                continue;
+            }
+            '\'' => {
+                out.push('"');
+                i += 1;
+                while i < chars.len() {
+                    let s = chars[i];
+                    i += 1;
+                    match s {
+                        '\\' if i < chars.len() && chars[i] == '\'' => {
+                            out.push('\'');
+                            i += 1;
+                        }
+                        '\\' if i < chars.len() => {
+                            out.push('\\');
+                            out.push(chars[i]);
+                            i += 1;
+                        }
+                        '"' => out.push_str("\\\""),
+                        '\'' => break,
+                        _ => out.push(s),
+                    }
+                }
+                out.push('"');
+                continue;
+            }
+            ',' => {
+                let next = chars[i + 1..].iter().find(|c| !c.is_whitespace());
+                if !matches!(next, Some('}') | Some(']')) {
+                    out.push(c);
+                }
+            }
+            c if c.is_ascii_alphabetic() => {
+                let end = chars[i..]
+                    .iter()
+                    .position(|c| !c.is_ascii_alphanumeric() && *c != '_')
+                    .map_or(chars.len(), |n| i + n);
+                let word: String = chars[i..end].iter().collect();
+                out.push_str(match word.as_str() {
+                    "None" => "null",
+                    "True" => "true",
+                    "False" => "false",
+                    other => other,
+                });
+                i = end;
+                continue;
+            }
+            _ => out.push(c),
+        }
+        i += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FULL: &str = r#"{"client_name": "Sarah Chen", "key_skills": ["React", "TypeScript"], "hidden_needs": [{"need": "Time-pressured", "evidence": "Mentions 'ASAP'"}], "client_quality_score": 85, "required_deliverables": ["Dashboard"], "nice_to_have_deliverables": []}"#;
+
+    /// (label, response text, expected client_name, expected score, warnings)
+    const RECOVERABLE: [(&str, &str, Option<&str>, Option<i32>, usize); 10] = [
+        (
+            "prose around object",
+            "Here is the analysis you asked for:\n{\"client_name\": \"Sarah\", \"client_quality_score\": 80}\nLet me know if you need more.",
+            Some("Sarah"),
+            Some(80),
+            0,
+        ),
+        (
+            "code fence",
+            "```json\n{\"client_name\": \"Acme Corp\", \"key_skills\": [\"Rust\"], \"client_quality_score\": 70}\n```",
+            Some("Acme Corp"),
+            Some(70),
+            0,
+        ),
+        (
+            "trailing comma in object",
+            "{\"client_name\": \"Sarah\", \"client_quality_score\": 65,}",
+            Some("Sarah"),
+            Some(65),
+            0,
+        ),
+        (
+            "trailing comma in array",
+            "{\"client_name\": null, \"key_skills\": [\"React\", \"Go\",\n], \"client_quality_score\": 60}",
+            None,
+            Some(60),
+            0,
+        ),
+        (
+            "single quotes",
+            "{'client_name': 'Sarah', 'key_skills': ['React'], 'client_quality_score': 90}",
+            Some("Sarah"),
+            Some(90),
+            0,
+        ),
+        (
+            "single-quoted keys, apostrophe in double-quoted value",
+            "{'client_name': \"Dan O'Brien\", 'client_quality_score': 75}",
+            Some("Dan O'Brien"),
+            Some(75),
+            0,
+        ),
+        (
+            "missing client_quality_score",
+            "{\"client_name\": \"Sarah\", \"key_skills\": [\"React\"]}",
+            Some("Sarah"),
+            None,
+            1,
+        ),
+        (
+            "braces in prose before the object",
+            "I used the {client_name} field as requested: {\"client_name\": \"Lee\", \"client_quality_score\": 72.6}",
+            Some("Lee"),
+            Some(73),
+            0,
+        ),
+        (
+            "Python literals",
+            "{\"client_name\": None, \"client_quality_score\": 55, \"hidden_needs\": [],}",
+            None,
+            Some(55),
+            0,
+        ),
+        (
+            "mistyped fields",
+            "{\"client_name\": 42, \"key_skills\": \"React, Go\", \"client_quality_score\": \"high\"}",
+            None,
+            None,
+            3,
+        ),
+    ];
+
+    const UNRECOVERABLE: [(&str, &str); 10] = [
+        ("empty", ""),
+        (
+            "prose only",
+            "I couldn't find enough information to analyze this job post.",
+        ),
+        (
+            "truncated object",
+            "{\"client_name\": \"Sarah\", \"key_skills\": [\"React\"",
+        ),
+        ("top-level array", "[\"React\", \"TypeScript\"]"),
+        ("no analysis fields", "{\"name\": \"Sarah\", \"score\": 80}"),
+        ("empty object", "```json\n{}\n```"),
+        (
+            "unquoted keys",
+            "{client_name: \"Sarah\", client_quality_score: 80}",
+        ),
+        (
+            "missing colon",
+            "{\"client_name\" \"Sarah\", \"client_quality_score\": 80}",
+        ),
+        (
+            "apostrophe in single-quoted value",
+            "{'client_name': 'Sarah's shop'}",
+        ),
+        ("bare null", "null"),
+    ];
+
+    #[test]
+    fn test_well_formed_response_parses_without_warnings() {
+        let parsed = parse_analysis_response(FULL).unwrap();
+        assert!(!parsed.repaired);
+        assert!(parsed.warnings.is_empty());
+        assert_eq!(parsed.response.client_name.as_deref(), Some("Sarah Chen"));
+        assert_eq!(parsed.response.key_skills, vec!["React", "TypeScript"]);
+        assert_eq!(parsed.response.hidden_needs.len(), 1);
+        assert_eq!(parsed.response.client_quality_score, Some(85));
+        assert_eq!(parsed.response.required_deliverables, vec!["Dashboard"]);
+    }
+
+    #[test]
+    fn test_recoverable_fixtures() {
+        for (label, text, name, score, warnings) in RECOVERABLE {
+            let parsed = parse_analysis_response(text)
+                .unwrap_or_else(|e| panic!("{}: expected recovery, got error {}", label, e));
+            assert_eq!(parsed.response.client_name.as_deref(), name, "{}", label);
+            assert_eq!(parsed.response.client_quality_score, score, "{}", label);
+            assert_eq!(
+                parsed.warnings.len(),
+                warnings,
+                "{}: {:?}",
+                label,
+                parsed.warnings
+            );
+        }
+    }
+
+    #[test]
+    fn test_unrecoverable_fixtures() {
+        for (label, text) in UNRECOVERABLE {
+            assert!(
+                parse_analysis_response(text).is_err(),
+                "{}: expected an error",
+                label
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_required_field_warns_and_keeps_the_rest() {
+        let parsed =
+            parse_analysis_response(r#"{"key_skills": ["Rust"], "hidden_needs": []}"#).unwrap();
+        assert_eq!(parsed.response.key_skills, vec!["Rust"]);
+        assert_eq!(parsed.response.client_name, None);
+        assert_eq!(parsed.response.client_quality_score, None);
+        assert_eq!(
+            parsed.warnings,
+            vec![
+                "client_name missing from analysis response",
+                "client_quality_score missing from analysis response",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_repair_leaves_double_quoted_text_alone() {
+        assert_eq!(
+            repair_json(r#"{"evidence": "Says 'None, True,]' twice", 'k': 'a "b"',}"#),
+            r#"{"evidence": "Says 'None, True,]' twice", "k": "a \"b\""}"#
+        );
+    }
+}
@@ -0,0 +1,313 @@
+//! Anthropic API quota tracking from rate-limit response headers
+//!
+//! Every Anthropic response carries `anthropic-ratelimit-*` headers with the
+//! request and token budget left in the current window. `http::send` records
+//! the latest values here for any request that sets `anthropic-version`, and
+//! refuses to send another one while the request budget is known to be 0 (or
+//! a 429 `retry-after` is pending), failing with the same `RATE_LIMITED:<secs>`
+//! error as the generation cooldown. Responses without the headers (older API
+//! versions, proxies) leave the last known values untouched.
+//!
+//! Overloaded responses (HTTP 529 / `overloaded_error`) are reported as
+//! `API_OVERLOADED:` errors so callers can back off longer than for a normal
+//! failure.
+
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use serde::Serialize;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Error message prefix for an overloaded API (`ErrorCode::ApiOverloaded`)
+pub const OVERLOADED_PREFIX: &str = "API_OVERLOADED:";
+
+/// Suggested wait before retrying after an overloaded response
+pub const OVERLOADED_BACKOFF_SECS: u64 = 60;
+
+const REQUESTS_LIMIT: &str = "anthropic-ratelimit-requests-limit";
+const REQUESTS_REMAINING: &str = "anthropic-ratelimit-requests-remaining";
+const REQUESTS_RESET: &str = "anthropic-ratelimit-requests-reset";
+const TOKENS_LIMIT: &str = "anthropic-ratelimit-tokens-limit";
+const TOKENS_REMAINING: &str = "anthropic-ratelimit-tokens-remaining";
+const TOKENS_RESET: &str = "anthropic-ratelimit-tokens-reset";
+const RETRY_AFTER: &str = "retry-after";
+
+static API_QUOTA: OnceLock<ApiQuotaState> = OnceLock::new();
+
+/// Process-wide quota state (also registered as managed state)
+pub fn api_quota() -> &'static ApiQuotaState {
+    API_QUOTA.get_or_init(ApiQuotaState::default)
+}
+
+/// Rate-limit values from one response
+#[derive(Debug, Clone, Default, PartialEq)]
+struct QuotaSnapshot {
+    requests_limit: Option<u64>,
+    requests_remaining: Option<u64>,
+    requests_reset: Option<DateTime<Utc>>,
+    tokens_limit: Option<u64>,
+    tokens_remaining: Option<u64>,
+    tokens_reset: Option<DateTime<Utc>>,
+    /// From `retry-after` (seconds), made absolute
+    retry_after: Option<DateTime<Utc>>,
+    observed_at: DateTime<Utc>,
+}
+
+impl QuotaSnapshot {
+    /// Parse the rate-limit headers. None when the response has none of them.
+    fn from_headers(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Self> {
+        let text = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+        let number = |name: &str| text(name).and_then(|v| v.trim().parse::<u64>().ok());
+        let time = |name: &str| {
+            text(name)
+                .and_then(|v| DateTime::parse_from_rfc3339(v.trim()).ok())
+                .map(|t| t.with_timezone(&Utc))
+        };
+
+        let snapshot = Self {
+            requests_limit: number(REQUESTS_LIMIT),
+            requests_remaining: number(REQUESTS_REMAINING),
+            requests_reset: time(REQUESTS_RESET),
+            tokens_limit: number(TOKENS_LIMIT),
+            tokens_remaining: number(TOKENS_REMAINING),
+            tokens_reset: time(TOKENS_RESET),
+            retry_after: number(RETRY_AFTER)
+                .map(|secs| now + chrono::Duration::seconds(secs as i64)),
+            observed_at: now,
+        };
+        let has_values = snapshot.requests_remaining.is_some()
+            || snapshot.tokens_remaining.is_some()
+            || snapshot.retry_after.is_some();
+        has_values.then_some(snapshot)
+    }
+
+    /// When requests may be sent again, if they are currently blocked
+    fn blocked_until(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let exhausted_until = match (self.requests_remaining, self.requests_reset) {
+            (Some(0), Some(reset)) => Some(reset),
+            _ => None,
+        };
+        exhausted_until
+            .into_iter()
+            .chain(self.retry_after)
+            .max()
+            .filter(|until| *until > now)
+    }
+}
+
+/// Seconds from `now` until `until`, rounded up; 0 once it has passed
+fn seconds_until(until: DateTime<Utc>, now: DateTime<Utc>) -> u64 {
+    let millis = (until - now).num_milliseconds();
+    if millis <= 0 {
+        0
+    } else {
+        (millis as u64).div_ceil(1000)
+    }
+}
+
+/// Latest API quota, for "12 requests remaining, resets in 40s"
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiQuotaStatus {
+    /// False until a response with rate-limit headers has been seen
+    pub known: bool,
+    pub requests_limit: Option<u64>,
+    pub requests_remaining: Option<u64>,
+    pub requests_reset_in_secs: Option<u64>,
+    pub tokens_limit: Option<u64>,
+    pub tokens_remaining: Option<u64>,
+    pub tokens_reset_in_secs: Option<u64>,
+    /// Set while requests are refused without calling the API
+    pub blocked_for_secs: Option<u64>,
+    /// RFC 3339 time of the response the values came from
+    pub observed_at: Option<String>,
+}
+
+/// Most recent rate-limit headers seen from the Anthropic API (memory only)
+#[derive(Clone, Default)]
+pub struct ApiQuotaState {
+    latest: Arc<Mutex<Option<QuotaSnapshot>>>,
+}
+
+impl ApiQuotaState {
+    fn with_latest<T>(&self, f: impl FnOnce(&mut Option<QuotaSnapshot>) -> T) -> T {
+        let mut guard = match self.latest.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                tracing::warn!("ApiQuotaState mutex poisoned, recovering");
+                poisoned.into_inner()
+            }
+        };
+        f(&mut guard)
+    }
+
+    /// Record a response's rate-limit headers. Returns false (state unchanged)
+    /// if it had none.
+    pub fn record(&self, headers: &HeaderMap, now: DateTime<Utc>) -> bool {
+        let Some(snapshot) = QuotaSnapshot::from_headers(headers, now) else {
+            return false;
+        };
+        if snapshot.requests_remaining == Some(0) {
+            tracing::warn!(
+                reset = ?snapshot.requests_reset,
+                "Anthropic API request quota exhausted"
+            );
+        }
+        self.with_latest(|latest| *latest = Some(snapshot));
+        true
+    }
+
+    /// Seconds until requests may be sent again, if they are currently blocked
+    pub fn blocked_for_secs(&self, now: DateTime<Utc>) -> Option<u64> {
+        self.with_latest(|latest| {
+            latest
+                .as_ref()
+                .and_then(|s| s.blocked_until(now))
+                .map(|until| seconds_until(until, now))
+        })
+    }
+
+    pub fn status(&self, now: DateTime<Utc>) -> ApiQuotaStatus {
+        self.with_latest(|latest| match latest {
+            Some(s) => ApiQuotaStatus {
+                known: true,
+                requests_limit: s.requests_limit,
+                requests_remaining: s.requests_remaining,
+                requests_reset_in_secs: s.requests_reset.map(|t| seconds_until(t, now)),
+                tokens_limit: s.tokens_limit,
+                tokens_remaining: s.tokens_remaining,
+                tokens_reset_in_secs: s.tokens_reset.map(|t| seconds_until(t, now)),
+                blocked_for_secs: s.blocked_until(now).map(|t| seconds_until(t, now)),
+                observed_at: Some(s.observed_at.to_rfc3339()),
+            },
+            None => ApiQuotaStatus::default(),
+        })
+    }
+}
+
+/// `API_OVERLOADED:` error for an HTTP 529 or an `overloaded_error` body;
+/// None for any other failure
+pub fn overloaded_error(status: u16, body: &str) -> Option<String> {
+    let overloaded_body = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|v| v["error"]["type"].as_str().map(|t| t == "overloaded_error"))
+        .unwrap_or(false);
+    if status != 529 && !overloaded_body {
+        return None;
+    }
+    tracing::warn!(status, "Anthropic API overloaded");
+    Some(format!(
+        "{} Anthropic's API is overloaded right now. Try again in a minute.",
+        OVERLOADED_PREFIX
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        map
+    }
+
+    fn at(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_records_full_header_set() {
+        let state = ApiQuotaState::default();
+        let now = at("2026-10-16T12:00:00Z");
+        assert!(state.record(
+            &headers(&[
+                (REQUESTS_LIMIT, "50"),
+                (REQUESTS_REMAINING, "12"),
+                (REQUESTS_RESET, "2026-10-16T12:00:40Z"),
+                (TOKENS_LIMIT, "40000"),
+                (TOKENS_REMAINING, "38500"),
+                (TOKENS_RESET, "2026-10-16T12:00:05.500Z"),
+            ]),
+            now,
+        ));
+
+        let status = state.status(now);
+        assert!(status.known);
+        assert_eq!(status.requests_limit, Some(50));
+        assert_eq!(status.requests_remaining, Some(12));
+        assert_eq!(status.requests_reset_in_secs, Some(40));
+        assert_eq!(status.tokens_remaining, Some(38500));
+        assert_eq!(status.tokens_reset_in_secs, Some(6));
+        assert_eq!(status.blocked_for_secs, None);
+        assert_eq!(state.blocked_for_secs(now), None);
+    }
+
+    #[test]
+    fn test_missing_headers_keep_last_values() {
+        let state = ApiQuotaState::default();
+        let now = at("2026-10-16T12:00:00Z");
+        // Older API versions send no rate-limit headers
+        assert!(!state.record(&headers(&[("content-type", "application/json")]), now));
+        assert_eq!(state.status(now), ApiQuotaStatus::default());
+
+        state.record(&headers(&[(REQUESTS_REMAINING, "7")]), now);
+        assert!(!state.record(&HeaderMap::new(), now));
+        let status = state.status(now);
+        assert_eq!(status.requests_remaining, Some(7));
+        assert_eq!(status.requests_limit, None);
+        assert_eq!(status.requests_reset_in_secs, None);
+        // Unparseable values are ignored rather than treated as 0
+        assert!(!state.record(&headers(&[(REQUESTS_REMAINING, "many")]), now));
+        assert_eq!(state.status(now).requests_remaining, Some(7));
+    }
+
+    #[test]
+    fn test_exhausted_quota_blocks_until_reset() {
+        let state = ApiQuotaState::default();
+        let now = at("2026-10-16T12:00:00Z");
+        state.record(
+            &headers(&[
+                (REQUESTS_REMAINING, "0"),
+                (REQUESTS_RESET, "2026-10-16T12:00:40Z"),
+            ]),
+            now,
+        );
+        assert_eq!(state.blocked_for_secs(now), Some(40));
+        assert_eq!(state.status(now).blocked_for_secs, Some(40));
+        assert_eq!(
+            state.blocked_for_secs(at("2026-10-16T12:00:39.200Z")),
+            Some(1)
+        );
+        // Window reset: allowed again without a new response
+        assert_eq!(state.blocked_for_secs(at("2026-10-16T12:00:40Z")), None);
+
+        // 0 remaining with no reset time can't be timed, so it doesn't block
+        state.record(&headers(&[(REQUESTS_REMAINING, "0")]), now);
+        assert_eq!(state.blocked_for_secs(now), None);
+
+        // A 429's retry-after blocks even with requests left
+        state.record(
+            &headers(&[(REQUESTS_REMAINING, "3"), (RETRY_AFTER, "25")]),
+            now,
+        );
+        assert_eq!(state.blocked_for_secs(now), Some(25));
+    }
+
+    #[test]
+    fn test_overloaded_error_detection() {
+        assert!(overloaded_error(529, "")
+            .unwrap()
+            .starts_with(OVERLOADED_PREFIX));
+        let body = r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#;
+        assert!(overloaded_error(500, body).is_some());
+        let invalid =
+            r#"{"type":"error","error":{"type":"invalid_request_error","message":"bad"}}"#;
+        assert!(overloaded_error(400, invalid).is_none());
+        assert!(overloaded_error(500, "not json").is_none());
+    }
+}
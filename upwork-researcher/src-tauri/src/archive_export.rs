@@ -9,11 +9,15 @@ use std::path::Path;
 /// Magic header for URB archive format (4 bytes)
 const MAGIC_HEADER: &[u8; 4] = b"URB1";
 
+/// Newest metadata format this app reads and the one it writes.
+/// v2 adds `voiceDataIncluded` and `goldenProposalCount`.
+pub const ARCHIVE_FORMAT_VERSION: u8 = 2;
+
 /// Metadata stored in the archive header
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ArchiveMetadata {
-    /// Format version (currently 1)
+    /// Format version (currently `ARCHIVE_FORMAT_VERSION`)
     pub format_version: u8,
     /// Export timestamp (ISO 8601)
     pub export_date: String,
@@ -33,6 +37,17 @@ pub struct ArchiveMetadata {
     pub voice_profile_count: usize,
     /// Size of the encrypted database file in bytes
     pub db_size_bytes: u64,
+    /// Count of golden set proposals in backup (v2+)
+    #[serde(default)]
+    pub golden_proposal_count: usize,
+    /// Voice profile and golden proposals were kept in the archive (v2+).
+    /// v1 archives always carried them, so check the archive itself for those.
+    #[serde(default)]
+    pub voice_data_included: bool,
+    /// Argon2id parameters the archive key was derived with. Absent in archives
+    /// from databases keyed before parameters were configurable: the defaults.
+    #[serde(default)]
+    pub kdf_params: Option<crate::passphrase::KdfParams>,
 }
 
 impl ArchiveMetadata {
@@ -48,7 +63,7 @@ impl ArchiveMetadata {
         db_size_bytes: u64,
     ) -> Self {
         Self {
-            format_version: 1,
+            format_version: ARCHIVE_FORMAT_VERSION,
             export_date: chrono::Utc::now().to_rfc3339(),
             app_version,
             passphrase_hint,
@@ -58,8 +73,40 @@ impl ArchiveMetadata {
             settings_count,
             voice_profile_count,
             db_size_bytes,
+            golden_proposal_count: 0,
+            voice_data_included: false,
+            kdf_params: None,
+        }
+    }
+
+    /// Record whether voice data (voice profile + golden proposals) was exported
+    pub fn with_voice_data(mut self, included: bool, golden_proposal_count: usize) -> Self {
+        self.voice_data_included = included;
+        self.golden_proposal_count = golden_proposal_count;
+        self
+    }
+
+    /// Record the key derivation parameters needed to open the archive
+    pub fn with_kdf_params(mut self, kdf_params: crate::passphrase::KdfParams) -> Self {
+        self.kdf_params = Some(kdf_params);
+        self
+    }
+}
+
+/// Parse metadata JSON, rejecting archives written in a newer format with a
+/// clear version error instead of a deserialization failure
+pub fn parse_metadata(bytes: &[u8]) -> Result<ArchiveMetadata, String> {
+    let value: serde_json::Value = serde_json::from_slice(bytes)
+        .map_err(|e| format!("Failed to parse metadata JSON: {}", e))?;
+    if let Some(version) = value.get("formatVersion").and_then(|v| v.as_u64()) {
+        if version > ARCHIVE_FORMAT_VERSION as u64 {
+            return Err(format!(
+                "This backup uses archive format v{}, but this app only supports up to v{}. Please update the app to import it.",
+                version, ARCHIVE_FORMAT_VERSION
+            ));
         }
     }
+    serde_json::from_value(value).map_err(|e| format!("Failed to parse metadata JSON: {}", e))
 }
 
 /// Writes a URB archive to the specified path
@@ -165,8 +212,7 @@ pub fn read_archive_metadata(path: &Path) -> Result<(ArchiveMetadata, Vec<u8>, V
 
     // Read and parse metadata JSON
     let metadata_bytes = &buffer[offset..offset + metadata_len];
-    let metadata: ArchiveMetadata = serde_json::from_slice(metadata_bytes)
-        .map_err(|e| format!("Failed to parse metadata JSON: {}", e))?;
+    let metadata = parse_metadata(metadata_bytes)?;
     offset += metadata_len;
 
     // Verify we have at least 4 more bytes for salt length
@@ -244,10 +290,7 @@ pub fn read_metadata_only(path: &Path) -> Result<ArchiveMetadata, String> {
         .read_exact(&mut metadata_bytes)
         .map_err(|e| format!("Failed to read metadata: {}", e))?;
 
-    let metadata: ArchiveMetadata = serde_json::from_slice(&metadata_bytes)
-        .map_err(|e| format!("Failed to parse metadata JSON: {}", e))?;
-
-    Ok(metadata)
+    parse_metadata(&metadata_bytes)
 }
 
 #[cfg(test)]
@@ -385,10 +428,32 @@ mod tests {
     fn test_metadata_format_version() {
         let metadata = ArchiveMetadata::new("1.0.0".to_string(), None, 1, 2, 3, 4, 5, 100);
 
-        assert_eq!(metadata.format_version, 1);
+        assert_eq!(metadata.format_version, ARCHIVE_FORMAT_VERSION);
         assert!(metadata.export_date.contains("T")); // ISO 8601 format
     }
 
+    #[test]
+    fn test_parse_metadata_versions() {
+        // v1 metadata (no voice data fields) still parses
+        let v1 = r#"{"formatVersion":1,"exportDate":"2026-01-01T00:00:00Z","appVersion":"0.1.0",
+            "passphraseHint":null,"proposalCount":1,"revisionCount":0,"jobPostCount":0,
+            "settingsCount":0,"voiceProfileCount":1,"dbSizeBytes":2048}"#;
+        let metadata = parse_metadata(v1.as_bytes()).unwrap();
+        assert_eq!(metadata.format_version, 1);
+        assert!(!metadata.voice_data_included);
+        assert_eq!(metadata.golden_proposal_count, 0);
+
+        // A newer format is a version error, even if its fields changed shape
+        let err = parse_metadata(br#"{"formatVersion":3,"proposals":{"count":1}}"#).unwrap_err();
+        assert!(err.contains("archive format v3"), "{}", err);
+        assert!(err.contains("update the app"));
+
+        let metadata = ArchiveMetadata::new("1.0.0".to_string(), None, 1, 0, 0, 0, 1, 2048)
+            .with_voice_data(true, 4);
+        let json = serde_json::to_vec(&metadata).unwrap();
+        assert_eq!(parse_metadata(&json).unwrap(), metadata);
+    }
+
     #[test]
     fn test_read_metadata_only_valid_archive() {
         // Create a valid archive and read metadata only (without loading DB)
@@ -410,7 +475,7 @@ mod tests {
 
         let read_meta = read_metadata_only(temp_file.path()).unwrap();
 
-        assert_eq!(read_meta.format_version, 1);
+        assert_eq!(read_meta.format_version, ARCHIVE_FORMAT_VERSION);
         assert_eq!(read_meta.app_version, "2.0.0");
         assert_eq!(read_meta.passphrase_hint, Some("pet name".to_string()));
         assert_eq!(read_meta.proposal_count, 50);
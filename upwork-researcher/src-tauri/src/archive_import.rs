@@ -11,10 +11,21 @@
 //! - Temp file cleanup with Drop guards
 //! - Disk space pre-flight checks
 //! - Path injection prevention via UUID temp filenames
+//!
+//! Crash recovery: `import:progress` events report decryption and insertion
+//! progress, and a journal file next to the decrypted temp file records how far
+//! the import got. The import runs in one transaction (inserted in batches,
+//! committed once at the end), so a crash leaves nothing applied; on the next
+//! launch `recover_interrupted_imports` cleans up and reports any journal that
+//! never reached "committed". Re-running the import is safe: merge mode skips
+//! rows already present by ID, and replace mode starts from empty tables.
 
 use crate::archive_export::{read_archive_metadata, read_metadata_only, ArchiveMetadata};
 use crate::db::Database;
+use crate::network;
+use crate::notifications;
 use crate::passphrase;
+use crate::remote_config;
 use rusqlite::Connection;
 use scopeguard::defer;
 use std::fs;
@@ -41,6 +52,38 @@ pub enum ImportMode {
     MergeSkipDuplicates,
 }
 
+/// What to do with the archive's voice data (voice profile and golden proposals)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoiceDataImport {
+    /// Leave local voice data untouched and import none from the archive
+    Skip,
+    /// Import archive voice data, keeping an existing local profile
+    KeepExisting,
+    /// Replace local voice data with the archive's
+    Replace,
+}
+
+impl VoiceDataImport {
+    /// From `execute_import`'s `import_voice_data` flag and conflict policy
+    /// ("keep" | "replace"; defaults to "keep")
+    pub fn from_options(import_voice_data: bool, conflict: Option<&str>) -> Result<Self, String> {
+        if !import_voice_data {
+            return Ok(Self::Skip);
+        }
+        match conflict.unwrap_or("keep") {
+            "keep" => Ok(Self::KeepExisting),
+            "replace" => Ok(Self::Replace),
+            other => Err(format!(
+                "Invalid voice data conflict policy: {} (expected keep or replace)",
+                other
+            )),
+        }
+    }
+}
+
+/// Tables holding voice data, imported only with `VoiceDataImport::KeepExisting`/`Replace`
+pub const VOICE_DATA_TABLES: [&str; 2] = ["voice_profiles", "golden_set_proposals"];
+
 /// Schema compatibility check result
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SchemaCompatibility {
@@ -61,6 +104,74 @@ pub struct ImportProgress {
     pub phase: String,
 }
 
+/// Row-level progress for the `import:progress` event.
+/// Stage "decrypting": archive tables opened and counted (processed/total = tables).
+/// Stage "inserting": rows copied, emitted per batch of up to 100 rows.
+/// Stage "committed": the import transaction committed.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ImportStageProgress {
+    pub stage: String,
+    pub processed: usize,
+    pub total: usize,
+}
+
+/// Journal of an import in progress, written next to the decrypted temp file
+/// (`<uuid>.urb.journal` beside `<uuid>.urb.tmp`) and removed with it.
+/// A journal left behind means the app quit mid-import.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportJournal {
+    pub archive_path: String,
+    /// "replace" | "merge"
+    pub mode: String,
+    pub started_at: String,
+    /// Last `ImportStageProgress` stage reached
+    pub stage: String,
+    pub processed: usize,
+    pub total: usize,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl ImportJournal {
+    /// New journal for an import from `archive_path` decrypted to `temp_db_path`
+    pub fn new(archive_path: &str, temp_db_path: &Path, mode: ImportMode) -> Self {
+        Self {
+            archive_path: archive_path.to_string(),
+            mode: match mode {
+                ImportMode::ReplaceAll => "replace",
+                ImportMode::MergeSkipDuplicates => "merge",
+            }
+            .to_string(),
+            started_at: chrono::Utc::now().to_rfc3339(),
+            stage: "started".to_string(),
+            processed: 0,
+            total: 0,
+            path: journal_path(temp_db_path),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn is_committed(&self) -> bool {
+        self.stage == "committed"
+    }
+
+    /// Write the journal to disk
+    pub fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string(self)
+            .map_err(|e| format!("Failed to serialize import journal: {}", e))?;
+        fs::write(&self.path, json).map_err(|e| format!("Failed to write import journal: {}", e))
+    }
+}
+
+/// Journal file for a decrypted temp file (`<uuid>.urb.tmp` -> `<uuid>.urb.journal`)
+pub fn journal_path(temp_db_path: &Path) -> PathBuf {
+    temp_db_path.with_extension("journal")
+}
+
 /// Import summary statistics
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -72,6 +183,7 @@ pub struct ImportSummary {
     pub settings_imported: usize,
     pub settings_skipped: usize,
     pub voice_profile_imported: bool,
+    pub golden_proposals_imported: usize,
     pub total_records: usize,
 }
 
@@ -177,11 +289,15 @@ pub fn extract_archive_db(
 /// Derives key from passphrase + salt, opens the temp DB file with SQLCipher,
 /// runs PRAGMA quick_check to verify decryption success.
 ///
+/// `kdf_params` come from the archive metadata; archives written before they were
+/// recorded use the defaults.
+///
 /// Returns opened Connection for schema inspection. Caller must close connection.
 pub fn open_archive_for_preview(
     temp_db_path: &Path,
     passphrase: &str,
     salt: &[u8],
+    kdf_params: &passphrase::KdfParams,
 ) -> Result<Connection, ArchiveImportError> {
     // Convert salt slice to array for derive_key
     if salt.len() != 16 {
@@ -194,7 +310,7 @@ pub fn open_archive_for_preview(
     salt_array.copy_from_slice(salt);
 
     // Derive key from passphrase + archive salt
-    let key = passphrase::derive_key(passphrase, &salt_array)
+    let key = passphrase::derive_key_with_params(passphrase, &salt_array, kdf_params)
         .map_err(|_| ArchiveImportError::DecryptionFailed)?;
 
     // Open connection to temp DB
@@ -320,9 +436,94 @@ pub fn cleanup_orphaned_temp_files(temp_dir: &Path) -> Result<usize, String> {
     Ok(cleaned)
 }
 
-/// System settings keys that should never be imported (H-1: expanded skip list)
-const SYSTEM_SETTINGS_KEYS: &str =
-    "'onboarding_completed', 'db_version', 'encryption_status', 'encryption_migrated', 'last_migration_version', 'last_migration_date'";
+/// Handle journals left by imports interrupted by a crash or quit
+///
+/// Each `*.urb.journal` in `temp_dir` is removed along with its decrypted temp
+/// file. The import transaction only commits at the end, so an uncommitted
+/// import left nothing behind in the database; those journals are returned so
+/// the user can be told to retry. Committed ones only needed the cleanup.
+pub fn recover_interrupted_imports(temp_dir: &Path) -> Result<Vec<ImportJournal>, String> {
+    let mut incomplete = Vec::new();
+
+    let entries = fs::read_dir(temp_dir).map_err(|e| format!("Failed to read temp dir: {}", e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
+        let path = entry.path();
+        if !path.to_string_lossy().ends_with(".urb.journal") {
+            continue;
+        }
+
+        // The temp file name comes from the journal's own name, never its contents
+        let temp_db_path = path.with_extension("tmp");
+        if temp_db_path.exists() {
+            if let Err(e) = fs::remove_file(&temp_db_path) {
+                tracing::warn!(
+                    "Failed to remove interrupted import temp file {}: {}",
+                    temp_db_path.display(),
+                    e
+                );
+            }
+        }
+
+        let journal = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|json| {
+                serde_json::from_str::<ImportJournal>(&json).map_err(|e| e.to_string())
+            });
+        let _ = fs::remove_file(&path);
+
+        match journal {
+            Ok(journal) if journal.is_committed() => {
+                tracing::info!("Cleaned up files of completed import: {}", path.display());
+            }
+            Ok(mut journal) => {
+                tracing::warn!(
+                    stage = %journal.stage,
+                    processed = journal.processed,
+                    total = journal.total,
+                    "Import was interrupted before it committed; nothing was applied"
+                );
+                journal.path = path;
+                incomplete.push(journal);
+            }
+            Err(e) => tracing::warn!(
+                "Discarded unreadable import journal {}: {}",
+                path.display(),
+                e
+            ),
+        }
+    }
+
+    Ok(incomplete)
+}
+
+/// Settings keys that should never be imported (H-1: expanded skip list): system
+/// state, the network settings that decide where the API key and milestone
+/// webhooks are sent, and the locally trusted config key (added only with
+/// `add_trusted_config_key`), so an archive can't redirect API traffic or
+/// proposal data, or sign remote config. Local values are kept in both modes.
+const SYSTEM_SETTINGS_KEYS: &[&str] = &[
+    "onboarding_completed",
+    "db_version",
+    "encryption_status",
+    "encryption_migrated",
+    "last_migration_version",
+    "last_migration_date",
+    network::API_BASE_URL_SETTING,
+    network::ALLOWLIST_EXTRA_SETTING,
+    network::ALLOW_INSECURE_API_SETTING,
+    notifications::WEBHOOK_URL_SETTING,
+    remote_config::LOCAL_TRUSTED_KEY_SETTING,
+];
+
+/// `WHERE` clause selecting the importable settings rows
+fn importable_settings_where() -> String {
+    let keys: Vec<String> = SYSTEM_SETTINGS_KEYS
+        .iter()
+        .map(|key| format!("'{}'", key))
+        .collect();
+    format!("key NOT IN ({})", keys.join(", "))
+}
 
 /// Build a column-mapped INSERT SQL for cross-schema import (C-2: older archive support)
 ///
@@ -417,6 +618,64 @@ fn get_pragma_columns(
     Ok(columns)
 }
 
+/// Tables imported, in FK-safe order
+const IMPORT_TABLES: [&str; 12] = [
+    "settings",
+    "user_skills",
+    "rss_imports",
+    "job_posts",
+    "voice_profiles",
+    "golden_set_proposals",
+    "proposals",
+    "proposal_revisions",
+    "safety_overrides",
+    "job_skills",
+    "job_scores",
+    "scoring_feedback",
+];
+
+/// Sends table progress (`import-progress`) and row progress (`import:progress`),
+/// keeping the journal (if any) in step with the latest row progress
+struct ImportReporter<'a, F, G> {
+    progress_callback: F,
+    stage_callback: G,
+    journal: Option<&'a mut ImportJournal>,
+    rows_processed: usize,
+    rows_total: usize,
+}
+
+impl<F, G> ImportReporter<'_, F, G>
+where
+    F: FnMut(ImportProgress),
+    G: FnMut(ImportStageProgress),
+{
+    fn table_progress(&mut self, progress: ImportProgress) {
+        (self.progress_callback)(progress);
+    }
+
+    fn stage(&mut self, stage: &str, processed: usize, total: usize) {
+        (self.stage_callback)(ImportStageProgress {
+            stage: stage.to_string(),
+            processed,
+            total,
+        });
+        if let Some(journal) = self.journal.as_deref_mut() {
+            journal.stage = stage.to_string();
+            journal.processed = processed;
+            journal.total = total;
+            if let Err(e) = journal.save() {
+                tracing::warn!("{}", e);
+            }
+        }
+    }
+
+    /// Record `rows` more archive rows processed (inserted or skipped)
+    fn rows_done(&mut self, rows: usize) {
+        self.rows_processed += rows;
+        self.stage("inserting", self.rows_processed, self.rows_total);
+    }
+}
+
 /// Import a single table with column mapping and optional batching
 ///
 /// Uses PRAGMA table_info to dynamically map archive columns to main columns,
@@ -424,17 +683,18 @@ fn get_pragma_columns(
 /// For tables with >100 rows, uses LIMIT/OFFSET batching with progress callbacks.
 ///
 /// Returns the number of rows actually inserted.
-fn import_table_batched<F>(
+fn import_table_batched<F, G>(
     conn: &Connection,
     table: &str,
     mode: ImportMode,
     where_clause: Option<&str>,
     total_steps: usize,
     step_num: usize,
-    progress_callback: &mut F,
+    reporter: &mut ImportReporter<'_, F, G>,
 ) -> Result<usize, ArchiveImportError>
 where
     F: FnMut(ImportProgress),
+    G: FnMut(ImportStageProgress),
 {
     // Count archive rows (with optional WHERE filter)
     let count_sql = match where_clause {
@@ -458,6 +718,7 @@ where
             ArchiveImportError::ImportFailed(format!("Failed to import {}: {}", table, e))
         })?;
         total_inserted = conn.changes() as usize;
+        reporter.rows_done(archive_count);
     } else {
         // Large table — batched column-mapped INSERT
         let mut offset = 0;
@@ -473,10 +734,11 @@ where
                 ArchiveImportError::ImportFailed(format!("Failed to import {} batch: {}", table, e))
             })?;
             total_inserted += conn.changes() as usize;
+            reporter.rows_done(IMPORT_BATCH_SIZE.min(archive_count - offset));
             offset += IMPORT_BATCH_SIZE;
 
             // Emit progress between batches
-            progress_callback(ImportProgress {
+            reporter.table_progress(ImportProgress {
                 table: table.to_string(),
                 current: step_num,
                 total: total_steps,
@@ -502,20 +764,37 @@ where
 /// * `temp_db_path` - Path to extracted/decrypted archive temp file
 /// * `hex_key` - Hex-encoded encryption key for archive
 /// * `mode` - Import mode (Replace or Merge)
-/// * `progress_callback` - Called with progress updates
+/// * `voice_import` - Whether archive voice data is imported, and what happens to local
+///   voice data (kept as-is unless `VoiceDataImport::Replace`, in either mode)
+/// * `journal` - Crash-recovery journal, updated with each stage progress update
+/// * `progress_callback` - Called with per-table progress updates
+/// * `stage_callback` - Called with row progress (`import:progress`)
 ///
 /// # Returns
 /// ImportSummary with counts of imported/skipped records
-pub fn import_from_archive<F>(
+#[allow(clippy::too_many_arguments)]
+pub fn import_from_archive<F, G>(
     target_db: &Database,
     temp_db_path: &Path,
     hex_key: &str,
     mode: ImportMode,
-    mut progress_callback: F,
+    voice_import: VoiceDataImport,
+    journal: Option<&mut ImportJournal>,
+    progress_callback: F,
+    stage_callback: G,
 ) -> Result<ImportSummary, ArchiveImportError>
 where
     F: FnMut(ImportProgress),
+    G: FnMut(ImportStageProgress),
 {
+    let mut reporter = ImportReporter {
+        progress_callback,
+        stage_callback,
+        journal,
+        rows_processed: 0,
+        rows_total: 0,
+    };
+
     // Acquire main DB lock
     let conn = target_db
         .conn
@@ -544,16 +823,37 @@ where
         let _ = conn.execute_batch("DETACH DATABASE archive;");
     }
 
+    // Total number of tables to import (for progress tracking)
+    const TOTAL_TABLES: usize = IMPORT_TABLES.len();
+    let settings_where = importable_settings_where();
+
+    // Read through every archive table once for the row total (decrypting its pages)
+    for (index, table) in IMPORT_TABLES.iter().enumerate() {
+        if voice_import == VoiceDataImport::Skip && VOICE_DATA_TABLES.contains(table) {
+            reporter.stage("decrypting", index + 1, TOTAL_TABLES);
+            continue;
+        }
+        let count_sql = if *table == "settings" {
+            format!(
+                "SELECT COUNT(*) FROM archive.settings WHERE {};",
+                settings_where
+            )
+        } else {
+            format!("SELECT COUNT(*) FROM archive.{};", table)
+        };
+        let count: usize = conn
+            .query_row(&count_sql, [], |row| row.get(0))
+            .unwrap_or(0);
+        reporter.rows_total += count;
+        reporter.stage("decrypting", index + 1, TOTAL_TABLES);
+    }
+
     // Begin exclusive transaction
     conn.execute_batch("BEGIN EXCLUSIVE TRANSACTION;")
         .map_err(|e| {
             ArchiveImportError::ImportFailed(format!("Failed to begin transaction: {}", e))
         })?;
 
-    // Total number of tables to import (for progress tracking)
-    const TOTAL_TABLES: usize = 12;
-    let settings_where = format!("key NOT IN ({})", SYSTEM_SETTINGS_KEYS);
-
     // Import result (will be set on success)
     let result: Result<ImportSummary, ArchiveImportError> = (|| {
         let mut summary = ImportSummary {
@@ -564,12 +864,13 @@ where
             settings_imported: 0,
             settings_skipped: 0,
             voice_profile_imported: false,
+            golden_proposals_imported: 0,
             total_records: 0,
         };
 
         if mode == ImportMode::ReplaceAll {
             // Delete all user data in FK-safe reverse order
-            progress_callback(ImportProgress {
+            reporter.table_progress(ImportProgress {
                 table: "Clearing data".to_string(),
                 current: 0,
                 total: TOTAL_TABLES,
@@ -585,8 +886,6 @@ where
                  DELETE FROM proposal_revisions;
                  DELETE FROM proposals;
                  DELETE FROM job_posts;
-                 DELETE FROM golden_set_proposals;
-                 DELETE FROM voice_profiles;
                  DELETE FROM user_skills;
                  DELETE FROM rss_imports;
                  DELETE FROM settings WHERE {};",
@@ -597,6 +896,21 @@ where
             })?;
         }
 
+        // Voice data follows its own policy: local data is only cleared to be replaced
+        if voice_import == VoiceDataImport::Replace {
+            conn.execute_batch(
+                "DELETE FROM golden_set_proposals;
+                 DELETE FROM voice_profiles;",
+            )
+            .map_err(|e| {
+                ArchiveImportError::ImportFailed(format!("Failed to clear voice data: {}", e))
+            })?;
+        }
+        let voice_mode = match voice_import {
+            VoiceDataImport::Replace => ImportMode::ReplaceAll,
+            _ => ImportMode::MergeSkipDuplicates,
+        };
+
         // For Merge mode: pre-count archive rows to calculate skipped counts
         let archive_proposal_count: usize = if mode == ImportMode::MergeSkipDuplicates {
             conn.query_row("SELECT COUNT(*) FROM archive.proposals;", [], |row| {
@@ -631,7 +945,7 @@ where
             Some(&settings_where),
             TOTAL_TABLES,
             1,
-            &mut progress_callback,
+            &mut reporter,
         )?;
 
         let user_skills_count = import_table_batched(
@@ -641,7 +955,7 @@ where
             None,
             TOTAL_TABLES,
             2,
-            &mut progress_callback,
+            &mut reporter,
         )?;
 
         let rss_count = import_table_batched(
@@ -651,7 +965,7 @@ where
             None,
             TOTAL_TABLES,
             3,
-            &mut progress_callback,
+            &mut reporter,
         )?;
 
         // Step 2: Independent tables (no FKs to other user tables)
@@ -662,29 +976,34 @@ where
             None,
             TOTAL_TABLES,
             4,
-            &mut progress_callback,
+            &mut reporter,
         )?;
 
-        let voice_count = import_table_batched(
-            &conn,
-            "voice_profiles",
-            mode,
-            None,
-            TOTAL_TABLES,
-            5,
-            &mut progress_callback,
-        )?;
+        let (voice_count, golden_count) = if voice_import == VoiceDataImport::Skip {
+            (0, 0)
+        } else {
+            let voice_count = import_table_batched(
+                &conn,
+                "voice_profiles",
+                voice_mode,
+                None,
+                TOTAL_TABLES,
+                5,
+                &mut reporter,
+            )?;
+            let golden_count = import_table_batched(
+                &conn,
+                "golden_set_proposals",
+                voice_mode,
+                None,
+                TOTAL_TABLES,
+                6,
+                &mut reporter,
+            )?;
+            (voice_count, golden_count)
+        };
         summary.voice_profile_imported = voice_count > 0;
-
-        let golden_count = import_table_batched(
-            &conn,
-            "golden_set_proposals",
-            mode,
-            None,
-            TOTAL_TABLES,
-            6,
-            &mut progress_callback,
-        )?;
+        summary.golden_proposals_imported = golden_count;
 
         // Step 3: proposals (FK: job_post_id → job_posts)
         summary.proposals_imported = import_table_batched(
@@ -694,7 +1013,7 @@ where
             None,
             TOTAL_TABLES,
             7,
-            &mut progress_callback,
+            &mut reporter,
         )?;
 
         // Merge mode: calculate skipped counts
@@ -713,7 +1032,7 @@ where
             None,
             TOTAL_TABLES,
             8,
-            &mut progress_callback,
+            &mut reporter,
         )?;
 
         let overrides_count = import_table_batched(
@@ -723,7 +1042,7 @@ where
             None,
             TOTAL_TABLES,
             9,
-            &mut progress_callback,
+            &mut reporter,
         )?;
 
         // Step 5: Tables depending on job_posts
@@ -734,7 +1053,7 @@ where
             None,
             TOTAL_TABLES,
             10,
-            &mut progress_callback,
+            &mut reporter,
         )?;
 
         let scores_count = import_table_batched(
@@ -744,7 +1063,7 @@ where
             None,
             TOTAL_TABLES,
             11,
-            &mut progress_callback,
+            &mut reporter,
         )?;
 
         let feedback_count = import_table_batched(
@@ -754,7 +1073,7 @@ where
             None,
             TOTAL_TABLES,
             12,
-            &mut progress_callback,
+            &mut reporter,
         )?;
 
         // H-3: total_records counts ALL imported tables (not just the named summary fields)
@@ -781,7 +1100,8 @@ where
                 ArchiveImportError::ImportFailed(format!("Failed to commit: {}", e))
             })?;
 
-            progress_callback(ImportProgress {
+            reporter.stage("committed", reporter.rows_processed, reporter.rows_total);
+            reporter.table_progress(ImportProgress {
                 table: "Complete".to_string(),
                 current: TOTAL_TABLES,
                 total: TOTAL_TABLES,
@@ -898,6 +1218,298 @@ mod tests {
         assert_eq!(cleaned, 0);
     }
 
+    #[test]
+    fn test_recover_interrupted_imports() {
+        let temp_dir = TempDir::new().unwrap();
+        let write_import = |name: &str, stage: &str| {
+            let temp_db_path = temp_dir.path().join(format!("{}.urb.tmp", name));
+            fs::write(&temp_db_path, b"decrypted").unwrap();
+            let mut journal = ImportJournal::new(
+                "/backups/old.urb",
+                &temp_db_path,
+                ImportMode::MergeSkipDuplicates,
+            );
+            journal.stage = stage.to_string();
+            journal.processed = 200;
+            journal.total = 450;
+            journal.save().unwrap();
+            temp_db_path
+        };
+        let interrupted = write_import("interrupted", "inserting");
+        let completed = write_import("completed", "committed");
+        fs::write(temp_dir.path().join("garbled.urb.journal"), b"{not json").unwrap();
+
+        let incomplete = recover_interrupted_imports(temp_dir.path()).unwrap();
+
+        assert_eq!(incomplete.len(), 1);
+        assert_eq!(incomplete[0].archive_path, "/backups/old.urb");
+        assert_eq!(incomplete[0].stage, "inserting");
+        assert_eq!(incomplete[0].processed, 200);
+        assert!(!interrupted.exists());
+        assert!(!completed.exists());
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_import_progress_journal_and_idempotent_rerun() {
+        let dir = TempDir::new().unwrap();
+        let key = vec![7u8; 32];
+        let archive_path = dir.path().join("archive.urb.tmp");
+        {
+            let archive = Database::new(archive_path.clone(), Some(key.clone())).unwrap();
+            let conn = archive.conn.lock().unwrap();
+            for i in 0..150 {
+                crate::db::queries::proposals::insert_proposal(
+                    &conn,
+                    &format!("Job {}", i),
+                    "Proposal text",
+                    None,
+                )
+                .unwrap();
+            }
+        }
+        let target = Database::new(dir.path().join("main.db"), None).unwrap();
+        let hex_key = hex::encode(&key);
+
+        let mut journal =
+            ImportJournal::new("backup.urb", &archive_path, ImportMode::MergeSkipDuplicates);
+        let mut stages = Vec::new();
+        let summary = import_from_archive(
+            &target,
+            &archive_path,
+            &hex_key,
+            ImportMode::MergeSkipDuplicates,
+            VoiceDataImport::Skip,
+            Some(&mut journal),
+            |_| {},
+            |progress: ImportStageProgress| stages.push(progress),
+        )
+        .unwrap();
+        assert_eq!(summary.proposals_imported, 150);
+
+        // Decryption per table, then rows per batch, then the commit
+        assert_eq!(stages[0].stage, "decrypting");
+        assert_eq!(
+            stages[IMPORT_TABLES.len() - 1].processed,
+            IMPORT_TABLES.len()
+        );
+        let inserting: Vec<_> = stages.iter().filter(|p| p.stage == "inserting").collect();
+        assert!(inserting
+            .windows(2)
+            .all(|w| w[0].processed <= w[1].processed));
+        assert!(inserting.iter().all(|p| p.total == inserting[0].total));
+        let last = stages.last().unwrap();
+        assert_eq!(last.stage, "committed");
+        assert_eq!(last.processed, last.total);
+
+        // The journal tracked the progress on disk
+        let saved: ImportJournal =
+            serde_json::from_str(&fs::read_to_string(journal.path()).unwrap()).unwrap();
+        assert!(saved.is_committed());
+
+        // Re-running skips everything already present
+        let rerun = import_from_archive(
+            &target,
+            &archive_path,
+            &hex_key,
+            ImportMode::MergeSkipDuplicates,
+            VoiceDataImport::Skip,
+            None,
+            |_| {},
+            |_| {},
+        )
+        .unwrap();
+        assert_eq!(rerun.proposals_imported, 0);
+        assert_eq!(rerun.proposals_skipped, 150);
+    }
+
+    #[test]
+    fn test_voice_data_import_policies() {
+        use crate::db::queries::{golden_set, voice_profile};
+
+        fn save_profile(conn: &Connection, tone_score: f64) {
+            voice_profile::save_voice_profile(
+                conn,
+                &voice_profile::VoiceProfileRow {
+                    id: None,
+                    user_id: "default".to_string(),
+                    tone_score,
+                    avg_sentence_length: 15.0,
+                    vocabulary_complexity: 9.0,
+                    structure_paragraphs_pct: 70,
+                    structure_bullets_pct: 30,
+                    technical_depth: 7.0,
+                    length_preference: 5.0,
+                    common_phrases: vec![],
+                    sample_count: 3,
+                    calibration_source: "GoldenSet".to_string(),
+                    created_at: None,
+                    updated_at: None,
+                },
+            )
+            .unwrap();
+        }
+        let tone = |db: &Database| {
+            let conn = db.conn.lock().unwrap();
+            voice_profile::get_voice_profile(&conn, "default")
+                .unwrap()
+                .map(|p| p.tone_score)
+        };
+        let golden = |db: &Database| {
+            let conn = db.conn.lock().unwrap();
+            golden_set::get_golden_proposal_count(&conn).unwrap()
+        };
+
+        let dir = TempDir::new().unwrap();
+        let key = vec![7u8; 32];
+        let archive_path = dir.path().join("archive.urb.tmp");
+        {
+            let archive = Database::new(archive_path.clone(), Some(key.clone())).unwrap();
+            let conn = archive.conn.lock().unwrap();
+            save_profile(&conn, 8.0);
+            golden_set::add_golden_proposal_with_min_words(&conn, "Archived golden one", None, 1)
+                .unwrap();
+            golden_set::add_golden_proposal_with_min_words(&conn, "Archived golden two", None, 1)
+                .unwrap();
+        }
+        let hex_key = hex::encode(&key);
+        let run = |target: &Database, mode: ImportMode, voice_import: VoiceDataImport| {
+            import_from_archive(
+                target,
+                &archive_path,
+                &hex_key,
+                mode,
+                voice_import,
+                None,
+                |_| {},
+                |_| {},
+            )
+            .unwrap()
+        };
+        let target = |name: &str| {
+            let db = Database::new(dir.path().join(name), None).unwrap();
+            save_profile(&db.conn.lock().unwrap(), 3.0);
+            db
+        };
+
+        // Not requested: local voice data survives even a replace-all import
+        let db = target("skip.db");
+        let summary = run(&db, ImportMode::ReplaceAll, VoiceDataImport::Skip);
+        assert!(!summary.voice_profile_imported);
+        assert_eq!(tone(&db), Some(3.0));
+        assert_eq!(golden(&db), 0);
+
+        // Keep: the local profile wins, golden proposals are added
+        let db = target("keep.db");
+        let summary = run(
+            &db,
+            ImportMode::MergeSkipDuplicates,
+            VoiceDataImport::KeepExisting,
+        );
+        assert!(!summary.voice_profile_imported);
+        assert_eq!(summary.golden_proposals_imported, 2);
+        assert_eq!(tone(&db), Some(3.0));
+        assert_eq!(golden(&db), 2);
+
+        // Replace: the archive's profile replaces the local one, in either mode
+        let db = target("replace.db");
+        let summary = run(
+            &db,
+            ImportMode::MergeSkipDuplicates,
+            VoiceDataImport::Replace,
+        );
+        assert!(summary.voice_profile_imported);
+        assert_eq!(tone(&db), Some(8.0));
+        assert_eq!(golden(&db), 2);
+
+        assert_eq!(
+            VoiceDataImport::from_options(false, Some("replace")),
+            Ok(VoiceDataImport::Skip)
+        );
+        assert_eq!(
+            VoiceDataImport::from_options(true, None),
+            Ok(VoiceDataImport::KeepExisting)
+        );
+        assert!(VoiceDataImport::from_options(true, Some("merge")).is_err());
+    }
+
+    #[test]
+    fn test_network_and_trust_settings_not_imported() {
+        use crate::db::queries::settings::{get_setting, set_setting};
+
+        let protected = [
+            (network::API_BASE_URL_SETTING, "https://attacker.example"),
+            (network::ALLOWLIST_EXTRA_SETTING, "attacker.example"),
+            (network::ALLOW_INSECURE_API_SETTING, "true"),
+            (
+                notifications::WEBHOOK_URL_SETTING,
+                "https://attacker.example/hook",
+            ),
+            (
+                remote_config::LOCAL_TRUSTED_KEY_SETTING,
+                "attacker-signing-key-attacker-signing-key",
+            ),
+        ];
+
+        let dir = TempDir::new().unwrap();
+        let key = vec![7u8; 32];
+        let archive_path = dir.path().join("archive.urb.tmp");
+        {
+            let archive = Database::new(archive_path.clone(), Some(key.clone())).unwrap();
+            let conn = archive.conn.lock().unwrap();
+            for (setting, value) in protected {
+                set_setting(&conn, setting, value).unwrap();
+            }
+            set_setting(&conn, "digest_enabled", "true").unwrap();
+        }
+        let hex_key = hex::encode(&key);
+
+        for (name, mode) in [
+            ("replace.db", ImportMode::ReplaceAll),
+            ("merge.db", ImportMode::MergeSkipDuplicates),
+        ] {
+            let db = Database::new(dir.path().join(name), None).unwrap();
+            set_setting(
+                &db.conn.lock().unwrap(),
+                network::API_BASE_URL_SETTING,
+                "https://local.example",
+            )
+            .unwrap();
+            import_from_archive(
+                &db,
+                &archive_path,
+                &hex_key,
+                mode,
+                VoiceDataImport::Skip,
+                None,
+                |_| {},
+                |_| {},
+            )
+            .unwrap();
+
+            let conn = db.conn.lock().unwrap();
+            assert_eq!(
+                get_setting(&conn, network::API_BASE_URL_SETTING).unwrap(),
+                Some("https://local.example".to_string()),
+                "{:?}",
+                mode
+            );
+            for (setting, value) in protected {
+                assert_ne!(
+                    get_setting(&conn, setting).unwrap().as_deref(),
+                    Some(value),
+                    "{} imported in {:?}",
+                    setting,
+                    mode
+                );
+            }
+            assert_eq!(
+                get_setting(&conn, "digest_enabled").unwrap(),
+                Some("true".to_string())
+            );
+        }
+    }
+
     #[test]
     fn test_invalid_archive_rejected() {
         let temp = NamedTempFile::new().unwrap();
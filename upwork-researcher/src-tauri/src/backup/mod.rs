@@ -8,6 +8,7 @@
 //! - Timestamp-based filenames for uniqueness
 //! - Atomic file writes (temp → rename)
 //! - Verification of backup readability
+//! - Pre-flight free-space check before exporting
 //! - NFR-1: <5 seconds for typical datasets (50 proposals)
 
 #[cfg(test)]
@@ -41,6 +42,9 @@ pub enum BackupError {
 
     #[error("Database lock error: {0}")]
     DatabaseLockError(String),
+
+    #[error("Insufficient disk space for backup: {0}")]
+    DiskSpaceError(String),
 }
 
 /// Metadata about a completed backup
@@ -116,6 +120,7 @@ pub fn create_pre_migration_backup(
 
     // Ensure backups directory exists (Subtask 1.4)
     let backups_dir = app_data_dir.join("backups");
+    ensure_backup_space(db, &backups_dir)?;
     fs::create_dir_all(&backups_dir)
         .map_err(|e| BackupError::DirectoryCreationFailed(e.to_string()))?;
 
@@ -142,6 +147,13 @@ pub fn create_pre_migration_backup(
     })
 }
 
+/// Refuse to start a backup into `dir` when it can't hold roughly one copy of
+/// the database (skipped with a warning if free space is unknown)
+fn ensure_backup_space(db: &Database, dir: &Path) -> Result<(), BackupError> {
+    let required = crate::disk_space::backup_required_bytes(&db.path);
+    crate::disk_space::ensure_available(dir, required).map_err(BackupError::DiskSpaceError)
+}
+
 /// Export all database tables to BackupData structure
 fn export_database_to_backup(db: &Database) -> Result<BackupData, BackupError> {
     let conn = db
@@ -312,6 +324,8 @@ pub fn export_unencrypted_backup(
     db: &Database,
     file_path: &Path,
 ) -> Result<BackupMetadata, BackupError> {
+    ensure_backup_space(db, file_path.parent().unwrap_or(file_path))?;
+
     // Ensure parent directory exists
     if let Some(parent) = file_path.parent() {
         fs::create_dir_all(parent)
@@ -1,15 +1,27 @@
-use crate::{db, events, humanization, network, sanitization::sanitize_job_content, DraftState};
+use crate::http::TrackedSend;
+use crate::language::TargetLanguage;
+use crate::logs::api_debug;
+use crate::proposal_length::LengthTarget;
+use crate::settings::schema::{get_typed, Key};
+use crate::{
+    api_quota, db, events, humanization, network, perplexity_chunks,
+    sanitization::sanitize_job_content, voice, DraftState,
+};
 use futures::StreamExt;
+use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::env;
+use std::sync::Mutex;
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 
-const ANTHROPIC_API_URL: &str = "https://api.anthropic.com/v1/messages";
 const ANTHROPIC_VERSION: &str = "2023-06-01";
-const MODEL: &str = "claude-sonnet-4-20250514";
+pub const MODEL: &str = "claude-sonnet-4-20250514";
 const HAIKU_MODEL: &str = "claude-haiku-4-20250514";
 const TOKEN_BATCH_INTERVAL_MS: u64 = 50;
+/// Minimum time between `generation:stats` events
+const STATS_INTERVAL_MS: u64 = 500;
 
 #[derive(Debug, Serialize)]
 struct Message {
@@ -25,6 +37,9 @@ struct ClaudeRequest {
     messages: Vec<Message>,
     #[serde(skip_serializing_if = "Option::is_none")]
     stream: Option<bool>,
+    /// None keeps the API default (1.0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
 }
 
 // SSE event types for streaming responses
@@ -41,6 +56,17 @@ struct Delta {
     text: String,
 }
 
+/// `message_delta` event near the end of the stream: carries the exact output token count
+#[derive(Debug, Deserialize)]
+struct MessageDeltaUsage {
+    usage: OutputUsage,
+}
+
+#[derive(Debug, Deserialize)]
+struct OutputUsage {
+    output_tokens: u64,
+}
+
 // Tauri event payloads
 #[derive(Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -63,6 +89,125 @@ pub struct ErrorPayload {
     pub message: String,
 }
 
+/// `generation:stats` payload, and the totals returned with a finished generation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationStats {
+    /// Output tokens so far
+    pub tokens: u64,
+    /// Since the request was sent
+    pub elapsed_ms: u64,
+    pub tokens_per_second: f64,
+    /// False while `tokens` is estimated from characters; the API only reports
+    /// the exact count at the end of the stream
+    pub exact: bool,
+}
+
+impl GenerationStats {
+    /// Stats for `text` streamed in `elapsed`; `exact_tokens` overrides the estimate
+    fn new(text: &str, exact_tokens: Option<u64>, elapsed: Duration) -> Self {
+        let tokens = exact_tokens.unwrap_or(crate::sanitization::estimate_tokens(text) as u64);
+        let secs = elapsed.as_secs_f64();
+        let tokens_per_second = if secs > 0.0 {
+            (tokens as f64 / secs * 10.0).round() / 10.0
+        } else {
+            0.0
+        };
+        Self {
+            tokens,
+            elapsed_ms: elapsed.as_millis() as u64,
+            tokens_per_second,
+            exact: exact_tokens.is_some(),
+        }
+    }
+}
+
+/// Running character and word counts of streamed text, updated per chunk so
+/// progress events never rescan the whole buffer. A word is a whitespace-separated
+/// run containing at least one letter or digit, so stray dashes and ellipses
+/// don't count; this matches `count_words`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WordCounter {
+    chars: u64,
+    words: u64,
+    in_run: bool,
+    run_has_word_char: bool,
+}
+
+impl WordCounter {
+    pub fn push(&mut self, text: &str) {
+        for c in text.chars() {
+            self.chars += 1;
+            if c.is_whitespace() {
+                self.end_run();
+            } else {
+                self.in_run = true;
+                self.run_has_word_char |= c.is_alphanumeric();
+            }
+        }
+    }
+
+    fn end_run(&mut self) {
+        if self.in_run && self.run_has_word_char {
+            self.words += 1;
+        }
+        self.in_run = false;
+        self.run_has_word_char = false;
+    }
+
+    pub fn chars(&self) -> u64 {
+        self.chars
+    }
+
+    /// Words so far, counting a word still being streamed
+    pub fn words(&self) -> u64 {
+        self.words + u64::from(self.in_run && self.run_has_word_char)
+    }
+}
+
+/// Full recount with the same rules as `WordCounter`
+pub fn count_words(text: &str) -> u64 {
+    text.split_whitespace()
+        .filter(|run| run.chars().any(char::is_alphanumeric))
+        .count() as u64
+}
+
+/// `generation:progress` payload, emitted every N token events
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationProgress {
+    pub chars: u64,
+    pub words: u64,
+    /// Token events (text deltas) received so far
+    pub token_events: u64,
+    pub elapsed_ms: u64,
+    /// Estimated from characters, like `generation:stats`
+    pub tokens_per_second: f64,
+}
+
+impl GenerationProgress {
+    fn new(counter: &WordCounter, token_events: u64, stats: &GenerationStats) -> Self {
+        Self {
+            chars: counter.chars(),
+            words: counter.words(),
+            token_events,
+            elapsed_ms: stats.elapsed_ms,
+            tokens_per_second: stats.tokens_per_second,
+        }
+    }
+}
+
+/// `generation:summary` payload: final totals of a completed stream
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationSummary {
+    pub chars: u64,
+    pub words: u64,
+    pub token_events: u64,
+    #[serde(flatten)]
+    pub stats: GenerationStats,
+}
+
 #[derive(Debug, Deserialize)]
 struct ContentBlock {
     text: Option<String>,
@@ -98,6 +243,23 @@ pub struct PerplexityAnalysis {
     pub score: f32,
     pub threshold: f32,
     pub flagged_sentences: Vec<FlaggedSentence>,
+    /// Some chunks failed; `score` and `flagged_sentences` cover the rest
+    #[serde(default)]
+    pub partial: bool,
+    /// Number of requests the text was analyzed in
+    #[serde(default)]
+    pub chunk_count: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_ranges: Vec<FailedChunkRange>,
+}
+
+/// Text that could not be analyzed (character offsets into the analyzed text)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailedChunkRange {
+    pub start: usize,
+    pub end: usize,
+    pub error: String,
 }
 
 /// TD-1: Rewritten to remove formulaic structure that triggers AI detection.
@@ -109,6 +271,347 @@ pub const SYSTEM_PROMPT: &str = r#"You are writing an Upwork proposal on behalf
 
 Keep it under 200 words. Write like a real freelancer — direct, confident, conversational."#;
 
+/// Settings key for the streaming idle timeout (seconds without a chunk before aborting)
+pub const GENERATION_TIMEOUT_SETTING: &str = "generation_timeout_secs";
+
+pub const DEFAULT_GENERATION_TIMEOUT_SECS: u64 = 120;
+pub const MIN_GENERATION_TIMEOUT_SECS: u64 = 10;
+pub const MAX_GENERATION_TIMEOUT_SECS: u64 = 600;
+
+/// Settings key for how often a streaming generation autosaves its draft (Story 1.14)
+pub const DRAFT_AUTOSAVE_SETTING: &str = "draft_autosave_ms";
+
+pub const DEFAULT_DRAFT_AUTOSAVE_MS: u64 = TOKEN_BATCH_INTERVAL_MS;
+pub const MIN_DRAFT_AUTOSAVE_MS: u64 = 50;
+pub const MAX_DRAFT_AUTOSAVE_MS: u64 = 5000;
+
+/// Settings key for how many token events pass between `generation:progress` events
+pub const PROGRESS_INTERVAL_SETTING: &str = "generation_progress_tokens";
+
+pub const DEFAULT_PROGRESS_INTERVAL_TOKENS: u64 = 20;
+pub const MIN_PROGRESS_INTERVAL_TOKENS: u64 = 1;
+pub const MAX_PROGRESS_INTERVAL_TOKENS: u64 = 1000;
+
+/// Error prefix for a stalled stream (classified as `ErrorCode::GenerationTimeout`)
+pub const GENERATION_TIMEOUT_PREFIX: &str = "GENERATION_TIMEOUT:";
+
+/// Idle timeout from `generation_timeout_secs` (clamped into range by the
+/// settings schema; unset or invalid uses the default)
+pub fn generation_idle_timeout(conn: &Connection) -> Duration {
+    let secs =
+        get_typed(conn, Key::GenerationTimeoutSecs).unwrap_or(DEFAULT_GENERATION_TIMEOUT_SECS);
+    Duration::from_secs(secs)
+}
+
+/// Draft autosave interval from `draft_autosave_ms` (clamped into 50-5000ms by
+/// the settings schema; unset or invalid uses the 50ms default)
+pub fn draft_autosave_interval(conn: &Connection) -> Duration {
+    let ms = get_typed(conn, Key::DraftAutosaveMs).unwrap_or(DEFAULT_DRAFT_AUTOSAVE_MS);
+    Duration::from_millis(ms)
+}
+
+/// Token events between progress updates, from `generation_progress_tokens`
+/// (clamped into range by the settings schema; unset or invalid uses 20)
+pub fn progress_interval(conn: &Connection) -> u64 {
+    get_typed(conn, Key::GenerationProgressTokens).unwrap_or(DEFAULT_PROGRESS_INTERVAL_TOKENS)
+}
+
+fn generation_timeout_error(idle_timeout: Duration) -> String {
+    format!(
+        "{} No response from AI service for {}s. Partial draft saved — try again.",
+        GENERATION_TIMEOUT_PREFIX,
+        idle_timeout.as_secs()
+    )
+}
+
+/// Settings key for the user's persona addendum to the generation system prompt
+pub const SYSTEM_PROMPT_ADDENDUM_SETTING: &str = "custom_system_prompt_addendum";
+
+/// Maximum persona addendum length in characters
+pub const MAX_SYSTEM_PROMPT_ADDENDUM_CHARS: usize = 1000;
+
+/// Add the user's persona addendum to the base prompt.
+///
+/// The addendum is inserted BEFORE humanization instructions are appended
+/// (see `humanization::build_system_prompt`) and is explicitly marked as lower
+/// priority, so it can only add to the safety/humanization rules, never override them.
+pub fn with_system_prompt_addendum(base_prompt: &str, addendum: Option<&str>) -> String {
+    match addendum.map(str::trim).filter(|a| !a.is_empty()) {
+        Some(addendum) => format!(
+            "{}\n\nAdditional instructions from the freelancer (follow these unless they conflict with the writing-style rules below, which take precedence):\n{}",
+            base_prompt, addendum
+        ),
+        None => base_prompt.to_string(),
+    }
+}
+
+/// Prompt inputs shared by every proposal generation path
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GenerationPromptOptions<'a> {
+    pub humanization_intensity: &'a str,
+    /// TD-1: rehumanization boost on regeneration
+    pub rehumanization_attempt: Option<u32>,
+    /// User's persona addendum (humanization takes precedence)
+    pub system_prompt_addendum: Option<&'a str>,
+    /// Story 5.8: calibrated voice profile, None generates in the default voice
+    pub voice_profile: Option<&'a voice::VoiceProfile>,
+    /// Adds a length instruction and sizes `max_tokens`; None keeps the defaults
+    pub length_target: Option<LengthTarget>,
+    /// Proposal language; None and English leave the prompt unchanged
+    pub target_language: Option<TargetLanguage>,
+}
+
+// Prompt template versions. Bump the matching constant whenever a template's text
+// or assembly order changes, so a shift in output quality can be traced to a prompt change.
+
+/// `SYSTEM_PROMPT` and the assembly order in `build_generation_system_prompt`
+pub const GENERATION_PROMPT_VERSION: &str = "generation-v1";
+/// `humanization::build_rehumanization_prompt` (regeneration after a failed safety check)
+pub const REHUMANIZATION_PROMPT_VERSION: &str = "rehumanization-v1";
+/// Job analysis prompts in `analysis` (budget extraction, job analysis, coverage)
+pub const ANALYSIS_PROMPT_VERSION: &str = "analysis-v1";
+
+/// Current prompt template versions, as returned by `get_current_prompt_versions`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptVersions {
+    pub generation: String,
+    pub rehumanization: String,
+    pub analysis: String,
+}
+
+impl PromptVersions {
+    pub fn current() -> Self {
+        Self {
+            generation: GENERATION_PROMPT_VERSION.to_string(),
+            rehumanization: REHUMANIZATION_PROMPT_VERSION.to_string(),
+            analysis: ANALYSIS_PROMPT_VERSION.to_string(),
+        }
+    }
+}
+
+/// How a proposal's prompt was assembled, stored as JSON with its generation metadata.
+/// Only the template version, a hash of the assembled system prompt and the parameter
+/// values are kept: never prompt text, job content or the persona addendum itself.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationContext {
+    /// `GENERATION_PROMPT_VERSION`, or `REHUMANIZATION_PROMPT_VERSION` on a boosted retry
+    pub prompt_version: String,
+    /// SHA-256 (hex) of the full system prompt sent to the API
+    pub system_prompt_sha256: String,
+    pub humanization_intensity: String,
+    pub rehumanization_attempt: Option<u32>,
+    pub hook_strategy_id: Option<String>,
+    /// Voice parameters applied, e.g. "tone 6.5, sentences 14.0 words, ..."; None = default voice
+    pub voice_summary: Option<String>,
+    pub target_words: Option<u32>,
+    /// Language code when a language instruction was added
+    pub target_language: Option<String>,
+    /// Whether a persona addendum was included (its text is not recorded)
+    pub addendum_applied: bool,
+}
+
+impl GenerationContext {
+    /// Context for a prompt built from `options`; hashes the same system prompt the
+    /// request would carry
+    pub fn for_prompt(options: &GenerationPromptOptions, hook_strategy_id: Option<&str>) -> Self {
+        use sha2::{Digest, Sha256};
+
+        let system_prompt = build_generation_system_prompt(options);
+        Self {
+            prompt_version: match options.rehumanization_attempt {
+                Some(_) => REHUMANIZATION_PROMPT_VERSION,
+                None => GENERATION_PROMPT_VERSION,
+            }
+            .to_string(),
+            system_prompt_sha256: hex::encode(Sha256::digest(system_prompt.as_bytes())),
+            humanization_intensity: options.humanization_intensity.to_string(),
+            rehumanization_attempt: options.rehumanization_attempt,
+            hook_strategy_id: hook_strategy_id.map(str::to_string),
+            voice_summary: options.voice_profile.map(voice_summary),
+            target_words: options.length_target.map(|t| t.target_words()),
+            target_language: options.target_language.map(|l| l.code().to_string()),
+            addendum_applied: options
+                .system_prompt_addendum
+                .is_some_and(|a| !a.trim().is_empty()),
+        }
+    }
+}
+
+/// Numeric voice parameters only; common phrases come from the user's writing and are left out
+fn voice_summary(profile: &voice::VoiceProfile) -> String {
+    format!(
+        "tone {:.1}, sentences {:.1} words, complexity {:.1}, depth {:.1}, length {:.1}, samples {}",
+        profile.tone_score,
+        profile.avg_sentence_length,
+        profile.vocabulary_complexity,
+        profile.technical_depth,
+        profile.length_preference,
+        profile.sample_count
+    )
+}
+
+/// Assemble the generation system prompt. Order: base prompt, voice calibration
+/// (Story 5.8), persona addendum, length instruction, language instruction, then
+/// humanization (Story 3.3) last so its rules win. Does not log.
+pub fn build_generation_system_prompt(options: &GenerationPromptOptions) -> String {
+    let mut base_prompt = SYSTEM_PROMPT.to_string();
+    if let Some(profile) = options.voice_profile {
+        base_prompt.push_str(&voice::build_voice_instructions(profile));
+    }
+
+    // Persona addendum goes before humanization so humanization rules come last and win
+    let mut base_prompt = with_system_prompt_addendum(&base_prompt, options.system_prompt_addendum);
+    if let Some(target) = options.length_target {
+        base_prompt.push_str(&target.prompt_instruction());
+    }
+    if let Some(language) = options.target_language {
+        base_prompt.push_str(&language.prompt_instruction());
+    }
+
+    // Story 3.3 + TD-1: Build system prompt with humanization (single API call, zero latency overhead)
+    // TD-1: Use rehumanization boost on retry attempts for stronger anti-detection
+    match options.rehumanization_attempt {
+        Some(attempt) => humanization::build_rehumanization_prompt(
+            &base_prompt,
+            options.humanization_intensity,
+            attempt,
+        ),
+        None => humanization::build_system_prompt(&base_prompt, options.humanization_intensity),
+    }
+}
+
+/// User message for already-sanitized job content
+pub fn build_generation_user_message(sanitized_job_content: &str) -> String {
+    // Story 4a.9 AC-2: Prompt boundary enforcement with XML delimiters (AR-13)
+    // Sanitized content is already XML-escaped, safe to wrap in <job_post> tags
+    format!(
+        "<job_post>\n{}\n</job_post>\n\nGenerate a proposal for this job:",
+        sanitized_job_content
+    )
+}
+
+/// Build the generation request for already-sanitized job content.
+///
+/// Both `generate_proposal_with_key` and `generate_proposal_streaming_with_key`
+/// go through here so their prompts can't drift; `preview_generation_prompt`
+/// shows the same system prompt and user message.
+fn build_generation_request(
+    sanitized_job_content: &str,
+    options: &GenerationPromptOptions,
+    stream: Option<bool>,
+) -> ClaudeRequest {
+    if let Some(addendum) = options.system_prompt_addendum {
+        tracing::info!(
+            addendum = %crate::logs::redaction::RedactedPromptText(addendum),
+            "Applying system prompt addendum"
+        );
+    }
+
+    ClaudeRequest {
+        model: MODEL.to_string(),
+        max_tokens: options.length_target.map_or(1024, |t| t.max_tokens()),
+        system: build_generation_system_prompt(options),
+        messages: vec![Message {
+            role: "user".to_string(),
+            content: build_generation_user_message(sanitized_job_content),
+        }],
+        stream,
+        temperature: None,
+    }
+}
+
+/// Partial regeneration prompt: rewrite only the marked span so it still fits its surroundings
+const SELECTION_REWRITE_PROMPT: &str = r#"You are editing one passage of an Upwork proposal the freelancer has already written. Rewrite only the text inside <selection>. The text in <before> and <after> is context: do not repeat, summarize or change it.
+- Keep the passage's role in the proposal (an opening stays an opening, a closing stays a closing) and roughly its length unless the instruction says otherwise
+- The rewrite must read naturally between the text before and after it
+- Reply with the rewritten passage only: no quotes, tags, labels or commentary"#;
+
+/// Selection and context for `rewrite_selection_with_key`, already validated by
+/// `partial_regen`. This is the freelancer's own proposal text, so it is sent
+/// as-is rather than sanitized like a job post.
+#[derive(Debug, Clone, Copy)]
+pub struct SelectionRewrite<'a> {
+    pub before: &'a str,
+    pub selection: &'a str,
+    pub after: &'a str,
+    pub instruction: Option<&'a str>,
+}
+
+/// Build the partial regeneration request. The system prompt follows the same
+/// order as `build_generation_request` (voice, persona addendum, humanization
+/// last) so a rewritten span matches the rest of the proposal.
+fn build_selection_rewrite_request(
+    rewrite: &SelectionRewrite,
+    options: &GenerationPromptOptions,
+) -> ClaudeRequest {
+    let mut base_prompt = SELECTION_REWRITE_PROMPT.to_string();
+    if let Some(profile) = options.voice_profile {
+        base_prompt.push_str(&voice::build_voice_instructions(profile));
+    }
+    let base_prompt = with_system_prompt_addendum(&base_prompt, options.system_prompt_addendum);
+    let system_prompt =
+        humanization::build_system_prompt(&base_prompt, options.humanization_intensity);
+
+    let mut user_message = format!(
+        "<before>\n{}\n</before>\n<selection>\n{}\n</selection>\n<after>\n{}\n</after>\n\n",
+        rewrite.before, rewrite.selection, rewrite.after
+    );
+    match rewrite.instruction {
+        Some(instruction) => user_message.push_str(&format!(
+            "<instruction>\n{}\n</instruction>\n\nRewrite the selection following the instruction:",
+            instruction
+        )),
+        None => user_message.push_str("Rewrite the selection:"),
+    }
+
+    ClaudeRequest {
+        model: MODEL.to_string(),
+        max_tokens: 1024,
+        system: system_prompt,
+        messages: vec![Message {
+            role: "user".to_string(),
+            content: user_message,
+        }],
+        stream: None,
+        temperature: None,
+    }
+}
+
+/// Re-humanization prompt: restyle a finished proposal at a new intensity, keeping its content
+const REHUMANIZE_PROMPT: &str = r#"You are revising an Upwork proposal the freelancer has already written and is happy with. Rewrite the text inside <proposal> so it follows the style instructions below.
+- Keep the meaning, facts, structure and paragraph order: the same hook, the same points, the same closing
+- Change wording and sentence rhythm only; do not add or remove claims, numbers, names or questions
+- Keep roughly the same length
+- Reply with the rewritten proposal only: no quotes, tags, labels or commentary"#;
+
+/// Build the re-humanization request for `rehumanize_proposal`. Same system prompt
+/// order as `build_generation_request` (voice, persona addendum, humanization last);
+/// `options.humanization_intensity` is the target intensity.
+fn build_rehumanize_request(text: &str, options: &GenerationPromptOptions) -> ClaudeRequest {
+    let mut base_prompt = REHUMANIZE_PROMPT.to_string();
+    if let Some(profile) = options.voice_profile {
+        base_prompt.push_str(&voice::build_voice_instructions(profile));
+    }
+    let base_prompt = with_system_prompt_addendum(&base_prompt, options.system_prompt_addendum);
+    let system_prompt =
+        humanization::build_system_prompt(&base_prompt, options.humanization_intensity);
+
+    ClaudeRequest {
+        model: MODEL.to_string(),
+        // Roughly twice the text's tokens (~4 chars each), so a long proposal isn't cut off
+        max_tokens: (text.len() / 2).clamp(1024, 4096) as u32,
+        system: system_prompt,
+        messages: vec![Message {
+            role: "user".to_string(),
+            content: format!("<proposal>\n{}\n</proposal>\n\nRewrite the proposal:", text),
+        }],
+        stream: None,
+        temperature: None,
+    }
+}
+
 /// Get API key from environment variable or provided value.
 fn resolve_api_key(provided_key: Option<&str>) -> Result<String, String> {
     // First try provided key (from config)
@@ -124,14 +627,113 @@ fn resolve_api_key(provided_key: Option<&str>) -> Result<String, String> {
     })
 }
 
+/// Minimum time between live API key checks; each one costs a request
+const API_KEY_VERIFY_INTERVAL: Duration = Duration::from_secs(3);
+
+static LAST_API_KEY_VERIFY: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Outcome of `verify_api_key_live`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ApiKeyVerification {
+    /// The API accepted the key
+    Valid,
+    /// The API rejected the key (wrong, revoked, or malformed)
+    Invalid { message: String },
+    /// The key could not be checked (unreachable API, timeout, server error)
+    NetworkError { message: String },
+    /// No request was made (API host blocked, or a check ran moments ago)
+    Skipped { message: String },
+}
+
+/// Map the models endpoint response to a verification result. Only 401/403 mean
+/// the key is bad; a 429 still proves it authenticated.
+fn classify_verification_response(status: u16) -> ApiKeyVerification {
+    match status {
+        200..=299 | 429 => ApiKeyVerification::Valid,
+        401 => ApiKeyVerification::Invalid {
+            message: "The API rejected this key. It may be wrong or revoked.".to_string(),
+        },
+        403 => ApiKeyVerification::Invalid {
+            message: "This API key is not permitted to use the API.".to_string(),
+        },
+        _ => ApiKeyVerification::NetworkError {
+            message: format!("Could not verify the API key (HTTP {}). Try again.", status),
+        },
+    }
+}
+
+/// Reserve a live check, or None when one ran within `API_KEY_VERIFY_INTERVAL`
+fn try_start_verification(last: &Mutex<Option<Instant>>, now: Instant) -> Option<()> {
+    let mut last = last.lock().unwrap_or_else(|e| e.into_inner());
+    if last.is_some_and(|t| now.duration_since(t) < API_KEY_VERIFY_INTERVAL) {
+        return None;
+    }
+    *last = Some(now);
+    Some(())
+}
+
+/// Check that `api_key` authenticates with one cheap request (models list, limit 1).
+/// Format is checked first so malformed keys never reach the network. Calls closer
+/// together than `API_KEY_VERIFY_INTERVAL` are skipped, so this is meant for an
+/// explicit "Verify" action rather than every keystroke. The key is never logged.
+pub async fn verify_api_key_live(api_key: &str) -> ApiKeyVerification {
+    if let Err(message) = crate::config::validate_api_key_format(api_key) {
+        return ApiKeyVerification::Invalid { message };
+    }
+
+    // AR-14: a blocked API host means the app is offline for this purpose
+    let url = network::models_url();
+    if let Err(e) = network::validate_url(&url) {
+        return ApiKeyVerification::Skipped {
+            message: format!("API key not verified: network access is blocked ({})", e),
+        };
+    }
+
+    if try_start_verification(&LAST_API_KEY_VERIFY, Instant::now()).is_none() {
+        return ApiKeyVerification::Skipped {
+            message: "API key was just verified. Wait a few seconds and try again.".to_string(),
+        };
+    }
+
+    let result = crate::http::client()
+        .get(&url)
+        .timeout(Duration::from_secs(10))
+        .header("x-api-key", api_key.trim())
+        .header("anthropic-version", ANTHROPIC_VERSION)
+        .send_tracked()
+        .await;
+
+    let verification = match result {
+        Ok(response) => classify_verification_response(response.status().as_u16()),
+        Err(e) if e.is_locally_rate_limited() => ApiKeyVerification::Skipped {
+            message: e.to_string(),
+        },
+        Err(e) if e.is_timeout() => ApiKeyVerification::NetworkError {
+            message: "Verification timed out. Check your internet connection.".to_string(),
+        },
+        Err(e) if e.is_connect() => ApiKeyVerification::NetworkError {
+            message: "Unable to reach AI service. Check your internet connection.".to_string(),
+        },
+        Err(e) => ApiKeyVerification::NetworkError {
+            message: format!("Network error: {}", e),
+        },
+    };
+    tracing::info!(outcome = ?verification, "Live API key verification");
+    verification
+}
+
 pub async fn generate_proposal(job_content: &str) -> Result<String, String> {
-    generate_proposal_with_key(job_content, None, "medium", None).await
+    generate_proposal_with_key(job_content, None, "medium", None, None).await
 }
 
+/// Non-streaming generation. Same prompt as the streaming path
+/// (`build_generation_request`), including the voice profile (Story 5.8).
 pub async fn generate_proposal_with_key(
     job_content: &str,
     api_key: Option<&str>,
     humanization_intensity: &str,
+    voice_profile: Option<&voice::VoiceProfile>,
     app_handle: Option<&AppHandle>,
 ) -> Result<String, String> {
     let api_key = resolve_api_key(api_key)?;
@@ -148,58 +750,107 @@ pub async fn generate_proposal_with_key(
     }
 
     // Story 3.3: Build system prompt with humanization (single API call, zero latency overhead)
-    let system_prompt = humanization::build_system_prompt(SYSTEM_PROMPT, humanization_intensity);
+    let options = GenerationPromptOptions {
+        humanization_intensity,
+        voice_profile,
+        ..Default::default()
+    };
+    let request_body = build_generation_request(&sanitization_result.content, &options, None);
 
     // AR-16: Log intensity, not prompt content
-    tracing::info!(intensity = %humanization_intensity, "Generating proposal with humanization");
+    tracing::info!(intensity = %humanization_intensity, voice_profile = voice_profile.is_some(), "Generating proposal with humanization");
 
-    let client = crate::http::client();
+    send_message_request(&request_body, &api_key, app_handle, "generate_proposal").await
+}
 
-    // Story 4a.9 AC-2: Prompt boundary enforcement with XML delimiters (AR-13)
-    // Sanitized content is already XML-escaped, safe to wrap in <job_post> tags
-    let user_message = format!(
-        "<job_post>\n{}\n</job_post>\n\nGenerate a proposal for this job:",
-        sanitization_result.content
+/// Rewrite a selected span of a proposal (non-streaming). Returns Claude's
+/// rewrite of the selection only; the caller splices it back in.
+pub async fn rewrite_selection_with_key(
+    rewrite: &SelectionRewrite<'_>,
+    api_key: Option<&str>,
+    options: &GenerationPromptOptions<'_>,
+    app_handle: Option<&AppHandle>,
+) -> Result<String, String> {
+    let api_key = resolve_api_key(api_key)?;
+    let request_body = build_selection_rewrite_request(rewrite, options);
+
+    // AR-16: Log sizes and intensity, not proposal text
+    tracing::info!(
+        intensity = %options.humanization_intensity,
+        voice_profile = options.voice_profile.is_some(),
+        selection_chars = rewrite.selection.chars().count(),
+        has_instruction = rewrite.instruction.is_some(),
+        "Rewriting proposal selection"
     );
 
-    let request_body = ClaudeRequest {
-        model: MODEL.to_string(),
-        max_tokens: 1024,
-        system: system_prompt,
-        messages: vec![Message {
-            role: "user".to_string(),
-            content: user_message,
-        }],
-        stream: None, // Non-streaming request
-    };
+    send_message_request(&request_body, &api_key, app_handle, "regenerate_selection").await
+}
+
+/// Rewrite a whole proposal at `options.humanization_intensity` (non-streaming).
+/// Returns Claude's rewrite; the caller saves it as a revision.
+pub async fn rehumanize_with_key(
+    text: &str,
+    api_key: Option<&str>,
+    options: &GenerationPromptOptions<'_>,
+    app_handle: Option<&AppHandle>,
+) -> Result<String, String> {
+    let api_key = resolve_api_key(api_key)?;
+    let request_body = build_rehumanize_request(text, options);
+
+    // AR-16: Log sizes and intensity, not proposal text
+    tracing::info!(
+        intensity = %options.humanization_intensity,
+        voice_profile = options.voice_profile.is_some(),
+        chars = text.chars().count(),
+        "Re-humanizing proposal"
+    );
+
+    send_message_request(&request_body, &api_key, app_handle, "rehumanize_proposal").await
+}
+
+/// Send a non-streaming Messages API request and return the first text block.
+/// `label` names the request in the API debug log.
+async fn send_message_request(
+    request_body: &ClaudeRequest,
+    api_key: &str,
+    app_handle: Option<&AppHandle>,
+    label: &str,
+) -> Result<String, String> {
+    let client = crate::http::client();
 
     // AR-14: Validate domain before making request (network allowlist enforcement)
     // Task 4.2: Emit event if domain is blocked and AppHandle is available
-    if let Err(e) = network::validate_url(ANTHROPIC_API_URL) {
+    // Configurable base URL (api_base_url); its host must be allowlisted
+    let api_url = network::messages_url();
+    if let Err(e) = network::validate_url(&api_url) {
         if let (Some(handle), network::NetworkError::BlockedDomain(domain)) = (app_handle, &e) {
-            network::emit_blocked_event(handle, domain.clone(), ANTHROPIC_API_URL.to_string());
+            network::emit_blocked_event(handle, domain.clone(), api_url.clone());
         }
         return Err(format!("Network security: {}", e));
     }
 
+    api_debug::log_request(label, api_key, request_body);
+
     let response = client
-        .post(ANTHROPIC_API_URL)
+        .post(&api_url)
         .timeout(Duration::from_secs(30))
-        .header("x-api-key", &api_key)
+        .header("x-api-key", api_key)
         .header("anthropic-version", ANTHROPIC_VERSION)
         .header("content-type", "application/json")
-        .json(&request_body)
-        .send()
+        .json(request_body)
+        .send_tracked()
         .await
         .map_err(|e| {
-            let error_msg = if e.is_timeout() {
+            let error_msg = if e.is_locally_rate_limited() {
+                e.to_string()
+            } else if e.is_timeout() {
                 "Generation timed out. Try again.".to_string()
             } else if e.is_connect() {
                 "Unable to reach AI service. Check your internet connection.".to_string()
             } else {
                 format!("Network error: {}", e)
             };
-            tracing::error!("Proposal generation failed: {}", error_msg);
+            tracing::error!(request = label, "Claude request failed: {}", error_msg);
             error_msg
         })?;
 
@@ -207,6 +858,10 @@ pub async fn generate_proposal_with_key(
 
     if !status.is_success() {
         let error_text = response.text().await.unwrap_or_default();
+        api_debug::log_response(label, api_key, status.as_u16(), &error_text);
+        if let Some(overloaded) = api_quota::overloaded_error(status.as_u16(), &error_text) {
+            return Err(overloaded);
+        }
         if let Ok(error) = serde_json::from_str::<ClaudeError>(&error_text) {
             tracing::error!("API error: {}", error.error.message);
             return Err(format!("API error: {}", error.error.message));
@@ -220,11 +875,13 @@ pub async fn generate_proposal_with_key(
         .await
         .map_err(|e| format!("Failed to parse API response: {}", e))?;
 
-    claude_response
+    let text = claude_response
         .content
         .first()
         .and_then(|block| block.text.clone())
-        .ok_or_else(|| "No text in API response".to_string())
+        .ok_or_else(|| "No text in API response".to_string())?;
+    api_debug::log_response(label, api_key, status.as_u16(), &text);
+    Ok(text)
 }
 
 /// Generate a proposal with streaming, emitting batched tokens via Tauri events.
@@ -240,24 +897,116 @@ pub async fn generate_proposal_streaming(
 }
 
 /// Generate a proposal with streaming using provided API key.
-/// Auto-saves draft every 50ms during generation (Story 1.14).
+/// Auto-saves draft every `draft_autosave_ms` (default 50ms) during generation (Story 1.14).
 /// Uses async queue to prevent race conditions in draft saving (Code Review Fix).
 /// Story 3.3: Humanization instructions injected via system prompt (zero latency overhead).
 /// Story 4a.9: Input sanitization with prompt injection defense (AR-13, AC-1, AC-2, AC-3).
 /// TD-1: Added rehumanization_attempt for boost prompts on regeneration.
+/// `redacted_job_content`, when set, is sent to Claude in place of `job_content`
+/// (pre-generation sanitization); drafts are still saved with the original `job_content`.
+/// `system_prompt_addendum` is the user's persona addendum (humanization takes precedence).
+/// `length_target` adds a length instruction and sizes `max_tokens`; None keeps the defaults.
+/// Story 5.8: `voice_profile` injects the calibrated voice instructions.
+/// `target_language` asks for the proposal in that language; None writes English.
+#[allow(clippy::too_many_arguments)]
 pub async fn generate_proposal_streaming_with_key(
     job_content: &str,
+    redacted_job_content: Option<&str>,
     app_handle: AppHandle,
     api_key: Option<&str>,
     database: &db::Database,
     draft_state: &DraftState,
     humanization_intensity: &str,
     rehumanization_attempt: Option<u32>,
+    system_prompt_addendum: Option<&str>,
+    length_target: Option<LengthTarget>,
+    voice_profile: Option<&voice::VoiceProfile>,
+    target_language: Option<TargetLanguage>,
 ) -> Result<String, String> {
+    generate_proposal_streaming_timed(
+        job_content,
+        redacted_job_content,
+        app_handle,
+        api_key,
+        database,
+        draft_state,
+        humanization_intensity,
+        rehumanization_attempt,
+        system_prompt_addendum,
+        length_target,
+        voice_profile,
+        target_language,
+        StreamChannel::Main,
+    )
+    .await
+    .map(|(text, _)| text)
+}
+
+/// Where a streaming generation spent its time (perf metrics breakdown)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StreamTimings {
+    /// Request sent until the last chunk arrived
+    pub api_stream: Duration,
+    /// Flushing queued draft saves and marking the draft completed
+    pub db_save: Duration,
+    /// Final token count and throughput of the stream
+    pub stats: GenerationStats,
+}
+
+/// One of several proposals generated for the same job ("try 3 variants")
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VariantStream {
+    /// 1-based; the stream's events go to `generation:variant:{index}:*`
+    pub index: u8,
+    pub temperature: f32,
+}
+
+/// Where a streaming generation emits its `generation:*` events
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum StreamChannel {
+    /// The global events the editor streams from
+    #[default]
+    Main,
+    /// The variant's own channel; the request also uses its temperature
+    Variant(VariantStream),
+    /// A queued job drained in the background: `generation:queued:{queue_id}:*`,
+    /// so it never streams into the editor
+    Queued(i64),
+}
+
+/// Name of `event` for this stream: unchanged, or on the stream's own channel
+fn stream_event(channel: StreamChannel, event: &'static str) -> Cow<'static, str> {
+    match channel {
+        StreamChannel::Main => Cow::Borrowed(event),
+        StreamChannel::Variant(v) => Cow::Owned(events::generation_variant_event(v.index, event)),
+        StreamChannel::Queued(queue_id) => {
+            Cow::Owned(events::generation_queued_event(queue_id, event))
+        }
+    }
+}
+
+/// `generate_proposal_streaming_with_key`, also returning phase timings.
+/// `channel` picks where the stream's events go (see `StreamChannel`).
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_proposal_streaming_timed(
+    job_content: &str,
+    redacted_job_content: Option<&str>,
+    app_handle: AppHandle,
+    api_key: Option<&str>,
+    database: &db::Database,
+    draft_state: &DraftState,
+    humanization_intensity: &str,
+    rehumanization_attempt: Option<u32>,
+    system_prompt_addendum: Option<&str>,
+    length_target: Option<LengthTarget>,
+    voice_profile: Option<&voice::VoiceProfile>,
+    target_language: Option<TargetLanguage>,
+    channel: StreamChannel,
+) -> Result<(String, StreamTimings), String> {
     let api_key = resolve_api_key(api_key)?;
 
     // Story 4a.9: Sanitize input before constructing prompt (AC-1, AC-5)
-    let sanitization_result = sanitize_job_content(job_content);
+    let sanitization_result = sanitize_job_content(redacted_job_content.unwrap_or(job_content));
     // H3 fix: Track truncation to propagate to frontend via completion event
     let was_truncated = sanitization_result.was_truncated;
 
@@ -269,19 +1018,38 @@ pub async fn generate_proposal_streaming_with_key(
         );
     }
 
-    // Story 3.3 + TD-1: Build system prompt with humanization (single API call, zero latency overhead)
-    // TD-1: Use rehumanization boost on retry attempts for stronger anti-detection
-    let system_prompt = match rehumanization_attempt {
-        Some(attempt) => humanization::build_rehumanization_prompt(
-            SYSTEM_PROMPT,
-            humanization_intensity,
-            attempt,
-        ),
-        None => humanization::build_system_prompt(SYSTEM_PROMPT, humanization_intensity),
+    // Story 3.3 + TD-1 + 5.8: Same prompt construction as the non-streaming path
+    let options = GenerationPromptOptions {
+        humanization_intensity,
+        rehumanization_attempt,
+        system_prompt_addendum,
+        voice_profile,
+        length_target,
+        target_language,
+    };
+    let mut request_body =
+        build_generation_request(&sanitization_result.content, &options, Some(true));
+    request_body.temperature = match channel {
+        StreamChannel::Variant(v) => Some(v.temperature),
+        _ => None,
     };
 
     // AR-16: Log intensity, not prompt content
-    tracing::info!(intensity = %humanization_intensity, attempt = ?rehumanization_attempt, "Generating streaming proposal with humanization");
+    tracing::info!(intensity = %humanization_intensity, attempt = ?rehumanization_attempt, voice_profile = voice_profile.is_some(), language = target_language.map_or("en", TargetLanguage::code), "Generating streaming proposal with humanization");
+
+    // Inter-chunk idle timeout: a slow stream that keeps producing tokens is never cut off
+    let (idle_timeout, autosave_interval, progress_every) = match database.conn.lock() {
+        Ok(conn) => (
+            generation_idle_timeout(&conn),
+            draft_autosave_interval(&conn),
+            progress_interval(&conn),
+        ),
+        Err(_) => (
+            Duration::from_secs(DEFAULT_GENERATION_TIMEOUT_SECS),
+            Duration::from_millis(DEFAULT_DRAFT_AUTOSAVE_MS),
+            DEFAULT_PROGRESS_INTERVAL_TOKENS,
+        ),
+    };
 
     let client = crate::http::client();
 
@@ -289,34 +1057,14 @@ pub async fn generate_proposal_streaming_with_key(
     // Queue ensures saves execute sequentially even if token batches arrive faster than saves complete
     let (save_tx, mut save_rx) = tokio::sync::mpsc::unbounded_channel::<(String, String)>();
 
-    // Story 4a.9 AC-2: Prompt boundary enforcement with XML delimiters (AR-13)
-    // Sanitized content is already XML-escaped, safe to wrap in <job_post> tags
-    let user_message = format!(
-        "<job_post>\n{}\n</job_post>\n\nGenerate a proposal for this job:",
-        sanitization_result.content
-    );
-
-    let request_body = ClaudeRequest {
-        model: MODEL.to_string(),
-        max_tokens: 1024,
-        system: system_prompt,
-        messages: vec![Message {
-            role: "user".to_string(),
-            content: user_message,
-        }],
-        stream: Some(true),
-    };
-
     // AR-14: Validate domain before making request (network allowlist enforcement)
     // Task 4.2: Emit event if domain is blocked
-    if let Err(e) = network::validate_url(ANTHROPIC_API_URL) {
+    // Configurable base URL (api_base_url); its host must be allowlisted
+    let api_url = network::messages_url();
+    if let Err(e) = network::validate_url(&api_url) {
         match &e {
             network::NetworkError::BlockedDomain(domain) => {
-                network::emit_blocked_event(
-                    &app_handle,
-                    domain.clone(),
-                    ANTHROPIC_API_URL.to_string(),
-                );
+                network::emit_blocked_event(&app_handle, domain.clone(), api_url.clone());
             }
             network::NetworkError::InvalidUrl(_) => {
                 // Invalid URL doesn't need event emission, just error
@@ -325,17 +1073,29 @@ pub async fn generate_proposal_streaming_with_key(
         return Err(format!("Network security: {}", e));
     }
 
-    let response = client
-        .post(ANTHROPIC_API_URL)
-        .timeout(Duration::from_secs(60)) // Longer timeout for streaming
+    // No request-level timeout here: it would cap the whole stream. The idle timeout
+    // covers waiting for response headers and every chunk after that.
+    api_debug::log_request("generate_proposal_streaming", &api_key, &request_body);
+
+    let stream_started = Instant::now();
+    let send = client
+        .post(&api_url)
         .header("x-api-key", &api_key)
         .header("anthropic-version", ANTHROPIC_VERSION)
         .header("content-type", "application/json")
         .json(&request_body)
-        .send()
+        .send_tracked();
+    let response = tokio::time::timeout(idle_timeout, send)
         .await
+        .map_err(|_| {
+            let error_msg = generation_timeout_error(idle_timeout);
+            tracing::error!("Proposal generation failed: {}", error_msg);
+            error_msg
+        })?
         .map_err(|e| {
-            let error_msg = if e.is_timeout() {
+            let error_msg = if e.is_locally_rate_limited() {
+                e.to_string()
+            } else if e.is_timeout() {
                 "Generation timed out. Try again.".to_string()
             } else if e.is_connect() {
                 "Unable to reach AI service. Check your internet connection.".to_string()
@@ -350,6 +1110,15 @@ pub async fn generate_proposal_streaming_with_key(
 
     if !status.is_success() {
         let error_text = response.text().await.unwrap_or_default();
+        api_debug::log_response(
+            "generate_proposal_streaming",
+            &api_key,
+            status.as_u16(),
+            &error_text,
+        );
+        if let Some(overloaded) = api_quota::overloaded_error(status.as_u16(), &error_text) {
+            return Err(overloaded);
+        }
         if let Ok(error) = serde_json::from_str::<ClaudeError>(&error_text) {
             tracing::error!("API error: {}", error.error.message);
             return Err(format!("API error: {}", error.error.message));
@@ -362,20 +1131,56 @@ pub async fn generate_proposal_streaming_with_key(
     let mut full_text = String::new();
     let mut token_buffer: Vec<String> = Vec::new();
     let mut last_emit = Instant::now();
+    let mut last_stats_emit = Instant::now();
+    let mut exact_output_tokens: Option<u64> = None;
+    let mut last_draft_save = Instant::now();
+    let mut counter = WordCounter::default();
+    let mut token_events: u64 = 0;
 
     let mut stream = response.bytes_stream();
 
     // Buffer for incomplete SSE lines
     let mut line_buffer = String::new();
 
-    while let Some(chunk_result) = stream.next().await {
+    loop {
+        let chunk_result = match tokio::time::timeout(idle_timeout, stream.next()).await {
+            Ok(Some(chunk_result)) => chunk_result,
+            Ok(None) => break,
+            Err(_) => {
+                // Stalled stream: flush what we have, keep it as a draft, skip cooldown
+                if !token_buffer.is_empty() {
+                    let _ = app_handle.emit(
+                        &stream_event(channel, events::GENERATION_TOKEN),
+                        TokenPayload {
+                            tokens: token_buffer.clone(),
+                            stage_id: "generation".to_string(),
+                        },
+                    );
+                }
+                save_partial_draft(database, draft_state, job_content, &full_text);
+
+                let error_msg = generation_timeout_error(idle_timeout);
+                tracing::warn!(
+                    idle_secs = idle_timeout.as_secs(),
+                    partial_chars = full_text.len(),
+                    "Generation stream stalled"
+                );
+                let _ = app_handle.emit(
+                    &stream_event(channel, events::GENERATION_ERROR),
+                    ErrorPayload {
+                        message: error_msg.clone(),
+                    },
+                );
+                return Err(error_msg);
+            }
+        };
         let chunk = match chunk_result {
             Ok(bytes) => bytes,
             Err(e) => {
                 // Mid-stream error: emit what we have and notify error
                 if !token_buffer.is_empty() {
                     let _ = app_handle.emit(
-                        events::GENERATION_TOKEN,
+                        &stream_event(channel, events::GENERATION_TOKEN),
                         TokenPayload {
                             tokens: token_buffer.clone(),
                             stage_id: "generation".to_string(),
@@ -383,7 +1188,7 @@ pub async fn generate_proposal_streaming_with_key(
                     );
                 }
                 let _ = app_handle.emit(
-                    events::GENERATION_ERROR,
+                    &stream_event(channel, events::GENERATION_ERROR),
                     ErrorPayload {
                         message: format!("Generation interrupted: {}", e),
                     },
@@ -414,13 +1219,16 @@ pub async fn generate_proposal_streaming_with_key(
                 if let Ok(delta) = serde_json::from_str::<ContentBlockDelta>(json_str) {
                     if delta.delta.delta_type == "text_delta" && !delta.delta.text.is_empty() {
                         full_text.push_str(&delta.delta.text);
+                        counter.push(&delta.delta.text);
+                        token_events += 1;
                         token_buffer.push(delta.delta.text);
 
-                        // Check if 50ms has passed - emit batch AND queue draft save
+                        // Check if 50ms has passed - emit batch, and queue a draft save
+                        // once the autosave interval has passed too
                         if last_emit.elapsed() >= Duration::from_millis(TOKEN_BATCH_INTERVAL_MS) {
                             // Emit tokens to frontend (non-blocking)
                             let _ = app_handle.emit(
-                                events::GENERATION_TOKEN,
+                                &stream_event(channel, events::GENERATION_TOKEN),
                                 TokenPayload {
                                     tokens: token_buffer.clone(),
                                     stage_id: "generation".to_string(),
@@ -429,16 +1237,52 @@ pub async fn generate_proposal_streaming_with_key(
 
                             // Queue draft save (non-blocking, processed sequentially after stream ends)
                             // Clone full_text snapshot to avoid race conditions
-                            let text_snapshot = full_text.clone();
-                            let job_snapshot = job_content.to_string();
-                            if let Err(e) = save_tx.send((text_snapshot, job_snapshot)) {
-                                eprintln!("Warning: Failed to queue draft save: {}", e);
+                            if last_draft_save.elapsed() >= autosave_interval {
+                                let text_snapshot = full_text.clone();
+                                let job_snapshot = job_content.to_string();
+                                if let Err(e) = save_tx.send((text_snapshot, job_snapshot)) {
+                                    eprintln!("Warning: Failed to queue draft save: {}", e);
+                                }
+                                last_draft_save = Instant::now();
                             }
 
                             token_buffer.clear();
                             last_emit = Instant::now();
                         }
+
+                        if last_stats_emit.elapsed() >= Duration::from_millis(STATS_INTERVAL_MS) {
+                            let _ = app_handle.emit(
+                                &stream_event(channel, events::GENERATION_STATS),
+                                GenerationStats::new(&full_text, None, stream_started.elapsed()),
+                            );
+                            last_stats_emit = Instant::now();
+                        }
+
+                        // Progress describes exactly the text the frontend has: flush the
+                        // pending batch first so it never runs ahead of the token events
+                        if token_events.is_multiple_of(progress_every) {
+                            if !token_buffer.is_empty() {
+                                let _ = app_handle.emit(
+                                    &stream_event(channel, events::GENERATION_TOKEN),
+                                    TokenPayload {
+                                        tokens: std::mem::take(&mut token_buffer),
+                                        stage_id: "generation".to_string(),
+                                    },
+                                );
+                                last_emit = Instant::now();
+                            }
+                            let stats =
+                                GenerationStats::new(&full_text, None, stream_started.elapsed());
+                            let _ = app_handle.emit(
+                                &stream_event(channel, events::GENERATION_PROGRESS),
+                                GenerationProgress::new(&counter, token_events, &stats),
+                            );
+                        }
                     }
+                } else if let Ok(message_delta) =
+                    serde_json::from_str::<MessageDeltaUsage>(json_str)
+                {
+                    exact_output_tokens = Some(message_delta.usage.output_tokens);
                 }
                 // Silently ignore other event types (message_start, etc.)
             }
@@ -448,7 +1292,7 @@ pub async fn generate_proposal_streaming_with_key(
     // Emit any remaining tokens
     if !token_buffer.is_empty() {
         let _ = app_handle.emit(
-            events::GENERATION_TOKEN,
+            &stream_event(channel, events::GENERATION_TOKEN),
             TokenPayload {
                 tokens: token_buffer,
                 stage_id: "generation".to_string(),
@@ -456,6 +1300,20 @@ pub async fn generate_proposal_streaming_with_key(
         );
     }
 
+    let api_stream = stream_started.elapsed();
+    let stats = GenerationStats::new(&full_text, exact_output_tokens, api_stream);
+    let _ = app_handle.emit(&stream_event(channel, events::GENERATION_STATS), stats);
+    let _ = app_handle.emit(
+        &stream_event(channel, events::GENERATION_SUMMARY),
+        GenerationSummary {
+            chars: counter.chars(),
+            words: counter.words(),
+            token_events,
+            stats,
+        },
+    );
+    let db_save_started = Instant::now();
+
     // Close the save queue channel
     drop(save_tx);
 
@@ -513,18 +1371,57 @@ pub async fn generate_proposal_streaming_with_key(
     } else {
         eprintln!("Warning: Failed to acquire database lock for draft completion");
     }
+    let timings = StreamTimings {
+        api_stream,
+        db_save: db_save_started.elapsed(),
+        stats,
+    };
+
+    api_debug::log_response("generate_proposal_streaming", &api_key, 200, &full_text);
 
     // Emit completion event
     // H3 fix: Include was_truncated to show warning in frontend
     let _ = app_handle.emit(
-        events::GENERATION_COMPLETE,
+        &stream_event(channel, events::GENERATION_COMPLETE),
         CompletePayload {
             full_text: full_text.clone(),
             was_truncated,
         },
     );
 
-    Ok(full_text)
+    Ok((full_text, timings))
+}
+
+/// Persist partial streamed text as a draft after an aborted generation.
+/// The draft keeps "draft" status so crash recovery (Story 1.14) can offer it.
+fn save_partial_draft(
+    database: &db::Database,
+    draft_state: &DraftState,
+    job_content: &str,
+    text: &str,
+) {
+    if text.is_empty() {
+        return;
+    }
+    let Ok(conn) = database.conn.lock() else {
+        tracing::warn!("Failed to acquire database lock for partial draft save");
+        return;
+    };
+    let Ok(mut draft_id) = draft_state.current_draft_id.lock() else {
+        return;
+    };
+
+    let result = match *draft_id {
+        Some(id) => db::queries::proposals::update_proposal_text(&conn, id, text).map(|_| id),
+        None => db::queries::proposals::insert_proposal(&conn, job_content, text, Some("draft")),
+    };
+    match result {
+        Ok(id) => tracing::info!(draft_id = id, "Partial generation saved as draft"),
+        Err(e) => tracing::warn!("Failed to save partial draft: {}", e),
+    }
+
+    // Next generation starts a fresh draft
+    *draft_id = None;
 }
 
 /// Parse a perplexity score from LLM response text.
@@ -620,28 +1517,35 @@ pub async fn analyze_perplexity(
             content: user_message,
         }],
         stream: None, // No streaming for quick analysis
+        temperature: None,
     };
 
     // AR-14: Validate domain before making request (network allowlist enforcement)
     // Task 4.2: Emit event if domain is blocked and AppHandle is available
-    if let Err(e) = network::validate_url(ANTHROPIC_API_URL) {
+    // Configurable base URL (api_base_url); its host must be allowlisted
+    let api_url = network::messages_url();
+    if let Err(e) = network::validate_url(&api_url) {
         if let (Some(handle), network::NetworkError::BlockedDomain(domain)) = (app_handle, &e) {
-            network::emit_blocked_event(handle, domain.clone(), ANTHROPIC_API_URL.to_string());
+            network::emit_blocked_event(handle, domain.clone(), api_url.clone());
         }
         return Err(format!("Network security: {}", e));
     }
 
+    api_debug::log_request("analyze_perplexity", &api_key, &request_body);
+
     let response = client
-        .post(ANTHROPIC_API_URL)
+        .post(&api_url)
         .timeout(Duration::from_secs(10)) // Fast analysis
         .header("x-api-key", &api_key)
         .header("anthropic-version", ANTHROPIC_VERSION)
         .header("content-type", "application/json")
         .json(&request_body)
-        .send()
+        .send_tracked()
         .await
         .map_err(|e| {
-            let error_msg = if e.is_timeout() {
+            let error_msg = if e.is_locally_rate_limited() {
+                e.to_string()
+            } else if e.is_timeout() {
                 "Perplexity analysis timed out".to_string()
             } else if e.is_connect() {
                 "Unable to reach AI service for analysis".to_string()
@@ -656,6 +1560,10 @@ pub async fn analyze_perplexity(
 
     if !status.is_success() {
         let error_text = response.text().await.unwrap_or_default();
+        api_debug::log_response("analyze_perplexity", &api_key, status.as_u16(), &error_text);
+        if let Some(overloaded) = api_quota::overloaded_error(status.as_u16(), &error_text) {
+            return Err(overloaded);
+        }
         if let Ok(error) = serde_json::from_str::<ClaudeError>(&error_text) {
             tracing::error!("Perplexity analysis API error: {}", error.error.message);
             return Err(format!("API error: {}", error.error.message));
@@ -676,6 +1584,7 @@ pub async fn analyze_perplexity(
         .first()
         .and_then(|block| block.text.as_ref())
         .ok_or_else(|| "No content in API response".to_string())?;
+    api_debug::log_response("analyze_perplexity", &api_key, status.as_u16(), score_text);
 
     let score = parse_perplexity_score(score_text)?;
 
@@ -688,14 +1597,77 @@ pub async fn analyze_perplexity(
 /// Uses Claude Haiku for cost-effective detailed analysis
 /// Story 3.5: Accepts configurable threshold parameter (140-220, default 180)
 /// Task 4.2: Added AppHandle for network event emission
+/// Long text is split into sentence-boundary chunks of at most `max_chunk_tokens`
+/// and analyzed concurrently; see `perplexity_chunks` for how results are merged.
 pub async fn analyze_perplexity_with_sentences(
     text: &str,
     threshold: i32,
     api_key: Option<&str>,
     app_handle: Option<&AppHandle>,
+    max_chunk_tokens: usize,
 ) -> Result<PerplexityAnalysis, String> {
     let api_key = resolve_api_key(api_key)?;
 
+    // AR-14: Validate domain before making request (network allowlist enforcement)
+    // Task 4.2: Emit event if domain is blocked and AppHandle is available
+    // Configurable base URL (api_base_url); its host must be allowlisted
+    let api_url = network::messages_url();
+    if let Err(e) = network::validate_url(&api_url) {
+        if let (Some(handle), network::NetworkError::BlockedDomain(domain)) = (app_handle, &e) {
+            network::emit_blocked_event(handle, domain.clone(), api_url.clone());
+        }
+        return Err(format!("Network security: {}", e));
+    }
+
+    let sentences = perplexity_chunks::split_sentences(text);
+    let chunks = perplexity_chunks::chunk_sentences(text, &sentences, max_chunk_tokens);
+    if chunks.is_empty() {
+        return Err("Text cannot be empty".to_string());
+    }
+
+    // Owned inputs: futures borrowing through the map closure fail the command's Send check.
+    // buffered() keeps results in chunk order
+    let requests: Vec<_> = chunks
+        .iter()
+        .map(|chunk| {
+            analyze_perplexity_chunk(
+                text[chunk.start..chunk.end].to_string(),
+                api_key.clone(),
+                api_url.clone(),
+            )
+        })
+        .collect();
+    let results: Vec<_> = futures::stream::iter(requests)
+        .buffered(perplexity_chunks::PERPLEXITY_CHUNK_CONCURRENCY)
+        .collect()
+        .await;
+
+    let result = perplexity_chunks::merge_chunk_results(
+        text,
+        &sentences,
+        &chunks,
+        results,
+        threshold as f32, // Story 3.5: Use configurable threshold
+    )?;
+
+    tracing::info!(
+        "Perplexity analysis complete: score={}, flagged_count={}, chunks={}, partial={}",
+        result.score,
+        result.flagged_sentences.len(),
+        result.chunk_count,
+        result.partial
+    );
+
+    Ok(result)
+}
+
+/// Analyze one chunk of text; returns its score and flagged sentences
+/// (indexes relative to the chunk).
+async fn analyze_perplexity_chunk(
+    text: String,
+    api_key: String,
+    api_url: String,
+) -> Result<(f32, Vec<FlaggedSentence>), String> {
     let client = crate::http::client();
 
     // Enhanced prompt for sentence-level analysis
@@ -736,28 +1708,24 @@ Return ONLY valid JSON, no other text."#,
             content: user_message,
         }],
         stream: None,
+        temperature: None,
     };
 
-    // AR-14: Validate domain before making request (network allowlist enforcement)
-    // Task 4.2: Emit event if domain is blocked and AppHandle is available
-    if let Err(e) = network::validate_url(ANTHROPIC_API_URL) {
-        if let (Some(handle), network::NetworkError::BlockedDomain(domain)) = (app_handle, &e) {
-            network::emit_blocked_event(handle, domain.clone(), ANTHROPIC_API_URL.to_string());
-        }
-        return Err(format!("Network security: {}", e));
-    }
+    api_debug::log_request("analyze_perplexity_sentences", &api_key, &request_body);
 
     let response = client
-        .post(ANTHROPIC_API_URL)
+        .post(&api_url)
         .timeout(Duration::from_secs(15)) // Slightly longer for sentence analysis
         .header("x-api-key", &api_key)
         .header("anthropic-version", ANTHROPIC_VERSION)
         .header("content-type", "application/json")
         .json(&request_body)
-        .send()
+        .send_tracked()
         .await
         .map_err(|e| {
-            let error_msg = if e.is_timeout() {
+            let error_msg = if e.is_locally_rate_limited() {
+                e.to_string()
+            } else if e.is_timeout() {
                 "Sentence analysis timed out".to_string()
             } else if e.is_connect() {
                 "Unable to reach AI service for analysis".to_string()
@@ -772,6 +1740,15 @@ Return ONLY valid JSON, no other text."#,
 
     if !status.is_success() {
         let error_text = response.text().await.unwrap_or_default();
+        api_debug::log_response(
+            "analyze_perplexity_sentences",
+            &api_key,
+            status.as_u16(),
+            &error_text,
+        );
+        if let Some(overloaded) = api_quota::overloaded_error(status.as_u16(), &error_text) {
+            return Err(overloaded);
+        }
         if let Ok(error) = serde_json::from_str::<ClaudeError>(&error_text) {
             tracing::error!("Sentence analysis API error: {}", error.error.message);
             return Err(format!("API error: {}", error.error.message));
@@ -792,6 +1769,12 @@ Return ONLY valid JSON, no other text."#,
         .first()
         .and_then(|block| block.text.as_ref())
         .ok_or_else(|| "No content in API response".to_string())?;
+    api_debug::log_response(
+        "analyze_perplexity_sentences",
+        &api_key,
+        status.as_u16(),
+        response_text,
+    );
 
     // Parse structured response (with fallback for LLM wrapping JSON in code blocks)
     #[derive(Deserialize)]
@@ -810,17 +1793,474 @@ Return ONLY valid JSON, no other text."#,
         format!("Failed to parse analysis response: {}", e)
     })?;
 
-    let result = PerplexityAnalysis {
-        score: analysis.score,
-        threshold: threshold as f32, // Story 3.5: Use configurable threshold
-        flagged_sentences: analysis.flagged_sentences,
-    };
+    Ok((analysis.score, analysis.flagged_sentences))
+}
 
-    tracing::info!(
-        "Perplexity analysis complete: score={}, flagged_count={}",
-        result.score,
-        result.flagged_sentences.len()
-    );
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generation_stats_estimate_and_exact() {
+        let text = "x".repeat(400);
+        let estimated = GenerationStats::new(&text, None, Duration::from_millis(2000));
+        assert_eq!(estimated.tokens, 100);
+        assert_eq!(estimated.elapsed_ms, 2000);
+        assert_eq!(estimated.tokens_per_second, 50.0);
+        assert!(!estimated.exact);
+
+        let exact = GenerationStats::new(&text, Some(90), Duration::from_millis(3000));
+        assert_eq!(exact.tokens, 90);
+        assert_eq!(exact.tokens_per_second, 30.0);
+        assert!(exact.exact);
+
+        assert_eq!(
+            GenerationStats::new("", None, Duration::ZERO).tokens_per_second,
+            0.0
+        );
 
-    Ok(result)
+        let usage: MessageDeltaUsage = serde_json::from_str(
+            r#"{"type":"message_delta","delta":{"stop_reason":"end_turn"},"usage":{"output_tokens":412}}"#,
+        )
+        .unwrap();
+        assert_eq!(usage.usage.output_tokens, 412);
+        assert!(serde_json::from_str::<MessageDeltaUsage>(
+            r#"{"type":"content_block_delta","delta":{"type":"text_delta","text":"Hi"}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_system_prompt_addendum_length() {
+        use crate::settings::schema::validate;
+        let key = SYSTEM_PROMPT_ADDENDUM_SETTING;
+        assert!(validate(key, "Write as a senior DevOps contractor").is_ok());
+        let too_long = "a".repeat(MAX_SYSTEM_PROMPT_ADDENDUM_CHARS + 1);
+        assert!(validate(key, &too_long).unwrap_err().contains("at most"));
+    }
+
+    #[test]
+    fn test_addendum_precedes_humanization_block() {
+        let base = with_system_prompt_addendum(SYSTEM_PROMPT, Some("  Mention Kubernetes.  "));
+        let prompt = humanization::build_system_prompt(&base, "medium");
+
+        let addendum_pos = prompt.find("Mention Kubernetes.").unwrap();
+        assert!(prompt.starts_with(SYSTEM_PROMPT));
+        assert!(addendum_pos > SYSTEM_PROMPT.len());
+        // Humanization rules are appended last so they take precedence
+        let block =
+            humanization::get_humanization_prompt(&humanization::HumanizationIntensity::Medium)
+                .unwrap();
+        assert!(prompt.find(&block).unwrap() > addendum_pos);
+    }
+
+    #[test]
+    fn test_generation_timeout_setting_and_default() {
+        let db = db::Database::new(":memory:".into(), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        assert_eq!(generation_idle_timeout(&conn), Duration::from_secs(120));
+
+        let set = |value: &str| {
+            db::queries::settings::set_setting(&conn, GENERATION_TIMEOUT_SETTING, value).unwrap()
+        };
+        set("garbage");
+        assert_eq!(generation_idle_timeout(&conn), Duration::from_secs(120));
+        set("45");
+        assert_eq!(generation_idle_timeout(&conn), Duration::from_secs(45));
+        set("5");
+        assert_eq!(generation_idle_timeout(&conn), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_verification_response_classification() {
+        assert_eq!(
+            classify_verification_response(200),
+            ApiKeyVerification::Valid
+        );
+        // Rate limited still means the key authenticated
+        assert_eq!(
+            classify_verification_response(429),
+            ApiKeyVerification::Valid
+        );
+        assert!(matches!(
+            classify_verification_response(401),
+            ApiKeyVerification::Invalid { .. }
+        ));
+        assert!(matches!(
+            classify_verification_response(403),
+            ApiKeyVerification::Invalid { .. }
+        ));
+        assert!(matches!(
+            classify_verification_response(529),
+            ApiKeyVerification::NetworkError { .. }
+        ));
+    }
+
+    #[test]
+    fn test_verification_is_throttled() {
+        let last = Mutex::new(None);
+        let start = Instant::now();
+        assert!(try_start_verification(&last, start).is_some());
+        assert!(try_start_verification(&last, start + Duration::from_secs(1)).is_none());
+        assert!(try_start_verification(&last, start + API_KEY_VERIFY_INTERVAL).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_malformed_key_without_request() {
+        let result = verify_api_key_live("not-a-key").await;
+        assert!(matches!(result, ApiKeyVerification::Invalid { .. }));
+        // No request reserved the throttle slot
+        assert!(LAST_API_KEY_VERIFY.lock().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_word_counter_matches_full_recount() {
+        let inputs = [
+            "",
+            "   ",
+            "Hello world",
+            "  leading and trailing  ",
+            "multiple    spaces\t\tand\n\nnewlines\r\n",
+            "Quick question — what's the timeline?",
+            "Wait… really?! «Oui» – c'est ça. 日本語 テキスト",
+            "dash-separated words and an em—dash",
+            "lone punctuation: - — ... ¿? ¡!",
+            "non\u{a0}breaking\u{2003}em space\u{3000}ideographic",
+            "emoji 🚀 rocket 👍🏽 and 3.5 numbers",
+        ];
+        for text in inputs {
+            let expected = count_words(text);
+            let chars = text.chars().count() as u64;
+
+            // Whole text in one push
+            let mut whole = WordCounter::default();
+            whole.push(text);
+            assert_eq!(whole.words(), expected, "{:?}", text);
+            assert_eq!(whole.chars(), chars);
+
+            // One char at a time, checking the running count against a recount of the prefix
+            let mut incremental = WordCounter::default();
+            let mut prefix = String::new();
+            for c in text.chars() {
+                prefix.push(c);
+                incremental.push(c.encode_utf8(&mut [0; 4]));
+                assert_eq!(incremental.words(), count_words(&prefix), "{:?}", prefix);
+            }
+            assert_eq!(incremental.chars(), chars);
+
+            // Uneven chunks that split words and whitespace runs
+            let chars_vec: Vec<char> = text.chars().collect();
+            let mut chunked = WordCounter::default();
+            for chunk in chars_vec.chunks(3) {
+                chunked.push(&chunk.iter().collect::<String>());
+            }
+            assert_eq!(chunked.words(), expected, "{:?}", text);
+        }
+
+        assert_eq!(count_words("lone punctuation: - — ... ¿? ¡!"), 2);
+        assert_eq!(count_words("Wait… really?! «Oui» – c'est ça."), 5);
+    }
+
+    #[test]
+    fn test_progress_interval_setting() {
+        let db = db::Database::new(":memory:".into(), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        assert_eq!(progress_interval(&conn), 20);
+        for (stored, tokens) in [("5", 5), ("0", 1), ("often", 20)] {
+            db::queries::settings::set_setting(&conn, PROGRESS_INTERVAL_SETTING, stored).unwrap();
+            assert_eq!(progress_interval(&conn), tokens, "{}", stored);
+        }
+
+        let summary = GenerationSummary {
+            chars: 12,
+            words: 2,
+            token_events: 3,
+            stats: GenerationStats::new("Hello world!", Some(3), Duration::from_millis(1000)),
+        };
+        let json = serde_json::to_value(summary).unwrap();
+        assert_eq!(json["words"], 2);
+        assert_eq!(json["tokens"], 3);
+        assert_eq!(json["exact"], true);
+    }
+
+    #[test]
+    fn test_draft_autosave_interval_is_clamped() {
+        let db = db::Database::new(":memory:".into(), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        assert_eq!(draft_autosave_interval(&conn), Duration::from_millis(50));
+
+        for (stored, ms) in [("10", 50), ("999999", 5000), ("750", 750), ("fast", 50)] {
+            db::queries::settings::set_setting(&conn, DRAFT_AUTOSAVE_SETTING, stored).unwrap();
+            assert_eq!(
+                draft_autosave_interval(&conn),
+                Duration::from_millis(ms),
+                "{}",
+                stored
+            );
+        }
+    }
+
+    #[test]
+    fn test_generation_timeout_error_is_classified() {
+        let err = crate::errors::AppError::from(generation_timeout_error(Duration::from_secs(30)));
+        assert_eq!(err.code, crate::errors::ErrorCode::GenerationTimeout);
+        assert!(err.message.contains("30s"));
+    }
+
+    fn sample_voice_profile() -> voice::VoiceProfile {
+        voice::analyze_single_proposal(
+            "Hi there! I have built three React dashboards this year. \
+             I'd love to help you ship yours. Let's talk through the details.",
+        )
+    }
+
+    #[test]
+    fn test_generation_paths_share_prompt_scaffolding() {
+        let profile = sample_voice_profile();
+        let options = GenerationPromptOptions {
+            humanization_intensity: "medium",
+            voice_profile: Some(&profile),
+            ..Default::default()
+        };
+
+        // generate_proposal_with_key vs generate_proposal_streaming_with_key
+        let non_streaming = build_generation_request("Build a dashboard", &options, None);
+        let streaming = build_generation_request("Build a dashboard", &options, Some(true));
+
+        assert_eq!(non_streaming.system, streaming.system);
+        assert_eq!(
+            serde_json::to_value(&non_streaming.messages).unwrap(),
+            serde_json::to_value(&streaming.messages).unwrap()
+        );
+        assert_eq!(non_streaming.max_tokens, streaming.max_tokens);
+        assert_eq!(non_streaming.stream, None);
+        assert_eq!(streaming.stream, Some(true));
+        assert!(non_streaming.messages[0]
+            .content
+            .starts_with("<job_post>\nBuild a dashboard\n</job_post>"));
+    }
+
+    #[test]
+    fn test_voice_profile_is_injected_before_humanization() {
+        let profile = sample_voice_profile();
+        let with_voice = build_generation_request(
+            "job",
+            &GenerationPromptOptions {
+                humanization_intensity: "medium",
+                system_prompt_addendum: Some("Mention Kubernetes."),
+                voice_profile: Some(&profile),
+                ..Default::default()
+            },
+            None,
+        );
+        let prompt = &with_voice.system;
+        let voice_pos = prompt.find("VOICE CALIBRATION").unwrap();
+        let addendum_pos = prompt.find("Mention Kubernetes.").unwrap();
+        let block =
+            humanization::get_humanization_prompt(&humanization::HumanizationIntensity::Medium)
+                .unwrap();
+        assert!(prompt.starts_with(SYSTEM_PROMPT));
+        assert!(voice_pos < addendum_pos);
+        assert!(prompt.find(&block).unwrap() > addendum_pos);
+
+        // Default voice: no calibration block, otherwise the pre-5.8 prompt
+        let default_voice = build_generation_request(
+            "job",
+            &GenerationPromptOptions {
+                humanization_intensity: "medium",
+                ..Default::default()
+            },
+            None,
+        );
+        assert!(!default_voice.system.contains("VOICE CALIBRATION"));
+        assert_eq!(
+            default_voice.system,
+            humanization::build_system_prompt(SYSTEM_PROMPT, "medium")
+        );
+    }
+
+    #[test]
+    fn test_selection_rewrite_uses_voice_and_intensity() {
+        let profile = sample_voice_profile();
+        let rewrite = SelectionRewrite {
+            before: "Hi there,",
+            selection: "I am a highly skilled developer.",
+            after: "Let's talk.",
+            instruction: Some("Mention Rust"),
+        };
+        let request = build_selection_rewrite_request(
+            &rewrite,
+            &GenerationPromptOptions {
+                humanization_intensity: "heavy",
+                voice_profile: Some(&profile),
+                ..Default::default()
+            },
+        );
+        let block =
+            humanization::get_humanization_prompt(&humanization::HumanizationIntensity::Heavy)
+                .unwrap();
+        assert!(request.system.starts_with(SELECTION_REWRITE_PROMPT));
+        assert!(
+            request.system.find(&block).unwrap()
+                > request.system.find("VOICE CALIBRATION").unwrap()
+        );
+        let message = &request.messages[0].content;
+        assert!(message.contains("<selection>\nI am a highly skilled developer.\n</selection>"));
+        assert!(message.contains("<instruction>\nMention Rust\n</instruction>"));
+        assert_eq!(request.stream, None);
+
+        let plain = build_selection_rewrite_request(
+            &SelectionRewrite {
+                instruction: None,
+                ..rewrite
+            },
+            &GenerationPromptOptions {
+                humanization_intensity: "medium",
+                ..Default::default()
+            },
+        );
+        assert!(!plain.messages[0].content.contains("<instruction>"));
+        assert!(!plain.system.contains("VOICE CALIBRATION"));
+    }
+
+    #[test]
+    fn test_rehumanize_request_targets_intensity() {
+        let text = "I am a highly skilled developer. I will deliver your project.";
+        let request = build_rehumanize_request(
+            text,
+            &GenerationPromptOptions {
+                humanization_intensity: "heavy",
+                system_prompt_addendum: Some("I specialise in fintech."),
+                ..Default::default()
+            },
+        );
+        let block =
+            humanization::get_humanization_prompt(&humanization::HumanizationIntensity::Heavy)
+                .unwrap();
+        assert!(request.system.starts_with(REHUMANIZE_PROMPT));
+        assert!(request.system.ends_with(&block));
+        assert!(request.system.contains("I specialise in fintech."));
+        assert!(request.messages[0]
+            .content
+            .contains(&format!("<proposal>\n{}\n</proposal>", text)));
+        assert_eq!(request.max_tokens, 1024);
+
+        // "off" sends the restyle instructions without a humanization block
+        let off = build_rehumanize_request(
+            text,
+            &GenerationPromptOptions {
+                humanization_intensity: "off",
+                ..Default::default()
+            },
+        );
+        assert_eq!(off.system, REHUMANIZE_PROMPT);
+    }
+
+    #[test]
+    fn test_generation_context_hashes_assembled_prompt() {
+        use sha2::{Digest, Sha256};
+
+        let options = GenerationPromptOptions {
+            humanization_intensity: "medium",
+            system_prompt_addendum: Some("Mention Kubernetes."),
+            ..Default::default()
+        };
+        let context = GenerationContext::for_prompt(&options, Some("social_proof"));
+        let request = build_generation_request("job", &options, None);
+
+        assert_eq!(context.prompt_version, GENERATION_PROMPT_VERSION);
+        assert_eq!(
+            context.system_prompt_sha256,
+            hex::encode(Sha256::digest(request.system.as_bytes()))
+        );
+        assert!(context.addendum_applied);
+        assert_eq!(context.hook_strategy_id.as_deref(), Some("social_proof"));
+        // Parameter values only: the addendum text is never recorded
+        let json = serde_json::to_string(&context).unwrap();
+        assert!(!json.contains("Kubernetes"));
+
+        // Any parameter that changes the prompt changes the hash
+        let heavy = GenerationContext::for_prompt(
+            &GenerationPromptOptions {
+                humanization_intensity: "heavy",
+                ..options
+            },
+            Some("social_proof"),
+        );
+        assert_ne!(heavy.system_prompt_sha256, context.system_prompt_sha256);
+
+        let boosted = GenerationContext::for_prompt(
+            &GenerationPromptOptions {
+                rehumanization_attempt: Some(1),
+                ..options
+            },
+            None,
+        );
+        assert_eq!(boosted.prompt_version, REHUMANIZATION_PROMPT_VERSION);
+    }
+
+    #[test]
+    fn test_empty_addendum_leaves_prompt_unchanged() {
+        assert_eq!(
+            with_system_prompt_addendum(SYSTEM_PROMPT, None),
+            SYSTEM_PROMPT
+        );
+        assert_eq!(
+            with_system_prompt_addendum(SYSTEM_PROMPT, Some("   ")),
+            SYSTEM_PROMPT
+        );
+    }
+
+    #[test]
+    fn test_target_language_instruction_precedes_humanization() {
+        let english = build_generation_request(
+            "job",
+            &GenerationPromptOptions {
+                humanization_intensity: "medium",
+                target_language: Some(TargetLanguage::English),
+                ..Default::default()
+            },
+            None,
+        );
+        assert_eq!(
+            english.system,
+            humanization::build_system_prompt(SYSTEM_PROMPT, "medium")
+        );
+
+        let spanish = build_generation_request(
+            "job",
+            &GenerationPromptOptions {
+                humanization_intensity: "medium",
+                target_language: Some(TargetLanguage::Spanish),
+                ..Default::default()
+            },
+            None,
+        );
+        let language_pos = spanish.system.find("entire proposal in Spanish").unwrap();
+        let block =
+            humanization::get_humanization_prompt(&humanization::HumanizationIntensity::Medium)
+                .unwrap();
+        assert!(spanish.system.find(&block).unwrap() > language_pos);
+    }
+
+    #[test]
+    fn test_stream_event_channels() {
+        assert_eq!(
+            stream_event(StreamChannel::Main, events::GENERATION_TOKEN),
+            "generation:token"
+        );
+        let variant = StreamChannel::Variant(VariantStream {
+            index: 2,
+            temperature: 0.9,
+        });
+        assert_eq!(
+            stream_event(variant, events::GENERATION_TOKEN),
+            "generation:variant:2:token"
+        );
+        // Queued jobs never stream on the editor's channels
+        assert_eq!(
+            stream_event(StreamChannel::Queued(7), events::GENERATION_ERROR),
+            "generation:queued:7:error"
+        );
+    }
 }
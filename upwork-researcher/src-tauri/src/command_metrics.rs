@@ -0,0 +1,179 @@
+//! Per-command timing for diagnosing slowness reports
+//!
+//! Hot commands (generation, analysis, proposal listing, search, save) start a
+//! `CommandTimer` with `command_metrics::time("name")`; when it drops, the
+//! elapsed time is recorded per command: invocation count, last duration, and
+//! p50/p95 over the last 100 calls. Nothing is timed or stored unless the
+//! `perf_metrics_enabled` setting is "true", so the disabled path is a single
+//! atomic load. Metrics are in memory only and reset on restart or via
+//! `reset_command_metrics`.
+
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// Settings key: "true" to record per-command timings
+pub const PERF_METRICS_SETTING: &str = "perf_metrics_enabled";
+
+/// Durations kept per command (ring buffer) for percentile calculation
+const DURATION_WINDOW: usize = 100;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static COMMAND_METRICS: OnceLock<CommandMetricsState> = OnceLock::new();
+
+/// Process-wide command metrics (also registered as managed state)
+pub fn command_metrics() -> &'static CommandMetricsState {
+    COMMAND_METRICS.get_or_init(CommandMetricsState::default)
+}
+
+/// Apply a saved `perf_metrics_enabled` value (unset or invalid = off)
+pub fn apply_setting(value: Option<&str>) {
+    ENABLED.store(value == Some("true"), Ordering::Relaxed);
+}
+
+/// Load the setting (startup and after unlock)
+pub fn load_setting(conn: &rusqlite::Connection) {
+    let value = crate::db::queries::settings::get_setting(conn, PERF_METRICS_SETTING)
+        .ok()
+        .flatten();
+    apply_setting(value.as_deref());
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Start timing `command`. None (nothing recorded) when metrics are disabled.
+pub fn time(command: &'static str) -> Option<CommandTimer> {
+    if !is_enabled() {
+        return None;
+    }
+    Some(CommandTimer {
+        command,
+        started: Instant::now(),
+    })
+}
+
+/// Records the elapsed time for its command when dropped
+pub struct CommandTimer {
+    command: &'static str,
+    started: Instant,
+}
+
+impl Drop for CommandTimer {
+    fn drop(&mut self) {
+        command_metrics().record(self.command, self.started.elapsed());
+    }
+}
+
+#[derive(Default)]
+struct CommandStats {
+    invocations: u64,
+    last_ms: u64,
+    durations_ms: VecDeque<u64>,
+}
+
+/// Metrics snapshot for one command
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandMetrics {
+    pub command: String,
+    pub invocations: u64,
+    pub last_ms: u64,
+    /// Percentiles over the last 100 invocations
+    pub p50_ms: Option<u64>,
+    pub p95_ms: Option<u64>,
+}
+
+/// In-memory per-command timings
+#[derive(Clone, Default)]
+pub struct CommandMetricsState {
+    commands: Arc<Mutex<HashMap<&'static str, CommandStats>>>,
+}
+
+impl CommandMetricsState {
+    pub fn record(&self, command: &'static str, elapsed: Duration) {
+        let mut guard = match self.commands.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                tracing::warn!("CommandMetricsState mutex poisoned, recovering");
+                poisoned.into_inner()
+            }
+        };
+        let stats = guard.entry(command).or_default();
+        let ms = elapsed.as_millis() as u64;
+        stats.invocations += 1;
+        stats.last_ms = ms;
+        if stats.durations_ms.len() == DURATION_WINDOW {
+            stats.durations_ms.pop_front();
+        }
+        stats.durations_ms.push_back(ms);
+    }
+
+    /// Snapshot of all timed commands, sorted by command name
+    pub fn snapshot(&self) -> Vec<CommandMetrics> {
+        let guard = match self.commands.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut metrics: Vec<CommandMetrics> = guard
+            .iter()
+            .map(|(command, stats)| {
+                let mut sorted: Vec<u64> = stats.durations_ms.iter().copied().collect();
+                sorted.sort_unstable();
+                CommandMetrics {
+                    command: command.to_string(),
+                    invocations: stats.invocations,
+                    last_ms: stats.last_ms,
+                    p50_ms: crate::http::percentile(&sorted, 50),
+                    p95_ms: crate::http::percentile(&sorted, 95),
+                }
+            })
+            .collect();
+        metrics.sort_by(|a, b| a.command.cmp(&b.command));
+        metrics
+    }
+
+    pub fn reset(&self) {
+        match self.commands.lock() {
+            Ok(mut guard) => guard.clear(),
+            Err(poisoned) => poisoned.into_inner().clear(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rolling_window_percentiles() {
+        let state = CommandMetricsState::default();
+        for ms in 1..=150 {
+            state.record("search_proposals", Duration::from_millis(ms));
+        }
+
+        let metrics = state.snapshot();
+        assert_eq!(metrics.len(), 1);
+        let search = &metrics[0];
+        assert_eq!(search.invocations, 150);
+        assert_eq!(search.last_ms, 150);
+        // Only the last 100 calls (51..=150) count toward percentiles
+        assert_eq!(search.p50_ms, Some(100));
+        assert_eq!(search.p95_ms, Some(145));
+
+        state.reset();
+        assert!(state.snapshot().is_empty());
+    }
+
+    #[test]
+    fn test_disabled_records_nothing() {
+        apply_setting(Some("false"));
+        assert!(time("get_proposals").is_none());
+        apply_setting(None);
+        assert!(time("get_proposals").is_none());
+        assert!(crate::settings::schema::validate(PERF_METRICS_SETTING, "yes").is_err());
+    }
+}
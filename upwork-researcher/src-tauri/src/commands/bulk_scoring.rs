@@ -0,0 +1,298 @@
+//! Bulk score backfill for previously imported jobs
+//!
+//! Scoring normally happens inside `analyze_job_post`, so jobs imported before the
+//! user configured skills or rates stay unscored. `score_all_unscored_jobs` scores
+//! them with the local components only: skills match from stored `job_skills` and
+//! budget alignment from the stored budget columns. Client quality needs the API,
+//! so those rows are stored with `partially_scored = 1` and the default quality.
+
+use crate::currency::FxRates;
+use crate::db::queries::{job_posts, scoring as score_queries};
+use crate::db::AppDatabase;
+use crate::{analysis, events, scoring, RateConfig};
+use rusqlite::Connection;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Emitter, State};
+
+/// Jobs scored per lock acquisition; progress is emitted after each batch
+const PROGRESS_INTERVAL: usize = 25;
+
+/// Managed state for the bulk backfill (single run at a time, cancellable)
+#[derive(Default)]
+pub struct BulkScoringState {
+    running: AtomicBool,
+    cancel_requested: AtomicBool,
+}
+
+/// Result counts for `score_all_unscored_jobs`
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct BulkScoringSummary {
+    pub scored: usize,
+    /// Not scored: no skills baseline (user or job skills missing) or a read error.
+    /// These stay unscored and are retried on the next run.
+    pub skipped_no_data: usize,
+    /// Jobs that already had a score before this run
+    pub already_scored: usize,
+    pub cancelled: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum LocalScoreOutcome {
+    Scored,
+    NoData,
+    AlreadyScored,
+}
+
+/// Score one job without the API. Never overwrites an existing job_scores row.
+fn score_job_locally(
+    conn: &Connection,
+    job_post_id: i64,
+    rates: &RateConfig,
+    fx: &FxRates,
+) -> Result<LocalScoreOutcome, String> {
+    // The overall score requires the skills baseline (Story 4b.5)
+    let Some(skills_match) = score_queries::calculate_skills_match(job_post_id, conn)? else {
+        return Ok(LocalScoreOutcome::NoData);
+    };
+
+    let budget_alignment = job_posts::get_job_post_budget(conn, job_post_id)
+        .map_err(|e| format!("Failed to read job budget: {}", e))?
+        .and_then(|budget| analysis::calculate_budget_alignment(&budget, rates, fx).percentage);
+
+    // Client quality deliberately omitted (needs the API)
+    let result = scoring::calculate_overall_score(Some(skills_match), None, budget_alignment);
+
+    let inserted = score_queries::insert_partial_score(
+        conn,
+        job_post_id,
+        Some(skills_match),
+        budget_alignment,
+        result.overall_score,
+        &result.color_flag,
+    )?;
+
+    Ok(if inserted {
+        LocalScoreOutcome::Scored
+    } else {
+        LocalScoreOutcome::AlreadyScored
+    })
+}
+
+/// Score every job post that has no job_scores row yet (optionally the oldest `limit`).
+/// Safe to re-run: scored jobs are never touched again, skipped jobs are retried.
+#[tauri::command]
+pub async fn score_all_unscored_jobs(
+    app_handle: AppHandle,
+    database: State<'_, AppDatabase>,
+    state: State<'_, BulkScoringState>,
+    limit: Option<usize>,
+) -> Result<BulkScoringSummary, String> {
+    let database = database.get()?;
+    if state.running.swap(true, Ordering::SeqCst) {
+        return Err("Bulk scoring is already running".to_string());
+    }
+    state.cancel_requested.store(false, Ordering::SeqCst);
+
+    let result = run_bulk_scoring(&app_handle, database, &state, limit).await;
+    state.running.store(false, Ordering::SeqCst);
+
+    if let Ok(summary) = &result {
+        tracing::info!(
+            scored = summary.scored,
+            skipped_no_data = summary.skipped_no_data,
+            already_scored = summary.already_scored,
+            cancelled = summary.cancelled,
+            "Bulk score backfill finished"
+        );
+    }
+    result
+}
+
+/// Request cancellation of a running backfill. Returns false if none is running.
+#[tauri::command]
+pub fn cancel_bulk_scoring(state: State<'_, BulkScoringState>) -> bool {
+    let running = state.running.load(Ordering::SeqCst);
+    if running {
+        state.cancel_requested.store(true, Ordering::SeqCst);
+    }
+    running
+}
+
+async fn run_bulk_scoring(
+    app_handle: &AppHandle,
+    database: &crate::db::Database,
+    state: &BulkScoringState,
+    limit: Option<usize>,
+) -> Result<BulkScoringSummary, String> {
+    let (job_ids, rates, fx, already_scored) = {
+        let conn = database
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        (
+            score_queries::list_unscored_job_post_ids(&conn, limit)?,
+            RateConfig::from_settings(&conn),
+            FxRates::from_settings(&conn),
+            score_queries::count_scored_job_posts(&conn)?,
+        )
+    };
+
+    let total = job_ids.len();
+    let mut summary = BulkScoringSummary {
+        already_scored,
+        ..Default::default()
+    };
+
+    for (batch_index, batch) in job_ids.chunks(PROGRESS_INTERVAL).enumerate() {
+        if state.cancel_requested.load(Ordering::SeqCst) {
+            summary.cancelled = true;
+            break;
+        }
+
+        // Lock per batch so other commands are not starved on large backfills
+        {
+            let conn = database
+                .conn
+                .lock()
+                .map_err(|e| format!("Database lock error: {}", e))?;
+            for &job_post_id in batch {
+                match score_job_locally(&conn, job_post_id, &rates, &fx) {
+                    Ok(LocalScoreOutcome::Scored) => summary.scored += 1,
+                    Ok(LocalScoreOutcome::NoData) => summary.skipped_no_data += 1,
+                    Ok(LocalScoreOutcome::AlreadyScored) => summary.already_scored += 1,
+                    Err(e) => {
+                        tracing::warn!(job_post_id, "Bulk scoring failed for job: {}", e);
+                        summary.skipped_no_data += 1;
+                    }
+                }
+            }
+        }
+
+        let _ = app_handle.emit(
+            events::SCORING_BULK_PROGRESS,
+            events::BulkScoringProgress {
+                processed: (batch_index * PROGRESS_INTERVAL + batch.len()).min(total),
+                total,
+                scored: summary.scored,
+            },
+        );
+        tokio::task::yield_now().await;
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use tempfile::tempdir;
+
+    fn setup() -> (tempfile::TempDir, Database) {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        (dir, db)
+    }
+
+    fn insert_job(conn: &Connection, skills: &[&str], budget: Option<(f64, &str)>) -> i64 {
+        let id = job_posts::insert_job_post(conn, None, "Imported job", None).unwrap();
+        job_posts::insert_job_skills(
+            conn,
+            id,
+            &skills.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+        )
+        .unwrap();
+        if let Some((amount, budget_type)) = budget {
+            let budget = analysis::BudgetInfo {
+                min: Some(amount),
+                max: Some(amount),
+                budget_type: budget_type.to_string(),
+                currency: None,
+            };
+            let alignment = analysis::BudgetAlignment {
+                percentage: None,
+                status: "gray".to_string(),
+                normalized_min: None,
+                normalized_max: None,
+            };
+            job_posts::update_job_post_budget(conn, id, &budget, &alignment).unwrap();
+        }
+        id
+    }
+
+    fn hourly_rates() -> RateConfig {
+        RateConfig {
+            hourly_rate: Some(50.0),
+            project_rate_min: None,
+        }
+    }
+
+    #[test]
+    fn test_scores_locally_and_flags_partial() {
+        let (_dir, db) = setup();
+        let conn = db.conn.lock().unwrap();
+        conn.execute("INSERT INTO user_skills (skill) VALUES ('Rust')", [])
+            .unwrap();
+        let id = insert_job(&conn, &["rust", "React"], Some((60.0, "hourly")));
+
+        let outcome = score_job_locally(&conn, id, &hourly_rates(), &FxRates::default()).unwrap();
+        assert_eq!(outcome, LocalScoreOutcome::Scored);
+
+        let score = score_queries::get_job_score(&conn, id).unwrap().unwrap();
+        assert_eq!(score.skills_match_percentage, Some(50.0));
+        assert!(score.budget_alignment_score.is_some());
+        assert!(score.client_quality_score.is_none());
+        assert!(score.overall_score.is_some());
+        assert!(score.partially_scored);
+
+        // Full analysis later supplies client quality and clears the flag
+        score_queries::store_client_quality_score(&conn, id, Some(85)).unwrap();
+        let score = score_queries::get_job_score(&conn, id).unwrap().unwrap();
+        assert!(!score.partially_scored);
+    }
+
+    #[test]
+    fn test_skips_jobs_without_skills_baseline() {
+        let (_dir, db) = setup();
+        let conn = db.conn.lock().unwrap();
+        // No user skills configured yet
+        let id = insert_job(&conn, &["Rust"], None);
+
+        let outcome = score_job_locally(&conn, id, &hourly_rates(), &FxRates::default()).unwrap();
+        assert_eq!(outcome, LocalScoreOutcome::NoData);
+        assert!(score_queries::get_job_score(&conn, id).unwrap().is_none());
+        // Still unscored, so the next run retries it
+        assert_eq!(
+            score_queries::list_unscored_job_post_ids(&conn, None).unwrap(),
+            vec![id]
+        );
+    }
+
+    #[test]
+    fn test_rerun_is_idempotent() {
+        let (_dir, db) = setup();
+        let conn = db.conn.lock().unwrap();
+        conn.execute("INSERT INTO user_skills (skill) VALUES ('Rust')", [])
+            .unwrap();
+        let first = insert_job(&conn, &["Rust"], None);
+        let second = insert_job(&conn, &["Rust"], None);
+
+        assert_eq!(
+            score_queries::list_unscored_job_post_ids(&conn, Some(1)).unwrap(),
+            vec![first]
+        );
+        score_job_locally(&conn, first, &hourly_rates(), &FxRates::default()).unwrap();
+
+        assert_eq!(
+            score_queries::list_unscored_job_post_ids(&conn, None).unwrap(),
+            vec![second]
+        );
+        assert_eq!(score_queries::count_scored_job_posts(&conn).unwrap(), 1);
+        // A row written in the meantime is never overwritten
+        assert_eq!(
+            score_job_locally(&conn, first, &hourly_rates(), &FxRates::default()).unwrap(),
+            LocalScoreOutcome::AlreadyScored
+        );
+    }
+}
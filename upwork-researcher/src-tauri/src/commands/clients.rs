@@ -0,0 +1,41 @@
+//! Client history commands
+//!
+//! Job posts are grouped by normalized client name (see `db::queries::clients`),
+//! so "Acme Inc." and "acme inc" are one client. Both commands read through the
+//! read pool.
+
+use crate::db::queries::clients::{self, ClientHistory, ClientSummary};
+use crate::db::AppDatabase;
+use tauri::State;
+
+/// Known clients with job and proposal stats, most recently seen first.
+/// `search` matches part of the name, ignoring case.
+#[tauri::command]
+pub fn list_known_clients(
+    search: Option<String>,
+    db: State<'_, AppDatabase>,
+) -> Result<Vec<ClientSummary>, String> {
+    let db = db.get()?;
+    db.read(|conn| {
+        clients::list_known_clients(conn, search.as_deref())
+            .map_err(|e| format!("Failed to list clients: {}", e))
+    })
+}
+
+/// A client's stats, job posts and sent proposals. Accepts the normalized name
+/// or any display variant of it.
+#[tauri::command]
+pub fn get_client_history(
+    normalized_name: String,
+    db: State<'_, AppDatabase>,
+) -> Result<ClientHistory, String> {
+    let key = clients::normalize_client_name(&normalized_name)
+        .ok_or_else(|| "Client name is required".to_string())?;
+
+    let db = db.get()?;
+    db.read(|conn| {
+        clients::get_client_history(conn, &key)
+            .map_err(|e| format!("Failed to get client history: {}", e))?
+            .ok_or_else(|| format!("Client not found: {}", normalized_name))
+    })
+}
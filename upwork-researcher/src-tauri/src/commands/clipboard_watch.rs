@@ -0,0 +1,280 @@
+//! Opt-in clipboard watch mode for copied Upwork job posts
+//!
+//! When `clipboard_watch_enabled` is "true", a background task polls the
+//! clipboard every ~2 seconds and emits `clipboard:job-detected` when the copied
+//! text looks like an Upwork job post. Nothing is saved here: the frontend asks
+//! the user, then imports through the existing `save_job_post`/`analyze_job_post`
+//! path. The setting is re-read on every poll, so `set_setting` toggles it at
+//! runtime. Clipboard content is never logged.
+
+use crate::db::AppDatabase;
+use crate::events;
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+/// Settings key ("true"/"false", default off)
+pub const CLIPBOARD_WATCH_SETTING: &str = "clipboard_watch_enabled";
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Clipboard changes within this window after the app copied something are the app's own
+const SELF_WRITE_GRACE: Duration = Duration::from_secs(5);
+
+const MIN_JOB_POST_CHARS: usize = 200;
+const MAX_JOB_POST_CHARS: usize = 10_000;
+const PREVIEW_CHARS: usize = 280;
+/// Independent signals required before text is treated as a job post
+const MIN_SIGNALS: usize = 2;
+
+/// Managed state for the clipboard watcher
+#[derive(Default)]
+pub struct ClipboardWatchState {
+    /// Hash of the last clipboard content seen (dedupes repeated identical content)
+    last_hash: Mutex<Option<String>>,
+    /// Set by `mark_clipboard_write` before the app writes to the clipboard
+    self_write_until: Mutex<Option<Instant>>,
+    started: AtomicBool,
+}
+
+impl ClipboardWatchState {
+    /// Start the grace window in which clipboard content is treated as the app's own
+    pub fn mark_self_write(&self) -> Result<(), String> {
+        let mut until = self
+            .self_write_until
+            .lock()
+            .map_err(|e| format!("Clipboard watch lock error: {}", e))?;
+        *until = Some(Instant::now() + SELF_WRITE_GRACE);
+        Ok(())
+    }
+}
+
+/// Tell the watcher the app is about to write to the clipboard (e.g. copying a
+/// proposal) so that content is never offered for import.
+#[tauri::command]
+pub fn mark_clipboard_write(state: State<'_, ClipboardWatchState>) -> Result<(), String> {
+    state.mark_self_write()
+}
+
+/// Heuristic: does this text look like a copied Upwork job post?
+///
+/// Requires 200–10000 characters and at least two independent signals: a rate
+/// type ("hourly"/"fixed-price"), a budget amount, "Payment verified", or
+/// Upwork job page labels.
+pub fn looks_like_job_post(text: &str) -> bool {
+    static RATE_TYPE: OnceLock<Regex> = OnceLock::new();
+    static BUDGET: OnceLock<Regex> = OnceLock::new();
+    static PAYMENT_VERIFIED: OnceLock<Regex> = OnceLock::new();
+    static PAGE_LABELS: OnceLock<Regex> = OnceLock::new();
+
+    let len = text.trim().chars().count();
+    if !(MIN_JOB_POST_CHARS..=MAX_JOB_POST_CHARS).contains(&len) {
+        return false;
+    }
+
+    let rate_type = RATE_TYPE
+        .get_or_init(|| Regex::new(r"(?i)\bhourly\b|\bfixed[- ]price\b").expect("valid regex"));
+    let budget = BUDGET.get_or_init(|| {
+        Regex::new(r"(?i)\$\s?\d[\d,]*(?:\.\d{1,2})?(?:\s?(?:-|–|to)\s?\$?\s?\d[\d,]*)?|\bbudget\b")
+            .expect("valid regex")
+    });
+    let payment_verified = PAYMENT_VERIFIED
+        .get_or_init(|| Regex::new(r"(?i)\bpayment (?:method )?verified\b").expect("valid regex"));
+    let page_labels = PAGE_LABELS.get_or_init(|| {
+        Regex::new(
+            r"(?i)\b(?:experience level|project type|proposals:|activity on this job|client's recent history|skills and expertise)",
+        )
+        .expect("valid regex")
+    });
+
+    let signals = [rate_type, budget, payment_verified, page_labels]
+        .iter()
+        .filter(|re| re.is_match(text))
+        .count();
+    signals >= MIN_SIGNALS
+}
+
+/// Shortest clipboard text `generate_from_clipboard` accepts as a job post
+const MIN_GENERATE_CHARS: usize = 100;
+/// Share of unreadable characters above which clipboard text is treated as binary
+const MAX_UNREADABLE_RATIO: f64 = 0.01;
+
+/// Check that clipboard text can be sent as job content.
+///
+/// Looser than `looks_like_job_post`: the user asked to generate, so only
+/// empty, binary-looking, or too-short content is refused.
+pub fn validate_clipboard_job_text(text: &str) -> Result<(), String> {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return Err("Clipboard is empty. Copy a job post first.".to_string());
+    }
+
+    let total = trimmed.chars().count();
+    let unreadable = trimmed
+        .chars()
+        .filter(|&c| c == '\u{FFFD}' || (c.is_control() && !matches!(c, '\n' | '\r' | '\t')))
+        .count();
+    if unreadable as f64 / total as f64 > MAX_UNREADABLE_RATIO {
+        return Err(
+            "Clipboard does not contain readable text. Copy the job post text.".to_string(),
+        );
+    }
+
+    if total < MIN_GENERATE_CHARS {
+        return Err(format!(
+            "Clipboard text is too short to be a job post ({} characters, at least {} needed)",
+            total, MIN_GENERATE_CHARS
+        ));
+    }
+    Ok(())
+}
+
+fn content_hash(text: &str) -> String {
+    hex::encode(Sha256::digest(text.as_bytes()))
+}
+
+/// Decide whether clipboard content should be offered for import.
+/// Every content change updates the dedupe hash, including skipped ones.
+fn check_clipboard(
+    state: &ClipboardWatchState,
+    text: &str,
+    now: Instant,
+) -> Option<events::ClipboardJobDetectedPayload> {
+    let hash = content_hash(text);
+    {
+        let mut last = state.last_hash.lock().ok()?;
+        if last.as_deref() == Some(hash.as_str()) {
+            return None;
+        }
+        *last = Some(hash.clone());
+    }
+
+    let self_written = state
+        .self_write_until
+        .lock()
+        .ok()
+        .and_then(|until| *until)
+        .is_some_and(|until| now < until);
+    if self_written || !looks_like_job_post(text) {
+        return None;
+    }
+
+    Some(events::ClipboardJobDetectedPayload {
+        preview: text.trim().chars().take(PREVIEW_CHARS).collect(),
+        hash,
+    })
+}
+
+fn watch_enabled(app_handle: &AppHandle) -> bool {
+    let db_state = app_handle.state::<AppDatabase>();
+    let Ok(database) = db_state.get() else {
+        return false;
+    };
+    let enabled = match database.conn.lock() {
+        Ok(conn) => crate::db::queries::settings::get_setting(&conn, CLIPBOARD_WATCH_SETTING)
+            .ok()
+            .flatten()
+            .is_some_and(|v| v == "true"),
+        Err(_) => false,
+    };
+    enabled
+}
+
+/// Spawn the clipboard watcher. Only the first call starts a task.
+pub fn start_clipboard_watcher(app_handle: AppHandle) {
+    let state = app_handle.state::<ClipboardWatchState>();
+    if state.started.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        let state = app_handle.state::<ClipboardWatchState>();
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+            // Off by default; the clipboard is not read at all unless enabled
+            if !watch_enabled(&app_handle) {
+                continue;
+            }
+            // Non-text or empty clipboard
+            let Ok(text) = app_handle.clipboard().read_text() else {
+                continue;
+            };
+
+            if let Some(payload) = check_clipboard(&state, &text, Instant::now()) {
+                tracing::info!(hash = %&payload.hash[..8], "Clipboard job post detected");
+                let _ = app_handle.emit(events::CLIPBOARD_JOB_DETECTED, payload);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JOB_POST: &str = "Senior React Developer needed for SaaS dashboard\n\n\
+        Hourly: $40-$60\nExperience Level: Expert\nProject Type: Ongoing project\n\n\
+        We are looking for an experienced React developer to rebuild our analytics \
+        dashboard. You will work closely with our design team and ship weekly.\n\n\
+        Payment verified. Proposals: 10 to 15";
+
+    #[test]
+    fn test_classifier_accepts_job_post() {
+        assert!(looks_like_job_post(JOB_POST));
+    }
+
+    #[test]
+    fn test_classifier_rejects_short_long_and_plain_text() {
+        assert!(!looks_like_job_post("Hourly $50, payment verified"));
+        assert!(!looks_like_job_post(&JOB_POST.repeat(40)));
+        let prose = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(10);
+        assert!(!looks_like_job_post(&prose));
+        // One signal is not enough
+        let one_signal = format!("{} This is an hourly role.", prose);
+        assert!(!looks_like_job_post(&one_signal));
+    }
+
+    #[test]
+    fn test_validate_clipboard_job_text() {
+        assert!(validate_clipboard_job_text(JOB_POST).is_ok());
+        assert!(validate_clipboard_job_text("  \n ")
+            .unwrap_err()
+            .contains("empty"));
+        assert!(validate_clipboard_job_text("Need a logo")
+            .unwrap_err()
+            .contains("too short"));
+        let binary = format!("{}{}", JOB_POST, "\u{0}\u{1}\u{FFFD}".repeat(10));
+        assert!(validate_clipboard_job_text(&binary)
+            .unwrap_err()
+            .contains("readable"));
+    }
+
+    #[test]
+    fn test_dedupes_identical_content() {
+        let state = ClipboardWatchState::default();
+        let now = Instant::now();
+
+        let payload = check_clipboard(&state, JOB_POST, now).unwrap();
+        assert_eq!(payload.hash, content_hash(JOB_POST));
+        assert!(payload.preview.chars().count() <= PREVIEW_CHARS);
+
+        assert!(check_clipboard(&state, JOB_POST, now).is_none());
+        // New content, then the old content again, is offered again
+        assert!(check_clipboard(&state, "something else", now).is_none());
+        assert!(check_clipboard(&state, JOB_POST, now).is_some());
+    }
+
+    #[test]
+    fn test_ignores_app_writes_during_grace_window() {
+        let state = ClipboardWatchState::default();
+        let now = Instant::now();
+        *state.self_write_until.lock().unwrap() = Some(now + SELF_WRITE_GRACE);
+
+        assert!(check_clipboard(&state, JOB_POST, now).is_none());
+        // Still deduped after the window ends, so it is never offered
+        assert!(check_clipboard(&state, JOB_POST, now + SELF_WRITE_GRACE * 2).is_none());
+    }
+}
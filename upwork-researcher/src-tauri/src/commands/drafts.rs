@@ -0,0 +1,185 @@
+//! Draft recovery commands (Story 1.14)
+//!
+//! Streaming generation autosaves its text as a `draft` proposal, so a crash
+//! can leave several drafts behind. These commands list them for recovery and
+//! discard them; startup maintenance removes drafts older than
+//! `draft_retention_days`. Only proposals still in `draft` status are ever
+//! deleted here.
+
+use crate::db::queries::proposals::{self, DraftSummary};
+use crate::db::AppDatabase;
+use crate::DraftState;
+use rusqlite::Connection;
+use tauri::State;
+use tracing::info;
+
+/// Settings key: days a draft is kept for recovery before it is discarded
+pub const DRAFT_RETENTION_DAYS_SETTING: &str = "draft_retention_days";
+pub const DEFAULT_DRAFT_RETENTION_DAYS: u32 = 14;
+pub const MAX_DRAFT_RETENTION_DAYS: u32 = 365;
+
+/// Startup maintenance (deferred init): discard drafts older than `draft_retention_days`
+pub fn discard_expired_drafts_from_settings(conn: &Connection) -> Result<usize, String> {
+    let retention_days =
+        crate::settings::schema::get_typed(conn, crate::settings::schema::Key::DraftRetentionDays)?;
+
+    proposals::discard_drafts_older_than(conn, retention_days)
+        .map_err(|e| format!("Failed to discard expired drafts: {}", e))
+}
+
+/// Draft of the generation currently streaming, which must not be discarded
+fn in_progress_draft(draft_state: &DraftState) -> Result<Option<i64>, String> {
+    draft_state
+        .current_draft_id
+        .lock()
+        .map(|id| *id)
+        .map_err(|e| format!("Draft state lock error: {}", e))
+}
+
+/// Every draft left by earlier sessions, most recently saved first, with word counts
+#[tauri::command]
+pub fn get_all_drafts(db: State<'_, AppDatabase>) -> Result<Vec<DraftSummary>, String> {
+    let db = db.get()?;
+    let conn = db
+        .conn
+        .lock()
+        .map_err(|e| format!("Failed to lock database: {}", e))?;
+
+    proposals::get_all_drafts(&conn).map_err(|e| format!("Failed to load drafts: {}", e))
+}
+
+/// Discard one draft. Returns false if the proposal is not a draft (completed
+/// proposals are never deleted).
+#[tauri::command]
+pub fn discard_draft(
+    proposal_id: i64,
+    db: State<'_, AppDatabase>,
+    draft_state: State<'_, DraftState>,
+) -> Result<bool, String> {
+    if in_progress_draft(&draft_state)? == Some(proposal_id) {
+        return Err("Cannot discard the draft of a generation in progress".to_string());
+    }
+
+    let db = db.get()?;
+    let conn = db
+        .conn
+        .lock()
+        .map_err(|e| format!("Failed to lock database: {}", e))?;
+
+    let discarded = proposals::discard_draft(&conn, proposal_id)
+        .map_err(|e| format!("Failed to discard draft: {}", e))?;
+    if discarded {
+        info!(proposal_id, "Discarded draft");
+    }
+    Ok(discarded)
+}
+
+/// Discard all drafts except one still being generated. Returns how many were removed.
+#[tauri::command]
+pub fn discard_all_drafts(
+    db: State<'_, AppDatabase>,
+    draft_state: State<'_, DraftState>,
+) -> Result<usize, String> {
+    let keep = in_progress_draft(&draft_state)?;
+
+    let db = db.get()?;
+    let conn = db
+        .conn
+        .lock()
+        .map_err(|e| format!("Failed to lock database: {}", e))?;
+
+    let count = proposals::discard_all_drafts(&conn, keep)
+        .map_err(|e| format!("Failed to discard drafts: {}", e))?;
+    info!(count, "Discarded all drafts");
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+
+    fn insert_draft(conn: &Connection, text: &str, saved_ago: &str) -> i64 {
+        let id = proposals::insert_proposal(conn, "job", text, Some("draft")).unwrap();
+        conn.execute(
+            "UPDATE proposals SET updated_at = datetime('now', ?1) WHERE id = ?2",
+            rusqlite::params![saved_ago, id],
+        )
+        .unwrap();
+        id
+    }
+
+    #[test]
+    fn test_retention_boundary() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+
+        let kept = insert_draft(&conn, "just inside", "-20159 minutes"); // 14 days - 1 minute
+        let expired = insert_draft(&conn, "just outside", "-20161 minutes"); // 14 days + 1 minute
+        let completed =
+            proposals::insert_proposal(&conn, "job", "old but sent", Some("completed")).unwrap();
+        conn.execute(
+            "UPDATE proposals SET created_at = datetime('now', '-30 days') WHERE id = ?1",
+            [completed],
+        )
+        .unwrap();
+
+        assert_eq!(discard_expired_drafts_from_settings(&conn).unwrap(), 1);
+        let ids: Vec<i64> = proposals::get_all_drafts(&conn)
+            .unwrap()
+            .iter()
+            .map(|d| d.id)
+            .collect();
+        assert_eq!(ids, vec![kept]);
+        assert!(proposals::get_proposal(&conn, expired).unwrap().is_none());
+        assert!(proposals::get_proposal(&conn, completed).unwrap().is_some());
+
+        // Shorter retention from settings also expires the 13-day-old draft
+        crate::db::queries::settings::set_setting(&conn, DRAFT_RETENTION_DAYS_SETTING, "7")
+            .unwrap();
+        insert_draft(&conn, "a week old", "-8 days");
+        let recent = insert_draft(&conn, "yesterday", "-1 days");
+        assert_eq!(discard_expired_drafts_from_settings(&conn).unwrap(), 2);
+        assert_eq!(proposals::get_all_drafts(&conn).unwrap()[0].id, recent);
+        assert!(crate::settings::schema::validate(DRAFT_RETENTION_DAYS_SETTING, "0").is_err());
+    }
+
+    #[test]
+    fn test_discard_leaves_completed_proposals() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+
+        let older = insert_draft(&conn, "first crash draft", "-2 hours");
+        let newer = insert_draft(&conn, "second crash draft here", "-1 hours");
+        let in_progress = insert_draft(&conn, "still streaming", "-1 seconds");
+        let completed =
+            proposals::insert_proposal(&conn, "job", "sent proposal", Some("completed")).unwrap();
+
+        let drafts = proposals::get_all_drafts(&conn).unwrap();
+        assert_eq!(
+            drafts.iter().map(|d| d.id).collect::<Vec<_>>(),
+            vec![in_progress, newer, older]
+        );
+        assert_eq!(drafts[1].word_count, 4);
+
+        // Not a draft: nothing deleted
+        assert!(!proposals::discard_draft(&conn, completed).unwrap());
+        assert!(proposals::discard_draft(&conn, older).unwrap());
+
+        assert_eq!(
+            proposals::discard_all_drafts(&conn, Some(in_progress)).unwrap(),
+            1
+        );
+        assert_eq!(
+            proposals::get_all_drafts(&conn)
+                .unwrap()
+                .iter()
+                .map(|d| d.id)
+                .collect::<Vec<_>>(),
+            vec![in_progress]
+        );
+        assert!(proposals::get_proposal(&conn, completed).unwrap().is_some());
+    }
+}
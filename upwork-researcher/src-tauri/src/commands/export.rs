@@ -1,6 +1,6 @@
 // Export commands for database backup and portability (Story 7.6)
 
-use crate::archive_export::{write_archive, ArchiveMetadata};
+use crate::archive_export::{parse_metadata, write_archive, ArchiveMetadata};
 use crate::db::AppDatabase;
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use base64::Engine;
@@ -51,32 +51,107 @@ struct TableCounts {
     job_posts: usize,
     settings: usize,
     voice_profiles: usize,
+    golden_proposals: usize,
 }
 
+/// Tables holding voice data: the calibrated voice profile and the golden set
+/// it was calibrated from
+const VOICE_DATA_TABLES: [&str; 2] = ["voice_profiles", "golden_set_proposals"];
+
+/// In-session edit history, left out unless requested
+const EDIT_EVENTS_TABLE: &str = "edit_events";
+
 /// Query all table counts in a single transaction
 fn get_table_counts(conn: &Connection) -> Result<TableCounts, String> {
+    get_table_counts_in(conn, "main")
+}
+
+/// Table counts for an attached schema (e.g. the export snapshot)
+fn get_table_counts_in(conn: &Connection, schema: &str) -> Result<TableCounts, String> {
     // Use subselects for efficiency (single query)
-    let query = "
+    let query = format!(
+        "
         SELECT
-            (SELECT COUNT(*) FROM proposals) as proposals,
-            (SELECT COUNT(*) FROM proposal_revisions) as revisions,
-            (SELECT COUNT(*) FROM job_posts) as job_posts,
-            (SELECT COUNT(*) FROM settings) as settings,
-            (SELECT COUNT(*) FROM voice_profiles) as voice_profiles
-    ";
-
-    conn.query_row(query, [], |row| {
+            (SELECT COUNT(*) FROM {schema}.proposals) as proposals,
+            (SELECT COUNT(*) FROM {schema}.proposal_revisions) as revisions,
+            (SELECT COUNT(*) FROM {schema}.job_posts) as job_posts,
+            (SELECT COUNT(*) FROM {schema}.settings) as settings,
+            (SELECT COUNT(*) FROM {schema}.voice_profiles) as voice_profiles,
+            (SELECT COUNT(*) FROM {schema}.golden_set_proposals) as golden_proposals
+    "
+    );
+
+    conn.query_row(&query, [], |row| {
         Ok(TableCounts {
             proposals: row.get::<_, i64>(0)? as usize,
             revisions: row.get::<_, i64>(1)? as usize,
             job_posts: row.get::<_, i64>(2)? as usize,
             settings: row.get::<_, i64>(3)? as usize,
             voice_profiles: row.get::<_, i64>(4)? as usize,
+            golden_proposals: row.get::<_, i64>(5)? as usize,
         })
     })
     .map_err(|e| format!("Failed to query table counts: {}", e))
 }
 
+/// Scratch copy of the database the archive is built from (next to the database)
+const EXPORT_SNAPSHOT_FILE: &str = ".export-snapshot.tmp";
+
+fn remove_export_snapshot(path: &Path) {
+    let _ = fs::remove_file(path);
+    let _ = fs::remove_file(path.with_extension("tmp-journal"));
+}
+
+/// Database bytes for the archive, with `excluded_tables` emptied. The live
+/// database is copied with sqlcipher_export into a scratch file next to it
+/// (attached without a KEY, so it is encrypted with the same key) and the
+/// tables are cleared in the copy only, so a failure here never touches user
+/// data. Cleared with secure_delete and vacuumed so no excluded row survives
+/// in the copy's free pages. Counts are taken from the copy.
+fn export_snapshot(
+    conn: &Connection,
+    db_path: &Path,
+    excluded_tables: &[&str],
+) -> Result<(TableCounts, Vec<u8>), String> {
+    let snapshot_path = db_path.with_file_name(EXPORT_SNAPSHOT_FILE);
+    remove_export_snapshot(&snapshot_path);
+
+    conn.execute_batch(&format!(
+        "ATTACH DATABASE '{}' AS export_snapshot;",
+        snapshot_path.to_string_lossy().replace('\'', "''")
+    ))
+    .map_err(|e| format!("Failed to create export snapshot: {}", e))?;
+
+    let built = (|| {
+        conn.execute_batch("SELECT sqlcipher_export('export_snapshot');")
+            .map_err(|e| format!("Failed to copy database for export: {}", e))?;
+        if !excluded_tables.is_empty() {
+            let deletes: String = excluded_tables
+                .iter()
+                .map(|table| format!("DELETE FROM export_snapshot.{};", table))
+                .collect();
+            conn.execute_batch(&format!(
+                "PRAGMA export_snapshot.secure_delete = ON; BEGIN; {} COMMIT;",
+                deletes
+            ))
+            .map_err(|e| format!("Failed to exclude data from export: {}", e))?;
+            conn.execute_batch("VACUUM export_snapshot;")
+                .map_err(|e| format!("Failed to compact export snapshot: {}", e))?;
+        }
+        get_table_counts_in(conn, "export_snapshot")
+    })();
+
+    let detached = conn.execute_batch("DETACH DATABASE export_snapshot;");
+    let result = built.and_then(|counts| {
+        detached.map_err(|e| format!("Failed to close export snapshot: {}", e))?;
+        let bytes = fs::read(&snapshot_path)
+            .map_err(|e| format!("Failed to read export snapshot: {}", e))?;
+        Ok((counts, bytes))
+    });
+    remove_export_snapshot(&snapshot_path);
+    result
+}
+
 /// Verify archive integrity using streaming reads (no full DB load into memory)
 ///
 /// NOTE: AC-3 specifies "opening the DB with the current encryption key" but the
@@ -125,8 +200,7 @@ fn verify_archive(archive_path: &Path) -> Result<(), String> {
     reader
         .read_exact(&mut metadata_bytes)
         .map_err(|e| format!("Failed to read metadata: {}", e))?;
-    let _metadata: ArchiveMetadata = serde_json::from_slice(&metadata_bytes)
-        .map_err(|e| format!("Failed to parse metadata JSON: {}", e))?;
+    let _metadata: ArchiveMetadata = parse_metadata(&metadata_bytes)?;
 
     // Read salt length (4 bytes, u32 LE)
     reader
@@ -165,6 +239,10 @@ fn verify_archive(archive_path: &Path) -> Result<(), String> {
 ///
 /// # Arguments
 /// * `passphrase_hint` - Optional user-provided hint for the passphrase
+/// * `include_edit_events` - Keep in-session edit history (edit_events) in the archive;
+///   excluded by default
+/// * `include_voice_data` - Keep the voice profile and golden proposals in the archive so
+///   a new machine needs no recalibration; excluded by default
 ///
 /// # Returns
 /// ExportArchiveResult with file path, counts, and success status
@@ -179,8 +257,14 @@ pub async fn export_encrypted_archive(
     app_handle: AppHandle,
     database: State<'_, AppDatabase>,
     rate_limit: State<'_, ExportRateLimitState>,
+    busy: State<'_, crate::db::maintenance::DbBusyState>,
     passphrase_hint: Option<String>,
+    include_edit_events: Option<bool>,
+    include_voice_data: Option<bool>,
 ) -> Result<ExportArchiveResult, String> {
+    let include_voice_data = include_voice_data.unwrap_or(false);
+    let _busy = busy.try_begin(crate::db::maintenance::DbOperation::Export)?;
+
     // AC-6: Rate limit check (60s cooldown)
     {
         let last_export = rate_limit
@@ -250,36 +334,31 @@ pub async fn export_encrypted_archive(
     // AC-2: Emit progress event — Preparing
     let _ = app_handle.emit("export-progress", "Preparing...");
 
-    // AC-2, AC-3: Perform export with WAL checkpoint, counts, and file copy
-    // CRITICAL: Hold DB lock during file read to prevent concurrent writes from
+    // AC-2, AC-3: Perform export from a snapshot copy, with counts
+    // CRITICAL: Hold DB lock while copying to prevent concurrent writes from
     // corrupting the export snapshot (per story Dev Notes).
-    let (counts, db_bytes, salt_bytes) = {
+    let (counts, db_bytes, salt_bytes, kdf_params) = {
         let conn = database
             .conn
             .lock()
             .map_err(|e| format!("Database lock error: {}", e))?;
 
-        // WAL checkpoint: flush all pending writes into main DB file
-        conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")
-            .map_err(|e| {
-                let msg = format!("Failed to checkpoint WAL: {}", e);
-                tracing::warn!("Export failed — {}", msg);
-                msg
-            })?;
-
-        // Query table counts
-        let counts = get_table_counts(&conn)?;
-
         // AC-2: Emit progress event — Copying database
         let _ = app_handle.emit("export-progress", "Copying database...");
 
-        // Read encrypted database file while holding the lock
-        let db_path = &database.path;
-        let db_bytes = fs::read(db_path).map_err(|e| {
-            let msg = format!("Failed to read database file: {}", e);
-            tracing::warn!("Export failed — {}", msg);
-            msg
-        })?;
+        // Voice data and in-session edit history stay out of the archive unless requested
+        let mut excluded_tables: Vec<&str> = Vec::new();
+        if !include_voice_data {
+            excluded_tables.extend(VOICE_DATA_TABLES);
+        }
+        if !include_edit_events.unwrap_or(false) {
+            excluded_tables.push(EDIT_EVENTS_TABLE);
+        }
+        let (counts, db_bytes) =
+            export_snapshot(&conn, &database.path, &excluded_tables).map_err(|e| {
+                tracing::warn!("Export failed — {}", e);
+                e
+            })?;
 
         // Lock released here when conn drops
         drop(conn);
@@ -297,8 +376,13 @@ pub async fn export_encrypted_archive(
             tracing::warn!("Export failed — {}", msg);
             msg
         })?;
+        let kdf_params = crate::passphrase::load_kdf_params(&app_data_dir).map_err(|e| {
+            let msg = format!("Failed to read key derivation parameters: {}", e);
+            tracing::warn!("Export failed — {}", msg);
+            msg
+        })?;
 
-        (counts, db_bytes, salt_bytes)
+        (counts, db_bytes, salt_bytes, kdf_params)
     };
 
     // Build metadata
@@ -311,7 +395,9 @@ pub async fn export_encrypted_archive(
         counts.settings,
         counts.voice_profiles,
         db_bytes.len() as u64,
-    );
+    )
+    .with_voice_data(include_voice_data, counts.golden_proposals)
+    .with_kdf_params(kdf_params);
 
     // Write to temp file first (atomic pattern)
     let temp_path = path.with_extension("urb.tmp");
@@ -379,6 +465,141 @@ pub async fn export_encrypted_archive(
     })
 }
 
+/// Result structure for export_proposal_bundle command
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportBundleResult {
+    pub success: bool,
+    pub file_path: Option<String>,
+    pub revision_count: usize,
+    pub message: String,
+}
+
+/// Tauri command: Export one proposal with its full revision history
+///
+/// Writes a self-contained JSON bundle (proposal, active and archived revisions,
+/// generation metadata, job post and analysis) chosen via a save dialog.
+/// Restore it with `import_proposal_bundle`.
+#[tauri::command]
+pub async fn export_proposal_bundle(
+    app_handle: AppHandle,
+    database: State<'_, AppDatabase>,
+    proposal_id: i64,
+) -> Result<ExportBundleResult, String> {
+    let bundle = {
+        let db = database.get()?;
+        let conn = db
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        crate::proposal_bundle::build_bundle(&conn, proposal_id)?
+            .ok_or_else(|| format!("Proposal {} not found", proposal_id))?
+    };
+    let revision_count = bundle.revisions.len();
+
+    let file_path = app_handle
+        .dialog()
+        .file()
+        .set_title("Export Proposal")
+        .set_file_name(format!("proposal-{}.json", proposal_id))
+        .add_filter("JSON Files", &["json"])
+        .blocking_save_file();
+
+    let Some(path) = file_path else {
+        return Ok(ExportBundleResult {
+            success: false,
+            file_path: None,
+            revision_count,
+            message: "Export cancelled".to_string(),
+        });
+    };
+
+    let json = serde_json::to_string_pretty(&bundle)
+        .map_err(|e| format!("Failed to serialize proposal bundle: {}", e))?;
+    let path_str = path.to_string();
+    fs::write(&path_str, json).map_err(|e| format!("Failed to write file: {}", e))?;
+
+    tracing::info!(proposal_id, revision_count, "Exported proposal bundle");
+
+    Ok(ExportBundleResult {
+        success: true,
+        file_path: Some(path_str.clone()),
+        revision_count,
+        message: format!(
+            "Exported proposal with {} revisions to {}",
+            revision_count, path_str
+        ),
+    })
+}
+
+/// Result structure for export_user_config command
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportUserConfigResult {
+    pub success: bool,
+    pub file_path: Option<String>,
+    pub message: String,
+}
+
+/// Tauri command: Export the user's configuration (skills, rates, humanization
+/// and threshold settings, scoring weights, hook strategy A/B preferences)
+///
+/// Writes to `path` when given, otherwise to a file chosen via a save dialog.
+/// Never contains the API key, passphrase material or recovery files. Restore it
+/// with `import_user_config`.
+#[tauri::command]
+pub async fn export_user_config(
+    app_handle: AppHandle,
+    database: State<'_, AppDatabase>,
+    path: Option<String>,
+) -> Result<ExportUserConfigResult, String> {
+    let config = {
+        let db = database.get()?;
+        let conn = db
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        crate::user_config::build_user_config(&conn)?
+    };
+
+    let path_str = match path {
+        Some(path) => path,
+        None => {
+            let file_path = app_handle
+                .dialog()
+                .file()
+                .set_title("Export Settings")
+                .set_file_name("upwork-researcher-config.json")
+                .add_filter("JSON Files", &["json"])
+                .blocking_save_file();
+            let Some(path) = file_path else {
+                return Ok(ExportUserConfigResult {
+                    success: false,
+                    file_path: None,
+                    message: "Export cancelled".to_string(),
+                });
+            };
+            path.to_string()
+        }
+    };
+
+    let json = serde_json::to_string_pretty(&config)
+        .map_err(|e| format!("Failed to serialize config: {}", e))?;
+    fs::write(&path_str, json).map_err(|e| format!("Failed to write file: {}", e))?;
+
+    tracing::info!(
+        skills = config.skills.len(),
+        hook_strategies = config.hook_strategies.len(),
+        "Exported user config"
+    );
+
+    Ok(ExportUserConfigResult {
+        success: true,
+        file_path: Some(path_str.clone()),
+        message: format!("Settings exported to {}", path_str),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -396,6 +617,7 @@ mod tests {
             CREATE TABLE job_posts (id INTEGER PRIMARY KEY);
             CREATE TABLE settings (id INTEGER PRIMARY KEY);
             CREATE TABLE voice_profiles (id INTEGER PRIMARY KEY);
+            CREATE TABLE golden_set_proposals (id INTEGER PRIMARY KEY);
         ",
         )
         .unwrap();
@@ -477,6 +699,99 @@ mod tests {
         assert!(result.unwrap_err().contains("suspiciously small"));
     }
 
+    #[test]
+    fn test_export_snapshot_excludes_voice_data_from_copy_only() {
+        use crate::db::queries::{golden_set, voice_profile};
+
+        let dir = tempfile::tempdir().unwrap();
+        let db = crate::db::Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        voice_profile::save_voice_profile(
+            &conn,
+            &voice_profile::VoiceProfileRow {
+                id: None,
+                user_id: "default".to_string(),
+                tone_score: 6.5,
+                avg_sentence_length: 15.2,
+                vocabulary_complexity: 9.8,
+                structure_paragraphs_pct: 70,
+                structure_bullets_pct: 30,
+                technical_depth: 7.5,
+                length_preference: 5.0,
+                common_phrases: vec!["happy to help".to_string()],
+                sample_count: 3,
+                calibration_source: "GoldenSet".to_string(),
+                created_at: None,
+                updated_at: None,
+            },
+        )
+        .unwrap();
+        golden_set::add_golden_proposal_with_min_words(&conn, "A calibrated proposal", None, 1)
+            .unwrap();
+        let before = voice_profile::get_voice_profile(&conn, "default")
+            .unwrap()
+            .unwrap();
+
+        let db_path = dir.path().join("test.db");
+        let (counts, bytes) = export_snapshot(&conn, &db_path, &VOICE_DATA_TABLES).unwrap();
+        assert_eq!(counts.voice_profiles, 0);
+        assert_eq!(counts.golden_proposals, 0);
+        assert!(!bytes.is_empty());
+        assert!(!dir.path().join(EXPORT_SNAPSHOT_FILE).exists());
+
+        // The snapshot bytes open as a database without the excluded rows
+        let copy_path = dir.path().join("copy.db");
+        fs::write(&copy_path, &bytes).unwrap();
+        let copy = Connection::open(&copy_path).unwrap();
+        let copied = get_table_counts(&copy).unwrap();
+        assert_eq!(copied.voice_profiles, 0);
+        assert_eq!(copied.golden_proposals, 0);
+
+        // The live database is never modified
+        let counts = get_table_counts(&conn).unwrap();
+        assert_eq!(counts.voice_profiles, 1);
+        assert_eq!(counts.golden_proposals, 1);
+        let after = voice_profile::get_voice_profile(&conn, "default")
+            .unwrap()
+            .unwrap();
+        assert_eq!(after.id, before.id);
+        assert_eq!(after.updated_at, before.updated_at);
+
+        // Nothing excluded: the copy keeps the voice data
+        let (counts, _) = export_snapshot(&conn, &db_path, &[]).unwrap();
+        assert_eq!(counts.voice_profiles, 1);
+        assert_eq!(counts.golden_proposals, 1);
+    }
+
+    #[test]
+    fn test_export_snapshot_excludes_edit_events_from_copy_only() {
+        use crate::db::queries::edit_events::{get_recent_edits, record_edit_event};
+
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = crate::db::Database::new(db_path.clone(), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO proposals (job_content, generated_text) VALUES ('Job', 'Draft')",
+            [],
+        )
+        .unwrap();
+        let proposal_id = conn.last_insert_rowid();
+        record_edit_event(&conn, proposal_id, "first").unwrap();
+        record_edit_event(&conn, proposal_id, "second").unwrap();
+
+        let (_, bytes) = export_snapshot(&conn, &db_path, &[EDIT_EVENTS_TABLE]).unwrap();
+        let copy_path = dir.path().join("copy.db");
+        fs::write(&copy_path, &bytes).unwrap();
+        let copy = Connection::open(&copy_path).unwrap();
+        let copied: i64 = copy
+            .query_row("SELECT COUNT(*) FROM edit_events", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(copied, 0);
+
+        assert_eq!(get_recent_edits(&conn, proposal_id, 10).unwrap().len(), 2);
+    }
+
     #[test]
     fn test_rate_limit_state_new() {
         let state = ExportRateLimitState::new();
@@ -0,0 +1,430 @@
+//! Queued generation commands and background worker
+//!
+//! Opt-in alternative to failing with `RATE_LIMITED` during the cooldown: the
+//! request is stored in `pending_generations` and a single background worker
+//! drains the queue FIFO once the cooldown clears, running the same streaming
+//! generation path as `generate_proposal_streaming`. The worker also waits out
+//! an exhausted API quota, and requeues a request the API rejected as
+//! overloaded, retrying after a longer backoff before giving up.
+
+use crate::db::queries::generation_metadata::{self, NewGenerationMetadata};
+use crate::db::queries::pending_generations::{self, PendingGeneration};
+use crate::db::AppDatabase;
+use crate::events;
+use crate::{api_quota, claude, config, db, CooldownState, DraftState};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::Notify;
+
+/// How long the idle worker sleeps before re-checking the queue without a wakeup
+const IDLE_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Overloaded responses are retried this many times per request,
+/// `OVERLOADED_BACKOFF_SECS` apart, before the request is marked failed
+const MAX_OVERLOADED_RETRIES: u32 = 3;
+
+/// Managed state shared by the queue commands and the worker
+pub struct GenerationQueueState {
+    /// Wakes the worker when a request is queued
+    notify: Notify,
+    /// Guards against spawning a second worker
+    worker_started: AtomicBool,
+}
+
+impl Default for GenerationQueueState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GenerationQueueState {
+    pub fn new() -> Self {
+        Self {
+            notify: Notify::new(),
+            worker_started: AtomicBool::new(false),
+        }
+    }
+}
+
+/// Options for a queued generation
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueGenerationOptions {
+    pub hook_strategy_id: Option<String>,
+}
+
+/// Queue a generation to run once the cooldown clears. Returns the queue id.
+#[tauri::command]
+pub fn queue_generation(
+    database: State<'_, AppDatabase>,
+    queue: State<'_, GenerationQueueState>,
+    job_content: String,
+    options: Option<QueueGenerationOptions>,
+) -> Result<i64, String> {
+    if job_content.trim().is_empty() {
+        return Err("Job content cannot be empty".to_string());
+    }
+    let options = options.unwrap_or_default();
+
+    let database = database.get()?;
+    let queue_id = {
+        let conn = database
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        pending_generations::enqueue_generation(
+            &conn,
+            &job_content,
+            options.hook_strategy_id.as_deref(),
+        )
+        .map_err(|e| format!("Failed to queue generation: {}", e))?
+    };
+
+    tracing::info!(queue_id = queue_id, "Generation queued");
+    queue.notify.notify_one();
+
+    Ok(queue_id)
+}
+
+/// List queued, running, and finished generation requests (oldest first)
+#[tauri::command]
+pub fn get_generation_queue(
+    database: State<'_, AppDatabase>,
+) -> Result<Vec<PendingGeneration>, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    pending_generations::list_pending_generations(&conn)
+        .map_err(|e| format!("Failed to get generation queue: {}", e))
+}
+
+/// Cancel a queued generation. Returns false if it already started or finished.
+#[tauri::command]
+pub fn cancel_queued_generation(
+    database: State<'_, AppDatabase>,
+    queue_id: i64,
+) -> Result<bool, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    pending_generations::cancel_queued_generation(&conn, queue_id)
+        .map_err(|e| format!("Failed to cancel queued generation: {}", e))
+}
+
+/// Spawn the queue worker. Only the first call starts a worker.
+pub fn start_generation_worker(app_handle: AppHandle) {
+    let queue = app_handle.state::<GenerationQueueState>();
+    if queue.worker_started.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        run_worker(app_handle).await;
+    });
+}
+
+async fn run_worker(app_handle: AppHandle) {
+    let queue = app_handle.state::<GenerationQueueState>();
+    let cooldown = app_handle.state::<CooldownState>();
+    let db_state = app_handle.state::<AppDatabase>();
+    let mut recovered = false;
+    let mut overloaded_retries: HashMap<i64, u32> = HashMap::new();
+
+    loop {
+        // Database may still be locked behind the passphrase; wait for a wakeup or poll
+        let Ok(database) = db_state.get() else {
+            let _ = tokio::time::timeout(IDLE_POLL_INTERVAL, queue.notify.notified()).await;
+            continue;
+        };
+
+        let next = match database.conn.lock() {
+            Ok(conn) => {
+                // Requests left running by a previous session are retried
+                if !recovered {
+                    match pending_generations::requeue_interrupted_generations(&conn) {
+                        Ok(n) if n > 0 => {
+                            tracing::info!("Requeued {} interrupted generation(s)", n)
+                        }
+                        Ok(_) => {}
+                        Err(e) => {
+                            tracing::warn!("Failed to requeue interrupted generations: {}", e)
+                        }
+                    }
+                    recovered = true;
+                }
+                pending_generations::next_queued_generation(&conn)
+            }
+            Err(e) => {
+                tracing::warn!("Generation queue: database lock error: {}", e);
+                Ok(None)
+            }
+        };
+
+        let item = match next {
+            Ok(Some(item)) => item,
+            Ok(None) => {
+                let _ = tokio::time::timeout(IDLE_POLL_INTERVAL, queue.notify.notified()).await;
+                continue;
+            }
+            Err(e) => {
+                tracing::warn!("Generation queue: failed to read queue: {}", e);
+                tokio::time::sleep(IDLE_POLL_INTERVAL).await;
+                continue;
+            }
+        };
+
+        // Story 3.8: respect the cooldown and any exhausted API quota, then re-read
+        // the queue (item may be cancelled)
+        let quota_wait = api_quota::api_quota()
+            .blocked_for_secs(chrono::Utc::now())
+            .unwrap_or(0);
+        let remaining = cooldown.remaining_seconds().max(quota_wait);
+        if remaining > 0 {
+            tokio::time::sleep(Duration::from_secs(remaining)).await;
+            continue;
+        }
+
+        let claimed = database
+            .conn
+            .lock()
+            .map_err(|e| e.to_string())
+            .and_then(|conn| {
+                pending_generations::mark_generation_running(&conn, item.id)
+                    .map_err(|e| e.to_string())
+            });
+        match claimed {
+            Ok(true) => {}
+            // Cancelled between read and claim
+            Ok(false) => continue,
+            Err(e) => {
+                tracing::warn!("Generation queue: failed to claim request: {}", e);
+                tokio::time::sleep(IDLE_POLL_INTERVAL).await;
+                continue;
+            }
+        }
+
+        let queue_id = item.id;
+        let retries = overloaded_retries.get(&queue_id).copied().unwrap_or(0);
+        let retry_overloaded = retries < MAX_OVERLOADED_RETRIES;
+        let requeued =
+            process_queued_generation(&app_handle, database, &cooldown, item, retry_overloaded)
+                .await;
+        if requeued {
+            overloaded_retries.insert(queue_id, retries + 1);
+            tokio::time::sleep(Duration::from_secs(api_quota::OVERLOADED_BACKOFF_SECS)).await;
+        } else {
+            overloaded_retries.remove(&queue_id);
+        }
+    }
+}
+
+/// Run one claimed request and record the outcome. Failures are stored, never
+/// dropped. Returns true if the API was overloaded and the request went back in
+/// the queue (only when `retry_overloaded`); the worker then backs off.
+async fn process_queued_generation(
+    app_handle: &AppHandle,
+    database: &db::Database,
+    cooldown: &CooldownState,
+    item: PendingGeneration,
+    retry_overloaded: bool,
+) -> bool {
+    let queue_id = item.id;
+    tracing::info!(queue_id = queue_id, "Starting queued generation");
+    let _ = app_handle.emit(
+        events::GENERATION_QUEUED_STARTED,
+        events::QueuedGenerationStartedPayload { queue_id },
+    );
+
+    let result = generate_and_save(app_handle, database, cooldown, &item).await;
+
+    let conn = match database.conn.lock() {
+        Ok(conn) => conn,
+        Err(e) => {
+            tracing::error!(
+                queue_id = queue_id,
+                "Failed to record queued generation result: {}",
+                e
+            );
+            return false;
+        }
+    };
+
+    match result {
+        Ok(proposal_id) => {
+            if let Err(e) =
+                pending_generations::mark_generation_completed(&conn, queue_id, proposal_id)
+            {
+                tracing::error!(
+                    queue_id = queue_id,
+                    "Failed to mark generation completed: {}",
+                    e
+                );
+            }
+            tracing::info!(
+                queue_id = queue_id,
+                proposal_id = proposal_id,
+                "Queued generation complete"
+            );
+            let _ = app_handle.emit(
+                events::GENERATION_QUEUED_COMPLETE,
+                events::QueuedGenerationCompletePayload {
+                    queue_id,
+                    proposal_id,
+                },
+            );
+        }
+        Err(error) => {
+            if retry_overloaded && error.starts_with(api_quota::OVERLOADED_PREFIX) {
+                match pending_generations::requeue_generation(&conn, queue_id) {
+                    Ok(true) => {
+                        tracing::warn!(
+                            queue_id = queue_id,
+                            "API overloaded; queued generation retries in {}s",
+                            api_quota::OVERLOADED_BACKOFF_SECS
+                        );
+                        return true;
+                    }
+                    Ok(false) => {}
+                    Err(e) => tracing::error!(
+                        queue_id = queue_id,
+                        "Failed to requeue overloaded generation: {}",
+                        e
+                    ),
+                }
+            }
+            if let Err(e) = pending_generations::mark_generation_failed(&conn, queue_id, &error) {
+                tracing::error!(
+                    queue_id = queue_id,
+                    "Failed to mark generation failed: {}",
+                    e
+                );
+            }
+            tracing::warn!(queue_id = queue_id, "Queued generation failed: {}", error);
+            let _ = app_handle.emit(
+                events::GENERATION_QUEUED_FAILED,
+                events::QueuedGenerationFailedPayload { queue_id, error },
+            );
+        }
+    }
+    false
+}
+
+/// Same context loading and streaming call as `generate_proposal_streaming`,
+/// then saves the proposal like `save_proposal`. Returns the new proposal id.
+async fn generate_and_save(
+    app_handle: &AppHandle,
+    database: &db::Database,
+    cooldown: &CooldownState,
+    item: &PendingGeneration,
+) -> Result<i64, String> {
+    let config_state = app_handle.state::<config::ConfigState>();
+    let draft_state = app_handle.state::<DraftState>();
+    let api_key = config_state.get_api_key()?;
+    let api_key_profile = config_state.active_profile().ok();
+
+    let (
+        voice_profile,
+        voice_profile_name,
+        voice_profile_version,
+        intensity,
+        sanitized,
+        prompt_addendum,
+    ) = {
+        let conn = database
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        let profile_name = db::queries::voice_profile::active_profile_name(&conn);
+        let voice_profile = crate::load_voice_profile(
+            &conn,
+            &app_handle.state::<crate::VoiceCache>(),
+            &profile_name,
+        )?;
+        let (voice_profile_version, voice_profile_name) = match voice_profile {
+            Some(_) => (
+                db::queries::voice_profile::get_voice_profile_version(&conn, &profile_name)
+                    .map_err(|e| format!("Failed to get voice profile version: {}", e))?,
+                Some(profile_name),
+            ),
+            None => (None, None),
+        };
+        let intensity = db::queries::settings::get_setting(&conn, "humanization_intensity")
+            .map_err(|e| format!("Failed to get humanization setting: {}", e))?
+            .unwrap_or_else(|| "medium".to_string());
+        (
+            voice_profile,
+            voice_profile_name,
+            voice_profile_version,
+            intensity,
+            crate::sanitize_job_content_for_generation(&conn, &item.job_content),
+            crate::load_system_prompt_addendum(&conn),
+        )
+    };
+
+    // Streamed on the job's own channel, not into the editor
+    let (generated_text, _) = claude::generate_proposal_streaming_timed(
+        &item.job_content,
+        Some(sanitized.content.as_str()),
+        app_handle.clone(),
+        api_key.as_deref(),
+        database,
+        &draft_state,
+        &intensity,
+        None,
+        prompt_addendum.as_deref(),
+        None,
+        voice_profile.as_ref(),
+        None,
+        claude::StreamChannel::Queued(item.id),
+    )
+    .await?;
+
+    // Story 3.8: Record successful generation timestamp (after API call succeeds)
+    cooldown.record();
+
+    let generation_context = claude::GenerationContext::for_prompt(
+        &claude::GenerationPromptOptions {
+            humanization_intensity: &intensity,
+            system_prompt_addendum: prompt_addendum.as_deref(),
+            voice_profile: voice_profile.as_ref(),
+            ..Default::default()
+        },
+        item.hook_strategy_id.as_deref(),
+    );
+    let metadata = NewGenerationMetadata {
+        model: claude::MODEL.to_string(),
+        humanization_intensity: intensity,
+        hook_strategy_id: item.hook_strategy_id.clone(),
+        voice_profile_version,
+        voice_profile_name,
+        target_words: None,
+        api_key_profile,
+        generation_context: Some(generation_context),
+    };
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+    generation_metadata::save_proposal_with_metadata(&conn, Some(&metadata), |tx| {
+        db::queries::proposals::insert_proposal_with_ab_context(
+            tx,
+            &item.job_content,
+            &generated_text,
+            None,
+            item.hook_strategy_id.as_deref(),
+            None,
+            false,
+            None,
+        )
+    })
+    .map_err(|e| format!("Failed to save proposal: {}", e))
+}
@@ -2,7 +2,9 @@
 //!
 //! Provides Tauri commands for fetching hook strategies from the database.
 
-use crate::db::queries::hook_strategies::{get_all_hook_strategies, HookStrategy};
+use crate::db::queries::hook_strategies::{
+    get_all_hook_strategies, set_in_ab_rotation, HookStrategy,
+};
 use crate::db::AppDatabase;
 use tauri::State;
 
@@ -27,6 +29,36 @@ pub async fn get_hook_strategies(
     get_all_hook_strategies(&conn).map_err(|e| format!("Failed to fetch hook strategies: {}", e))
 }
 
+/// Tauri command: Add a hook strategy to or remove it from A/B rotation
+///
+/// Automatic A/B selection only picks strategies in rotation; a strategy the
+/// user selects explicitly is used regardless. Returns the updated strategies.
+#[tauri::command]
+pub async fn set_hook_strategy_in_rotation(
+    database: State<'_, AppDatabase>,
+    strategy_id: i64,
+    in_rotation: bool,
+) -> Result<Vec<HookStrategy>, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    if !set_in_ab_rotation(&conn, strategy_id, in_rotation)
+        .map_err(|e| format!("Failed to update A/B rotation: {}", e))?
+    {
+        return Err(format!("Hook strategy not found: {}", strategy_id));
+    }
+    tracing::info!(
+        strategy_id,
+        in_rotation,
+        "Hook strategy A/B rotation changed"
+    );
+
+    get_all_hook_strategies(&conn).map_err(|e| format!("Failed to fetch hook strategies: {}", e))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,6 +104,23 @@ mod tests {
         assert!(!strategy.created_at.is_empty());
     }
 
+    #[test]
+    fn test_set_in_ab_rotation() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let strategies = get_all_hook_strategies(&conn).unwrap();
+        assert!(strategies.iter().all(|s| s.in_ab_rotation));
+
+        let id = strategies[0].id;
+        assert!(set_in_ab_rotation(&conn, id, false).unwrap());
+        let updated = get_all_hook_strategies(&conn).unwrap();
+        assert!(!updated.iter().find(|s| s.id == id).unwrap().in_ab_rotation);
+        assert_eq!(updated.iter().filter(|s| s.in_ab_rotation).count(), 4);
+
+        assert!(!set_in_ab_rotation(&conn, 9999, true).unwrap());
+    }
+
     #[test]
     fn test_get_hook_strategies_error_handling() {
         // Story 5.2: Subtask 1.4 - Test error handling
@@ -3,14 +3,17 @@
 use crate::archive_export::ArchiveMetadata;
 use crate::archive_import::{
     check_schema_compatibility, cleanup_orphaned_temp_files, extract_archive_db,
-    import_from_archive, open_archive_for_preview, read_metadata_preview, ArchiveImportError,
-    ImportMode, ImportProgress, ImportSummary, SchemaCompatibility,
+    import_from_archive, journal_path, open_archive_for_preview, read_metadata_preview,
+    recover_interrupted_imports, ArchiveImportError, ImportJournal, ImportMode, ImportProgress,
+    ImportStageProgress, ImportSummary, SchemaCompatibility, VoiceDataImport, VOICE_DATA_TABLES,
 };
 use crate::backup::create_pre_migration_backup;
 use crate::db::AppDatabase;
+use crate::events;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::Mutex;
 use tauri::{AppHandle, Emitter, Manager, State};
 use zeroize::Zeroizing;
 
@@ -23,6 +26,10 @@ pub struct ImportPreview {
     pub archive_version: Option<i32>,
     pub current_version: i32,
     pub warnings: Vec<String>,
+    /// The archive holds a voice profile or golden proposals (any format version)
+    pub voice_data_available: bool,
+    /// A local voice profile exists, so importing voice data needs a keep/replace choice
+    pub local_voice_profile_exists: bool,
 }
 
 /// Tauri command: Read archive metadata without decryption
@@ -57,7 +64,8 @@ pub async fn decrypt_archive(
     });
 
     // Open archive for schema inspection
-    let archive_conn = open_archive_for_preview(&temp_db_path, &passphrase, &salt)
+    let kdf_params = metadata.kdf_params.unwrap_or_default();
+    let archive_conn = open_archive_for_preview(&temp_db_path, &passphrase, &salt, &kdf_params)
         .map_err(|e| match e {
             ArchiveImportError::DecryptionFailed => {
                 format!("Wrong passphrase. This backup was created on {}. Try the passphrase you were using at that time.", metadata.export_date)
@@ -82,8 +90,21 @@ pub async fn decrypt_archive(
         )
         .map_err(|e| format!("Failed to query current schema version: {}", e))?;
 
+    let local_voice_profile_exists = !crate::db::queries::voice_profile::list_voice_profiles(&conn)
+        .map_err(|e| format!("Failed to check local voice profile: {}", e))?
+        .is_empty();
+
     drop(conn); // Release lock
 
+    // v1 archives carried voice data without saying so; look in the archive itself
+    let voice_data_available = VOICE_DATA_TABLES.iter().any(|table| {
+        archive_conn
+            .query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .is_ok_and(|count| count > 0)
+    });
+
     // Check schema compatibility
     let compat = check_schema_compatibility(&archive_conn, current_version)
         .map_err(|e| format!("Schema compatibility check failed: {}", e))?;
@@ -93,7 +114,10 @@ pub async fn decrypt_archive(
     let (compat_str, archive_version) = match compat {
         SchemaCompatibility::Compatible => ("compatible".to_string(), None),
         SchemaCompatibility::OlderArchive { archive_version } => {
-            warnings.push("Archive from older version — some newer fields will be set to defaults.".to_string());
+            warnings.push(
+                "Archive from older version — some newer fields will be set to defaults."
+                    .to_string(),
+            );
             ("older".to_string(), Some(archive_version))
         }
         SchemaCompatibility::NewerArchive { archive_version } => {
@@ -110,19 +134,31 @@ pub async fn decrypt_archive(
         archive_version,
         current_version,
         warnings,
+        voice_data_available,
+        local_voice_profile_exists,
     })
 }
 
 /// Tauri command: Execute import with progress events
 ///
 /// AC-4, AC-5, AC-6, AC-7: Import data with mode selection, progress tracking, atomic transaction
+/// Emits `import:progress` ({stage, processed, total}) alongside the per-table
+/// `import-progress`, and keeps a journal next to the temp file for crash recovery.
+///
+/// Voice data (voice profile and golden proposals) is imported only with
+/// `import_voice_data`; `voice_conflict` ("keep" | "replace", default "keep") decides
+/// what happens to an existing local profile. The voice cache is cleared afterwards.
+#[allow(clippy::too_many_arguments)]
 #[tauri::command]
 pub async fn execute_import(
     app_handle: AppHandle,
     database: State<'_, AppDatabase>,
+    voice_cache: State<'_, crate::VoiceCache>,
     archive_path: String,
     passphrase: String,
     mode: String, // "replace" | "merge"
+    import_voice_data: Option<bool>,
+    voice_conflict: Option<String>,
 ) -> Result<ImportSummary, String> {
     // Parse import mode
     let import_mode = match mode.as_str() {
@@ -130,6 +166,10 @@ pub async fn execute_import(
         "merge" => ImportMode::MergeSkipDuplicates,
         _ => return Err(format!("Invalid import mode: {}", mode)),
     };
+    let voice_import = VoiceDataImport::from_options(
+        import_voice_data.unwrap_or(false),
+        voice_conflict.as_deref(),
+    )?;
 
     // AC-9 (Task 9): Pre-import backup for ReplaceAll mode
     if import_mode == ImportMode::ReplaceAll {
@@ -155,14 +195,19 @@ pub async fn execute_import(
     let archive_path_buf = PathBuf::from(&archive_path);
 
     // Extract archive to temp file
-    let (_metadata, salt, temp_db_path) = extract_archive_db(&archive_path_buf)
+    let (metadata, salt, temp_db_path) = extract_archive_db(&archive_path_buf)
         .map_err(|e| format!("Failed to extract archive: {}", e))?;
 
-    // Ensure temp file cleanup on scope exit
+    // Ensure temp file and journal cleanup on scope exit (a crash leaves both for
+    // cleanup_import_temp_files on the next launch)
     let _cleanup = scopeguard::guard(temp_db_path.clone(), |path: PathBuf| {
+        let _ = fs::remove_file(journal_path(&path));
         let _ = fs::remove_file(path);
     });
 
+    let mut journal = ImportJournal::new(&archive_path, &temp_db_path, import_mode);
+    journal.save()?;
+
     // Derive key from passphrase
     let mut salt_array = [0u8; 16];
     if salt.len() != 16 {
@@ -170,7 +215,8 @@ pub async fn execute_import(
     }
     salt_array.copy_from_slice(&salt);
 
-    let key = crate::passphrase::derive_key(&passphrase, &salt_array)
+    let kdf_params = metadata.kdf_params.unwrap_or_default();
+    let key = crate::passphrase::derive_key_with_params(&passphrase, &salt_array, &kdf_params)
         .map_err(|_| "Decryption failed - wrong passphrase or corrupted archive".to_string())?;
 
     // Convert key to hex (wrapped in Zeroizing)
@@ -186,6 +232,9 @@ pub async fn execute_import(
     let progress_callback = move |progress: ImportProgress| {
         let _ = app_handle_clone.emit("import-progress", &progress);
     };
+    let stage_callback = move |progress: ImportStageProgress| {
+        let _ = app_handle.emit(events::IMPORT_PROGRESS, &progress);
+    };
 
     // Perform import
     let summary = import_from_archive(
@@ -193,16 +242,149 @@ pub async fn execute_import(
         &temp_db_path,
         &hex_key,
         import_mode,
+        voice_import,
+        Some(&mut journal),
         progress_callback,
+        stage_callback,
     )
     .map_err(|e| format!("Import failed: {}", e))?;
 
+    if voice_import != VoiceDataImport::Skip {
+        voice_cache.invalidate();
+    }
+
     Ok(summary)
 }
 
+/// Tauri command: Restore a proposal bundle written by `export_proposal_bundle`
+///
+/// The proposal is added as a new proposal with fresh IDs (existing rows are never
+/// touched); revision restore links are remapped to the new revision IDs.
+#[tauri::command]
+pub async fn import_proposal_bundle(
+    database: State<'_, AppDatabase>,
+    bundle_path: String,
+) -> Result<crate::proposal_bundle::BundleImportResult, String> {
+    let json =
+        fs::read_to_string(&bundle_path).map_err(|e| format!("Failed to read bundle: {}", e))?;
+    let bundle: crate::proposal_bundle::ProposalBundle =
+        serde_json::from_str(&json).map_err(|e| format!("Invalid proposal bundle: {}", e))?;
+
+    let db = database.get()?;
+    let conn = db
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+    crate::proposal_bundle::import_bundle(&conn, &bundle)
+}
+
+/// Tauri command: Import a config written by `export_user_config`
+///
+/// `merge` keeps existing config the file doesn't mention; otherwise the file
+/// replaces it (see `user_config::import_user_config`). Database changes are
+/// applied in one transaction; job scores are recalculated if skills or rates
+/// changed. Returns per-section applied/skipped counts.
+#[tauri::command]
+pub async fn import_user_config(
+    database: State<'_, AppDatabase>,
+    voice_cache: State<'_, crate::VoiceCache>,
+    path: String,
+    merge: bool,
+) -> Result<crate::user_config::UserConfigImportReport, String> {
+    let json = fs::read_to_string(&path).map_err(|e| format!("Failed to read config: {}", e))?;
+    let config: crate::user_config::UserConfig =
+        serde_json::from_str(&json).map_err(|e| format!("Invalid config file: {}", e))?;
+    crate::user_config::check_format_version(&config)?;
+
+    let db = database.get()?;
+    let mut report = {
+        let conn = db
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        let report = crate::user_config::import_user_config(&conn, &config, merge)?;
+
+        // AI-tell phrase lists are cached in memory; reload them from what was stored
+        for key in [
+            crate::humanization::AI_TELL_USER_PHRASES_SETTING,
+            crate::humanization::AI_TELL_MATCH_MODE_SETTING,
+        ] {
+            let value = crate::db::queries::settings::get_setting(&conn, key)
+                .map_err(|e| format!("Failed to read setting: {}", e))?;
+            crate::humanization::apply_ai_tell_setting(key, value.as_deref());
+        }
+        report
+    };
+
+    if report.affects_scores() {
+        match crate::recalculate_all_scores_internal(db) {
+            Ok(count) => report.scores_recalculated = Some(count),
+            Err(e) => tracing::warn!("Score recalculation after config import failed: {}", e),
+        }
+    }
+    if report.total_applied() > 0 {
+        // Generation settings changed under any cached profile; reload on next use
+        voice_cache.invalidate();
+    }
+
+    tracing::info!(
+        merge,
+        applied = report.total_applied(),
+        warnings = report.warnings.len(),
+        "Imported user config"
+    );
+    Ok(report)
+}
+
+/// Managed state: journals of imports interrupted before they committed, found
+/// at startup and kept until the user dismisses them
+#[derive(Default)]
+pub struct IncompleteImportsState {
+    journals: Mutex<Vec<ImportJournal>>,
+}
+
+impl IncompleteImportsState {
+    pub fn new(journals: Vec<ImportJournal>) -> Self {
+        Self {
+            journals: Mutex::new(journals),
+        }
+    }
+}
+
+/// Tauri command: imports interrupted before they committed (detected at
+/// startup, before the frontend could listen for an event). Called on mount.
+#[tauri::command]
+pub fn get_incomplete_imports(
+    state: State<'_, IncompleteImportsState>,
+) -> Result<Vec<ImportJournal>, String> {
+    let journals = state
+        .journals
+        .lock()
+        .map_err(|e| format!("Incomplete imports lock error: {}", e))?;
+    Ok(journals.clone())
+}
+
+/// Tauri command: forget the interrupted imports once the user has seen them
+#[tauri::command]
+pub fn dismiss_incomplete_imports(state: State<'_, IncompleteImportsState>) -> Result<(), String> {
+    state
+        .journals
+        .lock()
+        .map_err(|e| format!("Incomplete imports lock error: {}", e))?
+        .clear();
+    Ok(())
+}
+
 /// Cleanup orphaned temp files on app startup (called once)
-pub fn cleanup_import_temp_files() -> Result<(), String> {
+///
+/// Files of imports interrupted mid-run (left with a journal) are removed first;
+/// the journals of imports that never committed are returned so the caller can
+/// keep them in `IncompleteImportsState`.
+pub fn cleanup_import_temp_files() -> Result<Vec<ImportJournal>, String> {
     let temp_dir = std::env::temp_dir();
+    let incomplete = recover_interrupted_imports(&temp_dir)
+        .map_err(|e| format!("Failed to recover interrupted imports: {}", e))?;
+
     let cleaned = cleanup_orphaned_temp_files(&temp_dir)
         .map_err(|e| format!("Failed to cleanup temp files: {}", e))?;
 
@@ -210,7 +392,7 @@ pub fn cleanup_import_temp_files() -> Result<(), String> {
         tracing::info!("Cleaned up {} orphaned import temp files", cleaned);
     }
 
-    Ok(())
+    Ok(incomplete)
 }
 
 #[cfg(test)]
@@ -245,6 +427,25 @@ mod tests {
         assert!(invalid.is_none(), "Invalid mode should not parse");
     }
 
+    #[test]
+    fn test_incomplete_imports_state_holds_journals() {
+        let journal = ImportJournal::new(
+            "/tmp/backup.urb",
+            std::path::Path::new("/tmp/abc.urb.tmp"),
+            ImportMode::MergeSkipDuplicates,
+        );
+        let state = IncompleteImportsState::new(vec![journal.clone()]);
+        assert_eq!(*state.journals.lock().unwrap(), vec![journal]);
+
+        state.journals.lock().unwrap().clear();
+        assert!(state.journals.lock().unwrap().is_empty());
+        assert!(IncompleteImportsState::default()
+            .journals
+            .lock()
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn test_cleanup_import_temp_files_runs_without_error() {
         // cleanup_import_temp_files scans system temp dir — verify it doesn't panic
@@ -1,25 +1,90 @@
 //! Job Queue commands for Story 4b.9
 //!
-//! Provides Tauri commands for querying the job queue with sorting and filtering.
+//! Provides Tauri commands for querying the job queue with sorting and filtering,
+//! plus job freshness management (dismiss, mark applied, stale cleanup).
 
+use crate::commands::presentation::{ClientNameMasker, PresentationModeState};
+use crate::db::queries::job_posts::{self, PurgeStaleJobsResult};
 use crate::db::AppDatabase;
 use crate::job::types::{
     ColorCounts, JobQueueItem, JobQueueResponse, ScoreColor, ScoreFilter, SortField,
 };
+use chrono::{DateTime, NaiveDateTime, Utc};
 use rusqlite::Connection;
 use tauri::State;
 use tracing::{error, info};
 
+/// Settings key: days without being seen before an active job is marked stale
+pub const JOB_STALE_DAYS_SETTING: &str = "job_stale_days";
+pub const DEFAULT_JOB_STALE_DAYS: u32 = 14;
+pub const MAX_JOB_STALE_DAYS: u32 = 365;
+
+/// Job queue visibility: stale and dismissed jobs are hidden by default
+const ACTIVE_JOBS_CLAUSE: &str = "job_status NOT IN ('stale', 'dismissed')";
+
+/// Archived and snoozed jobs (V52) are hidden unless asked for
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct QueueVisibility {
+    pub include_archived: bool,
+    pub include_snoozed: bool,
+}
+
+/// Longest snooze `set_job_queue_status` accepts
+pub const MAX_SNOOZE_DAYS: i64 = 365;
+
+/// Visibility conditions shared by the job list, its count and the color counts
+fn visibility_conditions(include_inactive: bool, queue: QueueVisibility) -> Vec<String> {
+    let mut conditions = Vec::new();
+    if !include_inactive {
+        conditions.push(ACTIVE_JOBS_CLAUSE.to_string());
+    }
+    let mut hidden = Vec::new();
+    if !queue.include_archived {
+        hidden.push("'archived'");
+    }
+    if !queue.include_snoozed {
+        hidden.push("'snoozed'");
+    }
+    if !hidden.is_empty() {
+        conditions.push(format!(
+            "({}) NOT IN ({})",
+            job_posts::EFFECTIVE_QUEUE_STATUS_SQL,
+            hidden.join(", ")
+        ));
+    }
+    conditions
+}
+
+/// Startup maintenance (deferred init): mark jobs not seen for `job_stale_days` as stale
+pub fn mark_stale_jobs_from_settings(conn: &Connection) -> Result<usize, String> {
+    let stale_days =
+        crate::settings::schema::get_typed(conn, crate::settings::schema::Key::JobStaleDays)?;
+
+    job_posts::mark_stale_jobs(conn, stale_days)
+        .map_err(|e| format!("Failed to mark stale jobs: {}", e))
+}
+
 /// [AI-Review Fix H2]: Get counts per color for filter chip labels
-fn get_color_counts(conn: &Connection) -> Result<ColorCounts, String> {
+fn get_color_counts(
+    conn: &Connection,
+    include_inactive: bool,
+    queue: QueueVisibility,
+) -> Result<ColorCounts, String> {
     let mut counts = ColorCounts::default();
 
+    let conditions = visibility_conditions(include_inactive, queue);
+    let status_clause = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", conditions.join(" AND "))
+    };
     let mut stmt = conn
-        .prepare(
+        .prepare(&format!(
             "SELECT COALESCE(score_color, 'gray') as color, COUNT(*) as cnt
-             FROM job_posts
+             FROM job_posts{}
              GROUP BY COALESCE(score_color, 'gray')",
-        )
+            status_clause
+        ))
         .map_err(|e| format!("Failed to prepare color count query: {}", e))?;
 
     let rows = stmt
@@ -50,9 +115,11 @@ fn query_job_queue_internal(
     filter: &ScoreFilter,
     limit: u32,
     offset: u32,
+    include_inactive: bool,
+    queue: QueueVisibility,
 ) -> Result<JobQueueResponse, String> {
     // Build SQL query (AC-7: Select only lightweight columns, avoid raw_content, analysis_json)
-    let mut query = String::from(
+    let mut query = format!(
         "SELECT
             id,
             COALESCE(client_name, 'Unknown Client') as client_name,
@@ -61,17 +128,30 @@ fn query_job_queue_internal(
             client_quality_percent,
             overall_score,
             COALESCE(score_color, 'gray') as score_color,
-            created_at
+            created_at,
+            job_status,
+            {} as queue_status,
+            snoozed_until
         FROM job_posts",
+        job_posts::EFFECTIVE_QUEUE_STATUS_SQL
     );
 
-    // Apply filter (AC-5)
-    let filter_clause = match filter {
-        ScoreFilter::GreenOnly => " WHERE score_color = 'green'",
-        ScoreFilter::YellowAndGreen => " WHERE score_color IN ('green', 'yellow')",
-        ScoreFilter::All => "", // No filter
+    // Apply filter (AC-5), hiding stale/dismissed and archived/snoozed jobs unless requested
+    let mut conditions = Vec::new();
+    match filter {
+        ScoreFilter::GreenOnly => conditions.push("score_color = 'green'".to_string()),
+        ScoreFilter::YellowAndGreen => {
+            conditions.push("score_color IN ('green', 'yellow')".to_string())
+        }
+        ScoreFilter::All => {} // No filter
+    }
+    conditions.extend(visibility_conditions(include_inactive, queue));
+    let filter_clause = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", conditions.join(" AND "))
     };
-    query.push_str(filter_clause);
+    query.push_str(&filter_clause);
 
     // Apply sort (AC-3) with secondary sort by id for stability
     // [AI-Review Fix M2]: Equal values need secondary sort for deterministic results
@@ -104,6 +184,17 @@ fn query_job_queue_internal(
                     ScoreColor::from_db_value(&color_str)
                 },
                 created_at: row.get(7)?,
+                job_status: row.get(8)?,
+                snoozed_until: {
+                    // Only meaningful while the snooze lasts
+                    let queue_status: String = row.get(9)?;
+                    if queue_status == job_posts::QUEUE_STATUS_SNOOZED {
+                        row.get(10)?
+                    } else {
+                        None
+                    }
+                },
+                queue_status: row.get(9)?,
             })
         })
         .map_err(|e| format!("Failed to execute query: {}", e))?;
@@ -119,7 +210,7 @@ fn query_job_queue_internal(
         .map_err(|e| format!("Failed to count jobs: {}", e))?;
 
     // [AI-Review Fix H2]: Get color counts for filter chip labels (AC-5)
-    let color_counts = get_color_counts(conn)?;
+    let color_counts = get_color_counts(conn, include_inactive, queue)?;
 
     let has_more = (offset + jobs.len() as u32) < total_count as u32;
 
@@ -136,25 +227,41 @@ fn query_job_queue_internal(
 /// AC-3: Supports sorting by score (default), date, client name
 /// AC-5: Supports filtering by color (all, green only, yellow+green)
 /// AC-7: Query completes in <500ms even with 100+ jobs (NFR-17)
+/// Stale and dismissed jobs are excluded unless `include_inactive` is true;
+/// archived and snoozed jobs unless `include_archived` / `include_snoozed` are.
+/// A snooze that has run out counts as active.
+/// Client names are replaced with placeholders in presentation mode.
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn get_job_queue(
     sort_by: SortField,
     filter: ScoreFilter,
     limit: u32,
     offset: u32,
+    include_inactive: Option<bool>,
+    include_archived: Option<bool>,
+    include_snoozed: Option<bool>,
     db: State<'_, AppDatabase>,
+    presentation: State<'_, PresentationModeState>,
 ) -> Result<JobQueueResponse, String> {
     let db = db.get()?;
     let start = std::time::Instant::now();
 
-    // Lock database connection
-    let conn = db
-        .conn
-        .lock()
-        .map_err(|e| format!("Failed to lock database: {}", e))?;
-
-    // Call internal function
-    let response = query_job_queue_internal(&conn, &sort_by, &filter, limit, offset)?;
+    // Query on a read connection (doesn't wait behind long writes)
+    let mut response = db.read(|conn| {
+        query_job_queue_internal(
+            conn,
+            &sort_by,
+            &filter,
+            limit,
+            offset,
+            include_inactive.unwrap_or(false),
+            QueueVisibility {
+                include_archived: include_archived.unwrap_or(false),
+                include_snoozed: include_snoozed.unwrap_or(false),
+            },
+        )
+    })?;
 
     let elapsed = start.elapsed();
     info!(
@@ -173,9 +280,147 @@ pub async fn get_job_queue(
         );
     }
 
+    if presentation.is_enabled() {
+        let mut masker = ClientNameMasker::default();
+        for job in &mut response.jobs {
+            job.client_name = masker.mask(&job.client_name);
+        }
+    }
+
     Ok(response)
 }
 
+/// Dismiss a job so it no longer shows in the queue
+#[tauri::command]
+pub fn dismiss_job_post(id: i64, db: State<'_, AppDatabase>) -> Result<(), String> {
+    let db = db.get()?;
+    let conn = db
+        .conn
+        .lock()
+        .map_err(|e| format!("Failed to lock database: {}", e))?;
+
+    if !job_posts::set_job_status(&conn, id, job_posts::JOB_STATUS_DISMISSED)
+        .map_err(|e| format!("Failed to dismiss job: {}", e))?
+    {
+        return Err(format!("Job post {} not found", id));
+    }
+    info!(job_post_id = id, "Job post dismissed");
+    Ok(())
+}
+
+/// Normalize a snooze end time (RFC 3339, or `YYYY-MM-DD HH:MM:SS` taken as UTC)
+/// to the UTC `YYYY-MM-DD HH:MM:SS` form stored in `snoozed_until`. It must be
+/// in the future and at most `MAX_SNOOZE_DAYS` away.
+fn parse_snooze_until(value: &str, now: DateTime<Utc>) -> Result<String, String> {
+    let value = value.trim();
+    let until = DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").map(|t| t.and_utc()))
+        .map_err(|_| format!("Invalid snooze time: {}", value))?;
+    if until <= now {
+        return Err("Snooze time must be in the future".to_string());
+    }
+    if until > now + chrono::Duration::days(MAX_SNOOZE_DAYS) {
+        return Err(format!(
+            "Snooze time must be within {} days",
+            MAX_SNOOZE_DAYS
+        ));
+    }
+    Ok(until.format("%Y-%m-%d %H:%M:%S").to_string())
+}
+
+/// Archive, snooze or re-activate a job in the queue
+///
+/// `status` is "active", "archived" or "snoozed"; snoozing needs `snooze_until`
+/// (RFC 3339). Scores, analysis and freshness status are not touched.
+#[tauri::command]
+pub fn set_job_queue_status(
+    id: i64,
+    status: String,
+    snooze_until: Option<String>,
+    db: State<'_, AppDatabase>,
+) -> Result<(), String> {
+    let snoozed_until = match status.as_str() {
+        job_posts::QUEUE_STATUS_ACTIVE | job_posts::QUEUE_STATUS_ARCHIVED => None,
+        job_posts::QUEUE_STATUS_SNOOZED => {
+            let until = snooze_until
+                .as_deref()
+                .ok_or_else(|| "Snoozing a job needs a snooze time".to_string())?;
+            Some(parse_snooze_until(until, Utc::now())?)
+        }
+        other => {
+            return Err(format!(
+                "Invalid queue status '{}'. Valid values: active, archived, snoozed",
+                other
+            ))
+        }
+    };
+
+    let db = db.get()?;
+    let conn = db
+        .conn
+        .lock()
+        .map_err(|e| format!("Failed to lock database: {}", e))?;
+
+    if !job_posts::set_job_queue_status(&conn, id, &status, snoozed_until.as_deref())
+        .map_err(|e| format!("Failed to update job queue status: {}", e))?
+    {
+        return Err(format!("Job post {} not found", id));
+    }
+    info!(job_post_id = id, status = %status, snoozed_until = ?snoozed_until, "Job queue status changed");
+    Ok(())
+}
+
+/// Mark a job applied and link the proposal that was sent for it
+#[tauri::command]
+pub fn mark_job_applied(
+    id: i64,
+    proposal_id: i64,
+    db: State<'_, AppDatabase>,
+) -> Result<(), String> {
+    let db = db.get()?;
+    let conn = db
+        .conn
+        .lock()
+        .map_err(|e| format!("Failed to lock database: {}", e))?;
+
+    if !job_posts::mark_job_applied(&conn, id, proposal_id)
+        .map_err(|e| format!("Failed to mark job applied: {}", e))?
+    {
+        return Err(format!(
+            "Job post {} or proposal {} not found",
+            id, proposal_id
+        ));
+    }
+    info!(job_post_id = id, proposal_id, "Job post marked applied");
+    Ok(())
+}
+
+/// Delete stale and dismissed jobs not seen for `older_than_days`, with their
+/// skills and scores. Returns how many rows were removed.
+#[tauri::command]
+pub fn purge_stale_jobs(
+    older_than_days: u32,
+    db: State<'_, AppDatabase>,
+) -> Result<PurgeStaleJobsResult, String> {
+    let db = db.get()?;
+    let conn = db
+        .conn
+        .lock()
+        .map_err(|e| format!("Failed to lock database: {}", e))?;
+
+    let result = job_posts::purge_stale_jobs(&conn, older_than_days)
+        .map_err(|e| format!("Failed to purge stale jobs: {}", e))?;
+    info!(
+        jobs = result.jobs_deleted,
+        skills = result.skills_deleted,
+        scores = result.scores_deleted,
+        older_than_days,
+        "Purged stale jobs"
+    );
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,7 +478,15 @@ mod tests {
         )
         .unwrap();
 
-        let result = query_job_queue_internal(&conn, &SortField::Score, &ScoreFilter::All, 50, 0);
+        let result = query_job_queue_internal(
+            &conn,
+            &SortField::Score,
+            &ScoreFilter::All,
+            50,
+            0,
+            false,
+            QueueVisibility::default(),
+        );
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -267,8 +520,15 @@ mod tests {
         )
         .unwrap();
 
-        let result =
-            query_job_queue_internal(&conn, &SortField::Score, &ScoreFilter::GreenOnly, 50, 0);
+        let result = query_job_queue_internal(
+            &conn,
+            &SortField::Score,
+            &ScoreFilter::GreenOnly,
+            50,
+            0,
+            false,
+            QueueVisibility::default(),
+        );
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -303,6 +563,8 @@ mod tests {
             &ScoreFilter::YellowAndGreen,
             50,
             0,
+            false,
+            QueueVisibility::default(),
         );
 
         assert!(result.is_ok());
@@ -331,7 +593,15 @@ mod tests {
         )
         .unwrap();
 
-        let result = query_job_queue_internal(&conn, &SortField::Score, &ScoreFilter::All, 50, 0);
+        let result = query_job_queue_internal(
+            &conn,
+            &SortField::Score,
+            &ScoreFilter::All,
+            50,
+            0,
+            false,
+            QueueVisibility::default(),
+        );
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -363,8 +633,15 @@ mod tests {
         )
         .unwrap();
 
-        let result =
-            query_job_queue_internal(&conn, &SortField::ClientName, &ScoreFilter::All, 50, 0);
+        let result = query_job_queue_internal(
+            &conn,
+            &SortField::ClientName,
+            &ScoreFilter::All,
+            50,
+            0,
+            false,
+            QueueVisibility::default(),
+        );
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -390,7 +667,15 @@ mod tests {
         }
 
         // First page: limit 2, offset 0
-        let result = query_job_queue_internal(&conn, &SortField::Score, &ScoreFilter::All, 2, 0);
+        let result = query_job_queue_internal(
+            &conn,
+            &SortField::Score,
+            &ScoreFilter::All,
+            2,
+            0,
+            false,
+            QueueVisibility::default(),
+        );
         assert!(result.is_ok());
         let response = result.unwrap();
         assert_eq!(response.jobs.len(), 2);
@@ -398,7 +683,15 @@ mod tests {
         assert_eq!(response.has_more, true);
 
         // Second page: limit 2, offset 2
-        let result = query_job_queue_internal(&conn, &SortField::Score, &ScoreFilter::All, 2, 2);
+        let result = query_job_queue_internal(
+            &conn,
+            &SortField::Score,
+            &ScoreFilter::All,
+            2,
+            2,
+            false,
+            QueueVisibility::default(),
+        );
         assert!(result.is_ok());
         let response = result.unwrap();
         assert_eq!(response.jobs.len(), 2);
@@ -406,7 +699,15 @@ mod tests {
         assert_eq!(response.has_more, true);
 
         // Last page: limit 2, offset 4
-        let result = query_job_queue_internal(&conn, &SortField::Score, &ScoreFilter::All, 2, 4);
+        let result = query_job_queue_internal(
+            &conn,
+            &SortField::Score,
+            &ScoreFilter::All,
+            2,
+            4,
+            false,
+            QueueVisibility::default(),
+        );
         assert!(result.is_ok());
         let response = result.unwrap();
         assert_eq!(response.jobs.len(), 1);
@@ -419,7 +720,15 @@ mod tests {
         let db = setup_test_db();
         let conn = db.conn.lock().unwrap();
 
-        let result = query_job_queue_internal(&conn, &SortField::Score, &ScoreFilter::All, 50, 0);
+        let result = query_job_queue_internal(
+            &conn,
+            &SortField::Score,
+            &ScoreFilter::All,
+            50,
+            0,
+            false,
+            QueueVisibility::default(),
+        );
 
         if let Err(e) = &result {
             eprintln!("Error: {}", e);
@@ -430,4 +739,178 @@ mod tests {
         assert_eq!(response.total_count, 0);
         assert_eq!(response.has_more, false);
     }
+
+    #[test]
+    fn test_query_job_queue_hides_stale_and_dismissed_by_default() {
+        let db = setup_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        for (title, status) in [
+            ("Active", "active"),
+            ("Applied", "applied"),
+            ("Stale", "stale"),
+            ("Dismissed", "dismissed"),
+        ] {
+            conn.execute(
+                "INSERT INTO job_posts (raw_content, job_title, score_color, job_status) VALUES ('content', ?, 'green', ?)",
+                rusqlite::params![title, status],
+            )
+            .unwrap();
+        }
+
+        let response = query_job_queue_internal(
+            &conn,
+            &SortField::Date,
+            &ScoreFilter::GreenOnly,
+            50,
+            0,
+            false,
+            QueueVisibility::default(),
+        )
+        .unwrap();
+        assert_eq!(response.total_count, 2);
+        assert_eq!(response.color_counts.green, 2);
+        assert!(response
+            .jobs
+            .iter()
+            .all(|job| job.job_status == "active" || job.job_status == "applied"));
+
+        let response = query_job_queue_internal(
+            &conn,
+            &SortField::Date,
+            &ScoreFilter::GreenOnly,
+            50,
+            0,
+            true,
+            QueueVisibility::default(),
+        )
+        .unwrap();
+        assert_eq!(response.total_count, 4);
+        assert_eq!(response.color_counts.green, 4);
+    }
+
+    #[test]
+    fn test_query_job_queue_archive_and_snooze_visibility() {
+        let db = setup_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        for (title, queue_status, snoozed_until) in [
+            ("Active", "active", None),
+            ("Archived", "archived", None),
+            ("Snoozed", "snoozed", Some("datetime('now', '+3 days')")),
+            ("Snooze over", "snoozed", Some("datetime('now', '-1 hour')")),
+        ] {
+            conn.execute(
+                &format!(
+                    "INSERT INTO job_posts (raw_content, job_title, score_color, queue_status, snoozed_until)
+                     VALUES ('content', ?1, 'green', ?2, {})",
+                    snoozed_until.unwrap_or("NULL")
+                ),
+                rusqlite::params![title, queue_status],
+            )
+            .unwrap();
+        }
+        let query = |queue| {
+            query_job_queue_internal(
+                &conn,
+                &SortField::Date,
+                &ScoreFilter::All,
+                50,
+                0,
+                false,
+                queue,
+            )
+            .unwrap()
+        };
+
+        // An expired snooze is active without the row having been rewritten
+        let response = query(QueueVisibility::default());
+        assert_eq!(response.total_count, 2);
+        assert_eq!(response.color_counts.green, 2);
+        let over = response
+            .jobs
+            .iter()
+            .find(|job| job.job_title == "Snooze over")
+            .unwrap();
+        assert_eq!(over.queue_status, "active");
+        assert_eq!(over.snoozed_until, None);
+
+        let response = query(QueueVisibility {
+            include_archived: true,
+            include_snoozed: false,
+        });
+        assert_eq!(response.total_count, 3);
+
+        let response = query(QueueVisibility {
+            include_archived: false,
+            include_snoozed: true,
+        });
+        assert_eq!(response.total_count, 3);
+        let snoozed = response
+            .jobs
+            .iter()
+            .find(|job| job.job_title == "Snoozed")
+            .unwrap();
+        assert_eq!(snoozed.queue_status, "snoozed");
+        assert!(snoozed.snoozed_until.is_some());
+    }
+
+    #[test]
+    fn test_set_job_queue_status_leaves_scores_alone() {
+        let db = setup_test_db();
+        let id = insert_test_job(&db, "Client", Some(88.0), "green");
+        let conn = db.conn.lock().unwrap();
+
+        assert!(
+            job_posts::set_job_queue_status(&conn, id, "snoozed", Some("2099-01-01 00:00:00"))
+                .unwrap()
+        );
+        assert!(job_posts::set_job_queue_status(&conn, id, "archived", Some("ignored")).unwrap());
+        assert!(!job_posts::set_job_queue_status(&conn, 9999, "archived", None).unwrap());
+
+        let (queue_status, snoozed_until, score, job_status): (
+            String,
+            Option<String>,
+            f64,
+            String,
+        ) = conn
+            .query_row(
+                "SELECT queue_status, snoozed_until, overall_score, job_status FROM job_posts WHERE id = ?1",
+                [id],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .unwrap();
+        assert_eq!(queue_status, "archived");
+        assert_eq!(snoozed_until, None);
+        assert_eq!(score, 88.0);
+        assert_eq!(job_status, "active");
+    }
+
+    #[test]
+    fn test_parse_snooze_until() {
+        let now = DateTime::parse_from_rfc3339("2026-03-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            parse_snooze_until("2026-03-02T09:30:00+02:00", now).unwrap(),
+            "2026-03-02 07:30:00"
+        );
+        assert_eq!(
+            parse_snooze_until("2026-03-08 12:00:00", now).unwrap(),
+            "2026-03-08 12:00:00"
+        );
+        assert!(parse_snooze_until("2026-03-01T11:00:00Z", now).is_err());
+        assert!(parse_snooze_until("2027-06-01T00:00:00Z", now).is_err());
+        assert!(parse_snooze_until("next week", now).is_err());
+    }
+
+    #[test]
+    fn test_job_stale_days_range() {
+        use crate::settings::schema::validate;
+        assert!(validate(JOB_STALE_DAYS_SETTING, "14").is_ok());
+        assert!(validate(JOB_STALE_DAYS_SETTING, "0").is_err());
+        assert!(validate(JOB_STALE_DAYS_SETTING, "1000").is_err());
+        assert!(validate(JOB_STALE_DAYS_SETTING, "two weeks").is_err());
+    }
 }
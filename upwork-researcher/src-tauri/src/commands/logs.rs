@@ -0,0 +1,58 @@
+//! Log viewer Tauri commands
+//!
+//! Lets the in-app log viewer tail recent log lines and export a redacted
+//! bundle of recent logs to attach to bug reports.
+
+use crate::logs::viewer::{self, LogsBundle};
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+fn logs_dir(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+    Ok(app_data_dir.join("logs"))
+}
+
+/// Get the most recent log lines (oldest first), capped at `MAX_TAIL_LINES`.
+///
+/// `min_level` keeps lines at or above the given level (ERROR, WARN, INFO, DEBUG, TRACE);
+/// `contains` is a case-insensitive substring filter. Lines are redacted before returning.
+#[tauri::command]
+pub async fn get_recent_logs(
+    app_handle: AppHandle,
+    lines: usize,
+    min_level: Option<String>,
+    contains: Option<String>,
+) -> Result<Vec<String>, String> {
+    let dir = logs_dir(&app_handle)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        viewer::tail_logs(&dir, lines, min_level.as_deref(), contains.as_deref())
+    })
+    .await
+    .map_err(|e| format!("Log tail task failed: {}", e))?
+}
+
+/// Export the last `days` days of logs (default 7, clamped to the 7 days retention
+/// keeps) as a redacted .zip in the temp directory. Returns the bundle path and size
+/// so the user can attach it to a bug report.
+/// The API payload debug log is only included with `include_api_debug: true`.
+#[tauri::command]
+pub async fn export_logs_bundle(
+    app_handle: AppHandle,
+    days: Option<u64>,
+    include_api_debug: Option<bool>,
+) -> Result<LogsBundle, String> {
+    let days = viewer::bundle_days(days)?;
+    let dir = logs_dir(&app_handle)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        viewer::export_logs_bundle(
+            &dir,
+            &std::env::temp_dir(),
+            days,
+            include_api_debug.unwrap_or(false),
+        )
+    })
+    .map_err(|e| format!("Log export task failed: {}", e))?
+}
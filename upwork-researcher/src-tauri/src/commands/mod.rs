@@ -2,12 +2,21 @@
 //!
 //! Organizes all Tauri commands by feature area.
 
+pub mod bulk_scoring;
+pub mod clients;
+pub mod clipboard_watch;
+pub mod drafts;
 pub mod export;
+pub mod generation_queue;
 pub mod hooks;
 pub mod import;
 pub mod job_queue;
+pub mod logs;
+pub mod presentation;
 pub mod proposals;
+pub mod score_recalc;
 pub mod scoring_feedback;
 pub mod system;
 pub mod test_data;
+pub mod variants;
 pub mod voice;
@@ -0,0 +1,119 @@
+//! Presentation mode for screen sharing
+//!
+//! A UX safety rail for demos, not a security boundary: while it is on,
+//! `get_api_key_masked` returns a fully redacted key, client names in
+//! `get_proposals`/`get_job_queue` are replaced with "Client 1", "Client 2", …,
+//! and destructive commands (`clear_api_key`, `delete_proposal`,
+//! `factory_reset`) fail with `PRESENTATION_MODE_BLOCKED`. The flag lives in
+//! memory only, so a restart always comes back with presentation mode off.
+
+use crate::errors::{AppError, ErrorCode};
+use crate::events;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Emitter, State};
+
+/// What `get_api_key_masked` returns for a stored key in presentation mode
+pub const REDACTED_API_KEY: &str = "••••••••••••";
+
+/// Managed state: whether presentation mode is on (never persisted)
+#[derive(Default)]
+pub struct PresentationModeState {
+    enabled: AtomicBool,
+}
+
+impl PresentationModeState {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Refuse `command` while presentation mode is on
+    pub fn ensure_allowed(&self, command: &str) -> Result<(), AppError> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        Err(AppError::new(
+            ErrorCode::PresentationModeBlocked,
+            format!(
+                "{} is disabled in presentation mode. Turn presentation mode off to use it.",
+                command
+            ),
+        )
+        .with_detail(serde_json::json!({ "command": command })))
+    }
+}
+
+/// Replaces client names with stable placeholders for one response: the same
+/// client (by normalized name) gets the same "Client N" label throughout.
+#[derive(Default)]
+pub struct ClientNameMasker {
+    labels: HashMap<String, String>,
+}
+
+impl ClientNameMasker {
+    pub fn mask(&mut self, name: &str) -> String {
+        let key = crate::db::queries::clients::normalize_client_name(name).unwrap_or_default();
+        let next = self.labels.len() + 1;
+        self.labels
+            .entry(key)
+            .or_insert_with(|| format!("Client {}", next))
+            .clone()
+    }
+}
+
+#[tauri::command]
+pub fn get_presentation_mode(state: State<'_, PresentationModeState>) -> bool {
+    state.is_enabled()
+}
+
+/// Turn presentation mode on or off; emits `presentation:changed` so every
+/// window can refresh masked data
+#[tauri::command]
+pub fn set_presentation_mode(
+    enabled: bool,
+    app_handle: AppHandle,
+    state: State<'_, PresentationModeState>,
+) -> Result<bool, String> {
+    state.set(enabled);
+    tracing::info!(enabled, "Presentation mode changed");
+    if let Err(e) = app_handle.emit(events::PRESENTATION_CHANGED, enabled) {
+        tracing::warn!("Failed to emit presentation mode change: {}", e);
+    }
+    Ok(enabled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocked_only_while_enabled() {
+        let state = PresentationModeState::default();
+        assert!(state.ensure_allowed("delete_proposal").is_ok());
+
+        state.set(true);
+        let err = state.ensure_allowed("delete_proposal").unwrap_err();
+        assert_eq!(err.code, ErrorCode::PresentationModeBlocked);
+        assert_eq!(
+            serde_json::to_value(err.code).unwrap(),
+            "PRESENTATION_MODE_BLOCKED"
+        );
+
+        // Reversible
+        state.set(false);
+        assert!(state.ensure_allowed("delete_proposal").is_ok());
+    }
+
+    #[test]
+    fn test_masker_labels_clients_consistently() {
+        let mut masker = ClientNameMasker::default();
+        assert_eq!(masker.mask("Acme Corp"), "Client 1");
+        assert_eq!(masker.mask("Globex"), "Client 2");
+        assert_eq!(masker.mask("acme corp."), "Client 1");
+        assert_eq!(masker.mask("Unknown Client"), "Client 3");
+    }
+}
@@ -2,13 +2,20 @@
 //!
 //! Provides Tauri commands for querying proposal history with pagination and virtualization support.
 
+use crate::ab_testing::{
+    classify_p_value, two_proportion_p_value, Significance, MIN_SIGNIFICANCE_SAMPLES,
+};
 use crate::db::queries::proposals::ProposalListItem;
 use crate::db::AppDatabase;
-use rusqlite::Connection;
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
 use tauri::State;
 use tracing::info;
 
+/// Largest page `get_proposal_history` returns; bigger requests are capped
+pub const MAX_HISTORY_PAGE_SIZE: u32 = 200;
+
 /// Paginated response with metadata (AC-4, AC-5)
 /// CR L-3: ProposalListItem now defined in db::queries::proposals (single source of truth)
 #[derive(Debug, Serialize, Deserialize)]
@@ -17,44 +24,118 @@ pub struct ProposalHistoryResponse {
     pub proposals: Vec<ProposalListItem>,
     pub total_count: u32,
     pub has_more: bool,
+    /// Cursor for the next page (`before_id`), set when `has_more`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_before_id: Option<i64>,
 }
 
 /// Internal function to query proposal history (testable without Tauri State)
+/// `before_id` switches from OFFSET to keyset pagination: the page starts right
+/// after that proposal in sort order and `offset` is ignored.
+/// `job_post_id` keeps only proposals linked to that job post.
+/// `only_favorites` keeps only pinned proposals; `favorites_first` sorts them to the top.
 fn query_proposal_history_internal(
     conn: &Connection,
     limit: u32,
     offset: u32,
+    before_id: Option<i64>,
+    job_post_id: Option<i64>,
+    only_favorites: bool,
+    favorites_first: bool,
 ) -> Result<ProposalHistoryResponse, String> {
+    let limit = limit.clamp(1, MAX_HISTORY_PAGE_SIZE);
+
     // Count total proposals
     let total_count: u32 =
-        conn.query_row("SELECT COUNT(*) as count FROM proposals", [], |row| {
-            row.get::<_, i64>(0)
-        })
+        conn.query_row(
+            "SELECT COUNT(*) as count FROM proposals
+             WHERE (?1 IS NULL OR job_post_id = ?1) AND (?2 = 0 OR is_favorite = 1)
+               AND status != 'discarded'",
+            rusqlite::params![job_post_id, only_favorites],
+            |row| row.get::<_, i64>(0),
+        )
         .map_err(|e| format!("Failed to count proposals: {}", e))? as u32;
 
+    // Keyset cursor: the sort key of the last proposal the caller has seen
+    let cursor = match before_id {
+        Some(id) => Some(
+            conn.query_row(
+                "SELECT created_at, is_favorite FROM proposals WHERE id = ?1",
+                [id],
+                |row| Ok((row.get::<_, String>(0)?, row.get::<_, bool>(1)?)),
+            )
+            .optional()
+            .map_err(|e| format!("Failed to read history cursor: {}", e))?
+            .ok_or_else(|| {
+                format!(
+                    "Proposal {} no longer exists; reload history from the first page",
+                    id
+                )
+            })?,
+        ),
+        None => None,
+    };
+
     // AC-6: Select ONLY lightweight columns (NOT generated_text, full_job_content, revision_history)
     // AC-4: Use indexed created_at column with DESC order
     // Secondary sort by id DESC for deterministic ordering when timestamps are identical
     // Story 7.1 AC-4: Include outcome_status and hook_strategy_id
-    let query = "
+    let favorites_order = if favorites_first {
+        "is_favorite DESC, "
+    } else {
+        ""
+    };
+    let mut params: Vec<SqlValue> = vec![
+        // One extra row tells whether another page follows
+        SqlValue::Integer(limit as i64 + 1),
+        SqlValue::Integer(if cursor.is_some() { 0 } else { offset as i64 }),
+        job_post_id.map_or(SqlValue::Null, SqlValue::Integer),
+        SqlValue::Integer(only_favorites as i64),
+    ];
+    // Row-value comparison against the same key the ORDER BY uses, so SQLite
+    // seeks into idx_proposals_created_at (or idx_proposals_favorite) instead
+    // of scanning and discarding OFFSET rows
+    let keyset = match cursor {
+        None => "",
+        Some((created_at, is_favorite)) => {
+            params.push(SqlValue::Text(created_at));
+            params.push(SqlValue::Integer(before_id.unwrap_or_default()));
+            if favorites_first {
+                params.push(SqlValue::Integer(is_favorite as i64));
+                "AND (is_favorite, created_at, id) < (?7, ?5, ?6)"
+            } else {
+                "AND (created_at, id) < (?5, ?6)"
+            }
+        }
+    };
+    let query = format!(
+        "
         SELECT
             id,
             SUBSTR(COALESCE(job_content, ''), 1, 100) as job_excerpt,
             SUBSTR(COALESCE(generated_text, ''), 1, 200) as preview_text,
             created_at,
             outcome_status,
-            hook_strategy_id
+            hook_strategy_id,
+            perplexity_score,
+            perplexity_flagged_count,
+            job_post_id,
+            is_favorite
         FROM proposals
-        ORDER BY created_at DESC, id DESC
-        LIMIT ? OFFSET ?
-    ";
+        WHERE (?3 IS NULL OR job_post_id = ?3) AND (?4 = 0 OR is_favorite = 1)
+          AND status != 'discarded' {}
+        ORDER BY {}created_at DESC, id DESC
+        LIMIT ?1 OFFSET ?2
+    ",
+        keyset, favorites_order
+    );
 
     let mut stmt = conn
-        .prepare(query)
+        .prepare(&query)
         .map_err(|e| format!("Failed to prepare proposal history query: {}", e))?;
 
     let proposals_iter = stmt
-        .query_map([limit as i64, offset as i64], |row| {
+        .query_map(rusqlite::params_from_iter(params), |row| {
             Ok(ProposalListItem {
                 id: row.get(0)?,
                 job_excerpt: row.get(1)?,
@@ -62,6 +143,10 @@ fn query_proposal_history_internal(
                 created_at: row.get(3)?,
                 outcome_status: row.get(4)?,
                 hook_strategy_id: row.get(5)?,
+                perplexity_score: row.get(6)?,
+                perplexity_flagged_count: row.get(7)?,
+                job_post_id: row.get(8)?,
+                is_favorite: row.get(9)?,
             })
         })
         .map_err(|e| format!("Failed to execute proposal history query: {}", e))?;
@@ -72,24 +157,43 @@ fn query_proposal_history_internal(
     }
 
     // AC-5: Determine if more pages available
-    let has_more = (offset + limit) < total_count;
+    let has_more = proposals.len() > limit as usize;
+    proposals.truncate(limit as usize);
+    let next_before_id = if has_more {
+        proposals.last().map(|p| p.id)
+    } else {
+        None
+    };
 
     Ok(ProposalHistoryResponse {
         proposals,
         total_count,
         has_more,
+        next_before_id,
     })
 }
 
 /// Get paginated proposal history for virtualized list (Story 8.7)
 ///
 /// # Arguments
-/// * `limit` - Number of proposals to return (typically 50)
+/// * `limit` - Number of proposals to return (typically 50, capped at `MAX_HISTORY_PAGE_SIZE`)
 /// * `offset` - Number of proposals to skip (for pagination)
+/// * `before_id` - Keyset cursor: `nextBeforeId` from the previous page
+///
+/// # Pagination modes
+/// Without `before_id` this is OFFSET pagination, fine for the first few pages.
+/// With `before_id` it is keyset pagination: the page starts after that proposal
+/// and `offset` is ignored. Deep pages cost the same as the first one, and rows
+/// added while paging don't shift later pages. Every response carries
+/// `nextBeforeId` when `hasMore`, so callers can use either mode. `totalCount` is
+/// always the size of the whole (filtered) history.
 ///
 /// # Returns
 /// ProposalHistoryResponse with lightweight proposal items, total count, and has_more flag
 ///
+/// `job_post_id` optionally limits the history to proposals for one job post.
+/// `only_favorites` / `favorites_first` filter to or pin favorited proposals (default off).
+///
 /// # Performance
 /// AC-4: Uses idx_proposals_created_at index for fast sorting (<500ms with 100+ proposals)
 /// AC-6: Selects only lightweight columns (id, job_excerpt, preview_text, created_at)
@@ -100,32 +204,48 @@ fn query_proposal_history_internal(
 ///   limit: 50,
 ///   offset: 0,
 /// });
+/// const next = await invoke<ProposalHistoryResponse>('get_proposal_history', {
+///   limit: 50,
+///   offset: 0,
+///   beforeId: result.nextBeforeId,
+/// });
 /// ```
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn get_proposal_history(
     db: State<'_, AppDatabase>,
     limit: u32,
     offset: u32,
+    before_id: Option<i64>,
+    job_post_id: Option<i64>,
+    only_favorites: Option<bool>,
+    favorites_first: Option<bool>,
 ) -> Result<ProposalHistoryResponse, String> {
+    let _timer = crate::command_metrics::time("get_proposal_history");
     let db = db.get()?;
     let start = std::time::Instant::now();
 
-    // Get connection
-    let conn_guard = db
-        .conn
-        .lock()
-        .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
-
-    // Query using internal function
-    let response = query_proposal_history_internal(&conn_guard, limit, offset)?;
+    // Query on a read connection (doesn't wait behind long writes)
+    let response = db.read(|conn| {
+        query_proposal_history_internal(
+            conn,
+            limit,
+            offset,
+            before_id,
+            job_post_id,
+            only_favorites.unwrap_or(false),
+            favorites_first.unwrap_or(false),
+        )
+    })?;
 
     // AC-4: Log query performance (NFR-17: <500ms)
     let elapsed = start.elapsed();
     info!(
-        "Proposal history query: {:?} ({} proposals, offset {})",
+        "Proposal history query: {:?} ({} proposals, offset {}, before_id {:?})",
         elapsed,
         response.proposals.len(),
-        offset
+        offset,
+        before_id
     );
 
     Ok(response)
@@ -134,39 +254,40 @@ pub async fn get_proposal_history(
 /// Search proposals with filters and pagination (Story 7.3)
 ///
 /// Supports text search (job content + proposal text), outcome status filter,
-/// date range filter, and hook strategy filter. All filters are AND-combined.
+/// date range filter, hook strategy filter, and job post filter. All filters are AND-combined.
 ///
 /// Returns same ProposalHistoryResponse shape as get_proposal_history.
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn search_proposals(
     db: State<'_, AppDatabase>,
     search_text: Option<String>,
     outcome_status: Option<String>,
     date_range_days: Option<u32>,
     hook_strategy: Option<String>,
+    job_post_id: Option<i64>,
     limit: u32,
     offset: u32,
 ) -> Result<ProposalHistoryResponse, String> {
+    let _timer = crate::command_metrics::time("search_proposals");
     let db = db.get()?;
     let start = std::time::Instant::now();
     // CR R2 M-1: Cap limit to prevent unbounded result sets
     let limit = limit.min(500);
 
-    let conn_guard = db
-        .conn
-        .lock()
-        .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
-
-    let result = crate::db::queries::proposals::search_proposals(
-        &conn_guard,
-        search_text.as_deref(),
-        outcome_status.as_deref(),
-        date_range_days,
-        hook_strategy.as_deref(),
-        limit,
-        offset,
-    )
-    .map_err(|e| format!("Failed to search proposals: {}", e))?;
+    let result = db.read(|conn| {
+        crate::db::queries::proposals::search_proposals(
+            conn,
+            search_text.as_deref(),
+            outcome_status.as_deref(),
+            date_range_days,
+            hook_strategy.as_deref(),
+            job_post_id,
+            limit,
+            offset,
+        )
+        .map_err(|e| format!("Failed to search proposals: {}", e))
+    })?;
 
     let elapsed = start.elapsed();
     info!(
@@ -181,6 +302,7 @@ pub async fn search_proposals(
         proposals: result.proposals,
         total_count: result.total_count,
         has_more: result.has_more,
+        next_before_id: None,
     })
 }
 
@@ -195,14 +317,76 @@ pub async fn get_proposal_detail(
 ) -> Result<crate::db::queries::proposals::ProposalDetail, String> {
     let db = db.get()?;
 
-    let conn_guard = db
-        .conn
-        .lock()
-        .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
+    db.read(|conn| {
+        crate::db::queries::proposals::get_proposal_detail(conn, id)
+            .map_err(|e| format!("Failed to get proposal detail: {}", e))?
+            .ok_or_else(|| format!("Proposal not found: {}", id))
+    })
+}
+
+/// Get how a proposal was generated: model, humanization intensity, hook strategy,
+/// and voice profile version. Proposals saved before this was recorded report "unknown".
+#[tauri::command]
+pub async fn get_generation_metadata(
+    db: State<'_, AppDatabase>,
+    proposal_id: i64,
+) -> Result<crate::db::queries::generation_metadata::GenerationMetadata, String> {
+    let db = db.get()?;
+
+    db.read(|conn| {
+        if !crate::db::queries::safety_overrides::proposal_exists(conn, proposal_id)
+            .map_err(|e| format!("Failed to get generation metadata: {}", e))?
+        {
+            return Err(format!("Proposal not found: {}", proposal_id));
+        }
+
+        crate::db::queries::generation_metadata::get_generation_metadata(conn, proposal_id)
+            .map_err(|e| format!("Failed to get generation metadata: {}", e))
+    })
+}
+
+/// Get the prompt context a proposal was generated with: template version, hash of
+/// the assembled system prompt and parameter values. None for proposals generated
+/// before prompt versioning.
+#[tauri::command]
+pub async fn get_generation_context(
+    db: State<'_, AppDatabase>,
+    proposal_id: i64,
+) -> Result<Option<crate::claude::GenerationContext>, String> {
+    let db = db.get()?;
+
+    db.read(|conn| {
+        if !crate::db::queries::safety_overrides::proposal_exists(conn, proposal_id)
+            .map_err(|e| format!("Failed to get generation context: {}", e))?
+        {
+            return Err(format!("Proposal not found: {}", proposal_id));
+        }
+
+        crate::db::queries::generation_metadata::get_generation_context(conn, proposal_id)
+            .map_err(|e| format!("Failed to get generation context: {}", e))
+    })
+}
+
+/// Pre-send checklist for a saved proposal: length, perplexity, client name,
+/// skill and hidden-need coverage, humanization, and safety overrides, each
+/// pass/warn/fail/unknown. Local data only, no API calls.
+#[tauri::command]
+pub async fn get_presend_checklist(
+    db: State<'_, AppDatabase>,
+    proposal_id: i64,
+) -> Result<crate::presend::PresendChecklist, String> {
+    let db = db.get()?;
 
-    crate::db::queries::proposals::get_proposal_detail(&conn_guard, id)
-        .map_err(|e| format!("Failed to get proposal detail: {}", e))?
-        .ok_or_else(|| format!("Proposal not found: {}", id))
+    let inputs = db.read(|conn| {
+        crate::presend::load_presend_inputs(conn, proposal_id)
+            .map_err(|e| format!("Failed to build pre-send checklist: {}", e))?
+            .ok_or_else(|| format!("Proposal not found: {}", proposal_id))
+    })?;
+    // Threshold for the intensity the proposal was generated at, if recorded
+    let threshold =
+        crate::get_safety_threshold_for_intensity_internal(db, inputs.intensity.as_deref())?;
+
+    Ok(crate::presend::build_presend_checklist(&inputs, threshold))
 }
 
 /// Get distinct hook strategy IDs from proposals (Story 7.3)
@@ -215,13 +399,10 @@ pub async fn get_distinct_hook_strategies(
 ) -> Result<Vec<String>, String> {
     let db = db.get()?;
 
-    let conn_guard = db
-        .conn
-        .lock()
-        .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
-
-    crate::db::queries::proposals::get_distinct_hook_strategies(&conn_guard)
-        .map_err(|e| format!("Failed to get hook strategies: {}", e))
+    db.read(|conn| {
+        crate::db::queries::proposals::get_distinct_hook_strategies(conn)
+            .map_err(|e| format!("Failed to get hook strategies: {}", e))
+    })
 }
 
 /// Update proposal outcome status (Story 7.1 AC-1, Story 7.2 AC-2)
@@ -230,6 +411,7 @@ pub async fn get_distinct_hook_strategies(
 /// Called from OutcomeDropdown in both list and detail views.
 #[tauri::command]
 pub async fn update_proposal_outcome(
+    app_handle: tauri::AppHandle,
     db: State<'_, AppDatabase>,
     proposal_id: i64,
     outcome_status: String,
@@ -241,12 +423,149 @@ pub async fn update_proposal_outcome(
         .lock()
         .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
 
-    crate::db::queries::proposals::update_proposal_outcome(
+    let updated = crate::db::queries::proposals::update_proposal_outcome(
         &conn_guard,
         proposal_id,
         &outcome_status,
     )
-    .map_err(|e| format!("Failed to update proposal outcome: {}", e))
+    .map_err(|e| format!("Failed to update proposal outcome: {}", e))?;
+
+    // Milestone alerts are best-effort and never fail the update
+    if updated {
+        crate::notifications::after_outcome_update(
+            &app_handle,
+            &conn_guard,
+            proposal_id,
+            &outcome_status,
+        );
+    }
+    Ok(updated)
+}
+
+/// Result of `record_proposal_copied`
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalCopiedResult {
+    pub proposal_id: i64,
+    pub copied_at: String,
+    /// Proposals edited count after the increment (Story 8.6)
+    pub proposals_edited: i32,
+}
+
+/// Record that a proposal was copied for sending: stamps `copied_at` (the start
+/// of the outcome funnel) and increments the proposals edited count.
+///
+/// With `copy_to_clipboard`, the backend also writes the text to the clipboard
+/// so copying and tracking happen together: `text` if given (e.g. unsaved
+/// edits), otherwise the saved proposal text. Copying the same proposal again
+/// just moves `copied_at` forward.
+#[tauri::command]
+pub async fn record_proposal_copied(
+    app_handle: tauri::AppHandle,
+    db: State<'_, AppDatabase>,
+    proposal_id: i64,
+    copy_to_clipboard: bool,
+    text: Option<String>,
+) -> Result<ProposalCopiedResult, String> {
+    let db = db.get()?;
+
+    let conn_guard = db
+        .conn
+        .lock()
+        .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
+
+    let proposal = crate::db::queries::proposals::get_proposal(&conn_guard, proposal_id)
+        .map_err(|e| format!("Failed to load proposal: {}", e))?
+        .ok_or_else(|| format!("Proposal not found: {}", proposal_id))?;
+
+    if copy_to_clipboard {
+        use tauri::Manager;
+        use tauri_plugin_clipboard_manager::ClipboardExt;
+
+        let content = text.unwrap_or(proposal.generated_text);
+        // Keep the clipboard watcher from offering our own write for import
+        if let Some(watch) =
+            app_handle.try_state::<crate::commands::clipboard_watch::ClipboardWatchState>()
+        {
+            if let Err(e) = watch.mark_self_write() {
+                tracing::warn!("Failed to mark clipboard write: {}", e);
+            }
+        }
+        app_handle
+            .clipboard()
+            .write_text(content)
+            .map_err(|e| format!("Failed to copy to clipboard: {}", e))?;
+    }
+
+    record_proposal_copied_internal(&conn_guard, proposal_id)
+}
+
+fn record_proposal_copied_internal(
+    conn: &Connection,
+    proposal_id: i64,
+) -> Result<ProposalCopiedResult, String> {
+    let copied_at = crate::db::queries::proposals::mark_proposal_copied(conn, proposal_id)
+        .map_err(|e| format!("Failed to record proposal copy: {}", e))?
+        .ok_or_else(|| format!("Proposal not found: {}", proposal_id))?;
+    let proposals_edited = crate::db::queries::settings::increment_proposals_edited(conn)
+        .map_err(|e| format!("Failed to increment proposals edited count: {}", e))?;
+
+    info!(proposal_id, "Proposal copied");
+    Ok(ProposalCopiedResult {
+        proposal_id,
+        copied_at,
+        proposals_edited,
+    })
+}
+
+/// Reassign a saved proposal's hook strategy, e.g. when a manually written
+/// Contrarian hook was recorded as A/B-assigned Social Proof.
+///
+/// The strategy must exist. The proposal is marked as manually corrected and no
+/// longer counts as A/B assigned, since the experiment didn't pick that strategy.
+/// Returns the recomputed strategy effectiveness.
+#[tauri::command]
+pub async fn update_proposal_hook_strategy(
+    db: State<'_, AppDatabase>,
+    proposal_id: i64,
+    hook_strategy_id: String,
+) -> Result<Vec<StrategyEffectivenessData>, String> {
+    let db = db.get()?;
+
+    let conn_guard = db
+        .conn
+        .lock()
+        .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
+
+    update_proposal_hook_strategy_internal(&conn_guard, proposal_id, &hook_strategy_id)?;
+    get_strategy_effectiveness_internal(&conn_guard, false)
+}
+
+fn update_proposal_hook_strategy_internal(
+    conn: &Connection,
+    proposal_id: i64,
+    hook_strategy_id: &str,
+) -> Result<(), String> {
+    let hook_strategy_id = hook_strategy_id.trim();
+    let exists =
+        crate::db::queries::hook_strategies::hook_strategy_name_exists(conn, hook_strategy_id)
+            .map_err(|e| format!("Failed to look up hook strategy: {}", e))?;
+    if !exists {
+        return Err(format!("Unknown hook strategy: {}", hook_strategy_id));
+    }
+
+    let updated = crate::db::queries::proposals::update_proposal_hook_strategy(
+        conn,
+        proposal_id,
+        hook_strategy_id,
+    )
+    .map_err(|e| format!("Failed to update hook strategy: {}", e))?;
+    if !updated {
+        return Err(format!("Proposal {} not found", proposal_id));
+    }
+
+    info!(proposal_id, hook_strategy = %hook_strategy_id, "Hook strategy manually corrected");
+    Ok(())
 }
 
 // =========================================================================
@@ -265,13 +584,10 @@ pub async fn get_proposal_analytics_summary(
 ) -> Result<crate::db::queries::proposals::AnalyticsSummary, String> {
     let db = db.get()?;
 
-    let conn_guard = db
-        .conn
-        .lock()
-        .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
-
-    crate::db::queries::proposals::get_proposal_analytics_summary(&conn_guard)
-        .map_err(|e| format!("Failed to get analytics summary: {}", e))
+    db.read(|conn| {
+        crate::db::queries::proposals::get_proposal_analytics_summary(conn)
+            .map_err(|e| format!("Failed to get analytics summary: {}", e))
+    })
 }
 
 /// Get outcome distribution for bar chart (Story 7.5 AC-2)
@@ -284,13 +600,10 @@ pub async fn get_outcome_distribution(
 ) -> Result<Vec<crate::db::queries::proposals::OutcomeCount>, String> {
     let db = db.get()?;
 
-    let conn_guard = db
-        .conn
-        .lock()
-        .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
-
-    crate::db::queries::proposals::get_outcome_distribution(&conn_guard)
-        .map_err(|e| format!("Failed to get outcome distribution: {}", e))
+    db.read(|conn| {
+        crate::db::queries::proposals::get_outcome_distribution(conn)
+            .map_err(|e| format!("Failed to get outcome distribution: {}", e))
+    })
 }
 
 /// Get response rate by hook strategy (Story 7.5 AC-3)
@@ -304,13 +617,10 @@ pub async fn get_response_rate_by_strategy(
 ) -> Result<Vec<crate::db::queries::proposals::StrategyPerformance>, String> {
     let db = db.get()?;
 
-    let conn_guard = db
-        .conn
-        .lock()
-        .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
-
-    crate::db::queries::proposals::get_response_rate_by_strategy(&conn_guard)
-        .map_err(|e| format!("Failed to get response rate by strategy: {}", e))
+    db.read(|conn| {
+        crate::db::queries::proposals::get_response_rate_by_strategy(conn)
+            .map_err(|e| format!("Failed to get response rate by strategy: {}", e))
+    })
 }
 
 /// Get weekly proposal activity (Story 7.5 AC-4)
@@ -322,19 +632,44 @@ pub async fn get_response_rate_by_strategy(
 /// * `weeks` - Number of weeks to look back (default 12)
 #[tauri::command]
 pub async fn get_weekly_activity(
+    app_handle: tauri::AppHandle,
     db: State<'_, AppDatabase>,
     weeks: Option<u32>,
 ) -> Result<Vec<crate::db::queries::proposals::WeeklyActivity>, String> {
     let db = db.get()?;
     let weeks = weeks.unwrap_or(12);
 
-    let conn_guard = db
+    let activity = db.read(|conn| {
+        crate::db::queries::proposals::get_weekly_activity(conn, weeks)
+            .map_err(|e| format!("Failed to get weekly activity: {}", e))
+    })?;
+    crate::notifications::after_weekly_analytics(&app_handle, db);
+    Ok(activity)
+}
+
+/// Weekly digest for one ISO week (UTC), see `digest`
+///
+/// # Arguments
+/// * `week_offset` - 0 for the current week, -1 for last week, and so on
+/// * `force_regenerate` - Recompute a stored digest instead of returning it
+#[tauri::command]
+pub async fn generate_weekly_digest(
+    db: State<'_, AppDatabase>,
+    week_offset: i32,
+    force_regenerate: Option<bool>,
+) -> Result<crate::digest::DigestResult, String> {
+    let db = db.get()?;
+    let conn = db
         .conn
         .lock()
-        .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
+        .map_err(|e| format!("Database lock error: {}", e))?;
 
-    crate::db::queries::proposals::get_weekly_activity(&conn_guard, weeks)
-        .map_err(|e| format!("Failed to get weekly activity: {}", e))
+    crate::digest::generate_weekly_digest(
+        &conn,
+        week_offset,
+        force_regenerate.unwrap_or(false),
+        chrono::Utc::now(),
+    )
 }
 
 // =========================================================================
@@ -351,6 +686,14 @@ pub struct StrategyEffectivenessData {
     pub won: i64,
     pub response_rate: f32,
     pub avg_score: f32,
+    /// Proposals whose strategy was manually corrected (always manual rows)
+    pub corrected: i64,
+    /// Proposals with an outcome recorded (not pending or just submitted)
+    pub sample_size: i64,
+    /// Two-proportion z-test p-value against the baseline; None for the baseline
+    /// itself and for strategies with insufficient data
+    pub p_value: Option<f64>,
+    pub significance: Significance,
 }
 
 /// Get strategy effectiveness grouped by (hook_strategy_id, ab_assigned) (Story 10.4: AC-4)
@@ -358,23 +701,30 @@ pub struct StrategyEffectivenessData {
 /// Response rate = won / total where won = ['hired', 'interview', 'response_received']
 /// Average score: hired=3, interview=2, response_received=1, others=0
 /// Sorted by response_rate DESC.
+///
+/// Significance: within each assignment type (A/B vs manual), the strategy with
+/// the best won / sample_size among those with at least `MIN_SIGNIFICANCE_SAMPLES`
+/// recorded outcomes is the baseline; every other strategy with enough outcomes
+/// gets a two-proportion z-test p-value against it.
+///
+/// `exclude_corrected` leaves out proposals whose strategy was relabeled with
+/// `update_proposal_hook_strategy`. Corrections never count as A/B assigned.
 #[tauri::command]
 pub async fn get_strategy_effectiveness(
     db: State<'_, AppDatabase>,
+    exclude_corrected: Option<bool>,
 ) -> Result<Vec<StrategyEffectivenessData>, String> {
     let db = db.get()?;
 
-    let conn_guard = db
-        .conn
-        .lock()
-        .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
-
-    get_strategy_effectiveness_internal(&conn_guard)
-        .map_err(|e| format!("Failed to get strategy effectiveness: {}", e))
+    db.read(|conn| {
+        get_strategy_effectiveness_internal(conn, exclude_corrected.unwrap_or(false))
+            .map_err(|e| format!("Failed to get strategy effectiveness: {}", e))
+    })
 }
 
 fn get_strategy_effectiveness_internal(
     conn: &Connection,
+    exclude_corrected: bool,
 ) -> Result<Vec<StrategyEffectivenessData>, String> {
     let query = "
         SELECT
@@ -389,9 +739,13 @@ fn get_strategy_effectiveness_internal(
                 WHEN outcome_status = 'interview' THEN 2.0
                 WHEN outcome_status = 'response_received' THEN 1.0
                 ELSE 0.0
-            END) as avg_score
+            END) as avg_score,
+            SUM(CASE WHEN outcome_status NOT IN ('pending', 'submitted') THEN 1 ELSE 0 END) as sample_size,
+            SUM(hook_strategy_corrected) as corrected
         FROM proposals
         WHERE hook_strategy_id IS NOT NULL
+          AND status != 'discarded'
+          AND (?1 = 0 OR hook_strategy_corrected = 0)
         GROUP BY hook_strategy_id, ab_assigned
         ORDER BY response_rate DESC
     ";
@@ -401,7 +755,7 @@ fn get_strategy_effectiveness_internal(
         .map_err(|e| format!("Failed to prepare strategy effectiveness query: {}", e))?;
 
     let rows = stmt
-        .query_map([], |row| {
+        .query_map([exclude_corrected], |row| {
             let ab_assigned_int: i64 = row.get(1)?;
             let total: i64 = row.get(2)?;
             let won: i64 = row.get(3)?;
@@ -414,6 +768,10 @@ fn get_strategy_effectiveness_internal(
                 won,
                 response_rate: response_rate as f32,
                 avg_score: avg_score as f32,
+                corrected: row.get(7)?,
+                sample_size: row.get(6)?,
+                p_value: None,
+                significance: Significance::InsufficientData,
             })
         })
         .map_err(|e| format!("Failed to execute strategy effectiveness query: {}", e))?;
@@ -425,9 +783,47 @@ fn get_strategy_effectiveness_internal(
         );
     }
 
+    assign_significance(&mut results);
     Ok(results)
 }
 
+/// Fill in `p_value` and `significance`, comparing each strategy with the best
+/// one of the same assignment type
+fn assign_significance(rows: &mut [StrategyEffectivenessData]) {
+    let decided_rate = |row: &StrategyEffectivenessData| row.won as f64 / row.sample_size as f64;
+
+    for ab_assigned in [true, false] {
+        let baseline = rows
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| {
+                r.ab_assigned == ab_assigned && r.sample_size >= MIN_SIGNIFICANCE_SAMPLES
+            })
+            .max_by(|(_, a), (_, b)| {
+                decided_rate(a)
+                    .total_cmp(&decided_rate(b))
+                    .then(a.sample_size.cmp(&b.sample_size))
+            })
+            .map(|(i, r)| (i, r.won, r.sample_size));
+        let Some((baseline_index, baseline_won, baseline_n)) = baseline else {
+            continue;
+        };
+
+        for (i, row) in rows.iter_mut().enumerate() {
+            if row.ab_assigned != ab_assigned || row.sample_size < MIN_SIGNIFICANCE_SAMPLES {
+                continue;
+            }
+            if i == baseline_index {
+                row.significance = Significance::Baseline;
+                continue;
+            }
+            row.p_value =
+                two_proportion_p_value(row.won, row.sample_size, baseline_won, baseline_n);
+            row.significance = classify_p_value(row.p_value);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -445,7 +841,8 @@ mod tests {
         let db = create_test_db();
         let conn = db.conn.lock().unwrap();
 
-        let result = query_proposal_history_internal(&conn, 50, 0).unwrap();
+        let result =
+            query_proposal_history_internal(&conn, 50, 0, None, None, false, false).unwrap();
 
         assert_eq!(result.proposals.len(), 0);
         assert_eq!(result.total_count, 0);
@@ -469,13 +866,15 @@ mod tests {
         }
 
         // Page 1: limit 50, offset 0
-        let page1 = query_proposal_history_internal(&conn, 50, 0).unwrap();
+        let page1 =
+            query_proposal_history_internal(&conn, 50, 0, None, None, false, false).unwrap();
         assert_eq!(page1.proposals.len(), 50);
         assert_eq!(page1.total_count, 75);
         assert!(page1.has_more); // 75 - 50 = 25 remaining
 
         // Page 2: limit 50, offset 50
-        let page2 = query_proposal_history_internal(&conn, 50, 50).unwrap();
+        let page2 =
+            query_proposal_history_internal(&conn, 50, 50, None, None, false, false).unwrap();
         assert_eq!(page2.proposals.len(), 25); // Only 25 remaining
         assert_eq!(page2.total_count, 75);
         assert!(!page2.has_more); // No more pages
@@ -491,7 +890,8 @@ mod tests {
 
         insert_proposal(&conn, &job_content, &generated_text, None).unwrap();
 
-        let result = query_proposal_history_internal(&conn, 50, 0).unwrap();
+        let result =
+            query_proposal_history_internal(&conn, 50, 0, None, None, false, false).unwrap();
 
         assert_eq!(result.proposals.len(), 1);
         let proposal = &result.proposals[0];
@@ -513,7 +913,8 @@ mod tests {
         let id2 = insert_proposal(&conn, "Job 2", "Text 2", None).unwrap();
         let id3 = insert_proposal(&conn, "Job 3", "Text 3", None).unwrap();
 
-        let result = query_proposal_history_internal(&conn, 50, 0).unwrap();
+        let result =
+            query_proposal_history_internal(&conn, 50, 0, None, None, false, false).unwrap();
 
         // AC-4: Should be ordered by created_at DESC (newest first)
         // For same-timestamp inserts, expect reverse ID order
@@ -540,7 +941,8 @@ mod tests {
         }
 
         let start = std::time::Instant::now();
-        let result = query_proposal_history_internal(&conn, 50, 0).unwrap();
+        let result =
+            query_proposal_history_internal(&conn, 50, 0, None, None, false, false).unwrap();
         let elapsed = start.elapsed();
 
         // AC-4: Query should complete in <500ms (NFR-17)
@@ -566,19 +968,23 @@ mod tests {
         }
 
         // Test has_more = true (offset + limit < total_count)
-        let result1 = query_proposal_history_internal(&conn, 50, 0).unwrap();
+        let result1 =
+            query_proposal_history_internal(&conn, 50, 0, None, None, false, false).unwrap();
         assert!(result1.has_more); // 0 + 50 < 100
 
         // Test has_more = true (offset + limit < total_count)
-        let result2 = query_proposal_history_internal(&conn, 50, 25).unwrap();
+        let result2 =
+            query_proposal_history_internal(&conn, 50, 25, None, None, false, false).unwrap();
         assert!(result2.has_more); // 25 + 50 < 100
 
         // Test has_more = false (offset + limit >= total_count)
-        let result3 = query_proposal_history_internal(&conn, 50, 50).unwrap();
+        let result3 =
+            query_proposal_history_internal(&conn, 50, 50, None, None, false, false).unwrap();
         assert!(!result3.has_more); // 50 + 50 >= 100
 
         // Test has_more = false (offset + limit > total_count)
-        let result4 = query_proposal_history_internal(&conn, 50, 75).unwrap();
+        let result4 =
+            query_proposal_history_internal(&conn, 50, 75, None, None, false, false).unwrap();
         assert!(!result4.has_more); // 75 + 50 > 100
     }
 
@@ -590,7 +996,8 @@ mod tests {
         // Insert proposal with empty job_content (edge case)
         insert_proposal(&conn, "", "Test generated text", None).unwrap();
 
-        let result = query_proposal_history_internal(&conn, 50, 0).unwrap();
+        let result =
+            query_proposal_history_internal(&conn, 50, 0, None, None, false, false).unwrap();
 
         assert_eq!(result.proposals.len(), 1);
         assert_eq!(result.proposals[0].job_excerpt, ""); // Empty string truncation
@@ -609,7 +1016,8 @@ mod tests {
         // Insert proposal (default outcome_status = 'pending')
         insert_proposal(&conn, "Test job", "Test text", None).unwrap();
 
-        let result = query_proposal_history_internal(&conn, 50, 0).unwrap();
+        let result =
+            query_proposal_history_internal(&conn, 50, 0, None, None, false, false).unwrap();
 
         assert_eq!(result.proposals.len(), 1);
         assert_eq!(
@@ -637,7 +1045,8 @@ mod tests {
         // Insert proposal without hook strategy
         insert_proposal(&conn, "Job 2", "Text 2", None).unwrap();
 
-        let result = query_proposal_history_internal(&conn, 50, 0).unwrap();
+        let result =
+            query_proposal_history_internal(&conn, 50, 0, None, None, false, false).unwrap();
 
         assert_eq!(result.proposals.len(), 2);
 
@@ -673,7 +1082,7 @@ mod tests {
         let db = create_test_db();
         let conn = db.conn.lock().unwrap();
 
-        let result = get_strategy_effectiveness_internal(&conn).unwrap();
+        let result = get_strategy_effectiveness_internal(&conn, false).unwrap();
         assert!(result.is_empty(), "Should return empty for no proposals");
     }
 
@@ -693,7 +1102,7 @@ mod tests {
         conn.execute("UPDATE proposals SET outcome_status='hired' WHERE id=2", []).unwrap();
         // id=3 stays 'pending'
 
-        let results = get_strategy_effectiveness_internal(&conn).unwrap();
+        let results = get_strategy_effectiveness_internal(&conn, false).unwrap();
         assert_eq!(results.len(), 1, "Should have one row for (social_proof, ab_assigned=true)");
 
         let row = &results[0];
@@ -719,7 +1128,7 @@ mod tests {
         conn.execute("UPDATE proposals SET outcome_status='response_received' WHERE id=1", []).unwrap();
         conn.execute("UPDATE proposals SET outcome_status='hired' WHERE id=2", []).unwrap();
 
-        let results = get_strategy_effectiveness_internal(&conn).unwrap();
+        let results = get_strategy_effectiveness_internal(&conn, false).unwrap();
 
         // Should produce 2 rows: one for (contrarian, ab_assigned=1) and one for (contrarian, ab_assigned=0)
         assert_eq!(results.len(), 2, "A/B and Manual should produce separate rows for same strategy");
@@ -733,4 +1142,251 @@ mod tests {
         assert!((manual_row.avg_score - 3.0_f32).abs() < 1e-4, "Manual hired should have avg_score 3.0");
         assert!((ab_row.avg_score - 1.0_f32).abs() < 1e-4, "A/B response_received should have avg_score 1.0");
     }
+
+    #[test]
+    fn test_strategy_effectiveness_significance() {
+        use crate::db::queries::proposals::insert_proposal_with_ab_context;
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        // (strategy, won, lost, still pending)
+        let cohorts = [
+            ("social_proof", 25, 25, 5),
+            ("contrarian", 10, 40, 0),
+            ("question_based", 22, 28, 0),
+            ("problem_aware", 1, 0, 9),
+        ];
+        for (strategy, won, lost, pending) in cohorts {
+            let outcomes = std::iter::repeat_n("hired", won)
+                .chain(std::iter::repeat_n("rejected", lost))
+                .chain(std::iter::repeat_n("pending", pending));
+            for outcome in outcomes {
+                let id = insert_proposal_with_ab_context(
+                    &conn,
+                    "job",
+                    "text",
+                    None,
+                    Some(strategy),
+                    None,
+                    true,
+                    Some(0.25),
+                )
+                .unwrap();
+                conn.execute(
+                    "UPDATE proposals SET outcome_status = ?1 WHERE id = ?2",
+                    rusqlite::params![outcome, id],
+                )
+                .unwrap();
+            }
+        }
+
+        let results = get_strategy_effectiveness_internal(&conn, false).unwrap();
+        let row = |id: &str| results.iter().find(|r| r.hook_strategy_id == id).unwrap();
+
+        let baseline = row("social_proof");
+        assert_eq!(baseline.sample_size, 50);
+        assert_eq!(baseline.significance, Significance::Baseline);
+        assert_eq!(baseline.p_value, None);
+
+        // 20% vs 50%: real difference
+        let contrarian = row("contrarian");
+        assert_eq!(contrarian.significance, Significance::Significant);
+        assert!(contrarian.p_value.unwrap() < 0.01);
+
+        // 44% vs 50%: could be noise
+        let question = row("question_based");
+        assert_eq!(question.significance, Significance::NotSignificant);
+        assert!(question.p_value.unwrap() > 0.05);
+
+        // 100% response rate from a single outcome is not evidence of anything
+        let problem = row("problem_aware");
+        assert_eq!(problem.sample_size, 1);
+        assert_eq!(problem.significance, Significance::InsufficientData);
+        assert_eq!(problem.p_value, None);
+    }
+
+    #[test]
+    fn test_update_proposal_hook_strategy_corrects_ab_assignment() {
+        use crate::db::queries::proposals::insert_proposal_with_ab_context;
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let id = insert_proposal_with_ab_context(
+            &conn,
+            "job1",
+            "text1",
+            None,
+            Some("Social Proof"),
+            None,
+            true,
+            Some(0.5),
+        )
+        .unwrap();
+        insert_proposal_with_ab_context(
+            &conn,
+            "job2",
+            "text2",
+            None,
+            Some("Social Proof"),
+            None,
+            true,
+            Some(0.5),
+        )
+        .unwrap();
+
+        // Unknown strategy and unknown proposal are rejected without changes
+        assert!(update_proposal_hook_strategy_internal(&conn, id, "Flattery").is_err());
+        assert!(update_proposal_hook_strategy_internal(&conn, 9999, "Contrarian").is_err());
+
+        update_proposal_hook_strategy_internal(&conn, id, "Contrarian").unwrap();
+        let (strategy, ab_assigned, weight): (String, bool, Option<f64>) = conn
+            .query_row(
+                "SELECT hook_strategy_id, ab_assigned, ab_weight_at_assignment
+                 FROM proposals WHERE id = ?1",
+                [id],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(strategy, "Contrarian");
+        assert!(!ab_assigned);
+        assert_eq!(weight, None);
+
+        // The correction moved out of the A/B Social Proof row into a manual Contrarian row
+        let results = get_strategy_effectiveness_internal(&conn, false).unwrap();
+        let social = results
+            .iter()
+            .find(|r| r.hook_strategy_id == "Social Proof")
+            .unwrap();
+        assert!(social.ab_assigned);
+        assert_eq!(social.total, 1);
+        let contrarian = results
+            .iter()
+            .find(|r| r.hook_strategy_id == "Contrarian")
+            .unwrap();
+        assert!(!contrarian.ab_assigned);
+        assert_eq!(contrarian.corrected, 1);
+
+        let excluded = get_strategy_effectiveness_internal(&conn, true).unwrap();
+        assert_eq!(excluded.len(), 1);
+        assert_eq!(excluded[0].hook_strategy_id, "Social Proof");
+    }
+
+    #[test]
+    fn test_record_proposal_copied_increments_edited_count() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        let id = insert_proposal(&conn, "Job", "Text", None).unwrap();
+
+        let first = record_proposal_copied_internal(&conn, id).unwrap();
+        assert_eq!(first.proposals_edited, 1);
+        // Copying twice is fine and counts again
+        let second = record_proposal_copied_internal(&conn, id).unwrap();
+        assert_eq!(second.proposals_edited, 2);
+
+        // Unknown proposal fails without touching the counter
+        assert!(record_proposal_copied_internal(&conn, 9999).is_err());
+        assert_eq!(
+            crate::db::queries::settings::get_proposals_edited_count(&conn).unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_history_favorites_filter_and_pin() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let favorite = insert_proposal(&conn, "Template job", "Template text", None).unwrap();
+        for i in 0..3 {
+            insert_proposal(&conn, &format!("Job {}", i), "Text", None).unwrap();
+        }
+        crate::db::queries::proposals::toggle_proposal_favorite(&conn, favorite).unwrap();
+
+        let only = query_proposal_history_internal(&conn, 50, 0, None, None, true, false).unwrap();
+        assert_eq!(only.total_count, 1);
+        assert_eq!(only.proposals[0].id, favorite);
+        assert!(only.proposals[0].is_favorite);
+
+        let pinned =
+            query_proposal_history_internal(&conn, 50, 0, None, None, false, true).unwrap();
+        assert_eq!(pinned.total_count, 4);
+        assert_eq!(pinned.proposals[0].id, favorite);
+
+        // Default order is unchanged: newest first, favorite (oldest) last
+        let plain =
+            query_proposal_history_internal(&conn, 50, 0, None, None, false, false).unwrap();
+        assert_eq!(plain.proposals.last().unwrap().id, favorite);
+    }
+
+    #[test]
+    fn test_history_keyset_pages_match_offset_order() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        for i in 0..75 {
+            insert_proposal(&conn, &format!("Job {}", i), "Text", None).unwrap();
+        }
+
+        let all = query_proposal_history_internal(&conn, 100, 0, None, None, false, false).unwrap();
+        let mut walked = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page =
+                query_proposal_history_internal(&conn, 20, 0, cursor, None, false, false).unwrap();
+            assert_eq!(page.total_count, 75);
+            walked.extend(page.proposals.iter().map(|p| p.id));
+            assert_eq!(page.has_more, page.next_before_id.is_some());
+            cursor = page.next_before_id;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        let expected: Vec<i64> = all.proposals.iter().map(|p| p.id).collect();
+        assert_eq!(walked, expected);
+    }
+
+    #[test]
+    fn test_history_keyset_with_favorites_first() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        let mut ids = Vec::new();
+        for i in 0..6 {
+            ids.push(insert_proposal(&conn, &format!("Job {}", i), "Text", None).unwrap());
+        }
+        crate::db::queries::proposals::toggle_proposal_favorite(&conn, ids[0]).unwrap();
+        crate::db::queries::proposals::toggle_proposal_favorite(&conn, ids[3]).unwrap();
+
+        let first = query_proposal_history_internal(&conn, 3, 0, None, None, false, true).unwrap();
+        let second =
+            query_proposal_history_internal(&conn, 3, 0, first.next_before_id, None, false, true)
+                .unwrap();
+
+        let order: Vec<i64> = first
+            .proposals
+            .iter()
+            .chain(second.proposals.iter())
+            .map(|p| p.id)
+            .collect();
+        assert_eq!(order, vec![ids[3], ids[0], ids[5], ids[4], ids[2], ids[1]]);
+        assert!(!second.has_more);
+    }
+
+    #[test]
+    fn test_history_limit_capped_and_stale_cursor_rejected() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        for i in 0..(MAX_HISTORY_PAGE_SIZE + 5) {
+            insert_proposal(&conn, &format!("Job {}", i), "Text", None).unwrap();
+        }
+
+        let page =
+            query_proposal_history_internal(&conn, 10_000, 0, None, None, false, false).unwrap();
+        assert_eq!(page.proposals.len(), MAX_HISTORY_PAGE_SIZE as usize);
+        assert!(page.has_more);
+
+        assert!(
+            query_proposal_history_internal(&conn, 50, 0, Some(999_999), None, false, false)
+                .is_err()
+        );
+    }
 }
@@ -0,0 +1,288 @@
+//! Job score recalculation after the user's skills change
+//!
+//! Skill edits used to recalculate every scored job inside the command while holding
+//! the database lock. `add_user_skill` / `remove_user_skill` now only call
+//! `RecalcState::request`; a background worker waits out a short debounce so rapid
+//! edits coalesce into one pass, then recalculates in batches with a short lock per
+//! batch and emits `scores:recalculated`. Each pass recomputes skills match from
+//! `job_skills` as well as the weighted overall score.
+
+use crate::db::queries::scoring as score_queries;
+use crate::db::{AppDatabase, Database};
+use crate::{events, scoring};
+use rusqlite::Connection;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::Notify;
+
+/// Jobs recalculated per lock acquisition
+pub const RECALC_BATCH_SIZE: usize = 100;
+
+/// Quiet period after the last skill edit before the worker starts a pass
+const RECALC_DEBOUNCE: Duration = Duration::from_millis(750);
+
+/// Managed state shared by the skill commands and the recalculation worker
+pub struct RecalcState {
+    /// Set by skill edits, cleared when a pass picks it up
+    pending: AtomicBool,
+    /// Wakes the worker when a recalculation is requested
+    notify: Notify,
+    /// Guards against spawning a second worker
+    worker_started: AtomicBool,
+    /// Completed worker passes (coalesced requests count once)
+    completed_runs: AtomicUsize,
+}
+
+impl Default for RecalcState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RecalcState {
+    pub fn new() -> Self {
+        Self {
+            pending: AtomicBool::new(false),
+            notify: Notify::new(),
+            worker_started: AtomicBool::new(false),
+            completed_runs: AtomicUsize::new(0),
+        }
+    }
+
+    /// Ask for a recalculation. Returns immediately; requests made before the
+    /// worker picks up the pending flag share one pass.
+    pub fn request(&self) {
+        self.pending.store(true, Ordering::SeqCst);
+        self.notify.notify_one();
+    }
+
+    pub fn completed_runs(&self) -> usize {
+        self.completed_runs.load(Ordering::SeqCst)
+    }
+}
+
+/// Recalculate skills match and overall score for one scored job.
+/// Returns false if the job has no job_scores row.
+fn recalculate_job(conn: &Connection, job_post_id: i64) -> Result<bool, String> {
+    // None (no user skills or no job skills) clears a stale percentage
+    let skills_match = score_queries::calculate_skills_match(job_post_id, conn)?;
+    score_queries::update_skills_match(conn, job_post_id, skills_match)?;
+
+    let Some(score) = score_queries::get_job_score(conn, job_post_id)? else {
+        return Ok(false);
+    };
+    let result = scoring::calculate_overall_score(
+        score.skills_match_percentage,
+        score.client_quality_score,
+        score.budget_alignment_score,
+    );
+    score_queries::upsert_overall_score(
+        conn,
+        job_post_id,
+        result.overall_score,
+        &result.color_flag,
+    )?;
+    Ok(true)
+}
+
+/// Recalculate every scored job in batches of `RECALC_BATCH_SIZE`, taking the
+/// database lock once per batch. `on_progress(processed, total)` runs after each batch.
+pub fn recalculate_scores_in_batches(
+    database: &Database,
+    mut on_progress: impl FnMut(usize, usize),
+) -> Result<usize, String> {
+    let job_ids: Vec<i64> = {
+        let conn = database
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        let mut stmt = conn
+            .prepare("SELECT DISTINCT job_post_id FROM job_scores ORDER BY job_post_id")
+            .map_err(|e| format!("Failed to prepare query: {}", e))?;
+        let ids = stmt
+            .query_map([], |row| row.get(0))
+            .map_err(|e| format!("Failed to query job IDs: {}", e))?
+            .collect::<Result<Vec<i64>, _>>()
+            .map_err(|e| format!("Failed to collect job IDs: {}", e))?;
+        ids
+    };
+
+    let total = job_ids.len();
+    let mut processed = 0;
+    let mut recalculated = 0;
+
+    for batch in job_ids.chunks(RECALC_BATCH_SIZE) {
+        {
+            let conn = database
+                .conn
+                .lock()
+                .map_err(|e| format!("Database lock error: {}", e))?;
+            let tx = conn
+                .unchecked_transaction()
+                .map_err(|e| format!("Failed to start transaction: {}", e))?;
+            for &job_post_id in batch {
+                if recalculate_job(&tx, job_post_id)? {
+                    recalculated += 1;
+                }
+            }
+            tx.commit()
+                .map_err(|e| format!("Failed to commit recalculated scores: {}", e))?;
+        }
+
+        processed += batch.len();
+        on_progress(processed, total);
+    }
+
+    Ok(recalculated)
+}
+
+/// Run one pass if a recalculation is pending. Returns None when nothing was requested.
+fn run_pending(state: &RecalcState, database: &Database) -> Option<Result<usize, String>> {
+    if !state.pending.swap(false, Ordering::SeqCst) {
+        return None;
+    }
+    let result = recalculate_scores_in_batches(database, |_, _| {});
+    state.completed_runs.fetch_add(1, Ordering::SeqCst);
+    Some(result)
+}
+
+/// Spawn the recalculation worker. Only the first call starts a worker.
+pub fn start_recalc_worker(app_handle: AppHandle) {
+    let state = app_handle.state::<RecalcState>();
+    if state.worker_started.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        run_worker(app_handle).await;
+    });
+}
+
+async fn run_worker(app_handle: AppHandle) {
+    let state = app_handle.state::<RecalcState>();
+    let db_state = app_handle.state::<AppDatabase>();
+
+    loop {
+        state.notify.notified().await;
+        // Edits arriving during the debounce only re-set the pending flag
+        tokio::time::sleep(RECALC_DEBOUNCE).await;
+
+        // Skill edits need an unlocked database, so this only fails after a factory reset
+        let Ok(database) = db_state.get() else {
+            continue;
+        };
+
+        match run_pending(&state, database) {
+            Some(Ok(count)) => {
+                tracing::info!(count, "Recalculated job scores after skill change");
+                let _ = app_handle.emit(
+                    events::SCORES_RECALCULATED,
+                    events::ScoresRecalculatedPayload { count },
+                );
+            }
+            Some(Err(e)) => tracing::warn!("Score recalculation failed: {}", e),
+            None => {}
+        }
+    }
+}
+
+/// Recalculate all job scores now (Story 4b.5 Task 3.2)
+/// Emits `scores:recalc-progress` after each batch and `scores:recalculated` at the end.
+#[tauri::command]
+pub async fn recalculate_all_scores(
+    app_handle: AppHandle,
+    database: State<'_, AppDatabase>,
+) -> Result<usize, String> {
+    let database = database.get()?;
+    let count = recalculate_scores_in_batches(database, |processed, total| {
+        let _ = app_handle.emit(
+            events::SCORES_RECALC_PROGRESS,
+            events::ScoresRecalcProgress { processed, total },
+        );
+    })?;
+    let _ = app_handle.emit(
+        events::SCORES_RECALCULATED,
+        events::ScoresRecalculatedPayload { count },
+    );
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::queries::{job_posts, user_skills};
+    use tempfile::tempdir;
+
+    fn setup() -> (tempfile::TempDir, Database) {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        (dir, db)
+    }
+
+    fn insert_scored_job(conn: &Connection, skills: &[&str]) -> i64 {
+        let id = job_posts::insert_job_post(conn, None, "Scored job", None).unwrap();
+        job_posts::insert_job_skills(
+            conn,
+            id,
+            &skills.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let skills_match = score_queries::calculate_skills_match(id, conn).unwrap();
+        score_queries::store_skills_match(conn, id, skills_match).unwrap();
+        score_queries::store_client_quality_score(conn, id, Some(80)).unwrap();
+        id
+    }
+
+    #[test]
+    fn test_rapid_requests_coalesce_into_one_run() {
+        let (_dir, db) = setup();
+        let state = RecalcState::new();
+
+        state.request();
+        state.request();
+        state.request();
+
+        assert!(matches!(run_pending(&state, &db), Some(Ok(_))));
+        assert!(run_pending(&state, &db).is_none());
+        assert_eq!(state.completed_runs(), 1);
+
+        // A later edit starts a new pass
+        state.request();
+        assert!(run_pending(&state, &db).is_some());
+        assert_eq!(state.completed_runs(), 2);
+    }
+
+    #[test]
+    fn test_skill_add_updates_skills_match_and_overall() {
+        let (_dir, db) = setup();
+        let id = {
+            let conn = db.conn.lock().unwrap();
+            user_skills::add_user_skill(&conn, "Rust").unwrap();
+            insert_scored_job(&conn, &["Rust", "React"])
+        };
+        let before = {
+            let conn = db.conn.lock().unwrap();
+            score_queries::get_job_score(&conn, id).unwrap().unwrap()
+        };
+        assert_eq!(before.skills_match_percentage, Some(50.0));
+
+        {
+            let conn = db.conn.lock().unwrap();
+            user_skills::add_user_skill(&conn, "react").unwrap();
+        }
+        let mut progress = Vec::new();
+        let count = recalculate_scores_in_batches(&db, |processed, total| {
+            progress.push((processed, total))
+        })
+        .unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(progress, vec![(1, 1)]);
+
+        let conn = db.conn.lock().unwrap();
+        let after = score_queries::get_job_score(&conn, id).unwrap().unwrap();
+        assert_eq!(after.skills_match_percentage, Some(100.0));
+        let expected = scoring::calculate_overall_score(Some(100.0), Some(80), None);
+        assert_eq!(after.overall_score, expected.overall_score);
+    }
+}
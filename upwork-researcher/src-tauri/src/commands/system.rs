@@ -51,6 +51,55 @@ pub fn get_blocked_requests(
     Ok(blocked_requests_state.get_all())
 }
 
+/// Gets per-domain request counts, error counts, and latency percentiles
+/// recorded since startup or the last reset. Domains only, never full URLs.
+#[tauri::command]
+#[specta::specta]
+pub fn get_network_metrics(
+    metrics: State<crate::http::RequestMetricsState>,
+) -> Result<Vec<crate::http::DomainMetrics>, String> {
+    Ok(metrics.snapshot())
+}
+
+/// Clears all recorded network metrics
+#[tauri::command]
+#[specta::specta]
+pub fn reset_network_metrics(
+    metrics: State<crate::http::RequestMetricsState>,
+) -> Result<(), String> {
+    metrics.reset();
+    Ok(())
+}
+
+/// Latest Anthropic API rate-limit values (requests/tokens remaining, reset
+/// times) from response headers. `known` is false until a response has been seen.
+#[tauri::command]
+pub fn get_api_quota_status(
+    quota: State<crate::api_quota::ApiQuotaState>,
+) -> Result<crate::api_quota::ApiQuotaStatus, String> {
+    Ok(quota.status(chrono::Utc::now()))
+}
+
+/// Gets per-command invocation counts and timings. Empty unless the
+/// `perf_metrics_enabled` setting is on.
+#[tauri::command]
+#[specta::specta]
+pub fn get_command_metrics(
+    metrics: State<crate::command_metrics::CommandMetricsState>,
+) -> Result<Vec<crate::command_metrics::CommandMetrics>, String> {
+    Ok(metrics.snapshot())
+}
+
+/// Clears all recorded command metrics
+#[tauri::command]
+#[specta::specta]
+pub fn reset_command_metrics(
+    metrics: State<crate::command_metrics::CommandMetricsState>,
+) -> Result<(), String> {
+    metrics.reset();
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,215 +1,884 @@
 // Test data seeding commands for performance benchmarks
 // Story 8.10: Performance Validation Tests
-
+//
+// Seeding is deterministic: the same seed and options produce the same rows
+// (dates are stored as offsets from now). Lengths, outcomes, dates and job
+// scores follow realistic distributions so analytics and search benchmarks
+// exercise the same joins real data does. Every seeded proposal and job post
+// starts with SEED_MARKER, which is how clear_test_data finds them; revisions,
+// skills, scores and overrides hang off those rows and are removed with them.
+// Rows without the marker (real user data) are never touched.
+
+use crate::db::queries::clients::client_name_columns;
 use crate::db::AppDatabase;
-use rusqlite::Connection;
+use crate::scoring::calculate_overall_score;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
 use tauri::State;
 
+/// Prefix of `proposals.job_content` and `job_posts.raw_content` on seeded rows
+pub const SEED_MARKER: &str = "[perf-seed] ";
+
+/// Seed used when none is given
+const DEFAULT_SEED: u64 = 42;
+
+const SKILLS: &[&str] = &[
+    "Rust",
+    "TypeScript",
+    "React",
+    "Node.js",
+    "Python",
+    "Django",
+    "PostgreSQL",
+    "SQLite",
+    "AWS",
+    "Docker",
+    "Kubernetes",
+    "GraphQL",
+    "Tailwind CSS",
+    "Next.js",
+    "Figma",
+    "Shopify",
+    "WordPress",
+    "Data Scraping",
+    "Machine Learning",
+    "Tauri",
+];
+
+const JOB_TITLES: &[&str] = &[
+    "Build a customer dashboard",
+    "Fix performance issues in our web app",
+    "Develop a desktop app MVP",
+    "Migrate our backend to the cloud",
+    "Create a data pipeline for reporting",
+    "Redesign our Shopify storefront",
+    "Integrate a payments API",
+    "Write an internal admin tool",
+    "Automate our lead scraping",
+    "Ship a mobile-friendly landing page",
+];
+
+/// Client names are "<prefix> <suffix>": 10 x 5 = 50 distinct clients
+const CLIENT_PREFIXES: &[&str] = &[
+    "Northwind",
+    "Globex",
+    "Initech",
+    "Umbrella",
+    "Hooli",
+    "Stark",
+    "Wayne",
+    "Acme",
+    "Vandelay",
+    "Soylent",
+];
+const CLIENT_SUFFIXES: &[&str] = &["Labs", "Inc", "Digital", "Studio", "Group"];
+
+const HOOK_STRATEGIES: &[&str] = &[
+    "Social Proof",
+    "Contrarian",
+    "Immediate Value",
+    "Problem-Aware",
+    "Question-Based",
+];
+
+const PROPOSAL_SENTENCES: &[&str] = &[
+    "I've built almost exactly this for a logistics startup last year.",
+    "Happy to walk you through a similar project on a quick call.",
+    "The tricky part here is usually the data model, so I'd start there.",
+    "I can have a working prototype to you within the first week.",
+    "My last three clients in this space all hired me again.",
+    "Quick question before I quote: is the current codebase tested?",
+    "I'd keep the first milestone small so you can judge my work early.",
+    "Most teams I work with see page loads drop by half after this kind of cleanup.",
+    "I'll send short daily updates so you're never guessing where things stand.",
+    "You mentioned a tight deadline, and I'm free to start right away.",
+    "I write the docs as I go, so handover is painless.",
+    "Here's how I'd split the work into three milestones.",
+];
+
+/// Non-pending outcomes and their relative frequency
+const OUTCOMES: &[(&str, f64)] = &[
+    ("no_response", 0.40),
+    ("rejected", 0.18),
+    ("response_received", 0.25),
+    ("interview", 0.12),
+    ("hired", 0.05),
+];
+
+/// Share of seeded proposals still drafts
+const DRAFT_FRACTION: f64 = 0.1;
+/// Share of seeded proposals with a cached perplexity score
+const PERPLEXITY_FRACTION: f64 = 0.4;
+/// Share of seeded proposals with a safety override on record
+const OVERRIDE_FRACTION: f64 = 0.05;
+/// Safety threshold recorded on seeded overrides
+const OVERRIDE_THRESHOLD: f64 = 180.0;
+/// Most revisions per seeded proposal
+const MAX_REVISIONS: usize = 4;
+
+/// Dataset size preset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "lowercase")]
+pub enum SeedProfile {
+    Small,
+    Medium,
+    Large,
+}
+
+impl SeedProfile {
+    /// Rows seeded by the preset: proposals (job posts are half as many)
+    pub fn rows(self) -> usize {
+        match self {
+            SeedProfile::Small => 100,
+            SeedProfile::Medium => 2_000,
+            SeedProfile::Large => 20_000,
+        }
+    }
+}
+
+/// Distribution options; unset fields use the defaults
+#[derive(Debug, Clone, Default, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SeedOptions {
+    /// Share of proposals with an outcome other than pending (0-1, default 0.3)
+    pub outcome_fraction: Option<f64>,
+    /// Days the creation dates are spread over (default 90)
+    pub days: Option<u32>,
+    /// Share of proposals linked to a scored job post (0-1, default 0.6)
+    pub linked_fraction: Option<f64>,
+}
+
+/// Validated `SeedOptions`
+#[derive(Debug, Clone, Copy)]
+struct SeedConfig {
+    outcome_fraction: f64,
+    days: u32,
+    linked_fraction: f64,
+}
+
+impl SeedConfig {
+    fn from_options(options: Option<SeedOptions>) -> Result<Self, String> {
+        let options = options.unwrap_or_default();
+        let fraction = |value: Option<f64>, default: f64, name: &str| {
+            let value = value.unwrap_or(default);
+            if (0.0..=1.0).contains(&value) {
+                Ok(value)
+            } else {
+                Err(format!("{} must be between 0 and 1", name))
+            }
+        };
+        let days = options.days.unwrap_or(90);
+        if days == 0 {
+            return Err("days must be at least 1".to_string());
+        }
+        Ok(Self {
+            outcome_fraction: fraction(options.outcome_fraction, 0.3, "outcomeFraction")?,
+            days,
+            linked_fraction: fraction(options.linked_fraction, 0.6, "linkedFraction")?,
+        })
+    }
+}
+
+/// Rows created (or, from `clear_test_data`, removed) per table
+#[derive(Debug, Clone, Default, PartialEq, Serialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SeedSummary {
+    pub proposals: u32,
+    pub revisions: u32,
+    pub job_posts: u32,
+    pub job_skills: u32,
+    pub job_scores: u32,
+    pub safety_overrides: u32,
+}
+
+/// A seeded job post proposals can be linked to
+struct SeededJob {
+    id: i64,
+    raw_content: String,
+}
+
+/// `count` if given, else the profile's row count
+fn resolve_count(count: Option<i64>, profile: Option<SeedProfile>) -> Result<usize, String> {
+    match (count, profile) {
+        (Some(count), _) if count < 0 => Err("count must not be negative".to_string()),
+        (Some(count), _) => Ok(count as usize),
+        (None, Some(profile)) => Ok(profile.rows()),
+        (None, None) => Err("Provide a count or a profile".to_string()),
+    }
+}
+
 /// Seeds the database with test proposals for performance benchmarks
-/// Creates realistic proposal data matching production schema
+/// Proposals come with revisions and occasional safety overrides, and are
+/// linked to job posts from earlier seeding runs (none linked if there are none).
 #[tauri::command]
 #[specta::specta]
-pub async fn seed_proposals(database: State<'_, AppDatabase>, count: i64) -> Result<(), String> {
+pub async fn seed_proposals(
+    database: State<'_, AppDatabase>,
+    count: Option<i64>,
+    profile: Option<SeedProfile>,
+    seed: Option<u64>,
+    options: Option<SeedOptions>,
+) -> Result<SeedSummary, String> {
+    let count = resolve_count(count, profile)?;
+    let config = SeedConfig::from_options(options)?;
     let database = database.get()?;
     let mut conn = database
         .conn
         .lock()
         .map_err(|e| format!("Database lock error: {}", e))?;
 
-    seed_proposals_internal(&mut conn, count as usize)?;
-    Ok(())
+    seed_proposals_internal(&mut conn, count, seed.unwrap_or(DEFAULT_SEED), config)
 }
 
-/// Seeds the database with test job posts for performance benchmarks
+/// Seeds the database with test job posts (with skills and scores) for performance benchmarks
 #[tauri::command]
 #[specta::specta]
-pub async fn seed_job_posts(database: State<'_, AppDatabase>, count: i64) -> Result<(), String> {
+pub async fn seed_job_posts(
+    database: State<'_, AppDatabase>,
+    count: Option<i64>,
+    profile: Option<SeedProfile>,
+    seed: Option<u64>,
+    options: Option<SeedOptions>,
+) -> Result<SeedSummary, String> {
+    let count = resolve_count(count, profile)?;
+    let config = SeedConfig::from_options(options)?;
     let database = database.get()?;
     let mut conn = database
         .conn
         .lock()
         .map_err(|e| format!("Database lock error: {}", e))?;
 
-    seed_job_posts_internal(&mut conn, count as usize)?;
-    Ok(())
+    seed_job_posts_internal(&mut conn, count, seed.unwrap_or(DEFAULT_SEED), config)
 }
 
-/// Clears all test data from the database
-/// CAUTION: This will delete ALL data - only use in test environments
+/// Seeds a consistent dataset for the profile: job posts with skills and
+/// scores, then proposals linked to them with revisions and safety overrides
 #[tauri::command]
 #[specta::specta]
-pub async fn clear_test_data(database: State<'_, AppDatabase>) -> Result<(), String> {
+pub async fn seed_full_dataset(
+    database: State<'_, AppDatabase>,
+    profile: SeedProfile,
+    seed: Option<u64>,
+    options: Option<SeedOptions>,
+) -> Result<SeedSummary, String> {
+    let config = SeedConfig::from_options(options)?;
     let database = database.get()?;
-    let conn = database
+    let mut conn = database
         .conn
         .lock()
         .map_err(|e| format!("Database lock error: {}", e))?;
 
-    // Delete in reverse order of foreign key dependencies
-    // Clear dependent/leaf tables first
-    conn.execute("DELETE FROM scoring_feedback", [])
-        .map_err(|e| format!("Failed to clear scoring feedback: {}", e))?;
-
-    conn.execute("DELETE FROM rss_imports", [])
-        .map_err(|e| format!("Failed to clear rss imports: {}", e))?;
+    seed_full_dataset_internal(&mut conn, profile, seed.unwrap_or(DEFAULT_SEED), config)
+}
 
-    conn.execute("DELETE FROM golden_set_proposals", [])
-        .map_err(|e| format!("Failed to clear golden set proposals: {}", e))?;
+/// Removes everything seeding created: proposals and job posts tagged with
+/// SEED_MARKER, and the revisions, skills, scores and overrides attached to them.
+/// Real user data is left alone.
+#[tauri::command]
+#[specta::specta]
+pub async fn clear_test_data(database: State<'_, AppDatabase>) -> Result<SeedSummary, String> {
+    let database = database.get()?;
+    let mut conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
 
-    conn.execute("DELETE FROM hook_strategies", [])
-        .map_err(|e| format!("Failed to clear hook strategies: {}", e))?;
+    clear_test_data_internal(&mut conn)
+}
 
-    conn.execute("DELETE FROM voice_profiles", [])
-        .map_err(|e| format!("Failed to clear voice profiles: {}", e))?;
+// Internal seeding functions
 
-    conn.execute("DELETE FROM settings", [])
-        .map_err(|e| format!("Failed to clear settings: {}", e))?;
+fn seed_proposals_internal(
+    conn: &mut Connection,
+    count: usize,
+    seed: u64,
+    config: SeedConfig,
+) -> Result<SeedSummary, String> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut summary = SeedSummary::default();
+    let tx = conn
+        .transaction()
+        .map_err(|e| format!("Transaction error: {}", e))?;
 
-    conn.execute("DELETE FROM proposal_revisions", [])
-        .map_err(|e| format!("Failed to clear revisions: {}", e))?;
+    let jobs = seeded_jobs(&tx)?;
+    insert_proposals(&tx, count, &jobs, config, &mut rng, &mut summary)?;
 
-    conn.execute("DELETE FROM proposals", [])
-        .map_err(|e| format!("Failed to clear proposals: {}", e))?;
+    tx.commit().map_err(|e| format!("Commit error: {}", e))?;
+    Ok(summary)
+}
 
-    conn.execute("DELETE FROM job_scores", [])
-        .map_err(|e| format!("Failed to clear job scores: {}", e))?;
+fn seed_job_posts_internal(
+    conn: &mut Connection,
+    count: usize,
+    seed: u64,
+    config: SeedConfig,
+) -> Result<SeedSummary, String> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut summary = SeedSummary::default();
+    let tx = conn
+        .transaction()
+        .map_err(|e| format!("Transaction error: {}", e))?;
 
-    conn.execute("DELETE FROM job_posts", [])
-        .map_err(|e| format!("Failed to clear job posts: {}", e))?;
+    insert_job_posts(&tx, count, config, &mut rng, &mut summary)?;
 
-    Ok(())
+    tx.commit().map_err(|e| format!("Commit error: {}", e))?;
+    Ok(summary)
 }
 
-// Internal seeding functions
-
-fn seed_proposals_internal(conn: &mut Connection, count: usize) -> Result<(), String> {
+fn seed_full_dataset_internal(
+    conn: &mut Connection,
+    profile: SeedProfile,
+    seed: u64,
+    config: SeedConfig,
+) -> Result<SeedSummary, String> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut summary = SeedSummary::default();
     let tx = conn
         .transaction()
         .map_err(|e| format!("Transaction error: {}", e))?;
 
-    for i in 0..count {
-        let title = format!("Test Proposal {}", i + 1);
-        let content = format!("This is test proposal content for benchmark #{}", i + 1);
-        let job_text = format!("Test job description for proposal {}", i + 1);
-
-        tx.execute(
-            "INSERT INTO proposals (title, content, job_description, timestamp) VALUES (?, ?, ?, datetime('now', '-' || ? || ' hours'))",
-            rusqlite::params![title, content, job_text, i % 720], // Spread over 30 days
-        )
-        .map_err(|e| format!("Failed to insert proposal {}: {}", i, e))?;
-    }
+    let rows = profile.rows();
+    let jobs = insert_job_posts(&tx, rows / 2, config, &mut rng, &mut summary)?;
+    insert_proposals(&tx, rows, &jobs, config, &mut rng, &mut summary)?;
 
     tx.commit().map_err(|e| format!("Commit error: {}", e))?;
-    Ok(())
+    tracing::info!(?profile, seed, ?summary, "Seeded full test dataset");
+    Ok(summary)
 }
 
-fn seed_job_posts_internal(conn: &mut Connection, count: usize) -> Result<(), String> {
+fn clear_test_data_internal(conn: &mut Connection) -> Result<SeedSummary, String> {
     let tx = conn
         .transaction()
         .map_err(|e| format!("Transaction error: {}", e))?;
 
+    const SEEDED_PROPOSALS: &str =
+        "SELECT id FROM proposals WHERE substr(job_content, 1, length(?1)) = ?1";
+    const SEEDED_JOBS: &str =
+        "SELECT id FROM job_posts WHERE substr(raw_content, 1, length(?1)) = ?1";
+    let delete = |sql: String, what: &str| -> Result<u32, String> {
+        tx.execute(&sql, params![SEED_MARKER])
+            .map(|rows| rows as u32)
+            .map_err(|e| format!("Failed to clear seeded {}: {}", what, e))
+    };
+
+    // Children first, so this works whether or not foreign keys cascade
+    let summary = SeedSummary {
+        safety_overrides: delete(
+            format!(
+                "DELETE FROM safety_overrides WHERE proposal_id IN ({})",
+                SEEDED_PROPOSALS
+            ),
+            "safety overrides",
+        )?,
+        revisions: delete(
+            format!(
+                "DELETE FROM proposal_revisions WHERE proposal_id IN ({})",
+                SEEDED_PROPOSALS
+            ),
+            "revisions",
+        )?,
+        proposals: delete(
+            format!("DELETE FROM proposals WHERE id IN ({})", SEEDED_PROPOSALS),
+            "proposals",
+        )?,
+        job_skills: delete(
+            format!(
+                "DELETE FROM job_skills WHERE job_post_id IN ({})",
+                SEEDED_JOBS
+            ),
+            "job skills",
+        )?,
+        job_scores: delete(
+            format!(
+                "DELETE FROM job_scores WHERE job_post_id IN ({})",
+                SEEDED_JOBS
+            ),
+            "job scores",
+        )?,
+        job_posts: delete(
+            format!("DELETE FROM job_posts WHERE id IN ({})", SEEDED_JOBS),
+            "job posts",
+        )?,
+    };
+
+    tx.commit().map_err(|e| format!("Commit error: {}", e))?;
+    Ok(summary)
+}
+
+/// Job posts from earlier seeding runs
+fn seeded_jobs(conn: &Connection) -> Result<Vec<SeededJob>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, raw_content FROM job_posts
+             WHERE substr(raw_content, 1, length(?1)) = ?1 ORDER BY id",
+        )
+        .map_err(|e| format!("Failed to load seeded job posts: {}", e))?;
+    let jobs = stmt
+        .query_map(params![SEED_MARKER], |row| {
+            Ok(SeededJob {
+                id: row.get(0)?,
+                raw_content: row.get(1)?,
+            })
+        })
+        .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| format!("Failed to load seeded job posts: {}", e))?;
+    Ok(jobs)
+}
+
+fn insert_job_posts(
+    conn: &Connection,
+    count: usize,
+    config: SeedConfig,
+    rng: &mut StdRng,
+    summary: &mut SeedSummary,
+) -> Result<Vec<SeededJob>, String> {
+    let mut jobs = Vec::with_capacity(count);
     for i in 0..count {
-        let title = format!("Test Job {}", i + 1);
-        let description = format!(
-            "Test job description for benchmark #{}. Looking for skilled developer.",
-            i + 1
+        let title = JOB_TITLES[rng.gen_range(0..JOB_TITLES.len())];
+        let client = format!(
+            "{} {}",
+            CLIENT_PREFIXES[rng.gen_range(0..CLIENT_PREFIXES.len())],
+            CLIENT_SUFFIXES[rng.gen_range(0..CLIENT_SUFFIXES.len())]
+        );
+        let skills = pick_skills(rng);
+        let raw_content = format!(
+            "{}{}\n\n{} is looking for an experienced freelancer. Required skills: {}. \
+             Please share similar work you've done.",
+            SEED_MARKER,
+            title,
+            client,
+            skills.join(", ")
         );
-        let client_name = format!("Client{}", i % 50); // 50 unique clients
-        let skills = "Rust,TypeScript,React".to_string();
 
-        tx.execute(
-            "INSERT INTO job_posts (title, description, url, client_name, key_skills, posted_at, imported_at)
-             VALUES (?, ?, ?, ?, ?, datetime('now', '-' || ? || ' hours'), datetime('now'))",
-            rusqlite::params![
-                title,
-                description,
-                format!("https://upwork.com/job/{}", i),
+        let hourly = rng.gen_bool(0.6);
+        let (budget_min, budget_max) = if hourly {
+            let min = rng.gen_range(15.0..60.0_f64).round();
+            (min, min + rng.gen_range(5.0..40.0_f64).round())
+        } else {
+            let amount = log_normal(rng, 800.0, 0.9).round().clamp(50.0, 50_000.0);
+            (amount, amount)
+        };
+
+        let skills_match = rng.gen_range(20.0..100.0_f64).round();
+        let client_quality = (70.0 + 15.0 * standard_normal(rng)).clamp(0.0, 100.0) as i32;
+        let budget_alignment = rng.gen_range(0..=100);
+        let score = calculate_overall_score(
+            Some(skills_match),
+            Some(client_quality),
+            Some(budget_alignment),
+        );
+
+        let (client_name, client_key) = client_name_columns(Some(&client));
+        conn.execute(
+            "INSERT INTO job_posts (url, raw_content, client_name, client_name_normalized, created_at,
+                 job_title, budget_min, budget_max, budget_type, budget_min_usd, budget_max_usd,
+                 budget_alignment_pct, overall_score, score_color, skills_match_percent,
+                 client_quality_percent)
+             VALUES (?1, ?2, ?3, ?4, datetime('now', ?5), ?6, ?7, ?8, ?9, ?7, ?8, ?10, ?11, ?12, ?13, ?14)",
+            params![
+                format!("https://www.upwork.com/jobs/~01{:016x}", rng.gen::<u64>()),
+                raw_content,
                 client_name,
-                skills,
-                i % 168, // Spread over 7 days
+                client_key,
+                minutes_ago(rng, config.days),
+                title,
+                budget_min,
+                budget_max,
+                if hourly { "hourly" } else { "fixed" },
+                budget_alignment,
+                score.overall_score,
+                score.color_flag,
+                skills_match as i64,
+                client_quality,
             ],
         )
         .map_err(|e| format!("Failed to insert job post {}: {}", i, e))?;
+        let id = conn.last_insert_rowid();
+
+        for skill in &skills {
+            conn.execute(
+                "INSERT INTO job_skills (job_post_id, skill_name) VALUES (?1, ?2)",
+                params![id, skill],
+            )
+            .map_err(|e| format!("Failed to insert job skill for job post {}: {}", i, e))?;
+        }
+        conn.execute(
+            "INSERT INTO job_scores (job_post_id, skills_match_percentage, client_quality_score,
+                 budget_alignment_score, overall_score, color_flag)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                id,
+                skills_match,
+                client_quality,
+                budget_alignment,
+                score.overall_score,
+                score.color_flag
+            ],
+        )
+        .map_err(|e| format!("Failed to insert job score for job post {}: {}", i, e))?;
+
+        summary.job_posts += 1;
+        summary.job_skills += skills.len() as u32;
+        summary.job_scores += 1;
+        jobs.push(SeededJob { id, raw_content });
     }
+    Ok(jobs)
+}
 
-    tx.commit().map_err(|e| format!("Commit error: {}", e))?;
+fn insert_proposals(
+    conn: &Connection,
+    count: usize,
+    jobs: &[SeededJob],
+    config: SeedConfig,
+    rng: &mut StdRng,
+    summary: &mut SeedSummary,
+) -> Result<(), String> {
+    for i in 0..count {
+        let job = (!jobs.is_empty() && rng.gen_bool(config.linked_fraction))
+            .then(|| &jobs[rng.gen_range(0..jobs.len())]);
+        let job_content = match job {
+            Some(job) => job.raw_content.clone(),
+            None => format!("{}Test job description for proposal {}", SEED_MARKER, i + 1),
+        };
+
+        // Proposal lengths are right-skewed: most near 180 words, a long tail of essays
+        let words = log_normal(rng, 180.0, 0.45).round().clamp(40.0, 800.0) as usize;
+        let revision_count = revision_count(rng);
+        let mut revisions = vec![proposal_text(rng, words)];
+        for _ in 1..revision_count {
+            let previous = revisions.last().unwrap();
+            let edit = PROPOSAL_SENTENCES[rng.gen_range(0..PROPOSAL_SENTENCES.len())];
+            revisions.push(format!("{} {}", previous, edit));
+        }
+        let text = revisions.last().unwrap().clone();
+
+        let age = rng.gen_range(0..config.days as i64 * 1440);
+        let is_draft = rng.gen_bool(DRAFT_FRACTION);
+        let outcome = if !is_draft && rng.gen_bool(config.outcome_fraction) {
+            weighted_pick(rng, OUTCOMES)
+        } else {
+            "pending"
+        };
+        let hook = HOOK_STRATEGIES[rng.gen_range(0..HOOK_STRATEGIES.len())];
+        let ab_assigned = rng.gen_bool(0.5);
+        let perplexity = rng
+            .gen_bool(PERPLEXITY_FRACTION)
+            .then(|| (150.0 + 30.0 * standard_normal(rng)).clamp(60.0, 260.0));
+
+        conn.execute(
+            "INSERT INTO proposals (job_content, generated_text, created_at, status, outcome_status,
+                 outcome_updated_at, hook_strategy_id, job_post_id, ab_assigned,
+                 ab_weight_at_assignment, perplexity_score)
+             VALUES (?1, ?2, datetime('now', ?3), ?4, ?5,
+                 CASE WHEN ?5 = 'pending' THEN NULL ELSE datetime('now', ?3, '+3 days') END,
+                 ?6, ?7, ?8, ?9, ?10)",
+            params![
+                job_content,
+                text,
+                format!("-{} minutes", age),
+                if is_draft { "draft" } else { "completed" },
+                outcome,
+                hook,
+                job.map(|job| job.id),
+                ab_assigned,
+                ab_assigned.then_some(0.2),
+                perplexity,
+            ],
+        )
+        .map_err(|e| format!("Failed to insert proposal {}: {}", i, e))?;
+        let id = conn.last_insert_rowid();
+
+        for (n, content) in revisions.iter().enumerate() {
+            conn.execute(
+                "INSERT INTO proposal_revisions (proposal_id, content, revision_number, revision_type, created_at)
+                 VALUES (?1, ?2, ?3, ?4, datetime('now', ?5))",
+                params![
+                    id,
+                    content,
+                    n as i64 + 1,
+                    if n == 0 { "generation" } else { "edit" },
+                    format!("-{} minutes", (age - n as i64 * 10).max(0)),
+                ],
+            )
+            .map_err(|e| format!("Failed to insert revision for proposal {}: {}", i, e))?;
+        }
+        summary.revisions += revisions.len() as u32;
+
+        if rng.gen_bool(OVERRIDE_FRACTION) {
+            // Overrides older than a week have been resolved one way or the other
+            let status = if age < 7 * 1440 {
+                "pending"
+            } else if rng.gen_bool(0.5) {
+                "successful"
+            } else {
+                "unsuccessful"
+            };
+            conn.execute(
+                "INSERT INTO safety_overrides (proposal_id, timestamp, ai_score, threshold_at_override, status)
+                 VALUES (?1, datetime('now', ?2), ?3, ?4, ?5)",
+                params![
+                    id,
+                    format!("-{} minutes", age),
+                    OVERRIDE_THRESHOLD + rng.gen_range(1.0..50.0_f64).round(),
+                    OVERRIDE_THRESHOLD,
+                    status
+                ],
+            )
+            .map_err(|e| format!("Failed to insert safety override for proposal {}: {}", i, e))?;
+            summary.safety_overrides += 1;
+        }
+
+        summary.proposals += 1;
+    }
     Ok(())
 }
 
+/// Standard normal sample (Box-Muller)
+fn standard_normal(rng: &mut StdRng) -> f64 {
+    let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// Log-normal sample with the given median
+fn log_normal(rng: &mut StdRng, median: f64, sigma: f64) -> f64 {
+    median * (sigma * standard_normal(rng)).exp()
+}
+
+fn weighted_pick(rng: &mut StdRng, items: &[(&'static str, f64)]) -> &'static str {
+    let total: f64 = items.iter().map(|(_, weight)| weight).sum();
+    let mut r = rng.gen_range(0.0..total);
+    for (item, weight) in items {
+        if r < *weight {
+            return item;
+        }
+        r -= weight;
+    }
+    items[items.len() - 1].0
+}
+
+/// `datetime()` modifier for a random moment in the last `days` days
+fn minutes_ago(rng: &mut StdRng, days: u32) -> String {
+    format!("-{} minutes", rng.gen_range(0..days as i64 * 1440))
+}
+
+/// 2-5 distinct skills
+fn pick_skills(rng: &mut StdRng) -> Vec<&'static str> {
+    let count = rng.gen_range(2..=5);
+    rand::seq::index::sample(rng, SKILLS.len(), count)
+        .into_iter()
+        .map(|i| SKILLS[i])
+        .collect()
+}
+
+/// Revisions per proposal: usually one, each further edit half as likely
+fn revision_count(rng: &mut StdRng) -> usize {
+    let mut count = 1;
+    while count < MAX_REVISIONS && rng.gen_bool(0.5) {
+        count += 1;
+    }
+    count
+}
+
+/// Roughly `words` words of proposal-like text
+fn proposal_text(rng: &mut StdRng, words: usize) -> String {
+    let mut text = String::new();
+    let mut written = 0;
+    while written < words {
+        let sentence = PROPOSAL_SENTENCES[rng.gen_range(0..PROPOSAL_SENTENCES.len())];
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(sentence);
+        written += sentence.split_whitespace().count();
+    }
+    text
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rusqlite::Connection;
+    use crate::db::Database;
+    use tempfile::tempdir;
 
-    fn setup_test_db() -> Connection {
-        let conn = Connection::open_in_memory().unwrap();
-
-        // Create minimal schema for testing
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS proposals (
-                id INTEGER PRIMARY KEY,
-                title TEXT NOT NULL,
-                content TEXT NOT NULL,
-                job_description TEXT,
-                timestamp TEXT NOT NULL
-            )",
-            [],
-        )
-        .unwrap();
-
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS job_posts (
-                id INTEGER PRIMARY KEY,
-                title TEXT NOT NULL,
-                description TEXT NOT NULL,
-                url TEXT,
-                client_name TEXT,
-                key_skills TEXT,
-                posted_at TEXT,
-                imported_at TEXT NOT NULL
-            )",
-            [],
-        )
-        .unwrap();
+    fn config() -> SeedConfig {
+        SeedConfig::from_options(None).unwrap()
+    }
 
-        conn
+    fn count(conn: &Connection, table: &str) -> i64 {
+        conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+            row.get(0)
+        })
+        .unwrap()
     }
 
     #[test]
     fn test_seed_proposals() {
-        let mut conn = setup_test_db();
-        seed_proposals_internal(&mut conn, 10).unwrap();
-
-        let count: i64 = conn
-            .query_row("SELECT COUNT(*) FROM proposals", [], |row| row.get(0))
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let mut conn = db.conn.lock().unwrap();
+
+        let summary = seed_proposals_internal(&mut conn, 10, 1, config()).unwrap();
+        assert_eq!(summary.proposals, 10);
+        assert_eq!(count(&conn, "proposals"), 10);
+        assert_eq!(count(&conn, "proposal_revisions"), summary.revisions as i64);
+        // No seeded job posts yet, so nothing to link to
+        let linked: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM proposals WHERE job_post_id IS NOT NULL",
+                [],
+                |row| row.get(0),
+            )
             .unwrap();
-
-        assert_eq!(count, 10);
+        assert_eq!(linked, 0);
     }
 
     #[test]
     fn test_seed_job_posts() {
-        let mut conn = setup_test_db();
-        seed_job_posts_internal(&mut conn, 10).unwrap();
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let mut conn = db.conn.lock().unwrap();
+
+        let summary = seed_job_posts_internal(&mut conn, 10, 1, config()).unwrap();
+        assert_eq!(count(&conn, "job_posts"), 10);
+        assert_eq!(count(&conn, "job_scores"), 10);
+        assert_eq!(count(&conn, "job_skills"), summary.job_skills as i64);
+        assert!((20..=50).contains(&summary.job_skills));
+    }
 
-        let count: i64 = conn
-            .query_row("SELECT COUNT(*) FROM job_posts", [], |row| row.get(0))
-            .unwrap();
+    #[test]
+    fn test_same_seed_same_data() {
+        let dump = |seed: u64| {
+            let dir = tempdir().unwrap();
+            let db = Database::new(dir.path().join("test.db"), None).unwrap();
+            let mut conn = db.conn.lock().unwrap();
+            seed_full_dataset_internal(&mut conn, SeedProfile::Small, seed, config()).unwrap();
+            let mut stmt = conn
+                .prepare(
+                    "SELECT generated_text, outcome_status, job_post_id FROM proposals ORDER BY id",
+                )
+                .unwrap();
+            let rows = stmt
+                .query_map([], |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, Option<i64>>(2)?,
+                    ))
+                })
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            rows
+        };
+        assert_eq!(dump(7), dump(7));
+        assert_ne!(dump(7), dump(8));
+    }
 
-        assert_eq!(count, 10);
+    #[test]
+    fn test_full_dataset_is_consistent() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let mut conn = db.conn.lock().unwrap();
+
+        let summary =
+            seed_full_dataset_internal(&mut conn, SeedProfile::Small, 3, config()).unwrap();
+        assert_eq!(summary.proposals, 100);
+        assert_eq!(summary.job_posts, 50);
+        assert_eq!(summary.job_scores, 50);
+
+        // Every linked proposal points at a scored seeded job and carries its text
+        let orphans: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM proposals p
+                 LEFT JOIN job_posts j ON j.id = p.job_post_id
+                 LEFT JOIN job_scores s ON s.job_post_id = p.job_post_id
+                 WHERE p.job_post_id IS NOT NULL
+                   AND (s.id IS NULL OR p.job_content != j.raw_content)",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(orphans, 0);
+
+        let linked: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM proposals WHERE job_post_id IS NOT NULL",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert!((40..=80).contains(&linked), "linked = {}", linked);
+
+        // Revision numbers are contiguous and the latest matches the proposal text
+        let mismatched: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM proposals p
+                 JOIN proposal_revisions r ON r.proposal_id = p.id
+                 WHERE r.revision_number = (SELECT MAX(revision_number) FROM proposal_revisions
+                                            WHERE proposal_id = p.id)
+                   AND r.content != p.generated_text",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(mismatched, 0);
     }
 
     #[test]
-    fn test_large_seed() {
-        let mut conn = setup_test_db();
+    fn test_clear_removes_only_seeded_rows() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let mut conn = db.conn.lock().unwrap();
+
+        let job_id = crate::db::queries::job_posts::insert_job_post(
+            &conn,
+            None,
+            "Real job",
+            Some("Real Client"),
+        )
+        .unwrap();
+        crate::db::queries::proposals::insert_proposal_with_context(
+            &conn,
+            "Real job",
+            "Real proposal",
+            Some("completed"),
+            None,
+            Some(job_id),
+        )
+        .unwrap();
 
-        // Test seeding 500 proposals (benchmark requirement)
-        seed_proposals_internal(&mut conn, 500).unwrap();
+        let seeded =
+            seed_full_dataset_internal(&mut conn, SeedProfile::Small, 11, config()).unwrap();
+        let cleared = clear_test_data_internal(&mut conn).unwrap();
+        assert_eq!(cleared, seeded);
 
-        let count: i64 = conn
-            .query_row("SELECT COUNT(*) FROM proposals", [], |row| row.get(0))
-            .unwrap();
+        assert_eq!(count(&conn, "proposals"), 1);
+        assert_eq!(count(&conn, "job_posts"), 1);
+        assert_eq!(count(&conn, "job_scores"), 0);
+        assert_eq!(count(&conn, "safety_overrides"), 0);
+    }
 
-        assert_eq!(count, 500);
+    #[test]
+    fn test_distributions_and_options() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let lengths: Vec<f64> = (0..2000)
+            .map(|_| log_normal(&mut rng, 180.0, 0.45))
+            .collect();
+        let below = lengths.iter().filter(|l| **l < 180.0).count();
+        // Median near 180, with a right tail
+        assert!((900..1100).contains(&below), "below median = {}", below);
+        assert!(lengths.iter().any(|l| *l > 400.0));
+
+        assert_eq!(resolve_count(None, Some(SeedProfile::Medium)), Ok(2_000));
+        assert_eq!(resolve_count(Some(5), Some(SeedProfile::Large)), Ok(5));
+        assert!(resolve_count(None, None).is_err());
+        assert!(SeedConfig::from_options(Some(SeedOptions {
+            linked_fraction: Some(1.5),
+            ..Default::default()
+        }))
+        .is_err());
     }
 }
@@ -0,0 +1,308 @@
+//! "Try 3 variants": several proposals for one job from a single request
+//!
+//! `generate_proposal_variants` runs the same streaming generation as
+//! `generate_proposal_streaming` up to `MAX_VARIANTS` times in a row, under one
+//! cooldown. Variant n always gets the same temperature and, when A/B weights
+//! allow, its own hook strategy, so regenerating with unchanged settings gives
+//! the same line-up. Each variant streams on `generation:variant:{n}:*` and is
+//! saved as a proposal tagged with a shared `variant_group_id` (V57);
+//! `promote_variant` keeps one and discards the others.
+
+use crate::db::queries::generation_metadata::{self, NewGenerationMetadata};
+use crate::db::queries::{hook_strategies, proposal_variants, proposals};
+use crate::db::AppDatabase;
+use crate::errors::AppError;
+use crate::{
+    ab_testing, claude, config, db, proposal_length, CooldownState, DraftState, VoiceCache,
+};
+use rusqlite::Connection;
+use serde::Serialize;
+use tauri::{AppHandle, State};
+
+/// Most variants one request generates; larger counts are capped
+pub const MAX_VARIANTS: u8 = 3;
+
+/// Temperature of variant n is `VARIANT_TEMPERATURES[n - 1]`: the first matches an
+/// ordinary generation (the API default), later ones are progressively more conservative
+const VARIANT_TEMPERATURES: [f32; MAX_VARIANTS as usize] = [1.0, 0.8, 0.6];
+
+/// A saved variant
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedVariant {
+    /// 1-based, matches the `generation:variant:{index}:*` events
+    pub index: u8,
+    pub proposal_id: i64,
+    pub hook_strategy_id: Option<String>,
+    pub temperature: f32,
+    pub proposal_text: String,
+    pub word_count: usize,
+}
+
+/// Result of `generate_proposal_variants`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariantGenerationResult {
+    pub variant_group_id: String,
+    /// Number of variants asked for, after the cap
+    pub requested: u8,
+    pub variants: Vec<GeneratedVariant>,
+    /// Why generation stopped before `requested` variants; the saved ones are kept
+    pub error: Option<String>,
+}
+
+/// Insert a variant's proposal row before streaming, as a draft with its metadata
+/// and group, so the stream's autosave and completion update that row
+fn insert_variant_draft(
+    conn: &Connection,
+    job_content: &str,
+    metadata: &NewGenerationMetadata,
+    variant_group_id: &str,
+) -> Result<i64, rusqlite::Error> {
+    generation_metadata::save_proposal_with_metadata(conn, Some(metadata), |tx| {
+        // Rotated strategies are not a random assignment: keep them out of A/B stats
+        let id = proposals::insert_proposal_with_ab_context(
+            tx,
+            job_content,
+            "",
+            Some("draft"),
+            metadata.hook_strategy_id.as_deref(),
+            None,
+            false,
+            None,
+        )?;
+        proposal_variants::set_variant_group(tx, id, variant_group_id)?;
+        Ok(id)
+    })
+}
+
+/// Generate up to `MAX_VARIANTS` proposals for `job_content`, one after another.
+/// Uses the active voice profile, humanization intensity and persona addendum like
+/// `generate_proposal_streaming`; hook strategies rotate through the A/B rotation
+/// (`ab_testing::variant_hook_strategies`), or are left unset when no strategy has
+/// a weight. The cooldown is checked once and starts with the first saved variant.
+/// If the first variant fails the error is returned; a later failure stops the run
+/// and returns the variants saved so far with `error` set.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_proposal_variants(
+    job_content: String,
+    count: u8,
+    app_handle: AppHandle,
+    config_state: State<'_, config::ConfigState>,
+    database: State<'_, AppDatabase>,
+    draft_state: State<'_, DraftState>,
+    cooldown: State<'_, CooldownState>,
+    voice_cache: State<'_, VoiceCache>,
+) -> Result<VariantGenerationResult, AppError> {
+    let _timer = crate::command_metrics::time("generate_proposal_variants");
+    let database = database.get()?;
+    let remaining = cooldown.remaining_seconds();
+    if remaining > 0 {
+        return Err(AppError::rate_limited(remaining));
+    }
+    if count == 0 {
+        return Err(AppError::validation("Variant count must be at least 1"));
+    }
+    let count = count.min(MAX_VARIANTS);
+
+    let api_key = config_state.get_api_key()?;
+    let api_key_profile = config_state.active_profile().ok();
+
+    let (
+        voice_profile,
+        voice_profile_name,
+        voice_profile_version,
+        intensity,
+        sanitized,
+        prompt_addendum,
+        strategies,
+    ) = {
+        let conn = database.conn.lock().map_err(AppError::database_locked)?;
+        let profile_name = db::queries::voice_profile::active_profile_name(&conn);
+        let voice_profile = crate::load_voice_profile(&conn, &voice_cache, &profile_name)?;
+        let (voice_profile_version, voice_profile_name) = match voice_profile {
+            Some(_) => (
+                db::queries::voice_profile::get_voice_profile_version(&conn, &profile_name)
+                    .map_err(|e| {
+                        AppError::database(format!("Failed to get voice profile version: {}", e))
+                    })?,
+                Some(profile_name),
+            ),
+            None => (None, None),
+        };
+        let intensity: String = crate::settings::schema::get_typed(
+            &conn,
+            crate::settings::schema::Key::HumanizationIntensity,
+        )
+        .map_err(AppError::database)?;
+        let all_strategies = hook_strategies::get_all_hook_strategies(&conn)
+            .map_err(|e| AppError::database(format!("Failed to load hook strategies: {}", e)))?;
+        let strategies = ab_testing::variant_hook_strategies(&all_strategies, count as usize)
+            .unwrap_or_else(|_| {
+                tracing::info!("No hook strategy in A/B rotation, generating variants without one");
+                Vec::new()
+            });
+        (
+            voice_profile,
+            voice_profile_name,
+            voice_profile_version,
+            intensity,
+            crate::sanitize_job_content_for_generation(&conn, &job_content),
+            crate::load_system_prompt_addendum(&conn),
+            strategies,
+        )
+    };
+    let length_target = voice_profile.as_ref().map(|profile| {
+        proposal_length::LengthTarget::from_voice_preference(profile.length_preference)
+    });
+
+    let variant_group_id = uuid::Uuid::new_v4().to_string();
+    let mut variants = Vec::new();
+    let mut error = None;
+
+    for index in 1..=count {
+        let hook_strategy_id = strategies.get(usize::from(index - 1)).cloned();
+        let variant = claude::VariantStream {
+            index,
+            temperature: VARIANT_TEMPERATURES[usize::from(index - 1)],
+        };
+        let generation_context = claude::GenerationContext::for_prompt(
+            &claude::GenerationPromptOptions {
+                humanization_intensity: &intensity,
+                system_prompt_addendum: prompt_addendum.as_deref(),
+                voice_profile: voice_profile.as_ref(),
+                length_target,
+                ..Default::default()
+            },
+            hook_strategy_id.as_deref(),
+        );
+        let metadata = NewGenerationMetadata {
+            model: claude::MODEL.to_string(),
+            humanization_intensity: intensity.clone(),
+            hook_strategy_id: hook_strategy_id.clone(),
+            voice_profile_version: voice_profile_version.clone(),
+            voice_profile_name: voice_profile_name.clone(),
+            target_words: length_target.map(|t| t.target_words()),
+            api_key_profile: api_key_profile.clone(),
+            generation_context: Some(generation_context),
+        };
+
+        let inserted = database
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))
+            .and_then(|conn| {
+                insert_variant_draft(&conn, &job_content, &metadata, &variant_group_id)
+                    .map_err(|e| format!("Failed to save variant: {}", e))
+            });
+        let proposal_id = match inserted {
+            Ok(id) => id,
+            Err(e) if variants.is_empty() => return Err(AppError::database(e)),
+            Err(e) => {
+                error = Some(e);
+                break;
+            }
+        };
+        *draft_state
+            .current_draft_id
+            .lock()
+            .map_err(|e| format!("Draft state lock error: {}", e))? = Some(proposal_id);
+
+        let result = claude::generate_proposal_streaming_timed(
+            &job_content,
+            Some(sanitized.content.as_str()),
+            app_handle.clone(),
+            api_key.as_deref(),
+            database,
+            &draft_state,
+            &intensity,
+            None,
+            prompt_addendum.as_deref(),
+            length_target,
+            voice_profile.as_ref(),
+            None,
+            claude::StreamChannel::Variant(variant),
+        )
+        .await;
+
+        match result {
+            Ok((proposal_text, _)) => {
+                // Story 3.8: one cooldown for the whole set, from the first success
+                if variants.is_empty() {
+                    cooldown.record();
+                }
+                variants.push(GeneratedVariant {
+                    index,
+                    proposal_id,
+                    hook_strategy_id,
+                    temperature: variant.temperature,
+                    word_count: proposal_length::word_count(&proposal_text),
+                    proposal_text,
+                });
+            }
+            Err(e) => {
+                // A half-written variant isn't worth recovering: drop its draft
+                if let Ok(mut draft_id) = draft_state.current_draft_id.lock() {
+                    if *draft_id == Some(proposal_id) {
+                        *draft_id = None;
+                    }
+                }
+                if let Ok(conn) = database.conn.lock() {
+                    if let Err(e) = proposals::discard_draft(&conn, proposal_id) {
+                        tracing::warn!(proposal_id, "Failed to discard failed variant: {}", e);
+                    }
+                }
+                if variants.is_empty() {
+                    return Err(e.into());
+                }
+                tracing::warn!(
+                    index,
+                    "Variant generation failed, returning partial results: {}",
+                    e
+                );
+                error = Some(e);
+                break;
+            }
+        }
+    }
+
+    tracing::info!(
+        requested = count,
+        saved = variants.len(),
+        "Generated proposal variants"
+    );
+    Ok(VariantGenerationResult {
+        variant_group_id,
+        requested: count,
+        variants,
+        error,
+    })
+}
+
+/// Keep `proposal_id` from a variant group and mark the group's other proposals
+/// discarded (they leave history and analytics but are not deleted). Promoting a
+/// different variant later restores it. Returns the number of variants discarded.
+#[tauri::command]
+pub fn promote_variant(
+    database: State<'_, AppDatabase>,
+    variant_group_id: String,
+    proposal_id: i64,
+) -> Result<serde_json::Value, AppError> {
+    let database = database.get()?;
+    let conn = database.conn.lock().map_err(AppError::database_locked)?;
+
+    let discarded = proposal_variants::promote_variant(&conn, &variant_group_id, proposal_id)
+        .map_err(|e| AppError::database(format!("Failed to promote variant: {}", e)))?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Proposal {} is not in variant group {}",
+                proposal_id, variant_group_id
+            ))
+        })?;
+
+    Ok(serde_json::json!({
+        "proposalId": proposal_id,
+        "discarded": discarded
+    }))
+}
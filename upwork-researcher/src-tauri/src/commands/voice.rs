@@ -4,8 +4,8 @@
 //! Privacy: All proposal text stays local - never transmitted to API (AR-12).
 
 use crate::db::queries::golden_set::{
-    add_golden_proposal, delete_golden_proposal, get_golden_proposal_count, get_golden_proposals,
-    GoldenProposal,
+    add_golden_proposal_with_min_words, delete_golden_proposal, get_golden_proposal_count,
+    get_golden_proposals, get_min_word_count, GoldenProposal,
 };
 use crate::db::AppDatabase;
 use serde::{Deserialize, Serialize};
@@ -23,8 +23,9 @@ pub struct FileContent {
 /// # Story 5.3: AC-2, AC-3, AC-4, AC-6
 /// - AC-2: Stores proposals from file upload
 /// - AC-3: Stores proposals from paste area
-/// - AC-4: Validates minimum 200 words
+/// - AC-4: Validates minimum word count (200 by default, `golden_set_min_words` setting)
 /// - AC-6: Saves to golden_set_proposals table
+/// - Rejects empty content and near-duplicates of existing golden proposals
 #[tauri::command]
 pub async fn add_golden_proposal_command(
     content: String,
@@ -37,10 +38,12 @@ pub async fn add_golden_proposal_command(
         .lock()
         .map_err(|e| format!("Database lock error: {}", e))?;
 
-    add_golden_proposal(&conn, &content, source_filename.as_deref()).map_err(|e| match e {
-        rusqlite::Error::SqliteFailure(_, Some(msg)) => msg,
-        _ => format!("Failed to add proposal: {}", e),
-    })
+    let min_word_count = get_min_word_count(&conn);
+    add_golden_proposal_with_min_words(&conn, &content, source_filename.as_deref(), min_word_count)
+        .map_err(|e| match e {
+            rusqlite::Error::SqliteFailure(_, Some(msg)) => msg,
+            _ => format!("Failed to add proposal: {}", e),
+        })
 }
 
 /// Tauri command: Get all golden proposals
@@ -166,6 +169,8 @@ pub struct CalibrationResult {
     pub profile: crate::voice::VoiceProfile,
     pub elapsed_ms: u64,
     pub proposals_analyzed: usize,
+    /// Confidence and warnings based on sample count and consistency
+    pub quality: crate::voice::CalibrationQuality,
 }
 
 /// Progress event payload
@@ -186,9 +191,13 @@ struct AnalysisProgress {
 /// - AC-4: Emits progress events: "Analyzing proposals... (3/5)"
 /// - AC-5: Returns completion message with elapsed time
 /// - AC-6: Returns VoiceProfile to frontend
+///
+/// `profile_name` selects the voice profile to calibrate (created if missing);
+/// defaults to the active profile.
 #[tauri::command]
 pub async fn calibrate_voice(
     window: tauri::Window,
+    profile_name: Option<String>,
     database: State<'_, AppDatabase>,
     voice_cache: State<'_, crate::VoiceCache>, // Story 5.8 Subtask 4.5: Cache invalidation
 ) -> Result<CalibrationResult, String> {
@@ -198,21 +207,23 @@ pub async fn calibrate_voice(
     let start = Instant::now();
 
     // Load golden set proposals (Task 3.2)
-    let (proposals, total) = {
+    let (proposals, total, profile_name) = {
         let conn = database
             .conn
             .lock()
             .map_err(|e| format!("Database lock error: {}", e))?;
+        let profile_name = target_profile_name(&conn, profile_name.as_deref())?;
 
         let proposals =
             get_golden_proposals(&conn).map_err(|e| format!("Failed to load proposals: {}", e))?;
 
-        if proposals.len() < 3 {
-            return Err("At least 3 proposals required for voice calibration".to_string());
+        // Small sets are allowed but flagged as low confidence in the result
+        if proposals.is_empty() {
+            return Err("At least 1 proposal required for voice calibration".to_string());
         }
 
         let total = proposals.len();
-        (proposals, total)
+        (proposals, total, profile_name)
     }; // conn dropped here
 
     let mut proposal_texts = Vec::new();
@@ -234,6 +245,7 @@ pub async fn calibrate_voice(
 
     // Run local analysis (Task 3.3, AC-1, AC-2)
     let profile = crate::voice::aggregate_voice_profile(&proposal_texts);
+    let quality = crate::voice::assess_calibration_quality(&proposal_texts);
 
     // Track elapsed time (Task 3.6, AC-3)
     let elapsed = start.elapsed();
@@ -245,19 +257,21 @@ pub async fn calibrate_voice(
             .conn
             .lock()
             .map_err(|e| format!("Database lock error: {}", e))?;
-        let row = VoiceProfileRow::from_voice_profile(&profile, "default");
+        let row = VoiceProfileRow::from_voice_profile(&profile, &profile_name);
         voice_profile::save_voice_profile(&conn, &row)
             .map_err(|e| format!("Warning: Failed to save voice profile: {}", e))?;
+        reset_edits_after_calibration(&conn);
     }
 
     // Story 5.8 Subtask 4.5: Invalidate cache after recalibration (AC-6)
-    voice_cache.invalidate();
+    voice_cache.invalidate_profile(&profile_name);
     tracing::info!("Voice profile cache invalidated after calibration");
 
     Ok(CalibrationResult {
         profile,
         elapsed_ms: elapsed.as_millis() as u64,
         proposals_analyzed: total,
+        quality,
     })
 }
 
@@ -269,8 +283,10 @@ pub async fn calibrate_voice(
 /// - Returns voice profile if exists (AC-2: load on app startup)
 /// - Returns null if no profile exists (triggers empty state in UI)
 /// - Completes in <100ms (indexed query)
+/// - `profile_name` defaults to the active profile
 #[tauri::command]
 pub async fn get_voice_profile(
+    profile_name: Option<String>,
     database: State<'_, AppDatabase>,
 ) -> Result<Option<crate::voice::VoiceProfile>, String> {
     use crate::db::queries::voice_profile;
@@ -281,7 +297,8 @@ pub async fn get_voice_profile(
         .lock()
         .map_err(|e| format!("Database lock error: {}", e))?;
 
-    voice_profile::get_voice_profile(&conn, "default")
+    let profile_name = voice_profile::resolve_profile_name(&conn, profile_name.as_deref());
+    voice_profile::get_voice_profile(&conn, &profile_name)
         .map(|opt: Option<voice_profile::VoiceProfileRow>| opt.map(|row| row.to_voice_profile()))
         .map_err(|e| format!("Failed to get voice profile: {}", e))
 }
@@ -291,10 +308,13 @@ pub async fn get_voice_profile(
 /// # Story 5-5b: AC-1, AC-3, AC-4
 /// - Saves new profile or updates existing (UPSERT)
 /// - Validates data integrity (AC-5: ranges, JSON)
+/// - `profile_name` defaults to the active profile
 #[tauri::command]
 pub async fn save_voice_profile(
     profile: crate::voice::VoiceProfile,
+    profile_name: Option<String>,
     database: State<'_, AppDatabase>,
+    voice_cache: State<'_, crate::VoiceCache>,
 ) -> Result<(), String> {
     use crate::db::queries::voice_profile::{self, VoiceProfileRow};
 
@@ -304,28 +324,294 @@ pub async fn save_voice_profile(
         .lock()
         .map_err(|e| format!("Database lock error: {}", e))?;
 
-    let row = VoiceProfileRow::from_voice_profile(&profile, "default");
+    let profile_name = target_profile_name(&conn, profile_name.as_deref())?;
+    let row = VoiceProfileRow::from_voice_profile(&profile, &profile_name);
     voice_profile::save_voice_profile(&conn, &row)
-        .map_err(|e| format!("Failed to save voice profile: {}", e))
+        .map_err(|e| format!("Failed to save voice profile: {}", e))?;
+    reset_edits_after_calibration(&conn);
+    voice_cache.invalidate_profile(&profile_name);
+    Ok(())
 }
 
-/// Tauri command: Delete voice profile (reset for recalibration)
+/// Tauri command: Delete a voice profile by name
 ///
 /// # Story 5-5b: AC-4
-/// - Removes existing profile to allow recalibration
 /// - Returns true if profile was deleted, false if none existed
+/// - Refuses to delete the last profile (recalibrate it instead)
+/// - Deleting the active profile makes the oldest remaining one active
+#[tauri::command]
+pub async fn delete_voice_profile(
+    name: String,
+    database: State<'_, AppDatabase>,
+    voice_cache: State<'_, crate::VoiceCache>,
+) -> Result<bool, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    let deleted = delete_voice_profile_internal(&conn, name.trim())?;
+    if deleted {
+        voice_cache.invalidate_profile(name.trim());
+        tracing::info!("Voice profile deleted, cache entry invalidated");
+    }
+    Ok(deleted)
+}
+
+/// Delete `name` unless it's the last profile; moves the active selection if needed
+pub(crate) fn delete_voice_profile_internal(
+    conn: &rusqlite::Connection,
+    name: &str,
+) -> Result<bool, String> {
+    use crate::db::queries::{settings, voice_profile};
+
+    let profiles = voice_profile::list_voice_profiles(conn)
+        .map_err(|e| format!("Failed to list voice profiles: {}", e))?;
+    let Some(target) = profiles.iter().find(|p| p.name == name) else {
+        return Ok(false);
+    };
+    if profiles.len() == 1 {
+        return Err("Cannot delete the last voice profile. Recalibrate it instead.".to_string());
+    }
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+    voice_profile::delete_voice_profile(&tx, name)
+        .map_err(|e| format!("Failed to delete voice profile: {}", e))?;
+    if target.is_active {
+        let fallback = profiles
+            .iter()
+            .find(|p| p.name != name)
+            .map(|p| p.name.as_str())
+            .unwrap_or(voice_profile::DEFAULT_PROFILE_NAME);
+        settings::set_setting(&tx, voice_profile::ACTIVE_VOICE_PROFILE_SETTING, fallback)
+            .map_err(|e| format!("Failed to switch active voice profile: {}", e))?;
+        tracing::info!(
+            profile = fallback,
+            "Active voice profile deleted, switched to fallback"
+        );
+    }
+    tx.commit()
+        .map_err(|e| format!("Failed to delete voice profile: {}", e))?;
+    Ok(true)
+}
+
+/// Tauri command: List voice profiles, oldest first, with the active one flagged
+#[tauri::command]
+pub async fn list_voice_profiles(
+    database: State<'_, AppDatabase>,
+) -> Result<Vec<crate::db::queries::voice_profile::VoiceProfileSummary>, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    crate::db::queries::voice_profile::list_voice_profiles(&conn)
+        .map_err(|e| format!("Failed to list voice profiles: {}", e))
+}
+
+/// Tauri command: Create a voice profile with neutral parameters
+///
+/// The new profile isn't made active; calibrate it or adjust its sliders, then
+/// select it with `set_active_voice_profile` or per proposal at generation time.
+#[tauri::command]
+pub async fn create_voice_profile(
+    name: String,
+    database: State<'_, AppDatabase>,
+) -> Result<crate::db::queries::voice_profile::VoiceProfileSummary, String> {
+    use crate::db::queries::voice_profile::{self, VoiceProfileRow};
+
+    let name = voice_profile::validate_profile_name(&name)?;
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    ensure_profile_name_free(&conn, &name)?;
+    voice_profile::save_voice_profile(&conn, &VoiceProfileRow::with_defaults(&name))
+        .map_err(|e| format!("Failed to create voice profile: {}", e))?;
+    voice_profile::list_voice_profiles(&conn)
+        .map_err(|e| format!("Failed to list voice profiles: {}", e))?
+        .into_iter()
+        .find(|p| p.name == name)
+        .ok_or_else(|| format!("Voice profile '{}' was not created", name))
+}
+
+/// Tauri command: Rename a voice profile
+///
+/// Keeps it active if it was, and relabels proposals generated with it.
 #[tauri::command]
-pub async fn delete_voice_profile(database: State<'_, AppDatabase>) -> Result<bool, String> {
+pub async fn rename_voice_profile(
+    old_name: String,
+    new_name: String,
+    database: State<'_, AppDatabase>,
+    voice_cache: State<'_, crate::VoiceCache>,
+) -> Result<(), String> {
     use crate::db::queries::voice_profile;
 
+    let new_name = voice_profile::validate_profile_name(&new_name)?;
+    let old_name = old_name.trim();
+    if new_name == old_name {
+        return Ok(());
+    }
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    ensure_profile_name_free(&conn, &new_name)?;
+    if !voice_profile::rename_voice_profile(&conn, old_name, &new_name)
+        .map_err(|e| format!("Failed to rename voice profile: {}", e))?
+    {
+        return Err(format!("Voice profile '{}' not found", old_name));
+    }
+    voice_cache.invalidate_profile(old_name);
+    Ok(())
+}
+
+/// Tauri command: Make `name` the voice profile used for generation
+#[tauri::command]
+pub async fn set_active_voice_profile(
+    name: String,
+    database: State<'_, AppDatabase>,
+) -> Result<(), String> {
+    use crate::db::queries::{settings, voice_profile};
+
     let database = database.get()?;
     let conn = database
         .conn
         .lock()
         .map_err(|e| format!("Database lock error: {}", e))?;
 
-    voice_profile::delete_voice_profile(&conn, "default")
-        .map_err(|e| format!("Failed to delete voice profile: {}", e))
+    let name = name.trim();
+    if voice_profile::get_voice_profile(&conn, name)
+        .map_err(|e| format!("Failed to get voice profile: {}", e))?
+        .is_none()
+    {
+        return Err(format!("Voice profile '{}' not found", name));
+    }
+    settings::set_setting(&conn, voice_profile::ACTIVE_VOICE_PROFILE_SETTING, name)
+        .map_err(|e| format!("Failed to set active voice profile: {}", e))?;
+    tracing::info!(profile = name, "Active voice profile changed");
+    Ok(())
+}
+
+/// Profile a calibration writes to: `requested` (validated) or the active profile
+fn target_profile_name(
+    conn: &rusqlite::Connection,
+    requested: Option<&str>,
+) -> Result<String, String> {
+    match requested {
+        Some(name) => crate::db::queries::voice_profile::validate_profile_name(name),
+        None => Ok(crate::db::queries::voice_profile::active_profile_name(conn)),
+    }
+}
+
+fn ensure_profile_name_free(conn: &rusqlite::Connection, name: &str) -> Result<(), String> {
+    let exists = crate::db::queries::voice_profile::get_voice_profile(conn, name)
+        .map_err(|e| format!("Failed to get voice profile: {}", e))?
+        .is_some();
+    if exists {
+        return Err(format!("A voice profile named '{}' already exists", name));
+    }
+    Ok(())
+}
+
+/// Tauri command: Compare generated text against the calibrated voice profile
+///
+/// Runs the text through the calibration measurements and returns per-dimension
+/// deltas plus an overall match percentage. When no profile exists, returns the
+/// measured metrics alone with a note instead of erroring. `profile_name` defaults
+/// to the active profile.
+#[tauri::command]
+pub async fn analyze_voice_match(
+    text: String,
+    profile_name: Option<String>,
+    database: State<'_, AppDatabase>,
+) -> Result<crate::voice::VoiceMatchResult, String> {
+    use crate::db::queries::voice_profile;
+
+    if text.trim().is_empty() {
+        return Err("Text is required for voice match analysis".to_string());
+    }
+
+    let target = {
+        let database = database.get()?;
+        let conn = database
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        let profile_name = voice_profile::resolve_profile_name(&conn, profile_name.as_deref());
+        voice_profile::get_voice_profile(&conn, &profile_name)
+            .map_err(|e| format!("Failed to get voice profile: {}", e))?
+            .map(|row| row.to_voice_profile())
+    };
+
+    Ok(crate::voice::compare_voice_match(&text, target.as_ref()))
+}
+
+// ==================== Story 8.6: Voice Learning Recalibration ====================
+
+/// Default `voice_learning_threshold`: edited proposals before recalibration is suggested
+pub const DEFAULT_VOICE_LEARNING_THRESHOLD: i64 = 10;
+
+/// Whether enough proposals were edited since the last calibration to suggest recalibrating
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecalibrationSuggestion {
+    pub should_suggest: bool,
+    pub edited_count: i32,
+    pub threshold: i32,
+    /// Edits still needed before the suggestion fires (0 once it does)
+    pub edits_remaining: i32,
+}
+
+pub(crate) fn recalibration_suggestion(
+    conn: &rusqlite::Connection,
+) -> Result<RecalibrationSuggestion, String> {
+    use crate::settings::schema::{get_typed, Key};
+
+    let edited_count = crate::db::queries::settings::get_proposals_edited_count(conn)
+        .map_err(|e| format!("Failed to get proposals edited count: {}", e))?;
+    let threshold: i32 = get_typed(conn, Key::VoiceLearningThreshold)?;
+    let edits_remaining = (threshold - edited_count).max(0);
+
+    Ok(RecalibrationSuggestion {
+        should_suggest: edits_remaining == 0,
+        edited_count,
+        threshold,
+        edits_remaining,
+    })
+}
+
+/// Restart the edited count after a profile is (re)calibrated or saved, so the
+/// suggestion does not fire again straight away. A failure only delays the next suggestion.
+fn reset_edits_after_calibration(conn: &rusqlite::Connection) {
+    if let Err(e) = crate::db::queries::settings::reset_proposals_edited(conn) {
+        tracing::warn!("Failed to reset proposals edited count: {}", e);
+    }
+}
+
+/// Tauri command: Should the app suggest recalibrating the voice profile?
+///
+/// Compares proposals edited since the last calibration against the
+/// `voice_learning_threshold` setting (default 10).
+#[tauri::command]
+pub async fn should_suggest_recalibration(
+    database: State<'_, AppDatabase>,
+) -> Result<RecalibrationSuggestion, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    recalibration_suggestion(&conn)
 }
 
 // ==================== Story 6-2: Manual Voice Parameter Adjustments ====================
@@ -369,25 +655,8 @@ pub async fn update_voice_parameters(
         .map_err(|e| format!("Failed to get voice profile: {}", e))?
     {
         Some(existing) => existing,
-        None => {
-            // No profile exists - create default
-            VoiceProfileRow {
-                id: None,
-                user_id: user_id.clone(),
-                tone_score: 5.0,
-                avg_sentence_length: 15.0,
-                vocabulary_complexity: 8.0,
-                structure_paragraphs_pct: 70,
-                structure_bullets_pct: 30,
-                technical_depth: 5.0,
-                length_preference: 5.0,
-                common_phrases: vec![],
-                sample_count: 0,
-                calibration_source: "Implicit".to_string(), // Must match CHECK constraint enum
-                created_at: None,
-                updated_at: None,
-            }
-        }
+        // No profile exists - create default
+        None => VoiceProfileRow::with_defaults(&user_id),
     };
 
     // Apply partial updates
@@ -406,7 +675,7 @@ pub async fn update_voice_parameters(
         .map_err(|e| format!("Failed to save voice profile: {}", e))?;
 
     // Invalidate cache (changes affect future proposals)
-    voice_cache.invalidate();
+    voice_cache.invalidate_profile(&user_id);
     tracing::info!("Voice profile cache invalidated after manual parameter update");
 
     // Return updated profile for frontend
@@ -494,40 +763,57 @@ pub fn map_answers_to_profile(answers: &QuickCalibrationAnswers) -> crate::voice
     }
 }
 
+/// Result of quick calibration
+///
+/// Questionnaire answers carry no writing samples, so `quality` always flags low confidence.
+#[derive(Clone, Serialize)]
+pub struct QuickCalibrationResult {
+    pub profile: crate::voice::VoiceProfile,
+    pub quality: crate::voice::CalibrationQuality,
+}
+
 /// Tauri command: Quick calibrate voice from 5-question answers
 ///
 /// # Story 5.7: AC-4, AC-5
 /// - Maps answers to VoiceProfile parameters
 /// - Saves profile via save_voice_profile
-/// - Returns created profile
+/// - Returns created profile with calibration quality
+/// - `profile_name` selects the profile to calibrate; defaults to the active one
 #[tauri::command]
 pub async fn quick_calibrate(
     answers: QuickCalibrationAnswers,
+    profile_name: Option<String>,
     database: State<'_, AppDatabase>,
     voice_cache: State<'_, crate::VoiceCache>, // Story 5.8 Subtask 4.5: Cache invalidation
-) -> Result<crate::voice::VoiceProfile, String> {
+) -> Result<QuickCalibrationResult, String> {
     // TD-5 AC-2: Single source of truth — uses extracted map_answers_to_profile
     let profile = map_answers_to_profile(&answers);
 
     // Save profile to database (Subtask 3.4)
-    {
+    let profile_name = {
         use crate::db::queries::voice_profile::{self, VoiceProfileRow};
         let database = database.get()?;
         let conn = database
             .conn
             .lock()
             .map_err(|e| format!("Database lock error: {}", e))?;
-        let row = VoiceProfileRow::from_voice_profile(&profile, "default");
+        let profile_name = target_profile_name(&conn, profile_name.as_deref())?;
+        let row = VoiceProfileRow::from_voice_profile(&profile, &profile_name);
         voice_profile::save_voice_profile(&conn, &row)
             .map_err(|e| format!("Failed to save voice profile: {}", e))?;
-    }
+        reset_edits_after_calibration(&conn);
+        profile_name
+    };
 
     // Story 5.8 Subtask 4.5: Invalidate cache after recalibration (AC-6)
-    voice_cache.invalidate();
+    voice_cache.invalidate_profile(&profile_name);
     tracing::info!("Voice profile cache invalidated after quick calibration");
 
     // Return created profile (Subtask 3.5)
-    Ok(profile)
+    Ok(QuickCalibrationResult {
+        profile,
+        quality: crate::voice::quick_calibration_quality(),
+    })
 }
 
 #[cfg(test)]
@@ -584,8 +870,10 @@ mod tests {
 
         // Add 2 proposals
         let content = "word ".repeat(200); // 200 words
-        golden_set::add_golden_proposal(&conn, &content, Some("first.txt")).unwrap();
-        golden_set::add_golden_proposal(&conn, &content, Some("second.txt")).unwrap();
+        golden_set::add_golden_proposal(&conn, &format!("{}first", content), Some("first.txt"))
+            .unwrap();
+        golden_set::add_golden_proposal(&conn, &format!("{}second", content), Some("second.txt"))
+            .unwrap();
 
         let result = golden_set::get_golden_proposals(&conn);
 
@@ -625,13 +913,46 @@ mod tests {
         // Add 3 proposals
         let content = "word ".repeat(200); // 200 words
         for i in 1..=3 {
-            golden_set::add_golden_proposal(&conn, &content, Some(&format!("{}.txt", i))).unwrap();
+            let unique = format!("{}proposal {}", content, i);
+            golden_set::add_golden_proposal(&conn, &unique, Some(&format!("{}.txt", i))).unwrap();
         }
 
         let count = golden_set::get_golden_proposal_count(&conn).unwrap();
         assert_eq!(count, 3, "Should have 3 proposals");
     }
 
+    #[test]
+    fn test_delete_voice_profile_keeps_one_and_moves_active() {
+        use crate::db::queries::{settings, voice_profile};
+
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        for name in ["default", "technical"] {
+            voice_profile::save_voice_profile(
+                &conn,
+                &voice_profile::VoiceProfileRow::with_defaults(name),
+            )
+            .unwrap();
+        }
+        settings::set_setting(
+            &conn,
+            voice_profile::ACTIVE_VOICE_PROFILE_SETTING,
+            "technical",
+        )
+        .unwrap();
+
+        assert!(!super::delete_voice_profile_internal(&conn, "missing").unwrap());
+
+        // Deleting the active profile falls back to the remaining one
+        assert!(super::delete_voice_profile_internal(&conn, "technical").unwrap());
+        assert_eq!(voice_profile::active_profile_name(&conn), "default");
+
+        // The last profile can't be deleted
+        let err = super::delete_voice_profile_internal(&conn, "default").unwrap_err();
+        assert!(err.contains("last voice profile"));
+        assert_eq!(voice_profile::list_voice_profiles(&conn).unwrap().len(), 1);
+    }
+
     #[test]
     fn test_extract_text_from_txt_file() {
         // Story 5.3: Subtask 5.4 - Test file text extraction (.txt)
@@ -670,7 +991,8 @@ mod tests {
 
         // Add 5 proposals
         for i in 1..=5 {
-            golden_set::add_golden_proposal(&conn, &content, Some(&format!("{}.txt", i))).unwrap();
+            let unique = format!("{}proposal {}", content, i);
+            golden_set::add_golden_proposal(&conn, &unique, Some(&format!("{}.txt", i))).unwrap();
         }
 
         // 6th should fail
@@ -813,4 +1135,45 @@ mod tests {
             consultative.structure_preference.bullets_pct
         );
     }
+
+    #[test]
+    fn test_recalibration_suggestion_threshold_and_reset() {
+        use crate::db::queries::settings;
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        settings::set_setting(&conn, "voice_learning_threshold", "3").unwrap();
+
+        settings::increment_proposals_edited(&conn).unwrap();
+        let suggestion = super::recalibration_suggestion(&conn).unwrap();
+        assert!(!suggestion.should_suggest);
+        assert_eq!(suggestion.edits_remaining, 2);
+        assert_eq!(suggestion.threshold, 3);
+
+        settings::increment_proposals_edited(&conn).unwrap();
+        settings::increment_proposals_edited(&conn).unwrap();
+        settings::increment_proposals_edited(&conn).unwrap();
+        let suggestion = super::recalibration_suggestion(&conn).unwrap();
+        assert!(suggestion.should_suggest);
+        assert_eq!(suggestion.edits_remaining, 0);
+
+        // Recalibrating restarts the count so the suggestion does not re-fire
+        super::reset_edits_after_calibration(&conn);
+        let suggestion = super::recalibration_suggestion(&conn).unwrap();
+        assert!(!suggestion.should_suggest);
+        assert_eq!(suggestion.edits_remaining, 3);
+    }
+
+    #[test]
+    fn test_recalibration_suggestion_default_threshold() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let suggestion = super::recalibration_suggestion(&conn).unwrap();
+        assert_eq!(
+            suggestion.threshold as i64,
+            super::DEFAULT_VOICE_LEARNING_THRESHOLD
+        );
+        assert_eq!(suggestion.edited_count, 0);
+        assert!(!suggestion.should_suggest);
+    }
 }
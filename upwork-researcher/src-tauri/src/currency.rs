@@ -0,0 +1,228 @@
+//! Budget currency detection and USD normalization (Story 4b.4)
+//!
+//! User rates are in USD, so a job budget in another currency is converted
+//! before `calculate_budget_alignment` compares it. The currency is detected
+//! from the job text (symbols like €/£ and ISO codes like "EUR"); a post with
+//! no currency marker is assumed to be USD, as before.
+//!
+//! Conversion uses a static table of USD-per-unit rates. There is no network
+//! lookup: each rate can be overridden with a `fx_rate_<code>_usd` setting
+//! (e.g. `fx_rate_eur_usd = 1.08`) and is refreshed by the user. A currency we
+//! recognize but have no rate for is "unknown" and gets no alignment rather
+//! than comparing raw numbers.
+
+use rusqlite::Connection;
+use std::collections::HashMap;
+
+/// Currency user rates are expressed in
+pub const BASE_CURRENCY: &str = "USD";
+
+/// Settings key prefix for rate overrides: `fx_rate_<code>_usd`
+pub const FX_RATE_SETTING_PREFIX: &str = "fx_rate_";
+const FX_RATE_SETTING_SUFFIX: &str = "_usd";
+
+/// USD per unit for common Upwork currencies (manually maintained)
+const DEFAULT_FX_RATES: &[(&str, f64)] =
+    &[("EUR", 1.08), ("GBP", 1.27), ("CAD", 0.73), ("AUD", 0.66)];
+
+/// ISO 4217 codes recognized in job text. Codes without a default rate need a
+/// `fx_rate_<code>_usd` setting before they can be normalized.
+const KNOWN_CODES: &[&str] = &[
+    "USD", "EUR", "GBP", "CAD", "AUD", "NZD", "CHF", "SGD", "INR", "JPY", "PLN", "BRL", "MXN",
+    "ZAR", "SEK", "NOK", "DKK",
+];
+
+/// Currency symbols, prefixed dollars before the bare "$"
+const SYMBOLS: &[(&str, &str)] = &[
+    ("AU$", "AUD"),
+    ("CA$", "CAD"),
+    ("A$", "AUD"),
+    ("C$", "CAD"),
+    ("€", "EUR"),
+    ("£", "GBP"),
+    ("₹", "INR"),
+    ("¥", "JPY"),
+    ("$", "USD"),
+];
+
+/// Detect the budget currency from job text: the first currency symbol or ISO
+/// code that appears. None if the text has no currency marker.
+pub fn detect_currency(text: &str) -> Option<String> {
+    for (i, _) in text.char_indices() {
+        let rest = &text[i..];
+        let at_word_start = text[..i]
+            .chars()
+            .next_back()
+            .is_none_or(|prev| !prev.is_alphanumeric());
+
+        if at_word_start {
+            let code = KNOWN_CODES.iter().find(|code| {
+                rest.starts_with(**code)
+                    && rest[code.len()..]
+                        .chars()
+                        .next()
+                        .is_none_or(|next| !next.is_alphabetic())
+            });
+            if let Some(code) = code {
+                return Some(code.to_string());
+            }
+        }
+
+        // Letter-prefixed dollars ("A$", "CA$") only at the start of a word
+        let symbol = SYMBOLS.iter().find(|(symbol, _)| {
+            rest.starts_with(symbol) && (at_word_start || !symbol.starts_with(char::is_alphabetic))
+        });
+        if let Some((_, code)) = symbol {
+            return Some(code.to_string());
+        }
+    }
+    None
+}
+
+/// Whole `amount` in `currency` (None = USD) for display: the currency's symbol
+/// when it has one ("€500", "A$60"), else the ISO code ("3000 PLN")
+pub fn format_amount(amount: f64, currency: Option<&str>) -> String {
+    let code = currency.unwrap_or(BASE_CURRENCY);
+    // Shortest symbol per code: the table lists "AU$" before "A$"
+    match SYMBOLS.iter().rev().find(|(_, c)| *c == code) {
+        Some((symbol, _)) => format!("{}{}", symbol, amount as i64),
+        None => format!("{} {}", amount as i64, code),
+    }
+}
+
+/// Settings key holding the USD rate for `code`
+pub fn fx_rate_setting_key(code: &str) -> String {
+    format!(
+        "{}{}{}",
+        FX_RATE_SETTING_PREFIX,
+        code.to_lowercase(),
+        FX_RATE_SETTING_SUFFIX
+    )
+}
+
+/// Validate a `fx_rate_<code>_usd` setting before it is saved
+pub fn validate_fx_rate_setting(key: &str, value: &str) -> Result<f64, String> {
+    let code = key
+        .strip_prefix(FX_RATE_SETTING_PREFIX)
+        .and_then(|k| k.strip_suffix(FX_RATE_SETTING_SUFFIX))
+        .map(str::to_uppercase)
+        .filter(|code| code != BASE_CURRENCY && KNOWN_CODES.contains(&code.as_str()))
+        .ok_or_else(|| format!("Unsupported exchange rate setting: {}", key))?;
+
+    let rate = value
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| format!("Invalid exchange rate for {}: {}", code, value))?;
+    if !rate.is_finite() || rate <= 0.0 {
+        return Err(format!("Exchange rate for {} must be positive", code));
+    }
+    Ok(rate)
+}
+
+/// USD-per-unit conversion table
+#[derive(Debug, Clone)]
+pub struct FxRates {
+    rates: HashMap<String, f64>,
+}
+
+impl Default for FxRates {
+    /// The built-in table
+    fn default() -> Self {
+        let mut rates: HashMap<String, f64> = DEFAULT_FX_RATES
+            .iter()
+            .map(|(code, rate)| (code.to_string(), *rate))
+            .collect();
+        rates.insert(BASE_CURRENCY.to_string(), 1.0);
+        Self { rates }
+    }
+}
+
+impl FxRates {
+    /// Built-in table with `fx_rate_<code>_usd` overrides; invalid values are ignored
+    pub fn from_settings(conn: &Connection) -> Self {
+        let mut fx = Self::default();
+        for code in KNOWN_CODES.iter().filter(|code| **code != BASE_CURRENCY) {
+            let key = fx_rate_setting_key(code);
+            let stored = crate::db::queries::settings::get_setting(conn, &key)
+                .ok()
+                .flatten();
+            if let Some(rate) = stored.and_then(|v| validate_fx_rate_setting(&key, &v).ok()) {
+                fx.rates.insert(code.to_string(), rate);
+            }
+        }
+        fx
+    }
+
+    /// Convert `amount` in `currency` (None = USD) to USD. None if there is no
+    /// rate for the currency.
+    pub fn to_usd(&self, amount: f64, currency: Option<&str>) -> Option<f64> {
+        let rate = self.rates.get(currency.unwrap_or(BASE_CURRENCY))?;
+        Some(amount * rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_currency_symbols() {
+        assert_eq!(detect_currency("Budget: €40/hr").as_deref(), Some("EUR"));
+        assert_eq!(detect_currency("£2,000 fixed").as_deref(), Some("GBP"));
+        assert_eq!(detect_currency("Paying $50/hour").as_deref(), Some("USD"));
+        assert_eq!(detect_currency("Rate A$60/hr").as_deref(), Some("AUD"));
+        assert_eq!(detect_currency("CA$3k budget").as_deref(), Some("CAD"));
+        // First marker wins
+        assert_eq!(detect_currency("€500 (about $540)").as_deref(), Some("EUR"));
+        assert_eq!(detect_currency("Please quote your rate"), None);
+    }
+
+    #[test]
+    fn test_detect_currency_iso_codes() {
+        assert_eq!(detect_currency("Budget 1500 EUR").as_deref(), Some("EUR"));
+        assert_eq!(detect_currency("GBP 45/hr").as_deref(), Some("GBP"));
+        assert_eq!(detect_currency("Paying 3000 PLN").as_deref(), Some("PLN"));
+        // Codes inside words are not matches
+        assert_eq!(detect_currency("EUROPE based team"), None);
+        assert_eq!(detect_currency("AUDIO engineer needed"), None);
+    }
+
+    #[test]
+    fn test_format_amount() {
+        assert_eq!(format_amount(55.0, None), "$55");
+        assert_eq!(format_amount(500.0, Some("EUR")), "€500");
+        assert_eq!(format_amount(500.9, Some("GBP")), "£500");
+        assert_eq!(format_amount(60.0, Some("AUD")), "A$60");
+        assert_eq!(format_amount(3000.0, Some("PLN")), "3000 PLN");
+    }
+
+    #[test]
+    fn test_conversion_and_unknown_currency() {
+        let fx = FxRates::default();
+        assert_eq!(fx.to_usd(100.0, None), Some(100.0));
+        assert_eq!(fx.to_usd(100.0, Some("GBP")), Some(127.0));
+        // Recognized but no rate configured
+        assert_eq!(fx.to_usd(3000.0, Some("PLN")), None);
+
+        assert_eq!(
+            validate_fx_rate_setting("fx_rate_pln_usd", "0.25"),
+            Ok(0.25)
+        );
+        assert!(validate_fx_rate_setting("fx_rate_eur_usd", "-1").is_err());
+        assert!(validate_fx_rate_setting("fx_rate_usd_usd", "1").is_err());
+        assert!(validate_fx_rate_setting("fx_rate_xyz_usd", "1").is_err());
+    }
+
+    #[test]
+    fn test_rate_overrides_from_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = crate::db::Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        crate::db::queries::settings::set_setting(&conn, "fx_rate_pln_usd", "0.25").unwrap();
+        crate::db::queries::settings::set_setting(&conn, "fx_rate_eur_usd", "1.5").unwrap();
+
+        let fx = FxRates::from_settings(&conn);
+        assert_eq!(fx.to_usd(100.0, Some("PLN")), Some(25.0));
+        assert_eq!(fx.to_usd(100.0, Some("EUR")), Some(150.0));
+    }
+}
@@ -0,0 +1,307 @@
+//! Long-running whole-database operations (re-key, migration, vacuum, export)
+//!
+//! These operations rewrite or read the entire database file, so only one may
+//! run at a time. `DbBusyState` is the shared flag they all take through
+//! `try_begin`; a second operation is refused instead of queued.
+//!
+//! Re-keying a multi-GB database can take minutes, so the PRAGMA runs on a
+//! blocking task (`run_rekey_task`) and callers get a size-based duration
+//! estimate up front (`rekey_precheck`). Compaction (WAL checkpoint + VACUUM)
+//! also rewrites the whole file and is estimated the same way
+//! (`compact_precheck`).
+
+use super::Database;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Instant;
+use zeroize::Zeroizing;
+
+/// Assumed SQLCipher re-key throughput used for duration estimates.
+/// Conservative: PRAGMA rekey decrypts and re-encrypts every page.
+pub const REKEY_THROUGHPUT_MB_PER_SEC: f64 = 40.0;
+
+/// Assumed VACUUM throughput for duration estimates (copies every live page twice:
+/// into the temp database and back)
+pub const VACUUM_THROUGHPUT_MB_PER_SEC: f64 = 50.0;
+
+/// Whole-database operations that must not overlap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DbOperation {
+    Rekey,
+    Migration,
+    Vacuum,
+    Export,
+    FactoryReset,
+}
+
+impl DbOperation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Rekey => "re-key",
+            Self::Migration => "migration",
+            Self::Vacuum => "vacuum",
+            Self::Export => "export",
+            Self::FactoryReset => "factory reset",
+        }
+    }
+}
+
+/// Managed state: which whole-database operation is running, if any
+#[derive(Default)]
+pub struct DbBusyState {
+    current: Mutex<Option<DbOperation>>,
+}
+
+/// Held for the duration of an operation; clears the busy flag on drop
+/// (including on early return or panic).
+pub struct DbBusyGuard<'a> {
+    state: &'a DbBusyState,
+}
+
+impl DbBusyState {
+    /// Mark `operation` as running, or fail if another operation is in progress
+    pub fn try_begin(&self, operation: DbOperation) -> Result<DbBusyGuard<'_>, String> {
+        let mut current = self
+            .current
+            .lock()
+            .map_err(|e| format!("Database busy lock error: {}", e))?;
+        if let Some(running) = *current {
+            return Err(format!(
+                "Cannot start database {}: a database {} is already in progress",
+                operation.label(),
+                running.label()
+            ));
+        }
+        *current = Some(operation);
+        Ok(DbBusyGuard { state: self })
+    }
+
+    /// The operation currently running, if any
+    pub fn current(&self) -> Option<DbOperation> {
+        self.current.lock().ok().and_then(|current| *current)
+    }
+}
+
+impl Drop for DbBusyGuard<'_> {
+    fn drop(&mut self) {
+        match self.state.current.lock() {
+            Ok(mut current) => *current = None,
+            Err(poisoned) => *poisoned.into_inner() = None,
+        }
+    }
+}
+
+/// Size and expected duration of a re-key or compaction, computed before starting
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationPrecheck {
+    /// Database file plus its WAL file, in bytes
+    pub size_bytes: u64,
+    pub estimated_seconds: u64,
+}
+
+/// Estimated re-key duration for a database of `size_bytes` (at least 1 second)
+pub fn estimate_rekey_seconds(size_bytes: u64) -> u64 {
+    estimate_seconds(size_bytes, REKEY_THROUGHPUT_MB_PER_SEC)
+}
+
+fn estimate_seconds(size_bytes: u64, throughput_mb_per_sec: f64) -> u64 {
+    let size_mb = size_bytes as f64 / (1024.0 * 1024.0);
+    (size_mb / throughput_mb_per_sec).ceil().max(1.0) as u64
+}
+
+/// Database file plus its WAL file, in bytes
+pub fn database_size_bytes(db_path: &Path) -> u64 {
+    let file_size = |path: &Path| std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    let mut wal_path = db_path.as_os_str().to_owned();
+    wal_path.push("-wal");
+
+    file_size(db_path) + file_size(Path::new(&wal_path))
+}
+
+/// Measure the database on disk and estimate how long a re-key will take
+pub fn rekey_precheck(db_path: &Path) -> OperationPrecheck {
+    let size_bytes = database_size_bytes(db_path);
+    OperationPrecheck {
+        size_bytes,
+        estimated_seconds: estimate_rekey_seconds(size_bytes),
+    }
+}
+
+/// Measure the database on disk and estimate how long compaction will take
+pub fn compact_precheck(db_path: &Path) -> OperationPrecheck {
+    let size_bytes = database_size_bytes(db_path);
+    OperationPrecheck {
+        size_bytes,
+        estimated_seconds: estimate_seconds(size_bytes, VACUUM_THROUGHPUT_MB_PER_SEC),
+    }
+}
+
+/// Result of `compact_database`: sizes are the database plus WAL file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactResult {
+    pub size_before_bytes: u64,
+    pub size_after_bytes: u64,
+    pub reclaimed_bytes: u64,
+    pub actual_seconds: f64,
+}
+
+/// Checkpoint and VACUUM `db`, measuring the files before and after.
+/// Blocking: call from a blocking task.
+pub fn compact(db: &Database) -> Result<CompactResult, String> {
+    let started = Instant::now();
+    let size_before_bytes = database_size_bytes(&db.path);
+    db.compact()?;
+    let size_after_bytes = database_size_bytes(&db.path);
+
+    Ok(CompactResult {
+        size_before_bytes,
+        size_after_bytes,
+        reclaimed_bytes: size_before_bytes.saturating_sub(size_after_bytes),
+        actual_seconds: started.elapsed().as_secs_f64(),
+    })
+}
+
+/// Run a re-key on a blocking task so the async runtime is not starved while
+/// SQLCipher rewrites every page. Returns the new key and the elapsed seconds.
+pub async fn run_rekey_task<F>(rekey: F) -> Result<(Zeroizing<Vec<u8>>, f64), String>
+where
+    F: FnOnce() -> Result<Zeroizing<Vec<u8>>, String> + Send + 'static,
+{
+    let started = Instant::now();
+    let new_key = tokio::task::spawn_blocking(rekey)
+        .await
+        .map_err(|e| format!("Re-key task failed: {}", e))??;
+    Ok((new_key, started.elapsed().as_secs_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use std::sync::Arc;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_busy_flag_refuses_overlapping_operations() {
+        let state = DbBusyState::default();
+
+        let guard = state.try_begin(DbOperation::Export).unwrap();
+        assert_eq!(state.current(), Some(DbOperation::Export));
+
+        let err = state.try_begin(DbOperation::Rekey).err().unwrap();
+        assert!(err.contains("re-key"));
+        assert!(err.contains("export is already in progress"));
+        assert!(state.try_begin(DbOperation::Vacuum).is_err());
+
+        // Released on drop
+        drop(guard);
+        assert_eq!(state.current(), None);
+        let _rekey = state.try_begin(DbOperation::Rekey).unwrap();
+        assert!(state.try_begin(DbOperation::Migration).is_err());
+    }
+
+    #[test]
+    fn test_compact_reclaims_deleted_rows() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        db.write(|conn| {
+            let filler = "x".repeat(4000);
+            for _ in 0..200 {
+                conn.execute(
+                    "INSERT INTO proposals (job_content, generated_text) VALUES (?1, ?1)",
+                    [&filler],
+                )
+                .map_err(|e| e.to_string())?;
+            }
+            conn.execute("DELETE FROM proposals", [])
+                .map_err(|e| e.to_string())?;
+            Ok(())
+        })
+        .unwrap();
+
+        let result = compact(&db).unwrap();
+        assert!(result.size_before_bytes > 1_000_000);
+        assert!(result.size_after_bytes < result.size_before_bytes);
+        assert_eq!(
+            result.reclaimed_bytes,
+            result.size_before_bytes - result.size_after_bytes
+        );
+        // WAL truncated and the database still usable, readers included
+        assert_eq!(
+            std::fs::metadata(dir.path().join("test.db-wal"))
+                .map(|m| m.len())
+                .unwrap_or(0),
+            0
+        );
+        assert_eq!(db.query_proposals_count().unwrap(), 0);
+        db.read(|conn| {
+            conn.query_row("SELECT COUNT(*) FROM job_posts", [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .map_err(|e| e.to_string())
+        })
+        .unwrap();
+
+        // Refused mid-transaction instead of failing inside VACUUM
+        db.conn.lock().unwrap().execute_batch("BEGIN;").unwrap();
+        assert!(db.compact().unwrap_err().contains("transaction"));
+        db.conn.lock().unwrap().execute_batch("ROLLBACK;").unwrap();
+    }
+
+    #[test]
+    fn test_estimate_and_precheck() {
+        assert_eq!(estimate_rekey_seconds(0), 1);
+        let mb = 1024 * 1024;
+        assert_eq!(estimate_rekey_seconds(40 * mb), 1);
+        assert_eq!(estimate_rekey_seconds(4096 * mb), 103);
+
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        std::fs::write(&db_path, vec![0u8; 1000]).unwrap();
+        std::fs::write(dir.path().join("test.db-wal"), vec![0u8; 500]).unwrap();
+        let precheck = rekey_precheck(&db_path);
+        assert_eq!(precheck.size_bytes, 1500);
+        assert_eq!(precheck.estimated_seconds, 1);
+    }
+
+    #[tokio::test]
+    async fn test_rekey_through_blocking_task() {
+        use crate::passphrase;
+
+        let dir = tempdir().unwrap();
+        let key_a = passphrase::set_passphrase("OriginalPass123!", dir.path()).unwrap();
+        let db = Arc::new(
+            Database::new(
+                dir.path().join("upwork-researcher.db"),
+                Some(key_a.to_vec()),
+            )
+            .unwrap(),
+        );
+        {
+            let conn = db.conn.lock().unwrap();
+            conn.execute(
+                "INSERT INTO proposals (job_content, generated_text) VALUES (?, ?)",
+                ["Test job", "Test proposal"],
+            )
+            .unwrap();
+        }
+
+        let task_db = Arc::clone(&db);
+        let app_data_dir = dir.path().to_path_buf();
+        let (new_key, elapsed) =
+            run_rekey_task(move || task_db.rekey_database("NewSecurePass456!", &app_data_dir))
+                .await
+                .unwrap();
+
+        assert_eq!(new_key.len(), 32);
+        assert!(elapsed >= 0.0);
+        assert_eq!(db.query_proposals_count().unwrap(), 1);
+        drop(db);
+
+        let reopened = crate::db::open_encrypted_database(dir.path(), "NewSecurePass456!").unwrap();
+        assert_eq!(reopened.query_proposals_count().unwrap(), 1);
+    }
+}
@@ -2,15 +2,31 @@
 //!
 //! Provides thread-safe database access via Mutex<Connection>.
 //! For MVP (Epic 1), uses unencrypted SQLite. Epic 2 adds SQLCipher encryption.
+//!
+//! `Database` holds one writer connection (`conn`) plus a small pool of
+//! read-only connections. Under WAL, readers don't block on the writer, so
+//! list/search/analytics queries go through `read()` and keep working while a
+//! long write (bulk rescoring, import) holds `conn`. Writes stay serialized
+//! behind the writer's Mutex (`write()` or `conn.lock()`). Every connection
+//! gets the same key and pragmas; re-key drains the read pool first and reopens
+//! it with the new key.
+//!
+//! Never call `write()`/`conn.lock()` from inside a `read()` closure: re-key
+//! holds the writer while it waits for readers to drain.
 
+pub mod maintenance;
 pub mod queries;
 
 use refinery::embed_migrations;
-use rusqlite::Connection;
+use rusqlite::{Connection, OpenFlags};
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
 use zeroize::Zeroizing;
 
+/// Read-only connections opened alongside the writer
+const READ_POOL_SIZE: usize = 3;
+
 // Embed migrations from the migrations directory
 embed_migrations!("migrations");
 
@@ -28,15 +44,83 @@ pub enum DatabaseError {
 
     #[error("Database corrupted or incompatible: {0}")]
     CorruptedDatabase(String),
+
+    /// A key file changed since it was last written (see `crate::integrity`)
+    #[error("{0}")]
+    IntegrityMismatch(crate::integrity::IntegrityMismatch),
 }
 
 /// Database state wrapper for Tauri managed state.
 /// Provides thread-safe access to the SQLite connection.
 pub struct Database {
+    /// Writer connection; all writes are serialized through it
     pub conn: Mutex<Connection>,
+    /// Read-only pool (empty for in-memory databases, which can't be shared)
+    readers: Vec<Mutex<Connection>>,
+    next_reader: AtomicUsize,
     pub path: PathBuf,
 }
 
+/// Set the SQLCipher key (if any) and the per-connection pragmas.
+/// Must run first on every connection, writer or reader.
+fn configure_connection(conn: &Connection, encryption_key: Option<&[u8]>) -> Result<(), String> {
+    // If encryption key provided, set up SQLCipher (Story 2.1, Epic 2)
+    if let Some(key) = encryption_key {
+        // Validate key length (32 bytes for AES-256)
+        if key.len() != 32 {
+            return Err(format!(
+                "Invalid encryption key length: expected 32 bytes, got {}",
+                key.len()
+            ));
+        }
+
+        // Convert key to hex string for PRAGMA key — wrapped in Zeroizing
+        // so the hex string is zeroed from memory after PRAGMA executes (TD-3 AC-2)
+        let key_hex = Zeroizing::new(hex::encode(key));
+
+        // Set encryption key (must be first operation after open)
+        let pragma = Zeroizing::new(format!("PRAGMA key = \"x'{}'\"", &*key_hex));
+        conn.execute_batch(&pragma)
+            .map_err(|e| format!("Failed to set encryption key: {}", e))?;
+        // key_hex and pragma are zeroed on drop here
+
+        // Set SQLCipher compatibility version (4.x)
+        conn.execute_batch("PRAGMA cipher_compatibility = 4;")
+            .map_err(|e| format!("Failed to set cipher compatibility: {}", e))?;
+    }
+
+    // Enable WAL mode for better concurrency
+    conn.execute_batch("PRAGMA journal_mode=WAL;")
+        .map_err(|e| format!("Failed to enable WAL mode: {}", e))?;
+
+    // Enable foreign keys
+    conn.execute_batch("PRAGMA foreign_keys=ON;")
+        .map_err(|e| format!("Failed to enable foreign keys: {}", e))?;
+
+    Ok(())
+}
+
+/// Open the read-only pool (after migrations, so readers see the final schema)
+fn open_readers(
+    db_path: &Path,
+    encryption_key: Option<&[u8]>,
+) -> Result<Vec<Mutex<Connection>>, String> {
+    if db_path.as_os_str().is_empty() || db_path == Path::new(":memory:") {
+        return Ok(Vec::new());
+    }
+    (0..READ_POOL_SIZE)
+        .map(|_| {
+            let conn = Connection::open_with_flags(
+                db_path,
+                OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+            )
+            .map_err(|e| format!("Failed to open read connection: {}", e))?;
+            configure_connection(&conn, encryption_key)?;
+            Ok(Mutex::new(conn))
+        })
+        .collect()
+}
+
 impl Database {
     /// Initialize database at the given path.
     /// Creates the database file if it doesn't exist.
@@ -55,57 +139,84 @@ impl Database {
         let mut conn =
             Connection::open(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
 
-        // If encryption key provided, set up SQLCipher (Story 2.1, Epic 2)
-        if let Some(key) = encryption_key {
-            // TD-3 AC-2: Wrap incoming key bytes in Zeroizing so they're zeroed on drop
-            let key = Zeroizing::new(key);
-
-            // Validate key length (32 bytes for AES-256)
-            if key.len() != 32 {
-                return Err(format!(
-                    "Invalid encryption key length: expected 32 bytes, got {}",
-                    key.len()
-                ));
-            }
-
-            // Convert key to hex string for PRAGMA key — wrapped in Zeroizing
-            // so the hex string is zeroed from memory after PRAGMA executes (TD-3 AC-2)
-            let key_hex = Zeroizing::new(hex::encode(&*key));
-
-            // Set encryption key (must be first operation after open)
-            let pragma = Zeroizing::new(format!("PRAGMA key = \"x'{}'\"", &*key_hex));
-            conn.execute_batch(&pragma)
-                .map_err(|e| format!("Failed to set encryption key: {}", e))?;
-            // key, key_hex, and pragma are all zeroed on drop here
-
-            // Set SQLCipher compatibility version (4.x)
-            conn.execute_batch("PRAGMA cipher_compatibility = 4;")
-                .map_err(|e| format!("Failed to set cipher compatibility: {}", e))?;
-
+        // TD-3 AC-2: Wrap incoming key bytes in Zeroizing so they're zeroed on drop
+        // (kept until the read pool is keyed too)
+        let encryption_key = encryption_key.map(Zeroizing::new);
+        configure_connection(&conn, encryption_key.as_deref().map(|k| k.as_slice()))?;
+        if encryption_key.is_some() {
             tracing::info!("SQLCipher encryption enabled (AES-256)");
         } else {
             tracing::warn!("Database opened WITHOUT encryption (Epic 1 compatibility mode)");
         }
 
-        // Enable WAL mode for better concurrency
-        conn.execute_batch("PRAGMA journal_mode=WAL;")
-            .map_err(|e| format!("Failed to enable WAL mode: {}", e))?;
-
-        // Enable foreign keys
-        conn.execute_batch("PRAGMA foreign_keys=ON;")
-            .map_err(|e| format!("Failed to enable foreign keys: {}", e))?;
-
         // Run migrations
         migrations::runner()
             .run(&mut conn)
             .map_err(|e| format!("Failed to run migrations: {}", e))?;
 
+        let readers = open_readers(&db_path, encryption_key.as_deref().map(|k| k.as_slice()))?;
+
         Ok(Self {
             conn: Mutex::new(conn),
+            readers,
+            next_reader: AtomicUsize::new(0),
             path: db_path,
         })
     }
 
+    /// Run a read-only query on a pooled read connection. Does not wait for
+    /// the writer; falls back to the writer for in-memory databases.
+    pub fn read<T, F>(&self, f: F) -> Result<T, String>
+    where
+        F: FnOnce(&Connection) -> Result<T, String>,
+    {
+        if self.readers.is_empty() {
+            let conn = self
+                .conn
+                .lock()
+                .map_err(|e| format!("Database lock error: {}", e))?;
+            return f(&conn);
+        }
+
+        // Prefer an idle reader; otherwise wait for the next one in rotation
+        for reader in &self.readers {
+            if let Ok(conn) = reader.try_lock() {
+                return f(&conn);
+            }
+        }
+        let index = self.next_reader.fetch_add(1, Ordering::Relaxed) % self.readers.len();
+        let conn = self.readers[index]
+            .lock()
+            .map_err(|e| format!("Database read lock error: {}", e))?;
+        f(&conn)
+    }
+
+    /// Run a write on the writer connection (serialized with all other writes)
+    pub fn write<T, F>(&self, f: F) -> Result<T, String>
+    where
+        F: FnOnce(&mut Connection) -> Result<T, String>,
+    {
+        let mut conn = self
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        f(&mut conn)
+    }
+
+    /// Lock every read connection, waiting for in-flight reads to finish.
+    /// Whole-database operations (re-key, vacuum) hold these guards so no
+    /// reader runs against the file while it is rewritten.
+    pub fn drain_readers(&self) -> Result<Vec<MutexGuard<'_, Connection>>, String> {
+        self.readers
+            .iter()
+            .map(|reader| {
+                reader
+                    .lock()
+                    .map_err(|e| format!("Database read lock error: {}", e))
+            })
+            .collect()
+    }
+
     /// Check if the database connection is healthy.
     pub fn health_check(&self) -> Result<bool, String> {
         let conn = self.conn.lock().map_err(|e| format!("Lock error: {}", e))?;
@@ -141,6 +252,7 @@ impl Database {
     ///
     /// Derives a new key from the passphrase, executes SQLCipher's `PRAGMA rekey`
     /// to re-encrypt the database in-place, and atomically updates the salt file.
+    /// Keeps the current Argon2id parameters; see `rekey_database_with_params`.
     ///
     /// # Arguments
     /// * `new_passphrase` - New passphrase (must meet strength requirements, 12+ chars)
@@ -157,6 +269,20 @@ impl Database {
         &self,
         new_passphrase: &str,
         app_data_dir: &Path,
+    ) -> Result<Zeroizing<Vec<u8>>, String> {
+        let kdf_params = crate::passphrase::load_kdf_params(app_data_dir)
+            .map_err(|e| format!("Failed to load key derivation parameters: {}", e))?;
+        self.rekey_database_with_params(new_passphrase, app_data_dir, &kdf_params)
+    }
+
+    /// `rekey_database` deriving the new key with `kdf_params`, which replace the
+    /// stored parameters only once the re-key succeeded. Until then the database
+    /// still opens with the old salt and parameters.
+    pub fn rekey_database_with_params(
+        &self,
+        new_passphrase: &str,
+        app_data_dir: &Path,
+        kdf_params: &crate::passphrase::KdfParams,
     ) -> Result<Zeroizing<Vec<u8>>, String> {
         use crate::passphrase;
         use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine as _};
@@ -166,29 +292,44 @@ impl Database {
             return Err("Passphrase must be at least 12 characters".to_string());
         }
 
-        // Derive new 32-byte key from new passphrase using Argon2id (same params as Story 2-1)
+        // Derive new 32-byte key from new passphrase using Argon2id
         // TD-3: Key is wrapped in Zeroizing — auto-zeroed on drop
         let new_salt = passphrase::generate_random_salt()
             .map_err(|e| format!("Failed to generate new salt: {}", e))?;
-        let new_key = passphrase::derive_key(new_passphrase, &new_salt)
+        let new_key = passphrase::derive_key_with_params(new_passphrase, &new_salt, kdf_params)
             .map_err(|e| format!("Failed to derive new key: {}", e))?;
 
-        // Write new salt to temp file (atomic update pattern — AC-5)
+        // Write new salt and parameters to temp files (atomic update pattern — AC-5)
         let salt_path = app_data_dir.join(".salt");
         let salt_tmp_path = app_data_dir.join(".salt.tmp");
+        let params_path = passphrase::kdf_params_path(app_data_dir);
+        let params_tmp_path = params_path.with_extension("tmp");
+        let discard_tmp = || {
+            let _ = std::fs::remove_file(&salt_tmp_path);
+            let _ = std::fs::remove_file(&params_tmp_path);
+        };
         let salt_b64 = BASE64_STANDARD.encode(new_salt);
         std::fs::write(&salt_tmp_path, &salt_b64)
             .map_err(|e| format!("Failed to write temp salt: {}", e))?;
+        let params_json = passphrase::encode_kdf_params(kdf_params).map_err(|e| e.to_string())?;
+        std::fs::write(&params_tmp_path, params_json).map_err(|e| {
+            discard_tmp();
+            format!("Failed to write temp key derivation parameters: {}", e)
+        })?;
 
         // Execute PRAGMA rekey on open connection (AC-1)
         // Hex key and PRAGMA string are wrapped in Zeroizing for memory safety (TD-3 AC-2)
         let key_hex = Zeroizing::new(hex::encode(&*new_key));
         {
             let conn = self.conn.lock().map_err(|e| format!("Lock error: {}", e))?;
+            // Readers keyed with the old key must not run during or after the rekey
+            let mut readers = self.drain_readers().inspect_err(|_| {
+                discard_tmp();
+            })?;
 
             let pragma = Zeroizing::new(format!("PRAGMA rekey = \"x'{}'\"", &*key_hex));
             conn.execute_batch(&pragma).map_err(|e| {
-                let _ = std::fs::remove_file(&salt_tmp_path);
+                discard_tmp();
                 format!("PRAGMA rekey failed: {}", e)
             })?;
             // pragma and key_hex zeroed on drop
@@ -196,14 +337,28 @@ impl Database {
             // Verify database accessible with new key after rekey
             conn.execute_batch("SELECT count(*) FROM sqlite_master;")
                 .map_err(|e| {
-                    let _ = std::fs::remove_file(&salt_tmp_path);
+                    discard_tmp();
                     format!("Database verification failed after rekey: {}", e)
                 })?;
+
+            // Reopen the read pool with the new key
+            let reopened = open_readers(&self.path, Some(new_key.as_slice())).map_err(|e| {
+                discard_tmp();
+                format!("Failed to reopen read connections after rekey: {}", e)
+            })?;
+            for (guard, reader) in readers.iter_mut().zip(reopened) {
+                **guard = reader
+                    .into_inner()
+                    .map_err(|e| format!("Database read lock error: {}", e))?;
+            }
         }
 
-        // Rekey succeeded — atomically update salt file (AC-5)
+        // Rekey succeeded — atomically update parameters and salt files (AC-5)
+        std::fs::rename(&params_tmp_path, &params_path)
+            .map_err(|e| format!("Failed to update key derivation parameters: {}", e))?;
         std::fs::rename(&salt_tmp_path, &salt_path)
             .map_err(|e| format!("Failed to update salt file: {}", e))?;
+        crate::integrity::refresh_manifest(app_data_dir);
 
         tracing::info!("Database re-keyed successfully with new passphrase");
 
@@ -211,6 +366,132 @@ impl Database {
         Ok(new_key)
     }
 
+    /// Checkpoint the WAL into the main file and VACUUM it (`compact_database`).
+    ///
+    /// VACUUM takes the write lock and can't run inside a transaction, so this
+    /// holds the writer and drains the read pool first, and refuses to start if
+    /// the writer is mid-transaction. Under WAL the rebuilt pages are written to
+    /// the WAL, so it is checkpointed again afterwards to shrink both files.
+    pub fn compact(&self) -> Result<(), String> {
+        let conn = self.conn.lock().map_err(|e| format!("Lock error: {}", e))?;
+        let _readers = self.drain_readers()?;
+        if !conn.is_autocommit() {
+            return Err("Cannot compact database: a transaction is in progress".to_string());
+        }
+
+        let checkpoint = || -> Result<(), String> {
+            let busy: i64 = conn
+                .query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| row.get(0))
+                .map_err(|e| format!("WAL checkpoint failed: {}", e))?;
+            if busy != 0 {
+                tracing::warn!("WAL checkpoint could not complete: database busy");
+            }
+            Ok(())
+        };
+        checkpoint()?;
+        conn.execute_batch("VACUUM;")
+            .map_err(|e| format!("VACUUM failed: {}", e))?;
+        checkpoint()
+    }
+
+    /// Whether `key` decrypts this database. Factory reset uses it to re-confirm
+    /// the passphrase without touching the open connections.
+    pub fn key_matches(&self, key: &[u8]) -> bool {
+        let check = || -> Result<(), String> {
+            let conn = Connection::open_with_flags(&self.path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+                .map_err(|e| e.to_string())?;
+            configure_connection(&conn, Some(key))?;
+            conn.execute_batch("SELECT count(*) FROM sqlite_master;")
+                .map_err(|e| e.to_string())
+        };
+        check().is_ok()
+    }
+
+    /// Factory reset: erase every table, delete the database files and recreate
+    /// an empty unencrypted database at the same path (the first-run state).
+    ///
+    /// Deleting an encrypted file doesn't guarantee its pages are gone from disk,
+    /// so tables are dropped with `secure_delete` on and the file is VACUUMed and
+    /// checkpointed before it is removed. Returns how many tables were dropped.
+    pub fn wipe_and_recreate(&self) -> Result<usize, String> {
+        let mut conn = self.conn.lock().map_err(|e| format!("Lock error: {}", e))?;
+        let mut readers = self.drain_readers()?;
+
+        conn.execute_batch("PRAGMA secure_delete=ON; PRAGMA foreign_keys=OFF;")
+            .map_err(|e| format!("Failed to prepare database wipe: {}", e))?;
+
+        // Views first, then virtual (FTS) tables, whose drop removes their shadow tables
+        let objects: Vec<(String, String)> = {
+            let mut stmt = conn
+                .prepare(
+                    "SELECT type, name FROM sqlite_master
+                     WHERE type IN ('view', 'table') AND name NOT LIKE 'sqlite_%'
+                     ORDER BY type = 'view' DESC, sql LIKE 'CREATE VIRTUAL TABLE%' DESC",
+                )
+                .map_err(|e| format!("Failed to list tables: {}", e))?;
+            let rows = stmt
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+                .map_err(|e| format!("Failed to list tables: {}", e))?;
+            rows.collect::<Result<_, _>>()
+                .map_err(|e| format!("Failed to list tables: {}", e))?
+        };
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to begin database wipe: {}", e))?;
+        for (kind, name) in &objects {
+            let kind = if kind == "view" { "VIEW" } else { "TABLE" };
+            tx.execute_batch(&format!(
+                "DROP {} IF EXISTS \"{}\";",
+                kind,
+                name.replace('"', "\"\"")
+            ))
+            .map_err(|e| format!("Failed to drop {}: {}", name, e))?;
+        }
+        tx.commit()
+            .map_err(|e| format!("Failed to commit database wipe: {}", e))?;
+
+        conn.execute_batch("VACUUM; PRAGMA wal_checkpoint(TRUNCATE);")
+            .map_err(|e| format!("Failed to vacuum wiped database: {}", e))?;
+
+        // Close every handle on the file before deleting it
+        let placeholder = || {
+            Connection::open_in_memory()
+                .map_err(|e| format!("Failed to open placeholder connection: {}", e))
+        };
+        *conn = placeholder()?;
+        for guard in readers.iter_mut() {
+            **guard = placeholder()?;
+        }
+        for suffix in ["", "-wal", "-shm"] {
+            let mut file = self.path.clone().into_os_string();
+            file.push(suffix);
+            let file = PathBuf::from(file);
+            if file.exists() {
+                std::fs::remove_file(&file)
+                    .map_err(|e| format!("Failed to delete {}: {}", file.display(), e))?;
+            }
+        }
+
+        let Database {
+            conn: fresh_conn,
+            readers: fresh_readers,
+            ..
+        } = Database::new(self.path.clone(), None)?;
+        *conn = fresh_conn
+            .into_inner()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        for (guard, reader) in readers.iter_mut().zip(fresh_readers) {
+            **guard = reader
+                .into_inner()
+                .map_err(|e| format!("Database read lock error: {}", e))?;
+        }
+
+        let tables = objects.iter().filter(|(kind, _)| kind == "table").count();
+        tracing::info!(tables, "Database wiped and recreated");
+        Ok(tables)
+    }
+
     /// Query job_posts count (Story 2.4)
     pub fn query_job_posts_count(&self) -> Result<usize, String> {
         let conn = self.conn.lock().map_err(|e| format!("Lock error: {}", e))?;
@@ -230,19 +511,58 @@ impl Database {
 /// overhead on the hot path (database access in commands).
 pub struct AppDatabase {
     inner: std::sync::OnceLock<Database>,
+    /// Created for the encrypted startup path (waits for a passphrase)
+    encrypted: bool,
+}
+
+/// Result of `AppDatabase::try_get`: why the database is not available, when it isn't
+#[derive(Debug, Clone, Copy)]
+pub enum DbAccess<'a> {
+    Ready(&'a Database),
+    /// Encrypted database waiting for passphrase (or recovery key) unlock
+    Locked,
+    /// No encrypted database and the unencrypted one is not open
+    NeverEncrypted,
 }
 
+impl<'a> DbAccess<'a> {
+    /// The database, or the structured error for why it is unavailable
+    /// (`DATABASE_NOT_READY` with the same message `AppDatabase::get` returns)
+    pub fn ready(self) -> Result<&'a Database, crate::errors::AppError> {
+        use crate::errors::{AppError, ErrorCode};
+        match self {
+            DbAccess::Ready(db) => Ok(db),
+            DbAccess::Locked => Err(AppError::new(
+                ErrorCode::DatabaseNotReady,
+                DATABASE_LOCKED_MESSAGE,
+            )),
+            DbAccess::NeverEncrypted => Err(AppError::new(
+                ErrorCode::DatabaseNotReady,
+                "Database not initialized",
+            )),
+        }
+    }
+}
+
+/// Error text for commands that need the database while it is locked.
+/// `AppError::from(String)` classifies it as `DATABASE_NOT_READY`.
+pub const DATABASE_LOCKED_MESSAGE: &str = "Database not unlocked - passphrase required";
+
 impl AppDatabase {
     /// Create empty AppDatabase (encrypted startup path — waiting for passphrase).
     pub fn new_empty() -> Self {
         Self {
             inner: std::sync::OnceLock::new(),
+            encrypted: true,
         }
     }
 
     /// Create pre-initialized AppDatabase (unencrypted startup path).
     pub fn new_with(db: Database) -> Self {
-        let app_db = Self::new_empty();
+        let app_db = Self {
+            inner: std::sync::OnceLock::new(),
+            encrypted: false,
+        };
         // Safe: called only once during setup before any commands run
         let _ = app_db.inner.set(db);
         app_db
@@ -252,7 +572,17 @@ impl AppDatabase {
     pub fn get(&self) -> Result<&Database, String> {
         self.inner
             .get()
-            .ok_or_else(|| "Database not unlocked - passphrase required".to_string())
+            .ok_or_else(|| DATABASE_LOCKED_MESSAGE.to_string())
+    }
+
+    /// Get the database, or why it is unavailable. For commands that can
+    /// still answer (partially) before unlock instead of failing outright.
+    pub fn try_get(&self) -> DbAccess<'_> {
+        match self.inner.get() {
+            Some(db) => DbAccess::Ready(db),
+            None if self.encrypted => DbAccess::Locked,
+            None => DbAccess::NeverEncrypted,
+        }
     }
 
     /// Set database after successful passphrase verification.
@@ -267,6 +597,11 @@ impl AppDatabase {
     pub fn is_ready(&self) -> bool {
         self.inner.get().is_some()
     }
+
+    /// Whether this is the encrypted startup path (locked until unlock)
+    pub fn is_encrypted(&self) -> bool {
+        self.encrypted
+    }
 }
 
 /// Open encrypted database with passphrase verification (Story 2.7)
@@ -292,12 +627,20 @@ impl AppDatabase {
 /// - `Err(DatabaseError::IncorrectPassphrase)` - Passphrase incorrect, retry
 /// - `Err(DatabaseError::PassphraseError)` - Salt file missing or derivation failed
 /// - `Err(DatabaseError::CorruptedDatabase)` - Database file corrupted
+/// - `Err(DatabaseError::IntegrityMismatch)` - Salt or parameters file modified since
+///   it was written; checked first, since a damaged salt looks like a wrong passphrase
 pub fn open_encrypted_database(
     app_data_dir: &Path,
     passphrase: &str,
 ) -> Result<Database, DatabaseError> {
     use crate::passphrase;
 
+    if let Some(mismatch) =
+        crate::integrity::check_files(app_data_dir, &crate::integrity::PASSPHRASE_FILES)
+    {
+        return Err(DatabaseError::IntegrityMismatch(mismatch));
+    }
+
     // Subtask 2.2: Call verify_passphrase_and_derive_key() to get encryption key
     // Key is wrapped in Zeroizing — raw bytes are zeroed when this binding drops (TD-3 AC-2)
     let mut encryption_key = passphrase::verify_passphrase_and_derive_key(passphrase, app_data_dir)
@@ -340,6 +683,33 @@ mod tests {
         assert!(db.health_check().is_ok());
     }
 
+    #[test]
+    fn test_app_database_try_get_states() {
+        use crate::errors::{AppError, ErrorCode};
+
+        let locked = AppDatabase::new_empty();
+        assert!(matches!(locked.try_get(), DbAccess::Locked));
+        let err = locked.try_get().ready().unwrap_err();
+        assert_eq!(err.code, ErrorCode::DatabaseNotReady);
+        // get() keeps its string error, which classifies the same way
+        assert_eq!(locked.get().unwrap_err(), err.message);
+        assert_eq!(
+            AppError::from(locked.get().unwrap_err()).code,
+            ErrorCode::DatabaseNotReady
+        );
+
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        locked.set(db).unwrap();
+        assert!(matches!(locked.try_get(), DbAccess::Ready(_)));
+        assert!(locked.is_encrypted());
+
+        let db = Database::new(dir.path().join("plain.db"), None).unwrap();
+        let plain = AppDatabase::new_with(db);
+        assert!(!plain.is_encrypted());
+        assert!(plain.try_get().ready().is_ok());
+    }
+
     #[test]
     fn test_wal_mode_enabled() {
         let dir = tempdir().unwrap();
@@ -355,6 +725,88 @@ mod tests {
         assert_eq!(journal_mode.to_lowercase(), "wal");
     }
 
+    #[test]
+    fn test_pragmas_set_on_every_connection() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+
+        let check = |conn: &Connection| {
+            let journal_mode: String = conn
+                .query_row("PRAGMA journal_mode;", [], |row| row.get(0))
+                .unwrap();
+            let foreign_keys: i64 = conn
+                .query_row("PRAGMA foreign_keys;", [], |row| row.get(0))
+                .unwrap();
+            assert_eq!(journal_mode.to_lowercase(), "wal");
+            assert_eq!(foreign_keys, 1);
+        };
+
+        check(&db.conn.lock().unwrap());
+        let readers = db.drain_readers().unwrap();
+        assert_eq!(readers.len(), READ_POOL_SIZE);
+        for reader in &readers {
+            check(reader);
+            // Read-only: writes must go through the writer
+            assert!(reader
+                .execute("INSERT INTO settings (key, value) VALUES ('x', 'y')", [])
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_long_write_does_not_block_read() {
+        let dir = tempdir().unwrap();
+        let db = std::sync::Arc::new(Database::new(dir.path().join("test.db"), None).unwrap());
+        let (locked_tx, locked_rx) = std::sync::mpsc::channel();
+
+        let writer_db = db.clone();
+        let writer = std::thread::spawn(move || {
+            writer_db
+                .write(|conn| {
+                    let tx = conn.transaction().map_err(|e| e.to_string())?;
+                    tx.execute(
+                        "INSERT INTO proposals (job_content, generated_text) VALUES ('job', 'text')",
+                        [],
+                    )
+                    .map_err(|e| e.to_string())?;
+                    locked_tx.send(()).unwrap();
+                    std::thread::sleep(std::time::Duration::from_millis(1000));
+                    tx.commit().map_err(|e| e.to_string())
+                })
+                .unwrap();
+        });
+
+        locked_rx.recv().unwrap();
+        let started = std::time::Instant::now();
+        let count: i64 = db
+            .read(|conn| {
+                conn.query_row("SELECT COUNT(*) FROM proposals", [], |row| row.get(0))
+                    .map_err(|e| e.to_string())
+            })
+            .unwrap();
+        let waited = started.elapsed();
+        // Uncommitted write isn't visible, and the read didn't wait for the writer
+        assert_eq!(count, 0);
+        assert!(
+            waited < std::time::Duration::from_millis(500),
+            "{:?}",
+            waited
+        );
+        assert!(
+            db.conn.try_lock().is_err(),
+            "writer should still hold the lock"
+        );
+
+        writer.join().unwrap();
+        let count: i64 = db
+            .read(|conn| {
+                conn.query_row("SELECT COUNT(*) FROM proposals", [], |row| row.get(0))
+                    .map_err(|e| e.to_string())
+            })
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
     #[test]
     fn test_get_path() {
         let dir = tempdir().unwrap();
@@ -726,6 +1178,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_open_encrypted_database_modified_key_files() {
+        use crate::{integrity, passphrase};
+
+        let dir = tempdir().unwrap();
+        let passphrase = "CorrectTestPass123!";
+        let key = passphrase::set_passphrase(passphrase, dir.path()).unwrap();
+        drop(Database::new(dir.path().join("upwork-researcher.db"), Some(key.to_vec())).unwrap());
+        let salt_path = dir.path().join(".salt");
+        let salt = std::fs::read(&salt_path).unwrap();
+
+        // A changed salt derives a wrong key: reported as a modified file, not a wrong passphrase
+        let mut modified = salt.clone();
+        modified[0] = if modified[0] == b'A' { b'B' } else { b'A' };
+        std::fs::write(&salt_path, &modified).unwrap();
+        match open_encrypted_database(dir.path(), passphrase) {
+            Err(DatabaseError::IntegrityMismatch(m)) => {
+                assert_eq!(m.file, ".salt");
+                assert_eq!(m.to_string(), "Salt file appears corrupted or modified");
+            }
+            Err(e) => panic!("Expected IntegrityMismatch, got: {:?}", e),
+            Ok(_) => panic!("Expected error, got success"),
+        }
+
+        std::fs::write(&salt_path, &salt[..salt.len() / 2]).unwrap();
+        assert!(matches!(
+            open_encrypted_database(dir.path(), passphrase),
+            Err(DatabaseError::IntegrityMismatch(_))
+        ));
+
+        std::fs::write(&salt_path, &salt).unwrap();
+        let params_path = passphrase::kdf_params_path(dir.path());
+        std::fs::write(&params_path, "{").unwrap();
+        match open_encrypted_database(dir.path(), passphrase) {
+            Err(DatabaseError::IntegrityMismatch(m)) => assert_eq!(m.file, ".kdf_params"),
+            Err(e) => panic!("Expected IntegrityMismatch, got: {:?}", e),
+            Ok(_) => panic!("Expected error, got success"),
+        }
+        std::fs::remove_file(&params_path).unwrap();
+        assert!(matches!(
+            open_encrypted_database(dir.path(), passphrase),
+            Err(DatabaseError::IntegrityMismatch(_))
+        ));
+
+        // Repairing accepts the files as they are; a wrong salt then fails as usual
+        passphrase::store_kdf_params(&passphrase::KdfParams::DEFAULT, dir.path()).unwrap();
+        std::fs::write(&salt_path, &modified).unwrap();
+        integrity::record_manifest(dir.path()).unwrap();
+        assert!(matches!(
+            open_encrypted_database(dir.path(), passphrase),
+            Err(DatabaseError::IncorrectPassphrase)
+        ));
+
+        std::fs::write(&salt_path, &salt).unwrap();
+        integrity::record_manifest(dir.path()).unwrap();
+        assert!(open_encrypted_database(dir.path(), passphrase).is_ok());
+    }
+
     #[test]
     fn test_open_encrypted_database_validates_key() {
         // Task 2.4: Verify test query validates encryption key
@@ -1271,6 +1781,53 @@ mod tests {
         // Verify data still accessible after rekey
         assert_eq!(db.query_proposals_count().unwrap(), 1);
         assert!(db.health_check().is_ok());
+
+        // Read pool was reopened with the new key
+        let count: i64 = db
+            .read(|conn| {
+                conn.query_row("SELECT COUNT(*) FROM proposals", [], |row| row.get(0))
+                    .map_err(|e| e.to_string())
+            })
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_wipe_and_recreate_encrypted_database() {
+        use crate::passphrase;
+
+        let dir = tempdir().unwrap();
+        let key = passphrase::set_passphrase("OriginalPass123!", dir.path()).unwrap();
+        let db_path = dir.path().join("upwork-researcher.db");
+        let db = Database::new(db_path.clone(), Some(key.to_vec())).unwrap();
+        db.write(|conn| {
+            conn.execute(
+                "INSERT INTO proposals (job_content, generated_text) VALUES (?, ?)",
+                ["Secret client job", "Secret proposal"],
+            )
+            .map_err(|e| e.to_string())
+        })
+        .unwrap();
+
+        assert!(db.key_matches(&key));
+        assert!(!db.key_matches(&[7u8; 32]));
+
+        assert!(db.wipe_and_recreate().unwrap() > 0);
+
+        // Fresh unencrypted database with the full schema and no rows
+        assert_eq!(db.query_proposals_count().unwrap(), 0);
+        let count: i64 = db
+            .read(|conn| {
+                conn.query_row("SELECT COUNT(*) FROM proposals", [], |row| row.get(0))
+                    .map_err(|e| e.to_string())
+            })
+            .unwrap();
+        assert_eq!(count, 0);
+        let plain = Connection::open(&db_path).unwrap();
+        let schema_objects: i64 = plain
+            .query_row("SELECT COUNT(*) FROM sqlite_master", [], |row| row.get(0))
+            .unwrap();
+        assert!(schema_objects > 0);
     }
 
     #[test]
@@ -1300,6 +1857,40 @@ mod tests {
         result.unwrap().health_check().unwrap();
     }
 
+    #[test]
+    fn test_rekey_database_with_params_switches_kdf() {
+        // Databases keyed before .kdf_params existed unlock with the defaults,
+        // and a re-key with new parameters stores them for the next unlock
+        use crate::passphrase;
+
+        let dir = tempdir().unwrap();
+        let key_a = passphrase::set_passphrase("OriginalPass123!", dir.path()).unwrap();
+        std::fs::remove_file(passphrase::kdf_params_path(dir.path())).unwrap();
+        let db_path = dir.path().join("upwork-researcher.db");
+        let db = Database::new(db_path, Some(key_a.to_vec())).unwrap();
+        drop(db);
+        let db = open_encrypted_database(dir.path(), "OriginalPass123!").unwrap();
+
+        let cheap = passphrase::KdfParams {
+            memory_kb: passphrase::MIN_MEMORY_KB,
+            iterations: passphrase::MIN_ITERATIONS,
+            parallelism: 1,
+        };
+        let new_key = db
+            .rekey_database_with_params("NewSecurePass456!", dir.path(), &cheap)
+            .unwrap();
+        drop(db);
+
+        assert_eq!(passphrase::load_kdf_params(dir.path()).unwrap(), cheap);
+        assert!(!dir.path().join(".kdf_params.tmp").exists());
+        let derived = passphrase::verify_passphrase("NewSecurePass456!", dir.path()).unwrap();
+        assert_eq!(derived, new_key);
+        open_encrypted_database(dir.path(), "NewSecurePass456!")
+            .unwrap()
+            .health_check()
+            .unwrap();
+    }
+
     #[test]
     fn test_rekey_database_old_passphrase_fails() {
         // TD-2: Old passphrase no longer works after rekey
@@ -0,0 +1,162 @@
+//! Job analysis cache keyed by normalized content hash (V49)
+//!
+//! `analyze_job_post` stores the Haiku analysis and the extracted budget per job
+//! text, so analyzing the same job again skips both API calls. Entries older than
+//! `analysis_cache_days` are ignored on lookup and pruned by startup maintenance.
+
+use crate::analysis::{BudgetInfo, JobAnalysis};
+use rusqlite::{params, Connection, OptionalExtension};
+use sha2::{Digest, Sha256};
+
+pub const ANALYSIS_CACHE_DAYS_SETTING: &str = "analysis_cache_days";
+pub const DEFAULT_ANALYSIS_CACHE_DAYS: u32 = 30;
+pub const MAX_ANALYSIS_CACHE_DAYS: u32 = 365;
+
+/// A cache hit: the analysis as returned by Haiku (before budget, alignment and
+/// client history are applied) and the extracted budget
+#[derive(Debug, Clone)]
+pub struct CachedAnalysis {
+    pub analysis: JobAnalysis,
+    pub budget: BudgetInfo,
+}
+
+/// SHA-256 of the job text with whitespace runs collapsed and ends trimmed, so the
+/// same job pasted with different line breaks or indentation hits the same entry
+pub fn content_hash(raw_content: &str) -> String {
+    let normalized = raw_content.split_whitespace().collect::<Vec<_>>().join(" ");
+    hex::encode(Sha256::digest(normalized.as_bytes()))
+}
+
+/// Cache lifetime from settings; 0 disables the cache
+pub fn analysis_cache_days(conn: &Connection) -> u32 {
+    crate::settings::schema::get_typed(conn, crate::settings::schema::Key::AnalysisCacheDays)
+        .unwrap_or(DEFAULT_ANALYSIS_CACHE_DAYS)
+}
+
+/// Look up a cached analysis no older than `max_age_days`. An entry that no longer
+/// deserializes (older shape) is treated as a miss.
+pub fn get_cached_analysis(
+    conn: &Connection,
+    content_hash: &str,
+    max_age_days: u32,
+) -> Result<Option<CachedAnalysis>, rusqlite::Error> {
+    let row: Option<(String, String)> = conn
+        .query_row(
+            "SELECT analysis_json, budget_json FROM analysis_cache
+             WHERE content_hash = ?1 AND created_at >= datetime('now', '-' || ?2 || ' days')",
+            params![content_hash, max_age_days],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+
+    let Some((analysis_json, budget_json)) = row else {
+        return Ok(None);
+    };
+    match (
+        serde_json::from_str(&analysis_json),
+        serde_json::from_str(&budget_json),
+    ) {
+        (Ok(analysis), Ok(budget)) => Ok(Some(CachedAnalysis { analysis, budget })),
+        _ => {
+            tracing::warn!("Ignoring unreadable analysis cache entry {}", content_hash);
+            Ok(None)
+        }
+    }
+}
+
+/// Store (or refresh) the cache entry for `content_hash`
+pub fn store_cached_analysis(
+    conn: &Connection,
+    content_hash: &str,
+    analysis: &JobAnalysis,
+    budget: &BudgetInfo,
+) -> Result<(), rusqlite::Error> {
+    let analysis_json = to_json(analysis)?;
+    let budget_json = to_json(budget)?;
+    conn.execute(
+        "INSERT OR REPLACE INTO analysis_cache (content_hash, analysis_json, budget_json, created_at)
+         VALUES (?1, ?2, ?3, datetime('now'))",
+        params![content_hash, analysis_json, budget_json],
+    )?;
+    Ok(())
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, rusqlite::Error> {
+    serde_json::to_string(value).map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))
+}
+
+/// Delete entries older than `max_age_days` (everything when 0). Returns rows deleted.
+pub fn prune_analysis_cache(
+    conn: &Connection,
+    max_age_days: u32,
+) -> Result<usize, rusqlite::Error> {
+    conn.execute(
+        "DELETE FROM analysis_cache WHERE created_at < datetime('now', '-' || ?1 || ' days')",
+        params![max_age_days],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+
+    fn sample_analysis() -> JobAnalysis {
+        serde_json::from_value(serde_json::json!({
+            "clientName": "Acme",
+            "keySkills": ["Rust"],
+            "clientQualityScore": 80
+        }))
+        .unwrap()
+    }
+
+    fn sample_budget() -> BudgetInfo {
+        BudgetInfo {
+            min: Some(50.0),
+            max: Some(75.0),
+            budget_type: "hourly".to_string(),
+            currency: None,
+        }
+    }
+
+    #[test]
+    fn test_content_hash_ignores_whitespace() {
+        let a = content_hash("Need a Rust developer.\n\nBudget: $50/hr");
+        let b = content_hash("  Need a  Rust developer.\r\n Budget:\t$50/hr \n");
+        assert_eq!(a, b);
+        assert_ne!(a, content_hash("Need a Go developer. Budget: $50/hr"));
+        assert_eq!(a.len(), 64);
+    }
+
+    #[test]
+    fn test_cache_roundtrip_and_expiry() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        let hash = content_hash("job text");
+
+        assert!(get_cached_analysis(&conn, &hash, 30).unwrap().is_none());
+        store_cached_analysis(&conn, &hash, &sample_analysis(), &sample_budget()).unwrap();
+
+        let hit = get_cached_analysis(&conn, &hash, 30).unwrap().unwrap();
+        assert_eq!(hit.analysis.client_name.as_deref(), Some("Acme"));
+        assert_eq!(hit.analysis.client_quality_score, Some(80));
+        assert_eq!(hit.budget.budget_type, "hourly");
+
+        // Age the entry past the window: lookups miss and pruning removes it
+        conn.execute(
+            "UPDATE analysis_cache SET created_at = datetime('now', '-31 days')",
+            [],
+        )
+        .unwrap();
+        assert!(get_cached_analysis(&conn, &hash, 30).unwrap().is_none());
+        assert!(get_cached_analysis(&conn, &hash, 60).unwrap().is_some());
+        assert_eq!(prune_analysis_cache(&conn, 60).unwrap(), 0);
+        assert_eq!(prune_analysis_cache(&conn, 30).unwrap(), 1);
+
+        // Default lifetime comes from settings
+        assert_eq!(analysis_cache_days(&conn), DEFAULT_ANALYSIS_CACHE_DAYS);
+        crate::db::queries::settings::set_setting(&conn, ANALYSIS_CACHE_DAYS_SETTING, "0").unwrap();
+        assert_eq!(analysis_cache_days(&conn), 0);
+    }
+}
@@ -0,0 +1,414 @@
+//! Client queries: name normalization and per-client history.
+//!
+//! The analyzer extracts client names inconsistently ("Acme Inc." vs "Acme Inc"),
+//! so job posts store both the display name (`client_name`, whitespace cleaned,
+//! casing kept) and a matching key (`client_name_normalized`, trailing
+//! punctuation stripped and casefolded). The `clients` view (V44) aggregates job
+//! posts and sent proposals by that key. Jobs without a client name have no key
+//! and never appear as a client.
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// Punctuation dropped from the end of a name when building the matching key
+const TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?'];
+
+/// Most clients returned by `list_known_clients`
+const MAX_CLIENTS: i64 = 500;
+
+/// Display form of a client name: trimmed, internal whitespace collapsed.
+/// None if the name is blank.
+pub fn clean_client_name(name: &str) -> Option<String> {
+    let cleaned = name.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!cleaned.is_empty()).then_some(cleaned)
+}
+
+/// Matching key for a client name: the display form without trailing
+/// punctuation, casefolded. None if nothing is left.
+pub fn normalize_client_name(name: &str) -> Option<String> {
+    let cleaned = clean_client_name(name)?;
+    let key = cleaned
+        .trim_end_matches(|c: char| TRAILING_PUNCTUATION.contains(&c) || c.is_whitespace())
+        .to_lowercase();
+    (!key.is_empty()).then_some(key)
+}
+
+/// Display name and matching key to store for an extracted client name
+pub fn client_name_columns(name: Option<&str>) -> (Option<String>, Option<String>) {
+    let display = name.and_then(clean_client_name);
+    let key = display.as_deref().and_then(normalize_client_name);
+    (display, key)
+}
+
+/// One row of the `clients` view
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientSummary {
+    pub normalized_name: String,
+    /// Name as extracted from the most recent job post
+    pub display_name: String,
+    pub job_count: i64,
+    /// Non-draft proposals for this client's jobs
+    pub proposals_sent: i64,
+    /// Proposals that got a response, interview or hire
+    pub responses: i64,
+    pub interviews: i64,
+    pub hired: i64,
+    pub rejected: i64,
+    pub avg_hourly_budget_usd: Option<f64>,
+    pub avg_fixed_budget_usd: Option<f64>,
+    pub first_seen: String,
+    pub last_seen: String,
+    pub last_proposal_at: Option<String>,
+}
+
+/// A job post from a client's history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientJob {
+    pub id: i64,
+    pub url: Option<String>,
+    pub client_name: String,
+    pub created_at: String,
+    pub budget_type: Option<String>,
+    pub budget_min: Option<f64>,
+    pub budget_max: Option<f64>,
+    pub budget_currency: Option<String>,
+}
+
+/// A sent proposal from a client's history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientProposal {
+    pub id: i64,
+    pub job_post_id: i64,
+    pub created_at: String,
+    pub outcome_status: String,
+    pub hook_strategy_id: Option<String>,
+}
+
+/// Everything known about one client, newest first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientHistory {
+    pub client: ClientSummary,
+    pub jobs: Vec<ClientJob>,
+    pub proposals: Vec<ClientProposal>,
+}
+
+/// Earlier interactions with the client of a job being analyzed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientPriorHistory {
+    pub normalized_name: String,
+    pub display_name: String,
+    /// Other job posts from this client
+    pub previous_jobs: i64,
+    pub proposals_sent: i64,
+    pub responses: i64,
+    pub hired: i64,
+    pub last_seen: String,
+}
+
+const CLIENT_SUMMARY_COLUMNS: &str = "normalized_name, display_name, job_count, proposals_sent,
+     responses, interviews, hired, rejected, avg_hourly_budget_usd, avg_fixed_budget_usd,
+     first_seen, last_seen, last_proposal_at";
+
+fn client_summary_from_row(row: &rusqlite::Row) -> rusqlite::Result<ClientSummary> {
+    Ok(ClientSummary {
+        normalized_name: row.get(0)?,
+        display_name: row.get(1)?,
+        job_count: row.get(2)?,
+        proposals_sent: row.get(3)?,
+        responses: row.get(4)?,
+        interviews: row.get(5)?,
+        hired: row.get(6)?,
+        rejected: row.get(7)?,
+        avg_hourly_budget_usd: row.get(8)?,
+        avg_fixed_budget_usd: row.get(9)?,
+        first_seen: row.get(10)?,
+        last_seen: row.get(11)?,
+        last_proposal_at: row.get(12)?,
+    })
+}
+
+/// Known clients, most recently seen first. `search` matches anywhere in the
+/// normalized name, ignoring case and extra whitespace.
+pub fn list_known_clients(
+    conn: &Connection,
+    search: Option<&str>,
+) -> Result<Vec<ClientSummary>, rusqlite::Error> {
+    let search = search
+        .map(|s| {
+            s.split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase()
+        })
+        .filter(|s| !s.is_empty());
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM clients
+         WHERE ?1 IS NULL OR instr(normalized_name, ?1) > 0
+         ORDER BY last_seen DESC, normalized_name
+         LIMIT ?2",
+        CLIENT_SUMMARY_COLUMNS
+    ))?;
+    let clients = stmt
+        .query_map(params![search, MAX_CLIENTS], client_summary_from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(clients)
+}
+
+/// A client's summary, job posts and sent proposals. None if no job post has
+/// this normalized name.
+pub fn get_client_history(
+    conn: &Connection,
+    normalized_name: &str,
+) -> Result<Option<ClientHistory>, rusqlite::Error> {
+    let client = conn
+        .query_row(
+            &format!(
+                "SELECT {} FROM clients WHERE normalized_name = ?1",
+                CLIENT_SUMMARY_COLUMNS
+            ),
+            params![normalized_name],
+            client_summary_from_row,
+        )
+        .optional()?;
+    let Some(client) = client else {
+        return Ok(None);
+    };
+
+    let mut stmt = conn.prepare(
+        "SELECT id, url, client_name, created_at, budget_type, budget_min, budget_max,
+                budget_currency
+         FROM job_posts
+         WHERE client_name_normalized = ?1
+         ORDER BY created_at DESC, id DESC",
+    )?;
+    let jobs = stmt
+        .query_map(params![normalized_name], |row| {
+            Ok(ClientJob {
+                id: row.get(0)?,
+                url: row.get(1)?,
+                client_name: row.get(2)?,
+                created_at: row.get(3)?,
+                budget_type: row.get(4)?,
+                budget_min: row.get(5)?,
+                budget_max: row.get(6)?,
+                budget_currency: row.get(7)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut stmt = conn.prepare(
+        "SELECT p.id, p.job_post_id, p.created_at, p.outcome_status, p.hook_strategy_id
+         FROM proposals p
+         JOIN job_posts jp ON jp.id = p.job_post_id
+         WHERE jp.client_name_normalized = ?1 AND p.status NOT IN ('draft', 'discarded')
+         ORDER BY p.created_at DESC, p.id DESC",
+    )?;
+    let proposals = stmt
+        .query_map(params![normalized_name], |row| {
+            Ok(ClientProposal {
+                id: row.get(0)?,
+                job_post_id: row.get(1)?,
+                created_at: row.get(2)?,
+                outcome_status: row.get(3)?,
+                hook_strategy_id: row.get(4)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Some(ClientHistory {
+        client,
+        jobs,
+        proposals,
+    }))
+}
+
+/// Earlier job posts and proposals for a client, excluding `exclude_job_post_id`
+/// (the job being analyzed). None if this is a new client.
+pub fn prior_client_history(
+    conn: &Connection,
+    normalized_name: &str,
+    exclude_job_post_id: Option<i64>,
+) -> Result<Option<ClientPriorHistory>, rusqlite::Error> {
+    let exclude = exclude_job_post_id.unwrap_or(-1);
+
+    let (previous_jobs, last_seen, display_name): (i64, Option<String>, Option<String>) = conn
+        .query_row(
+            "SELECT COUNT(*), MAX(created_at),
+                    (SELECT client_name FROM job_posts
+                     WHERE client_name_normalized = ?1 AND id != ?2
+                     ORDER BY created_at DESC, id DESC LIMIT 1)
+             FROM job_posts
+             WHERE client_name_normalized = ?1 AND id != ?2",
+            params![normalized_name, exclude],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )?;
+    let (Some(last_seen), Some(display_name)) = (last_seen, display_name) else {
+        return Ok(None);
+    };
+
+    let (proposals_sent, responses, hired): (i64, i64, i64) = conn.query_row(
+        "SELECT COUNT(*),
+                COALESCE(SUM(p.outcome_status IN ('response_received', 'interview', 'hired')), 0),
+                COALESCE(SUM(p.outcome_status = 'hired'), 0)
+         FROM proposals p
+         JOIN job_posts jp ON jp.id = p.job_post_id
+         WHERE jp.client_name_normalized = ?1 AND jp.id != ?2 AND p.status NOT IN ('draft', 'discarded')",
+        params![normalized_name, exclude],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    )?;
+
+    Ok(Some(ClientPriorHistory {
+        normalized_name: normalized_name.to_string(),
+        display_name,
+        previous_jobs,
+        proposals_sent,
+        responses,
+        hired,
+        last_seen,
+    }))
+}
+
+/// Fill `client_name_normalized` for job posts saved before V44. Returns the
+/// number of rows updated.
+pub fn backfill_normalized_client_names(conn: &Connection) -> Result<usize, rusqlite::Error> {
+    let rows: Vec<(i64, String)> = {
+        let mut stmt = conn.prepare(
+            "SELECT id, client_name FROM job_posts
+             WHERE client_name IS NOT NULL AND client_name_normalized IS NULL",
+        )?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        rows
+    };
+    if rows.is_empty() {
+        return Ok(0);
+    }
+
+    let tx = conn.unchecked_transaction()?;
+    {
+        let mut stmt = tx.prepare(
+            "UPDATE job_posts SET client_name = ?1, client_name_normalized = ?2 WHERE id = ?3",
+        )?;
+        for (id, name) in &rows {
+            let (display, key) = client_name_columns(Some(name));
+            stmt.execute(params![display, key, id])?;
+        }
+    }
+    tx.commit()?;
+    Ok(rows.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::queries::job_posts::insert_job_post;
+    use crate::db::Database;
+
+    fn insert_proposal(conn: &Connection, job_post_id: i64, status: &str, outcome: &str) -> i64 {
+        conn.execute(
+            "INSERT INTO proposals (job_content, generated_text, status, outcome_status, job_post_id)
+             VALUES ('job', 'proposal', ?1, ?2, ?3)",
+            params![status, outcome, job_post_id],
+        )
+        .unwrap();
+        conn.last_insert_rowid()
+    }
+
+    #[test]
+    fn test_normalize_client_name() {
+        assert_eq!(
+            normalize_client_name("Acme Inc.").as_deref(),
+            Some("acme inc")
+        );
+        assert_eq!(
+            normalize_client_name("  ACME   Inc  ").as_deref(),
+            Some("acme inc")
+        );
+        assert_eq!(
+            clean_client_name("  Acme   Inc. ").as_deref(),
+            Some("Acme Inc.")
+        );
+        assert_eq!(
+            normalize_client_name("Zoë Müller!").as_deref(),
+            Some("zoë müller")
+        );
+        assert_eq!(normalize_client_name("   "), None);
+        assert_eq!(normalize_client_name("..."), None);
+    }
+
+    #[test]
+    fn test_client_history_groups_name_variants() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+
+        let first = insert_job_post(&conn, None, "job one", Some("Acme Inc.")).unwrap();
+        let second = insert_job_post(&conn, None, "job two", Some("acme  inc")).unwrap();
+        insert_job_post(&conn, None, "other client", Some("Globex")).unwrap();
+        // Unnamed jobs never form a client
+        insert_job_post(&conn, None, "anonymous one", None).unwrap();
+        insert_job_post(&conn, None, "anonymous two", Some("  ")).unwrap();
+
+        insert_proposal(&conn, first, "completed", "hired");
+        insert_proposal(&conn, second, "completed", "no_response");
+        insert_proposal(&conn, second, "draft", "pending");
+
+        let clients = list_known_clients(&conn, None).unwrap();
+        assert_eq!(clients.len(), 2);
+        let acme = list_known_clients(&conn, Some(" ACME ")).unwrap();
+        assert_eq!(acme.len(), 1);
+        assert_eq!(acme[0].normalized_name, "acme inc");
+        assert_eq!(acme[0].job_count, 2);
+        assert_eq!(acme[0].proposals_sent, 2);
+        assert_eq!(acme[0].responses, 1);
+        assert_eq!(acme[0].hired, 1);
+
+        let history = get_client_history(&conn, "acme inc").unwrap().unwrap();
+        assert_eq!(history.jobs.len(), 2);
+        assert_eq!(history.proposals.len(), 2);
+        assert!(get_client_history(&conn, "initech").unwrap().is_none());
+
+        // Re-analyzing the second job only counts the first as prior history
+        let prior = prior_client_history(&conn, "acme inc", Some(second))
+            .unwrap()
+            .unwrap();
+        assert_eq!(prior.previous_jobs, 1);
+        assert_eq!(prior.proposals_sent, 1);
+        assert_eq!(prior.hired, 1);
+        assert_eq!(prior.display_name, "Acme Inc.");
+        let globex = insert_job_post(&conn, None, "globex", Some("Globex")).unwrap();
+        assert!(prior_client_history(&conn, "initech", Some(globex))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_backfill_normalized_client_names() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO job_posts (raw_content, client_name) VALUES ('a', ' Acme  Inc. '), ('b', NULL)",
+            [],
+        )
+        .unwrap();
+
+        assert_eq!(backfill_normalized_client_names(&conn).unwrap(), 1);
+        assert_eq!(backfill_normalized_client_names(&conn).unwrap(), 0);
+        let (name, key): (String, String) = conn
+            .query_row(
+                "SELECT client_name, client_name_normalized FROM job_posts WHERE raw_content = 'a'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((name.as_str(), key.as_str()), ("Acme Inc.", "acme inc"));
+    }
+}
@@ -0,0 +1,117 @@
+//! Remote config override queries.
+//!
+//! Stores locally pinned values that are merged on top of the fetched remote config.
+
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+/// A locally pinned config value
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConfigOverride {
+    /// Dotted path into the config JSON (e.g. `strategies.social-proof.ab_weight`)
+    pub key_path: String,
+    /// JSON-encoded value applied at `key_path`
+    pub value_json: String,
+    pub created_at: String,
+}
+
+/// Insert or replace an override for `key_path`
+pub fn set_config_override(
+    conn: &Connection,
+    key_path: &str,
+    value_json: &str,
+) -> Result<(), rusqlite::Error> {
+    conn.execute(
+        "INSERT INTO config_overrides (key_path, value_json)
+         VALUES (?1, ?2)
+         ON CONFLICT(key_path) DO UPDATE SET
+            value_json = excluded.value_json,
+            created_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')",
+        params![key_path, value_json],
+    )?;
+
+    Ok(())
+}
+
+/// Remove the override for `key_path`
+///
+/// Returns true if an override was removed, false if none existed.
+pub fn delete_config_override(conn: &Connection, key_path: &str) -> Result<bool, rusqlite::Error> {
+    let deleted = conn.execute(
+        "DELETE FROM config_overrides WHERE key_path = ?1",
+        params![key_path],
+    )?;
+
+    Ok(deleted > 0)
+}
+
+/// List all overrides in the order they were created
+pub fn list_config_overrides(conn: &Connection) -> Result<Vec<ConfigOverride>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT key_path, value_json, created_at FROM config_overrides
+         ORDER BY created_at ASC, key_path ASC",
+    )?;
+
+    let overrides = stmt
+        .query_map([], |row| {
+            Ok(ConfigOverride {
+                key_path: row.get(0)?,
+                value_json: row.get(1)?,
+                created_at: row.get(2)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(overrides)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use tempfile::tempdir;
+
+    fn create_test_db() -> Database {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        Database::new(db_path, None).unwrap()
+    }
+
+    #[test]
+    fn test_set_and_list_overrides() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        set_config_override(&conn, "strategies.social-proof.ab_weight", "0.5").unwrap();
+        let overrides = list_config_overrides(&conn).unwrap();
+
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].key_path, "strategies.social-proof.ab_weight");
+        assert_eq!(overrides[0].value_json, "0.5");
+    }
+
+    #[test]
+    fn test_set_override_replaces_existing_value() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        set_config_override(&conn, "min_app_version", "\"0.1.0\"").unwrap();
+        set_config_override(&conn, "min_app_version", "\"0.2.0\"").unwrap();
+        let overrides = list_config_overrides(&conn).unwrap();
+
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].value_json, "\"0.2.0\"");
+    }
+
+    #[test]
+    fn test_delete_override() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        set_config_override(&conn, "min_app_version", "\"0.1.0\"").unwrap();
+
+        assert!(delete_config_override(&conn, "min_app_version").unwrap());
+        assert!(!delete_config_override(&conn, "min_app_version").unwrap());
+        assert!(list_config_overrides(&conn).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+//! Stored weekly digests (V54)
+//!
+//! `digest::generate_weekly_digest` keeps one row per completed ISO week; the
+//! digest itself is stored as JSON next to its rendered Markdown.
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+/// A stored digest row
+#[derive(Debug, Clone)]
+pub struct StoredDigest {
+    pub week_label: String,
+    pub digest_json: String,
+    pub markdown: String,
+}
+
+/// Look up the digest stored for a week label ("YYYY-Www")
+pub fn get_digest(
+    conn: &Connection,
+    week_label: &str,
+) -> Result<Option<StoredDigest>, rusqlite::Error> {
+    conn.query_row(
+        "SELECT week_label, digest_json, markdown FROM digests WHERE week_label = ?1",
+        params![week_label],
+        |row| {
+            Ok(StoredDigest {
+                week_label: row.get(0)?,
+                digest_json: row.get(1)?,
+                markdown: row.get(2)?,
+            })
+        },
+    )
+    .optional()
+}
+
+/// Store a digest, replacing any earlier one for the same week
+pub fn save_digest(
+    conn: &Connection,
+    week_label: &str,
+    week_start: &str,
+    digest_json: &str,
+    markdown: &str,
+) -> Result<(), rusqlite::Error> {
+    conn.execute(
+        "INSERT INTO digests (week_label, week_start, digest_json, markdown)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(week_label) DO UPDATE SET
+            week_start = excluded.week_start,
+            digest_json = excluded.digest_json,
+            markdown = excluded.markdown,
+            created_at = datetime('now')",
+        params![week_label, week_start, digest_json, markdown],
+    )?;
+    Ok(())
+}
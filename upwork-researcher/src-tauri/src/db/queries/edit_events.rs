@@ -0,0 +1,274 @@
+//! Edit event queries (in-session undo beyond revisions).
+//!
+//! Revisions are coarse and archived after a handful; edit events snapshot the
+//! proposal text on every editor save so in-session work can be stepped back
+//! through, even after a crash. The table is append-only and pruned after each
+//! insert so it never bloats the encrypted database:
+//! - only the last `EDIT_EVENT_RETENTION_HOURS` hours are kept
+//! - at most `MAX_EDIT_EVENTS_PER_PROPOSAL` per proposal
+//! - at most `EDIT_EVENT_BYTE_BUDGET` bytes of snapshots per proposal, oldest dropped first
+//!
+//! Edit events are left out of encrypted archive exports unless requested, and
+//! archive import never brings them in.
+
+use rusqlite::{params, Connection, OptionalExtension, Result};
+use serde::{Deserialize, Serialize};
+
+/// Snapshots kept per proposal
+pub const MAX_EDIT_EVENTS_PER_PROPOSAL: i64 = 50;
+/// Snapshots older than this are pruned
+pub const EDIT_EVENT_RETENTION_HOURS: i64 = 6;
+/// Total snapshot bytes kept per proposal (256 KB)
+pub const EDIT_EVENT_BYTE_BUDGET: i64 = 256 * 1024;
+
+/// Edit event metadata for the undo list (without full content)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EditEventSummary {
+    pub id: i64,
+    pub proposal_id: i64,
+    pub created_at: String,
+    pub content_bytes: i64,
+    pub content_preview: String, // First 50 chars
+}
+
+/// Full edit event for restore
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EditEvent {
+    pub id: i64,
+    pub proposal_id: i64,
+    pub content: String,
+    pub created_at: String,
+}
+
+/// `strftime` modifier for the retention cutoff ("-6 hours")
+fn retention_modifier() -> String {
+    format!("-{} hours", EDIT_EVENT_RETENTION_HOURS)
+}
+
+/// Snapshot a proposal's content and prune. Returns the new event id, or None when
+/// nothing was recorded (unchanged since the last snapshot, or larger than the budget).
+pub fn record_edit_event(
+    conn: &Connection,
+    proposal_id: i64,
+    content: &str,
+) -> Result<Option<i64>> {
+    let content_bytes = content.len() as i64;
+    if content_bytes > EDIT_EVENT_BYTE_BUDGET {
+        return Ok(None);
+    }
+
+    let latest: Option<String> = conn
+        .query_row(
+            "SELECT content FROM edit_events WHERE proposal_id = ?1 ORDER BY id DESC LIMIT 1",
+            params![proposal_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    if latest.as_deref() == Some(content) {
+        return Ok(None);
+    }
+
+    conn.execute(
+        "INSERT INTO edit_events (proposal_id, content, content_bytes) VALUES (?1, ?2, ?3)",
+        params![proposal_id, content, content_bytes],
+    )?;
+    let id = conn.last_insert_rowid();
+    prune_edit_events(conn, proposal_id)?;
+    Ok(Some(id))
+}
+
+/// Apply the retention window (all proposals), then the count and byte limits
+/// (this proposal). Returns the number of events deleted.
+pub fn prune_edit_events(conn: &Connection, proposal_id: i64) -> Result<usize> {
+    let mut deleted = conn.execute(
+        "DELETE FROM edit_events
+         WHERE created_at < strftime('%Y-%m-%d %H:%M:%f', 'now', ?1)",
+        params![retention_modifier()],
+    )?;
+
+    deleted += conn.execute(
+        "DELETE FROM edit_events
+         WHERE proposal_id = ?1
+           AND id NOT IN (
+               SELECT id FROM edit_events WHERE proposal_id = ?1 ORDER BY id DESC LIMIT ?2
+           )",
+        params![proposal_id, MAX_EDIT_EVENTS_PER_PROPOSAL],
+    )?;
+
+    // Newest first, drop every event past the point the running total exceeds the budget
+    deleted += conn.execute(
+        "DELETE FROM edit_events
+         WHERE id IN (
+             SELECT id FROM (
+                 SELECT id, SUM(content_bytes) OVER (ORDER BY id DESC) AS running_bytes
+                 FROM edit_events
+                 WHERE proposal_id = ?1
+             )
+             WHERE running_bytes > ?2
+         )",
+        params![proposal_id, EDIT_EVENT_BYTE_BUDGET],
+    )?;
+
+    Ok(deleted)
+}
+
+/// Recent edit events for a proposal, newest first (within the retention window)
+pub fn get_recent_edits(
+    conn: &Connection,
+    proposal_id: i64,
+    limit: i64,
+) -> Result<Vec<EditEventSummary>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, proposal_id, created_at, content_bytes, SUBSTR(content, 1, 50)
+         FROM edit_events
+         WHERE proposal_id = ?1
+           AND created_at >= strftime('%Y-%m-%d %H:%M:%f', 'now', ?2)
+         ORDER BY id DESC
+         LIMIT ?3",
+    )?;
+    let events = stmt
+        .query_map(params![proposal_id, retention_modifier(), limit], |row| {
+            Ok(EditEventSummary {
+                id: row.get(0)?,
+                proposal_id: row.get(1)?,
+                created_at: row.get(2)?,
+                content_bytes: row.get(3)?,
+                content_preview: row.get(4)?,
+            })
+        })?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(events)
+}
+
+/// Full edit event by id
+pub fn get_edit_event(conn: &Connection, edit_event_id: i64) -> Result<Option<EditEvent>> {
+    conn.query_row(
+        "SELECT id, proposal_id, content, created_at FROM edit_events WHERE id = ?1",
+        params![edit_event_id],
+        |row| {
+            Ok(EditEvent {
+                id: row.get(0)?,
+                proposal_id: row.get(1)?,
+                content: row.get(2)?,
+                created_at: row.get(3)?,
+            })
+        },
+    )
+    .optional()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use tempfile::tempdir;
+
+    fn create_test_db() -> Database {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        Database::new(db_path, None).unwrap()
+    }
+
+    fn insert_proposal(conn: &Connection) -> i64 {
+        conn.execute(
+            "INSERT INTO proposals (job_content, generated_text) VALUES ('job', 'proposal')",
+            [],
+        )
+        .unwrap();
+        conn.last_insert_rowid()
+    }
+
+    fn event_count(conn: &Connection, proposal_id: i64) -> i64 {
+        conn.query_row(
+            "SELECT COUNT(*) FROM edit_events WHERE proposal_id = ?1",
+            [proposal_id],
+            |row| row.get(0),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_record_skips_unchanged_and_lists_newest_first() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        let proposal_id = insert_proposal(&conn);
+
+        let first = record_edit_event(&conn, proposal_id, "Hello").unwrap();
+        assert!(first.is_some());
+        assert_eq!(
+            record_edit_event(&conn, proposal_id, "Hello").unwrap(),
+            None
+        );
+        let second = record_edit_event(&conn, proposal_id, "Hello there").unwrap();
+
+        let recent = get_recent_edits(&conn, proposal_id, 10).unwrap();
+        assert_eq!(recent.len(), 2);
+        assert_eq!(Some(recent[0].id), second);
+        assert_eq!(recent[0].content_bytes, 11);
+
+        let event = get_edit_event(&conn, first.unwrap()).unwrap().unwrap();
+        assert_eq!(event.content, "Hello");
+        assert_eq!(get_edit_event(&conn, 9999).unwrap(), None);
+    }
+
+    #[test]
+    fn test_prune_keeps_last_n_and_recent_hours() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        let proposal_id = insert_proposal(&conn);
+        conn.execute(
+            "INSERT INTO edit_events (proposal_id, content, content_bytes, created_at)
+             VALUES (?1, 'old', 3, strftime('%Y-%m-%d %H:%M:%f', 'now', '-1 day'))",
+            [proposal_id],
+        )
+        .unwrap();
+
+        for i in 0..(MAX_EDIT_EVENTS_PER_PROPOSAL + 5) {
+            record_edit_event(&conn, proposal_id, &format!("draft {}", i)).unwrap();
+        }
+
+        assert_eq!(
+            event_count(&conn, proposal_id),
+            MAX_EDIT_EVENTS_PER_PROPOSAL
+        );
+        let recent = get_recent_edits(&conn, proposal_id, 1000).unwrap();
+        assert!(recent.iter().all(|e| e.content_preview != "old"));
+        assert_eq!(
+            recent.last().unwrap().content_preview,
+            "draft 5",
+            "oldest snapshots go first"
+        );
+    }
+
+    #[test]
+    fn test_prune_enforces_byte_budget() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        let proposal_id = insert_proposal(&conn);
+        let other_id = insert_proposal(&conn);
+        let chunk = (EDIT_EVENT_BYTE_BUDGET / 4) as usize;
+
+        record_edit_event(&conn, other_id, &"x".repeat(chunk)).unwrap();
+        for c in ['a', 'b', 'c', 'd', 'e', 'f'] {
+            record_edit_event(&conn, proposal_id, &c.to_string().repeat(chunk)).unwrap();
+        }
+
+        let bytes: i64 = conn
+            .query_row(
+                "SELECT SUM(content_bytes) FROM edit_events WHERE proposal_id = ?1",
+                [proposal_id],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert!(bytes <= EDIT_EVENT_BYTE_BUDGET);
+        assert_eq!(event_count(&conn, proposal_id), 4);
+        // Budget is per proposal
+        assert_eq!(event_count(&conn, other_id), 1);
+
+        // A single snapshot over the budget is not recorded
+        let huge = "z".repeat(EDIT_EVENT_BYTE_BUDGET as usize + 1);
+        assert_eq!(record_edit_event(&conn, proposal_id, &huge).unwrap(), None);
+    }
+}
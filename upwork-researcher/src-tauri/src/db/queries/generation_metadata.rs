@@ -0,0 +1,309 @@
+//! Generation metadata queries.
+//!
+//! One row per generated proposal recording how it was produced: model,
+//! humanization intensity, hook strategy, and voice profile name and version
+//! (the profile's `updated_at`; NULL = default voice), the API key profile whose
+//! key was used, plus the prompt context (`claude::GenerationContext`: template
+//! version, system prompt hash and parameter values) as JSON. No prompt text is stored.
+//! Rows are written in the same transaction as the proposal, so a proposal
+//! saved with metadata never exists without it. Proposals saved before the
+//! table existed have no row and report "unknown".
+
+use crate::claude::GenerationContext;
+use rusqlite::{params, Connection, OptionalExtension, Result};
+use serde::{Deserialize, Serialize};
+
+/// Reported for fields of proposals that predate generation metadata
+pub const UNKNOWN: &str = "unknown";
+/// Reported as the hook strategy when none was used
+pub const NO_HOOK_STRATEGY: &str = "none";
+/// Reported as the voice profile version when generated with the default voice
+pub const DEFAULT_VOICE: &str = "default";
+
+/// Values computed at generation time, passed back to `save_proposal`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NewGenerationMetadata {
+    pub model: String,
+    pub humanization_intensity: String,
+    pub hook_strategy_id: Option<String>,
+    pub voice_profile_version: Option<String>,
+    /// Voice profile used; None with the default voice. Absent from older clients.
+    #[serde(default)]
+    pub voice_profile_name: Option<String>,
+    pub target_words: Option<u32>,
+    /// API key profile used (`ConfigState::active_profile`). Absent from older clients.
+    #[serde(default)]
+    pub api_key_profile: Option<String>,
+    /// Prompt version and hash; absent from older clients and queued generations
+    /// saved before prompt versioning
+    #[serde(default)]
+    pub generation_context: Option<GenerationContext>,
+}
+
+/// Generation metadata for one proposal, as returned by `get_generation_metadata`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationMetadata {
+    pub proposal_id: i64,
+    /// False for proposals saved before metadata was recorded (all fields "unknown")
+    pub recorded: bool,
+    pub model: String,
+    pub humanization_intensity: String,
+    /// Hook strategy key, or "none"
+    pub hook_strategy_id: String,
+    /// Voice profile `updated_at` at generation time, or "default"
+    pub voice_profile_version: String,
+    /// Voice profile name; None with the default voice or when not recorded
+    pub voice_profile_name: Option<String>,
+    pub target_words: Option<u32>,
+    /// API key profile used; None when not recorded
+    pub api_key_profile: Option<String>,
+    pub recorded_at: Option<String>,
+}
+
+impl GenerationMetadata {
+    fn unknown(proposal_id: i64) -> Self {
+        Self {
+            proposal_id,
+            recorded: false,
+            model: UNKNOWN.to_string(),
+            humanization_intensity: UNKNOWN.to_string(),
+            hook_strategy_id: UNKNOWN.to_string(),
+            voice_profile_version: UNKNOWN.to_string(),
+            voice_profile_name: None,
+            target_words: None,
+            api_key_profile: None,
+            recorded_at: None,
+        }
+    }
+}
+
+/// Insert the metadata row for a proposal
+pub fn insert_generation_metadata(
+    conn: &Connection,
+    proposal_id: i64,
+    metadata: &NewGenerationMetadata,
+) -> Result<()> {
+    let generation_context = metadata
+        .generation_context
+        .as_ref()
+        .map(serde_json::to_string)
+        .transpose()
+        .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+    conn.execute(
+        "INSERT INTO generation_metadata
+            (proposal_id, model, humanization_intensity, hook_strategy_id,
+             voice_profile_version, target_words, voice_profile_name, generation_context,
+             api_key_profile)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            proposal_id,
+            metadata.model,
+            metadata.humanization_intensity,
+            metadata.hook_strategy_id,
+            metadata.voice_profile_version,
+            metadata.target_words,
+            metadata.voice_profile_name,
+            generation_context,
+            metadata.api_key_profile,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Insert a proposal (via `insert_proposal`, which returns its id) and its
+/// metadata in one transaction. If either insert fails, nothing is saved.
+pub fn save_proposal_with_metadata<F>(
+    conn: &Connection,
+    metadata: Option<&NewGenerationMetadata>,
+    insert_proposal: F,
+) -> Result<i64>
+where
+    F: FnOnce(&Connection) -> Result<i64>,
+{
+    let tx = conn.unchecked_transaction()?;
+    let proposal_id = insert_proposal(&tx)?;
+    if let Some(metadata) = metadata {
+        insert_generation_metadata(&tx, proposal_id, metadata)?;
+    }
+    tx.commit()?;
+    Ok(proposal_id)
+}
+
+/// Metadata for a proposal; "unknown" fields when none was recorded
+pub fn get_generation_metadata(conn: &Connection, proposal_id: i64) -> Result<GenerationMetadata> {
+    let row = conn
+        .query_row(
+            "SELECT model, humanization_intensity, hook_strategy_id, voice_profile_version,
+                    target_words, created_at, voice_profile_name, api_key_profile
+             FROM generation_metadata
+             WHERE proposal_id = ?1",
+            params![proposal_id],
+            |row| {
+                Ok(GenerationMetadata {
+                    proposal_id,
+                    recorded: true,
+                    model: row.get(0)?,
+                    humanization_intensity: row.get(1)?,
+                    hook_strategy_id: row
+                        .get::<_, Option<String>>(2)?
+                        .unwrap_or_else(|| NO_HOOK_STRATEGY.to_string()),
+                    voice_profile_version: row
+                        .get::<_, Option<String>>(3)?
+                        .unwrap_or_else(|| DEFAULT_VOICE.to_string()),
+                    voice_profile_name: row.get(6)?,
+                    target_words: row.get(4)?,
+                    api_key_profile: row.get(7)?,
+                    recorded_at: Some(row.get(5)?),
+                })
+            },
+        )
+        .optional()?;
+    Ok(row.unwrap_or_else(|| GenerationMetadata::unknown(proposal_id)))
+}
+
+/// Prompt context recorded for a proposal; None when it was generated before
+/// prompt versioning or saved without metadata
+pub fn get_generation_context(
+    conn: &Connection,
+    proposal_id: i64,
+) -> Result<Option<GenerationContext>> {
+    let stored: Option<String> = conn
+        .query_row(
+            "SELECT generation_context FROM generation_metadata WHERE proposal_id = ?1",
+            params![proposal_id],
+            |row| row.get(0),
+        )
+        .optional()?
+        .flatten();
+    stored
+        .map(|json| serde_json::from_str(&json))
+        .transpose()
+        .map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use tempfile::tempdir;
+
+    fn create_test_db() -> Database {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        Database::new(db_path, None).unwrap()
+    }
+
+    fn metadata() -> NewGenerationMetadata {
+        NewGenerationMetadata {
+            model: "claude-sonnet-4-20250514".to_string(),
+            humanization_intensity: "medium".to_string(),
+            hook_strategy_id: Some("social_proof".to_string()),
+            voice_profile_version: Some("2026-01-05 10:00:00".to_string()),
+            voice_profile_name: Some("technical".to_string()),
+            target_words: Some(200),
+            api_key_profile: Some("client-work".to_string()),
+            generation_context: None,
+        }
+    }
+
+    fn insert_proposal(conn: &Connection) -> Result<i64> {
+        conn.execute(
+            "INSERT INTO proposals (job_content, generated_text) VALUES ('job', 'proposal')",
+            [],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    #[test]
+    fn test_save_records_metadata_with_proposal() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let id = save_proposal_with_metadata(&conn, Some(&metadata()), insert_proposal).unwrap();
+        let stored = get_generation_metadata(&conn, id).unwrap();
+        assert!(stored.recorded);
+        assert_eq!(stored.model, "claude-sonnet-4-20250514");
+        assert_eq!(stored.humanization_intensity, "medium");
+        assert_eq!(stored.hook_strategy_id, "social_proof");
+        assert_eq!(stored.voice_profile_version, "2026-01-05 10:00:00");
+        assert_eq!(stored.voice_profile_name.as_deref(), Some("technical"));
+        assert_eq!(stored.target_words, Some(200));
+        assert_eq!(stored.api_key_profile.as_deref(), Some("client-work"));
+        assert!(stored.recorded_at.is_some());
+
+        // Deleting the proposal removes its metadata
+        conn.execute("DELETE FROM proposals WHERE id = ?1", [id])
+            .unwrap();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM generation_metadata", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_failed_metadata_insert_rolls_back_proposal() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        // A metadata row already exists for the new proposal, so the second insert fails
+        let result = save_proposal_with_metadata(&conn, Some(&metadata()), |tx| {
+            let id = insert_proposal(tx)?;
+            insert_generation_metadata(tx, id, &metadata())?;
+            Ok(id)
+        });
+        assert!(result.is_err());
+
+        let proposals: i64 = conn
+            .query_row("SELECT COUNT(*) FROM proposals", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(proposals, 0, "proposal must not be saved without metadata");
+    }
+
+    #[test]
+    fn test_proposal_without_metadata_reports_unknown() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let id = save_proposal_with_metadata(&conn, None, insert_proposal).unwrap();
+        let stored = get_generation_metadata(&conn, id).unwrap();
+        assert_eq!(stored, GenerationMetadata::unknown(id));
+        assert_eq!(stored.model, UNKNOWN);
+        assert_eq!(stored.voice_profile_version, UNKNOWN);
+    }
+
+    #[test]
+    fn test_generation_context_round_trip() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let context = GenerationContext {
+            prompt_version: crate::claude::GENERATION_PROMPT_VERSION.to_string(),
+            system_prompt_sha256: "ab".repeat(32),
+            humanization_intensity: "medium".to_string(),
+            rehumanization_attempt: None,
+            hook_strategy_id: Some("social_proof".to_string()),
+            voice_summary: None,
+            target_words: Some(200),
+            target_language: Some("en".to_string()),
+            addendum_applied: false,
+        };
+        let with_context = NewGenerationMetadata {
+            generation_context: Some(context.clone()),
+            ..metadata()
+        };
+        let id = save_proposal_with_metadata(&conn, Some(&with_context), insert_proposal).unwrap();
+        assert_eq!(get_generation_context(&conn, id).unwrap(), Some(context));
+
+        // Metadata without context, and no metadata at all, both report None
+        let id = save_proposal_with_metadata(&conn, Some(&metadata()), insert_proposal).unwrap();
+        assert_eq!(get_generation_context(&conn, id).unwrap(), None);
+        let id = save_proposal_with_metadata(&conn, None, insert_proposal).unwrap();
+        assert_eq!(get_generation_context(&conn, id).unwrap(), None);
+    }
+}
@@ -15,12 +15,52 @@ pub struct GoldenProposal {
     pub created_at: String,
 }
 
-/// Minimum word count for valid proposal (AC-4)
-const MIN_WORD_COUNT: usize = 200;
+/// Default minimum word count for valid proposal (AC-4)
+pub const DEFAULT_MIN_WORD_COUNT: usize = 200;
+
+/// Lowest configurable minimum - calibration still needs real prose
+pub const MIN_WORD_COUNT_FLOOR: usize = 50;
+
+/// Setting key overriding the minimum word count for short-but-valid proposal styles
+pub const MIN_WORD_COUNT_SETTING: &str = "golden_set_min_words";
 
 /// Maximum number of golden proposals allowed (validation rules)
 const MAX_PROPOSALS: i64 = 5;
 
+fn constraint_error(msg: String) -> rusqlite::Error {
+    rusqlite::Error::SqliteFailure(
+        rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_CONSTRAINT),
+        Some(msg),
+    )
+}
+
+/// Hash of content with whitespace collapsed and casing ignored, for duplicate detection
+pub fn normalized_content_hash(content: &str) -> String {
+    use sha2::{Digest, Sha256};
+
+    let normalized = content
+        .split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ");
+    hex::encode(Sha256::digest(normalized.as_bytes()))
+}
+
+/// Minimum word count from settings, falling back to the default.
+/// Values below `MIN_WORD_COUNT_FLOOR` are raised to the floor.
+pub fn get_min_word_count(conn: &Connection) -> usize {
+    match crate::db::queries::settings::get_setting(conn, MIN_WORD_COUNT_SETTING) {
+        Ok(Some(value)) => match value.trim().parse::<usize>() {
+            Ok(min) => min.max(MIN_WORD_COUNT_FLOOR),
+            Err(_) => {
+                tracing::warn!("Invalid {} setting '{}', using default", MIN_WORD_COUNT_SETTING, value);
+                DEFAULT_MIN_WORD_COUNT
+            }
+        },
+        _ => DEFAULT_MIN_WORD_COUNT,
+    }
+}
+
 /// Add a new golden proposal to the set
 /// Returns proposal ID on success, error if word count < 200 or max 5 reached
 pub fn add_golden_proposal(
@@ -28,24 +68,49 @@ pub fn add_golden_proposal(
     content: &str,
     source_filename: Option<&str>,
 ) -> Result<i64, rusqlite::Error> {
+    add_golden_proposal_with_min_words(conn, content, source_filename, DEFAULT_MIN_WORD_COUNT)
+}
+
+/// Add a new golden proposal with an explicit minimum word count
+///
+/// Rejects empty content, content below `min_word_count`, and near-duplicates
+/// (same text ignoring whitespace and casing) of existing golden proposals.
+pub fn add_golden_proposal_with_min_words(
+    conn: &Connection,
+    content: &str,
+    source_filename: Option<&str>,
+    min_word_count: usize,
+) -> Result<i64, rusqlite::Error> {
+    if content.trim().is_empty() {
+        return Err(constraint_error("Proposal content cannot be empty".to_string()));
+    }
+
     // Validate maximum 5 proposals (validation rules)
     let current_count = get_golden_proposal_count(conn)?;
     if current_count >= MAX_PROPOSALS {
-        return Err(rusqlite::Error::SqliteFailure(
-            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_CONSTRAINT),
-            Some(format!("Maximum {} proposals allowed", MAX_PROPOSALS)),
-        ));
+        return Err(constraint_error(format!(
+            "Maximum {} proposals allowed",
+            MAX_PROPOSALS
+        )));
     }
 
-    // AC-4: Validate minimum 200 words
+    // AC-4: Validate minimum word count
     let word_count = content.split_whitespace().count();
-    if word_count < MIN_WORD_COUNT {
-        return Err(rusqlite::Error::SqliteFailure(
-            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_CONSTRAINT),
-            Some(format!(
-                "Proposal must be at least {} words (currently: {})",
-                MIN_WORD_COUNT, word_count
-            )),
+    if word_count < min_word_count {
+        return Err(constraint_error(format!(
+            "Proposal must be at least {} words (currently: {})",
+            min_word_count, word_count
+        )));
+    }
+
+    // Reject near-duplicates (at most MAX_PROPOSALS rows, so hash on the fly)
+    let content_hash = normalized_content_hash(content);
+    let is_duplicate = get_golden_proposals(conn)?
+        .iter()
+        .any(|existing| normalized_content_hash(&existing.content) == content_hash);
+    if is_duplicate {
+        return Err(constraint_error(
+            "This proposal has already been added to your golden set".to_string(),
         ));
     }
 
@@ -275,9 +340,9 @@ mod tests {
 
         // Add 3 proposals
         let content = "word ".repeat(200); // 200 words
-        add_golden_proposal(&conn, &content, Some("1.txt")).unwrap();
-        add_golden_proposal(&conn, &content, Some("2.txt")).unwrap();
-        add_golden_proposal(&conn, &content, Some("3.txt")).unwrap();
+        add_golden_proposal(&conn, &format!("{}one", content), Some("1.txt")).unwrap();
+        add_golden_proposal(&conn, &format!("{}two", content), Some("2.txt")).unwrap();
+        add_golden_proposal(&conn, &format!("{}three", content), Some("3.txt")).unwrap();
 
         let count = get_golden_proposal_count(&conn).unwrap();
         assert_eq!(count, 3, "Should return 3 after adding 3 proposals");
@@ -318,7 +383,8 @@ mod tests {
         let id1 = add_golden_proposal(&conn, &content, None).unwrap();
 
         // With filename (uploaded file)
-        let id2 = add_golden_proposal(&conn, &content, Some("uploaded.pdf")).unwrap();
+        let id2 = add_golden_proposal(&conn, &format!("{}uploaded", content), Some("uploaded.pdf"))
+            .unwrap();
 
         let proposals = get_golden_proposals(&conn).unwrap();
 
@@ -345,7 +411,9 @@ mod tests {
 
         // Add 5 proposals (should succeed)
         for i in 1..=5 {
-            let result = add_golden_proposal(&conn, &content, Some(&format!("{}.txt", i)));
+            // Distinct content per proposal (duplicates are rejected)
+            let unique = format!("{}proposal {}", content, i);
+            let result = add_golden_proposal(&conn, &unique, Some(&format!("{}.txt", i)));
             assert!(result.is_ok(), "Proposal {} should be accepted", i);
         }
 
@@ -365,4 +433,76 @@ mod tests {
         let count = get_golden_proposal_count(&conn).unwrap();
         assert_eq!(count, 5, "Should have exactly 5 proposals");
     }
+
+    #[test]
+    fn test_add_golden_proposal_rejects_empty_content() {
+        let conn = setup_test_db();
+
+        for content in ["", "   \n\t  "] {
+            match add_golden_proposal(&conn, content, None) {
+                Err(rusqlite::Error::SqliteFailure(_, Some(msg))) => {
+                    assert!(msg.contains("empty"), "Unexpected error: {}", msg)
+                }
+                other => panic!("Expected empty-content error, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_golden_proposal_rejects_near_duplicate() {
+        let conn = setup_test_db();
+
+        let content = "I deliver clean React code on time. ".repeat(40);
+        add_golden_proposal(&conn, &content, None).unwrap();
+
+        // Same text with different casing and whitespace
+        let variant = content.to_uppercase().replace(' ', "  \n");
+        match add_golden_proposal(&conn, &variant, None) {
+            Err(rusqlite::Error::SqliteFailure(_, Some(msg))) => {
+                assert!(msg.contains("already been added"), "Unexpected error: {}", msg)
+            }
+            other => panic!("Expected duplicate error, got {:?}", other),
+        }
+        assert_eq!(get_golden_proposal_count(&conn).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_add_golden_proposal_custom_min_words() {
+        let conn = setup_test_db();
+
+        let content = "word ".repeat(80);
+        assert!(add_golden_proposal(&conn, &content, None).is_err());
+        assert!(add_golden_proposal_with_min_words(&conn, &content, None, 60).is_ok());
+    }
+
+    #[test]
+    fn test_get_min_word_count_from_setting() {
+        let conn = setup_test_db();
+        conn.execute_batch(
+            "CREATE TABLE settings (key TEXT PRIMARY KEY, value TEXT NOT NULL, updated_at TEXT NOT NULL DEFAULT (datetime('now')));",
+        )
+        .unwrap();
+
+        assert_eq!(get_min_word_count(&conn), DEFAULT_MIN_WORD_COUNT);
+
+        crate::db::queries::settings::set_setting(&conn, MIN_WORD_COUNT_SETTING, "120").unwrap();
+        assert_eq!(get_min_word_count(&conn), 120);
+
+        // Clamped to floor
+        crate::db::queries::settings::set_setting(&conn, MIN_WORD_COUNT_SETTING, "5").unwrap();
+        assert_eq!(get_min_word_count(&conn), MIN_WORD_COUNT_FLOOR);
+
+        // Garbage falls back to default
+        crate::db::queries::settings::set_setting(&conn, MIN_WORD_COUNT_SETTING, "abc").unwrap();
+        assert_eq!(get_min_word_count(&conn), DEFAULT_MIN_WORD_COUNT);
+    }
+
+    #[test]
+    fn test_normalized_content_hash_ignores_case_and_whitespace() {
+        assert_eq!(
+            normalized_content_hash("Hello   World\n"),
+            normalized_content_hash("hello world")
+        );
+        assert_ne!(normalized_content_hash("hello world"), normalized_content_hash("hello there"));
+    }
 }
@@ -27,6 +27,8 @@ pub struct HookStrategy {
     pub remote_id: Option<String>,
     /// A/B testing weight in [0.0, 1.0]. 0.0 = inactive for A/B (Story 10.4: AC-1)
     pub ab_weight: f64,
+    /// Whether automatic A/B selection may pick this strategy (local preference, V46)
+    pub in_ab_rotation: bool,
 }
 
 /// Get all non-retired hook strategies.
@@ -48,7 +50,7 @@ pub struct HookStrategy {
 /// Use `get_all_hook_strategies_including_retired()` for history/admin views.
 pub fn get_all_hook_strategies(conn: &Connection) -> Result<Vec<HookStrategy>, rusqlite::Error> {
     let mut stmt = conn.prepare(
-        "SELECT id, name, description, examples_json, best_for, created_at, status, remote_id, ab_weight
+        "SELECT id, name, description, examples_json, best_for, created_at, status, remote_id, ab_weight, in_ab_rotation
          FROM hook_strategies
          WHERE status != 'retired'
          ORDER BY status ASC, id ASC",
@@ -66,6 +68,7 @@ pub fn get_all_hook_strategies(conn: &Connection) -> Result<Vec<HookStrategy>, r
                 status: row.get(6)?,
                 remote_id: row.get(7)?,
                 ab_weight: row.get(8)?,
+                in_ab_rotation: row.get(9)?,
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -86,7 +89,7 @@ pub fn get_all_hook_strategies_including_retired(
     conn: &Connection,
 ) -> Result<Vec<HookStrategy>, rusqlite::Error> {
     let mut stmt = conn.prepare(
-        "SELECT id, name, description, examples_json, best_for, created_at, status, remote_id, ab_weight
+        "SELECT id, name, description, examples_json, best_for, created_at, status, remote_id, ab_weight, in_ab_rotation
          FROM hook_strategies
          ORDER BY status ASC, id ASC",
     )?;
@@ -103,6 +106,7 @@ pub fn get_all_hook_strategies_including_retired(
                 status: row.get(6)?,
                 remote_id: row.get(7)?,
                 ab_weight: row.get(8)?,
+                in_ab_rotation: row.get(9)?,
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -119,7 +123,7 @@ pub fn get_hook_strategy_by_id(
     id: i64,
 ) -> Result<Option<HookStrategy>, rusqlite::Error> {
     let mut stmt = conn.prepare(
-        "SELECT id, name, description, examples_json, best_for, created_at, status, remote_id, ab_weight
+        "SELECT id, name, description, examples_json, best_for, created_at, status, remote_id, ab_weight, in_ab_rotation
          FROM hook_strategies
          WHERE id = ?1",
     )?;
@@ -137,12 +141,39 @@ pub fn get_hook_strategy_by_id(
             status: row.get(6)?,
             remote_id: row.get(7)?,
             ab_weight: row.get(8)?,
+            in_ab_rotation: row.get(9)?,
         }))
     } else {
         Ok(None)
     }
 }
 
+/// Whether a strategy with this name exists (retired ones included: past
+/// proposals can legitimately have used them). Proposals store the name as
+/// `hook_strategy_id`.
+pub fn hook_strategy_name_exists(conn: &Connection, name: &str) -> Result<bool, rusqlite::Error> {
+    conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM hook_strategies WHERE name = ?1)",
+        params![name],
+        |row| row.get(0),
+    )
+}
+
+/// Add a strategy to or remove it from A/B rotation.
+///
+/// Returns false if no non-retired strategy has this ID.
+pub fn set_in_ab_rotation(
+    conn: &Connection,
+    id: i64,
+    in_rotation: bool,
+) -> Result<bool, rusqlite::Error> {
+    let rows = conn.execute(
+        "UPDATE hook_strategies SET in_ab_rotation = ?1 WHERE id = ?2 AND status != 'retired'",
+        params![in_rotation, id],
+    )?;
+    Ok(rows > 0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
+use super::clients::client_name_columns;
 use rusqlite::{params, Connection, OptionalExtension, Result};
 
 /// Insert a new job post into the database
@@ -8,9 +9,11 @@ pub fn insert_job_post(
     raw_content: &str,
     client_name: Option<&str>,
 ) -> Result<i64> {
+    let (client_name, client_key) = client_name_columns(client_name);
     conn.execute(
-        "INSERT INTO job_posts (url, raw_content, client_name) VALUES (?1, ?2, ?3)",
-        params![url, raw_content, client_name],
+        "INSERT INTO job_posts (url, raw_content, client_name, client_name_normalized)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![url, raw_content, client_name, client_key],
     )?;
     Ok(conn.last_insert_rowid())
 }
@@ -34,8 +37,9 @@ pub fn insert_job_post_from_rss(
         )
         .optional()?;
 
-    if existing.is_some() {
-        // Duplicate detected, skip
+    if let Some(id) = existing {
+        // Duplicate detected: still listed in the feed, so refresh freshness and skip
+        touch_job_post_last_seen(conn, id)?;
         return Ok(None);
     }
 
@@ -49,6 +53,194 @@ pub fn insert_job_post_from_rss(
     Ok(Some(conn.last_insert_rowid()))
 }
 
+/// Find an existing job post with the same URL, or with identical content when
+/// there is no URL. Used for duplicate detection on file imports.
+pub fn find_duplicate_job_post(
+    conn: &Connection,
+    url: Option<&str>,
+    raw_content: &str,
+) -> Result<Option<i64>> {
+    match url {
+        Some(url) => conn
+            .query_row(
+                "SELECT id FROM job_posts WHERE url = ?1",
+                params![url],
+                |row| row.get(0),
+            )
+            .optional(),
+        None => conn
+            .query_row(
+                "SELECT id FROM job_posts WHERE raw_content = ?1 LIMIT 1",
+                params![raw_content],
+                |row| row.get(0),
+            )
+            .optional(),
+    }
+}
+
+// ==========================================
+// Job Freshness Functions
+// ==========================================
+
+pub const JOB_STATUS_ACTIVE: &str = "active";
+pub const JOB_STATUS_STALE: &str = "stale";
+pub const JOB_STATUS_APPLIED: &str = "applied";
+pub const JOB_STATUS_DISMISSED: &str = "dismissed";
+
+/// Record that an RSS refresh saw this job again. A stale job becomes active again.
+pub fn touch_job_post_last_seen(conn: &Connection, job_post_id: i64) -> Result<()> {
+    conn.execute(
+        "UPDATE job_posts
+         SET last_seen_at = datetime('now'),
+             job_status = CASE WHEN job_status = 'stale' THEN 'active' ELSE job_status END
+         WHERE id = ?1",
+        params![job_post_id],
+    )?;
+    Ok(())
+}
+
+/// Set a job's status. Returns false if the job doesn't exist.
+pub fn set_job_status(conn: &Connection, job_post_id: i64, status: &str) -> Result<bool> {
+    let updated = conn.execute(
+        "UPDATE job_posts SET job_status = ?1 WHERE id = ?2",
+        params![status, job_post_id],
+    )?;
+    Ok(updated > 0)
+}
+
+pub const QUEUE_STATUS_ACTIVE: &str = "active";
+pub const QUEUE_STATUS_ARCHIVED: &str = "archived";
+pub const QUEUE_STATUS_SNOOZED: &str = "snoozed";
+
+/// A job's queue status as of now (V52): a snooze that has run out reads as
+/// active without the row being rewritten
+pub const EFFECTIVE_QUEUE_STATUS_SQL: &str = "CASE WHEN queue_status = 'snoozed'
+         AND (snoozed_until IS NULL OR snoozed_until <= datetime('now'))
+         THEN 'active' ELSE queue_status END";
+
+/// Archive, snooze or re-activate a job. Only `queue_status` and `snoozed_until`
+/// change; scores, analysis and `job_status` are left alone. `snoozed_until`
+/// (UTC, `YYYY-MM-DD HH:MM:SS`) is stored for snoozes and cleared otherwise.
+/// Returns false if the job doesn't exist.
+pub fn set_job_queue_status(
+    conn: &Connection,
+    job_post_id: i64,
+    queue_status: &str,
+    snoozed_until: Option<&str>,
+) -> Result<bool> {
+    let snoozed_until = if queue_status == QUEUE_STATUS_SNOOZED {
+        snoozed_until
+    } else {
+        None
+    };
+    let updated = conn.execute(
+        "UPDATE job_posts SET queue_status = ?1, snoozed_until = ?2 WHERE id = ?3",
+        params![queue_status, snoozed_until, job_post_id],
+    )?;
+    Ok(updated > 0)
+}
+
+/// Mark a job applied and link the proposal sent for it (proposals.job_post_id).
+/// Returns false if the job or proposal doesn't exist; nothing is changed then.
+pub fn mark_job_applied(conn: &Connection, job_post_id: i64, proposal_id: i64) -> Result<bool> {
+    conn.execute("BEGIN IMMEDIATE TRANSACTION", [])?;
+
+    let result = (|| {
+        if !set_job_status(conn, job_post_id, JOB_STATUS_APPLIED)? {
+            return Ok(false);
+        }
+        let linked = conn.execute(
+            "UPDATE proposals SET job_post_id = ?1 WHERE id = ?2",
+            params![job_post_id, proposal_id],
+        )?;
+        Ok(linked > 0)
+    })();
+
+    match result {
+        Ok(true) => {
+            conn.execute("COMMIT", [])?;
+            Ok(true)
+        }
+        Ok(false) => {
+            let _ = conn.execute("ROLLBACK", []);
+            Ok(false)
+        }
+        Err(e) => {
+            let _ = conn.execute("ROLLBACK", []);
+            Err(e)
+        }
+    }
+}
+
+/// Mark active jobs not seen (last_seen_at, else created_at) for `stale_days` as stale.
+/// Applied and dismissed jobs are left alone. Returns the number of jobs marked.
+pub fn mark_stale_jobs(conn: &Connection, stale_days: u32) -> Result<usize> {
+    conn.execute(
+        "UPDATE job_posts SET job_status = 'stale'
+         WHERE job_status = 'active'
+           AND COALESCE(last_seen_at, created_at) < datetime('now', ?1)",
+        params![format!("-{} days", stale_days)],
+    )
+}
+
+/// Counts removed by `purge_stale_jobs`
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeStaleJobsResult {
+    pub jobs_deleted: usize,
+    pub skills_deleted: usize,
+    pub scores_deleted: usize,
+}
+
+/// Delete stale and dismissed jobs not seen for `older_than_days`, with their
+/// job_skills and job_scores rows, in one transaction. Linked proposals are kept
+/// (their job_post_id is cleared by the foreign key).
+pub fn purge_stale_jobs(conn: &Connection, older_than_days: u32) -> Result<PurgeStaleJobsResult> {
+    const CANDIDATES: &str = "SELECT id FROM job_posts
+         WHERE job_status IN ('stale', 'dismissed')
+           AND COALESCE(last_seen_at, created_at) < datetime('now', ?1)";
+    let cutoff = format!("-{} days", older_than_days);
+
+    conn.execute("BEGIN IMMEDIATE TRANSACTION", [])?;
+
+    let result = (|| {
+        let skills_deleted = conn.execute(
+            &format!(
+                "DELETE FROM job_skills WHERE job_post_id IN ({})",
+                CANDIDATES
+            ),
+            params![cutoff],
+        )?;
+        let scores_deleted = conn.execute(
+            &format!(
+                "DELETE FROM job_scores WHERE job_post_id IN ({})",
+                CANDIDATES
+            ),
+            params![cutoff],
+        )?;
+        let jobs_deleted = conn.execute(
+            &format!("DELETE FROM job_posts WHERE id IN ({})", CANDIDATES),
+            params![cutoff],
+        )?;
+        Ok(PurgeStaleJobsResult {
+            jobs_deleted,
+            skills_deleted,
+            scores_deleted,
+        })
+    })();
+
+    match result {
+        Ok(counts) => {
+            conn.execute("COMMIT", [])?;
+            Ok(counts)
+        }
+        Err(e) => {
+            let _ = conn.execute("ROLLBACK", []);
+            Err(e)
+        }
+    }
+}
+
 // ==========================================
 // RSS Import Background Worker Functions (Story 4b.7)
 // ==========================================
@@ -99,9 +291,10 @@ pub fn update_job_post_client_name(
     id: i64,
     client_name: Option<&str>,
 ) -> Result<()> {
+    let (client_name, client_key) = client_name_columns(client_name);
     conn.execute(
-        "UPDATE job_posts SET client_name = ?1 WHERE id = ?2",
-        params![client_name, id],
+        "UPDATE job_posts SET client_name = ?1, client_name_normalized = ?2 WHERE id = ?3",
+        params![client_name, client_key, id],
     )?;
     Ok(())
 }
@@ -220,6 +413,29 @@ pub fn get_job_post_hidden_needs(
     }
 }
 
+/// Retrieve the deliverables checklist for a job post
+///
+/// # Returns
+/// - `JobDeliverables` with required and nice-to-have items
+/// - Empty checklist if the post was analyzed before deliverables were extracted
+pub fn get_job_post_deliverables(
+    conn: &Connection,
+    job_post_id: i64,
+) -> Result<crate::analysis::JobDeliverables> {
+    let json_str: Option<String> = conn.query_row(
+        "SELECT deliverables FROM job_posts WHERE id = ?1",
+        params![job_post_id],
+        |row| row.get(0),
+    )?;
+
+    match json_str {
+        Some(json) => serde_json::from_str(&json).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))
+        }),
+        None => Ok(Default::default()),
+    }
+}
+
 // ==========================================
 // Budget Fields Functions (Story 4b.4)
 // ==========================================
@@ -230,27 +446,57 @@ pub fn get_job_post_hidden_needs(
 /// # Arguments
 /// * `conn` - Database connection
 /// * `id` - Job post ID
-/// * `budget_min` - Minimum budget (hourly rate or project minimum)
-/// * `budget_max` - Maximum budget (for ranges, else same as min)
-/// * `budget_type` - "hourly", "fixed", or "unknown"
-/// * `alignment_pct` - Budget alignment percentage (0-100+ or null)
-/// * `alignment_status` - "green", "yellow", "red", "gray", or "mismatch"
+/// * `budget` - Budget as posted: min/max in the original currency, type, and
+///   detected currency (None = USD assumed)
+/// * `alignment` - Alignment result, including the USD-normalized min/max it
+///   compared (None when the currency has no exchange rate)
 pub fn update_job_post_budget(
     conn: &Connection,
     id: i64,
-    budget_min: Option<f64>,
-    budget_max: Option<f64>,
-    budget_type: &str,
-    alignment_pct: Option<i32>,
-    alignment_status: &str,
+    budget: &crate::analysis::BudgetInfo,
+    alignment: &crate::analysis::BudgetAlignment,
 ) -> Result<()> {
     conn.execute(
-        "UPDATE job_posts SET budget_min = ?1, budget_max = ?2, budget_type = ?3, budget_alignment_pct = ?4, budget_alignment_status = ?5 WHERE id = ?6",
-        params![budget_min, budget_max, budget_type, alignment_pct, alignment_status, id],
+        "UPDATE job_posts SET budget_min = ?1, budget_max = ?2, budget_type = ?3, budget_alignment_pct = ?4, budget_alignment_status = ?5,
+            budget_currency = ?6, budget_min_usd = ?7, budget_max_usd = ?8 WHERE id = ?9",
+        params![
+            budget.min,
+            budget.max,
+            budget.budget_type,
+            alignment.percentage,
+            alignment.status,
+            budget.currency,
+            alignment.normalized_min,
+            alignment.normalized_max,
+            id
+        ],
     )?;
     Ok(())
 }
 
+/// Read the stored budget fields for a job post (Story 4b.4)
+/// Returns None if the job post does not exist; missing type reads as "unknown".
+pub fn get_job_post_budget(
+    conn: &Connection,
+    job_post_id: i64,
+) -> Result<Option<crate::analysis::BudgetInfo>> {
+    conn.query_row(
+        "SELECT budget_min, budget_max, budget_type, budget_currency FROM job_posts WHERE id = ?1",
+        params![job_post_id],
+        |row| {
+            Ok(crate::analysis::BudgetInfo {
+                min: row.get(0)?,
+                max: row.get(1)?,
+                budget_type: row
+                    .get::<_, Option<String>>(2)?
+                    .unwrap_or_else(|| "unknown".to_string()),
+                currency: row.get(3)?,
+            })
+        },
+    )
+    .optional()
+}
+
 // ==========================================
 // Atomic Save Function (Story 4a.8)
 // ==========================================
@@ -264,10 +510,12 @@ pub fn update_job_post_budget(
 /// * `client_name` - Extracted client name (or None)
 /// * `key_skills` - Slice of skill names
 /// * `hidden_needs_json` - Serialized JSON string of hidden needs array
+/// * `deliverables_json` - Serialized `JobDeliverables` (required / nice-to-have checklist)
 ///
 /// # Behavior
 /// - Wraps all operations in BEGIN EXCLUSIVE TRANSACTION / COMMIT
-/// - Updates client_name, deletes old skills, inserts new skills, updates hidden_needs
+/// - Updates client_name (and its normalized matching key), deletes old skills, inserts new
+///   skills, updates hidden_needs and deliverables
 /// - On any error: executes ROLLBACK, returns error (all-or-nothing)
 /// - Re-analysis safe: replaces existing skills (delete + insert)
 ///
@@ -276,7 +524,7 @@ pub fn update_job_post_budget(
 /// - Uses prepared statement for batch skill insert
 ///
 /// # References
-/// - Pattern: migration/mod.rs → copy_data_atomic()
+/// - Pattern: migration/mod.rs → copy_tables_resumable()
 /// - AC-2: All-or-nothing transaction semantics
 /// - AC-6: Re-analysis replaces existing data (no duplicates)
 pub fn save_job_analysis_atomic(
@@ -285,6 +533,7 @@ pub fn save_job_analysis_atomic(
     client_name: Option<&str>,
     key_skills: &[String],
     hidden_needs_json: &str,
+    deliverables_json: &str,
 ) -> Result<()> {
     // BEGIN EXCLUSIVE TRANSACTION (prevents other writes)
     conn.execute("BEGIN EXCLUSIVE TRANSACTION", [])?;
@@ -298,10 +547,11 @@ pub fn save_job_analysis_atomic(
             |_row| Ok(()),
         )?;
 
-        // 1. Update client_name
+        // 1. Update client_name and its matching key
+        let (client_name, client_key) = client_name_columns(client_name);
         conn.execute(
-            "UPDATE job_posts SET client_name = ?1 WHERE id = ?2",
-            params![client_name, job_post_id],
+            "UPDATE job_posts SET client_name = ?1, client_name_normalized = ?2 WHERE id = ?3",
+            params![client_name, client_key, job_post_id],
         )?;
 
         // 2. Delete old skills (re-analysis safe)
@@ -317,10 +567,10 @@ pub fn save_job_analysis_atomic(
             stmt.execute(params![job_post_id, skill])?;
         }
 
-        // 4. Update hidden_needs JSON
+        // 4. Update hidden_needs and deliverables JSON
         conn.execute(
-            "UPDATE job_posts SET hidden_needs = ?1 WHERE id = ?2",
-            params![hidden_needs_json, job_post_id],
+            "UPDATE job_posts SET hidden_needs = ?1, deliverables = ?2 WHERE id = ?3",
+            params![hidden_needs_json, deliverables_json, job_post_id],
         )?;
 
         Ok::<(), rusqlite::Error>(())
@@ -755,8 +1005,15 @@ mod tests {
         let skills = vec!["React".to_string(), "TypeScript".to_string()];
         let hidden_needs_json = r#"[{"need":"Time-pressured","evidence":"Urgent"}]"#;
 
-        save_job_analysis_atomic(&conn, job_id, Some("John Doe"), &skills, hidden_needs_json)
-            .unwrap();
+        save_job_analysis_atomic(
+            &conn,
+            job_id,
+            Some("John Doe"),
+            &skills,
+            hidden_needs_json,
+            "{}",
+        )
+        .unwrap();
 
         // Verify all data saved
         let saved_name: Option<String> = conn
@@ -791,7 +1048,7 @@ mod tests {
 
         let job_id = insert_job_post(&conn, None, "Job", Some("Old Name")).unwrap();
 
-        save_job_analysis_atomic(&conn, job_id, Some("New Name"), &[], "[]").unwrap();
+        save_job_analysis_atomic(&conn, job_id, Some("New Name"), &[], "[]", "{}").unwrap();
 
         let client: Option<String> = conn
             .query_row(
@@ -818,7 +1075,7 @@ mod tests {
             "PostgreSQL".to_string(),
         ];
 
-        save_job_analysis_atomic(&conn, job_id, None, &skills, "[]").unwrap();
+        save_job_analysis_atomic(&conn, job_id, None, &skills, "[]", "{}").unwrap();
 
         let saved_skills = get_job_skills(&conn, job_id).unwrap();
         assert_eq!(saved_skills, skills);
@@ -835,7 +1092,7 @@ mod tests {
         let job_id = insert_job_post(&conn, None, "Job", None).unwrap();
         let needs_json = r#"[{"need":"Budget-conscious","evidence":"Cost-effective"}]"#;
 
-        save_job_analysis_atomic(&conn, job_id, None, &[], needs_json).unwrap();
+        save_job_analysis_atomic(&conn, job_id, None, &[], needs_json, "{}").unwrap();
 
         let saved: String = conn
             .query_row(
@@ -847,6 +1104,33 @@ mod tests {
         assert_eq!(saved, needs_json);
     }
 
+    #[test]
+    fn test_save_job_analysis_atomic_deliverables_stored() {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::new(db_path, None).unwrap();
+        let conn = db.conn.lock().unwrap();
+
+        let job_id = insert_job_post(&conn, None, "Job", None).unwrap();
+        // Not analyzed yet: empty checklist
+        assert_eq!(
+            get_job_post_deliverables(&conn, job_id).unwrap(),
+            crate::analysis::JobDeliverables::default()
+        );
+
+        let deliverables = crate::analysis::JobDeliverables {
+            required: vec!["Landing page".to_string(), "Stripe checkout".to_string()],
+            nice_to_have: vec!["Dark mode".to_string()],
+        };
+        let json = serde_json::to_string(&deliverables).unwrap();
+        save_job_analysis_atomic(&conn, job_id, None, &[], "[]", &json).unwrap();
+
+        assert_eq!(
+            get_job_post_deliverables(&conn, job_id).unwrap(),
+            deliverables
+        );
+    }
+
     #[test]
     fn test_save_job_analysis_atomic_replaces_old_skills() {
         // Task 5.5: Test re-analysis replaces old skills
@@ -859,12 +1143,12 @@ mod tests {
 
         // First analysis
         let old_skills = vec!["React".to_string(), "Vue".to_string()];
-        save_job_analysis_atomic(&conn, job_id, None, &old_skills, "[]").unwrap();
+        save_job_analysis_atomic(&conn, job_id, None, &old_skills, "[]", "{}").unwrap();
         assert_eq!(get_job_skills(&conn, job_id).unwrap(), old_skills);
 
         // Re-analysis with different skills
         let new_skills = vec!["Angular".to_string()];
-        save_job_analysis_atomic(&conn, job_id, None, &new_skills, "[]").unwrap();
+        save_job_analysis_atomic(&conn, job_id, None, &new_skills, "[]", "{}").unwrap();
 
         // Verify old skills replaced, not duplicated
         let saved = get_job_skills(&conn, job_id).unwrap();
@@ -888,7 +1172,7 @@ mod tests {
         // Flow: existence check ✓ → UPDATE client_name ✓ → DELETE FROM job_skills ✗ → ROLLBACK
         conn.execute("DROP TABLE job_skills", []).unwrap();
 
-        let result = save_job_analysis_atomic(&conn, job_id, Some("New Name"), &[], "[]");
+        let result = save_job_analysis_atomic(&conn, job_id, Some("New Name"), &[], "[]", "{}");
         assert!(
             result.is_err(),
             "Expected error from missing job_skills table"
@@ -924,7 +1208,7 @@ mod tests {
         let needs_json = r#"[{"need":"Test","evidence":"Evidence"}]"#;
 
         let start = Instant::now();
-        save_job_analysis_atomic(&conn, job_id, Some("Name"), &skills, needs_json).unwrap();
+        save_job_analysis_atomic(&conn, job_id, Some("Name"), &skills, needs_json, "{}").unwrap();
         let duration = start.elapsed();
 
         assert!(
@@ -944,7 +1228,7 @@ mod tests {
 
         let job_id = insert_job_post(&conn, None, "Job", None).unwrap();
 
-        save_job_analysis_atomic(&conn, job_id, Some("Name"), &[], "[]").unwrap();
+        save_job_analysis_atomic(&conn, job_id, Some("Name"), &[], "[]", "{}").unwrap();
 
         let skills = get_job_skills(&conn, job_id).unwrap();
         assert_eq!(skills.len(), 0);
@@ -960,7 +1244,7 @@ mod tests {
 
         let job_id = insert_job_post(&conn, None, "Job", Some("Original")).unwrap();
 
-        save_job_analysis_atomic(&conn, job_id, None, &[], "[]").unwrap();
+        save_job_analysis_atomic(&conn, job_id, None, &[], "[]", "{}").unwrap();
 
         let client: Option<String> = conn
             .query_row(
@@ -980,7 +1264,7 @@ mod tests {
         let db = Database::new(db_path, None).unwrap();
         let conn = db.conn.lock().unwrap();
 
-        let result = save_job_analysis_atomic(&conn, 999999, Some("Name"), &[], "[]");
+        let result = save_job_analysis_atomic(&conn, 999999, Some("Name"), &[], "[]", "{}");
         assert!(
             result.is_err(),
             "Expected error for non-existent job_post_id"
@@ -1091,4 +1375,113 @@ mod tests {
         // Both IDs should be different
         assert_ne!(result1.unwrap(), result2.unwrap());
     }
+
+    fn job_status(conn: &Connection, id: i64) -> String {
+        conn.query_row(
+            "SELECT job_status FROM job_posts WHERE id = ?1",
+            params![id],
+            |row| row.get(0),
+        )
+        .unwrap()
+    }
+
+    fn backdate(conn: &Connection, id: i64, days: i64) {
+        conn.execute(
+            "UPDATE job_posts SET created_at = datetime('now', ?1) WHERE id = ?2",
+            params![format!("-{} days", days), id],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_stale_marking_uses_last_seen_then_created_at() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+
+        let url = "https://www.upwork.com/jobs/~01SEEN";
+        let seen = insert_job_post_from_rss(&conn, url, "Seen again", "batch_1")
+            .unwrap()
+            .unwrap();
+        let pasted = insert_job_post(&conn, None, "Old pasted job", None).unwrap();
+        let applied = insert_job_post(&conn, None, "Applied job", None).unwrap();
+        for id in [seen, pasted, applied] {
+            backdate(&conn, id, 30);
+        }
+        set_job_status(&conn, applied, JOB_STATUS_APPLIED).unwrap();
+
+        // Re-encountered by a later refresh: fresh despite its old created_at
+        assert!(
+            insert_job_post_from_rss(&conn, url, "Seen again", "batch_2")
+                .unwrap()
+                .is_none()
+        );
+
+        assert_eq!(mark_stale_jobs(&conn, 14).unwrap(), 1);
+        assert_eq!(job_status(&conn, seen), JOB_STATUS_ACTIVE);
+        assert_eq!(job_status(&conn, pasted), JOB_STATUS_STALE);
+        assert_eq!(job_status(&conn, applied), JOB_STATUS_APPLIED);
+
+        // Seen again after going stale: active again
+        conn.execute(
+            "UPDATE job_posts SET job_status = 'stale' WHERE id = ?1",
+            params![seen],
+        )
+        .unwrap();
+        touch_job_post_last_seen(&conn, seen).unwrap();
+        assert_eq!(job_status(&conn, seen), JOB_STATUS_ACTIVE);
+    }
+
+    #[test]
+    fn test_mark_applied_and_purge_stale_jobs() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+
+        let applied = insert_job_post(&conn, None, "Applied job", None).unwrap();
+        conn.execute(
+            "INSERT INTO proposals (job_content, generated_text) VALUES ('job', 'proposal')",
+            [],
+        )
+        .unwrap();
+        let proposal_id = conn.last_insert_rowid();
+        assert!(mark_job_applied(&conn, applied, proposal_id).unwrap());
+        assert!(!mark_job_applied(&conn, applied + 100, proposal_id).unwrap());
+        let linked: Option<i64> = conn
+            .query_row(
+                "SELECT job_post_id FROM proposals WHERE id = ?1",
+                params![proposal_id],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(linked, Some(applied));
+
+        let dismissed = insert_job_post(&conn, None, "Dismissed job", None).unwrap();
+        insert_job_skills(&conn, dismissed, &["Rust".to_string(), "SQL".to_string()]).unwrap();
+        conn.execute(
+            "INSERT INTO job_scores (job_post_id, skills_match_percentage) VALUES (?1, 50.0)",
+            params![dismissed],
+        )
+        .unwrap();
+        set_job_status(&conn, dismissed, JOB_STATUS_DISMISSED).unwrap();
+        let recent_stale = insert_job_post(&conn, None, "Recently stale", None).unwrap();
+        set_job_status(&conn, recent_stale, JOB_STATUS_STALE).unwrap();
+        for id in [applied, dismissed] {
+            backdate(&conn, id, 60);
+        }
+
+        let result = purge_stale_jobs(&conn, 30).unwrap();
+        assert_eq!(
+            result,
+            PurgeStaleJobsResult {
+                jobs_deleted: 1,
+                skills_deleted: 2,
+                scores_deleted: 1,
+            }
+        );
+        // Applied jobs and recently stale jobs are kept
+        assert_eq!(job_status(&conn, applied), JOB_STATUS_APPLIED);
+        assert_eq!(job_status(&conn, recent_stale), JOB_STATUS_STALE);
+        assert!(get_job_skills(&conn, dismissed).unwrap().is_empty());
+    }
 }
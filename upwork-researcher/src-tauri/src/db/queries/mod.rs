@@ -3,14 +3,24 @@
 //! Each file exports standalone functions that operate on the database.
 //! All queries use prepared statements via rusqlite's params![] macro.
 
+pub mod analysis_cache;
+pub mod clients;
+pub mod config_overrides;
+pub mod digests;
+pub mod edit_events;
+pub mod generation_metadata;
 pub mod golden_set;
 pub mod hook_strategies;
 pub mod job_posts;
+pub mod notification_log;
+pub mod pending_generations;
+pub mod proposal_variants;
 pub mod proposals;
 pub mod remote_config;
 pub mod revisions;
 pub mod rss_imports;
 pub mod safety_overrides;
+pub mod score_guard_overrides;
 pub mod scoring;
 pub mod settings;
 pub mod user_skills;
@@ -0,0 +1,107 @@
+//! Milestone notification log (V55)
+//!
+//! Written by `notifications` for every alert, whatever happened to its delivery.
+//! `dedupe_key` keeps a milestone from firing twice.
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// A notification log row
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationLogEntry {
+    pub id: i64,
+    pub rule: String,
+    pub dedupe_key: String,
+    pub title: String,
+    pub body: String,
+    pub desktop_status: String,
+    pub webhook_status: String,
+    pub error: Option<String>,
+    pub created_at: String,
+}
+
+/// A new log row
+#[derive(Debug, Clone)]
+pub struct NewNotification<'a> {
+    pub rule: &'a str,
+    pub dedupe_key: &'a str,
+    pub title: &'a str,
+    pub body: &'a str,
+    pub desktop_status: &'a str,
+    pub webhook_status: &'a str,
+    pub error: Option<&'a str>,
+}
+
+/// Record an alert; returns the row id
+pub fn insert_notification(
+    conn: &Connection,
+    entry: &NewNotification,
+) -> Result<i64, rusqlite::Error> {
+    conn.execute(
+        "INSERT INTO notification_log
+            (rule, dedupe_key, title, body, desktop_status, webhook_status, error)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            entry.rule,
+            entry.dedupe_key,
+            entry.title,
+            entry.body,
+            entry.desktop_status,
+            entry.webhook_status,
+            entry.error
+        ],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+/// Record the webhook result for an alert; an existing error is kept if `error` is None
+pub fn update_webhook_status(
+    conn: &Connection,
+    id: i64,
+    webhook_status: &str,
+    error: Option<&str>,
+) -> Result<(), rusqlite::Error> {
+    conn.execute(
+        "UPDATE notification_log SET webhook_status = ?1, error = COALESCE(?2, error)
+         WHERE id = ?3",
+        params![webhook_status, error, id],
+    )?;
+    Ok(())
+}
+
+/// True if an alert with this dedupe key was already recorded
+pub fn was_notified(conn: &Connection, dedupe_key: &str) -> Result<bool, rusqlite::Error> {
+    conn.query_row(
+        "SELECT 1 FROM notification_log WHERE dedupe_key = ?1 LIMIT 1",
+        params![dedupe_key],
+        |_| Ok(()),
+    )
+    .optional()
+    .map(|row| row.is_some())
+}
+
+/// Most recent alerts first
+pub fn get_notification_log(
+    conn: &Connection,
+    limit: u32,
+) -> Result<Vec<NotificationLogEntry>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id, rule, dedupe_key, title, body, desktop_status, webhook_status, error, created_at
+         FROM notification_log ORDER BY id DESC LIMIT ?1",
+    )?;
+    let rows = stmt.query_map(params![limit], |row| {
+        Ok(NotificationLogEntry {
+            id: row.get(0)?,
+            rule: row.get(1)?,
+            dedupe_key: row.get(2)?,
+            title: row.get(3)?,
+            body: row.get(4)?,
+            desktop_status: row.get(5)?,
+            webhook_status: row.get(6)?,
+            error: row.get(7)?,
+            created_at: row.get(8)?,
+        })
+    })?;
+    rows.collect()
+}
@@ -0,0 +1,260 @@
+//! Queued generation queries.
+//!
+//! Generation requests made while the cooldown is active are stored here and
+//! drained FIFO by the background generation worker. Rows survive restart.
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// A queued generation request
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingGeneration {
+    pub id: i64,
+    pub job_content: String,
+    pub hook_strategy_id: Option<String>,
+    /// queued | running | completed | failed | cancelled
+    pub status: String,
+    pub error: Option<String>,
+    pub proposal_id: Option<i64>,
+    pub created_at: String,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+}
+
+const SELECT_COLUMNS: &str = "id, job_content, hook_strategy_id, status, error, proposal_id,
+     created_at, started_at, completed_at";
+
+fn row_to_pending(row: &rusqlite::Row) -> rusqlite::Result<PendingGeneration> {
+    Ok(PendingGeneration {
+        id: row.get(0)?,
+        job_content: row.get(1)?,
+        hook_strategy_id: row.get(2)?,
+        status: row.get(3)?,
+        error: row.get(4)?,
+        proposal_id: row.get(5)?,
+        created_at: row.get(6)?,
+        started_at: row.get(7)?,
+        completed_at: row.get(8)?,
+    })
+}
+
+/// Queue a generation request, returning its id
+pub fn enqueue_generation(
+    conn: &Connection,
+    job_content: &str,
+    hook_strategy_id: Option<&str>,
+) -> Result<i64, rusqlite::Error> {
+    conn.execute(
+        "INSERT INTO pending_generations (job_content, hook_strategy_id) VALUES (?1, ?2)",
+        params![job_content, hook_strategy_id],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+/// Oldest queued request, if any (FIFO)
+pub fn next_queued_generation(
+    conn: &Connection,
+) -> Result<Option<PendingGeneration>, rusqlite::Error> {
+    conn.query_row(
+        &format!(
+            "SELECT {} FROM pending_generations WHERE status = 'queued' ORDER BY id ASC LIMIT 1",
+            SELECT_COLUMNS
+        ),
+        [],
+        row_to_pending,
+    )
+    .optional()
+}
+
+/// Claim a queued request for processing
+///
+/// Returns false if the request is no longer queued (e.g. cancelled meanwhile).
+pub fn mark_generation_running(conn: &Connection, id: i64) -> Result<bool, rusqlite::Error> {
+    let updated = conn.execute(
+        "UPDATE pending_generations
+         SET status = 'running', started_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+         WHERE id = ?1 AND status = 'queued'",
+        params![id],
+    )?;
+
+    Ok(updated > 0)
+}
+
+/// Record a successful generation and the proposal it produced
+pub fn mark_generation_completed(
+    conn: &Connection,
+    id: i64,
+    proposal_id: i64,
+) -> Result<(), rusqlite::Error> {
+    conn.execute(
+        "UPDATE pending_generations
+         SET status = 'completed', proposal_id = ?2, error = NULL,
+             completed_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+         WHERE id = ?1",
+        params![id, proposal_id],
+    )?;
+
+    Ok(())
+}
+
+/// Record a failed generation with its error
+pub fn mark_generation_failed(
+    conn: &Connection,
+    id: i64,
+    error: &str,
+) -> Result<(), rusqlite::Error> {
+    conn.execute(
+        "UPDATE pending_generations
+         SET status = 'failed', error = ?2,
+             completed_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+         WHERE id = ?1",
+        params![id, error],
+    )?;
+
+    Ok(())
+}
+
+/// Put a running request back in the queue to retry later (overloaded API)
+pub fn requeue_generation(conn: &Connection, id: i64) -> Result<bool, rusqlite::Error> {
+    let updated = conn.execute(
+        "UPDATE pending_generations SET status = 'queued', started_at = NULL
+         WHERE id = ?1 AND status = 'running'",
+        params![id],
+    )?;
+
+    Ok(updated > 0)
+}
+
+/// Cancel a request that has not started yet
+///
+/// Returns false if the request does not exist or is already running/finished.
+pub fn cancel_queued_generation(conn: &Connection, id: i64) -> Result<bool, rusqlite::Error> {
+    let updated = conn.execute(
+        "UPDATE pending_generations
+         SET status = 'cancelled', completed_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+         WHERE id = ?1 AND status = 'queued'",
+        params![id],
+    )?;
+
+    Ok(updated > 0)
+}
+
+/// Requeue requests left `running` by a previous session that exited mid-generation
+pub fn requeue_interrupted_generations(conn: &Connection) -> Result<usize, rusqlite::Error> {
+    conn.execute(
+        "UPDATE pending_generations SET status = 'queued', started_at = NULL
+         WHERE status = 'running'",
+        [],
+    )
+}
+
+/// All requests, oldest first
+pub fn list_pending_generations(
+    conn: &Connection,
+) -> Result<Vec<PendingGeneration>, rusqlite::Error> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM pending_generations ORDER BY id ASC",
+        SELECT_COLUMNS
+    ))?;
+
+    let rows = stmt
+        .query_map([], row_to_pending)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use tempfile::tempdir;
+
+    fn create_test_db() -> Database {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        Database::new(db_path, None).unwrap()
+    }
+
+    #[test]
+    fn test_queue_is_fifo() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let first = enqueue_generation(&conn, "Job A", None).unwrap();
+        let second = enqueue_generation(&conn, "Job B", Some("social-proof")).unwrap();
+
+        assert_eq!(next_queued_generation(&conn).unwrap().unwrap().id, first);
+        assert!(mark_generation_running(&conn, first).unwrap());
+
+        let next = next_queued_generation(&conn).unwrap().unwrap();
+        assert_eq!(next.id, second);
+        assert_eq!(next.hook_strategy_id.as_deref(), Some("social-proof"));
+    }
+
+    #[test]
+    fn test_cancel_only_affects_queued() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let queued = enqueue_generation(&conn, "Job A", None).unwrap();
+        let running = enqueue_generation(&conn, "Job B", None).unwrap();
+        mark_generation_running(&conn, running).unwrap();
+
+        assert!(cancel_queued_generation(&conn, queued).unwrap());
+        assert!(!cancel_queued_generation(&conn, running).unwrap());
+        assert!(!cancel_queued_generation(&conn, 999).unwrap());
+
+        // Cancelled requests can't be claimed
+        assert!(!mark_generation_running(&conn, queued).unwrap());
+        assert!(next_queued_generation(&conn).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_failed_generation_keeps_error() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let id = enqueue_generation(&conn, "Job A", None).unwrap();
+        mark_generation_running(&conn, id).unwrap();
+        mark_generation_failed(&conn, id, "API error: 529 overloaded").unwrap();
+
+        let rows = list_pending_generations(&conn).unwrap();
+        assert_eq!(rows[0].status, "failed");
+        assert_eq!(rows[0].error.as_deref(), Some("API error: 529 overloaded"));
+        assert!(rows[0].completed_at.is_some());
+    }
+
+    #[test]
+    fn test_requeue_interrupted_generations() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let id = enqueue_generation(&conn, "Job A", None).unwrap();
+        mark_generation_running(&conn, id).unwrap();
+
+        assert_eq!(requeue_interrupted_generations(&conn).unwrap(), 1);
+        let next = next_queued_generation(&conn).unwrap().unwrap();
+        assert_eq!(next.id, id);
+        assert!(next.started_at.is_none());
+    }
+
+    #[test]
+    fn test_requeue_overloaded_generation() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let id = enqueue_generation(&conn, "Job A", None).unwrap();
+        // Only a running request goes back in the queue
+        assert!(!requeue_generation(&conn, id).unwrap());
+        mark_generation_running(&conn, id).unwrap();
+        assert!(requeue_generation(&conn, id).unwrap());
+
+        let next = next_queued_generation(&conn).unwrap().unwrap();
+        assert_eq!(next.id, id);
+        assert_eq!(next.status, "queued");
+        assert!(next.started_at.is_none());
+    }
+}
@@ -0,0 +1,169 @@
+//! Proposal variant groups (V57)
+//!
+//! `generate_proposal_variants` saves each variant as its own proposal sharing a
+//! `variant_group_id`. Promoting one keeps it and marks the rest of the group
+//! `status = 'discarded'`: the rows stay (with their generation metadata) but
+//! drop out of the proposal history and analytics.
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+
+/// Status of a variant that lost to the promoted one
+pub const DISCARDED_STATUS: &str = "discarded";
+
+/// One proposal in a variant group
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariantProposal {
+    pub id: i64,
+    pub hook_strategy_id: Option<String>,
+    pub status: String,
+}
+
+/// Tag a proposal as a member of `group_id`
+pub fn set_variant_group(
+    conn: &Connection,
+    proposal_id: i64,
+    group_id: &str,
+) -> Result<(), rusqlite::Error> {
+    conn.execute(
+        "UPDATE proposals SET variant_group_id = ?1 WHERE id = ?2",
+        params![group_id, proposal_id],
+    )?;
+    Ok(())
+}
+
+/// Proposals in a variant group, oldest (variant 1) first
+pub fn list_variant_group(
+    conn: &Connection,
+    group_id: &str,
+) -> Result<Vec<VariantProposal>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id, hook_strategy_id, status FROM proposals
+         WHERE variant_group_id = ?1
+         ORDER BY id ASC",
+    )?;
+    let variants = stmt
+        .query_map(params![group_id], |row| {
+            Ok(VariantProposal {
+                id: row.get(0)?,
+                hook_strategy_id: row.get(1)?,
+                status: row.get(2)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(variants)
+}
+
+/// Keep `proposal_id` and discard the other variants in its group, in one transaction.
+/// A previously discarded variant can be promoted again. Returns how many variants
+/// were discarded, or None if `proposal_id` is not in `group_id`.
+pub fn promote_variant(
+    conn: &Connection,
+    group_id: &str,
+    proposal_id: i64,
+) -> Result<Option<usize>, rusqlite::Error> {
+    let tx = conn.unchecked_transaction()?;
+    let in_group = tx
+        .query_row(
+            "SELECT 1 FROM proposals WHERE id = ?1 AND variant_group_id = ?2",
+            params![proposal_id, group_id],
+            |_| Ok(()),
+        )
+        .optional()?
+        .is_some();
+    if !in_group {
+        return Ok(None);
+    }
+
+    tx.execute(
+        "UPDATE proposals SET status = 'completed', updated_at = datetime('now')
+         WHERE id = ?1 AND status = ?2",
+        params![proposal_id, DISCARDED_STATUS],
+    )?;
+    let discarded = tx.execute(
+        "UPDATE proposals SET status = ?1, updated_at = datetime('now')
+         WHERE variant_group_id = ?2 AND id != ?3 AND status != ?1",
+        params![DISCARDED_STATUS, group_id, proposal_id],
+    )?;
+    tx.commit()?;
+    Ok(Some(discarded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::queries::proposals;
+    use crate::db::Database;
+    use tempfile::tempdir;
+
+    fn create_test_db() -> Database {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        Database::new(db_path, None).unwrap()
+    }
+
+    fn insert_variant(conn: &Connection, group_id: &str, strategy: &str) -> i64 {
+        let id = proposals::insert_proposal_with_context(
+            conn,
+            "Job",
+            "Variant text",
+            Some("completed"),
+            Some(strategy),
+            None,
+        )
+        .unwrap();
+        set_variant_group(conn, id, group_id).unwrap();
+        id
+    }
+
+    #[test]
+    fn test_promote_keeps_one_and_discards_the_rest() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        let a = insert_variant(&conn, "group-1", "contrarian");
+        let b = insert_variant(&conn, "group-1", "social_proof");
+        let c = insert_variant(&conn, "group-1", "question_based");
+        let other = insert_variant(&conn, "group-2", "contrarian");
+
+        assert_eq!(promote_variant(&conn, "group-1", b).unwrap(), Some(2));
+        let statuses: Vec<(i64, String)> = list_variant_group(&conn, "group-1")
+            .unwrap()
+            .into_iter()
+            .map(|v| (v.id, v.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (a, "discarded".to_string()),
+                (b, "completed".to_string()),
+                (c, "discarded".to_string()),
+            ]
+        );
+        assert_eq!(
+            proposals::get_proposal(&conn, other)
+                .unwrap()
+                .unwrap()
+                .status,
+            "completed"
+        );
+
+        // Changing one's mind restores the discarded variant
+        assert_eq!(promote_variant(&conn, "group-1", a).unwrap(), Some(1));
+        assert_eq!(
+            proposals::get_proposal(&conn, a).unwrap().unwrap().status,
+            "completed"
+        );
+        assert_eq!(
+            proposals::get_proposal(&conn, b).unwrap().unwrap().status,
+            "discarded"
+        );
+
+        // A proposal from another group is rejected without changes
+        assert_eq!(promote_variant(&conn, "group-1", other).unwrap(), None);
+        assert!(proposals::list_proposals(&conn)
+            .unwrap()
+            .iter()
+            .all(|p| p.id != b && p.id != c));
+    }
+}
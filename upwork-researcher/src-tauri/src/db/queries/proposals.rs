@@ -2,8 +2,9 @@
 //!
 //! Provides CRUD operations for the proposals table.
 
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Saved proposal with its database ID (full content)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,13 +17,32 @@ pub struct SavedProposal {
     pub status: String,
 }
 
+/// Draft offered for crash recovery (Story 1.14)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftSummary {
+    pub id: i64,
+    pub job_content: String,
+    pub generated_text: String,
+    pub created_at: String,
+    /// Last autosave, or created_at if never updated
+    pub updated_at: String,
+    pub word_count: usize,
+}
+
 /// Proposal summary for list view (excludes full generated_text for performance)
+/// Job fields come from the linked job post, None when there is no link.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProposalSummary {
     pub id: i64,
     pub job_content: String,
     pub created_at: String,
+    pub job_post_id: Option<i64>,
+    pub client_name: Option<String>,
+    pub overall_score: Option<f64>,
+    pub budget_type: Option<String>,
+    pub is_favorite: bool,
 }
 
 /// Insert a new proposal into the database.
@@ -111,16 +131,43 @@ pub fn get_proposal(conn: &Connection, id: i64) -> Result<Option<SavedProposal>,
 /// Returns summaries without full generated_text for performance.
 /// Limited to 100 items per NFR-17 (<500ms load time).
 pub fn list_proposals(conn: &Connection) -> Result<Vec<ProposalSummary>, rusqlite::Error> {
-    let mut stmt = conn.prepare(
-        "SELECT id, job_content, created_at FROM proposals ORDER BY created_at DESC LIMIT 100",
-    )?;
+    list_proposals_filtered(conn, false, false)
+}
+
+/// List proposals like `list_proposals`, optionally only favorites and/or
+/// with favorites sorted ahead of the rest.
+pub fn list_proposals_filtered(
+    conn: &Connection,
+    only_favorites: bool,
+    favorites_first: bool,
+) -> Result<Vec<ProposalSummary>, rusqlite::Error> {
+    let favorites_order = if favorites_first {
+        "p.is_favorite DESC, "
+    } else {
+        ""
+    };
+    let sql = format!(
+        "SELECT p.id, p.job_content, p.created_at, \
+                p.job_post_id, jp.client_name, jp.overall_score, jp.budget_type, p.is_favorite \
+         FROM proposals p \
+         LEFT JOIN job_posts jp ON p.job_post_id = jp.id \
+         WHERE (?1 = 0 OR p.is_favorite = 1) AND p.status != 'discarded' \
+         ORDER BY {}p.created_at DESC LIMIT 100",
+        favorites_order
+    );
+    let mut stmt = conn.prepare(&sql)?;
 
     let proposals = stmt
-        .query_map([], |row| {
+        .query_map(params![only_favorites], |row| {
             Ok(ProposalSummary {
                 id: row.get(0)?,
                 job_content: row.get(1)?,
                 created_at: row.get(2)?,
+                job_post_id: row.get(3)?,
+                client_name: row.get(4)?,
+                overall_score: row.get(5)?,
+                budget_type: row.get(6)?,
+                is_favorite: row.get(7)?,
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -178,7 +225,63 @@ pub fn get_latest_draft(conn: &Connection) -> Result<Option<SavedProposal>, rusq
     }
 }
 
+/// Get every draft proposal, most recently saved first.
+/// Drafts never autosaved after creation sort by created_at.
+pub fn get_all_drafts(conn: &Connection) -> Result<Vec<DraftSummary>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id, job_content, generated_text, created_at, COALESCE(updated_at, created_at)
+         FROM proposals
+         WHERE status = 'draft'
+         ORDER BY COALESCE(updated_at, created_at) DESC, id DESC",
+    )?;
+
+    let drafts = stmt
+        .query_map([], |row| {
+            let generated_text: String = row.get(2)?;
+            Ok(DraftSummary {
+                id: row.get(0)?,
+                job_content: row.get(1)?,
+                word_count: generated_text.split_whitespace().count(),
+                generated_text,
+                created_at: row.get(3)?,
+                updated_at: row.get(4)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(drafts)
+}
+
+/// Delete a proposal only if it is still a draft.
+/// Returns false if it doesn't exist or is not a draft.
+pub fn discard_draft(conn: &Connection, id: i64) -> Result<bool, rusqlite::Error> {
+    let rows = conn.execute(
+        "DELETE FROM proposals WHERE id = ?1 AND status = 'draft'",
+        params![id],
+    )?;
+    Ok(rows > 0)
+}
+
+/// Delete all drafts except `keep` (the draft of an in-progress generation).
+/// Returns how many were deleted.
+pub fn discard_all_drafts(conn: &Connection, keep: Option<i64>) -> Result<usize, rusqlite::Error> {
+    conn.execute(
+        "DELETE FROM proposals WHERE status = 'draft' AND (?1 IS NULL OR id != ?1)",
+        params![keep],
+    )
+}
+
+/// Delete drafts last saved more than `days` days ago. Returns how many were deleted.
+pub fn discard_drafts_older_than(conn: &Connection, days: u32) -> Result<usize, rusqlite::Error> {
+    conn.execute(
+        "DELETE FROM proposals WHERE status = 'draft' \
+         AND COALESCE(updated_at, created_at) < datetime('now', '-' || ?1 || ' days')",
+        params![days],
+    )
+}
+
 /// Update the generated text of a proposal (for draft auto-save).
+/// Clears any cached perplexity result, which described the old text.
 /// Note: Single UPDATE statements are atomic in SQLite per the transaction semantics.
 /// Explicit transactions would require &mut Connection which breaks the API.
 pub fn update_proposal_text(
@@ -187,12 +290,45 @@ pub fn update_proposal_text(
     generated_text: &str,
 ) -> Result<(), rusqlite::Error> {
     conn.execute(
-        "UPDATE proposals SET generated_text = ?1, updated_at = datetime('now') WHERE id = ?2",
+        "UPDATE proposals SET generated_text = ?1, updated_at = datetime('now'), \
+         perplexity_score = NULL, perplexity_flagged_count = NULL, perplexity_analyzed_at = NULL \
+         WHERE id = ?2",
         params![generated_text, id],
     )?;
     Ok(())
 }
 
+/// Cache a perplexity analysis of the proposal's current text (risk badge in history).
+/// Returns false if the proposal does not exist.
+pub fn store_perplexity_result(
+    conn: &Connection,
+    id: i64,
+    score: f32,
+    flagged_count: usize,
+) -> Result<bool, rusqlite::Error> {
+    let rows = conn.execute(
+        "UPDATE proposals SET perplexity_score = ?1, perplexity_flagged_count = ?2, \
+         perplexity_analyzed_at = datetime('now') WHERE id = ?3",
+        params![score as f64, flagged_count as i64, id],
+    )?;
+    Ok(rows > 0)
+}
+
+/// Cached perplexity score of the proposal's current text; None if never
+/// analyzed, edited since, or the proposal does not exist.
+pub fn get_cached_perplexity_score(
+    conn: &Connection,
+    id: i64,
+) -> Result<Option<f64>, rusqlite::Error> {
+    conn.query_row(
+        "SELECT perplexity_score FROM proposals WHERE id = ?1",
+        params![id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map(Option::flatten)
+}
+
 /// Update the status of a proposal (e.g., mark draft as completed).
 /// Note: Single UPDATE statements are atomic in SQLite per the transaction semantics.
 /// Explicit transactions would require &mut Connection which breaks the API.
@@ -227,25 +363,36 @@ pub struct ProposalListItem {
     pub created_at: String,
     pub outcome_status: String,
     pub hook_strategy_id: Option<String>,
+    /// Cached perplexity score, None if never analyzed or edited since
+    pub perplexity_score: Option<f64>,
+    pub perplexity_flagged_count: Option<i64>,
+    /// Originating job post, None if unknown or the job post was deleted
+    pub job_post_id: Option<i64>,
+    #[serde(default)]
+    pub is_favorite: bool,
 }
 
 /// Search proposals with optional filters (Story 7.3).
 ///
 /// Builds a dynamic WHERE clause based on which filters are provided.
 /// All filters are AND-combined. Text search uses LIKE on job_content and generated_text.
+/// `job_post_id` keeps only proposals written for that job post.
 ///
 /// Returns `SearchProposalsResult` with matching items, total filtered count, and has_more flag.
+#[allow(clippy::too_many_arguments)]
 pub fn search_proposals(
     conn: &Connection,
     search_text: Option<&str>,
     outcome_status: Option<&str>,
     date_range_days: Option<u32>,
     hook_strategy: Option<&str>,
+    job_post_id: Option<i64>,
     limit: u32,
     offset: u32,
 ) -> Result<SearchProposalsResult, rusqlite::Error> {
     // Build WHERE clauses dynamically
-    let mut conditions = vec!["1=1".to_string()];
+    // Variants discarded by promote_variant stay out of history
+    let mut conditions = vec!["status != 'discarded'".to_string()];
     let mut param_values: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
 
     // Text search: LIKE on job_content OR generated_text (case-insensitive via SQLite default)
@@ -294,6 +441,12 @@ pub fn search_proposals(
         }
     }
 
+    // Job post filter
+    if let Some(job_post_id) = job_post_id {
+        conditions.push(format!("job_post_id = ?{}", param_values.len() + 1));
+        param_values.push(Box::new(job_post_id));
+    }
+
     let where_clause = conditions.join(" AND ");
 
     // Count query for total filtered results
@@ -309,7 +462,8 @@ pub fn search_proposals(
     let data_sql = format!(
         "SELECT id, SUBSTR(COALESCE(job_content, ''), 1, 100) AS job_excerpt, \
          SUBSTR(COALESCE(generated_text, ''), 1, 200) AS preview_text, \
-         created_at, outcome_status, hook_strategy_id \
+         created_at, outcome_status, hook_strategy_id, \
+         perplexity_score, perplexity_flagged_count, job_post_id, is_favorite \
          FROM proposals WHERE {} \
          ORDER BY created_at DESC, id DESC \
          LIMIT ?{} OFFSET ?{}",
@@ -334,6 +488,10 @@ pub fn search_proposals(
                 created_at: row.get(3)?,
                 outcome_status: row.get(4)?,
                 hook_strategy_id: row.get(5)?,
+                perplexity_score: row.get(6)?,
+                perplexity_flagged_count: row.get(7)?,
+                job_post_id: row.get(8)?,
+                is_favorite: row.get(9)?,
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -399,8 +557,44 @@ pub fn update_proposal_outcome(
     Ok(rows_affected > 0)
 }
 
+/// Relabel a proposal's hook strategy after the fact.
+///
+/// The proposal stops counting as an A/B observation (`ab_assigned` and its weight
+/// are cleared) and is flagged `hook_strategy_corrected`. Returns false if no
+/// proposal has this ID; the caller validates the strategy name.
+pub fn update_proposal_hook_strategy(
+    conn: &Connection,
+    proposal_id: i64,
+    hook_strategy_id: &str,
+) -> Result<bool, rusqlite::Error> {
+    let rows = conn.execute(
+        "UPDATE proposals
+         SET hook_strategy_id = ?1, ab_assigned = 0, ab_weight_at_assignment = NULL,
+             hook_strategy_corrected = 1, updated_at = datetime('now')
+         WHERE id = ?2",
+        params![hook_strategy_id, proposal_id],
+    )?;
+    Ok(rows > 0)
+}
+
+/// Stamp `copied_at` when a proposal is copied for sending (start of the outcome
+/// funnel). Copying again moves the timestamp forward. Returns the new timestamp,
+/// or None if no proposal has this ID.
+pub fn mark_proposal_copied(
+    conn: &Connection,
+    proposal_id: i64,
+) -> Result<Option<String>, rusqlite::Error> {
+    conn.query_row(
+        "UPDATE proposals SET copied_at = datetime('now') WHERE id = ?1 RETURNING copied_at",
+        params![proposal_id],
+        |row| row.get(0),
+    )
+    .optional()
+}
+
 /// Full proposal detail for detail view (Story 7.4 AC-1).
 /// Includes outcome tracking fields, hook strategy, job post join, and revision count.
+/// `job_client_name`, `job_overall_score` and `job_budget_type` come from the linked job post.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProposalDetail {
@@ -415,7 +609,11 @@ pub struct ProposalDetail {
     pub hook_strategy_id: Option<String>,
     pub job_post_id: Option<i64>,
     pub job_title: Option<String>,
+    pub job_client_name: Option<String>,
+    pub job_overall_score: Option<f64>,
+    pub job_budget_type: Option<String>,
     pub revision_count: i64,
+    pub is_favorite: bool,
 }
 
 /// Get full proposal detail by ID (Story 7.4 AC-1).
@@ -438,7 +636,11 @@ pub fn get_proposal_detail(
             p.hook_strategy_id, \
             p.job_post_id, \
             COALESCE(jp.client_name, SUBSTR(jp.raw_content, 1, 80)) AS job_title, \
-            (SELECT COUNT(*) FROM proposal_revisions WHERE proposal_id = p.id) AS revision_count \
+            (SELECT COUNT(*) FROM proposal_revisions WHERE proposal_id = p.id) AS revision_count, \
+            jp.client_name, \
+            jp.overall_score, \
+            jp.budget_type, \
+            p.is_favorite \
         FROM proposals p \
         LEFT JOIN job_posts jp ON p.job_post_id = jp.id \
         WHERE p.id = ?1",
@@ -459,15 +661,41 @@ pub fn get_proposal_detail(
             hook_strategy_id: row.get(8)?,
             job_post_id: row.get(9)?,
             job_title: row.get(10)?,
+            job_client_name: row.get(12)?,
+            job_overall_score: row.get(13)?,
+            job_budget_type: row.get(14)?,
             revision_count: row.get(11)?,
+            is_favorite: row.get(15)?,
         }))
     } else {
         Ok(None)
     }
 }
 
+/// Flip a proposal's favorite pin. Leaves status, outcome, and updated_at alone.
+/// Returns the new value, or None if the proposal doesn't exist.
+pub fn toggle_proposal_favorite(
+    conn: &Connection,
+    id: i64,
+) -> Result<Option<bool>, rusqlite::Error> {
+    let rows = conn.execute(
+        "UPDATE proposals SET is_favorite = 1 - is_favorite WHERE id = ?1",
+        params![id],
+    )?;
+    if rows == 0 {
+        return Ok(None);
+    }
+    conn.query_row(
+        "SELECT is_favorite FROM proposals WHERE id = ?1",
+        params![id],
+        |row| row.get(0),
+    )
+    .map(Some)
+}
+
 /// Delete a proposal and all its revisions (Story 6.8).
 /// Uses CASCADE delete via foreign key constraint on proposal_revisions table.
+/// The linked job post (if any) is kept.
 /// Returns true if a proposal was deleted, false if not found.
 ///
 /// # GDPR Compliance
@@ -483,6 +711,192 @@ pub fn delete_proposal(conn: &Connection, id: i64) -> Result<bool, rusqlite::Err
     Ok(rows_affected > 0)
 }
 
+/// Rows removed by `purge_proposal_data`, per table
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalPurgeReport {
+    pub proposal_id: i64,
+    /// Table name → rows deleted. `archived_revisions` counts revisions that
+    /// were in the proposal's compressed archive blob.
+    pub deleted: BTreeMap<String, usize>,
+    /// The linked job post was deleted too
+    pub job_post_deleted: bool,
+    /// Purging the job post was requested but other proposals still reference it
+    pub job_post_kept: bool,
+    /// The database uses incremental auto-vacuum and freed pages were released
+    pub space_reclaimed: bool,
+    /// Freed pages stay in the file until `compact_database` runs (they were
+    /// zeroed by `secure_delete`, so the data itself is already gone)
+    pub compact_recommended: bool,
+}
+
+/// Tables cleared by proposal ID, children before the proposal row
+const PROPOSAL_CHILD_TABLES: [&str; 5] = [
+    "proposal_revisions",
+    "safety_overrides",
+    "edit_events",
+    "generation_metadata",
+    "pending_generations",
+];
+
+/// Tables cleared by job post ID before the job post itself
+const JOB_POST_CHILD_TABLES: [&str; 4] = [
+    "job_skills",
+    "job_scores",
+    "scoring_feedback",
+    "score_guard_overrides",
+];
+
+/// Delete a proposal and everything derived from it, in one transaction
+/// ("right to deletion"). Unlike `delete_proposal`, nothing is left to foreign
+/// key actions: revisions (active and archived), safety overrides, edit events,
+/// generation metadata and queued generation rows (which keep the job text) are
+/// deleted explicitly, as is the analysis cache entry for the job text.
+///
+/// With `include_job_post`, the linked job post and its skills, scores, scoring
+/// feedback and score guard overrides are deleted as well, unless another
+/// proposal still references it. Deletes run with `secure_delete` on, so freed
+/// pages are overwritten. Returns Ok(None) if the proposal does not exist.
+pub fn purge_proposal_data(
+    conn: &Connection,
+    proposal_id: i64,
+    include_job_post: bool,
+) -> Result<Option<ProposalPurgeReport>, String> {
+    let db_err = |e: rusqlite::Error| format!("Failed to purge proposal: {}", e);
+
+    let Some((job_content, job_post_id)) = conn
+        .query_row(
+            "SELECT job_content, job_post_id FROM proposals WHERE id = ?1",
+            params![proposal_id],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, Option<i64>>(1)?)),
+        )
+        .optional()
+        .map_err(db_err)?
+    else {
+        return Ok(None);
+    };
+
+    let secure_delete: i64 = conn
+        .query_row("PRAGMA secure_delete", [], |row| row.get(0))
+        .map_err(db_err)?;
+    conn.execute_batch("PRAGMA secure_delete=ON;")
+        .map_err(db_err)?;
+
+    let result = (|| {
+        let tx = conn.unchecked_transaction().map_err(db_err)?;
+        let mut report = ProposalPurgeReport {
+            proposal_id,
+            ..Default::default()
+        };
+
+        let archived = crate::db::queries::revisions::get_archived_revisions(&tx, proposal_id)?;
+        report
+            .deleted
+            .insert("archived_revisions".to_string(), archived.len());
+
+        for table in PROPOSAL_CHILD_TABLES {
+            let rows = tx
+                .execute(
+                    &format!("DELETE FROM {} WHERE proposal_id = ?1", table),
+                    params![proposal_id],
+                )
+                .map_err(db_err)?;
+            report.deleted.insert(table.to_string(), rows);
+        }
+        let rows = tx
+            .execute("DELETE FROM proposals WHERE id = ?1", params![proposal_id])
+            .map_err(db_err)?;
+        report.deleted.insert("proposals".to_string(), rows);
+
+        let mut cache_hashes = vec![crate::db::queries::analysis_cache::content_hash(
+            &job_content,
+        )];
+
+        if let (true, Some(job_post_id)) = (include_job_post, job_post_id) {
+            let still_referenced: bool = tx
+                .query_row(
+                    "SELECT EXISTS(SELECT 1 FROM proposals WHERE job_post_id = ?1)",
+                    params![job_post_id],
+                    |row| row.get(0),
+                )
+                .map_err(db_err)?;
+            if still_referenced {
+                report.job_post_kept = true;
+            } else {
+                let raw_content: Option<String> = tx
+                    .query_row(
+                        "SELECT raw_content FROM job_posts WHERE id = ?1",
+                        params![job_post_id],
+                        |row| row.get(0),
+                    )
+                    .optional()
+                    .map_err(db_err)?;
+                if let Some(raw_content) = raw_content {
+                    cache_hashes.push(crate::db::queries::analysis_cache::content_hash(
+                        &raw_content,
+                    ));
+                }
+                for table in JOB_POST_CHILD_TABLES {
+                    let rows = tx
+                        .execute(
+                            &format!("DELETE FROM {} WHERE job_post_id = ?1", table),
+                            params![job_post_id],
+                        )
+                        .map_err(db_err)?;
+                    report.deleted.insert(table.to_string(), rows);
+                }
+                let rows = tx
+                    .execute("DELETE FROM job_posts WHERE id = ?1", params![job_post_id])
+                    .map_err(db_err)?;
+                report.deleted.insert("job_posts".to_string(), rows);
+                report.job_post_deleted = rows > 0;
+            }
+        }
+
+        let mut cache_rows = 0;
+        for hash in cache_hashes {
+            cache_rows += tx
+                .execute(
+                    "DELETE FROM analysis_cache WHERE content_hash = ?1",
+                    params![hash],
+                )
+                .map_err(db_err)?;
+        }
+        report
+            .deleted
+            .insert("analysis_cache".to_string(), cache_rows);
+
+        tx.commit().map_err(db_err)?;
+        Ok(report)
+    })();
+
+    // Restore the connection's setting whether or not the purge went through
+    let previous = if secure_delete == 2 {
+        "FAST".to_string()
+    } else {
+        secure_delete.to_string()
+    };
+    if let Err(e) = conn.execute_batch(&format!("PRAGMA secure_delete={};", previous)) {
+        tracing::warn!("Failed to restore secure_delete after purge: {}", e);
+    }
+    let mut report = result?;
+
+    // auto_vacuum = 2 (INCREMENTAL) can release the freed pages right away;
+    // otherwise only a full VACUUM (`compact_database`) shrinks the file
+    let auto_vacuum: i64 = conn
+        .query_row("PRAGMA auto_vacuum", [], |row| row.get(0))
+        .map_err(db_err)?;
+    if auto_vacuum == 2 {
+        conn.execute_batch("PRAGMA incremental_vacuum;")
+            .map_err(db_err)?;
+        report.space_reclaimed = true;
+    } else {
+        report.compact_recommended = true;
+    }
+
+    Ok(Some(report))
+}
+
 // =========================================================================
 // Story 7.5: Analytics Dashboard — Aggregate Query Functions
 // =========================================================================
@@ -499,6 +913,12 @@ pub struct AnalyticsSummary {
     pub response_rate: f64,
     pub best_strategy: Option<String>,
     pub best_strategy_rate: f64,
+    /// Proposals copied at least once (`copied_at` set)
+    pub copied_proposals: i64,
+    /// Copied proposals with a positive outcome
+    pub copied_positive: i64,
+    /// copied_positive / copied_proposals * 100 (copy→outcome conversion)
+    pub copy_conversion_rate: f64,
 }
 
 /// Outcome status distribution for bar chart (Story 7.5 AC-2).
@@ -510,7 +930,7 @@ pub struct OutcomeCount {
 }
 
 /// Hook strategy performance metrics (Story 7.5 AC-3).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StrategyPerformance {
     pub strategy: String,
@@ -528,11 +948,16 @@ pub struct WeeklyActivity {
     pub proposal_count: i64,
     pub positive_count: i64,
     pub response_rate: f64,
+    /// Proposals from this week that were copied
+    pub copied_count: i64,
+    /// Copied proposals from this week with a positive outcome, as % of copied_count
+    pub copy_conversion_rate: f64,
 }
 
 /// Get proposal analytics summary (Story 7.5 AC-1).
-/// Returns aggregate metrics: total proposals, response rate, best strategy, monthly count.
-/// Filters: status != 'draft'.
+/// Returns aggregate metrics: total proposals, response rate, best strategy, monthly count,
+/// and the copy funnel (copied count and copy→positive-outcome conversion).
+/// Filters: status not 'draft' or 'discarded'.
 pub fn get_proposal_analytics_summary(
     conn: &Connection,
 ) -> Result<AnalyticsSummary, rusqlite::Error> {
@@ -541,20 +966,30 @@ pub fn get_proposal_analytics_summary(
             COUNT(*) as total_proposals, \
             COALESCE(SUM(CASE WHEN outcome_status IN ('response_received','interview','hired') THEN 1 ELSE 0 END), 0) as positive_outcomes, \
             COALESCE(SUM(CASE WHEN outcome_status NOT IN ('pending','submitted') THEN 1 ELSE 0 END), 0) as resolved_proposals, \
-            COUNT(CASE WHEN created_at >= datetime('now', '-30 days') THEN 1 END) as proposals_this_month \
+            COUNT(CASE WHEN created_at >= datetime('now', '-30 days') THEN 1 END) as proposals_this_month, \
+            COUNT(copied_at) as copied_proposals, \
+            COALESCE(SUM(CASE WHEN copied_at IS NOT NULL AND outcome_status IN ('response_received','interview','hired') THEN 1 ELSE 0 END), 0) as copied_positive \
         FROM proposals \
-        WHERE status != 'draft'"
+        WHERE status NOT IN ('draft', 'discarded')"
     )?;
 
-    let (total_proposals, positive_outcomes, resolved_proposals, proposals_this_month) = stmt
-        .query_row([], |row| {
-            Ok((
-                row.get::<_, i64>(0)?,
-                row.get::<_, i64>(1)?,
-                row.get::<_, i64>(2)?,
-                row.get::<_, i64>(3)?,
-            ))
-        })?;
+    let (
+        total_proposals,
+        positive_outcomes,
+        resolved_proposals,
+        proposals_this_month,
+        copied_proposals,
+        copied_positive,
+    ) = stmt.query_row([], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            row.get::<_, i64>(1)?,
+            row.get::<_, i64>(2)?,
+            row.get::<_, i64>(3)?,
+            row.get::<_, i64>(4)?,
+            row.get::<_, i64>(5)?,
+        ))
+    })?;
 
     // Calculate response rate: positive / resolved * 100
     let response_rate = if resolved_proposals > 0 {
@@ -570,7 +1005,7 @@ pub fn get_proposal_analytics_summary(
             COUNT(*) as total, \
             SUM(CASE WHEN outcome_status IN ('response_received','interview','hired') THEN 1 ELSE 0 END) as positive \
         FROM proposals \
-        WHERE status != 'draft' \
+        WHERE status NOT IN ('draft', 'discarded') \
         GROUP BY hook_strategy_id \
         ORDER BY positive * 1.0 / NULLIF(total, 0) DESC \
         LIMIT 1",
@@ -595,17 +1030,29 @@ pub fn get_proposal_analytics_summary(
         response_rate,
         best_strategy,
         best_strategy_rate,
+        copied_proposals,
+        copied_positive,
+        copy_conversion_rate: percentage(copied_positive, copied_proposals),
     })
 }
 
+/// `part / whole * 100`, or 0.0 when `whole` is 0
+pub(crate) fn percentage(part: i64, whole: i64) -> f64 {
+    if whole > 0 {
+        (part as f64 / whole as f64) * 100.0
+    } else {
+        0.0
+    }
+}
+
 /// Get outcome distribution for bar chart (Story 7.5 AC-2).
 /// Returns count of proposals per outcome status.
-/// Filters: status != 'draft'.
+/// Filters: status not 'draft' or 'discarded'.
 pub fn get_outcome_distribution(conn: &Connection) -> Result<Vec<OutcomeCount>, rusqlite::Error> {
     let mut stmt = conn.prepare(
         "SELECT outcome_status, COUNT(*) as count \
         FROM proposals \
-        WHERE status != 'draft' \
+        WHERE status NOT IN ('draft', 'discarded') \
         GROUP BY outcome_status \
         ORDER BY count DESC",
     )?;
@@ -624,7 +1071,7 @@ pub fn get_outcome_distribution(conn: &Connection) -> Result<Vec<OutcomeCount>,
 
 /// Get response rate by hook strategy (Story 7.5 AC-3).
 /// Returns performance metrics per strategy, sorted by response rate descending.
-/// Filters: status != 'draft'.
+/// Filters: status not 'draft' or 'discarded'.
 /// COALESCE(hook_strategy_id, 'none') handles NULL strategies.
 pub fn get_response_rate_by_strategy(
     conn: &Connection,
@@ -635,7 +1082,7 @@ pub fn get_response_rate_by_strategy(
             COUNT(*) as total, \
             SUM(CASE WHEN outcome_status IN ('response_received','interview','hired') THEN 1 ELSE 0 END) as positive \
         FROM proposals \
-        WHERE status != 'draft' \
+        WHERE status NOT IN ('draft', 'discarded') \
         GROUP BY hook_strategy_id \
         ORDER BY positive * 1.0 / NULLIF(total, 0) DESC"
     )?;
@@ -664,7 +1111,7 @@ pub fn get_response_rate_by_strategy(
 
 /// Get weekly proposal activity (Story 7.5 AC-4).
 /// Returns proposal count and response rate per week for the last N weeks.
-/// Filters: status != 'draft'.
+/// Filters: status not 'draft' or 'discarded'.
 /// weeks: number of weeks to look back (default 12).
 pub fn get_weekly_activity(
     conn: &Connection,
@@ -677,9 +1124,11 @@ pub fn get_weekly_activity(
             DATE(created_at, 'weekday 0', '-6 days') as week_start, \
             COUNT(*) as proposal_count, \
             SUM(CASE WHEN outcome_status IN ('response_received','interview','hired') THEN 1 ELSE 0 END) as positive_count, \
-            SUM(CASE WHEN outcome_status NOT IN ('pending','submitted') THEN 1 ELSE 0 END) as resolved_count \
+            SUM(CASE WHEN outcome_status NOT IN ('pending','submitted') THEN 1 ELSE 0 END) as resolved_count, \
+            COUNT(copied_at) as copied_count, \
+            SUM(CASE WHEN copied_at IS NOT NULL AND outcome_status IN ('response_received','interview','hired') THEN 1 ELSE 0 END) as copied_positive \
         FROM proposals \
-        WHERE status != 'draft' \
+        WHERE status NOT IN ('draft', 'discarded') \
             AND created_at >= datetime('now', '-' || ?1 || ' days') \
         GROUP BY week_label \
         ORDER BY week_start ASC"
@@ -690,6 +1139,8 @@ pub fn get_weekly_activity(
             let proposal_count: i64 = row.get(2)?;
             let positive_count: i64 = row.get(3)?;
             let resolved_count: i64 = row.get(4)?;
+            let copied_count: i64 = row.get(5)?;
+            let copied_positive: i64 = row.get(6)?;
             // CR R2 H-1: Use resolved_count (excluding pending/submitted) as denominator
             // for consistency with get_proposal_analytics_summary response rate formula
             let response_rate = if resolved_count > 0 {
@@ -704,6 +1155,8 @@ pub fn get_weekly_activity(
                 proposal_count,
                 positive_count,
                 response_rate,
+                copied_count,
+                copy_conversion_rate: percentage(copied_positive, copied_count),
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -802,6 +1255,49 @@ mod tests {
         assert!(job_contents.contains(&"Job content 2"));
     }
 
+    #[test]
+    fn test_toggle_favorite_filters_and_sorts() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let template =
+            insert_proposal(&conn, "Template job", "Best proposal", Some("completed")).unwrap();
+        let draft = insert_proposal(&conn, "Other job", "Draft text", None).unwrap();
+
+        assert_eq!(
+            toggle_proposal_favorite(&conn, template).unwrap(),
+            Some(true)
+        );
+        assert_eq!(toggle_proposal_favorite(&conn, 99999).unwrap(), None);
+
+        // Status is untouched
+        assert_eq!(
+            get_proposal(&conn, template).unwrap().unwrap().status,
+            "completed"
+        );
+        assert_eq!(get_latest_draft(&conn).unwrap().unwrap().id, draft);
+
+        let favorites = list_proposals_filtered(&conn, true, false).unwrap();
+        assert_eq!(favorites.len(), 1);
+        assert_eq!(favorites[0].id, template);
+        assert!(favorites[0].is_favorite);
+
+        let pinned = list_proposals_filtered(&conn, false, true).unwrap();
+        assert_eq!(pinned.len(), 2);
+        assert_eq!(pinned[0].id, template);
+
+        // Toggling again unpins; deleting a favorite works as usual
+        assert_eq!(
+            toggle_proposal_favorite(&conn, template).unwrap(),
+            Some(false)
+        );
+        toggle_proposal_favorite(&conn, template).unwrap();
+        assert!(delete_proposal(&conn, template).unwrap());
+        assert!(list_proposals_filtered(&conn, true, false)
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn test_list_proposals_excludes_generated_text() {
         let db = create_test_db();
@@ -1013,6 +1509,35 @@ mod tests {
         assert_eq!(proposal.generated_text, "Updated text");
     }
 
+    #[test]
+    fn test_editing_clears_cached_perplexity() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let id = insert_proposal(&conn, "Job", "Initial text", Some("completed")).unwrap();
+        assert!(store_perplexity_result(&conn, id, 192.5, 3).unwrap());
+        assert!(!store_perplexity_result(&conn, 9999, 100.0, 0).unwrap());
+
+        let cached = |conn: &Connection| -> (Option<f64>, Option<i64>, Option<String>) {
+            conn.query_row(
+                "SELECT perplexity_score, perplexity_flagged_count, perplexity_analyzed_at FROM proposals WHERE id = ?1",
+                params![id],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap()
+        };
+        let (score, flagged, analyzed_at) = cached(&conn);
+        assert_eq!(score, Some(192.5));
+        assert_eq!(flagged, Some(3));
+        assert!(analyzed_at.is_some());
+
+        let listed = search_proposals(&conn, None, None, None, None, None, 10, 0).unwrap();
+        assert_eq!(listed.proposals[0].perplexity_score, Some(192.5));
+
+        update_proposal_text(&conn, id, "Edited text").unwrap();
+        assert_eq!(cached(&conn), (None, None, None));
+    }
+
     #[test]
     fn test_update_proposal_status() {
         let db = create_test_db();
@@ -1067,6 +1592,151 @@ mod tests {
         assert!(get_proposal(&conn, id).unwrap().is_none());
     }
 
+    /// Proposal linked to a job post, with rows in every table derived from it
+    fn seed_purgeable(conn: &mut Connection, job_content: &str) -> (i64, i64) {
+        use crate::db::queries::{edit_events, job_posts, revisions, safety_overrides};
+
+        let job_post_id = job_posts::insert_job_post(conn, None, job_content, None).unwrap();
+        job_posts::insert_job_skills(conn, job_post_id, &["Rust".to_string()]).unwrap();
+        conn.execute(
+            "INSERT INTO job_scores (job_post_id, overall_score) VALUES (?1, 70.0)",
+            params![job_post_id],
+        )
+        .unwrap();
+        let id = insert_proposal(conn, job_content, "Generated", None).unwrap();
+        conn.execute(
+            "UPDATE proposals SET job_post_id = ?1 WHERE id = ?2",
+            params![job_post_id, id],
+        )
+        .unwrap();
+
+        for n in 0..8 {
+            revisions::create_revision(conn, id, &format!("v{}", n), "edit", None).unwrap();
+        }
+        revisions::archive_old_revisions(conn, id).unwrap();
+        safety_overrides::record_override(conn, id, 190.0, 180.0).unwrap();
+        edit_events::record_edit_event(conn, id, "Edited").unwrap();
+        conn.execute(
+            "INSERT INTO pending_generations (job_content, status, proposal_id)
+             VALUES (?1, 'completed', ?2)",
+            params![job_content, id],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO analysis_cache (content_hash, analysis_json, budget_json)
+             VALUES (?1, '{}', '{}')",
+            params![crate::db::queries::analysis_cache::content_hash(
+                job_content
+            )],
+        )
+        .unwrap();
+        (id, job_post_id)
+    }
+
+    /// Rows in any table whose `column` equals `id`
+    fn referencing_rows(conn: &Connection, column: &str, id: i64) -> Vec<String> {
+        let tables: Vec<String> = conn
+            .prepare("SELECT name FROM sqlite_master WHERE type = 'table'")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        tables
+            .into_iter()
+            .filter(|table| {
+                let has_column = conn
+                    .prepare(&format!(
+                        "SELECT 1 FROM pragma_table_info('{}') WHERE name = ?1",
+                        table
+                    ))
+                    .unwrap()
+                    .exists(params![column])
+                    .unwrap();
+                has_column
+                    && conn
+                        .query_row(
+                            &format!("SELECT COUNT(*) FROM {} WHERE {} = ?1", table, column),
+                            params![id],
+                            |row| row.get::<_, i64>(0),
+                        )
+                        .unwrap()
+                        > 0
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_purge_proposal_data_leaves_no_referencing_rows() {
+        let db = create_test_db();
+        let mut conn = db.conn.lock().unwrap();
+        let (id, job_post_id) = seed_purgeable(&mut conn, "Sensitive job text");
+        let (other, _) = seed_purgeable(&mut conn, "Another job");
+
+        let report = purge_proposal_data(&conn, id, true).unwrap().unwrap();
+
+        assert_eq!(report.deleted["proposals"], 1);
+        assert_eq!(report.deleted["archived_revisions"], 3);
+        assert_eq!(report.deleted["proposal_revisions"], 5);
+        assert_eq!(report.deleted["safety_overrides"], 1);
+        assert_eq!(report.deleted["pending_generations"], 1);
+        assert_eq!(report.deleted["analysis_cache"], 1);
+        assert_eq!(report.deleted["job_skills"], 1);
+        assert_eq!(report.deleted["job_posts"], 1);
+        assert!(report.job_post_deleted);
+        assert!(referencing_rows(&conn, "proposal_id", id).is_empty());
+        assert!(referencing_rows(&conn, "job_post_id", job_post_id).is_empty());
+        assert!(get_proposal(&conn, id).unwrap().is_none());
+        let cached: i64 = conn
+            .query_row("SELECT COUNT(*) FROM analysis_cache", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(cached, 1, "other job's analysis is kept");
+
+        // The other proposal is untouched
+        assert!(get_proposal(&conn, other).unwrap().is_some());
+        assert!(!referencing_rows(&conn, "proposal_id", other).is_empty());
+    }
+
+    #[test]
+    fn test_purge_proposal_data_keeps_shared_or_unrequested_job_post() {
+        let db = create_test_db();
+        let mut conn = db.conn.lock().unwrap();
+        let (id, job_post_id) = seed_purgeable(&mut conn, "Shared job");
+        let sibling = insert_proposal(&conn, "Shared job", "Second take", None).unwrap();
+        conn.execute(
+            "UPDATE proposals SET job_post_id = ?1 WHERE id = ?2",
+            params![job_post_id, sibling],
+        )
+        .unwrap();
+
+        let report = purge_proposal_data(&conn, id, true).unwrap().unwrap();
+        assert!(report.job_post_kept);
+        assert!(!report.job_post_deleted);
+        assert!(!report.deleted.contains_key("job_posts"));
+
+        let report = purge_proposal_data(&conn, sibling, false).unwrap().unwrap();
+        assert!(!report.job_post_kept);
+        assert_eq!(referencing_rows(&conn, "job_post_id", job_post_id).len(), 2);
+        assert!(referencing_rows(&conn, "proposal_id", id).is_empty());
+    }
+
+    #[test]
+    fn test_purge_proposal_data_missing_and_restores_secure_delete() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        assert!(purge_proposal_data(&conn, 999, false).unwrap().is_none());
+
+        let id = insert_proposal(&conn, "Job", "Text", None).unwrap();
+        conn.execute_batch("PRAGMA secure_delete=OFF;").unwrap();
+        let report = purge_proposal_data(&conn, id, false).unwrap().unwrap();
+        assert_ne!(report.space_reclaimed, report.compact_recommended);
+        let secure_delete: i64 = conn
+            .query_row("PRAGMA secure_delete", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(secure_delete, 0);
+    }
+
     #[test]
     fn test_delete_proposal_nonexistent() {
         let db = create_test_db();
@@ -1490,7 +2160,7 @@ mod tests {
         insert_proposal(&conn, "Job A", "Proposal A", None).unwrap();
         insert_proposal(&conn, "Job B", "Proposal B", None).unwrap();
 
-        let result = search_proposals(&conn, None, None, None, None, 50, 0).unwrap();
+        let result = search_proposals(&conn, None, None, None, None, None, 50, 0).unwrap();
 
         assert_eq!(result.proposals.len(), 2);
         assert_eq!(result.total_count, 2);
@@ -1506,7 +2176,7 @@ mod tests {
         insert_proposal(&conn, "Need Python developer", "I have 5 years", None).unwrap();
         insert_proposal(&conn, "Java backend role", "Spring boot", None).unwrap();
 
-        let result = search_proposals(&conn, Some("React"), None, None, None, 50, 0).unwrap();
+        let result = search_proposals(&conn, Some("React"), None, None, None, None, 50, 0).unwrap();
 
         assert_eq!(result.total_count, 1);
         assert_eq!(result.proposals.len(), 1);
@@ -1527,7 +2197,7 @@ mod tests {
         .unwrap();
         insert_proposal(&conn, "Job B", "Python is my forte", None).unwrap();
 
-        let result = search_proposals(&conn, Some("Rust"), None, None, None, 50, 0).unwrap();
+        let result = search_proposals(&conn, Some("Rust"), None, None, None, None, 50, 0).unwrap();
 
         assert_eq!(result.total_count, 1);
         assert_eq!(result.proposals.len(), 1);
@@ -1540,7 +2210,7 @@ mod tests {
 
         insert_proposal(&conn, "Looking for REACT developer", "Proposal", None).unwrap();
 
-        let result = search_proposals(&conn, Some("react"), None, None, None, 50, 0).unwrap();
+        let result = search_proposals(&conn, Some("react"), None, None, None, None, 50, 0).unwrap();
 
         assert_eq!(
             result.total_count, 1,
@@ -1561,7 +2231,7 @@ mod tests {
         update_proposal_outcome(&conn, id1, "hired").unwrap();
         update_proposal_outcome(&conn, id2, "rejected").unwrap();
 
-        let result = search_proposals(&conn, None, Some("hired"), None, None, 50, 0).unwrap();
+        let result = search_proposals(&conn, None, Some("hired"), None, None, None, 50, 0).unwrap();
 
         assert_eq!(result.total_count, 1);
         assert_eq!(result.proposals[0].outcome_status, "hired");
@@ -1575,13 +2245,13 @@ mod tests {
         // Insert proposals — all have "now" timestamp, so they're within any date range
         insert_proposal(&conn, "Job A", "Text A", None).unwrap();
 
-        let result = search_proposals(&conn, None, None, Some(7), None, 50, 0).unwrap();
+        let result = search_proposals(&conn, None, None, Some(7), None, None, 50, 0).unwrap();
         assert_eq!(
             result.total_count, 1,
             "Proposal created 'now' should be within 7 days"
         );
 
-        let result_all = search_proposals(&conn, None, None, Some(0), None, 50, 0).unwrap();
+        let result_all = search_proposals(&conn, None, None, Some(0), None, None, 50, 0).unwrap();
         assert_eq!(
             result_all.total_count, 1,
             "date_range_days=0 means no date filter"
@@ -1600,7 +2270,7 @@ mod tests {
         insert_proposal(&conn, "Job C", "Text C", None).unwrap();
 
         let result =
-            search_proposals(&conn, None, None, None, Some("social_proof"), 50, 0).unwrap();
+            search_proposals(&conn, None, None, None, Some("social_proof"), None, 50, 0).unwrap();
 
         assert_eq!(result.total_count, 1);
         assert_eq!(
@@ -1651,6 +2321,7 @@ mod tests {
             Some("hired"),
             None,
             Some("social_proof"),
+            None,
             50,
             0,
         )
@@ -1668,7 +2339,7 @@ mod tests {
         insert_proposal(&conn, "Job A", "Text A", None).unwrap();
 
         let result =
-            search_proposals(&conn, Some("nonexistent_xyz"), None, None, None, 50, 0).unwrap();
+            search_proposals(&conn, Some("nonexistent_xyz"), None, None, None, None, 50, 0).unwrap();
 
         assert_eq!(result.total_count, 0);
         assert_eq!(result.proposals.len(), 0);
@@ -1684,12 +2355,12 @@ mod tests {
             insert_proposal(&conn, &format!("Job {}", i), &format!("Text {}", i), None).unwrap();
         }
 
-        let page1 = search_proposals(&conn, None, None, None, None, 3, 0).unwrap();
+        let page1 = search_proposals(&conn, None, None, None, None, None, 3, 0).unwrap();
         assert_eq!(page1.proposals.len(), 3);
         assert_eq!(page1.total_count, 10);
         assert!(page1.has_more);
 
-        let page2 = search_proposals(&conn, None, None, None, None, 3, 9).unwrap();
+        let page2 = search_proposals(&conn, None, None, None, None, None, 3, 9).unwrap();
         assert_eq!(page2.proposals.len(), 1);
         assert!(!page2.has_more);
     }
@@ -1711,7 +2382,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = search_proposals(&conn, None, None, None, None, 50, 0).unwrap();
+        let result = search_proposals(&conn, None, None, None, None, None, 50, 0).unwrap();
 
         assert_eq!(result.proposals.len(), 1);
         let p = &result.proposals[0];
@@ -1738,7 +2409,7 @@ mod tests {
         insert_proposal(&conn, "Job A", "Text A", None).unwrap();
 
         // Empty string should be treated as no filter
-        let result = search_proposals(&conn, Some(""), None, None, None, 50, 0).unwrap();
+        let result = search_proposals(&conn, Some(""), None, None, None, None, 50, 0).unwrap();
         assert_eq!(result.total_count, 1);
     }
 
@@ -1754,7 +2425,7 @@ mod tests {
         insert_proposal(&conn, "Has dataXfield in code", "Text D", None).unwrap();
 
         // Searching "100%" should only match the literal "100%", not "100X"
-        let result = search_proposals(&conn, Some("100%"), None, None, None, 50, 0).unwrap();
+        let result = search_proposals(&conn, Some("100%"), None, None, None, None, 50, 0).unwrap();
         assert_eq!(
             result.total_count, 1,
             "% should be escaped: only literal '100%' matches"
@@ -1762,7 +2433,7 @@ mod tests {
         assert!(result.proposals[0].job_excerpt.contains("100%"));
 
         // Searching "data_field" should only match the literal "data_field", not "dataXfield"
-        let result2 = search_proposals(&conn, Some("data_field"), None, None, None, 50, 0).unwrap();
+        let result2 = search_proposals(&conn, Some("data_field"), None, None, None, None, 50, 0).unwrap();
         assert_eq!(
             result2.total_count, 1,
             "_ should be escaped: only literal 'data_field' matches"
@@ -1901,6 +2572,91 @@ mod tests {
         assert_eq!(detail.hook_strategy_id.as_deref(), Some("social_proof"));
     }
 
+    #[test]
+    fn test_job_post_link_fields_filter_and_delete_semantics() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        conn.execute(
+            "INSERT INTO job_posts (raw_content, client_name, overall_score, budget_type) \
+             VALUES ('Job A', 'Acme Corp', 82.5, 'fixed')",
+            [],
+        )
+        .unwrap();
+        let job_post_id = conn.last_insert_rowid();
+        let linked =
+            insert_proposal_with_context(&conn, "Job A", "Text A", None, None, Some(job_post_id))
+                .unwrap();
+        let unlinked = insert_proposal(&conn, "Job B", "Text B", None).unwrap();
+
+        let detail = get_proposal_detail(&conn, linked).unwrap().unwrap();
+        assert_eq!(detail.job_client_name.as_deref(), Some("Acme Corp"));
+        assert_eq!(detail.job_overall_score, Some(82.5));
+        assert_eq!(detail.job_budget_type.as_deref(), Some("fixed"));
+
+        let summaries = list_proposals(&conn).unwrap();
+        let summary = summaries.iter().find(|p| p.id == linked).unwrap();
+        assert_eq!(summary.client_name.as_deref(), Some("Acme Corp"));
+        assert_eq!(summary.overall_score, Some(82.5));
+        let other = summaries.iter().find(|p| p.id == unlinked).unwrap();
+        assert_eq!(other.job_post_id, None);
+        assert_eq!(other.budget_type, None);
+
+        let filtered =
+            search_proposals(&conn, None, None, None, None, Some(job_post_id), 50, 0).unwrap();
+        assert_eq!(filtered.total_count, 1);
+        assert_eq!(filtered.proposals[0].id, linked);
+        assert_eq!(filtered.proposals[0].job_post_id, Some(job_post_id));
+
+        // Deleting the proposal keeps the job post
+        let second =
+            insert_proposal_with_context(&conn, "Job A", "Text A2", None, None, Some(job_post_id))
+                .unwrap();
+        assert!(delete_proposal(&conn, second).unwrap());
+        let job_posts: i64 = conn
+            .query_row("SELECT COUNT(*) FROM job_posts", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(job_posts, 1);
+
+        // Deleting the job post nulls the link (ON DELETE SET NULL)
+        conn.execute("DELETE FROM job_posts WHERE id = ?1", params![job_post_id])
+            .unwrap();
+        let detail = get_proposal_detail(&conn, linked).unwrap().unwrap();
+        assert_eq!(detail.job_post_id, None);
+        assert_eq!(detail.job_client_name, None);
+    }
+
+    #[test]
+    fn test_backfill_migration_links_exact_job_content() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        conn.execute(
+            "INSERT INTO job_posts (raw_content) VALUES ('Exact job'), ('Exact job'), ('Other')",
+            [],
+        )
+        .unwrap();
+        let newest_match = conn.last_insert_rowid() - 1;
+        let exact = insert_proposal(&conn, "Exact job", "Text", None).unwrap();
+        let no_match = insert_proposal(&conn, "Exact job, edited", "Text", None).unwrap();
+
+        conn.execute_batch(include_str!(
+            "../../../migrations/V38__backfill_proposal_job_post_links.sql"
+        ))
+        .unwrap();
+
+        let link = |id: i64| -> Option<i64> {
+            conn.query_row(
+                "SELECT job_post_id FROM proposals WHERE id = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .unwrap()
+        };
+        assert_eq!(link(exact), Some(newest_match));
+        assert_eq!(link(no_match), None);
+    }
+
     #[test]
     fn test_get_proposal_detail_job_post_fallback_to_raw_content() {
         let db = create_test_db();
@@ -2361,4 +3117,37 @@ mod tests {
         let activities_52w = get_weekly_activity(&conn, 52).unwrap();
         assert_eq!(activities_52w.len(), 1);
     }
+
+    #[test]
+    fn test_mark_proposal_copied_feeds_copy_funnel() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let id1 = insert_proposal(&conn, "J1", "T1", Some("completed")).unwrap();
+        let id2 = insert_proposal(&conn, "J2", "T2", Some("completed")).unwrap();
+        let id3 = insert_proposal(&conn, "J3", "T3", Some("completed")).unwrap();
+        update_proposal_outcome(&conn, id1, "interview").unwrap();
+        update_proposal_outcome(&conn, id3, "hired").unwrap(); // never copied
+
+        assert!(mark_proposal_copied(&conn, id1).unwrap().is_some());
+        assert!(mark_proposal_copied(&conn, id2).unwrap().is_some());
+        // Copying again updates the timestamp instead of failing
+        conn.execute(
+            "UPDATE proposals SET copied_at = '2020-01-01 00:00:00' WHERE id = ?1",
+            params![id2],
+        )
+        .unwrap();
+        let again = mark_proposal_copied(&conn, id2).unwrap().unwrap();
+        assert_ne!(again, "2020-01-01 00:00:00");
+        assert!(mark_proposal_copied(&conn, 9999).unwrap().is_none());
+
+        let summary = get_proposal_analytics_summary(&conn).unwrap();
+        assert_eq!(summary.copied_proposals, 2);
+        assert_eq!(summary.copied_positive, 1);
+        assert_eq!(summary.copy_conversion_rate, 50.0);
+
+        let activities = get_weekly_activity(&conn, 12).unwrap();
+        assert_eq!(activities[0].copied_count, 2);
+        assert_eq!(activities[0].copy_conversion_rate, 50.0);
+    }
 }
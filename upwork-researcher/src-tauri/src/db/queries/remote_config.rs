@@ -13,6 +13,8 @@ pub struct CachedConfig {
     pub fetched_at: String,
     pub signature: String,
     pub source: String,
+    /// ID of the trusted key that verified the signature (None for configs stored before V32)
+    pub signing_key_id: Option<String>,
 }
 
 /// Store or update remote config in database (UPSERT pattern)
@@ -32,20 +34,33 @@ pub fn store_remote_config(
     conn: &Connection,
     config: &RemoteConfig,
     signature: &str,
+) -> Result<(), rusqlite::Error> {
+    store_verified_remote_config(conn, config, signature, None)
+}
+
+/// Store remote config along with the ID of the trusted key that verified it
+///
+/// Same UPSERT semantics as [`store_remote_config`].
+pub fn store_verified_remote_config(
+    conn: &Connection,
+    config: &RemoteConfig,
+    signature: &str,
+    signing_key_id: Option<&str>,
 ) -> Result<(), rusqlite::Error> {
     let config_json = serde_json::to_string(config)
         .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
 
     conn.execute(
-        "INSERT INTO remote_config (id, schema_version, config_json, fetched_at, signature, source)
-         VALUES (1, ?1, ?2, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'), ?3, 'remote')
+        "INSERT INTO remote_config (id, schema_version, config_json, fetched_at, signature, source, signing_key_id)
+         VALUES (1, ?1, ?2, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'), ?3, 'remote', ?4)
          ON CONFLICT(id) DO UPDATE SET
             schema_version = excluded.schema_version,
             config_json = excluded.config_json,
             fetched_at = excluded.fetched_at,
             signature = excluded.signature,
-            source = excluded.source",
-        params![&config.schema_version, &config_json, signature],
+            source = excluded.source,
+            signing_key_id = excluded.signing_key_id",
+        params![&config.schema_version, &config_json, signature, signing_key_id],
     )?;
 
     Ok(())
@@ -64,7 +79,7 @@ pub fn store_remote_config(
 /// * `Err(rusqlite::Error)` on database or deserialization failure
 pub fn get_cached_config(conn: &Connection) -> Result<Option<CachedConfig>, rusqlite::Error> {
     let mut stmt = conn.prepare(
-        "SELECT config_json, fetched_at, signature, source FROM remote_config WHERE id = 1",
+        "SELECT config_json, fetched_at, signature, source, signing_key_id FROM remote_config WHERE id = 1",
     )?;
 
     let mut rows = stmt.query([])?;
@@ -74,6 +89,7 @@ pub fn get_cached_config(conn: &Connection) -> Result<Option<CachedConfig>, rusq
         let fetched_at: String = row.get(1)?;
         let signature: String = row.get(2)?;
         let source: String = row.get(3)?;
+        let signing_key_id: Option<String> = row.get(4)?;
 
         // Deserialize config from JSON
         let config: RemoteConfig = serde_json::from_str(&config_json).map_err(|e| {
@@ -89,6 +105,7 @@ pub fn get_cached_config(conn: &Connection) -> Result<Option<CachedConfig>, rusq
             fetched_at,
             signature,
             source,
+            signing_key_id,
         }))
     } else {
         Ok(None)
@@ -122,6 +139,8 @@ mod tests {
                 status: StrategyStatus::Active,
                 ab_weight: 1.0,
             }],
+            ai_tell_phrases: vec![],
+            beta_update_feed_url: None,
         }
     }
 
@@ -261,4 +280,20 @@ mod tests {
         assert_eq!(cached.config.strategies[1].ab_weight, 0.5);
         assert_eq!(cached.config.strategies[1].examples.len(), 3);
     }
+
+    #[test]
+    fn test_store_verified_config_records_signing_key() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let config = create_test_config();
+        store_verified_remote_config(&conn, &config, "sig", Some("abcd1234")).unwrap();
+        let cached = get_cached_config(&conn).unwrap().unwrap();
+        assert_eq!(cached.signing_key_id.as_deref(), Some("abcd1234"));
+
+        // Plain store clears the key id
+        store_remote_config(&conn, &config, "sig").unwrap();
+        let cached = get_cached_config(&conn).unwrap().unwrap();
+        assert!(cached.signing_key_id.is_none());
+    }
 }
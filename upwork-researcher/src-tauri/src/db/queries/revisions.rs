@@ -1,8 +1,28 @@
 // src-tauri/src/db/queries/revisions.rs
 // Proposal revision history operations
-
-use rusqlite::{params, Connection};
+//
+// Storage (V51): a revision identical to the proposal's latest one is not stored
+// again (matched by `content_hash`). Every `revision_keyframe_interval`-th
+// revision is stored as full text (a keyframe); the ones in between are stored
+// as a delta against the latest keyframe. Readers always get full text: deltas
+// are reconstructed here, and a delta that can't be reconstructed falls back to
+// its nearest keyframe with a warning instead of failing.
+
+use rusqlite::{params, Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub const REVISION_KEYFRAME_INTERVAL_SETTING: &str = "revision_keyframe_interval";
+pub const DEFAULT_REVISION_KEYFRAME_INTERVAL: u32 = 10;
+pub const MAX_REVISION_KEYFRAME_INTERVAL: u32 = 100;
+
+/// `storage` of a revision whose `content` is the full text
+const STORAGE_FULL: &str = "full";
+/// `storage` of a revision whose `content` is a `RevisionDelta` (JSON)
+const STORAGE_DELTA: &str = "delta";
+
+/// Characters shown in the history list
+const PREVIEW_CHARS: usize = 50;
 
 /// Revision metadata for list display (without full content)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -26,34 +46,363 @@ pub struct ProposalRevision {
     pub revision_type: String,
     pub restored_from_id: Option<i64>,
     pub created_at: String,
+    /// Set when the stored delta was unreadable and `content` is the nearest
+    /// keyframe instead of this revision's own text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+/// Result of `create_revision`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedRevision {
+    /// The new revision, or the latest one when deduplicated
+    pub id: i64,
+    /// True when the content matched the latest revision and nothing was stored
+    pub deduplicated: bool,
+}
+
+/// Delta against a keyframe: the keyframe's first `prefix` and last `suffix`
+/// bytes with `insert` between them. Autosaves change one region at a time, so
+/// a single replaced span keeps deltas small without a diff library.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct RevisionDelta {
+    prefix: usize,
+    suffix: usize,
+    insert: String,
+}
+
+impl RevisionDelta {
+    fn between(base: &str, content: &str) -> Self {
+        // Byte offsets of whole matching chars, so both ends are char boundaries
+        let prefix = base
+            .char_indices()
+            .zip(content.chars())
+            .find(|((_, a), b)| a != b)
+            .map(|((i, _), _)| i)
+            .unwrap_or_else(|| base.len().min(content.len()));
+        let suffix: usize = base[prefix..]
+            .chars()
+            .rev()
+            .zip(content[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum();
+
+        Self {
+            prefix,
+            suffix,
+            insert: content[prefix..content.len() - suffix].to_string(),
+        }
+    }
+
+    fn apply(&self, base: &str) -> Result<String, String> {
+        if self.prefix + self.suffix > base.len() {
+            return Err("delta spans past the end of its keyframe".to_string());
+        }
+        let head = base
+            .get(..self.prefix)
+            .ok_or("delta prefix is not on a character boundary")?;
+        let tail = base
+            .get(base.len() - self.suffix..)
+            .ok_or("delta suffix is not on a character boundary")?;
+        Ok(format!("{}{}{}", head, self.insert, tail))
+    }
+}
+
+/// SHA-256 (hex) of a revision's full text
+pub fn revision_content_hash(content: &str) -> String {
+    hex::encode(Sha256::digest(content.as_bytes()))
+}
+
+/// Keyframe interval from settings; 1 stores every revision as full text
+pub fn revision_keyframe_interval(conn: &Connection) -> u32 {
+    crate::settings::schema::get_typed(conn, crate::settings::schema::Key::RevisionKeyframeInterval)
+        .unwrap_or(DEFAULT_REVISION_KEYFRAME_INTERVAL)
+}
+
+/// A `proposal_revisions` row as stored
+struct StoredRevision {
+    id: i64,
+    proposal_id: i64,
+    content: String,
+    revision_type: String,
+    restored_from_id: Option<i64>,
+    created_at: String,
+    content_hash: Option<String>,
+    storage: String,
+    keyframe_id: Option<i64>,
+}
+
+const STORED_REVISION_COLUMNS: &str = "id, proposal_id, content, revision_type, restored_from_id, \
+     created_at, content_hash, storage, keyframe_id";
+
+impl StoredRevision {
+    fn from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            proposal_id: row.get(1)?,
+            content: row.get(2)?,
+            revision_type: row.get(3)?,
+            restored_from_id: row.get(4)?,
+            created_at: row.get(5)?,
+            content_hash: row.get(6)?,
+            storage: row.get(7)?,
+            keyframe_id: row.get(8)?,
+        })
+    }
+
+    /// Full text of this revision; a broken delta falls back to the nearest
+    /// keyframe, returned as the warning alongside it
+    fn materialize(&self, conn: &Connection) -> Result<(String, Option<String>), String> {
+        if self.storage != STORAGE_DELTA {
+            return Ok((self.content.clone(), None));
+        }
+        match self.reconstruct(conn) {
+            Ok(content) => Ok((content, None)),
+            Err(e) => {
+                tracing::warn!(
+                    revision_id = self.id,
+                    proposal_id = self.proposal_id,
+                    error = %e,
+                    "Revision delta could not be reconstructed; using nearest keyframe"
+                );
+                let keyframe =
+                    nearest_keyframe(conn, self.proposal_id, self.id)?.ok_or_else(|| {
+                        format!(
+                            "Revision {} could not be reconstructed and no keyframe is left: {}",
+                            self.id, e
+                        )
+                    })?;
+                Ok((
+                    keyframe,
+                    Some(format!(
+                        "This revision could not be reconstructed ({}); showing the nearest full copy instead",
+                        e
+                    )),
+                ))
+            }
+        }
+    }
+
+    fn reconstruct(&self, conn: &Connection) -> Result<String, String> {
+        let keyframe_id = self.keyframe_id.ok_or("delta has no keyframe")?;
+        let keyframe: Option<String> = conn
+            .query_row(
+                "SELECT content FROM proposal_revisions
+                 WHERE id = ?1 AND proposal_id = ?2 AND storage = 'full'",
+                params![keyframe_id, self.proposal_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| e.to_string())?;
+        let keyframe = keyframe.ok_or_else(|| format!("keyframe {} is missing", keyframe_id))?;
+        let delta: RevisionDelta = serde_json::from_str(&self.content)
+            .map_err(|e| format!("delta is unreadable: {}", e))?;
+        let content = delta.apply(&keyframe)?;
+        if let Some(expected) = &self.content_hash {
+            if revision_content_hash(&content) != *expected {
+                return Err("reconstructed text does not match its hash".to_string());
+            }
+        }
+        Ok(content)
+    }
+
+    fn into_revision(self, conn: &Connection) -> Result<ProposalRevision, String> {
+        let (content, warning) = self.materialize(conn)?;
+        Ok(ProposalRevision {
+            id: self.id,
+            proposal_id: self.proposal_id,
+            content,
+            revision_type: self.revision_type,
+            restored_from_id: self.restored_from_id,
+            created_at: self.created_at,
+            warning,
+        })
+    }
+}
+
+/// Full text of the keyframe closest to `revision_id` (earlier first)
+fn nearest_keyframe(
+    conn: &Connection,
+    proposal_id: i64,
+    revision_id: i64,
+) -> Result<Option<String>, String> {
+    conn.query_row(
+        "SELECT content FROM proposal_revisions
+         WHERE proposal_id = ?1 AND storage = 'full'
+         ORDER BY id > ?2, ABS(id - ?2)
+         LIMIT 1",
+        params![proposal_id, revision_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|e| e.to_string())
+}
+
+fn get_stored_revision(conn: &Connection, revision_id: i64) -> Result<StoredRevision, String> {
+    conn.query_row(
+        &format!(
+            "SELECT {} FROM proposal_revisions WHERE id = ?1",
+            STORED_REVISION_COLUMNS
+        ),
+        params![revision_id],
+        StoredRevision::from_row,
+    )
+    .map_err(|e| e.to_string())
 }
 
 /// Create a new revision (called on auto-save)
+///
+/// Skips the insert when `content` matches the proposal's latest revision and
+/// returns that revision with `deduplicated: true`.
 pub fn create_revision(
     conn: &Connection,
     proposal_id: i64,
     content: &str,
     revision_type: &str,
     restored_from_id: Option<i64>,
-) -> Result<i64, String> {
+) -> Result<CreatedRevision, String> {
     // Validate revision_type
-    if !matches!(revision_type, "generation" | "edit" | "restore") {
+    if !matches!(
+        revision_type,
+        "generation" | "edit" | "restore" | "partial_regen" | "rehumanize"
+    ) {
         return Err(format!(
-            "Invalid revision_type: {}. Must be 'generation', 'edit', or 'restore'",
+            "Invalid revision_type: {}. Must be 'generation', 'edit', 'restore', 'partial_regen' or 'rehumanize'",
             revision_type
         ));
     }
 
+    let content_hash = revision_content_hash(content);
+    let latest = conn
+        .query_row(
+            &format!(
+                "SELECT {} FROM proposal_revisions WHERE proposal_id = ?1
+                 ORDER BY revision_number DESC, id DESC LIMIT 1",
+                STORED_REVISION_COLUMNS
+            ),
+            params![proposal_id],
+            StoredRevision::from_row,
+        )
+        .optional()
+        .map_err(|e| e.to_string())?;
+    if let Some(latest) = latest {
+        // Rows from before V51 have no hash until the startup backfill runs
+        let latest_hash = match latest.content_hash.clone() {
+            Some(hash) => Some(hash),
+            None => latest
+                .materialize(conn)
+                .ok()
+                .filter(|(_, warning)| warning.is_none())
+                .map(|(text, _)| revision_content_hash(&text)),
+        };
+        if latest_hash.as_deref() == Some(content_hash.as_str()) {
+            return Ok(CreatedRevision {
+                id: latest.id,
+                deduplicated: true,
+            });
+        }
+    }
+
+    let (storage, stored, keyframe_id) = match delta_keyframe(conn, proposal_id)? {
+        Some((keyframe_id, keyframe)) => {
+            let delta = serde_json::to_string(&RevisionDelta::between(&keyframe, content))
+                .map_err(|e| e.to_string())?;
+            if delta.len() < content.len() {
+                (STORAGE_DELTA, delta, Some(keyframe_id))
+            } else {
+                (STORAGE_FULL, content.to_string(), None)
+            }
+        }
+        None => (STORAGE_FULL, content.to_string(), None),
+    };
+
     conn.execute(
-        "INSERT INTO proposal_revisions (proposal_id, content, revision_type, restored_from_id, revision_number)
+        "INSERT INTO proposal_revisions
+            (proposal_id, content, revision_type, restored_from_id, revision_number,
+             content_hash, storage, keyframe_id)
          VALUES (?1, ?2, ?3, ?4,
-            COALESCE((SELECT MAX(revision_number) + 1 FROM proposal_revisions WHERE proposal_id = ?1), 1)
+            COALESCE((SELECT MAX(revision_number) + 1 FROM proposal_revisions WHERE proposal_id = ?1), 1),
+            ?5, ?6, ?7
          )",
-        params![proposal_id, content, revision_type, restored_from_id],
+        params![
+            proposal_id,
+            stored,
+            revision_type,
+            restored_from_id,
+            content_hash,
+            storage,
+            keyframe_id
+        ],
     )
     .map_err(|e| e.to_string())?;
 
-    Ok(conn.last_insert_rowid())
+    Ok(CreatedRevision {
+        id: conn.last_insert_rowid(),
+        deduplicated: false,
+    })
+}
+
+/// The keyframe the next revision should be a delta against, or None when the
+/// next revision is due to be a keyframe itself
+fn delta_keyframe(conn: &Connection, proposal_id: i64) -> Result<Option<(i64, String)>, String> {
+    let interval = revision_keyframe_interval(conn) as i64;
+    if interval <= 1 {
+        return Ok(None);
+    }
+
+    let keyframe: Option<(i64, String)> = conn
+        .query_row(
+            "SELECT id, content FROM proposal_revisions
+             WHERE proposal_id = ?1 AND storage = 'full'
+             ORDER BY id DESC LIMIT 1",
+            params![proposal_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()
+        .map_err(|e| e.to_string())?;
+    let Some((keyframe_id, content)) = keyframe else {
+        return Ok(None);
+    };
+
+    let since_keyframe: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM proposal_revisions WHERE proposal_id = ?1 AND id > ?2",
+            params![proposal_id, keyframe_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+
+    // The keyframe plus its deltas make up one interval
+    Ok((since_keyframe + 1 < interval).then_some((keyframe_id, content)))
+}
+
+/// Fill in `content_hash` for full-text rows stored before V51. Run at startup;
+/// returns the number of rows updated.
+pub fn backfill_content_hashes(conn: &Connection) -> Result<usize, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, content FROM proposal_revisions
+             WHERE content_hash IS NULL AND storage = 'full'",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    for (id, content) in &rows {
+        conn.execute(
+            "UPDATE proposal_revisions SET content_hash = ?1 WHERE id = ?2",
+            params![revision_content_hash(content), id],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(rows.len())
 }
 
 /// Get revision summaries for a proposal (newest first)
@@ -61,7 +410,7 @@ pub fn get_revisions(conn: &Connection, proposal_id: i64) -> Result<Vec<Revision
     let mut stmt = conn
         .prepare(
             "SELECT id, proposal_id, revision_type, restored_from_id, created_at,
-                SUBSTR(content, 1, 50) as content_preview
+                SUBSTR(content, 1, 50) as content_preview, storage
          FROM proposal_revisions
          WHERE proposal_id = ?1
          ORDER BY created_at DESC",
@@ -70,41 +419,41 @@ pub fn get_revisions(conn: &Connection, proposal_id: i64) -> Result<Vec<Revision
 
     let revisions = stmt
         .query_map(params![proposal_id], |row| {
-            Ok(RevisionSummary {
-                id: row.get(0)?,
-                proposal_id: row.get(1)?,
-                revision_type: row.get(2)?,
-                restored_from_id: row.get(3)?,
-                created_at: row.get(4)?,
-                content_preview: row.get(5)?,
-            })
+            Ok((
+                RevisionSummary {
+                    id: row.get(0)?,
+                    proposal_id: row.get(1)?,
+                    revision_type: row.get(2)?,
+                    restored_from_id: row.get(3)?,
+                    created_at: row.get(4)?,
+                    content_preview: row.get(5)?,
+                },
+                row.get::<_, String>(6)?,
+            ))
         })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
         .map_err(|e| e.to_string())?;
 
+    // A delta's stored text is not the revision's text
     revisions
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| e.to_string())
+        .into_iter()
+        .map(|(mut summary, storage)| {
+            if storage == STORAGE_DELTA {
+                summary.content_preview = get_revision(conn, summary.id)?
+                    .content
+                    .chars()
+                    .take(PREVIEW_CHARS)
+                    .collect();
+            }
+            Ok(summary)
+        })
+        .collect()
 }
 
 /// Get full revision content for preview
 pub fn get_revision(conn: &Connection, revision_id: i64) -> Result<ProposalRevision, String> {
-    conn.query_row(
-        "SELECT id, proposal_id, content, revision_type, restored_from_id, created_at
-         FROM proposal_revisions
-         WHERE id = ?1",
-        params![revision_id],
-        |row| {
-            Ok(ProposalRevision {
-                id: row.get(0)?,
-                proposal_id: row.get(1)?,
-                content: row.get(2)?,
-                revision_type: row.get(3)?,
-                restored_from_id: row.get(4)?,
-                created_at: row.get(5)?,
-            })
-        },
-    )
-    .map_err(|e| e.to_string())
+    get_stored_revision(conn, revision_id)?.into_revision(conn)
 }
 
 /// Get revision count for warning threshold
@@ -139,33 +488,29 @@ pub fn archive_old_revisions(conn: &mut Connection, proposal_id: i64) -> Result<
     // Begin transaction
     let tx = conn.transaction().map_err(|e| e.to_string())?;
 
-    // Fetch oldest revisions (the ones to archive)
-    let mut stmt = tx
-        .prepare(
-            "SELECT id, proposal_id, content, revision_type, restored_from_id, created_at
-         FROM proposal_revisions
-         WHERE proposal_id = ?1
-         ORDER BY created_at ASC
-         LIMIT ?2",
-        )
-        .map_err(|e| e.to_string())?;
-
-    let revisions_to_archive: Vec<ProposalRevision> = stmt
-        .query_map(params![proposal_id, to_archive], |row| {
-            Ok(ProposalRevision {
-                id: row.get(0)?,
-                proposal_id: row.get(1)?,
-                content: row.get(2)?,
-                revision_type: row.get(3)?,
-                restored_from_id: row.get(4)?,
-                created_at: row.get(5)?,
-            })
-        })
-        .map_err(|e| e.to_string())?
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| e.to_string())?;
-
-    drop(stmt); // Explicitly drop stmt before transaction operations
+    // Fetch oldest revisions (the ones to archive), as full text: the archive
+    // never holds deltas
+    let revisions_to_archive: Vec<ProposalRevision> = {
+        let mut stmt = tx
+            .prepare(&format!(
+                "SELECT {}
+             FROM proposal_revisions
+             WHERE proposal_id = ?1
+             ORDER BY created_at ASC
+             LIMIT ?2",
+                STORED_REVISION_COLUMNS
+            ))
+            .map_err(|e| e.to_string())?;
+        let stored = stmt
+            .query_map(params![proposal_id, to_archive], StoredRevision::from_row)
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        stored
+            .into_iter()
+            .map(|revision| revision.into_revision(&tx))
+            .collect::<Result<Vec<_>, _>>()?
+    };
 
     if revisions_to_archive.is_empty() {
         return Ok(0);
@@ -199,12 +544,45 @@ pub fn archive_old_revisions(conn: &mut Connection, proposal_id: i64) -> Result<
     )
     .map_err(|e| e.to_string())?;
 
-    // Delete archived revisions from active table
     let placeholders = ids_to_delete
         .iter()
         .map(|_| "?")
         .collect::<Vec<_>>()
         .join(",");
+
+    // Deltas that stay active but whose keyframe is being archived become
+    // keyframes themselves
+    let orphaned: Vec<StoredRevision> = {
+        let mut stmt = tx
+            .prepare(&format!(
+                "SELECT {} FROM proposal_revisions
+                 WHERE storage = 'delta' AND keyframe_id IN ({}) AND id NOT IN ({})",
+                STORED_REVISION_COLUMNS, placeholders, placeholders
+            ))
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map(
+                rusqlite::params_from_iter(ids_to_delete.iter().chain(ids_to_delete.iter())),
+                StoredRevision::from_row,
+            )
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        rows
+    };
+    for revision in orphaned {
+        let id = revision.id;
+        let (content, _) = revision.materialize(&tx)?;
+        tx.execute(
+            "UPDATE proposal_revisions
+             SET content = ?1, storage = 'full', keyframe_id = NULL, content_hash = ?2
+             WHERE id = ?3",
+            params![content, revision_content_hash(&content), id],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+
+    // Delete archived revisions from active table
     tx.execute(
         &format!(
             "DELETE FROM proposal_revisions WHERE id IN ({})",
@@ -293,9 +671,12 @@ mod tests {
                 content TEXT NOT NULL,
                 revision_number INTEGER NOT NULL,
                 revision_type TEXT NOT NULL DEFAULT 'edit'
-                    CHECK (revision_type IN ('generation', 'edit', 'restore')),
+                    CHECK (revision_type IN ('generation', 'edit', 'restore', 'partial_regen', 'rehumanize')),
                 restored_from_id INTEGER,
                 created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                content_hash TEXT,
+                storage TEXT NOT NULL DEFAULT 'full' CHECK (storage IN ('full', 'delta')),
+                keyframe_id INTEGER,
                 FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE,
                 FOREIGN KEY (restored_from_id) REFERENCES proposal_revisions(id)
             )",
@@ -328,7 +709,9 @@ mod tests {
 
         // Create revision
         let revision_id =
-            create_revision(&conn, proposal_id, "First revision content", "edit", None).unwrap();
+            create_revision(&conn, proposal_id, "First revision content", "edit", None)
+                .unwrap()
+                .id;
 
         assert!(revision_id > 0);
 
@@ -353,18 +736,23 @@ mod tests {
         let proposal_id = conn.last_insert_rowid();
 
         // Test generation type
-        let gen_id = create_revision(&conn, proposal_id, "Generated", "generation", None).unwrap();
+        let gen_id = create_revision(&conn, proposal_id, "Generated", "generation", None)
+            .unwrap()
+            .id;
         let gen_rev = get_revision(&conn, gen_id).unwrap();
         assert_eq!(gen_rev.revision_type, "generation");
 
         // Test edit type
-        let edit_id = create_revision(&conn, proposal_id, "Edited", "edit", None).unwrap();
+        let edit_id = create_revision(&conn, proposal_id, "Edited", "edit", None)
+            .unwrap()
+            .id;
         let edit_rev = get_revision(&conn, edit_id).unwrap();
         assert_eq!(edit_rev.revision_type, "edit");
 
         // Test restore type
-        let restore_id =
-            create_revision(&conn, proposal_id, "Restored", "restore", Some(gen_id)).unwrap();
+        let restore_id = create_revision(&conn, proposal_id, "Restored", "restore", Some(gen_id))
+            .unwrap()
+            .id;
         let restore_rev = get_revision(&conn, restore_id).unwrap();
         assert_eq!(restore_rev.revision_type, "restore");
         assert_eq!(restore_rev.restored_from_id, Some(gen_id));
@@ -398,21 +786,27 @@ mod tests {
         let proposal_id = conn.last_insert_rowid();
 
         // Create three revisions with explicit timestamps to ensure ordering
-        let id1 = create_revision(&conn, proposal_id, "First revision", "edit", None).unwrap();
+        let id1 = create_revision(&conn, proposal_id, "First revision", "edit", None)
+            .unwrap()
+            .id;
         conn.execute(
             "UPDATE proposal_revisions SET created_at = datetime('2024-01-01 10:00:00') WHERE id = ?1",
             params![id1],
         )
         .unwrap();
 
-        let id2 = create_revision(&conn, proposal_id, "Second revision", "edit", None).unwrap();
+        let id2 = create_revision(&conn, proposal_id, "Second revision", "edit", None)
+            .unwrap()
+            .id;
         conn.execute(
             "UPDATE proposal_revisions SET created_at = datetime('2024-01-01 11:00:00') WHERE id = ?1",
             params![id2],
         )
         .unwrap();
 
-        let id3 = create_revision(&conn, proposal_id, "Third revision", "edit", None).unwrap();
+        let id3 = create_revision(&conn, proposal_id, "Third revision", "edit", None)
+            .unwrap()
+            .id;
         conn.execute(
             "UPDATE proposal_revisions SET created_at = datetime('2024-01-01 12:00:00') WHERE id = ?1",
             params![id3],
@@ -457,7 +851,9 @@ mod tests {
         let proposal_id = conn.last_insert_rowid();
 
         let long_content = "This is a very long content that should be stored completely in the database and not truncated when retrieved";
-        let revision_id = create_revision(&conn, proposal_id, long_content, "edit", None).unwrap();
+        let revision_id = create_revision(&conn, proposal_id, long_content, "edit", None)
+            .unwrap()
+            .id;
 
         let revision = get_revision(&conn, revision_id).unwrap();
         assert_eq!(revision.content, long_content);
@@ -499,12 +895,17 @@ mod tests {
         let proposal_id = conn.last_insert_rowid();
 
         // Create original revision
-        let original_id =
-            create_revision(&conn, proposal_id, "Original", "generation", None).unwrap();
+        let original_id = create_revision(&conn, proposal_id, "Original", "generation", None)
+            .unwrap()
+            .id;
+
+        create_revision(&conn, proposal_id, "Edited", "edit", None).unwrap();
 
         // Create restore revision
         let restored_id =
-            create_revision(&conn, proposal_id, "Original", "restore", Some(original_id)).unwrap();
+            create_revision(&conn, proposal_id, "Original", "restore", Some(original_id))
+                .unwrap()
+                .id;
 
         let restored = get_revision(&conn, restored_id).unwrap();
         assert_eq!(restored.revision_type, "restore");
@@ -597,8 +998,9 @@ mod tests {
         // Create 8 revisions with explicit timestamps
         let mut revision_ids = Vec::new();
         for i in 1..=8 {
-            let id =
-                create_revision(&conn, proposal_id, &format!("Rev {}", i), "edit", None).unwrap();
+            let id = create_revision(&conn, proposal_id, &format!("Rev {}", i), "edit", None)
+                .unwrap()
+                .id;
             conn.execute(
                 "UPDATE proposal_revisions SET created_at = datetime(?) WHERE id = ?",
                 params![format!("2024-01-01 {:02}:00:00", i), id],
@@ -708,8 +1110,9 @@ mod tests {
         // Create 6 revisions with explicit timestamps
         let mut revision_ids = Vec::new();
         for i in 1..=6 {
-            let id =
-                create_revision(&conn, proposal_id, &format!("Rev {}", i), "edit", None).unwrap();
+            let id = create_revision(&conn, proposal_id, &format!("Rev {}", i), "edit", None)
+                .unwrap()
+                .id;
             // Set explicit timestamps to ensure ordering
             conn.execute(
                 "UPDATE proposal_revisions SET created_at = datetime(?) WHERE id = ?",
@@ -775,8 +1178,9 @@ mod tests {
 
         // Create exactly 5 revisions (at threshold)
         for i in 1..=5 {
-            let id =
-                create_revision(&conn, proposal_id, &format!("Rev {}", i), "edit", None).unwrap();
+            let id = create_revision(&conn, proposal_id, &format!("Rev {}", i), "edit", None)
+                .unwrap()
+                .id;
             conn.execute(
                 "UPDATE proposal_revisions SET created_at = datetime(?) WHERE id = ?",
                 params![format!("2024-01-01 {:02}:00:00", i), id],
@@ -798,7 +1202,8 @@ mod tests {
             "restore",
             None, // FK reference not needed for this test
         )
-        .unwrap();
+        .unwrap()
+        .id;
         assert!(restore_id > 0);
 
         // Now trigger archiving (as lib.rs commands do after creating restore revision)
@@ -831,8 +1236,9 @@ mod tests {
 
         // Create 5 revisions
         for i in 1..=5 {
-            let id =
-                create_revision(&conn, proposal_id, &format!("Rev {}", i), "edit", None).unwrap();
+            let id = create_revision(&conn, proposal_id, &format!("Rev {}", i), "edit", None)
+                .unwrap()
+                .id;
             conn.execute(
                 "UPDATE proposal_revisions SET created_at = datetime(?) WHERE id = ?",
                 params![format!("2024-01-01 {:02}:00:00", i), id],
@@ -859,4 +1265,248 @@ mod tests {
         assert_eq!(get_revision_count(&conn, proposal_id).unwrap(), 5);
         assert_eq!(get_archived_revision_count(&conn, proposal_id).unwrap(), 3);
     }
+
+    #[test]
+    fn test_partial_regen_revision_on_migrated_schema() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = crate::db::Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        let proposal_id =
+            crate::db::queries::proposals::insert_proposal(&conn, "job", "v1", None).unwrap();
+
+        let first = create_revision(&conn, proposal_id, "v1", "generation", None)
+            .unwrap()
+            .id;
+        create_revision(&conn, proposal_id, "v1", "restore", Some(first)).unwrap();
+        let partial = create_revision(&conn, proposal_id, "v2", "partial_regen", None)
+            .unwrap()
+            .id;
+        assert_eq!(
+            get_revision(&conn, partial).unwrap().revision_type,
+            "partial_regen"
+        );
+        let rehumanized = create_revision(&conn, proposal_id, "v3", "rehumanize", None)
+            .unwrap()
+            .id;
+        assert_eq!(
+            get_revision(&conn, rehumanized).unwrap().revision_type,
+            "rehumanize"
+        );
+
+        // V45 and V58 rebuilt the table: the self-reference and CASCADE still hold
+        assert!(create_revision(&conn, proposal_id, "v4", "restore", Some(9999)).is_err());
+        conn.execute("DELETE FROM proposals WHERE id = ?1", [proposal_id])
+            .unwrap();
+        assert_eq!(get_revision_count(&conn, proposal_id).unwrap(), 0);
+    }
+
+    // ========================================================================
+    // Deduplication and delta storage (V51)
+    // ========================================================================
+
+    fn long_text(edit: &str) -> String {
+        format!(
+            "<p>Hi, I read your job post carefully.</p><p>{}</p><p>{}</p>",
+            edit,
+            "I have built several similar dashboards in React and Rust. ".repeat(10)
+        )
+    }
+
+    fn storage_of(conn: &Connection, revision_id: i64) -> (String, Option<i64>) {
+        conn.query_row(
+            "SELECT storage, keyframe_id FROM proposal_revisions WHERE id = ?1",
+            params![revision_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap()
+    }
+
+    fn insert_test_proposal(conn: &Connection) -> i64 {
+        conn.execute(
+            "INSERT INTO proposals (job_url, generated_text) VALUES ('http://test.com', 'Test')",
+            [],
+        )
+        .unwrap();
+        conn.last_insert_rowid()
+    }
+
+    #[test]
+    fn test_create_revision_deduplicates_identical_latest_content() {
+        let conn = setup_test_db();
+        let proposal_id = insert_test_proposal(&conn);
+
+        let first = create_revision(&conn, proposal_id, "Same text", "edit", None).unwrap();
+        assert!(!first.deduplicated);
+        let again = create_revision(&conn, proposal_id, "Same text", "edit", None).unwrap();
+        assert_eq!(
+            again,
+            CreatedRevision {
+                id: first.id,
+                deduplicated: true
+            }
+        );
+        assert_eq!(get_revision_count(&conn, proposal_id).unwrap(), 1);
+
+        // Only the latest revision counts: going back to older text is stored
+        create_revision(&conn, proposal_id, "Other text", "edit", None).unwrap();
+        let back = create_revision(&conn, proposal_id, "Same text", "edit", None).unwrap();
+        assert!(!back.deduplicated);
+        assert_eq!(get_revision_count(&conn, proposal_id).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_create_revision_deduplicates_against_rows_without_hash() {
+        let conn = setup_test_db();
+        let proposal_id = insert_test_proposal(&conn);
+        conn.execute(
+            "INSERT INTO proposal_revisions (proposal_id, content, revision_number) VALUES (?1, 'Legacy', 1)",
+            params![proposal_id],
+        )
+        .unwrap();
+        let legacy_id = conn.last_insert_rowid();
+
+        let created = create_revision(&conn, proposal_id, "Legacy", "edit", None).unwrap();
+        assert_eq!(created.id, legacy_id);
+        assert!(created.deduplicated);
+
+        assert_eq!(backfill_content_hashes(&conn).unwrap(), 1);
+        let hash: String = conn
+            .query_row(
+                "SELECT content_hash FROM proposal_revisions WHERE id = ?1",
+                params![legacy_id],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(hash, revision_content_hash("Legacy"));
+        assert_eq!(backfill_content_hashes(&conn).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_revisions_between_keyframes_are_stored_as_deltas() {
+        let conn = setup_test_db();
+        let proposal_id = insert_test_proposal(&conn);
+
+        let ids: Vec<i64> = (0..12)
+            .map(|i| {
+                create_revision(
+                    &conn,
+                    proposal_id,
+                    &long_text(&format!("Edit {}", i)),
+                    "edit",
+                    None,
+                )
+                .unwrap()
+                .id
+            })
+            .collect();
+
+        // Default interval 10: a keyframe, nine deltas, then the next keyframe
+        assert_eq!(storage_of(&conn, ids[0]), ("full".to_string(), None));
+        for id in &ids[1..10] {
+            assert_eq!(storage_of(&conn, *id), ("delta".to_string(), Some(ids[0])));
+        }
+        assert_eq!(storage_of(&conn, ids[10]), ("full".to_string(), None));
+        assert_eq!(
+            storage_of(&conn, ids[11]),
+            ("delta".to_string(), Some(ids[10]))
+        );
+
+        for (i, id) in ids.iter().enumerate() {
+            let revision = get_revision(&conn, *id).unwrap();
+            assert_eq!(revision.content, long_text(&format!("Edit {}", i)));
+            assert_eq!(revision.warning, None);
+        }
+        let summaries = get_revisions(&conn, proposal_id).unwrap();
+        assert!(summaries
+            .iter()
+            .all(|s| s.content_preview == "<p>Hi, I read your job post carefully.</p><p>Edit "));
+    }
+
+    #[test]
+    fn test_revision_delta_round_trips_multibyte_text() {
+        for (base, content) in [
+            ("café résumé", "café résumés"),
+            ("naïve", "naive"),
+            ("abc", "abc"),
+            ("", "new"),
+            ("old", ""),
+            ("aaa", "aaaa"),
+            ("日本語のテキスト", "日本のテキスト"),
+        ] {
+            let delta = RevisionDelta::between(base, content);
+            assert_eq!(
+                delta.apply(base).unwrap(),
+                content,
+                "{} -> {}",
+                base,
+                content
+            );
+        }
+    }
+
+    #[test]
+    fn test_corrupt_delta_falls_back_to_nearest_keyframe() {
+        let conn = setup_test_db();
+        let proposal_id = insert_test_proposal(&conn);
+        let keyframe = create_revision(&conn, proposal_id, &long_text("A"), "edit", None)
+            .unwrap()
+            .id;
+        let delta = create_revision(&conn, proposal_id, &long_text("B"), "edit", None)
+            .unwrap()
+            .id;
+        assert_eq!(storage_of(&conn, delta).0, "delta");
+
+        conn.execute(
+            "UPDATE proposal_revisions SET content = 'not a delta' WHERE id = ?1",
+            params![delta],
+        )
+        .unwrap();
+
+        let revision = get_revision(&conn, delta).unwrap();
+        assert_eq!(revision.content, long_text("A"));
+        assert!(revision.warning.is_some());
+        assert_eq!(get_revision(&conn, keyframe).unwrap().warning, None);
+    }
+
+    #[test]
+    fn test_archiving_keyframe_promotes_remaining_deltas() {
+        let mut conn = setup_test_db_with_archive();
+        let proposal_id = insert_test_proposal(&conn);
+
+        let ids: Vec<i64> = (0..7)
+            .map(|i| {
+                let id = create_revision(
+                    &conn,
+                    proposal_id,
+                    &long_text(&format!("Edit {}", i)),
+                    "edit",
+                    None,
+                )
+                .unwrap()
+                .id;
+                conn.execute(
+                    "UPDATE proposal_revisions SET created_at = datetime(?1) WHERE id = ?2",
+                    params![format!("2024-01-01 {:02}:00:00", i), id],
+                )
+                .unwrap();
+                id
+            })
+            .collect();
+
+        assert_eq!(archive_old_revisions(&mut conn, proposal_id).unwrap(), 2);
+
+        // The archive holds full text, never deltas
+        let archived = get_archived_revisions(&conn, proposal_id).unwrap();
+        assert_eq!(archived[0].content, long_text("Edit 0"));
+        assert_eq!(archived[1].content, long_text("Edit 1"));
+
+        // The keyframe left the table, so the remaining deltas were rewritten
+        for (i, id) in ids.iter().enumerate().skip(2) {
+            assert_eq!(storage_of(&conn, *id), ("full".to_string(), None));
+            assert_eq!(
+                get_revision(&conn, *id).unwrap().content,
+                long_text(&format!("Edit {}", i))
+            );
+        }
+    }
 }
@@ -103,7 +103,8 @@ pub fn update_override_status_with_feedback(
 /// Get successful overrides from the last 30 days (Task 4.1).
 ///
 /// Used by learning detection algorithm to count successful overrides
-/// within the threshold proximity window.
+/// within the threshold proximity window. Overrides whose proposal no longer
+/// exists are skipped even before `cleanup_orphaned_overrides` reclassifies them.
 ///
 /// # Returns
 /// List of successful overrides in the last 30 days, ordered by timestamp DESC
@@ -115,6 +116,7 @@ pub fn get_successful_overrides_last_30_days(
          FROM safety_overrides
          WHERE status = 'successful'
            AND timestamp >= datetime('now', '-30 days')
+           AND EXISTS (SELECT 1 FROM proposals p WHERE p.id = safety_overrides.proposal_id)
          ORDER BY timestamp DESC",
     )?;
 
@@ -181,6 +183,40 @@ pub fn get_pending_overrides_older_than_7_days(
     Ok(overrides)
 }
 
+/// Pending overrides older than 7 days with `id > after_id`, oldest id first, at most `limit`.
+/// Lets startup maintenance confirm overrides in short batches with an id cursor.
+pub fn get_pending_overrides_older_than_7_days_batch(
+    conn: &Connection,
+    after_id: i64,
+    limit: usize,
+) -> Result<Vec<SafetyOverride>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT so.id, so.proposal_id, so.timestamp, so.ai_score, so.threshold_at_override, so.status, so.user_feedback
+         FROM safety_overrides so
+         WHERE so.status = 'pending'
+           AND so.timestamp <= datetime('now', '-7 days')
+           AND so.id > ?1
+         ORDER BY so.id
+         LIMIT ?2",
+    )?;
+
+    let overrides = stmt
+        .query_map(params![after_id, limit as i64], |row| {
+            Ok(SafetyOverride {
+                id: row.get(0)?,
+                proposal_id: row.get(1)?,
+                timestamp: row.get(2)?,
+                ai_score: row.get(3)?,
+                threshold_at_override: row.get(4)?,
+                status: row.get(5)?,
+                user_feedback: row.get(6)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(overrides)
+}
+
 /// Check if a proposal exists (for success confirmation).
 pub fn proposal_exists(conn: &Connection, proposal_id: i64) -> Result<bool, rusqlite::Error> {
     let count: i32 = conn.query_row(
@@ -205,14 +241,76 @@ pub fn mark_proposal_overrides_unsuccessful(
     Ok(rows)
 }
 
+/// Result of `cleanup_orphaned_overrides`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanedOverrideCleanup {
+    /// Overrides whose proposal no longer exists
+    pub found: usize,
+    /// Pending or successful orphans reclassified as unsuccessful
+    pub marked_unsuccessful: usize,
+    /// Orphans removed (delete mode only)
+    pub deleted: usize,
+}
+
+/// Reclassify or delete overrides whose proposal no longer exists.
+///
+/// Proposals are normally deleted with their overrides (ON DELETE CASCADE), but
+/// rows deleted with foreign keys off leave orphans behind. A missing proposal
+/// means the override did not work out, which is how startup auto-confirmation
+/// classifies pending orphans, so by default pending and successful orphans are
+/// marked unsuccessful and kept for history and stats. With `delete` they are removed.
+pub fn cleanup_orphaned_overrides(
+    conn: &Connection,
+    delete: bool,
+) -> Result<OrphanedOverrideCleanup, rusqlite::Error> {
+    const ORPHANED: &str =
+        "NOT EXISTS (SELECT 1 FROM proposals p WHERE p.id = safety_overrides.proposal_id)";
+
+    let tx = conn.unchecked_transaction()?;
+    let found: i64 = tx.query_row(
+        &format!("SELECT COUNT(*) FROM safety_overrides WHERE {}", ORPHANED),
+        [],
+        |row| row.get(0),
+    )?;
+    let mut report = OrphanedOverrideCleanup {
+        found: found as usize,
+        ..Default::default()
+    };
+
+    if delete {
+        report.deleted = tx.execute(
+            &format!("DELETE FROM safety_overrides WHERE {}", ORPHANED),
+            [],
+        )?;
+    } else {
+        report.marked_unsuccessful = tx.execute(
+            &format!(
+                "UPDATE safety_overrides SET status = ?1
+                 WHERE status IN (?2, ?3) AND {}",
+                ORPHANED
+            ),
+            params![STATUS_UNSUCCESSFUL, STATUS_PENDING, STATUS_SUCCESSFUL],
+        )?;
+    }
+    tx.commit()?;
+
+    Ok(report)
+}
+
 /// Get overrides in the last 60 days (for downward adjustment detection).
 ///
 /// Used to check if user has not overridden any warnings recently,
 /// suggesting their threshold could be lowered.
 pub fn count_overrides_last_60_days(conn: &Connection) -> Result<usize, rusqlite::Error> {
+    count_overrides_last_n_days(conn, 60)
+}
+
+/// Count overrides recorded in the last `days` days (configurable inactivity window).
+pub fn count_overrides_last_n_days(conn: &Connection, days: u32) -> Result<usize, rusqlite::Error> {
     let count: i32 = conn.query_row(
-        "SELECT COUNT(*) FROM safety_overrides WHERE timestamp >= datetime('now', '-60 days')",
-        [],
+        "SELECT COUNT(*) FROM safety_overrides WHERE timestamp >= datetime('now', '-' || ?1 || ' days')",
+        params![days],
         |row| row.get(0),
     )?;
     Ok(count as usize)
@@ -331,6 +429,178 @@ pub fn get_pending_overrides_for_proposal(
     Ok(overrides)
 }
 
+/// Get every override recorded for a proposal, any status, newest first.
+pub fn get_overrides_for_proposal(
+    conn: &Connection,
+    proposal_id: i64,
+) -> Result<Vec<SafetyOverride>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id, proposal_id, timestamp, ai_score, threshold_at_override, status, user_feedback
+         FROM safety_overrides
+         WHERE proposal_id = ?1
+         ORDER BY timestamp DESC, id DESC",
+    )?;
+
+    let overrides = stmt
+        .query_map(params![proposal_id], |row| {
+            Ok(SafetyOverride {
+                id: row.get(0)?,
+                proposal_id: row.get(1)?,
+                timestamp: row.get(2)?,
+                ai_score: row.get(3)?,
+                threshold_at_override: row.get(4)?,
+                status: row.get(5)?,
+                user_feedback: row.get(6)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(overrides)
+}
+
+/// Shown in place of the proposal snippet when the proposal no longer exists
+pub const DELETED_PROPOSAL_PLACEHOLDER: &str = "(proposal deleted)";
+
+/// Override record joined to its proposal, for the override history view.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverrideHistoryItem {
+    pub id: i64,
+    pub proposal_id: i64,
+    pub timestamp: String,
+    pub ai_score: f32,
+    pub threshold_at_override: f32,
+    pub status: String,
+    pub user_feedback: Option<String>,
+    /// First 120 characters of the proposal, or `DELETED_PROPOSAL_PLACEHOLDER`
+    pub proposal_snippet: String,
+    pub proposal_deleted: bool,
+}
+
+/// Get a page of override history, newest first.
+///
+/// Overrides whose proposal was deleted are kept (they still count for
+/// stats, not for threshold learning) and carry a placeholder snippet.
+pub fn get_override_history(
+    conn: &Connection,
+    limit: u32,
+    offset: u32,
+) -> Result<Vec<OverrideHistoryItem>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT o.id, o.proposal_id, o.timestamp, o.ai_score, o.threshold_at_override,
+                o.status, o.user_feedback, SUBSTR(p.generated_text, 1, 120), p.id IS NULL
+         FROM safety_overrides o
+         LEFT JOIN proposals p ON p.id = o.proposal_id
+         ORDER BY o.timestamp DESC, o.id DESC
+         LIMIT ?1 OFFSET ?2",
+    )?;
+
+    let items = stmt
+        .query_map(params![limit, offset], |row| {
+            let snippet: Option<String> = row.get(7)?;
+            Ok(OverrideHistoryItem {
+                id: row.get(0)?,
+                proposal_id: row.get(1)?,
+                timestamp: row.get(2)?,
+                ai_score: row.get(3)?,
+                threshold_at_override: row.get(4)?,
+                status: row.get(5)?,
+                user_feedback: row.get(6)?,
+                proposal_snippet: snippet
+                    .unwrap_or_else(|| DELETED_PROPOSAL_PLACEHOLDER.to_string()),
+                proposal_deleted: row.get(8)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(items)
+}
+
+/// Override counts for one calendar month.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverrideMonthCount {
+    /// "YYYY-MM"
+    pub month: String,
+    pub total: usize,
+    pub successful: usize,
+    pub unsuccessful: usize,
+    pub pending: usize,
+}
+
+/// Aggregate override statistics.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverrideStats {
+    pub total: usize,
+    pub pending: usize,
+    pub successful: usize,
+    pub unsuccessful: usize,
+    /// Overrides whose proposal was deleted (included in all other counts)
+    pub orphaned: usize,
+    /// Mean of ai_score - threshold_at_override, None with no overrides
+    pub average_score_over_threshold: Option<f64>,
+    /// Oldest month first
+    pub monthly: Vec<OverrideMonthCount>,
+}
+
+/// Counts by status, average score-over-threshold delta, and a monthly histogram.
+pub fn get_override_stats(conn: &Connection) -> Result<OverrideStats, rusqlite::Error> {
+    let (total, pending, successful, unsuccessful, orphaned, average_delta) = conn.query_row(
+        "SELECT COUNT(*),
+                COALESCE(SUM(o.status = 'pending'), 0),
+                COALESCE(SUM(o.status = 'successful'), 0),
+                COALESCE(SUM(o.status = 'unsuccessful'), 0),
+                COALESCE(SUM(p.id IS NULL), 0),
+                AVG(o.ai_score - o.threshold_at_override)
+         FROM safety_overrides o
+         LEFT JOIN proposals p ON p.id = o.proposal_id",
+        [],
+        |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get::<_, i64>(3)?,
+                row.get::<_, i64>(4)?,
+                row.get::<_, Option<f64>>(5)?,
+            ))
+        },
+    )?;
+
+    let mut stmt = conn.prepare(
+        "SELECT strftime('%Y-%m', timestamp) AS month,
+                COUNT(*),
+                SUM(status = 'successful'),
+                SUM(status = 'unsuccessful'),
+                SUM(status = 'pending')
+         FROM safety_overrides
+         GROUP BY month
+         ORDER BY month",
+    )?;
+    let monthly = stmt
+        .query_map([], |row| {
+            Ok(OverrideMonthCount {
+                month: row.get(0)?,
+                total: row.get::<_, i64>(1)? as usize,
+                successful: row.get::<_, i64>(2)? as usize,
+                unsuccessful: row.get::<_, i64>(3)? as usize,
+                pending: row.get::<_, i64>(4)? as usize,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(OverrideStats {
+        total: total as usize,
+        pending: pending as usize,
+        successful: successful as usize,
+        unsuccessful: unsuccessful as usize,
+        orphaned: orphaned as usize,
+        average_score_over_threshold: average_delta,
+        monthly,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -525,6 +795,16 @@ mod tests {
 
         let count = count_overrides_last_60_days(&conn).unwrap();
         assert_eq!(count, 2);
+
+        // Configurable window: a 45-day-old override only counts in wider windows
+        let old = record_override(&conn, proposal_id, 188.0, 180.0).unwrap();
+        conn.execute(
+            "UPDATE safety_overrides SET timestamp = datetime('now', '-45 days') WHERE id = ?1",
+            params![old],
+        )
+        .unwrap();
+        assert_eq!(count_overrides_last_n_days(&conn, 30).unwrap(), 2);
+        assert_eq!(count_overrides_last_n_days(&conn, 60).unwrap(), 3);
     }
 
     // Test update_override_status_with_feedback
@@ -622,4 +902,110 @@ mod tests {
         assert!(!nearby_scores.contains(&195.0));
         assert!(!nearby_scores.contains(&250.0));
     }
+
+    #[test]
+    fn test_override_history_and_stats_keep_orphans() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let kept = insert_test_proposal(&conn);
+        let deleted = insert_test_proposal(&conn);
+        record_override(&conn, kept, 190.0, 180.0).unwrap();
+        let confirmed = record_override(&conn, kept, 200.0, 180.0).unwrap();
+        update_override_status(&conn, confirmed, STATUS_SUCCESSFUL).unwrap();
+        let orphan = record_override(&conn, deleted, 185.0, 180.0).unwrap();
+        conn.execute(
+            "UPDATE safety_overrides SET timestamp = '2025-01-15 10:00:00' WHERE id = ?1",
+            params![orphan],
+        )
+        .unwrap();
+
+        // Simulate a proposal deleted without the cascade (e.g. foreign keys off)
+        conn.execute_batch("PRAGMA foreign_keys = OFF").unwrap();
+        conn.execute("DELETE FROM proposals WHERE id = ?1", params![deleted])
+            .unwrap();
+        conn.execute_batch("PRAGMA foreign_keys = ON").unwrap();
+
+        let history = get_override_history(&conn, 10, 0).unwrap();
+        assert_eq!(history.len(), 3);
+        let orphan_item = history.iter().find(|o| o.id == orphan).unwrap();
+        assert!(orphan_item.proposal_deleted);
+        assert_eq!(orphan_item.proposal_snippet, DELETED_PROPOSAL_PLACEHOLDER);
+        assert_eq!(history[0].proposal_snippet, "proposal");
+        assert_eq!(get_override_history(&conn, 2, 2).unwrap().len(), 1);
+
+        let stats = get_override_stats(&conn).unwrap();
+        assert_eq!(stats.total, 3);
+        assert_eq!(stats.pending, 2);
+        assert_eq!(stats.successful, 1);
+        assert_eq!(stats.unsuccessful, 0);
+        assert_eq!(stats.orphaned, 1);
+        // (10 + 20 + 5) / 3
+        assert!((stats.average_score_over_threshold.unwrap() - 35.0 / 3.0).abs() < 1e-9);
+        assert_eq!(stats.monthly.len(), 2);
+        assert_eq!(stats.monthly[0].month, "2025-01");
+        assert_eq!(stats.monthly[0].pending, 1);
+        assert_eq!(stats.monthly[1].total, 2);
+
+        let empty = create_test_db();
+        let empty_conn = empty.conn.lock().unwrap();
+        let stats = get_override_stats(&empty_conn).unwrap();
+        assert_eq!(stats.total, 0);
+        assert_eq!(stats.average_score_over_threshold, None);
+        assert!(stats.monthly.is_empty());
+    }
+
+    #[test]
+    fn test_cleanup_orphaned_overrides() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let kept = insert_test_proposal(&conn);
+        let deleted = insert_test_proposal(&conn);
+        let live = record_override(&conn, kept, 185.0, 180.0).unwrap();
+        update_override_status(&conn, live, STATUS_SUCCESSFUL).unwrap();
+        let orphan_pending = record_override(&conn, deleted, 182.0, 180.0).unwrap();
+        let orphan_successful = record_override(&conn, deleted, 184.0, 180.0).unwrap();
+        update_override_status(&conn, orphan_successful, STATUS_SUCCESSFUL).unwrap();
+
+        conn.execute_batch("PRAGMA foreign_keys = OFF").unwrap();
+        conn.execute("DELETE FROM proposals WHERE id = ?1", params![deleted])
+            .unwrap();
+        conn.execute_batch("PRAGMA foreign_keys = ON").unwrap();
+
+        // Learning ignores the orphan before any cleanup
+        let successful = get_successful_overrides_last_30_days(&conn).unwrap();
+        assert_eq!(
+            successful.iter().map(|o| o.id).collect::<Vec<_>>(),
+            vec![live]
+        );
+
+        // Same classification as startup auto-confirm: missing proposal = unsuccessful
+        let report = cleanup_orphaned_overrides(&conn, false).unwrap();
+        assert_eq!(
+            report,
+            OrphanedOverrideCleanup {
+                found: 2,
+                marked_unsuccessful: 2,
+                deleted: 0,
+            }
+        );
+        for id in [orphan_pending, orphan_successful] {
+            let status = get_override(&conn, id).unwrap().unwrap().status;
+            assert_eq!(status, STATUS_UNSUCCESSFUL);
+        }
+        assert_eq!(
+            get_override(&conn, live).unwrap().unwrap().status,
+            STATUS_SUCCESSFUL
+        );
+
+        // Running again finds the orphans but has nothing left to reclassify
+        let report = cleanup_orphaned_overrides(&conn, false).unwrap();
+        assert_eq!(report.found, 2);
+        assert_eq!(report.marked_unsuccessful, 0);
+
+        let report = cleanup_orphaned_overrides(&conn, true).unwrap();
+        assert_eq!(report.deleted, 2);
+        assert_eq!(get_all_overrides(&conn).unwrap().len(), 1);
+    }
 }
@@ -0,0 +1,96 @@
+//! Score guard override queries.
+//!
+//! Each row is a generation the user ran for a job scored below the
+//! `min_score_to_generate` floor. Kept for analytics on whether low-score
+//! generations ever convert (join on job_post_id).
+
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+/// A score guard override record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreGuardOverride {
+    pub id: i64,
+    pub job_post_id: i64,
+    pub overall_score: f64,
+    pub floor_at_override: f64,
+    pub timestamp: String,
+}
+
+/// Record that the user generated past the score floor for `job_post_id`.
+///
+/// # Returns
+/// The ID of the newly created override record
+pub fn record_override(
+    conn: &Connection,
+    job_post_id: i64,
+    overall_score: f64,
+    floor: f64,
+) -> Result<i64, rusqlite::Error> {
+    conn.execute(
+        "INSERT INTO score_guard_overrides (job_post_id, overall_score, floor_at_override)
+         VALUES (?1, ?2, ?3)",
+        params![job_post_id, overall_score, floor],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+/// All score guard overrides, newest first.
+pub fn get_overrides(conn: &Connection) -> Result<Vec<ScoreGuardOverride>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id, job_post_id, overall_score, floor_at_override, timestamp
+         FROM score_guard_overrides
+         ORDER BY timestamp DESC, id DESC",
+    )?;
+
+    let overrides = stmt
+        .query_map([], |row| {
+            Ok(ScoreGuardOverride {
+                id: row.get(0)?,
+                job_post_id: row.get(1)?,
+                overall_score: row.get(2)?,
+                floor_at_override: row.get(3)?,
+                timestamp: row.get(4)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(overrides)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_record_and_list_overrides() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO job_posts (raw_content) VALUES ('Low-budget scraping job')",
+            [],
+        )
+        .unwrap();
+        let job_post_id = conn.last_insert_rowid();
+
+        let first = record_override(&conn, job_post_id, 32.5, 50.0).unwrap();
+        let second = record_override(&conn, job_post_id, 41.0, 45.0).unwrap();
+
+        let overrides = get_overrides(&conn).unwrap();
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides[0].id, second);
+        assert_eq!(overrides[1].id, first);
+        assert_eq!(overrides[1].overall_score, 32.5);
+        assert_eq!(overrides[1].floor_at_override, 50.0);
+
+        // Deleting the job post removes its overrides
+        conn.execute("DELETE FROM job_posts WHERE id = ?1", [job_post_id])
+            .unwrap();
+        assert!(get_overrides(&conn).unwrap().is_empty());
+    }
+}
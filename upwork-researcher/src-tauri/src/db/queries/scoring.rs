@@ -15,6 +15,9 @@ pub struct JobScore {
     pub overall_score: Option<f64>,
     pub color_flag: String, // Story 4b.5: "green", "yellow", "red", or "gray"
     pub calculated_at: String,
+    /// Scored by the bulk backfill without client quality (cleared by full analysis)
+    #[serde(default)]
+    pub partially_scored: bool,
 }
 
 /// Calculate skills match percentage between user skills and job skills (AC-1, AC-3)
@@ -99,6 +102,22 @@ pub fn store_skills_match(
     Ok(())
 }
 
+/// Overwrite skills match on an existing job_scores row, clearing it when `None`
+/// (used by recalculation after the user's skills change; never inserts a row)
+pub fn update_skills_match(
+    conn: &Connection,
+    job_post_id: i64,
+    percentage: Option<f64>,
+) -> Result<(), String> {
+    conn.execute(
+        "UPDATE job_scores SET skills_match_percentage = ?2, calculated_at = CURRENT_TIMESTAMP
+         WHERE job_post_id = ?1",
+        params![job_post_id, percentage],
+    )
+    .map_err(|e| format!("Failed to update skills match: {}", e))?;
+    Ok(())
+}
+
 /// Store client quality score in job_scores table (Story 4b.3 AC-1)
 /// Uses INSERT OR UPDATE (ON CONFLICT) for upsert behavior
 /// Preserves existing skills_match_percentage if row already exists
@@ -113,6 +132,7 @@ pub fn store_client_quality_score(
              VALUES (?1, ?2)
              ON CONFLICT(job_post_id) DO UPDATE SET
                 client_quality_score = excluded.client_quality_score,
+                partially_scored = 0,
                 calculated_at = CURRENT_TIMESTAMP",
             params![job_post_id, s],
         )
@@ -171,7 +191,8 @@ pub fn get_job_score(conn: &Connection, job_post_id: i64) -> Result<Option<JobSc
     let mut stmt = conn
         .prepare(
             "SELECT job_post_id, skills_match_percentage, client_quality_score,
-                    budget_alignment_score, overall_score, color_flag, calculated_at
+                    budget_alignment_score, overall_score, color_flag, calculated_at,
+                    partially_scored
              FROM job_scores WHERE job_post_id = ?",
         )
         .map_err(|e| format!("Failed to prepare job score query: {}", e))?;
@@ -185,6 +206,7 @@ pub fn get_job_score(conn: &Connection, job_post_id: i64) -> Result<Option<JobSc
             overall_score: row.get(4)?,
             color_flag: row.get(5)?,
             calculated_at: row.get(6)?,
+            partially_scored: row.get(7)?,
         })
     });
 
@@ -195,6 +217,67 @@ pub fn get_job_score(conn: &Connection, job_post_id: i64) -> Result<Option<JobSc
     }
 }
 
+/// Job posts without a job_scores row, oldest first (bulk score backfill)
+pub fn list_unscored_job_post_ids(
+    conn: &Connection,
+    limit: Option<usize>,
+) -> Result<Vec<i64>, String> {
+    let limit = limit.map_or(-1, |l| l as i64); // SQLite: negative LIMIT = no limit
+    let mut stmt = conn
+        .prepare(
+            "SELECT jp.id FROM job_posts jp
+             LEFT JOIN job_scores js ON js.job_post_id = jp.id
+             WHERE js.id IS NULL
+             ORDER BY jp.id ASC
+             LIMIT ?1",
+        )
+        .map_err(|e| format!("Failed to prepare unscored jobs query: {}", e))?;
+
+    let ids = stmt
+        .query_map([limit], |row| row.get(0))
+        .map_err(|e| format!("Failed to query unscored jobs: {}", e))?
+        .collect::<Result<Vec<i64>, _>>()
+        .map_err(|e| format!("Failed to collect unscored jobs: {}", e))?;
+    Ok(ids)
+}
+
+/// Number of job posts that already have a job_scores row
+pub fn count_scored_job_posts(conn: &Connection) -> Result<usize, String> {
+    conn.query_row("SELECT COUNT(*) FROM job_scores", [], |row| {
+        row.get::<_, i64>(0)
+    })
+    .map(|n| n as usize)
+    .map_err(|e| format!("Failed to count scored jobs: {}", e))
+}
+
+/// Store a locally computed score without client quality, flagged `partially_scored`.
+/// Never overwrites an existing row; returns false if the job was scored meanwhile.
+pub fn insert_partial_score(
+    conn: &Connection,
+    job_post_id: i64,
+    skills_match: Option<f64>,
+    budget_alignment: Option<i32>,
+    overall_score: Option<f64>,
+    color_flag: &str,
+) -> Result<bool, String> {
+    let inserted = conn
+        .execute(
+            "INSERT INTO job_scores (job_post_id, skills_match_percentage, budget_alignment_score,
+                                     overall_score, color_flag, partially_scored)
+             VALUES (?1, ?2, ?3, ?4, ?5, 1)
+             ON CONFLICT(job_post_id) DO NOTHING",
+            params![
+                job_post_id,
+                skills_match,
+                budget_alignment,
+                overall_score,
+                color_flag
+            ],
+        )
+        .map_err(|e| format!("Failed to store partial score: {}", e))?;
+    Ok(inserted > 0)
+}
+
 /// Get matched and missing skills for scoring breakdown (Story 4b.6)
 ///
 /// Returns (matched_skills, missing_skills, total_job_skills_count)
@@ -285,6 +368,7 @@ mod tests {
                 overall_score REAL,
                 color_flag TEXT DEFAULT 'gray',
                 calculated_at TEXT DEFAULT CURRENT_TIMESTAMP,
+                partially_scored INTEGER NOT NULL DEFAULT 0,
                 FOREIGN KEY (job_post_id) REFERENCES job_posts(id) ON DELETE CASCADE
             );
             CREATE INDEX idx_job_scores_job_post_id ON job_scores(job_post_id);
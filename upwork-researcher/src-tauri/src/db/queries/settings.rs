@@ -82,6 +82,12 @@ pub fn increment_proposals_edited(conn: &Connection) -> Result<i32, rusqlite::Er
     Ok(new_count)
 }
 
+/// Reset the proposals edited count to 0 after a voice (re)calibration,
+/// so the recalibration suggestion counts edits made since then.
+pub fn reset_proposals_edited(conn: &Connection) -> Result<(), rusqlite::Error> {
+    set_setting(conn, "proposals_edited_count", "0")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -254,4 +260,18 @@ mod tests {
         let result = get_proposals_edited_count(&conn);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_reset_proposals_edited() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        increment_proposals_edited(&conn).unwrap();
+        increment_proposals_edited(&conn).unwrap();
+        reset_proposals_edited(&conn).unwrap();
+        assert_eq!(get_proposals_edited_count(&conn).unwrap(), 0);
+
+        // Counting resumes from zero
+        assert_eq!(increment_proposals_edited(&conn).unwrap(), 1);
+    }
 }
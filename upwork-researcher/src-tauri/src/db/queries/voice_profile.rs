@@ -2,12 +2,32 @@
 //!
 //! Handles CRUD operations for voice_profiles table with UPSERT support.
 //! All operations use prepared statements for safety.
+//!
+//! A user can keep several named profiles (e.g. "technical", "marketing"). The
+//! `user_id` column holds the profile name; the `active_voice_profile` setting
+//! selects the one used for generation, "default" until the user picks another.
 
 use crate::voice::{CalibrationSource, StructurePreference, VoiceProfile};
 use rusqlite::Result;
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
 
+/// Profile used before the user creates or selects another
+pub const DEFAULT_PROFILE_NAME: &str = "default";
+pub const ACTIVE_VOICE_PROFILE_SETTING: &str = "active_voice_profile";
+pub const MAX_PROFILE_NAME_CHARS: usize = 50;
+
+/// One profile in the profile picker
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceProfileSummary {
+    pub name: String,
+    pub calibration_source: String,
+    pub sample_count: i32,
+    pub updated_at: String,
+    pub is_active: bool,
+}
+
 /// Database row representation of VoiceProfile
 ///
 /// Maps between frontend VoiceProfile struct and database schema.
@@ -55,6 +75,27 @@ impl VoiceProfileRow {
         }
     }
 
+    /// Neutral, uncalibrated parameters for a new profile (mid-scale scores,
+    /// mostly paragraphs). Calibration or the sliders fill it in later.
+    pub fn with_defaults(user_id: &str) -> Self {
+        Self {
+            id: None,
+            user_id: user_id.to_string(),
+            tone_score: 5.0,
+            avg_sentence_length: 15.0,
+            vocabulary_complexity: 8.0,
+            structure_paragraphs_pct: 70,
+            structure_bullets_pct: 30,
+            technical_depth: 5.0,
+            length_preference: 5.0,
+            common_phrases: vec![],
+            sample_count: 0,
+            calibration_source: "Implicit".to_string(), // Must match CHECK constraint enum
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
     /// Convert back to VoiceProfile for frontend
     pub fn to_voice_profile(&self) -> VoiceProfile {
         VoiceProfile {
@@ -190,6 +231,91 @@ pub fn get_voice_profile(conn: &Connection, user_id: &str) -> Result<Option<Voic
     }
 }
 
+/// Version of the user's voice profile (its `updated_at`), None if not calibrated.
+/// Recorded in generation metadata to tell which calibration a proposal used.
+pub fn get_voice_profile_version(conn: &Connection, user_id: &str) -> Result<Option<String>> {
+    conn.query_row(
+        "SELECT updated_at FROM voice_profiles WHERE user_id = ?1",
+        params![user_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map(Option::flatten)
+}
+
+/// Trim a profile name and check it's usable; the error is user-facing
+pub fn validate_profile_name(name: &str) -> std::result::Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Voice profile name cannot be empty".to_string());
+    }
+    if name.chars().count() > MAX_PROFILE_NAME_CHARS {
+        return Err(format!(
+            "Voice profile name must be at most {} characters",
+            MAX_PROFILE_NAME_CHARS
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// Name of the profile used for generation (`active_voice_profile` setting)
+pub fn active_profile_name(conn: &Connection) -> String {
+    crate::settings::schema::get_typed(conn, crate::settings::schema::Key::ActiveVoiceProfile)
+        .unwrap_or_else(|_| DEFAULT_PROFILE_NAME.to_string())
+}
+
+/// The explicitly requested profile if given, else the active one
+pub fn resolve_profile_name(conn: &Connection, requested: Option<&str>) -> String {
+    match requested.map(str::trim).filter(|name| !name.is_empty()) {
+        Some(name) => name.to_string(),
+        None => active_profile_name(conn),
+    }
+}
+
+/// All profiles, oldest first, with the active one flagged
+pub fn list_voice_profiles(conn: &Connection) -> Result<Vec<VoiceProfileSummary>> {
+    let active = active_profile_name(conn);
+    let mut stmt = conn.prepare(
+        "SELECT user_id, calibration_source, sample_count, updated_at
+         FROM voice_profiles
+         ORDER BY created_at, id",
+    )?;
+    let profiles = stmt
+        .query_map([], |row| {
+            let name: String = row.get(0)?;
+            Ok(VoiceProfileSummary {
+                is_active: name == active,
+                name,
+                calibration_source: row.get(1)?,
+                sample_count: row.get(2)?,
+                updated_at: row.get(3)?,
+            })
+        })?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(profiles)
+}
+
+/// Rename a profile, keeping the active selection and the profile name recorded
+/// in generation metadata in step. Returns false if `old_name` doesn't exist.
+pub fn rename_voice_profile(conn: &Connection, old_name: &str, new_name: &str) -> Result<bool> {
+    let tx = conn.unchecked_transaction()?;
+    let renamed = tx.execute(
+        "UPDATE voice_profiles SET user_id = ?2 WHERE user_id = ?1",
+        params![old_name, new_name],
+    )? > 0;
+    if renamed {
+        tx.execute(
+            "UPDATE generation_metadata SET voice_profile_name = ?2 WHERE voice_profile_name = ?1",
+            params![old_name, new_name],
+        )?;
+        if active_profile_name(&tx) == old_name {
+            crate::db::queries::settings::set_setting(&tx, ACTIVE_VOICE_PROFILE_SETTING, new_name)?;
+        }
+    }
+    tx.commit()?;
+    Ok(renamed)
+}
+
 /// Delete voice profile (for reset/recalibration)
 ///
 /// Story 5-5b: AC-4 (Tauri commands exposed)
@@ -513,4 +639,52 @@ mod tests {
             CalibrationSource::GoldenSet
         ));
     }
+
+    #[test]
+    fn test_list_and_rename_profiles_follow_active_selection() {
+        let (_temp_dir, conn) = create_test_db();
+        assert!(list_voice_profiles(&conn).unwrap().is_empty());
+        assert_eq!(active_profile_name(&conn), DEFAULT_PROFILE_NAME);
+
+        save_voice_profile(&conn, &create_test_profile("default")).unwrap();
+        save_voice_profile(&conn, &VoiceProfileRow::with_defaults("technical")).unwrap();
+        let profiles = list_voice_profiles(&conn).unwrap();
+        assert_eq!(profiles.len(), 2);
+        assert!(profiles[0].is_active && profiles[0].name == "default");
+        assert!(!profiles[1].is_active);
+        assert_eq!(profiles[1].calibration_source, "Implicit");
+
+        assert_eq!(
+            resolve_profile_name(&conn, Some(" technical ")),
+            "technical"
+        );
+        assert_eq!(resolve_profile_name(&conn, Some("")), "default");
+        assert!(validate_profile_name("   ").is_err());
+        assert!(validate_profile_name(&"x".repeat(MAX_PROFILE_NAME_CHARS + 1)).is_err());
+
+        // Renaming the active profile keeps it active and relabels its proposals
+        conn.execute(
+            "INSERT INTO proposals (job_content, generated_text) VALUES ('job', 'text')",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO generation_metadata (proposal_id, model, humanization_intensity, voice_profile_name)
+             VALUES (last_insert_rowid(), 'model', 'medium', 'default')",
+            [],
+        )
+        .unwrap();
+        assert!(rename_voice_profile(&conn, "default", "marketing").unwrap());
+        assert!(!rename_voice_profile(&conn, "missing", "other").unwrap());
+        assert_eq!(active_profile_name(&conn), "marketing");
+        assert!(get_voice_profile(&conn, "marketing").unwrap().is_some());
+        let recorded: String = conn
+            .query_row(
+                "SELECT voice_profile_name FROM generation_metadata",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(recorded, "marketing");
+    }
 }
@@ -0,0 +1,637 @@
+//! Weekly digest: one summary of a week's proposals, outcomes and job imports
+//!
+//! Weeks are ISO weeks in UTC: Monday 00:00:00 UTC up to (not including) the next
+//! Monday, compared against the UTC `datetime('now')` timestamps SQLite stores.
+//! Digests of completed weeks are kept in `digests` and served from there unless
+//! `force_regenerate` is set; the current week is still running, so its digest is
+//! always computed fresh and never stored.
+//!
+//! There is no spend figure: the app keeps no persistent record of token usage
+//! (`api_quota` only tracks the live rate-limit window).
+//!
+//! With `digest_enabled` on, startup maintenance prepares last week's digest once
+//! and emits `digest:ready`.
+
+use crate::db::queries::digests;
+use crate::db::queries::proposals::{percentage, StrategyPerformance};
+use crate::settings::schema::{get_typed, Key};
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, Utc};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+/// Timestamp format of SQLite's `datetime('now')`
+const SQLITE_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Oldest week a digest can be asked for (about ten years back)
+pub const MAX_WEEKS_BACK: i32 = 520;
+
+/// Jobs imported in the week by score color; `unscored` have no score yet
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreDistribution {
+    pub green: i64,
+    pub yellow: i64,
+    pub red: i64,
+    pub gray: i64,
+    pub unscored: i64,
+}
+
+/// One week's activity. All times are UTC in SQLite's "YYYY-MM-DD HH:MM:SS" form;
+/// the week runs from `week_start` (Monday 00:00:00 UTC) up to, not including,
+/// `week_end` (the following Monday).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeeklyDigest {
+    /// ISO week, e.g. "2026-W41" (ISO week-numbering year)
+    pub week_label: String,
+    pub week_start: String,
+    pub week_end: String,
+    /// False for the current week, whose numbers can still change
+    pub complete: bool,
+    pub generated_at: String,
+    /// Non-draft proposals created in the week
+    pub proposals_generated: i64,
+    /// Proposals copied to the clipboard in the week
+    pub proposals_copied: i64,
+    /// Outcomes (other than pending or submitted) recorded in the week
+    pub outcomes_recorded: i64,
+    /// Recorded outcomes that were a response, interview or hire
+    pub positive_outcomes: i64,
+    /// positive_outcomes / outcomes_recorded, in percent
+    pub response_rate: f64,
+    pub prior_week_response_rate: f64,
+    /// response_rate minus prior_week_response_rate, in percentage points
+    pub response_rate_change: f64,
+    /// Hook strategy with the best response rate among outcomes recorded this week
+    pub top_strategy: Option<StrategyPerformance>,
+    pub jobs_imported: i64,
+    pub job_scores: ScoreDistribution,
+    /// Safety warnings overridden in the week
+    pub safety_overrides: i64,
+}
+
+/// What `generate_weekly_digest` returns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestResult {
+    pub digest: WeeklyDigest,
+    pub markdown: String,
+    /// True when served from `digests` instead of computed now
+    pub cached: bool,
+}
+
+/// Start and end (exclusive) of the ISO week `week_offset` weeks from the one
+/// containing `now`. 0 is the current week, -1 last week; future weeks are rejected.
+pub fn week_bounds(
+    now: DateTime<Utc>,
+    week_offset: i32,
+) -> Result<(NaiveDateTime, NaiveDateTime), String> {
+    if week_offset > 0 {
+        return Err("week_offset must be 0 (this week) or negative (past weeks)".to_string());
+    }
+    if week_offset < -MAX_WEEKS_BACK {
+        return Err(format!(
+            "week_offset must not be earlier than -{}",
+            MAX_WEEKS_BACK
+        ));
+    }
+    let today = now.date_naive();
+    let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let start = (monday + Duration::weeks(week_offset as i64))
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is a valid time");
+    Ok((start, start + Duration::weeks(1)))
+}
+
+/// "YYYY-Www" for the ISO week starting at `week_start`
+pub fn week_label(week_start: NaiveDateTime) -> String {
+    let week = week_start.date().iso_week();
+    format!("{}-W{:02}", week.year(), week.week())
+}
+
+fn format_timestamp(t: NaiveDateTime) -> String {
+    t.format(SQLITE_DATETIME_FORMAT).to_string()
+}
+
+/// Run a `SELECT COUNT(*)` query whose ?1 and ?2 are the week's start and end
+fn count_between(
+    conn: &Connection,
+    sql: &str,
+    start: &str,
+    end: &str,
+) -> Result<i64, rusqlite::Error> {
+    conn.query_row(sql, params![start, end], |row| row.get(0))
+}
+
+/// (recorded, positive) outcomes whose outcome_updated_at falls in [start, end)
+fn outcome_counts(
+    conn: &Connection,
+    start: &str,
+    end: &str,
+) -> Result<(i64, i64), rusqlite::Error> {
+    conn.query_row(
+        "SELECT COUNT(*),
+            COALESCE(SUM(CASE WHEN outcome_status IN ('response_received','interview','hired') THEN 1 ELSE 0 END), 0)
+         FROM proposals
+         WHERE status NOT IN ('draft', 'discarded')
+           AND outcome_status NOT IN ('pending', 'submitted')
+           AND outcome_updated_at >= ?1 AND outcome_updated_at < ?2",
+        params![start, end],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )
+}
+
+fn top_strategy(
+    conn: &Connection,
+    start: &str,
+    end: &str,
+) -> Result<Option<StrategyPerformance>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT COALESCE(hook_strategy_id, 'none') AS strategy,
+            COUNT(*) AS total,
+            SUM(CASE WHEN outcome_status IN ('response_received','interview','hired') THEN 1 ELSE 0 END) AS positive
+         FROM proposals
+         WHERE status NOT IN ('draft', 'discarded')
+           AND outcome_status NOT IN ('pending', 'submitted')
+           AND outcome_updated_at >= ?1 AND outcome_updated_at < ?2
+         GROUP BY strategy
+         HAVING positive > 0
+         ORDER BY positive * 1.0 / total DESC, total DESC, strategy
+         LIMIT 1",
+    )?;
+    let mut rows = stmt.query_map(params![start, end], |row| {
+        let total: i64 = row.get(1)?;
+        let positive: i64 = row.get(2)?;
+        Ok(StrategyPerformance {
+            strategy: row.get(0)?,
+            total,
+            positive,
+            response_rate: percentage(positive, total),
+        })
+    })?;
+    rows.next().transpose()
+}
+
+fn score_distribution(
+    conn: &Connection,
+    start: &str,
+    end: &str,
+) -> Result<ScoreDistribution, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT js.color_flag, COUNT(*)
+         FROM job_posts jp
+         LEFT JOIN job_scores js ON js.job_post_id = jp.id
+         WHERE jp.created_at >= ?1 AND jp.created_at < ?2
+         GROUP BY js.color_flag",
+    )?;
+    let rows = stmt.query_map(params![start, end], |row| {
+        Ok((row.get::<_, Option<String>>(0)?, row.get::<_, i64>(1)?))
+    })?;
+
+    let mut distribution = ScoreDistribution::default();
+    for row in rows {
+        let (flag, count) = row?;
+        match flag.as_deref() {
+            Some("green") => distribution.green += count,
+            Some("yellow") => distribution.yellow += count,
+            Some("red") => distribution.red += count,
+            Some("gray") => distribution.gray += count,
+            _ => distribution.unscored += count,
+        }
+    }
+    Ok(distribution)
+}
+
+/// Compute the digest for the week `week_offset` weeks from the one containing `now`
+pub fn compute_weekly_digest(
+    conn: &Connection,
+    week_offset: i32,
+    now: DateTime<Utc>,
+) -> Result<WeeklyDigest, String> {
+    let (start, end) = week_bounds(now, week_offset)?;
+    let prior_start = start - Duration::weeks(1);
+    let (start_s, end_s, prior_s) = (
+        format_timestamp(start),
+        format_timestamp(end),
+        format_timestamp(prior_start),
+    );
+    let db_err = |e: rusqlite::Error| format!("Failed to compute weekly digest: {}", e);
+
+    let proposals_generated = count_between(
+        conn,
+        "SELECT COUNT(*) FROM proposals
+         WHERE status NOT IN ('draft', 'discarded') AND created_at >= ?1 AND created_at < ?2",
+        &start_s,
+        &end_s,
+    )
+    .map_err(db_err)?;
+    let proposals_copied = count_between(
+        conn,
+        "SELECT COUNT(*) FROM proposals WHERE copied_at >= ?1 AND copied_at < ?2",
+        &start_s,
+        &end_s,
+    )
+    .map_err(db_err)?;
+    let (outcomes_recorded, positive_outcomes) =
+        outcome_counts(conn, &start_s, &end_s).map_err(db_err)?;
+    let (prior_recorded, prior_positive) =
+        outcome_counts(conn, &prior_s, &start_s).map_err(db_err)?;
+    let jobs_imported = count_between(
+        conn,
+        "SELECT COUNT(*) FROM job_posts WHERE created_at >= ?1 AND created_at < ?2",
+        &start_s,
+        &end_s,
+    )
+    .map_err(db_err)?;
+    let safety_overrides = count_between(
+        conn,
+        "SELECT COUNT(*) FROM safety_overrides WHERE timestamp >= ?1 AND timestamp < ?2",
+        &start_s,
+        &end_s,
+    )
+    .map_err(db_err)?;
+
+    let response_rate = percentage(positive_outcomes, outcomes_recorded);
+    let prior_week_response_rate = percentage(prior_positive, prior_recorded);
+
+    Ok(WeeklyDigest {
+        week_label: week_label(start),
+        week_start: start_s.clone(),
+        week_end: end_s.clone(),
+        complete: end <= now.naive_utc(),
+        generated_at: format_timestamp(now.naive_utc()),
+        proposals_generated,
+        proposals_copied,
+        outcomes_recorded,
+        positive_outcomes,
+        response_rate,
+        prior_week_response_rate,
+        response_rate_change: response_rate - prior_week_response_rate,
+        top_strategy: top_strategy(conn, &start_s, &end_s).map_err(db_err)?,
+        jobs_imported,
+        job_scores: score_distribution(conn, &start_s, &end_s).map_err(db_err)?,
+        safety_overrides,
+    })
+}
+
+/// Markdown summary of a digest, ready to paste or show as-is
+pub fn render_markdown(digest: &WeeklyDigest) -> String {
+    let dates = match (
+        NaiveDateTime::parse_from_str(&digest.week_start, SQLITE_DATETIME_FORMAT),
+        NaiveDateTime::parse_from_str(&digest.week_end, SQLITE_DATETIME_FORMAT),
+    ) {
+        (Ok(start), Ok(end)) => format!(
+            "{} – {}",
+            start.format("%b %-d"),
+            (end - Duration::days(1)).format("%b %-d, %Y")
+        ),
+        _ => digest.week_start.clone(),
+    };
+    let in_progress = if digest.complete {
+        ""
+    } else {
+        " (in progress)"
+    };
+
+    let mut md = format!(
+        "# Weekly digest {}{}\n\n_{} (UTC)_\n\n",
+        digest.week_label, in_progress, dates
+    );
+    md.push_str("## Proposals\n\n");
+    md.push_str(&format!(
+        "- Generated: {}\n- Copied: {}\n\n",
+        digest.proposals_generated, digest.proposals_copied
+    ));
+
+    md.push_str("## Outcomes\n\n");
+    md.push_str(&format!(
+        "- Recorded: {} ({} positive)\n",
+        digest.outcomes_recorded, digest.positive_outcomes
+    ));
+    md.push_str(&format!(
+        "- Response rate: {:.1}% (prior week {:.1}%, {:+.1} pts)\n",
+        digest.response_rate, digest.prior_week_response_rate, digest.response_rate_change
+    ));
+    match &digest.top_strategy {
+        Some(top) => md.push_str(&format!(
+            "- Top hook strategy: {} ({}/{} positive, {:.1}%)\n\n",
+            top.strategy, top.positive, top.total, top.response_rate
+        )),
+        None => md.push_str("- Top hook strategy: none with a positive outcome\n\n"),
+    }
+
+    let scores = &digest.job_scores;
+    md.push_str("## Jobs\n\n");
+    md.push_str(&format!("- Imported: {}\n", digest.jobs_imported));
+    md.push_str(&format!(
+        "- Scores: {} green, {} yellow, {} red, {} gray, {} unscored\n\n",
+        scores.green, scores.yellow, scores.red, scores.gray, scores.unscored
+    ));
+
+    md.push_str("## Safety\n\n");
+    md.push_str(&format!(
+        "- Overridden warnings: {}\n",
+        digest.safety_overrides
+    ));
+    md
+}
+
+/// Digest for `week_offset`, served from `digests` when stored and not forced.
+/// Completed weeks are stored after computing; the current week never is.
+pub fn generate_weekly_digest(
+    conn: &Connection,
+    week_offset: i32,
+    force_regenerate: bool,
+    now: DateTime<Utc>,
+) -> Result<DigestResult, String> {
+    let (start, _) = week_bounds(now, week_offset)?;
+    let label = week_label(start);
+
+    if !force_regenerate {
+        let stored = digests::get_digest(conn, &label)
+            .map_err(|e| format!("Failed to read stored digest: {}", e))?;
+        // A digest that no longer deserializes (older shape) is recomputed
+        if let Some(stored) = stored {
+            if let Ok(digest) = serde_json::from_str::<WeeklyDigest>(&stored.digest_json) {
+                return Ok(DigestResult {
+                    digest,
+                    markdown: stored.markdown,
+                    cached: true,
+                });
+            }
+        }
+    }
+
+    let digest = compute_weekly_digest(conn, week_offset, now)?;
+    let markdown = render_markdown(&digest);
+    if digest.complete {
+        let json = serde_json::to_string(&digest)
+            .map_err(|e| format!("Failed to serialize digest: {}", e))?;
+        digests::save_digest(
+            conn,
+            &digest.week_label,
+            &digest.week_start,
+            &json,
+            &markdown,
+        )
+        .map_err(|e| format!("Failed to store digest: {}", e))?;
+    }
+    Ok(DigestResult {
+        digest,
+        markdown,
+        cached: false,
+    })
+}
+
+/// Startup check: with `digest_enabled` on, store last week's digest if it has not
+/// been generated yet. Returns the new digest's week label, or None if nothing was done.
+pub fn prepare_last_week_digest(
+    conn: &Connection,
+    now: DateTime<Utc>,
+) -> Result<Option<String>, String> {
+    if !get_typed::<bool>(conn, Key::DigestEnabled)? {
+        return Ok(None);
+    }
+    let (start, _) = week_bounds(now, -1)?;
+    let label = week_label(start);
+    let stored = digests::get_digest(conn, &label)
+        .map_err(|e| format!("Failed to read stored digest: {}", e))?;
+    if stored.is_some() {
+        return Ok(None);
+    }
+    let result = generate_weekly_digest(conn, -1, false, now)?;
+    Ok(Some(result.digest.week_label))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use chrono::TimeZone;
+    use tempfile::tempdir;
+
+    fn setup() -> (tempfile::TempDir, Database) {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        (dir, db)
+    }
+
+    /// Wednesday 2026-10-14 12:00 UTC, in ISO week 2026-W42 (Mon Oct 12 – Sun Oct 18)
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 14, 12, 0, 0).unwrap()
+    }
+
+    fn insert_proposal(
+        conn: &Connection,
+        created_at: &str,
+        outcome: &str,
+        outcome_at: Option<&str>,
+        strategy: Option<&str>,
+    ) -> i64 {
+        conn.execute(
+            "INSERT INTO proposals (job_content, generated_text, status, created_at,
+                outcome_status, outcome_updated_at, hook_strategy_id)
+             VALUES ('job', 'text', 'completed', ?1, ?2, ?3, ?4)",
+            params![created_at, outcome, outcome_at, strategy],
+        )
+        .unwrap();
+        conn.last_insert_rowid()
+    }
+
+    fn insert_job(conn: &Connection, created_at: &str, color: Option<&str>) {
+        conn.execute(
+            "INSERT INTO job_posts (raw_content, created_at) VALUES ('job', ?1)",
+            params![created_at],
+        )
+        .unwrap();
+        let id = conn.last_insert_rowid();
+        if let Some(color) = color {
+            conn.execute(
+                "INSERT INTO job_scores (job_post_id, color_flag) VALUES (?1, ?2)",
+                params![id, color],
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_week_bounds_use_utc_iso_weeks() {
+        let (start, end) = week_bounds(now(), 0).unwrap();
+        assert_eq!(format_timestamp(start), "2026-10-12 00:00:00");
+        assert_eq!(format_timestamp(end), "2026-10-19 00:00:00");
+        assert_eq!(week_label(start), "2026-W42");
+
+        let (start, _) = week_bounds(now(), -1).unwrap();
+        assert_eq!(format_timestamp(start), "2026-10-05 00:00:00");
+
+        // Sunday 23:59 UTC still belongs to the week that started the Monday before
+        let sunday = Utc.with_ymd_and_hms(2026, 10, 18, 23, 59, 59).unwrap();
+        assert_eq!(week_label(week_bounds(sunday, 0).unwrap().0), "2026-W42");
+
+        // ISO week-numbering year: Jan 1 2027 (a Friday) is in 2026-W53
+        let new_year = Utc.with_ymd_and_hms(2027, 1, 1, 8, 0, 0).unwrap();
+        let (start, _) = week_bounds(new_year, 0).unwrap();
+        assert_eq!(format_timestamp(start), "2026-12-28 00:00:00");
+        assert_eq!(week_label(start), "2026-W53");
+
+        assert!(week_bounds(now(), 1).is_err());
+        assert!(week_bounds(now(), -MAX_WEEKS_BACK - 1).is_err());
+    }
+
+    #[test]
+    fn test_digest_counts_only_rows_inside_the_week() {
+        let (_dir, db) = setup();
+        let conn = db.conn.lock().unwrap();
+
+        // Last week (2026-W41): Oct 5 00:00 up to Oct 12 00:00 UTC
+        insert_proposal(&conn, "2026-10-04 23:59:59", "pending", None, None); // W40
+        let p1 = insert_proposal(
+            &conn,
+            "2026-10-05 00:00:00",
+            "hired",
+            Some("2026-10-08 10:00:00"),
+            Some("social_proof"),
+        );
+        insert_proposal(
+            &conn,
+            "2026-10-07 09:00:00",
+            "no_response",
+            Some("2026-10-11 23:59:59"),
+            Some("social_proof"),
+        );
+        insert_proposal(
+            &conn,
+            "2026-10-08 09:00:00",
+            "interview",
+            Some("2026-10-09 09:00:00"),
+            Some("contrarian"),
+        );
+        // Outcome recorded Monday 00:00 of the next week counts there, not here
+        insert_proposal(
+            &conn,
+            "2026-10-11 23:59:59",
+            "response_received",
+            Some("2026-10-12 00:00:00"),
+            None,
+        );
+        // Drafts are not generated proposals
+        conn.execute(
+            "INSERT INTO proposals (job_content, generated_text, status, created_at)
+             VALUES ('job', 'text', 'draft', '2026-10-06 10:00:00')",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "UPDATE proposals SET copied_at = '2026-10-05 08:00:00' WHERE id = ?1",
+            params![p1],
+        )
+        .unwrap();
+
+        // Prior week (W40): one positive, one negative outcome
+        insert_proposal(
+            &conn,
+            "2026-09-29 09:00:00",
+            "hired",
+            Some("2026-10-01 09:00:00"),
+            None,
+        );
+        insert_proposal(
+            &conn,
+            "2026-09-29 09:00:00",
+            "rejected",
+            Some("2026-10-04 23:59:59"),
+            None,
+        );
+
+        insert_job(&conn, "2026-10-05 00:00:00", Some("green"));
+        insert_job(&conn, "2026-10-06 00:00:00", Some("red"));
+        insert_job(&conn, "2026-10-11 23:59:59", None);
+        insert_job(&conn, "2026-10-12 00:00:00", Some("green")); // W42
+
+        conn.execute(
+            "INSERT INTO safety_overrides (proposal_id, timestamp, ai_score, threshold_at_override)
+             VALUES (?1, '2026-10-06 12:00:00', 190.0, 180.0)",
+            params![p1],
+        )
+        .unwrap();
+
+        let digest = compute_weekly_digest(&conn, -1, now()).unwrap();
+        assert_eq!(digest.week_label, "2026-W41");
+        assert!(digest.complete);
+        assert_eq!(digest.proposals_generated, 4);
+        assert_eq!(digest.proposals_copied, 1);
+        assert_eq!(digest.outcomes_recorded, 3);
+        assert_eq!(digest.positive_outcomes, 2);
+        assert!((digest.response_rate - 200.0 / 3.0).abs() < 1e-9);
+        assert!((digest.prior_week_response_rate - 50.0).abs() < 1e-9);
+        assert!((digest.response_rate_change - (200.0 / 3.0 - 50.0)).abs() < 1e-9);
+
+        let top = digest.top_strategy.unwrap();
+        assert_eq!(top.strategy, "contrarian");
+        assert_eq!((top.positive, top.total), (1, 1));
+
+        assert_eq!(digest.jobs_imported, 3);
+        assert_eq!(
+            digest.job_scores,
+            ScoreDistribution {
+                green: 1,
+                red: 1,
+                unscored: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(digest.safety_overrides, 1);
+
+        // The current week is incomplete
+        let current = compute_weekly_digest(&conn, 0, now()).unwrap();
+        assert!(!current.complete);
+        assert_eq!(current.outcomes_recorded, 1);
+        assert_eq!(current.jobs_imported, 1);
+    }
+
+    #[test]
+    fn test_completed_weeks_are_stored_and_force_regenerate_recomputes() {
+        let (_dir, db) = setup();
+        let conn = db.conn.lock().unwrap();
+        insert_proposal(&conn, "2026-10-06 09:00:00", "pending", None, None);
+
+        let first = generate_weekly_digest(&conn, -1, false, now()).unwrap();
+        assert!(!first.cached);
+        assert_eq!(first.digest.proposals_generated, 1);
+        assert!(first.markdown.contains("# Weekly digest 2026-W41"));
+        assert!(first.markdown.contains("Oct 5 – Oct 11, 2026 (UTC)"));
+        assert!(first.markdown.contains("- Generated: 1"));
+
+        insert_proposal(&conn, "2026-10-07 09:00:00", "pending", None, None);
+        let cached = generate_weekly_digest(&conn, -1, false, now()).unwrap();
+        assert!(cached.cached);
+        assert_eq!(cached.digest, first.digest);
+
+        let forced = generate_weekly_digest(&conn, -1, true, now()).unwrap();
+        assert!(!forced.cached);
+        assert_eq!(forced.digest.proposals_generated, 2);
+        let stored = digests::get_digest(&conn, "2026-W41").unwrap().unwrap();
+        assert_eq!(stored.markdown, forced.markdown);
+
+        // The running week is never stored
+        let current = generate_weekly_digest(&conn, 0, false, now()).unwrap();
+        assert!(current.markdown.contains("(in progress)"));
+        assert!(digests::get_digest(&conn, "2026-W42").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_prepare_last_week_digest_respects_setting() {
+        let (_dir, db) = setup();
+        let conn = db.conn.lock().unwrap();
+
+        assert_eq!(prepare_last_week_digest(&conn, now()).unwrap(), None);
+
+        crate::db::queries::settings::set_setting(&conn, "digest_enabled", "true").unwrap();
+        assert_eq!(
+            prepare_last_week_digest(&conn, now()).unwrap(),
+            Some("2026-W41".to_string())
+        );
+        // Already prepared
+        assert_eq!(prepare_last_week_digest(&conn, now()).unwrap(), None);
+    }
+}
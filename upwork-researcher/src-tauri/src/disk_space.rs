@@ -0,0 +1,134 @@
+//! Pre-flight free-space checks for operations that copy the database
+//!
+//! Encryption migration and backups both write a second copy of the data, and
+//! running out of space halfway through leaves a truncated file behind. These
+//! helpers estimate what an operation needs and refuse to start when the
+//! volume is short. If free space can't be determined on this platform the
+//! check is skipped with a warning rather than blocking the user.
+
+use std::path::Path;
+
+/// Minimum headroom added to every estimate, covering the WAL, the `-shm`
+/// file and SQLite temp files
+pub const MIN_SAFETY_MARGIN_BYTES: u64 = 32 * 1024 * 1024;
+
+/// On-disk size of a SQLite database including its `-wal` and `-shm` files.
+/// Missing files count as zero.
+pub fn database_footprint(db_path: &Path) -> u64 {
+    let file_len = |path: &Path| std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    let sidecar = |suffix: &str| {
+        let mut name = db_path.as_os_str().to_owned();
+        name.push(suffix);
+        file_len(Path::new(&name))
+    };
+    file_len(db_path) + sidecar("-wal") + sidecar("-shm")
+}
+
+/// Add 10% or `MIN_SAFETY_MARGIN_BYTES`, whichever is larger
+fn with_margin(bytes: u64) -> u64 {
+    bytes.saturating_add((bytes / 10).max(MIN_SAFETY_MARGIN_BYTES))
+}
+
+/// Space needed to migrate `db_path`: the new encrypted copy plus the WAL of
+/// the copy transaction, each roughly the size of the source
+pub fn migration_required_bytes(db_path: &Path) -> u64 {
+    with_margin(database_footprint(db_path).saturating_mul(2))
+}
+
+/// Space needed for a JSON backup of `db_path`: roughly one copy of the data
+pub fn backup_required_bytes(db_path: &Path) -> u64 {
+    with_margin(database_footprint(db_path))
+}
+
+/// Free bytes on the volume holding `path`, or `None` if it can't be queried.
+/// Walks up to the nearest existing ancestor so not-yet-created directories work.
+pub fn available_bytes(path: &Path) -> Option<u64> {
+    let existing = path.ancestors().find(|p| p.exists())?;
+    match fs2::available_space(existing) {
+        Ok(bytes) => Some(bytes),
+        Err(e) => {
+            tracing::warn!(
+                "Cannot query free disk space at {}: {}",
+                existing.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
+/// Fail if the volume holding `path` has less than `required` bytes free.
+/// The error text is meant to be wrapped in the caller's disk-space error.
+pub fn ensure_available(path: &Path, required: u64) -> Result<(), String> {
+    check_space(available_bytes(path), required)
+}
+
+fn check_space(available: Option<u64>, required: u64) -> Result<(), String> {
+    match available {
+        None => {
+            tracing::warn!(
+                "Free disk space unknown; proceeding without pre-flight check ({} needed)",
+                format_mb(required)
+            );
+            Ok(())
+        }
+        Some(available) if available < required => Err(format!(
+            "about {} free space needed, only {} available",
+            format_mb(required),
+            format_mb(available)
+        )),
+        Some(_) => Ok(()),
+    }
+}
+
+fn format_mb(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_estimates_include_sidecars_and_margin() {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("app.db");
+        assert_eq!(database_footprint(&db_path), 0);
+        assert_eq!(migration_required_bytes(&db_path), MIN_SAFETY_MARGIN_BYTES);
+
+        std::fs::write(&db_path, vec![0u8; 1000]).unwrap();
+        std::fs::write(dir.path().join("app.db-wal"), vec![0u8; 200]).unwrap();
+        assert_eq!(database_footprint(&db_path), 1200);
+        assert_eq!(
+            backup_required_bytes(&db_path),
+            1200 + MIN_SAFETY_MARGIN_BYTES
+        );
+        assert_eq!(
+            migration_required_bytes(&db_path),
+            2400 + MIN_SAFETY_MARGIN_BYTES
+        );
+
+        // Large databases get a proportional margin
+        assert_eq!(with_margin(1_000_000_000), 1_100_000_000);
+    }
+
+    #[test]
+    fn test_check_space() {
+        assert!(check_space(Some(100), 100).is_ok());
+        let err = check_space(Some(1024 * 1024), 3 * 1024 * 1024).unwrap_err();
+        assert_eq!(err, "about 3.0 MB free space needed, only 1.0 MB available");
+
+        // Unknown free space never blocks
+        assert!(check_space(None, u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_available_bytes_uses_existing_ancestor() {
+        let dir = tempdir().unwrap();
+        let missing = dir.path().join("not").join("yet").join("created");
+        assert!(available_bytes(&missing).is_some());
+        assert!(ensure_available(dir.path(), 0).is_ok());
+        assert!(ensure_available(dir.path(), u64::MAX).is_err());
+    }
+}
@@ -0,0 +1,388 @@
+//! Structured errors for Tauri commands
+//!
+//! Commands historically returned `Result<T, String>` and the frontend parsed
+//! magic prefixes (`RATE_LIMITED:<secs>`, `AB_NO_ACTIVE_WEIGHTS:`) out of the
+//! message. `AppError` carries a machine-readable `code` alongside the original
+//! message text, so existing message-based handling keeps working while the
+//! frontend migrates to matching on `code`.
+
+use crate::backup::BackupError;
+use crate::db::DatabaseError;
+use crate::migration::MigrationError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Machine-readable error code (serialized as SCREAMING_SNAKE_CASE)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// Generation cooldown active (Story 3.8); detail carries `remainingSeconds`
+    RateLimited,
+    /// Outbound request rejected by the local per-domain limit before it was sent
+    /// (not an upstream 429); detail carries `retryAfterSeconds`
+    LocallyRateLimited,
+    /// All A/B strategy weights are 0.0 (Story 10.4)
+    AbNoActiveWeights,
+    /// Linked job scores below `min_score_to_generate`; detail carries `score` and `floor`
+    ScoreBelowFloor,
+    /// Database mutex poisoned or unavailable
+    DatabaseLocked,
+    /// Encrypted database not yet unlocked (Story 2-7b)
+    DatabaseNotReady,
+    IncorrectPassphrase,
+    ApiKeyMissing,
+    /// Generation stream stalled past the idle timeout; partial draft was kept
+    GenerationTimeout,
+    ValidationFailed,
+    NotFound,
+    DatabaseError,
+    MigrationFailed,
+    BackupFailed,
+    /// Destructive or revealing command refused while presentation mode is on;
+    /// detail carries `command`
+    PresentationModeBlocked,
+    /// Anthropic API overloaded (HTTP 529); detail carries `retryAfterSeconds`,
+    /// a longer wait than for other failures
+    ApiOverloaded,
+    /// A key file (e.g. `.salt`) changed since it was written; detail carries the
+    /// `integrity::IntegrityMismatch` (`file`, `expected`, `actual`)
+    IntegrityMismatch,
+    /// Anything not yet classified
+    Internal,
+}
+
+/// Structured command error
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppError {
+    pub code: ErrorCode,
+    /// Human-readable message (identical to the legacy string error)
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<serde_json::Value>,
+}
+
+impl AppError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            detail: None,
+        }
+    }
+
+    pub fn with_detail(mut self, detail: serde_json::Value) -> Self {
+        self.detail = Some(detail);
+        self
+    }
+
+    /// Cooldown error; message keeps the legacy `RATE_LIMITED:<secs>` format
+    pub fn rate_limited(remaining_seconds: u64) -> Self {
+        Self::new(
+            ErrorCode::RateLimited,
+            format!("RATE_LIMITED:{}", remaining_seconds),
+        )
+        .with_detail(serde_json::json!({ "remainingSeconds": remaining_seconds }))
+    }
+
+    /// Apply-rate guardrail; message is `SCORE_BELOW_FLOOR:<score>:<floor>`
+    pub fn score_below_floor(score: f64, floor: f64) -> Self {
+        Self::new(
+            ErrorCode::ScoreBelowFloor,
+            format!("SCORE_BELOW_FLOOR:{}:{}", score, floor),
+        )
+        .with_detail(serde_json::json!({ "score": score, "floor": floor }))
+    }
+
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::ValidationFailed, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::NotFound, message)
+    }
+
+    pub fn database_locked(err: impl fmt::Display) -> Self {
+        Self::new(
+            ErrorCode::DatabaseLocked,
+            format!("Database lock error: {}", err),
+        )
+    }
+
+    pub fn database(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::DatabaseError, message)
+    }
+}
+
+/// Compatibility: displays the message only, so `.to_string()` call sites
+/// produce exactly what the old `String` error contained.
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for AppError {}
+
+/// Legacy string errors from helpers that still return `Result<_, String>`.
+/// Known prefixes are classified so their codes survive the conversion.
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        if let Some(secs) = message
+            .strip_prefix("RATE_LIMITED:")
+            .and_then(|s| s.trim().parse::<u64>().ok())
+        {
+            return Self::rate_limited(secs);
+        }
+        if let Some(rest) = message.strip_prefix(crate::http::LOCAL_RATE_LIMIT_PREFIX) {
+            let retry_after: Option<u64> = rest.split(':').next().and_then(|s| s.parse().ok());
+            let err = Self::new(ErrorCode::LocallyRateLimited, message.clone());
+            return match retry_after {
+                Some(secs) => err.with_detail(serde_json::json!({ "retryAfterSeconds": secs })),
+                None => err,
+            };
+        }
+
+        if message.starts_with(crate::api_quota::OVERLOADED_PREFIX) {
+            return Self::new(ErrorCode::ApiOverloaded, message).with_detail(serde_json::json!({
+                "retryAfterSeconds": crate::api_quota::OVERLOADED_BACKOFF_SECS
+            }));
+        }
+
+        let code = if message.starts_with("AB_NO_ACTIVE_WEIGHTS:") {
+            ErrorCode::AbNoActiveWeights
+        } else if message.starts_with("SCORE_BELOW_FLOOR:") {
+            ErrorCode::ScoreBelowFloor
+        } else if message.starts_with("Database lock error") {
+            ErrorCode::DatabaseLocked
+        } else if message.starts_with("Database not unlocked") {
+            ErrorCode::DatabaseNotReady
+        } else if message.starts_with("No API key configured") {
+            ErrorCode::ApiKeyMissing
+        } else if message.starts_with(crate::claude::GENERATION_TIMEOUT_PREFIX) {
+            ErrorCode::GenerationTimeout
+        } else {
+            ErrorCode::Internal
+        };
+        Self::new(code, message)
+    }
+}
+
+impl From<&str> for AppError {
+    fn from(message: &str) -> Self {
+        Self::from(message.to_string())
+    }
+}
+
+impl From<AppError> for String {
+    fn from(err: AppError) -> Self {
+        err.message
+    }
+}
+
+impl From<DatabaseError> for AppError {
+    fn from(err: DatabaseError) -> Self {
+        let code = match &err {
+            DatabaseError::IncorrectPassphrase => ErrorCode::IncorrectPassphrase,
+            DatabaseError::PassphraseError(_) => ErrorCode::ValidationFailed,
+            DatabaseError::DatabaseError(_) | DatabaseError::CorruptedDatabase(_) => {
+                ErrorCode::DatabaseError
+            }
+            DatabaseError::IntegrityMismatch(mismatch) => {
+                return Self::new(ErrorCode::IntegrityMismatch, err.to_string())
+                    .with_detail(serde_json::json!(mismatch));
+            }
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+impl From<MigrationError> for AppError {
+    fn from(err: MigrationError) -> Self {
+        let code = match err {
+            MigrationError::DatabaseLockError(_) => ErrorCode::DatabaseLocked,
+            _ => ErrorCode::MigrationFailed,
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+impl From<BackupError> for AppError {
+    fn from(err: BackupError) -> Self {
+        let code = match err {
+            BackupError::DatabaseLockError(_) => ErrorCode::DatabaseLocked,
+            _ => ErrorCode::BackupFailed,
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wire string of every code and the code listed after it. The match is
+    /// exhaustive, so a new variant fails to compile until it is listed here.
+    fn wire_and_next(code: ErrorCode) -> (&'static str, Option<ErrorCode>) {
+        use ErrorCode::*;
+        match code {
+            RateLimited => ("RATE_LIMITED", Some(LocallyRateLimited)),
+            LocallyRateLimited => ("LOCALLY_RATE_LIMITED", Some(AbNoActiveWeights)),
+            AbNoActiveWeights => ("AB_NO_ACTIVE_WEIGHTS", Some(ScoreBelowFloor)),
+            ScoreBelowFloor => ("SCORE_BELOW_FLOOR", Some(DatabaseLocked)),
+            DatabaseLocked => ("DATABASE_LOCKED", Some(DatabaseNotReady)),
+            DatabaseNotReady => ("DATABASE_NOT_READY", Some(IncorrectPassphrase)),
+            IncorrectPassphrase => ("INCORRECT_PASSPHRASE", Some(ApiKeyMissing)),
+            ApiKeyMissing => ("API_KEY_MISSING", Some(GenerationTimeout)),
+            GenerationTimeout => ("GENERATION_TIMEOUT", Some(ValidationFailed)),
+            ValidationFailed => ("VALIDATION_FAILED", Some(NotFound)),
+            NotFound => ("NOT_FOUND", Some(DatabaseError)),
+            DatabaseError => ("DATABASE_ERROR", Some(MigrationFailed)),
+            MigrationFailed => ("MIGRATION_FAILED", Some(BackupFailed)),
+            BackupFailed => ("BACKUP_FAILED", Some(PresentationModeBlocked)),
+            PresentationModeBlocked => ("PRESENTATION_MODE_BLOCKED", Some(ApiOverloaded)),
+            ApiOverloaded => ("API_OVERLOADED", Some(IntegrityMismatch)),
+            IntegrityMismatch => ("INTEGRITY_MISMATCH", Some(Internal)),
+            Internal => ("INTERNAL", None),
+        }
+    }
+
+    fn all_codes() -> Vec<ErrorCode> {
+        std::iter::successors(Some(ErrorCode::RateLimited), |&code| wire_and_next(code).1).collect()
+    }
+
+    #[test]
+    fn test_serde_round_trip_every_code() {
+        for code in all_codes() {
+            let err = AppError::new(code, format!("message for {:?}", code))
+                .with_detail(serde_json::json!({ "n": 1 }));
+            let json = serde_json::to_string(&err).unwrap();
+            let back: AppError = serde_json::from_str(&json).unwrap();
+            assert_eq!(back, err);
+        }
+    }
+
+    #[test]
+    fn test_code_serializes_screaming_snake_case() {
+        let json = serde_json::to_value(AppError::rate_limited(42)).unwrap();
+        assert_eq!(json["code"], "RATE_LIMITED");
+        assert_eq!(json["message"], "RATE_LIMITED:42");
+        assert_eq!(json["detail"]["remainingSeconds"], 42);
+
+        let json = serde_json::to_value(AppError::new(ErrorCode::AbNoActiveWeights, "x")).unwrap();
+        assert_eq!(json["code"], "AB_NO_ACTIVE_WEIGHTS");
+        assert!(json.get("detail").is_none());
+
+        // The frontend switches on these strings
+        let codes = all_codes();
+        assert_eq!(codes.len(), 18);
+        for code in codes {
+            assert_eq!(
+                serde_json::to_value(code).unwrap(),
+                wire_and_next(code).0,
+                "{:?}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_matches_legacy_message() {
+        let err = AppError::database_locked("poisoned");
+        assert_eq!(err.to_string(), "Database lock error: poisoned");
+        assert_eq!(String::from(err), "Database lock error: poisoned");
+    }
+
+    #[test]
+    fn test_from_string_classifies_legacy_prefixes() {
+        assert_eq!(
+            AppError::from("RATE_LIMITED:30".to_string()),
+            AppError::rate_limited(30)
+        );
+        assert_eq!(
+            AppError::from("AB_NO_ACTIVE_WEIGHTS: none").code,
+            ErrorCode::AbNoActiveWeights
+        );
+        assert_eq!(
+            AppError::from("SCORE_BELOW_FLOOR:32.5:50").code,
+            ErrorCode::ScoreBelowFloor
+        );
+        assert_eq!(
+            AppError::from("Database not unlocked - passphrase required").code,
+            ErrorCode::DatabaseNotReady
+        );
+        assert_eq!(
+            AppError::from("No API key configured. Please add one.").code,
+            ErrorCode::ApiKeyMissing
+        );
+        assert_eq!(
+            AppError::from("GENERATION_TIMEOUT: No response from AI service for 120s.").code,
+            ErrorCode::GenerationTimeout
+        );
+        let local = AppError::from(
+            "LOCAL_RATE_LIMITED:4: Too many requests to api.anthropic.com, try again in 4s",
+        );
+        assert_eq!(local.code, ErrorCode::LocallyRateLimited);
+        assert_eq!(local.detail.unwrap()["retryAfterSeconds"], 4);
+        let overloaded = AppError::from(crate::api_quota::overloaded_error(529, "").unwrap());
+        assert_eq!(overloaded.code, ErrorCode::ApiOverloaded);
+        assert_eq!(overloaded.detail.unwrap()["retryAfterSeconds"], 60);
+        // Preemptive quota block surfaces as the usual rate-limit error
+        let quota = AppError::from(
+            crate::http::HttpError::ApiQuotaExhausted {
+                retry_after_secs: 40,
+            }
+            .to_string(),
+        );
+        assert_eq!(quota.code, ErrorCode::RateLimited);
+        // Upstream 429s are not local limits
+        assert_eq!(
+            AppError::from("API error (429 Too Many Requests): slow down").code,
+            ErrorCode::Internal
+        );
+        assert_eq!(AppError::from("boom").code, ErrorCode::Internal);
+    }
+
+    #[test]
+    fn test_from_database_error() {
+        let err = AppError::from(DatabaseError::IncorrectPassphrase);
+        assert_eq!(err.code, ErrorCode::IncorrectPassphrase);
+        assert_eq!(
+            err.message,
+            "Incorrect passphrase - database cannot be decrypted"
+        );
+        assert_eq!(
+            AppError::from(DatabaseError::CorruptedDatabase("bad".into())).code,
+            ErrorCode::DatabaseError
+        );
+
+        let expected = crate::integrity::FileFingerprint::of(b"salt");
+        let err = AppError::from(DatabaseError::IntegrityMismatch(
+            crate::integrity::IntegrityMismatch {
+                file: ".salt".to_string(),
+                expected: expected.clone(),
+                actual: None,
+            },
+        ));
+        assert_eq!(err.code, ErrorCode::IntegrityMismatch);
+        assert_eq!(err.message, "Salt file appears corrupted or modified");
+        let detail = err.detail.unwrap();
+        assert_eq!(detail["file"], ".salt");
+        assert_eq!(detail["expected"]["len"], 4);
+        assert!(detail["actual"].is_null());
+    }
+
+    #[test]
+    fn test_from_migration_and_backup_errors() {
+        let err = AppError::from(MigrationError::DatabaseLockError("held".into()));
+        assert_eq!(err.code, ErrorCode::DatabaseLocked);
+        assert_eq!(
+            AppError::from(MigrationError::IoError("disk".into())).code,
+            ErrorCode::MigrationFailed
+        );
+
+        let err = AppError::from(BackupError::FileWriteFailed("full".into()));
+        assert_eq!(err.code, ErrorCode::BackupFailed);
+        assert_eq!(err.message, "Failed to write backup file: full");
+    }
+}
@@ -6,6 +6,31 @@ pub const GENERATION_TOKEN: &str = "generation:token";
 pub const GENERATION_COMPLETE: &str = "generation:complete";
 pub const GENERATION_ERROR: &str = "generation:error";
 pub const GENERATION_STAGE: &str = "generation:stage";
+/// Throttled tokens-so-far / tokens-per-second during streaming
+pub const GENERATION_STATS: &str = "generation:stats";
+/// Running char/word counts every N token events, and final totals after the stream
+pub const GENERATION_PROGRESS: &str = "generation:progress";
+pub const GENERATION_SUMMARY: &str = "generation:summary";
+
+/// A `generation:*` event on one variant's channel (`generate_proposal_variants`),
+/// e.g. variant 2's `generation:token` is `generation:variant:2:token`
+pub fn generation_variant_event(variant: u8, event: &str) -> String {
+    let action = event.strip_prefix("generation:").unwrap_or(event);
+    format!("generation:variant:{}:{}", variant, action)
+}
+
+// Queued generation (drained by the background worker once the cooldown clears)
+pub const GENERATION_QUEUED_STARTED: &str = "generation:queued-started";
+pub const GENERATION_QUEUED_COMPLETE: &str = "generation:queued-complete";
+pub const GENERATION_QUEUED_FAILED: &str = "generation:queued-failed";
+
+/// A `generation:*` event on a queued job's channel, so background generation
+/// never streams into the editor: queue item 7's `generation:token` is
+/// `generation:queued:7:token`
+pub fn generation_queued_event(queue_id: i64, event: &str) -> String {
+    let action = event.strip_prefix("generation:").unwrap_or(event);
+    format!("generation:queued:{}:{}", queue_id, action)
+}
 
 // Story 4b.7: RSS Feed Import Events
 pub const RSS_IMPORT_PROGRESS: &str = "rss:import-progress";
@@ -16,9 +41,60 @@ pub const RSS_IMPORT_ERROR: &str = "rss:import-error";
 pub const RSS_FALLBACK_STARTED: &str = "rss:fallback-started";
 // Note: SCRAPE_FAILED was defined but not used - composite error via rss:import-error instead
 
+// Bulk score backfill (score_all_unscored_jobs)
+pub const SCORING_BULK_PROGRESS: &str = "scoring:bulk-progress";
+
+// Score recalculation after skill edits (debounced worker) or recalculate_all_scores
+pub const SCORES_RECALC_PROGRESS: &str = "scores:recalc-progress";
+pub const SCORES_RECALCULATED: &str = "scores:recalculated";
+
+// Clipboard watch mode (opt-in, clipboard_watch_enabled)
+pub const CLIPBOARD_JOB_DETECTED: &str = "clipboard:job-detected";
+
 // Story 8.13: Network Allowlist Enforcement
 pub const NETWORK_BLOCKED: &str = "network:blocked";
 
+// Remote config signature verification failure (payload never stored)
+pub const CONFIG_SIGNATURE_INVALID: &str = "config:signature-invalid";
+
+// Startup maintenance finished (runs after database-ready, see startup_maintenance)
+pub const STARTUP_MAINTENANCE_COMPLETE: &str = "startup:maintenance-complete";
+
+// Last week's digest prepared at startup (digest_enabled); payload is DigestReadyPayload
+pub const DIGEST_READY: &str = "digest:ready";
+
+// Database re-key (rekey_database); large databases can take minutes
+pub const REKEY_STARTED: &str = "rekey:started";
+pub const REKEY_COMPLETED: &str = "rekey:completed";
+
+// Database compaction (compact_database); started carries a db::maintenance::OperationPrecheck
+// size estimate, completed carries db::maintenance::CompactResult
+pub const COMPACT_STARTED: &str = "compact:started";
+pub const COMPACT_COMPLETED: &str = "compact:completed";
+
+// Encrypted archive import (execute_import); payload is archive_import::ImportStageProgress.
+// Imports interrupted before they committed are found at startup, before the frontend
+// listens, so they are read with get_incomplete_imports rather than sent as an event.
+pub const IMPORT_PROGRESS: &str = "import:progress";
+
+// All user data erased (factory_reset); payload is factory_reset::FactoryResetReport.
+// The frontend returns to the first-run (onboarding) state.
+pub const APP_FACTORY_RESET: &str = "app:factory-reset";
+
+// A key file (.salt, .kdf_params, recovery files) no longer matches the integrity
+// manifest, checked at startup before passphrase-required; payload is
+// integrity::IntegrityMismatch ({file, expected, actual})
+pub const SECURITY_INTEGRITY_WARNING: &str = "security:integrity-warning";
+
+// Presentation mode toggled (set_presentation_mode); payload is the new bool
+pub const PRESENTATION_CHANGED: &str = "presentation:changed";
+
+// An update installed by prepare_and_apply_update failed its startup health
+// checks and the pre-update backup was restored
+pub const UPDATE_ROLLED_BACK: &str = "update:rolled-back";
+// Download progress while prepare_and_apply_update installs an update
+pub const UPDATE_DOWNLOAD_PROGRESS: &str = "update:download-progress";
+
 /// RSS import progress event payload
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RssImportProgress {
@@ -43,3 +119,126 @@ pub struct NetworkBlockedPayload {
     pub url: String,
     pub timestamp: String,
 }
+
+/// Remote config signature failure event payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigSignatureInvalidPayload {
+    pub url: String,
+    pub reason: String,
+    pub timestamp: String,
+}
+
+/// Queued generation started event payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedGenerationStartedPayload {
+    pub queue_id: i64,
+}
+
+/// Queued generation completed event payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedGenerationCompletePayload {
+    pub queue_id: i64,
+    pub proposal_id: i64,
+}
+
+/// Queued generation failed event payload (error is also stored on the queue row)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedGenerationFailedPayload {
+    pub queue_id: i64,
+    pub error: String,
+}
+
+/// Update download progress payload, shaped like the updater plugin's JS
+/// download events: `event` is "Started", "Progress" or "Finished"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateDownloadProgress {
+    pub event: String,
+    pub data: Option<UpdateDownloadProgressData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDownloadProgressData {
+    pub content_length: Option<u64>,
+    pub chunk_length: Option<usize>,
+}
+
+/// Bulk score backfill progress payload (emitted every 25 jobs)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkScoringProgress {
+    pub processed: usize,
+    pub total: usize,
+    pub scored: usize,
+}
+
+/// Score recalculation progress payload (emitted after each batch)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoresRecalcProgress {
+    pub processed: usize,
+    pub total: usize,
+}
+
+/// Score recalculation finished payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoresRecalculatedPayload {
+    pub count: usize,
+}
+
+/// Copied job post detected by the clipboard watcher (import needs user confirmation)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClipboardJobDetectedPayload {
+    /// First ~280 characters of the copied text
+    pub preview: String,
+    /// SHA-256 of the full clipboard text
+    pub hash: String,
+}
+
+/// Database re-key started (estimate from file size, see db::maintenance)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RekeyStartedPayload {
+    pub size_bytes: u64,
+    pub estimated_seconds: u64,
+}
+
+/// Database re-key finished successfully
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RekeyCompletedPayload {
+    pub actual_seconds: f64,
+}
+
+/// What startup maintenance did (override auto-confirmation, log level migration, stale jobs)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StartupMaintenancePayload {
+    pub overrides_confirmed_successful: usize,
+    pub overrides_confirmed_unsuccessful: usize,
+    /// Log level copied from the database into config.json, if any
+    pub log_level_migrated: Option<String>,
+    pub stale_jobs_marked: usize,
+    /// Drafts older than `draft_retention_days` that were discarded
+    pub expired_drafts_discarded: usize,
+    /// Job posts from before V44 given a normalized client name
+    pub client_names_normalized: usize,
+    /// Analysis cache entries older than `analysis_cache_days` that were deleted
+    pub analysis_cache_pruned: usize,
+    /// Proposal revisions from before V51 given a content hash
+    pub revision_hashes_backfilled: usize,
+    /// Week label of the digest prepared for last week (digest_enabled), if any
+    pub weekly_digest_prepared: Option<String>,
+    pub duration_ms: u64,
+}
+
+/// A stored weekly digest is ready to show
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigestReadyPayload {
+    /// ISO week label, e.g. "2026-W41"
+    pub week_label: String,
+}
+
+/// Update rolled back after failing startup health checks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateRolledBackPayload {
+    /// The version that was rolled back
+    pub version: String,
+    /// Critical health check failures
+    pub reason: String,
+}
@@ -0,0 +1,107 @@
+//! Factory reset: erase all user data for privacy / offboarding
+//!
+//! `factory_reset` wipes the database (`Database::wipe_and_recreate`), removes
+//! every profile's API key (`ConfigState::reset`) and then deletes the files
+//! here: the encryption salt and migration marker, recovery key files and their
+//! integrity manifest, the encrypted API key file's secret, logs, backups and
+//! leftover migration copies. Afterwards the app is in its first-run state: an
+//! empty unencrypted database and no passphrase.
+
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+/// Files in the app data directory removed by a factory reset
+const USER_FILES: &[&str] = &[
+    ".salt",
+    ".salt.tmp",
+    crate::passphrase::KDF_PARAMS_FILE,
+    ".kdf_params.tmp",
+    ".migration_complete",
+    ".recovery_hash",
+    ".recovery_wrapped_key",
+    crate::integrity::INTEGRITY_FILE,
+    ".integrity.tmp",
+    crate::keychain::file_store::SECRET_FILE,
+    "upwork-researcher-encrypted.db",
+    "upwork-researcher.db.old",
+];
+
+/// Directories whose files are removed (logs, pre-encryption backups)
+const USER_DIRS: &[&str] = &["logs", "backups"];
+
+/// Outcome of `factory_reset`, also the `app:factory-reset` event payload
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FactoryResetReport {
+    pub tables_dropped: usize,
+    pub files_removed: usize,
+    /// Files that could not be deleted (e.g. the log file still open on Windows)
+    pub files_not_removed: Vec<String>,
+}
+
+/// Delete the user files in `app_data_dir`. Failures are collected rather than
+/// aborting, so one locked file doesn't leave the rest behind.
+pub fn remove_user_files(app_data_dir: &Path, report: &mut FactoryResetReport) {
+    let mut remove = |path: &Path| match fs::remove_file(path) {
+        Ok(()) => report.files_removed += 1,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => {
+            tracing::warn!("Factory reset could not delete {}: {}", path.display(), e);
+            report
+                .files_not_removed
+                .push(path.to_string_lossy().to_string());
+        }
+    };
+
+    for name in USER_FILES {
+        remove(&app_data_dir.join(name));
+    }
+
+    for dir in USER_DIRS {
+        let Ok(entries) = fs::read_dir(app_data_dir.join(dir)) else {
+            continue;
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            if entry.path().is_file() {
+                remove(&entry.path());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_user_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let app_data = dir.path();
+        for name in [
+            ".salt",
+            ".recovery_hash",
+            ".recovery_wrapped_key",
+            ".migration_complete",
+        ] {
+            fs::write(app_data.join(name), "x").unwrap();
+        }
+        fs::create_dir_all(app_data.join("logs")).unwrap();
+        fs::write(app_data.join("logs/app.2026-10-15.log"), "log").unwrap();
+        fs::write(app_data.join("logs/app.2026-10-16.log"), "log").unwrap();
+        fs::create_dir_all(app_data.join("backups")).unwrap();
+        fs::write(app_data.join("backups/pre-encryption-backup-1.json"), "{}").unwrap();
+        // Not user data: left in place
+        fs::write(app_data.join("unrelated.txt"), "keep").unwrap();
+
+        let mut report = FactoryResetReport::default();
+        remove_user_files(app_data, &mut report);
+
+        assert_eq!(report.files_removed, 7);
+        assert!(report.files_not_removed.is_empty());
+        assert!(!app_data.join(".salt").exists());
+        assert_eq!(fs::read_dir(app_data.join("logs")).unwrap().count(), 0);
+        assert_eq!(fs::read_dir(app_data.join("backups")).unwrap().count(), 0);
+        assert!(app_data.join("unrelated.txt").exists());
+    }
+}
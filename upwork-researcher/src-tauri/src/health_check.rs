@@ -2,6 +2,11 @@
 //!
 //! Provides version tracking, health checks, and rollback capabilities
 //! to ensure app stability after updates.
+//!
+//! `prepare_and_apply_update` backs up the running version and writes a
+//! `.pending_update` marker before the updater installs anything; after the
+//! restart, `verify_pending_update` runs the health checks and either records
+//! the new version or rolls back automatically.
 
 use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
@@ -26,23 +31,71 @@ pub fn get_installed_version(conn: &Connection) -> Result<Option<String>, String
         .map_err(|e| format!("Failed to get installed version: {}", e))
 }
 
-/// Compare two semver version strings (major.minor.patch).
+/// Compare two semver version strings (major.minor.patch[-pre-release][+build]).
+/// A pre-release sorts below its release (`1.3.0-beta.2` < `1.3.0`); pre-release
+/// identifiers compare dot by dot, numeric ones as numbers and below text ones.
+/// Build metadata is ignored; missing or non-numeric core parts count as 0.
+pub fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    fn split(v: &str) -> ((u64, u64, u64), Option<&str>) {
+        let v = v.trim();
+        let v = v.split('+').next().unwrap_or(v);
+        let (core, pre) = match v.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (v, None),
+        };
+        let mut parts = core.split('.').map(|p| p.parse::<u64>().unwrap_or(0));
+        let mut next = || parts.next().unwrap_or(0);
+        ((next(), next(), next()), pre)
+    }
+
+    let (core_a, pre_a) = split(a);
+    let (core_b, pre_b) = split(b);
+    core_a.cmp(&core_b).then_with(|| match (pre_a, pre_b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(pre_a), Some(pre_b)) => {
+            let mut ids_a = pre_a.split('.');
+            let mut ids_b = pre_b.split('.');
+            loop {
+                let order = match (ids_a.next(), ids_b.next()) {
+                    (None, None) => return Ordering::Equal,
+                    (None, Some(_)) => Ordering::Less,
+                    (Some(_), None) => Ordering::Greater,
+                    (Some(x), Some(y)) => match (x.parse::<u64>(), y.parse::<u64>()) {
+                        (Ok(x), Ok(y)) => x.cmp(&y),
+                        (Ok(_), Err(_)) => Ordering::Less,
+                        (Err(_), Ok(_)) => Ordering::Greater,
+                        (Err(_), Err(_)) => x.cmp(y),
+                    },
+                };
+                if order != Ordering::Equal {
+                    return order;
+                }
+            }
+        }
+    })
+}
+
+/// Whether `version` is a pre-release (`1.3.0-beta.1`)
+pub fn is_prerelease(version: &str) -> bool {
+    let version = version.trim();
+    version.split('+').next().unwrap_or(version).contains('-')
+}
+
+/// Compare two semver version strings (see `compare_versions`).
 /// Returns true if `current` is strictly greater than `installed`.
 /// Used by Story 9.9 (health checks) and Story 10.2 (remote config version comparison).
 pub fn is_version_newer(current: &str, installed: &str) -> bool {
-    let parse = |v: &str| -> (u32, u32, u32) {
-        let parts: Vec<u32> = v.split('.').filter_map(|p| p.parse().ok()).collect();
-        (
-            parts.first().copied().unwrap_or(0),
-            parts.get(1).copied().unwrap_or(0),
-            parts.get(2).copied().unwrap_or(0),
-        )
-    };
-    parse(current) > parse(installed)
+    compare_versions(current, installed) == std::cmp::Ordering::Greater
 }
 
 /// Detect if an update has occurred by comparing current vs installed version.
 /// Returns true if current version is strictly newer than installed version (Task 1.4).
+/// Pre-releases sort below their release, so going from a beta to the matching
+/// stable release counts as an update, and going back never does.
 pub fn detect_update(conn: &Connection) -> Result<bool, String> {
     let current = get_current_version();
     let installed = get_installed_version(conn)?;
@@ -771,6 +824,333 @@ pub fn check_and_clear_rollback(conn: &Connection) -> Result<Option<String>, Str
     Ok(version.filter(|v| !v.is_empty()))
 }
 
+// ═══════════════════════════════════════════════════════════
+// Update Orchestration (backup → marker → install → verify)
+// ═══════════════════════════════════════════════════════════
+
+/// Marker file in the app data dir, present while an update awaits verification
+pub const PENDING_UPDATE_FILE: &str = ".pending_update";
+
+/// Written by `apply_update_with_backup` before the updater installs anything.
+/// The next startup verifies the update it describes, then removes it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingUpdateMarker {
+    pub from_version: String,
+    pub target_version: String,
+    pub created_at: String,
+}
+
+/// Path of the pending update marker in `app_data_dir`
+pub fn pending_update_path(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join(PENDING_UPDATE_FILE)
+}
+
+/// Write the marker crash-safely: temp file, fsync, rename. A crash leaves
+/// either no marker or a complete one, never a truncated file.
+pub fn write_pending_update_marker(
+    app_data_dir: &Path,
+    marker: &PendingUpdateMarker,
+) -> Result<(), String> {
+    use std::io::Write;
+
+    let path = pending_update_path(app_data_dir);
+    let tmp_path = path.with_extension("tmp");
+    let json = serde_json::to_vec(marker)
+        .map_err(|e| format!("Failed to serialize pending update marker: {}", e))?;
+
+    let result = (|| {
+        let mut file = std::fs::File::create(&tmp_path)?;
+        file.write_all(&json)?;
+        file.sync_all()?;
+        std::fs::rename(&tmp_path, &path)
+    })();
+    result.map_err(|e| {
+        let _ = std::fs::remove_file(&tmp_path);
+        format!("Failed to write pending update marker: {}", e)
+    })
+}
+
+/// Read the pending update marker, if any. A leftover `.tmp` from a crash
+/// mid-write is ignored: the update it was for never started.
+pub fn read_pending_update_marker(
+    app_data_dir: &Path,
+) -> Result<Option<PendingUpdateMarker>, String> {
+    let json = match std::fs::read(pending_update_path(app_data_dir)) {
+        Ok(json) => json,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Failed to read pending update marker: {}", e)),
+    };
+    serde_json::from_slice(&json)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse pending update marker: {}", e))
+}
+
+/// Remove the marker (and any leftover temp file). Missing files are fine.
+pub fn clear_pending_update_marker(app_data_dir: &Path) -> Result<(), String> {
+    let path = pending_update_path(app_data_dir);
+    let _ = std::fs::remove_file(path.with_extension("tmp"));
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Failed to clear pending update marker: {}", e)),
+    }
+}
+
+/// What the startup health checks say about a pending update
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingUpdateVerdict {
+    /// Running version is not the target: the install failed or never ran
+    NotApplied,
+    Healthy,
+    Failed {
+        reason: String,
+    },
+}
+
+/// Judge a pending update from the running version and the health check result.
+/// Only critical failures fail it; settings problems alone do not.
+pub fn assess_pending_update(
+    marker: &PendingUpdateMarker,
+    running_version: &str,
+    health: &Result<HealthCheckReport, HealthCheckError>,
+) -> PendingUpdateVerdict {
+    if running_version != marker.target_version {
+        return PendingUpdateVerdict::NotApplied;
+    }
+    match health {
+        Ok(report) if report.passed => PendingUpdateVerdict::Healthy,
+        Ok(report) => PendingUpdateVerdict::Failed {
+            reason: report
+                .failures
+                .iter()
+                .filter(|f| f.critical)
+                .map(|f| format!("{}: {}", f.check, f.error))
+                .collect::<Vec<_>>()
+                .join("; "),
+        },
+        Err(e) => PendingUpdateVerdict::Failed {
+            reason: e.to_string(),
+        },
+    }
+}
+
+/// Healthy update: record the new installed version, then drop the marker
+pub fn complete_verified_update(conn: &Connection, app_data_dir: &Path) -> Result<(), String> {
+    set_installed_version(conn)?;
+    clear_update_detected(conn)?;
+    clear_pending_update_marker(app_data_dir)
+}
+
+/// Failed update: skip-list the target version, then drop the marker. Runs
+/// whether or not restoring the backup worked, so the updater never offers
+/// the same broken version again.
+pub fn record_failed_update(
+    conn: &Connection,
+    app_data_dir: &Path,
+    marker: &PendingUpdateMarker,
+) -> Result<(), String> {
+    add_to_failed_versions_list(conn, &marker.target_version)?;
+    clear_pending_update_marker(app_data_dir)
+}
+
+/// Outcome of `verify_pending_update`
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingUpdateOutcome {
+    NoPendingUpdate,
+    NotApplied { version: String },
+    Verified { version: String },
+    RolledBack { version: String, reason: String },
+    RollbackFailed { version: String, reason: String },
+}
+
+/// Verify an update installed by `apply_update_with_backup`. Needs an open
+/// database (health checks and settings), so it runs after `database-ready`.
+/// Critical failures restore the pre-update backup and emit `update:rolled-back`.
+pub async fn verify_pending_update(app_handle: &AppHandle) -> Result<PendingUpdateOutcome, String> {
+    use tauri::Emitter;
+
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+    let Some(marker) = read_pending_update_marker(&app_data_dir)? else {
+        return Ok(PendingUpdateOutcome::NoPendingUpdate);
+    };
+
+    let health = run_health_checks(app_handle).await;
+    let verdict = assess_pending_update(&marker, &get_current_version(), &health);
+
+    let version = marker.target_version.clone();
+    match verdict {
+        PendingUpdateVerdict::NotApplied => {
+            tracing::warn!(
+                target_version = %version,
+                running_version = %get_current_version(),
+                "Pending update was not applied; clearing marker"
+            );
+            clear_pending_update_marker(&app_data_dir)?;
+            Ok(PendingUpdateOutcome::NotApplied { version })
+        }
+        PendingUpdateVerdict::Healthy => {
+            with_db_conn(app_handle, |conn| {
+                complete_verified_update(conn, &app_data_dir)
+            })?;
+            tracing::info!("Update to v{} verified by health checks", version);
+            Ok(PendingUpdateOutcome::Verified { version })
+        }
+        PendingUpdateVerdict::Failed { reason } => {
+            tracing::error!("Update to v{} failed health checks: {}", version, reason);
+            // Restoring the backup also skip-lists the running version and
+            // sets the rollback flag the frontend toasts on next launch
+            let rollback = rollback_to_previous_version(app_handle).await;
+            with_db_conn(app_handle, |conn| {
+                record_failed_update(conn, &app_data_dir, &marker)
+            })?;
+
+            match rollback {
+                Ok(()) => {
+                    let _ = app_handle.emit(
+                        crate::events::UPDATE_ROLLED_BACK,
+                        crate::events::UpdateRolledBackPayload {
+                            version: version.clone(),
+                            reason: reason.clone(),
+                        },
+                    );
+                    Ok(PendingUpdateOutcome::RolledBack { version, reason })
+                }
+                Err(e) => {
+                    tracing::error!("Automatic rollback from v{} failed: {}", version, e);
+                    Ok(PendingUpdateOutcome::RollbackFailed {
+                        version,
+                        reason: format!("{}; rollback failed: {}", reason, e),
+                    })
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn with_db_conn<T>(
+    app_handle: &AppHandle,
+    f: impl FnOnce(&Connection) -> Result<T, String>,
+) -> Result<T, String> {
+    let db_state = app_handle.state::<crate::db::AppDatabase>();
+    let db = db_state
+        .get()
+        .map_err(|e| format!("Database not available: {}", e))?;
+    let conn = db
+        .conn
+        .lock()
+        .map_err(|e| format!("Failed to lock database: {}", e))?;
+    f(&conn)
+}
+
+/// Spawn `verify_pending_update`. Call after `database-ready`.
+pub fn spawn_pending_update_verification(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        match verify_pending_update(&app_handle).await {
+            Ok(PendingUpdateOutcome::NoPendingUpdate) => {}
+            Ok(outcome) => tracing::info!(?outcome, "Pending update verification finished"),
+            Err(e) => tracing::warn!("Pending update verification failed: {}", e),
+        }
+    });
+}
+
+/// Back up the running version, write the pending update marker, then let the
+/// updater download and install `version` from the configured update channel's
+/// feed. Refuses if the feed does not currently offer exactly `version` as a
+/// newer release (never a downgrade). The marker is removed again if the
+/// install fails; the frontend restarts the app on success.
+pub async fn apply_update_with_backup(
+    app_handle: &AppHandle,
+    version: &str,
+) -> Result<PendingUpdateMarker, String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    let feed = crate::update_channel::current_feed(app_handle)?;
+    let update = crate::update_channel::feed_updater(app_handle, &feed)?
+        .build()
+        .map_err(|e| format!("Updater unavailable: {}", e))?
+        .check()
+        .await
+        .map_err(|e| format!("Update check failed: {}", e))?
+        .ok_or_else(|| "No update available".to_string())?;
+    if update.version != version {
+        return Err(format!(
+            "Available update is v{}, not v{}",
+            update.version, version
+        ));
+    }
+
+    // 1. Record the running version as the rollback baseline
+    with_db_conn(app_handle, set_installed_version)?;
+
+    // 2. Back up the running binary/bundle
+    create_pre_update_backup(app_handle)
+        .await
+        .map_err(|e| format!("Pre-update backup failed: {}", e))?;
+
+    // 3. Marker before anything is installed
+    let marker = PendingUpdateMarker {
+        from_version: get_current_version(),
+        target_version: version.to_string(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+    };
+    write_pending_update_marker(&app_data_dir, &marker)?;
+
+    // 4. Only now let the updater proceed (progress for the update UI)
+    use crate::events::{UpdateDownloadProgress, UpdateDownloadProgressData};
+    use tauri::Emitter;
+    let emit_progress = |event: &str, data: Option<UpdateDownloadProgressData>| {
+        let _ = app_handle.emit(
+            crate::events::UPDATE_DOWNLOAD_PROGRESS,
+            UpdateDownloadProgress {
+                event: event.to_string(),
+                data,
+            },
+        );
+    };
+    let mut started = false;
+    let installed = update
+        .download_and_install(
+            |chunk_length, content_length| {
+                if !started {
+                    started = true;
+                    emit_progress(
+                        "Started",
+                        Some(UpdateDownloadProgressData {
+                            content_length,
+                            chunk_length: None,
+                        }),
+                    );
+                }
+                emit_progress(
+                    "Progress",
+                    Some(UpdateDownloadProgressData {
+                        content_length: None,
+                        chunk_length: Some(chunk_length),
+                    }),
+                );
+            },
+            || emit_progress("Finished", None),
+        )
+        .await;
+    if let Err(e) = installed {
+        let _ = clear_pending_update_marker(&app_data_dir);
+        return Err(format!("Update install failed: {}", e));
+    }
+
+    tracing::info!(
+        "Update v{} installed; verification pending restart",
+        version
+    );
+    Ok(marker)
+}
+
 // ═══════════════════════════════════════════════════════════
 // Tauri Commands
 // ═══════════════════════════════════════════════════════════
@@ -877,6 +1257,16 @@ pub async fn cleanup_old_backups_command(
         .map_err(|e| e.to_string())
 }
 
+/// Back up, mark, and install `version` (see `apply_update_with_backup`).
+/// The app must be restarted afterwards; the next startup verifies the update.
+#[tauri::command]
+pub async fn prepare_and_apply_update(
+    app_handle: AppHandle,
+    version: String,
+) -> Result<PendingUpdateMarker, String> {
+    apply_update_with_backup(&app_handle, &version).await
+}
+
 /// Record the current app version as installed (Story TD2.3 Task 1).
 /// Called by frontend after successful health checks to advance the baseline version.
 #[tauri::command]
@@ -1042,6 +1432,24 @@ mod tests {
         assert!(is_version_newer("2.0.0", "1.99.99"));
     }
 
+    #[test]
+    fn test_is_version_newer_prerelease() {
+        // Stable catching up with a beta is an update, the beta isn't
+        assert!(is_version_newer("1.3.0", "1.3.0-beta.2"));
+        assert!(!is_version_newer("1.3.0-beta.2", "1.3.0"));
+        assert!(is_version_newer("1.3.0-beta.1", "1.2.0"));
+        assert!(is_version_newer("1.3.0-beta.10", "1.3.0-beta.2"));
+        assert!(is_version_newer("1.3.0-beta.1", "1.3.0-alpha.5"));
+        assert!(is_version_newer("1.3.0-beta.1", "1.3.0-beta"));
+        assert!(is_version_newer("1.3.0-rc", "1.3.0-1"));
+        assert_eq!(
+            compare_versions("1.3.0+build.7", "1.3.0"),
+            std::cmp::Ordering::Equal
+        );
+        assert!(is_prerelease("1.3.0-beta.1"));
+        assert!(!is_prerelease("1.3.0+build-7"));
+    }
+
     #[test]
     fn test_detect_update_false_on_downgrade() {
         let db = create_test_db();
@@ -1157,6 +1565,141 @@ mod tests {
         assert_eq!(second, None);
     }
 
+    // ═══════════════════════════════════════════════════════════
+    // Pending update marker & verification outcomes
+    // ═══════════════════════════════════════════════════════════
+
+    fn pending_marker() -> PendingUpdateMarker {
+        PendingUpdateMarker {
+            from_version: "0.9.0".to_string(),
+            target_version: get_current_version(),
+            created_at: "2026-10-01T00:00:00Z".to_string(),
+        }
+    }
+
+    fn report(failures: Vec<HealthCheckFailure>) -> HealthCheckReport {
+        HealthCheckReport {
+            passed: failures.iter().all(|f| !f.critical),
+            checks_run: 4,
+            failures,
+            duration_ms: 12,
+        }
+    }
+
+    #[test]
+    fn test_pending_update_marker_roundtrip_is_atomic() {
+        let dir = tempdir().unwrap();
+        assert_eq!(read_pending_update_marker(dir.path()).unwrap(), None);
+
+        // A temp file left by a crash mid-write is not a marker
+        std::fs::write(
+            pending_update_path(dir.path()).with_extension("tmp"),
+            b"{\"trunc",
+        )
+        .unwrap();
+        assert_eq!(read_pending_update_marker(dir.path()).unwrap(), None);
+
+        let marker = pending_marker();
+        write_pending_update_marker(dir.path(), &marker).unwrap();
+        assert_eq!(
+            read_pending_update_marker(dir.path()).unwrap(),
+            Some(marker)
+        );
+        assert!(!pending_update_path(dir.path())
+            .with_extension("tmp")
+            .exists());
+
+        clear_pending_update_marker(dir.path()).unwrap();
+        assert_eq!(read_pending_update_marker(dir.path()).unwrap(), None);
+        // Clearing twice is fine
+        clear_pending_update_marker(dir.path()).unwrap();
+    }
+
+    #[test]
+    fn test_assess_pending_update_outcomes() {
+        let marker = pending_marker();
+        let current = get_current_version();
+
+        assert_eq!(
+            assess_pending_update(&marker, &current, &Ok(report(vec![]))),
+            PendingUpdateVerdict::Healthy
+        );
+
+        // Non-critical failures alone keep the update
+        let settings_only = report(vec![HealthCheckFailure {
+            check: "settings".to_string(),
+            error: "unreadable".to_string(),
+            critical: false,
+        }]);
+        assert_eq!(
+            assess_pending_update(&marker, &current, &Ok(settings_only)),
+            PendingUpdateVerdict::Healthy
+        );
+
+        let critical = report(vec![HealthCheckFailure {
+            check: "schema_version".to_string(),
+            error: "mismatch".to_string(),
+            critical: true,
+        }]);
+        assert_eq!(
+            assess_pending_update(&marker, &current, &Ok(critical)),
+            PendingUpdateVerdict::Failed {
+                reason: "schema_version: mismatch".to_string()
+            }
+        );
+        assert_eq!(
+            assess_pending_update(&marker, &current, &Err(HealthCheckError::Timeout)),
+            PendingUpdateVerdict::Failed {
+                reason: "Health check timeout".to_string()
+            }
+        );
+
+        // Still on the old version: the install never happened
+        assert_eq!(
+            assess_pending_update(&marker, "0.9.0", &Err(HealthCheckError::Timeout)),
+            PendingUpdateVerdict::NotApplied
+        );
+    }
+
+    #[test]
+    fn test_complete_verified_update_records_version_and_clears_marker() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        let dir = tempdir().unwrap();
+        crate::db::queries::settings::set_setting(&conn, "installed_version", "0.9.0").unwrap();
+        mark_update_detected(&conn, "0.9.0").unwrap();
+        write_pending_update_marker(dir.path(), &pending_marker()).unwrap();
+
+        complete_verified_update(&conn, dir.path()).unwrap();
+
+        assert_eq!(
+            get_installed_version(&conn).unwrap(),
+            Some(get_current_version())
+        );
+        assert!(!detect_update(&conn).unwrap());
+        assert_eq!(read_pending_update_marker(dir.path()).unwrap(), None);
+        assert!(get_failed_update_versions(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_record_failed_update_skip_lists_target_and_clears_marker() {
+        let db = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        let dir = tempdir().unwrap();
+        let marker = pending_marker();
+        write_pending_update_marker(dir.path(), &marker).unwrap();
+
+        record_failed_update(&conn, dir.path(), &marker).unwrap();
+
+        assert_eq!(
+            get_failed_update_versions(&conn).unwrap(),
+            vec![marker.target_version.clone()]
+        );
+        assert_eq!(read_pending_update_marker(dir.path()).unwrap(), None);
+        // The installed version baseline still points at the pre-update version
+        assert_eq!(get_installed_version(&conn).unwrap(), None);
+    }
+
     // ═══════════════════════════════════════════════════════════
     // macOS tarball helper tests (TD2-2, AC-3, AC-4)
     // These tests are NOT #[cfg(target_os = "macos")] — the helpers
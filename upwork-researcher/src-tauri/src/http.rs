@@ -1,11 +1,436 @@
-use reqwest::Client;
-use std::sync::OnceLock;
+//! Shared HTTP client, per-domain request metrics, and local rate limiting
+//!
+//! Outbound calls (Claude, RSS, scraper, remote config) are sent through
+//! `TrackedSend::send_tracked`, which enforces the per-domain token buckets
+//! configured by the `rate_limit_*_rpm` settings and records request counts,
+//! error counts, and latency percentiles per domain. Only hostnames are
+//! recorded, never full URLs (AR-16 logging hygiene). Anthropic responses also
+//! update the API quota (`api_quota`), which can refuse a call before it is sent.
+
+use crate::settings::schema::{get_typed, Key};
+use chrono::Utc;
+use reqwest::{Client, RequestBuilder, Response};
+use rusqlite::Connection;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 /// Shared HTTP client for connection pooling across all modules.
 /// Initialized once on first use. Per-request timeouts should be set
 /// via `RequestBuilder::timeout()` at each call site.
 static HTTP_CLIENT: OnceLock<Client> = OnceLock::new();
+static REQUEST_METRICS: OnceLock<RequestMetricsState> = OnceLock::new();
+static RATE_LIMITER: OnceLock<DomainRateLimiter> = OnceLock::new();
 
 pub fn client() -> &'static Client {
     HTTP_CLIENT.get_or_init(|| Client::builder().build().expect("Failed to create HTTP client"))
 }
+
+/// Process-wide request metrics (also registered as managed state)
+pub fn request_metrics() -> &'static RequestMetricsState {
+    REQUEST_METRICS.get_or_init(RequestMetricsState::default)
+}
+
+/// Process-wide per-domain rate limiter
+pub fn rate_limiter() -> &'static DomainRateLimiter {
+    RATE_LIMITER.get_or_init(DomainRateLimiter::default)
+}
+
+/// Message prefix of local rate limit errors (classified as `LOCALLY_RATE_LIMITED`)
+pub const LOCAL_RATE_LIMIT_PREFIX: &str = "LOCAL_RATE_LIMITED:";
+
+/// Settings key → domain it throttles. Values are requests per minute;
+/// unset or "0" means unlimited. A domain also covers its subdomains.
+pub const RATE_LIMIT_SETTINGS: &[(&str, &str)] = &[
+    ("rate_limit_anthropic_rpm", "api.anthropic.com"),
+    ("rate_limit_github_rpm", "raw.githubusercontent.com"),
+    ("rate_limit_upwork_rpm", "upwork.com"),
+];
+
+pub const MAX_RATE_LIMIT_RPM: u32 = 1000;
+/// Latency samples kept per domain (ring buffer) for percentile calculation
+const LATENCY_WINDOW: usize = 100;
+
+/// Errors from `send_tracked`
+#[derive(Debug, thiserror::Error)]
+pub enum HttpError {
+    /// Rejected by the local token bucket; the request was never sent.
+    /// Distinct from an upstream 429, which arrives as a normal response.
+    #[error("LOCAL_RATE_LIMITED:{retry_after_secs}: Too many requests to {domain}, try again in {retry_after_secs}s")]
+    LocallyRateLimited {
+        domain: String,
+        retry_after_secs: u64,
+    },
+
+    /// Anthropic request refused because the last response reported the
+    /// request quota exhausted (see `api_quota`); the request was never sent.
+    /// Same message as the generation cooldown so the UI shows one countdown.
+    #[error("RATE_LIMITED:{retry_after_secs}")]
+    ApiQuotaExhausted { retry_after_secs: u64 },
+
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
+}
+
+impl HttpError {
+    /// The request was refused before sending (local token bucket or exhausted
+    /// API quota); the error message is meant to be surfaced as-is
+    pub fn is_locally_rate_limited(&self) -> bool {
+        matches!(
+            self,
+            Self::LocallyRateLimited { .. } | Self::ApiQuotaExhausted { .. }
+        )
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Request(e) if e.is_timeout())
+    }
+
+    pub fn is_connect(&self) -> bool {
+        matches!(self, Self::Request(e) if e.is_connect())
+    }
+}
+
+/// Sends a request through the rate limiter and metrics recorder
+pub trait TrackedSend {
+    fn send_tracked(self) -> impl Future<Output = Result<Response, HttpError>> + Send;
+}
+
+impl TrackedSend for RequestBuilder {
+    async fn send_tracked(self) -> Result<Response, HttpError> {
+        send(self).await
+    }
+}
+
+async fn send(builder: RequestBuilder) -> Result<Response, HttpError> {
+    let (client, request) = builder.build_split();
+    let request = request?;
+    let domain = request.url().host_str().unwrap_or("unknown").to_string();
+    let metrics = request_metrics();
+    // Every Anthropic API call sets anthropic-version; only those track the API quota
+    let anthropic = request.headers().contains_key("anthropic-version");
+
+    if anthropic {
+        if let Some(retry_after_secs) = crate::api_quota::api_quota().blocked_for_secs(Utc::now()) {
+            metrics.record_rate_limited(&domain);
+            tracing::warn!(
+                retry_after_secs = retry_after_secs,
+                "Anthropic request blocked: API request quota exhausted"
+            );
+            return Err(HttpError::ApiQuotaExhausted { retry_after_secs });
+        }
+    }
+
+    if let Err(wait) = rate_limiter().try_acquire(&domain, Instant::now()) {
+        metrics.record_rate_limited(&domain);
+        let retry_after_secs = wait.as_secs_f64().ceil().max(1.0) as u64;
+        tracing::warn!(
+            domain = %domain,
+            retry_after_secs = retry_after_secs,
+            "Outbound request blocked by local rate limit"
+        );
+        return Err(HttpError::LocallyRateLimited {
+            domain,
+            retry_after_secs,
+        });
+    }
+
+    let started = Instant::now();
+    match client.execute(request).await {
+        Ok(response) => {
+            metrics.record_response(&domain, started.elapsed(), response.status().is_success());
+            if anthropic {
+                crate::api_quota::api_quota().record(response.headers(), Utc::now());
+            }
+            Ok(response)
+        }
+        Err(e) => {
+            metrics.record_failure(&domain);
+            Err(e.into())
+        }
+    }
+}
+
+// ==========================================
+// Request metrics
+// ==========================================
+
+#[derive(Default)]
+struct DomainStats {
+    requests: u64,
+    errors: u64,
+    locally_rate_limited: u64,
+    latencies_ms: VecDeque<u64>,
+}
+
+/// Metrics snapshot for one domain
+#[derive(Debug, Clone, PartialEq, Serialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainMetrics {
+    pub domain: String,
+    /// Requests that reached the network
+    pub requests: u64,
+    /// Transport failures and non-2xx responses
+    pub errors: u64,
+    /// Requests rejected locally before sending (not included in `requests`)
+    pub locally_rate_limited: u64,
+    /// Latency percentiles over the last 100 responses (time to response headers)
+    pub p50_ms: Option<u64>,
+    pub p95_ms: Option<u64>,
+    pub p99_ms: Option<u64>,
+}
+
+/// In-memory per-domain request metrics. Not persisted; reset on restart or
+/// via `reset_network_metrics`.
+#[derive(Clone, Default)]
+pub struct RequestMetricsState {
+    domains: Arc<Mutex<HashMap<String, DomainStats>>>,
+}
+
+impl RequestMetricsState {
+    fn with_stats(&self, domain: &str, f: impl FnOnce(&mut DomainStats)) {
+        let mut guard = match self.domains.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                tracing::warn!("RequestMetricsState mutex poisoned, recovering");
+                poisoned.into_inner()
+            }
+        };
+        f(guard.entry(domain.to_string()).or_default());
+    }
+
+    pub fn record_response(&self, domain: &str, latency: Duration, success: bool) {
+        self.with_stats(domain, |stats| {
+            stats.requests += 1;
+            if !success {
+                stats.errors += 1;
+            }
+            if stats.latencies_ms.len() == LATENCY_WINDOW {
+                stats.latencies_ms.pop_front();
+            }
+            stats.latencies_ms.push_back(latency.as_millis() as u64);
+        });
+    }
+
+    pub fn record_failure(&self, domain: &str) {
+        self.with_stats(domain, |stats| {
+            stats.requests += 1;
+            stats.errors += 1;
+        });
+    }
+
+    pub fn record_rate_limited(&self, domain: &str) {
+        self.with_stats(domain, |stats| stats.locally_rate_limited += 1);
+    }
+
+    /// Snapshot of all domains, sorted by domain
+    pub fn snapshot(&self) -> Vec<DomainMetrics> {
+        let guard = match self.domains.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut metrics: Vec<DomainMetrics> = guard
+            .iter()
+            .map(|(domain, stats)| {
+                let mut sorted: Vec<u64> = stats.latencies_ms.iter().copied().collect();
+                sorted.sort_unstable();
+                DomainMetrics {
+                    domain: domain.clone(),
+                    requests: stats.requests,
+                    errors: stats.errors,
+                    locally_rate_limited: stats.locally_rate_limited,
+                    p50_ms: percentile(&sorted, 50),
+                    p95_ms: percentile(&sorted, 95),
+                    p99_ms: percentile(&sorted, 99),
+                }
+            })
+            .collect();
+        metrics.sort_by(|a, b| a.domain.cmp(&b.domain));
+        metrics
+    }
+
+    pub fn reset(&self) {
+        match self.domains.lock() {
+            Ok(mut guard) => guard.clear(),
+            Err(poisoned) => poisoned.into_inner().clear(),
+        }
+    }
+}
+
+/// Nearest-rank percentile of an ascending slice
+pub(crate) fn percentile(sorted: &[u64], pct: usize) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
+
+// ==========================================
+// Per-domain rate limiting
+// ==========================================
+
+struct TokenBucket {
+    rpm: u32,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Starts full: a burst of up to `rpm` requests, then `rpm / 60` per second
+    fn new(rpm: u32, now: Instant) -> Self {
+        Self {
+            rpm,
+            tokens: rpm as f64,
+            last_refill: now,
+        }
+    }
+
+    /// Take one token, or return how long until one is available
+    fn try_acquire(&mut self, now: Instant) -> Result<(), Duration> {
+        let per_sec = self.rpm as f64 / 60.0;
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * per_sec).min(self.rpm as f64);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / per_sec))
+        }
+    }
+}
+
+/// Token buckets keyed by the configured domain from `RATE_LIMIT_SETTINGS`
+#[derive(Default)]
+pub struct DomainRateLimiter {
+    buckets: Mutex<HashMap<&'static str, TokenBucket>>,
+}
+
+impl DomainRateLimiter {
+    /// Set a domain's limit; 0 removes it. Unchanged limits keep their bucket state.
+    pub fn set_limit(&self, domain: &'static str, rpm: u32) {
+        let Ok(mut buckets) = self.buckets.lock() else {
+            return;
+        };
+        if rpm == 0 {
+            buckets.remove(domain);
+        } else if buckets.get(domain).map(|b| b.rpm) != Some(rpm) {
+            buckets.insert(domain, TokenBucket::new(rpm, Instant::now()));
+        }
+    }
+
+    fn try_acquire(&self, host: &str, now: Instant) -> Result<(), Duration> {
+        let Ok(mut buckets) = self.buckets.lock() else {
+            return Ok(());
+        };
+        let bucket = buckets.iter_mut().find(|(domain, _)| {
+            host == **domain
+                || host
+                    .strip_suffix(**domain)
+                    .is_some_and(|prefix| prefix.ends_with('.'))
+        });
+        match bucket {
+            Some((_, bucket)) => bucket.try_acquire(now),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Apply the saved limit for `key` to the limiter (unset = unlimited). Returns
+/// false if `key` is not a rate limit key.
+pub fn apply_rate_limit_setting(conn: &Connection, key: &str) -> bool {
+    let Some((_, domain)) = RATE_LIMIT_SETTINGS.iter().find(|(k, _)| *k == key) else {
+        return false;
+    };
+    let rpm = Key::from_name(key)
+        .map(|k| get_typed::<u32>(conn, k))
+        .unwrap_or(Ok(0))
+        .unwrap_or_else(|e| {
+            tracing::warn!(key = %key, "Ignoring rate limit setting: {}", e);
+            0
+        });
+    rate_limiter().set_limit(domain, rpm);
+    true
+}
+
+/// Load all rate limit settings (startup and after unlock)
+pub fn load_rate_limit_settings(conn: &Connection) {
+    for (key, _) in RATE_LIMIT_SETTINGS {
+        apply_rate_limit_setting(conn, key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_bucket_burst_then_refill() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(60, now);
+        for _ in 0..60 {
+            assert!(bucket.try_acquire(now).is_ok());
+        }
+        let wait = bucket.try_acquire(now).unwrap_err();
+        assert!(wait <= Duration::from_secs(1));
+        // 60 rpm refills one token per second
+        assert!(bucket.try_acquire(now + Duration::from_secs(1)).is_ok());
+    }
+
+    #[test]
+    fn test_limiter_matches_domain_and_subdomains_only() {
+        let limiter = DomainRateLimiter::default();
+        limiter.set_limit("upwork.com", 1);
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire("www.upwork.com", now).is_ok());
+        assert!(limiter.try_acquire("upwork.com", now).is_err());
+        // Not a subdomain, and unconfigured domains are unlimited
+        assert!(limiter.try_acquire("notupwork.com", now).is_ok());
+        assert!(limiter.try_acquire("api.anthropic.com", now).is_ok());
+
+        limiter.set_limit("upwork.com", 0);
+        assert!(limiter.try_acquire("www.upwork.com", now).is_ok());
+    }
+
+    #[test]
+    fn test_metrics_percentiles_and_ring_buffer() {
+        let metrics = RequestMetricsState::default();
+        for ms in 1..=150u64 {
+            metrics.record_response("api.anthropic.com", Duration::from_millis(ms), ms % 10 != 0);
+        }
+        metrics.record_failure("api.anthropic.com");
+        metrics.record_rate_limited("api.anthropic.com");
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        let m = &snapshot[0];
+        assert_eq!(m.requests, 151);
+        assert_eq!(m.errors, 16);
+        assert_eq!(m.locally_rate_limited, 1);
+        // Only the last 100 samples (51..=150) are kept
+        assert_eq!(m.p50_ms, Some(100));
+        assert_eq!(m.p95_ms, Some(145));
+        assert_eq!(m.p99_ms, Some(149));
+
+        metrics.reset();
+        assert!(metrics.snapshot().is_empty());
+    }
+
+    #[test]
+    fn test_local_rate_limit_error_prefix() {
+        let err = HttpError::LocallyRateLimited {
+            domain: "api.anthropic.com".to_string(),
+            retry_after_secs: 3,
+        };
+        assert!(err.to_string().starts_with(LOCAL_RATE_LIMIT_PREFIX));
+        assert!(err.is_locally_rate_limited());
+        assert!(!err.is_timeout());
+    }
+}
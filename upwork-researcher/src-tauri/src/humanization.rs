@@ -11,6 +11,8 @@
 //! - NFR-15: Professional quality maintained
 
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
 
 // ============================================================================
 // Humanization Intensity
@@ -56,6 +58,12 @@ impl HumanizationIntensity {
         }
     }
 
+    /// Settings key for this intensity's optional safety threshold
+    /// (e.g. `safety_threshold_heavy`); unset falls back to `safety_threshold`.
+    pub fn safety_threshold_setting_key(&self) -> String {
+        format!("safety_threshold_{}", self.as_str())
+    }
+
     /// Check if a string is a valid intensity value.
     pub fn is_valid(s: &str) -> bool {
         Self::from_str_value(s).is_ok()
@@ -71,6 +79,16 @@ impl HumanizationIntensity {
         }
     }
 
+    /// The instructions this level adds to the system prompt; None for Off
+    pub fn instructions(&self) -> Option<&'static IntensityInstructions> {
+        match self {
+            Self::Off => None,
+            Self::Light => Some(&LIGHT_INSTRUCTIONS),
+            Self::Medium => Some(&MEDIUM_INSTRUCTIONS),
+            Self::Heavy => Some(&HEAVY_INSTRUCTIONS),
+        }
+    }
+
     /// Escalate to the next higher intensity level for re-humanization (Story 3.4).
     ///
     /// Returns the escalated intensity or an error if already at maximum.
@@ -153,98 +171,715 @@ pub const AI_HEDGING_PHRASES: &[&str] = &[
     "i bring a wealth of",
 ];
 
+// ============================================================================
+// Configurable AI-Tell Phrases
+// ============================================================================
+
+/// Settings key: JSON array of user-added AI-tell phrases
+pub const AI_TELL_USER_PHRASES_SETTING: &str = "ai_tell_user_phrases";
+/// Settings key: "word" (whole words only) or "word_prefix" (default; "delve" also matches "delved")
+pub const AI_TELL_MATCH_MODE_SETTING: &str = "ai_tell_match_mode";
+
+const MAX_USER_AI_TELL_PHRASES: usize = 200;
+const MAX_AI_TELL_PHRASE_CHARS: usize = 60;
+
+/// Phrases pushed by the remote config, added to the built-in list
+static REMOTE_AI_TELLS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+/// User additions from `ai_tell_user_phrases`
+static USER_AI_TELLS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+/// `ai_tell_match_mode` is "word"
+static WHOLE_WORD_MATCH: AtomicBool = AtomicBool::new(false);
+
+/// How AI-tell phrases are matched. Both are case-insensitive and require a
+/// word start, so "realm" never matches "overrealm".
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AiTellMatchMode {
+    /// The phrase must end at a word boundary: "delve" doesn't match "delved"
+    Word,
+    /// Inflections match too: "delve" matches "delved", "leverage" matches "leveraging"
+    #[default]
+    WordPrefix,
+}
+
+impl AiTellMatchMode {
+    pub fn from_str_value(value: &str) -> Result<Self, String> {
+        match value {
+            "word" => Ok(Self::Word),
+            "word_prefix" => Ok(Self::WordPrefix),
+            _ => Err(format!(
+                "Invalid ai_tell_match_mode '{}'. Valid values: word, word_prefix",
+                value
+            )),
+        }
+    }
+}
+
+/// The AI-tell phrase lists used for detection. All three lists are always
+/// checked, so an empty user list never disables the built-in set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiTellPhrases {
+    /// `AI_TELLS` and `AI_HEDGING_PHRASES`
+    pub built_in: Vec<String>,
+    /// Added by the remote config
+    pub remote: Vec<String>,
+    /// Added by the user
+    pub user: Vec<String>,
+    pub match_mode: AiTellMatchMode,
+}
+
+impl AiTellPhrases {
+    /// Built-in phrases only, default matching
+    pub fn built_in_only() -> Self {
+        Self {
+            built_in: AI_TELLS
+                .iter()
+                .chain(AI_HEDGING_PHRASES)
+                .map(|p| p.to_string())
+                .collect(),
+            remote: Vec::new(),
+            user: Vec::new(),
+            match_mode: AiTellMatchMode::default(),
+        }
+    }
+
+    /// Every phrase once, in order: built-in, remote, user
+    pub fn all(&self) -> Vec<&str> {
+        let mut seen = std::collections::HashSet::new();
+        self.built_in
+            .iter()
+            .chain(&self.remote)
+            .chain(&self.user)
+            .map(String::as_str)
+            .filter(|p| seen.insert(*p))
+            .collect()
+    }
+
+    fn contains(&self, phrase: &str) -> bool {
+        self.all().contains(&phrase)
+    }
+}
+
+/// The phrase lists currently in effect (built-in + remote + user)
+pub fn active_ai_tell_phrases() -> AiTellPhrases {
+    let read = |lock: &RwLock<Vec<String>>| {
+        lock.read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    };
+    AiTellPhrases {
+        remote: read(&REMOTE_AI_TELLS),
+        user: read(&USER_AI_TELLS),
+        match_mode: if WHOLE_WORD_MATCH.load(Ordering::Relaxed) {
+            AiTellMatchMode::Word
+        } else {
+            AiTellMatchMode::WordPrefix
+        },
+        ..AiTellPhrases::built_in_only()
+    }
+}
+
+/// Trim, lowercase and collapse whitespace; reject empty or overlong phrases
+pub fn normalize_ai_tell_phrase(phrase: &str) -> Result<String, String> {
+    let normalized = phrase
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    if !normalized.chars().any(|c| c.is_alphanumeric()) {
+        return Err("AI-tell phrase cannot be empty".to_string());
+    }
+    if normalized.chars().count() > MAX_AI_TELL_PHRASE_CHARS {
+        return Err(format!(
+            "AI-tell phrase too long (max {} characters)",
+            MAX_AI_TELL_PHRASE_CHARS
+        ));
+    }
+    Ok(normalized)
+}
+
+/// Normalize a phrase list, dropping invalid entries and duplicates
+fn normalize_phrase_list(phrases: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for phrase in phrases {
+        match normalize_ai_tell_phrase(phrase) {
+            Ok(p) if !normalized.contains(&p) => normalized.push(p),
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Ignoring AI-tell phrase: {}", e),
+        }
+    }
+    normalized
+}
+
+/// Replace the remote phrase list (called whenever a config is applied)
+pub fn set_remote_ai_tell_phrases(phrases: &[String]) {
+    *REMOTE_AI_TELLS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = normalize_phrase_list(phrases);
+}
+
+/// Validate an `ai_tell_user_phrases` value (JSON array of strings) before it is saved
+pub fn validate_user_ai_tell_phrases(value: &str) -> Result<Vec<String>, String> {
+    let phrases: Vec<String> = serde_json::from_str(value).map_err(|e| {
+        format!(
+            "ai_tell_user_phrases must be a JSON array of strings: {}",
+            e
+        )
+    })?;
+    if phrases.len() > MAX_USER_AI_TELL_PHRASES {
+        return Err(format!(
+            "Too many AI-tell phrases (max {})",
+            MAX_USER_AI_TELL_PHRASES
+        ));
+    }
+    phrases
+        .iter()
+        .map(|p| normalize_ai_tell_phrase(p))
+        .collect()
+}
+
+/// Apply a saved AI-tell setting; other keys are ignored. Unset or invalid
+/// values fall back to defaults (no user phrases, word-prefix matching).
+pub fn apply_ai_tell_setting(key: &str, value: Option<&str>) {
+    if key == AI_TELL_USER_PHRASES_SETTING {
+        let phrases = value
+            .and_then(|v| validate_user_ai_tell_phrases(v).ok())
+            .unwrap_or_default();
+        *USER_AI_TELLS
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = normalize_phrase_list(&phrases);
+    } else if key == AI_TELL_MATCH_MODE_SETTING {
+        WHOLE_WORD_MATCH.store(value == Some("word"), Ordering::Relaxed);
+    }
+}
+
+/// Load the AI-tell settings (startup and after unlock)
+pub fn load_ai_tell_settings(conn: &rusqlite::Connection) {
+    for key in [AI_TELL_USER_PHRASES_SETTING, AI_TELL_MATCH_MODE_SETTING] {
+        let value = crate::db::queries::settings::get_setting(conn, key)
+            .ok()
+            .flatten();
+        apply_ai_tell_setting(key, value.as_deref());
+    }
+}
+
+/// Read the stored user phrases (invalid JSON = none)
+fn stored_user_ai_tell_phrases(conn: &rusqlite::Connection) -> Result<Vec<String>, String> {
+    let value = crate::db::queries::settings::get_setting(conn, AI_TELL_USER_PHRASES_SETTING)
+        .map_err(|e| format!("Failed to read AI-tell phrases: {}", e))?;
+    Ok(value
+        .and_then(|v| validate_user_ai_tell_phrases(&v).ok())
+        .unwrap_or_default())
+}
+
+fn save_user_ai_tell_phrases(
+    conn: &rusqlite::Connection,
+    phrases: &[String],
+) -> Result<(), String> {
+    let value = serde_json::to_string(phrases)
+        .map_err(|e| format!("Failed to serialize AI-tell phrases: {}", e))?;
+    crate::db::queries::settings::set_setting(conn, AI_TELL_USER_PHRASES_SETTING, &value)
+        .map_err(|e| format!("Failed to save AI-tell phrases: {}", e))?;
+    apply_ai_tell_setting(AI_TELL_USER_PHRASES_SETTING, Some(&value));
+    Ok(())
+}
+
+/// Add a user AI-tell phrase. Phrases already detected (built-in, remote, or
+/// user) are rejected.
+pub fn add_user_ai_tell_phrase(conn: &rusqlite::Connection, phrase: &str) -> Result<(), String> {
+    let phrase = normalize_ai_tell_phrase(phrase)?;
+    let mut phrases = stored_user_ai_tell_phrases(conn)?;
+    let active = AiTellPhrases {
+        user: phrases.clone(),
+        ..active_ai_tell_phrases()
+    };
+    if active.contains(&phrase) {
+        return Err(format!("'{}' is already an AI-tell phrase", phrase));
+    }
+    if phrases.len() >= MAX_USER_AI_TELL_PHRASES {
+        return Err(format!(
+            "Too many AI-tell phrases (max {})",
+            MAX_USER_AI_TELL_PHRASES
+        ));
+    }
+    phrases.push(phrase);
+    save_user_ai_tell_phrases(conn, &phrases)
+}
+
+/// Remove a user AI-tell phrase. Built-in and remote phrases can't be removed.
+pub fn remove_user_ai_tell_phrase(conn: &rusqlite::Connection, phrase: &str) -> Result<(), String> {
+    let phrase = normalize_ai_tell_phrase(phrase)?;
+    let mut phrases = stored_user_ai_tell_phrases(conn)?;
+    let Some(index) = phrases.iter().position(|p| *p == phrase) else {
+        let active = active_ai_tell_phrases();
+        if active.built_in.contains(&phrase) || active.remote.contains(&phrase) {
+            return Err(format!(
+                "'{}' is a built-in AI-tell phrase and can't be removed",
+                phrase
+            ));
+        }
+        return Err(format!("'{}' is not a user AI-tell phrase", phrase));
+    };
+    phrases.remove(index);
+    save_user_ai_tell_phrases(conn, &phrases)
+}
+
+/// Does lowercase `text` contain lowercase `phrase` starting at a word start
+/// (and, in `Word` mode, ending at a word end)?
+fn contains_phrase(text: &str, phrase: &str, mode: AiTellMatchMode) -> bool {
+    text.match_indices(phrase).any(|(i, _)| {
+        let starts_word = !text[..i]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric());
+        let ends_word = mode == AiTellMatchMode::WordPrefix
+            || !text[i + phrase.len()..]
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_alphanumeric());
+        starts_word && ends_word
+    })
+}
+
+/// AI-tell phrases found in `text`, in list order
+pub fn find_ai_tells(text: &str, phrases: &AiTellPhrases) -> Vec<String> {
+    let lower_text = text.to_lowercase();
+    phrases
+        .all()
+        .into_iter()
+        .filter(|phrase| contains_phrase(&lower_text, phrase, phrases.match_mode))
+        .map(str::to_string)
+        .collect()
+}
+
 // ============================================================================
 // Prompt Templates
 // ============================================================================
 
 /// Lightweight guardrails appended to all non-off prompts.
 /// TD-1: Relaxed from the original "quality constraints" which fought humanization.
-const QUALITY_GUARDRAILS: &str = "\n\nQUALITY GUARDRAILS:
-- Stay professional — casual doesn't mean sloppy
-- Technical claims must be accurate
-- The message should be clear and actionable
-- Typos are NOT humanization — maintain correct spelling";
+const QUALITY_GUARDRAILS: &[&str] = &[
+    "Stay professional — casual doesn't mean sloppy",
+    "Technical claims must be accurate",
+    "The message should be clear and actionable",
+    "Typos are NOT humanization — maintain correct spelling",
+];
+
+/// What a prompt rule is about, for `describe_intensity`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleKind {
+    /// Contraction targets
+    Contractions,
+    /// Sentence and paragraph length variation
+    SentenceVariation,
+    /// Everything else (openers, asides, word choice, specifics)
+    Other,
+}
+
+/// One bullet of a prompt section
+#[derive(Debug, Clone, Copy)]
+pub struct PromptRule {
+    pub kind: RuleKind,
+    pub text: &'static str,
+}
+
+const fn rule(kind: RuleKind, text: &'static str) -> PromptRule {
+    PromptRule { kind, text }
+}
+
+/// A headed list of rules ("GUIDELINES:", "MANDATORY STRUCTURE ...:")
+#[derive(Debug, Clone, Copy)]
+pub struct PromptSection {
+    pub heading: &'static str,
+    pub rules: &'static [PromptRule],
+}
+
+/// How the avoid lists are phrased: suggestions (Light) or a hard ban (Medium, Heavy)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvoidStyle {
+    Guideline,
+    Forbidden,
+}
+
+/// Everything an intensity level instructs. `get_humanization_prompt` renders it
+/// into the prompt and `describe_intensity` summarizes it, so the description
+/// can't drift from what generation actually sends.
+#[derive(Debug, Clone, Copy)]
+pub struct IntensityInstructions {
+    pub opening: &'static str,
+    pub sections: &'static [PromptSection],
+    pub avoid_style: AvoidStyle,
+    pub avoid_words: &'static [&'static str],
+    pub avoid_phrases: &'static [&'static str],
+    /// Structural patterns to avoid, one sentence each
+    pub avoid_patterns: &'static [&'static str],
+    pub tone: Option<&'static str>,
+}
+
+const LIGHT_AVOID_WORDS: &[&str] = &[
+    "delve",
+    "leverage",
+    "utilize",
+    "robust",
+    "multifaceted",
+    "innovative",
+    "comprehensive",
+    "seamless",
+    "streamline",
+    "cutting-edge",
+    "holistic",
+    "transformative",
+    "facilitate",
+    "optimize",
+    "spearheaded",
+];
+
+const LIGHT_AVOID_PHRASES: &[&str] = &[
+    "I am excited to",
+    "I look forward to",
+    "It's important to note",
+    "proven track record",
+    "I am confident that",
+    "don't hesitate to",
+];
+
+/// Words banned at Medium and Heavy
+const FORBIDDEN_WORDS: &[&str] = &[
+    "delve",
+    "leverage",
+    "utilize",
+    "robust",
+    "multifaceted",
+    "tapestry",
+    "holistic",
+    "nuanced",
+    "paradigm",
+    "game-changing",
+    "transformative",
+    "innovative",
+    "cutting-edge",
+    "state-of-the-art",
+    "comprehensive",
+    "seamless",
+    "streamline",
+    "optimize",
+    "spearheaded",
+    "synergy",
+    "ecosystem",
+    "landscape",
+    "realm",
+    "endeavor",
+    "keen",
+    "pivotal",
+    "elevate",
+    "foster",
+    "harness",
+    "empower",
+    "facilitate",
+    "cornerstone",
+    "testament",
+    "underscore",
+    "meticulous",
+];
+
+/// Phrases banned at Medium and Heavy
+const FORBIDDEN_PHRASES: &[&str] = &[
+    "I am excited to",
+    "I am confident that",
+    "I would be happy to",
+    "It's important to note",
+    "It is worth mentioning",
+    "In today's",
+    "In the ever-evolving",
+    "I look forward to",
+    "rest assured",
+    "don't hesitate to",
+    "feel free to",
+    "my extensive experience",
+    "proven track record",
+    "I am well-versed in",
+    "I bring a wealth of",
+];
+
+const LIGHT_INSTRUCTIONS: IntensityInstructions = IntensityInstructions {
+    opening: "Write naturally with a conversational touch. About 0.5-1 subtle human elements per 100 words.",
+    sections: &[PromptSection {
+        heading: "GUIDELINES",
+        rules: &[
+            rule(
+                RuleKind::Contractions,
+                "Use contractions where natural (I'm, you're, I've, that's, won't, can't)",
+            ),
+            rule(
+                RuleKind::SentenceVariation,
+                "Vary sentence length — mix short and long",
+            ),
+            rule(RuleKind::Other, "Avoid overly formal or stilted language"),
+        ],
+    }],
+    avoid_style: AvoidStyle::Guideline,
+    avoid_words: LIGHT_AVOID_WORDS,
+    avoid_phrases: LIGHT_AVOID_PHRASES,
+    avoid_patterns: &[],
+    tone: None,
+};
+
+const MEDIUM_INSTRUCTIONS: IntensityInstructions = IntensityInstructions {
+    opening: "CRITICAL: Write this as a real human freelancer would actually type it. This is a quick, confident message — NOT a formal cover letter.",
+    sections: &[
+        PromptSection {
+            heading: "MANDATORY STRUCTURE (follow these exactly)",
+            rules: &[
+                rule(
+                    RuleKind::SentenceVariation,
+                    "Do NOT write exactly 3 equal paragraphs. Use 2-4 paragraphs of varying length.",
+                ),
+                rule(
+                    RuleKind::SentenceVariation,
+                    "At least one paragraph must be only 1-2 sentences. Another must be 3+ sentences.",
+                ),
+                rule(
+                    RuleKind::SentenceVariation,
+                    "Include at least ONE sentence under 6 words (e.g. \"Here's my take.\" or \"Happy to chat.\")",
+                ),
+                rule(
+                    RuleKind::SentenceVariation,
+                    "Include at least ONE sentence over 20 words.",
+                ),
+                rule(
+                    RuleKind::Other,
+                    "Start at least one sentence with \"And\", \"But\", or \"So\".",
+                ),
+                rule(
+                    RuleKind::Other,
+                    "Use at least one parenthetical aside (like this) or em-dash — for a natural break.",
+                ),
+            ],
+        },
+        PromptSection {
+            heading: "MANDATORY WORD CHOICE",
+            rules: &[
+                rule(
+                    RuleKind::Contractions,
+                    "Use contractions everywhere: I'm, I've, you're, that's, won't, can't, I'd, we'll — minimum 6 per 200 words.",
+                ),
+                rule(
+                    RuleKind::Other,
+                    "Reference ONE specific detail from past experience (a project type, a metric, a tool).",
+                ),
+                rule(
+                    RuleKind::Other,
+                    "Include at least one casual expression: \"happy to chat\", \"sounds like\", \"right up my alley\", \"pretty straightforward\", \"the short version\", \"quick note\".",
+                ),
+                rule(
+                    RuleKind::Other,
+                    "Replace formal verbs with casual ones: \"utilize\" → \"use\", \"implement\" → \"build\", \"facilitate\" → \"help\", \"demonstrate\" → \"show\".",
+                ),
+            ],
+        },
+    ],
+    avoid_style: AvoidStyle::Forbidden,
+    avoid_words: FORBIDDEN_WORDS,
+    avoid_phrases: FORBIDDEN_PHRASES,
+    avoid_patterns: &[
+        "Starting 3+ sentences the same way.",
+        "Every paragraph being similar length.",
+        "Using the same transition word twice.",
+    ],
+    tone: Some("Imagine you're messaging a potential client about a project that genuinely interests you — confident but not salesy, direct but not blunt."),
+};
+
+const HEAVY_INSTRUCTIONS: IntensityInstructions = IntensityInstructions {
+    opening: "CRITICAL: Write exactly as a busy, confident freelancer would — typing quickly, genuine interest. Think Slack message to a potential client, not cover letter.",
+    sections: &[
+        PromptSection {
+            heading: "MANDATORY STRUCTURE (follow these exactly)",
+            rules: &[
+                rule(
+                    RuleKind::SentenceVariation,
+                    "Use 2-4 paragraphs of DRAMATICALLY different lengths. One short (1-2 sentences), one longer (3-4 sentences).",
+                ),
+                rule(
+                    RuleKind::SentenceVariation,
+                    "Include at least TWO sentences under 6 words. (\"That's my jam.\" \"Happy to jump in.\" \"Quick background.\")",
+                ),
+                rule(
+                    RuleKind::SentenceVariation,
+                    "Include ONE sentence over 25 words with a natural mid-sentence break (dash or parenthetical).",
+                ),
+                rule(
+                    RuleKind::Other,
+                    "Start at least TWO sentences with \"And\", \"But\", \"So\", or \"Plus\".",
+                ),
+                rule(
+                    RuleKind::Other,
+                    "Include one aside in parentheses or after a dash.",
+                ),
+                rule(
+                    RuleKind::Other,
+                    "Break one expected pattern: use a rhetorical question, a self-correction (\"well, actually...\"), or an incomplete thought.",
+                ),
+            ],
+        },
+        PromptSection {
+            heading: "MANDATORY WORD CHOICE",
+            rules: &[
+                rule(
+                    RuleKind::Contractions,
+                    "Contractions everywhere — minimum 8 per 200 words. Never write \"I am\" when \"I'm\" works.",
+                ),
+                rule(
+                    RuleKind::Other,
+                    "Reference TWO specific details (project type + a metric, tool, or outcome from experience).",
+                ),
+                rule(
+                    RuleKind::Other,
+                    "Include at least TWO casual expressions.",
+                ),
+                rule(
+                    RuleKind::Other,
+                    "Include ONE thinking-aloud moment: \"I'm thinking...\", \"The way I see it...\", \"Off the top of my head...\".",
+                ),
+                rule(
+                    RuleKind::Other,
+                    "One or two natural fillers: \"pretty much\", \"honestly\", \"basically\".",
+                ),
+            ],
+        },
+    ],
+    avoid_style: AvoidStyle::Forbidden,
+    avoid_words: FORBIDDEN_WORDS,
+    avoid_phrases: FORBIDDEN_PHRASES,
+    avoid_patterns: &[
+        "Starting 3+ sentences the same way.",
+        "Every paragraph being similar length.",
+        "Using the same transition word twice.",
+        "Numbered lists in proposals.",
+    ],
+    tone: Some("You just saw a job post that's exactly what you do, and you're genuinely stoked. Write like it."),
+};
+
+/// `"a", "b", "c"` as the prompts list words and phrases
+fn quoted_list(items: &[&str]) -> String {
+    items
+        .iter()
+        .map(|item| format!("\"{}\"", item))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn render_instructions(instructions: &IntensityInstructions) -> String {
+    let mut prompt = format!("\n{}", instructions.opening);
+    for section in instructions.sections {
+        prompt.push_str(&format!("\n\n{}:", section.heading));
+        for rule in section.rules {
+            prompt.push_str(&format!("\n- {}", rule.text));
+        }
+    }
+
+    let words = quoted_list(instructions.avoid_words);
+    let phrases = quoted_list(instructions.avoid_phrases);
+    match instructions.avoid_style {
+        AvoidStyle::Guideline => prompt.push_str(&format!(
+            "\n\nAVOID these AI-signaling words: {}\n\nAVOID these phrases: {}",
+            words, phrases
+        )),
+        AvoidStyle::Forbidden => prompt.push_str(&format!(
+            "\n\nABSOLUTELY FORBIDDEN (these instantly flag AI detection):\nWords: {}\nPhrases: {}",
+            words, phrases
+        )),
+    }
+    if !instructions.avoid_patterns.is_empty() {
+        prompt.push_str(&format!(
+            "\nPatterns: {}",
+            instructions.avoid_patterns.join(" ")
+        ));
+    }
+    if let Some(tone) = instructions.tone {
+        prompt.push_str(&format!("\n\nTONE: {}", tone));
+    }
+
+    prompt.push_str("\n\nQUALITY GUARDRAILS:");
+    for guardrail in QUALITY_GUARDRAILS {
+        prompt.push_str(&format!("\n- {}", guardrail));
+    }
+    prompt
+}
 
 /// Build the humanization prompt block for the given intensity.
 /// Returns `None` if intensity is Off (no humanization instructions needed).
 ///
 /// TD-1 rewrite: Prompts now use specific, countable requirements targeting
 /// the statistical signals (burstiness, perplexity variation, structural
-/// unpredictability) that AI detection tools measure.
+/// unpredictability) that AI detection tools measure. The text comes from
+/// `HumanizationIntensity::instructions`.
 pub fn get_humanization_prompt(intensity: &HumanizationIntensity) -> Option<String> {
-    match intensity {
-        HumanizationIntensity::Off => None,
-
-        HumanizationIntensity::Light => Some(format!(
-            r#"
-Write naturally with a conversational touch. About 0.5-1 subtle human elements per 100 words.
-
-GUIDELINES:
-- Use contractions where natural (I'm, you're, I've, that's, won't, can't)
-- Vary sentence length — mix short and long
-- Avoid overly formal or stilted language
-
-AVOID these AI-signaling words: "delve", "leverage", "utilize", "robust", "multifaceted", "innovative", "comprehensive", "seamless", "streamline", "cutting-edge", "holistic", "transformative", "facilitate", "optimize", "spearheaded"
+    intensity.instructions().map(render_instructions)
+}
 
-AVOID these phrases: "I am excited to", "I look forward to", "It's important to note", "proven track record", "I am confident that", "don't hesitate to"{}"#,
-            QUALITY_GUARDRAILS
-        )),
+/// What an intensity level instructs, for the settings UI
+/// (`describe_humanization_intensity`). Built from `IntensityInstructions`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntensityDescription {
+    pub intensity: HumanizationIntensity,
+    /// Touches per 100 words (`rate_description`)
+    pub rate: &'static str,
+    /// The prompt's opening instruction; None for Off
+    pub summary: Option<&'static str>,
+    pub contraction_rules: Vec<&'static str>,
+    pub sentence_variation_rules: Vec<&'static str>,
+    pub other_rules: Vec<&'static str>,
+    /// True when the avoid lists are a hard ban rather than a suggestion
+    pub avoidance_strict: bool,
+    pub avoided_words: Vec<&'static str>,
+    pub avoided_phrases: Vec<&'static str>,
+    pub avoided_patterns: Vec<&'static str>,
+    pub tone: Option<&'static str>,
+    pub quality_guardrails: Vec<&'static str>,
+}
 
-        HumanizationIntensity::Medium => Some(format!(
-            r#"
-CRITICAL: Write this as a real human freelancer would actually type it. This is a quick, confident message — NOT a formal cover letter.
-
-MANDATORY STRUCTURE (follow these exactly):
-- Do NOT write exactly 3 equal paragraphs. Use 2-4 paragraphs of varying length.
-- At least one paragraph must be only 1-2 sentences. Another must be 3+ sentences.
-- Include at least ONE sentence under 6 words (e.g. "Here's my take." or "Happy to chat.")
-- Include at least ONE sentence over 20 words.
-- Start at least one sentence with "And", "But", or "So".
-- Use at least one parenthetical aside (like this) or em-dash — for a natural break.
-
-MANDATORY WORD CHOICE:
-- Use contractions everywhere: I'm, I've, you're, that's, won't, can't, I'd, we'll — minimum 6 per 200 words.
-- Reference ONE specific detail from past experience (a project type, a metric, a tool).
-- Include at least one casual expression: "happy to chat", "sounds like", "right up my alley", "pretty straightforward", "the short version", "quick note".
-- Replace formal verbs with casual ones: "utilize" → "use", "implement" → "build", "facilitate" → "help", "demonstrate" → "show".
-
-ABSOLUTELY FORBIDDEN (these instantly flag AI detection):
-Words: "delve", "leverage", "utilize", "robust", "multifaceted", "tapestry", "holistic", "nuanced", "paradigm", "game-changing", "transformative", "innovative", "cutting-edge", "state-of-the-art", "comprehensive", "seamless", "streamline", "optimize", "spearheaded", "synergy", "ecosystem", "landscape", "realm", "endeavor", "keen", "pivotal", "elevate", "foster", "harness", "empower", "facilitate", "cornerstone", "testament", "underscore", "meticulous"
-Phrases: "I am excited to", "I am confident that", "I would be happy to", "It's important to note", "It is worth mentioning", "In today's", "In the ever-evolving", "I look forward to", "rest assured", "don't hesitate to", "feel free to", "my extensive experience", "proven track record", "I am well-versed in", "I bring a wealth of"
-Patterns: Starting 3+ sentences the same way. Every paragraph being similar length. Using the same transition word twice.
-
-TONE: Imagine you're messaging a potential client about a project that genuinely interests you — confident but not salesy, direct but not blunt.{}"#,
-            QUALITY_GUARDRAILS
-        )),
+/// Summarize the instructions `get_humanization_prompt` sends for an intensity
+pub fn describe_intensity(intensity: HumanizationIntensity) -> IntensityDescription {
+    let mut description = IntensityDescription {
+        intensity,
+        rate: intensity.rate_description(),
+        summary: None,
+        contraction_rules: Vec::new(),
+        sentence_variation_rules: Vec::new(),
+        other_rules: Vec::new(),
+        avoidance_strict: false,
+        avoided_words: Vec::new(),
+        avoided_phrases: Vec::new(),
+        avoided_patterns: Vec::new(),
+        tone: None,
+        quality_guardrails: Vec::new(),
+    };
+    let Some(instructions) = intensity.instructions() else {
+        return description;
+    };
 
-        HumanizationIntensity::Heavy => Some(format!(
-            r#"
-CRITICAL: Write exactly as a busy, confident freelancer would — typing quickly, genuine interest. Think Slack message to a potential client, not cover letter.
-
-MANDATORY STRUCTURE (follow these exactly):
-- Use 2-4 paragraphs of DRAMATICALLY different lengths. One short (1-2 sentences), one longer (3-4 sentences).
-- Include at least TWO sentences under 6 words. ("That's my jam." "Happy to jump in." "Quick background.")
-- Include ONE sentence over 25 words with a natural mid-sentence break (dash or parenthetical).
-- Start at least TWO sentences with "And", "But", "So", or "Plus".
-- Include one aside in parentheses or after a dash.
-- Break one expected pattern: use a rhetorical question, a self-correction ("well, actually..."), or an incomplete thought.
-
-MANDATORY WORD CHOICE:
-- Contractions everywhere — minimum 8 per 200 words. Never write "I am" when "I'm" works.
-- Reference TWO specific details (project type + a metric, tool, or outcome from experience).
-- Include at least TWO casual expressions.
-- Include ONE thinking-aloud moment: "I'm thinking...", "The way I see it...", "Off the top of my head...".
-- One or two natural fillers: "pretty much", "honestly", "basically".
-
-ABSOLUTELY FORBIDDEN (these instantly flag AI detection):
-Words: "delve", "leverage", "utilize", "robust", "multifaceted", "tapestry", "holistic", "nuanced", "paradigm", "game-changing", "transformative", "innovative", "cutting-edge", "state-of-the-art", "comprehensive", "seamless", "streamline", "optimize", "spearheaded", "synergy", "ecosystem", "landscape", "realm", "endeavor", "keen", "pivotal", "elevate", "foster", "harness", "empower", "facilitate", "cornerstone", "testament", "underscore", "meticulous"
-Phrases: "I am excited to", "I am confident that", "I would be happy to", "It's important to note", "It is worth mentioning", "In today's", "In the ever-evolving", "I look forward to", "rest assured", "don't hesitate to", "feel free to", "my extensive experience", "proven track record", "I am well-versed in", "I bring a wealth of"
-Patterns: Starting 3+ sentences the same way. Every paragraph being similar length. Using the same transition word twice. Numbered lists in proposals.
-
-TONE: You just saw a job post that's exactly what you do, and you're genuinely stoked. Write like it.{}"#,
-            QUALITY_GUARDRAILS
-        )),
+    description.summary = Some(instructions.opening);
+    for rule in instructions
+        .sections
+        .iter()
+        .flat_map(|section| section.rules)
+    {
+        let rules = match rule.kind {
+            RuleKind::Contractions => &mut description.contraction_rules,
+            RuleKind::SentenceVariation => &mut description.sentence_variation_rules,
+            RuleKind::Other => &mut description.other_rules,
+        };
+        rules.push(rule.text);
     }
+    description.avoidance_strict = instructions.avoid_style == AvoidStyle::Forbidden;
+    description.avoided_words = instructions.avoid_words.to_vec();
+    description.avoided_phrases = instructions.avoid_phrases.to_vec();
+    description.avoided_patterns = instructions.avoid_patterns.to_vec();
+    description.tone = instructions.tone;
+    description.quality_guardrails = QUALITY_GUARDRAILS.to_vec();
+    description
 }
 
 /// Build a complete system prompt by appending humanization instructions
@@ -348,6 +983,46 @@ pub struct HumanizationMetrics {
     pub ai_tells_found: Vec<String>,
     /// Humanization rate per 100 words.
     pub rate_per_100_words: f32,
+    /// Average sentence length in words.
+    #[serde(default)]
+    pub sentence_length_mean: f32,
+    /// Standard deviation of sentence length in words.
+    #[serde(default)]
+    pub sentence_length_stddev: f32,
+    /// Sentence length stddev / mean. Low values mean uniform, robotic rhythm.
+    #[serde(default)]
+    pub burstiness_index: f32,
+    /// Percentage of sentences with a passive construction (heuristic).
+    #[serde(default)]
+    pub passive_voice_pct: f32,
+    /// "-ly" adverbs per 100 words.
+    #[serde(default)]
+    pub adverb_density: f32,
+    /// Per-paragraph breakdown, in text order.
+    #[serde(default)]
+    pub paragraphs: Vec<ParagraphMetrics>,
+    /// Index into `paragraphs` of the highest `robotic_score`, if any.
+    #[serde(default)]
+    pub most_robotic_paragraph: Option<usize>,
+}
+
+/// Sentence rhythm and style metrics for one paragraph (blank-line separated).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParagraphMetrics {
+    /// Byte offset of the paragraph start in the analyzed text.
+    pub start: usize,
+    /// Byte offset of the paragraph end in the analyzed text.
+    pub end: usize,
+    pub word_count: usize,
+    pub sentence_count: usize,
+    pub sentence_length_mean: f32,
+    pub sentence_length_stddev: f32,
+    pub burstiness_index: f32,
+    pub passive_voice_pct: f32,
+    pub adverb_density: f32,
+    /// 0-100, higher = more uniform, passive, and adverb-heavy.
+    pub robotic_score: f32,
 }
 
 /// Common contractions that indicate human-like writing.
@@ -417,11 +1092,201 @@ const MULTI_WORD_TRANSITIONS: &[&str] = &[
     "long story short",
 ];
 
+/// Forms of "to be" that precede a past participle in passive constructions.
+const BE_FORMS: &[&str] = &["am", "is", "are", "was", "were", "be", "been", "being"];
+
+/// Common irregular past participles (regular ones end in "-ed").
+const IRREGULAR_PARTICIPLES: &[&str] = &[
+    "built",
+    "done",
+    "made",
+    "given",
+    "taken",
+    "written",
+    "known",
+    "shown",
+    "seen",
+    "found",
+    "sent",
+    "paid",
+    "told",
+    "held",
+    "kept",
+    "left",
+    "brought",
+    "bought",
+    "thought",
+    "caught",
+    "taught",
+    "run",
+    "set",
+    "put",
+    "chosen",
+    "driven",
+    "spoken",
+    "broken",
+    "forgotten",
+    "hidden",
+];
+
+/// Words ending in "-ly" that are not adverbs (or not the manner adverbs we count).
+const NON_ADVERB_LY: &[&str] = &[
+    "only", "early", "family", "reply", "supply", "apply", "likely", "daily", "weekly", "monthly",
+    "yearly", "friendly", "lovely", "ugly", "holy", "italy", "july", "rely", "ally", "fly",
+    "belly", "silly", "jolly", "bully",
+];
+
+/// Lowercase a word and strip surrounding punctuation (keeps inner apostrophes).
+fn clean_word(word: &str) -> String {
+    word.to_lowercase()
+        .trim_matches(|c: char| !c.is_alphanumeric() && c != '\'')
+        .to_string()
+}
+
+fn is_past_participle(word: &str) -> bool {
+    (word.len() > 3 && word.ends_with("ed")) || IRREGULAR_PARTICIPLES.contains(&word)
+}
+
+fn is_adverb(word: &str) -> bool {
+    word.len() > 4 && word.ends_with("ly") && !NON_ADVERB_LY.contains(&word)
+}
+
+/// A form of "to be" followed by a past participle, allowing one adverb or
+/// "not" in between ("was quickly built", "is not included").
+fn is_passive(sentence: &str) -> bool {
+    let words: Vec<String> = sentence.split_whitespace().map(clean_word).collect();
+    words.iter().enumerate().any(|(i, w)| {
+        if !BE_FORMS.contains(&w.as_str()) {
+            return false;
+        }
+        match words.get(i + 1) {
+            Some(next) if is_past_participle(next) => true,
+            Some(next) if next == "not" || is_adverb(next) => {
+                words.get(i + 2).is_some_and(|w| is_past_participle(w))
+            }
+            _ => false,
+        }
+    })
+}
+
+/// Population mean and standard deviation
+fn mean_and_stddev(values: &[usize]) -> (f32, f32) {
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = values.len() as f32;
+    let mean = values.iter().sum::<usize>() as f32 / n;
+    let variance = values
+        .iter()
+        .map(|&v| (v as f32 - mean).powi(2))
+        .sum::<f32>()
+        / n;
+    (mean, variance.sqrt())
+}
+
+/// Sentence rhythm and style for a set of sentences
+struct SentenceStats {
+    word_count: usize,
+    sentence_count: usize,
+    mean: f32,
+    stddev: f32,
+    burstiness: f32,
+    passive_pct: f32,
+    adverb_density: f32,
+}
+
+fn sentence_stats(sentences: &[&str]) -> SentenceStats {
+    let lengths: Vec<usize> = sentences
+        .iter()
+        .map(|s| s.split_whitespace().count())
+        .collect();
+    let word_count: usize = lengths.iter().sum();
+    let (mean, stddev) = mean_and_stddev(&lengths);
+    let burstiness = if mean > 0.0 { stddev / mean } else { 0.0 };
+    let passive_count = sentences.iter().filter(|s| is_passive(s)).count();
+    let passive_pct = if sentences.is_empty() {
+        0.0
+    } else {
+        passive_count as f32 / sentences.len() as f32 * 100.0
+    };
+    let adverb_count = sentences
+        .iter()
+        .flat_map(|s| s.split_whitespace())
+        .filter(|w| is_adverb(&clean_word(w)))
+        .count();
+    let adverb_density = if word_count > 0 {
+        adverb_count as f32 / word_count as f32 * 100.0
+    } else {
+        0.0
+    };
+    SentenceStats {
+        word_count,
+        sentence_count: sentences.len(),
+        mean,
+        stddev,
+        burstiness,
+        passive_pct,
+        adverb_density,
+    }
+}
+
+/// Combine rhythm, passive voice, and adverbs into a 0-100 score.
+/// Uniform sentence lengths only count when there are several sentences to compare.
+fn robotic_score(stats: &SentenceStats) -> f32 {
+    let uniformity = if stats.sentence_count >= 3 {
+        (1.0 - stats.burstiness.min(1.0)) * 50.0
+    } else {
+        0.0
+    };
+    let passive = stats.passive_pct.min(100.0) * 0.3;
+    let adverbs = (stats.adverb_density * 4.0).min(20.0);
+    (uniformity + passive + adverbs).min(100.0)
+}
+
+/// Split text into paragraphs at blank lines, as (start, end) byte ranges of
+/// trimmed, non-empty paragraphs.
+fn split_paragraphs(text: &str) -> Vec<(usize, usize)> {
+    let mut paragraphs = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            if let Some(s) = start.take() {
+                paragraphs.push((s, end));
+            }
+        } else {
+            let lead = line.len() - line.trim_start().len();
+            start.get_or_insert(offset + lead);
+            end = offset + lead + trimmed.len();
+        }
+        offset += line.len();
+    }
+    if let Some(s) = start {
+        paragraphs.push((s, end));
+    }
+    paragraphs
+}
+
 /// Analyze text for humanization metrics.
 ///
-/// Counts contractions, informal transitions, sentence fragments, and AI tells.
-/// Returns metrics including rate per 100 words.
+/// Counts contractions, informal transitions, sentence fragments, and AI tells,
+/// and measures sentence-length variance, passive voice, and adverb density
+/// overall and per paragraph. Sentences are split with
+/// `perplexity_chunks::split_sentences`, so abbreviations and decimals don't
+/// inflate counts. Returns metrics including rate per 100 words.
+///
+/// AI tells are matched against `active_ai_tell_phrases()`.
 pub fn analyze_humanization(text: &str) -> HumanizationMetrics {
+    analyze_humanization_with(text, &active_ai_tell_phrases())
+}
+
+/// `analyze_humanization` with an explicit AI-tell phrase list
+pub fn analyze_humanization_with(
+    text: &str,
+    ai_tell_phrases: &AiTellPhrases,
+) -> HumanizationMetrics {
     let words: Vec<&str> = text.split_whitespace().collect();
     let word_count = words.len();
 
@@ -435,12 +1300,37 @@ pub fn analyze_humanization(text: &str) -> HumanizationMetrics {
         })
         .count();
 
-    // Split into sentences for transition and fragment analysis
-    let sentences: Vec<&str> = text
-        .split(['.', '!', '?'])
-        .map(|s| s.trim())
-        .filter(|s| !s.is_empty())
-        .collect();
+    // Split into paragraphs, then sentences (never spanning a paragraph break)
+    let mut sentences: Vec<&str> = Vec::new();
+    let mut paragraphs: Vec<ParagraphMetrics> = Vec::new();
+    for (start, end) in split_paragraphs(text) {
+        let paragraph = &text[start..end];
+        let paragraph_sentences: Vec<&str> = crate::perplexity_chunks::split_sentences(paragraph)
+            .into_iter()
+            .map(|span| &paragraph[span.start..span.end])
+            .collect();
+        let stats = sentence_stats(&paragraph_sentences);
+        paragraphs.push(ParagraphMetrics {
+            start,
+            end,
+            word_count: stats.word_count,
+            sentence_count: stats.sentence_count,
+            sentence_length_mean: stats.mean,
+            sentence_length_stddev: stats.stddev,
+            burstiness_index: stats.burstiness,
+            passive_voice_pct: stats.passive_pct,
+            adverb_density: stats.adverb_density,
+            robotic_score: robotic_score(&stats),
+        });
+        sentences.extend(paragraph_sentences);
+    }
+    let overall = sentence_stats(&sentences);
+    let most_robotic_paragraph = paragraphs
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| p.robotic_score > 0.0)
+        .max_by(|(_, a), (_, b)| a.robotic_score.total_cmp(&b.robotic_score))
+        .map(|(i, _)| i);
 
     // Count informal transitions at sentence starts
     let mut informal_transition_count = 0;
@@ -474,20 +1364,7 @@ pub fn analyze_humanization(text: &str) -> HumanizationMetrics {
         .count();
 
     // Detect AI tells
-    let lower_text = text.to_lowercase();
-    let mut ai_tells_found: Vec<String> = Vec::new();
-
-    for tell in AI_TELLS {
-        if lower_text.contains(tell) {
-            ai_tells_found.push(tell.to_string());
-        }
-    }
-
-    for phrase in AI_HEDGING_PHRASES {
-        if lower_text.contains(phrase) {
-            ai_tells_found.push(phrase.to_string());
-        }
-    }
+    let ai_tells_found = find_ai_tells(text, ai_tell_phrases);
 
     // Calculate humanization rate per 100 words
     let humanization_elements =
@@ -505,7 +1382,187 @@ pub fn analyze_humanization(text: &str) -> HumanizationMetrics {
         sentence_fragment_count,
         ai_tells_found,
         rate_per_100_words,
+        sentence_length_mean: overall.mean,
+        sentence_length_stddev: overall.stddev,
+        burstiness_index: overall.burstiness,
+        passive_voice_pct: overall.passive_pct,
+        adverb_density: overall.adverb_density,
+        paragraphs,
+        most_robotic_paragraph,
+    }
+}
+
+// ============================================================================
+// Local AI-Detection Risk Estimate
+// ============================================================================
+
+/// Label returned with every local estimate so it is never mistaken for the
+/// Claude-based perplexity check (`analyze_perplexity`).
+pub const LOCAL_RISK_METHOD: &str = "local_heuristic_estimate";
+
+/// Formal transitions that AI text overuses ("Furthermore, ...").
+const FORMAL_TRANSITIONS: &[&str] = &[
+    "furthermore",
+    "moreover",
+    "additionally",
+    "consequently",
+    "therefore",
+    "ultimately",
+    "in conclusion",
+    "in addition",
+    "as a result",
+    "overall",
+];
+
+/// Maximum points per factor (sum = 100)
+const AI_TELL_MAX_POINTS: f32 = 30.0;
+const UNIFORMITY_MAX_POINTS: f32 = 25.0;
+const CONTRACTION_MAX_POINTS: f32 = 20.0;
+const TRANSITION_MAX_POINTS: f32 = 25.0;
+
+/// Burstiness at or above this adds no uniformity risk
+const HUMAN_BURSTINESS: f32 = 0.6;
+/// Contractions per 100 words at or above this add no contraction risk
+const HUMAN_CONTRACTION_RATE: f32 = 2.0;
+/// Formal transitions per 100 words at which transition risk is maxed out
+const MAX_RISK_TRANSITION_DENSITY: f32 = 2.0;
+/// Below this many words, contraction rate is not meaningful
+const MIN_WORDS_FOR_RATES: usize = 20;
+
+/// One contribution to the local risk score.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskFactor {
+    /// "ai_tells", "sentence_uniformity", "low_contractions", or "formal_transitions"
+    pub name: String,
+    /// Measured value (count, burstiness index, or rate per 100 words)
+    pub value: f32,
+    /// Points this factor adds to the score
+    pub points: f32,
+    pub max_points: f32,
+    pub description: String,
+}
+
+/// Instant, offline AI-detection risk estimate. Not a perplexity score.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalAiRiskEstimate {
+    /// Always `LOCAL_RISK_METHOD`
+    pub method: String,
+    /// Always true: this is a heuristic, not the Claude perplexity analysis
+    pub is_estimate: bool,
+    /// 0-100, higher = more likely to read as AI-generated
+    pub score: u8,
+    /// "low" (<35), "medium" (<65), or "high"
+    pub level: String,
+    pub factors: Vec<RiskFactor>,
+}
+
+/// Estimate AI-detection risk from locally measurable features, without an
+/// API call. Deterministic: the same text always gets the same score.
+///
+/// Factors: AI-tell phrases, uniform sentence lengths (low burstiness), few
+/// contractions, and dense formal transitions.
+pub fn local_ai_risk_estimate(text: &str) -> LocalAiRiskEstimate {
+    let metrics = analyze_humanization(text);
+    let mut factors = Vec::with_capacity(4);
+
+    let tell_count = metrics.ai_tells_found.len();
+    factors.push(RiskFactor {
+        name: "ai_tells".to_string(),
+        value: tell_count as f32,
+        points: (tell_count as f32 * 8.0).min(AI_TELL_MAX_POINTS),
+        max_points: AI_TELL_MAX_POINTS,
+        description: format!("{} AI-tell words or phrases", tell_count),
+    });
+
+    let sentence_count: usize = metrics.paragraphs.iter().map(|p| p.sentence_count).sum();
+    let uniformity_points = if sentence_count >= 3 {
+        (1.0 - (metrics.burstiness_index / HUMAN_BURSTINESS).min(1.0)) * UNIFORMITY_MAX_POINTS
+    } else {
+        0.0
+    };
+    factors.push(RiskFactor {
+        name: "sentence_uniformity".to_string(),
+        value: metrics.burstiness_index,
+        points: uniformity_points,
+        max_points: UNIFORMITY_MAX_POINTS,
+        description: format!(
+            "Sentence length variation {:.2} (below {:.1} reads as uniform)",
+            metrics.burstiness_index, HUMAN_BURSTINESS
+        ),
+    });
+
+    let per_100 = |count: usize| {
+        if metrics.word_count > 0 {
+            count as f32 / metrics.word_count as f32 * 100.0
+        } else {
+            0.0
+        }
+    };
+
+    let contraction_rate = per_100(metrics.contraction_count);
+    let contraction_points = if metrics.word_count >= MIN_WORDS_FOR_RATES {
+        (1.0 - (contraction_rate / HUMAN_CONTRACTION_RATE).min(1.0)) * CONTRACTION_MAX_POINTS
+    } else {
+        0.0
+    };
+    factors.push(RiskFactor {
+        name: "low_contractions".to_string(),
+        value: contraction_rate,
+        points: contraction_points,
+        max_points: CONTRACTION_MAX_POINTS,
+        description: format!("{:.1} contractions per 100 words", contraction_rate),
+    });
+
+    let transition_count = count_formal_transitions(text);
+    let transition_density = per_100(transition_count);
+    factors.push(RiskFactor {
+        name: "formal_transitions".to_string(),
+        value: transition_density,
+        points: (transition_density / MAX_RISK_TRANSITION_DENSITY).min(1.0) * TRANSITION_MAX_POINTS,
+        max_points: TRANSITION_MAX_POINTS,
+        description: format!("{:.1} formal transitions per 100 words", transition_density),
+    });
+
+    let total: f32 = factors.iter().map(|f| f.points).sum();
+    let score = total.round().clamp(0.0, 100.0) as u8;
+    let level = match score {
+        0..=34 => "low",
+        35..=64 => "medium",
+        _ => "high",
+    };
+
+    LocalAiRiskEstimate {
+        method: LOCAL_RISK_METHOD.to_string(),
+        is_estimate: true,
+        score,
+        level: level.to_string(),
+        factors,
+    }
+}
+
+/// Count formal transitions at the start of sentences or clauses
+fn count_formal_transitions(text: &str) -> usize {
+    let words: Vec<String> = text.split_whitespace().map(clean_word).collect();
+    let raw: Vec<&str> = text.split_whitespace().collect();
+    let mut count = 0;
+    for i in 0..words.len() {
+        // Only at a sentence/clause start: first word, or after a word ending in punctuation
+        let at_start = i == 0 || raw[i - 1].ends_with(['.', '!', '?', ';', ':', ',']);
+        if !at_start {
+            continue;
+        }
+        let matched = FORMAL_TRANSITIONS.iter().any(|t| {
+            let parts: Vec<&str> = t.split(' ').collect();
+            parts.len() <= words.len() - i
+                && parts.iter().zip(&words[i..]).all(|(p, w)| *p == w.as_str())
+        });
+        if matched {
+            count += 1;
+        }
     }
+    count
 }
 
 // ============================================================================
@@ -676,6 +1733,47 @@ mod tests {
         assert!(prompt.contains("QUALITY GUARDRAILS"));
     }
 
+    #[test]
+    fn test_intensity_descriptions_come_from_the_prompt() {
+        let off = describe_intensity(HumanizationIntensity::Off);
+        assert_eq!(off.summary, None);
+        assert!(off.contraction_rules.is_empty() && off.avoided_words.is_empty());
+
+        for intensity in [
+            HumanizationIntensity::Light,
+            HumanizationIntensity::Medium,
+            HumanizationIntensity::Heavy,
+        ] {
+            let description = describe_intensity(intensity);
+            let prompt = get_humanization_prompt(&intensity).unwrap();
+            let described = description
+                .contraction_rules
+                .iter()
+                .chain(&description.sentence_variation_rules)
+                .chain(&description.other_rules)
+                .chain(&description.avoided_words)
+                .chain(&description.avoided_phrases)
+                .chain(&description.avoided_patterns)
+                .chain(&description.quality_guardrails)
+                .chain(description.summary.as_ref())
+                .chain(description.tone.as_ref());
+            for text in described {
+                assert!(prompt.contains(text), "{:?}: {}", intensity, text);
+            }
+            assert_eq!(description.contraction_rules.len(), 1, "{:?}", intensity);
+            assert!(!description.sentence_variation_rules.is_empty());
+        }
+
+        let medium = describe_intensity(HumanizationIntensity::Medium);
+        assert!(medium.contraction_rules[0].contains("minimum 6 per 200 words"));
+        assert!(medium.avoidance_strict);
+        assert!(!describe_intensity(HumanizationIntensity::Light).avoidance_strict);
+        assert!(
+            describe_intensity(HumanizationIntensity::Heavy).contraction_rules[0]
+                .contains("minimum 8 per 200 words")
+        );
+    }
+
     #[test]
     fn test_build_system_prompt_off_returns_base() {
         let base = "You are a proposal writer.";
@@ -762,6 +1860,58 @@ mod tests {
         assert_eq!(metrics.word_count, 0);
         assert_eq!(metrics.contraction_count, 0);
         assert_eq!(metrics.rate_per_100_words, 0.0);
+        assert_eq!(metrics.sentence_length_mean, 0.0);
+        assert!(metrics.paragraphs.is_empty());
+        assert_eq!(metrics.most_robotic_paragraph, None);
+    }
+
+    #[test]
+    fn test_analyze_sentence_length_variance() {
+        // Abbreviations and decimals don't split sentences: 4, 2, 6 words
+        let text = "I used e.g. React. Fast turnaround. Version 2.5 shipped last week too.";
+        let metrics = analyze_humanization(text);
+        assert!((metrics.sentence_length_mean - 4.0).abs() < 0.01);
+        let expected_stddev = (8.0f32 / 3.0).sqrt();
+        assert!((metrics.sentence_length_stddev - expected_stddev).abs() < 0.01);
+        assert!((metrics.burstiness_index - expected_stddev / 4.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_analyze_passive_voice_and_adverbs() {
+        let text = "The app was built in React. I quickly fixed the bug. Tests are not included yet. I really enjoyed it.";
+        let metrics = analyze_humanization(text);
+        assert!((metrics.passive_voice_pct - 50.0).abs() < 0.01);
+        // quickly, really over 20 words
+        assert!((metrics.adverb_density - 2.0 / 20.0 * 100.0).abs() < 0.01);
+
+        let active = analyze_humanization("I built the app in React. It is fast.");
+        assert_eq!(active.passive_voice_pct, 0.0);
+        assert_eq!(active.adverb_density, 0.0);
+    }
+
+    #[test]
+    fn test_analyze_paragraph_breakdown_flags_most_robotic() {
+        let text = "Sure. I've shipped three apps like this, and the last one went live in under a month. Happy to share links.\n\n\
+                    The project was completed successfully. The code was carefully reviewed. The tests were thoroughly written.";
+        let metrics = analyze_humanization(text);
+        assert_eq!(metrics.paragraphs.len(), 2);
+        assert_eq!(metrics.paragraphs[0].sentence_count, 3);
+        assert_eq!(metrics.paragraphs[1].sentence_count, 3);
+        assert_eq!(
+            &text[metrics.paragraphs[1].start..metrics.paragraphs[1].end],
+            "The project was completed successfully. The code was carefully reviewed. The tests were thoroughly written."
+        );
+        assert!(metrics.paragraphs[1].robotic_score > metrics.paragraphs[0].robotic_score);
+        assert_eq!(metrics.most_robotic_paragraph, Some(1));
+    }
+
+    #[test]
+    fn test_metrics_deserialize_without_new_fields() {
+        let json = r#"{"wordCount":3,"contractionCount":0,"informalTransitionCount":0,"sentenceFragmentCount":1,"aiTellsFound":[],"ratePer100Words":33.3}"#;
+        let metrics: HumanizationMetrics = serde_json::from_str(json).unwrap();
+        assert_eq!(metrics.word_count, 3);
+        assert_eq!(metrics.burstiness_index, 0.0);
+        assert!(metrics.paragraphs.is_empty());
     }
 
     #[test]
@@ -850,6 +2000,135 @@ mod tests {
             .contains(&"proven track record".to_string()));
     }
 
+    // -- Configurable AI-tell phrase tests --
+
+    #[test]
+    fn test_ai_tell_match_modes() {
+        let mut phrases = AiTellPhrases::built_in_only();
+        let text = "I delved into the Realm of APIs. Overrealm isn't a word.";
+
+        let found = find_ai_tells(text, &phrases);
+        assert!(found.contains(&"delve".to_string()));
+        assert_eq!(found.iter().filter(|p| *p == "realm").count(), 1);
+
+        phrases.match_mode = AiTellMatchMode::Word;
+        let found = find_ai_tells(text, &phrases);
+        assert!(!found.contains(&"delve".to_string()));
+        assert!(found.contains(&"realm".to_string()));
+
+        // Mid-word occurrences never match
+        assert!(find_ai_tells("the overrealm", &phrases).is_empty());
+    }
+
+    #[test]
+    fn test_ai_tells_merge_built_in_remote_and_user() {
+        let phrases = AiTellPhrases {
+            remote: vec!["circle back".to_string(), "delve".to_string()],
+            user: vec!["synergize".to_string()],
+            ..AiTellPhrases::built_in_only()
+        };
+        let text = "Let's circle back and SYNERGIZE. I'll delve in.";
+        let metrics = analyze_humanization_with(text, &phrases);
+        assert_eq!(
+            metrics.ai_tells_found,
+            vec!["delve", "circle back", "synergize"]
+        );
+
+        // An empty user list keeps built-in detection
+        let empty_user = AiTellPhrases {
+            user: Vec::new(),
+            ..phrases
+        };
+        assert_eq!(
+            find_ai_tells("We leverage tools.", &empty_user),
+            vec!["leverage"]
+        );
+    }
+
+    #[test]
+    fn test_user_ai_tell_phrase_add_remove() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = crate::db::Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+
+        add_user_ai_tell_phrase(&conn, "  Circle   BACK ").unwrap();
+        assert_eq!(
+            stored_user_ai_tell_phrases(&conn).unwrap(),
+            vec!["circle back"]
+        );
+
+        assert!(add_user_ai_tell_phrase(&conn, "circle back").is_err());
+        assert!(add_user_ai_tell_phrase(&conn, "Delve").is_err());
+        assert!(add_user_ai_tell_phrase(&conn, "  ").is_err());
+
+        assert!(remove_user_ai_tell_phrase(&conn, "delve")
+            .unwrap_err()
+            .contains("can't be removed"));
+        remove_user_ai_tell_phrase(&conn, "circle back").unwrap();
+        assert!(stored_user_ai_tell_phrases(&conn).unwrap().is_empty());
+        assert!(remove_user_ai_tell_phrase(&conn, "circle back").is_err());
+    }
+
+    #[test]
+    fn test_validate_user_ai_tell_phrases() {
+        assert_eq!(
+            validate_user_ai_tell_phrases(r#"["Game Changer"]"#).unwrap(),
+            vec!["game changer"]
+        );
+        assert!(validate_user_ai_tell_phrases("not json").is_err());
+        assert!(validate_user_ai_tell_phrases(r#"[""]"#).is_err());
+        assert!(AiTellMatchMode::from_str_value("word").is_ok());
+        assert!(AiTellMatchMode::from_str_value("substring").is_err());
+    }
+
+    // -- Local AI risk estimate tests --
+
+    #[test]
+    fn test_local_risk_empty_text_is_zero() {
+        let estimate = local_ai_risk_estimate("");
+        assert_eq!(estimate.score, 0);
+        assert_eq!(estimate.level, "low");
+        assert!(estimate.is_estimate);
+        assert_eq!(estimate.method, LOCAL_RISK_METHOD);
+        assert_eq!(estimate.factors.len(), 4);
+    }
+
+    #[test]
+    fn test_local_risk_fixed_inputs() {
+        // 3 tells (24), uniform 6-word sentences (25), no contractions in 24 words (20),
+        // 2 formal transitions / 24 words = 8.3 per 100 (maxed, 25)
+        let robotic = "I leverage robust React skills here. Furthermore, I deliver comprehensive solutions quickly. \
+                       Moreover, my code is always tested. I am available to start today.";
+        let estimate = local_ai_risk_estimate(robotic);
+        let points: Vec<f32> = estimate.factors.iter().map(|f| f.points).collect();
+        assert_eq!(points, vec![24.0, 25.0, 20.0, 25.0]);
+        assert_eq!(estimate.score, 94);
+        assert_eq!(estimate.level, "high");
+
+        // Same text always gets the same score
+        assert_eq!(local_ai_risk_estimate(robotic), estimate);
+
+        let human = "Sure. I've built three dashboards like this one, and the last went live in two weeks flat. \
+                     You're after speed? That's my thing. I'd start Monday.";
+        let estimate = local_ai_risk_estimate(human);
+        assert_eq!(estimate.score, 0);
+        assert_eq!(estimate.level, "low");
+    }
+
+    #[test]
+    fn test_count_formal_transitions_only_at_clause_start() {
+        assert_eq!(
+            count_formal_transitions("In addition, tests. As a result, speed."),
+            2
+        );
+        // Mid-sentence use doesn't count
+        assert_eq!(
+            count_formal_transitions("The overall plan is therefore sound."),
+            0
+        );
+        assert_eq!(count_formal_transitions("Done; therefore we ship."), 1);
+    }
+
     // -- Prompt/constant sync verification (Code Review H1 fix) --
 
     #[test]
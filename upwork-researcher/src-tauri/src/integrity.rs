@@ -0,0 +1,348 @@
+//! Integrity manifest for the key files next to the database
+//!
+//! `.salt`, `.kdf_params` and the recovery key files are plain files in the app
+//! data directory, so a sync tool or antivirus can truncate or replace them. A
+//! damaged salt derives a different key, which SQLCipher reports exactly like a
+//! wrong passphrase: the user keeps retrying a passphrase that was never the
+//! problem. `.integrity` records the SHA-256 and length of each of these files
+//! and is rewritten (atomically) whenever one of them changes. Unlock checks it
+//! first and reports a modified file as such (`security:integrity-warning`,
+//! `DatabaseError::IntegrityMismatch`). After a legitimate restore from backup,
+//! `repair_integrity_manifest` records the current files instead.
+//!
+//! Only files listed in the manifest are checked. Installs from before the
+//! manifest get one after their first successful passphrase unlock.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+
+/// Manifest file (app data dir)
+pub const INTEGRITY_FILE: &str = ".integrity";
+
+const MANIFEST_VERSION: u32 = 1;
+
+const SALT_FILE: &str = ".salt";
+
+/// Files the passphrase unlock reads
+pub const PASSPHRASE_FILES: [&str; 2] = [SALT_FILE, crate::passphrase::KDF_PARAMS_FILE];
+
+/// Files the recovery key unlock reads
+pub const RECOVERY_FILES: [&str; 2] = [
+    crate::keychain::recovery::RECOVERY_HASH_FILE,
+    crate::keychain::recovery::RECOVERY_WRAPPED_KEY_FILE,
+];
+
+/// Error types for manifest operations
+#[derive(Debug, thiserror::Error)]
+pub enum IntegrityError {
+    #[error("Failed to read {0}: {1}")]
+    ReadFailed(String, String),
+
+    #[error("Invalid integrity manifest: {0}")]
+    InvalidManifest(String),
+
+    #[error("Failed to write integrity manifest: {0}")]
+    WriteFailed(String),
+}
+
+/// Checksum and length of a file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileFingerprint {
+    pub sha256: String,
+    pub len: u64,
+}
+
+impl FileFingerprint {
+    pub fn of(content: &[u8]) -> Self {
+        Self {
+            sha256: hex::encode(Sha256::digest(content)),
+            len: content.len() as u64,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    version: u32,
+    files: BTreeMap<String, FileFingerprint>,
+}
+
+/// A recorded file that no longer matches the manifest; also the
+/// `security:integrity-warning` event payload
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityMismatch {
+    pub file: String,
+    pub expected: FileFingerprint,
+    /// None when the file is missing
+    pub actual: Option<FileFingerprint>,
+}
+
+/// "Salt file appears corrupted or modified", naming the file that was checked
+impl fmt::Display for IntegrityMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.file.as_str() {
+            SALT_FILE => "Salt file",
+            crate::passphrase::KDF_PARAMS_FILE => "Key derivation parameters file",
+            crate::keychain::recovery::RECOVERY_HASH_FILE => "Recovery hash file",
+            crate::keychain::recovery::RECOVERY_WRAPPED_KEY_FILE => "Recovery key file",
+            other => other,
+        };
+        write!(f, "{} appears corrupted or modified", label)
+    }
+}
+
+fn tracked_files() -> impl Iterator<Item = &'static str> {
+    PASSPHRASE_FILES.into_iter().chain(RECOVERY_FILES)
+}
+
+fn fingerprint(path: &Path) -> Result<Option<FileFingerprint>, IntegrityError> {
+    match std::fs::read(path) {
+        Ok(content) => Ok(Some(FileFingerprint::of(&content))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(IntegrityError::ReadFailed(
+            path.display().to_string(),
+            e.to_string(),
+        )),
+    }
+}
+
+fn load_manifest(app_data_dir: &Path) -> Result<Option<Manifest>, IntegrityError> {
+    let path = app_data_dir.join(INTEGRITY_FILE);
+    let json = match std::fs::read_to_string(&path) {
+        Ok(json) => json,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(IntegrityError::ReadFailed(
+                path.display().to_string(),
+                e.to_string(),
+            ))
+        }
+    };
+    let manifest: Manifest =
+        serde_json::from_str(&json).map_err(|e| IntegrityError::InvalidManifest(e.to_string()))?;
+    if manifest.version != MANIFEST_VERSION {
+        return Err(IntegrityError::InvalidManifest(format!(
+            "unsupported version {}",
+            manifest.version
+        )));
+    }
+    Ok(Some(manifest))
+}
+
+/// Record the key files currently present, replacing the manifest via a
+/// temp file and rename. Returns the number of files recorded.
+pub fn record_manifest(app_data_dir: &Path) -> Result<usize, IntegrityError> {
+    let mut manifest = Manifest {
+        version: MANIFEST_VERSION,
+        ..Default::default()
+    };
+    for name in tracked_files() {
+        if let Some(fingerprint) = fingerprint(&app_data_dir.join(name))? {
+            manifest.files.insert(name.to_string(), fingerprint);
+        }
+    }
+    let json = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| IntegrityError::WriteFailed(e.to_string()))?;
+
+    let path = app_data_dir.join(INTEGRITY_FILE);
+    let tmp = app_data_dir.join(format!("{}.tmp", INTEGRITY_FILE));
+    let written = std::fs::File::create(&tmp)
+        .and_then(|mut file| {
+            file.write_all(json.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|_| std::fs::rename(&tmp, &path));
+    if let Err(e) = written {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(IntegrityError::WriteFailed(e.to_string()));
+    }
+    Ok(manifest.files.len())
+}
+
+/// `record_manifest` after a key file changed. If the manifest can't be
+/// rewritten it is removed, so the stale one doesn't flag the new files.
+pub fn refresh_manifest(app_data_dir: &Path) {
+    if let Err(e) = record_manifest(app_data_dir) {
+        tracing::warn!("{}; integrity checks disabled until it is rewritten", e);
+        let _ = std::fs::remove_file(app_data_dir.join(INTEGRITY_FILE));
+    }
+}
+
+/// Record the manifest if there is none yet (installs from before it existed).
+/// Only call once the files are known good, e.g. after a successful unlock.
+pub fn ensure_manifest(app_data_dir: &Path) {
+    if !app_data_dir.join(INTEGRITY_FILE).exists() {
+        refresh_manifest(app_data_dir);
+    }
+}
+
+/// Compare every recorded file with the manifest. No manifest means nothing
+/// to check.
+pub fn verify_manifest(app_data_dir: &Path) -> Result<Vec<IntegrityMismatch>, IntegrityError> {
+    let Some(manifest) = load_manifest(app_data_dir)? else {
+        return Ok(Vec::new());
+    };
+    let mut mismatches = Vec::new();
+    for (file, expected) in manifest.files {
+        let actual = fingerprint(&app_data_dir.join(&file))?;
+        if actual.as_ref() != Some(&expected) {
+            mismatches.push(IntegrityMismatch {
+                file,
+                expected,
+                actual,
+            });
+        }
+    }
+    Ok(mismatches)
+}
+
+/// First mismatch among `files`, for the unlock paths. An unreadable manifest
+/// is logged and ignored: it says nothing about the key files themselves.
+pub fn check_files(app_data_dir: &Path, files: &[&str]) -> Option<IntegrityMismatch> {
+    match verify_manifest(app_data_dir) {
+        Ok(mismatches) => mismatches
+            .into_iter()
+            .find(|m| files.contains(&m.file.as_str())),
+        Err(e) => {
+            tracing::warn!("Skipping key file integrity check: {}", e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn write_key_files(dir: &Path) {
+        fs::write(
+            dir.join(".salt"),
+            "c2FsdHNhbHRzYWx0c2FsdHNhbHRzYWx0c2FsdHNhbHQ=",
+        )
+        .unwrap();
+        fs::write(dir.join(".kdf_params"), r#"{"memoryKb":65536}"#).unwrap();
+        fs::write(
+            dir.join(".recovery_hash"),
+            "$argon2id$v=19$m=19456,t=2,p=1$abc",
+        )
+        .unwrap();
+        fs::write(dir.join(".recovery_wrapped_key"), "d3JhcHBlZA==").unwrap();
+    }
+
+    #[test]
+    fn test_record_and_verify_clean() {
+        let dir = tempdir().unwrap();
+        // No manifest: nothing to check
+        assert!(verify_manifest(dir.path()).unwrap().is_empty());
+
+        write_key_files(dir.path());
+        assert_eq!(record_manifest(dir.path()).unwrap(), 4);
+        assert!(verify_manifest(dir.path()).unwrap().is_empty());
+        assert!(!dir.path().join(".integrity.tmp").exists());
+    }
+
+    #[test]
+    fn test_truncated_or_modified_files_are_reported() {
+        for file in tracked_files() {
+            let dir = tempdir().unwrap();
+            write_key_files(dir.path());
+            record_manifest(dir.path()).unwrap();
+
+            // Truncated
+            let content = fs::read(dir.path().join(file)).unwrap();
+            fs::write(dir.path().join(file), &content[..content.len() / 2]).unwrap();
+            let mismatches = verify_manifest(dir.path()).unwrap();
+            assert_eq!(mismatches.len(), 1, "{}", file);
+            assert_eq!(mismatches[0].file, file);
+            assert_eq!(mismatches[0].expected, FileFingerprint::of(&content));
+            assert_eq!(
+                mismatches[0].actual.as_ref().map(|a| a.len),
+                Some(content.len() as u64 / 2)
+            );
+
+            // Same length, different bytes
+            let mut modified = content.clone();
+            modified[0] ^= 0x01;
+            fs::write(dir.path().join(file), &modified).unwrap();
+            let mismatch = verify_manifest(dir.path()).unwrap().remove(0);
+            assert_eq!(mismatch.actual, Some(FileFingerprint::of(&modified)));
+
+            // Removed
+            fs::remove_file(dir.path().join(file)).unwrap();
+            assert_eq!(verify_manifest(dir.path()).unwrap()[0].actual, None);
+
+            // Restored from backup: repair accepts the current files
+            fs::write(dir.path().join(file), &modified).unwrap();
+            record_manifest(dir.path()).unwrap();
+            assert!(verify_manifest(dir.path()).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_check_files_classifies_by_unlock_path() {
+        let dir = tempdir().unwrap();
+        write_key_files(dir.path());
+        record_manifest(dir.path()).unwrap();
+        fs::write(dir.path().join(".salt"), "").unwrap();
+
+        let mismatch = check_files(dir.path(), &PASSPHRASE_FILES).unwrap();
+        assert_eq!(mismatch.file, ".salt");
+        assert_eq!(
+            mismatch.to_string(),
+            "Salt file appears corrupted or modified"
+        );
+        // The recovery key unlock doesn't read the salt
+        assert!(check_files(dir.path(), &RECOVERY_FILES).is_none());
+
+        fs::write(dir.path().join(".recovery_wrapped_key"), "x").unwrap();
+        assert_eq!(
+            check_files(dir.path(), &RECOVERY_FILES)
+                .unwrap()
+                .to_string(),
+            "Recovery key file appears corrupted or modified"
+        );
+    }
+
+    #[test]
+    fn test_unreadable_manifest_does_not_block_unlock() {
+        let dir = tempdir().unwrap();
+        write_key_files(dir.path());
+        fs::write(dir.path().join(INTEGRITY_FILE), "{trunc").unwrap();
+
+        assert!(matches!(
+            verify_manifest(dir.path()),
+            Err(IntegrityError::InvalidManifest(_))
+        ));
+        assert!(check_files(dir.path(), &PASSPHRASE_FILES).is_none());
+
+        // ensure_manifest leaves an existing (even broken) manifest to repair
+        ensure_manifest(dir.path());
+        assert!(verify_manifest(dir.path()).is_err());
+        refresh_manifest(dir.path());
+        assert!(verify_manifest(dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_files_added_later_are_unchecked_until_recorded() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join(".salt"), "salt").unwrap();
+        assert_eq!(record_manifest(dir.path()).unwrap(), 1);
+
+        fs::write(dir.path().join(".recovery_hash"), "hash").unwrap();
+        assert!(verify_manifest(dir.path()).unwrap().is_empty());
+        refresh_manifest(dir.path());
+        fs::write(dir.path().join(".recovery_hash"), "changed").unwrap();
+        assert_eq!(
+            verify_manifest(dir.path()).unwrap()[0].file,
+            ".recovery_hash"
+        );
+    }
+}
@@ -0,0 +1,342 @@
+//! CSV job post import
+//!
+//! Imports job leads kept in spreadsheets. Columns are matched by header name
+//! (`url`, `raw_content`, `client_name`; case and spacing ignored), so column
+//! order and extra columns don't matter. Only `raw_content` is required.
+//!
+//! Malformed rows are collected and reported instead of aborting the import.
+//! Duplicates (same URL, or identical content when there is no URL) are skipped.
+//! File size and row count are capped, and rows are inserted in batches with
+//! the database lock released in between, so a huge file can't lock the DB.
+
+use crate::db::queries::job_posts;
+use crate::db::{AppDatabase, Database};
+use crate::sanitization;
+use serde::Serialize;
+use std::io::Read;
+use std::path::PathBuf;
+use tauri::{AppHandle, State};
+use tauri_plugin_dialog::DialogExt;
+use tracing::info;
+
+/// Largest CSV file accepted (20 MB)
+pub const MAX_CSV_IMPORT_BYTES: u64 = 20 * 1024 * 1024;
+/// Data rows read from one file; the rest are reported as not imported
+pub const MAX_CSV_IMPORT_ROWS: usize = 5_000;
+/// Rows inserted per database lock acquisition
+const INSERT_BATCH_SIZE: usize = 100;
+
+/// One valid data row
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvJobRow {
+    /// Line number where the row starts (header is line 1)
+    pub line: u64,
+    pub url: Option<String>,
+    pub raw_content: String,
+    pub client_name: Option<String>,
+}
+
+/// A row that could not be imported
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CsvRowError {
+    pub line: u64,
+    pub message: String,
+}
+
+/// Rows read from a CSV file
+#[derive(Debug, Default)]
+pub struct ParsedCsv {
+    pub rows: Vec<CsvJobRow>,
+    pub errors: Vec<CsvRowError>,
+    /// The file had more than `MAX_CSV_IMPORT_ROWS` data rows
+    pub row_limit_reached: bool,
+}
+
+/// Result returned from import_job_posts_csv
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CsvImportResult {
+    pub imported: usize,
+    pub duplicates: usize,
+    pub errors: Vec<CsvRowError>,
+    pub row_limit_reached: bool,
+}
+
+/// "Raw Content" / "raw-content" / "\u{feff}raw_content" -> "raw_content"
+fn normalize_header(header: &str) -> String {
+    header
+        .trim_start_matches('\u{feff}')
+        .trim()
+        .to_lowercase()
+        .replace([' ', '-'], "_")
+}
+
+/// Parse job post rows from CSV, reading at most `max_rows` data rows.
+/// Fails only if the header is unreadable or has no `raw_content` column.
+pub fn parse_job_posts_csv<R: Read>(reader: R, max_rows: usize) -> Result<ParsedCsv, String> {
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| format!("Failed to read CSV header: {}", e))?
+        .iter()
+        .map(normalize_header)
+        .collect();
+    let column = |name: &str| headers.iter().position(|h| h == name);
+    let content_col =
+        column("raw_content").ok_or_else(|| "CSV must have a raw_content column".to_string())?;
+    let url_col = column("url");
+    let client_col = column("client_name");
+
+    let mut parsed = ParsedCsv::default();
+    for (count, record) in reader.records().enumerate() {
+        if count >= max_rows {
+            parsed.row_limit_reached = true;
+            break;
+        }
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                let line = e.position().map(|p| p.line()).unwrap_or(0);
+                let is_io_error = matches!(e.kind(), csv::ErrorKind::Io(_));
+                parsed.errors.push(CsvRowError {
+                    line,
+                    message: e.to_string(),
+                });
+                // The reader can't recover from a failed read
+                if is_io_error {
+                    break;
+                }
+                continue;
+            }
+        };
+
+        let line = record.position().map(|p| p.line()).unwrap_or(0);
+        let field = |col: Option<usize>| {
+            col.and_then(|i| record.get(i))
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        };
+
+        let Some(raw_content) = field(Some(content_col)) else {
+            parsed.errors.push(CsvRowError {
+                line,
+                message: "raw_content is empty".to_string(),
+            });
+            continue;
+        };
+        let url = field(url_col);
+        if let Some(url) = &url {
+            if !matches!(url::Url::parse(url), Ok(u) if matches!(u.scheme(), "http" | "https")) {
+                parsed.errors.push(CsvRowError {
+                    line,
+                    message: format!("Invalid url: {}", url),
+                });
+                continue;
+            }
+        }
+
+        parsed.rows.push(CsvJobRow {
+            line,
+            url,
+            raw_content,
+            client_name: field(client_col),
+        });
+    }
+    Ok(parsed)
+}
+
+/// Insert parsed rows in batches, skipping duplicates. Content gets the same
+/// cleaning as `save_job_post`.
+pub async fn import_csv_rows(
+    database: &Database,
+    parsed: ParsedCsv,
+) -> Result<CsvImportResult, String> {
+    let mut result = CsvImportResult {
+        errors: parsed.errors,
+        row_limit_reached: parsed.row_limit_reached,
+        ..Default::default()
+    };
+
+    for batch in parsed.rows.chunks(INSERT_BATCH_SIZE) {
+        {
+            let conn = database
+                .conn
+                .lock()
+                .map_err(|e| format!("Database lock error: {}", e))?;
+            let tx = conn
+                .unchecked_transaction()
+                .map_err(|e| format!("Failed to start import transaction: {}", e))?;
+
+            for row in batch {
+                let cleaned = sanitization::clean_job_content(&row.raw_content);
+                let inserted =
+                    job_posts::find_duplicate_job_post(&tx, row.url.as_deref(), &cleaned.content)
+                        .and_then(|existing| match existing {
+                            Some(_) => Ok(None),
+                            None => job_posts::insert_job_post(
+                                &tx,
+                                row.url.as_deref(),
+                                &cleaned.content,
+                                row.client_name.as_deref(),
+                            )
+                            .map(Some),
+                        });
+                match inserted {
+                    Ok(Some(_)) => result.imported += 1,
+                    Ok(None) => result.duplicates += 1,
+                    Err(e) => result.errors.push(CsvRowError {
+                        line: row.line,
+                        message: format!("Failed to save job post: {}", e),
+                    }),
+                }
+            }
+
+            tx.commit()
+                .map_err(|e| format!("Failed to commit import batch: {}", e))?;
+        }
+        tokio::task::yield_now().await;
+    }
+
+    result.errors.sort_by_key(|e| e.line);
+    Ok(result)
+}
+
+/// Pick a CSV file and import its job posts
+///
+/// Returns imported/duplicate counts and the rows that could not be imported.
+#[tauri::command]
+pub async fn import_job_posts_csv(
+    app_handle: AppHandle,
+    database: State<'_, AppDatabase>,
+) -> Result<CsvImportResult, String> {
+    let database = database.get()?;
+
+    // Note: blocking_pick_file is correct - dialog is modal by nature
+    let file_path = app_handle
+        .dialog()
+        .file()
+        .set_title("Import Job Posts from CSV")
+        .add_filter("CSV Files", &["csv"])
+        .blocking_pick_file()
+        .ok_or("No file selected")?;
+    let path = PathBuf::from(file_path.to_string());
+
+    let size = std::fs::metadata(&path)
+        .map_err(|e| format!("Failed to read file: {}", e))?
+        .len();
+    if size > MAX_CSV_IMPORT_BYTES {
+        return Err(format!(
+            "CSV file is too large ({:.1} MB, max {} MB)",
+            size as f64 / (1024.0 * 1024.0),
+            MAX_CSV_IMPORT_BYTES / (1024 * 1024)
+        ));
+    }
+
+    let file = std::fs::File::open(&path).map_err(|e| format!("Failed to open file: {}", e))?;
+    // take() also guards against the file growing after the size check
+    let parsed = parse_job_posts_csv(file.take(MAX_CSV_IMPORT_BYTES), MAX_CSV_IMPORT_ROWS)?;
+    let result = import_csv_rows(database, parsed).await?;
+
+    info!(
+        "CSV import complete: {} imported, {} duplicates, {} errors",
+        result.imported,
+        result.duplicates,
+        result.errors.len()
+    );
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_matches_columns_by_header_name() {
+        let csv = "Notes,Client Name,RAW_CONTENT,url\n\
+                   ignored,Acme,\"Need a React dev.\nMulti-line, with \"\"quotes\"\".\",https://www.upwork.com/jobs/~01\n\
+                   x,,Second job,\n";
+        let parsed = parse_job_posts_csv(csv.as_bytes(), 100).unwrap();
+
+        assert!(parsed.errors.is_empty());
+        assert_eq!(parsed.rows.len(), 2);
+        assert_eq!(
+            parsed.rows[0].raw_content,
+            "Need a React dev.\nMulti-line, with \"quotes\"."
+        );
+        assert_eq!(parsed.rows[0].client_name.as_deref(), Some("Acme"));
+        assert_eq!(
+            parsed.rows[0].url.as_deref(),
+            Some("https://www.upwork.com/jobs/~01")
+        );
+        // Line numbers account for the embedded newline
+        assert_eq!(parsed.rows[1].line, 4);
+        assert_eq!(parsed.rows[1].url, None);
+        assert_eq!(parsed.rows[1].client_name, None);
+    }
+
+    #[test]
+    fn test_parse_collects_malformed_rows() {
+        let csv = "url,raw_content\n\
+                   https://example.com/1,Good job\n\
+                   https://example.com/2,\n\
+                   not a url,Bad url job\n\
+                   https://example.com/3\n\
+                   https://example.com/4,Another good job,extra,fields\n";
+        let parsed = parse_job_posts_csv(csv.as_bytes(), 100).unwrap();
+
+        assert_eq!(parsed.rows.len(), 2);
+        assert_eq!(
+            parsed.errors.iter().map(|e| e.line).collect::<Vec<_>>(),
+            vec![3, 4, 5]
+        );
+        assert!(parsed.errors[1].message.contains("Invalid url"));
+
+        let err =
+            parse_job_posts_csv("url,title\nhttps://example.com,Job".as_bytes(), 100).unwrap_err();
+        assert!(err.contains("raw_content"));
+    }
+
+    #[test]
+    fn test_parse_caps_row_count() {
+        let mut csv = String::from("raw_content\n");
+        for i in 0..10 {
+            csv.push_str(&format!("Job {}\n", i));
+        }
+        let parsed = parse_job_posts_csv(csv.as_bytes(), 4).unwrap();
+        assert_eq!(parsed.rows.len(), 4);
+        assert!(parsed.row_limit_reached);
+    }
+
+    #[tokio::test]
+    async fn test_import_skips_duplicates() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        {
+            let conn = db.conn.lock().unwrap();
+            job_posts::insert_job_post(&conn, Some("https://example.com/1"), "Existing", None)
+                .unwrap();
+        }
+
+        let csv = "url,raw_content,client_name\n\
+                   https://example.com/1,Same URL as existing,\n\
+                   https://example.com/2,New job with url,Acme\n\
+                   ,New job without url,\n\
+                   ,New job without url,\n";
+        let parsed = parse_job_posts_csv(csv.as_bytes(), 100).unwrap();
+        let result = import_csv_rows(&db, parsed).await.unwrap();
+
+        assert_eq!(result.imported, 2);
+        assert_eq!(result.duplicates, 2);
+        assert!(result.errors.is_empty());
+
+        let conn = db.conn.lock().unwrap();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM job_posts", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 3);
+    }
+}
@@ -1,6 +1,7 @@
 // Story 4b.7: Job input module
 // Handles various job input sources (RSS, manual, etc.)
 
+pub mod csv_import;
 pub mod rss;
 pub mod scraper;
 pub mod types;
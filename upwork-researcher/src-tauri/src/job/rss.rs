@@ -3,17 +3,18 @@
 // Story 4b.8: Fallback to web scraping when RSS fails
 
 use crate::analysis;
+use crate::config;
 use crate::db;
 use crate::db::queries::{job_posts, rss_imports};
 use crate::events;
+use crate::http::TrackedSend;
 use crate::job::scraper;
-use crate::keychain;
 use chrono::Utc;
 use regex::Regex;
 use rss::Channel;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
-use tauri::{Emitter, State};
+use tauri::{Emitter, Manager, State};
 use tokio::time::sleep;
 use tracing::{error, info, warn};
 
@@ -305,9 +306,17 @@ async fn process_rss_analysis_queue<R: tauri::Runtime>(
 ) {
     info!("Starting background analysis for batch: {}", batch_id);
 
-    // Get API key from keychain
-    let api_key = match keychain::retrieve_api_key() {
-        Ok(key) => key,
+    // Get API key from keychain (or the encrypted file fallback)
+    let api_key = match app.state::<config::ConfigState>().get_api_key() {
+        Ok(Some(key)) => key,
+        Ok(None) => {
+            error!("Failed to retrieve API key: no API key configured");
+            let _ = app.emit(
+                events::RSS_IMPORT_ERROR,
+                "Failed to get API key: no API key configured".to_string(),
+            );
+            return;
+        }
         Err(e) => {
             error!("Failed to retrieve API key: {}", e);
             let _ = app.emit(
@@ -399,6 +408,8 @@ async fn process_rss_analysis_queue<R: tauri::Runtime>(
                     // Save analysis results
                     let hidden_needs_json = serde_json::to_string(&analysis.hidden_needs)
                         .unwrap_or_else(|_| "[]".to_string());
+                    let deliverables_json = serde_json::to_string(&analysis.deliverables())
+                        .unwrap_or_else(|_| "{}".to_string());
 
                     job_posts::save_job_analysis_atomic(
                         &conn,
@@ -406,6 +417,7 @@ async fn process_rss_analysis_queue<R: tauri::Runtime>(
                         analysis.client_name.as_deref(),
                         &analysis.key_skills,
                         &hidden_needs_json,
+                        &deliverables_json,
                     )
                     .map_err(|e| format!("Failed to save analysis: {}", e))?;
 
@@ -551,8 +563,10 @@ pub async fn fetch_rss_feed(url: &str) -> Result<String, String> {
     info!("Fetching RSS feed from: {}", url);
 
     // Perform GET request
-    let response = client.get(url).timeout(Duration::from_secs(10)).send().await.map_err(|e| {
-        if e.is_timeout() {
+    let response = client.get(url).timeout(Duration::from_secs(10)).send_tracked().await.map_err(|e| {
+        if e.is_locally_rate_limited() {
+            e.to_string()
+        } else if e.is_timeout() {
             "Feed request timed out after 10 seconds".to_string()
         } else if e.is_connect() {
             "Failed to connect to RSS feed server".to_string()
@@ -1076,7 +1090,9 @@ mod tests {
                 client_name TEXT,
                 source TEXT NOT NULL,
                 analysis_status TEXT DEFAULT 'pending_analysis',
-                import_batch_id TEXT
+                import_batch_id TEXT,
+                last_seen_at TEXT,
+                job_status TEXT NOT NULL DEFAULT 'active'
             )",
             [],
         )
@@ -1,6 +1,7 @@
 // Story 4b.8: Web Scraping Fallback
 // Scrapes Upwork search pages when RSS feeds are blocked
 
+use crate::http::TrackedSend;
 use crate::job::rss::ParsedJob;
 use scraper::{Html, Selector};
 use std::collections::HashSet;
@@ -64,10 +65,12 @@ pub async fn fetch_upwork_search_page(search_url: &str) -> Result<String, String
         )
         .header("Accept", "text/html,application/xhtml+xml")
         .header("Accept-Language", "en-US,en;q=0.9")
-        .send()
+        .send_tracked()
         .await
         .map_err(|e| {
-            if e.is_timeout() {
+            if e.is_locally_rate_limited() {
+                e.to_string()
+            } else if e.is_timeout() {
                 "Scrape request timed out after 5 seconds".to_string()
             } else if e.is_connect() {
                 "Failed to connect to Upwork search page".to_string()
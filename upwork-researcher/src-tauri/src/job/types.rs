@@ -81,6 +81,12 @@ pub struct JobQueueItem {
     pub overall_score: Option<f32>,
     pub score_color: ScoreColor,
     pub created_at: String,
+    /// "active", "stale", "applied", or "dismissed"
+    pub job_status: String,
+    /// "active", "archived", or "snoozed"; an expired snooze reads as "active"
+    pub queue_status: String,
+    /// End of the snooze (UTC), while snoozed
+    pub snoozed_until: Option<String>,
 }
 
 /// Color counts for filter chips (AC-5)
@@ -6,14 +6,22 @@
 //! - Linux: Secret Service API (libsecret)
 //!
 //! Service name: "upwork-research-agent" (hyphenated for Windows Credential Manager compatibility)
-//! Username/key: "anthropic_api_key"
+//! Username/key: "anthropic_api_key" (other profiles: "anthropic_api_key.<profile>")
+//!
+//! When no keychain service is available, `ApiKeyStore` falls back to an
+//! encrypted file in the app data directory (see `file_store`).
 
 #[cfg(test)]
 mod tests;
 
+pub mod file_store;
 pub mod recovery;
+pub mod recovery_sheet;
 
 use keyring::Entry;
+use serde::Serialize;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 /// Service identifier for keychain entries
 /// Note: Using hyphenated name for Windows Credential Manager compatibility
@@ -22,6 +30,61 @@ const SERVICE_NAME: &str = "upwork-research-agent";
 /// Key identifier for API key in keychain
 const API_KEY_USERNAME: &str = "anthropic_api_key";
 
+/// Profile whose key uses the original, unsuffixed keychain entry
+pub const DEFAULT_PROFILE: &str = "default";
+
+const MAX_PROFILE_NAME_LENGTH: usize = 32;
+
+/// Write `contents` to `path`, readable and writable by the owner only on
+/// Unix, and flush it to disk. Shared by every file holding key material.
+pub(crate) fn write_owner_only(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+
+    // mode() only applies on create; tighten files left by an older build
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    }
+    Ok(())
+}
+
+/// Normalize and validate a profile name: 1-32 lowercase letters, digits, `-` or `_`
+pub fn validate_profile_name(name: &str) -> Result<String, String> {
+    let name = name.trim().to_lowercase();
+    if name.is_empty() || name.len() > MAX_PROFILE_NAME_LENGTH {
+        return Err(format!(
+            "Profile name must be 1-{} characters",
+            MAX_PROFILE_NAME_LENGTH
+        ));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("Profile name may only contain letters, digits, '-' and '_'".to_string());
+    }
+    Ok(name)
+}
+
+/// Keychain account for a profile's API key
+fn api_key_account(profile: &str) -> String {
+    if profile == DEFAULT_PROFILE {
+        API_KEY_USERNAME.to_string()
+    } else {
+        format!("{}.{}", API_KEY_USERNAME, profile)
+    }
+}
+
 /// Error types for keychain operations
 #[derive(Debug, thiserror::Error)]
 pub enum KeychainError {
@@ -39,6 +102,19 @@ pub enum KeychainError {
 
     #[error("API key not found in keychain")]
     NotFound,
+
+    /// No keychain service on this system (e.g. Linux without Secret Service)
+    #[error("OS keychain unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// Platform errors that mean there is no usable keychain service, as opposed to
+/// a failure with a working one
+fn is_unavailable(e: &keyring::Error) -> bool {
+    matches!(
+        e,
+        keyring::Error::PlatformFailure(_) | keyring::Error::NoStorageAccess(_)
+    )
 }
 
 /// Store API key in OS keychain.
@@ -55,17 +131,26 @@ pub enum KeychainError {
 /// - Windows: Stored in Credential Manager
 /// - Linux: Stored via Secret Service (requires libsecret)
 pub fn store_api_key(api_key: &str) -> Result<(), KeychainError> {
-    let entry = Entry::new(SERVICE_NAME, API_KEY_USERNAME)
-        .map_err(|e| KeychainError::AccessFailed(e.to_string()))?;
+    store_profile_api_key(DEFAULT_PROFILE, api_key)
+}
 
-    entry
-        .set_password(api_key)
-        .map_err(|e| KeychainError::StoreFailed(e.to_string()))?;
+/// Store a profile's API key in OS keychain (see `store_api_key`).
+pub fn store_profile_api_key(profile: &str, api_key: &str) -> Result<(), KeychainError> {
+    let account = api_key_account(profile);
+    let entry = Entry::new(SERVICE_NAME, &account).map_err(access_error)?;
+
+    entry.set_password(api_key).map_err(|e| {
+        if is_unavailable(&e) {
+            KeychainError::Unavailable(e.to_string())
+        } else {
+            KeychainError::StoreFailed(e.to_string())
+        }
+    })?;
 
     tracing::info!(
         "API key stored in OS keychain (service: {}, user: {})",
         SERVICE_NAME,
-        API_KEY_USERNAME
+        account
     );
 
     Ok(())
@@ -78,8 +163,12 @@ pub fn store_api_key(api_key: &str) -> Result<(), KeychainError> {
 /// * `Err(KeychainError::NotFound)` if not in keychain
 /// * `Err(KeychainError)` for other failures
 pub fn retrieve_api_key() -> Result<String, KeychainError> {
-    let entry = Entry::new(SERVICE_NAME, API_KEY_USERNAME)
-        .map_err(|e| KeychainError::AccessFailed(e.to_string()))?;
+    retrieve_profile_api_key(DEFAULT_PROFILE)
+}
+
+/// Retrieve a profile's API key from OS keychain (see `retrieve_api_key`).
+pub fn retrieve_profile_api_key(profile: &str) -> Result<String, KeychainError> {
+    let entry = Entry::new(SERVICE_NAME, &api_key_account(profile)).map_err(access_error)?;
 
     match entry.get_password() {
         Ok(password) => {
@@ -90,6 +179,7 @@ pub fn retrieve_api_key() -> Result<String, KeychainError> {
             tracing::warn!("API key not found in OS keychain");
             Err(KeychainError::NotFound)
         }
+        Err(e) if is_unavailable(&e) => Err(KeychainError::Unavailable(e.to_string())),
         Err(e) => {
             tracing::error!("Failed to retrieve API key from keychain: {}", e);
             Err(KeychainError::RetrieveFailed(e.to_string()))
@@ -103,8 +193,12 @@ pub fn retrieve_api_key() -> Result<String, KeychainError> {
 /// * `Ok(())` if deleted successfully or not found
 /// * `Err(KeychainError)` if deletion fails
 pub fn delete_api_key() -> Result<(), KeychainError> {
-    let entry = Entry::new(SERVICE_NAME, API_KEY_USERNAME)
-        .map_err(|e| KeychainError::AccessFailed(e.to_string()))?;
+    delete_profile_api_key(DEFAULT_PROFILE)
+}
+
+/// Delete a profile's API key from OS keychain (see `delete_api_key`).
+pub fn delete_profile_api_key(profile: &str) -> Result<(), KeychainError> {
+    let entry = Entry::new(SERVICE_NAME, &api_key_account(profile)).map_err(access_error)?;
 
     match entry.delete_credential() {
         Ok(_) => {
@@ -116,6 +210,7 @@ pub fn delete_api_key() -> Result<(), KeychainError> {
             tracing::debug!("API key not found in keychain (already deleted)");
             Ok(())
         }
+        Err(e) if is_unavailable(&e) => Err(KeychainError::Unavailable(e.to_string())),
         Err(e) => {
             tracing::error!("Failed to delete API key from keychain: {}", e);
             Err(KeychainError::DeleteFailed(e.to_string()))
@@ -136,3 +231,140 @@ pub fn has_api_key() -> Result<bool, KeychainError> {
         Err(e) => Err(e),
     }
 }
+
+fn access_error(e: keyring::Error) -> KeychainError {
+    if is_unavailable(&e) {
+        KeychainError::Unavailable(e.to_string())
+    } else {
+        KeychainError::AccessFailed(e.to_string())
+    }
+}
+
+/// A place API keys can be kept, one per profile (OS keychain or the encrypted file fallback)
+pub trait SecretStore: Send + Sync {
+    fn store(&self, profile: &str, api_key: &str) -> Result<(), KeychainError>;
+    /// `Err(KeychainError::NotFound)` when nothing is stored
+    fn retrieve(&self, profile: &str) -> Result<String, KeychainError>;
+    /// Ok if nothing was stored
+    fn delete(&self, profile: &str) -> Result<(), KeychainError>;
+}
+
+/// The platform keychain via the functions above
+pub struct OsKeychain;
+
+impl SecretStore for OsKeychain {
+    fn store(&self, profile: &str, api_key: &str) -> Result<(), KeychainError> {
+        store_profile_api_key(profile, api_key)
+    }
+
+    fn retrieve(&self, profile: &str) -> Result<String, KeychainError> {
+        retrieve_profile_api_key(profile)
+    }
+
+    fn delete(&self, profile: &str) -> Result<(), KeychainError> {
+        delete_profile_api_key(profile)
+    }
+}
+
+/// Where the API key currently lives (reported by `get_encryption_status`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyBackend {
+    Keychain,
+    EncryptedFile,
+    PlaintextConfig,
+}
+
+/// Per-profile API key storage: the OS keychain when it works, else the encrypted file.
+///
+/// The keychain counts as unavailable when it reports no platform service or
+/// storage access, or accepts a key it can't read back.
+pub struct ApiKeyStore {
+    keychain: Box<dyn SecretStore>,
+    fallback: Box<dyn SecretStore>,
+}
+
+impl ApiKeyStore {
+    /// OS keychain with `app_data_dir/.apikey.enc` as the fallback
+    pub fn new(app_data_dir: PathBuf) -> Self {
+        Self::with_backends(
+            Box::new(OsKeychain),
+            Box::new(file_store::EncryptedFileStore::new(app_data_dir)),
+        )
+    }
+
+    /// Explicit backends (tests inject fakes here)
+    pub fn with_backends(keychain: Box<dyn SecretStore>, fallback: Box<dyn SecretStore>) -> Self {
+        Self { keychain, fallback }
+    }
+
+    /// Store the key in the keychain, or in the encrypted file if the keychain is
+    /// unavailable. Returns the backend used; the other backend's copy is removed.
+    pub fn store(&self, profile: &str, api_key: &str) -> Result<ApiKeyBackend, KeychainError> {
+        let keychain_result = self.keychain.store(profile, api_key).and_then(|()| {
+            match self.keychain.retrieve(profile) {
+                Ok(stored) if stored == api_key => Ok(()),
+                Ok(_) => Err(KeychainError::Unavailable(
+                    "keychain returned a different key than was stored".to_string(),
+                )),
+                Err(KeychainError::NotFound) => Err(KeychainError::Unavailable(
+                    "keychain accepted the key but cannot read it back".to_string(),
+                )),
+                Err(e) => Err(e),
+            }
+        });
+
+        match keychain_result {
+            Ok(()) => {
+                if let Err(e) = self.fallback.delete(profile) {
+                    tracing::warn!("Failed to remove encrypted API key file: {}", e);
+                }
+                Ok(ApiKeyBackend::Keychain)
+            }
+            Err(KeychainError::Unavailable(reason)) => {
+                tracing::warn!(
+                    "OS keychain unavailable ({}). Storing API key in encrypted file.",
+                    reason
+                );
+                let _ = self.keychain.delete(profile);
+                self.fallback.store(profile, api_key)?;
+                Ok(ApiKeyBackend::EncryptedFile)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The stored key and its backend. `Err(NotFound)` if neither backend has one.
+    pub fn retrieve(&self, profile: &str) -> Result<(String, ApiKeyBackend), KeychainError> {
+        let keychain_error = match self.keychain.retrieve(profile) {
+            Ok(key) => return Ok((key, ApiKeyBackend::Keychain)),
+            Err(e) => e,
+        };
+        match self.fallback.retrieve(profile) {
+            Ok(key) => Ok((key, ApiKeyBackend::EncryptedFile)),
+            Err(KeychainError::NotFound) => match keychain_error {
+                KeychainError::Unavailable(_) => Err(KeychainError::NotFound),
+                e => Err(e),
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Whether either backend holds a key
+    pub fn has_api_key(&self, profile: &str) -> Result<bool, KeychainError> {
+        match self.retrieve(profile) {
+            Ok(_) => Ok(true),
+            Err(KeychainError::NotFound) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Remove the key from both backends (an unavailable keychain is not an error)
+    pub fn delete(&self, profile: &str) -> Result<(), KeychainError> {
+        match self.keychain.delete(profile) {
+            Ok(()) | Err(KeychainError::Unavailable(_)) => {}
+            Err(e) => return Err(e),
+        }
+        self.fallback.delete(profile)
+    }
+}
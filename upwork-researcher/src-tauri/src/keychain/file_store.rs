@@ -0,0 +1,161 @@
+//! Encrypted file fallback for API key storage.
+//!
+//! Used when no OS keychain service is available (e.g. Linux without a
+//! Secret Service). The API key is encrypted with AES-256-GCM under a
+//! machine-local key derived from a random secret file, so it never sits in
+//! config.json in plaintext. Both files are created owner-only (0600).
+//!
+//! Files (in the app data directory):
+//! - `.apikey.enc`: base64(nonce || ciphertext) (other profiles: `.apikey.<profile>.enc`)
+//! - `.apikey.secret`: 32 random bytes, generated on first store, shared by all profiles
+
+use super::{write_owner_only, KeychainError, SecretStore, DEFAULT_PROFILE};
+use aes_gcm::aead::Aead;
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine as _};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use zeroize::Zeroizing;
+
+/// Encrypted API key file name
+pub const API_KEY_FILE: &str = ".apikey.enc";
+
+/// Machine-local secret the file key is derived from
+pub const SECRET_FILE: &str = ".apikey.secret";
+
+const SECRET_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 12;
+
+/// Domain separation for the file key derivation
+const KEY_CONTEXT: &[u8] = b"upwork-research-agent/api-key-file/v1";
+
+/// API key encrypted at rest in the app data directory
+pub struct EncryptedFileStore {
+    dir: PathBuf,
+}
+
+impl EncryptedFileStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn key_path(&self, profile: &str) -> PathBuf {
+        if profile == DEFAULT_PROFILE {
+            self.dir.join(API_KEY_FILE)
+        } else {
+            self.dir.join(format!(".apikey.{}.enc", profile))
+        }
+    }
+
+    fn secret_path(&self) -> PathBuf {
+        self.dir.join(SECRET_FILE)
+    }
+
+    /// Load the machine-local secret, generating it on first use
+    fn file_key(&self, create: bool) -> Result<Zeroizing<[u8; 32]>, KeychainError> {
+        let secret_path = self.secret_path();
+        let secret = match fs::read(&secret_path) {
+            Ok(bytes) => Zeroizing::new(bytes),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && create => {
+                let mut bytes = Zeroizing::new(vec![0u8; SECRET_LENGTH]);
+                rand::thread_rng().fill_bytes(&mut bytes);
+                write_owner_only(&secret_path, &bytes)
+                    .map_err(|e| KeychainError::StoreFailed(format!("secret file: {}", e)))?;
+                bytes
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(KeychainError::NotFound)
+            }
+            Err(e) => {
+                return Err(KeychainError::RetrieveFailed(format!(
+                    "Failed to read secret file: {}",
+                    e
+                )))
+            }
+        };
+        if secret.len() != SECRET_LENGTH {
+            return Err(KeychainError::RetrieveFailed(
+                "Secret file is corrupted".to_string(),
+            ));
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(KEY_CONTEXT);
+        hasher.update(&*secret);
+        Ok(Zeroizing::new(hasher.finalize().into()))
+    }
+}
+
+impl SecretStore for EncryptedFileStore {
+    fn store(&self, profile: &str, api_key: &str) -> Result<(), KeychainError> {
+        fs::create_dir_all(&self.dir).map_err(|e| KeychainError::StoreFailed(e.to_string()))?;
+        let key = self.file_key(true)?;
+        let cipher = Aes256Gcm::new_from_slice(&*key)
+            .map_err(|e| KeychainError::StoreFailed(e.to_string()))?;
+
+        let mut nonce_bytes = [0u8; NONCE_LENGTH];
+        rand::thread_rng().fill_bytes(&mut nonce_bytes);
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce_bytes), api_key.as_bytes())
+            .map_err(|e| KeychainError::StoreFailed(format!("Encryption failed: {}", e)))?;
+
+        let mut payload = nonce_bytes.to_vec();
+        payload.extend_from_slice(&ciphertext);
+        write_owner_only(
+            &self.key_path(profile),
+            BASE64_STANDARD.encode(payload).as_bytes(),
+        )
+        .map_err(|e| KeychainError::StoreFailed(e.to_string()))?;
+
+        tracing::info!(profile, "API key stored in encrypted file");
+        Ok(())
+    }
+
+    fn retrieve(&self, profile: &str) -> Result<String, KeychainError> {
+        let encoded = match fs::read_to_string(self.key_path(profile)) {
+            Ok(encoded) => encoded,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(KeychainError::NotFound)
+            }
+            Err(e) => return Err(KeychainError::RetrieveFailed(e.to_string())),
+        };
+        let payload = BASE64_STANDARD
+            .decode(encoded.trim())
+            .map_err(|e| KeychainError::RetrieveFailed(format!("Invalid key file: {}", e)))?;
+        if payload.len() <= NONCE_LENGTH {
+            return Err(KeychainError::RetrieveFailed(
+                "Key file is truncated".to_string(),
+            ));
+        }
+
+        let key = self.file_key(false)?;
+        let cipher = Aes256Gcm::new_from_slice(&*key)
+            .map_err(|e| KeychainError::RetrieveFailed(e.to_string()))?;
+        let (nonce, ciphertext) = payload.split_at(NONCE_LENGTH);
+        let plaintext = Zeroizing::new(
+            cipher
+                .decrypt(Nonce::from_slice(nonce), ciphertext)
+                .map_err(|_| {
+                    KeychainError::RetrieveFailed(
+                        "Failed to decrypt API key file (secret file changed?)".to_string(),
+                    )
+                })?,
+        );
+
+        String::from_utf8(plaintext.to_vec())
+            .map_err(|e| KeychainError::RetrieveFailed(e.to_string()))
+    }
+
+    fn delete(&self, profile: &str) -> Result<(), KeychainError> {
+        match fs::remove_file(self.key_path(profile)) {
+            Ok(()) => {
+                tracing::info!("Encrypted API key file deleted");
+                Ok(())
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(KeychainError::DeleteFailed(e.to_string())),
+        }
+    }
+}
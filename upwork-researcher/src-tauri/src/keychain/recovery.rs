@@ -9,6 +9,7 @@
 //! Security: Recovery key shown to user (print/save), then encrypted at rest
 //! using AES-256-GCM with Argon2id-derived key.
 
+use super::write_owner_only;
 use aes_gcm::aead::Aead;
 use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
 use argon2::{
@@ -18,6 +19,7 @@ use argon2::{
 use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine as _};
 use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng, RngCore};
+use std::path::{Path, PathBuf};
 
 /// Recovery key length (32 alphanumeric characters)
 pub const RECOVERY_KEY_LENGTH: usize = 32;
@@ -25,6 +27,12 @@ pub const RECOVERY_KEY_LENGTH: usize = 32;
 /// AES-256-GCM nonce length (96 bits / 12 bytes)
 const NONCE_LENGTH: usize = 12;
 
+/// Argon2 hash of the current recovery key (app data dir)
+pub const RECOVERY_HASH_FILE: &str = ".recovery_hash";
+
+/// DB key wrapped with the current recovery key (app data dir)
+pub const RECOVERY_WRAPPED_KEY_FILE: &str = ".recovery_wrapped_key";
+
 /// Error types for recovery key operations
 #[derive(Debug, thiserror::Error)]
 pub enum RecoveryError {
@@ -44,6 +52,9 @@ pub enum RecoveryError {
 
     #[error("Recovery key derivation failed: {0}")]
     DerivationFailed(String),
+
+    #[error("Failed to store recovery files: {0}")]
+    StorageFailed(String),
 }
 
 /// Derive a 32-byte encryption key from passphrase using Argon2id.
@@ -388,6 +399,134 @@ pub fn unwrap_db_key(wrapped_key: &str, recovery_key: &str) -> Result<Vec<u8>, R
     Ok(db_key)
 }
 
+/// Hash the recovery key for verification without the passphrase
+/// (Argon2 PHC string, as stored in `.recovery_hash`).
+pub fn hash_recovery_key(recovery_key: &str) -> Result<String, RecoveryError> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(recovery_key.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| RecoveryError::GenerationFailed(format!("Argon2 hash failed: {}", e)))
+}
+
+/// Whether both recovery files exist, so `unlock_with_recovery_key` can work.
+///
+/// Only checks existence; nothing is read or decrypted. A lone file (partial or
+/// corrupt setup) counts as not configured and is logged, since recovery would
+/// fail and the user should generate a new key.
+pub fn has_recovery_files(app_data_dir: &Path) -> bool {
+    let has_hash = app_data_dir.join(RECOVERY_HASH_FILE).exists();
+    let has_wrapped_key = app_data_dir.join(RECOVERY_WRAPPED_KEY_FILE).exists();
+    if has_hash != has_wrapped_key {
+        tracing::warn!(
+            has_hash = has_hash,
+            has_wrapped_key = has_wrapped_key,
+            "Incomplete recovery setup: only one recovery file exists; generate a new recovery key"
+        );
+    }
+    has_hash && has_wrapped_key
+}
+
+/// Replace the recovery hash and wrapped key files as a pair, then run `commit`.
+///
+/// Both new files are written to `.tmp` siblings (owner-only on Unix) and
+/// renamed into place only once both are on disk. `commit` runs last (the
+/// caller commits its `encryption_metadata` update there); if a rename or
+/// `commit` fails, the previous files are put back, so recovery never sees the
+/// old hash with the new wrapped key or vice versa. On success the integrity
+/// manifest (`crate::integrity`) is rewritten for the new files.
+pub fn replace_recovery_files<F>(
+    app_data_dir: &Path,
+    recovery_key_hash: &str,
+    wrapped_db_key: &str,
+    commit: F,
+) -> Result<(), RecoveryError>
+where
+    F: FnOnce() -> Result<(), String>,
+{
+    let hash_path = app_data_dir.join(RECOVERY_HASH_FILE);
+    let wrapped_path = app_data_dir.join(RECOVERY_WRAPPED_KEY_FILE);
+    let hash_tmp = tmp_path(&hash_path);
+    let wrapped_tmp = tmp_path(&wrapped_path);
+
+    let staged = write_owner_only(&hash_tmp, recovery_key_hash.as_bytes())
+        .and_then(|_| write_owner_only(&wrapped_tmp, wrapped_db_key.as_bytes()));
+    if let Err(e) = staged {
+        let _ = std::fs::remove_file(&hash_tmp);
+        let _ = std::fs::remove_file(&wrapped_tmp);
+        return Err(RecoveryError::StorageFailed(format!(
+            "Failed to write new recovery files: {}",
+            e
+        )));
+    }
+
+    let previous_hash = read_if_exists(&hash_path)?;
+    let previous_wrapped = read_if_exists(&wrapped_path)?;
+
+    let result = std::fs::rename(&hash_tmp, &hash_path)
+        .map_err(|e| format!("Failed to replace recovery hash file: {}", e))
+        .and_then(|_| {
+            std::fs::rename(&wrapped_tmp, &wrapped_path)
+                .map_err(|e| format!("Failed to replace wrapped key file: {}", e))
+        })
+        .and_then(|_| commit());
+
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&hash_tmp);
+        let _ = std::fs::remove_file(&wrapped_tmp);
+        for (path, previous) in [
+            (&hash_path, &previous_hash),
+            (&wrapped_path, &previous_wrapped),
+        ] {
+            if let Err(restore_err) = restore_file(path, previous.as_deref()) {
+                tracing::error!(
+                    path = %path.display(),
+                    error = %restore_err,
+                    "Failed to restore recovery file after failed rotation"
+                );
+            }
+        }
+        return Err(RecoveryError::StorageFailed(e));
+    }
+
+    crate::integrity::refresh_manifest(app_data_dir);
+    Ok(())
+}
+
+fn tmp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+fn read_if_exists(path: &Path) -> Result<Option<String>, RecoveryError> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(RecoveryError::StorageFailed(format!(
+            "Failed to read {}: {}",
+            path.display(),
+            e
+        ))),
+    }
+}
+
+/// Put `previous` back at `path` (via a temp file), or remove `path` if there
+/// was no file before
+fn restore_file(path: &Path, previous: Option<&str>) -> std::io::Result<()> {
+    match previous {
+        Some(content) => {
+            let tmp = tmp_path(path);
+            write_owner_only(&tmp, content.as_bytes())?;
+            std::fs::rename(&tmp, path)
+        }
+        None => match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -547,4 +686,108 @@ mod tests {
             "Old passphrase must not decrypt re-encrypted key"
         );
     }
+
+    #[test]
+    fn test_replace_recovery_files_swaps_pair_and_old_key_stops_working() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_key = [7u8; 32];
+        let old_key = generate_recovery_key().unwrap();
+        let new_key = generate_recovery_key().unwrap();
+
+        replace_recovery_files(
+            dir.path(),
+            &hash_recovery_key(&old_key).unwrap(),
+            &wrap_db_key(&db_key, &old_key).unwrap(),
+            || Ok(()),
+        )
+        .unwrap();
+        let new_hash = hash_recovery_key(&new_key).unwrap();
+        let new_wrapped = wrap_db_key(&db_key, &new_key).unwrap();
+        replace_recovery_files(dir.path(), &new_hash, &new_wrapped, || Ok(())).unwrap();
+
+        let stored_hash = std::fs::read_to_string(dir.path().join(RECOVERY_HASH_FILE)).unwrap();
+        let stored_wrapped =
+            std::fs::read_to_string(dir.path().join(RECOVERY_WRAPPED_KEY_FILE)).unwrap();
+        assert_eq!(stored_hash, new_hash);
+        assert_eq!(stored_wrapped, new_wrapped);
+        assert_eq!(unwrap_db_key(&stored_wrapped, &new_key).unwrap(), db_key);
+        assert!(unwrap_db_key(&stored_wrapped, &old_key).is_err());
+
+        // No temp files left behind; the integrity manifest covers the new pair
+        let names: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .collect();
+        assert_eq!(names.len(), 3);
+        assert!(crate::integrity::verify_manifest(dir.path())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_replace_recovery_files_restores_previous_pair_when_commit_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(RECOVERY_HASH_FILE), "old-hash").unwrap();
+        std::fs::write(dir.path().join(RECOVERY_WRAPPED_KEY_FILE), "old-wrapped").unwrap();
+
+        let result = replace_recovery_files(dir.path(), "new-hash", "new-wrapped", || {
+            Err("metadata update failed".to_string())
+        });
+
+        assert!(
+            matches!(result, Err(RecoveryError::StorageFailed(ref e)) if e.contains("metadata"))
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join(RECOVERY_HASH_FILE)).unwrap(),
+            "old-hash"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join(RECOVERY_WRAPPED_KEY_FILE)).unwrap(),
+            "old-wrapped"
+        );
+        assert!(!dir.path().join(".recovery_hash.tmp").exists());
+        assert!(!dir.path().join(".recovery_wrapped_key.tmp").exists());
+    }
+
+    #[test]
+    fn test_replace_recovery_files_removes_new_files_when_none_existed() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let result =
+            replace_recovery_files(dir.path(), "new-hash", "new-wrapped", || Err("no".into()));
+
+        assert!(result.is_err());
+        assert!(!dir.path().join(RECOVERY_HASH_FILE).exists());
+        assert!(!dir.path().join(RECOVERY_WRAPPED_KEY_FILE).exists());
+    }
+
+    #[test]
+    fn test_replace_recovery_files_fails_before_touching_files_when_staging_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("missing");
+        let mut committed = false;
+
+        let result = replace_recovery_files(&missing, "h", "w", || {
+            committed = true;
+            Ok(())
+        });
+
+        assert!(matches!(result, Err(RecoveryError::StorageFailed(_))));
+        assert!(!committed);
+    }
+
+    #[test]
+    fn test_has_recovery_files_requires_both_files() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!has_recovery_files(dir.path()));
+
+        std::fs::write(dir.path().join(RECOVERY_HASH_FILE), "hash").unwrap();
+        assert!(!has_recovery_files(dir.path()));
+
+        std::fs::write(dir.path().join(RECOVERY_WRAPPED_KEY_FILE), "wrapped").unwrap();
+        assert!(has_recovery_files(dir.path()));
+
+        std::fs::remove_file(dir.path().join(RECOVERY_HASH_FILE)).unwrap();
+        assert!(!has_recovery_files(dir.path()));
+    }
 }
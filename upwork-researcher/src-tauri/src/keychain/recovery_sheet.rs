@@ -0,0 +1,172 @@
+//! Printable recovery sheet for the recovery key (Story 2.9 follow-up).
+//!
+//! Renders a self-contained HTML page (open it in a browser and print) with the
+//! recovery key, the date it was generated, instructions, and a QR code of the
+//! key as inline SVG. The page never references external resources.
+//!
+//! Security: the sheet is built in memory and written only to the path the user
+//! chose, created with owner-only permissions on Unix. Neither the key nor the
+//! rendered sheet is logged or cached.
+
+use super::recovery::{validate_recovery_key, RecoveryError};
+use qrcode::render::svg;
+use qrcode::{EcLevel, QrCode};
+use std::path::Path;
+use zeroize::Zeroizing;
+
+/// Characters per visual group when printing the key
+const KEY_GROUP_SIZE: usize = 4;
+
+/// Render the recovery sheet for `recovery_key`.
+///
+/// The key is printed in groups of four for reading, but the groups are
+/// separate spans so copying the text yields the key without spaces.
+pub fn render_recovery_sheet(
+    recovery_key: &str,
+    generated_at: chrono::DateTime<chrono::Local>,
+) -> Result<Zeroizing<String>, RecoveryError> {
+    validate_recovery_key(recovery_key)?;
+
+    let qr = QrCode::with_error_correction_level(recovery_key.as_bytes(), EcLevel::M)
+        .map_err(|e| RecoveryError::GenerationFailed(format!("QR code: {}", e)))?;
+    let qr_svg = Zeroizing::new(
+        qr.render::<svg::Color>()
+            .min_dimensions(220, 220)
+            .quiet_zone(true)
+            .build(),
+    );
+    // Drop the XML declaration: the SVG is embedded inline in HTML
+    let qr_inline = &qr_svg[qr_svg.find("<svg").unwrap_or(0)..];
+
+    let key_groups = Zeroizing::new(
+        recovery_key
+            .as_bytes()
+            .chunks(KEY_GROUP_SIZE)
+            .map(|group| {
+                // Alphanumeric (validated above), so no escaping needed
+                format!(
+                    "<span class=\"group\">{}</span>",
+                    std::str::from_utf8(group).unwrap_or_default()
+                )
+            })
+            .collect::<String>(),
+    );
+    let generated = generated_at.format("%B %-d, %Y at %H:%M").to_string();
+
+    Ok(Zeroizing::new(format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Upwork Research Agent - Recovery Sheet</title>
+<style>
+  body {{ font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; max-width: 640px; margin: 32px auto; color: #111; }}
+  .warning {{ border: 2px solid #b91c1c; background: #fef2f2; color: #7f1d1d; padding: 12px 16px; border-radius: 6px; }}
+  .key {{ font-family: "SF Mono", Consolas, "Courier New", monospace; font-size: 22px; text-align: center; padding: 16px; border: 1px dashed #555; margin: 16px 0; }}
+  .key .group {{ margin: 0 6px; }}
+  .qr {{ text-align: center; margin: 16px 0; }}
+  ol li {{ margin-bottom: 6px; }}
+  .meta {{ color: #555; font-size: 13px; }}
+</style>
+</head>
+<body>
+<div class="warning">
+  <strong>Sensitive: this sheet contains your recovery key.</strong>
+  Anyone who has it and your database file can read your data. Print it and
+  keep it somewhere safe, then delete this file.
+</div>
+<h1>Recovery Sheet</h1>
+<p class="meta">Generated {generated}</p>
+<h2>Recovery key</h2>
+<div class="key">{key_groups}</div>
+<div class="qr">{qr_svg}</div>
+<h2>If you forget your passphrase</h2>
+<ol>
+  <li>Open Upwork Research Agent and choose <em>Forgot passphrase? Use recovery key</em> on the unlock screen.</li>
+  <li>Type the 32-character key above (the spaces are only for readability), or scan the QR code and paste it.</li>
+  <li>Set a new passphrase when prompted, then generate a new recovery key and sheet.</li>
+</ol>
+<p class="meta">Generating a new recovery key replaces this one; this sheet then stops working.</p>
+</body>
+</html>
+"#,
+        generated = generated,
+        key_groups = key_groups.as_str(),
+        qr_svg = qr_inline,
+    )))
+}
+
+/// Write the sheet to `path`, creating it with owner-only permissions on Unix.
+/// An existing file is truncated and its permissions tightened as well.
+pub fn write_recovery_sheet(path: &Path, sheet: &str) -> std::io::Result<()> {
+    super::write_owner_only(path, sheet.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tempfile::tempdir;
+
+    const KEY: &str = "AbCd1234EfGh5678IjKl9012MnOp3456";
+
+    #[test]
+    fn test_render_recovery_sheet_contains_key_date_and_qr() {
+        let generated_at = chrono::Local
+            .with_ymd_and_hms(2026, 3, 7, 14, 5, 0)
+            .unwrap();
+        let sheet = render_recovery_sheet(KEY, generated_at).unwrap();
+
+        assert!(
+            sheet.contains("<span class=\"group\">AbCd</span><span class=\"group\">1234</span>")
+        );
+        assert!(sheet.contains("<span class=\"group\">3456</span>"));
+        assert!(sheet.contains("March 7, 2026 at 14:05"));
+        assert!(sheet.contains("<svg"));
+        assert!(!sheet.contains("<?xml"));
+        assert!(sheet.contains("Sensitive"));
+        // Self-contained: nothing fetched when the file is opened
+        assert!(!sheet.contains("https://"));
+        assert!(!sheet.contains("<script"));
+        assert!(!sheet.contains("<link"));
+    }
+
+    #[test]
+    fn test_render_recovery_sheet_rejects_invalid_key() {
+        let result = render_recovery_sheet("too-short", chrono::Local::now());
+        assert!(matches!(result, Err(RecoveryError::InvalidFormat)));
+    }
+
+    #[test]
+    fn test_write_recovery_sheet_writes_content() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("sheet.html");
+
+        write_recovery_sheet(&path, "<html>sheet</html>").unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "<html>sheet</html>"
+        );
+
+        // Overwrite truncates
+        write_recovery_sheet(&path, "new").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_recovery_sheet_owner_only_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("sheet.html");
+        // Pre-existing world-readable file is tightened too
+        std::fs::write(&path, "old").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_recovery_sheet(&path, "sheet").unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
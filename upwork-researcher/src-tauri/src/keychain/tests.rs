@@ -193,3 +193,332 @@ fn test_error_handling_graceful_degradation() {
 
     // All operations completed without panicking = graceful handling ✓
 }
+
+// ============================================================================
+// Encrypted file fallback (injected backends, no real keyring needed)
+// ============================================================================
+
+/// Fake keychain backend with a switchable failure mode
+#[derive(Clone, Copy)]
+enum FakeKeychainMode {
+    /// Stores and reads back normally
+    Working,
+    /// No keychain service (Secret Service missing)
+    NoService,
+    /// Accepts the key but can't read it back (unsigned binary)
+    WriteOnly,
+}
+
+struct FakeKeychain {
+    mode: FakeKeychainMode,
+    values: std::sync::Mutex<std::collections::HashMap<String, String>>,
+}
+
+impl FakeKeychain {
+    fn new(mode: FakeKeychainMode) -> Box<Self> {
+        Box::new(Self {
+            mode,
+            values: std::sync::Mutex::new(std::collections::HashMap::new()),
+        })
+    }
+}
+
+impl SecretStore for FakeKeychain {
+    fn store(&self, profile: &str, api_key: &str) -> Result<(), KeychainError> {
+        match self.mode {
+            FakeKeychainMode::NoService => {
+                Err(KeychainError::Unavailable("no Secret Service".to_string()))
+            }
+            FakeKeychainMode::WriteOnly => Ok(()),
+            FakeKeychainMode::Working => {
+                self.values
+                    .lock()
+                    .unwrap()
+                    .insert(profile.to_string(), api_key.to_string());
+                Ok(())
+            }
+        }
+    }
+
+    fn retrieve(&self, profile: &str) -> Result<String, KeychainError> {
+        match self.mode {
+            FakeKeychainMode::NoService => {
+                Err(KeychainError::Unavailable("no Secret Service".to_string()))
+            }
+            _ => self
+                .values
+                .lock()
+                .unwrap()
+                .get(profile)
+                .cloned()
+                .ok_or(KeychainError::NotFound),
+        }
+    }
+
+    fn delete(&self, profile: &str) -> Result<(), KeychainError> {
+        match self.mode {
+            FakeKeychainMode::NoService => {
+                Err(KeychainError::Unavailable("no Secret Service".to_string()))
+            }
+            _ => {
+                self.values.lock().unwrap().remove(profile);
+                Ok(())
+            }
+        }
+    }
+}
+
+fn store_with(mode: FakeKeychainMode, dir: &std::path::Path) -> ApiKeyStore {
+    ApiKeyStore::with_backends(
+        FakeKeychain::new(mode),
+        Box::new(file_store::EncryptedFileStore::new(dir.to_path_buf())),
+    )
+}
+
+#[test]
+fn test_fallback_to_encrypted_file_without_keychain_service() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = store_with(FakeKeychainMode::NoService, dir.path());
+
+    assert!(!store.has_api_key(DEFAULT_PROFILE).unwrap());
+    assert_eq!(
+        store.store(DEFAULT_PROFILE, TEST_API_KEY).unwrap(),
+        ApiKeyBackend::EncryptedFile
+    );
+    assert_eq!(
+        store.retrieve(DEFAULT_PROFILE).unwrap(),
+        (TEST_API_KEY.to_string(), ApiKeyBackend::EncryptedFile)
+    );
+
+    // Encrypted at rest, owner-only
+    let key_file = dir.path().join(file_store::API_KEY_FILE);
+    let on_disk = std::fs::read_to_string(&key_file).unwrap();
+    assert!(!on_disk.contains(TEST_API_KEY));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&key_file).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    // Survives a restart (new store over the same directory)
+    let reopened = store_with(FakeKeychainMode::NoService, dir.path());
+    assert_eq!(reopened.retrieve(DEFAULT_PROFILE).unwrap().0, TEST_API_KEY);
+
+    reopened.delete(DEFAULT_PROFILE).unwrap();
+    assert!(!key_file.exists());
+    assert!(!reopened.has_api_key(DEFAULT_PROFILE).unwrap());
+}
+
+#[test]
+fn test_write_only_keychain_uses_encrypted_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = store_with(FakeKeychainMode::WriteOnly, dir.path());
+
+    assert_eq!(
+        store.store(DEFAULT_PROFILE, TEST_API_KEY).unwrap(),
+        ApiKeyBackend::EncryptedFile
+    );
+    assert_eq!(store.retrieve(DEFAULT_PROFILE).unwrap().0, TEST_API_KEY);
+}
+
+#[test]
+fn test_working_keychain_removes_file_copy() {
+    let dir = tempfile::tempdir().unwrap();
+    let key_file = dir.path().join(file_store::API_KEY_FILE);
+
+    // Key stored while the keychain was down...
+    store_with(FakeKeychainMode::NoService, dir.path())
+        .store(DEFAULT_PROFILE, TEST_API_KEY)
+        .unwrap();
+    assert!(key_file.exists());
+
+    // ...moves to the keychain once it works
+    let store = store_with(FakeKeychainMode::Working, dir.path());
+    assert_eq!(
+        store.store(DEFAULT_PROFILE, TEST_API_KEY).unwrap(),
+        ApiKeyBackend::Keychain
+    );
+    assert!(!key_file.exists());
+    assert_eq!(
+        store.retrieve(DEFAULT_PROFILE).unwrap(),
+        (TEST_API_KEY.to_string(), ApiKeyBackend::Keychain)
+    );
+}
+
+#[test]
+fn test_config_state_with_fallback_backend() {
+    let dir = tempfile::tempdir().unwrap();
+
+    // Plaintext key from an older version
+    std::fs::write(
+        dir.path().join("config.json"),
+        r#"{"api_key": "sk-ant-REDACTED"}"#,
+    )
+    .unwrap();
+    let state = crate::config::ConfigState::with_api_key_store(
+        dir.path().to_path_buf(),
+        store_with(FakeKeychainMode::NoService, dir.path()),
+    )
+    .unwrap();
+    assert_eq!(
+        state.api_key_backend().unwrap(),
+        Some(ApiKeyBackend::PlaintextConfig)
+    );
+
+    assert!(state.migrate_api_key_to_keychain().unwrap());
+    assert!(state.config.lock().unwrap().api_key.is_none());
+    assert_eq!(
+        state.api_key_backend().unwrap(),
+        Some(ApiKeyBackend::EncryptedFile)
+    );
+    assert_eq!(
+        state.get_api_key().unwrap().as_deref(),
+        Some("sk-ant-REDACTED")
+    );
+    assert!(!state.migrate_api_key_to_keychain().unwrap());
+
+    state.clear_api_key().unwrap();
+    assert_eq!(state.api_key_backend().unwrap(), None);
+    assert!(!state.has_api_key().unwrap());
+}
+
+#[test]
+fn test_switch_profile_uses_that_profiles_key() {
+    let dir = tempfile::tempdir().unwrap();
+    let state = crate::config::ConfigState::with_api_key_store(
+        dir.path().to_path_buf(),
+        store_with(FakeKeychainMode::Working, dir.path()),
+    )
+    .unwrap();
+    state
+        .set_api_key("sk-ant-REDACTED".to_string())
+        .unwrap();
+
+    // New profile has no key: the UI must prompt rather than reuse the default's
+    let switched = state.switch_profile("Client-Work").unwrap();
+    assert_eq!(switched.name, "client-work");
+    assert!(!switched.has_api_key);
+    assert!(!state.has_api_key().unwrap());
+    assert_eq!(state.get_api_key().unwrap(), None);
+
+    state
+        .set_api_key("sk-ant-client-account-5678".to_string())
+        .unwrap();
+    assert_eq!(
+        state.get_api_key().unwrap().as_deref(),
+        Some("sk-ant-client-account-5678")
+    );
+
+    state.switch_profile("default").unwrap();
+    assert_eq!(
+        state.get_api_key().unwrap().as_deref(),
+        Some("sk-ant-REDACTED")
+    );
+    let profiles = state.list_profiles().unwrap();
+    assert_eq!(
+        profiles
+            .iter()
+            .map(|p| (p.name.as_str(), p.active, p.has_api_key))
+            .collect::<Vec<_>>(),
+        vec![("default", true, true), ("client-work", false, true)]
+    );
+
+    assert!(state.switch_profile("bad name!").is_err());
+    assert!(state.switch_profile("").is_err());
+}
+
+#[test]
+fn test_config_reset_removes_every_profile_key() {
+    let dir = tempfile::tempdir().unwrap();
+    let state = crate::config::ConfigState::with_api_key_store(
+        dir.path().to_path_buf(),
+        store_with(FakeKeychainMode::NoService, dir.path()),
+    )
+    .unwrap();
+    state
+        .set_api_key("sk-ant-REDACTED".to_string())
+        .unwrap();
+    state.switch_profile("client-work").unwrap();
+    state
+        .set_api_key("sk-ant-client-account-5678".to_string())
+        .unwrap();
+
+    state.reset().unwrap();
+
+    assert_eq!(state.active_profile().unwrap(), "default");
+    assert!(!state.has_api_key().unwrap());
+    assert_eq!(
+        state
+            .switch_profile("client-work")
+            .map(|profile| profile.has_api_key),
+        Ok(false)
+    );
+    assert!(!dir.path().join(file_store::API_KEY_FILE).exists());
+}
+
+#[test]
+fn test_named_profiles_listing_and_deletion() {
+    let dir = tempfile::tempdir().unwrap();
+    let state = crate::config::ConfigState::with_api_key_store(
+        dir.path().to_path_buf(),
+        store_with(FakeKeychainMode::Working, dir.path()),
+    )
+    .unwrap();
+    state
+        .set_api_key("sk-ant-REDACTED".to_string())
+        .unwrap();
+
+    // Storing a named key creates the profile without switching to it
+    let stored = state
+        .set_profile_api_key("Client-Work", "sk-ant-client-account-5678".to_string())
+        .unwrap();
+    assert_eq!(stored.name, "client-work");
+    assert!(!stored.active);
+    assert_eq!(state.active_profile().unwrap(), "default");
+    assert_eq!(
+        state
+            .list_profiles()
+            .unwrap()
+            .iter()
+            .map(|p| (p.name.as_str(), p.active, p.masked_key.as_deref()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("default", true, Some("sk-ant-...1234")),
+            ("client-work", false, Some("sk-ant-...5678")),
+        ]
+    );
+
+    // Only existing profiles can be activated
+    assert!(state.set_active_profile("unknown").is_err());
+    state.set_active_profile("client-work").unwrap();
+    assert_eq!(
+        state.get_api_key().unwrap().as_deref(),
+        Some("sk-ant-client-account-5678")
+    );
+
+    // Deleting the active profile falls back to another profile with a key
+    let deletion = state.delete_profile("client-work").unwrap();
+    assert_eq!(deletion.active_profile.as_deref(), Some("default"));
+    assert_eq!(
+        state.get_api_key().unwrap().as_deref(),
+        Some("sk-ant-REDACTED")
+    );
+    assert!(state.delete_profile("client-work").is_err());
+
+    // With no key left anywhere, nothing is active and generation gets a clear error
+    let deletion = state.delete_profile("default").unwrap();
+    assert_eq!(deletion.active_profile, None);
+    assert!(!state.has_api_key().unwrap());
+    assert_eq!(
+        state.get_api_key(),
+        Err(crate::config::NO_ACTIVE_PROFILE.to_string())
+    );
+
+    // The setup flow's next key goes to the default profile, which becomes active
+    state
+        .set_api_key("sk-ant-REDACTED".to_string())
+        .unwrap();
+    assert_eq!(state.active_profile().unwrap(), "default");
+    assert!(state.has_api_key().unwrap());
+}
@@ -0,0 +1,324 @@
+//! Proposal language selection for generation.
+//!
+//! Proposals are written in English unless the job post is confidently in
+//! another supported language, or the user picks one explicitly. Detection
+//! counts common function words per language and is deliberately
+//! conservative: short or mixed posts stay English.
+//!
+//! Humanization (AI-tell lists, perplexity thresholds) and voice calibration
+//! are English-tuned, so non-English generation skips the voice profile and
+//! reports humanization as best-effort.
+
+use serde::{Deserialize, Serialize};
+
+/// Posts shorter than this are never auto-detected as non-English
+const MIN_WORDS_FOR_DETECTION: usize = 20;
+/// Share of words that must be the language's function words
+const MIN_FUNCTION_WORD_RATIO: f32 = 0.15;
+/// The detected language must have at least this many times the English hits
+const MIN_ENGLISH_MARGIN: usize = 3;
+
+const ENGLISH_WORDS: &[&str] = &[
+    "the", "and", "to", "of", "for", "with", "is", "are", "you", "we", "our", "this", "that",
+    "will", "be", "have", "in", "on", "a", "an",
+];
+const SPANISH_WORDS: &[&str] = &[
+    "el",
+    "la",
+    "los",
+    "las",
+    "de",
+    "del",
+    "que",
+    "y",
+    "en",
+    "para",
+    "con",
+    "por",
+    "una",
+    "un",
+    "es",
+    "se",
+    "necesitamos",
+    "nuestro",
+    "nuestra",
+    "buscamos",
+];
+const GERMAN_WORDS: &[&str] = &[
+    "der", "die", "das", "und", "ist", "mit", "für", "wir", "sie", "ein", "eine", "nicht", "zu",
+    "auf", "den", "dem", "suchen", "unser", "unsere", "ich",
+];
+const FRENCH_WORDS: &[&str] = &[
+    "le",
+    "la",
+    "les",
+    "des",
+    "et",
+    "est",
+    "pour",
+    "avec",
+    "nous",
+    "vous",
+    "une",
+    "un",
+    "du",
+    "dans",
+    "sur",
+    "pas",
+    "qui",
+    "que",
+    "cherchons",
+    "notre",
+];
+const PORTUGUESE_WORDS: &[&str] = &[
+    "o",
+    "os",
+    "as",
+    "de",
+    "do",
+    "da",
+    "que",
+    "e",
+    "em",
+    "para",
+    "com",
+    "uma",
+    "um",
+    "não",
+    "nós",
+    "precisamos",
+    "nosso",
+    "nossa",
+    "procuramos",
+    "é",
+];
+
+/// Language a proposal is written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TargetLanguage {
+    English,
+    Spanish,
+    German,
+    French,
+    Portuguese,
+}
+
+impl TargetLanguage {
+    /// Parse an ISO 639-1 code ("en", "es", "de", "fr", "pt") or English name
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "en" | "english" => Ok(Self::English),
+            "es" | "spanish" => Ok(Self::Spanish),
+            "de" | "german" => Ok(Self::German),
+            "fr" | "french" => Ok(Self::French),
+            "pt" | "portuguese" => Ok(Self::Portuguese),
+            _ => Err(format!(
+                "Invalid target language: {}. Must be auto, en, es, de, fr, or pt",
+                value
+            )),
+        }
+    }
+
+    /// ISO 639-1 code
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::Spanish => "es",
+            Self::German => "de",
+            Self::French => "fr",
+            Self::Portuguese => "pt",
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::Spanish => "Spanish",
+            Self::German => "German",
+            Self::French => "French",
+            Self::Portuguese => "Portuguese",
+        }
+    }
+
+    fn function_words(self) -> &'static [&'static str] {
+        match self {
+            Self::English => ENGLISH_WORDS,
+            Self::Spanish => SPANISH_WORDS,
+            Self::German => GERMAN_WORDS,
+            Self::French => FRENCH_WORDS,
+            Self::Portuguese => PORTUGUESE_WORDS,
+        }
+    }
+
+    pub fn is_english(self) -> bool {
+        self == Self::English
+    }
+
+    /// System prompt instruction; empty for English so English prompts are unchanged
+    pub fn prompt_instruction(self) -> String {
+        if self.is_english() {
+            return String::new();
+        }
+        format!(
+            "\n\nLANGUAGE: Write the entire proposal in {name}, the language of the job post. \
+             Use natural, professional {name} as a native freelancer would, not a translation \
+             of English phrasing. Keep technology names and product names as they are.",
+            name = self.name()
+        )
+    }
+
+    /// Shown with the result when humanization checks don't fit this language
+    pub fn humanization_note(self) -> Option<String> {
+        (!self.is_english()).then(|| {
+            format!(
+                "Humanization and AI-detection checks are tuned for English, so for {} proposals they are best-effort.",
+                self.name()
+            )
+        })
+    }
+}
+
+/// Resolved language for one generation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageChoice {
+    pub language: TargetLanguage,
+    /// Chosen by `detect_language` rather than the user
+    pub auto_detected: bool,
+}
+
+/// Detect the job post language. Returns English unless another language is
+/// clearly dominant: enough words, a high share of its function words, and
+/// well ahead of English.
+pub fn detect_language(text: &str) -> TargetLanguage {
+    let words: Vec<String> = text
+        .split_whitespace()
+        .map(|w| {
+            w.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|w| !w.is_empty())
+        .collect();
+    if words.len() < MIN_WORDS_FOR_DETECTION {
+        return TargetLanguage::English;
+    }
+
+    let hits = |language: TargetLanguage| {
+        let list = language.function_words();
+        words.iter().filter(|w| list.contains(&w.as_str())).count()
+    };
+    let english_hits = hits(TargetLanguage::English);
+    let best = [
+        TargetLanguage::Spanish,
+        TargetLanguage::German,
+        TargetLanguage::French,
+        TargetLanguage::Portuguese,
+    ]
+    .into_iter()
+    .map(|language| (language, hits(language)))
+    .max_by_key(|(_, count)| *count);
+
+    match best {
+        Some((language, count))
+            if count as f32 / words.len() as f32 >= MIN_FUNCTION_WORD_RATIO
+                && count >= english_hits.max(1) * MIN_ENGLISH_MARGIN =>
+        {
+            language
+        }
+        _ => TargetLanguage::English,
+    }
+}
+
+/// Resolve the `target_language` argument: None or "auto" detects from the job post,
+/// anything else must be a supported language
+pub fn resolve_target_language(
+    job_content: &str,
+    requested: Option<&str>,
+) -> Result<LanguageChoice, String> {
+    match requested.map(str::trim).filter(|r| !r.is_empty()) {
+        None => Ok(auto_choice(job_content)),
+        Some(r) if r.eq_ignore_ascii_case("auto") => Ok(auto_choice(job_content)),
+        Some(r) => Ok(LanguageChoice {
+            language: TargetLanguage::parse(r)?,
+            auto_detected: false,
+        }),
+    }
+}
+
+fn auto_choice(job_content: &str) -> LanguageChoice {
+    LanguageChoice {
+        language: detect_language(job_content),
+        auto_detected: true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPANISH_JOB: &str = "Buscamos un desarrollador con experiencia en React para \
+        crear el panel de control de nuestra empresa. Necesitamos que el proyecto se \
+        entregue en dos semanas y que la interfaz sea clara para los usuarios de la plataforma.";
+    const GERMAN_JOB: &str = "Wir suchen einen erfahrenen Entwickler für unsere Webseite. \
+        Die Aufgabe ist die Umstellung auf React und die Anbindung an unsere API. Sie \
+        arbeiten mit dem Team zusammen und das Projekt soll in vier Wochen fertig sein.";
+
+    #[test]
+    fn test_detects_spanish_and_german() {
+        assert_eq!(detect_language(SPANISH_JOB), TargetLanguage::Spanish);
+        assert_eq!(detect_language(GERMAN_JOB), TargetLanguage::German);
+    }
+
+    #[test]
+    fn test_detection_is_conservative() {
+        // English post with a few Spanish words stays English
+        let mixed = "We are looking for a developer to build our landing page for the \
+            Mexico market. The copy will be in Spanish (la página de inicio) but the team \
+            and all communication are in English, so you should be fluent in it.";
+        assert_eq!(detect_language(mixed), TargetLanguage::English);
+
+        // Too short to be confident
+        assert_eq!(
+            detect_language("Necesitamos un desarrollador de React"),
+            TargetLanguage::English
+        );
+        assert_eq!(detect_language(""), TargetLanguage::English);
+    }
+
+    #[test]
+    fn test_resolve_target_language() {
+        let auto = resolve_target_language(SPANISH_JOB, None).unwrap();
+        assert_eq!(auto.language, TargetLanguage::Spanish);
+        assert!(auto.auto_detected);
+        assert_eq!(
+            resolve_target_language(SPANISH_JOB, Some("auto")).unwrap(),
+            auto
+        );
+
+        // Explicit choice overrides detection
+        let chosen = resolve_target_language(SPANISH_JOB, Some("EN")).unwrap();
+        assert_eq!(chosen.language, TargetLanguage::English);
+        assert!(!chosen.auto_detected);
+        assert_eq!(
+            resolve_target_language("job", Some("german"))
+                .unwrap()
+                .language,
+            TargetLanguage::German
+        );
+        assert!(resolve_target_language("job", Some("klingon")).is_err());
+    }
+
+    #[test]
+    fn test_prompt_instruction_and_note() {
+        assert_eq!(TargetLanguage::English.prompt_instruction(), "");
+        assert!(TargetLanguage::English.humanization_note().is_none());
+        assert!(TargetLanguage::German
+            .prompt_instruction()
+            .contains("entire proposal in German"));
+        assert!(TargetLanguage::Spanish
+            .humanization_note()
+            .unwrap()
+            .contains("best-effort"));
+    }
+}
@@ -5,27 +5,48 @@
 
 pub mod ab_testing;
 pub mod analysis;
+pub mod api_quota;
 pub mod archive;
 pub mod archive_export;
 pub mod archive_import;
 pub mod backup;
 pub mod claude;
+pub mod command_metrics;
 pub mod commands;
 pub mod config;
+pub mod currency;
 pub mod db;
+pub mod digest;
+pub mod disk_space;
+pub mod errors;
 pub mod events;
+pub mod factory_reset;
 pub mod health_check;
 pub mod http;
 pub mod humanization;
+pub mod integrity;
 pub mod job;
 pub mod keychain;
+pub mod language;
 pub mod logs;
 pub mod migration;
 pub mod network;
+pub mod notifications;
+pub mod partial_regen;
 pub mod passphrase;
+pub mod perplexity_chunks;
+pub mod presend;
+pub mod proposal_bundle;
+pub mod proposal_length;
+pub mod quality;
+pub mod rehumanize;
 pub mod remote_config;
 pub mod sanitization;
 pub mod scoring;
+pub mod settings;
+pub mod startup_maintenance;
+pub mod update_channel;
+pub mod user_config;
 pub mod voice;
 
 // Encryption spike module (Story 1.6)
@@ -34,6 +55,9 @@ pub mod voice;
 #[cfg(test)]
 pub mod encryption_spike;
 
+use errors::{AppError, ErrorCode};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 use tauri::{AppHandle, Emitter, Manager, State};
@@ -48,8 +72,25 @@ pub struct DraftState {
 // Cooldown State (Story 3.8: Rate Limiting Enforcement)
 // ============================================================================
 
-/// Cooldown period in seconds (FR-12: max 1 generation per 2 minutes)
-const COOLDOWN_SECONDS: u64 = 120;
+/// Default cooldown period in seconds (FR-12: max 1 generation per 2 minutes)
+const DEFAULT_COOLDOWN_SECONDS: u64 = 120;
+/// Settings key: cooldown between generations in seconds (0 disables it)
+pub const COOLDOWN_SECONDS_SETTING: &str = "cooldown_seconds";
+const MAX_COOLDOWN_SECONDS: u64 = 600;
+
+/// Configured cooldown, read at check time so a change applies without restart
+static COOLDOWN_SECONDS: AtomicU64 = AtomicU64::new(DEFAULT_COOLDOWN_SECONDS);
+
+/// Apply the saved `cooldown_seconds` (unset or invalid = default)
+fn apply_cooldown_setting(conn: &rusqlite::Connection) {
+    let seconds = settings::schema::get_typed(conn, settings::schema::Key::CooldownSeconds)
+        .unwrap_or(DEFAULT_COOLDOWN_SECONDS);
+    COOLDOWN_SECONDS.store(seconds, Ordering::Relaxed);
+}
+
+fn cooldown_seconds() -> u64 {
+    COOLDOWN_SECONDS.load(Ordering::Relaxed)
+}
 
 /// Cooldown state for generation rate limiting (FR-12)
 /// In-memory only — resets on app restart (acceptable: UX protection, not security)
@@ -64,10 +105,9 @@ pub struct CooldownState {
 /// Voice profile cache for generation optimization (AR-5: Prompt Caching)
 /// In-memory only — resets on app restart or manual invalidation
 /// Story 5.8 Subtask 4.1: Cache to avoid repeated DB queries per generation
+/// Keyed by voice profile name, each entry with the time it was cached.
 pub struct VoiceCache {
-    pub cached_profile: Mutex<Option<voice::VoiceProfile>>,
-    /// Timestamp of last cache update (for potential TTL support)
-    pub cached_at: Mutex<Option<Instant>>,
+    pub cached_profiles: Mutex<HashMap<String, (voice::VoiceProfile, Instant)>>,
 }
 
 impl Default for VoiceCache {
@@ -79,67 +119,50 @@ impl Default for VoiceCache {
 impl VoiceCache {
     pub fn new() -> Self {
         Self {
-            cached_profile: Mutex::new(None),
-            cached_at: Mutex::new(None),
+            cached_profiles: Mutex::new(HashMap::new()),
         }
     }
 
-    /// Get cached voice profile (Subtask 4.3)
-    /// Returns None if cache is empty or lock fails
-    pub fn get(&self) -> Option<voice::VoiceProfile> {
-        match self.cached_profile.lock() {
-            Ok(guard) => guard.clone(),
-            Err(poisoned) => {
-                // M1 fix: Log warning instead of silently returning None
-                tracing::warn!("VoiceCache mutex poisoned, recovering: {}", poisoned);
-                poisoned.into_inner().clone()
-            }
-        }
+    fn profiles(
+        &self,
+    ) -> std::sync::MutexGuard<'_, HashMap<String, (voice::VoiceProfile, Instant)>> {
+        self.cached_profiles.lock().unwrap_or_else(|poisoned| {
+            // M1 fix: Log warning instead of silently dropping the cache
+            tracing::warn!("VoiceCache mutex poisoned, recovering");
+            poisoned.into_inner()
+        })
     }
 
-    /// Set cached voice profile (Subtask 4.2)
-    pub fn set(&self, profile: voice::VoiceProfile) {
-        match self.cached_profile.lock() {
-            Ok(mut guard) => *guard = Some(profile),
-            Err(poisoned) => {
-                tracing::warn!("VoiceCache mutex poisoned during set, recovering");
-                *poisoned.into_inner() = Some(profile);
-            }
-        }
-        // Update cached_at timestamp
-        match self.cached_at.lock() {
-            Ok(mut guard) => *guard = Some(Instant::now()),
-            Err(poisoned) => {
-                *poisoned.into_inner() = Some(Instant::now());
-            }
-        }
+    /// Get the cached profile named `name` (Subtask 4.3)
+    /// Returns None if it isn't cached
+    pub fn get(&self, name: &str) -> Option<voice::VoiceProfile> {
+        self.profiles()
+            .get(name)
+            .map(|(profile, _)| profile.clone())
+    }
+
+    /// Cache the profile named `name` (Subtask 4.2)
+    pub fn set(&self, name: &str, profile: voice::VoiceProfile) {
+        self.profiles()
+            .insert(name.to_string(), (profile, Instant::now()));
+    }
+
+    /// Drop one profile, after it was recalibrated, renamed or deleted
+    pub fn invalidate_profile(&self, name: &str) {
+        self.profiles().remove(name);
     }
 
-    /// Invalidate cache (Subtask 4.4)
-    /// Called after recalibration (Story 5-4/5-7)
+    /// Invalidate the whole cache (Subtask 4.4)
+    /// Called when profiles change wholesale (import, factory reset)
     pub fn invalidate(&self) {
-        match self.cached_profile.lock() {
-            Ok(mut guard) => *guard = None,
-            Err(poisoned) => {
-                tracing::warn!("VoiceCache mutex poisoned during invalidate, recovering");
-                *poisoned.into_inner() = None;
-            }
-        }
-        // Clear cached_at timestamp
-        match self.cached_at.lock() {
-            Ok(mut guard) => *guard = None,
-            Err(poisoned) => {
-                *poisoned.into_inner() = None;
-            }
-        }
+        self.profiles().clear();
     }
 
-    /// Get cache age in seconds (for TTL checks)
-    pub fn age_seconds(&self) -> Option<u64> {
-        match self.cached_at.lock() {
-            Ok(guard) => guard.map(|t| t.elapsed().as_secs()),
-            Err(poisoned) => poisoned.into_inner().map(|t| t.elapsed().as_secs()),
-        }
+    /// Get cache age in seconds of the profile named `name` (for TTL checks)
+    pub fn age_seconds(&self, name: &str) -> Option<u64> {
+        self.profiles()
+            .get(name)
+            .map(|(_, cached_at)| cached_at.elapsed().as_secs())
     }
 }
 
@@ -220,12 +243,20 @@ impl CooldownState {
     }
 
     /// Check if cooldown is active. Returns remaining seconds or 0.
+    /// Uses the configured `cooldown_seconds`; 0 disables the cooldown.
     pub fn remaining_seconds(&self) -> u64 {
+        self.remaining_for(cooldown_seconds())
+    }
+
+    fn remaining_for(&self, cooldown_seconds: u64) -> u64 {
+        if cooldown_seconds == 0 {
+            return 0;
+        }
         let guard = self.last_generation.lock().unwrap();
         match *guard {
             Some(last) => {
                 let elapsed = last.elapsed().as_secs();
-                COOLDOWN_SECONDS.saturating_sub(elapsed)
+                cooldown_seconds.saturating_sub(elapsed)
             }
             None => 0,
         }
@@ -244,10 +275,135 @@ impl Default for CooldownState {
     }
 }
 
+/// Pre-generation sanitization of job content sent to Claude.
+/// PII masking is opt-in (`redact_pii_before_generation`) and the length cap comes from
+/// `generation_max_job_chars`; the original content is always what gets stored.
+fn sanitize_job_content_for_generation(
+    conn: &rusqlite::Connection,
+    job_content: &str,
+) -> sanitization::GenerationSanitization {
+    let strip_pii = db::queries::settings::get_setting(conn, sanitization::REDACT_PII_SETTING)
+        .ok()
+        .flatten()
+        .is_some_and(|v| v == "true");
+    let max_chars = settings::schema::get_typed(conn, settings::schema::Key::GenerationMaxJobChars)
+        .unwrap_or(sanitization::DEFAULT_GENERATION_MAX_CHARS);
+    sanitization::sanitize_for_generation(job_content, strip_pii, max_chars)
+}
+
+/// User's persona addendum for the generation system prompt, if set (Story 3.3 extension).
+fn load_system_prompt_addendum(conn: &rusqlite::Connection) -> Option<String> {
+    db::queries::settings::get_setting(conn, claude::SYSTEM_PROMPT_ADDENDUM_SETTING)
+        .ok()
+        .flatten()
+        .filter(|v| !v.trim().is_empty())
+}
+
+/// Apply-rate guardrail: refuse generation when the linked job's overall score is below
+/// `min_score_to_generate` (`SCORE_BELOW_FLOOR:<score>:<floor>`). The job is `job_post_id`,
+/// else a saved job post with identical content; unlinked or unscored jobs are never blocked.
+/// With `override_guard` the generation proceeds and the override is recorded for analytics.
+fn check_score_guard(
+    conn: &rusqlite::Connection,
+    job_content: &str,
+    job_post_id: Option<i64>,
+    override_guard: bool,
+) -> Result<(), AppError> {
+    let floor: f64 =
+        settings::schema::get_typed(conn, settings::schema::Key::MinScoreToGenerate)
+            .map_err(|e| AppError::database(format!("Failed to get score floor setting: {}", e)))?;
+    if floor <= 0.0 {
+        return Ok(());
+    }
+
+    let job_post_id = match job_post_id {
+        Some(id) => Some(id),
+        None => db::queries::job_posts::find_duplicate_job_post(conn, None, job_content)
+            .map_err(|e| AppError::database(format!("Failed to look up job post: {}", e)))?,
+    };
+    let Some(job_post_id) = job_post_id else {
+        return Ok(());
+    };
+    let score = db::queries::scoring::get_job_score(conn, job_post_id)
+        .map_err(AppError::database)?
+        .and_then(|s| s.overall_score);
+    let Some(score) = score.filter(|s| scoring::is_below_score_floor(*s, floor)) else {
+        return Ok(());
+    };
+
+    if !override_guard {
+        return Err(AppError::score_below_floor(score, floor));
+    }
+    db::queries::score_guard_overrides::record_override(conn, job_post_id, score, floor)
+        .map_err(|e| AppError::database(format!("Failed to record score guard override: {}", e)))?;
+    tracing::info!(
+        job_post_id,
+        score,
+        floor,
+        "Generating below score floor (user override)"
+    );
+    Ok(())
+}
+
+/// Story 5.8 Subtask 4.3: Voice profile `name` from the cache, else the database (cached
+/// on miss). None when that profile doesn't exist or isn't calibrated (default voice).
+fn load_voice_profile(
+    conn: &rusqlite::Connection,
+    voice_cache: &VoiceCache,
+    name: &str,
+) -> Result<Option<voice::VoiceProfile>, AppError> {
+    if let Some(profile) = voice_cache.get(name) {
+        tracing::debug!(profile = name, "Using cached voice profile");
+        return Ok(Some(profile));
+    }
+
+    tracing::debug!(profile = name, "Voice cache miss, loading from database");
+    let voice_profile_row = db::queries::voice_profile::get_voice_profile(conn, name)
+        .map_err(|e| AppError::database(format!("Failed to get voice profile: {}", e)))?;
+    let profile_opt = voice_profile_row.as_ref().map(|row| row.to_voice_profile());
+    if let Some(ref profile) = profile_opt {
+        voice_cache.set(name, profile.clone());
+        tracing::debug!(profile = name, "Voice profile cached");
+    }
+    Ok(profile_opt)
+}
+
+/// The active voice profile (see `voice_profile::active_profile_name`) via `load_voice_profile`
+fn load_active_voice_profile(
+    conn: &rusqlite::Connection,
+    voice_cache: &VoiceCache,
+) -> Result<Option<voice::VoiceProfile>, AppError> {
+    let name = db::queries::voice_profile::active_profile_name(conn);
+    load_voice_profile(conn, voice_cache, &name)
+}
+
+/// Setting: load the active voice profile into `VoiceCache` during deferred init
+pub const PREWARM_VOICE_CACHE_SETTING: &str = "prewarm_voice_cache";
+
+/// Pre-load the active voice profile so the session's first generation is a
+/// cache hit. Goes through `load_voice_profile`, so the entry is exactly what a
+/// generation would have cached and recalibration invalidates it the same way.
+/// Never fails: errors are logged and generation falls back to loading lazily.
+fn prewarm_voice_cache(conn: &rusqlite::Connection, voice_cache: &VoiceCache) {
+    let enabled =
+        settings::schema::get_typed(conn, settings::schema::Key::PrewarmVoiceCache).unwrap_or(true);
+    if !enabled {
+        tracing::debug!("Voice cache pre-warming disabled");
+        return;
+    }
+
+    match load_active_voice_profile(conn, voice_cache) {
+        Ok(Some(_)) => tracing::info!("Voice cache pre-warmed with the active profile"),
+        Ok(None) => tracing::debug!("No calibrated voice profile to pre-warm"),
+        Err(e) => tracing::warn!("Voice cache pre-warming failed (non-fatal): {}", e),
+    }
+}
+
 /// Non-streaming proposal generation (kept for backwards compatibility/testing)
 /// Story 3.3: Reads humanization intensity from settings.
 /// Story 3.8: Enforces cooldown rate limiting (FR-12).
 /// Task 4.2: Added AppHandle for network event emission
+/// Story 5.8: Generates in the calibrated voice, same as the streaming path.
 #[tauri::command]
 async fn generate_proposal(
     job_content: String,
@@ -255,33 +411,45 @@ async fn generate_proposal(
     config_state: State<'_, config::ConfigState>,
     database: State<'_, db::AppDatabase>,
     cooldown: State<'_, CooldownState>,
-) -> Result<String, String> {
+    voice_cache: State<'_, VoiceCache>,
+) -> Result<String, AppError> {
     let database = database.get()?;
     // Story 3.8: Check cooldown FIRST — before any API call (FR-12)
     let remaining = cooldown.remaining_seconds();
     if remaining > 0 {
-        return Err(format!("RATE_LIMITED:{}", remaining));
+        return Err(AppError::rate_limited(remaining));
     }
 
     let api_key = config_state.get_api_key()?;
 
     // Story 3.3: Read humanization intensity from settings
-    let intensity = {
-        let conn = database
-            .conn
-            .lock()
-            .map_err(|e| format!("Database lock error: {}", e))?;
-        db::queries::settings::get_setting(&conn, "humanization_intensity")
-            .map_err(|e| format!("Failed to get humanization setting: {}", e))?
-            .unwrap_or_else(|| "medium".to_string())
+    let (voice_profile, intensity, sanitized) = {
+        let conn = database.conn.lock().map_err(AppError::database_locked)?;
+        let voice_profile = load_active_voice_profile(&conn, &voice_cache)?;
+        let intensity: String =
+            settings::schema::get_typed(&conn, settings::schema::Key::HumanizationIntensity)
+                .map_err(AppError::database)?;
+        (
+            voice_profile,
+            intensity,
+            sanitize_job_content_for_generation(&conn, &job_content),
+        )
     };
+    // Plain-string response: the report is only logged here
+    if !sanitized.report.changes.is_empty() {
+        tracing::info!(
+            changes = ?sanitized.report.changes,
+            "Job content sanitized before generation"
+        );
+    }
 
-    // Story 5.8 Subtask 2.1: voice_profile parameter (loaded in Task 3)
+    // Story 5.8 Subtask 2.1: voice_profile parameter
     // Task 4.2: Pass AppHandle for network event emission
     let result = claude::generate_proposal_with_key(
-        &job_content,
+        &sanitized.content,
         api_key.as_deref(),
         &intensity,
+        voice_profile.as_ref(),
         Some(&app_handle),
     )
     .await?;
@@ -298,64 +466,99 @@ async fn generate_proposal(
 /// Story 3.8: Enforces cooldown rate limiting (FR-12).
 /// Story 5.2 Subtask 5.7: Accepts hook strategy ID to customize generation prompt.
 /// Story 10.4: If user_selected_strategy_id is None, A/B assigns a strategy via weighted random.
+/// `length_preference`: "short" / "medium" / "long" or a target word count; defaults to the
+/// calibrated voice profile's length preference. The response includes the actual word count.
+/// `target_language`: "auto" (default) detects from the job post and stays English unless
+/// confident; "en" / "es" / "de" / "fr" / "pt" override. Non-English generation skips the
+/// English-calibrated voice style and returns a `humanizationNote` (checks are best-effort).
+/// `job_post_id` links the job for the `min_score_to_generate` guard (see `check_score_guard`);
+/// `override_score_guard` generates anyway. A refused generation doesn't start the cooldown.
+/// `voice_profile_name` picks a voice profile for this proposal instead of the active one;
+/// the profile used is recorded in the generation metadata.
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 async fn generate_proposal_streaming(
     job_content: String,
     _strategy_id: Option<i64>,
     user_selected_strategy_id: Option<String>,
+    length_preference: Option<String>,
+    target_language: Option<String>,
+    job_post_id: Option<i64>,
+    override_score_guard: Option<bool>,
+    voice_profile_name: Option<String>,
     app_handle: AppHandle,
     config_state: State<'_, config::ConfigState>,
     database: State<'_, db::AppDatabase>,
     draft_state: State<'_, DraftState>,
     cooldown: State<'_, CooldownState>,
     voice_cache: State<'_, VoiceCache>, // Story 5.8 Subtask 4.1: Voice cache state
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
+    let _timer = command_metrics::time("generate_proposal_streaming");
     let database = database.get()?;
     // Story 3.8: Check cooldown FIRST — before any API call (FR-12)
     let remaining = cooldown.remaining_seconds();
     if remaining > 0 {
-        return Err(format!("RATE_LIMITED:{}", remaining));
+        return Err(AppError::rate_limited(remaining));
     }
 
     let api_key = config_state.get_api_key()?;
+    let api_key_profile = config_state.active_profile().ok();
+    let language_choice =
+        language::resolve_target_language(&job_content, target_language.as_deref())
+            .map_err(AppError::validation)?;
 
     // Story 5.8 Subtask 3.3: Optimized parallel loading (AC-2)
     let load_start = std::time::Instant::now();
 
-    // Story 5.8 Subtask 4.3: Check voice cache first (AC-6)
-    let cached_profile = voice_cache.get();
-    let use_cache = cached_profile.is_some();
-
     // Single lock acquisition for voice, intensity, and A/B strategy selection (Story 10.4)
-    let (voice_profile, intensity, selected_hook_strategy_id, ab_assigned, ab_weight_at_assignment) = {
-        let conn = database
-            .conn
-            .lock()
-            .map_err(|e| format!("Database lock error: {}", e))?;
-
-        // Query 1: Voice profile (skip if cached)
-        let voice_profile = if use_cache {
-            tracing::debug!("Using cached voice profile");
-            cached_profile
-        } else {
-            tracing::debug!("Voice cache miss, loading from database");
-            let voice_profile_row = db::queries::voice_profile::get_voice_profile(&conn, "default")
-                .map_err(|e| format!("Failed to get voice profile: {}", e))?;
-
-            let profile_opt = voice_profile_row.as_ref().map(|row| row.to_voice_profile());
-
-            if let Some(ref profile) = profile_opt {
-                voice_cache.set(profile.clone());
-                tracing::debug!("Voice profile cached");
-            }
+    let (
+        mut voice_profile,
+        mut voice_profile_version,
+        mut voice_profile_name,
+        intensity,
+        sanitized,
+        prompt_addendum,
+        selected_hook_strategy_id,
+        ab_assigned,
+        ab_weight_at_assignment,
+    ) = {
+        let conn = database.conn.lock().map_err(AppError::database_locked)?;
 
-            profile_opt
+        // Apply-rate guardrail, before anything that could reach the API
+        check_score_guard(
+            &conn,
+            &job_content,
+            job_post_id,
+            override_score_guard.unwrap_or(false),
+        )?;
+
+        // Query 1: Voice profile (Story 5.8 Subtask 4.3: cache first, AC-6), the one
+        // requested for this proposal or else the active one
+        let profile_name =
+            db::queries::voice_profile::resolve_profile_name(&conn, voice_profile_name.as_deref());
+        let voice_profile = load_voice_profile(&conn, &voice_cache, &profile_name)?;
+        if voice_profile.is_none() && voice_profile_name.is_some() {
+            return Err(AppError::not_found(format!(
+                "Voice profile '{}' not found",
+                profile_name
+            )));
+        }
+        // Recorded in generation metadata (None = default voice)
+        let (voice_profile_version, voice_profile_name) = match voice_profile {
+            Some(_) => (
+                db::queries::voice_profile::get_voice_profile_version(&conn, &profile_name)
+                    .map_err(|e| {
+                        AppError::database(format!("Failed to get voice profile version: {}", e))
+                    })?,
+                Some(profile_name),
+            ),
+            None => (None, None),
         };
 
         // Query 2: Humanization intensity (always load fresh for settings changes)
-        let intensity = db::queries::settings::get_setting(&conn, "humanization_intensity")
-            .map_err(|e| format!("Failed to get humanization setting: {}", e))?
-            .unwrap_or_else(|| "medium".to_string());
+        let intensity: String =
+            settings::schema::get_typed(&conn, settings::schema::Key::HumanizationIntensity)
+                .map_err(AppError::database)?;
 
         // Story 10.4 Task 3: A/B strategy selection (AC-2, AC-3)
         let (selected_hook_strategy_id, ab_assigned, ab_weight_at_assignment) =
@@ -366,9 +569,16 @@ async fn generate_proposal_streaming(
             } else {
                 // Load strategies and apply weighted random A/B selection
                 let strategies = db::queries::hook_strategies::get_all_hook_strategies(&conn)
-                    .map_err(|e| format!("Failed to load hook strategies: {}", e))?;
+                    .map_err(|e| {
+                        AppError::database(format!("Failed to load hook strategies: {}", e))
+                    })?;
 
                 match ab_testing::select_hook_strategy_ab(&strategies) {
+                    // A rotation of one has nothing to compare: record it as a fixed choice
+                    Ok((strategy_name, _)) if ab_testing::is_fixed_selection(&strategies) => {
+                        tracing::info!(strategy = %strategy_name, "Single strategy in A/B rotation, using it as a fixed selection");
+                        (Some(strategy_name), false, None)
+                    }
                     Ok((strategy_name, weight)) => {
                         tracing::info!(strategy = %strategy_name, weight = %weight, "A/B assigned hook strategy");
                         (Some(strategy_name), true, Some(weight))
@@ -379,12 +589,29 @@ async fn generate_proposal_streaming(
                         if let Err(e) = app_handle.emit("ab:no-active-weights", ()) {
                             tracing::warn!("Failed to emit ab:no-active-weights event: {}", e);
                         }
-                        return Err("AB_NO_ACTIVE_WEIGHTS: No strategies are currently in A/B testing. Please select a strategy manually.".to_string());
+                        return Err(AppError::new(
+                            ErrorCode::AbNoActiveWeights,
+                            "AB_NO_ACTIVE_WEIGHTS: No strategies are currently in A/B testing. Please select a strategy manually.",
+                        ));
                     }
                 }
             };
 
-        (voice_profile, intensity, selected_hook_strategy_id, ab_assigned, ab_weight_at_assignment)
+        // Sanitized content goes to Claude, original is stored
+        let sanitized = sanitize_job_content_for_generation(&conn, &job_content);
+        let prompt_addendum = load_system_prompt_addendum(&conn);
+
+        (
+            voice_profile,
+            voice_profile_version,
+            voice_profile_name,
+            intensity,
+            sanitized,
+            prompt_addendum,
+            selected_hook_strategy_id,
+            ab_assigned,
+            ab_weight_at_assignment,
+        )
     }; // Lock released here
 
     let load_elapsed = load_start.elapsed();
@@ -400,27 +627,181 @@ async fn generate_proposal_streaming(
         tracing::info!("Generating with default voice (no calibration)");
     }
 
-    let result = claude::generate_proposal_streaming_with_key(
+    // Explicit length preference wins; otherwise use the calibrated voice preference
+    let length_target = match length_preference.as_deref() {
+        Some(value) => {
+            Some(proposal_length::LengthTarget::parse(value).map_err(AppError::validation)?)
+        }
+        None => voice_profile.as_ref().map(|profile| {
+            proposal_length::LengthTarget::from_voice_preference(profile.length_preference)
+        }),
+    };
+
+    // Voice profile is calibrated on English samples: keep its length preference
+    // (above) but don't apply its style instructions to other languages
+    let language = language_choice.language;
+    if !language.is_english() && voice_profile.take().is_some() {
+        voice_profile_version = None;
+        voice_profile_name = None;
+        tracing::info!(
+            language = language.code(),
+            auto_detected = language_choice.auto_detected,
+            "Skipping English-calibrated voice profile for non-English generation"
+        );
+    }
+
+    // Hash of the same system prompt the request carries (prompt text is not stored)
+    let generation_context = claude::GenerationContext::for_prompt(
+        &claude::GenerationPromptOptions {
+            humanization_intensity: &intensity,
+            rehumanization_attempt: None,
+            system_prompt_addendum: prompt_addendum.as_deref(),
+            voice_profile: voice_profile.as_ref(),
+            length_target,
+            target_language: Some(language),
+        },
+        selected_hook_strategy_id.as_deref(),
+    );
+
+    let (result, stream_timings) = claude::generate_proposal_streaming_timed(
         &job_content,
+        Some(sanitized.content.as_str()),
         app_handle,
         api_key.as_deref(),
         database,
         &draft_state,
         &intensity,
         None, // rehumanization_attempt (Story TD-1)
+        prompt_addendum.as_deref(),
+        length_target,
+        voice_profile.as_ref(),
+        Some(language),
+        claude::StreamChannel::Main,
     )
     .await?;
 
     // Story 3.8: Record successful generation timestamp (after API call succeeds)
     cooldown.record();
 
+    let generation_metadata = db::queries::generation_metadata::NewGenerationMetadata {
+        model: claude::MODEL.to_string(),
+        humanization_intensity: intensity,
+        hook_strategy_id: selected_hook_strategy_id.clone(),
+        voice_profile_version,
+        voice_profile_name,
+        target_words: length_target.map(|t| t.target_words()),
+        api_key_profile,
+        generation_context: Some(generation_context),
+    };
+
     // Story 10.4 AC-3: Return A/B metadata so frontend can pass to save_proposal
-    Ok(serde_json::json!({
+    // (generationMetadata too, recorded with the proposal)
+    let mut response = serde_json::json!({
         "proposalText": result,
         "hookStrategyId": selected_hook_strategy_id,
         "abAssigned": ab_assigned,
         "abWeightAtAssignment": ab_weight_at_assignment,
-    }))
+        "sanitizationReport": sanitized.report,
+        "wordCount": proposal_length::word_count(&result),
+        "targetWords": length_target.map(|t| t.target_words()),
+        "generationMetadata": generation_metadata,
+        "targetLanguage": language.code(),
+        "languageAutoDetected": language_choice.auto_detected,
+        "humanizationNote": language.humanization_note(),
+        // Exact from the API's final usage when reported, else a character estimate
+        "totalTokens": stream_timings.stats.tokens,
+        "tokensExact": stream_timings.stats.exact,
+        "elapsedMs": stream_timings.stats.elapsed_ms,
+    });
+    // Phase breakdown only when perf metrics are on (perf_metrics_enabled)
+    if command_metrics::is_enabled() {
+        response["phaseTimings"] = serde_json::json!({
+            "contextLoadMs": load_elapsed.as_millis() as u64,
+            "apiStreamMs": stream_timings.api_stream.as_millis() as u64,
+            "dbSaveMs": stream_timings.db_save.as_millis() as u64,
+        });
+    }
+    Ok(response)
+}
+
+/// Read the clipboard as job content, save and analyze it, then stream a proposal.
+/// Same cooldown and streaming events as `generate_proposal_streaming`; the response adds
+/// `jobPostId` and `analysis` (null if analysis failed, which does not block generation).
+/// Empty, too-short, or non-text clipboard content is rejected before any API call.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+async fn generate_from_clipboard(
+    user_selected_strategy_id: Option<String>,
+    length_preference: Option<String>,
+    app_handle: AppHandle,
+    config_state: State<'_, config::ConfigState>,
+    database: State<'_, db::AppDatabase>,
+    draft_state: State<'_, DraftState>,
+    cooldown: State<'_, CooldownState>,
+    voice_cache: State<'_, VoiceCache>,
+) -> Result<serde_json::Value, AppError> {
+    use tauri_plugin_clipboard_manager::ClipboardExt;
+
+    // Story 3.8: Check cooldown before saving or analyzing anything
+    let remaining = cooldown.remaining_seconds();
+    if remaining > 0 {
+        return Err(AppError::rate_limited(remaining));
+    }
+
+    let clipboard_text = app_handle.clipboard().read_text().map_err(|_| {
+        AppError::validation("Clipboard is empty or does not contain text. Copy a job post first.")
+    })?;
+    commands::clipboard_watch::validate_clipboard_job_text(&clipboard_text)
+        .map_err(AppError::validation)?;
+
+    // Same cleaning as save_job_post
+    let cleaned = sanitization::clean_job_content(&clipboard_text);
+    let job_post_id = {
+        let db = database.get()?;
+        let conn = db.conn.lock().map_err(AppError::database_locked)?;
+        db::queries::job_posts::insert_job_post(&conn, None, &cleaned.content, None)
+            .map_err(|e| AppError::database(format!("Failed to save job post: {}", e)))?
+    };
+    tracing::info!(job_post_id = job_post_id, "Job post saved from clipboard");
+
+    let analysis = match analyze_job_post(
+        cleaned.content.clone(),
+        Some(job_post_id),
+        None,
+        database.clone(),
+        config_state.clone(),
+    )
+    .await
+    {
+        Ok(analysis) => Some(analysis),
+        Err(e) => {
+            tracing::warn!(job_post_id = job_post_id, "Clipboard job analysis failed: {}", e);
+            None
+        }
+    };
+
+    let mut response = generate_proposal_streaming(
+        cleaned.content,
+        None,
+        user_selected_strategy_id,
+        length_preference,
+        None, // target_language: auto-detect
+        Some(job_post_id),
+        None,
+        None, // voice_profile_name: active profile
+        app_handle,
+        config_state,
+        database,
+        draft_state,
+        cooldown,
+        voice_cache,
+    )
+    .await?;
+
+    response["jobPostId"] = serde_json::json!(job_post_id);
+    response["analysis"] = serde_json::to_value(&analysis)
+        .map_err(|e| format!("Failed to serialize analysis: {}", e))?;
+    Ok(response)
 }
 
 /// Regenerate proposal with escalated humanization intensity (Story 3.4)
@@ -442,22 +823,22 @@ async fn regenerate_with_humanization(
     database: State<'_, db::AppDatabase>,
     draft_state: State<'_, DraftState>,
     cooldown: State<'_, CooldownState>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
     let database = database.get()?;
     // Story 3.8: Check cooldown FIRST — before any API call (FR-12)
     let remaining = cooldown.remaining_seconds();
     if remaining > 0 {
-        return Err(format!("RATE_LIMITED:{}", remaining));
+        return Err(AppError::rate_limited(remaining));
     }
 
     const MAX_ATTEMPTS: u32 = 3;
 
     // Enforce max attempts
     if attempt_count >= MAX_ATTEMPTS {
-        return Err(format!(
+        return Err(AppError::validation(format!(
             "Maximum regeneration attempts ({}) reached. Consider manual editing.",
             MAX_ATTEMPTS
-        ));
+        )));
     }
 
     // Parse and escalate intensity
@@ -467,26 +848,54 @@ async fn regenerate_with_humanization(
 
     // Generate with escalated intensity (does NOT persist — user's preferred setting unchanged)
     let api_key = config_state.get_api_key()?;
+    let api_key_profile = config_state.active_profile().ok();
 
     // Story 5.8 Subtask 3.3: Load voice profile for regeneration
-    let voice_profile_row = {
-        let conn = database
-            .conn
-            .lock()
-            .map_err(|e| format!("Database lock error: {}", e))?;
-        db::queries::voice_profile::get_voice_profile(&conn, "default")
-            .map_err(|e| format!("Failed to get voice profile: {}", e))?
+    let (voice_profile_row, sanitized, prompt_addendum) = {
+        let conn = database.conn.lock().map_err(AppError::database_locked)?;
+        let profile_name = db::queries::voice_profile::active_profile_name(&conn);
+        let row = db::queries::voice_profile::get_voice_profile(&conn, &profile_name)
+            .map_err(|e| AppError::database(format!("Failed to get voice profile: {}", e)))?;
+        (
+            row,
+            sanitize_job_content_for_generation(&conn, &job_content),
+            load_system_prompt_addendum(&conn),
+        )
+    };
+    let voice_profile = voice_profile_row.as_ref().map(|row| row.to_voice_profile());
+    let generation_context = claude::GenerationContext::for_prompt(
+        &claude::GenerationPromptOptions {
+            humanization_intensity: &escalated_str,
+            system_prompt_addendum: prompt_addendum.as_deref(),
+            voice_profile: voice_profile.as_ref(),
+            ..Default::default()
+        },
+        None,
+    );
+    let generation_metadata = db::queries::generation_metadata::NewGenerationMetadata {
+        model: claude::MODEL.to_string(),
+        humanization_intensity: escalated_str.clone(),
+        hook_strategy_id: None,
+        voice_profile_name: voice_profile_row.as_ref().map(|row| row.user_id.clone()),
+        voice_profile_version: voice_profile_row.and_then(|row| row.updated_at),
+        target_words: None,
+        api_key_profile,
+        generation_context: Some(generation_context),
     };
-    let _voice_profile = voice_profile_row.as_ref().map(|row| row.to_voice_profile());
 
     let generated_text = claude::generate_proposal_streaming_with_key(
         &job_content,
+        Some(sanitized.content.as_str()),
         app_handle,
         api_key.as_deref(),
         database,
         &draft_state,
         &escalated_str,
         None, // rehumanization_attempt (Story TD-1)
+        prompt_addendum.as_deref(),
+        None,
+        voice_profile.as_ref(),
+        None,
     )
     .await?;
 
@@ -496,29 +905,565 @@ async fn regenerate_with_humanization(
     Ok(serde_json::json!({
         "generated_text": generated_text,
         "new_intensity": escalated_str,
-        "attempt_count": attempt_count + 1
+        "attempt_count": attempt_count + 1,
+        "sanitization_report": sanitized.report,
+        "generation_metadata": generation_metadata,
     }))
 }
 
-/// Analyze text for AI detection risk (Story 3.1 + 3.2 + 3.5)
-/// Returns perplexity analysis with score and flagged sentences.
-/// Story 3.5: Uses configurable threshold (default 180)
-/// Task 4.2: Added AppHandle for network event emission
+/// Options for `preview_generation_prompt`; unset fields resolve as a real generation would
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PromptPreviewOptions {
+    humanization_intensity: Option<String>,
+    rehumanization_attempt: Option<u32>,
+    length_preference: Option<String>,
+    target_language: Option<String>,
+    voice_profile_name: Option<String>,
+}
+
+/// Assembled generation prompt returned by `preview_generation_prompt`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PromptPreview {
+    system_prompt: String,
+    user_message: String,
+    /// What would be recorded with the proposal (version, prompt hash, parameters)
+    context: claude::GenerationContext,
+    prompt_versions: claude::PromptVersions,
+}
+
+/// Assemble the generation prompt for `job_content` without calling the API, so
+/// prompt changes can be reviewed. Requires the `dev_mode` setting.
+/// Applies the same sanitization, voice profile, persona addendum, length and
+/// language resolution as `generate_proposal_streaming`. Logs only sizes and the
+/// prompt hash: never the job content, prompt text or API key.
+#[tauri::command]
+fn preview_generation_prompt(
+    job_content: String,
+    options: Option<PromptPreviewOptions>,
+    database: State<'_, db::AppDatabase>,
+    voice_cache: State<'_, VoiceCache>,
+) -> Result<PromptPreview, AppError> {
+    let options = options.unwrap_or_default();
+    let database = database.get()?;
+    let conn = database.conn.lock().map_err(AppError::database_locked)?;
+
+    let dev_mode: bool = settings::schema::get_typed(&conn, settings::schema::Key::DevMode)
+        .map_err(AppError::database)?;
+    if !dev_mode {
+        return Err(AppError::validation(
+            "Prompt preview requires developer mode (dev_mode setting)",
+        ));
+    }
+
+    let intensity = match options.humanization_intensity.as_deref() {
+        Some(value) => humanization::HumanizationIntensity::from_str_value(value)
+            .map_err(AppError::validation)?
+            .as_str()
+            .to_string(),
+        None => settings::schema::get_typed(&conn, settings::schema::Key::HumanizationIntensity)
+            .map_err(AppError::database)?,
+    };
+    let language_choice =
+        language::resolve_target_language(&job_content, options.target_language.as_deref())
+            .map_err(AppError::validation)?;
+    let language = language_choice.language;
+
+    let profile_name = db::queries::voice_profile::resolve_profile_name(
+        &conn,
+        options.voice_profile_name.as_deref(),
+    );
+    let mut voice_profile = load_voice_profile(&conn, &voice_cache, &profile_name)?;
+    let length_target = match options.length_preference.as_deref() {
+        Some(value) => {
+            Some(proposal_length::LengthTarget::parse(value).map_err(AppError::validation)?)
+        }
+        None => voice_profile.as_ref().map(|profile| {
+            proposal_length::LengthTarget::from_voice_preference(profile.length_preference)
+        }),
+    };
+    // As in generation: English-calibrated voice style is skipped for other languages
+    if !language.is_english() {
+        voice_profile = None;
+    }
+
+    // Same two sanitization passes as the generation path
+    let redacted = sanitize_job_content_for_generation(&conn, &job_content);
+    let sanitized = sanitization::sanitize_job_content(&redacted.content);
+    let prompt_addendum = load_system_prompt_addendum(&conn);
+    drop(conn);
+
+    let prompt_options = claude::GenerationPromptOptions {
+        humanization_intensity: &intensity,
+        rehumanization_attempt: options.rehumanization_attempt,
+        system_prompt_addendum: prompt_addendum.as_deref(),
+        voice_profile: voice_profile.as_ref(),
+        length_target,
+        target_language: Some(language),
+    };
+    let context = claude::GenerationContext::for_prompt(&prompt_options, None);
+
+    tracing::info!(
+        job_chars = job_content.chars().count(),
+        prompt_version = %context.prompt_version,
+        system_prompt_sha256 = %context.system_prompt_sha256,
+        "Previewed generation prompt"
+    );
+
+    Ok(PromptPreview {
+        system_prompt: claude::build_generation_system_prompt(&prompt_options),
+        user_message: claude::build_generation_user_message(&sanitized.content),
+        context,
+        prompt_versions: claude::PromptVersions::current(),
+    })
+}
+
+/// Current prompt template versions (generation, re-humanization, analysis)
+#[tauri::command]
+fn get_current_prompt_versions() -> claude::PromptVersions {
+    claude::PromptVersions::current()
+}
+
+/// Rewrite a selected span of a saved proposal and splice it back in.
+/// `start_offset`/`end_offset` are UTF-8 byte offsets into the proposal text;
+/// selections over 60% of the proposal are refused. The rewrite uses the
+/// current humanization intensity, voice profile and persona addendum, and the
+/// result is saved as a `partial_regen` revision.
+/// Story 3.8: Enforces cooldown rate limiting (FR-12).
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+async fn regenerate_selection(
+    proposal_id: i64,
+    start_offset: usize,
+    end_offset: usize,
+    instruction: Option<String>,
+    app_handle: AppHandle,
+    config_state: State<'_, config::ConfigState>,
+    database: State<'_, db::AppDatabase>,
+    cooldown: State<'_, CooldownState>,
+    voice_cache: State<'_, VoiceCache>,
+) -> Result<partial_regen::SelectionRegenResult, AppError> {
+    let database = database.get()?;
+    // Story 3.8: Check cooldown FIRST — before any API call (FR-12)
+    let remaining = cooldown.remaining_seconds();
+    if remaining > 0 {
+        return Err(AppError::rate_limited(remaining));
+    }
+
+    let instruction = partial_regen::validate_instruction(instruction.as_deref())
+        .map_err(AppError::validation)?;
+
+    let (text, intensity, voice_profile, prompt_addendum) = {
+        let conn = database.conn.lock().map_err(AppError::database_locked)?;
+        let proposal = db::queries::proposals::get_proposal(&conn, proposal_id)
+            .map_err(|e| AppError::database(format!("Failed to load proposal: {}", e)))?
+            .ok_or_else(|| AppError::not_found(format!("Proposal {} not found", proposal_id)))?;
+        let intensity: String =
+            settings::schema::get_typed(&conn, settings::schema::Key::HumanizationIntensity)
+                .map_err(AppError::database)?;
+        (
+            proposal.generated_text,
+            intensity,
+            load_active_voice_profile(&conn, &voice_cache)?,
+            load_system_prompt_addendum(&conn),
+        )
+    };
+
+    partial_regen::validate_selection(&text, start_offset, end_offset)
+        .map_err(AppError::validation)?;
+    let selection = &text[start_offset..end_offset];
+    let (before, after) = partial_regen::surrounding_context(&text, start_offset, end_offset);
+
+    let api_key = config_state.get_api_key()?;
+    let response = claude::rewrite_selection_with_key(
+        &claude::SelectionRewrite {
+            before,
+            selection,
+            after,
+            instruction: instruction.as_deref(),
+        },
+        api_key.as_deref(),
+        &claude::GenerationPromptOptions {
+            humanization_intensity: &intensity,
+            system_prompt_addendum: prompt_addendum.as_deref(),
+            voice_profile: voice_profile.as_ref(),
+            ..Default::default()
+        },
+        Some(&app_handle),
+    )
+    .await?;
+
+    // Story 3.8: Record successful generation timestamp (after API call succeeds)
+    cooldown.record();
+
+    let rewrite =
+        partial_regen::match_edge_whitespace(selection, partial_regen::clean_rewrite(&response)?);
+    let new_text = partial_regen::splice(&text, start_offset, end_offset, &rewrite);
+
+    let revision_id = {
+        let mut conn = database.conn.lock().map_err(AppError::database_locked)?;
+        // The editor may have autosaved while Claude was rewriting; the offsets
+        // no longer point at the same text then
+        let current = db::queries::proposals::get_proposal(&conn, proposal_id)
+            .map_err(|e| AppError::database(format!("Failed to load proposal: {}", e)))?
+            .map(|proposal| proposal.generated_text);
+        if current.as_deref() != Some(text.as_str()) {
+            return Err(AppError::validation(
+                "The proposal changed while the selection was being rewritten. Try again.",
+            ));
+        }
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| AppError::database(format!("Failed to start transaction: {}", e)))?;
+        db::queries::proposals::update_proposal_text(&tx, proposal_id, &new_text)
+            .map_err(|e| AppError::database(format!("Failed to update proposal: {}", e)))?;
+        let revision_id = db::queries::revisions::create_revision(
+            &tx,
+            proposal_id,
+            &new_text,
+            partial_regen::PARTIAL_REGEN_REVISION_TYPE,
+            None,
+        )
+        .map_err(|e| AppError::database(format!("Failed to create revision: {}", e)))?
+        .id;
+        tx.commit()
+            .map_err(|e| AppError::database(format!("Failed to save rewrite: {}", e)))?;
+
+        // Undo history and archiving are best-effort, as for editor saves
+        if let Err(e) = db::queries::edit_events::record_edit_event(&conn, proposal_id, &new_text) {
+            tracing::warn!(proposal_id = proposal_id, error = %e, "Failed to record edit event");
+        }
+        if let Err(e) = db::queries::revisions::archive_old_revisions(&mut conn, proposal_id) {
+            tracing::warn!(
+                proposal_id = proposal_id,
+                error = %e,
+                "Archiving failed after revision creation"
+            );
+        }
+        revision_id
+    };
+
+    tracing::info!(
+        proposal_id = proposal_id,
+        revision_id = revision_id,
+        "Regenerated proposal selection"
+    );
+
+    Ok(partial_regen::SelectionRegenResult {
+        proposal_id,
+        revision_id,
+        start_offset,
+        end_offset: start_offset + rewrite.len(),
+        text: new_text,
+    })
+}
+
+/// Rewrite a saved proposal at `intensity` ("off" / "light" / "medium" / "heavy"),
+/// keeping its meaning and structure: cheaper than regenerating and keeps the hook.
+/// Uses the active voice profile and persona addendum like `regenerate_selection`.
+/// The previous text is kept as a revision and the rewrite is saved as a
+/// `rehumanize` revision, so the original stays restorable. The rewrite is then
+/// analyzed for perplexity at the target intensity's threshold and compared with
+/// the previous text's score (cached, else analyzed now); a failed analysis is
+/// reported in `perplexityError` and does not undo the rewrite.
+/// Story 3.8: Enforces cooldown rate limiting (FR-12).
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+async fn rehumanize_proposal(
+    proposal_id: i64,
+    intensity: String,
+    app_handle: AppHandle,
+    config_state: State<'_, config::ConfigState>,
+    database: State<'_, db::AppDatabase>,
+    cooldown: State<'_, CooldownState>,
+    voice_cache: State<'_, VoiceCache>,
+) -> Result<rehumanize::RehumanizeResult, AppError> {
+    let _timer = command_metrics::time("rehumanize_proposal");
+    let database = database.get()?;
+    // Story 3.8: Check cooldown FIRST — before any API call (FR-12)
+    let remaining = cooldown.remaining_seconds();
+    if remaining > 0 {
+        return Err(AppError::rate_limited(remaining));
+    }
+
+    let intensity = humanization::HumanizationIntensity::from_str_value(&intensity)
+        .map_err(AppError::validation)?
+        .as_str()
+        .to_string();
+
+    let (text, cached_score, voice_profile, prompt_addendum) = {
+        let conn = database.conn.lock().map_err(AppError::database_locked)?;
+        let proposal = db::queries::proposals::get_proposal(&conn, proposal_id)
+            .map_err(|e| AppError::database(format!("Failed to load proposal: {}", e)))?
+            .ok_or_else(|| AppError::not_found(format!("Proposal {} not found", proposal_id)))?;
+        let cached_score = db::queries::proposals::get_cached_perplexity_score(&conn, proposal_id)
+            .map_err(|e| AppError::database(format!("Failed to load perplexity score: {}", e)))?;
+        (
+            proposal.generated_text,
+            cached_score,
+            load_active_voice_profile(&conn, &voice_cache)?,
+            load_system_prompt_addendum(&conn),
+        )
+    };
+    if text.trim().is_empty() {
+        return Err(AppError::validation(
+            "Proposal has no content to re-humanize",
+        ));
+    }
+
+    let api_key = config_state.get_api_key()?;
+    let response = claude::rehumanize_with_key(
+        &text,
+        api_key.as_deref(),
+        &claude::GenerationPromptOptions {
+            humanization_intensity: &intensity,
+            system_prompt_addendum: prompt_addendum.as_deref(),
+            voice_profile: voice_profile.as_ref(),
+            ..Default::default()
+        },
+        Some(&app_handle),
+    )
+    .await?;
+
+    // Story 3.8: Record successful generation timestamp (after API call succeeds)
+    cooldown.record();
+
+    let new_text = rehumanize::clean_rewrite(&response)?.to_string();
+
+    let revision_id = {
+        let mut conn = database.conn.lock().map_err(AppError::database_locked)?;
+        // An autosave while Claude was rewriting would be silently overwritten
+        let current = db::queries::proposals::get_proposal(&conn, proposal_id)
+            .map_err(|e| AppError::database(format!("Failed to load proposal: {}", e)))?
+            .map(|proposal| proposal.generated_text);
+        if current.as_deref() != Some(text.as_str()) {
+            return Err(AppError::validation(
+                "The proposal changed while it was being re-humanized. Try again.",
+            ));
+        }
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| AppError::database(format!("Failed to start transaction: {}", e)))?;
+        // Keep the previous text restorable: a never-edited proposal has no revision
+        // yet, and one matching the latest revision is deduplicated
+        let previous_type = match db::queries::revisions::get_revision_count(&tx, proposal_id)
+            .map_err(AppError::database)?
+        {
+            0 => "generation",
+            _ => "edit",
+        };
+        db::queries::revisions::create_revision(&tx, proposal_id, &text, previous_type, None)
+            .map_err(|e| AppError::database(format!("Failed to create revision: {}", e)))?;
+        db::queries::proposals::update_proposal_text(&tx, proposal_id, &new_text)
+            .map_err(|e| AppError::database(format!("Failed to update proposal: {}", e)))?;
+        let revision_id = db::queries::revisions::create_revision(
+            &tx,
+            proposal_id,
+            &new_text,
+            rehumanize::REHUMANIZE_REVISION_TYPE,
+            None,
+        )
+        .map_err(|e| AppError::database(format!("Failed to create revision: {}", e)))?
+        .id;
+        tx.commit()
+            .map_err(|e| AppError::database(format!("Failed to save rewrite: {}", e)))?;
+
+        // Undo history and archiving are best-effort, as for editor saves
+        if let Err(e) = db::queries::edit_events::record_edit_event(&conn, proposal_id, &new_text) {
+            tracing::warn!(proposal_id = proposal_id, error = %e, "Failed to record edit event");
+        }
+        if let Err(e) = db::queries::revisions::archive_old_revisions(&mut conn, proposal_id) {
+            tracing::warn!(
+                proposal_id = proposal_id,
+                error = %e,
+                "Archiving failed after revision creation"
+            );
+        }
+        revision_id
+    };
+
+    // Did the new intensity help? Compare against the previous text's score
+    let check: Result<(rehumanize::PerplexityComparison, usize), String> = async {
+        let threshold =
+            get_safety_threshold_for_intensity_internal(database, Some(intensity.as_str()))?;
+        let max_chunk_tokens = get_perplexity_chunk_tokens(database)?;
+        let before = match cached_score {
+            Some(score) => score as f32,
+            None => {
+                claude::analyze_perplexity_with_sentences(
+                    &text,
+                    threshold,
+                    api_key.as_deref(),
+                    Some(&app_handle),
+                    max_chunk_tokens,
+                )
+                .await?
+                .score
+            }
+        };
+        let after = claude::analyze_perplexity_with_sentences(
+            &new_text,
+            threshold,
+            api_key.as_deref(),
+            Some(&app_handle),
+            max_chunk_tokens,
+        )
+        .await?;
+        Ok((
+            rehumanize::PerplexityComparison::new(before, after.score, threshold as f32),
+            after.flagged_sentences.len(),
+        ))
+    }
+    .await;
+
+    let (perplexity, perplexity_error) = match check {
+        Ok((comparison, flagged_count)) => {
+            // Cache the new score for the history risk badge
+            if let Ok(conn) = database.conn.lock() {
+                if let Err(e) = db::queries::proposals::store_perplexity_result(
+                    &conn,
+                    proposal_id,
+                    comparison.after,
+                    flagged_count,
+                ) {
+                    tracing::warn!(proposal_id, "Failed to store perplexity result: {}", e);
+                }
+            }
+            (Some(comparison), None)
+        }
+        Err(e) => {
+            tracing::warn!(
+                proposal_id,
+                "Perplexity check after re-humanization failed: {}",
+                e
+            );
+            (None, Some(e))
+        }
+    };
+
+    tracing::info!(
+        proposal_id = proposal_id,
+        revision_id = revision_id,
+        intensity = %intensity,
+        improved = perplexity.map(|p| p.improved),
+        "Re-humanized proposal"
+    );
+
+    Ok(rehumanize::RehumanizeResult {
+        proposal_id,
+        revision_id,
+        text: new_text,
+        intensity,
+        perplexity,
+        perplexity_error,
+    })
+}
+
+/// Analyze text for AI detection risk (Story 3.1 + 3.2 + 3.5)
+/// Returns perplexity analysis with score and flagged sentences.
+/// Story 3.5: Uses configurable threshold (default 180)
+/// Task 4.2: Added AppHandle for network event emission
+/// When `intensity` (the humanization level the text was generated with) is
+/// given, its per-intensity threshold replaces `threshold`.
+#[tauri::command]
+async fn analyze_perplexity(
+    text: String,
+    threshold: i32,
+    intensity: Option<String>,
+    app_handle: AppHandle,
+    config_state: State<'_, config::ConfigState>,
+    database: State<'_, db::AppDatabase>,
+) -> Result<claude::PerplexityAnalysis, String> {
+    let threshold = match intensity.as_deref() {
+        Some(intensity) => {
+            get_safety_threshold_for_intensity_internal(database.get()?, Some(intensity))?
+        }
+        None => threshold,
+    };
+    let max_chunk_tokens = get_perplexity_chunk_tokens(database.get()?)?;
+    let api_key = config_state.get_api_key()?;
+    claude::analyze_perplexity_with_sentences(
+        &text,
+        threshold,
+        api_key.as_deref(),
+        Some(&app_handle),
+        max_chunk_tokens,
+    )
+    .await
+}
+
+/// Per-request token budget for perplexity analysis chunks (`perplexity_chunk_tokens`)
+fn get_perplexity_chunk_tokens(database: &db::Database) -> Result<usize, String> {
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+    Ok(perplexity_chunks::chunk_token_budget(&conn))
+}
+
+/// Re-run perplexity analysis on a saved proposal's current content.
+/// Uses the configured safety threshold. With `store`, the score and flagged
+/// sentence count are cached on the proposal for the history risk badge
+/// (cleared again when the proposal is edited).
 #[tauri::command]
-async fn analyze_perplexity(
-    text: String,
-    threshold: i32,
+async fn analyze_stored_proposal_perplexity(
+    proposal_id: i64,
+    store: Option<bool>,
     app_handle: AppHandle,
     config_state: State<'_, config::ConfigState>,
+    database: State<'_, db::AppDatabase>,
 ) -> Result<claude::PerplexityAnalysis, String> {
+    let database = database.get()?;
+    let content = {
+        let conn = database
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        db::queries::proposals::get_proposal(&conn, proposal_id)
+            .map_err(|e| format!("Failed to load proposal: {}", e))?
+            .ok_or_else(|| format!("Proposal {} not found", proposal_id))?
+            .generated_text
+    };
+    if content.trim().is_empty() {
+        return Err("Proposal has no content to analyze".to_string());
+    }
+
+    let threshold = get_safety_threshold_for_intensity_internal(database, None)?;
+    let max_chunk_tokens = get_perplexity_chunk_tokens(database)?;
     let api_key = config_state.get_api_key()?;
-    claude::analyze_perplexity_with_sentences(
-        &text,
+    let analysis = claude::analyze_perplexity_with_sentences(
+        &content,
         threshold,
         api_key.as_deref(),
         Some(&app_handle),
+        max_chunk_tokens,
     )
-    .await
+    .await?;
+
+    if store.unwrap_or(false) {
+        let conn = database
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        // Skip caching if the proposal was edited while the analysis ran
+        let unchanged = db::queries::proposals::get_proposal(&conn, proposal_id)
+            .map_err(|e| format!("Failed to load proposal: {}", e))?
+            .is_some_and(|p| p.generated_text == content);
+        if unchanged {
+            db::queries::proposals::store_perplexity_result(
+                &conn,
+                proposal_id,
+                analysis.score,
+                analysis.flagged_sentences.len(),
+            )
+            .map_err(|e| format!("Failed to store perplexity result: {}", e))?;
+        }
+    }
+
+    Ok(analysis)
 }
 
 // ============================================================================
@@ -533,6 +1478,33 @@ fn get_cooldown_remaining(cooldown: State<CooldownState>) -> u64 {
     cooldown.remaining_seconds()
 }
 
+/// Get the configured cooldown between generations in seconds (0 = disabled)
+#[tauri::command]
+fn get_cooldown_seconds() -> u64 {
+    cooldown_seconds()
+}
+
+/// Set the cooldown between generations (0 to disable, max 600 seconds).
+/// Takes effect immediately, including for a cooldown already running.
+#[tauri::command]
+fn set_cooldown_seconds(database: State<'_, db::AppDatabase>, seconds: u64) -> Result<(), String> {
+    let value = seconds.to_string();
+    settings::schema::validate(COOLDOWN_SECONDS_SETTING, &value)?;
+
+    let database = database.get()?;
+    {
+        let conn = database
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        db::queries::settings::set_setting(&conn, COOLDOWN_SECONDS_SETTING, &value)
+            .map_err(|e| format!("Failed to save cooldown: {}", e))?;
+        apply_cooldown_setting(&conn);
+    }
+    tracing::info!(seconds, "Generation cooldown updated");
+    Ok(())
+}
+
 // ============================================================================
 // Voice Cache Commands (Story 5.8: Voice Profile Caching)
 // ============================================================================
@@ -560,7 +1532,10 @@ fn check_database(database: State<'_, db::AppDatabase>) -> Result<serde_json::Va
 
 /// Save a generated proposal to the database
 /// Returns the ID of the saved proposal
+/// `job_post_id` links the proposal to the job post it was written for.
+/// `generation_metadata` (from the generation response) is saved in the same transaction.
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 fn save_proposal(
     database: State<'_, db::AppDatabase>,
     job_content: String,
@@ -568,24 +1543,30 @@ fn save_proposal(
     hook_strategy_id: Option<String>,
     ab_assigned: Option<bool>,
     ab_weight_at_assignment: Option<f32>,
-) -> Result<serde_json::Value, String> {
+    job_post_id: Option<i64>,
+    generation_metadata: Option<db::queries::generation_metadata::NewGenerationMetadata>,
+) -> Result<serde_json::Value, AppError> {
+    let _timer = command_metrics::time("save_proposal");
     let database = database.get()?;
-    let conn = database
-        .conn
-        .lock()
-        .map_err(|e| format!("Database lock error: {}", e))?;
+    let conn = database.conn.lock().map_err(AppError::database_locked)?;
 
-    let id = db::queries::proposals::insert_proposal_with_ab_context(
+    let id = db::queries::generation_metadata::save_proposal_with_metadata(
         &conn,
-        &job_content,
-        &generated_text,
-        None,
-        hook_strategy_id.as_deref(),
-        None,
-        ab_assigned.unwrap_or(false),
-        ab_weight_at_assignment,
+        generation_metadata.as_ref(),
+        |tx| {
+            db::queries::proposals::insert_proposal_with_ab_context(
+                tx,
+                &job_content,
+                &generated_text,
+                None,
+                hook_strategy_id.as_deref(),
+                job_post_id,
+                ab_assigned.unwrap_or(false),
+                ab_weight_at_assignment,
+            )
+        },
     )
-    .map_err(|e| format!("Failed to save proposal: {}", e))?;
+    .map_err(|e| AppError::database(format!("Failed to save proposal: {}", e)))?;
 
     Ok(serde_json::json!({
         "id": id,
@@ -595,18 +1576,52 @@ fn save_proposal(
 
 /// Get list of past proposals (summaries only, for performance)
 /// Returns proposals ordered by created_at DESC, limited to 100
+/// `only_favorites` keeps pinned proposals only; `favorites_first` sorts them to the top.
+/// Client names are replaced with placeholders in presentation mode.
 #[tauri::command]
 fn get_proposals(
     database: State<'_, db::AppDatabase>,
-) -> Result<Vec<db::queries::proposals::ProposalSummary>, String> {
+    presentation: State<'_, commands::presentation::PresentationModeState>,
+    only_favorites: Option<bool>,
+    favorites_first: Option<bool>,
+) -> Result<Vec<db::queries::proposals::ProposalSummary>, AppError> {
+    let _timer = command_metrics::time("get_proposals");
     let database = database.get()?;
-    let conn = database
-        .conn
-        .lock()
-        .map_err(|e| format!("Database lock error: {}", e))?;
 
-    db::queries::proposals::list_proposals(&conn)
-        .map_err(|e| format!("Failed to get proposals: {}", e))
+    let mut proposals = database
+        .read(|conn| {
+            db::queries::proposals::list_proposals_filtered(
+                conn,
+                only_favorites.unwrap_or(false),
+                favorites_first.unwrap_or(false),
+            )
+            .map_err(|e| format!("Failed to get proposals: {}", e))
+        })
+        .map_err(AppError::database)?;
+
+    if presentation.is_enabled() {
+        let mut masker = commands::presentation::ClientNameMasker::default();
+        for proposal in &mut proposals {
+            proposal.client_name = proposal.client_name.as_deref().map(|n| masker.mask(n));
+        }
+    }
+    Ok(proposals)
+}
+
+/// Pin or unpin a proposal as a favorite. Returns the new `isFavorite` value.
+#[tauri::command]
+fn toggle_proposal_favorite(
+    database: State<'_, db::AppDatabase>,
+    proposal_id: i64,
+) -> Result<serde_json::Value, AppError> {
+    let database = database.get()?;
+    let conn = database.conn.lock().map_err(AppError::database_locked)?;
+
+    let is_favorite = db::queries::proposals::toggle_proposal_favorite(&conn, proposal_id)
+        .map_err(|e| AppError::database(format!("Failed to update favorite: {}", e)))?
+        .ok_or_else(|| AppError::not_found(format!("Proposal {} not found", proposal_id)))?;
+
+    Ok(serde_json::json!({ "isFavorite": is_favorite }))
 }
 
 /// Delete a proposal and all its revisions (Story 6.8)
@@ -615,20 +1630,23 @@ fn get_proposals(
 ///
 /// # Cascade Behavior
 /// - proposal_revisions: Deleted via ON DELETE CASCADE
-/// - safety_overrides: Orphaned (acceptable - historical data)
+/// - safety_overrides: Deleted via ON DELETE CASCADE (V7); `cleanup_orphaned_overrides`
+///   handles any rows left by deletes made with foreign keys off
+///
+/// `purge_proposal_data` erases everything derived from the proposal as well.
+/// Blocked in presentation mode.
 #[tauri::command]
 fn delete_proposal(
     database: State<'_, db::AppDatabase>,
+    presentation: State<'_, commands::presentation::PresentationModeState>,
     proposal_id: i64,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
+    presentation.ensure_allowed("delete_proposal")?;
     let database = database.get()?;
-    let conn = database
-        .conn
-        .lock()
-        .map_err(|e| format!("Database lock error: {}", e))?;
+    let conn = database.conn.lock().map_err(AppError::database_locked)?;
 
     let deleted = db::queries::proposals::delete_proposal(&conn, proposal_id)
-        .map_err(|e| format!("Failed to delete proposal: {}", e))?;
+        .map_err(|e| AppError::database(format!("Failed to delete proposal: {}", e)))?;
 
     if deleted {
         tracing::info!(proposal_id = proposal_id, "Proposal deleted");
@@ -644,23 +1662,65 @@ fn delete_proposal(
     }
 }
 
+/// Erase a proposal and all data derived from it ("right to deletion")
+///
+/// Deletes, in one transaction, the proposal, its active and archived
+/// revisions, safety overrides, edit events, generation metadata, queued
+/// generation rows and cached job analysis. With `include_job_post`, the linked
+/// job post (with its skills and scores) goes too if no other proposal uses it.
+/// Returns per-table deletion counts; `compactRecommended` means the file only
+/// shrinks after `compact_database`.
+///
+/// Blocked in presentation mode.
+#[tauri::command]
+fn purge_proposal_data(
+    database: State<'_, db::AppDatabase>,
+    presentation: State<'_, commands::presentation::PresentationModeState>,
+    proposal_id: i64,
+    include_job_post: Option<bool>,
+) -> Result<db::queries::proposals::ProposalPurgeReport, AppError> {
+    presentation.ensure_allowed("purge_proposal_data")?;
+    let database = database.get()?;
+    let conn = database.conn.lock().map_err(AppError::database_locked)?;
+
+    let report = db::queries::proposals::purge_proposal_data(
+        &conn,
+        proposal_id,
+        include_job_post.unwrap_or(false),
+    )
+    .map_err(AppError::database)?
+    .ok_or_else(|| AppError::not_found(format!("Proposal {} not found", proposal_id)))?;
+
+    // Counts only: never log what was deleted
+    tracing::info!(
+        proposal_id = proposal_id,
+        rows = report.deleted.values().sum::<usize>(),
+        job_post_deleted = report.job_post_deleted,
+        "Proposal data purged"
+    );
+    Ok(report)
+}
+
 /// Update proposal content (Story 6.1: TipTap Editor auto-save)
 /// Called by frontend editor on content changes (2-second debounce)
-/// Updates both content and updated_at timestamp
+/// Updates both content and updated_at timestamp, and snapshots the content as
+/// an edit event for in-session undo (`get_recent_edits` / `restore_edit`)
 #[tauri::command]
 fn update_proposal_content(
     database: State<'_, db::AppDatabase>,
     proposal_id: i64,
     content: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let database = database.get()?;
-    let conn = database
-        .conn
-        .lock()
-        .map_err(|e| format!("Database lock error: {}", e))?;
+    let conn = database.conn.lock().map_err(AppError::database_locked)?;
 
     db::queries::proposals::update_proposal_text(&conn, proposal_id, &content)
-        .map_err(|e| format!("Failed to update proposal: {}", e))?;
+        .map_err(|e| AppError::database(format!("Failed to update proposal: {}", e)))?;
+
+    // Undo history is best-effort; never fail the save over it
+    if let Err(e) = db::queries::edit_events::record_edit_event(&conn, proposal_id, &content) {
+        tracing::warn!(proposal_id = proposal_id, error = %e, "Failed to record edit event");
+    }
 
     tracing::debug!(proposal_id = proposal_id, "Proposal content auto-saved");
 
@@ -669,6 +1729,8 @@ fn update_proposal_content(
 
 /// Create a revision (Story 6.3: Proposal Revision History)
 /// Called on auto-save from TipTap editor to track version history
+/// Content identical to the latest revision is not stored again; the latest
+/// revision is returned with `deduplicated: true`
 /// Triggers archiving if revision count exceeds threshold (Story 6.7)
 #[tauri::command]
 async fn create_revision(
@@ -676,7 +1738,7 @@ async fn create_revision(
     proposal_id: i64,
     content: String,
     revision_type: Option<String>,
-) -> Result<i64, String> {
+) -> Result<db::queries::revisions::CreatedRevision, String> {
     let database = database.get()?;
     let mut conn = database
         .conn
@@ -685,9 +1747,12 @@ async fn create_revision(
 
     let rev_type = revision_type.unwrap_or_else(|| "edit".to_string());
 
-    let revision_id =
+    let created =
         db::queries::revisions::create_revision(&conn, proposal_id, &content, &rev_type, None)
             .map_err(|e| format!("Failed to create revision: {}", e))?;
+    if created.deduplicated {
+        return Ok(created);
+    }
 
     // Archive old revisions if threshold exceeded (AC1, AC4)
     // This is fast (<100ms) so we do it synchronously to avoid lifetime complications
@@ -700,7 +1765,7 @@ async fn create_revision(
         );
     }
 
-    Ok(revision_id)
+    Ok(created)
 }
 
 /// Get revision summaries for history panel (Story 6.3)
@@ -762,7 +1827,8 @@ fn restore_revision(
         "restore",
         Some(source_revision_id),
     )
-    .map_err(|e| format!("Failed to create restore revision: {}", e))?;
+    .map_err(|e| format!("Failed to create restore revision: {}", e))?
+    .id;
 
     // Archive old revisions if threshold exceeded (AC1 fix)
     if let Err(e) = db::queries::revisions::archive_old_revisions(&mut conn, proposal_id) {
@@ -787,6 +1853,62 @@ fn restore_revision(
     Ok(new_revision_id)
 }
 
+/// Recent edit snapshots for in-session undo, newest first (default 20).
+/// Finer-grained than revisions, kept only for the last few hours.
+#[tauri::command]
+fn get_recent_edits(
+    database: State<'_, db::AppDatabase>,
+    proposal_id: i64,
+    limit: Option<u32>,
+) -> Result<Vec<db::queries::edit_events::EditEventSummary>, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    let limit = limit
+        .unwrap_or(20)
+        .min(db::queries::edit_events::MAX_EDIT_EVENTS_PER_PROPOSAL as u32);
+    db::queries::edit_events::get_recent_edits(&conn, proposal_id, limit as i64)
+        .map_err(|e| format!("Failed to get recent edits: {}", e))
+}
+
+/// Restore a proposal to an edit snapshot. The restore is itself recorded as an
+/// edit event, so it can be undone. Returns the restored content.
+#[tauri::command]
+fn restore_edit(
+    database: State<'_, db::AppDatabase>,
+    proposal_id: i64,
+    edit_event_id: i64,
+) -> Result<String, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    let event = db::queries::edit_events::get_edit_event(&conn, edit_event_id)
+        .map_err(|e| format!("Failed to get edit event: {}", e))?
+        .filter(|event| event.proposal_id == proposal_id)
+        .ok_or_else(|| format!("Edit event not found: {}", edit_event_id))?;
+
+    db::queries::proposals::update_proposal_text(&conn, proposal_id, &event.content)
+        .map_err(|e| format!("Failed to update proposal: {}", e))?;
+    if let Err(e) = db::queries::edit_events::record_edit_event(&conn, proposal_id, &event.content)
+    {
+        tracing::warn!(proposal_id = proposal_id, error = %e, "Failed to record edit event");
+    }
+
+    tracing::info!(
+        proposal_id = proposal_id,
+        edit_event_id = edit_event_id,
+        "Restored proposal to edit snapshot"
+    );
+
+    Ok(event.content)
+}
+
 // ============================================================================
 // Archived Revision Commands (Story 6-7: Archive Old Revisions)
 // ============================================================================
@@ -855,7 +1977,8 @@ async fn restore_archived_revision(
             "restore",
             Some(source.id), // Reference the original archived revision ID
         )
-        .map_err(|e| format!("Failed to create restore revision: {}", e))?;
+        .map_err(|e| format!("Failed to create restore revision: {}", e))?
+        .id;
 
         // Archive old revisions if threshold exceeded (AC1 fix)
         if let Err(e) = db::queries::revisions::archive_old_revisions(&mut conn, proposal_id) {
@@ -891,7 +2014,7 @@ async fn restore_archived_revision(
 }
 
 /// Check for draft proposal from previous session (Story 1.14)
-/// Returns the latest draft if exists, None otherwise
+/// Returns the latest draft if exists, None otherwise (see `get_all_drafts` for every draft)
 #[tauri::command]
 fn check_for_draft(
     database: State<'_, db::AppDatabase>,
@@ -928,7 +2051,30 @@ fn update_proposal_status(
     }))
 }
 
+/// Preview how pasted job content will be cleaned before saving.
+/// Pure: no database access. Uses the same cleaning function as save_job_post.
+#[tauri::command]
+fn sanitize_preview(raw_content: String) -> sanitization::SanitizationPreview {
+    sanitization::preview_sanitization(&raw_content)
+}
+
+/// Preview the pre-generation pass: the exact job content Claude will receive and
+/// what was removed, using the current PII and length-cap settings.
+#[tauri::command]
+fn preview_sanitization(
+    database: State<'_, db::AppDatabase>,
+    text: String,
+) -> Result<sanitization::GenerationSanitization, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+    Ok(sanitize_job_content_for_generation(&conn, &text))
+}
+
 /// Save a job post for later (Story 1.13: API Error Handling)
+/// Content is cleaned via `sanitization::clean_job_content` before insert
 /// Used when API errors occur and user wants to save job to process later
 /// Story 4a.2: Now accepts client_name from job analysis (AC-3)
 #[tauri::command]
@@ -938,6 +2084,9 @@ fn save_job_post(
     url: Option<String>,
     client_name: Option<String>,
 ) -> Result<serde_json::Value, String> {
+    // Same cleaning step that sanitize_preview shows, so the preview never drifts
+    let cleaned = sanitization::clean_job_content(&job_content);
+
     let database = database.get()?;
     let conn = database
         .conn
@@ -947,7 +2096,7 @@ fn save_job_post(
     let id = db::queries::job_posts::insert_job_post(
         &conn,
         url.as_deref(),
-        &job_content,
+        &cleaned.content,
         client_name.as_deref(),
     )
     .map_err(|e| format!("Failed to save job post: {}", e))?;
@@ -958,6 +2107,31 @@ fn save_job_post(
     }))
 }
 
+/// Best-effort analysis cache write; a failure only costs a future API call
+fn store_analysis_cache(
+    database: &db::Database,
+    content_hash: &str,
+    analysis: &analysis::JobAnalysis,
+    budget: &analysis::BudgetInfo,
+) {
+    let stored = database
+        .conn
+        .lock()
+        .map_err(|e| e.to_string())
+        .and_then(|conn| {
+            db::queries::analysis_cache::store_cached_analysis(
+                &conn,
+                content_hash,
+                analysis,
+                budget,
+            )
+            .map_err(|e| e.to_string())
+        });
+    if let Err(e) = stored {
+        tracing::warn!("Failed to cache job analysis: {}", e);
+    }
+}
+
 /// Analyze job post to extract client name, skills, and hidden needs (Story 4a.2 + 4a.3 + 4a.4 + 4a.8)
 /// Story 4a.2: Extracts client name
 /// Story 4a.3: Extracts key skills
@@ -967,63 +2141,92 @@ fn save_job_post(
 /// AC-3: Saves extracted data to database atomically (if job_post_id provided)
 /// AC-4: Completes in <3 seconds for analysis + <100ms for save
 /// AC-5: Returns error string on failure (non-blocking)
+///
+/// Identical job text (ignoring whitespace) within `analysis_cache_days` reuses the
+/// cached Haiku analysis and budget with `cached: true`, skipping both API calls;
+/// persistence for `job_post_id` still runs. `force_refresh` bypasses the cache.
 #[tauri::command]
 async fn analyze_job_post(
     raw_content: String,
     job_post_id: Option<i64>,
+    force_refresh: Option<bool>,
     database: State<'_, db::AppDatabase>,
     config_state: State<'_, config::ConfigState>,
 ) -> Result<analysis::JobAnalysis, String> {
+    let _timer = command_metrics::time("analyze_job_post");
     let database = database.get()?;
-    // AC-5: Retrieve API key from keychain (follows existing pattern)
-    let api_key = config_state.get_api_key()?;
-
-    // AC-1: Call analysis function with Haiku (extracts client_name, key_skills, and hidden_needs)
-    let mut analysis =
-        analysis::analyze_job(&raw_content, api_key.as_deref().ok_or("API key not found")?).await?;
 
-    // Story 4b.4 Task 6: Extract budget and calculate alignment (Subtask 6.1-6.5)
-    // Extract budget from job post
-    let budget_info =
-        analysis::extract_budget(&raw_content, api_key.as_deref().ok_or("API key not found")?)
-            .await
+    let content_hash = db::queries::analysis_cache::content_hash(&raw_content);
+    let cache_days =
+        database.read(|conn| Ok(db::queries::analysis_cache::analysis_cache_days(conn)))?;
+    let cache_hit = if force_refresh.unwrap_or(false) || cache_days == 0 {
+        None
+    } else {
+        database
+            .read(|conn| {
+                db::queries::analysis_cache::get_cached_analysis(conn, &content_hash, cache_days)
+                    .map_err(|e| e.to_string())
+            })
             .unwrap_or_else(|e| {
-                tracing::warn!("Budget extraction failed, defaulting to unknown: {}", e);
-                analysis::BudgetInfo {
-                    min: None,
-                    max: None,
-                    budget_type: "unknown".to_string(),
+                tracing::warn!("Analysis cache lookup failed: {}", e);
+                None
+            })
+    };
+
+    let (mut analysis, budget_info) = match cache_hit {
+        Some(hit) => {
+            tracing::info!("Job analysis served from cache (no API call)");
+            let mut analysis = hit.analysis;
+            analysis.cached = true;
+            (analysis, hit.budget)
+        }
+        None => {
+            // AC-5: Retrieve API key from keychain (follows existing pattern)
+            let api_key = config_state.get_api_key()?;
+            let api_key = api_key.as_deref().ok_or("API key not found")?;
+
+            // AC-1: Call analysis function with Haiku (extracts client_name, key_skills, and hidden_needs)
+            let analysis = analysis::analyze_job(&raw_content, api_key).await?;
+
+            // Story 4b.4 Task 6: Extract budget and calculate alignment (Subtask 6.1-6.5)
+            match analysis::extract_budget(&raw_content, api_key).await {
+                Ok(budget_info) => {
+                    // Only complete results are cached; a failed budget extraction retries next time
+                    if cache_days > 0 {
+                        store_analysis_cache(database, &content_hash, &analysis, &budget_info);
+                    }
+                    (analysis, budget_info)
+                }
+                Err(e) => {
+                    tracing::warn!("Budget extraction failed, defaulting to unknown: {}", e);
+                    let budget_info = analysis::BudgetInfo {
+                        min: None,
+                        max: None,
+                        budget_type: "unknown".to_string(),
+                        currency: None,
+                    };
+                    (analysis, budget_info)
                 }
-            });
+            }
+        }
+    };
 
-    // Get user rate configuration (Subtask 6.2)
-    let user_rate_config = {
+    // Get user rate configuration (Subtask 6.2) and exchange rates for non-USD budgets
+    let (user_rate_config, fx_rates) = {
         let conn = database
             .conn
             .lock()
             .map_err(|e| format!("Database lock error: {}", e))?;
 
-        // Get hourly rate
-        let hourly_rate = match db::queries::settings::get_setting(&conn, "user_hourly_rate") {
-            Ok(Some(value)) => value.parse::<f64>().ok(),
-            _ => None,
-        };
-
-        // Get project rate minimum
-        let project_rate_min =
-            match db::queries::settings::get_setting(&conn, "user_project_rate_min") {
-                Ok(Some(value)) => value.parse::<f64>().ok(),
-                _ => None,
-            };
-
-        RateConfig {
-            hourly_rate,
-            project_rate_min,
-        }
+        (
+            RateConfig::from_settings(&conn),
+            currency::FxRates::from_settings(&conn),
+        )
     };
 
     // Calculate budget alignment (Subtask 6.3)
-    let alignment = analysis::calculate_budget_alignment(&budget_info, &user_rate_config);
+    let alignment =
+        analysis::calculate_budget_alignment(&budget_info, &user_rate_config, &fx_rates);
 
     // Update analysis with budget fields (Subtask 6.5)
     analysis.budget_min = budget_info.min;
@@ -1033,6 +2236,21 @@ async fn analyze_job_post(
     analysis.budget_alignment_pct = alignment.percentage;
     analysis.budget_alignment_status = alignment.status.clone();
 
+    // Repeat client: summarize earlier jobs/proposals (excluding this job) so the UI can flag it
+    if let Some(client_key) = analysis
+        .client_name
+        .as_deref()
+        .and_then(db::queries::clients::normalize_client_name)
+    {
+        match database.read(|conn| {
+            db::queries::clients::prior_client_history(conn, &client_key, job_post_id)
+                .map_err(|e| e.to_string())
+        }) {
+            Ok(prior_history) => analysis.prior_history = prior_history,
+            Err(e) => tracing::warn!("Failed to load prior client history: {}", e),
+        }
+    }
+
     // Story 4a.8: Save all analysis data atomically if job_post_id provided (AC-1, AC-2, AC-3)
     if let Some(job_id) = job_post_id {
         let conn = database
@@ -1043,6 +2261,9 @@ async fn analyze_job_post(
         // Serialize hidden needs to JSON
         let hidden_needs_json = serde_json::to_string(&analysis.hidden_needs)
             .map_err(|e| format!("Failed to serialize hidden needs: {}", e))?;
+        // Must-have and nice-to-have deliverables are stored as separate buckets
+        let deliverables_json = serde_json::to_string(&analysis.deliverables())
+            .map_err(|e| format!("Failed to serialize deliverables: {}", e))?;
 
         // Story 4a.8: Atomic save - all-or-nothing transaction
         // Log timing to validate <100ms target (NFR-4)
@@ -1054,6 +2275,7 @@ async fn analyze_job_post(
             analysis.client_name.as_deref(),
             &analysis.key_skills,
             &hidden_needs_json,
+            &deliverables_json,
         )
         .map_err(|e| format!("Failed to save job analysis: {}", e))?;
 
@@ -1089,15 +2311,7 @@ async fn analyze_job_post(
         }
 
         // Story 4b.4: Save budget fields to database (AC-5, Subtask 6.4)
-        db::queries::job_posts::update_job_post_budget(
-            &conn,
-            job_id,
-            analysis.budget_min,
-            analysis.budget_max,
-            &analysis.budget_type,
-            alignment.percentage,
-            &alignment.status,
-        )
+        db::queries::job_posts::update_job_post_budget(&conn, job_id, &budget_info, &alignment)
         .map_err(|e| {
             tracing::warn!("Failed to store budget data: {}", e);
             // Non-blocking: don't fail analysis if budget storage fails (Subtask 6.6)
@@ -1138,35 +2352,150 @@ async fn analyze_job_post(
                 score.client_quality_score,
                 score.budget_alignment_score,
             ),
-            None => (None, None, None),
+            None => (None, None, None),
+        };
+
+        // Calculate overall score
+        let scoring_result =
+            scoring::calculate_overall_score(skills_match, client_quality, budget_alignment_score);
+
+        // Store overall score and color flag
+        db::queries::scoring::upsert_overall_score(
+            &conn,
+            job_id,
+            scoring_result.overall_score,
+            &scoring_result.color_flag,
+        )
+        .map_err(|e| {
+            tracing::warn!("Failed to store overall score: {}", e);
+            e
+        })
+        .ok(); // Non-blocking: don't fail analysis if overall score storage fails
+
+        tracing::info!(
+            "Calculated overall score for job_post_id {}: score={:?}, flag={}",
+            job_id,
+            scoring_result.overall_score,
+            scoring_result.color_flag
+        );
+    }
+
+    Ok(analysis)
+}
+
+/// Check which of a job's required deliverables a proposal addresses
+/// Semantic check with Claude Haiku; keyword matching when offline or no API key.
+/// Returns the coverage percentage and the unaddressed requirements.
+#[tauri::command]
+async fn check_proposal_coverage(
+    proposal_text: String,
+    required_deliverables: Vec<String>,
+    config_state: State<'_, config::ConfigState>,
+) -> Result<analysis::ProposalCoverage, String> {
+    if proposal_text.trim().is_empty() {
+        return Err("Proposal text cannot be empty".to_string());
+    }
+    // Missing key or keychain errors fall back to keyword matching
+    let api_key = config_state.get_api_key().ok().flatten();
+
+    let coverage = analysis::check_proposal_coverage(
+        &proposal_text,
+        &required_deliverables,
+        api_key.as_deref(),
+    )
+    .await;
+    tracing::info!(
+        requirements = required_deliverables.len(),
+        coverage = coverage.coverage_percentage,
+        method = ?coverage.method,
+        "Proposal coverage checked"
+    );
+    Ok(coverage)
+}
+
+/// Score a proposal's overall quality (0-100) before submitting
+/// Combines perplexity, voice match, and coverage of the job's required
+/// deliverables (when `job_post_id` is given) using the `quality_weight_*`
+/// settings. Components that can't be computed are skipped and reported.
+#[tauri::command]
+async fn score_proposal_quality(
+    proposal_text: String,
+    job_post_id: Option<i64>,
+    app_handle: AppHandle,
+    config_state: State<'_, config::ConfigState>,
+    database: State<'_, db::AppDatabase>,
+) -> Result<quality::ProposalQualityScore, String> {
+    if proposal_text.trim().is_empty() {
+        return Err("Proposal text cannot be empty".to_string());
+    }
+    let database = database.get()?;
+    let threshold = get_safety_threshold_internal(database)?;
+    let max_chunk_tokens = get_perplexity_chunk_tokens(database)?;
+
+    let (weights, voice_profile, requirements) = {
+        let conn = database
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        let profile_name = db::queries::voice_profile::active_profile_name(&conn);
+        let voice_profile = db::queries::voice_profile::get_voice_profile(&conn, &profile_name)
+            .map_err(|e| format!("Failed to get voice profile: {}", e))?
+            .map(|row| row.to_voice_profile());
+        let requirements = match job_post_id {
+            Some(id) => Some(
+                db::queries::job_posts::get_job_post_deliverables(&conn, id)
+                    .map_err(|e| format!("Failed to get job deliverables: {}", e))?
+                    .required,
+            ),
+            None => None,
         };
-
-        // Calculate overall score
-        let scoring_result =
-            scoring::calculate_overall_score(skills_match, client_quality, budget_alignment_score);
-
-        // Store overall score and color flag
-        db::queries::scoring::upsert_overall_score(
-            &conn,
-            job_id,
-            scoring_result.overall_score,
-            &scoring_result.color_flag,
+        (
+            quality::QualityWeights::from_settings(&conn),
+            voice_profile,
+            requirements,
         )
-        .map_err(|e| {
-            tracing::warn!("Failed to store overall score: {}", e);
-            e
-        })
-        .ok(); // Non-blocking: don't fail analysis if overall score storage fails
+    };
+    let api_key = config_state.get_api_key().ok().flatten();
+
+    let perplexity = match api_key.as_deref() {
+        Some(key) => claude::analyze_perplexity_with_sentences(
+            &proposal_text,
+            threshold,
+            Some(key),
+            Some(&app_handle),
+            max_chunk_tokens,
+        )
+        .await
+        .map(|analysis| quality::perplexity_component_score(analysis.score, analysis.threshold))
+        .map_err(|e| format!("Perplexity analysis failed: {}", e)),
+        None => Err("No API key configured".to_string()),
+    };
 
-        tracing::info!(
-            "Calculated overall score for job_post_id {}: score={:?}, flag={}",
-            job_id,
-            scoring_result.overall_score,
-            scoring_result.color_flag
-        );
-    }
+    let voice_match = match voice_profile {
+        Some(profile) => voice::compare_voice_match(&proposal_text, Some(&profile))
+            .overall_match_pct
+            .map(f64::from)
+            .ok_or_else(|| "Voice match unavailable".to_string()),
+        None => Err("No voice profile calibrated".to_string()),
+    };
 
-    Ok(analysis)
+    let coverage = match requirements {
+        Some(requirements) if !requirements.is_empty() => Ok(f64::from(
+            analysis::check_proposal_coverage(&proposal_text, &requirements, api_key.as_deref())
+                .await
+                .coverage_percentage,
+        )),
+        Some(_) => Err("Job has no extracted requirements".to_string()),
+        None => Err("No job post selected".to_string()),
+    };
+
+    let result = quality::calculate_quality_score(perplexity, voice_match, coverage, &weights);
+    tracing::info!(
+        overall_score = ?result.overall_score,
+        skipped = ?result.skipped,
+        "Proposal quality scored"
+    );
+    Ok(result)
 }
 
 /// Check if API key is configured
@@ -1189,10 +2518,16 @@ fn set_api_key(config_state: State<config::ConfigState>, api_key: String) -> Res
     config_state.set_api_key(api_key.trim().to_string())
 }
 
-/// Get masked API key for display (shows sk-ant-...XXXX)
+/// Get masked API key for display (shows sk-ant-...XXXX; fully redacted in presentation mode)
 #[tauri::command]
-fn get_api_key_masked(config_state: State<config::ConfigState>) -> Result<Option<String>, String> {
+fn get_api_key_masked(
+    config_state: State<config::ConfigState>,
+    presentation: State<commands::presentation::PresentationModeState>,
+) -> Result<Option<String>, String> {
     let api_key = config_state.get_api_key()?;
+    if presentation.is_enabled() {
+        return Ok(api_key.map(|_| commands::presentation::REDACTED_API_KEY.to_string()));
+    }
     Ok(api_key.map(|k| config::mask_api_key(&k)))
 }
 
@@ -1202,6 +2537,24 @@ fn validate_api_key(api_key: String) -> Result<(), String> {
     config::validate_api_key_format(&api_key)
 }
 
+/// Verify an API key against the live API with one cheap request. Checks the
+/// given key (setup), or the stored key when `api_key` is omitted. Call on an
+/// explicit user action, not per keystroke: checks within a few seconds of the
+/// last one are skipped.
+#[tauri::command]
+async fn verify_api_key_live(
+    config_state: State<'_, config::ConfigState>,
+    api_key: Option<String>,
+) -> Result<claude::ApiKeyVerification, String> {
+    let api_key = match api_key {
+        Some(key) => key,
+        None => config_state
+            .get_api_key()?
+            .ok_or("No API key configured. Please add your Anthropic API key in Settings.")?,
+    };
+    Ok(claude::verify_api_key_live(&api_key).await)
+}
+
 /// Migrate API key from config.json to OS keychain (Story 2.6)
 #[tauri::command]
 fn migrate_api_key_to_keychain(config_state: State<config::ConfigState>) -> Result<bool, String> {
@@ -1209,16 +2562,115 @@ fn migrate_api_key_to_keychain(config_state: State<config::ConfigState>) -> Resu
     config_state.migrate_api_key_to_keychain()
 }
 
-/// Clear the API key
+/// Clear the API key (blocked in presentation mode)
+#[tauri::command]
+fn clear_api_key(
+    config_state: State<config::ConfigState>,
+    presentation: State<commands::presentation::PresentationModeState>,
+) -> Result<(), AppError> {
+    presentation.ensure_allowed("clear_api_key")?;
+    Ok(config_state.clear_api_key()?)
+}
+
+/// Replace masked keys with the redaction placeholder in presentation mode
+fn redact_profile_key(
+    mut profile: config::ProfileInfo,
+    presentation: &commands::presentation::PresentationModeState,
+) -> config::ProfileInfo {
+    if presentation.is_enabled() && profile.masked_key.is_some() {
+        profile.masked_key = Some(commands::presentation::REDACTED_API_KEY.to_string());
+    }
+    profile
+}
+
+/// List API key profiles with which one is active and which have a key stored
+#[tauri::command]
+fn list_profiles(
+    config_state: State<config::ConfigState>,
+    presentation: State<commands::presentation::PresentationModeState>,
+) -> Result<Vec<config::ProfileInfo>, String> {
+    Ok(config_state
+        .list_profiles()?
+        .into_iter()
+        .map(|profile| redact_profile_key(profile, &presentation))
+        .collect())
+}
+
+/// Switch the active API key profile (`active_profile` in config.json), creating it
+/// if new. Returns `has_api_key: false` when the profile has no key yet so the UI
+/// prompts for one.
+#[tauri::command]
+fn switch_profile(
+    config_state: State<config::ConfigState>,
+    presentation: State<commands::presentation::PresentationModeState>,
+    name: String,
+) -> Result<config::ProfileInfo, String> {
+    Ok(redact_profile_key(
+        config_state.switch_profile(&name)?,
+        &presentation,
+    ))
+}
+
+/// Store an API key under a named profile (created if new) without switching to it
+#[tauri::command]
+fn set_api_key_named(
+    config_state: State<config::ConfigState>,
+    presentation: State<commands::presentation::PresentationModeState>,
+    name: String,
+    api_key: String,
+) -> Result<config::ProfileInfo, String> {
+    tracing::info!(profile = %name, api_key = %logs::redaction::RedactedApiKey(&api_key), "Setting profile API key");
+    Ok(redact_profile_key(
+        config_state.set_profile_api_key(&name, api_key.trim().to_string())?,
+        &presentation,
+    ))
+}
+
+/// API key profiles with masked keys (same as `list_profiles`)
+#[tauri::command]
+fn list_api_key_profiles(
+    config_state: State<config::ConfigState>,
+    presentation: State<commands::presentation::PresentationModeState>,
+) -> Result<Vec<config::ProfileInfo>, String> {
+    list_profiles(config_state, presentation)
+}
+
+/// Make an existing API key profile active
+#[tauri::command]
+fn set_active_api_key_profile(
+    config_state: State<config::ConfigState>,
+    presentation: State<commands::presentation::PresentationModeState>,
+    name: String,
+) -> Result<config::ProfileInfo, String> {
+    Ok(redact_profile_key(
+        config_state.set_active_profile(&name)?,
+        &presentation,
+    ))
+}
+
+/// Delete an API key profile and its stored key (blocked in presentation mode).
+/// See `ConfigState::delete_profile` for what becomes active.
 #[tauri::command]
-fn clear_api_key(config_state: State<config::ConfigState>) -> Result<(), String> {
-    config_state.clear_api_key()
+fn delete_api_key_profile(
+    config_state: State<config::ConfigState>,
+    presentation: State<commands::presentation::PresentationModeState>,
+    name: String,
+) -> Result<config::ProfileDeletion, AppError> {
+    presentation.ensure_allowed("delete_api_key_profile")?;
+    Ok(config_state.delete_profile(&name)?)
 }
 
 // ============================================================================
 // Settings Commands (Story 1.9)
 // ============================================================================
 
+/// Every known settings key with its type, constraints, default and description,
+/// so the frontend can render a settings form
+#[tauri::command]
+fn get_settings_schema() -> Vec<settings::schema::SettingDef> {
+    settings::schema::all()
+}
+
 /// Get a setting value by key
 /// Returns None if the setting doesn't exist
 #[tauri::command]
@@ -1277,6 +2729,37 @@ fn set_log_level(
     Ok(())
 }
 
+/// Validate a setting write: the schema owns type and range; only checks it
+/// can't express (per-currency FX rates, URLs, JSON phrase lists,
+/// allowlisted hosts) are added here.
+/// `api_base_url` is checked by `set_setting` (it depends on another setting).
+/// Shared with `import_user_config`.
+pub(crate) fn validate_setting_value(key: &str, value: &str) -> Result<(), String> {
+    // Only add_trusted_config_key, which requires confirmation and a minimum length
+    if key == remote_config::LOCAL_TRUSTED_KEY_SETTING {
+        return Err(format!(
+            "{} can only be set with add_trusted_config_key",
+            key
+        ));
+    }
+    // Known keys must match their declared type and range; unknown keys pass
+    settings::schema::validate(key, value)?;
+    if key.starts_with(currency::FX_RATE_SETTING_PREFIX) {
+        currency::validate_fx_rate_setting(key, value)?;
+    }
+    if key == notifications::WEBHOOK_URL_SETTING {
+        notifications::validate_webhook_url_setting(value)?;
+    }
+    if key == network::ALLOWLIST_EXTRA_SETTING {
+        network::validate_allowlist_extra(value)?;
+    }
+    if key == humanization::AI_TELL_USER_PHRASES_SETTING {
+        humanization::validate_user_ai_tell_phrases(value)?;
+    }
+
+    Ok(())
+}
+
 /// Set a setting value (insert or update)
 /// Uses UPSERT pattern for atomic operation
 #[tauri::command]
@@ -1299,6 +2782,20 @@ fn set_setting(
     if value.len() > 10000 {
         return Err("Setting value too long (max 10000 characters)".to_string());
     }
+    validate_setting_value(key, &value)?;
+    if key == network::API_BASE_URL_SETTING && !value.trim().is_empty() {
+        let allow_insecure = {
+            let conn = database
+                .conn
+                .lock()
+                .map_err(|e| format!("Database lock error: {}", e))?;
+            db::queries::settings::get_setting(&conn, network::ALLOW_INSECURE_API_SETTING)
+                .map_err(|e| format!("Failed to read setting: {}", e))?
+                .as_deref()
+                == Some("true")
+        };
+        network::validate_api_base_url(&value, allow_insecure)?;
+    }
 
     // Redact key name if it might contain sensitive data
     let key_lower = key.to_lowercase();
@@ -1323,7 +2820,88 @@ fn set_setting(
         .map_err(|e| format!("Database lock error: {}", e))?;
 
     db::queries::settings::set_setting(&conn, key, &value)
-        .map_err(|e| format!("Failed to set setting: {}", e))
+        .map_err(|e| format!("Failed to set setting: {}", e))?;
+
+    // Per-domain rate limits, network settings and payload logging take effect immediately
+    http::apply_rate_limit_setting(&conn, key);
+    if key == command_metrics::PERF_METRICS_SETTING {
+        command_metrics::apply_setting(Some(&value));
+    }
+    if key == COOLDOWN_SECONDS_SETTING {
+        apply_cooldown_setting(&conn);
+    }
+    if key == logs::api_debug::LOG_API_PAYLOADS_SETTING {
+        logs::api_debug::apply_setting(Some(&value));
+    }
+    humanization::apply_ai_tell_setting(key, Some(&value));
+    if network::apply_network_setting(key, Some(&value)) {
+        if let Some(warning) = network::api_base_url_warning(&network::api_base_url()) {
+            tracing::warn!("{}", warning);
+        }
+    }
+    Ok(())
+}
+
+/// Result of set_api_base_url
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiBaseUrlStatus {
+    /// Effective base URL (default when reset)
+    base_url: String,
+    host: String,
+    host_allowlisted: bool,
+    /// Set when requests to the base URL will be blocked by the allowlist
+    warning: Option<String>,
+}
+
+/// Set the Anthropic-compatible API base URL (proxy or gateway); None or empty
+/// resets to the default. The host must also be in `network_allowlist_extra`,
+/// otherwise the URL is saved but requests are blocked and a warning is returned.
+#[tauri::command]
+fn set_api_base_url(
+    url: Option<String>,
+    database: State<'_, db::AppDatabase>,
+) -> Result<ApiBaseUrlStatus, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    let url = url.map(|u| u.trim().to_string()).unwrap_or_default();
+    let value = if url.is_empty() {
+        String::new()
+    } else {
+        let allow_insecure =
+            db::queries::settings::get_setting(&conn, network::ALLOW_INSECURE_API_SETTING)
+                .map_err(|e| format!("Failed to read setting: {}", e))?
+                .as_deref()
+                == Some("true");
+        network::validate_api_base_url(&url, allow_insecure)?
+    };
+
+    db::queries::settings::set_setting(&conn, network::API_BASE_URL_SETTING, &value)
+        .map_err(|e| format!("Failed to set setting: {}", e))?;
+    network::apply_network_setting(network::API_BASE_URL_SETTING, Some(&value));
+
+    let base_url = network::api_base_url();
+    let host = url::Url::parse(&base_url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_string))
+        .unwrap_or_default();
+    let warning = network::api_base_url_warning(&base_url);
+    if let Some(warning) = &warning {
+        tracing::warn!("{}", warning);
+    } else {
+        tracing::info!(host = %host, "API base URL updated");
+    }
+
+    Ok(ApiBaseUrlStatus {
+        host_allowlisted: network::is_domain_allowed(&host),
+        base_url,
+        host,
+        warning,
+    })
 }
 
 /// Get all settings as a list
@@ -1421,9 +2999,13 @@ fn get_skill_suggestions(query: String) -> Result<Vec<String>, String> {
 
 /// Add a new skill to user's profile (Story 4b.1)
 /// Returns skill ID on success, error if duplicate (case-insensitive)
-/// Story 4b.5 Task 5.1: Triggers recalculation of all job scores
+/// Story 4b.5 Task 5.1: Queues a background recalculation of all job scores
 #[tauri::command]
-fn add_user_skill(database: State<'_, db::AppDatabase>, skill: String) -> Result<i64, String> {
+fn add_user_skill(
+    database: State<'_, db::AppDatabase>,
+    recalc: State<'_, commands::score_recalc::RecalcState>,
+    skill: String,
+) -> Result<i64, String> {
     let database = database.get()?;
     let skill_id = {
         let conn = database
@@ -1435,16 +3017,20 @@ fn add_user_skill(database: State<'_, db::AppDatabase>, skill: String) -> Result
             .map_err(|e| format!("Failed to add skill: {}", e))?
     };
 
-    // Story 4b.5 Task 5.1: Recalculate all scores after skill added
-    let _ = recalculate_all_scores_internal(database);
+    // Story 4b.5 Task 5.1: Recalculate all scores after skill added (debounced worker)
+    recalc.request();
 
     Ok(skill_id)
 }
 
 /// Remove a skill from user's profile (Story 4b.1)
-/// Story 4b.5 Task 5.1: Triggers recalculation of all job scores
+/// Story 4b.5 Task 5.1: Queues a background recalculation of all job scores
 #[tauri::command]
-fn remove_user_skill(database: State<'_, db::AppDatabase>, skill_id: i64) -> Result<(), String> {
+fn remove_user_skill(
+    database: State<'_, db::AppDatabase>,
+    recalc: State<'_, commands::score_recalc::RecalcState>,
+    skill_id: i64,
+) -> Result<(), String> {
     let database = database.get()?;
     {
         let conn = database
@@ -1456,8 +3042,8 @@ fn remove_user_skill(database: State<'_, db::AppDatabase>, skill_id: i64) -> Res
             .map_err(|e| format!("Failed to remove skill: {}", e))?;
     }
 
-    // Story 4b.5 Task 5.1: Recalculate all scores after skill removed
-    let _ = recalculate_all_scores_internal(database);
+    // Story 4b.5 Task 5.1: Recalculate all scores after skill removed (debounced worker)
+    recalc.request();
 
     Ok(())
 }
@@ -1490,6 +3076,21 @@ pub struct RateConfig {
     pub project_rate_min: Option<f64>,
 }
 
+impl RateConfig {
+    /// Read `user_hourly_rate` / `user_project_rate_min`; unset or unparsable values are None
+    pub fn from_settings(conn: &rusqlite::Connection) -> Self {
+        let read = |key| {
+            settings::schema::get_typed::<Option<f64>>(conn, key)
+                .ok()
+                .flatten()
+        };
+        Self {
+            hourly_rate: read(settings::schema::Key::UserHourlyRate),
+            project_rate_min: read(settings::schema::Key::UserProjectRateMin),
+        }
+    }
+}
+
 /// Get user rate configuration from settings (Story 4b.4, Task 2)
 /// Returns hourly rate and minimum project rate (null if not configured)
 #[tauri::command]
@@ -1500,23 +3101,7 @@ fn get_user_rate_config(database: State<'_, db::AppDatabase>) -> Result<RateConf
         .lock()
         .map_err(|e| format!("Database lock error: {}", e))?;
 
-    // Get hourly rate
-    let hourly_rate = match db::queries::settings::get_setting(&conn, "user_hourly_rate") {
-        Ok(Some(value)) => value.parse::<f64>().ok(),
-        _ => None,
-    };
-
-    // Get project rate minimum
-    let project_rate_min = match db::queries::settings::get_setting(&conn, "user_project_rate_min")
-    {
-        Ok(Some(value)) => value.parse::<f64>().ok(),
-        _ => None,
-    };
-
-    Ok(RateConfig {
-        hourly_rate,
-        project_rate_min,
-    })
+    Ok(RateConfig::from_settings(&conn))
 }
 
 /// Set user hourly rate (Story 4b.4, Task 2)
@@ -1621,12 +3206,8 @@ fn get_job_score(
     job_post_id: i64,
 ) -> Result<Option<db::queries::scoring::JobScore>, String> {
     let database = database.get()?;
-    let conn = database
-        .conn
-        .lock()
-        .map_err(|e| format!("Database lock error: {}", e))?;
 
-    db::queries::scoring::get_job_score(&conn, job_post_id)
+    database.read(|conn| db::queries::scoring::get_job_score(conn, job_post_id))
 }
 
 /// Get detailed scoring breakdown for UI display (Story 4b.6)
@@ -1637,12 +3218,8 @@ fn get_scoring_breakdown(
     job_post_id: i64,
 ) -> Result<scoring::ScoringBreakdown, String> {
     let database = database.get()?;
-    let conn = database
-        .conn
-        .lock()
-        .map_err(|e| format!("Database lock error: {}", e))?;
 
-    scoring::assemble_scoring_breakdown(&conn, job_post_id)
+    database.read(|conn| scoring::assemble_scoring_breakdown(conn, job_post_id))
 }
 
 /// Calculate and store overall job score (Story 4b.5 Task 3.1)
@@ -1685,54 +3262,9 @@ fn calculate_overall_job_score(
 }
 
 /// Internal helper: Recalculate all job scores (Story 4b.5 Task 3.2)
+/// Batched, so other commands can take the lock between batches
 fn recalculate_all_scores_internal(database: &db::Database) -> Result<usize, String> {
-    let conn = database
-        .conn
-        .lock()
-        .map_err(|e| format!("Database lock error: {}", e))?;
-
-    let mut stmt = conn
-        .prepare("SELECT DISTINCT job_post_id FROM job_scores")
-        .map_err(|e| format!("Failed to prepare query: {}", e))?;
-
-    let job_ids: Vec<i64> = stmt
-        .query_map([], |row| row.get(0))
-        .map_err(|e| format!("Failed to query job IDs: {}", e))?
-        .collect::<Result<Vec<i64>, _>>()
-        .map_err(|e| format!("Failed to collect job IDs: {}", e))?;
-
-    let mut recalculated = 0;
-
-    for job_id in job_ids {
-        let job_score = db::queries::scoring::get_job_score(&conn, job_id)?;
-
-        if let Some(score) = job_score {
-            let result = scoring::calculate_overall_score(
-                score.skills_match_percentage,
-                score.client_quality_score,
-                score.budget_alignment_score,
-            );
-
-            db::queries::scoring::upsert_overall_score(
-                &conn,
-                job_id,
-                result.overall_score,
-                &result.color_flag,
-            )?;
-
-            recalculated += 1;
-        }
-    }
-
-    Ok(recalculated)
-}
-
-/// Recalculate all job scores (Story 4b.5 Task 3.2)
-/// Bulk recalculation when user updates skills or rate configuration
-#[tauri::command]
-fn recalculate_all_scores(database: State<'_, db::AppDatabase>) -> Result<usize, String> {
-    let database = database.get()?;
-    recalculate_all_scores_internal(database)
+    commands::score_recalc::recalculate_scores_in_batches(database, |_, _| {})
 }
 
 // ============================================================================
@@ -1748,22 +3280,47 @@ fn get_safety_threshold_internal(database: &db::Database) -> Result<i32, String>
         .lock()
         .map_err(|e| format!("Database lock error: {}", e))?;
 
-    match db::queries::settings::get_setting(&conn, "safety_threshold") {
-        Ok(Some(value)) => {
-            let threshold = value.parse::<i32>().unwrap_or(180);
-            // Sanitize: ensure within valid range (140-220)
-            Ok(threshold.clamp(140, 220))
-        }
-        Ok(None) => Ok(180), // Default threshold (FR-11)
-        Err(e) => Err(format!("Database error: {}", e)),
+    // The schema clamps stored values into 140-220; unset or unparsable is 180 (FR-11)
+    settings::schema::get_typed(&conn, settings::schema::Key::SafetyThreshold)
+}
+
+/// Safety threshold for proposals generated at `intensity`
+/// Uses `safety_threshold_{intensity}` when set, clamped to the same 140-220
+/// range as the global threshold; unset or unparsable falls back to the global one.
+fn get_safety_threshold_for_intensity_internal(
+    database: &db::Database,
+    intensity: Option<&str>,
+) -> Result<i32, String> {
+    let Some(intensity) = intensity else {
+        return get_safety_threshold_internal(database);
+    };
+    let intensity = humanization::HumanizationIntensity::from_str_value(intensity)?;
+
+    let value: Option<i32> = {
+        let conn = database
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        settings::schema::get_typed(
+            &conn,
+            settings::schema::Key::safety_threshold_for(intensity),
+        )?
+    };
+
+    match value {
+        Some(threshold) => Ok(threshold),
+        None => get_safety_threshold_internal(database),
     }
 }
 
 /// Tauri command wrapper for get_safety_threshold
 #[tauri::command]
-fn get_safety_threshold(database: State<'_, db::AppDatabase>) -> Result<i32, String> {
+fn get_safety_threshold(
+    database: State<'_, db::AppDatabase>,
+    intensity: Option<String>,
+) -> Result<i32, String> {
     let database = database.get()?;
-    get_safety_threshold_internal(database)
+    get_safety_threshold_for_intensity_internal(database, intensity.as_deref())
 }
 
 // ============================================================================
@@ -1814,11 +3371,7 @@ fn get_humanization_intensity(database: State<'_, db::AppDatabase>) -> Result<St
         .lock()
         .map_err(|e| format!("Database lock error: {}", e))?;
 
-    let intensity = db::queries::settings::get_setting(&conn, "humanization_intensity")
-        .map_err(|e| format!("Failed to get humanization setting: {}", e))?
-        .unwrap_or_else(|| "medium".to_string());
-
-    Ok(intensity)
+    settings::schema::get_typed(&conn, settings::schema::Key::HumanizationIntensity)
 }
 
 /// Set humanization intensity with validation.
@@ -1856,6 +3409,60 @@ fn analyze_humanization_metrics(text: String) -> humanization::HumanizationMetri
     humanization::analyze_humanization(&text)
 }
 
+/// Describe what an intensity level instructs (contractions, sentence variation,
+/// AI-tell avoidance), built from the same instructions generation sends
+#[tauri::command]
+fn describe_humanization_intensity(
+    intensity: String,
+) -> Result<humanization::IntensityDescription, String> {
+    let intensity = humanization::HumanizationIntensity::from_str_value(&intensity)?;
+    Ok(humanization::describe_intensity(intensity))
+}
+
+/// AI-tell phrase lists in effect: built-in, remote config, and user additions
+#[tauri::command]
+fn get_ai_tell_phrases() -> humanization::AiTellPhrases {
+    humanization::active_ai_tell_phrases()
+}
+
+/// Add a user AI-tell phrase (case-insensitive; rejected if already detected)
+#[tauri::command]
+fn add_ai_tell_phrase(
+    database: State<'_, db::AppDatabase>,
+    phrase: String,
+) -> Result<humanization::AiTellPhrases, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+    humanization::add_user_ai_tell_phrase(&conn, &phrase)?;
+    Ok(humanization::active_ai_tell_phrases())
+}
+
+/// Remove a user AI-tell phrase. Built-in and remote phrases can't be removed.
+#[tauri::command]
+fn remove_ai_tell_phrase(
+    database: State<'_, db::AppDatabase>,
+    phrase: String,
+) -> Result<humanization::AiTellPhrases, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+    humanization::remove_user_ai_tell_phrase(&conn, &phrase)?;
+    Ok(humanization::active_ai_tell_phrases())
+}
+
+/// Instant local AI-detection risk estimate (no API call, no quota).
+/// A heuristic for live editing feedback, labeled as an estimate; the real
+/// check is `analyze_perplexity`.
+#[tauri::command]
+fn local_ai_risk_estimate(text: String) -> humanization::LocalAiRiskEstimate {
+    humanization::local_ai_risk_estimate(&text)
+}
+
 // ============================================================================
 // Export Commands (Story 1.10)
 // ============================================================================
@@ -1964,16 +3571,30 @@ async fn export_proposals_to_json(
 // ============================================================================
 
 /// Set passphrase and derive encryption key
-/// Called during first-time setup to establish passphrase-based encryption
+/// Called during first-time setup to establish passphrase-based encryption.
+/// `kdf_params` (e.g. from `calibrate_kdf_params`) default to the fixed Argon2id costs.
 #[tauri::command]
-async fn set_passphrase(passphrase: String, app_handle: AppHandle) -> Result<(), String> {
+async fn set_passphrase(
+    passphrase: String,
+    kdf_params: Option<passphrase::KdfParams>,
+    app_handle: AppHandle,
+) -> Result<(), AppError> {
     let app_data_dir = app_handle
         .path()
         .app_data_dir()
         .map_err(|e| format!("Failed to get app data directory: {}", e))?;
 
-    passphrase::set_passphrase(&passphrase, &app_data_dir)
-        .map_err(|e| format!("Failed to set passphrase: {}", e))?;
+    let kdf_params = kdf_params.unwrap_or_default();
+    passphrase::set_passphrase_with_params(&passphrase, &app_data_dir, &kdf_params).map_err(
+        |e| {
+            let code = match e {
+                passphrase::PassphraseError::TooShort
+                | passphrase::PassphraseError::InvalidKdfParams(_) => ErrorCode::ValidationFailed,
+                _ => ErrorCode::Internal,
+            };
+            AppError::new(code, format!("Failed to set passphrase: {}", e))
+        },
+    )?;
 
     Ok(())
 }
@@ -1988,7 +3609,7 @@ fn verify_passphrase_strength(passphrase: String) -> passphrase::PassphraseStren
 /// Verify passphrase and derive key (for app restart/unlock)
 /// Loads salt and derives key to verify correctness
 #[tauri::command]
-async fn verify_passphrase(passphrase: String, app_handle: AppHandle) -> Result<(), String> {
+async fn verify_passphrase(passphrase: String, app_handle: AppHandle) -> Result<(), AppError> {
     let app_data_dir = app_handle
         .path()
         .app_data_dir()
@@ -2024,7 +3645,8 @@ async fn verify_passphrase_on_restart(
     passphrase: String,
     app_database: State<'_, db::AppDatabase>,
     config_state: State<'_, config::ConfigState>,
-) -> Result<VerifyPassphraseResult, String> {
+    voice_cache: State<'_, VoiceCache>,
+) -> Result<VerifyPassphraseResult, AppError> {
     use std::sync::atomic::{AtomicU8, Ordering};
 
     // Task 4: Failed attempt tracking (in-memory, reset on restart)
@@ -2036,6 +3658,7 @@ async fn verify_passphrase_on_restart(
         .map_err(|e| format!("Failed to get app data directory: {}", e))?;
 
     // Call open_encrypted_database() with passphrase
+    let unlock_started = Instant::now();
     match db::open_encrypted_database(&app_data_dir, &passphrase) {
         Ok(database) => {
             // Story 2-7b: Store Database instance in AppDatabase via OnceLock
@@ -2047,17 +3670,27 @@ async fn verify_passphrase_on_restart(
             let log_level = config_state
                 .get_log_level()
                 .unwrap_or_else(|_| "INFO".to_string());
-            if let Err(e) = run_deferred_db_init(&app_database, &config_state, &log_level) {
+            if let Err(e) = run_deferred_db_init(&app_database, &voice_cache, &log_level) {
                 tracing::warn!("Deferred init warning (non-fatal): {}", e);
             }
 
             // Reset failed attempts on success
             FAILED_ATTEMPTS.store(0, Ordering::SeqCst);
 
-            tracing::info!("Database unlocked successfully on restart");
+            // The key files just unlocked the database: record them if no manifest exists yet
+            integrity::ensure_manifest(&app_data_dir);
+
+            tracing::info!(
+                unlock_ms = unlock_started.elapsed().as_millis() as u64,
+                "Database unlocked successfully on restart"
+            );
 
             // Emit database-ready event for frontend state transition
             let _ = app_handle.emit("database-ready", ());
+            startup_maintenance::spawn_startup_maintenance(app_handle.clone(), log_level);
+            health_check::spawn_pending_update_verification(app_handle.clone());
+            // Cached config and local overrides were unreadable while locked
+            remote_config::reload_config_after_unlock(&app_handle);
 
             Ok(VerifyPassphraseResult {
                 success: true,
@@ -2090,9 +3723,17 @@ async fn verify_passphrase_on_restart(
                 show_recovery,
             })
         }
+        Err(db::DatabaseError::IntegrityMismatch(mismatch)) => {
+            // Not a failed attempt: no passphrase would work with this salt
+            tracing::warn!(file = %mismatch.file, "{}", mismatch);
+            let _ = app_handle.emit(events::SECURITY_INTEGRITY_WARNING, &mismatch);
+            Err(db::DatabaseError::IntegrityMismatch(mismatch).into())
+        }
         Err(e) => {
             tracing::error!("Database unlock error: {}", e);
-            Err(format!("Database unlock failed: {}", e))
+            let mut err = AppError::from(e);
+            err.message = format!("Database unlock failed: {}", err.message);
+            Err(err)
         }
     }
 }
@@ -2107,6 +3748,8 @@ async fn verify_passphrase_on_restart(
 struct EncryptionStatus {
     database_encrypted: bool,
     api_key_in_keychain: bool,
+    /// "keychain", "encrypted_file", or "plaintext_config"; None if no API key
+    api_key_backend: Option<keychain::ApiKeyBackend>,
     cipher_version: String,
 }
 
@@ -2122,12 +3765,14 @@ struct EncryptionStatus {
 /// EncryptionStatus {
 ///   database_encrypted: true if migration complete and SQLCipher active,
 ///   api_key_in_keychain: true if API key stored in OS keychain,
+///   api_key_backend: where the API key is stored (None if not configured),
 ///   cipher_version: SQLCipher version string (e.g., "4.10.0")
 /// }
 #[tauri::command]
 async fn get_encryption_status(
     app_handle: AppHandle,
     database: State<'_, db::AppDatabase>,
+    config_state: State<'_, config::ConfigState>,
 ) -> Result<EncryptionStatus, String> {
     let database = database.get()?;
     let app_data_dir = app_handle
@@ -2154,15 +3799,106 @@ async fn get_encryption_status(
         "N/A".to_string() // Migration not complete, no encryption
     };
 
-    // Subtask 1.4: Check keychain for API key presence
-    let api_key_in_keychain = keychain::has_api_key().unwrap_or(false);
+    // Subtask 1.4: Check keychain for API key presence
+    let api_key_backend = config_state.api_key_backend().unwrap_or(None);
+    let api_key_in_keychain = api_key_backend == Some(keychain::ApiKeyBackend::Keychain);
+
+    // Subtask 1.5: Return EncryptionStatus
+    Ok(EncryptionStatus {
+        database_encrypted: migration_complete,
+        api_key_in_keychain,
+        api_key_backend,
+        cipher_version,
+    })
+}
+
+/// Which state the database is in, as reported by `get_app_lock_state`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+enum AppLockMode {
+    /// Encrypted database, unlocked
+    Unlocked,
+    /// Encrypted database waiting for the passphrase
+    Locked,
+    /// Unencrypted database, open
+    Unencrypted,
+    /// Unencrypted path, but no database open
+    NeverEncrypted,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AppLockState {
+    mode: AppLockMode,
+    migration_marker_exists: bool,
+}
+
+/// Report whether the app is locked, unlocked, or unencrypted. Works in every
+/// state, so the frontend can ask before invoking anything that needs the database.
+#[tauri::command]
+fn get_app_lock_state(
+    app_handle: AppHandle,
+    database: State<'_, db::AppDatabase>,
+) -> Result<AppLockState, String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    let mode = match database.try_get() {
+        db::DbAccess::Ready(_) if database.is_encrypted() => AppLockMode::Unlocked,
+        db::DbAccess::Ready(_) => AppLockMode::Unencrypted,
+        db::DbAccess::Locked => AppLockMode::Locked,
+        db::DbAccess::NeverEncrypted => AppLockMode::NeverEncrypted,
+    };
+
+    Ok(AppLockState {
+        mode,
+        migration_marker_exists: migration::is_migration_complete(&app_data_dir),
+    })
+}
+
+/// Prove SQLCipher works on this machine before migrating: round-trips a row
+/// through a throwaway encrypted database in the app data dir, then deletes it.
+/// Failed checks are reported in the result (`success: false`), not as errors.
+#[tauri::command]
+async fn test_encryption_roundtrip(
+    app_handle: AppHandle,
+) -> Result<migration::preflight::EncryptionRoundtripResult, String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        migration::preflight::run_encryption_roundtrip(&app_data_dir)
+    })
+    .await
+    .map_err(|e| format!("Encryption preflight failed to run: {}", e))
+}
 
-    // Subtask 1.5: Return EncryptionStatus
-    Ok(EncryptionStatus {
-        database_encrypted: migration_complete,
-        api_key_in_keychain,
-        cipher_version,
+/// Benchmark Argon2id on this machine and suggest parameters for a ~1s unlock
+/// (`target_ms`, clamped to 250-5000). Changes nothing: pass the suggestion to
+/// `rekey_database` (or `set_passphrase` on first setup) to apply it.
+#[tauri::command]
+async fn calibrate_kdf_params(
+    target_ms: Option<u64>,
+    app_handle: AppHandle,
+) -> Result<passphrase::KdfCalibration, String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+    let target_ms = target_ms
+        .unwrap_or(passphrase::DEFAULT_TARGET_DERIVATION_MS)
+        .clamp(250, 5000);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        passphrase::calibrate_kdf_params(&app_data_dir, target_ms)
     })
+    .await
+    .map_err(|e| format!("Key derivation calibration failed to run: {}", e))?
+    .map_err(|e| format!("Failed to calibrate key derivation: {}", e))
 }
 
 // ============================================================================
@@ -2238,35 +3974,17 @@ async fn generate_recovery_key(
     let wrapped_db_key = keychain::recovery::wrap_db_key(&db_key, &recovery_key)
         .map_err(|e| format!("Failed to wrap DB key: {}", e))?;
 
-    // Story 2-7b: Store recovery hash and wrapped DB key to external files
-    // These are needed when database is locked (can't read from encrypted DB)
-    let recovery_hash_path = app_data_dir.join(".recovery_hash");
-    let wrapped_key_path = app_data_dir.join(".recovery_wrapped_key");
-
-    std::fs::write(&recovery_hash_path, &recovery_key_hash)
-        .map_err(|e| format!("Failed to write recovery hash file: {}", e))?;
-    std::fs::write(&wrapped_key_path, &wrapped_db_key)
-        .map_err(|e| format!("Failed to write wrapped key file: {}", e))?;
-
-    // M2 fix (Review 2): Restrict file permissions on Unix (sensitive recovery data)
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        let restricted = std::fs::Permissions::from_mode(0o600);
-        let _ = std::fs::set_permissions(&recovery_hash_path, restricted.clone());
-        let _ = std::fs::set_permissions(&wrapped_key_path, restricted);
-    }
-
-    tracing::info!("Recovery data stored to external files for locked-DB recovery");
-
     // Task 1.3: Store encrypted key and hash in database (M3 fix: check affected rows)
     {
-        let conn = database
+        let mut conn = database
             .conn
             .lock()
             .map_err(|e| format!("Database lock error: {}", e))?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
 
-        let rows_affected = conn.execute(
+        let rows_affected = tx.execute(
             "UPDATE encryption_metadata SET recovery_key_encrypted = ?, recovery_key_hash = ?, updated_at = datetime('now') WHERE id = 1",
             rusqlite::params![&encrypted_key, &recovery_key_hash],
         )
@@ -2275,9 +3993,107 @@ async fn generate_recovery_key(
         if rows_affected == 0 {
             return Err("Failed to store recovery key: encryption_metadata row not found. Database may need migration.".to_string());
         }
+
+        // Story 2-7b: Store recovery hash and wrapped DB key to external files
+        // These are needed when database is locked (can't read from encrypted DB).
+        // Both files are replaced as a pair (owner-only), then the row commits.
+        keychain::recovery::replace_recovery_files(
+            &app_data_dir,
+            &recovery_key_hash,
+            &wrapped_db_key,
+            || {
+                tx.commit()
+                    .map_err(|e| format!("Failed to commit recovery key: {}", e))
+            },
+        )
+        .map_err(|e| format!("Failed to store recovery files: {}", e))?;
+    }
+
+    tracing::info!("Recovery key generated; hash and wrapped DB key stored");
+
+    Ok(RecoveryKeyData {
+        key: recovery_key,
+        encrypted: encrypted_key,
+    })
+}
+
+/// Replace the recovery key with a fresh one (requires the current passphrase)
+///
+/// Generates a new key, wraps the DB key with it, and swaps `.recovery_hash`,
+/// `.recovery_wrapped_key` and the `encryption_metadata` row together: the
+/// files are staged as temp files and renamed into place, and the row update
+/// commits last, with the previous files restored if anything fails. The old
+/// recovery key stops working as soon as this returns. The new plaintext key
+/// is returned once and never logged.
+#[tauri::command]
+async fn rotate_recovery_key(
+    passphrase: String,
+    app_handle: AppHandle,
+    database: State<'_, db::AppDatabase>,
+) -> Result<RecoveryKeyData, AppError> {
+    let database = database.get()?;
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    let db_key = passphrase::verify_passphrase(&passphrase, &app_data_dir)
+        .map_err(|e| format!("Failed to verify passphrase: {}", e))?;
+    if !database.key_matches(&db_key) {
+        return Err(AppError::new(
+            ErrorCode::IncorrectPassphrase,
+            "Incorrect passphrase",
+        ));
+    }
+    if !app_data_dir
+        .join(keychain::recovery::RECOVERY_HASH_FILE)
+        .exists()
+    {
+        return Err(AppError::validation(
+            "No recovery key to rotate. Generate a recovery key first.",
+        ));
+    }
+
+    let recovery_key = keychain::recovery::generate_recovery_key()
+        .map_err(|e| format!("Failed to generate recovery key: {}", e))?;
+    let encrypted_key = keychain::recovery::encrypt_recovery_key(&recovery_key, &passphrase)
+        .map_err(|e| format!("Failed to encrypt recovery key: {}", e))?;
+    let recovery_key_hash = keychain::recovery::hash_recovery_key(&recovery_key)
+        .map_err(|e| format!("Failed to hash recovery key: {}", e))?;
+    let wrapped_db_key = keychain::recovery::wrap_db_key(&db_key, &recovery_key)
+        .map_err(|e| format!("Failed to wrap DB key: {}", e))?;
+
+    {
+        let mut conn = database.conn.lock().map_err(AppError::database_locked)?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| AppError::database(format!("Failed to start transaction: {}", e)))?;
+        let rows_affected = tx
+            .execute(
+                "UPDATE encryption_metadata SET recovery_key_encrypted = ?, recovery_key_hash = ?, updated_at = datetime('now') WHERE id = 1",
+                rusqlite::params![&encrypted_key, &recovery_key_hash],
+            )
+            .map_err(|e| AppError::database(format!("Failed to update recovery key: {}", e)))?;
+        if rows_affected == 0 {
+            return Err(AppError::database(
+                "Failed to rotate recovery key: encryption_metadata row not found",
+            ));
+        }
+
+        // Dropping the transaction without committing rolls the row back
+        keychain::recovery::replace_recovery_files(
+            &app_data_dir,
+            &recovery_key_hash,
+            &wrapped_db_key,
+            || {
+                tx.commit()
+                    .map_err(|e| format!("Failed to commit recovery key: {}", e))
+            },
+        )
+        .map_err(|e| AppError::database(format!("Failed to rotate recovery key: {}", e)))?;
     }
 
-    tracing::info!("Recovery key generated, encrypted, and hash stored");
+    tracing::info!("Recovery key rotated; previous recovery key revoked");
 
     Ok(RecoveryKeyData {
         key: recovery_key,
@@ -2285,6 +4101,93 @@ async fn generate_recovery_key(
     })
 }
 
+/// Result of `generate_recovery_sheet`
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RecoverySheetResult {
+    success: bool,
+    file_path: Option<String>,
+    message: String,
+}
+
+/// Save a printable HTML recovery sheet (key, date, instructions, QR code)
+///
+/// Takes the key `generate_recovery_key` just returned and checks it against
+/// `.recovery_hash` first, so the sheet always holds the key that unlocks.
+/// The sheet is written only to the path chosen in the save dialog (owner-only
+/// permissions on Unix); the key is never logged and nothing is cached.
+#[tauri::command]
+async fn generate_recovery_sheet(
+    recovery_key: String,
+    app_handle: AppHandle,
+) -> Result<RecoverySheetResult, String> {
+    let recovery_key = zeroize::Zeroizing::new(recovery_key);
+    keychain::recovery::validate_recovery_key(&recovery_key)
+        .map_err(|e| format!("Invalid recovery key: {}", e))?;
+
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+    let stored_hash =
+        std::fs::read_to_string(app_data_dir.join(keychain::recovery::RECOVERY_HASH_FILE))
+            .map_err(|_| "No recovery key configured. Generate one first.".to_string())?;
+    {
+        use argon2::password_hash::PasswordHash;
+        use argon2::{Argon2, PasswordVerifier};
+
+        let parsed_hash = PasswordHash::new(stored_hash.trim())
+            .map_err(|e| format!("Invalid stored hash format: {}", e))?;
+        Argon2::default()
+            .verify_password(recovery_key.as_bytes(), &parsed_hash)
+            .map_err(|_| "This is not the current recovery key".to_string())?;
+    }
+
+    let sheet =
+        keychain::recovery_sheet::render_recovery_sheet(&recovery_key, chrono::Local::now())
+            .map_err(|e| format!("Failed to render recovery sheet: {}", e))?;
+
+    let file_path = app_handle
+        .dialog()
+        .file()
+        .set_title("Save Recovery Sheet (contains your recovery key - keep it private)")
+        .set_file_name("recovery-sheet.html")
+        .add_filter("HTML", &["html"])
+        .blocking_save_file();
+    let Some(path) = file_path else {
+        return Ok(RecoverySheetResult {
+            success: false,
+            file_path: None,
+            message: "Save cancelled".to_string(),
+        });
+    };
+
+    let path_str = path.to_string();
+    keychain::recovery_sheet::write_recovery_sheet(std::path::Path::new(&path_str), &sheet)
+        .map_err(|e| format!("Failed to write recovery sheet: {}", e))?;
+
+    tracing::info!("Recovery sheet saved (key NOT logged)");
+
+    Ok(RecoverySheetResult {
+        success: true,
+        file_path: Some(path_str),
+        message: "Recovery sheet saved. It contains your recovery key: print it, store it safely, and delete the file.".to_string(),
+    })
+}
+
+/// Whether a recovery key is set up (both `.recovery_hash` and
+/// `.recovery_wrapped_key` exist). Works while the database is locked and
+/// decrypts nothing, so the UI can nudge users to set up recovery before they
+/// need it. A partial setup reports false.
+#[tauri::command]
+fn has_recovery_key(app_handle: AppHandle) -> Result<bool, String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+    Ok(keychain::recovery::has_recovery_files(&app_data_dir))
+}
+
 /// Result structure for recovery key unlock (Story 2.9, AC6)
 #[derive(serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -2304,6 +4207,10 @@ struct RecoveryUnlockResult {
 /// - `.recovery_hash` - Argon2id hash for verification
 /// - `.recovery_wrapped_key` - DB encryption key wrapped with recovery key
 ///
+/// Both are checked against the integrity manifest first: a modified file is
+/// reported as such (with `security:integrity-warning`) rather than as an
+/// invalid recovery key.
+///
 /// # Flow
 /// 1. Read recovery hash from `.recovery_hash` file
 /// 2. Verify recovery key against hash (Argon2id)
@@ -2319,6 +4226,7 @@ async fn unlock_with_recovery_key(
     app_handle: AppHandle,
     app_database: State<'_, db::AppDatabase>,
     config_state: State<'_, config::ConfigState>,
+    voice_cache: State<'_, VoiceCache>,
 ) -> Result<RecoveryUnlockResult, String> {
     // Validate recovery key format first
     keychain::recovery::validate_recovery_key(&recovery_key)
@@ -2330,14 +4238,21 @@ async fn unlock_with_recovery_key(
         .map_err(|e| format!("Failed to get app data directory: {}", e))?;
 
     // Story 2-7b: Read recovery data from external files (not locked DB)
-    let recovery_hash_path = app_data_dir.join(".recovery_hash");
-    let wrapped_key_path = app_data_dir.join(".recovery_wrapped_key");
+    let recovery_hash_path = app_data_dir.join(keychain::recovery::RECOVERY_HASH_FILE);
+    let wrapped_key_path = app_data_dir.join(keychain::recovery::RECOVERY_WRAPPED_KEY_FILE);
 
     // Check if recovery files exist
     if !recovery_hash_path.exists() || !wrapped_key_path.exists() {
         return Err("No recovery key configured. Set one up in recovery options before locking yourself out.".to_string());
     }
 
+    // A damaged file would otherwise surface as "Invalid recovery key"
+    if let Some(mismatch) = integrity::check_files(&app_data_dir, &integrity::RECOVERY_FILES) {
+        tracing::warn!(file = %mismatch.file, "{}", mismatch);
+        let _ = app_handle.emit(events::SECURITY_INTEGRITY_WARNING, &mismatch);
+        return Err(mismatch.to_string());
+    }
+
     // Read recovery hash from file
     let stored_hash = std::fs::read_to_string(&recovery_hash_path)
         .map_err(|e| format!("Failed to read recovery hash: {}", e))?;
@@ -2381,7 +4296,7 @@ async fn unlock_with_recovery_key(
     let log_level = config_state
         .get_log_level()
         .unwrap_or_else(|_| "INFO".to_string());
-    if let Err(e) = run_deferred_db_init(&app_database, &config_state, &log_level) {
+    if let Err(e) = run_deferred_db_init(&app_database, &voice_cache, &log_level) {
         tracing::warn!("Deferred init warning (non-fatal): {}", e);
     }
 
@@ -2389,6 +4304,9 @@ async fn unlock_with_recovery_key(
 
     // Emit database-ready event for frontend
     let _ = app_handle.emit("database-ready", ());
+    startup_maintenance::spawn_startup_maintenance(app_handle.clone(), log_level);
+    health_check::spawn_pending_update_verification(app_handle.clone());
+    remote_config::reload_config_after_unlock(&app_handle);
 
     Ok(RecoveryUnlockResult {
         success: true,
@@ -2446,6 +4364,213 @@ async fn set_new_passphrase_after_recovery(
     Ok(())
 }
 
+/// Result of `repair_integrity_manifest`
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct IntegrityRepairResult {
+    files_recorded: usize,
+    /// Mismatches the new manifest accepts
+    cleared: Vec<integrity::IntegrityMismatch>,
+}
+
+/// Accept the current key files after restoring them from a backup: rewrites
+/// `.integrity` from the files on disk so unlock stops reporting them as
+/// modified. Works while the database is locked. If the restored salt is not the
+/// one the database was encrypted with, unlock then fails as an incorrect passphrase.
+#[tauri::command]
+fn repair_integrity_manifest(app_handle: AppHandle) -> Result<IntegrityRepairResult, AppError> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    let cleared = integrity::verify_manifest(&app_data_dir).unwrap_or_default();
+    let files_recorded = integrity::record_manifest(&app_data_dir)
+        .map_err(|e| AppError::new(ErrorCode::Internal, e.to_string()))?;
+    tracing::info!(
+        files_recorded,
+        cleared = cleared.len(),
+        "Integrity manifest rewritten from current key files"
+    );
+
+    Ok(IntegrityRepairResult {
+        files_recorded,
+        cleared,
+    })
+}
+
+/// Change the database passphrase by re-encrypting the database in place (TD-2)
+///
+/// Emits `rekey:started` with a size-based estimate before the PRAGMA and
+/// `rekey:completed` with the actual duration after. The PRAGMA runs on a
+/// blocking task; multi-GB databases can take minutes. Refused while a
+/// migration, vacuum, or export is running.
+///
+/// `kdf_params` switch the Argon2id costs for the new key (e.g. from
+/// `calibrate_kdf_params`); when omitted the current parameters are kept.
+///
+/// An existing `.recovery_wrapped_key` still wraps the old key afterwards;
+/// the user should generate a new recovery key.
+#[tauri::command]
+async fn rekey_database(
+    new_passphrase: String,
+    kdf_params: Option<passphrase::KdfParams>,
+    app_handle: AppHandle,
+    database: State<'_, db::AppDatabase>,
+    busy: State<'_, db::maintenance::DbBusyState>,
+) -> Result<(), String> {
+    let _busy = busy.try_begin(db::maintenance::DbOperation::Rekey)?;
+    let db_path = database.get()?.path.clone();
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    let precheck = db::maintenance::rekey_precheck(&db_path);
+    tracing::info!(
+        size_bytes = precheck.size_bytes,
+        estimated_seconds = precheck.estimated_seconds,
+        "Starting database re-key"
+    );
+    let _ = app_handle.emit(
+        events::REKEY_STARTED,
+        events::RekeyStartedPayload {
+            size_bytes: precheck.size_bytes,
+            estimated_seconds: precheck.estimated_seconds,
+        },
+    );
+
+    let task_handle = app_handle.clone();
+    let (_new_key, actual_seconds) = db::maintenance::run_rekey_task(move || {
+        let database = task_handle.state::<db::AppDatabase>();
+        let database = database.get()?;
+        match kdf_params {
+            Some(params) => {
+                database.rekey_database_with_params(&new_passphrase, &app_data_dir, &params)
+            }
+            None => database.rekey_database(&new_passphrase, &app_data_dir),
+        }
+    })
+    .await?;
+
+    tracing::info!(actual_seconds = actual_seconds, "Database re-key complete");
+    let _ = app_handle.emit(
+        events::REKEY_COMPLETED,
+        events::RekeyCompletedPayload { actual_seconds },
+    );
+
+    Ok(())
+}
+
+/// Reclaim disk space: checkpoint the WAL (TRUNCATE) and VACUUM the database.
+///
+/// Useful after bulk deletions or clearing test data. Works the same on
+/// encrypted databases (SQLCipher vacuums transparently). Rewrites the whole
+/// file, so `compact:started` is emitted first with a size-based estimate and
+/// the work runs on a blocking task. Refused while a re-key, migration or
+/// export is running. Returns the size before and after.
+#[tauri::command]
+async fn compact_database(
+    app_handle: AppHandle,
+    database: State<'_, db::AppDatabase>,
+    busy: State<'_, db::maintenance::DbBusyState>,
+) -> Result<db::maintenance::CompactResult, String> {
+    let _busy = busy.try_begin(db::maintenance::DbOperation::Vacuum)?;
+    let db_path = database.get()?.path.clone();
+
+    let precheck = db::maintenance::compact_precheck(&db_path);
+    tracing::info!(
+        size_bytes = precheck.size_bytes,
+        estimated_seconds = precheck.estimated_seconds,
+        "Starting database compaction"
+    );
+    let _ = app_handle.emit(events::COMPACT_STARTED, &precheck);
+
+    let task_handle = app_handle.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        db::maintenance::compact(task_handle.state::<db::AppDatabase>().get()?)
+    })
+    .await
+    .map_err(|e| format!("Compaction task failed: {}", e))??;
+
+    tracing::info!(
+        size_before_bytes = result.size_before_bytes,
+        size_after_bytes = result.size_after_bytes,
+        actual_seconds = result.actual_seconds,
+        "Database compaction complete"
+    );
+    let _ = app_handle.emit(events::COMPACT_COMPLETED, &result);
+    Ok(result)
+}
+
+/// Erase all user data and return the app to its first-run state.
+///
+/// Wipes the database (tables dropped under `secure_delete`, VACUUMed, file
+/// deleted and recreated empty and unencrypted), removes every profile's API
+/// key from the keychain and encrypted-file fallback, and deletes logs,
+/// backups, recovery files and the encryption salt. Encrypted installs must
+/// re-enter the passphrase. Emits `app:factory-reset` when done. Blocked in
+/// presentation mode.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+async fn factory_reset(
+    passphrase: Option<String>,
+    app_handle: AppHandle,
+    database: State<'_, db::AppDatabase>,
+    config_state: State<'_, config::ConfigState>,
+    busy: State<'_, db::maintenance::DbBusyState>,
+    voice_cache: State<'_, VoiceCache>,
+    presentation: State<'_, commands::presentation::PresentationModeState>,
+) -> Result<factory_reset::FactoryResetReport, AppError> {
+    presentation.ensure_allowed("factory_reset")?;
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+    let db = database.get()?;
+
+    if migration::is_migration_complete(&app_data_dir) {
+        let passphrase = passphrase.ok_or_else(|| {
+            AppError::new(
+                ErrorCode::ValidationFailed,
+                "Passphrase required to reset an encrypted database",
+            )
+        })?;
+        let key = passphrase::verify_passphrase(&passphrase, &app_data_dir)
+            .map_err(|e| format!("Failed to verify passphrase: {}", e))?;
+        if !db.key_matches(&key) {
+            return Err(AppError::new(
+                ErrorCode::IncorrectPassphrase,
+                "Incorrect passphrase",
+            ));
+        }
+    }
+
+    let _busy = busy.try_begin(db::maintenance::DbOperation::FactoryReset)?;
+    tracing::warn!("Factory reset started: erasing all user data");
+
+    let mut report = factory_reset::FactoryResetReport {
+        tables_dropped: db.wipe_and_recreate().map_err(AppError::database)?,
+        ..Default::default()
+    };
+    config_state.reset()?;
+    factory_reset::remove_user_files(&app_data_dir, &mut report);
+    voice_cache.invalidate();
+
+    // Settings-driven globals (cooldown, rate limits, ...) back to defaults
+    run_deferred_db_init(&database, &voice_cache, &config_state.get_log_level()?)
+        .map_err(|e| format!("Failed to reinitialize after reset: {}", e))?;
+
+    tracing::info!(
+        tables_dropped = report.tables_dropped,
+        files_removed = report.files_removed,
+        files_not_removed = report.files_not_removed.len(),
+        "Factory reset complete"
+    );
+    let _ = app_handle.emit(events::APP_FACTORY_RESET, &report);
+    Ok(report)
+}
+
 // ============================================================================
 // Backup Commands (Story 2.2 - Epic 2: Pre-Migration Backup)
 // ============================================================================
@@ -2470,8 +4595,10 @@ struct BackupResult {
 async fn export_unencrypted_backup(
     app_handle: AppHandle,
     database: State<'_, db::AppDatabase>,
+    busy: State<'_, db::maintenance::DbBusyState>,
 ) -> Result<BackupResult, String> {
     let database = database.get()?;
+    let _busy = busy.try_begin(db::maintenance::DbOperation::Export)?;
     // Show save dialog for user to pick location
     let file_path = app_handle
         .dialog()
@@ -2571,9 +4698,11 @@ struct MigrationResult {
 #[tauri::command]
 async fn migrate_database(
     app_handle: AppHandle,
+    busy: State<'_, db::maintenance::DbBusyState>,
     passphrase: String,
     backup_path: String,
 ) -> Result<MigrationResult, String> {
+    let _busy = busy.try_begin(db::maintenance::DbOperation::Migration)?;
     let app_data_dir = app_handle
         .path()
         .app_data_dir()
@@ -2651,106 +4780,38 @@ async fn delete_old_database(old_db_path: String) -> Result<String, String> {
 /// Called during setup for unencrypted databases, or after passphrase unlock for encrypted ones.
 fn run_deferred_db_init(
     app_database: &db::AppDatabase,
-    config_state: &config::ConfigState,
+    voice_cache: &VoiceCache,
     log_level: &str,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let database = app_database
         .get()
         .map_err(|e| format!("Database not ready: {}", e))?;
 
-    // Story 2.1, Task 8 (Subtask 8.6): One-time migration from database to config.json
-    if log_level == "INFO" {
-        let conn = database
-            .conn
-            .lock()
-            .map_err(|e| format!("Database lock error: {}", e))?;
-        if let Ok(Some(db_log_level)) = db::queries::settings::get_setting(&conn, "log_level") {
-            if db_log_level != "INFO" {
-                tracing::info!(
-                    "Migrating log level from database to config.json: {}",
-                    db_log_level
-                );
-                config_state
-                    .set_log_level(db_log_level.clone())
-                    .map_err(|e| format!("Failed to migrate log level: {}", e))?;
-            }
-        }
-        drop(conn);
-    }
-
     tracing::info!("Config initialized successfully (log level: {})", log_level);
 
-    // Story 3.7: Auto-confirm successful overrides on startup (Task 3.1)
+    // Per-domain outbound rate limits (rate_limit_*_rpm settings)
     {
         let conn = database
             .conn
             .lock()
             .map_err(|e| format!("Database lock error: {}", e))?;
-
-        match db::queries::safety_overrides::get_pending_overrides_older_than_7_days(&conn) {
-            Ok(pending_overrides) => {
-                let mut successful_count = 0;
-                let mut unsuccessful_count = 0;
-
-                for override_record in pending_overrides {
-                    match db::queries::safety_overrides::proposal_exists(
-                        &conn,
-                        override_record.proposal_id,
-                    ) {
-                        Ok(true) => {
-                            if let Err(e) = db::queries::safety_overrides::update_override_status(
-                                &conn,
-                                override_record.id,
-                                db::queries::safety_overrides::STATUS_SUCCESSFUL,
-                            ) {
-                                tracing::warn!(
-                                    "Failed to update override {} to successful: {}",
-                                    override_record.id,
-                                    e
-                                );
-                            } else {
-                                successful_count += 1;
-                            }
-                        }
-                        Ok(false) => {
-                            if let Err(e) = db::queries::safety_overrides::update_override_status(
-                                &conn,
-                                override_record.id,
-                                db::queries::safety_overrides::STATUS_UNSUCCESSFUL,
-                            ) {
-                                tracing::warn!(
-                                    "Failed to update override {} to unsuccessful: {}",
-                                    override_record.id,
-                                    e
-                                );
-                            } else {
-                                unsuccessful_count += 1;
-                            }
-                        }
-                        Err(e) => {
-                            tracing::warn!(
-                                "Failed to check proposal existence for override {}: {}",
-                                override_record.id,
-                                e
-                            );
-                        }
-                    }
-                }
-
-                if successful_count > 0 || unsuccessful_count > 0 {
-                    tracing::info!(
-                        "Auto-confirmed overrides: {} successful, {} unsuccessful",
-                        successful_count,
-                        unsuccessful_count
-                    );
-                }
-            }
-            Err(e) => {
-                tracing::warn!("Failed to query pending overrides (non-fatal): {}", e);
-            }
-        }
+        http::load_rate_limit_settings(&conn);
+        // API base URL and extra allowlisted hosts
+        network::load_network_settings(&conn);
+        // Debug-only API payload logging (log_api_payloads, DEBUG level)
+        logs::api_debug::load_setting(&conn);
+        // Per-command timings (perf_metrics_enabled)
+        command_metrics::load_setting(&conn);
+        // Generation cooldown (cooldown_seconds)
+        apply_cooldown_setting(&conn);
+        // User AI-tell phrases and match mode
+        humanization::load_ai_tell_settings(&conn);
+        // Active voice profile, so the first generation skips the query
+        prewarm_voice_cache(&conn, voice_cache);
     }
 
+    // Log level migration, override auto-confirmation (Story 3.7) and stale job
+    // marking run after database-ready, see startup_maintenance
     tracing::info!("Database-dependent initialization complete");
     Ok(())
 }
@@ -2762,6 +4823,7 @@ pub fn run() {
         .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(tauri_plugin_notification::init())
         .setup(|app| {
             // Get app data directory
             let app_data_dir = app
@@ -2797,6 +4859,7 @@ pub fn run() {
             tracing::info!("Application starting");
             tracing::info!("App data directory: {:?}", app_data_dir);
             tracing::info!("Logs directory: {:?}", logs_dir);
+            logs::api_debug::init(&logs_dir, &log_level);
 
             // Clean up old logs (7-day retention) - non-blocking
             match logs::cleanup_old_logs(&logs_dir) {
@@ -2858,8 +4921,12 @@ pub fn run() {
 
             // Story 2-7b: Database-dependent initialization runs only when DB is available
             // For encrypted databases, this runs after passphrase unlock via run_deferred_db_init()
-            if app_database.is_ready() {
-                run_deferred_db_init(&app_database, &config_state, &log_level)?;
+            // Story 5.8 Subtask 4.1: Initialize voice cache for prompt caching (AC-6)
+            let voice_cache = VoiceCache::new();
+
+            let database_ready = app_database.is_ready();
+            if database_ready {
+                run_deferred_db_init(&app_database, &voice_cache, &log_level)?;
             } else {
                 tracing::info!(
                     "Deferring database-dependent initialization until passphrase unlock"
@@ -2888,9 +4955,6 @@ pub fn run() {
             // Story 3.8: Initialize cooldown state for rate limiting (FR-12)
             let cooldown_state = CooldownState::new();
 
-            // Story 5.8 Subtask 4.1: Initialize voice cache for prompt caching (AC-6)
-            let voice_cache = VoiceCache::new();
-
             // Story 8.13: Initialize blocked requests state for network security transparency
             let blocked_requests_state = BlockedRequestsState::new();
 
@@ -2901,15 +4965,44 @@ pub fn run() {
             app.manage(cooldown_state);
             app.manage(voice_cache);
             app.manage(blocked_requests_state);
+            app.manage(commands::generation_queue::GenerationQueueState::new());
+            app.manage(commands::bulk_scoring::BulkScoringState::default());
+            app.manage(commands::score_recalc::RecalcState::new());
+            app.manage(commands::clipboard_watch::ClipboardWatchState::default());
+            // In memory only: presentation mode is always off after a restart
+            app.manage(commands::presentation::PresentationModeState::default());
+            // Re-key / migration / vacuum / export mutual exclusion
+            app.manage(db::maintenance::DbBusyState::default());
+            // Shares its counters with the global recorder used by http::TrackedSend
+            app.manage(http::request_metrics().clone());
+            app.manage(api_quota::api_quota().clone());
+            app.manage(command_metrics::command_metrics().clone());
 
             // Story 7.6: Export rate limiting state (AC-6: 60s cooldown)
             app.manage(commands::export::ExportRateLimitState::new());
 
-            // Story 7.7: Clean up orphaned import temp files from previous crashes
-            if let Err(e) = commands::import::cleanup_import_temp_files() {
-                tracing::warn!("Failed to cleanup orphaned import temp files: {}", e);
+            // Unencrypted databases are ready now; encrypted ones start this after unlock
+            if database_ready {
+                startup_maintenance::spawn_startup_maintenance(
+                    app.handle().clone(),
+                    log_level.clone(),
+                );
+                // Verify (or roll back) an update installed before the restart
+                health_check::spawn_pending_update_verification(app.handle().clone());
             }
 
+            // Story 7.7: Clean up orphaned import temp files from previous crashes.
+            // Imports interrupted before they committed are kept for the frontend,
+            // which asks for them (get_incomplete_imports) once it has mounted.
+            let incomplete = match commands::import::cleanup_import_temp_files() {
+                Ok(incomplete) => incomplete,
+                Err(e) => {
+                    tracing::warn!("Failed to cleanup orphaned import temp files: {}", e);
+                    Vec::new()
+                }
+            };
+            app.manage(commands::import::IncompleteImportsState::new(incomplete));
+
             // Story 10.2: Load cached remote config on startup (AC-3)
             // Returns immediately from cache (no network delay), spawns background fetch if stale
             let _startup_config = remote_config::load_config_on_startup(app.handle());
@@ -2918,12 +5011,31 @@ pub fn run() {
             // Story 10.2: Start periodic config refresh every 4 hours (AC-6)
             let _config_refresh_handle = remote_config::start_periodic_config_refresh(app.handle().clone());
 
+            // Queued generation worker: drains pending_generations FIFO once cooldown clears
+            commands::generation_queue::start_generation_worker(app.handle().clone());
+
+            // Debounced score recalculation after skill edits
+            commands::score_recalc::start_recalc_worker(app.handle().clone());
+
+            // Clipboard watch mode: polls only while clipboard_watch_enabled is "true"
+            commands::clipboard_watch::start_clipboard_watcher(app.handle().clone());
+
             // Story 2-7b: Emit passphrase-required event after state is registered
             if migration_complete {
+                // Key files modified since they were last written are reported first,
+                // so the unlock screen can explain the failure that follows
+                let mismatches = integrity::verify_manifest(&app_data_dir).unwrap_or_else(|e| {
+                    tracing::warn!("Skipping key file integrity check: {}", e);
+                    Vec::new()
+                });
                 let handle = app.handle().clone();
                 // Brief delay to ensure frontend is ready to listen
                 tauri::async_runtime::spawn(async move {
                     tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+                    for mismatch in &mismatches {
+                        tracing::warn!(file = %mismatch.file, "{}", mismatch);
+                        let _ = handle.emit(events::SECURITY_INTEGRITY_WARNING, mismatch);
+                    }
                     let _ = handle.emit("passphrase-required", ());
                     tracing::info!("Emitted passphrase-required event to frontend");
                 });
@@ -2934,43 +5046,88 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             generate_proposal,
             generate_proposal_streaming,
+            generate_from_clipboard,
             analyze_perplexity,
+            analyze_stored_proposal_perplexity,
             // Cooldown commands (Story 3.8)
             get_cooldown_remaining,
+            get_cooldown_seconds,
+            set_cooldown_seconds,
+            // Queued generation commands
+            commands::generation_queue::queue_generation,
+            commands::generation_queue::get_generation_queue,
+            commands::generation_queue::cancel_queued_generation,
+            // Variant generation ("try 3 variants")
+            commands::variants::generate_proposal_variants,
+            commands::variants::promote_variant,
+            // Clipboard watch mode
+            commands::clipboard_watch::mark_clipboard_write,
+            commands::presentation::get_presentation_mode,
+            commands::presentation::set_presentation_mode,
             // Voice cache commands (Story 5.8)
             invalidate_voice_cache,
             check_database,
             save_proposal,
             get_proposals,
+            toggle_proposal_favorite,
             commands::proposals::get_proposal_history, // Story 8.7: Memory Optimization
             commands::proposals::search_proposals,     // Story 7.3: Search & Filter
             commands::proposals::get_distinct_hook_strategies, // Story 7.3: Hook strategy filter
             commands::proposals::get_proposal_detail,  // Story 7.4: Full proposal detail view
+            commands::proposals::get_generation_metadata,
+            commands::proposals::get_generation_context,
+            commands::proposals::get_presend_checklist,
             commands::proposals::update_proposal_outcome, // Story 7.1/7.2: Outcome status mutation
+            commands::proposals::update_proposal_hook_strategy,
+            commands::proposals::record_proposal_copied,
             delete_proposal,                           // Story 6.8: Delete Proposal & All Revisions
+            purge_proposal_data,
             update_proposal_content,                   // Story 6.1: TipTap Editor auto-save
             // Revision commands (Story 6.3: Proposal Revision History)
             create_revision,
             get_proposal_revisions,
             get_revision_content,
             restore_revision,
+            // Edit events (in-session undo beyond revisions)
+            get_recent_edits,
+            restore_edit,
             // Archived revision commands (Story 6.7: Archive Old Revisions)
             get_archived_revisions,
             get_archived_revision_count,
             restore_archived_revision,
             save_job_post,
+            sanitize_preview,
+            preview_sanitization,
             analyze_job_post,                   // Story 4a.2: Client Name Extraction
+            check_proposal_coverage,
+            score_proposal_quality,
             job::rss::import_rss_feed,          // Story 4b.7: RSS Feed Import
+            job::csv_import::import_job_posts_csv,
             commands::job_queue::get_job_queue, // Story 4b.9: Job Queue View with Sorting
+            commands::job_queue::dismiss_job_post,
+            commands::job_queue::set_job_queue_status,
+            commands::job_queue::mark_job_applied,
+            commands::job_queue::purge_stale_jobs,
+            commands::clients::list_known_clients,
+            commands::clients::get_client_history,
             has_api_key,
             set_api_key,
             get_api_key_masked,
             validate_api_key,
+            verify_api_key_live,
             clear_api_key,
             migrate_api_key_to_keychain,
+            list_profiles,
+            switch_profile,
+            set_api_key_named,
+            list_api_key_profiles,
+            set_active_api_key_profile,
+            delete_api_key_profile,
             // Settings commands (Story 1.9)
             get_setting,
+            get_settings_schema,
             set_setting,
+            set_api_base_url,
             get_all_settings,
             // Logging commands (Story 1.16)
             set_log_level,
@@ -2986,14 +5143,17 @@ pub fn run() {
             // Job scoring commands (Story 4b.2, 4b.5, 4b.6)
             calculate_and_store_skills_match,
             get_job_score,
-            get_scoring_breakdown,       // Story 4b.6
-            calculate_overall_job_score, // Story 4b.5
-            recalculate_all_scores,      // Story 4b.5
+            get_scoring_breakdown,                          // Story 4b.6
+            calculate_overall_job_score,                    // Story 4b.5
+            commands::score_recalc::recalculate_all_scores, // Story 4b.5
+            commands::bulk_scoring::score_all_unscored_jobs,
+            commands::bulk_scoring::cancel_bulk_scoring,
             // Scoring feedback commands (Story 4b.10)
             commands::scoring_feedback::submit_scoring_feedback,
             commands::scoring_feedback::check_can_report_score,
             // Hook strategies commands (Story 5.2)
             commands::hooks::get_hook_strategies,
+            commands::hooks::set_hook_strategy_in_rotation,
             // Golden set voice learning commands (Story 5.3, 5.4)
             commands::voice::add_golden_proposal_command,
             commands::voice::get_golden_proposals_command,
@@ -3005,6 +5165,11 @@ pub fn run() {
             commands::voice::get_voice_profile,
             commands::voice::save_voice_profile,
             commands::voice::delete_voice_profile,
+            commands::voice::list_voice_profiles,
+            commands::voice::create_voice_profile,
+            commands::voice::rename_voice_profile,
+            commands::voice::set_active_voice_profile,
+            commands::voice::analyze_voice_match,
             // Manual voice parameter adjustments (Story 6.2)
             commands::voice::update_voice_parameters,
             // Quick calibration command (Story 5-7)
@@ -3014,25 +5179,46 @@ pub fn run() {
             // Voice learning progress commands (Story 8.6)
             get_proposals_edited_count,
             increment_proposals_edited,
+            commands::voice::should_suggest_recalibration,
             // Safety override commands (Story 3.6 + 3.7)
             log_safety_override, // Deprecated: kept for backwards compatibility
             record_safety_override, // Story 3.7: Per-override record tracking
             // Learning algorithm commands (Story 3.7)
             check_threshold_learning,
+            get_safety_override_history,
+            get_override_stats,
+            cleanup_orphaned_overrides,
             check_threshold_decrease,
+            get_threshold_suggestion,
             apply_threshold_adjustment,
             dismiss_threshold_suggestion,
+            reset_threshold_learning,
             // Humanization commands (Story 3.3)
             get_humanization_intensity,
             set_humanization_intensity,
             analyze_humanization_metrics,
+            describe_humanization_intensity,
+            local_ai_risk_estimate,
+            get_ai_tell_phrases,
+            add_ai_tell_phrase,
+            remove_ai_tell_phrase,
             // Re-humanization command (Story 3.4)
             regenerate_with_humanization,
+            regenerate_selection,
+            rehumanize_proposal,
+            // Prompt versioning (preview requires dev_mode)
+            preview_generation_prompt,
+            get_current_prompt_versions,
             // Export commands (Story 1.10)
             export_proposals_to_json,
             commands::export::export_encrypted_archive, // Story 7.6: Encrypted archive export
+            commands::export::export_proposal_bundle,
+            commands::export::export_user_config,
             // Draft recovery commands (Story 1.14)
             check_for_draft,
+            commands::drafts::get_all_drafts,
+            commands::drafts::discard_draft,
+            commands::drafts::discard_all_drafts,
             update_proposal_status,
             // Passphrase commands (Story 2.1 - Epic 2)
             set_passphrase,
@@ -3040,9 +5226,19 @@ pub fn run() {
             verify_passphrase,
             verify_passphrase_on_restart,      // Story 2.7
             get_encryption_status,             // Story 2.8
+            get_app_lock_state,
+            test_encryption_roundtrip,
+            calibrate_kdf_params,
             generate_recovery_key,             // Story 2.9
+            generate_recovery_sheet,
+            rotate_recovery_key,
+            has_recovery_key,
             unlock_with_recovery_key,          // Story 2.9 AC6
             set_new_passphrase_after_recovery, // Story 2.9 AC6
+            repair_integrity_manifest,
+            rekey_database, // TD-2: passphrase change with progress events
+            compact_database,
+            factory_reset,
             // Backup commands (Story 2.2 + 2.9)
             create_pre_migration_backup,
             export_unencrypted_backup, // Story 2.9 AC3
@@ -3056,19 +5252,34 @@ pub fn run() {
             commands::system::signal_ready,
             // Network security commands (Story 8.13)
             commands::system::get_blocked_requests,
+            commands::system::get_network_metrics,
+            commands::system::reset_network_metrics,
+            commands::system::get_api_quota_status,
+            commands::system::get_command_metrics,
+            commands::system::reset_command_metrics,
+            // Log viewer commands
+            commands::logs::get_recent_logs,
+            commands::logs::export_logs_bundle,
             // Test data seeding commands (Story 8.10)
             commands::test_data::seed_proposals,
             commands::test_data::seed_job_posts,
+            commands::test_data::seed_full_dataset,
             commands::test_data::clear_test_data,
             // Analytics commands (Story 7.5)
             commands::proposals::get_proposal_analytics_summary,
             commands::proposals::get_outcome_distribution,
             commands::proposals::get_response_rate_by_strategy,
             commands::proposals::get_weekly_activity,
+            commands::proposals::generate_weekly_digest,
+            notifications::get_notification_log,
             // Import commands (Story 7.7)
             commands::import::read_archive_metadata,
             commands::import::decrypt_archive,
             commands::import::execute_import,
+            commands::import::import_proposal_bundle,
+            commands::import::import_user_config,
+            commands::import::get_incomplete_imports,
+            commands::import::dismiss_incomplete_imports,
             // Health check & version tracking commands (Story 9.9, TD2.3)
             health_check::get_installed_version_command,
             health_check::set_installed_version_command,
@@ -3076,11 +5287,16 @@ pub fn run() {
             health_check::run_health_checks_command,
             // Backup & rollback commands (Story 9.9)
             health_check::create_pre_update_backup_command,
+            health_check::prepare_and_apply_update,
             health_check::rollback_to_previous_version_command,
             health_check::get_failed_update_versions_command,
             health_check::clear_failed_update_versions_command,
             health_check::cleanup_old_backups_command,
             health_check::check_and_clear_rollback_command,
+            // Update channel (stable/beta release feed)
+            update_channel::get_update_channel,
+            update_channel::set_update_channel,
+            update_channel::check_for_update,
             // A/B testing analytics (Story 10.4)
             commands::proposals::get_strategy_effectiveness,
             // Remote config commands (Story 10.1)
@@ -3091,6 +5307,13 @@ pub fn run() {
             remote_config::force_config_refresh_command,
             // Config update check command (Story 10.5)
             remote_config::check_for_config_updates,
+            // Local config overrides (pin individual remote config keys)
+            remote_config::set_config_override,
+            remote_config::clear_config_override,
+            // Remote config signing key rotation
+            remote_config::get_config_trust_status,
+            remote_config::add_trusted_config_key,
+            remote_config::remove_trusted_config_key,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -3224,17 +5447,51 @@ pub struct ThresholdSuggestion {
     pub successful_override_count: usize,
     pub average_override_score: f32,
     pub direction: String, // "increase" | "decrease"
+    /// Overrides that triggered an increase suggestion (evidence for the dialog)
+    pub triggering_override_ids: Vec<i64>,
 }
 
-/// Constants for learning algorithm
+/// Constants for learning algorithm. Increment, proximity, override count and
+/// inactivity days are the defaults of the threshold_learning_* /
+/// threshold_inactivity_days settings (see `LearningSensitivity`).
 const THRESHOLD_INCREMENT: i32 = 10;
 const THRESHOLD_MAX: i32 = 220;
 const THRESHOLD_DEFAULT: i32 = 180;
-const THRESHOLD_PROXIMITY: f32 = 10.0; // Only count overrides within 10 points of threshold
+const THRESHOLD_PROXIMITY: i32 = 10; // Only count overrides within 10 points of threshold
 const SUCCESSFUL_OVERRIDE_THRESHOLD: usize = 3; // Need 3 overrides to suggest change
-#[allow(dead_code)] // Reserved for Story 3.7 threshold decrease feature
 const INACTIVITY_DAYS: i32 = 60; // Days without overrides to suggest decrease
 
+/// How aggressively the threshold adapts, from settings (ranges enforced by the schema)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct LearningSensitivity {
+    increment: i32,
+    proximity: f32,
+    override_count: usize,
+    inactivity_days: u32,
+}
+
+impl LearningSensitivity {
+    /// Read the sensitivity settings; a failed read falls back to the default constant
+    fn load(conn: &rusqlite::Connection) -> Self {
+        use settings::schema::{get_typed, Key};
+        let read = |key: Key, default: i64| {
+            get_typed::<i64>(conn, key).unwrap_or_else(|e| {
+                tracing::warn!("Failed to read learning sensitivity: {}", e);
+                default
+            })
+        };
+        Self {
+            increment: read(Key::ThresholdLearningIncrement, THRESHOLD_INCREMENT as i64) as i32,
+            proximity: read(Key::ThresholdLearningProximity, THRESHOLD_PROXIMITY as i64) as f32,
+            override_count: read(
+                Key::ThresholdLearningOverrideCount,
+                SUCCESSFUL_OVERRIDE_THRESHOLD as i64,
+            ) as usize,
+            inactivity_days: read(Key::ThresholdInactivityDays, INACTIVITY_DAYS as i64) as u32,
+        }
+    }
+}
+
 /// Check for learning opportunity and suggest threshold adjustment (Story 3.7, Task 4.3)
 ///
 /// Called on app startup and after each successful override confirmation.
@@ -3242,8 +5499,9 @@ const INACTIVITY_DAYS: i32 = 60; // Days without overrides to suggest decrease
 ///
 /// # Learning Algorithm
 /// 1. Query successful overrides in last 30 days
-/// 2. Filter overrides within 10 points of current threshold
-/// 3. If count >= 3, suggest new_threshold = current + 10
+/// 2. Filter overrides within `threshold_learning_proximity` (default 10) points of current threshold
+/// 3. If count >= `threshold_learning_override_count` (default 3), suggest
+///    new_threshold = current + `threshold_learning_increment` (default 10)
 /// 4. Cap at maximum 220
 ///
 /// # Returns
@@ -3259,8 +5517,14 @@ async fn check_threshold_learning(
         .lock()
         .map_err(|e| format!("Database lock error: {}", e))?;
 
+    check_threshold_learning_internal(&conn)
+}
+
+fn check_threshold_learning_internal(
+    conn: &rusqlite::Connection,
+) -> Result<Option<ThresholdSuggestion>, String> {
     // Get current threshold
-    let current_threshold = match db::queries::settings::get_setting(&conn, "safety_threshold") {
+    let current_threshold = match db::queries::settings::get_setting(conn, "safety_threshold") {
         Ok(Some(value)) => value
             .parse::<i32>()
             .unwrap_or(THRESHOLD_DEFAULT)
@@ -3272,6 +5536,8 @@ async fn check_threshold_learning(
         }
     };
 
+    let sensitivity = LearningSensitivity::load(conn);
+
     // H3 fix: Check if already at maximum - show warning instead of returning None
     if current_threshold >= THRESHOLD_MAX {
         tracing::debug!("Threshold at maximum ({}), showing warning", THRESHOLD_MAX);
@@ -3281,29 +5547,30 @@ async fn check_threshold_learning(
             successful_override_count: 0,
             average_override_score: 0.0,
             direction: "at_maximum".to_string(), // Special direction for AC7 warning
+            triggering_override_ids: Vec::new(),
         }));
     }
 
     // H2 fix: Get dismissal timestamp to filter out overrides from before rejection
     let dismissal_timestamp =
-        db::queries::settings::get_setting(&conn, "threshold_suggestion_dismissed_at")
+        db::queries::settings::get_setting(conn, "threshold_suggestion_dismissed_at")
             .ok()
             .flatten();
 
     // Get successful overrides from last 30 days
     let successful_overrides =
-        db::queries::safety_overrides::get_successful_overrides_last_30_days(&conn)
+        db::queries::safety_overrides::get_successful_overrides_last_30_days(conn)
             .map_err(|e| format!("Failed to query overrides: {}", e))?;
 
     // Filter overrides:
-    // 1. Within THRESHOLD_PROXIMITY points of current threshold
+    // 1. Within sensitivity.proximity points of current threshold
     // 2. After dismissal timestamp (if any) - implements AC6 counter reset
     let nearby_overrides: Vec<_> = successful_overrides
         .iter()
         .filter(|o| {
             // Proximity check
             let score_diff = (o.ai_score - current_threshold as f32).abs();
-            if score_diff > THRESHOLD_PROXIMITY {
+            if score_diff > sensitivity.proximity {
                 return false;
             }
 
@@ -3320,8 +5587,8 @@ async fn check_threshold_learning(
         .collect();
 
     // Check if we have enough overrides to suggest an increase
-    if nearby_overrides.len() >= SUCCESSFUL_OVERRIDE_THRESHOLD {
-        let suggested_threshold = (current_threshold + THRESHOLD_INCREMENT).min(THRESHOLD_MAX);
+    if nearby_overrides.len() >= sensitivity.override_count {
+        let suggested_threshold = (current_threshold + sensitivity.increment).min(THRESHOLD_MAX);
 
         // Calculate average score of nearby overrides
         let total_score: f32 = nearby_overrides.iter().map(|o| o.ai_score).sum();
@@ -3341,22 +5608,84 @@ async fn check_threshold_learning(
             successful_override_count: nearby_overrides.len(),
             average_override_score: average_score,
             direction: "increase".to_string(),
+            triggering_override_ids: nearby_overrides.iter().map(|o| o.id).collect(),
         }));
     }
 
     tracing::debug!(
         "No learning opportunity: {} overrides (need {})",
         nearby_overrides.len(),
-        SUCCESSFUL_OVERRIDE_THRESHOLD
+        sensitivity.override_count
     );
 
     Ok(None)
 }
 
+/// Get a page of safety override history joined to proposal snippets (newest first)
+/// Overrides whose proposal was deleted are included with a placeholder snippet.
+#[tauri::command]
+async fn get_safety_override_history(
+    limit: u32,
+    offset: u32,
+    database: State<'_, db::AppDatabase>,
+) -> Result<Vec<db::queries::safety_overrides::OverrideHistoryItem>, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    db::queries::safety_overrides::get_override_history(&conn, limit.clamp(1, 200), offset)
+        .map_err(|e| format!("Failed to get override history: {}", e))
+}
+
+/// Get override statistics: counts by status, average score over threshold, monthly histogram
+#[tauri::command]
+async fn get_override_stats(
+    database: State<'_, db::AppDatabase>,
+) -> Result<db::queries::safety_overrides::OverrideStats, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    db::queries::safety_overrides::get_override_stats(&conn)
+        .map_err(|e| format!("Failed to get override stats: {}", e))
+}
+
+/// Reclassify overrides whose proposal no longer exists as unsuccessful (the same
+/// classification startup auto-confirmation uses), or delete them with `delete`.
+/// Threshold learning already skips them; this keeps stored statuses consistent.
+/// Delete mode is blocked in presentation mode.
+#[tauri::command]
+async fn cleanup_orphaned_overrides(
+    delete: Option<bool>,
+    database: State<'_, db::AppDatabase>,
+    presentation: State<'_, commands::presentation::PresentationModeState>,
+) -> Result<db::queries::safety_overrides::OrphanedOverrideCleanup, AppError> {
+    let delete = delete.unwrap_or(false);
+    if delete {
+        presentation.ensure_allowed("cleanup_orphaned_overrides")?;
+    }
+    let database = database.get()?;
+    let conn = database.conn.lock().map_err(AppError::database_locked)?;
+
+    let report = db::queries::safety_overrides::cleanup_orphaned_overrides(&conn, delete)
+        .map_err(|e| AppError::database(format!("Failed to clean up orphaned overrides: {}", e)))?;
+    tracing::info!(
+        found = report.found,
+        marked_unsuccessful = report.marked_unsuccessful,
+        deleted = report.deleted,
+        "Cleaned up orphaned safety overrides"
+    );
+    Ok(report)
+}
+
 /// Check for downward threshold adjustment opportunity (Story 3.7, Task 7.1)
 ///
-/// Detects when user hasn't overridden any warnings for 60 days,
-/// suggesting their threshold could be lowered back to default.
+/// Detects when user hasn't overridden any warnings for `threshold_inactivity_days`
+/// (default 60), suggesting their threshold could be lowered back to default.
 ///
 /// # Returns
 /// * `Ok(Some(ThresholdSuggestion))` - Decrease opportunity detected
@@ -3371,8 +5700,14 @@ async fn check_threshold_decrease(
         .lock()
         .map_err(|e| format!("Database lock error: {}", e))?;
 
+    check_threshold_decrease_internal(&conn)
+}
+
+fn check_threshold_decrease_internal(
+    conn: &rusqlite::Connection,
+) -> Result<Option<ThresholdSuggestion>, String> {
     // Get current threshold
-    let current_threshold = match db::queries::settings::get_setting(&conn, "safety_threshold") {
+    let current_threshold = match db::queries::settings::get_setting(conn, "safety_threshold") {
         Ok(Some(value)) => value
             .parse::<i32>()
             .unwrap_or(THRESHOLD_DEFAULT)
@@ -3393,15 +5728,18 @@ async fn check_threshold_decrease(
         return Ok(None);
     }
 
-    // Check override activity in last 60 days
-    let override_count = db::queries::safety_overrides::count_overrides_last_60_days(&conn)
-        .map_err(|e| format!("Failed to count overrides: {}", e))?;
+    // Check override activity in the inactivity window
+    let inactivity_days = LearningSensitivity::load(conn).inactivity_days;
+    let override_count =
+        db::queries::safety_overrides::count_overrides_last_n_days(conn, inactivity_days)
+            .map_err(|e| format!("Failed to count overrides: {}", e))?;
 
-    // If no overrides in 60 days, suggest decrease
+    // If no overrides in the window, suggest decrease
     if override_count == 0 {
         tracing::info!(
             current_threshold = current_threshold,
             suggested_threshold = THRESHOLD_DEFAULT,
+            inactivity_days = inactivity_days,
             "Inactivity detected: threshold decrease suggestion"
         );
 
@@ -3411,12 +5749,58 @@ async fn check_threshold_decrease(
             successful_override_count: 0,
             average_override_score: 0.0,
             direction: "decrease".to_string(),
+            triggering_override_ids: Vec::new(),
         }));
     }
 
     Ok(None)
 }
 
+/// Pick one suggestion from the increase and decrease checks.
+///
+/// Precedence: "increase" > "decrease" > "at_maximum". The at-maximum warning is
+/// informational, so an actionable decrease replaces it. An increase and a decrease
+/// together should be impossible (a decrease needs no overrides in the inactivity
+/// window, an increase needs recent ones) and is logged as a data bug.
+fn combine_threshold_suggestions(
+    increase: Option<ThresholdSuggestion>,
+    decrease: Option<ThresholdSuggestion>,
+) -> Option<ThresholdSuggestion> {
+    match (increase, decrease) {
+        (Some(increase), Some(decrease)) if increase.direction == "increase" => {
+            tracing::error!(
+                current_threshold = increase.current_threshold,
+                increase_to = increase.suggested_threshold,
+                decrease_to = decrease.suggested_threshold,
+                override_count = increase.successful_override_count,
+                "Contradictory threshold suggestions (increase and decrease); using increase"
+            );
+            Some(increase)
+        }
+        (_, Some(decrease)) => Some(decrease),
+        (increase, None) => increase,
+    }
+}
+
+/// Run both threshold checks and return the suggestion that applies, if any
+///
+/// Replaces calling `check_threshold_learning` and `check_threshold_decrease`
+/// separately; see `combine_threshold_suggestions` for precedence.
+#[tauri::command]
+async fn get_threshold_suggestion(
+    database: State<'_, db::AppDatabase>,
+) -> Result<Option<ThresholdSuggestion>, String> {
+    let database = database.get()?;
+    let conn = database
+        .conn
+        .lock()
+        .map_err(|e| format!("Database lock error: {}", e))?;
+
+    let increase = check_threshold_learning_internal(&conn)?;
+    let decrease = check_threshold_decrease_internal(&conn)?;
+    Ok(combine_threshold_suggestions(increase, decrease))
+}
+
 /// Apply threshold adjustment and reset override tracking (Story 3.7, Task 6.1)
 ///
 /// Updates the safety threshold in settings and resets the learning counter.
@@ -3495,6 +5879,91 @@ async fn dismiss_threshold_suggestion(database: State<'_, db::AppDatabase>) -> R
     Ok(())
 }
 
+/// What `reset_threshold_learning` reset
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThresholdLearningReset {
+    /// A suggestion dismissal timestamp was stored and is now cleared
+    pub dismissal_cleared: bool,
+    /// The deprecated override counter settings were stored and are now cleared
+    pub override_counter_cleared: bool,
+    /// Override records deleted (only with `clear_overrides`)
+    pub overrides_deleted: usize,
+    pub previous_threshold: i32,
+    pub threshold: i32,
+}
+
+/// Reset threshold learning to a clean slate: clear the dismissal timestamp and the
+/// override counter settings, set `safety_threshold` back to the default and, with
+/// `clear_overrides`, delete every override record. Proposals are not touched.
+fn reset_threshold_learning_internal(
+    conn: &rusqlite::Connection,
+    clear_overrides: bool,
+) -> Result<ThresholdLearningReset, rusqlite::Error> {
+    let tx = conn.unchecked_transaction()?;
+
+    let previous_threshold = db::queries::settings::get_setting(&tx, "safety_threshold")?
+        .and_then(|v| v.parse::<i32>().ok())
+        .unwrap_or(THRESHOLD_DEFAULT);
+    let dismissal_cleared = tx.execute(
+        "DELETE FROM settings WHERE key = 'threshold_suggestion_dismissed_at'",
+        [],
+    )? > 0;
+    let override_counter_cleared = tx.execute(
+        "DELETE FROM settings WHERE key IN ('safety_override_count', 'safety_override_last')",
+        [],
+    )? > 0;
+    let overrides_deleted = if clear_overrides {
+        tx.execute("DELETE FROM safety_overrides", [])?
+    } else {
+        0
+    };
+    db::queries::settings::set_setting(&tx, "safety_threshold", &THRESHOLD_DEFAULT.to_string())?;
+    tx.commit()?;
+
+    Ok(ThresholdLearningReset {
+        dismissal_cleared,
+        override_counter_cleared,
+        overrides_deleted,
+        previous_threshold,
+        threshold: THRESHOLD_DEFAULT,
+    })
+}
+
+/// Reset threshold learning (see `reset_threshold_learning_internal`)
+///
+/// Learning history is lost, so `confirm` must be true. Override records are only
+/// deleted with `clear_overrides`, which is blocked in presentation mode.
+#[tauri::command]
+async fn reset_threshold_learning(
+    confirm: bool,
+    clear_overrides: Option<bool>,
+    database: State<'_, db::AppDatabase>,
+    presentation: State<'_, commands::presentation::PresentationModeState>,
+) -> Result<ThresholdLearningReset, AppError> {
+    if !confirm {
+        return Err(AppError::validation(
+            "Resetting threshold learning discards learning history; pass confirm: true",
+        ));
+    }
+    let clear_overrides = clear_overrides.unwrap_or(false);
+    if clear_overrides {
+        presentation.ensure_allowed("reset_threshold_learning")?;
+    }
+    let database = database.get()?;
+    let conn = database.conn.lock().map_err(AppError::database_locked)?;
+
+    let report = reset_threshold_learning_internal(&conn, clear_overrides)
+        .map_err(|e| AppError::database(format!("Failed to reset threshold learning: {}", e)))?;
+    tracing::info!(
+        previous_threshold = report.previous_threshold,
+        threshold = report.threshold,
+        overrides_deleted = report.overrides_deleted,
+        "Threshold learning reset by user"
+    );
+    Ok(report)
+}
+
 // ============================================================================
 // Tests (Story 3.5)
 // ============================================================================
@@ -3503,6 +5972,123 @@ async fn dismiss_threshold_suggestion(database: State<'_, db::AppDatabase>) -> R
 mod tests {
     use super::*;
 
+    // =========================================================================
+    // PII redaction before generation (opt-in setting)
+    // =========================================================================
+
+    #[test]
+    fn test_pii_redaction_is_opt_in() {
+        let db =
+            db::Database::new(":memory:".into(), None).expect("Failed to create test database");
+        let conn = db.conn.lock().unwrap();
+        let job = "Contact hiring@acme.io for details";
+
+        let sanitized = sanitize_job_content_for_generation(&conn, job);
+        assert_eq!(sanitized.content, job);
+        assert!(sanitized.report.pii_redactions.is_empty());
+
+        db::queries::settings::set_setting(&conn, sanitization::REDACT_PII_SETTING, "true")
+            .unwrap();
+        let sanitized = sanitize_job_content_for_generation(&conn, job);
+        assert_eq!(sanitized.content, "Contact [EMAIL] for details");
+        assert_eq!(sanitized.report.pii_redactions[0].placeholder, "[EMAIL]");
+    }
+
+    #[test]
+    fn test_generation_length_cap_setting() {
+        let db =
+            db::Database::new(":memory:".into(), None).expect("Failed to create test database");
+        let conn = db.conn.lock().unwrap();
+        let job = "Build a dashboard. ".repeat(100);
+
+        assert!(!sanitize_job_content_for_generation(&conn, &job).report.truncated);
+
+        db::queries::settings::set_setting(
+            &conn,
+            sanitization::GENERATION_MAX_CHARS_SETTING,
+            "1000",
+        )
+        .unwrap();
+        let sanitized = sanitize_job_content_for_generation(&conn, &job);
+        assert!(sanitized.report.truncated);
+        assert!(sanitized.content.len() <= 1000);
+        assert!(sanitized.content.ends_with("dashboard."));
+    }
+
+    // =========================================================================
+    // Apply-rate guardrail (min_score_to_generate)
+    // =========================================================================
+
+    fn insert_scored_job(conn: &rusqlite::Connection, content: &str, score: f64) -> i64 {
+        let id = db::queries::job_posts::insert_job_post(conn, None, content, None).unwrap();
+        db::queries::scoring::upsert_overall_score(conn, id, Some(score), "red").unwrap();
+        id
+    }
+
+    #[test]
+    fn test_score_guard_blocks_low_scored_linked_job() {
+        let db =
+            db::Database::new(":memory:".into(), None).expect("Failed to create test database");
+        let conn = db.conn.lock().unwrap();
+        let job = "Scrape 10k listings for $20";
+        let job_post_id = insert_scored_job(&conn, job, 30.0);
+
+        // Disabled by default
+        assert!(check_score_guard(&conn, job, Some(job_post_id), false).is_ok());
+
+        db::queries::settings::set_setting(&conn, scoring::MIN_SCORE_TO_GENERATE_SETTING, "50")
+            .unwrap();
+        let err = check_score_guard(&conn, job, Some(job_post_id), false).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ScoreBelowFloor);
+        assert_eq!(err.message, "SCORE_BELOW_FLOOR:30:50");
+
+        // Matched by content when the frontend doesn't pass job_post_id
+        let err = check_score_guard(&conn, job, None, false).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ScoreBelowFloor);
+        assert!(db::queries::score_guard_overrides::get_overrides(&conn)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_score_guard_ignores_unlinked_and_unscored_jobs() {
+        let db =
+            db::Database::new(":memory:".into(), None).expect("Failed to create test database");
+        let conn = db.conn.lock().unwrap();
+        db::queries::settings::set_setting(&conn, scoring::MIN_SCORE_TO_GENERATE_SETTING, "50")
+            .unwrap();
+
+        // Pasted job that was never saved
+        assert!(check_score_guard(&conn, "Build a React dashboard", None, false).is_ok());
+
+        // Saved but not scored yet
+        let unscored =
+            db::queries::job_posts::insert_job_post(&conn, None, "Unscored job", None).unwrap();
+        assert!(check_score_guard(&conn, "Unscored job", Some(unscored), false).is_ok());
+
+        // At or above the floor
+        let good = insert_scored_job(&conn, "Good job", 50.0);
+        assert!(check_score_guard(&conn, "Good job", Some(good), false).is_ok());
+    }
+
+    #[test]
+    fn test_score_guard_override_is_recorded() {
+        let db =
+            db::Database::new(":memory:".into(), None).expect("Failed to create test database");
+        let conn = db.conn.lock().unwrap();
+        db::queries::settings::set_setting(&conn, scoring::MIN_SCORE_TO_GENERATE_SETTING, "60")
+            .unwrap();
+        let job_post_id = insert_scored_job(&conn, "Low budget job", 42.5);
+
+        assert!(check_score_guard(&conn, "Low budget job", Some(job_post_id), true).is_ok());
+
+        let overrides = db::queries::score_guard_overrides::get_overrides(&conn).unwrap();
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].job_post_id, job_post_id);
+        assert_eq!(overrides[0].overall_score, 42.5);
+        assert_eq!(overrides[0].floor_at_override, 60.0);
+    }
+
     // =========================================================================
     // Story 4b.4: User Rate Configuration Tests
     // =========================================================================
@@ -3689,6 +6275,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_per_intensity_safety_threshold_falls_back_and_clamps() {
+        let db =
+            db::Database::new(":memory:".into(), None).expect("Failed to create test database");
+        {
+            let conn = db.conn.lock().unwrap();
+            db::queries::settings::set_setting(&conn, "safety_threshold", "190").unwrap();
+            db::queries::settings::set_setting(&conn, "safety_threshold_heavy", "150").unwrap();
+            // Corrupted values must not disable safety
+            db::queries::settings::set_setting(&conn, "safety_threshold_light", "9999").unwrap();
+            db::queries::settings::set_setting(&conn, "safety_threshold_off", "nope").unwrap();
+        }
+
+        let threshold = |intensity| get_safety_threshold_for_intensity_internal(&db, intensity);
+        assert_eq!(threshold(Some("heavy")).unwrap(), 150);
+        assert_eq!(threshold(Some("light")).unwrap(), 220);
+        // Unset or unparsable: global threshold
+        assert_eq!(threshold(Some("medium")).unwrap(), 190);
+        assert_eq!(threshold(Some("off")).unwrap(), 190);
+        assert_eq!(threshold(None).unwrap(), 190);
+        assert!(threshold(Some("extreme")).is_err());
+    }
+
     // =========================================================================
     // Story 3.6: log_safety_override tests
     // =========================================================================
@@ -3859,6 +6468,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cooldown_uses_configured_seconds() {
+        let cooldown = CooldownState::new();
+        {
+            let mut guard = cooldown.last_generation.lock().unwrap();
+            *guard = Some(Instant::now() - std::time::Duration::from_secs(30));
+        }
+
+        // Shorter cooldown already elapsed; longer one still running
+        assert_eq!(cooldown.remaining_for(20), 0);
+        let remaining = cooldown.remaining_for(300);
+        assert!((269..=270).contains(&remaining), "got {}", remaining);
+
+        // 0 disables it, even right after a generation
+        cooldown.record();
+        assert_eq!(cooldown.remaining_for(0), 0);
+
+        assert!(settings::schema::validate(COOLDOWN_SECONDS_SETTING, "0").is_ok());
+        assert!(settings::schema::validate(COOLDOWN_SECONDS_SETTING, "601").is_err());
+        assert!(settings::schema::validate(COOLDOWN_SECONDS_SETTING, "-5").is_err());
+    }
+
     // Story 3.8: Test CooldownState Default impl
     #[test]
     fn test_cooldown_default() {
@@ -3964,11 +6595,62 @@ mod tests {
     // Story 5.8: VoiceCache tests (Task 4, Subtasks 6.7, 6.8)
     // =========================================================================
 
+    #[test]
+    fn test_prewarm_voice_cache_loads_active_profile_unless_disabled() {
+        use voice::{CalibrationSource, StructurePreference, VoiceProfile};
+
+        let db = db::Database::new(":memory:".into(), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        let profile = VoiceProfile {
+            tone_score: 6.0,
+            avg_sentence_length: 15.0,
+            vocabulary_complexity: 10.0,
+            structure_preference: StructurePreference {
+                paragraphs_pct: 70,
+                bullets_pct: 30,
+            },
+            technical_depth: 7.0,
+            length_preference: 5.0,
+            common_phrases: vec![],
+            sample_count: 5,
+            calibration_source: CalibrationSource::GoldenSet,
+        };
+        db::queries::voice_profile::save_voice_profile(
+            &conn,
+            &db::queries::voice_profile::VoiceProfileRow::from_voice_profile(&profile, "default"),
+        )
+        .unwrap();
+
+        let cache = VoiceCache::new();
+        prewarm_voice_cache(&conn, &cache);
+        assert!(cache.get("default").is_some());
+
+        // Disabled: nothing loaded
+        db::queries::settings::set_setting(&conn, PREWARM_VOICE_CACHE_SETTING, "false").unwrap();
+        let cache = VoiceCache::new();
+        prewarm_voice_cache(&conn, &cache);
+        assert!(cache.get("default").is_none());
+    }
+
+    #[test]
+    fn test_prewarm_voice_cache_tolerates_query_errors() {
+        let db = db::Database::new(":memory:".into(), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        conn.execute("DROP TABLE voice_profiles", []).unwrap();
+
+        let cache = VoiceCache::new();
+        prewarm_voice_cache(&conn, &cache);
+        assert!(cache.get("default").is_none());
+    }
+
     /// Subtask 6.7: Test voice profile caching behavior
     #[test]
     fn test_voice_cache_get_returns_none_initially() {
         let cache = VoiceCache::new();
-        assert!(cache.get().is_none(), "Cache should return None when empty");
+        assert!(
+            cache.get("default").is_none(),
+            "Cache should return None when empty"
+        );
     }
 
     /// Subtask 6.7: Test voice profile caching - set and get
@@ -3994,10 +6676,10 @@ mod tests {
         };
 
         // Set profile
-        cache.set(profile.clone());
+        cache.set("default", profile.clone());
 
         // Get should return the profile
-        let cached = cache.get();
+        let cached = cache.get("default");
         assert!(cached.is_some(), "Cache should return profile after set");
 
         let cached_profile = cached.unwrap();
@@ -4028,15 +6710,19 @@ mod tests {
         };
 
         // Set profile
-        cache.set(profile.clone());
-        assert!(cache.get().is_some(), "Profile should be cached");
+        cache.set("default", profile.clone());
+        assert!(cache.get("default").is_some(), "Profile should be cached");
+
+        // Invalidating another profile leaves it cached
+        cache.invalidate_profile("technical");
+        assert!(cache.get("default").is_some());
 
         // Invalidate
-        cache.invalidate();
+        cache.invalidate_profile("default");
 
         // Get should return None after invalidation
         assert!(
-            cache.get().is_none(),
+            cache.get("default").is_none(),
             "Cache should return None after invalidation"
         );
     }
@@ -4064,20 +6750,59 @@ mod tests {
         };
 
         // First request: set profile
-        cache.set(profile.clone());
+        cache.set("default", profile.clone());
 
         // Second request: get profile (simulates subsequent generation)
-        let cached1 = cache.get();
+        let cached1 = cache.get("default");
         assert!(cached1.is_some(), "First get should return profile");
 
         // Third request: get profile again (simulates another generation)
-        let cached2 = cache.get();
+        let cached2 = cache.get("default");
         assert!(cached2.is_some(), "Second get should still return profile");
 
         // Verify same profile
         assert_eq!(cached1.unwrap().tone_score, cached2.unwrap().tone_score);
     }
 
+    /// Both generate commands load the voice profile through load_voice_profile
+    #[test]
+    fn test_load_voice_profile_prefers_cache() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let database = db::Database::new(temp_dir.path().join("voice.db"), None).unwrap();
+        let conn = database.conn.lock().unwrap();
+        let cache = VoiceCache::new();
+
+        // Uncalibrated: default voice, nothing cached
+        assert!(load_voice_profile(&conn, &cache, "default")
+            .unwrap()
+            .is_none());
+        assert!(cache.get("default").is_none());
+
+        let profile = voice::analyze_single_proposal("I build fast, reliable React apps.");
+        cache.set("default", profile.clone());
+        let loaded = load_voice_profile(&conn, &cache, "default")
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.tone_score, profile.tone_score);
+
+        // Entries are per profile: another profile still comes from the database
+        assert!(load_voice_profile(&conn, &cache, "marketing")
+            .unwrap()
+            .is_none());
+        let mut row = db::queries::voice_profile::VoiceProfileRow::with_defaults("marketing");
+        row.tone_score = 3.0;
+        db::queries::voice_profile::save_voice_profile(&conn, &row).unwrap();
+        let marketing = load_voice_profile(&conn, &cache, "marketing")
+            .unwrap()
+            .unwrap();
+        assert_eq!(marketing.tone_score, 3.0);
+        assert_eq!(cache.get("marketing").unwrap().tone_score, 3.0);
+        assert_eq!(cache.get("default").unwrap().tone_score, profile.tone_score);
+        assert!(cache.age_seconds("marketing").is_some());
+    }
+
     /// M2 fix: Test parallel loading performance (<150ms target per AC-2)
     /// Tests that voice profile + settings queries complete within target time.
     #[test]
@@ -4120,6 +6845,192 @@ mod tests {
         );
     }
 
+    // =========================================================================
+    // Learning sensitivity settings
+    // =========================================================================
+
+    #[test]
+    fn test_learning_sensitivity_reads_settings_with_defaults() {
+        let db =
+            db::Database::new(":memory:".into(), None).expect("Failed to create test database");
+        let conn = db.conn.lock().unwrap();
+
+        assert_eq!(
+            LearningSensitivity::load(&conn),
+            LearningSensitivity {
+                increment: THRESHOLD_INCREMENT,
+                proximity: THRESHOLD_PROXIMITY as f32,
+                override_count: SUCCESSFUL_OVERRIDE_THRESHOLD,
+                inactivity_days: INACTIVITY_DAYS as u32,
+            }
+        );
+
+        db::queries::settings::set_setting(&conn, "threshold_learning_increment", "5").unwrap();
+        db::queries::settings::set_setting(&conn, "threshold_learning_override_count", "5")
+            .unwrap();
+        db::queries::settings::set_setting(&conn, "threshold_inactivity_days", "30").unwrap();
+        // Out-of-range stored values are clamped into range
+        db::queries::settings::set_setting(&conn, "threshold_learning_proximity", "50").unwrap();
+
+        let sensitivity = LearningSensitivity::load(&conn);
+        assert_eq!(sensitivity.increment, 5);
+        assert_eq!(sensitivity.override_count, 5);
+        assert_eq!(sensitivity.inactivity_days, 30);
+        assert_eq!(sensitivity.proximity, 20.0);
+    }
+
+    #[test]
+    fn test_learning_sensitivity_ranges_are_validated() {
+        use settings::schema::Key;
+        for (key, low, high) in [
+            (Key::ThresholdLearningIncrement, "4", "21"),
+            (Key::ThresholdLearningProximity, "4", "21"),
+            (Key::ThresholdLearningOverrideCount, "1", "11"),
+        ] {
+            let name = key.def().key;
+            assert!(validate_setting_value(name, low).is_err(), "{}", name);
+            assert!(validate_setting_value(name, high).is_err(), "{}", name);
+        }
+        assert!(validate_setting_value("threshold_learning_increment", "5").is_ok());
+        assert!(validate_setting_value("threshold_learning_override_count", "10").is_ok());
+    }
+
+    #[test]
+    fn test_trusted_config_key_not_settable_directly() {
+        let key = "k".repeat(64);
+        assert!(validate_setting_value(remote_config::LOCAL_TRUSTED_KEY_SETTING, &key).is_err());
+    }
+
+    // =========================================================================
+    // Threshold learning reset
+    // =========================================================================
+
+    #[test]
+    fn test_reset_threshold_learning_keeps_proposals() {
+        let db =
+            db::Database::new(":memory:".into(), None).expect("Failed to create test database");
+        let conn = db.conn.lock().unwrap();
+        let proposal_id =
+            db::queries::proposals::insert_proposal(&conn, "job", "text", None).unwrap();
+        db::queries::safety_overrides::record_override(&conn, proposal_id, 185.0, 190.0).unwrap();
+        db::queries::settings::set_setting(&conn, "safety_threshold", "200").unwrap();
+        db::queries::settings::set_setting(&conn, "safety_override_count", "4").unwrap();
+        conn.execute(
+            "INSERT INTO settings (key, value) VALUES ('threshold_suggestion_dismissed_at', datetime('now'))",
+            [],
+        )
+        .unwrap();
+
+        // Without clear_overrides the records stay
+        let report = reset_threshold_learning_internal(&conn, false).unwrap();
+        assert_eq!(
+            report,
+            ThresholdLearningReset {
+                dismissal_cleared: true,
+                override_counter_cleared: true,
+                overrides_deleted: 0,
+                previous_threshold: 200,
+                threshold: THRESHOLD_DEFAULT,
+            }
+        );
+        assert_eq!(
+            db::queries::settings::get_setting(&conn, "safety_threshold").unwrap(),
+            Some("180".to_string())
+        );
+        assert!(
+            db::queries::settings::get_setting(&conn, "threshold_suggestion_dismissed_at")
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            db::queries::safety_overrides::get_all_overrides(&conn)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let report = reset_threshold_learning_internal(&conn, true).unwrap();
+        assert_eq!(report.overrides_deleted, 1);
+        assert!(!report.dismissal_cleared);
+        assert!(db::queries::safety_overrides::get_all_overrides(&conn)
+            .unwrap()
+            .is_empty());
+        assert!(db::queries::proposals::get_proposal(&conn, proposal_id)
+            .unwrap()
+            .is_some());
+    }
+
+    // =========================================================================
+    // Combined threshold suggestion
+    // =========================================================================
+
+    fn suggestion(direction: &str, current: i32, suggested: i32) -> ThresholdSuggestion {
+        ThresholdSuggestion {
+            current_threshold: current,
+            suggested_threshold: suggested,
+            successful_override_count: 0,
+            average_override_score: 0.0,
+            direction: direction.to_string(),
+            triggering_override_ids: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_combined_threshold_suggestion_precedence() {
+        let pick = |inc: Option<ThresholdSuggestion>, dec: Option<ThresholdSuggestion>| {
+            combine_threshold_suggestions(inc, dec).map(|s| s.direction)
+        };
+        assert_eq!(pick(None, None), None);
+        assert_eq!(
+            pick(Some(suggestion("increase", 180, 190)), None).as_deref(),
+            Some("increase")
+        );
+        assert_eq!(
+            pick(None, Some(suggestion("decrease", 200, 180))).as_deref(),
+            Some("decrease")
+        );
+        // Contradictory state: increase wins
+        assert_eq!(
+            pick(
+                Some(suggestion("increase", 200, 210)),
+                Some(suggestion("decrease", 200, 180))
+            )
+            .as_deref(),
+            Some("increase")
+        );
+        // The at-maximum warning gives way to an actionable decrease
+        assert_eq!(
+            pick(
+                Some(suggestion("at_maximum", 220, 220)),
+                Some(suggestion("decrease", 220, 180))
+            )
+            .as_deref(),
+            Some("decrease")
+        );
+    }
+
+    #[test]
+    fn test_combined_threshold_suggestion_from_database() {
+        let db =
+            db::Database::new(":memory:".into(), None).expect("Failed to create test database");
+        let conn = db.conn.lock().unwrap();
+        let suggest = |conn: &rusqlite::Connection| {
+            combine_threshold_suggestions(
+                check_threshold_learning_internal(conn).unwrap(),
+                check_threshold_decrease_internal(conn).unwrap(),
+            )
+        };
+
+        // Default threshold and no overrides: nothing to suggest
+        assert!(suggest(&conn).is_none());
+
+        // Raised threshold with no recent overrides: decrease
+        db::queries::settings::set_setting(&conn, "safety_threshold", "200").unwrap();
+        let found = suggest(&conn).unwrap();
+        assert_eq!(found.direction, "decrease");
+        assert_eq!(found.suggested_threshold, THRESHOLD_DEFAULT);
+    }
+
     // =========================================================================
     // Story 9.6: Updater Plugin Registration (Task 6.5)
     // =========================================================================
@@ -4142,5 +7053,6 @@ mod tests {
         let _clipboard = tauri_plugin_clipboard_manager::init::<tauri::Wry>();
         let _dialog = tauri_plugin_dialog::init::<tauri::Wry>();
         let _updater = tauri_plugin_updater::Builder::new().build::<tauri::Wry>();
+        let _notification = tauri_plugin_notification::init::<tauri::Wry>();
     }
 }
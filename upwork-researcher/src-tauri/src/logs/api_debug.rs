@@ -0,0 +1,201 @@
+//! Full request/response logging for Claude API calls (prompt debugging)
+//!
+//! Normal logs never record prompt or response text. With the `log_api_payloads`
+//! setting on AND the log level at DEBUG, every Claude request body and response
+//! text is appended to a separate `logs/api_debug.log` instead. The API key is
+//! never written: payloads are passed through `RedactedApiKey` and
+//! `redact_api_key` first, and the file is created 0o600 on Unix like the
+//! recovery files. The file is left out of log tailing and of exported log
+//! bundles unless explicitly requested.
+
+use super::redaction::{redact_api_key, RedactedApiKey};
+use serde::Serialize;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// Settings key: "true" to log full Claude payloads (only takes effect at DEBUG level)
+pub const LOG_API_PAYLOADS_SETTING: &str = "log_api_payloads";
+
+/// File name inside the logs directory
+pub const API_DEBUG_LOG_FILE: &str = "api_debug.log";
+
+/// The file is started over once it grows past this size (10 MB)
+const MAX_API_DEBUG_LOG_BYTES: u64 = 10 * 1024 * 1024;
+
+static LOGS_DIR: OnceLock<PathBuf> = OnceLock::new();
+static DEBUG_LEVEL: AtomicBool = AtomicBool::new(false);
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Serializes appends so concurrent requests don't interleave entries
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Record the logs directory and the log level the app started with.
+/// Called once after logging is initialized.
+pub fn init(logs_dir: &Path, log_level: &str) {
+    let _ = LOGS_DIR.set(logs_dir.to_path_buf());
+    DEBUG_LEVEL.store(log_level.eq_ignore_ascii_case("DEBUG"), Ordering::Relaxed);
+}
+
+/// Apply a saved `log_api_payloads` value (unset or invalid = off)
+pub fn apply_setting(value: Option<&str>) {
+    ENABLED.store(value == Some("true"), Ordering::Relaxed);
+}
+
+/// Load the setting (startup and after unlock)
+pub fn load_setting(conn: &rusqlite::Connection) {
+    let value = crate::db::queries::settings::get_setting(conn, LOG_API_PAYLOADS_SETTING)
+        .ok()
+        .flatten();
+    apply_setting(value.as_deref());
+    if is_active() {
+        tracing::warn!(
+            "API payload logging is enabled: prompts and responses are written to {}",
+            API_DEBUG_LOG_FILE
+        );
+    }
+}
+
+/// Payload logging is on: setting enabled and running at DEBUG level
+pub fn is_active() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+        && DEBUG_LEVEL.load(Ordering::Relaxed)
+        && LOGS_DIR.get().is_some()
+}
+
+/// Log a request body before it is sent. No-op unless `is_active`.
+pub fn log_request<T: Serialize>(label: &str, api_key: &str, request: &T) {
+    if !is_active() {
+        return;
+    }
+    let body = serde_json::to_string_pretty(request)
+        .unwrap_or_else(|e| format!("<failed to serialize request: {}>", e));
+    write_entry(label, api_key, "REQUEST", &body);
+}
+
+/// Log a response (status and text). No-op unless `is_active`.
+pub fn log_response(label: &str, api_key: &str, status: u16, body: &str) {
+    if !is_active() {
+        return;
+    }
+    write_entry(label, api_key, &format!("RESPONSE {}", status), body);
+}
+
+fn write_entry(label: &str, api_key: &str, kind: &str, body: &str) {
+    let Some(logs_dir) = LOGS_DIR.get() else {
+        return;
+    };
+    let _guard = WRITE_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Err(e) = append_entry(logs_dir, label, api_key, kind, body) {
+        tracing::warn!("Failed to write API debug log: {}", e);
+    }
+}
+
+/// Remove every occurrence of the API key (and any other sk-ant- key) from `text`
+fn redact_payload(text: &str, api_key: &str) -> String {
+    let text = if api_key.is_empty() {
+        text.to_string()
+    } else {
+        text.replace(api_key, &RedactedApiKey(api_key).to_string())
+    };
+    redact_api_key(&text)
+}
+
+/// Append one entry to `<logs_dir>/api_debug.log`, creating it with 0o600
+fn append_entry(
+    logs_dir: &Path,
+    label: &str,
+    api_key: &str,
+    kind: &str,
+    body: &str,
+) -> io::Result<()> {
+    let path = logs_dir.join(API_DEBUG_LOG_FILE);
+    if fs::metadata(&path).is_ok_and(|m| m.len() > MAX_API_DEBUG_LOG_BYTES) {
+        fs::remove_file(&path)?;
+    }
+
+    let mut options = OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&path)?;
+    // Also tighten a file created before this setting existed
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+
+    writeln!(
+        file,
+        "===== {} {} {} (x-api-key: {}) =====\n{}\n",
+        chrono::Utc::now().to_rfc3339(),
+        label,
+        kind,
+        RedactedApiKey(api_key),
+        redact_payload(body, api_key)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_entry_never_contains_api_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let key = "sk-ant-REDACTED";
+        let request = serde_json::json!({
+            "system": "You are a proposal writer.",
+            "messages": [{"role": "user", "content": format!("Job text pasted with {} inside", key)}],
+        });
+        let body = serde_json::to_string_pretty(&request).unwrap();
+
+        append_entry(temp_dir.path(), "generate_proposal", key, "REQUEST", &body).unwrap();
+        append_entry(
+            temp_dir.path(),
+            "generate_proposal",
+            "custom-gateway-key",
+            "RESPONSE 200",
+            "echo custom-gateway-key",
+        )
+        .unwrap();
+
+        let logged = fs::read_to_string(temp_dir.path().join(API_DEBUG_LOG_FILE)).unwrap();
+        assert!(logged.contains("You are a proposal writer."));
+        assert!(logged.contains("RESPONSE 200"));
+        assert!(!logged.contains("secretsecret"));
+        assert!(!logged.contains("custom-gateway-key"));
+        assert!(logged.contains("sk-ant-...REDACTED"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_api_debug_log_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(API_DEBUG_LOG_FILE);
+        fs::write(&path, "old entry\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        append_entry(temp_dir.path(), "analyze_job", "key", "REQUEST", "{}").unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_log_api_payloads_is_a_flag() {
+        use crate::settings::schema::validate;
+        assert!(validate(LOG_API_PAYLOADS_SETTING, "true").is_ok());
+        assert!(validate(LOG_API_PAYLOADS_SETTING, "false").is_ok());
+        assert!(validate(LOG_API_PAYLOADS_SETTING, "yes").is_err());
+    }
+}
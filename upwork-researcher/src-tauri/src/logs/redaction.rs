@@ -0,0 +1,198 @@
+/// Sensitive data redaction utilities for logging
+///
+/// Provides wrappers and utilities to automatically redact sensitive information
+/// (API keys, passphrases) from logs to prevent credential leakage.
+use std::fmt;
+
+/// Wrapper for API keys that redacts the value when displayed
+///
+/// Masks all but the prefix (sk-ant-) for identification purposes.
+/// Usage: `tracing::info!(api_key = %RedactedApiKey(&key), "Setting API key")`
+pub struct RedactedApiKey<'a>(pub &'a str);
+
+impl<'a> fmt::Display for RedactedApiKey<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.starts_with("sk-ant-") {
+            write!(f, "sk-ant-...REDACTED")
+        } else {
+            write!(f, "[REDACTED]")
+        }
+    }
+}
+
+/// Wrapper for passphrases that completely redacts the value
+///
+/// Always displays as [REDACTED] regardless of the actual value.
+/// Usage: `tracing::debug!(passphrase = %RedactedPassphrase(&pass), "Checking passphrase")`
+pub struct RedactedPassphrase<'a>(pub &'a str);
+
+impl<'a> fmt::Display for RedactedPassphrase<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+/// Wrapper for user-authored prompt text (e.g. persona addendum)
+///
+/// Logs only the length and a short SHA-256 prefix so changes are traceable
+/// without recording the text itself.
+/// Usage: `tracing::info!(addendum = %RedactedPromptText(&text), "Using addendum")`
+pub struct RedactedPromptText<'a>(pub &'a str);
+
+impl<'a> fmt::Display for RedactedPromptText<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use sha2::{Digest, Sha256};
+        let digest = hex::encode(Sha256::digest(self.0.as_bytes()));
+        write!(
+            f,
+            "[REDACTED len={} sha256={}]",
+            self.0.chars().count(),
+            &digest[..8]
+        )
+    }
+}
+
+/// Redact API key in a string (for general purpose redaction)
+///
+/// Replaces any API key pattern (sk-ant-...) with sk-ant-...REDACTED
+pub fn redact_api_key(input: &str) -> String {
+    // Simple pattern matching for sk-ant- prefix
+    // In production, could use regex for more sophisticated matching
+    if input.contains("sk-ant-") {
+        let parts: Vec<&str> = input.split("sk-ant-").collect();
+        let mut result = String::from(parts[0]);
+
+        for (i, part) in parts.iter().enumerate().skip(1) {
+            result.push_str("sk-ant-");
+            if i < parts.len() - 1 || !part.is_empty() {
+                result.push_str("...REDACTED");
+                // Keep any text after the key (e.g., punctuation, spaces)
+                if let Some(idx) = part.find(|c: char| !c.is_alphanumeric() && c != '-' && c != '_')
+                {
+                    result.push_str(&part[idx..]);
+                }
+            }
+        }
+
+        result
+    } else {
+        input.to_string()
+    }
+}
+
+/// Redact API keys and obvious secrets from a log line
+///
+/// Applies `redact_api_key`, then masks `Bearer` tokens and values of
+/// secret-looking fields (`passphrase=...`, `"token": "..."`, `x-api-key: ...`).
+pub fn redact_secrets(input: &str) -> String {
+    use regex::Regex;
+    use std::sync::OnceLock;
+
+    static SECRET_FIELD: OnceLock<Regex> = OnceLock::new();
+    static BEARER: OnceLock<Regex> = OnceLock::new();
+
+    let secret_field = SECRET_FIELD.get_or_init(|| {
+        Regex::new(
+            r#"(?i)((?:passphrase|password|secret|token|api[_-]?key|x-api-key|authorization)"?\s*[:=]\s*"?)[^\s",}]+"#,
+        )
+        .expect("valid secret field regex")
+    });
+    let bearer = BEARER.get_or_init(|| {
+        Regex::new(r"(?i)(bearer\s+)[A-Za-z0-9._~+/=-]+").expect("valid bearer regex")
+    });
+
+    let output = redact_api_key(input);
+    let output = bearer.replace_all(&output, "${1}[REDACTED]");
+    secret_field
+        .replace_all(&output, "${1}[REDACTED]")
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacted_api_key_display() {
+        let key = "sk-ant-1234567890abcdef";
+        let redacted = RedactedApiKey(key);
+        assert_eq!(format!("{}", redacted), "sk-ant-...REDACTED");
+    }
+
+    #[test]
+    fn test_redacted_api_key_invalid_format() {
+        let key = "invalid-key-format";
+        let redacted = RedactedApiKey(key);
+        assert_eq!(format!("{}", redacted), "[REDACTED]");
+    }
+
+    #[test]
+    fn test_redacted_passphrase_display() {
+        let passphrase = "my-secret-passphrase-123";
+        let redacted = RedactedPassphrase(passphrase);
+        assert_eq!(format!("{}", redacted), "[REDACTED]");
+    }
+
+    #[test]
+    fn test_redact_api_key_in_string() {
+        let input = "API key: sk-ant-abc123def456";
+        let output = redact_api_key(input);
+        assert_eq!(output, "API key: sk-ant-...REDACTED");
+    }
+
+    #[test]
+    fn test_redact_api_key_multiple() {
+        let input = "Keys: sk-ant-key1 and sk-ant-key2";
+        let output = redact_api_key(input);
+        assert!(output.contains("sk-ant-...REDACTED"));
+        assert!(!output.contains("key1"));
+        assert!(!output.contains("key2"));
+    }
+
+    #[test]
+    fn test_redact_api_key_no_match() {
+        let input = "No API key here";
+        let output = redact_api_key(input);
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn test_redact_api_key_with_punctuation() {
+        let input = "API key: sk-ant-abc123, done";
+        let output = redact_api_key(input);
+        assert!(output.contains("sk-ant-...REDACTED, done"));
+    }
+
+    #[test]
+    fn test_redact_secrets_fields_and_bearer() {
+        let output =
+            redact_secrets("passphrase=hunter2 token: abc123 Authorization: Bearer eyJhbGciOi.x");
+        assert!(!output.contains("hunter2"));
+        assert!(!output.contains("abc123"));
+        assert!(!output.contains("eyJhbGciOi"));
+        assert!(output.contains("passphrase=[REDACTED]"));
+    }
+
+    #[test]
+    fn test_redact_secrets_json_and_api_key() {
+        let output = redact_secrets(r#"{"api_key": "sk-ant-abc123", "name": "ok"}"#);
+        assert!(!output.contains("abc123"));
+        assert!(output.contains(r#""name": "ok""#));
+    }
+
+    #[test]
+    fn test_redact_secrets_leaves_plain_text() {
+        let input = "INFO Generated proposal in 1.2s";
+        assert_eq!(redact_secrets(input), input);
+    }
+
+    #[test]
+    fn test_redacted_prompt_text_hides_content() {
+        let text = "Write as a senior DevOps contractor";
+        let shown = format!("{}", RedactedPromptText(text));
+        assert!(shown.starts_with("[REDACTED len=35 sha256="));
+        assert!(!shown.contains("DevOps"));
+        // Stable for the same input
+        assert_eq!(shown, format!("{}", RedactedPromptText(text)));
+    }
+}
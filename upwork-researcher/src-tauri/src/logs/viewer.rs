@@ -0,0 +1,457 @@
+//! Log viewer utilities: tail recent log lines and export a redacted bundle
+//!
+//! Log files are read backwards in fixed-size chunks so tailing a large daily
+//! log never loads the whole file. Files that disappear mid-read (rotation or
+//! retention cleanup) are skipped rather than treated as errors.
+
+use super::api_debug::API_DEBUG_LOG_FILE;
+use super::redaction::redact_secrets;
+use serde::Serialize;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Maximum number of lines returned by a single tail request
+pub const MAX_TAIL_LINES: usize = 2000;
+
+/// Chunk size for backwards reads
+const READ_CHUNK_SIZE: u64 = 64 * 1024;
+
+/// Days of logs included in an exported bundle when not specified
+pub const DEFAULT_BUNDLE_DAYS: u64 = 7;
+/// Older logs are already deleted by retention cleanup
+pub const MAX_BUNDLE_DAYS: u64 = super::LOG_RETENTION_DAYS;
+
+/// Validate the requested bundle length: at least one day, clamped to what
+/// retention keeps
+pub fn bundle_days(days: Option<u64>) -> Result<u64, String> {
+    match days {
+        None => Ok(DEFAULT_BUNDLE_DAYS),
+        Some(0) => Err("Log bundle must cover at least 1 day".to_string()),
+        Some(days) => Ok(days.min(MAX_BUNDLE_DAYS)),
+    }
+}
+
+/// Exported log bundle
+#[derive(Debug, Clone, Serialize)]
+pub struct LogsBundle {
+    pub path: String,
+    pub size_bytes: u64,
+    pub file_count: usize,
+}
+
+/// Severity rank for a level name (higher = more severe)
+fn level_rank(level: &str) -> Option<u8> {
+    match level.to_ascii_uppercase().as_str() {
+        "TRACE" => Some(0),
+        "DEBUG" => Some(1),
+        "INFO" => Some(2),
+        "WARN" => Some(3),
+        "ERROR" => Some(4),
+        _ => None,
+    }
+}
+
+/// Extract the level of a formatted log line (`<timestamp>  INFO target: message`)
+fn line_level(line: &str) -> Option<u8> {
+    line.split_whitespace().take(3).find_map(level_rank)
+}
+
+/// Log files in `logs_dir`, newest first. The API payload debug log is
+/// skipped unless `include_api_debug` is set.
+fn log_files_newest_first(
+    logs_dir: &Path,
+    include_api_debug: bool,
+) -> Result<Vec<(PathBuf, SystemTime)>, String> {
+    if !logs_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries =
+        fs::read_dir(logs_dir).map_err(|e| format!("Failed to read logs directory: {}", e))?;
+
+    let mut files: Vec<(PathBuf, SystemTime)> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("log"))
+        .filter(|path| {
+            include_api_debug
+                || path.file_name().and_then(|n| n.to_str()) != Some(API_DEBUG_LOG_FILE)
+        })
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect();
+
+    files.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| b.0.cmp(&a.0)));
+    Ok(files)
+}
+
+/// Read lines of `path` from the end, calling `visit` newest line first.
+/// Stops early when `visit` returns false.
+fn read_lines_backwards(path: &Path, mut visit: impl FnMut(&str) -> bool) -> io::Result<()> {
+    let mut file = File::open(path)?;
+    // Snapshot the length: lines appended while reading are picked up next time
+    let mut pos = file.metadata()?.len();
+    let mut carry: Vec<u8> = Vec::new();
+
+    while pos > 0 {
+        let chunk_len = READ_CHUNK_SIZE.min(pos);
+        pos -= chunk_len;
+        file.seek(SeekFrom::Start(pos))?;
+
+        let mut chunk = vec![0u8; chunk_len as usize];
+        file.read_exact(&mut chunk)?;
+        chunk.extend_from_slice(&carry);
+
+        // Everything before the first newline may be a partial line - carry it over
+        let first_newline = chunk.iter().position(|&b| b == b'\n');
+        let (head, complete) = match first_newline {
+            Some(idx) if pos > 0 => (chunk[..idx].to_vec(), &chunk[idx + 1..]),
+            _ => (Vec::new(), &chunk[..]),
+        };
+
+        for line in complete.split(|&b| b == b'\n').rev() {
+            if line.is_empty() {
+                continue;
+            }
+            if !visit(&String::from_utf8_lossy(line)) {
+                return Ok(());
+            }
+        }
+        carry = head;
+    }
+
+    Ok(())
+}
+
+/// Return up to `lines` most recent log lines (oldest first), newest files first.
+///
+/// `min_level` filters by severity (e.g. "WARN" keeps WARN and ERROR), `contains`
+/// is a case-insensitive substring filter. Returned lines are redacted.
+pub fn tail_logs(
+    logs_dir: &Path,
+    lines: usize,
+    min_level: Option<&str>,
+    contains: Option<&str>,
+) -> Result<Vec<String>, String> {
+    let limit = lines.min(MAX_TAIL_LINES);
+    if limit == 0 {
+        return Ok(Vec::new());
+    }
+
+    let min_rank = match min_level {
+        Some(level) => {
+            Some(level_rank(level).ok_or_else(|| format!("Invalid log level: {}", level))?)
+        }
+        None => None,
+    };
+    let needle = contains.filter(|c| !c.is_empty()).map(|c| c.to_lowercase());
+
+    let mut collected: Vec<String> = Vec::with_capacity(limit);
+
+    for (path, _) in log_files_newest_first(logs_dir, false)? {
+        let result = read_lines_backwards(&path, |line| {
+            let level_ok = match min_rank {
+                Some(min) => line_level(line).is_some_and(|rank| rank >= min),
+                None => true,
+            };
+            let text_ok = needle
+                .as_ref()
+                .is_none_or(|n| line.to_lowercase().contains(n));
+
+            if level_ok && text_ok {
+                collected.push(redact_secrets(line));
+            }
+            collected.len() < limit
+        });
+
+        match result {
+            Ok(()) => {}
+            // Rotated or cleaned up while reading - skip it
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                tracing::debug!("Log file disappeared during tail: {:?}", path);
+            }
+            Err(e) => return Err(format!("Failed to read log file {:?}: {}", path, e)),
+        }
+
+        if collected.len() >= limit {
+            break;
+        }
+    }
+
+    collected.reverse();
+    Ok(collected)
+}
+
+/// Zip entry timestamp (local time, 2-second resolution); None outside 1980-2107
+fn zip_mtime(modified: SystemTime) -> Option<zip::DateTime> {
+    use chrono::{Datelike, Timelike};
+
+    let local = chrono::DateTime::<chrono::Local>::from(modified);
+    zip::DateTime::from_date_and_time(
+        u16::try_from(local.year()).ok()?,
+        local.month() as u8,
+        local.day() as u8,
+        local.hour() as u8,
+        local.minute() as u8,
+        local.second() as u8,
+    )
+    .ok()
+}
+
+/// Write a zip of the last `days` days of logs (each line redacted) into `output_dir`.
+/// `api_debug.log` (full prompts and responses) is only included when `include_api_debug` is set.
+pub fn export_logs_bundle(
+    logs_dir: &Path,
+    output_dir: &Path,
+    days: u64,
+    include_api_debug: bool,
+) -> Result<LogsBundle, String> {
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    let cutoff = SystemTime::now() - Duration::from_secs(days * 24 * 60 * 60);
+    let bundle_path = output_dir.join(format!(
+        "upwork-researcher-logs-{}.zip",
+        chrono::Utc::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    let file =
+        File::create(&bundle_path).map_err(|e| format!("Failed to create log bundle: {}", e))?;
+    let mut archive = zip::ZipWriter::new(file);
+    let mut file_count = 0;
+
+    for (path, modified) in log_files_newest_first(logs_dir, include_api_debug)? {
+        if modified < cutoff {
+            continue;
+        }
+
+        let source = match File::open(&path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(format!("Failed to open log file {:?}: {}", path, e)),
+        };
+
+        let mut redacted = Vec::new();
+        for line in BufReader::new(source).lines() {
+            let line = line.map_err(|e| format!("Failed to read log file {:?}: {}", path, e))?;
+            writeln!(redacted, "{}", redact_secrets(&line))
+                .map_err(|e| format!("Failed to buffer log line: {}", e))?;
+        }
+
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("app.log");
+        let mut options = SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .unix_permissions(0o644);
+        if let Some(mtime) = zip_mtime(modified) {
+            options = options.last_modified_time(mtime);
+        }
+        archive
+            .start_file(name, options)
+            .map_err(|e| format!("Failed to add {} to log bundle: {}", name, e))?;
+        archive
+            .write_all(&redacted)
+            .map_err(|e| format!("Failed to add {} to log bundle: {}", name, e))?;
+        file_count += 1;
+    }
+
+    // Write the central directory
+    archive
+        .finish()
+        .map_err(|e| format!("Failed to finalize log bundle: {}", e))?;
+
+    let size_bytes = fs::metadata(&bundle_path)
+        .map(|m| m.len())
+        .map_err(|e| format!("Failed to read log bundle size: {}", e))?;
+
+    Ok(LogsBundle {
+        path: bundle_path.to_string_lossy().to_string(),
+        size_bytes,
+        file_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    fn write_log(dir: &Path, name: &str, lines: &[&str]) -> PathBuf {
+        let path = dir.join(name);
+        let mut file = File::create(&path).unwrap();
+        for line in lines {
+            writeln!(file, "{}", line).unwrap();
+        }
+        path
+    }
+
+    #[test]
+    fn test_tail_returns_newest_lines_in_order() {
+        let dir = TempDir::new().unwrap();
+        let lines: Vec<String> = (0..10)
+            .map(|i| format!("2026-01-01T00:00:0{}Z  INFO app: line {}", i, i))
+            .collect();
+        let refs: Vec<&str> = lines.iter().map(|s| s.as_str()).collect();
+        write_log(dir.path(), "app.2026-01-01.log", &refs);
+
+        let tail = tail_logs(dir.path(), 3, None, None).unwrap();
+        assert_eq!(tail.len(), 3);
+        assert!(tail[0].ends_with("line 7"));
+        assert!(tail[2].ends_with("line 9"));
+    }
+
+    #[test]
+    fn test_tail_reads_across_chunk_boundaries() {
+        let dir = TempDir::new().unwrap();
+        let long = "x".repeat(1000);
+        let lines: Vec<String> = (0..200).map(|i| format!("INFO {} {}", i, long)).collect();
+        let refs: Vec<&str> = lines.iter().map(|s| s.as_str()).collect();
+        write_log(dir.path(), "app.log", &refs);
+
+        let tail = tail_logs(dir.path(), 150, None, None).unwrap();
+        assert_eq!(tail.len(), 150);
+        for (offset, line) in tail.iter().enumerate() {
+            assert_eq!(line, &format!("INFO {} {}", 50 + offset, long));
+        }
+    }
+
+    #[test]
+    fn test_tail_filters_level_and_text() {
+        let dir = TempDir::new().unwrap();
+        write_log(
+            dir.path(),
+            "app.log",
+            &[
+                "2026-01-01T00:00:00Z  INFO app: started",
+                "2026-01-01T00:00:01Z  WARN app: slow request",
+                "2026-01-01T00:00:02Z ERROR app: request failed",
+                "2026-01-01T00:00:03Z DEBUG app: request details",
+            ],
+        );
+
+        let warn_up = tail_logs(dir.path(), 10, Some("warn"), None).unwrap();
+        assert_eq!(warn_up.len(), 2);
+
+        let failed = tail_logs(dir.path(), 10, None, Some("FAILED")).unwrap();
+        assert_eq!(failed.len(), 1);
+        assert!(failed[0].contains("ERROR"));
+
+        assert!(tail_logs(dir.path(), 10, Some("LOUD"), None).is_err());
+    }
+
+    #[test]
+    fn test_tail_caps_requested_lines() {
+        let dir = TempDir::new().unwrap();
+        let lines: Vec<String> = (0..(MAX_TAIL_LINES + 50))
+            .map(|i| format!("INFO {}", i))
+            .collect();
+        let refs: Vec<&str> = lines.iter().map(|s| s.as_str()).collect();
+        write_log(dir.path(), "app.log", &refs);
+
+        let tail = tail_logs(dir.path(), usize::MAX, None, None).unwrap();
+        assert_eq!(tail.len(), MAX_TAIL_LINES);
+    }
+
+    #[test]
+    fn test_tail_missing_dir_and_redaction() {
+        let dir = TempDir::new().unwrap();
+        assert!(tail_logs(&dir.path().join("missing"), 10, None, None)
+            .unwrap()
+            .is_empty());
+
+        write_log(
+            dir.path(),
+            "app.log",
+            &["INFO key=sk-ant-secret123 passphrase=hunter2"],
+        );
+        let tail = tail_logs(dir.path(), 10, None, None).unwrap();
+        assert!(!tail[0].contains("secret123"));
+        assert!(!tail[0].contains("hunter2"));
+    }
+
+    #[test]
+    fn test_export_bundle_redacts_and_skips_old_files() {
+        let logs = TempDir::new().unwrap();
+        let out = TempDir::new().unwrap();
+        write_log(
+            logs.path(),
+            "app.2026-01-02.log",
+            &["INFO api key sk-ant-abcdef123 set"],
+        );
+        let old = write_log(logs.path(), "app.2025-01-01.log", &["INFO ancient"]);
+        let ten_days_ago = SystemTime::now() - Duration::from_secs(10 * 24 * 60 * 60);
+        filetime::set_file_mtime(&old, filetime::FileTime::from_system_time(ten_days_ago)).unwrap();
+
+        let bundle =
+            export_logs_bundle(logs.path(), out.path(), DEFAULT_BUNDLE_DAYS, false).unwrap();
+        assert_eq!(bundle.file_count, 1);
+        assert!(bundle.size_bytes > 0);
+
+        assert!(bundle.path.ends_with(".zip"));
+        let mut archive = zip::ZipArchive::new(File::open(&bundle.path).unwrap()).unwrap();
+        assert_eq!(archive.len(), 1);
+        let mut entry = archive.by_name("app.2026-01-02.log").unwrap();
+        let mut contents = String::new();
+        entry.read_to_string(&mut contents).unwrap();
+        assert!(contents.contains("sk-ant-...REDACTED"));
+        assert!(!contents.contains("abcdef123"));
+    }
+
+    #[test]
+    fn test_bundle_days_validated_and_clamped() {
+        assert_eq!(bundle_days(None), Ok(DEFAULT_BUNDLE_DAYS));
+        assert_eq!(bundle_days(Some(3)), Ok(3));
+        assert_eq!(bundle_days(Some(90)), Ok(MAX_BUNDLE_DAYS));
+        assert!(bundle_days(Some(0)).is_err());
+
+        // A shorter window leaves out older files
+        let logs = TempDir::new().unwrap();
+        let out = TempDir::new().unwrap();
+        write_log(logs.path(), "app.2026-01-05.log", &["INFO today"]);
+        let older = write_log(logs.path(), "app.2026-01-02.log", &["INFO three days ago"]);
+        let three_days_ago = SystemTime::now() - Duration::from_secs(3 * 24 * 60 * 60);
+        filetime::set_file_mtime(&older, filetime::FileTime::from_system_time(three_days_ago))
+            .unwrap();
+        assert_eq!(
+            export_logs_bundle(logs.path(), out.path(), 1, false)
+                .unwrap()
+                .file_count,
+            1
+        );
+        assert_eq!(
+            export_logs_bundle(logs.path(), out.path(), 7, false)
+                .unwrap()
+                .file_count,
+            2
+        );
+    }
+
+    #[test]
+    fn test_api_debug_log_excluded_unless_requested() {
+        let logs = TempDir::new().unwrap();
+        let out = TempDir::new().unwrap();
+        write_log(logs.path(), "app.2026-01-02.log", &["INFO normal line"]);
+        write_log(
+            logs.path(),
+            API_DEBUG_LOG_FILE,
+            &["INFO full prompt text for debugging"],
+        );
+
+        let tail = tail_logs(logs.path(), 10, None, None).unwrap();
+        assert_eq!(tail, vec!["INFO normal line"]);
+
+        let bundle =
+            export_logs_bundle(logs.path(), out.path(), DEFAULT_BUNDLE_DAYS, false).unwrap();
+        assert_eq!(bundle.file_count, 1);
+        let bundle =
+            export_logs_bundle(logs.path(), out.path(), DEFAULT_BUNDLE_DAYS, true).unwrap();
+        assert_eq!(bundle.file_count, 2);
+    }
+}
@@ -1,15 +1,19 @@
 //! Database migration module for SQLite → SQLCipher migration (Story 2.3)
 //!
-//! Provides atomic migration from unencrypted SQLite to encrypted SQLCipher database.
-//! Uses ATTACH DATABASE approach for single-transaction migration.
+//! Provides migration from unencrypted SQLite to encrypted SQLCipher database.
+//! Uses ATTACH DATABASE with one committed transaction per table.
 //!
 //! Key features:
-//! - Atomic migration (all-or-nothing with single transaction)
+//! - Resumable: each table is copied in its own transaction with idempotent
+//!   `INSERT OR REPLACE`, and committed tables are checkpointed in a sidecar file
+//!   (`upwork-researcher-encrypted.db.progress`) so an interrupted run skips them
 //! - ATTACH DATABASE for cross-database operations
-//! - Verification of row counts after migration
+//! - Verification of exact row counts after the last table, resumed or not
 //! - Rollback and backup restore on failure
 //! - Migration marker file prevents re-migration
+//! - Pre-flight free-space check before anything is written
 
+pub mod preflight;
 #[cfg(test)]
 mod tests;
 
@@ -94,6 +98,56 @@ pub struct MigrationVerification {
     pub old_db_path: String,
 }
 
+/// Checkpoint of a migration in progress, stored next to the encrypted database.
+/// Lists tables whose copy transaction has committed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MigrationProgress {
+    pub completed_tables: Vec<String>,
+    pub updated_at: String,
+    /// `source_fingerprint` of the old database the tables were copied from.
+    /// Checkpoints without one (or with a different one) are not resumed.
+    #[serde(default)]
+    pub source_fingerprint: Option<String>,
+}
+
+impl MigrationProgress {
+    fn new(source_fingerprint: &str) -> Self {
+        Self {
+            source_fingerprint: Some(source_fingerprint.to_string()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Tables copied by the migration, in order, with their copy statement.
+/// `INSERT OR REPLACE` on the primary key makes re-running a table after an
+/// interruption safe. Explicit columns handle schema evolution; settings and
+/// refinery history also replace rows the new database's own migrations created.
+const COPY_STEPS: [(&str, &str); 4] = [
+    (
+        "proposals",
+        "INSERT OR REPLACE INTO proposals (id, job_content, generated_text, created_at, updated_at, status)
+         SELECT id, job_content, generated_text, created_at, updated_at,
+                COALESCE(status, 'completed')
+         FROM old_db.proposals",
+    ),
+    (
+        "settings",
+        "INSERT OR REPLACE INTO settings (key, value, updated_at)
+         SELECT key, value, updated_at FROM old_db.settings",
+    ),
+    (
+        "job_posts",
+        "INSERT OR REPLACE INTO job_posts (id, url, raw_content, client_name, created_at)
+         SELECT id, url, raw_content, client_name, created_at FROM old_db.job_posts",
+    ),
+    (
+        "refinery_schema_history",
+        "INSERT OR REPLACE INTO refinery_schema_history (version, name, applied_on, checksum)
+         SELECT version, name, applied_on, checksum FROM old_db.refinery_schema_history",
+    ),
+];
+
 /// Row counts for verification
 #[derive(Debug, Clone)]
 struct RowCounts {
@@ -105,11 +159,12 @@ struct RowCounts {
 
 /// Migrate unencrypted SQLite database to encrypted SQLCipher database
 ///
-/// This function performs an atomic migration using ATTACH DATABASE:
-/// 1. Creates new encrypted SQLCipher database
+/// This function performs a resumable migration using ATTACH DATABASE:
+/// 1. Creates new encrypted SQLCipher database, or reopens a partial one that
+///    has a progress sidecar from an interrupted run
 /// 2. Runs migrations on new database (schema creation)
 /// 3. Attaches old unencrypted database
-/// 4. Copies all data in single transaction
+/// 4. Copies each table not yet checkpointed, one committed transaction per table
 /// 5. Verifies row counts match
 /// 6. Renames old database to .old extension
 /// 7. Creates migration marker file
@@ -152,22 +207,52 @@ pub fn migrate_to_encrypted_database(
         ));
     }
 
+    // Fail fast if the volume can't hold the encrypted copy and its WAL
+    let required = crate::disk_space::migration_required_bytes(&old_db_path);
+    crate::disk_space::ensure_available(app_data_dir, required)
+        .map_err(MigrationError::DiskSpaceError)?;
+
     // Subtask 1.3: Open old unencrypted database
     let old_conn = rusqlite::Connection::open(&old_db_path)
         .map_err(|e| MigrationError::OldDatabaseOpenFailed(e.to_string()))?;
 
-    // Subtask 1.4: Create new SQLCipher database with derived Argon2id key
-    // Use verify_passphrase which loads salt and derives key
-    // TD-3: Key wrapped in Zeroizing — extract via std::mem::take, Database::new re-wraps
-    let mut encryption_key = passphrase::verify_passphrase(passphrase, app_data_dir)
-        .map_err(|e| MigrationError::KeyDerivationFailed(e.to_string()))?;
+    // Resume only if both the partial database and its checkpoint survived, and
+    // the old database still holds the data the checkpointed tables came from
+    let fingerprint = source_fingerprint(&old_conn)?;
+    let mut progress = match load_migration_progress(app_data_dir) {
+        Some(progress)
+            if new_db_path.exists()
+                && progress.source_fingerprint.as_deref() == Some(fingerprint.as_str()) =>
+        {
+            tracing::info!(
+                "Resuming interrupted migration; already copied: {:?}",
+                progress.completed_tables
+            );
+            progress
+        }
+        Some(_) if new_db_path.exists() => {
+            tracing::warn!("Old database changed since the interrupted migration; starting over");
+            discard_partial_migration(app_data_dir);
+            MigrationProgress::new(&fingerprint)
+        }
+        _ => {
+            discard_partial_migration(app_data_dir);
+            MigrationProgress::new(&fingerprint)
+        }
+    };
 
-    // Create new encrypted database with key
-    let new_db = Database::new(
-        new_db_path.clone(),
-        Some(std::mem::take(&mut *encryption_key)),
-    )
-    .map_err(|e| MigrationError::NewDatabaseCreationFailed(e.to_string()))?;
+    // Subtask 1.4: Create new SQLCipher database with derived Argon2id key
+    let new_db = match open_encrypted_database(&new_db_path, passphrase, app_data_dir) {
+        Ok(db) => db,
+        Err(e) if !progress.completed_tables.is_empty() => {
+            // A partial database we can't open is useless; start over
+            tracing::warn!("Cannot reopen partial migration ({}); starting over", e);
+            discard_partial_migration(app_data_dir);
+            progress = MigrationProgress::new(&fingerprint);
+            open_encrypted_database(&new_db_path, passphrase, app_data_dir)?
+        }
+        Err(e) => return Err(e),
+    };
 
     // Lock new database connection for migration
     let new_conn = new_db
@@ -187,8 +272,17 @@ pub fn migrate_to_encrypted_database(
 
     tracing::info!("Attached old database for migration");
 
-    // Copy data in transaction and verify
-    let row_counts = copy_data_atomic(&new_conn, &old_conn)?;
+    // Copy remaining tables, then verify final counts
+    copy_tables_resumable(
+        &new_conn,
+        &old_conn,
+        &migration_progress_path(app_data_dir),
+        &mut progress,
+    )?;
+    let row_counts = verify_migration_counts(&new_conn, &old_conn).inspect_err(|_| {
+        // Counts are wrong; resuming from this checkpoint would keep them wrong
+        remove_migration_progress(app_data_dir);
+    })?;
 
     // Subtask 3.6: DETACH old database after successful copy
     new_conn
@@ -248,6 +342,8 @@ pub fn migrate_to_encrypted_database(
         MigrationError::RenameFailed(format!("Failed to rename new database: {}", e))
     })?;
 
+    remove_migration_progress(app_data_dir);
+
     // Subtask 4.2: Create migration marker file
     create_migration_marker(app_data_dir)?;
 
@@ -278,80 +374,183 @@ pub fn migrate_to_encrypted_database(
     Ok(metadata)
 }
 
-/// Copy all data from old_db to new encrypted database in single atomic transaction
-/// (Task 2: Atomic table copy, Task 3: Verification)
-fn copy_data_atomic(
+/// Derive the key from the passphrase and open (or create) the encrypted database
+fn open_encrypted_database(
+    new_db_path: &Path,
+    passphrase: &str,
+    app_data_dir: &Path,
+) -> Result<Database, MigrationError> {
+    // Use verify_passphrase which loads salt and derives key
+    // TD-3: Key wrapped in Zeroizing — extract via std::mem::take, Database::new re-wraps
+    let mut encryption_key = passphrase::verify_passphrase(passphrase, app_data_dir)
+        .map_err(|e| MigrationError::KeyDerivationFailed(e.to_string()))?;
+
+    Database::new(
+        new_db_path.to_path_buf(),
+        Some(std::mem::take(&mut *encryption_key)),
+    )
+    .map_err(|e| MigrationError::NewDatabaseCreationFailed(e.to_string()))
+}
+
+/// Copy each table from old_db not yet in `progress`, one committed transaction
+/// per table, checkpointing after every commit (Task 2: table copy)
+///
+/// A table interrupted mid-copy rolls back as a whole and is redone on resume.
+/// If the process dies between COMMIT and the checkpoint write, the idempotent
+/// copy statement simply runs again.
+fn copy_tables_resumable(
     new_conn: &rusqlite::Connection,
     old_conn: &rusqlite::Connection,
-) -> Result<RowCounts, MigrationError> {
-    // Subtask 2.1: Begin explicit EXCLUSIVE transaction
-    new_conn
-        .execute("BEGIN EXCLUSIVE TRANSACTION", [])
-        .map_err(|e| MigrationError::CopyFailed(format!("Failed to begin transaction: {}", e)))?;
-
-    // Attempt to copy all tables - rollback on any failure
-    let copy_result = (|| {
-        // Subtask 2.2: Copy proposals (explicit columns to handle schema evolution)
-        new_conn.execute(
-            "INSERT INTO proposals (id, job_content, generated_text, created_at, updated_at, status)
-             SELECT id, job_content, generated_text, created_at, updated_at,
-                    COALESCE(status, 'completed')
-             FROM old_db.proposals",
-            []
-        )
-        .map_err(|e| MigrationError::CopyFailed(format!("Failed to copy proposals: {}", e)))?;
+    progress_path: &Path,
+    progress: &mut MigrationProgress,
+) -> Result<(), MigrationError> {
+    for (table, copy_sql) in COPY_STEPS {
+        if progress.completed_tables.iter().any(|t| t == table) {
+            tracing::info!("Skipping {} (copied before interruption)", table);
+            continue;
+        }
 
-        // Subtask 2.3: Copy settings (use REPLACE to handle default settings from migrations)
         new_conn
-            .execute(
-                "INSERT OR REPLACE INTO settings (key, value, updated_at)
-             SELECT key, value, updated_at FROM old_db.settings",
-                [],
-            )
-            .map_err(|e| MigrationError::CopyFailed(format!("Failed to copy settings: {}", e)))?;
-
-        // Subtask 2.4: Copy job_posts
-        new_conn
-            .execute(
-                "INSERT INTO job_posts (id, url, raw_content, client_name, created_at)
-             SELECT id, url, raw_content, client_name, created_at FROM old_db.job_posts",
-                [],
-            )
-            .map_err(|e| MigrationError::CopyFailed(format!("Failed to copy job_posts: {}", e)))?;
-
-        // Subtask 2.5: Copy refinery_schema_history (use REPLACE to handle migrations already run)
-        new_conn.execute(
-            "INSERT OR REPLACE INTO refinery_schema_history (version, name, applied_on, checksum)
-             SELECT version, name, applied_on, checksum FROM old_db.refinery_schema_history",
-            []
-        )
-        .map_err(|e| MigrationError::CopyFailed(format!("Failed to copy refinery_schema_history: {}", e)))?;
-
-        Ok::<(), MigrationError>(())
-    })();
+            .execute("BEGIN IMMEDIATE TRANSACTION", [])
+            .map_err(|e| {
+                MigrationError::CopyFailed(format!("Failed to begin transaction: {}", e))
+            })?;
+
+        // Subtask 2.7: If the INSERT fails, ROLLBACK this table (Story 2.5)
+        if let Err(e) = new_conn.execute(copy_sql, []) {
+            tracing::error!("Copy of {} failed, executing rollback: {}", table, e);
+            new_conn.execute("ROLLBACK", []).map_err(|rollback_err| {
+                MigrationError::CopyFailed(format!("Rollback failed: {}", rollback_err))
+            })?;
+
+            // Verify old database is still intact after rollback
+            tracing::info!("Verifying old database integrity after rollback");
+            verify_old_database_intact(old_conn)?;
+
+            return Err(MigrationError::CopyFailed(format!(
+                "Failed to copy {}: {}",
+                table, e
+            )));
+        }
 
-    // Subtask 2.7: If any INSERT fails, ROLLBACK transaction (Story 2.5)
-    if let Err(e) = copy_result {
-        tracing::error!("Copy failed, executing rollback: {}", e);
-        new_conn.execute("ROLLBACK", []).map_err(|rollback_err| {
-            MigrationError::CopyFailed(format!("Rollback failed: {}", rollback_err))
+        // Subtask 2.6: Commit this table
+        new_conn.execute("COMMIT", []).map_err(|e| {
+            tracing::error!("Transaction commit failed: {}", e);
+            MigrationError::TransactionFailed(format!("Failed to commit {}: {}", table, e))
         })?;
 
-        // Verify old database is still intact after rollback
-        tracing::info!("Verifying old database integrity after rollback");
-        verify_old_database_intact(old_conn)?;
+        progress.completed_tables.push(table.to_string());
+        progress.updated_at = Utc::now().to_rfc3339();
+        save_migration_progress(progress_path, progress)?;
+    }
 
-        return Err(e);
+    Ok(())
+}
+
+/// SHA-256 over every row the migration copies, in rowid order. Unlike the
+/// file's size or mtime this is unaffected by WAL checkpoints, and it changes
+/// whenever a copied table's data does.
+fn source_fingerprint(old_conn: &rusqlite::Connection) -> Result<String, MigrationError> {
+    use rusqlite::types::ValueRef;
+    use sha2::{Digest, Sha256};
+
+    let read_failed = |e: rusqlite::Error| {
+        MigrationError::OldDatabaseOpenFailed(format!("Failed to read: {}", e))
+    };
+    let mut hasher = Sha256::new();
+    for (table, _) in COPY_STEPS {
+        hasher.update(table.as_bytes());
+        let mut stmt = old_conn
+            .prepare(&format!("SELECT * FROM {} ORDER BY rowid", table))
+            .map_err(read_failed)?;
+        let columns = stmt.column_count();
+        let mut rows = stmt.query([]).map_err(read_failed)?;
+        while let Some(row) = rows.next().map_err(read_failed)? {
+            for i in 0..columns {
+                match row.get_ref(i).map_err(read_failed)? {
+                    ValueRef::Null => hasher.update([0]),
+                    ValueRef::Integer(v) => {
+                        hasher.update([1]);
+                        hasher.update(v.to_le_bytes());
+                    }
+                    ValueRef::Real(v) => {
+                        hasher.update([2]);
+                        hasher.update(v.to_le_bytes());
+                    }
+                    ValueRef::Text(bytes) | ValueRef::Blob(bytes) => {
+                        hasher.update([3]);
+                        hasher.update((bytes.len() as u64).to_le_bytes());
+                        hasher.update(bytes);
+                    }
+                }
+            }
+        }
     }
+    Ok(hex::encode(hasher.finalize()))
+}
 
-    // Subtask 2.6: Commit transaction (all-or-nothing)
-    new_conn.execute("COMMIT", []).map_err(|e| {
-        tracing::error!("Transaction commit failed: {}", e);
-        MigrationError::TransactionFailed(format!("Failed to commit transaction: {}", e))
-    })?;
+fn migration_progress_path(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("upwork-researcher-encrypted.db.progress")
+}
+
+/// Read the checkpoint of an interrupted migration, if any. An unreadable
+/// checkpoint is treated as absent (the migration starts over).
+pub fn load_migration_progress(app_data_dir: &Path) -> Option<MigrationProgress> {
+    let content = fs::read_to_string(migration_progress_path(app_data_dir)).ok()?;
+    match serde_json::from_str(&content) {
+        Ok(progress) => Some(progress),
+        Err(e) => {
+            tracing::warn!("Ignoring unreadable migration progress file: {}", e);
+            None
+        }
+    }
+}
+
+/// Write the checkpoint atomically (temp file → rename)
+fn save_migration_progress(
+    progress_path: &Path,
+    progress: &MigrationProgress,
+) -> Result<(), MigrationError> {
+    let json = serde_json::to_string(progress)
+        .map_err(|e| MigrationError::IoError(format!("Failed to serialize progress: {}", e)))?;
+    let temp_path = progress_path.with_extension("progress.tmp");
+    fs::write(&temp_path, json)
+        .and_then(|_| fs::rename(&temp_path, progress_path))
+        .map_err(|e| MigrationError::IoError(format!("Failed to save migration progress: {}", e)))
+}
+
+fn remove_migration_progress(app_data_dir: &Path) {
+    let path = migration_progress_path(app_data_dir);
+    if path.exists() {
+        if let Err(e) = fs::remove_file(&path) {
+            tracing::warn!("Failed to remove migration progress file: {}", e);
+        }
+    }
+}
+
+/// Delete a partial encrypted database (with its WAL/SHM) and its checkpoint
+fn discard_partial_migration(app_data_dir: &Path) {
+    let new_db_path = app_data_dir.join("upwork-researcher-encrypted.db");
+    for path in [
+        new_db_path.with_extension("db-wal"),
+        new_db_path.with_extension("db-shm"),
+    ] {
+        if path.exists() {
+            fs::remove_file(&path).ok();
+        }
+    }
+
+    if new_db_path.exists() {
+        match fs::remove_file(&new_db_path) {
+            Ok(_) => tracing::warn!(
+                "Cleaned up incomplete encrypted database: {}",
+                new_db_path.display()
+            ),
+            Err(e) => tracing::error!("Failed to clean up incomplete encrypted database: {}", e),
+        }
+    }
 
-    // Task 3: Verify data integrity after migration
-    verify_migration_counts(new_conn, old_conn)
+    remove_migration_progress(app_data_dir);
 }
 
 /// Verify row counts match between old and new databases (Subtasks 3.1-3.5)
@@ -527,19 +726,18 @@ pub fn is_migration_complete(app_data_dir: &Path) -> bool {
 
 /// Clean up incomplete encrypted database on migration failure (Story 2-5)
 ///
-/// Removes the partially created encrypted database file to prevent corruption.
+/// Keeps the partial database when a checkpoint shows committed tables, so the
+/// next attempt resumes instead of starting over. Otherwise removes the
+/// partially created encrypted database file to prevent corruption.
 pub fn cleanup_failed_migration(app_data_dir: &Path) {
-    let new_db_path = app_data_dir.join("upwork-researcher-encrypted.db");
-
-    if new_db_path.exists() {
-        match fs::remove_file(&new_db_path) {
-            Ok(_) => tracing::warn!(
-                "Cleaned up incomplete encrypted database: {}",
-                new_db_path.display()
-            ),
-            Err(e) => tracing::error!("Failed to clean up incomplete encrypted database: {}", e),
-        }
+    let resumable = load_migration_progress(app_data_dir)
+        .is_some_and(|progress| !progress.completed_tables.is_empty());
+    if resumable {
+        tracing::info!("Keeping partial encrypted database so the migration can resume");
+        return;
     }
+
+    discard_partial_migration(app_data_dir);
 }
 
 /// Delete old unencrypted database file (Story 2.4, Task 2)
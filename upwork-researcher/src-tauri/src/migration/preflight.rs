@@ -0,0 +1,187 @@
+//! Encryption round-trip preflight (Story 1.6 spike, productized)
+//!
+//! Lets cautious users prove SQLCipher works on their machine before running the
+//! real migration. A throwaway encrypted database is created in a private
+//! directory, a random token is written and read back with the same key, and the
+//! checks below must all pass:
+//! - `PRAGMA cipher_version` reports a version (SQLCipher is linked, not plain SQLite)
+//! - the file on disk doesn't start with the plaintext SQLite header
+//! - the database can't be read with a different key
+//!
+//! The directory is removed when the check finishes, whether it passed or not.
+
+use rusqlite::{Connection, OptionalExtension};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// First 16 bytes of every unencrypted SQLite database file
+const PLAINTEXT_SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+/// Outcome of `test_encryption_roundtrip`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptionRoundtripResult {
+    pub success: bool,
+    /// SQLCipher version reported by `PRAGMA cipher_version`; None if SQLCipher isn't active
+    pub cipher_version: Option<String>,
+    pub message: String,
+    pub duration_ms: u64,
+}
+
+/// Removes the preflight directory on drop, so cleanup also happens on early returns
+struct TempDirGuard(PathBuf);
+
+impl Drop for TempDirGuard {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_dir_all(&self.0) {
+            tracing::warn!(
+                "Failed to remove encryption preflight directory {}: {}",
+                self.0.display(),
+                e
+            );
+        }
+    }
+}
+
+/// Run the encryption round-trip in a fresh directory under `parent_dir`
+pub fn run_encryption_roundtrip(parent_dir: &Path) -> EncryptionRoundtripResult {
+    let started = Instant::now();
+    let mut cipher_version = None;
+    let outcome = roundtrip(parent_dir, &mut cipher_version);
+    let duration_ms = started.elapsed().as_millis() as u64;
+
+    match outcome {
+        Ok(()) => {
+            let version = cipher_version.clone().unwrap_or_default();
+            tracing::info!(cipher_version = %version, duration_ms, "Encryption preflight passed");
+            EncryptionRoundtripResult {
+                success: true,
+                cipher_version,
+                message: format!("Encryption works on this machine (SQLCipher {}).", version),
+                duration_ms,
+            }
+        }
+        Err(message) => {
+            tracing::warn!(duration_ms, "Encryption preflight failed: {}", message);
+            EncryptionRoundtripResult {
+                success: false,
+                cipher_version,
+                message,
+                duration_ms,
+            }
+        }
+    }
+}
+
+fn roundtrip(parent_dir: &Path, cipher_version: &mut Option<String>) -> Result<(), String> {
+    let dir = parent_dir.join(format!(".encryption-preflight-{}", uuid::Uuid::new_v4()));
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Could not create a temporary test directory: {}", e))?;
+    let _cleanup = TempDirGuard(dir.clone());
+    let db_path = dir.join("preflight.db");
+
+    let key: [u8; 32] = rand::random();
+    let token = uuid::Uuid::new_v4().to_string();
+
+    {
+        let conn = open_with_key(&db_path, &key)?;
+        *cipher_version = conn
+            .query_row("PRAGMA cipher_version;", [], |row| row.get::<_, String>(0))
+            .optional()
+            .map_err(|e| format!("Could not query the SQLCipher version: {}", e))?
+            .filter(|version| !version.trim().is_empty());
+        if cipher_version.is_none() {
+            return Err(
+                "SQLCipher is not active in this build: the database library \
+                 would store data unencrypted. Do not migrate; reinstall the app."
+                    .to_string(),
+            );
+        }
+
+        conn.execute_batch("CREATE TABLE preflight (value TEXT NOT NULL);")
+            .and_then(|_| conn.execute("INSERT INTO preflight (value) VALUES (?1)", [&token]))
+            .map_err(|e| format!("Could not write to the encrypted test database: {}", e))?;
+    }
+
+    let mut header = [0u8; 16];
+    let file = fs::read(&db_path)
+        .map_err(|e| format!("Could not read the encrypted test database: {}", e))?;
+    header.copy_from_slice(file.get(..16).ok_or("Encrypted test database is empty")?);
+    if &header == PLAINTEXT_SQLITE_HEADER {
+        return Err(
+            "The test database was written in plaintext even though a key was set. \
+             Do not migrate; reinstall the app."
+                .to_string(),
+        );
+    }
+
+    let read_back: String = open_with_key(&db_path, &key)?
+        .query_row("SELECT value FROM preflight", [], |row| row.get(0))
+        .map_err(|e| {
+            format!(
+                "Could not read back from the encrypted test database: {}",
+                e
+            )
+        })?;
+    if read_back != token {
+        return Err("Data read back from the encrypted test database did not match.".to_string());
+    }
+
+    let wrong_key: [u8; 32] = rand::random();
+    let readable_with_wrong_key = open_with_key(&db_path, &wrong_key)
+        .and_then(|conn| {
+            conn.query_row("SELECT COUNT(*) FROM preflight", [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .map_err(|e| e.to_string())
+        })
+        .is_ok();
+    if readable_with_wrong_key {
+        return Err("The test database could be read without the right key.".to_string());
+    }
+
+    Ok(())
+}
+
+/// Open `path` with a raw 32-byte key, using the same pragmas as the app database
+fn open_with_key(path: &Path, key: &[u8; 32]) -> Result<Connection, String> {
+    let conn = Connection::open(path)
+        .map_err(|e| format!("Could not open the encrypted test database: {}", e))?;
+    let pragma = zeroize::Zeroizing::new(format!(
+        "PRAGMA key = \"x'{}'\"; PRAGMA cipher_compatibility = 4;",
+        hex::encode(key)
+    ));
+    conn.execute_batch(&pragma)
+        .map_err(|e| format!("Could not set the encryption key: {}", e))?;
+    Ok(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_passes_and_cleans_up() {
+        let parent = tempfile::tempdir().unwrap();
+        let result = run_encryption_roundtrip(parent.path());
+
+        assert!(result.success, "{}", result.message);
+        assert!(result.cipher_version.is_some());
+        assert!(result.message.contains("SQLCipher"));
+        // Nothing left behind
+        assert_eq!(fs::read_dir(parent.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_roundtrip_reports_unwritable_location() {
+        let parent = tempfile::tempdir().unwrap();
+        let not_a_dir = parent.path().join("file");
+        fs::write(&not_a_dir, "x").unwrap();
+
+        let result = run_encryption_roundtrip(&not_a_dir);
+        assert!(!result.success);
+        assert!(result.message.contains("temporary test directory"));
+    }
+}
@@ -572,3 +572,159 @@ fn test_recovery_error_messages_include_safety() {
         error_message
     );
 }
+
+/// Set up an old database and simulate a migration that committed proposals,
+/// then died. Returns the backup path to pass to the next attempt.
+fn interrupt_migration_after_proposals(app_data_dir: &Path, passphrase: &str) -> PathBuf {
+    let old_db_path = app_data_dir.join("upwork-researcher.db");
+    create_test_database(&old_db_path).unwrap();
+    let salt = passphrase::generate_random_salt().unwrap();
+    passphrase::store_salt(&salt, app_data_dir).unwrap();
+    let backup_path = app_data_dir.join("backup.json");
+    std::fs::write(&backup_path, "{}").unwrap();
+
+    // Simulate a run that committed proposals, then died
+    let new_db_path = app_data_dir.join("upwork-researcher-encrypted.db");
+    {
+        let new_db = open_encrypted_database(&new_db_path, passphrase, app_data_dir).unwrap();
+        let new_conn = new_db.conn.lock().unwrap();
+        let old_conn = Connection::open(&old_db_path).unwrap();
+        new_conn
+            .execute(
+                &format!(
+                    "ATTACH DATABASE '{}' AS old_db KEY ''",
+                    old_db_path.display()
+                ),
+                [],
+            )
+            .unwrap();
+        // Copy only proposals (the fixture's fake refinery history would stop the
+        // partial database from reopening), then checkpoint just that table
+        let mut progress = MigrationProgress {
+            completed_tables: COPY_STEPS[1..].iter().map(|(t, _)| t.to_string()).collect(),
+            updated_at: String::new(),
+            source_fingerprint: Some(source_fingerprint(&old_conn).unwrap()),
+        };
+        copy_tables_resumable(
+            &new_conn,
+            &old_conn,
+            &migration_progress_path(app_data_dir),
+            &mut progress,
+        )
+        .unwrap();
+        progress.completed_tables = vec!["proposals".to_string()];
+        save_migration_progress(&migration_progress_path(app_data_dir), &progress).unwrap();
+        // Mark the copied rows so a re-copy would show
+        new_conn
+            .execute("UPDATE proposals SET generated_text = 'resumed'", [])
+            .unwrap();
+    }
+
+    backup_path
+}
+
+/// Test an interrupted migration resumes from its checkpoint, skipping committed tables
+#[test]
+fn test_interrupted_migration_resumes() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_data_dir = temp_dir.path();
+    let passphrase = "test_passphrase_123!";
+    let backup_path = interrupt_migration_after_proposals(app_data_dir, passphrase);
+    let new_db_path = app_data_dir.join("upwork-researcher-encrypted.db");
+
+    // A failure with committed tables keeps the partial database for resume
+    cleanup_failed_migration(app_data_dir);
+    assert!(new_db_path.exists());
+    assert_eq!(
+        load_migration_progress(app_data_dir)
+            .unwrap()
+            .completed_tables,
+        vec!["proposals"]
+    );
+
+    let metadata = migrate_to_encrypted_database(app_data_dir, passphrase, &backup_path).unwrap();
+    assert_eq!(metadata.proposals_count, 5);
+    assert_eq!(metadata.job_posts_count, 3); // re-copied
+    assert!(load_migration_progress(app_data_dir).is_none());
+
+    // Proposals were skipped, not copied again. Raw connection: the fixture's fake
+    // refinery history would trip Database::new's migration check.
+    let key = passphrase::verify_passphrase(passphrase, app_data_dir).unwrap();
+    let conn = Connection::open(app_data_dir.join("upwork-researcher.db")).unwrap();
+    conn.execute_batch(&format!("PRAGMA key = \"x'{}'\";", hex::encode(&*key)))
+        .unwrap();
+    let resumed: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM proposals WHERE generated_text = 'resumed'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(resumed, 5);
+}
+
+/// Test a checkpoint is not resumed once the old database has changed: the
+/// skipped tables would otherwise keep data that no longer matches it
+#[test]
+fn test_interrupted_migration_restarts_when_source_changed() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_data_dir = temp_dir.path();
+    let passphrase = "test_passphrase_123!";
+    let backup_path = interrupt_migration_after_proposals(app_data_dir, passphrase);
+
+    Connection::open(app_data_dir.join("upwork-researcher.db"))
+        .unwrap()
+        .execute(
+            "UPDATE proposals SET generated_text = 'edited' WHERE id = 1",
+            [],
+        )
+        .unwrap();
+
+    let metadata = migrate_to_encrypted_database(app_data_dir, passphrase, &backup_path).unwrap();
+    assert_eq!(metadata.proposals_count, 5);
+
+    // Everything was copied again from the changed database
+    let key = passphrase::verify_passphrase(passphrase, app_data_dir).unwrap();
+    let conn = Connection::open(app_data_dir.join("upwork-researcher.db")).unwrap();
+    conn.execute_batch(&format!("PRAGMA key = \"x'{}'\";", hex::encode(&*key)))
+        .unwrap();
+    let (resumed, edited): (i64, i64) = conn
+        .query_row(
+            "SELECT COUNT(*) FILTER (WHERE generated_text = 'resumed'),
+                    COUNT(*) FILTER (WHERE generated_text = 'edited')
+             FROM proposals",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!((resumed, edited), (0, 1));
+}
+
+/// Test a checkpoint without its partial database (or vice versa) starts over
+#[test]
+fn test_orphaned_migration_state_starts_over() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_data_dir = temp_dir.path();
+
+    let progress = MigrationProgress {
+        completed_tables: vec!["proposals".to_string()],
+        updated_at: Utc::now().to_rfc3339(),
+        source_fingerprint: None,
+    };
+    save_migration_progress(&migration_progress_path(app_data_dir), &progress).unwrap();
+    assert_eq!(load_migration_progress(app_data_dir), Some(progress));
+
+    // Unreadable checkpoint is ignored
+    std::fs::write(migration_progress_path(app_data_dir), "not json").unwrap();
+    assert!(load_migration_progress(app_data_dir).is_none());
+
+    // Partial database with no usable checkpoint is deleted along with it
+    std::fs::write(
+        app_data_dir.join("upwork-researcher-encrypted.db"),
+        "incomplete data",
+    )
+    .unwrap();
+    cleanup_failed_migration(app_data_dir);
+    assert!(!app_data_dir.join("upwork-researcher-encrypted.db").exists());
+    assert!(!migration_progress_path(app_data_dir).exists());
+}
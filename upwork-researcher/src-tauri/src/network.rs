@@ -1,6 +1,8 @@
 // Network allowlist enforcement module
 // Implements AR-14: Dual-layer network security (Rust-side domain validation)
 
+use rusqlite::Connection;
+use std::sync::{OnceLock, RwLock};
 use tauri::{AppHandle, Emitter, Manager};
 use thiserror::Error;
 
@@ -13,6 +15,254 @@ const ALLOWED_DOMAINS: &[&str] = &[
     "raw.githubusercontent.com", // Story 10.1: Remote config fetch endpoint
 ];
 
+/// Settings key: Anthropic-compatible API base URL (gateway or proxy, e.g. LiteLLM)
+pub const API_BASE_URL_SETTING: &str = "api_base_url";
+/// Settings key: extra allowlisted hosts, comma-separated. A custom API base
+/// URL's host must be added here, or its requests are blocked.
+pub const ALLOWLIST_EXTRA_SETTING: &str = "network_allowlist_extra";
+/// Settings key: dev flag ("true"/"false") allowing a plain-http API base URL
+/// on an extra allowlisted host. Off by default: the API key is sent in cleartext.
+pub const ALLOW_INSECURE_API_SETTING: &str = "allow_insecure_api";
+
+pub const DEFAULT_API_BASE_URL: &str = "https://api.anthropic.com";
+
+/// User-configurable network settings, loaded from the settings table at
+/// startup/unlock and updated by `set_setting`.
+#[derive(Debug, Clone)]
+pub struct NetworkSettings {
+    pub api_base_url: String,
+    pub extra_domains: Vec<String>,
+    pub allow_insecure_api: bool,
+}
+
+impl Default for NetworkSettings {
+    fn default() -> Self {
+        Self {
+            api_base_url: DEFAULT_API_BASE_URL.to_string(),
+            extra_domains: Vec::new(),
+            allow_insecure_api: false,
+        }
+    }
+}
+
+static NETWORK_SETTINGS: OnceLock<RwLock<NetworkSettings>> = OnceLock::new();
+
+fn network_settings() -> &'static RwLock<NetworkSettings> {
+    NETWORK_SETTINGS.get_or_init(|| RwLock::new(NetworkSettings::default()))
+}
+
+fn current_settings() -> NetworkSettings {
+    match network_settings().read() {
+        Ok(settings) => settings.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+/// Configured API base URL (no trailing slash)
+pub fn api_base_url() -> String {
+    current_settings().api_base_url
+}
+
+/// Messages endpoint under the configured API base URL
+pub fn messages_url() -> String {
+    format!("{}/v1/messages", api_base_url())
+}
+
+/// Models list endpoint under the configured API base URL (API key verification)
+pub fn models_url() -> String {
+    format!("{}/v1/models?limit=1", api_base_url())
+}
+
+/// Is `host` allowlisted (built-in or user-added)?
+pub fn is_domain_allowed(host: &str) -> bool {
+    current_settings().is_domain_allowed(host)
+}
+
+/// Warning when `base_url`'s host is not allowlisted, so its requests would be blocked
+pub fn api_base_url_warning(base_url: &str) -> Option<String> {
+    let host = url::Url::parse(base_url).ok()?.host_str()?.to_string();
+    (!is_domain_allowed(&host)).then(|| {
+        format!(
+            "Host {} is not in the network allowlist; add it to {} or API requests will be blocked",
+            host, ALLOWLIST_EXTRA_SETTING
+        )
+    })
+}
+
+/// Validate and normalize an `api_base_url` value (trailing slash removed).
+/// Must be https, or http when `allow_insecure` is set; no query or fragment.
+pub fn validate_api_base_url(value: &str, allow_insecure: bool) -> Result<String, String> {
+    let parsed =
+        url::Url::parse(value.trim()).map_err(|e| format!("Invalid API base URL: {}", e))?;
+    if parsed.host_str().is_none() {
+        return Err("API base URL must include a host".to_string());
+    }
+    match parsed.scheme() {
+        "https" => {}
+        "http" if allow_insecure => {}
+        "http" => {
+            return Err(
+                "API base URL must use https (plain http sends the API key in cleartext; \
+                 set allow_insecure_api for local development)"
+                    .to_string(),
+            )
+        }
+        other => return Err(format!("API base URL must use https, not {}", other)),
+    }
+    if parsed.query().is_some() || parsed.fragment().is_some() {
+        return Err("API base URL must not include a query or fragment".to_string());
+    }
+    if !parsed.username().is_empty() || parsed.password().is_some() {
+        return Err("API base URL must not include credentials".to_string());
+    }
+    Ok(parsed.as_str().trim_end_matches('/').to_string())
+}
+
+/// Validate a `network_allowlist_extra` value: comma-separated hostnames (no scheme or path)
+pub fn validate_allowlist_extra(value: &str) -> Result<Vec<String>, String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|host| !host.is_empty())
+        .map(|host| {
+            let host = host.to_lowercase();
+            match url::Host::parse(&host) {
+                Ok(url::Host::Domain(_)) if !host.contains(['/', ':', '@']) => Ok(host),
+                Ok(url::Host::Ipv4(_)) => Ok(host),
+                _ => Err(format!("Invalid allowlist host: {}", host)),
+            }
+        })
+        .collect()
+}
+
+impl NetworkSettings {
+    pub fn is_domain_allowed(&self, host: &str) -> bool {
+        ALLOWED_DOMAINS.contains(&host) || self.extra_domains.iter().any(|d| d == host)
+    }
+
+    /// Allowlist and scheme check for `url` under these settings
+    pub fn validate_url(&self, url: &str) -> Result<(), NetworkError> {
+        // Parse URL
+        let parsed = url::Url::parse(url)
+            .map_err(|e| NetworkError::InvalidUrl(format!("{}: {}", e, url)))?;
+
+        // Extract host
+        let host = parsed
+            .host_str()
+            .ok_or_else(|| NetworkError::InvalidUrl(format!("No host in URL: {}", url)))?;
+
+        // Check against allowlist
+        if !self.is_domain_allowed(host) {
+            tracing::warn!(
+                domain = %host,
+                url = %url,
+                "Blocked network request to unauthorized domain"
+            );
+            return Err(NetworkError::BlockedDomain(host.to_string()));
+        }
+
+        // Enforce HTTPS to prevent downgrade attacks (Rust-side calls bypass CSP).
+        // Dev exception: plain http to a user-added host with allow_insecure_api.
+        let scheme = parsed.scheme();
+        let insecure_allowed = scheme == "http"
+            && self.allow_insecure_api
+            && self.extra_domains.iter().any(|d| d == host);
+        if scheme != "https" && !insecure_allowed {
+            tracing::warn!(
+                scheme = %scheme,
+                domain = %host,
+                "Blocked non-HTTPS request - downgrade attack prevention"
+            );
+            return Err(NetworkError::BlockedDomain(format!(
+                "{}:// not allowed (HTTPS required)",
+                scheme
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Webhook URLs (`webhook_url`) follow `validate_url`, except that plain http
+    /// is accepted for an allowlisted loopback host: the request never leaves the
+    /// machine, so there is nothing to downgrade.
+    pub fn validate_webhook_url(&self, url: &str) -> Result<(), NetworkError> {
+        let parsed = url::Url::parse(url)
+            .map_err(|e| NetworkError::InvalidUrl(format!("{}: {}", e, url)))?;
+        let loopback = match parsed.host() {
+            Some(url::Host::Domain(host)) => host.eq_ignore_ascii_case("localhost"),
+            Some(url::Host::Ipv4(ip)) => ip.is_loopback(),
+            Some(url::Host::Ipv6(ip)) => ip.is_loopback(),
+            None => false,
+        };
+        if parsed.scheme() == "http" && loopback {
+            let host = parsed.host_str().unwrap_or_default();
+            if !self.is_domain_allowed(host) {
+                return Err(NetworkError::BlockedDomain(host.to_string()));
+            }
+            return Ok(());
+        }
+        self.validate_url(url)
+    }
+
+    /// Apply one saved setting. Returns false if `key` is not a network setting.
+    /// Invalid values are logged and fall back to the default.
+    pub fn apply_setting(&mut self, key: &str, value: Option<&str>) -> bool {
+        let value = value.map(str::trim).filter(|v| !v.is_empty());
+        match key {
+            API_BASE_URL_SETTING => {
+                self.api_base_url = value
+                    .map(|v| {
+                        validate_api_base_url(v, self.allow_insecure_api).unwrap_or_else(|e| {
+                            tracing::warn!("Ignoring api_base_url setting: {}", e);
+                            DEFAULT_API_BASE_URL.to_string()
+                        })
+                    })
+                    .unwrap_or_else(|| DEFAULT_API_BASE_URL.to_string());
+            }
+            ALLOWLIST_EXTRA_SETTING => {
+                self.extra_domains = value
+                    .map(|v| {
+                        validate_allowlist_extra(v).unwrap_or_else(|e| {
+                            tracing::warn!("Ignoring network_allowlist_extra setting: {}", e);
+                            Vec::new()
+                        })
+                    })
+                    .unwrap_or_default();
+            }
+            ALLOW_INSECURE_API_SETTING => {
+                self.allow_insecure_api = value == Some("true");
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Apply a saved setting to the process-wide network settings.
+/// Returns false if `key` is not a network setting.
+pub fn apply_network_setting(key: &str, value: Option<&str>) -> bool {
+    let mut settings = match network_settings().write() {
+        Ok(settings) => settings,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    settings.apply_setting(key, value)
+}
+
+/// Load all network settings (startup and after unlock).
+/// The insecure flag is applied first since it affects base URL validation.
+pub fn load_network_settings(conn: &Connection) {
+    for key in [
+        ALLOW_INSECURE_API_SETTING,
+        ALLOWLIST_EXTRA_SETTING,
+        API_BASE_URL_SETTING,
+    ] {
+        let value = crate::db::queries::settings::get_setting(conn, key)
+            .ok()
+            .flatten();
+        apply_network_setting(key, value.as_deref());
+    }
+}
+
 /// Network validation errors
 #[derive(Debug, Error)]
 pub enum NetworkError {
@@ -43,44 +293,12 @@ pub enum NetworkError {
 /// assert!(validate_url("https://evil.com/exfiltrate").is_err());
 /// ```
 pub fn validate_url(url: &str) -> Result<(), NetworkError> {
-    // NOTE: Currently all call sites validate the same ANTHROPIC_API_URL constant,
-    // so this validation is effectively a compile-time sanity check.
-    // Runtime enforcement comes from CSP in the WebView layer.
-
-    // Parse URL
-    let parsed =
-        url::Url::parse(url).map_err(|e| NetworkError::InvalidUrl(format!("{}: {}", e, url)))?;
-
-    // Extract host
-    let host = parsed
-        .host_str()
-        .ok_or_else(|| NetworkError::InvalidUrl(format!("No host in URL: {}", url)))?;
-
-    // Check against allowlist
-    if !ALLOWED_DOMAINS.contains(&host) {
-        tracing::warn!(
-            domain = %host,
-            url = %url,
-            "Blocked network request to unauthorized domain"
-        );
-        return Err(NetworkError::BlockedDomain(host.to_string()));
-    }
-
-    // Enforce HTTPS to prevent downgrade attacks (Rust-side calls bypass CSP)
-    let scheme = parsed.scheme();
-    if scheme != "https" {
-        tracing::warn!(
-            scheme = %scheme,
-            domain = %host,
-            "Blocked non-HTTPS request - downgrade attack prevention"
-        );
-        return Err(NetworkError::BlockedDomain(format!(
-            "{}:// not allowed (HTTPS required)",
-            scheme
-        )));
-    }
+    current_settings().validate_url(url)
+}
 
-    Ok(())
+/// `NetworkSettings::validate_webhook_url` under the current settings
+pub fn validate_webhook_url(url: &str) -> Result<(), NetworkError> {
+    current_settings().validate_webhook_url(url)
 }
 
 /// Helper to emit network:blocked event and record blocked request.
@@ -197,4 +415,105 @@ mod tests {
             "ANTHROPIC_API_URL constant should pass validation"
         );
     }
+
+    #[test]
+    fn test_validate_api_base_url() {
+        assert_eq!(
+            validate_api_base_url("https://gateway.example.com/anthropic/", false).unwrap(),
+            "https://gateway.example.com/anthropic"
+        );
+        assert!(validate_api_base_url("http://localhost:4000", false)
+            .unwrap_err()
+            .contains("https"));
+        assert_eq!(
+            validate_api_base_url("http://localhost:4000", true).unwrap(),
+            "http://localhost:4000"
+        );
+        assert!(validate_api_base_url("ftp://gateway.example.com", true).is_err());
+        assert!(validate_api_base_url("https://gateway.example.com?x=1", false).is_err());
+        assert!(validate_api_base_url("https://user:pw@gateway.example.com", false).is_err());
+        assert!(validate_api_base_url("gateway.example.com", false).is_err());
+    }
+
+    #[test]
+    fn test_custom_base_url_requires_allowlisted_host() {
+        let mut settings = NetworkSettings::default();
+        settings.apply_setting(API_BASE_URL_SETTING, Some("https://gateway.example.com/"));
+        assert_eq!(settings.api_base_url, "https://gateway.example.com");
+
+        let url = format!("{}/v1/messages", settings.api_base_url);
+        assert!(matches!(
+            settings.validate_url(&url),
+            Err(NetworkError::BlockedDomain(d)) if d == "gateway.example.com"
+        ));
+
+        settings.apply_setting(
+            ALLOWLIST_EXTRA_SETTING,
+            Some("Gateway.Example.com, localhost"),
+        );
+        assert!(settings.validate_url(&url).is_ok());
+        // Built-in domains still allowed
+        assert!(settings
+            .validate_url("https://api.anthropic.com/v1/messages")
+            .is_ok());
+
+        // Plain http only with the dev flag, and only for user-added hosts
+        assert!(settings
+            .validate_url("http://localhost/v1/messages")
+            .is_err());
+        settings.apply_setting(ALLOW_INSECURE_API_SETTING, Some("true"));
+        assert!(settings
+            .validate_url("http://localhost/v1/messages")
+            .is_ok());
+        assert!(settings
+            .validate_url("http://api.anthropic.com/v1/messages")
+            .is_err());
+
+        // Invalid or cleared values fall back to the default endpoint
+        settings.apply_setting(API_BASE_URL_SETTING, Some("not a url"));
+        assert_eq!(settings.api_base_url, DEFAULT_API_BASE_URL);
+        assert!(!settings.apply_setting("unrelated", Some("x")));
+    }
+
+    #[test]
+    fn test_webhook_url_allows_http_only_to_allowlisted_loopback() {
+        let mut settings = NetworkSettings::default();
+        assert!(matches!(
+            settings.validate_webhook_url("http://localhost:8080/hook"),
+            Err(NetworkError::BlockedDomain(d)) if d == "localhost"
+        ));
+
+        settings.apply_setting(
+            ALLOWLIST_EXTRA_SETTING,
+            Some("localhost,127.0.0.1,hooks.example.com"),
+        );
+        assert!(settings
+            .validate_webhook_url("http://localhost:8080/hook")
+            .is_ok());
+        assert!(settings
+            .validate_webhook_url("http://127.0.0.1:9000/milestones")
+            .is_ok());
+        // Non-loopback hosts still need HTTPS
+        assert!(settings
+            .validate_webhook_url("http://hooks.example.com/in")
+            .is_err());
+        assert!(settings
+            .validate_webhook_url("https://hooks.example.com/in")
+            .is_ok());
+        assert!(settings
+            .validate_webhook_url("https://elsewhere.example.com/in")
+            .is_err());
+        assert!(settings.validate_webhook_url("not a url").is_err());
+    }
+
+    #[test]
+    fn test_validate_allowlist_extra() {
+        assert_eq!(
+            validate_allowlist_extra(" a.example.com ,B.example.com,,").unwrap(),
+            vec!["a.example.com", "b.example.com"]
+        );
+        assert!(validate_allowlist_extra("https://a.example.com").is_err());
+        assert!(validate_allowlist_extra("a.example.com/path").is_err());
+        assert!(validate_allowlist_extra("").unwrap().is_empty());
+    }
 }
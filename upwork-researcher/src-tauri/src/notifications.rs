@@ -0,0 +1,545 @@
+//! Milestone notifications: desktop alerts and an optional local webhook
+//!
+//! Rules, all off by default and read from settings:
+//! - `notify_on_hired`: a proposal's outcome is set to hired (once per proposal)
+//! - `notify_strategy_threshold_pct`: a hook strategy's response rate reaches the
+//!   percentage over at least `MIN_STRATEGY_SAMPLE` recorded outcomes (once per
+//!   strategy and threshold value)
+//!
+//! Rules run after `update_proposal_outcome` and after the weekly analytics
+//! (`get_weekly_activity`). Each alert is shown as a desktop notification and, when
+//! `webhook_url` is set, POSTed as JSON with a `WEBHOOK_TIMEOUT` cap and no retries.
+//! The URL must pass the network allowlist (`network::validate_webhook_url`).
+//! Every alert is recorded in `notification_log` with what happened on each channel;
+//! delivery failures end up there and in the log, never in the triggering command.
+
+use crate::db::queries::notification_log::{self, NewNotification, NotificationLogEntry};
+use crate::db::queries::proposals::percentage;
+use crate::db::{AppDatabase, Database};
+use crate::http::TrackedSend;
+use crate::network::NetworkError;
+use crate::settings::schema::{get_typed, Key};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use std::future::Future;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+
+pub const NOTIFY_ON_HIRED_SETTING: &str = "notify_on_hired";
+pub const STRATEGY_THRESHOLD_SETTING: &str = "notify_strategy_threshold_pct";
+pub const WEBHOOK_URL_SETTING: &str = "webhook_url";
+
+/// Whole-request cap for a webhook POST
+pub const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Recorded outcomes a strategy needs before its response rate can trigger an alert
+pub const MIN_STRATEGY_SAMPLE: i64 = 5;
+
+pub const RULE_HIRED: &str = "hired";
+pub const RULE_STRATEGY_THRESHOLD: &str = "strategy_threshold";
+
+// Channel statuses in notification_log
+const STATUS_SENT: &str = "sent";
+const STATUS_FAILED: &str = "failed";
+const STATUS_BLOCKED: &str = "blocked";
+const STATUS_NOT_CONFIGURED: &str = "not_configured";
+const STATUS_PENDING: &str = "pending";
+
+/// A milestone worth an alert; also the JSON body POSTed to the webhook
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Milestone {
+    pub rule: &'static str,
+    pub dedupe_key: String,
+    pub title: String,
+    pub body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proposal_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<String>,
+    /// Response rate in percent (strategy milestones)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_rate: Option<f64>,
+}
+
+/// Validate a `webhook_url` write; empty clears it
+pub fn validate_webhook_url_setting(value: &str) -> Result<(), String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(());
+    }
+    crate::network::validate_webhook_url(value)
+        .map_err(|e| format!("{} (add the host to network_allowlist_extra first)", e))
+}
+
+fn db_err(e: rusqlite::Error) -> String {
+    format!("Failed to evaluate notification rules: {}", e)
+}
+
+/// The hired milestone for a proposal whose outcome was just set, if the rule is on
+/// and it has not fired for this proposal before
+pub fn hired_milestone(
+    conn: &Connection,
+    proposal_id: i64,
+    outcome_status: &str,
+) -> Result<Option<Milestone>, String> {
+    if outcome_status != "hired" || !get_typed::<bool>(conn, Key::NotifyOnHired)? {
+        return Ok(None);
+    }
+    let dedupe_key = format!("{}:{}", RULE_HIRED, proposal_id);
+    if notification_log::was_notified(conn, &dedupe_key).map_err(db_err)? {
+        return Ok(None);
+    }
+
+    let strategy: Option<String> = conn
+        .query_row(
+            "SELECT hook_strategy_id FROM proposals WHERE id = ?1",
+            params![proposal_id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(db_err)?
+        .flatten();
+    let body = match &strategy {
+        Some(strategy) => format!(
+            "Proposal #{} ({} hook) was marked as hired.",
+            proposal_id, strategy
+        ),
+        None => format!("Proposal #{} was marked as hired.", proposal_id),
+    };
+
+    Ok(Some(Milestone {
+        rule: RULE_HIRED,
+        dedupe_key,
+        title: "You got hired!".to_string(),
+        body,
+        proposal_id: Some(proposal_id),
+        strategy,
+        response_rate: None,
+    }))
+}
+
+/// Strategies whose response rate has reached `notify_strategy_threshold_pct` and
+/// have not been announced for that threshold yet
+pub fn strategy_milestones(conn: &Connection) -> Result<Vec<Milestone>, String> {
+    let Some(threshold) = get_typed::<Option<i64>>(conn, Key::NotifyStrategyThresholdPct)? else {
+        return Ok(Vec::new());
+    };
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT hook_strategy_id, COUNT(*),
+                SUM(CASE WHEN outcome_status IN ('response_received','interview','hired') THEN 1 ELSE 0 END)
+             FROM proposals
+             WHERE status NOT IN ('draft', 'discarded')
+               AND hook_strategy_id IS NOT NULL
+               AND outcome_status NOT IN ('pending', 'submitted')
+             GROUP BY hook_strategy_id
+             HAVING COUNT(*) >= ?1
+             ORDER BY hook_strategy_id",
+        )
+        .map_err(db_err)?;
+    let rows = stmt
+        .query_map(params![MIN_STRATEGY_SAMPLE], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, i64>(2)?,
+            ))
+        })
+        .map_err(db_err)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(db_err)?;
+
+    let mut milestones = Vec::new();
+    for (strategy, total, positive) in rows {
+        let rate = percentage(positive, total);
+        if rate < threshold as f64 {
+            continue;
+        }
+        let dedupe_key = format!("{}:{}:{}", RULE_STRATEGY_THRESHOLD, strategy, threshold);
+        if notification_log::was_notified(conn, &dedupe_key).map_err(db_err)? {
+            continue;
+        }
+        milestones.push(Milestone {
+            rule: RULE_STRATEGY_THRESHOLD,
+            dedupe_key,
+            title: format!("{} hook passed {}%", strategy, threshold),
+            body: format!(
+                "The {} hook strategy has a {:.0}% response rate ({} of {} outcomes).",
+                strategy, rate, positive, total
+            ),
+            proposal_id: None,
+            strategy: Some(strategy),
+            response_rate: Some(rate),
+        });
+    }
+    Ok(milestones)
+}
+
+/// Run the rules after `update_proposal_outcome`. Never fails the caller.
+pub fn after_outcome_update(
+    app_handle: &AppHandle,
+    conn: &Connection,
+    proposal_id: i64,
+    outcome_status: &str,
+) {
+    let mut milestones = Vec::new();
+    match hired_milestone(conn, proposal_id, outcome_status) {
+        Ok(Some(milestone)) => milestones.push(milestone),
+        Ok(None) => {}
+        Err(e) => tracing::warn!("Hired notification rule failed: {}", e),
+    }
+    match strategy_milestones(conn) {
+        Ok(found) => milestones.extend(found),
+        Err(e) => tracing::warn!("Strategy notification rule failed: {}", e),
+    }
+    notify(app_handle, conn, milestones);
+}
+
+/// Run the strategy rule after the weekly analytics are computed. Never fails the caller.
+pub fn after_weekly_analytics(app_handle: &AppHandle, database: &Database) {
+    let conn = match database.conn.lock() {
+        Ok(conn) => conn,
+        Err(e) => {
+            tracing::warn!(
+                "Strategy notification rule skipped: database lock error: {}",
+                e
+            );
+            return;
+        }
+    };
+    match strategy_milestones(&conn) {
+        Ok(milestones) => notify(app_handle, &conn, milestones),
+        Err(e) => tracing::warn!("Strategy notification rule failed: {}", e),
+    }
+}
+
+/// Show, log and (in the background) POST each milestone
+fn notify(app_handle: &AppHandle, conn: &Connection, milestones: Vec<Milestone>) {
+    if milestones.is_empty() {
+        return;
+    }
+    let webhook_url = get_typed::<Option<String>>(conn, Key::WebhookUrl)
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to read webhook URL: {}", e);
+            None
+        })
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty());
+
+    for milestone in milestones {
+        let (desktop_status, mut error) = match show_desktop(app_handle, &milestone) {
+            Ok(()) => (STATUS_SENT, None),
+            Err(e) => {
+                tracing::warn!(rule = milestone.rule, "Desktop notification failed: {}", e);
+                (STATUS_FAILED, Some(e))
+            }
+        };
+        let webhook_status = match &webhook_url {
+            None => STATUS_NOT_CONFIGURED,
+            Some(url) => match crate::network::validate_webhook_url(url) {
+                Ok(()) => STATUS_PENDING,
+                Err(e) => {
+                    if let NetworkError::BlockedDomain(domain) = &e {
+                        crate::network::emit_blocked_event(app_handle, domain.clone(), url.clone());
+                    }
+                    error = Some(e.to_string());
+                    STATUS_BLOCKED
+                }
+            },
+        };
+
+        let entry = NewNotification {
+            rule: milestone.rule,
+            dedupe_key: &milestone.dedupe_key,
+            title: &milestone.title,
+            body: &milestone.body,
+            desktop_status,
+            webhook_status,
+            error: error.as_deref(),
+        };
+        let log_id = match notification_log::insert_notification(conn, &entry) {
+            Ok(id) => id,
+            Err(e) => {
+                tracing::warn!(
+                    rule = milestone.rule,
+                    "Failed to record notification: {}",
+                    e
+                );
+                continue;
+            }
+        };
+
+        if let (STATUS_PENDING, Some(url)) = (webhook_status, &webhook_url) {
+            let app_handle = app_handle.clone();
+            let url = url.clone();
+            tauri::async_runtime::spawn(async move {
+                let db_state = app_handle.state::<AppDatabase>();
+                let Ok(database) = db_state.get() else {
+                    return;
+                };
+                deliver_webhook(&HttpWebhook, database, log_id, &url, &milestone).await;
+            });
+        }
+    }
+}
+
+fn show_desktop(app_handle: &AppHandle, milestone: &Milestone) -> Result<(), String> {
+    use tauri_plugin_notification::NotificationExt;
+    app_handle
+        .notification()
+        .builder()
+        .title(&milestone.title)
+        .body(&milestone.body)
+        .show()
+        .map_err(|e| e.to_string())
+}
+
+/// Sends a milestone to the webhook; `HttpWebhook` in the app, a fake in tests
+pub trait WebhookTransport {
+    fn post(
+        &self,
+        url: &str,
+        milestone: &Milestone,
+    ) -> impl Future<Output = Result<(), String>> + Send;
+}
+
+/// POST through the shared HTTP client with `WEBHOOK_TIMEOUT` and no retries
+pub struct HttpWebhook;
+
+impl WebhookTransport for HttpWebhook {
+    async fn post(&self, url: &str, milestone: &Milestone) -> Result<(), String> {
+        // The allowlist may have changed since the alert was queued
+        crate::network::validate_webhook_url(url).map_err(|e| e.to_string())?;
+        let response = crate::http::client()
+            .post(url)
+            .timeout(WEBHOOK_TIMEOUT)
+            .json(milestone)
+            .send_tracked()
+            .await
+            .map_err(|e| format!("Webhook request failed: {}", e))?;
+        if !response.status().is_success() {
+            return Err(format!("Webhook returned {}", response.status()));
+        }
+        Ok(())
+    }
+}
+
+/// POST one alert and record the result on its log row. Returns whether it was
+/// delivered; failures are only logged.
+pub async fn deliver_webhook<T: WebhookTransport>(
+    transport: &T,
+    database: &Database,
+    log_id: i64,
+    url: &str,
+    milestone: &Milestone,
+) -> bool {
+    let result = transport.post(url, milestone).await;
+    let (status, error) = match &result {
+        Ok(()) => (STATUS_SENT, None),
+        Err(e) => {
+            tracing::warn!(rule = milestone.rule, "Milestone webhook failed: {}", e);
+            (STATUS_FAILED, Some(e.as_str()))
+        }
+    };
+    match database.conn.lock() {
+        Ok(conn) => {
+            if let Err(e) = notification_log::update_webhook_status(&conn, log_id, status, error) {
+                tracing::warn!("Failed to record webhook result: {}", e);
+            }
+        }
+        Err(e) => tracing::warn!("Webhook result not recorded: database lock error: {}", e),
+    }
+    result.is_ok()
+}
+
+/// Most recent milestone notifications first, with their delivery results
+#[tauri::command]
+pub async fn get_notification_log(
+    limit: u32,
+    database: State<'_, AppDatabase>,
+) -> Result<Vec<NotificationLogEntry>, String> {
+    let database = database.get()?;
+    database.read(|conn| {
+        notification_log::get_notification_log(conn, limit.clamp(1, 500))
+            .map_err(|e| format!("Failed to get notification log: {}", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::queries::settings::set_setting;
+    use std::sync::Mutex;
+    use tempfile::tempdir;
+
+    fn setup() -> (tempfile::TempDir, Database) {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        (dir, db)
+    }
+
+    fn insert_outcome(conn: &Connection, strategy: &str, outcome: &str) -> i64 {
+        conn.execute(
+            "INSERT INTO proposals (job_content, generated_text, status, outcome_status, hook_strategy_id)
+             VALUES ('job', 'text', 'completed', ?1, ?2)",
+            params![outcome, strategy],
+        )
+        .unwrap();
+        conn.last_insert_rowid()
+    }
+
+    fn log(conn: &Connection, milestone: &Milestone, webhook_status: &str) -> i64 {
+        notification_log::insert_notification(
+            conn,
+            &NewNotification {
+                rule: milestone.rule,
+                dedupe_key: &milestone.dedupe_key,
+                title: &milestone.title,
+                body: &milestone.body,
+                desktop_status: STATUS_SENT,
+                webhook_status,
+                error: None,
+            },
+        )
+        .unwrap()
+    }
+
+    /// Records every POST; fails with `fail` when set
+    struct FakeTransport {
+        fail: Option<String>,
+        posts: Mutex<Vec<(String, Milestone)>>,
+    }
+
+    impl FakeTransport {
+        fn new(fail: Option<&str>) -> Self {
+            Self {
+                fail: fail.map(str::to_string),
+                posts: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    impl WebhookTransport for FakeTransport {
+        async fn post(&self, url: &str, milestone: &Milestone) -> Result<(), String> {
+            self.posts
+                .lock()
+                .unwrap()
+                .push((url.to_string(), milestone.clone()));
+            match &self.fail {
+                Some(e) => Err(e.clone()),
+                None => Ok(()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_hired_rule_respects_setting_and_fires_once() {
+        let (_dir, db) = setup();
+        let conn = db.conn.lock().unwrap();
+        let id = insert_outcome(&conn, "social_proof", "hired");
+
+        assert_eq!(hired_milestone(&conn, id, "hired").unwrap(), None);
+
+        set_setting(&conn, NOTIFY_ON_HIRED_SETTING, "true").unwrap();
+        assert_eq!(hired_milestone(&conn, id, "interview").unwrap(), None);
+        let milestone = hired_milestone(&conn, id, "hired").unwrap().unwrap();
+        assert_eq!(milestone.dedupe_key, format!("hired:{}", id));
+        assert_eq!(milestone.strategy.as_deref(), Some("social_proof"));
+
+        log(&conn, &milestone, STATUS_NOT_CONFIGURED);
+        assert_eq!(hired_milestone(&conn, id, "hired").unwrap(), None);
+    }
+
+    #[test]
+    fn test_strategy_rule_needs_threshold_sample_and_fires_once_per_threshold() {
+        let (_dir, db) = setup();
+        let conn = db.conn.lock().unwrap();
+        // social_proof: 3 of 5 positive (60%)
+        for outcome in [
+            "hired",
+            "interview",
+            "response_received",
+            "no_response",
+            "rejected",
+        ] {
+            insert_outcome(&conn, "social_proof", outcome);
+        }
+        // contrarian: 4 of 4 positive, below the minimum sample
+        for _ in 0..4 {
+            insert_outcome(&conn, "contrarian", "hired");
+        }
+        // Pending outcomes do not count toward the sample
+        insert_outcome(&conn, "contrarian", "pending");
+
+        assert!(strategy_milestones(&conn).unwrap().is_empty());
+
+        set_setting(&conn, STRATEGY_THRESHOLD_SETTING, "70").unwrap();
+        assert!(strategy_milestones(&conn).unwrap().is_empty());
+
+        set_setting(&conn, STRATEGY_THRESHOLD_SETTING, "50").unwrap();
+        let milestones = strategy_milestones(&conn).unwrap();
+        assert_eq!(milestones.len(), 1);
+        assert_eq!(milestones[0].strategy.as_deref(), Some("social_proof"));
+        assert_eq!(milestones[0].response_rate, Some(60.0));
+        assert_eq!(
+            milestones[0].dedupe_key,
+            "strategy_threshold:social_proof:50"
+        );
+
+        log(&conn, &milestones[0], STATUS_NOT_CONFIGURED);
+        assert!(strategy_milestones(&conn).unwrap().is_empty());
+
+        // A new threshold is a new milestone
+        set_setting(&conn, STRATEGY_THRESHOLD_SETTING, "55").unwrap();
+        assert_eq!(strategy_milestones(&conn).unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_results_are_recorded_and_failures_never_propagate() {
+        let (_dir, db) = setup();
+        let milestone = Milestone {
+            rule: RULE_HIRED,
+            dedupe_key: "hired:1".to_string(),
+            title: "You got hired!".to_string(),
+            body: "Proposal #1 was marked as hired.".to_string(),
+            proposal_id: Some(1),
+            strategy: None,
+            response_rate: None,
+        };
+        let url = "http://localhost:8080/hook";
+
+        let sent_id = log(&db.conn.lock().unwrap(), &milestone, STATUS_PENDING);
+        let ok = FakeTransport::new(None);
+        assert!(deliver_webhook(&ok, &db, sent_id, url, &milestone).await);
+        assert_eq!(
+            ok.posts.lock().unwrap().as_slice(),
+            &[(url.to_string(), milestone.clone())]
+        );
+
+        let failed_id = log(&db.conn.lock().unwrap(), &milestone, STATUS_PENDING);
+        let failing = FakeTransport::new(Some("Webhook request failed: timed out"));
+        assert!(!deliver_webhook(&failing, &db, failed_id, url, &milestone).await);
+        // No retries
+        assert_eq!(failing.posts.lock().unwrap().len(), 1);
+
+        let entries = notification_log::get_notification_log(&db.conn.lock().unwrap(), 10).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, failed_id);
+        assert_eq!(entries[0].webhook_status, STATUS_FAILED);
+        assert_eq!(
+            entries[0].error.as_deref(),
+            Some("Webhook request failed: timed out")
+        );
+        assert_eq!(entries[1].webhook_status, STATUS_SENT);
+        assert_eq!(entries[1].error, None);
+    }
+
+    #[test]
+    fn test_webhook_url_setting_must_be_allowlisted() {
+        assert!(validate_webhook_url_setting("").is_ok());
+        let err = validate_webhook_url_setting("https://dashboard.invalid/hook").unwrap_err();
+        assert!(err.contains("network_allowlist_extra"));
+    }
+}
@@ -0,0 +1,216 @@
+//! Partial regeneration: rewrite a selected span of a saved proposal
+//!
+//! `regenerate_selection` sends the selection plus a window of the text around
+//! it to Claude with a constrained rewrite prompt, splices the rewrite back in
+//! and saves the result as a `partial_regen` revision. Offsets are UTF-8 byte
+//! offsets into the proposal text, so they are checked against char boundaries
+//! before anything is sliced. Selections covering most of the proposal are
+//! refused; a full regeneration is the better tool for those.
+
+use serde::{Deserialize, Serialize};
+
+/// Revision type recorded for a spliced rewrite
+pub const PARTIAL_REGEN_REVISION_TYPE: &str = "partial_regen";
+
+/// Largest selection, as a share of the proposal's characters, that can be rewritten
+pub const MAX_SELECTION_FRACTION: f64 = 0.6;
+
+/// Longest optional rewrite instruction ("make it shorter", "mention React")
+pub const MAX_INSTRUCTION_CHARS: usize = 500;
+
+/// Characters of surrounding text sent on each side of the selection
+const CONTEXT_CHARS: usize = 800;
+
+/// Result of `regenerate_selection`: the new full text and where the rewrite landed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionRegenResult {
+    pub proposal_id: i64,
+    pub revision_id: i64,
+    pub text: String,
+    /// Byte range of the rewritten span in `text`
+    pub start_offset: usize,
+    pub end_offset: usize,
+}
+
+/// Check that `start..end` is a non-empty, in-bounds selection on char
+/// boundaries that covers at most `MAX_SELECTION_FRACTION` of the text.
+pub fn validate_selection(text: &str, start: usize, end: usize) -> Result<(), String> {
+    if start >= end {
+        return Err("Select some text to regenerate".to_string());
+    }
+    if end > text.len() {
+        return Err(format!(
+            "Selection end {} is past the end of the proposal ({} bytes)",
+            end,
+            text.len()
+        ));
+    }
+    if !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+        return Err("Selection offsets must fall on character boundaries".to_string());
+    }
+
+    let selection = &text[start..end];
+    if selection.trim().is_empty() {
+        return Err("Selection contains only whitespace".to_string());
+    }
+
+    let selected_chars = selection.chars().count();
+    let total_chars = text.chars().count();
+    if selected_chars as f64 > total_chars as f64 * MAX_SELECTION_FRACTION {
+        return Err(format!(
+            "Selection covers more than {}% of the proposal; regenerate the whole proposal instead",
+            (MAX_SELECTION_FRACTION * 100.0).round()
+        ));
+    }
+    Ok(())
+}
+
+/// Trim an optional instruction; blank is None
+pub fn validate_instruction(instruction: Option<&str>) -> Result<Option<String>, String> {
+    let instruction = match instruction.map(str::trim).filter(|i| !i.is_empty()) {
+        Some(instruction) => instruction,
+        None => return Ok(None),
+    };
+    if instruction.chars().count() > MAX_INSTRUCTION_CHARS {
+        return Err(format!(
+            "Instruction must be at most {} characters",
+            MAX_INSTRUCTION_CHARS
+        ));
+    }
+    Ok(Some(instruction.to_string()))
+}
+
+/// Up to `CONTEXT_CHARS` characters before and after an already-validated selection
+pub fn surrounding_context(text: &str, start: usize, end: usize) -> (&str, &str) {
+    let before = &text[..start];
+    let before_start = before
+        .char_indices()
+        .rev()
+        .nth(CONTEXT_CHARS - 1)
+        .map_or(0, |(i, _)| i);
+
+    let after = &text[end..];
+    let after_len = after
+        .char_indices()
+        .nth(CONTEXT_CHARS)
+        .map_or(after.len(), |(i, _)| i);
+
+    (&before[before_start..], &after[..after_len])
+}
+
+/// Claude's reply with any echoed `<selection>` tags removed; an empty rewrite is an error
+pub fn clean_rewrite(response: &str) -> Result<&str, String> {
+    let mut rewrite = response.trim();
+    if let Some(inner) = rewrite.strip_prefix("<selection>") {
+        rewrite = inner.strip_suffix("</selection>").unwrap_or(inner).trim();
+    }
+    if rewrite.is_empty() {
+        return Err("Claude returned an empty rewrite".to_string());
+    }
+    Ok(rewrite)
+}
+
+/// Give the rewrite the selection's leading and trailing whitespace, so
+/// splicing doesn't merge or split the neighbouring words and paragraphs
+pub fn match_edge_whitespace(selection: &str, rewrite: &str) -> String {
+    let leading = &selection[..selection.len() - selection.trim_start().len()];
+    let trailing = &selection[selection.trim_end().len()..];
+    format!("{}{}{}", leading, rewrite.trim(), trailing)
+}
+
+/// Replace `start..end` of `text` with `replacement`
+pub fn splice(text: &str, start: usize, end: usize, replacement: &str) -> String {
+    let mut spliced = String::with_capacity(text.len() - (end - start) + replacement.len());
+    spliced.push_str(&text[..start]);
+    spliced.push_str(replacement);
+    spliced.push_str(&text[end..]);
+    spliced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_selection_multibyte_boundaries() {
+        // "é" is 2 bytes, "日本" 3 bytes each, "🚀" 4 bytes
+        let text = "Café work: 日本語 apps shipped fast 🚀 with tests and docs included here.";
+        let e = text.find('é').unwrap();
+        assert!(validate_selection(text, 0, e + 2).is_ok());
+        assert!(validate_selection(text, 0, e + 1).is_err());
+
+        let jp = text.find('日').unwrap();
+        assert!(validate_selection(text, jp, jp + 6).is_ok());
+        assert!(validate_selection(text, jp + 1, jp + 6).is_err());
+        assert!(validate_selection(text, jp, jp + 4).is_err());
+
+        let rocket = text.find('🚀').unwrap();
+        assert!(validate_selection(text, rocket, rocket + 4).is_ok());
+        assert!(validate_selection(text, rocket + 2, rocket + 4).is_err());
+    }
+
+    #[test]
+    fn test_validate_selection_bounds_and_size() {
+        let text = "0123456789";
+        assert!(validate_selection(text, 3, 3).is_err());
+        assert!(validate_selection(text, 5, 2).is_err());
+        assert!(validate_selection(text, 5, 11).is_err());
+        assert!(validate_selection(text, 0, 6).is_ok());
+        assert!(validate_selection(text, 0, 7).is_err());
+        assert!(validate_selection("hello   world", 5, 8).is_err());
+
+        // The 60% limit counts characters, not bytes: 6 of 10 chars is 18 of 30 bytes
+        let wide = "日本語日本語日本語日";
+        assert!(validate_selection(wide, 0, 18).is_ok());
+        assert!(validate_selection(wide, 0, 21).is_err());
+    }
+
+    #[test]
+    fn test_splice_and_context() {
+        let text = "Hi — I’ve built this before. Happy to chat.";
+        let start = text.find("I’ve").unwrap();
+        let end = text.find(" Happy").unwrap();
+        validate_selection(text, start, end).unwrap();
+
+        let rewrite =
+            match_edge_whitespace(&text[start..end], "  I shipped the same thing last month. ");
+        let spliced = splice(text, start, end, &rewrite);
+        assert_eq!(
+            spliced,
+            "Hi — I shipped the same thing last month. Happy to chat."
+        );
+
+        let (before, after) = surrounding_context(text, start, end);
+        assert_eq!(before, "Hi — ");
+        assert_eq!(after, " Happy to chat.");
+
+        let long = format!("{}X{}", "é".repeat(1000), "ü".repeat(1000));
+        let x = long.find('X').unwrap();
+        let (before, after) = surrounding_context(&long, x, x + 1);
+        assert_eq!(before.chars().count(), CONTEXT_CHARS);
+        assert_eq!(after.chars().count(), CONTEXT_CHARS);
+    }
+
+    #[test]
+    fn test_validate_instruction() {
+        assert_eq!(validate_instruction(None), Ok(None));
+        assert_eq!(validate_instruction(Some("   ")), Ok(None));
+        assert_eq!(
+            validate_instruction(Some(" shorter ")),
+            Ok(Some("shorter".to_string()))
+        );
+        assert!(validate_instruction(Some(&"x".repeat(MAX_INSTRUCTION_CHARS + 1))).is_err());
+    }
+
+    #[test]
+    fn test_clean_rewrite() {
+        assert_eq!(clean_rewrite("  New text.\n"), Ok("New text."));
+        assert_eq!(
+            clean_rewrite("<selection>\nNew text.\n</selection>"),
+            Ok("New text.")
+        );
+        assert!(clean_rewrite("<selection></selection>").is_err());
+        assert!(clean_rewrite("   ").is_err());
+    }
+}
@@ -2,9 +2,14 @@
 //!
 //! Implements secure passphrase-based key derivation for SQLCipher database encryption.
 //! - Argon2id algorithm with OWASP-recommended parameters
-//! - 64MB memory, 3 iterations, 4 parallelism
+//! - 64MB memory, 3 iterations, 4 parallelism by default
 //! - ~200ms derivation time (within NFR-1 startup budget)
 //! - Salt management (random generation + persistent storage)
+//! - Configurable cost parameters (`KdfParams`), stored next to the salt in
+//!   `.kdf_params`. Databases set up before they existed have no file and use
+//!   the defaults. Parameters only change together with the key (first-time
+//!   setup or re-key), so a database stays unlockable with the parameters it
+//!   was keyed with.
 
 #[cfg(test)]
 mod tests;
@@ -15,8 +20,10 @@ use argon2::{
 };
 use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine as _};
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use zeroize::Zeroizing;
 
 /// Minimum passphrase length (updated from 8 to 12 per Round 5 Security Audit)
@@ -33,6 +40,20 @@ const MEMORY_SIZE_KB: u32 = 65536; // 64MB
 const ITERATIONS: u32 = 3;
 const PARALLELISM: u32 = 4;
 
+/// Bounds for custom parameters: OWASP's 19 MiB / 2 iterations floor, and a
+/// ceiling that keeps a mistyped value from making the database unopenable
+pub const MIN_MEMORY_KB: u32 = 19 * 1024;
+pub const MAX_MEMORY_KB: u32 = 1024 * 1024; // 1GB
+pub const MIN_ITERATIONS: u32 = 2;
+pub const MAX_ITERATIONS: u32 = 20;
+pub const MAX_PARALLELISM: u32 = 16;
+
+/// Derivation time `calibrate_kdf_params` aims for by default
+pub const DEFAULT_TARGET_DERIVATION_MS: u64 = 1000;
+
+/// Parameters file, next to `.salt`
+pub const KDF_PARAMS_FILE: &str = ".kdf_params";
+
 /// Error types for passphrase operations
 #[derive(Debug, thiserror::Error)]
 pub enum PassphraseError {
@@ -53,6 +74,59 @@ pub enum PassphraseError {
 
     #[error("Invalid salt format: {0}")]
     InvalidSalt(String),
+
+    #[error("Invalid key derivation parameters: {0}")]
+    InvalidKdfParams(String),
+
+    #[error("Failed to access key derivation parameters file: {0}")]
+    KdfParamsFileFailed(String),
+}
+
+/// Argon2id cost parameters a key was derived with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KdfParams {
+    pub memory_kb: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl KdfParams {
+    /// The fixed parameters used before they were configurable
+    pub const DEFAULT: KdfParams = KdfParams {
+        memory_kb: MEMORY_SIZE_KB,
+        iterations: ITERATIONS,
+        parallelism: PARALLELISM,
+    };
+
+    /// Check the parameters are within the supported bounds
+    pub fn validate(&self) -> Result<(), PassphraseError> {
+        if !(MIN_MEMORY_KB..=MAX_MEMORY_KB).contains(&self.memory_kb) {
+            return Err(PassphraseError::InvalidKdfParams(format!(
+                "memory must be {}-{} KB, got {}",
+                MIN_MEMORY_KB, MAX_MEMORY_KB, self.memory_kb
+            )));
+        }
+        if !(MIN_ITERATIONS..=MAX_ITERATIONS).contains(&self.iterations) {
+            return Err(PassphraseError::InvalidKdfParams(format!(
+                "iterations must be {}-{}, got {}",
+                MIN_ITERATIONS, MAX_ITERATIONS, self.iterations
+            )));
+        }
+        if !(1..=MAX_PARALLELISM).contains(&self.parallelism) {
+            return Err(PassphraseError::InvalidKdfParams(format!(
+                "parallelism must be 1-{}, got {}",
+                MAX_PARALLELISM, self.parallelism
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
 }
 
 /// Generate a cryptographically secure random salt
@@ -116,6 +190,38 @@ pub fn load_salt(app_data_dir: &Path) -> Result<[u8; SALT_LENGTH], PassphraseErr
     Ok(salt)
 }
 
+/// Path of the parameters file in `app_data_dir`
+pub fn kdf_params_path(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join(KDF_PARAMS_FILE)
+}
+
+/// Serialize parameters for the parameters file (validated first)
+pub fn encode_kdf_params(params: &KdfParams) -> Result<String, PassphraseError> {
+    params.validate()?;
+    serde_json::to_string(params).map_err(|e| PassphraseError::InvalidKdfParams(e.to_string()))
+}
+
+/// Store parameters next to the salt
+pub fn store_kdf_params(params: &KdfParams, app_data_dir: &Path) -> Result<(), PassphraseError> {
+    let json = encode_kdf_params(params)?;
+    fs::write(kdf_params_path(app_data_dir), json)
+        .map_err(|e| PassphraseError::KdfParamsFileFailed(e.to_string()))
+}
+
+/// Load the parameters the current key was derived with. No file means the
+/// database predates configurable parameters: the defaults.
+pub fn load_kdf_params(app_data_dir: &Path) -> Result<KdfParams, PassphraseError> {
+    let json = match fs::read_to_string(kdf_params_path(app_data_dir)) {
+        Ok(json) => json,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(KdfParams::DEFAULT),
+        Err(e) => return Err(PassphraseError::KdfParamsFileFailed(e.to_string())),
+    };
+    let params: KdfParams = serde_json::from_str(&json)
+        .map_err(|e| PassphraseError::KdfParamsFileFailed(format!("Unreadable file: {}", e)))?;
+    params.validate()?;
+    Ok(params)
+}
+
 /// Derive encryption key from passphrase using Argon2id
 ///
 /// # Parameters
@@ -134,17 +240,27 @@ pub fn load_salt(app_data_dir: &Path) -> Result<[u8; SALT_LENGTH], PassphraseErr
 pub fn derive_key(
     passphrase: &str,
     salt: &[u8; SALT_LENGTH],
+) -> Result<Zeroizing<Vec<u8>>, PassphraseError> {
+    derive_key_with_params(passphrase, salt, &KdfParams::DEFAULT)
+}
+
+/// `derive_key` with custom Argon2id cost parameters
+pub fn derive_key_with_params(
+    passphrase: &str,
+    salt: &[u8; SALT_LENGTH],
+    kdf_params: &KdfParams,
 ) -> Result<Zeroizing<Vec<u8>>, PassphraseError> {
     // Validate passphrase length
     if passphrase.len() < MIN_PASSPHRASE_LENGTH {
         return Err(PassphraseError::TooShort);
     }
+    kdf_params.validate()?;
 
     // Build Argon2id parameters
     let params = ParamsBuilder::new()
-        .m_cost(MEMORY_SIZE_KB)
-        .t_cost(ITERATIONS)
-        .p_cost(PARALLELISM)
+        .m_cost(kdf_params.memory_kb)
+        .t_cost(kdf_params.iterations)
+        .p_cost(kdf_params.parallelism)
         .output_len(KEY_LENGTH)
         .build()
         .map_err(|e| PassphraseError::DerivationFailed(format!("Failed to build params: {}", e)))?;
@@ -201,17 +317,28 @@ pub fn derive_key(
 pub fn set_passphrase(
     passphrase: &str,
     app_data_dir: &Path,
+) -> Result<Zeroizing<Vec<u8>>, PassphraseError> {
+    set_passphrase_with_params(passphrase, app_data_dir, &KdfParams::DEFAULT)
+}
+
+/// `set_passphrase` with custom Argon2id parameters, stored with the salt
+pub fn set_passphrase_with_params(
+    passphrase: &str,
+    app_data_dir: &Path,
+    kdf_params: &KdfParams,
 ) -> Result<Zeroizing<Vec<u8>>, PassphraseError> {
     // Generate new salt
     let salt = generate_random_salt()?;
 
     // Derive key from passphrase — returned in Zeroizing wrapper, auto-zeroed on drop
-    let key = derive_key(passphrase, &salt)?;
+    let key = derive_key_with_params(passphrase, &salt, kdf_params)?;
 
-    // Store salt for future use (restart scenarios)
+    // Store salt and parameters for future use (restart scenarios)
     store_salt(&salt, app_data_dir)?;
+    store_kdf_params(kdf_params, app_data_dir)?;
+    crate::integrity::refresh_manifest(app_data_dir);
 
-    tracing::info!("Passphrase set successfully, key derived (~200ms)");
+    tracing::info!(?kdf_params, "Passphrase set successfully, key derived");
 
     Ok(key)
 }
@@ -219,16 +346,17 @@ pub fn set_passphrase(
 /// Verify passphrase by deriving key and comparing (constant-time)
 ///
 /// Used during app restart to unlock the encrypted database.
-/// Loads existing salt and derives key to verify correctness.
+/// Loads existing salt and parameters and derives key to verify correctness.
 pub fn verify_passphrase(
     passphrase: &str,
     app_data_dir: &Path,
 ) -> Result<Zeroizing<Vec<u8>>, PassphraseError> {
-    // Load existing salt
+    // Load existing salt and the parameters the key was derived with
     let salt = load_salt(app_data_dir)?;
+    let kdf_params = load_kdf_params(app_data_dir)?;
 
     // Derive key from passphrase — returned in Zeroizing wrapper, auto-zeroed on drop
-    let key = derive_key(passphrase, &salt)?;
+    let key = derive_key_with_params(passphrase, &salt, &kdf_params)?;
 
     tracing::info!("Passphrase verified successfully");
 
@@ -252,6 +380,94 @@ pub fn verify_passphrase_and_derive_key(
     verify_passphrase(passphrase, app_data_dir)
 }
 
+/// Result of `calibrate_kdf_params`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KdfCalibration {
+    /// Parameters the current key was derived with
+    pub current: KdfParams,
+    pub suggested: KdfParams,
+    /// Measured derivation time with the default parameters
+    pub benchmark_ms: u64,
+    /// Expected derivation time with `suggested`
+    pub estimated_ms: u64,
+    pub target_ms: u64,
+}
+
+/// Time one key derivation with `params` (throwaway passphrase and salt)
+pub fn benchmark_derivation(params: &KdfParams) -> Result<Duration, PassphraseError> {
+    let salt = generate_random_salt()?;
+    let started = Instant::now();
+    derive_key_with_params("kdf-calibration-probe", &salt, params)?;
+    Ok(started.elapsed())
+}
+
+/// Scale `probe` so a derivation takes about `target`, given it took
+/// `probe_elapsed`. Argon2id time grows linearly with memory × iterations:
+/// memory is scaled at the default 3 iterations, and iterations adjusted only
+/// when memory hits a bound. Parallelism is kept (it changes the time per unit).
+pub fn suggest_kdf_params(
+    probe: &KdfParams,
+    probe_elapsed: Duration,
+    target: Duration,
+) -> KdfParams {
+    let probe_cost = probe.memory_kb as f64 * probe.iterations as f64;
+    let secs_per_unit = probe_elapsed.as_secs_f64().max(0.001) / probe_cost;
+    let budget = target.as_secs_f64() / secs_per_unit;
+
+    let mut iterations = ITERATIONS;
+    let mut memory_kb = budget / iterations as f64;
+    if memory_kb > MAX_MEMORY_KB as f64 {
+        iterations = (budget / MAX_MEMORY_KB as f64).floor() as u32;
+        memory_kb = MAX_MEMORY_KB as f64;
+    } else if memory_kb < MIN_MEMORY_KB as f64 {
+        iterations = (budget / MIN_MEMORY_KB as f64).floor() as u32;
+        memory_kb = MIN_MEMORY_KB as f64;
+    }
+
+    // Nearest whole MiB, within bounds
+    let memory_kb =
+        ((memory_kb / 1024.0).round() as u32 * 1024).clamp(MIN_MEMORY_KB, MAX_MEMORY_KB);
+    KdfParams {
+        memory_kb,
+        iterations: iterations.clamp(MIN_ITERATIONS, MAX_ITERATIONS),
+        parallelism: probe.parallelism,
+    }
+}
+
+/// Expected derivation time for `params`, from a probe measurement
+fn estimate_derivation(probe: &KdfParams, probe_elapsed: Duration, params: &KdfParams) -> Duration {
+    let probe_cost = probe.memory_kb as f64 * probe.iterations as f64;
+    let cost = params.memory_kb as f64 * params.iterations as f64;
+    probe_elapsed.mul_f64(cost / probe_cost)
+}
+
+/// Benchmark this machine and suggest parameters for a ~`target_ms` unlock.
+/// Read-only: applying them takes a re-key.
+pub fn calibrate_kdf_params(
+    app_data_dir: &Path,
+    target_ms: u64,
+) -> Result<KdfCalibration, PassphraseError> {
+    let current = load_kdf_params(app_data_dir)?;
+    let probe = KdfParams::DEFAULT;
+    let elapsed = benchmark_derivation(&probe)?;
+    let suggested = suggest_kdf_params(&probe, elapsed, Duration::from_millis(target_ms));
+    let estimated = estimate_derivation(&probe, elapsed, &suggested);
+
+    tracing::info!(
+        benchmark_ms = elapsed.as_millis() as u64,
+        ?suggested,
+        "Key derivation calibrated"
+    );
+    Ok(KdfCalibration {
+        current,
+        suggested,
+        benchmark_ms: elapsed.as_millis() as u64,
+        estimated_ms: estimated.as_millis() as u64,
+        target_ms,
+    })
+}
+
 /// Passphrase strength assessment
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PassphraseStrength {
@@ -444,3 +444,89 @@ fn test_zeroizing_string_zeroes_passphrase() {
         "Passphrase string bytes should be zeroed after zeroize()"
     );
 }
+
+fn cheap_params() -> KdfParams {
+    KdfParams {
+        memory_kb: MIN_MEMORY_KB,
+        iterations: MIN_ITERATIONS,
+        parallelism: 1,
+    }
+}
+
+#[test]
+fn test_kdf_params_stored_with_salt_and_used_by_verify() {
+    let temp_dir = tempdir().unwrap();
+    let passphrase = "MySecurePassphrase123!";
+
+    let key = set_passphrase_with_params(passphrase, temp_dir.path(), &cheap_params()).unwrap();
+    assert_eq!(load_kdf_params(temp_dir.path()).unwrap(), cheap_params());
+
+    let verified = verify_passphrase(passphrase, temp_dir.path()).unwrap();
+    assert_eq!(key, verified);
+
+    // Same salt, default costs: a different key
+    let salt = load_salt(temp_dir.path()).unwrap();
+    assert_ne!(key, derive_key(passphrase, &salt).unwrap());
+}
+
+#[test]
+fn test_load_kdf_params_defaults_and_validation() {
+    let temp_dir = tempdir().unwrap();
+
+    // Installs keyed before the file existed use the original fixed costs
+    assert_eq!(
+        load_kdf_params(temp_dir.path()).unwrap(),
+        KdfParams::DEFAULT
+    );
+
+    std::fs::write(kdf_params_path(temp_dir.path()), "not json").unwrap();
+    assert!(matches!(
+        load_kdf_params(temp_dir.path()),
+        Err(PassphraseError::KdfParamsFileFailed(_))
+    ));
+
+    let too_cheap = KdfParams {
+        memory_kb: 1024,
+        ..KdfParams::DEFAULT
+    };
+    assert!(matches!(
+        store_kdf_params(&too_cheap, temp_dir.path()),
+        Err(PassphraseError::InvalidKdfParams(_))
+    ));
+    assert!(matches!(
+        derive_key_with_params("MySecurePassphrase123!", &[1u8; 16], &too_cheap),
+        Err(PassphraseError::InvalidKdfParams(_))
+    ));
+}
+
+#[test]
+fn test_suggest_kdf_params_scales_to_target() {
+    let probe = KdfParams::DEFAULT;
+
+    // 200ms at 64 MiB x 3 -> 1s target: 5x the memory, same iterations
+    let suggested = suggest_kdf_params(
+        &probe,
+        Duration::from_millis(200),
+        Duration::from_millis(1000),
+    );
+    assert_eq!(suggested.memory_kb, 320 * 1024);
+    assert_eq!(suggested.iterations, ITERATIONS);
+    assert_eq!(suggested.parallelism, probe.parallelism);
+    let estimate = estimate_derivation(&probe, Duration::from_millis(200), &suggested);
+    assert_eq!(estimate.as_millis(), 1000);
+
+    // Very fast machine: memory capped, extra time goes to iterations
+    let fast = suggest_kdf_params(
+        &probe,
+        Duration::from_millis(10),
+        Duration::from_millis(1000),
+    );
+    assert_eq!(fast.memory_kb, MAX_MEMORY_KB);
+    assert!(fast.iterations > ITERATIONS);
+    assert!(fast.validate().is_ok());
+
+    // Very slow machine: never below the minimums
+    let slow = suggest_kdf_params(&probe, Duration::from_secs(30), Duration::from_millis(250));
+    assert_eq!(slow.memory_kb, MIN_MEMORY_KB);
+    assert_eq!(slow.iterations, MIN_ITERATIONS);
+}
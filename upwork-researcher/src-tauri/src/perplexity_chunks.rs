@@ -0,0 +1,400 @@
+//! Sentence-boundary chunking for perplexity analysis of long proposals
+//!
+//! A single analysis request for a long proposal can hit token limits, and a
+//! failure returns nothing. `analyze_perplexity_with_sentences` instead splits
+//! the text at sentence boundaries into chunks under a token budget
+//! (`perplexity_chunk_tokens`), analyzes them with bounded concurrency, and
+//! merges the results here:
+//! - Flagged sentence indexes are remapped to the whole text, in original order
+//! - The overall score is the length-weighted mean of the analyzed chunks
+//! - Failed chunks are listed as character ranges and the result is `partial`
+
+use crate::claude::{FailedChunkRange, FlaggedSentence, PerplexityAnalysis};
+use crate::sanitization::estimate_tokens;
+use rusqlite::Connection;
+
+/// Settings key for the per-chunk token budget
+pub const PERPLEXITY_CHUNK_TOKENS_SETTING: &str = "perplexity_chunk_tokens";
+pub const DEFAULT_PERPLEXITY_CHUNK_TOKENS: usize = 1500;
+pub const MIN_PERPLEXITY_CHUNK_TOKENS: usize = 200;
+pub const MAX_PERPLEXITY_CHUNK_TOKENS: usize = 8000;
+
+/// Chunks analyzed at the same time
+pub const PERPLEXITY_CHUNK_CONCURRENCY: usize = 2;
+
+/// Words that end with a period without ending the sentence (compared lowercase, without the final period)
+const ABBREVIATIONS: &[&str] = &[
+    "e.g", "i.e", "etc", "vs", "approx", "dr", "mr", "mrs", "ms", "prof", "sr", "jr", "st", "inc",
+    "ltd", "co", "corp", "no", "fig", "cf", "u.s", "a.m", "p.m",
+];
+
+/// Configured chunk budget (clamped into range by the settings schema; unset
+/// or invalid values use the default)
+pub fn chunk_token_budget(conn: &Connection) -> usize {
+    crate::settings::schema::get_typed(conn, crate::settings::schema::Key::PerplexityChunkTokens)
+        .unwrap_or(DEFAULT_PERPLEXITY_CHUNK_TOKENS)
+}
+
+/// One sentence as byte offsets into the analyzed text (whitespace trimmed)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SentenceSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A run of consecutive sentences analyzed in one request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextChunk {
+    pub start: usize,
+    pub end: usize,
+    /// Index of the chunk's first sentence in the whole text
+    pub first_sentence: usize,
+    pub sentence_count: usize,
+}
+
+/// Is the period ending `before` (the text up to, not including, the period) part of an abbreviation or initial?
+fn is_abbreviation(before: &str) -> bool {
+    let word = before
+        .rsplit(|c: char| c.is_whitespace() || c == '(' || c == '"')
+        .next()
+        .unwrap_or("");
+    let lower = word.to_lowercase();
+    if ABBREVIATIONS.contains(&lower.as_str()) {
+        return true;
+    }
+    // Single-letter initials ("J. Smith")
+    let mut chars = word.chars();
+    matches!((chars.next(), chars.next()), (Some(c), None) if c.is_uppercase())
+}
+
+/// Split text into sentences at `.`/`!`/`?` followed by whitespace, and at line breaks.
+/// Abbreviations ("e.g.", "Dr."), initials, and decimals ("3.5") do not end a sentence.
+pub fn split_sentences(text: &str) -> Vec<SentenceSpan> {
+    let mut spans = Vec::new();
+    let mut push = |start: usize, end: usize| {
+        let raw = &text[start..end];
+        let trimmed = raw.trim();
+        if !trimmed.is_empty() {
+            let lead = raw.len() - raw.trim_start().len();
+            spans.push(SentenceSpan {
+                start: start + lead,
+                end: start + lead + trimmed.len(),
+            });
+        }
+    };
+
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '\n' {
+            push(start, i);
+            start = i + 1;
+            continue;
+        }
+        if !matches!(c, '.' | '!' | '?') {
+            continue;
+        }
+
+        // Include repeated terminators and closing quotes/brackets ("?!", ".\"", ".)")
+        let mut end = i + c.len_utf8();
+        while let Some(&(j, next)) = chars.peek() {
+            if matches!(next, '.' | '!' | '?' | '"' | '\'' | ')' | ']' | '”' | '’') {
+                end = j + next.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        let at_boundary = chars.peek().is_none_or(|&(_, next)| next.is_whitespace());
+        if at_boundary && !(c == '.' && is_abbreviation(&text[start..i])) {
+            push(start, end);
+            start = end;
+        }
+    }
+    push(start, text.len());
+    spans
+}
+
+/// Group consecutive sentences into chunks of at most `max_tokens` (estimated).
+/// A single sentence over the budget becomes its own chunk.
+pub fn chunk_sentences(
+    text: &str,
+    sentences: &[SentenceSpan],
+    max_tokens: usize,
+) -> Vec<TextChunk> {
+    let mut chunks: Vec<TextChunk> = Vec::new();
+    for (index, sentence) in sentences.iter().enumerate() {
+        match chunks.last_mut() {
+            Some(chunk) if estimate_tokens(&text[chunk.start..sentence.end]) <= max_tokens => {
+                chunk.end = sentence.end;
+                chunk.sentence_count += 1;
+            }
+            _ => chunks.push(TextChunk {
+                start: sentence.start,
+                end: sentence.end,
+                first_sentence: index,
+                sentence_count: 1,
+            }),
+        }
+    }
+    chunks
+}
+
+/// Byte offset to character offset (what the frontend highlights by)
+fn char_offset(text: &str, byte: usize) -> usize {
+    text[..byte].chars().count()
+}
+
+/// Index of the whole-text sentence a flagged sentence from `chunk` refers to.
+/// Prefers locating the quoted text; falls back to the chunk-relative index.
+fn global_sentence_index(
+    text: &str,
+    sentences: &[SentenceSpan],
+    chunk: &TextChunk,
+    flagged: &FlaggedSentence,
+) -> usize {
+    let chunk_sentences =
+        &sentences[chunk.first_sentence..chunk.first_sentence + chunk.sentence_count];
+    let needle = flagged.text.trim();
+    if !needle.is_empty() {
+        if let Some(pos) = text[chunk.start..chunk.end].find(needle) {
+            let offset = chunk.start + pos;
+            if let Some(local) = chunk_sentences.iter().position(|s| offset < s.end) {
+                return chunk.first_sentence + local;
+            }
+        }
+    }
+    chunk.first_sentence + flagged.index.min(chunk.sentence_count.saturating_sub(1))
+}
+
+/// Merge per-chunk results (score and flagged sentences, or the error) into one analysis.
+/// Fails only if every chunk failed, with the first chunk's error.
+pub fn merge_chunk_results(
+    text: &str,
+    sentences: &[SentenceSpan],
+    chunks: &[TextChunk],
+    results: Vec<Result<(f32, Vec<FlaggedSentence>), String>>,
+    threshold: f32,
+) -> Result<PerplexityAnalysis, String> {
+    let mut weighted_score = 0.0f64;
+    let mut analyzed_chars = 0usize;
+    let mut flagged_sentences = Vec::new();
+    let mut failed_ranges = Vec::new();
+    let mut first_error = None;
+
+    for (chunk, result) in chunks.iter().zip(results) {
+        match result {
+            Ok((score, flagged)) => {
+                let chars = text[chunk.start..chunk.end].chars().count();
+                weighted_score += score as f64 * chars as f64;
+                analyzed_chars += chars;
+                flagged_sentences.extend(flagged.into_iter().map(|mut sentence| {
+                    sentence.index = global_sentence_index(text, sentences, chunk, &sentence);
+                    sentence
+                }));
+            }
+            Err(error) => {
+                failed_ranges.push(FailedChunkRange {
+                    start: char_offset(text, chunk.start),
+                    end: char_offset(text, chunk.end),
+                    error: error.clone(),
+                });
+                first_error.get_or_insert(error);
+            }
+        }
+    }
+
+    if analyzed_chars == 0 {
+        return Err(first_error.unwrap_or_else(|| "No text to analyze".to_string()));
+    }
+
+    // Stable sort keeps the model's order for sentences flagged more than once
+    flagged_sentences.sort_by_key(|sentence| sentence.index);
+
+    Ok(PerplexityAnalysis {
+        score: (weighted_score / analyzed_chars as f64) as f32,
+        threshold,
+        flagged_sentences,
+        partial: !failed_ranges.is_empty(),
+        chunk_count: chunks.len(),
+        failed_ranges,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sentence_texts(text: &str) -> Vec<&str> {
+        split_sentences(text)
+            .iter()
+            .map(|s| &text[s.start..s.end])
+            .collect()
+    }
+
+    fn flagged(text: &str, index: usize) -> FlaggedSentence {
+        FlaggedSentence {
+            text: text.to_string(),
+            suggestion: "rephrase".to_string(),
+            index,
+        }
+    }
+
+    #[test]
+    fn test_split_keeps_abbreviations_and_decimals_together() {
+        let text = "I worked with Dr. Lee on React, e.g. dashboards and i.e. admin panels. \
+                    Uptime rose to 99.9% in 3.5 months! Want details? J. Smith can confirm.";
+        assert_eq!(
+            sentence_texts(text),
+            vec![
+                "I worked with Dr. Lee on React, e.g. dashboards and i.e. admin panels.",
+                "Uptime rose to 99.9% in 3.5 months!",
+                "Want details?",
+                "J. Smith can confirm.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_on_line_breaks_and_closing_quotes() {
+        let text = "Hi there,\n\n- Fast delivery\n- Clean code\nHe said \"done.\" Then left...";
+        assert_eq!(
+            sentence_texts(text),
+            vec![
+                "Hi there,",
+                "- Fast delivery",
+                "- Clean code",
+                "He said \"done.\"",
+                "Then left...",
+            ]
+        );
+        assert!(split_sentences("  \n ").is_empty());
+    }
+
+    #[test]
+    fn test_chunks_respect_budget_and_sentence_boundaries() {
+        // Each sentence is 40 bytes = 10 estimated tokens
+        let sentence = "This sentence is exactly forty bytes ok.";
+        assert_eq!(sentence.len(), 40);
+        let text = [sentence; 5].join(" ");
+        let sentences = split_sentences(&text);
+        assert_eq!(sentences.len(), 5);
+
+        let chunks = chunk_sentences(&text, &sentences, 21);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            chunks.iter().map(|c| c.sentence_count).collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+        assert_eq!(chunks[1].first_sentence, 2);
+        assert_eq!(chunks[1].start, sentences[2].start);
+        assert_eq!(chunks[2].end, text.len());
+
+        // Oversized single sentence is still analyzed, alone
+        let chunks = chunk_sentences(&text, &sentences, 5);
+        assert_eq!(chunks.len(), 5);
+        // Short text stays a single request
+        assert_eq!(chunk_sentences(&text, &sentences, 1500).len(), 1);
+    }
+
+    #[test]
+    fn test_merge_remaps_indexes_and_weights_by_length() {
+        let text = "First one is short. Second sentence is a fair bit longer than that. Third.";
+        let sentences = split_sentences(text);
+        let chunks = vec![
+            TextChunk {
+                start: sentences[0].start,
+                end: sentences[0].end,
+                first_sentence: 0,
+                sentence_count: 1,
+            },
+            TextChunk {
+                start: sentences[1].start,
+                end: sentences[2].end,
+                first_sentence: 1,
+                sentence_count: 2,
+            },
+        ];
+        let results = vec![
+            Ok((100.0, vec![flagged("First one is short.", 0)])),
+            // Chunk-relative indexes; located by text when possible
+            Ok((
+                200.0,
+                vec![flagged("Third.", 0), flagged("not in the text", 0)],
+            )),
+        ];
+
+        let merged = merge_chunk_results(text, &sentences, &chunks, results, 180.0).unwrap();
+        assert_eq!(merged.chunk_count, 2);
+        assert!(!merged.partial);
+        assert_eq!(
+            merged
+                .flagged_sentences
+                .iter()
+                .map(|s| s.index)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        let first = text[chunks[0].start..chunks[0].end].len() as f32;
+        let second = text[chunks[1].start..chunks[1].end].len() as f32;
+        let expected = (100.0 * first + 200.0 * second) / (first + second);
+        assert!((merged.score - expected).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_merge_reports_failed_chunks_as_partial() {
+        let text = "Café is open. Second part fails.";
+        let sentences = split_sentences(text);
+        let chunks = chunk_sentences(text, &sentences, 4);
+        assert_eq!(chunks.len(), 2);
+
+        let merged = merge_chunk_results(
+            text,
+            &sentences,
+            &chunks,
+            vec![
+                Ok((150.0, vec![])),
+                Err("Sentence analysis timed out".into()),
+            ],
+            180.0,
+        )
+        .unwrap();
+        assert!(merged.partial);
+        assert_eq!(merged.score, 150.0);
+        assert_eq!(merged.failed_ranges.len(), 1);
+        // Character offsets, not bytes ("é" is two bytes)
+        assert_eq!(merged.failed_ranges[0].start, 14);
+        assert_eq!(merged.failed_ranges[0].end, text.chars().count());
+
+        let err = merge_chunk_results(
+            text,
+            &sentences,
+            &chunks,
+            vec![Err("first".into()), Err("second".into())],
+            180.0,
+        )
+        .unwrap_err();
+        assert_eq!(err, "first");
+    }
+
+    #[test]
+    fn test_chunk_token_budget_setting() {
+        let db = crate::db::Database::new(":memory:".into(), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        assert_eq!(chunk_token_budget(&conn), DEFAULT_PERPLEXITY_CHUNK_TOKENS);
+        for (stored, tokens) in [
+            ("1500", 1500),
+            ("50", MIN_PERPLEXITY_CHUNK_TOKENS),
+            ("lots", DEFAULT_PERPLEXITY_CHUNK_TOKENS),
+        ] {
+            crate::db::queries::settings::set_setting(
+                &conn,
+                PERPLEXITY_CHUNK_TOKENS_SETTING,
+                stored,
+            )
+            .unwrap();
+            assert_eq!(chunk_token_budget(&conn), tokens, "{}", stored);
+        }
+    }
+}
@@ -0,0 +1,521 @@
+//! Pre-send checklist
+//!
+//! One report of the checks worth doing before pasting a proposal into Upwork:
+//! length against the generation target, the cached perplexity score against
+//! the safety threshold, whether the client is named, keyword coverage of the
+//! job's key skills and hidden needs, humanization metrics, and any safety
+//! overrides on record. Everything comes from stored data and local analysis,
+//! so the report is instant and makes no API calls. A check whose input is
+//! missing (no linked job post, never analyzed) reports `unknown` rather than
+//! failing the whole report.
+
+use crate::analysis::keyword_coverage;
+use crate::db::queries::{generation_metadata, job_posts, proposals, safety_overrides};
+use crate::humanization::{self, HumanizationIntensity};
+use crate::proposal_length;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Word count within this share of the target passes; within twice it warns
+const LENGTH_TOLERANCE: f64 = 0.2;
+
+/// A perplexity score this close below the threshold (as a share of it) warns
+const PERPLEXITY_MARGIN: f64 = 0.1;
+
+/// Key skill coverage (percent) that passes, and below which the check fails
+const SKILLS_PASS_PCT: u8 = 60;
+const SKILLS_FAIL_PCT: u8 = 30;
+
+/// AI tell phrases at which the humanization check fails rather than warns
+const AI_TELLS_FAIL_COUNT: usize = 3;
+
+/// Words in a client name too generic to count as naming the client
+const CLIENT_NAME_STOPWORDS: &[&str] = &[
+    "the", "inc", "llc", "ltd", "corp", "co", "company", "gmbh", "group",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+    /// The data the check needs isn't available
+    Unknown,
+}
+
+/// One line of the checklist
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistItem {
+    /// "length", "perplexity", "clientName", "keySkills", "hiddenNeeds",
+    /// "humanization", or "safetyOverrides"
+    pub check: String,
+    pub status: CheckStatus,
+    pub detail: String,
+    /// Coverage checks only: items found in the proposal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched: Option<Vec<String>>,
+    /// Coverage checks only: items not found, in job order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing: Option<Vec<String>>,
+}
+
+impl ChecklistItem {
+    fn new(check: &str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            check: check.to_string(),
+            status,
+            detail: detail.into(),
+            matched: None,
+            missing: None,
+        }
+    }
+}
+
+/// Result of `get_presend_checklist`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresendChecklist {
+    pub proposal_id: i64,
+    pub items: Vec<ChecklistItem>,
+    /// Worst pass/warn/fail among the items (`unknown` items don't count);
+    /// `unknown` only when every item is
+    pub overall: CheckStatus,
+}
+
+/// Stored data the checklist is built from
+#[derive(Debug, Clone, Default)]
+pub struct PresendInputs {
+    pub proposal_id: i64,
+    pub text: String,
+    pub job_post_id: Option<i64>,
+    pub client_name: Option<String>,
+    pub target_words: Option<u32>,
+    /// Humanization intensity recorded at generation, None if unknown
+    pub intensity: Option<String>,
+    /// Cached score of the current text, None if not analyzed since the last edit
+    pub perplexity_score: Option<f64>,
+    /// Empty when the job has none extracted
+    pub key_skills: Vec<String>,
+    pub hidden_needs: Vec<String>,
+    pub overrides: Vec<safety_overrides::SafetyOverride>,
+}
+
+/// Gather the checklist inputs for a proposal. None if it does not exist.
+/// Hidden needs stored as malformed JSON count as none extracted.
+pub fn load_presend_inputs(
+    conn: &Connection,
+    proposal_id: i64,
+) -> Result<Option<PresendInputs>, rusqlite::Error> {
+    let Some(detail) = proposals::get_proposal_detail(conn, proposal_id)? else {
+        return Ok(None);
+    };
+    let metadata = generation_metadata::get_generation_metadata(conn, proposal_id)?;
+
+    let (key_skills, hidden_needs) = match detail.job_post_id {
+        Some(job_post_id) => (
+            job_posts::get_job_skills(conn, job_post_id)?,
+            job_posts::get_job_post_hidden_needs(conn, job_post_id)
+                .map(|needs| needs.into_iter().map(|n| n.need).collect())
+                .unwrap_or_default(),
+        ),
+        None => (Vec::new(), Vec::new()),
+    };
+
+    Ok(Some(PresendInputs {
+        proposal_id,
+        text: detail.generated_text,
+        job_post_id: detail.job_post_id,
+        client_name: detail.job_client_name,
+        target_words: metadata.target_words,
+        intensity: Some(metadata.humanization_intensity)
+            .filter(|intensity| HumanizationIntensity::is_valid(intensity)),
+        perplexity_score: proposals::get_cached_perplexity_score(conn, proposal_id)?,
+        key_skills,
+        hidden_needs,
+        overrides: safety_overrides::get_overrides_for_proposal(conn, proposal_id)?,
+    }))
+}
+
+/// Build the checklist; `threshold` is the safety threshold that applies to the proposal
+pub fn build_presend_checklist(inputs: &PresendInputs, threshold: i32) -> PresendChecklist {
+    let job_linked = inputs.job_post_id.is_some();
+    let items = vec![
+        length_check(&inputs.text, inputs.target_words),
+        perplexity_check(inputs.perplexity_score, threshold),
+        client_name_check(&inputs.text, job_linked, inputs.client_name.as_deref()),
+        coverage_check(
+            "keySkills",
+            "key skills",
+            &inputs.text,
+            job_linked,
+            &inputs.key_skills,
+            true,
+        ),
+        coverage_check(
+            "hiddenNeeds",
+            "hidden needs",
+            &inputs.text,
+            job_linked,
+            &inputs.hidden_needs,
+            false,
+        ),
+        humanization_check(&inputs.text),
+        overrides_check(&inputs.overrides),
+    ];
+    let overall = overall_status(&items);
+    PresendChecklist {
+        proposal_id: inputs.proposal_id,
+        items,
+        overall,
+    }
+}
+
+fn overall_status(items: &[ChecklistItem]) -> CheckStatus {
+    let has = |status| items.iter().any(|item| item.status == status);
+    if has(CheckStatus::Fail) {
+        CheckStatus::Fail
+    } else if has(CheckStatus::Warn) {
+        CheckStatus::Warn
+    } else if has(CheckStatus::Pass) {
+        CheckStatus::Pass
+    } else {
+        CheckStatus::Unknown
+    }
+}
+
+fn length_check(text: &str, target_words: Option<u32>) -> ChecklistItem {
+    let words = proposal_length::word_count(text);
+    let Some(target) = target_words.filter(|t| *t > 0) else {
+        return ChecklistItem::new(
+            "length",
+            CheckStatus::Unknown,
+            format!("{} words (no length target recorded)", words),
+        );
+    };
+    let deviation = (words as f64 - target as f64) / target as f64;
+    let status = if deviation.abs() <= LENGTH_TOLERANCE {
+        CheckStatus::Pass
+    } else if deviation.abs() <= LENGTH_TOLERANCE * 2.0 {
+        CheckStatus::Warn
+    } else {
+        CheckStatus::Fail
+    };
+    ChecklistItem::new(
+        "length",
+        status,
+        format!(
+            "{} words vs target {} ({:+.0}%)",
+            words,
+            target,
+            deviation * 100.0
+        ),
+    )
+}
+
+fn perplexity_check(score: Option<f64>, threshold: i32) -> ChecklistItem {
+    let Some(score) = score else {
+        return ChecklistItem::new(
+            "perplexity",
+            CheckStatus::Unknown,
+            "Not analyzed since the last edit",
+        );
+    };
+    let threshold = threshold as f64;
+    let (status, relation) = if score >= threshold {
+        (CheckStatus::Fail, "at or above")
+    } else if score >= threshold * (1.0 - PERPLEXITY_MARGIN) {
+        (CheckStatus::Warn, "just below")
+    } else {
+        (CheckStatus::Pass, "below")
+    };
+    ChecklistItem::new(
+        "perplexity",
+        status,
+        format!(
+            "Score {:.1} is {} the threshold {:.0}",
+            score, relation, threshold
+        ),
+    )
+}
+
+/// Lowercased words of `text`
+fn words(text: &str) -> HashSet<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether the proposal names the client: any distinctive word of the name
+/// ("Acme" for "Acme Corp"), or the whole name if it has none
+fn mentions_client(text: &str, client_name: &str) -> bool {
+    let name_words: Vec<String> = words(client_name)
+        .into_iter()
+        .filter(|w| w.chars().count() >= 2 && !CLIENT_NAME_STOPWORDS.contains(&w.as_str()))
+        .collect();
+    if name_words.is_empty() {
+        return text
+            .to_lowercase()
+            .contains(&client_name.trim().to_lowercase());
+    }
+    let text_words = words(text);
+    name_words.iter().any(|w| text_words.contains(w))
+}
+
+fn client_name_check(text: &str, job_linked: bool, client_name: Option<&str>) -> ChecklistItem {
+    if !job_linked {
+        return ChecklistItem::new("clientName", CheckStatus::Unknown, "No linked job post");
+    }
+    let Some(name) = client_name.filter(|n| !n.trim().is_empty()) else {
+        return ChecklistItem::new(
+            "clientName",
+            CheckStatus::Unknown,
+            "No client name extracted from the job post",
+        );
+    };
+    if mentions_client(text, name) {
+        ChecklistItem::new(
+            "clientName",
+            CheckStatus::Pass,
+            format!("Mentions {}", name),
+        )
+    } else {
+        ChecklistItem::new(
+            "clientName",
+            CheckStatus::Warn,
+            format!("Doesn't mention {}", name),
+        )
+    }
+}
+
+/// Keyword coverage of `items`. Only key skills can fail: hidden needs are
+/// phrased abstractly, so a missed keyword is weaker evidence.
+fn coverage_check(
+    check: &str,
+    noun: &str,
+    text: &str,
+    job_linked: bool,
+    items: &[String],
+    can_fail: bool,
+) -> ChecklistItem {
+    if !job_linked {
+        return ChecklistItem::new(check, CheckStatus::Unknown, "No linked job post");
+    }
+    if items.is_empty() {
+        return ChecklistItem::new(
+            check,
+            CheckStatus::Unknown,
+            format!("No {} extracted from the job post", noun),
+        );
+    }
+    let coverage = keyword_coverage(text, items);
+    let pct = coverage.coverage_percentage;
+    let status = if coverage.missing.is_empty() || (can_fail && pct >= SKILLS_PASS_PCT) {
+        CheckStatus::Pass
+    } else if can_fail && pct < SKILLS_FAIL_PCT {
+        CheckStatus::Fail
+    } else {
+        CheckStatus::Warn
+    };
+    ChecklistItem {
+        matched: Some(coverage.addressed.clone()),
+        missing: Some(coverage.missing.clone()),
+        ..ChecklistItem::new(
+            check,
+            status,
+            format!(
+                "{} of {} {} mentioned ({}%)",
+                coverage.addressed.len(),
+                items.len(),
+                noun,
+                pct
+            ),
+        )
+    }
+}
+
+fn humanization_check(text: &str) -> ChecklistItem {
+    let metrics = humanization::analyze_humanization(text);
+    let tells = &metrics.ai_tells_found;
+    let status = match tells.len() {
+        0 => CheckStatus::Pass,
+        n if n < AI_TELLS_FAIL_COUNT => CheckStatus::Warn,
+        _ => CheckStatus::Fail,
+    };
+    let tells_detail = if tells.is_empty() {
+        "no AI tell phrases".to_string()
+    } else {
+        format!("AI tell phrases: {}", tells.join(", "))
+    };
+    ChecklistItem::new(
+        "humanization",
+        status,
+        format!(
+            "{:.1} humanizing elements per 100 words, {} contractions, burstiness {:.2}; {}",
+            metrics.rate_per_100_words,
+            metrics.contraction_count,
+            metrics.burstiness_index,
+            tells_detail
+        ),
+    )
+}
+
+fn overrides_check(overrides: &[safety_overrides::SafetyOverride]) -> ChecklistItem {
+    let Some(latest) = overrides.first() else {
+        return ChecklistItem::new(
+            "safetyOverrides",
+            CheckStatus::Pass,
+            "No safety overrides on record",
+        );
+    };
+    ChecklistItem::new(
+        "safetyOverrides",
+        CheckStatus::Warn,
+        format!(
+            "AI detection warning overridden {} time(s); latest score {:.0} vs threshold {:.0} ({})",
+            overrides.len(),
+            latest.ai_score,
+            latest.threshold_at_override,
+            latest.status
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use tempfile::tempdir;
+
+    fn item<'a>(checklist: &'a PresendChecklist, check: &str) -> &'a ChecklistItem {
+        checklist.items.iter().find(|i| i.check == check).unwrap()
+    }
+
+    #[test]
+    fn test_missing_data_degrades_to_unknown() {
+        let inputs = PresendInputs {
+            proposal_id: 1,
+            text: "I'd love to help. I've done this before.".to_string(),
+            ..Default::default()
+        };
+        let checklist = build_presend_checklist(&inputs, 180);
+
+        for check in [
+            "length",
+            "perplexity",
+            "clientName",
+            "keySkills",
+            "hiddenNeeds",
+        ] {
+            assert_eq!(
+                item(&checklist, check).status,
+                CheckStatus::Unknown,
+                "{}",
+                check
+            );
+        }
+        assert_eq!(
+            item(&checklist, "safetyOverrides").status,
+            CheckStatus::Pass
+        );
+        assert_eq!(checklist.overall, CheckStatus::Pass);
+        assert!(item(&checklist, "keySkills").matched.is_none());
+    }
+
+    #[test]
+    fn test_checks_against_linked_job() {
+        let text = "Hi Acme team, I've shipped React and TypeScript dashboards. \
+                    I can start today and deliver fast."
+            .to_string();
+        let inputs = PresendInputs {
+            proposal_id: 7,
+            text: text.clone(),
+            job_post_id: Some(3),
+            client_name: Some("Acme Corp".to_string()),
+            target_words: Some(proposal_length::word_count(&text) as u32),
+            perplexity_score: Some(170.0),
+            key_skills: vec![
+                "React".to_string(),
+                "TypeScript".to_string(),
+                "GraphQL".to_string(),
+            ],
+            hidden_needs: vec!["Needs fast delivery".to_string()],
+            ..Default::default()
+        };
+        let checklist = build_presend_checklist(&inputs, 180);
+
+        assert_eq!(item(&checklist, "length").status, CheckStatus::Pass);
+        // 170 is within 10% below 180
+        assert_eq!(item(&checklist, "perplexity").status, CheckStatus::Warn);
+        assert_eq!(item(&checklist, "clientName").status, CheckStatus::Pass);
+
+        let skills = item(&checklist, "keySkills");
+        assert_eq!(skills.status, CheckStatus::Pass);
+        assert_eq!(
+            skills.matched.as_deref(),
+            Some(&["React".to_string(), "TypeScript".to_string()][..])
+        );
+        assert_eq!(
+            skills.missing.as_deref(),
+            Some(&["GraphQL".to_string()][..])
+        );
+        assert_eq!(item(&checklist, "hiddenNeeds").status, CheckStatus::Pass);
+        assert_eq!(checklist.overall, CheckStatus::Warn);
+
+        let unnamed = PresendInputs {
+            client_name: Some("Globex LLC".to_string()),
+            perplexity_score: Some(200.0),
+            target_words: Some(300),
+            ..inputs
+        };
+        let checklist = build_presend_checklist(&unnamed, 180);
+        assert_eq!(item(&checklist, "clientName").status, CheckStatus::Warn);
+        assert_eq!(item(&checklist, "perplexity").status, CheckStatus::Fail);
+        assert_eq!(item(&checklist, "length").status, CheckStatus::Fail);
+        assert_eq!(checklist.overall, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn test_load_inputs_from_database() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+
+        assert!(load_presend_inputs(&conn, 999).unwrap().is_none());
+
+        let job_id =
+            job_posts::insert_job_post(&conn, None, "Need a React dev", Some("Acme Corp")).unwrap();
+        job_posts::insert_job_skills(&conn, job_id, &["React".to_string()]).unwrap();
+        job_posts::update_job_post_hidden_needs(&conn, job_id, "not json").unwrap();
+        let proposal_id = proposals::insert_proposal_with_context(
+            &conn,
+            "Need a React dev",
+            "Hi Acme, I build React apps.",
+            Some("completed"),
+            None,
+            Some(job_id),
+        )
+        .unwrap();
+        proposals::store_perplexity_result(&conn, proposal_id, 150.0, 0).unwrap();
+        safety_overrides::record_override(&conn, proposal_id, 190.0, 180.0).unwrap();
+
+        let inputs = load_presend_inputs(&conn, proposal_id).unwrap().unwrap();
+        assert_eq!(inputs.client_name.as_deref(), Some("Acme Corp"));
+        assert_eq!(inputs.key_skills, vec!["React"]);
+        assert!(inputs.hidden_needs.is_empty());
+        assert_eq!(inputs.perplexity_score, Some(150.0));
+        assert_eq!(inputs.intensity, None);
+        assert_eq!(inputs.overrides.len(), 1);
+
+        let checklist = build_presend_checklist(&inputs, 180);
+        assert_eq!(
+            item(&checklist, "safetyOverrides").status,
+            CheckStatus::Warn
+        );
+        assert_eq!(item(&checklist, "hiddenNeeds").status, CheckStatus::Unknown);
+    }
+}
@@ -0,0 +1,568 @@
+//! Single-proposal bundles for archival
+//!
+//! `export_proposals_to_json` only writes current proposal text. A bundle is a
+//! self-contained JSON document for ONE proposal: its row, every active and
+//! archived revision, its generation metadata, and the job post it was written
+//! for (with the job's skills and score, i.e. the analysis).
+//!
+//! Rows are carried as column → value maps read with `SELECT *`, and imported
+//! by matching against `PRAGMA table_info` (the same approach as the archive
+//! importer), so a bundle from an older or newer schema still restores the
+//! columns both sides have.
+//!
+//! Importing never reuses the bundle's IDs: the job post, proposal and every
+//! revision get fresh IDs, and `restored_from_id` links are rewritten through
+//! the old → new ID map. Archived revisions are inserted into
+//! `proposal_revisions` to reserve an ID (the table is AUTOINCREMENT, so IDs
+//! are never handed out again) and then moved into the compressed archive,
+//! exactly as `archive_old_revisions` would.
+
+use crate::archive::{self, ArchivedRevision};
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Bundle format version; bundles with a newer version are rejected
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// Proposal columns that are not carried as-is (re-created on import)
+const PROPOSAL_SKIPPED_COLUMNS: [&str; 3] = ["id", "job_post_id", "archived_revisions"];
+
+/// A proposal with everything needed to restore it on another machine
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalBundle {
+    pub format_version: u32,
+    pub exported_at: String,
+    pub app_version: String,
+    /// ID of the proposal in the exporting database (informational only)
+    pub source_proposal_id: i64,
+    /// Proposal row, without id, job_post_id and archived_revisions
+    pub proposal: Map<String, Value>,
+    /// Active and archived revisions, oldest first
+    pub revisions: Vec<BundleRevision>,
+    pub generation_metadata: Option<Map<String, Value>>,
+    pub job_post: Option<BundleJobPost>,
+}
+
+/// One revision. IDs are the exporting database's and are remapped on import.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleRevision {
+    pub id: i64,
+    pub content: String,
+    pub revision_type: String,
+    pub restored_from_id: Option<i64>,
+    /// None for archived revisions (the archive does not keep it)
+    pub revision_number: Option<i64>,
+    pub created_at: String,
+    /// True if the revision was in the compressed archive
+    pub archived: bool,
+}
+
+/// The job post the proposal was written for, with its analysis
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleJobPost {
+    /// job_posts row, without id
+    pub job_post: Map<String, Value>,
+    pub skills: Vec<String>,
+    /// job_scores row, without id and job_post_id
+    pub score: Option<Map<String, Value>>,
+}
+
+/// Result of importing a bundle
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleImportResult {
+    pub proposal_id: i64,
+    pub job_post_id: Option<i64>,
+    pub revision_count: usize,
+    pub archived_revision_count: usize,
+}
+
+/// Build the bundle for a proposal. Returns Ok(None) if it does not exist.
+pub fn build_bundle(conn: &Connection, proposal_id: i64) -> Result<Option<ProposalBundle>, String> {
+    let Some(mut proposal) = read_row(conn, "proposals", "id", proposal_id)? else {
+        return Ok(None);
+    };
+    let job_post_id = proposal.get("job_post_id").and_then(Value::as_i64);
+    for column in PROPOSAL_SKIPPED_COLUMNS {
+        proposal.remove(column);
+    }
+
+    let mut revisions: Vec<BundleRevision> =
+        crate::db::queries::revisions::get_archived_revisions(conn, proposal_id)?
+            .into_iter()
+            .map(|r| BundleRevision {
+                id: r.id,
+                content: r.content,
+                revision_type: r.revision_type,
+                restored_from_id: r.restored_from_id,
+                revision_number: None,
+                created_at: r.created_at,
+                archived: true,
+            })
+            .collect();
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, revision_type, restored_from_id, revision_number, created_at
+             FROM proposal_revisions
+             WHERE proposal_id = ?1",
+        )
+        .map_err(|e| format!("Failed to read revisions: {}", e))?;
+    let active = stmt
+        .query_map(params![proposal_id], |row| {
+            Ok(BundleRevision {
+                id: row.get(0)?,
+                content: String::new(),
+                revision_type: row.get(1)?,
+                restored_from_id: row.get(2)?,
+                revision_number: row.get(3)?,
+                created_at: row.get(4)?,
+                archived: false,
+            })
+        })
+        .map_err(|e| format!("Failed to read revisions: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read revisions: {}", e))?;
+    // Full text, with deltas reconstructed
+    for mut revision in active {
+        revision.content = crate::db::queries::revisions::get_revision(conn, revision.id)
+            .map_err(|e| format!("Failed to read revision {}: {}", revision.id, e))?
+            .content;
+        revisions.push(revision);
+    }
+    revisions.sort_by_key(|r| r.id);
+
+    let generation_metadata = read_row(conn, "generation_metadata", "proposal_id", proposal_id)?
+        .map(|mut row| {
+            row.remove("id");
+            row.remove("proposal_id");
+            row
+        });
+
+    let job_post = match job_post_id {
+        Some(job_post_id) => read_job_post(conn, job_post_id)?,
+        None => None,
+    };
+
+    Ok(Some(ProposalBundle {
+        format_version: BUNDLE_FORMAT_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        source_proposal_id: proposal_id,
+        proposal,
+        revisions,
+        generation_metadata,
+        job_post,
+    }))
+}
+
+fn read_job_post(conn: &Connection, job_post_id: i64) -> Result<Option<BundleJobPost>, String> {
+    let Some(mut job_post) = read_row(conn, "job_posts", "id", job_post_id)? else {
+        return Ok(None);
+    };
+    job_post.remove("id");
+
+    let skills = crate::db::queries::job_posts::get_job_skills(conn, job_post_id)
+        .map_err(|e| format!("Failed to read job skills: {}", e))?;
+    let score = read_row(conn, "job_scores", "job_post_id", job_post_id)?.map(|mut row| {
+        row.remove("id");
+        row.remove("job_post_id");
+        row
+    });
+
+    Ok(Some(BundleJobPost {
+        job_post,
+        skills,
+        score,
+    }))
+}
+
+/// Restore a bundle as a new proposal, in one transaction. All IDs are freshly
+/// assigned; revision links are remapped to the new revision IDs.
+pub fn import_bundle(
+    conn: &Connection,
+    bundle: &ProposalBundle,
+) -> Result<BundleImportResult, String> {
+    if bundle.format_version > BUNDLE_FORMAT_VERSION {
+        return Err(format!(
+            "Bundle format version {} is newer than supported version {}",
+            bundle.format_version, BUNDLE_FORMAT_VERSION
+        ));
+    }
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    let job_post_id = match &bundle.job_post {
+        Some(job) => {
+            let job_post_id = insert_row(&tx, "job_posts", &job.job_post, &[])?;
+            crate::db::queries::job_posts::insert_job_skills(&tx, job_post_id, &job.skills)
+                .map_err(|e| format!("Failed to insert job skills: {}", e))?;
+            if let Some(score) = &job.score {
+                insert_row(
+                    &tx,
+                    "job_scores",
+                    score,
+                    &[("job_post_id", SqlValue::Integer(job_post_id))],
+                )?;
+            }
+            Some(job_post_id)
+        }
+        None => None,
+    };
+
+    let job_post_value = job_post_id.map_or(SqlValue::Null, SqlValue::Integer);
+    let proposal_id = insert_row(
+        &tx,
+        "proposals",
+        &bundle.proposal,
+        &[("job_post_id", job_post_value)],
+    )?;
+
+    if let Some(metadata) = &bundle.generation_metadata {
+        insert_row(
+            &tx,
+            "generation_metadata",
+            metadata,
+            &[("proposal_id", SqlValue::Integer(proposal_id))],
+        )?;
+    }
+
+    // Reserve a new ID for every revision (oldest first) before linking them
+    let mut revisions: Vec<&BundleRevision> = bundle.revisions.iter().collect();
+    revisions.sort_by_key(|r| r.id);
+    let mut new_ids: HashMap<i64, i64> = HashMap::new();
+    for (index, revision) in revisions.iter().enumerate() {
+        tx.execute(
+            "INSERT INTO proposal_revisions
+                (proposal_id, content, revision_type, revision_number, created_at, content_hash)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                proposal_id,
+                revision.content,
+                revision.revision_type,
+                revision.revision_number.unwrap_or(index as i64 + 1),
+                revision.created_at,
+                crate::db::queries::revisions::revision_content_hash(&revision.content),
+            ],
+        )
+        .map_err(|e| format!("Failed to insert revision {}: {}", revision.id, e))?;
+        new_ids.insert(revision.id, tx.last_insert_rowid());
+    }
+
+    let mut archived = Vec::new();
+    for revision in &revisions {
+        let new_id = new_ids[&revision.id];
+        let restored_from_id = revision
+            .restored_from_id
+            .and_then(|old| new_ids.get(&old).copied());
+        if revision.archived {
+            archived.push(ArchivedRevision {
+                id: new_id,
+                proposal_id,
+                content: revision.content.clone(),
+                revision_type: revision.revision_type.clone(),
+                restored_from_id,
+                created_at: revision.created_at.clone(),
+            });
+            continue;
+        }
+        // The active table's foreign key cannot point at an archived revision
+        // (the live schema can't hold that link either), so only active → active
+        // links are stored on the row.
+        let target_is_active = revision
+            .restored_from_id
+            .and_then(|old| bundle.revisions.iter().find(|r| r.id == old))
+            .is_some_and(|target| !target.archived);
+        if target_is_active {
+            tx.execute(
+                "UPDATE proposal_revisions SET restored_from_id = ?1 WHERE id = ?2",
+                params![restored_from_id, new_id],
+            )
+            .map_err(|e| format!("Failed to link revision {}: {}", revision.id, e))?;
+        }
+    }
+
+    if !archived.is_empty() {
+        let blob = archive::compress_revisions(&archived)?;
+        tx.execute(
+            "UPDATE proposals SET archived_revisions = ?1 WHERE id = ?2",
+            params![blob, proposal_id],
+        )
+        .map_err(|e| format!("Failed to store archived revisions: {}", e))?;
+        for revision in &archived {
+            tx.execute(
+                "DELETE FROM proposal_revisions WHERE id = ?1",
+                params![revision.id],
+            )
+            .map_err(|e| format!("Failed to archive revision {}: {}", revision.id, e))?;
+        }
+    }
+
+    tx.commit()
+        .map_err(|e| format!("Failed to commit bundle import: {}", e))?;
+
+    tracing::info!(
+        source_proposal_id = bundle.source_proposal_id,
+        proposal_id,
+        revisions = bundle.revisions.len(),
+        archived = archived.len(),
+        "Imported proposal bundle"
+    );
+
+    Ok(BundleImportResult {
+        proposal_id,
+        job_post_id,
+        revision_count: bundle.revisions.len() - archived.len(),
+        archived_revision_count: archived.len(),
+    })
+}
+
+/// Read one row as a column → JSON map (BLOB columns are skipped)
+fn read_row(
+    conn: &Connection,
+    table: &str,
+    key_column: &str,
+    key: i64,
+) -> Result<Option<Map<String, Value>>, String> {
+    let sql = format!("SELECT * FROM {} WHERE {} = ?1", table, key_column);
+    let mut stmt = conn
+        .prepare(&sql)
+        .map_err(|e| format!("Failed to read {}: {}", table, e))?;
+    let columns: Vec<String> = stmt.column_names().iter().map(|c| c.to_string()).collect();
+
+    stmt.query_row(params![key], |row| {
+        let mut map = Map::new();
+        for (index, column) in columns.iter().enumerate() {
+            let value = match row.get::<_, SqlValue>(index)? {
+                SqlValue::Null => Value::Null,
+                SqlValue::Integer(i) => Value::from(i),
+                SqlValue::Real(f) => Value::from(f),
+                SqlValue::Text(s) => Value::from(s),
+                SqlValue::Blob(_) => continue,
+            };
+            map.insert(column.clone(), value);
+        }
+        Ok(map)
+    })
+    .optional()
+    .map_err(|e| format!("Failed to read {}: {}", table, e))
+}
+
+/// Insert a row from a column → JSON map, keeping only columns the table has.
+/// `id` is never copied; `overrides` replace values from the map.
+fn insert_row(
+    conn: &Connection,
+    table: &str,
+    row: &Map<String, Value>,
+    overrides: &[(&str, SqlValue)],
+) -> Result<i64, String> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({})", table))
+        .map_err(|e| format!("Failed to read {} schema: {}", table, e))?;
+    let table_columns: Vec<String> = stmt
+        .query_map([], |r| r.get(1))
+        .map_err(|e| format!("Failed to read {} schema: {}", table, e))?
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to read {} schema: {}", table, e))?;
+
+    let mut columns = Vec::new();
+    let mut values = Vec::new();
+    for column in table_columns.iter().filter(|c| c.as_str() != "id") {
+        if let Some((_, value)) = overrides.iter().find(|(name, _)| name == column) {
+            columns.push(column.as_str());
+            values.push(value.clone());
+        } else if let Some(value) = row.get(column) {
+            columns.push(column.as_str());
+            values.push(json_to_sql(value));
+        }
+    }
+
+    let placeholders = (1..=columns.len())
+        .map(|i| format!("?{}", i))
+        .collect::<Vec<_>>()
+        .join(", ");
+    conn.execute(
+        &format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            table,
+            columns.join(", "),
+            placeholders
+        ),
+        rusqlite::params_from_iter(values),
+    )
+    .map_err(|e| format!("Failed to insert into {}: {}", table, e))?;
+    Ok(conn.last_insert_rowid())
+}
+
+fn json_to_sql(value: &Value) -> SqlValue {
+    match value {
+        Value::Null => SqlValue::Null,
+        Value::Bool(b) => SqlValue::Integer(*b as i64),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => SqlValue::Integer(i),
+            None => SqlValue::Real(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => SqlValue::Text(s.clone()),
+        other => SqlValue::Text(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::queries::{generation_metadata, proposals, revisions};
+    use crate::db::Database;
+
+    /// Proposal with a job post, metadata, 7 revisions (2 archived) and a
+    /// restore link from the newest revision to an older active one
+    fn seed_source(conn: &mut Connection) -> i64 {
+        let job_post_id = crate::db::queries::job_posts::insert_job_post(
+            conn,
+            Some("https://example.com/job/1"),
+            "Need a Rust developer",
+            Some("Acme"),
+        )
+        .unwrap();
+        crate::db::queries::job_posts::insert_job_skills(
+            conn,
+            job_post_id,
+            &["Rust".to_string(), "SQLite".to_string()],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO job_scores (job_post_id, overall_score) VALUES (?1, 82.5)",
+            [job_post_id],
+        )
+        .unwrap();
+
+        let proposal_id =
+            proposals::insert_proposal(conn, "Need a Rust developer", "v7", Some("completed"))
+                .unwrap();
+        conn.execute(
+            "UPDATE proposals SET job_post_id = ?1 WHERE id = ?2",
+            [job_post_id, proposal_id],
+        )
+        .unwrap();
+        generation_metadata::insert_generation_metadata(
+            conn,
+            proposal_id,
+            &generation_metadata::NewGenerationMetadata {
+                model: "claude-sonnet-4-5".to_string(),
+                humanization_intensity: "medium".to_string(),
+                hook_strategy_id: Some("social_proof".to_string()),
+                voice_profile_version: None,
+                voice_profile_name: None,
+                target_words: Some(250),
+                api_key_profile: None,
+                generation_context: None,
+            },
+        )
+        .unwrap();
+
+        let mut ids = Vec::new();
+        for n in 1..=6 {
+            let kind = if n == 1 { "generation" } else { "edit" };
+            ids.push(
+                revisions::create_revision(conn, proposal_id, &format!("v{}", n), kind, None)
+                    .unwrap()
+                    .id,
+            );
+            conn.execute(
+                "UPDATE proposal_revisions SET created_at = datetime('now', ?1) WHERE id = ?2",
+                rusqlite::params![format!("-{} minutes", 10 - n), ids[n - 1]],
+            )
+            .unwrap();
+        }
+        // Restore v4 (stays active after archiving)
+        revisions::create_revision(conn, proposal_id, "v4", "restore", Some(ids[3])).unwrap();
+        assert_eq!(
+            revisions::archive_old_revisions(conn, proposal_id).unwrap(),
+            2
+        );
+        proposal_id
+    }
+
+    #[test]
+    fn test_bundle_round_trip_remaps_ids() {
+        let source_dir = tempfile::tempdir().unwrap();
+        let source = Database::new(source_dir.path().join("source.db"), None).unwrap();
+        let mut source_conn = source.conn.lock().unwrap();
+        let source_id = seed_source(&mut source_conn);
+
+        let bundle = build_bundle(&source_conn, source_id).unwrap().unwrap();
+        assert_eq!(bundle.revisions.len(), 7);
+        assert_eq!(bundle.revisions.iter().filter(|r| r.archived).count(), 2);
+        assert!(!bundle.proposal.contains_key("id"));
+        let json = serde_json::to_string(&bundle).unwrap();
+
+        // Target already has rows with the same IDs
+        let target_dir = tempfile::tempdir().unwrap();
+        let target = Database::new(target_dir.path().join("target.db"), None).unwrap();
+        let mut target_conn = target.conn.lock().unwrap();
+        let existing = seed_source(&mut target_conn);
+
+        let restored: ProposalBundle = serde_json::from_str(&json).unwrap();
+        let result = import_bundle(&target_conn, &restored).unwrap();
+        assert_ne!(result.proposal_id, existing);
+        assert_eq!(result.revision_count, 5);
+        assert_eq!(result.archived_revision_count, 2);
+
+        // Existing proposal is untouched
+        assert_eq!(
+            revisions::get_revisions(&target_conn, existing)
+                .unwrap()
+                .len(),
+            5
+        );
+
+        let proposal = proposals::get_proposal(&target_conn, result.proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(proposal.generated_text, "v7");
+
+        let active = revisions::get_revisions(&target_conn, result.proposal_id).unwrap();
+        let old_ids: Vec<i64> = bundle.revisions.iter().map(|r| r.id).collect();
+        assert!(active.iter().all(|r| !old_ids.contains(&r.id)));
+        let restore = active
+            .iter()
+            .find(|r| r.revision_type == "restore")
+            .unwrap();
+        let restored_from =
+            revisions::get_revision(&target_conn, restore.restored_from_id.unwrap()).unwrap();
+        assert_eq!(restored_from.proposal_id, result.proposal_id);
+        assert_eq!(restored_from.content, "v4");
+
+        let archived = revisions::get_archived_revisions(&target_conn, result.proposal_id).unwrap();
+        assert_eq!(
+            archived
+                .iter()
+                .map(|r| r.content.as_str())
+                .collect::<Vec<_>>(),
+            vec!["v1", "v2"]
+        );
+        assert!(archived.iter().all(|r| !old_ids.contains(&r.id)));
+
+        let metadata =
+            generation_metadata::get_generation_metadata(&target_conn, result.proposal_id).unwrap();
+        assert_eq!(metadata.hook_strategy_id, "social_proof");
+        let job_post_id = result.job_post_id.unwrap();
+        assert_eq!(
+            crate::db::queries::job_posts::get_job_skills(&target_conn, job_post_id)
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // Re-importing creates another independent copy
+        let again = import_bundle(&target_conn, &restored).unwrap();
+        assert_ne!(again.proposal_id, result.proposal_id);
+    }
+}
@@ -0,0 +1,154 @@
+//! Proposal length targeting for generation.
+//!
+//! A length target is injected into the system prompt as a brevity/detail
+//! instruction and also sizes `max_tokens`. `max_tokens` always keeps generous
+//! headroom above the target: a short proposal comes from the instruction, never
+//! from cutting the response off mid-sentence.
+//!
+//! When the user doesn't pick a target, the calibrated
+//! `VoiceProfile::length_preference` (1-10, brief to detailed) is used.
+
+use serde::{Deserialize, Serialize};
+
+/// Floor for `max_tokens` (the pre-length-control default)
+const MIN_MAX_TOKENS: u32 = 1024;
+const MAX_MAX_TOKENS: u32 = 4096;
+
+/// Explicit word-count targets are clamped to this range
+const MIN_TARGET_WORDS: u32 = 50;
+const MAX_TARGET_WORDS: u32 = 800;
+
+/// Requested proposal length
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LengthTarget {
+    /// Tight pitch, ~3-5 sentences
+    Short,
+    /// Default proposal length
+    Medium,
+    /// Detailed breakdown of approach and deliverables
+    Long,
+    /// Explicit target word count
+    Words(u32),
+}
+
+impl LengthTarget {
+    /// Parse "short" / "medium" / "long" or a target word count (e.g. "250")
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let value = value.trim();
+        match value.to_lowercase().as_str() {
+            "short" => Ok(Self::Short),
+            "medium" => Ok(Self::Medium),
+            "long" => Ok(Self::Long),
+            other => other
+                .parse::<u32>()
+                .map(|words| Self::Words(words.clamp(MIN_TARGET_WORDS, MAX_TARGET_WORDS)))
+                .map_err(|_| {
+                    format!(
+                        "Invalid length preference: {}. Must be short, medium, long, or a word count",
+                        value
+                    )
+                }),
+        }
+    }
+
+    /// Map the calibrated voice `length_preference` (1-10) to a target
+    pub fn from_voice_preference(length_preference: f32) -> Self {
+        match length_preference.round() as i32 {
+            i32::MIN..=3 => Self::Short,
+            4..=7 => Self::Medium,
+            _ => Self::Long,
+        }
+    }
+
+    /// Approximate target word count
+    pub fn target_words(&self) -> u32 {
+        match self {
+            Self::Short => 100,
+            Self::Medium => 200,
+            Self::Long => 350,
+            Self::Words(words) => *words,
+        }
+    }
+
+    /// `max_tokens` for the API call: ~3 tokens per target word, never below the old default
+    pub fn max_tokens(&self) -> u32 {
+        (self.target_words() * 3).clamp(MIN_MAX_TOKENS, MAX_MAX_TOKENS)
+    }
+
+    /// Length instruction appended to the system prompt.
+    /// Replaces the base prompt's "under 200 words" guideline.
+    pub fn prompt_instruction(&self) -> String {
+        let style = match self {
+            Self::Short => "a tight pitch of 3-5 sentences: hook, one line of proof, call to action",
+            Self::Medium => "a focused proposal of a few short paragraphs",
+            Self::Long => {
+                "a detailed proposal that walks through your approach, relevant experience, and deliverables"
+            }
+            Self::Words(_) => "a proposal close to the target length",
+        };
+        format!(
+            "\n\nLENGTH TARGET (replaces the 200-word guideline above): about {} words — {}. \
+Reach the length by choosing what to include, not by stopping early: always finish with a complete closing sentence and call to action.",
+            self.target_words(),
+            style
+        )
+    }
+}
+
+/// Count words in generated text (whitespace-separated)
+pub fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_labels_and_word_counts() {
+        assert_eq!(LengthTarget::parse("short").unwrap(), LengthTarget::Short);
+        assert_eq!(LengthTarget::parse(" Long ").unwrap(), LengthTarget::Long);
+        assert_eq!(
+            LengthTarget::parse("250").unwrap(),
+            LengthTarget::Words(250)
+        );
+        // Clamped to a sane range
+        assert_eq!(LengthTarget::parse("5").unwrap(), LengthTarget::Words(50));
+        assert_eq!(
+            LengthTarget::parse("5000").unwrap(),
+            LengthTarget::Words(800)
+        );
+        assert!(LengthTarget::parse("tiny").is_err());
+    }
+
+    #[test]
+    fn test_from_voice_preference() {
+        assert_eq!(
+            LengthTarget::from_voice_preference(1.0),
+            LengthTarget::Short
+        );
+        assert_eq!(
+            LengthTarget::from_voice_preference(5.0),
+            LengthTarget::Medium
+        );
+        assert_eq!(LengthTarget::from_voice_preference(9.0), LengthTarget::Long);
+    }
+
+    #[test]
+    fn test_short_target_keeps_token_headroom() {
+        // Short is achieved by instruction, not by truncation
+        assert_eq!(LengthTarget::Short.max_tokens(), MIN_MAX_TOKENS);
+        assert!(LengthTarget::Short
+            .prompt_instruction()
+            .contains("complete closing"));
+        assert!(LengthTarget::Long.max_tokens() > LengthTarget::Medium.max_tokens());
+        assert_eq!(LengthTarget::Words(800).max_tokens(), 2400);
+    }
+
+    #[test]
+    fn test_word_count() {
+        assert_eq!(word_count("  Hi there,\n\nI can help.  "), 5);
+        assert_eq!(word_count(""), 0);
+    }
+}
@@ -0,0 +1,225 @@
+//! Proposal quality score
+//!
+//! Combines three checks into one weighted 0-100 "is this a good proposal?"
+//! number, in the same spirit as the job scoring formula (`scoring.rs`):
+//! - Perplexity (AI detection risk, lower is better relative to the safety threshold)
+//! - Voice match against the calibrated voice profile
+//! - Coverage of the job's required deliverables
+//!
+//! A component that can't be computed (no API key, no voice profile, no job
+//! requirements) is skipped and the remaining weights are renormalized, so a
+//! missing input never drags the score down.
+
+use crate::settings::schema::{get_typed, Key};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+/// Settings keys for the component weights (any non-negative number; relative, not percentages)
+pub const QUALITY_WEIGHT_SETTINGS: [&str; 3] = [
+    "quality_weight_perplexity",
+    "quality_weight_voice",
+    "quality_weight_coverage",
+];
+
+/// Relative component weights. Defaults: perplexity 40%, voice 30%, coverage 30%.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QualityWeights {
+    pub perplexity: f64,
+    pub voice_match: f64,
+    pub coverage: f64,
+}
+
+impl Default for QualityWeights {
+    fn default() -> Self {
+        Self {
+            perplexity: 0.4,
+            voice_match: 0.3,
+            coverage: 0.3,
+        }
+    }
+}
+
+impl QualityWeights {
+    /// Read the `quality_weight_*` settings through the settings schema (negative
+    /// values clamp to 0; unset or unparsable values use the default)
+    pub fn from_settings(conn: &Connection) -> Self {
+        let defaults = Self::default();
+        let read = |key: Key, default: f64| get_typed(conn, key).unwrap_or(default);
+        Self {
+            perplexity: read(Key::QualityWeightPerplexity, defaults.perplexity),
+            voice_match: read(Key::QualityWeightVoice, defaults.voice_match),
+            coverage: read(Key::QualityWeightCoverage, defaults.coverage),
+        }
+    }
+}
+
+/// One component of the quality score
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QualityComponent {
+    /// "perplexity", "voiceMatch", or "coverage"
+    pub name: String,
+    /// Component score 0-100, None when skipped
+    pub score: Option<f64>,
+    /// Configured weight
+    pub weight: f64,
+    /// Share of the overall score after renormalizing over available components (0-1)
+    pub effective_weight: f64,
+    /// Why the component was skipped
+    pub skipped_reason: Option<String>,
+}
+
+/// Result of `score_proposal_quality`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalQualityScore {
+    /// Weighted score 0-100 (1 decimal), None when every component was skipped
+    pub overall_score: Option<f64>,
+    pub components: Vec<QualityComponent>,
+    /// Names of skipped components
+    pub skipped: Vec<String>,
+}
+
+/// Map a perplexity score to 0-100 relative to the safety threshold.
+/// Half the threshold or lower scores 100, the threshold itself 50, 1.5x the threshold 0.
+pub fn perplexity_component_score(perplexity: f32, threshold: f32) -> f64 {
+    if threshold <= 0.0 {
+        return 0.0;
+    }
+    let ratio = perplexity as f64 / threshold as f64;
+    ((1.5 - ratio) * 100.0).clamp(0.0, 100.0)
+}
+
+/// Combine component scores (`Err` = skipped, with the reason) into the quality score
+pub fn calculate_quality_score(
+    perplexity: Result<f64, String>,
+    voice_match: Result<f64, String>,
+    coverage: Result<f64, String>,
+    weights: &QualityWeights,
+) -> ProposalQualityScore {
+    let inputs = [
+        ("perplexity", perplexity, weights.perplexity),
+        ("voiceMatch", voice_match, weights.voice_match),
+        ("coverage", coverage, weights.coverage),
+    ];
+
+    let available_weight: f64 = inputs
+        .iter()
+        .filter(|(_, score, _)| score.is_ok())
+        .map(|(_, _, weight)| weight)
+        .sum();
+
+    let mut overall = 0.0;
+    let mut components = Vec::with_capacity(inputs.len());
+    let mut skipped = Vec::new();
+
+    for (name, score, weight) in inputs {
+        match score {
+            Ok(score) => {
+                let score = score.clamp(0.0, 100.0);
+                let effective_weight = if available_weight > 0.0 {
+                    weight / available_weight
+                } else {
+                    0.0
+                };
+                overall += score * effective_weight;
+                components.push(QualityComponent {
+                    name: name.to_string(),
+                    score: Some(score),
+                    weight,
+                    effective_weight,
+                    skipped_reason: None,
+                });
+            }
+            Err(reason) => {
+                skipped.push(name.to_string());
+                components.push(QualityComponent {
+                    name: name.to_string(),
+                    score: None,
+                    weight,
+                    effective_weight: 0.0,
+                    skipped_reason: Some(reason),
+                });
+            }
+        }
+    }
+
+    // All available components weighted 0 is as uninformative as none at all
+    let overall_score = (available_weight > 0.0).then(|| (overall * 10.0).round() / 10.0);
+
+    ProposalQualityScore {
+        overall_score,
+        components,
+        skipped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_perplexity_component_score() {
+        assert_eq!(perplexity_component_score(90.0, 180.0), 100.0);
+        assert_eq!(perplexity_component_score(180.0, 180.0), 50.0);
+        assert_eq!(perplexity_component_score(300.0, 180.0), 0.0);
+        assert_eq!(perplexity_component_score(10.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_weighted_score_with_all_components() {
+        let result =
+            calculate_quality_score(Ok(100.0), Ok(50.0), Ok(80.0), &QualityWeights::default());
+        // 100*0.4 + 50*0.3 + 80*0.3
+        assert_eq!(result.overall_score, Some(79.0));
+        assert!(result.skipped.is_empty());
+        assert_eq!(result.components.len(), 3);
+    }
+
+    #[test]
+    fn test_skipped_components_are_reweighted_not_zeroed() {
+        let result = calculate_quality_score(
+            Ok(90.0),
+            Err("No voice profile".to_string()),
+            Err("No job requirements".to_string()),
+            &QualityWeights::default(),
+        );
+        assert_eq!(result.overall_score, Some(90.0));
+        assert_eq!(result.skipped, vec!["voiceMatch", "coverage"]);
+        let voice = &result.components[1];
+        assert_eq!(voice.score, None);
+        assert_eq!(voice.effective_weight, 0.0);
+        assert_eq!(voice.skipped_reason.as_deref(), Some("No voice profile"));
+        assert_eq!(result.components[0].effective_weight, 1.0);
+
+        let none = calculate_quality_score(
+            Err("a".into()),
+            Err("b".into()),
+            Err("c".into()),
+            &QualityWeights::default(),
+        );
+        assert_eq!(none.overall_score, None);
+        assert_eq!(none.skipped.len(), 3);
+    }
+
+    #[test]
+    fn test_weights_from_settings() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        assert_eq!(
+            QualityWeights::from_settings(&conn),
+            QualityWeights::default()
+        );
+
+        crate::db::queries::settings::set_setting(&conn, "quality_weight_voice", "2").unwrap();
+        crate::db::queries::settings::set_setting(&conn, "quality_weight_coverage", "-1").unwrap();
+        let weights = QualityWeights::from_settings(&conn);
+        assert_eq!(weights.voice_match, 2.0);
+        // Out-of-range stored values clamp into the schema's range
+        assert_eq!(weights.coverage, 0.0);
+    }
+}
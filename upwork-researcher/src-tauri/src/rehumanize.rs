@@ -0,0 +1,94 @@
+//! Re-humanization of a saved proposal at a new intensity
+//!
+//! `rehumanize_proposal` asks Claude to restyle the whole proposal at a target
+//! humanization intensity while keeping its meaning and structure, which is
+//! cheaper than regenerating and keeps the hook the user liked. The result is
+//! saved as a `rehumanize` revision (the previous text is kept as a revision
+//! too, so it can be restored), then checked with perplexity analysis against
+//! the proposal's previous score.
+
+use serde::{Deserialize, Serialize};
+
+/// Revision type recorded for a re-humanized proposal
+pub const REHUMANIZE_REVISION_TYPE: &str = "rehumanize";
+
+/// Perplexity of the proposal before and after re-humanization
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PerplexityComparison {
+    pub before: f32,
+    pub after: f32,
+    /// Safety threshold for the target intensity
+    pub threshold: f32,
+    /// The rewrite scores lower (less AI-like) than the previous text
+    pub improved: bool,
+    /// The rewrite scores below the threshold
+    pub passes: bool,
+}
+
+impl PerplexityComparison {
+    pub fn new(before: f32, after: f32, threshold: f32) -> Self {
+        Self {
+            before,
+            after,
+            threshold,
+            improved: after < before,
+            passes: after < threshold,
+        }
+    }
+}
+
+/// Result of `rehumanize_proposal`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RehumanizeResult {
+    pub proposal_id: i64,
+    pub revision_id: i64,
+    pub text: String,
+    /// Target intensity, normalized ("off" / "light" / "medium" / "heavy")
+    pub intensity: String,
+    /// None when the perplexity check failed; the rewrite is saved regardless
+    pub perplexity: Option<PerplexityComparison>,
+    pub perplexity_error: Option<String>,
+}
+
+/// Claude's reply with any echoed `<proposal>` tags removed; an empty rewrite is an error
+pub fn clean_rewrite(response: &str) -> Result<&str, String> {
+    let mut rewrite = response.trim();
+    if let Some(inner) = rewrite.strip_prefix("<proposal>") {
+        rewrite = inner.strip_suffix("</proposal>").unwrap_or(inner).trim();
+    }
+    if rewrite.is_empty() {
+        return Err("Claude returned an empty rewrite".to_string());
+    }
+    Ok(rewrite)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_rewrite_strips_echoed_tags() {
+        assert_eq!(
+            clean_rewrite("<proposal>\nHi Sam,\n\nI've shipped this before.\n</proposal>"),
+            Ok("Hi Sam,\n\nI've shipped this before.")
+        );
+        assert_eq!(clean_rewrite("  Hi Sam,  \n"), Ok("Hi Sam,"));
+        assert!(clean_rewrite("<proposal>\n</proposal>").is_err());
+        assert!(clean_rewrite("   ").is_err());
+    }
+
+    #[test]
+    fn test_perplexity_comparison() {
+        let helped = PerplexityComparison::new(210.0, 150.0, 180.0);
+        assert!(helped.improved && helped.passes);
+
+        let worse = PerplexityComparison::new(150.0, 190.0, 180.0);
+        assert!(!worse.improved && !worse.passes);
+
+        // Lower but still at the threshold
+        let not_enough = PerplexityComparison::new(240.0, 180.0, 180.0);
+        assert!(not_enough.improved && !not_enough.passes);
+    }
+}
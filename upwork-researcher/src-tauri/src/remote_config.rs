@@ -3,6 +3,7 @@
 //! This module handles fetching hook strategy configurations from a remote endpoint,
 //! validating signatures, and falling back to bundled defaults when necessary.
 
+use crate::http::TrackedSend;
 use crate::network;
 use hmac::{Hmac, Mac};
 use rusqlite::{params, Connection};
@@ -91,6 +92,18 @@ pub struct RemoteConfig {
 
     /// Array of strategy configurations
     pub strategies: Vec<RemoteStrategy>,
+
+    /// AI-tell phrases added to the built-in detection list (optional)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ai_tell_phrases: Vec<String>,
+
+    /// Updater feed for the beta update channel (https, optional; see `update_channel`)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "validate_feed_url"
+    )]
+    pub beta_update_feed_url: Option<String>,
 }
 
 // Custom deserializers with validation
@@ -168,6 +181,22 @@ where
     Ok(weight)
 }
 
+fn validate_feed_url<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let url = Option::<String>::deserialize(deserializer)?;
+    if let Some(url) = &url {
+        if !url.starts_with("https://") || url.len() > 2048 {
+            return Err(serde::de::Error::custom(format!(
+                "update feed must be an https URL of at most 2048 characters: {}",
+                url
+            )));
+        }
+    }
+    Ok(url)
+}
+
 fn validate_semver<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -245,6 +274,15 @@ impl From<network::NetworkError> for RemoteConfigError {
     }
 }
 
+impl From<crate::http::HttpError> for RemoteConfigError {
+    fn from(err: crate::http::HttpError) -> Self {
+        match err {
+            crate::http::HttpError::Request(e) => e.into(),
+            locally_limited => RemoteConfigError::NetworkError(locally_limited.to_string()),
+        }
+    }
+}
+
 impl From<reqwest::Error> for RemoteConfigError {
     fn from(err: reqwest::Error) -> Self {
         if err.is_timeout() {
@@ -255,10 +293,76 @@ impl From<reqwest::Error> for RemoteConfigError {
     }
 }
 
-/// Verify HMAC-SHA256 signature of config JSON
+/// Bundled verification keys, newest first
 ///
-/// Loads the bundled verification key and compares the computed HMAC
-/// against the provided signature (hex-encoded).
+/// Rotation: ship the new key alongside the old one for at least one release
+/// before the server switches signing keys, then drop the old key.
+const BUNDLED_VERIFICATION_KEYS: &[&str] = &[include_str!("../resources/config-signing-key.pub")];
+
+/// Settings key for the optional locally-trusted verification key
+pub const LOCAL_TRUSTED_KEY_SETTING: &str = "config_trusted_local_key";
+
+/// Minimum length for a locally-added verification key
+const MIN_TRUSTED_KEY_LEN: usize = 32;
+
+/// Where a trusted verification key came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "lowercase")]
+pub enum TrustedKeySource {
+    Bundled,
+    Local,
+}
+
+/// A key trusted to verify remote config signatures
+#[derive(Debug, Clone)]
+pub struct TrustedConfigKey {
+    pub key_id: String,
+    pub source: TrustedKeySource,
+    secret: String,
+}
+
+impl TrustedConfigKey {
+    pub fn new(secret: &str, source: TrustedKeySource) -> Self {
+        let secret = secret.trim().to_string();
+        Self {
+            key_id: config_key_id(&secret),
+            source,
+            secret,
+        }
+    }
+}
+
+/// Short, non-secret identifier for a verification key (first 8 bytes of its SHA-256)
+pub fn config_key_id(key: &str) -> String {
+    use sha2::Digest;
+    let digest = Sha256::digest(key.trim().as_bytes());
+    hex::encode(&digest[..8])
+}
+
+/// Keys bundled with the app
+pub fn bundled_trusted_keys() -> Vec<TrustedConfigKey> {
+    BUNDLED_VERIFICATION_KEYS
+        .iter()
+        .map(|k| TrustedConfigKey::new(k, TrustedKeySource::Bundled))
+        .collect()
+}
+
+/// Bundled keys plus the locally-added key, if any
+pub fn load_trusted_keys(conn: &Connection) -> Vec<TrustedConfigKey> {
+    let mut keys = bundled_trusted_keys();
+    match crate::db::queries::settings::get_setting(conn, LOCAL_TRUSTED_KEY_SETTING) {
+        Ok(Some(local)) if !local.trim().is_empty() => {
+            keys.push(TrustedConfigKey::new(&local, TrustedKeySource::Local));
+        }
+        Ok(_) => {}
+        Err(e) => tracing::warn!("Failed to load local trusted config key: {}", e),
+    }
+    keys
+}
+
+/// Verify HMAC-SHA256 signature of config JSON against the bundled keys
+///
+/// Returns the ID of the key that verified the signature.
 ///
 /// # Arguments
 /// * `config_json` - Raw JSON body of the config response
@@ -268,31 +372,69 @@ impl From<reqwest::Error> for RemoteConfigError {
 /// Returns `RemoteConfigError::SignatureError` if:
 /// - Signature is missing or invalid format
 /// - HMAC computation fails
-/// - Signature does not match computed HMAC
+/// - Signature does not match any trusted key
 pub fn verify_config_signature(
     config_json: &str,
     signature: &str,
-) -> Result<(), RemoteConfigError> {
-    // Load bundled verification key
-    const VERIFICATION_KEY: &str = include_str!("../resources/config-signing-key.pub");
-
-    // Create HMAC instance
-    let mut mac = HmacSha256::new_from_slice(VERIFICATION_KEY.trim().as_bytes())
-        .map_err(|e| RemoteConfigError::SignatureError(format!("Invalid key: {}", e)))?;
-
-    // Compute HMAC of config JSON
-    mac.update(config_json.as_bytes());
+) -> Result<String, RemoteConfigError> {
+    verify_config_signature_with_keys(config_json, signature, &bundled_trusted_keys())
+}
 
+/// Verify HMAC-SHA256 signature of config JSON against any of `keys`
+///
+/// Returns the ID of the first key that verifies the signature.
+pub fn verify_config_signature_with_keys(
+    config_json: &str,
+    signature: &str,
+    keys: &[TrustedConfigKey],
+) -> Result<String, RemoteConfigError> {
     // Decode provided signature from hex
     let expected_signature = hex::decode(signature).map_err(|e| {
         RemoteConfigError::SignatureError(format!("Invalid signature format: {}", e))
     })?;
 
-    // Verify signature
-    mac.verify_slice(&expected_signature)
-        .map_err(|_| RemoteConfigError::SignatureError("Signature mismatch".to_string()))?;
+    for key in keys {
+        let mut mac = HmacSha256::new_from_slice(key.secret.as_bytes())
+            .map_err(|e| RemoteConfigError::SignatureError(format!("Invalid key: {}", e)))?;
+        mac.update(config_json.as_bytes());
 
-    Ok(())
+        if mac.verify_slice(&expected_signature).is_ok() {
+            return Ok(key.key_id.clone());
+        }
+    }
+
+    Err(RemoteConfigError::SignatureError(
+        "Signature mismatch (no trusted key matched)".to_string(),
+    ))
+}
+
+/// Signature details of a verified remote config
+#[derive(Debug, Clone)]
+pub struct VerifiedSignature {
+    pub signature: String,
+    pub key_id: String,
+}
+
+/// Record a signature failure: emit `config:signature-invalid` and the blocked-request event.
+/// The payload is never written to `remote_config`.
+fn report_signature_invalid(app_handle: &AppHandle, reason: &str) {
+    use tauri::Emitter;
+
+    tracing::warn!("Remote config signature verification failed: {}", reason);
+    network::emit_blocked_event(
+        app_handle,
+        "raw.githubusercontent.com".to_string(),
+        REMOTE_CONFIG_URL.to_string(),
+    );
+
+    let payload = crate::events::ConfigSignatureInvalidPayload {
+        url: REMOTE_CONFIG_URL.to_string(),
+        reason: reason.to_string(),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+    };
+    if let Err(e) = app_handle.emit(crate::events::CONFIG_SIGNATURE_INVALID, &payload) {
+        tracing::warn!("Failed to emit config:signature-invalid event: {}", e);
+    }
 }
 
 /// Fetch remote configuration from the configured endpoint
@@ -311,7 +453,7 @@ pub fn verify_config_signature(
 /// - Required fields are missing or invalid
 pub async fn fetch_remote_config(
     app_handle: &AppHandle,
-) -> Result<(RemoteConfig, String), RemoteConfigError> {
+) -> Result<(RemoteConfig, VerifiedSignature), RemoteConfigError> {
     // Validate URL against allowlist (AR-14)
     network::validate_url(REMOTE_CONFIG_URL)?;
 
@@ -320,7 +462,7 @@ pub async fn fetch_remote_config(
     let client = crate::http::client();
 
     // Fetch config
-    let response = client.get(REMOTE_CONFIG_URL).timeout(Duration::from_secs(10)).send().await?;
+    let response = client.get(REMOTE_CONFIG_URL).timeout(Duration::from_secs(10)).send_tracked().await?;
 
     // Check HTTP status
     if !response.status().is_success() {
@@ -351,26 +493,26 @@ pub async fn fetch_remote_config(
     // Verification must use the raw bytes (not re-serialized JSON) because
     // serde round-tripping changes formatting/field order, breaking HMAC.
     if signature.is_empty() {
-        tracing::warn!("Remote config rejected: missing X-Config-Signature header");
-        network::emit_blocked_event(
-            app_handle,
-            "raw.githubusercontent.com".to_string(),
-            REMOTE_CONFIG_URL.to_string(),
-        );
-        return Err(RemoteConfigError::SignatureError(
-            "Missing X-Config-Signature header".to_string(),
-        ));
+        let reason = "Missing X-Config-Signature header";
+        report_signature_invalid(app_handle, reason);
+        return Err(RemoteConfigError::SignatureError(reason.to_string()));
     }
 
-    verify_config_signature(&body, &signature).map_err(|e| {
-        tracing::warn!("Remote config signature verification failed: {}", e);
-        network::emit_blocked_event(
-            app_handle,
-            "raw.githubusercontent.com".to_string(),
-            REMOTE_CONFIG_URL.to_string(),
-        );
-        e
-    })?;
+    // Verify against any trusted key (bundled + locally added) to support rotation
+    let trusted_keys = {
+        use crate::db::AppDatabase;
+        use tauri::Manager;
+
+        let db_state = app_handle.state::<AppDatabase>();
+        let keys = match db_state.get().ok().map(|db| db.conn.lock()) {
+            Some(Ok(conn)) => load_trusted_keys(&conn),
+            _ => bundled_trusted_keys(),
+        };
+        keys
+    };
+
+    let key_id = verify_config_signature_with_keys(&body, &signature, &trusted_keys)
+        .inspect_err(|e| report_signature_invalid(app_handle, &e.to_string()))?;
 
     // Parse response body (signature already verified against raw bytes)
     let config: RemoteConfig = serde_json::from_str(&body).map_err(|e| {
@@ -396,12 +538,13 @@ pub async fn fetch_remote_config(
     }
 
     tracing::info!(
-        "Successfully fetched remote config v{} with {} strategies (signature verified)",
+        "Successfully fetched remote config v{} with {} strategies (signature verified by key {})",
         config.schema_version,
-        config.strategies.len()
+        config.strategies.len(),
+        key_id
     );
 
-    Ok((config, signature))
+    Ok((config, VerifiedSignature { signature, key_id }))
 }
 
 /// Get the active configuration, falling back to bundled config on any error
@@ -427,6 +570,11 @@ pub async fn get_active_config(app_handle: &AppHandle) -> RemoteConfig {
             );
             config
         }
+        Err(e @ RemoteConfigError::SignatureError(_)) => {
+            // Never trust an unverified payload - keep serving the previous cached config
+            tracing::warn!("Remote config rejected: {}. Keeping cached config.", e);
+            cached_config(app_handle).unwrap_or_else(|| fallback_to_bundled(&e.to_string()))
+        }
         Err(e) => {
             tracing::warn!("Remote config fetch failed: {}. Falling back to bundled config.", e);
             fallback_to_bundled(&e.to_string())
@@ -434,6 +582,26 @@ pub async fn get_active_config(app_handle: &AppHandle) -> RemoteConfig {
     }
 }
 
+/// Previously verified config from the local cache, if any
+fn cached_config(app_handle: &AppHandle) -> Option<RemoteConfig> {
+    use crate::db::AppDatabase;
+    use crate::db::queries::remote_config;
+    use tauri::Manager;
+
+    let db_state = app_handle.state::<AppDatabase>();
+    let db = db_state.get().ok()?;
+    let conn = db.conn.lock().ok()?;
+    let cached = remote_config::get_cached_config(&conn).ok().flatten();
+    cached.map(|cached| cached.config)
+}
+
+/// Beta channel update feed from the cached config (or the bundled one), if published
+pub fn beta_update_feed_url(app_handle: &AppHandle) -> Option<String> {
+    cached_config(app_handle)
+        .or_else(|| load_bundled_config().ok())
+        .and_then(|config| config.beta_update_feed_url)
+}
+
 /// Fall back to the bundled default config, logging the reason
 fn fallback_to_bundled(reason: &str) -> RemoteConfig {
     match load_bundled_config() {
@@ -473,6 +641,7 @@ pub async fn fetch_remote_config_command(app_handle: AppHandle) -> Result<Remote
 /// Tauri command: Get bundled default configuration
 ///
 /// Allows frontend to explicitly request the bundled config (e.g., for comparison or debugging).
+/// Never touches the network.
 #[tauri::command]
 #[specta::specta]
 pub fn get_bundled_config_command() -> Result<RemoteConfig, String> {
@@ -544,7 +713,7 @@ pub fn load_config_on_startup(app_handle: &AppHandle) -> RemoteConfig {
                 cached_config.config.schema_version,
                 cached_config.fetched_at
             );
-            return cached_config.config;
+            return with_config_overrides(app_handle, cached_config.config);
         }
 
         tracing::info!(
@@ -558,7 +727,7 @@ pub fn load_config_on_startup(app_handle: &AppHandle) -> RemoteConfig {
             background_config_fetch(&app_handle_clone).await;
         });
 
-        return cached_config.config;
+        return with_config_overrides(app_handle, cached_config.config);
     }
 
     tracing::info!("No cached config found - using bundled config and spawning background fetch");
@@ -568,10 +737,11 @@ pub fn load_config_on_startup(app_handle: &AppHandle) -> RemoteConfig {
         background_config_fetch(&app_handle_clone).await;
     });
 
-    load_bundled_config().unwrap_or_else(|e| {
+    let bundled = load_bundled_config().unwrap_or_else(|e| {
         tracing::error!("CRITICAL: Failed to load bundled config: {}", e);
         panic!("Bundled config unavailable: {}", e);
-    })
+    });
+    with_config_overrides(app_handle, bundled)
 }
 
 /// Background config fetch — fetch, verify, compare version, store, sync strategies, emit event
@@ -581,7 +751,7 @@ async fn background_config_fetch(app_handle: &AppHandle) {
     use tauri::{Emitter, Manager};
 
     match fetch_remote_config(app_handle).await {
-        Ok((fetched_config, signature)) => {
+        Ok((fetched_config, verified)) => {
             // Signature already verified inside fetch_remote_config against raw body
 
             // AC-5: Check min_app_version before applying
@@ -597,7 +767,7 @@ async fn background_config_fetch(app_handle: &AppHandle) {
                 if let Ok(db) = db_state.get() {
                     match db.conn.lock() {
                         Ok(conn) => {
-                            if let Err(e) = rc_queries::store_remote_config(&conn, &fetched_config, &signature) {
+                            if let Err(e) = rc_queries::store_verified_remote_config(&conn, &fetched_config, &verified.signature, Some(&verified.key_id)) {
                                 tracing::warn!("Failed to store deferred config: {}", e);
                             } else {
                                 tracing::info!("Deferred config v{} stored (app version too old)", fetched_config.schema_version);
@@ -636,7 +806,7 @@ async fn background_config_fetch(app_handle: &AppHandle) {
                 if let Ok(db) = db_state.get() {
                     match db.conn.lock() {
                         Ok(conn) => {
-                            if let Err(e) = rc_queries::store_remote_config(&conn, &fetched_config, &signature) {
+                            if let Err(e) = rc_queries::store_verified_remote_config(&conn, &fetched_config, &verified.signature, Some(&verified.key_id)) {
                                 tracing::warn!("Failed to store updated config: {}", e);
                                 return;
                             }
@@ -648,7 +818,14 @@ async fn background_config_fetch(app_handle: &AppHandle) {
 
                             // Story 10.3: Sync hook strategies after config update
                             // Depends on V28 (remote_id, status) and V29 (ab_weight) migrations
-                            match sync_hook_strategies_impl(&conn, &fetched_config) {
+                            // Local overrides are merged on top of the stored remote config
+                            let overrides = crate::db::queries::config_overrides::list_config_overrides(&conn)
+                                .unwrap_or_else(|e| {
+                                    tracing::warn!("Failed to load config overrides: {}", e);
+                                    Vec::new()
+                                });
+                            let (effective_config, _) = apply_config_overrides(&fetched_config, &overrides);
+                            match sync_hook_strategies_impl(&conn, &effective_config) {
                                 Ok(sync_result) => {
                                     tracing::info!(
                                         "Hook strategies synced: +{} added, ~{} updated, -{} retired",
@@ -674,7 +851,8 @@ async fn background_config_fetch(app_handle: &AppHandle) {
                     }
                 }
 
-                if let Err(e) = app_handle.emit("config:updated", &fetched_config) {
+                let effective_config = with_config_overrides(app_handle, fetched_config);
+                if let Err(e) = app_handle.emit("config:updated", &effective_config) {
                     tracing::warn!("Failed to emit config:updated event: {}", e);
                 }
             } else {
@@ -709,20 +887,42 @@ pub fn start_periodic_config_refresh(
 }
 
 /// Tauri command: Get cached config from database (Story 10.2, Task 7.1)
+///
+/// Returns the effective config (cached remote config merged with local overrides)
+/// and the active overrides with the remote values they shadow.
 #[tauri::command]
 #[specta::specta]
-pub fn get_cached_config_command(app_handle: AppHandle) -> Result<Option<RemoteConfig>, String> {
+pub fn get_cached_config_command(app_handle: AppHandle) -> Result<EffectiveConfig, String> {
     use crate::db::AppDatabase;
-    use crate::db::queries::remote_config;
+    use crate::db::queries::{config_overrides, remote_config};
     use tauri::Manager;
 
     let db_state = app_handle.state::<AppDatabase>();
     let db = db_state.get().map_err(|e: String| e)?;
     let conn = db.conn.lock().map_err(|e| format!("Database lock failed: {}", e))?;
 
+    let overrides = config_overrides::list_config_overrides(&conn)
+        .map_err(|e| format!("Failed to load config overrides: {}", e))?;
+
     match remote_config::get_cached_config(&conn) {
-        Ok(Some(cached)) => Ok(Some(cached.config)),
-        Ok(None) => Ok(None),
+        Ok(Some(cached)) => {
+            let (config, active) = apply_config_overrides(&cached.config, &overrides);
+            Ok(EffectiveConfig {
+                config: Some(config),
+                overrides: active,
+            })
+        }
+        Ok(None) => Ok(EffectiveConfig {
+            config: None,
+            overrides: overrides
+                .into_iter()
+                .map(|o| ActiveConfigOverride {
+                    key_path: o.key_path,
+                    value_json: o.value_json,
+                    remote_value_json: None,
+                })
+                .collect(),
+        }),
         Err(e) => Err(e.to_string()),
     }
 }
@@ -732,7 +932,8 @@ pub fn get_cached_config_command(app_handle: AppHandle) -> Result<Option<RemoteC
 #[specta::specta]
 pub async fn force_config_refresh_command(app_handle: AppHandle) -> Result<RemoteConfig, String> {
     background_config_fetch(&app_handle).await;
-    Ok(get_active_config(&app_handle).await)
+    let config = get_active_config(&app_handle).await;
+    Ok(with_config_overrides(&app_handle, config))
 }
 
 // ============================================================================
@@ -806,6 +1007,151 @@ pub async fn check_for_config_updates(app_handle: AppHandle) -> Result<ConfigChe
     }
 }
 
+// ============================================================================
+// Config Signing Key Rotation
+// ============================================================================
+
+/// Public info about a trusted key (the key material itself is never returned)
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct TrustedKeyInfo {
+    pub key_id: String,
+    pub source: TrustedKeySource,
+}
+
+/// Which key signed the active config, and when it was fetched
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct ConfigTrustStatus {
+    pub source: ConfigSourceStatus,
+    /// None when serving bundled defaults or a config cached before key tracking
+    pub signing_key_id: Option<String>,
+    /// None when the signing key is no longer trusted (or unknown)
+    pub signing_key_source: Option<TrustedKeySource>,
+    pub fetched_at: Option<String>,
+    pub trusted_keys: Vec<TrustedKeyInfo>,
+}
+
+/// Build the trust status from the cached config and the trusted keys
+fn build_trust_status(
+    cached: Option<crate::db::queries::remote_config::CachedConfig>,
+    trusted_keys: &[TrustedConfigKey],
+) -> ConfigTrustStatus {
+    let trusted_keys_info = trusted_keys
+        .iter()
+        .map(|k| TrustedKeyInfo {
+            key_id: k.key_id.clone(),
+            source: k.source,
+        })
+        .collect();
+
+    match cached {
+        Some(cached) => {
+            let signing_key_source = cached.signing_key_id.as_ref().and_then(|id| {
+                trusted_keys
+                    .iter()
+                    .find(|k| &k.key_id == id)
+                    .map(|k| k.source)
+            });
+            ConfigTrustStatus {
+                source: ConfigSourceStatus::Cached,
+                signing_key_id: cached.signing_key_id,
+                signing_key_source,
+                fetched_at: Some(cached.fetched_at),
+                trusted_keys: trusted_keys_info,
+            }
+        }
+        None => ConfigTrustStatus {
+            source: ConfigSourceStatus::Defaults,
+            signing_key_id: None,
+            signing_key_source: None,
+            fetched_at: None,
+            trusted_keys: trusted_keys_info,
+        },
+    }
+}
+
+/// Tauri command: Report which key signed the active config and when it was fetched
+#[tauri::command]
+#[specta::specta]
+pub fn get_config_trust_status(app_handle: AppHandle) -> Result<ConfigTrustStatus, String> {
+    use crate::db::AppDatabase;
+    use crate::db::queries::remote_config;
+    use tauri::Manager;
+
+    let db_state = app_handle.state::<AppDatabase>();
+    let db = db_state.get().map_err(|e: String| e)?;
+    let conn = db.conn.lock().map_err(|e| format!("Database lock failed: {}", e))?;
+
+    let cached = remote_config::get_cached_config(&conn).map_err(|e| e.to_string())?;
+    let trusted_keys = load_trusted_keys(&conn);
+
+    Ok(build_trust_status(cached, &trusted_keys))
+}
+
+/// Tauri command: Trust an additional config verification key
+///
+/// Replaces any previously added local key. Requires `confirmed = true` because a
+/// trusted key lets whoever holds it change hook strategies in this app.
+/// Returns the new key's ID.
+#[tauri::command]
+#[specta::specta]
+pub fn add_trusted_config_key(
+    app_handle: AppHandle,
+    key: String,
+    confirmed: bool,
+) -> Result<String, String> {
+    use crate::db::AppDatabase;
+    use crate::db::queries::settings;
+    use tauri::Manager;
+
+    if !confirmed {
+        return Err("Adding a trusted config key requires explicit confirmation".to_string());
+    }
+    let key = key.trim();
+    if key.len() < MIN_TRUSTED_KEY_LEN {
+        return Err(format!(
+            "Trusted config key must be at least {} characters",
+            MIN_TRUSTED_KEY_LEN
+        ));
+    }
+
+    let db_state = app_handle.state::<AppDatabase>();
+    let db = db_state.get().map_err(|e: String| e)?;
+    let conn = db.conn.lock().map_err(|e| format!("Database lock failed: {}", e))?;
+
+    settings::set_setting(&conn, LOCAL_TRUSTED_KEY_SETTING, key)
+        .map_err(|e| format!("Failed to save trusted config key: {}", e))?;
+
+    let key_id = config_key_id(key);
+    tracing::info!("Local trusted config key added: {}", key_id);
+    Ok(key_id)
+}
+
+/// Tauri command: Remove the locally added config verification key
+///
+/// Returns true if a local key was removed.
+#[tauri::command]
+#[specta::specta]
+pub fn remove_trusted_config_key(app_handle: AppHandle) -> Result<bool, String> {
+    use crate::db::AppDatabase;
+    use tauri::Manager;
+
+    let db_state = app_handle.state::<AppDatabase>();
+    let db = db_state.get().map_err(|e: String| e)?;
+    let conn = db.conn.lock().map_err(|e| format!("Database lock failed: {}", e))?;
+
+    let deleted = conn
+        .execute(
+            "DELETE FROM settings WHERE key = ?1",
+            params![LOCAL_TRUSTED_KEY_SETTING],
+        )
+        .map_err(|e| format!("Failed to remove trusted config key: {}", e))?;
+
+    if deleted > 0 {
+        tracing::info!("Local trusted config key removed");
+    }
+    Ok(deleted > 0)
+}
+
 // ============================================================================
 // Story 10.3: Dynamic Hook Strategy Updates
 // ============================================================================
@@ -1004,6 +1350,291 @@ pub fn sync_hook_strategies(
     Ok(result)
 }
 
+// ============================================================================
+// Local Config Overrides (pin individual keys over the remote config)
+// ============================================================================
+
+/// An override currently applied to the effective config
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct ActiveConfigOverride {
+    /// Dotted path into the config JSON
+    pub key_path: String,
+    /// JSON-encoded pinned value
+    pub value_json: String,
+    /// JSON-encoded value the override shadows (None if the path did not resolve)
+    pub remote_value_json: Option<String>,
+}
+
+/// Effective (merged) config plus the overrides applied to it
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct EffectiveConfig {
+    pub config: Option<RemoteConfig>,
+    pub overrides: Vec<ActiveConfigOverride>,
+}
+
+/// Resolve a dotted path (e.g. `strategies.social-proof.ab_weight`) into the config JSON.
+///
+/// Object segments are field names. Array segments are either a numeric index
+/// or the `id` of an element (so strategy paths survive reordering).
+fn resolve_config_path_mut<'a>(
+    root: &'a mut serde_json::Value,
+    key_path: &str,
+) -> Option<&'a mut serde_json::Value> {
+    if key_path.trim().is_empty() {
+        return None;
+    }
+
+    key_path.split('.').try_fold(root, |node, segment| match node {
+        serde_json::Value::Object(map) => map.get_mut(segment),
+        serde_json::Value::Array(items) => match segment.parse::<usize>() {
+            Ok(index) => items.get_mut(index),
+            Err(_) => items
+                .iter_mut()
+                .find(|item| item.get("id").and_then(|id| id.as_str()) == Some(segment)),
+        },
+        _ => None,
+    })
+}
+
+/// Apply a single override to a config JSON value, returning the shadowed value.
+fn apply_override_to_value(
+    root: &mut serde_json::Value,
+    key_path: &str,
+    value_json: &str,
+) -> Result<serde_json::Value, String> {
+    let value: serde_json::Value = serde_json::from_str(value_json)
+        .map_err(|e| format!("Invalid override value JSON: {}", e))?;
+
+    let target = resolve_config_path_mut(root, key_path)
+        .ok_or_else(|| format!("Invalid config path: '{}' does not exist", key_path))?;
+
+    Ok(std::mem::replace(target, value))
+}
+
+/// Validate an override against a config: the path must resolve and the
+/// merged config must still pass schema validation.
+pub fn validate_config_override(
+    config: &RemoteConfig,
+    key_path: &str,
+    value_json: &str,
+) -> Result<(), String> {
+    let mut root =
+        serde_json::to_value(config).map_err(|e| format!("Failed to serialize config: {}", e))?;
+
+    apply_override_to_value(&mut root, key_path, value_json)?;
+
+    serde_json::from_value::<RemoteConfig>(root)
+        .map(|_| ())
+        .map_err(|e| format!("Override produces an invalid config: {}", e))
+}
+
+/// Merge overrides on top of a config.
+///
+/// Overrides are applied in order; any override whose path no longer resolves
+/// (e.g. a strategy removed remotely) or that would make the config invalid is
+/// skipped with a warning rather than discarding the whole config.
+pub fn apply_config_overrides(
+    config: &RemoteConfig,
+    overrides: &[crate::db::queries::config_overrides::ConfigOverride],
+) -> (RemoteConfig, Vec<ActiveConfigOverride>) {
+    let mut merged = config.clone();
+    let mut active = Vec::with_capacity(overrides.len());
+
+    for o in overrides {
+        let mut root = match serde_json::to_value(&merged) {
+            Ok(root) => root,
+            Err(e) => {
+                tracing::warn!("Failed to serialize config for override merge: {}", e);
+                break;
+            }
+        };
+
+        let applied = apply_override_to_value(&mut root, &o.key_path, &o.value_json)
+            .and_then(|shadowed| {
+                serde_json::from_value::<RemoteConfig>(root)
+                    .map(|cfg| (cfg, shadowed))
+                    .map_err(|e| format!("Override produces an invalid config: {}", e))
+            });
+
+        match applied {
+            Ok((cfg, shadowed)) => {
+                merged = cfg;
+                active.push(ActiveConfigOverride {
+                    key_path: o.key_path.clone(),
+                    value_json: o.value_json.clone(),
+                    remote_value_json: Some(shadowed.to_string()),
+                });
+            }
+            Err(e) => {
+                tracing::warn!("Skipping config override '{}': {}", o.key_path, e);
+                active.push(ActiveConfigOverride {
+                    key_path: o.key_path.clone(),
+                    value_json: o.value_json.clone(),
+                    remote_value_json: None,
+                });
+            }
+        }
+    }
+
+    (merged, active)
+}
+
+/// Reload the config once an encrypted database is unlocked. At startup the
+/// cached config and local overrides could not be read, so the bundled config
+/// was used as is.
+pub fn reload_config_after_unlock(app_handle: &AppHandle) {
+    let config = load_config_on_startup(app_handle);
+    tracing::info!(
+        schema_version = %config.schema_version,
+        "Remote config reloaded after unlock"
+    );
+}
+
+/// Load stored overrides, returning an empty list if the database is unavailable
+fn load_config_overrides(
+    app_handle: &AppHandle,
+) -> Vec<crate::db::queries::config_overrides::ConfigOverride> {
+    use crate::db::AppDatabase;
+    use crate::db::queries::config_overrides;
+    use tauri::Manager;
+
+    let db_state = app_handle.state::<AppDatabase>();
+    let Ok(db) = db_state.get() else {
+        // Encrypted database still locked: reload_config_after_unlock applies them
+        tracing::info!("Database locked, deferring local config overrides until unlock");
+        return Vec::new();
+    };
+    let overrides = match db.conn.lock() {
+        Ok(conn) => config_overrides::list_config_overrides(&conn).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load config overrides: {}", e);
+            Vec::new()
+        }),
+        Err(e) => {
+            tracing::warn!("Failed to lock database for config overrides: {}", e);
+            Vec::new()
+        }
+    };
+    overrides
+}
+
+/// Apply stored overrides to a config
+fn with_config_overrides(app_handle: &AppHandle, config: RemoteConfig) -> RemoteConfig {
+    let overrides = load_config_overrides(app_handle);
+    let config = if overrides.is_empty() {
+        config
+    } else {
+        let (merged, active) = apply_config_overrides(&config, &overrides);
+        tracing::info!("Applied {} local config override(s)", active.len());
+        merged
+    };
+    crate::humanization::set_remote_ai_tell_phrases(&config.ai_tell_phrases);
+    config
+}
+
+/// Tauri command: Pin a config value locally, shadowing the remote value
+///
+/// `key_path` is a dotted path into the config JSON (e.g. `strategies.social-proof.ab_weight`)
+/// and `value_json` is the JSON-encoded value. Invalid paths or values are rejected here
+/// rather than being silently ignored at merge time.
+#[tauri::command]
+#[specta::specta]
+pub fn set_config_override(
+    app_handle: AppHandle,
+    key_path: String,
+    value_json: String,
+) -> Result<EffectiveConfig, String> {
+    use crate::db::AppDatabase;
+    use crate::db::queries::{config_overrides, remote_config};
+    use tauri::Manager;
+
+    let db_state = app_handle.state::<AppDatabase>();
+    let db = db_state.get().map_err(|e: String| e)?;
+    let conn = db.conn.lock().map_err(|e| format!("Database lock failed: {}", e))?;
+
+    let cached = remote_config::get_cached_config(&conn).map_err(|e| e.to_string())?;
+    let base = match &cached {
+        Some(cached) => cached.config.clone(),
+        None => load_bundled_config().map_err(|e| e.to_string())?,
+    };
+
+    // Validate against the base merged with the other overrides, so combinations stay valid
+    let existing: Vec<_> = config_overrides::list_config_overrides(&conn)
+        .map_err(|e| format!("Failed to load config overrides: {}", e))?
+        .into_iter()
+        .filter(|o| o.key_path != key_path)
+        .collect();
+    let (merged_base, _) = apply_config_overrides(&base, &existing);
+    validate_config_override(&merged_base, &key_path, &value_json)?;
+
+    config_overrides::set_config_override(&conn, &key_path, &value_json)
+        .map_err(|e| format!("Failed to save config override: {}", e))?;
+    tracing::info!("Config override set: {}", key_path);
+
+    effective_config_and_sync(&conn, cached.map(|c| c.config))
+}
+
+/// Tauri command: Remove a local config override, restoring the remote value
+#[tauri::command]
+#[specta::specta]
+pub fn clear_config_override(
+    app_handle: AppHandle,
+    key_path: String,
+) -> Result<EffectiveConfig, String> {
+    use crate::db::AppDatabase;
+    use crate::db::queries::{config_overrides, remote_config};
+    use tauri::Manager;
+
+    let db_state = app_handle.state::<AppDatabase>();
+    let db = db_state.get().map_err(|e: String| e)?;
+    let conn = db.conn.lock().map_err(|e| format!("Database lock failed: {}", e))?;
+
+    if !config_overrides::delete_config_override(&conn, &key_path)
+        .map_err(|e| format!("Failed to clear config override: {}", e))?
+    {
+        return Err(format!("No config override for '{}'", key_path));
+    }
+    tracing::info!("Config override cleared: {}", key_path);
+
+    let cached = remote_config::get_cached_config(&conn).map_err(|e| e.to_string())?;
+    effective_config_and_sync(&conn, cached.map(|c| c.config))
+}
+
+/// Merge overrides into the cached config and re-sync hook strategies so the
+/// change takes effect without waiting for the next remote fetch.
+fn effective_config_and_sync(
+    conn: &Connection,
+    cached: Option<RemoteConfig>,
+) -> Result<EffectiveConfig, String> {
+    use crate::db::queries::config_overrides;
+
+    let overrides = config_overrides::list_config_overrides(conn)
+        .map_err(|e| format!("Failed to load config overrides: {}", e))?;
+
+    let Some(cached) = cached else {
+        return Ok(EffectiveConfig {
+            config: None,
+            overrides: overrides
+                .into_iter()
+                .map(|o| ActiveConfigOverride {
+                    key_path: o.key_path,
+                    value_json: o.value_json,
+                    remote_value_json: None,
+                })
+                .collect(),
+        });
+    };
+
+    let (merged, active) = apply_config_overrides(&cached, &overrides);
+    if let Err(e) = sync_hook_strategies_impl(conn, &merged) {
+        tracing::warn!("Hook strategies sync after override change failed (non-fatal): {}", e);
+    }
+
+    Ok(EffectiveConfig {
+        config: Some(merged),
+        overrides: active,
+    })
+}
+
 #[cfg(test)]
 
 mod tests {
@@ -1291,6 +1922,53 @@ mod tests {
         assert!(config.is_err(), "Should reject unknown fields due to deny_unknown_fields");
     }
 
+    #[test]
+    fn test_ai_tell_phrases_optional() {
+        let json = r#"{
+            "schema_version": "1.0.0",
+            "min_app_version": "0.1.0",
+            "updated_at": "2024-01-15T10:30:00Z",
+            "strategies": [],
+            "ai_tell_phrases": ["circle back", "deep dive"]
+        }"#;
+        let config: RemoteConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.ai_tell_phrases, vec!["circle back", "deep dive"]);
+
+        let config = make_test_config(vec![]);
+        assert!(config.ai_tell_phrases.is_empty());
+        let json_str = serde_json::to_string(&config).unwrap();
+        assert!(!json_str.contains("ai_tell_phrases"));
+    }
+
+    #[test]
+    fn test_beta_update_feed_url_optional_https_only() {
+        let with_feed = |url: &str| {
+            format!(
+                r#"{{
+                    "schema_version": "1.0.0",
+                    "min_app_version": "0.1.0",
+                    "updated_at": "2024-01-15T10:30:00Z",
+                    "strategies": [],
+                    "beta_update_feed_url": "{}"
+                }}"#,
+                url
+            )
+        };
+        let config: RemoteConfig =
+            serde_json::from_str(&with_feed("https://example.com/beta/latest.json")).unwrap();
+        assert_eq!(
+            config.beta_update_feed_url.as_deref(),
+            Some("https://example.com/beta/latest.json")
+        );
+        let insecure = with_feed("http://example.com/beta.json");
+        assert!(serde_json::from_str::<RemoteConfig>(&insecure).is_err());
+
+        let config = make_test_config(vec![]);
+        assert!(config.beta_update_feed_url.is_none());
+        let json_str = serde_json::to_string(&config).unwrap();
+        assert!(!json_str.contains("beta_update_feed_url"));
+    }
+
     #[test]
     fn test_all_status_variants() {
         let json = r#"{
@@ -1952,6 +2630,8 @@ mod tests {
                 status: StrategyStatus::Active,
                 ab_weight: 0.5,
             }],
+            ai_tell_phrases: vec![],
+            beta_update_feed_url: None,
         };
 
         let json = serde_json::to_string(&config);
@@ -1972,12 +2652,15 @@ mod tests {
             min_app_version: "0.1.0".to_string(),
             updated_at: "2026-02-18T00:00:00Z".to_string(),
             strategies: vec![],
+            ai_tell_phrases: vec![],
+            beta_update_feed_url: None,
         };
         let cached = CachedConfig {
             config: config.clone(),
             fetched_at: "2026-02-18T00:00:00Z".to_string(),
             signature: "test_sig".to_string(),
             source: "remote".to_string(),
+            signing_key_id: None,
         };
         assert_eq!(cached.config.schema_version, "1.0.0");
         assert_eq!(cached.source, "remote");
@@ -2095,6 +2778,8 @@ mod tests {
             min_app_version: "0.1.0".to_string(),
             updated_at: "2024-01-15T10:30:00Z".to_string(),
             strategies,
+            ai_tell_phrases: vec![],
+            beta_update_feed_url: None,
         }
     }
 
@@ -2415,6 +3100,207 @@ mod tests {
         assert_eq!(count, 5, "All 5 seed strategies should remain active and unaffected");
     }
 
+    // ========================================================================
+    // Config signing key rotation tests
+    // ========================================================================
+
+    const FIXTURE_KEY_A: &str = "fixture-config-signing-key-a-0123456789abcdef0123456789";
+    const FIXTURE_KEY_B: &str = "fixture-config-signing-key-b-0123456789abcdef0123456789";
+    const FIXTURE_UNKNOWN_KEY: &str = "fixture-config-signing-key-unknown-0123456789abcdef";
+
+    fn sign_with(key: &str, body: &str) -> String {
+        use hmac::{Hmac, Mac};
+        use sha2::Sha256;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(key.as_bytes()).unwrap();
+        mac.update(body.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    fn fixture_keys() -> Vec<TrustedConfigKey> {
+        vec![
+            TrustedConfigKey::new(FIXTURE_KEY_A, TrustedKeySource::Bundled),
+            TrustedConfigKey::new(FIXTURE_KEY_B, TrustedKeySource::Local),
+        ]
+    }
+
+    #[test]
+    fn test_rotation_valid_signature_reports_signing_key() {
+        let body = include_str!("../resources/default-config.json");
+
+        let key_id = verify_config_signature_with_keys(body, &sign_with(FIXTURE_KEY_B, body), &fixture_keys()).unwrap();
+        assert_eq!(key_id, config_key_id(FIXTURE_KEY_B));
+
+        let key_id = verify_config_signature_with_keys(body, &sign_with(FIXTURE_KEY_A, body), &fixture_keys()).unwrap();
+        assert_eq!(key_id, config_key_id(FIXTURE_KEY_A));
+    }
+
+    #[test]
+    fn test_rotation_tampered_payload_rejected() {
+        let body = r#"{"schema_version":"1.0.0"}"#;
+        let signature = sign_with(FIXTURE_KEY_A, body);
+
+        let result = verify_config_signature_with_keys(
+            r#"{"schema_version":"9.9.9"}"#,
+            &signature,
+            &fixture_keys(),
+        );
+        assert!(matches!(result, Err(RemoteConfigError::SignatureError(_))));
+    }
+
+    #[test]
+    fn test_rotation_unknown_key_rejected() {
+        let body = r#"{"schema_version":"1.0.0"}"#;
+        let signature = sign_with(FIXTURE_UNKNOWN_KEY, body);
+
+        match verify_config_signature_with_keys(body, &signature, &fixture_keys()) {
+            Err(RemoteConfigError::SignatureError(msg)) => assert!(msg.contains("mismatch")),
+            other => panic!("Unknown key should be rejected, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_config_key_id_is_stable_and_not_the_key() {
+        let id = config_key_id(FIXTURE_KEY_A);
+        assert_eq!(id, config_key_id(&format!("  {}\n", FIXTURE_KEY_A)));
+        assert_eq!(id.len(), 16);
+        assert!(!FIXTURE_KEY_A.contains(&id));
+        assert_ne!(id, config_key_id(FIXTURE_KEY_B));
+    }
+
+    #[test]
+    fn test_load_trusted_keys_includes_local_key() {
+        let db = create_sync_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        assert_eq!(load_trusted_keys(&conn).len(), BUNDLED_VERIFICATION_KEYS.len());
+
+        crate::db::queries::settings::set_setting(&conn, LOCAL_TRUSTED_KEY_SETTING, FIXTURE_KEY_B).unwrap();
+        let keys = load_trusted_keys(&conn);
+        assert_eq!(keys.len(), BUNDLED_VERIFICATION_KEYS.len() + 1);
+        assert_eq!(keys.last().unwrap().source, TrustedKeySource::Local);
+        assert_eq!(keys.last().unwrap().key_id, config_key_id(FIXTURE_KEY_B));
+    }
+
+    #[test]
+    fn test_trust_status_reports_signing_key_and_fetch_time() {
+        use crate::db::queries::remote_config as rc_queries;
+
+        let db = create_sync_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let status = build_trust_status(None, &fixture_keys());
+        assert_eq!(status.source, ConfigSourceStatus::Defaults);
+        assert!(status.signing_key_id.is_none());
+        assert_eq!(status.trusted_keys.len(), 2);
+
+        let config = make_test_config(vec![make_strategy("a", "A", StrategyStatus::Active)]);
+        let key_id = config_key_id(FIXTURE_KEY_B);
+        rc_queries::store_verified_remote_config(&conn, &config, "sig", Some(&key_id)).unwrap();
+        let cached = rc_queries::get_cached_config(&conn).unwrap();
+
+        let status = build_trust_status(cached, &fixture_keys());
+        assert_eq!(status.source, ConfigSourceStatus::Cached);
+        assert_eq!(status.signing_key_id, Some(key_id));
+        assert_eq!(status.signing_key_source, Some(TrustedKeySource::Local));
+        assert!(status.fetched_at.is_some());
+    }
+
+    #[test]
+    fn test_bundled_config_command_bypasses_network() {
+        // Synchronous and reads only the embedded resource - no HTTP client involved
+        let config = get_bundled_config_command().unwrap();
+        assert_eq!(config.strategies.len(), 5);
+    }
+
+    // ========================================================================
+    // Local config override tests
+    // ========================================================================
+
+    fn make_override(key_path: &str, value_json: &str) -> crate::db::queries::config_overrides::ConfigOverride {
+        crate::db::queries::config_overrides::ConfigOverride {
+            key_path: key_path.to_string(),
+            value_json: value_json.to_string(),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_override_resolves_strategy_by_id() {
+        let config = make_test_config(vec![
+            make_strategy("social-proof", "Social Proof", StrategyStatus::Active),
+            make_strategy("contrarian", "Contrarian", StrategyStatus::Active),
+        ]);
+
+        let (merged, active) = apply_config_overrides(
+            &config,
+            &[make_override("strategies.contrarian.ab_weight", "0.9")],
+        );
+
+        assert!((merged.strategies[1].ab_weight - 0.9).abs() < f64::EPSILON);
+        assert!((merged.strategies[0].ab_weight - 0.5).abs() < f64::EPSILON);
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].remote_value_json.as_deref(), Some("0.5"));
+    }
+
+    #[test]
+    fn test_override_resolves_array_index_and_top_level_key() {
+        let config = make_test_config(vec![make_strategy("a", "A", StrategyStatus::Active)]);
+
+        let (merged, active) = apply_config_overrides(
+            &config,
+            &[
+                make_override("strategies.0.name", "\"Pinned\""),
+                make_override("min_app_version", "\"0.2.0\""),
+            ],
+        );
+
+        assert_eq!(merged.strategies[0].name, "Pinned");
+        assert_eq!(merged.min_app_version, "0.2.0");
+        assert_eq!(active[1].remote_value_json.as_deref(), Some("\"0.1.0\""));
+    }
+
+    #[test]
+    fn test_validate_override_rejects_unknown_path() {
+        let config = make_test_config(vec![make_strategy("a", "A", StrategyStatus::Active)]);
+
+        let err = validate_config_override(&config, "prompts.hook_temperature", "0.7").unwrap_err();
+        assert!(err.contains("Invalid config path"), "Error: {}", err);
+
+        let err = validate_config_override(&config, "strategies.missing.ab_weight", "0.7").unwrap_err();
+        assert!(err.contains("Invalid config path"), "Error: {}", err);
+    }
+
+    #[test]
+    fn test_validate_override_rejects_invalid_value() {
+        let config = make_test_config(vec![make_strategy("a", "A", StrategyStatus::Active)]);
+
+        // Out of range ab_weight fails schema validation
+        let err = validate_config_override(&config, "strategies.a.ab_weight", "5.0").unwrap_err();
+        assert!(err.contains("invalid config"), "Error: {}", err);
+
+        // Malformed JSON value
+        let err = validate_config_override(&config, "strategies.a.ab_weight", "not json").unwrap_err();
+        assert!(err.contains("Invalid override value JSON"), "Error: {}", err);
+
+        assert!(validate_config_override(&config, "strategies.a.ab_weight", "0.3").is_ok());
+    }
+
+    #[test]
+    fn test_stale_override_is_skipped_not_fatal() {
+        // Strategy removed remotely after the override was pinned
+        let config = make_test_config(vec![make_strategy("a", "A", StrategyStatus::Active)]);
+
+        let (merged, active) = apply_config_overrides(
+            &config,
+            &[make_override("strategies.removed.ab_weight", "0.9")],
+        );
+
+        assert_eq!(merged.strategies.len(), 1);
+        assert_eq!(active.len(), 1);
+        assert!(active[0].remote_value_json.is_none());
+    }
+
     // NOTE: Task 4.3 (event emission tests) and Task 7.3 (integration tests)
     // require Tauri AppHandle mock which is not available in unit test context.
     // The sync_hook_strategies() function and background_config_fetch integration
@@ -6,7 +6,7 @@
 use unicode_normalization::UnicodeNormalization;
 
 /// Maximum input characters (~25K tokens at 4 chars/token heuristic)
-const MAX_INPUT_CHARS: usize = 100_000;
+pub const MAX_INPUT_CHARS: usize = 100_000;
 
 /// Result of sanitization process
 #[derive(Debug, Clone, PartialEq)]
@@ -112,6 +112,539 @@ fn truncate_at_sentence_boundary(content: &str, max_chars: usize) -> &str {
     }
 }
 
+/// Query parameters treated as tracking noise when cleaning pasted URLs
+const TRACKING_PARAM_PREFIXES: &[&str] =
+    &["utm_", "fbclid", "gclid", "msclkid", "mc_eid", "mc_cid"];
+
+/// Result of cleaning pasted job content before it is stored
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct CleanedJobContent {
+    /// Content as it will be saved
+    pub content: String,
+    /// Human-readable list of what was changed (empty if nothing changed)
+    pub changes: Vec<String>,
+}
+
+/// Preview of what saving (and later prompting with) pasted job content will do
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct SanitizationPreview {
+    pub content: String,
+    pub changes: Vec<String>,
+    pub original_length: usize,
+    pub sanitized_length: usize,
+    /// True if the saved content exceeds the prompt limit and will be truncated at generation time
+    pub prompt_truncated: bool,
+}
+
+fn plural(count: usize, singular: &str, plural: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, singular)
+    } else {
+        format!("{} {}", count, plural)
+    }
+}
+
+fn is_invisible_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}' | '\u{00AD}'
+    )
+}
+
+/// Remove tracking query parameters from a single URL, returning None if unchanged
+fn strip_tracking_params(url: &str) -> Option<String> {
+    let (base, rest) = url.split_once('?')?;
+    let (query, fragment) = match rest.split_once('#') {
+        Some((q, f)) => (q, Some(f)),
+        None => (rest, None),
+    };
+
+    let params: Vec<&str> = query.split('&').filter(|p| !p.is_empty()).collect();
+    let kept: Vec<&str> = params
+        .iter()
+        .copied()
+        .filter(|p| {
+            let name = p.split('=').next().unwrap_or("").to_ascii_lowercase();
+            !TRACKING_PARAM_PREFIXES.iter().any(|t| name.starts_with(t))
+        })
+        .collect();
+
+    if kept.len() == params.len() {
+        return None;
+    }
+
+    let mut cleaned = base.to_string();
+    if !kept.is_empty() {
+        cleaned.push('?');
+        cleaned.push_str(&kept.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        cleaned.push('#');
+        cleaned.push_str(fragment);
+    }
+    Some(cleaned)
+}
+
+/// Clean pasted job content before storage (Story 4a.9 follow-up: sanitization preview)
+///
+/// This is the single cleaning step applied by the save path, so `preview_sanitization`
+/// always shows exactly what will be stored. Unlike `sanitize_job_content` it does not
+/// escape XML — escaping happens at prompt-build time.
+///
+/// Pipeline:
+/// 1. Normalize Windows/Mac line endings to `\n`
+/// 2. Remove invisible characters (zero-width spaces, BOM, soft hyphens)
+/// 3. Remove non-printable control characters (tabs and newlines are kept)
+/// 4. Strip tracking query parameters (utm_*, fbclid, gclid, ...) from URLs
+/// 5. Collapse runs of more than two blank lines and trim surrounding whitespace
+pub fn clean_job_content(raw_content: &str) -> CleanedJobContent {
+    let mut changes = Vec::new();
+
+    // Step 1: Line endings
+    let crlf_count = raw_content.matches("\r\n").count();
+    let lone_cr_count = raw_content.matches('\r').count() - crlf_count;
+    let mut content = raw_content.replace("\r\n", "\n").replace('\r', "\n");
+    if crlf_count + lone_cr_count > 0 {
+        changes.push(format!(
+            "normalized {}",
+            plural(crlf_count + lone_cr_count, "line ending", "line endings")
+        ));
+    }
+
+    // Step 2 & 3: Invisible and non-printable characters
+    let mut invisible = 0;
+    let mut control = 0;
+    content = content
+        .chars()
+        .filter(|&c| {
+            if is_invisible_char(c) {
+                invisible += 1;
+                false
+            } else if c.is_control() && c != '\n' && c != '\t' {
+                control += 1;
+                false
+            } else {
+                true
+            }
+        })
+        .collect();
+    if invisible > 0 {
+        changes.push(format!(
+            "removed {}",
+            plural(invisible, "invisible character", "invisible characters")
+        ));
+    }
+    if control > 0 {
+        changes.push(format!(
+            "removed {}",
+            plural(
+                control,
+                "non-printable character",
+                "non-printable characters"
+            )
+        ));
+    }
+
+    // Step 4: Tracking URLs
+    let mut stripped_urls = 0;
+    let mut rebuilt = String::with_capacity(content.len());
+    let mut last = 0;
+    for (start, _) in content.match_indices("http") {
+        if start < last {
+            continue;
+        }
+        let candidate = &content[start..];
+        if !(candidate.starts_with("http://") || candidate.starts_with("https://")) {
+            continue;
+        }
+        let end = candidate
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | ')'))
+            .map_or(content.len(), |i| start + i);
+        if let Some(cleaned) = strip_tracking_params(&content[start..end]) {
+            rebuilt.push_str(&content[last..start]);
+            rebuilt.push_str(&cleaned);
+            last = end;
+            stripped_urls += 1;
+        }
+    }
+    if stripped_urls > 0 {
+        rebuilt.push_str(&content[last..]);
+        content = rebuilt;
+        changes.push(format!(
+            "stripped tracking parameters from {}",
+            plural(stripped_urls, "URL", "URLs")
+        ));
+    }
+
+    // Step 5: Excess blank lines and surrounding whitespace
+    let mut collapsed_runs = 0;
+    let mut output = String::with_capacity(content.len());
+    let mut blank_run = 0;
+    for line in content.split('\n') {
+        if line.trim().is_empty() {
+            blank_run += 1;
+            if blank_run == 3 {
+                collapsed_runs += 1;
+            }
+            if blank_run > 2 {
+                continue;
+            }
+        } else {
+            blank_run = 0;
+        }
+        output.push_str(line);
+        output.push('\n');
+    }
+    output.pop();
+    if collapsed_runs > 0 {
+        changes.push(format!(
+            "collapsed {}",
+            plural(collapsed_runs, "run of blank lines", "runs of blank lines")
+        ));
+    }
+
+    let trimmed = output.trim();
+    if trimmed.len() != output.len() {
+        changes.push("trimmed leading/trailing whitespace".to_string());
+    }
+
+    CleanedJobContent {
+        content: trimmed.to_string(),
+        changes,
+    }
+}
+
+/// Preview the save-path cleaning without touching the database
+///
+/// Also reports whether the cleaned content will be truncated when it is later
+/// sanitized for a prompt, so users can see if requirements would be dropped.
+pub fn preview_sanitization(raw_content: &str) -> SanitizationPreview {
+    let cleaned = clean_job_content(raw_content);
+    let prompt_truncated = sanitize_job_content(&cleaned.content).was_truncated;
+    let mut changes = cleaned.changes;
+    if prompt_truncated {
+        changes.push(format!(
+            "content exceeds {} characters and will be truncated when generating",
+            MAX_INPUT_CHARS
+        ));
+    }
+
+    SanitizationPreview {
+        original_length: raw_content.len(),
+        sanitized_length: cleaned.content.len(),
+        content: cleaned.content,
+        changes,
+        prompt_truncated,
+    }
+}
+
+/// Settings key for opt-in PII redaction before generation
+pub const REDACT_PII_SETTING: &str = "redact_pii_before_generation";
+
+/// One kind of PII masked in job content
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PiiRedaction {
+    /// Placeholder used in the redacted text (e.g. "[EMAIL]")
+    pub placeholder: String,
+    pub count: usize,
+}
+
+/// Result of masking PII in job content
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct PiiRedactionResult {
+    pub content: String,
+    pub redactions: Vec<PiiRedaction>,
+}
+
+/// Mask emails, URLs, and phone numbers with typed placeholders
+///
+/// Placeholders (`[EMAIL]`, `[URL]`, `[PHONE]`) keep sentences readable so the
+/// model still understands e.g. "send samples to [EMAIL]". Only the text sent to
+/// Claude is redacted; callers keep storing the original job content.
+///
+/// Phone candidates need 10-15 digits so dates, budgets, and IDs are left alone.
+pub fn redact_pii(content: &str) -> PiiRedactionResult {
+    use regex::Regex;
+    use std::sync::OnceLock;
+
+    static EMAIL: OnceLock<Regex> = OnceLock::new();
+    static URL: OnceLock<Regex> = OnceLock::new();
+    static PHONE: OnceLock<Regex> = OnceLock::new();
+
+    let email = EMAIL.get_or_init(|| {
+        Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}").expect("valid email regex")
+    });
+    let url = URL.get_or_init(|| {
+        Regex::new(r#"(?i)\b(?:https?://|www\.)[^\s<>"')\]]+"#).expect("valid URL regex")
+    });
+    let phone =
+        PHONE.get_or_init(|| Regex::new(r"\+?\(?\d[\d\s().-]{8,}\d").expect("valid phone regex"));
+
+    let mut redactions = Vec::new();
+    let mut text = content.to_string();
+
+    for (pattern, placeholder) in [(email, "[EMAIL]"), (url, "[URL]")] {
+        let count = pattern.find_iter(&text).count();
+        if count > 0 {
+            text = pattern.replace_all(&text, placeholder).into_owned();
+            redactions.push(PiiRedaction {
+                placeholder: placeholder.to_string(),
+                count,
+            });
+        }
+    }
+
+    let mut phone_count = 0;
+    text = phone
+        .replace_all(&text, |caps: &regex::Captures| {
+            let matched = &caps[0];
+            let digits = matched.chars().filter(|c| c.is_ascii_digit()).count();
+            if (10..=15).contains(&digits) {
+                phone_count += 1;
+                "[PHONE]".to_string()
+            } else {
+                matched.to_string()
+            }
+        })
+        .into_owned();
+    if phone_count > 0 {
+        redactions.push(PiiRedaction {
+            placeholder: "[PHONE]".to_string(),
+            count: phone_count,
+        });
+    }
+
+    PiiRedactionResult {
+        content: text,
+        redactions,
+    }
+}
+
+/// Settings key for the generation-time job content cap (characters)
+pub const GENERATION_MAX_CHARS_SETTING: &str = "generation_max_job_chars";
+pub const DEFAULT_GENERATION_MAX_CHARS: usize = 20_000;
+pub const MIN_GENERATION_MAX_CHARS: usize = 1_000;
+
+/// What the pre-generation pass changed in the job content sent to Claude
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SanitizationReport {
+    pub invisible_chars_removed: usize,
+    /// URLs with tracking parameters, reduced to their bare domain
+    pub tracking_urls_stripped: usize,
+    pub whitespace_runs_collapsed: usize,
+    /// Emails, phone numbers, and URLs masked (only with `redact_pii_before_generation`)
+    pub pii_redactions: Vec<PiiRedaction>,
+    pub truncated: bool,
+    pub original_length: usize,
+    pub sanitized_length: usize,
+    /// Human-readable summary (empty if nothing changed)
+    pub changes: Vec<String>,
+}
+
+/// Job content as sent to Claude, with a report of what was removed
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationSanitization {
+    pub content: String,
+    pub report: SanitizationReport,
+}
+
+/// Replace each URL carrying tracking parameters with its bare domain
+fn reduce_tracking_urls(content: &str) -> (String, usize) {
+    let mut stripped = 0;
+    let mut rebuilt = String::with_capacity(content.len());
+    let mut last = 0;
+    for (start, _) in content.match_indices("http") {
+        if start < last {
+            continue;
+        }
+        let candidate = &content[start..];
+        if !(candidate.starts_with("http://") || candidate.starts_with("https://")) {
+            continue;
+        }
+        let end = candidate
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | ')'))
+            .map_or(content.len(), |i| start + i);
+        let url = &content[start..end];
+        if strip_tracking_params(url).is_none() {
+            continue;
+        }
+        if let Some(host) = url::Url::parse(url)
+            .ok()
+            .and_then(|u| u.host_str().map(str::to_string))
+        {
+            rebuilt.push_str(&content[last..start]);
+            rebuilt.push_str(&host);
+            last = end;
+            stripped += 1;
+        }
+    }
+    rebuilt.push_str(&content[last..]);
+    (rebuilt, stripped)
+}
+
+/// Collapse runs of spaces/tabs to one space and more than one blank line to one
+fn collapse_whitespace(content: &str) -> (String, usize) {
+    let mut collapsed = 0;
+    let mut output = String::with_capacity(content.len());
+    let mut blank_run = 0;
+    for line in content.split('\n') {
+        let mut squeezed = String::with_capacity(line.len());
+        let mut in_run = 0;
+        for c in line.trim_end().chars() {
+            if c == ' ' || c == '\t' {
+                in_run += 1;
+                if in_run == 2 {
+                    collapsed += 1;
+                }
+                if in_run == 1 {
+                    squeezed.push(' ');
+                }
+            } else {
+                in_run = 0;
+                squeezed.push(c);
+            }
+        }
+        if squeezed.is_empty() {
+            blank_run += 1;
+            if blank_run == 2 {
+                collapsed += 1;
+            }
+            if blank_run > 1 {
+                continue;
+            }
+        } else {
+            blank_run = 0;
+        }
+        output.push_str(&squeezed);
+        output.push('\n');
+    }
+    (output.trim().to_string(), collapsed)
+}
+
+/// Cut to at most `max_chars` bytes at a sentence end, else at a word boundary
+fn truncate_for_generation(content: &str, max_chars: usize) -> &str {
+    if content.len() <= max_chars {
+        return content;
+    }
+    let mut end = max_chars;
+    while !content.is_char_boundary(end) {
+        end -= 1;
+    }
+    let slice = &content[..end];
+    // Only accept a sentence end in the second half, so we never drop most of the post
+    let sentence_end = [". ", "! ", "? ", ".\n", "!\n", "?\n"]
+        .iter()
+        .filter_map(|p| slice.rfind(p))
+        .max()
+        .filter(|&pos| pos >= end / 2);
+    match sentence_end {
+        Some(pos) => &content[..=pos],
+        None => match slice.rfind(char::is_whitespace) {
+            Some(pos) => content[..pos].trim_end(),
+            None => slice,
+        },
+    }
+}
+
+/// Pre-generation pass over job content sent to Claude
+///
+/// Only the API payload is cleaned; job_posts keeps what the user pasted.
+///
+/// Pipeline:
+/// 1. Remove invisible characters (zero-width spaces, BOM, soft hyphens) and control chars
+/// 2. Reduce URLs with tracking parameters to their bare domain
+/// 3. Mask emails, phone numbers, and URLs when `strip_pii` is set
+/// 4. Collapse repeated spaces/tabs and blank lines
+/// 5. Cap at `max_chars`, cutting at a sentence boundary (never mid-word)
+pub fn sanitize_for_generation(
+    raw_content: &str,
+    strip_pii: bool,
+    max_chars: usize,
+) -> GenerationSanitization {
+    let mut report = SanitizationReport {
+        original_length: raw_content.len(),
+        ..Default::default()
+    };
+
+    // Step 1: Invisible and non-printable characters
+    let content: String = raw_content
+        .replace("\r\n", "\n")
+        .chars()
+        .filter(|&c| {
+            let drop = is_invisible_char(c) || (c.is_control() && c != '\n' && c != '\t');
+            if drop {
+                report.invisible_chars_removed += 1;
+            }
+            !drop
+        })
+        .collect();
+    if report.invisible_chars_removed > 0 {
+        report.changes.push(format!(
+            "removed {}",
+            plural(
+                report.invisible_chars_removed,
+                "invisible character",
+                "invisible characters"
+            )
+        ));
+    }
+
+    // Step 2: Tracking URLs
+    let (mut content, tracking_urls) = reduce_tracking_urls(&content);
+    report.tracking_urls_stripped = tracking_urls;
+    if tracking_urls > 0 {
+        report.changes.push(format!(
+            "reduced {} with tracking parameters to the domain",
+            plural(tracking_urls, "URL", "URLs")
+        ));
+    }
+
+    // Step 3: PII (toggleable)
+    if strip_pii {
+        let redacted = redact_pii(&content);
+        for redaction in &redacted.redactions {
+            report
+                .changes
+                .push(format!("masked {} as {}", redaction.count, redaction.placeholder));
+        }
+        content = redacted.content;
+        report.pii_redactions = redacted.redactions;
+    }
+
+    // Step 4: Whitespace
+    let (content, collapsed) = collapse_whitespace(&content);
+    report.whitespace_runs_collapsed = collapsed;
+    if collapsed > 0 {
+        report.changes.push(format!(
+            "collapsed {}",
+            plural(collapsed, "whitespace run", "whitespace runs")
+        ));
+    }
+
+    // Step 5: Length cap
+    let capped = truncate_for_generation(&content, max_chars);
+    if capped.len() < content.len() {
+        report.truncated = true;
+        tracing::warn!(
+            original_length = content.len(),
+            kept = capped.len(),
+            max_chars = max_chars,
+            "Job content truncated before generation"
+        );
+        report.changes.push(format!(
+            "truncated to {} characters at a sentence boundary",
+            capped.len()
+        ));
+    }
+
+    let content = capped.to_string();
+    report.sanitized_length = content.len();
+    GenerationSanitization { content, report }
+}
+
 /// Estimate token count using character heuristic
 ///
 /// Approximation: 1 token ≈ 4 characters (English text average for Claude)
@@ -445,4 +978,160 @@ mod tests {
             elapsed, threshold_ms
         );
     }
+
+    // ====================
+    // Save-path cleaning (sanitization preview)
+    // ====================
+
+    #[test]
+    fn test_clean_job_content_unchanged() {
+        let input = "Need a React developer.\n\nBudget: $500";
+        let cleaned = clean_job_content(input);
+        assert_eq!(cleaned.content, input);
+        assert!(cleaned.changes.is_empty());
+    }
+
+    #[test]
+    fn test_clean_job_content_strips_tracking_params() {
+        let input = "See https://example.com/job?id=42&utm_source=x&utm_medium=y#top and https://a.io/?gclid=1 now";
+        let cleaned = clean_job_content(input);
+        assert_eq!(
+            cleaned.content,
+            "See https://example.com/job?id=42#top and https://a.io/ now"
+        );
+        assert_eq!(
+            cleaned.changes,
+            vec!["stripped tracking parameters from 2 URLs".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_clean_job_content_removes_hidden_characters() {
+        let input = "Re\u{200B}act\u{0007} dev\r\nwith\u{FEFF} TypeScript\tskills";
+        let cleaned = clean_job_content(input);
+        assert_eq!(cleaned.content, "React dev\nwith TypeScript\tskills");
+        assert!(cleaned
+            .changes
+            .contains(&"normalized 1 line ending".to_string()));
+        assert!(cleaned
+            .changes
+            .contains(&"removed 2 invisible characters".to_string()));
+        assert!(cleaned
+            .changes
+            .contains(&"removed 1 non-printable character".to_string()));
+    }
+
+    #[test]
+    fn test_clean_job_content_collapses_blank_lines() {
+        let input = "  First\n\n\n\n\nSecond\n";
+        let cleaned = clean_job_content(input);
+        assert_eq!(cleaned.content, "First\n\n\nSecond");
+        assert!(cleaned
+            .changes
+            .contains(&"collapsed 1 run of blank lines".to_string()));
+        assert!(cleaned
+            .changes
+            .contains(&"trimmed leading/trailing whitespace".to_string()));
+    }
+
+    #[test]
+    fn test_preview_matches_save_path_cleaning() {
+        let input = "Job\r\nhttps://x.com/?utm_campaign=a";
+        let preview = preview_sanitization(input);
+        assert_eq!(preview.content, clean_job_content(input).content);
+        assert_eq!(preview.original_length, input.len());
+        assert_eq!(preview.sanitized_length, preview.content.len());
+        assert!(!preview.prompt_truncated);
+    }
+
+    #[test]
+    fn test_preview_flags_prompt_truncation() {
+        let input = "Long sentence here. ".repeat(6000);
+        let preview = preview_sanitization(&input);
+        assert!(preview.prompt_truncated);
+        assert!(preview.changes.iter().any(|c| c.contains("truncated")));
+    }
+
+    // ====================
+    // PII redaction before generation
+    // ====================
+
+    #[test]
+    fn test_redact_pii_masks_email_url_phone() {
+        let input =
+            "Email jane.doe@acme.io or call +1 (555) 123-4567. Site: https://acme.io/careers";
+        let result = redact_pii(input);
+        assert_eq!(result.content, "Email [EMAIL] or call [PHONE]. Site: [URL]");
+        assert_eq!(result.redactions.len(), 3);
+        assert!(result.redactions.iter().all(|r| r.count == 1));
+    }
+
+    #[test]
+    fn test_redact_pii_leaves_dates_and_budgets() {
+        let input = "Start 2026-01-15, budget $1,500-2,000, 40 hrs/week.";
+        let result = redact_pii(input);
+        assert_eq!(result.content, input);
+        assert!(result.redactions.is_empty());
+    }
+
+    #[test]
+    fn test_redact_pii_counts_repeats() {
+        let result = redact_pii("a@b.co and c@d.org, www.example.com");
+        assert_eq!(result.content, "[EMAIL] and [EMAIL], [URL]");
+        assert_eq!(
+            result.redactions,
+            vec![
+                PiiRedaction {
+                    placeholder: "[EMAIL]".to_string(),
+                    count: 2
+                },
+                PiiRedaction {
+                    placeholder: "[URL]".to_string(),
+                    count: 1
+                },
+            ]
+        );
+    }
+
+    // ====================
+    // Pre-generation sanitization
+    // ====================
+
+    #[test]
+    fn test_generation_pass_strips_junk_and_reports_it() {
+        let input = "Need\u{200B} a   React dev.\n\n\n\nSee https://acme.io/jobs?id=7&utm_source=upwork for details. \
+            Email hiring@acme.io or call +1 (555) 123-4567.";
+
+        let kept = sanitize_for_generation(input, false, DEFAULT_GENERATION_MAX_CHARS);
+        assert_eq!(
+            kept.content,
+            "Need a React dev.\n\nSee acme.io for details. Email hiring@acme.io or call +1 (555) 123-4567."
+        );
+        assert_eq!(kept.report.invisible_chars_removed, 1);
+        assert_eq!(kept.report.tracking_urls_stripped, 1);
+        assert_eq!(kept.report.whitespace_runs_collapsed, 2);
+        assert!(kept.report.pii_redactions.is_empty());
+        assert!(!kept.report.truncated);
+
+        // Aggressive PII stripping is opt-in
+        let stripped = sanitize_for_generation(input, true, DEFAULT_GENERATION_MAX_CHARS);
+        assert!(stripped.content.contains("Email [EMAIL] or call [PHONE]."));
+        assert_eq!(stripped.report.pii_redactions.len(), 2);
+    }
+
+    #[test]
+    fn test_generation_pass_truncates_at_sentence_not_mid_word() {
+        let input = "First sentence here. Second sentence is longer than the cap allows";
+        let result = sanitize_for_generation(input, false, 30);
+        assert_eq!(result.content, "First sentence here.");
+        assert!(result.report.truncated);
+
+        // No sentence end in range: cut at the last whole word
+        let result = sanitize_for_generation("alpha beta gamma delta epsilon", false, 14);
+        assert_eq!(result.content, "alpha beta");
+
+        let validate = |v| crate::settings::schema::validate(GENERATION_MAX_CHARS_SETTING, v);
+        assert!(validate("500").is_err());
+        assert!(validate("5000").is_ok());
+    }
 }
@@ -87,6 +87,14 @@ pub fn determine_color_flag(skills_match: f64, client_quality: i32, is_zero_hire
     }
 }
 
+/// Apply-rate guardrail: generation is refused for jobs scored below this (0-100, 0 disables)
+pub const MIN_SCORE_TO_GENERATE_SETTING: &str = "min_score_to_generate";
+
+/// Whether `overall_score` falls below the guard floor (a floor of 0 never blocks)
+pub fn is_below_score_floor(overall_score: f64, floor: f64) -> bool {
+    floor > 0.0 && overall_score < floor
+}
+
 /// Scoring breakdown for detailed UI display (Story 4b.6)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -110,6 +118,8 @@ pub struct ScoringBreakdown {
     pub budget_alignment_pct: Option<i32>,
     pub budget_display: String,
     pub budget_type: String,
+    /// ISO code of the posted budget; None = no marker (USD assumed)
+    pub budget_currency: Option<String>,
 
     // Recommendation
     pub recommendation: String,
@@ -183,22 +193,27 @@ fn generate_client_quality_signals(score: Option<i32>) -> String {
 
 /// Generate budget display text (Story 4b.6)
 ///
-/// Formats budget data into human-readable string like "$55/hr vs your $50/hr rate"
+/// Formats budget data into human-readable string like "$55/hr vs your $50/hr rate".
+/// The job's amounts are shown in its own currency (None = USD, e.g. "€55/hr"); the
+/// user's rate is always USD.
 fn generate_budget_display(
     budget_min: Option<f64>,
     budget_max: Option<f64>,
     budget_type: &str,
+    budget_currency: Option<&str>,
     user_hourly_rate: Option<f64>,
 ) -> String {
+    let job_amount = |amount: f64| crate::currency::format_amount(amount, budget_currency);
     match budget_type {
         "hourly" => {
             if let (Some(job_rate), Some(user_rate)) = (budget_min, user_hourly_rate) {
                 format!(
-                    "${}/hr vs your ${}/hr rate",
-                    job_rate as i32, user_rate as i32
+                    "{}/hr vs your ${}/hr rate",
+                    job_amount(job_rate),
+                    user_rate as i32
                 )
             } else if let Some(job_rate) = budget_min {
-                format!("${}/hr (rate not configured)", job_rate as i32)
+                format!("{}/hr (rate not configured)", job_amount(job_rate))
             } else {
                 "Hourly rate not specified".into()
             }
@@ -206,12 +221,12 @@ fn generate_budget_display(
         "fixed" => {
             if let (Some(min), Some(max)) = (budget_min, budget_max) {
                 if min == max {
-                    format!("${} fixed project", min as i32)
+                    format!("{} fixed project", job_amount(min))
                 } else {
-                    format!("${}-${} fixed project", min as i32, max as i32)
+                    format!("{}-{} fixed project", job_amount(min), job_amount(max))
                 }
             } else if let Some(amount) = budget_min {
-                format!("${} fixed project", amount as i32)
+                format!("{} fixed project", job_amount(amount))
             } else {
                 "Fixed budget not specified".into()
             }
@@ -258,11 +273,16 @@ pub fn assemble_scoring_breakdown(
     };
 
     // 4. Get budget data from job_posts table
-    let (budget_min, budget_max, budget_type): (Option<f64>, Option<f64>, String) = conn
+    let (budget_min, budget_max, budget_type, budget_currency): (
+        Option<f64>,
+        Option<f64>,
+        String,
+        Option<String>,
+    ) = conn
         .query_row(
-            "SELECT budget_min, budget_max, budget_type FROM job_posts WHERE id = ?",
+            "SELECT budget_min, budget_max, budget_type, budget_currency FROM job_posts WHERE id = ?",
             rusqlite::params![job_post_id],
-            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
         )
         .map_err(|e| format!("Failed to query budget data: {}", e))?;
 
@@ -281,8 +301,13 @@ pub fn assemble_scoring_breakdown(
     let client_quality_signals = generate_client_quality_signals(score.client_quality_score);
 
     // 7. Generate budget display
-    let budget_display =
-        generate_budget_display(budget_min, budget_max, &budget_type, user_hourly_rate);
+    let budget_display = generate_budget_display(
+        budget_min,
+        budget_max,
+        &budget_type,
+        budget_currency.as_deref(),
+        user_hourly_rate,
+    );
 
     // 8. Generate recommendation using recalculated skills percentage
     let recommendation = generate_recommendation(
@@ -306,6 +331,7 @@ pub fn assemble_scoring_breakdown(
         budget_alignment_pct: score.budget_alignment_score,
         budget_display,
         budget_type,
+        budget_currency,
         recommendation,
     })
 }
@@ -356,6 +382,40 @@ pub struct CanReportResult {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_min_score_to_generate_floor() {
+        let validate = |v| crate::settings::schema::validate(MIN_SCORE_TO_GENERATE_SETTING, v);
+        assert!(validate(" 55 ").is_ok());
+        assert!(validate("101").is_err());
+        assert!(validate("-1").is_err());
+        assert!(validate("high").is_err());
+
+        assert!(is_below_score_floor(49.9, 50.0));
+        assert!(!is_below_score_floor(50.0, 50.0));
+        // 0 disables the guard
+        assert!(!is_below_score_floor(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_budget_display_uses_job_currency() {
+        assert_eq!(
+            generate_budget_display(Some(55.0), Some(55.0), "hourly", None, Some(50.0)),
+            "$55/hr vs your $50/hr rate"
+        );
+        assert_eq!(
+            generate_budget_display(Some(500.0), Some(500.0), "fixed", Some("EUR"), None),
+            "€500 fixed project"
+        );
+        assert_eq!(
+            generate_budget_display(Some(400.0), Some(600.0), "fixed", Some("GBP"), None),
+            "£400-£600 fixed project"
+        );
+        assert_eq!(
+            generate_budget_display(Some(40.0), None, "hourly", Some("PLN"), None),
+            "40 PLN/hr (rate not configured)"
+        );
+    }
+
     #[test]
     fn test_perfect_score() {
         let result = calculate_overall_score(Some(100.0), Some(100), Some(100));
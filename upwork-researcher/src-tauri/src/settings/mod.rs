@@ -0,0 +1,6 @@
+//! Typed access to the key/value `settings` table
+//!
+//! Storage lives in `db::queries::settings`; `schema` declares what each known
+//! key holds and how it is validated.
+
+pub mod schema;
@@ -0,0 +1,985 @@
+//! Settings schema registry
+//!
+//! Declares every settings key the app reads with its type, default and a
+//! description. `set_setting` validates writes to known keys against it (unknown
+//! keys are still stored, so newer frontends keep working), readers go through
+//! `get_typed`, and `get_settings_schema` hands the registry to the settings form.
+//!
+//! Writes are strict: an out-of-range or malformed value is rejected. Reads are
+//! lenient, because older versions stored values without checking: numbers are
+//! clamped into range, enum values are matched case-insensitively, and anything
+//! else invalid falls back to the default. Keys holding structured text (URLs,
+//! phrase lists) are declared as `Text`; their own validators in `set_setting`
+//! still run after the schema check.
+
+use crate::db::queries::settings::get_setting;
+use crate::humanization::HumanizationIntensity;
+use rusqlite::Connection;
+use serde::Serialize;
+
+/// Value type and constraints of a setting
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SettingKind {
+    /// Whole number in `min..=max` (no upper bound when `max` is None)
+    Int { min: i64, max: Option<i64> },
+    /// Finite number in `min..=max` (no upper bound when `max` is None)
+    Float { min: f64, max: Option<f64> },
+    /// One of `values`, exact case
+    Enum { values: &'static [&'static str] },
+    /// "true" or "false"
+    Bool,
+    /// RFC 3339, or SQLite's `datetime('now')` format ("YYYY-MM-DD HH:MM:SS", UTC)
+    Timestamp,
+    /// Free text of at most `max_chars` characters (surrounding whitespace not counted)
+    Text {
+        #[serde(rename = "maxChars")]
+        max_chars: usize,
+    },
+}
+
+/// Default value of a setting, serialized as the matching JSON type
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum SettingDefault {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(&'static str),
+}
+
+impl SettingDefault {
+    /// The default as it would be stored in the settings table
+    pub fn to_stored(self) -> String {
+        match self {
+            Self::Int(v) => v.to_string(),
+            Self::Float(v) => v.to_string(),
+            Self::Bool(v) => v.to_string(),
+            Self::Str(v) => v.to_string(),
+        }
+    }
+}
+
+/// One entry of the registry
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingDef {
+    pub key: &'static str,
+    pub kind: SettingKind,
+    /// None: unset means "not configured" (or falls back to another setting)
+    pub default: Option<SettingDefault>,
+    pub description: &'static str,
+}
+
+/// Longest free-text value `set_setting` accepts
+const MAX_TEXT_CHARS: usize = 10_000;
+
+const SAFETY_THRESHOLD: SettingKind = SettingKind::Int {
+    min: 140,
+    max: Some(220),
+};
+const RATE: SettingKind = SettingKind::Float {
+    min: 0.01,
+    max: Some(999_999.0),
+};
+const FLAG: SettingKind = SettingKind::Bool;
+const TEXT: SettingKind = SettingKind::Text {
+    max_chars: MAX_TEXT_CHARS,
+};
+const QUALITY_WEIGHT: SettingKind = SettingKind::Float {
+    min: 0.0,
+    max: None,
+};
+const RATE_LIMIT: SettingKind = SettingKind::Int {
+    min: 0,
+    max: Some(crate::http::MAX_RATE_LIMIT_RPM as i64),
+};
+
+/// Every known settings key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    SafetyThreshold,
+    SafetyThresholdOff,
+    SafetyThresholdLight,
+    SafetyThresholdMedium,
+    SafetyThresholdHeavy,
+    HumanizationIntensity,
+    UserHourlyRate,
+    UserProjectRateMin,
+    Theme,
+    LogLevel,
+    OnboardingCompleted,
+    AutoUpdateEnabled,
+    UpdateChannel,
+    CrashReportingEnabled,
+    LastUpdateCheck,
+    CooldownSeconds,
+    GenerationTimeoutSecs,
+    DraftAutosaveMs,
+    GenerationProgressTokens,
+    DraftRetentionDays,
+    JobStaleDays,
+    AnalysisCacheDays,
+    RevisionKeyframeInterval,
+    GenerationMaxJobChars,
+    RedactPiiBeforeGeneration,
+    MinScoreToGenerate,
+    PerplexityChunkTokens,
+    GoldenSetMinWords,
+    ActiveVoiceProfile,
+    PrewarmVoiceCache,
+    QualityWeightPerplexity,
+    QualityWeightVoice,
+    QualityWeightCoverage,
+    RateLimitAnthropicRpm,
+    RateLimitGithubRpm,
+    RateLimitUpworkRpm,
+    LogApiPayloads,
+    PerfMetricsEnabled,
+    ClipboardWatchEnabled,
+    AllowInsecureApi,
+    ApiBaseUrl,
+    NetworkAllowlistExtra,
+    CustomSystemPromptAddendum,
+    AiTellUserPhrases,
+    AiTellMatchMode,
+    ThresholdSuggestionDismissedAt,
+    SafetyOverrideLast,
+    SafetyOverrideCount,
+    ProposalsEditedCount,
+    VoiceLearningThreshold,
+    DevMode,
+    DigestEnabled,
+    ThresholdLearningIncrement,
+    ThresholdLearningProximity,
+    ThresholdLearningOverrideCount,
+    ThresholdInactivityDays,
+    NotifyOnHired,
+    NotifyStrategyThresholdPct,
+    WebhookUrl,
+}
+
+impl Key {
+    pub const ALL: [Key; 59] = [
+        Key::SafetyThreshold,
+        Key::SafetyThresholdOff,
+        Key::SafetyThresholdLight,
+        Key::SafetyThresholdMedium,
+        Key::SafetyThresholdHeavy,
+        Key::HumanizationIntensity,
+        Key::UserHourlyRate,
+        Key::UserProjectRateMin,
+        Key::Theme,
+        Key::LogLevel,
+        Key::OnboardingCompleted,
+        Key::AutoUpdateEnabled,
+        Key::UpdateChannel,
+        Key::CrashReportingEnabled,
+        Key::LastUpdateCheck,
+        Key::CooldownSeconds,
+        Key::GenerationTimeoutSecs,
+        Key::DraftAutosaveMs,
+        Key::GenerationProgressTokens,
+        Key::DraftRetentionDays,
+        Key::JobStaleDays,
+        Key::AnalysisCacheDays,
+        Key::RevisionKeyframeInterval,
+        Key::GenerationMaxJobChars,
+        Key::RedactPiiBeforeGeneration,
+        Key::MinScoreToGenerate,
+        Key::PerplexityChunkTokens,
+        Key::GoldenSetMinWords,
+        Key::ActiveVoiceProfile,
+        Key::PrewarmVoiceCache,
+        Key::QualityWeightPerplexity,
+        Key::QualityWeightVoice,
+        Key::QualityWeightCoverage,
+        Key::RateLimitAnthropicRpm,
+        Key::RateLimitGithubRpm,
+        Key::RateLimitUpworkRpm,
+        Key::LogApiPayloads,
+        Key::PerfMetricsEnabled,
+        Key::ClipboardWatchEnabled,
+        Key::AllowInsecureApi,
+        Key::ApiBaseUrl,
+        Key::NetworkAllowlistExtra,
+        Key::CustomSystemPromptAddendum,
+        Key::AiTellUserPhrases,
+        Key::AiTellMatchMode,
+        Key::ThresholdSuggestionDismissedAt,
+        Key::SafetyOverrideLast,
+        Key::SafetyOverrideCount,
+        Key::ProposalsEditedCount,
+        Key::VoiceLearningThreshold,
+        Key::DevMode,
+        Key::DigestEnabled,
+        Key::ThresholdLearningIncrement,
+        Key::ThresholdLearningProximity,
+        Key::ThresholdLearningOverrideCount,
+        Key::ThresholdInactivityDays,
+        Key::NotifyOnHired,
+        Key::NotifyStrategyThresholdPct,
+        Key::WebhookUrl,
+    ];
+
+    /// The key stored under `name`, if it is a known key
+    pub fn from_name(name: &str) -> Option<Key> {
+        Key::ALL.into_iter().find(|k| k.def().key == name)
+    }
+
+    /// The per-intensity safety threshold key (`safety_threshold_{intensity}`)
+    pub fn safety_threshold_for(intensity: HumanizationIntensity) -> Key {
+        match intensity {
+            HumanizationIntensity::Off => Key::SafetyThresholdOff,
+            HumanizationIntensity::Light => Key::SafetyThresholdLight,
+            HumanizationIntensity::Medium => Key::SafetyThresholdMedium,
+            HumanizationIntensity::Heavy => Key::SafetyThresholdHeavy,
+        }
+    }
+
+    pub fn def(self) -> SettingDef {
+        use SettingDefault::{Bool, Float, Int, Str};
+        let (key, kind, default, description) = match self {
+            Key::SafetyThreshold => (
+                "safety_threshold",
+                SAFETY_THRESHOLD,
+                Some(Int(180)),
+                "Perplexity score above which a proposal is flagged as likely AI-written",
+            ),
+            Key::SafetyThresholdOff => (
+                "safety_threshold_off",
+                SAFETY_THRESHOLD,
+                None,
+                "Safety threshold for humanization off; unset uses safety_threshold",
+            ),
+            Key::SafetyThresholdLight => (
+                "safety_threshold_light",
+                SAFETY_THRESHOLD,
+                None,
+                "Safety threshold for light humanization; unset uses safety_threshold",
+            ),
+            Key::SafetyThresholdMedium => (
+                "safety_threshold_medium",
+                SAFETY_THRESHOLD,
+                None,
+                "Safety threshold for medium humanization; unset uses safety_threshold",
+            ),
+            Key::SafetyThresholdHeavy => (
+                "safety_threshold_heavy",
+                SAFETY_THRESHOLD,
+                None,
+                "Safety threshold for heavy humanization; unset uses safety_threshold",
+            ),
+            Key::HumanizationIntensity => (
+                "humanization_intensity",
+                SettingKind::Enum {
+                    values: &["off", "light", "medium", "heavy"],
+                },
+                Some(Str("medium")),
+                "How strongly generated proposals are rewritten to sound human",
+            ),
+            Key::UserHourlyRate => (
+                "user_hourly_rate",
+                RATE,
+                None,
+                "Your hourly rate in USD, used for budget alignment",
+            ),
+            Key::UserProjectRateMin => (
+                "user_project_rate_min",
+                RATE,
+                None,
+                "Smallest fixed-price project you take, in USD",
+            ),
+            Key::Theme => (
+                "theme",
+                SettingKind::Enum {
+                    values: &["dark", "light"],
+                },
+                Some(Str("dark")),
+                "Color theme",
+            ),
+            Key::LogLevel => (
+                "log_level",
+                SettingKind::Enum {
+                    values: &["ERROR", "WARN", "INFO", "DEBUG"],
+                },
+                Some(Str("INFO")),
+                "Log verbosity (applies after restart)",
+            ),
+            Key::OnboardingCompleted => (
+                "onboarding_completed",
+                FLAG,
+                Some(Bool(false)),
+                "Whether first-run onboarding has been finished",
+            ),
+            Key::AutoUpdateEnabled => (
+                "auto_update_enabled",
+                FLAG,
+                Some(Bool(true)),
+                "Check for and install app updates automatically",
+            ),
+            Key::UpdateChannel => (
+                "update_channel",
+                SettingKind::Enum {
+                    values: &["stable", "beta"],
+                },
+                Some(Str("stable")),
+                "Release feed for app updates; beta offers pre-release builds",
+            ),
+            Key::CrashReportingEnabled => (
+                "crash_reporting_enabled",
+                FLAG,
+                Some(Bool(false)),
+                "Opt in to anonymous crash reports",
+            ),
+            Key::LastUpdateCheck => (
+                "last_update_check",
+                SettingKind::Timestamp,
+                None,
+                "When the app last checked for updates",
+            ),
+            Key::CooldownSeconds => (
+                crate::COOLDOWN_SECONDS_SETTING,
+                SettingKind::Int {
+                    min: 0,
+                    max: Some(crate::MAX_COOLDOWN_SECONDS as i64),
+                },
+                Some(Int(crate::DEFAULT_COOLDOWN_SECONDS as i64)),
+                "Seconds between generations (0 disables the cooldown)",
+            ),
+            Key::GenerationTimeoutSecs => (
+                crate::claude::GENERATION_TIMEOUT_SETTING,
+                SettingKind::Int {
+                    min: crate::claude::MIN_GENERATION_TIMEOUT_SECS as i64,
+                    max: Some(crate::claude::MAX_GENERATION_TIMEOUT_SECS as i64),
+                },
+                Some(Int(crate::claude::DEFAULT_GENERATION_TIMEOUT_SECS as i64)),
+                "Seconds a generation may go without receiving text before it is aborted",
+            ),
+            Key::DraftAutosaveMs => (
+                crate::claude::DRAFT_AUTOSAVE_SETTING,
+                SettingKind::Int {
+                    min: crate::claude::MIN_DRAFT_AUTOSAVE_MS as i64,
+                    max: Some(crate::claude::MAX_DRAFT_AUTOSAVE_MS as i64),
+                },
+                Some(Int(crate::claude::DEFAULT_DRAFT_AUTOSAVE_MS as i64)),
+                "Milliseconds between draft autosaves while generating",
+            ),
+            Key::GenerationProgressTokens => (
+                crate::claude::PROGRESS_INTERVAL_SETTING,
+                SettingKind::Int {
+                    min: crate::claude::MIN_PROGRESS_INTERVAL_TOKENS as i64,
+                    max: Some(crate::claude::MAX_PROGRESS_INTERVAL_TOKENS as i64),
+                },
+                Some(Int(crate::claude::DEFAULT_PROGRESS_INTERVAL_TOKENS as i64)),
+                "Token events between generation progress updates",
+            ),
+            Key::DraftRetentionDays => (
+                crate::commands::drafts::DRAFT_RETENTION_DAYS_SETTING,
+                SettingKind::Int {
+                    min: 1,
+                    max: Some(crate::commands::drafts::MAX_DRAFT_RETENTION_DAYS as i64),
+                },
+                Some(Int(
+                    crate::commands::drafts::DEFAULT_DRAFT_RETENTION_DAYS as i64
+                )),
+                "Days an unfinished draft is kept before it is discarded",
+            ),
+            Key::JobStaleDays => (
+                crate::commands::job_queue::JOB_STALE_DAYS_SETTING,
+                SettingKind::Int {
+                    min: 1,
+                    max: Some(crate::commands::job_queue::MAX_JOB_STALE_DAYS as i64),
+                },
+                Some(Int(
+                    crate::commands::job_queue::DEFAULT_JOB_STALE_DAYS as i64
+                )),
+                "Days without being seen before a job is marked stale",
+            ),
+            Key::AnalysisCacheDays => (
+                crate::db::queries::analysis_cache::ANALYSIS_CACHE_DAYS_SETTING,
+                SettingKind::Int {
+                    min: 0,
+                    max: Some(crate::db::queries::analysis_cache::MAX_ANALYSIS_CACHE_DAYS as i64),
+                },
+                Some(Int(
+                    crate::db::queries::analysis_cache::DEFAULT_ANALYSIS_CACHE_DAYS as i64,
+                )),
+                "Days a job analysis is reused for identical job text (0 disables the cache)",
+            ),
+            Key::RevisionKeyframeInterval => (
+                crate::db::queries::revisions::REVISION_KEYFRAME_INTERVAL_SETTING,
+                SettingKind::Int {
+                    min: 1,
+                    max: Some(crate::db::queries::revisions::MAX_REVISION_KEYFRAME_INTERVAL as i64),
+                },
+                Some(Int(
+                    crate::db::queries::revisions::DEFAULT_REVISION_KEYFRAME_INTERVAL as i64,
+                )),
+                "Every Nth proposal revision is stored in full, the rest as changes (1 stores all in full)",
+            ),
+            Key::GenerationMaxJobChars => (
+                crate::sanitization::GENERATION_MAX_CHARS_SETTING,
+                SettingKind::Int {
+                    min: crate::sanitization::MIN_GENERATION_MAX_CHARS as i64,
+                    max: Some(crate::sanitization::MAX_INPUT_CHARS as i64),
+                },
+                Some(Int(crate::sanitization::DEFAULT_GENERATION_MAX_CHARS as i64)),
+                "Characters of a job post sent to Claude",
+            ),
+            Key::RedactPiiBeforeGeneration => (
+                crate::sanitization::REDACT_PII_SETTING,
+                FLAG,
+                Some(Bool(false)),
+                "Mask emails, phone numbers and similar in job posts before generating",
+            ),
+            Key::MinScoreToGenerate => (
+                crate::scoring::MIN_SCORE_TO_GENERATE_SETTING,
+                SettingKind::Float {
+                    min: 0.0,
+                    max: Some(100.0),
+                },
+                Some(Float(0.0)),
+                "Warn before generating for jobs scored below this (0 never warns)",
+            ),
+            Key::PerplexityChunkTokens => (
+                crate::perplexity_chunks::PERPLEXITY_CHUNK_TOKENS_SETTING,
+                SettingKind::Int {
+                    min: crate::perplexity_chunks::MIN_PERPLEXITY_CHUNK_TOKENS as i64,
+                    max: Some(crate::perplexity_chunks::MAX_PERPLEXITY_CHUNK_TOKENS as i64),
+                },
+                Some(Int(
+                    crate::perplexity_chunks::DEFAULT_PERPLEXITY_CHUNK_TOKENS as i64,
+                )),
+                "Tokens per chunk when analyzing long proposals for AI detection",
+            ),
+            Key::GoldenSetMinWords => (
+                crate::db::queries::golden_set::MIN_WORD_COUNT_SETTING,
+                SettingKind::Int {
+                    min: crate::db::queries::golden_set::MIN_WORD_COUNT_FLOOR as i64,
+                    max: None,
+                },
+                Some(Int(
+                    crate::db::queries::golden_set::DEFAULT_MIN_WORD_COUNT as i64
+                )),
+                "Fewest words a past proposal needs to join the voice golden set",
+            ),
+            Key::ActiveVoiceProfile => (
+                crate::db::queries::voice_profile::ACTIVE_VOICE_PROFILE_SETTING,
+                SettingKind::Text {
+                    max_chars: crate::db::queries::voice_profile::MAX_PROFILE_NAME_CHARS,
+                },
+                Some(Str(crate::db::queries::voice_profile::DEFAULT_PROFILE_NAME)),
+                "Voice profile used for generation",
+            ),
+            Key::PrewarmVoiceCache => (
+                crate::PREWARM_VOICE_CACHE_SETTING,
+                FLAG,
+                Some(Bool(true)),
+                "Load the active voice profile at startup so the first generation is faster",
+            ),
+            Key::QualityWeightPerplexity => (
+                crate::quality::QUALITY_WEIGHT_SETTINGS[0],
+                QUALITY_WEIGHT,
+                Some(Float(0.4)),
+                "Relative weight of the perplexity component in the quality score",
+            ),
+            Key::QualityWeightVoice => (
+                crate::quality::QUALITY_WEIGHT_SETTINGS[1],
+                QUALITY_WEIGHT,
+                Some(Float(0.3)),
+                "Relative weight of the voice match component in the quality score",
+            ),
+            Key::QualityWeightCoverage => (
+                crate::quality::QUALITY_WEIGHT_SETTINGS[2],
+                QUALITY_WEIGHT,
+                Some(Float(0.3)),
+                "Relative weight of the job coverage component in the quality score",
+            ),
+            Key::RateLimitAnthropicRpm => (
+                crate::http::RATE_LIMIT_SETTINGS[0].0,
+                RATE_LIMIT,
+                Some(Int(0)),
+                "Requests per minute to the Anthropic API (0 is unlimited)",
+            ),
+            Key::RateLimitGithubRpm => (
+                crate::http::RATE_LIMIT_SETTINGS[1].0,
+                RATE_LIMIT,
+                Some(Int(0)),
+                "Requests per minute for remote config downloads (0 is unlimited)",
+            ),
+            Key::RateLimitUpworkRpm => (
+                crate::http::RATE_LIMIT_SETTINGS[2].0,
+                RATE_LIMIT,
+                Some(Int(0)),
+                "Requests per minute to Upwork (0 is unlimited)",
+            ),
+            Key::LogApiPayloads => (
+                crate::logs::api_debug::LOG_API_PAYLOADS_SETTING,
+                FLAG,
+                Some(Bool(false)),
+                "Log redacted API request and response bodies for debugging",
+            ),
+            Key::PerfMetricsEnabled => (
+                crate::command_metrics::PERF_METRICS_SETTING,
+                FLAG,
+                Some(Bool(false)),
+                "Record command timings",
+            ),
+            Key::ClipboardWatchEnabled => (
+                crate::commands::clipboard_watch::CLIPBOARD_WATCH_SETTING,
+                FLAG,
+                Some(Bool(false)),
+                "Offer to import job posts copied to the clipboard",
+            ),
+            Key::AllowInsecureApi => (
+                crate::network::ALLOW_INSECURE_API_SETTING,
+                FLAG,
+                Some(Bool(false)),
+                "Allow an http:// API base URL",
+            ),
+            Key::ApiBaseUrl => (
+                crate::network::API_BASE_URL_SETTING,
+                TEXT,
+                None,
+                "Alternative API base URL (a proxy or gateway); unset uses Anthropic",
+            ),
+            Key::NetworkAllowlistExtra => (
+                crate::network::ALLOWLIST_EXTRA_SETTING,
+                TEXT,
+                None,
+                "Extra domains the app may contact",
+            ),
+            Key::CustomSystemPromptAddendum => (
+                crate::claude::SYSTEM_PROMPT_ADDENDUM_SETTING,
+                SettingKind::Text {
+                    max_chars: crate::claude::MAX_SYSTEM_PROMPT_ADDENDUM_CHARS,
+                },
+                None,
+                "Persona instructions added to the generation prompt",
+            ),
+            Key::AiTellUserPhrases => (
+                crate::humanization::AI_TELL_USER_PHRASES_SETTING,
+                TEXT,
+                None,
+                "Your own phrases to flag as AI tells",
+            ),
+            Key::AiTellMatchMode => (
+                crate::humanization::AI_TELL_MATCH_MODE_SETTING,
+                SettingKind::Enum {
+                    values: &["word", "word_prefix"],
+                },
+                Some(Str("word_prefix")),
+                "Whether AI-tell phrases also match inflections",
+            ),
+            Key::ThresholdSuggestionDismissedAt => (
+                "threshold_suggestion_dismissed_at",
+                SettingKind::Timestamp,
+                None,
+                "When the threshold adjustment suggestion was last dismissed",
+            ),
+            Key::SafetyOverrideLast => (
+                "safety_override_last",
+                SettingKind::Timestamp,
+                None,
+                "When a safety warning was last overridden",
+            ),
+            Key::SafetyOverrideCount => (
+                "safety_override_count",
+                SettingKind::Int { min: 0, max: None },
+                Some(Int(0)),
+                "Safety warnings overridden so far",
+            ),
+            Key::ProposalsEditedCount => (
+                "proposals_edited_count",
+                SettingKind::Int { min: 0, max: None },
+                Some(Int(0)),
+                "Proposals edited so far (voice learning progress)",
+            ),
+            Key::VoiceLearningThreshold => (
+                "voice_learning_threshold",
+                SettingKind::Int {
+                    min: 1,
+                    max: Some(1000),
+                },
+                Some(Int(crate::commands::voice::DEFAULT_VOICE_LEARNING_THRESHOLD)),
+                "Edited proposals after which recalibrating your voice is suggested",
+            ),
+            Key::DevMode => (
+                "dev_mode",
+                FLAG,
+                Some(Bool(false)),
+                "Enable developer tools such as the generation prompt preview",
+            ),
+            Key::DigestEnabled => (
+                "digest_enabled",
+                FLAG,
+                Some(Bool(false)),
+                "Prepare last week's digest at startup and notify when it is ready",
+            ),
+            Key::ThresholdLearningIncrement => (
+                "threshold_learning_increment",
+                SettingKind::Int {
+                    min: 5,
+                    max: Some(20),
+                },
+                Some(Int(crate::THRESHOLD_INCREMENT as i64)),
+                "Points a threshold learning suggestion raises the safety threshold by",
+            ),
+            Key::ThresholdLearningProximity => (
+                "threshold_learning_proximity",
+                SettingKind::Int {
+                    min: 5,
+                    max: Some(20),
+                },
+                Some(Int(crate::THRESHOLD_PROXIMITY as i64)),
+                "How close to the threshold an overridden score must be to count toward learning",
+            ),
+            Key::ThresholdLearningOverrideCount => (
+                "threshold_learning_override_count",
+                SettingKind::Int {
+                    min: 2,
+                    max: Some(10),
+                },
+                Some(Int(crate::SUCCESSFUL_OVERRIDE_THRESHOLD as i64)),
+                "Successful overrides within 30 days needed before a threshold increase is suggested",
+            ),
+            Key::ThresholdInactivityDays => (
+                "threshold_inactivity_days",
+                SettingKind::Int {
+                    min: 14,
+                    max: Some(365),
+                },
+                Some(Int(crate::INACTIVITY_DAYS as i64)),
+                "Days without overrides before lowering a raised threshold is suggested",
+            ),
+            Key::NotifyOnHired => (
+                crate::notifications::NOTIFY_ON_HIRED_SETTING,
+                FLAG,
+                Some(Bool(false)),
+                "Notify when a proposal's outcome is set to hired",
+            ),
+            Key::NotifyStrategyThresholdPct => (
+                crate::notifications::STRATEGY_THRESHOLD_SETTING,
+                SettingKind::Int {
+                    min: 1,
+                    max: Some(100),
+                },
+                None,
+                "Notify when a hook strategy's response rate reaches this percentage; unset turns it off",
+            ),
+            Key::WebhookUrl => (
+                crate::notifications::WEBHOOK_URL_SETTING,
+                TEXT,
+                None,
+                "Local webhook that milestone notifications are also POSTed to (must be allowlisted)",
+            ),
+        };
+        SettingDef {
+            key,
+            kind,
+            default,
+            description,
+        }
+    }
+}
+
+impl SettingKind {
+    /// Check a value about to be written; the error names what was expected
+    fn check(&self, value: &str) -> Result<(), String> {
+        match *self {
+            SettingKind::Int { min, max } => {
+                let n = value
+                    .trim()
+                    .parse::<i64>()
+                    .map_err(|_| "expected a whole number".to_string())?;
+                if n < min || max.is_some_and(|max| n > max) {
+                    return Err(range_error(min, max));
+                }
+            }
+            SettingKind::Float { min, max } => {
+                let n = value
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|n| n.is_finite())
+                    .ok_or_else(|| "expected a number".to_string())?;
+                if n < min || max.is_some_and(|max| n > max) {
+                    return Err(range_error(min, max));
+                }
+            }
+            SettingKind::Enum { values } => {
+                if !values.contains(&value) {
+                    return Err(format!("expected one of {}", values.join(", ")));
+                }
+            }
+            SettingKind::Bool => {
+                if value != "true" && value != "false" {
+                    return Err("expected \"true\" or \"false\"".to_string());
+                }
+            }
+            SettingKind::Timestamp => {
+                if !is_timestamp(value) {
+                    return Err("expected an RFC 3339 timestamp".to_string());
+                }
+            }
+            SettingKind::Text { max_chars } => {
+                if value.trim().chars().count() > max_chars {
+                    return Err(format!("must be at most {} characters", max_chars));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Normalize a stored value for reading; None when it can't be salvaged
+    fn coerce(&self, value: &str) -> Option<String> {
+        match *self {
+            SettingKind::Int { min, max } => {
+                let n = value.trim().parse::<i64>().ok()?.max(min);
+                Some(max.map_or(n, |max| n.min(max)).to_string())
+            }
+            SettingKind::Float { min, max } => {
+                let n = value
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|n| n.is_finite())?
+                    .max(min);
+                Some(max.map_or(n, |max| n.min(max)).to_string())
+            }
+            SettingKind::Enum { values } => values
+                .iter()
+                .find(|v| v.eq_ignore_ascii_case(value.trim()))
+                .map(|v| v.to_string()),
+            SettingKind::Bool => {
+                matches!(value.trim(), "true" | "false").then(|| value.trim().to_string())
+            }
+            SettingKind::Timestamp => is_timestamp(value.trim()).then(|| value.trim().to_string()),
+            SettingKind::Text { .. } => Some(value.to_string()),
+        }
+    }
+}
+
+fn range_error<T: std::fmt::Display>(min: T, max: Option<T>) -> String {
+    match max {
+        Some(max) => format!("must be between {} and {}", min, max),
+        None => format!("must be at least {}", min),
+    }
+}
+
+fn is_timestamp(value: &str) -> bool {
+    chrono::DateTime::parse_from_rfc3339(value).is_ok()
+        || chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").is_ok()
+}
+
+/// The registry entry for `key`, if it is a known key
+pub fn lookup(key: &str) -> Option<SettingDef> {
+    Key::from_name(key).map(Key::def)
+}
+
+/// All registry entries, in declaration order
+pub fn all() -> Vec<SettingDef> {
+    Key::ALL.iter().map(|k| k.def()).collect()
+}
+
+/// Validate a write. Unknown keys pass (forward compatibility); known keys must
+/// match their schema, and the error names the key.
+pub fn validate(key: &str, value: &str) -> Result<(), String> {
+    match lookup(key) {
+        Some(def) => def
+            .kind
+            .check(value)
+            .map_err(|e| format!("Invalid value for {}: {}", key, e)),
+        None => Ok(()),
+    }
+}
+
+/// A Rust type a setting can be read as
+pub trait SettingValue: Sized {
+    /// Parse an already-normalized stored value
+    fn from_setting(value: &str) -> Option<Self>;
+
+    /// Value for an unset key without a default; None makes `get_typed` fail
+    fn unset() -> Option<Self> {
+        None
+    }
+}
+
+macro_rules! parsed_setting_value {
+    ($($t:ty),*) => {
+        $(impl SettingValue for $t {
+            fn from_setting(value: &str) -> Option<Self> {
+                value.parse().ok()
+            }
+        })*
+    };
+}
+
+parsed_setting_value!(i32, i64, u32, u64, usize, f64, bool);
+
+impl SettingValue for String {
+    fn from_setting(value: &str) -> Option<Self> {
+        Some(value.to_string())
+    }
+}
+
+/// Optional settings: None when unset (and without a default) or unusable
+impl<T: SettingValue> SettingValue for Option<T> {
+    fn from_setting(value: &str) -> Option<Self> {
+        Some(T::from_setting(value))
+    }
+
+    fn unset() -> Option<Self> {
+        Some(None)
+    }
+}
+
+/// Read `key` as `T`: the stored value normalized per the schema (numbers clamped
+/// into range), or the default when unset or invalid. Read `Option<T>` for keys
+/// without a default.
+pub fn get_typed<T: SettingValue>(conn: &Connection, key: Key) -> Result<T, String> {
+    let def = key.def();
+    let stored = get_setting(conn, def.key)
+        .map_err(|e| format!("Failed to read setting {}: {}", def.key, e))?;
+    let value = stored
+        .as_deref()
+        .and_then(|v| def.kind.coerce(v))
+        .or_else(|| def.default.map(SettingDefault::to_stored));
+
+    match value {
+        Some(value) => T::from_setting(&value).ok_or_else(|| {
+            format!(
+                "Setting {} holds '{}', which can't be read as {}",
+                def.key,
+                value,
+                std::any::type_name::<T>()
+            )
+        }),
+        None => {
+            T::unset().ok_or_else(|| format!("Setting {} is not set and has no default", def.key))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::queries::settings::set_setting;
+    use crate::db::Database;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_keys_are_unique_and_defaults_are_valid() {
+        let mut seen = std::collections::HashSet::new();
+        for def in all() {
+            assert!(seen.insert(def.key), "duplicate key {}", def.key);
+            if let Some(default) = def.default {
+                let stored = default.to_stored();
+                assert_eq!(validate(def.key, &stored), Ok(()), "{}", def.key);
+                assert_eq!(
+                    def.kind.coerce(&stored),
+                    Some(stored.clone()),
+                    "{}",
+                    def.key
+                );
+            }
+        }
+        assert_eq!(seen.len(), Key::ALL.len());
+    }
+
+    #[test]
+    fn test_defaults_round_trip_through_the_database() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+
+        for key in Key::ALL {
+            let def = key.def();
+            let Some(default) = def.default else {
+                assert_eq!(
+                    get_typed::<Option<String>>(&conn, key),
+                    Ok(None),
+                    "{}",
+                    def.key
+                );
+                continue;
+            };
+            let stored = default.to_stored();
+
+            // Unset reads the default, and so does the default written back
+            let unset: String = get_typed(&conn, key).unwrap();
+            assert_eq!(unset, stored, "{}", def.key);
+            set_setting(&conn, def.key, &stored).unwrap();
+            let written: String = get_typed(&conn, key).unwrap();
+            assert_eq!(written, stored, "{}", def.key);
+
+            match default {
+                SettingDefault::Int(v) => assert_eq!(get_typed::<i64>(&conn, key), Ok(v)),
+                SettingDefault::Float(v) => assert_eq!(get_typed::<f64>(&conn, key), Ok(v)),
+                SettingDefault::Bool(v) => assert_eq!(get_typed::<bool>(&conn, key), Ok(v)),
+                SettingDefault::Str(v) => assert_eq!(get_typed::<String>(&conn, key), Ok(v.into())),
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_writes_are_rejected_with_key_name() {
+        let cases = [
+            ("safety_threshold", "300"),
+            ("safety_threshold", "139"),
+            ("safety_threshold_heavy", "abc"),
+            ("cooldown_seconds", "601"),
+            ("user_hourly_rate", "0"),
+            ("user_hourly_rate", "NaN"),
+            ("min_score_to_generate", "100.5"),
+            ("humanization_intensity", "extreme"),
+            ("log_level", "info"),
+            ("onboarding_completed", "yes"),
+            ("last_update_check", "yesterday"),
+            ("custom_system_prompt_addendum", &"x".repeat(1001)),
+        ];
+        for (key, value) in cases {
+            let err = validate(key, value).unwrap_err();
+            assert!(err.contains(key), "{} -> {}", key, err);
+        }
+
+        assert!(validate("safety_threshold", "220").is_ok());
+        assert!(validate("last_update_check", "2026-10-16T20:03:23.934Z").is_ok());
+        assert!(validate("threshold_suggestion_dismissed_at", "2026-10-16 20:03:23").is_ok());
+        // Unknown keys are accepted as-is
+        assert!(validate("some_future_setting", "anything").is_ok());
+    }
+
+    #[test]
+    fn test_reads_are_lenient() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+
+        set_setting(&conn, "safety_threshold", "300").unwrap();
+        assert_eq!(get_typed::<i32>(&conn, Key::SafetyThreshold), Ok(220));
+        set_setting(&conn, "safety_threshold", "garbage").unwrap();
+        assert_eq!(get_typed::<i32>(&conn, Key::SafetyThreshold), Ok(180));
+
+        set_setting(&conn, "humanization_intensity", "Heavy").unwrap();
+        assert_eq!(
+            get_typed::<String>(&conn, Key::HumanizationIntensity),
+            Ok("heavy".to_string())
+        );
+
+        assert_eq!(
+            get_typed::<Option<f64>>(&conn, Key::UserHourlyRate),
+            Ok(None)
+        );
+        set_setting(&conn, "user_hourly_rate", "75.00").unwrap();
+        assert_eq!(
+            get_typed::<Option<f64>>(&conn, Key::UserHourlyRate),
+            Ok(Some(75.0))
+        );
+
+        // No default and not optional: an error that names the key
+        let err = get_typed::<f64>(&conn, Key::UserProjectRateMin).unwrap_err();
+        assert!(err.contains("user_project_rate_min"));
+    }
+}
@@ -0,0 +1,326 @@
+//! Startup maintenance that runs after `database-ready` instead of before it
+//!
+//! Unlock (and unencrypted startup) only validates the connection and loads
+//! settings needed immediately; `run_deferred_db_init` no longer scans data.
+//! The slower housekeeping runs here in a spawned task:
+//! - One-time log level migration from the database to config.json
+//! - Story 3.7 auto-confirmation of pending overrides older than 7 days
+//! - Marking jobs stale after `job_stale_days`
+//! - Discarding drafts older than `draft_retention_days`
+//! - Normalizing client names of job posts saved before V44
+//! - Pruning job analysis cache entries older than `analysis_cache_days`
+//! - Hashing proposal revisions saved before V51 (revision deduplication)
+//! - Preparing last week's digest when `digest_enabled` is on (emits `digest:ready`)
+//!
+//! Overrides are confirmed in batches of `OVERRIDE_BATCH_SIZE` with the lock
+//! released and the task yielding between batches. Every confirmed override
+//! leaves the pending state immediately, so quitting mid-run is safe: the next
+//! launch picks up whatever is still pending. When done, the task emits
+//! `startup:maintenance-complete` with what it did.
+
+use crate::db::queries::{analysis_cache, safety_overrides};
+use crate::db::{AppDatabase, Database};
+use crate::{config, events};
+use std::time::Instant;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Overrides confirmed per lock acquisition
+pub const OVERRIDE_BATCH_SIZE: usize = 100;
+
+/// Spawn startup maintenance for an unlocked database. Call after `database-ready`.
+pub fn spawn_startup_maintenance(app_handle: AppHandle, log_level: String) {
+    tauri::async_runtime::spawn(async move {
+        let db_state = app_handle.state::<AppDatabase>();
+        let config_state = app_handle.state::<config::ConfigState>();
+        let Ok(database) = db_state.get() else {
+            tracing::warn!("Startup maintenance skipped: database not ready");
+            return;
+        };
+
+        let summary = run_startup_maintenance(database, &config_state, &log_level).await;
+        if let Some(week_label) = summary.weekly_digest_prepared.clone() {
+            let _ = app_handle.emit(
+                events::DIGEST_READY,
+                events::DigestReadyPayload { week_label },
+            );
+        }
+        let _ = app_handle.emit(events::STARTUP_MAINTENANCE_COMPLETE, summary);
+    });
+}
+
+/// Run all startup maintenance steps. Failures are logged and never abort later steps.
+pub async fn run_startup_maintenance(
+    database: &Database,
+    config_state: &config::ConfigState,
+    log_level: &str,
+) -> events::StartupMaintenancePayload {
+    let started = Instant::now();
+    let mut summary = events::StartupMaintenancePayload::default();
+
+    // Story 2.1, Task 8 (Subtask 8.6): One-time migration from database to config.json
+    if log_level == "INFO" {
+        match migrate_log_level(database, config_state) {
+            Ok(migrated) => summary.log_level_migrated = migrated,
+            Err(e) => tracing::warn!("Log level migration failed (non-fatal): {}", e),
+        }
+    }
+
+    // Story 3.7: Auto-confirm overrides older than 7 days (Task 3.1)
+    if let Err(e) = confirm_pending_overrides(database, &mut summary).await {
+        tracing::warn!("Failed to auto-confirm overrides (non-fatal): {}", e);
+    }
+
+    // Job freshness: mark jobs not seen for job_stale_days as stale
+    match database.conn.lock() {
+        Ok(conn) => match crate::commands::job_queue::mark_stale_jobs_from_settings(&conn) {
+            Ok(count) => summary.stale_jobs_marked = count,
+            Err(e) => tracing::warn!("Stale job maintenance failed (non-fatal): {}", e),
+        },
+        Err(e) => tracing::warn!("Stale job maintenance skipped: database lock error: {}", e),
+    }
+
+    // Draft retention (Story 1.14): crash drafts are only kept for recovery so long
+    match database.conn.lock() {
+        Ok(conn) => match crate::commands::drafts::discard_expired_drafts_from_settings(&conn) {
+            Ok(count) => summary.expired_drafts_discarded = count,
+            Err(e) => tracing::warn!("Draft retention failed (non-fatal): {}", e),
+        },
+        Err(e) => tracing::warn!("Draft retention skipped: database lock error: {}", e),
+    }
+
+    // Client history: matching keys for job posts saved before V44
+    match database.conn.lock() {
+        Ok(conn) => match crate::db::queries::clients::backfill_normalized_client_names(&conn) {
+            Ok(count) => summary.client_names_normalized = count,
+            Err(e) => tracing::warn!("Client name backfill failed (non-fatal): {}", e),
+        },
+        Err(e) => tracing::warn!("Client name backfill skipped: database lock error: {}", e),
+    }
+
+    // Job analysis cache: expired entries are never served, so drop them
+    match database.conn.lock() {
+        Ok(conn) => {
+            let days = analysis_cache::analysis_cache_days(&conn);
+            match analysis_cache::prune_analysis_cache(&conn, days) {
+                Ok(count) => summary.analysis_cache_pruned = count,
+                Err(e) => tracing::warn!("Analysis cache pruning failed (non-fatal): {}", e),
+            }
+        }
+        Err(e) => tracing::warn!("Analysis cache pruning skipped: database lock error: {}", e),
+    }
+
+    // Revision deduplication: content hashes for revisions saved before V51
+    match database.conn.lock() {
+        Ok(conn) => match crate::db::queries::revisions::backfill_content_hashes(&conn) {
+            Ok(count) => summary.revision_hashes_backfilled = count,
+            Err(e) => tracing::warn!("Revision hash backfill failed (non-fatal): {}", e),
+        },
+        Err(e) => tracing::warn!("Revision hash backfill skipped: database lock error: {}", e),
+    }
+
+    // Weekly digest: store last week's once, if enabled
+    match database.conn.lock() {
+        Ok(conn) => match crate::digest::prepare_last_week_digest(&conn, chrono::Utc::now()) {
+            Ok(prepared) => summary.weekly_digest_prepared = prepared,
+            Err(e) => tracing::warn!("Weekly digest failed (non-fatal): {}", e),
+        },
+        Err(e) => tracing::warn!("Weekly digest skipped: database lock error: {}", e),
+    }
+
+    summary.duration_ms = started.elapsed().as_millis() as u64;
+    tracing::info!(
+        duration_ms = summary.duration_ms,
+        overrides_successful = summary.overrides_confirmed_successful,
+        overrides_unsuccessful = summary.overrides_confirmed_unsuccessful,
+        stale_jobs_marked = summary.stale_jobs_marked,
+        expired_drafts_discarded = summary.expired_drafts_discarded,
+        client_names_normalized = summary.client_names_normalized,
+        analysis_cache_pruned = summary.analysis_cache_pruned,
+        revision_hashes_backfilled = summary.revision_hashes_backfilled,
+        weekly_digest_prepared = summary.weekly_digest_prepared.as_deref(),
+        "Startup maintenance complete (ran after database-ready; previously blocked unlock for this long)"
+    );
+    summary
+}
+
+fn migrate_log_level(
+    database: &Database,
+    config_state: &config::ConfigState,
+) -> Result<Option<String>, String> {
+    let db_log_level = {
+        let conn = database
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock error: {}", e))?;
+        crate::db::queries::settings::get_setting(&conn, "log_level")
+            .map_err(|e| format!("Failed to read log level: {}", e))?
+    };
+
+    match db_log_level {
+        Some(level) if level != "INFO" => {
+            tracing::info!(
+                "Migrating log level from database to config.json: {}",
+                level
+            );
+            config_state
+                .set_log_level(level.clone())
+                .map_err(|e| format!("Failed to migrate log level: {}", e))?;
+            Ok(Some(level))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Confirm pending overrides in batches, releasing the lock and yielding between batches
+async fn confirm_pending_overrides(
+    database: &Database,
+    summary: &mut events::StartupMaintenancePayload,
+) -> Result<(), String> {
+    let mut cursor = 0;
+    loop {
+        let batch_len = {
+            let conn = database
+                .conn
+                .lock()
+                .map_err(|e| format!("Database lock error: {}", e))?;
+            let batch = safety_overrides::get_pending_overrides_older_than_7_days_batch(
+                &conn,
+                cursor,
+                OVERRIDE_BATCH_SIZE,
+            )
+            .map_err(|e| format!("Failed to query pending overrides: {}", e))?;
+
+            for override_record in &batch {
+                cursor = override_record.id;
+                let status =
+                    match safety_overrides::proposal_exists(&conn, override_record.proposal_id) {
+                        Ok(true) => safety_overrides::STATUS_SUCCESSFUL,
+                        Ok(false) => safety_overrides::STATUS_UNSUCCESSFUL,
+                        Err(e) => {
+                            tracing::warn!(
+                                "Failed to check proposal existence for override {}: {}",
+                                override_record.id,
+                                e
+                            );
+                            continue;
+                        }
+                    };
+                match safety_overrides::update_override_status(&conn, override_record.id, status) {
+                    Ok(_) if status == safety_overrides::STATUS_SUCCESSFUL => {
+                        summary.overrides_confirmed_successful += 1
+                    }
+                    Ok(_) => summary.overrides_confirmed_unsuccessful += 1,
+                    Err(e) => tracing::warn!(
+                        "Failed to update override {} to {}: {}",
+                        override_record.id,
+                        status,
+                        e
+                    ),
+                }
+            }
+            batch.len()
+        };
+
+        if batch_len < OVERRIDE_BATCH_SIZE {
+            return Ok(());
+        }
+        tokio::task::yield_now().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn seed_old_pending_overrides(database: &Database, count: usize) {
+        let conn = database.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO proposals (job_content, generated_text) VALUES ('job', 'proposal')",
+            [],
+        )
+        .unwrap();
+        let proposal_id = conn.last_insert_rowid();
+        conn.execute_batch("BEGIN").unwrap();
+        for _ in 0..count {
+            conn.execute(
+                "INSERT INTO safety_overrides (proposal_id, timestamp, ai_score, threshold_at_override)
+                 VALUES (?1, datetime('now', '-10 days'), 190.0, 180.0)",
+                [proposal_id],
+            )
+            .unwrap();
+        }
+        conn.execute_batch("COMMIT").unwrap();
+    }
+
+    fn pending_count(database: &Database) -> i64 {
+        let conn = database.conn.lock().unwrap();
+        conn.query_row(
+            "SELECT COUNT(*) FROM safety_overrides WHERE status = 'pending'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_unlock_path_no_longer_waits_for_override_scan() {
+        let dir = tempdir().unwrap();
+        let database = Database::new(dir.path().join("test.db"), None).unwrap();
+        seed_old_pending_overrides(&database, 2_000);
+        let config_state = config::ConfigState::new(dir.path().to_path_buf()).unwrap();
+
+        // New unlock path: database set + deferred init, then database-ready
+        let app_database = AppDatabase::new_with(database);
+        let unlock_started = Instant::now();
+        crate::run_deferred_db_init(&app_database, "INFO").unwrap();
+        let unlock_elapsed = unlock_started.elapsed();
+        let database = app_database.get().unwrap();
+        assert_eq!(
+            pending_count(database),
+            2_000,
+            "scan must not run before ready"
+        );
+
+        // The work the old path did inline, now in the background task
+        let maintenance_started = Instant::now();
+        let summary = run_startup_maintenance(database, &config_state, "INFO").await;
+        let maintenance_elapsed = maintenance_started.elapsed();
+
+        assert_eq!(summary.overrides_confirmed_successful, 2_000);
+        assert_eq!(pending_count(database), 0);
+        assert!(
+            unlock_elapsed < maintenance_elapsed,
+            "unlock {:?} should be well under the override scan {:?}",
+            unlock_elapsed,
+            maintenance_elapsed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_maintenance_resumes_remaining_overrides() {
+        let dir = tempdir().unwrap();
+        let database = Database::new(dir.path().join("test.db"), None).unwrap();
+        seed_old_pending_overrides(&database, OVERRIDE_BATCH_SIZE + 20);
+        let config_state = config::ConfigState::new(dir.path().to_path_buf()).unwrap();
+
+        // Simulate quitting after the first batch
+        {
+            let conn = database.conn.lock().unwrap();
+            conn.execute(
+                "UPDATE safety_overrides SET status = 'successful'
+                 WHERE id IN (SELECT id FROM safety_overrides ORDER BY id LIMIT ?1)",
+                [OVERRIDE_BATCH_SIZE as i64],
+            )
+            .unwrap();
+        }
+
+        let summary = run_startup_maintenance(&database, &config_state, "INFO").await;
+        assert_eq!(summary.overrides_confirmed_successful, 20);
+        assert_eq!(pending_count(&database), 0);
+
+        // Nothing left on the next launch
+        let summary = run_startup_maintenance(&database, &config_state, "INFO").await;
+        assert_eq!(summary.overrides_confirmed_successful, 0);
+    }
+}
@@ -0,0 +1,321 @@
+//! Update channel: stable or beta release feed
+//!
+//! The `update_channel` setting picks the feed the updater checks. Stable uses
+//! the endpoints in tauri.conf.json; beta uses `beta_update_feed_url` from the
+//! remote config, so the feed can move without an app update, and falls back to
+//! stable while no beta feed is published. Switching back to stable never offers
+//! a downgrade: a beta build newer than the latest stable release stays
+//! installed until stable catches up (`UpdateStatus::AheadOfChannel`).
+
+use crate::db::queries::settings::set_setting;
+use crate::db::AppDatabase;
+use crate::errors::AppError;
+use crate::health_check::{compare_versions, get_current_version, is_prerelease};
+use crate::settings::schema::{get_typed, Key};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use tauri::{AppHandle, State, Url};
+use tauri_plugin_updater::{UpdaterBuilder, UpdaterExt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    Stable,
+    Beta,
+}
+
+impl UpdateChannel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Beta => "beta",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "stable" => Some(Self::Stable),
+            "beta" => Some(Self::Beta),
+            _ => None,
+        }
+    }
+}
+
+/// The configured channel (stable when unset)
+pub fn load_channel(conn: &Connection) -> Result<UpdateChannel, String> {
+    let value: String = get_typed(conn, Key::UpdateChannel)?;
+    Ok(UpdateChannel::parse(&value).unwrap_or(UpdateChannel::Stable))
+}
+
+/// Feed to check for a channel
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelFeed {
+    pub channel: UpdateChannel,
+    /// Feed actually used: stable while no beta feed is configured
+    pub feed: UpdateChannel,
+    /// None keeps the endpoints from tauri.conf.json
+    pub endpoint: Option<Url>,
+}
+
+/// Resolve the feed for `channel`, given the remote config's beta feed URL
+pub fn resolve_feed(
+    channel: UpdateChannel,
+    beta_feed_url: Option<&str>,
+) -> Result<ChannelFeed, String> {
+    let stable = ChannelFeed {
+        channel,
+        feed: UpdateChannel::Stable,
+        endpoint: None,
+    };
+    match (channel, beta_feed_url) {
+        (UpdateChannel::Stable, _) => Ok(stable),
+        (UpdateChannel::Beta, None) => {
+            tracing::info!("No beta update feed configured, checking the stable feed");
+            Ok(stable)
+        }
+        (UpdateChannel::Beta, Some(url)) => {
+            let url = Url::parse(url).map_err(|e| format!("Invalid beta update feed: {}", e))?;
+            if url.scheme() != "https" {
+                return Err(format!("Beta update feed must use https: {}", url));
+            }
+            Ok(ChannelFeed {
+                channel,
+                feed: UpdateChannel::Beta,
+                endpoint: Some(url),
+            })
+        }
+    }
+}
+
+/// Feed for the configured channel. Needs the database (for the setting).
+pub fn current_feed(app_handle: &AppHandle) -> Result<ChannelFeed, String> {
+    let channel = crate::health_check::with_db_conn(app_handle, load_channel)?;
+    let beta_feed_url = match channel {
+        UpdateChannel::Beta => crate::remote_config::beta_update_feed_url(app_handle),
+        UpdateChannel::Stable => None,
+    };
+    resolve_feed(channel, beta_feed_url.as_deref())
+}
+
+/// Updater pointed at `feed`. The default version check (newer than the
+/// running version) is kept unless the caller overrides it.
+pub fn feed_updater(app_handle: &AppHandle, feed: &ChannelFeed) -> Result<UpdaterBuilder, String> {
+    let builder = app_handle.updater_builder();
+    match &feed.endpoint {
+        Some(url) => builder
+            .endpoints(vec![url.clone()])
+            .map_err(|e| format!("Invalid update feed: {}", e)),
+        None => Ok(builder),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UpdateStatus {
+    UpToDate,
+    Available,
+    /// Running a beta newer than the channel's latest release; no downgrade is offered
+    AheadOfChannel,
+}
+
+/// Result of `check_for_update`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCheckResult {
+    pub channel: UpdateChannel,
+    /// Feed checked: stable while no beta feed is configured
+    pub feed: UpdateChannel,
+    pub current_version: String,
+    /// Latest release on the feed, None if it has none for this platform
+    pub latest_version: Option<String>,
+    pub status: UpdateStatus,
+    pub message: String,
+    /// Release notes, when an update is available
+    pub body: Option<String>,
+    pub date: Option<String>,
+    /// `critical` flag from the feed's manifest, when an update is available
+    pub critical: bool,
+}
+
+/// Compare the running version with the feed's latest release
+pub fn assess_update(
+    channel: UpdateChannel,
+    current: &str,
+    latest: Option<&str>,
+) -> (UpdateStatus, String) {
+    let Some(latest) = latest else {
+        return (
+            UpdateStatus::UpToDate,
+            format!("v{} is the latest version", current),
+        );
+    };
+    match compare_versions(latest, current) {
+        Ordering::Greater => (
+            UpdateStatus::Available,
+            format!("v{} is available (you have v{})", latest, current),
+        ),
+        Ordering::Less if is_prerelease(current) => (
+            UpdateStatus::AheadOfChannel,
+            format!(
+                "You're on beta v{}, newer than the latest {} release v{}. You'll stay on this \
+                 version until a newer {} release is out.",
+                current,
+                channel.as_str(),
+                latest,
+                channel.as_str()
+            ),
+        ),
+        _ => (
+            UpdateStatus::UpToDate,
+            format!("v{} is the latest version", current),
+        ),
+    }
+}
+
+/// The configured update channel
+#[tauri::command]
+pub fn get_update_channel(database: State<'_, AppDatabase>) -> Result<UpdateChannel, AppError> {
+    let database = database.get()?;
+    let conn = database.conn.lock().map_err(AppError::database_locked)?;
+    load_channel(&conn).map_err(AppError::database)
+}
+
+/// Switch the update channel ("stable" or "beta"). Takes effect at the next
+/// update check; nothing is downgraded when leaving beta.
+#[tauri::command]
+pub fn set_update_channel(
+    database: State<'_, AppDatabase>,
+    channel: String,
+) -> Result<UpdateChannel, AppError> {
+    let channel = UpdateChannel::parse(&channel).ok_or_else(|| {
+        AppError::validation(format!(
+            "Invalid update channel '{}' (expected stable or beta)",
+            channel
+        ))
+    })?;
+    let database = database.get()?;
+    let conn = database.conn.lock().map_err(AppError::database_locked)?;
+    set_setting(&conn, "update_channel", channel.as_str())
+        .map_err(|e| AppError::database(format!("Failed to save update channel: {}", e)))?;
+
+    tracing::info!(channel = channel.as_str(), "Update channel changed");
+    Ok(channel)
+}
+
+/// Check the configured channel's feed. Unlike the updater's own check this
+/// reports the feed's latest release even when it is older than the running
+/// version, so a beta user back on stable is told why nothing is offered.
+/// Install with `prepare_and_apply_update`, which uses the same feed (the
+/// update UI, `useUpdater`, goes through both).
+#[tauri::command]
+pub async fn check_for_update(app_handle: AppHandle) -> Result<UpdateCheckResult, String> {
+    let feed = current_feed(&app_handle)?;
+    let update = feed_updater(&app_handle, &feed)?
+        .version_comparator(|_, _| true)
+        .build()
+        .map_err(|e| format!("Updater unavailable: {}", e))?
+        .check()
+        .await
+        .map_err(|e| format!("Update check failed: {}", e))?;
+
+    let current_version = get_current_version();
+    let latest_version = update.as_ref().map(|u| u.version.clone());
+    let (status, message) =
+        assess_update(feed.channel, &current_version, latest_version.as_deref());
+    let (body, date, critical) = match (&update, status) {
+        (Some(update), UpdateStatus::Available) => (
+            update.body.clone(),
+            update
+                .date
+                .and_then(|d| chrono::DateTime::from_timestamp(d.unix_timestamp(), 0))
+                .map(|d| d.to_rfc3339()),
+            update
+                .raw_json
+                .get("critical")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+        ),
+        _ => (None, None, false),
+    };
+
+    Ok(UpdateCheckResult {
+        channel: feed.channel,
+        feed: feed.feed,
+        current_version,
+        latest_version,
+        status,
+        message,
+        body,
+        date,
+        critical,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_channel_setting_defaults_to_stable() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        let conn = db.conn.lock().unwrap();
+        assert_eq!(load_channel(&conn).unwrap(), UpdateChannel::Stable);
+
+        set_setting(&conn, "update_channel", "beta").unwrap();
+        assert_eq!(load_channel(&conn).unwrap(), UpdateChannel::Beta);
+        assert_eq!(UpdateChannel::parse(" Beta "), Some(UpdateChannel::Beta));
+        assert_eq!(UpdateChannel::parse("nightly"), None);
+    }
+
+    #[test]
+    fn test_resolve_feed() {
+        let stable = resolve_feed(UpdateChannel::Stable, Some("https://e.com/beta.json")).unwrap();
+        assert_eq!(stable.feed, UpdateChannel::Stable);
+        assert!(stable.endpoint.is_none());
+
+        let beta = resolve_feed(UpdateChannel::Beta, Some("https://e.com/beta.json")).unwrap();
+        assert_eq!(beta.feed, UpdateChannel::Beta);
+        assert_eq!(beta.endpoint.unwrap().as_str(), "https://e.com/beta.json");
+
+        // No beta feed published: beta users still get stable updates
+        let fallback = resolve_feed(UpdateChannel::Beta, None).unwrap();
+        assert_eq!(fallback.channel, UpdateChannel::Beta);
+        assert_eq!(fallback.feed, UpdateChannel::Stable);
+
+        assert!(resolve_feed(UpdateChannel::Beta, Some("http://e.com/beta.json")).is_err());
+    }
+
+    #[test]
+    fn test_assess_update() {
+        let stable = UpdateChannel::Stable;
+        assert_eq!(
+            assess_update(stable, "1.2.0", Some("1.3.0")).0,
+            UpdateStatus::Available
+        );
+        assert_eq!(
+            assess_update(stable, "1.2.0", Some("1.2.0")).0,
+            UpdateStatus::UpToDate
+        );
+        assert_eq!(
+            assess_update(stable, "1.2.0", None).0,
+            UpdateStatus::UpToDate
+        );
+        assert_eq!(
+            assess_update(UpdateChannel::Beta, "1.3.0-beta.1", Some("1.3.0-beta.2")).0,
+            UpdateStatus::Available
+        );
+
+        // Back on stable from a newer beta: stay put until stable catches up
+        let (status, message) = assess_update(stable, "1.3.0-beta.2", Some("1.2.0"));
+        assert_eq!(status, UpdateStatus::AheadOfChannel);
+        assert!(message.contains("v1.3.0-beta.2") && message.contains("v1.2.0"));
+        assert_eq!(
+            assess_update(stable, "1.3.0-beta.2", Some("1.3.0")).0,
+            UpdateStatus::Available
+        );
+    }
+}
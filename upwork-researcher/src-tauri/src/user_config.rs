@@ -0,0 +1,676 @@
+//! User configuration export/import
+//!
+//! A small JSON file with the user's own setup — skills, rates, humanization and
+//! safety thresholds, scoring weights, and hook strategy A/B preferences — kept
+//! apart from proposal data (archives and bundles carry that) so a setup can be
+//! moved to a fresh install or shared without any proposals.
+//!
+//! Only the settings keys listed in the section arrays below are exported, so the
+//! API key, passphrase material and recovery files can never end up in the file.
+//!
+//! Imported values go through the same checks as the individual setters: rates
+//! must be positive, intensity must be a known level, and every value must pass
+//! `validate_setting_value`. Safety thresholds are clamped into range rather than
+//! rejected, as the settings reader does. Invalid entries are skipped and
+//! reported; they never fail the import.
+
+use crate::db::queries::{hook_strategies, settings, user_skills};
+use crate::settings::schema::{Key, SettingKind};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Config file format version; files with a newer version are rejected
+pub const USER_CONFIG_FORMAT_VERSION: u32 = 1;
+
+/// Settings in the rates section
+pub const RATE_KEYS: [Key; 2] = [Key::UserHourlyRate, Key::UserProjectRateMin];
+
+/// Settings in the humanization section (intensity, thresholds, AI-tell lists)
+pub const HUMANIZATION_KEYS: [Key; 8] = [
+    Key::HumanizationIntensity,
+    Key::SafetyThreshold,
+    Key::SafetyThresholdOff,
+    Key::SafetyThresholdLight,
+    Key::SafetyThresholdMedium,
+    Key::SafetyThresholdHeavy,
+    Key::AiTellUserPhrases,
+    Key::AiTellMatchMode,
+];
+
+/// Settings in the scoring section
+pub const SCORING_KEYS: [Key; 4] = [
+    Key::QualityWeightPerplexity,
+    Key::QualityWeightVoice,
+    Key::QualityWeightCoverage,
+    Key::MinScoreToGenerate,
+];
+
+/// An exported user configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserConfig {
+    pub metadata: UserConfigMetadata,
+    #[serde(default)]
+    pub skills: Vec<ConfigSkill>,
+    /// Stored setting values by key; unset keys are omitted
+    #[serde(default)]
+    pub rates: BTreeMap<String, String>,
+    #[serde(default)]
+    pub humanization: BTreeMap<String, String>,
+    #[serde(default)]
+    pub scoring: BTreeMap<String, String>,
+    #[serde(default)]
+    pub hook_strategies: Vec<ConfigHookStrategy>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserConfigMetadata {
+    pub format_version: u32,
+    pub exported_at: String,
+    pub app_version: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigSkill {
+    pub skill: String,
+    #[serde(default)]
+    pub is_primary: bool,
+}
+
+/// A hook strategy with the user's A/B preferences. Strategies already present
+/// (matched by name) only take the weight and rotation flag; unknown ones are
+/// added as custom strategies.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigHookStrategy {
+    pub name: String,
+    pub description: String,
+    pub examples_json: String,
+    pub best_for: String,
+    pub ab_weight: f64,
+    pub in_ab_rotation: bool,
+}
+
+/// Applied/skipped counts for one section
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionCounts {
+    pub applied: usize,
+    pub skipped: usize,
+}
+
+/// Result of importing a config
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserConfigImportReport {
+    pub skills: SectionCounts,
+    pub rates: SectionCounts,
+    pub humanization: SectionCounts,
+    pub scoring: SectionCounts,
+    pub hook_strategies: SectionCounts,
+    /// Why each skipped entry was skipped
+    pub warnings: Vec<String>,
+    /// Jobs rescored because skills or rates changed (filled in by the command)
+    pub scores_recalculated: Option<usize>,
+}
+
+impl UserConfigImportReport {
+    /// Whether job scores depend on anything this import changed
+    pub fn affects_scores(&self) -> bool {
+        self.skills.applied > 0 || self.rates.applied > 0
+    }
+
+    pub fn total_applied(&self) -> usize {
+        self.skills.applied
+            + self.rates.applied
+            + self.humanization.applied
+            + self.scoring.applied
+            + self.hook_strategies.applied
+    }
+}
+
+/// Read the current configuration
+pub fn build_user_config(conn: &Connection) -> Result<UserConfig, String> {
+    let mut skills: Vec<ConfigSkill> = user_skills::get_user_skills(conn)
+        .map_err(|e| format!("Failed to read skills: {}", e))?
+        .into_iter()
+        .map(|s| ConfigSkill {
+            skill: s.skill,
+            is_primary: s.is_primary,
+        })
+        .collect();
+    // get_user_skills is newest first; keep the order they were added in
+    skills.reverse();
+
+    let hook_strategies = hook_strategies::get_all_hook_strategies(conn)
+        .map_err(|e| format!("Failed to read hook strategies: {}", e))?
+        .into_iter()
+        .map(|s| ConfigHookStrategy {
+            name: s.name,
+            description: s.description,
+            examples_json: s.examples_json,
+            best_for: s.best_for,
+            ab_weight: s.ab_weight,
+            in_ab_rotation: s.in_ab_rotation,
+        })
+        .collect();
+
+    Ok(UserConfig {
+        metadata: UserConfigMetadata {
+            format_version: USER_CONFIG_FORMAT_VERSION,
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+        },
+        skills,
+        rates: read_section(conn, &RATE_KEYS)?,
+        humanization: read_section(conn, &HUMANIZATION_KEYS)?,
+        scoring: read_section(conn, &SCORING_KEYS)?,
+        hook_strategies,
+    })
+}
+
+fn read_section(conn: &Connection, keys: &[Key]) -> Result<BTreeMap<String, String>, String> {
+    let mut values = BTreeMap::new();
+    for key in keys {
+        let name = key.def().key;
+        if let Some(value) = settings::get_setting(conn, name)
+            .map_err(|e| format!("Failed to read setting {}: {}", name, e))?
+        {
+            values.insert(name.to_string(), value);
+        }
+    }
+    Ok(values)
+}
+
+/// Check the format version before anything is applied
+pub fn check_format_version(config: &UserConfig) -> Result<(), String> {
+    match config.metadata.format_version {
+        0 => Err("Invalid config file: missing format version".to_string()),
+        v if v > USER_CONFIG_FORMAT_VERSION => Err(format!(
+            "Config file format version {} is newer than supported ({}). Update the app first.",
+            v, USER_CONFIG_FORMAT_VERSION
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Apply a config in one transaction.
+///
+/// `merge` keeps existing config the file does not mention: skills are added,
+/// and section settings absent from the file are left alone. Without it the
+/// file replaces the config: skills are cleared first, absent section settings
+/// are reset to their defaults, and strategies absent from the file go back
+/// into A/B rotation. Score recalculation and runtime side effects are left to
+/// the caller, after commit.
+pub fn import_user_config(
+    conn: &Connection,
+    config: &UserConfig,
+    merge: bool,
+) -> Result<UserConfigImportReport, String> {
+    check_format_version(config)?;
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+    let mut report = UserConfigImportReport::default();
+
+    import_skills(&tx, &config.skills, merge, &mut report)?;
+    report.rates = import_section(&tx, &RATE_KEYS, &config.rates, merge, &mut report.warnings)?;
+    report.humanization = import_section(
+        &tx,
+        &HUMANIZATION_KEYS,
+        &config.humanization,
+        merge,
+        &mut report.warnings,
+    )?;
+    report.scoring = import_section(
+        &tx,
+        &SCORING_KEYS,
+        &config.scoring,
+        merge,
+        &mut report.warnings,
+    )?;
+    import_hook_strategies(&tx, &config.hook_strategies, merge, &mut report)?;
+
+    tx.commit()
+        .map_err(|e| format!("Failed to commit config import: {}", e))?;
+    Ok(report)
+}
+
+fn import_skills(
+    conn: &Connection,
+    skills: &[ConfigSkill],
+    merge: bool,
+    report: &mut UserConfigImportReport,
+) -> Result<(), String> {
+    if !merge {
+        conn.execute("DELETE FROM user_skills", [])
+            .map_err(|e| format!("Failed to clear skills: {}", e))?;
+    }
+    for entry in skills {
+        match user_skills::add_user_skill(conn, entry.skill.trim()) {
+            Ok(id) => {
+                if entry.is_primary {
+                    conn.execute(
+                        "UPDATE user_skills SET is_primary = 1 WHERE id = ?1",
+                        params![id],
+                    )
+                    .map_err(|e| format!("Failed to update skill: {}", e))?;
+                }
+                report.skills.applied += 1;
+            }
+            // Validation failures (empty, too long, duplicate) skip the entry
+            Err(rusqlite::Error::SqliteFailure(_, Some(reason))) => {
+                report.skills.skipped += 1;
+                report
+                    .warnings
+                    .push(format!("Skill '{}' skipped: {}", entry.skill, reason));
+            }
+            Err(e) => return Err(format!("Failed to add skill: {}", e)),
+        }
+    }
+    Ok(())
+}
+
+fn import_section(
+    conn: &Connection,
+    keys: &[Key],
+    values: &BTreeMap<String, String>,
+    merge: bool,
+    warnings: &mut Vec<String>,
+) -> Result<SectionCounts, String> {
+    let mut counts = SectionCounts::default();
+
+    for (name, value) in values {
+        let Some(key) = keys.iter().copied().find(|k| k.def().key == name) else {
+            counts.skipped += 1;
+            warnings.push(format!("Setting '{}' is not part of this section", name));
+            continue;
+        };
+        match normalize_setting(key, value) {
+            Ok(stored) => {
+                settings::set_setting(conn, name, &stored)
+                    .map_err(|e| format!("Failed to set {}: {}", name, e))?;
+                counts.applied += 1;
+            }
+            Err(reason) => {
+                counts.skipped += 1;
+                warnings.push(format!("Setting '{}' skipped: {}", name, reason));
+            }
+        }
+    }
+
+    if !merge {
+        for key in keys {
+            let name = key.def().key;
+            if !values.contains_key(name) {
+                settings::delete_setting(conn, name)
+                    .map_err(|e| format!("Failed to reset {}: {}", name, e))?;
+            }
+        }
+    }
+
+    Ok(counts)
+}
+
+/// The value to store for `key`, or why it was rejected. Mirrors the setters:
+/// `set_user_hourly_rate`/`set_user_project_rate_min` for rates,
+/// `set_humanization_intensity` for intensity, `set_setting` for the rest.
+fn normalize_setting(key: Key, value: &str) -> Result<String, String> {
+    let value = value.trim();
+    let stored = match key {
+        Key::UserHourlyRate | Key::UserProjectRateMin => {
+            let rate: f64 = value
+                .parse()
+                .map_err(|_| format!("'{}' is not a number", value))?;
+            if !rate.is_finite() || rate <= 0.0 {
+                return Err("Rate must be positive".to_string());
+            }
+            if rate > 999999.0 {
+                return Err("Rate too large (max 999999)".to_string());
+            }
+            format!("{:.2}", rate)
+        }
+        Key::HumanizationIntensity => {
+            if !crate::humanization::HumanizationIntensity::is_valid(value) {
+                return Err(format!(
+                    "Invalid humanization intensity '{}'. Valid values: off, light, medium, heavy",
+                    value
+                ));
+            }
+            value.to_lowercase()
+        }
+        Key::SafetyThreshold
+        | Key::SafetyThresholdOff
+        | Key::SafetyThresholdLight
+        | Key::SafetyThresholdMedium
+        | Key::SafetyThresholdHeavy => {
+            let threshold: f64 = value
+                .parse()
+                .map_err(|_| format!("'{}' is not a number", value))?;
+            if !threshold.is_finite() {
+                return Err(format!("'{}' is not a number", value));
+            }
+            // Clamped to the range the settings schema allows for this key
+            let SettingKind::Int { min, max } = key.def().kind else {
+                return Err(format!("'{}' is not a whole-number setting", key.def().key));
+            };
+            (threshold.round() as i64)
+                .clamp(min, max.unwrap_or(i64::MAX))
+                .to_string()
+        }
+        _ => value.to_string(),
+    };
+    crate::validate_setting_value(key.def().key, &stored)?;
+    Ok(stored)
+}
+
+fn import_hook_strategies(
+    conn: &Connection,
+    strategies: &[ConfigHookStrategy],
+    merge: bool,
+    report: &mut UserConfigImportReport,
+) -> Result<(), String> {
+    let mut seen = Vec::with_capacity(strategies.len());
+
+    for strategy in strategies {
+        match apply_hook_strategy(conn, strategy) {
+            Ok(()) => {
+                report.hook_strategies.applied += 1;
+                seen.push(strategy.name.clone());
+            }
+            Err(StrategySkip::Invalid(reason)) => {
+                report.hook_strategies.skipped += 1;
+                report.warnings.push(format!(
+                    "Hook strategy '{}' skipped: {}",
+                    strategy.name, reason
+                ));
+            }
+            Err(StrategySkip::Db(e)) => {
+                return Err(format!("Failed to import hook strategy: {}", e))
+            }
+        }
+    }
+
+    if !merge {
+        let existing = hook_strategies::get_all_hook_strategies(conn)
+            .map_err(|e| format!("Failed to read hook strategies: {}", e))?;
+        for strategy in existing.iter().filter(|s| !seen.contains(&s.name)) {
+            hook_strategies::set_in_ab_rotation(conn, strategy.id, true)
+                .map_err(|e| format!("Failed to reset hook strategy rotation: {}", e))?;
+        }
+    }
+
+    Ok(())
+}
+
+enum StrategySkip {
+    Invalid(String),
+    Db(rusqlite::Error),
+}
+
+impl From<rusqlite::Error> for StrategySkip {
+    fn from(e: rusqlite::Error) -> Self {
+        StrategySkip::Db(e)
+    }
+}
+
+fn apply_hook_strategy(
+    conn: &Connection,
+    strategy: &ConfigHookStrategy,
+) -> Result<(), StrategySkip> {
+    if !(0.0..=1.0).contains(&strategy.ab_weight) {
+        return Err(StrategySkip::Invalid(format!(
+            "ab_weight must be between 0.0 and 1.0, got: {}",
+            strategy.ab_weight
+        )));
+    }
+
+    if hook_strategies::hook_strategy_name_exists(conn, &strategy.name)? {
+        let rows = conn.execute(
+            "UPDATE hook_strategies SET ab_weight = ?1, in_ab_rotation = ?2
+             WHERE name = ?3 AND status != 'retired'",
+            params![strategy.ab_weight, strategy.in_ab_rotation, strategy.name],
+        )?;
+        if rows == 0 {
+            return Err(StrategySkip::Invalid("strategy is retired".to_string()));
+        }
+        return Ok(());
+    }
+
+    // A new (custom) strategy: same shape rules as remote config strategies
+    let name = strategy.name.trim();
+    if name.is_empty()
+        || strategy.description.trim().is_empty()
+        || strategy.best_for.trim().is_empty()
+    {
+        return Err(StrategySkip::Invalid(
+            "name, description and best_for are required".to_string(),
+        ));
+    }
+    let examples: Vec<String> = serde_json::from_str(&strategy.examples_json).map_err(|_| {
+        StrategySkip::Invalid("examples_json must be a JSON array of strings".to_string())
+    })?;
+    if examples.is_empty() {
+        return Err(StrategySkip::Invalid(
+            "at least one example is required".to_string(),
+        ));
+    }
+
+    conn.execute(
+        "INSERT INTO hook_strategies (name, description, examples_json, best_for, ab_weight, in_ab_rotation)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            name,
+            strategy.description,
+            strategy.examples_json,
+            strategy.best_for,
+            strategy.ab_weight,
+            strategy.in_ab_rotation
+        ],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use tempfile::TempDir;
+
+    fn create_test_db() -> (TempDir, Database) {
+        let dir = tempfile::tempdir().unwrap();
+        let db = Database::new(dir.path().join("test.db"), None).unwrap();
+        (dir, db)
+    }
+
+    fn counts(section: &SectionCounts) -> (usize, usize) {
+        (section.applied, section.skipped)
+    }
+
+    fn config() -> UserConfig {
+        UserConfig {
+            metadata: UserConfigMetadata {
+                format_version: USER_CONFIG_FORMAT_VERSION,
+                exported_at: "2026-01-01T00:00:00Z".to_string(),
+                app_version: "0.1.0".to_string(),
+            },
+            skills: vec![
+                ConfigSkill {
+                    skill: "Rust".to_string(),
+                    is_primary: true,
+                },
+                ConfigSkill {
+                    skill: "SQLite".to_string(),
+                    is_primary: false,
+                },
+            ],
+            rates: BTreeMap::from([("user_hourly_rate".to_string(), "85".to_string())]),
+            humanization: BTreeMap::from([
+                ("humanization_intensity".to_string(), "Heavy".to_string()),
+                ("safety_threshold".to_string(), "250".to_string()),
+            ]),
+            scoring: BTreeMap::new(),
+            hook_strategies: vec![],
+        }
+    }
+
+    #[test]
+    fn test_export_contains_only_section_settings() {
+        let (_dir, db) = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        settings::set_setting(&conn, "user_hourly_rate", "75.00").unwrap();
+        settings::set_setting(&conn, "api_base_url", "https://example.com").unwrap();
+        user_skills::add_user_skill(&conn, "Rust").unwrap();
+
+        let exported = build_user_config(&conn).unwrap();
+        let json = serde_json::to_string(&exported).unwrap();
+
+        assert_eq!(exported.metadata.format_version, USER_CONFIG_FORMAT_VERSION);
+        assert_eq!(exported.rates.get("user_hourly_rate").unwrap(), "75.00");
+        assert_eq!(exported.skills.len(), 1);
+        assert_eq!(exported.hook_strategies.len(), 5);
+        assert!(!json.contains("api_base_url"));
+    }
+
+    #[test]
+    fn test_import_normalizes_like_the_setters() {
+        let (_dir, db) = create_test_db();
+        let conn = db.conn.lock().unwrap();
+
+        let report = import_user_config(&conn, &config(), true).unwrap();
+
+        assert_eq!(report.skills.applied, 2);
+        assert_eq!(report.rates.applied, 1);
+        assert_eq!(report.humanization.applied, 2);
+        assert!(report.affects_scores());
+        let get = |key| settings::get_setting(&conn, key).unwrap().unwrap();
+        assert_eq!(get("user_hourly_rate"), "85.00");
+        assert_eq!(get("humanization_intensity"), "heavy");
+        assert_eq!(get("safety_threshold"), "220", "thresholds are clamped");
+        let primary: Vec<_> = user_skills::get_user_skills(&conn)
+            .unwrap()
+            .into_iter()
+            .filter(|s| s.is_primary)
+            .map(|s| s.skill)
+            .collect();
+        assert_eq!(primary, vec!["Rust".to_string()]);
+    }
+
+    #[test]
+    fn test_import_skips_invalid_entries() {
+        let (_dir, db) = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        user_skills::add_user_skill(&conn, "rust").unwrap();
+        let mut config = config();
+        config
+            .rates
+            .insert("user_project_rate_min".to_string(), "-5".to_string());
+        config
+            .humanization
+            .insert("humanization_intensity".to_string(), "extreme".to_string());
+        config.scoring.insert(
+            "api_base_url".to_string(),
+            "https://evil.example".to_string(),
+        );
+
+        let report = import_user_config(&conn, &config, true).unwrap();
+
+        assert_eq!(counts(&report.skills), (1, 1));
+        assert_eq!(counts(&report.rates), (1, 1));
+        assert_eq!(counts(&report.humanization), (1, 1));
+        assert_eq!(counts(&report.scoring), (0, 1));
+        assert_eq!(report.warnings.len(), 4);
+        assert!(settings::get_setting(&conn, "api_base_url")
+            .unwrap()
+            .is_none());
+        assert!(settings::get_setting(&conn, "humanization_intensity")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_replace_clears_what_the_file_omits() {
+        let (_dir, db) = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        user_skills::add_user_skill(&conn, "Go").unwrap();
+        settings::set_setting(&conn, "user_project_rate_min", "500.00").unwrap();
+        conn.execute("UPDATE hook_strategies SET in_ab_rotation = 0", [])
+            .unwrap();
+
+        import_user_config(&conn, &config(), false).unwrap();
+
+        let skills: Vec<_> = user_skills::get_user_skills(&conn)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.skill)
+            .collect();
+        assert_eq!(skills.len(), 2);
+        assert!(!skills.contains(&"Go".to_string()));
+        assert!(settings::get_setting(&conn, "user_project_rate_min")
+            .unwrap()
+            .is_none());
+        assert!(hook_strategies::get_all_hook_strategies(&conn)
+            .unwrap()
+            .iter()
+            .all(|s| s.in_ab_rotation));
+    }
+
+    #[test]
+    fn test_hook_strategies_update_existing_and_add_custom() {
+        let (_dir, db) = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        let mut config = config();
+        config.hook_strategies = vec![
+            ConfigHookStrategy {
+                name: "Contrarian".to_string(),
+                description: "ignored for existing strategies".to_string(),
+                examples_json: "[]".to_string(),
+                best_for: "".to_string(),
+                ab_weight: 0.7,
+                in_ab_rotation: false,
+            },
+            ConfigHookStrategy {
+                name: "Storyteller".to_string(),
+                description: "Open with a short story".to_string(),
+                examples_json: r#"["Last spring a client..."]"#.to_string(),
+                best_for: "Best for: creative projects".to_string(),
+                ab_weight: 0.2,
+                in_ab_rotation: true,
+            },
+            ConfigHookStrategy {
+                name: "Broken".to_string(),
+                description: "Bad weight".to_string(),
+                examples_json: r#"["x"]"#.to_string(),
+                best_for: "n/a".to_string(),
+                ab_weight: 1.5,
+                in_ab_rotation: true,
+            },
+        ];
+
+        let report = import_user_config(&conn, &config, true).unwrap();
+
+        assert_eq!(counts(&report.hook_strategies), (2, 1));
+        let strategies = hook_strategies::get_all_hook_strategies(&conn).unwrap();
+        let contrarian = strategies.iter().find(|s| s.name == "Contrarian").unwrap();
+        assert_eq!(contrarian.ab_weight, 0.7);
+        assert!(!contrarian.in_ab_rotation);
+        assert_ne!(contrarian.description, "ignored for existing strategies");
+        assert!(strategies.iter().any(|s| s.name == "Storyteller"));
+        assert!(!strategies.iter().any(|s| s.name == "Broken"));
+    }
+
+    #[test]
+    fn test_newer_format_version_rejected_without_changes() {
+        let (_dir, db) = create_test_db();
+        let conn = db.conn.lock().unwrap();
+        let mut config = config();
+        config.metadata.format_version = USER_CONFIG_FORMAT_VERSION + 1;
+
+        assert!(import_user_config(&conn, &config, true).is_err());
+        assert!(user_skills::get_user_skills(&conn).unwrap().is_empty());
+    }
+}
@@ -4,6 +4,7 @@
 //! All analysis happens locally - NO API calls (AR-12)
 
 use crate::voice::profile::{CalibrationSource, StructurePreference, VoiceProfile};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Segment text into sentences, handling common abbreviations
@@ -461,6 +462,224 @@ pub fn aggregate_voice_profile(proposals: &[String]) -> VoiceProfile {
     }
 }
 
+/// Recommended minimum number of samples for a reliable calibration
+pub const RECOMMENDED_MIN_SAMPLES: u32 = 5;
+
+/// Average normalized spread above which samples are considered inconsistent
+const HIGH_VARIANCE_THRESHOLD: f32 = 0.2;
+
+/// Quality assessment of a voice calibration
+///
+/// Lets the UI prompt "add more samples for better results" when the
+/// sample set is small or inconsistent.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CalibrationQuality {
+    /// Overall confidence in the calibrated profile (0-100)
+    pub confidence_score: u8,
+    /// "low" (<50), "medium" (<80) or "high"
+    pub confidence_level: String,
+    pub sample_count: u32,
+    pub recommended_min_samples: u32,
+    /// Average normalized standard deviation across measured parameters (0.0-1.0)
+    pub variance: f32,
+    pub warnings: Vec<String>,
+}
+
+/// Standard deviation of `values` divided by `scale` (the parameter's range)
+fn normalized_std_dev(values: &[f32], scale: f32) -> f32 {
+    if values.len() < 2 || scale <= 0.0 {
+        return 0.0;
+    }
+    let mean = values.iter().sum::<f32>() / values.len() as f32;
+    let variance =
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / values.len() as f32;
+    (variance.sqrt() / scale).min(1.0)
+}
+
+fn confidence_level(score: u8) -> String {
+    match score {
+        0..=49 => "low",
+        50..=79 => "medium",
+        _ => "high",
+    }
+    .to_string()
+}
+
+/// Assess how trustworthy a calibration from these proposals is
+///
+/// Confidence scales with sample count (up to `RECOMMENDED_MIN_SAMPLES`) and
+/// drops as the measured parameters diverge between samples.
+pub fn assess_calibration_quality(proposals: &[String]) -> CalibrationQuality {
+    let sample_count = proposals.len() as u32;
+    let profiles: Vec<VoiceProfile> = proposals
+        .iter()
+        .map(|p| analyze_single_proposal(p))
+        .collect();
+
+    let collect = |f: fn(&VoiceProfile) -> f32| profiles.iter().map(f).collect::<Vec<f32>>();
+
+    // Sentence length has no fixed range - normalize by its mean instead
+    let sentence_lengths = collect(|p| p.avg_sentence_length);
+    let mean_sentence_length = if sentence_lengths.is_empty() {
+        0.0
+    } else {
+        sentence_lengths.iter().sum::<f32>() / sentence_lengths.len() as f32
+    };
+
+    let spreads = [
+        normalized_std_dev(&collect(|p| p.tone_score), 9.0),
+        normalized_std_dev(&sentence_lengths, mean_sentence_length),
+        normalized_std_dev(&collect(|p| p.vocabulary_complexity), 15.0),
+        normalized_std_dev(&collect(|p| p.technical_depth), 9.0),
+        normalized_std_dev(
+            &collect(|p| p.structure_preference.bullets_pct as f32),
+            100.0,
+        ),
+    ];
+    let variance = spreads.iter().sum::<f32>() / spreads.len() as f32;
+
+    let sample_factor = (sample_count as f32 / RECOMMENDED_MIN_SAMPLES as f32).min(1.0);
+    let consistency_factor = (1.0 - variance * 2.0).clamp(0.0, 1.0);
+    let confidence_score =
+        (100.0 * sample_factor * (0.5 + 0.5 * consistency_factor)).round() as u8;
+
+    let mut warnings = Vec::new();
+    if sample_count < RECOMMENDED_MIN_SAMPLES {
+        warnings.push(format!(
+            "Only {} sample{} analyzed - add at least {} proposals for better results",
+            sample_count,
+            if sample_count == 1 { "" } else { "s" },
+            RECOMMENDED_MIN_SAMPLES
+        ));
+    }
+    if variance > HIGH_VARIANCE_THRESHOLD {
+        warnings.push(
+            "Samples are highly inconsistent - the calibrated voice may be incoherent".to_string(),
+        );
+    }
+
+    CalibrationQuality {
+        confidence_score,
+        confidence_level: confidence_level(confidence_score),
+        sample_count,
+        recommended_min_samples: RECOMMENDED_MIN_SAMPLES,
+        variance,
+        warnings,
+    }
+}
+
+/// Quality assessment for a questionnaire-based (quick) calibration
+///
+/// No writing samples are analyzed, so confidence is always low.
+pub fn quick_calibration_quality() -> CalibrationQuality {
+    CalibrationQuality {
+        confidence_score: 25,
+        confidence_level: confidence_level(25),
+        sample_count: 0,
+        recommended_min_samples: RECOMMENDED_MIN_SAMPLES,
+        variance: 0.0,
+        warnings: vec![format!(
+            "Based on questionnaire answers only - upload at least {} past proposals for better results",
+            RECOMMENDED_MIN_SAMPLES
+        )],
+    }
+}
+
+/// Comparison of one measured dimension against the target profile
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VoiceDimensionMatch {
+    pub dimension: String,
+    pub measured: f32,
+    pub target: f32,
+    /// measured - target (positive = text overshoots the target)
+    pub delta: f32,
+    /// Similarity for this dimension (0-100)
+    pub match_pct: f32,
+}
+
+/// Result of comparing generated text against the calibrated voice
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceMatchResult {
+    /// Voice parameters measured from the text
+    pub measured: VoiceProfile,
+    /// Per-dimension deltas (empty when there is no target profile)
+    pub dimensions: Vec<VoiceDimensionMatch>,
+    /// Average of per-dimension matches (None when there is no target profile)
+    pub overall_match_pct: Option<f32>,
+    pub note: Option<String>,
+}
+
+fn dimension_match(dimension: &str, measured: f32, target: f32, scale: f32) -> VoiceDimensionMatch {
+    let delta = measured - target;
+    let match_pct = if scale > 0.0 {
+        ((1.0 - (delta.abs() / scale).min(1.0)) * 100.0).round()
+    } else {
+        100.0
+    };
+
+    VoiceDimensionMatch {
+        dimension: dimension.to_string(),
+        measured,
+        target,
+        delta,
+        match_pct,
+    }
+}
+
+/// Measure `text` with the calibration logic and compare it against `target`
+///
+/// Without a target profile only the measured metrics are returned.
+pub fn compare_voice_match(text: &str, target: Option<&VoiceProfile>) -> VoiceMatchResult {
+    let measured = analyze_single_proposal(text);
+
+    let Some(target) = target else {
+        return VoiceMatchResult {
+            measured,
+            dimensions: Vec::new(),
+            overall_match_pct: None,
+            note: Some("No voice profile found - nothing to compare against".to_string()),
+        };
+    };
+
+    let dimensions = vec![
+        dimension_match("tone_score", measured.tone_score, target.tone_score, 9.0),
+        // Sentence length has no fixed range - scale by the target itself
+        dimension_match(
+            "avg_sentence_length",
+            measured.avg_sentence_length,
+            target.avg_sentence_length,
+            target.avg_sentence_length.max(1.0),
+        ),
+        dimension_match(
+            "vocabulary_complexity",
+            measured.vocabulary_complexity,
+            target.vocabulary_complexity,
+            15.0,
+        ),
+        dimension_match(
+            "structure_bullets_pct",
+            measured.structure_preference.bullets_pct as f32,
+            target.structure_preference.bullets_pct as f32,
+            100.0,
+        ),
+        dimension_match(
+            "technical_depth",
+            measured.technical_depth,
+            target.technical_depth,
+            9.0,
+        ),
+    ];
+
+    let overall = dimensions.iter().map(|d| d.match_pct).sum::<f32>() / dimensions.len() as f32;
+
+    VoiceMatchResult {
+        measured,
+        dimensions,
+        overall_match_pct: Some(overall.round()),
+        note: None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -622,4 +841,91 @@ mod tests {
             elapsed.as_secs_f32()
         );
     }
+
+    #[test]
+    fn test_calibration_quality_single_sample_low_confidence() {
+        let proposals = vec!["I build reliable web apps. I care about clean code.".to_string()];
+
+        let quality = assess_calibration_quality(&proposals);
+        assert_eq!(quality.sample_count, 1);
+        assert_eq!(quality.confidence_level, "low");
+        assert!(quality.confidence_score < 50);
+        assert!(quality.warnings.iter().any(|w| w.contains("Only 1 sample")));
+    }
+
+    #[test]
+    fn test_calibration_quality_consistent_samples_high_confidence() {
+        let sample = "I build reliable web apps for small businesses. I care about clean code and clear communication.".to_string();
+        let proposals: Vec<String> = (0..5).map(|_| sample.clone()).collect();
+
+        let quality = assess_calibration_quality(&proposals);
+        assert_eq!(quality.confidence_score, 100);
+        assert_eq!(quality.confidence_level, "high");
+        assert!(quality.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_calibration_quality_high_variance_warns() {
+        let casual = "- Hey!\n- Yeah, cool stuff.\n- Gonna help.".to_string();
+        let formal = "Therefore, I shall consequently provide a comprehensive architectural implementation regarding your distributed database authentication infrastructure, furthermore ensuring scalable deployment pipelines and sophisticated algorithmic optimization throughout the entire microservice ecosystem.".to_string();
+        let proposals = vec![
+            casual.clone(),
+            formal.clone(),
+            casual.clone(),
+            formal.clone(),
+            casual,
+            formal,
+        ];
+
+        let quality = assess_calibration_quality(&proposals);
+        assert!(quality.variance > HIGH_VARIANCE_THRESHOLD, "Variance: {}", quality.variance);
+        assert!(quality.warnings.iter().any(|w| w.contains("incoherent")));
+        assert!(quality.confidence_score < 100);
+    }
+
+    #[test]
+    fn test_quick_calibration_quality_flags_low_confidence() {
+        let quality = quick_calibration_quality();
+        assert_eq!(quality.sample_count, 0);
+        assert_eq!(quality.confidence_level, "low");
+        assert!(!quality.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_voice_match_identical_text_is_full_match() {
+        let text = "I build reliable web apps for small businesses. I care about clean code and clear communication.";
+        let target = analyze_single_proposal(text);
+
+        let result = compare_voice_match(text, Some(&target));
+        assert_eq!(result.overall_match_pct, Some(100.0));
+        assert_eq!(result.dimensions.len(), 5);
+        assert!(result.dimensions.iter().all(|d| d.delta == 0.0));
+        assert!(result.note.is_none());
+    }
+
+    #[test]
+    fn test_voice_match_reports_deltas_for_divergent_text() {
+        let target = analyze_single_proposal("- Hey!\n- Cool stuff.\n- Gonna help.");
+        let text = "Therefore, I shall consequently provide a comprehensive architectural implementation regarding your distributed database authentication infrastructure.";
+
+        let result = compare_voice_match(text, Some(&target));
+        let overall = result.overall_match_pct.unwrap();
+        assert!(overall < 70.0, "Overall: {}", overall);
+
+        let structure = result
+            .dimensions
+            .iter()
+            .find(|d| d.dimension == "structure_bullets_pct")
+            .unwrap();
+        assert!(structure.delta < 0.0, "Fewer bullets than target: {}", structure.delta);
+    }
+
+    #[test]
+    fn test_voice_match_without_profile_returns_metrics_only() {
+        let result = compare_voice_match("I build reliable web apps.", None);
+        assert!(result.dimensions.is_empty());
+        assert!(result.overall_match_pct.is_none());
+        assert!(result.note.unwrap().contains("nothing to compare"));
+        assert!(result.measured.avg_sentence_length > 0.0);
+    }
 }
@@ -0,0 +1,300 @@
+//! Locked-state audit of every registered Tauri command
+//!
+//! With an encrypted database, commands run before the passphrase unlock.
+//! Commands that need the database get `AppDatabase::get()`'s "Database not
+//! unlocked" error (`DATABASE_NOT_READY`); everything else must keep working.
+//! This test reads the `generate_handler!` list in `src/lib.rs`, finds each
+//! command's source, and classifies it by how it reaches the database:
+//!
+//! - touches `AppDatabase` directly: needs the database, fails while locked
+//! - also checks readiness (`try_get` / `is_ready` / `set`): lock-aware
+//! - never mentions `AppDatabase`: must be listed below
+//!
+//! Adding a command that works without the database, or making a listed one
+//! need it, fails the test until the lists are updated deliberately. A gated
+//! command is also invoked on a mock app with a locked `AppDatabase`.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// Answer while locked without touching the database
+const WORKS_WHILE_LOCKED: &[&str] = &[
+    "analyze_humanization_metrics",
+    "calibrate_kdf_params",
+    "check_proposal_coverage",
+    "clear_api_key",
+    "commands::bulk_scoring::cancel_bulk_scoring",
+    "commands::clipboard_watch::mark_clipboard_write",
+    "commands::import::dismiss_incomplete_imports",
+    "commands::import::get_incomplete_imports",
+    "commands::import::read_archive_metadata",
+    "commands::logs::export_logs_bundle",
+    "commands::logs::get_recent_logs",
+    "commands::presentation::get_presentation_mode",
+    "commands::presentation::set_presentation_mode",
+    "commands::system::get_api_quota_status",
+    "commands::system::get_blocked_requests",
+    "commands::system::get_command_metrics",
+    "commands::system::get_memory_usage",
+    "commands::system::get_network_metrics",
+    "commands::system::reset_command_metrics",
+    "commands::system::reset_network_metrics",
+    "commands::system::signal_ready",
+    "commands::voice::pick_and_read_file",
+    "delete_api_key_profile",
+    "delete_old_database",
+    "describe_humanization_intensity",
+    "generate_recovery_sheet",
+    "get_ai_tell_phrases",
+    "get_api_key_masked",
+    "get_cooldown_remaining",
+    "get_cooldown_seconds",
+    "get_current_prompt_versions",
+    "get_settings_schema",
+    "get_skill_suggestions",
+    "has_api_key",
+    "has_recovery_key",
+    "health_check::cleanup_old_backups_command",
+    "invalidate_voice_cache",
+    "list_api_key_profiles",
+    "list_profiles",
+    "local_ai_risk_estimate",
+    "migrate_api_key_to_keychain",
+    "migrate_database",
+    // Fall back to the bundled config when the cache is unreadable
+    "remote_config::fetch_remote_config_command",
+    "remote_config::force_config_refresh_command",
+    "remote_config::get_bundled_config_command",
+    "repair_integrity_manifest",
+    "sanitize_preview",
+    "set_active_api_key_profile",
+    "set_api_key",
+    "set_api_key_named",
+    "set_passphrase",
+    "switch_profile",
+    "test_encryption_roundtrip",
+    "validate_api_key",
+    "verify_api_key_live",
+    "verify_passphrase",
+    "verify_passphrase_strength",
+];
+
+/// Need the database through helpers rather than directly; the locked error
+/// reaches the frontend wrapped in the helper's own error
+const DATABASE_VIA_HELPERS: &[&str] = &[
+    "health_check::create_pre_update_backup_command",
+    "health_check::prepare_and_apply_update",
+    "health_check::rollback_to_previous_version_command",
+    "health_check::run_health_checks_command",
+    "update_channel::check_for_update",
+];
+
+/// Use the database only once it is ready, and answer before that
+const LOCK_AWARE: &[&str] = &[
+    "get_app_lock_state",
+    "unlock_with_recovery_key",
+    "verify_passphrase_on_restart",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LockedBehavior {
+    RequiresDatabase,
+    LockAware,
+    NoDatabase,
+}
+
+fn src_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+/// Command paths in `tauri::generate_handler![...]`, comments stripped
+fn registered_commands() -> Vec<String> {
+    let lib = std::fs::read_to_string(src_dir().join("lib.rs")).unwrap();
+    let start = lib
+        .find("generate_handler![")
+        .expect("generate_handler! not found in lib.rs")
+        + "generate_handler![".len();
+    let end = start + lib[start..].find(']').unwrap();
+
+    lib[start..end]
+        .lines()
+        .filter_map(|line| {
+            let entry = line
+                .split("//")
+                .next()
+                .unwrap()
+                .trim()
+                .trim_end_matches(',');
+            (!entry.is_empty()).then(|| entry.to_string())
+        })
+        .collect()
+}
+
+/// Source of the command's function (signature and body), located from its
+/// module path: `a::b::name` lives in `src/a/b.rs` or `src/a/b/mod.rs`
+fn command_source(command: &str) -> Option<String> {
+    let mut parts: Vec<&str> = command.split("::").collect();
+    let name = parts.pop()?;
+    if parts.first() == Some(&"crate") {
+        parts.remove(0);
+    }
+    let file = if parts.is_empty() {
+        src_dir().join("lib.rs")
+    } else {
+        let base = src_dir().join(parts.join("/"));
+        [base.with_extension("rs"), base.join("mod.rs")]
+            .into_iter()
+            .find(|p| p.exists())?
+    };
+    let text = std::fs::read_to_string(file).ok()?;
+
+    let needle = format!("fn {}", name);
+    let start = text.match_indices(&needle).map(|(i, _)| i).find(|&i| {
+        matches!(
+            text[i + needle.len()..].chars().next(),
+            Some('(') | Some('<')
+        )
+    })?;
+
+    // Body: from the first `{` to its matching `}`
+    let open = start + text[start..].find('{')?;
+    let mut depth = 0usize;
+    for (offset, ch) in text[open..].char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(text[start..=open + offset].to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn classify(source: &str) -> LockedBehavior {
+    if !source.contains("AppDatabase") {
+        LockedBehavior::NoDatabase
+    } else if ["try_get(", "is_ready(", ".set("]
+        .iter()
+        .any(|call| source.contains(call))
+    {
+        LockedBehavior::LockAware
+    } else {
+        LockedBehavior::RequiresDatabase
+    }
+}
+
+#[test]
+fn test_every_command_has_known_locked_behavior() {
+    let commands = registered_commands();
+    assert!(
+        commands.len() > 100,
+        "parsed only {} commands",
+        commands.len()
+    );
+
+    let mut unresolved = Vec::new();
+    let mut no_database = BTreeSet::new();
+    let mut lock_aware = BTreeSet::new();
+    for command in &commands {
+        match command_source(command).as_deref().map(classify) {
+            None => unresolved.push(command.clone()),
+            Some(LockedBehavior::NoDatabase) => {
+                no_database.insert(command.as_str());
+            }
+            Some(LockedBehavior::LockAware) => {
+                lock_aware.insert(command.as_str());
+            }
+            Some(LockedBehavior::RequiresDatabase) => {}
+        }
+    }
+    assert!(
+        unresolved.is_empty(),
+        "could not find the source of: {:?}",
+        unresolved
+    );
+
+    let expected_no_database: BTreeSet<&str> = WORKS_WHILE_LOCKED
+        .iter()
+        .chain(DATABASE_VIA_HELPERS)
+        .copied()
+        .collect();
+    let unlisted: Vec<_> = no_database.difference(&expected_no_database).collect();
+    assert!(
+        unlisted.is_empty(),
+        "commands that never touch AppDatabase must be listed in WORKS_WHILE_LOCKED \
+         or DATABASE_VIA_HELPERS: {:?}",
+        unlisted
+    );
+    let now_need_database: Vec<_> = expected_no_database.difference(&no_database).collect();
+    assert!(
+        now_need_database.is_empty(),
+        "listed as working without the database, but now touch AppDatabase \
+         (or are no longer registered): {:?}",
+        now_need_database
+    );
+
+    let expected_lock_aware: BTreeSet<&str> = LOCK_AWARE.iter().copied().collect();
+    assert_eq!(
+        lock_aware, expected_lock_aware,
+        "lock-aware commands changed"
+    );
+}
+
+#[test]
+fn test_commands_named_in_locked_audit_stay_database_free() {
+    // Read-only commands the frontend calls before unlock
+    for command in [
+        "get_cooldown_remaining",
+        "get_skill_suggestions",
+        "verify_passphrase_strength",
+        "remote_config::get_bundled_config_command",
+        "commands::system::get_blocked_requests",
+    ] {
+        let source = command_source(command).unwrap_or_else(|| panic!("{} not found", command));
+        assert_eq!(
+            classify(&source),
+            LockedBehavior::NoDatabase,
+            "{} must answer while the database is locked",
+            command
+        );
+    }
+}
+
+#[test]
+fn test_gated_command_returns_not_ready_while_locked() {
+    use tauri::ipc::{CallbackFn, InvokeBody};
+    use tauri::test::{get_ipc_response, mock_builder, mock_context, noop_assets, INVOKE_KEY};
+    use tauri::webview::InvokeRequest;
+    use upwork_research_agent_lib::db::{AppDatabase, DATABASE_LOCKED_MESSAGE};
+    use upwork_research_agent_lib::update_channel;
+
+    let app = mock_builder()
+        .manage(AppDatabase::new_empty())
+        .invoke_handler(tauri::generate_handler![update_channel::get_update_channel])
+        .build(mock_context(noop_assets()))
+        .unwrap();
+    let webview = tauri::WebviewWindowBuilder::new(&app, "main", Default::default())
+        .build()
+        .unwrap();
+
+    let error = get_ipc_response(
+        &webview,
+        InvokeRequest {
+            cmd: "get_update_channel".into(),
+            callback: CallbackFn(0),
+            error: CallbackFn(1),
+            url: "http://tauri.localhost".parse().unwrap(),
+            body: InvokeBody::default(),
+            headers: Default::default(),
+            invoke_key: INVOKE_KEY.to_string(),
+        },
+    )
+    .expect_err("get_update_channel must fail while the database is locked");
+
+    assert_eq!(error["code"], "DATABASE_NOT_READY");
+    assert_eq!(error["message"], DATABASE_LOCKED_MESSAGE);
+}
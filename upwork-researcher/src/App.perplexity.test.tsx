@@ -78,6 +78,7 @@ describe("App — Perplexity Analysis Integration", () => {
       expect(mockInvoke).toHaveBeenCalledWith("analyze_perplexity", {
         text: "A generated proposal about React development.",
         threshold: 180,
+        intensity: "medium",
       });
     });
   });
@@ -18,11 +18,7 @@ const mockInvoke = vi.mocked(invoke);
 import { listen } from "@tauri-apps/api/event";
 const mockListen = vi.mocked(listen);
 
-// Mock Tauri updater/process plugins (Story 9.7 Task 5.7)
-vi.mock("@tauri-apps/plugin-updater", () => ({
-  check: vi.fn(),
-}));
-
+// Mock Tauri process plugin (Story 9.7 Task 5.7)
 vi.mock("@tauri-apps/plugin-process", () => ({
   relaunch: vi.fn(),
 }));
@@ -32,8 +28,16 @@ vi.mock("@tauri-apps/api/app", () => ({
   getVersion: vi.fn().mockResolvedValue("1.2.0"),
 }));
 
-import { check } from "@tauri-apps/plugin-updater";
-const mockCheck = vi.mocked(check);
+// Release offered by the update channel's feed (check_for_update), null when up to date
+const mockCheck = vi.fn<
+  () => Promise<{
+    version: string;
+    currentVersion: string;
+    body?: string;
+    date?: string;
+    critical?: boolean;
+  } | null>
+>();
 
 // Helper to create invoke handler with base commands + custom overrides
 const createInvokeHandler = (overrides: Record<string, (args?: any) => any> = {}) => {
@@ -41,6 +45,18 @@ const createInvokeHandler = (overrides: Record<string, (args?: any) => any> = {}
     if (overrides[command]) {
       return overrides[command](args);
     }
+    if (command === "check_for_update")
+      return Promise.resolve(mockCheck()).then((release) => ({
+        channel: "stable",
+        feed: "stable",
+        currentVersion: release?.currentVersion ?? "1.0.0",
+        latestVersion: release?.version ?? null,
+        status: release ? "available" : "upToDate",
+        message: "",
+        body: release?.body ?? null,
+        date: release?.date ?? null,
+        critical: release?.critical ?? false,
+      }));
     if (command === "has_api_key") return Promise.resolve(true);
     if (command === "get_setting" && args?.key === "onboarding_completed")
       return Promise.resolve("true");
@@ -113,6 +129,27 @@ describe("App", () => {
     expect(screen.getByRole("button", { name: /generate proposal/i })).toBeDisabled();
   });
 
+  it("returns to first run when app:factory-reset fires", async () => {
+    render(<App />);
+    await waitForAppReady();
+    act(() => {
+      useGenerationStore.getState().setComplete("Proposal from before the reset");
+    });
+
+    const resetCall = mockListen.mock.calls.find((call) => call[0] === "app:factory-reset");
+    expect(resetCall).toBeDefined();
+    const callback = resetCall![1] as (event: { payload: unknown }) => void;
+    act(() => {
+      callback({ payload: { tables_dropped: 30, files_removed: 5, files_not_removed: [] } });
+    });
+
+    await waitFor(() => {
+      expect(screen.getByText("Welcome! Let's get started")).toBeInTheDocument();
+    });
+    expect(useGenerationStore.getState().fullText).toBeNull();
+    expect(useOnboardingStore.getState().showOnboarding).toBe(true);
+  });
+
   it("enables generate button when input has content", async () => {
     const user = userEvent.setup();
     render(<App />);
@@ -785,8 +822,7 @@ describe("App - Auto-Update Integration (Story 9.7)", () => {
       currentVersion: "1.0.0",
       body: "Bug fixes and improvements",
       date: "2026-02-16",
-      downloadAndInstall: vi.fn(),
-    } as any);
+    });
 
     render(<App />);
 
@@ -806,8 +842,7 @@ describe("App - Auto-Update Integration (Story 9.7)", () => {
       body: "Critical security fix",
       date: "2026-02-16",
       critical: true,
-      downloadAndInstall: vi.fn(),
-    } as any);
+    });
 
     render(<App />);
 
@@ -891,8 +926,7 @@ describe("App - Config Update Integration (Story 10.5)", () => {
       currentVersion: "1.0.0",
       body: "Bug fixes",
       date: "2026-02-16",
-      downloadAndInstall: vi.fn(),
-    } as any);
+    });
 
     render(<App />);
     await waitForAppReady();
@@ -926,8 +960,7 @@ describe("App - Config Update Integration (Story 10.5)", () => {
       currentVersion: "1.0.0",
       body: "Bug fixes",
       date: "2026-02-16",
-      downloadAndInstall: vi.fn(),
-    } as any);
+    });
 
     render(<App />);
     await waitForAppReady();
@@ -1047,8 +1080,7 @@ describe("App health check orchestration (TD2.3)", () => {
         get_encryption_status: { databaseEncrypted: false },
         check_for_draft: null,
         get_cooldown_remaining: 0,
-        check_threshold_learning: null,
-        check_threshold_decrease: null,
+        get_threshold_suggestion: null,
         list_settings: [],
         ...overrides,
       };
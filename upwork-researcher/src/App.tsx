@@ -44,6 +44,7 @@ import {
   ProposalHistoryList,
   ProposalDetailView,
   ProposalAnalyticsDashboard,
+  IncompleteImportNotice,
 } from "./features/proposal-history";
 import { useGenerationStream } from "./hooks/useGenerationStream";
 import { useKeyboardShortcuts } from "./hooks/useKeyboardShortcuts";
@@ -65,6 +66,7 @@ import {
 } from "./stores/useSettingsStore";
 import type { PerplexityAnalysis } from "./types/perplexity";
 import { DEFAULT_PERPLEXITY_THRESHOLD } from "./types/perplexity";
+import { getErrorMessage, getRateLimitSeconds } from "./utils/appError";
 import "./styles/tokens.css";
 import "./App.css";
 
@@ -82,6 +84,15 @@ interface HealthCheckReport {
   duration_ms: number;
 }
 
+// generate_proposal_streaming response fields passed back to save_proposal
+interface StreamingGenerationResult {
+  hookStrategyId: string | null;
+  abAssigned: boolean;
+  abWeightAtAssignment: number | null;
+  // Opaque to the frontend: recorded with the proposal as-is
+  generationMetadata: Record<string, unknown>;
+}
+
 type View = "generate" | "history" | "settings" | "proposal-detail" | "analytics";
 type MigrationPhase =
   | "idle"
@@ -345,6 +356,10 @@ function AppContent() {
 
   // Story 5.2: Hook strategy selection state (AC-4)
   const [selectedStrategyId, setSelectedStrategyId] = useState<number | null>(null);
+  // Result of the last generate_proposal_streaming call, saved with the proposal
+  const [generationResult, setGenerationResult] = useState<StreamingGenerationResult | null>(
+    null,
+  );
 
   // Story 4a.6: Timer refs for analysis progress stages
   const extractingTimerRef = useRef<ReturnType<typeof setTimeout> | null>(null);
@@ -496,8 +511,8 @@ function AppContent() {
           // Non-blocking - app works without cooldown sync
         });
 
-      // Story 3.7: Check for threshold learning opportunity on startup
-      const learningPromise = invoke<ThresholdSuggestion | null>("check_threshold_learning")
+      // Story 3.7: Check for a threshold adjustment (increase or decrease, AC8) on startup
+      const learningPromise = invoke<ThresholdSuggestion | null>("get_threshold_suggestion")
         .then((suggestion) => {
           if (suggestion) {
             setThresholdSuggestion(suggestion);
@@ -525,21 +540,6 @@ function AppContent() {
           }
         });
 
-      // Story 3.7: Also check for downward adjustment on startup (AC8)
-      const decreasePromise = invoke<ThresholdSuggestion | null>("check_threshold_decrease")
-        .then((suggestion) => {
-          if (suggestion) {
-            // Only set if no increase suggestion already exists
-            setThresholdSuggestion((prev) => prev || suggestion);
-          }
-        })
-        .catch((err) => {
-          if (import.meta.env.DEV) {
-            console.error("Failed to check threshold decrease:", err);
-          }
-          // Non-blocking
-        });
-
       // Wait for all to complete
       await Promise.all([
         settingsPromise,
@@ -548,7 +548,6 @@ function AppContent() {
         encryptionPromise,
         cooldownPromise,
         learningPromise,
-        decreasePromise,
         rollbackPromise,
       ]);
       setCheckingApiKey(false);
@@ -685,10 +684,30 @@ function AppContent() {
     };
   }, []);
 
+  // Factory reset (factory_reset): all user data is gone, API key included. Drop
+  // in-memory state and return to first run: API key setup, then onboarding.
+  useEffect(() => {
+    const unlisten = listen("app:factory-reset", () => {
+      useGenerationStore.getState().reset();
+      useOnboardingStore.getState().reset();
+      setShowOnboarding(true);
+      setJobContent("");
+      setPerplexityAnalysis(null);
+      setThresholdSuggestion(null);
+      setSelectedProposalId(null);
+      setActiveView("generate");
+      setHasApiKey(false);
+      loadSettings().catch(() => {});
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [loadSettings, setShowOnboarding]);
+
   // Story 2-7b: Handler for successful database unlock
   // M1+M3 fix (Review 2): Re-run DB-dependent initialization that failed while DB was locked.
   // The initial initializeApp() runs before unlock — queries like check_for_draft,
-  // get_cooldown_remaining, and check_threshold_learning silently fail when DB is locked.
+  // get_cooldown_remaining, and get_threshold_suggestion silently fail when DB is locked.
   const handleDatabaseUnlocked = useCallback(() => {
     setNeedsUnlock(false);
 
@@ -726,21 +745,13 @@ function AppContent() {
       .catch(() => {});
 
     // Re-check threshold learning (Story 3.7)
-    invoke<ThresholdSuggestion | null>("check_threshold_learning")
+    invoke<ThresholdSuggestion | null>("get_threshold_suggestion")
       .then((suggestion) => {
         if (suggestion) {
           setThresholdSuggestion(suggestion);
         }
       })
       .catch(() => {});
-
-    invoke<ThresholdSuggestion | null>("check_threshold_decrease")
-      .then((suggestion) => {
-        if (suggestion) {
-          setThresholdSuggestion((prev) => prev || suggestion);
-        }
-      })
-      .catch(() => {});
   }, [setDraftRecovery, setCooldown]);
 
   // Handler for when API key setup is complete
@@ -883,14 +894,19 @@ function AppContent() {
 
   // Auto-save when generation completes
   useEffect(() => {
-    if (fullText && !isSaved && jobContentRef.current) {
+    // Waits for the generate_proposal_streaming response, which resolves after the stream
+    // completes, so the proposal is recorded with its model, intensity and strategy
+    if (fullText && !isSaved && jobContentRef.current && generationResult) {
       const saveProposal = async () => {
         try {
           const result = await invoke<{ id: number; saved: boolean }>("save_proposal", {
             jobContent: jobContentRef.current,
             generatedText: fullText,
-            strategyId: selectedStrategyId, // Story 7.1 AC-2: Hook strategy used
+            hookStrategyId: generationResult.hookStrategyId, // Story 7.1 AC-2: Hook strategy used
+            abAssigned: generationResult.abAssigned,
+            abWeightAtAssignment: generationResult.abWeightAtAssignment,
             jobPostId: jobPostId, // Story 7.1 AC-3: Originating job post
+            generationMetadata: generationResult.generationMetadata,
           });
           if (result.saved) {
             setSaved(result.id);
@@ -902,7 +918,7 @@ function AppContent() {
       };
       saveProposal();
     }
-  }, [fullText, isSaved, setSaved]);
+  }, [fullText, isSaved, setSaved, generationResult]);
 
   // Reset retry count on successful generation (Story 1.13 Code Review Fix)
   useEffect(() => {
@@ -956,16 +972,18 @@ function AppContent() {
       }
 
       try {
+        // Judged against the threshold for the intensity the text was generated with
         const analysis = await invoke<PerplexityAnalysis>("analyze_perplexity", {
           text: fullText,
           threshold: DEFAULT_PERPLEXITY_THRESHOLD,
+          intensity: humanizationIntensity,
         });
 
         // Mark this text as analyzed so we don't re-run after modal dismissal
         analyzedTextRef.current = fullText;
 
         // Only show modal if score exceeds threshold
-        if (analysis.score >= DEFAULT_PERPLEXITY_THRESHOLD) {
+        if (analysis.score >= analysis.threshold) {
           setPerplexityAnalysis(analysis);
         }
         // If score is passing, no modal needed - user can copy directly
@@ -977,7 +995,7 @@ function AppContent() {
     };
 
     runPerplexityAnalysis();
-  }, [fullText, isStreaming, streamError, isRegenerating]);
+  }, [fullText, isStreaming, streamError, isRegenerating, humanizationIntensity]);
 
   // Story 3.8: Start cooldown timer after successful generation (AC1)
   useEffect(() => {
@@ -1052,8 +1070,11 @@ function AppContent() {
         clientName: string | null;
         keySkills: string[];
         hiddenNeeds: Array<{ need: string; evidence: string }>;
+        requiredDeliverables: string[];
+        niceToHaveDeliverables: string[];
         wasTruncated: boolean; // Story 4a.9: AC-3 - truncation flag
         clientQualityScore: number | null; // Story 4b.3: Client quality score
+        cached: boolean; // Served from the analysis cache (no API call)
       }>("analyze_job_post", {
         rawContent: jobContent,
         jobPostId: jobPostId, // Story 4a.8: Pass ID for atomic save
@@ -1145,6 +1166,7 @@ function AppContent() {
     analyzedTextRef.current = null;
     setAnalysisSkipped(false);
     resetAttempts();
+    setGenerationResult(null);
 
     // Story 8.3 AC3: Announce generation start
     announce("Generating proposal...");
@@ -1155,23 +1177,22 @@ function AppContent() {
 
       // Use streaming command - tokens will arrive via events
       // Story 5.2: Pass selected strategy ID to backend (AC-4, Subtask 5.5)
-      await invoke<string>("generate_proposal_streaming", {
+      const result = await invoke<StreamingGenerationResult>("generate_proposal_streaming", {
         jobContent: jobContent,
         strategyId: selectedStrategyId,
       });
+      setGenerationResult(result);
     } catch (err) {
       // Error will be set via event, but catch invoke errors too
-      const errorMessage = err instanceof Error ? err.message : String(err);
+      const errorMessage = getErrorMessage(err);
 
       // Story 3.8: Handle RATE_LIMITED error from backend (AC2)
-      if (errorMessage.startsWith("RATE_LIMITED:")) {
-        const remaining = parseInt(errorMessage.split(":")[1], 10);
-        if (!isNaN(remaining) && remaining > 0) {
-          setCooldown(remaining * 1000);
-          // Clear streaming state - rate limit is not an error to display
-          setStreaming(false);
-          return;
-        }
+      const remaining = getRateLimitSeconds(err);
+      if (remaining !== null && remaining > 0) {
+        setCooldown(remaining * 1000);
+        // Clear streaming state - rate limit is not an error to display
+        setStreaming(false);
+        return;
       }
 
       useGenerationStore.getState().setError(errorMessage);
@@ -1452,6 +1473,8 @@ function AppContent() {
         reason={rollbackReason}
         onRestart={handleRollbackRestart}
       />
+      {/* Story 7.7: Archive imports interrupted before they committed */}
+      <IncompleteImportNotice />
       {/* Story TD2.3: Update success toast (AC-2, AC-4) */}
       {updateSuccessToast && (
         <div
@@ -9,8 +9,7 @@ vi.mock("@tauri-apps/api/core", () => ({
     if (cmd === "has_api_key") return Promise.resolve(true);
     if (cmd === "get_encryption_status") return Promise.resolve({ databaseEncrypted: false });
     if (cmd === "get_cooldown_remaining") return Promise.resolve(0);
-    if (cmd === "check_threshold_learning") return Promise.resolve(null);
-    if (cmd === "check_threshold_decrease") return Promise.resolve(null);
+    if (cmd === "get_threshold_suggestion") return Promise.resolve(null);
     if (cmd === "check_for_draft") return Promise.resolve(null);
     if (cmd === "get_setting") return Promise.resolve(null);
     return Promise.resolve(null);
@@ -26,9 +26,7 @@ vi.mock("@tauri-apps/api/core", () => ({
         });
       case "get_cooldown_remaining":
         return Promise.resolve(0);
-      case "check_threshold_learning":
-        return Promise.resolve(null);
-      case "check_threshold_decrease":
+      case "get_threshold_suggestion":
         return Promise.resolve(null);
       case "get_setting":
         return Promise.resolve("true"); // onboarding_completed
@@ -547,6 +547,7 @@ describe("Component-Specific Accessibility Audits", () => {
         budgetAlignmentPct: 100,
         budgetDisplay: "$50-80/hr (matches your rate)",
         budgetType: "hourly",
+        budgetCurrency: null,
         recommendation: "Strong match - apply with confidence",
       };
 
@@ -56,11 +56,12 @@ describe("CopyButton", () => {
     });
 
     // Story 3.5: Should fetch threshold first
-    expect(mockInvoke).toHaveBeenCalledWith("get_safety_threshold");
-    // Story 3.5: Should pass threshold to analyze_perplexity
+    expect(mockInvoke).toHaveBeenCalledWith("get_safety_threshold", { intensity: "medium" });
+    // Story 3.5: Should pass threshold to analyze_perplexity, with the current intensity
     expect(mockInvoke).toHaveBeenCalledWith("analyze_perplexity", {
       text: "Test proposal text",
       threshold: 180,
+      intensity: "medium",
     });
     expect(mockWriteText).toHaveBeenCalledWith("Test proposal text");
   });
@@ -76,6 +77,21 @@ describe("CopyButton", () => {
     expect(screen.getByRole("button")).toHaveTextContent("Copied!");
   });
 
+  it("records the copy for a saved proposal", async () => {
+    mockWriteText.mockResolvedValueOnce(undefined);
+    renderWithLiveAnnouncer(<CopyButton text="Test proposal" proposalId={7} />);
+
+    await act(async () => {
+      fireEvent.click(screen.getByRole("button"));
+    });
+
+    expect(mockInvoke).toHaveBeenCalledWith("record_proposal_copied", {
+      proposalId: 7,
+      copyToClipboard: false,
+    });
+    expect(mockInvoke).not.toHaveBeenCalledWith("increment_proposals_edited");
+  });
+
   it("returns to 'Copy to Clipboard' after 2 seconds", async () => {
     mockWriteText.mockResolvedValueOnce(undefined);
     renderWithLiveAnnouncer(<CopyButton text="Test proposal" />);
@@ -404,6 +420,7 @@ describe("CopyButton", () => {
     expect(mockInvoke).toHaveBeenCalledWith("analyze_perplexity", {
       text: "Test proposal",
       threshold: 180,
+      intensity: "medium",
     });
   });
 
@@ -49,17 +49,27 @@ function CopyButton({ text, getContent, disabled = false, proposalId }: CopyButt
   const prevCopiedRef = useRef(false);
 
   useEffect(() => {
-    // Only increment if:
-    // 1. Copy just succeeded (copied changed from false to true)
-    // 2. getContent is provided (indicates edited proposal, not initial generation)
-    if (copied && !prevCopiedRef.current && getContent) {
-      invoke<number>("increment_proposals_edited").catch((err) => {
+    const justCopied = copied && !prevCopiedRef.current;
+    prevCopiedRef.current = copied;
+    if (!justCopied) return;
+
+    // Saved proposal: record the copy (start of the outcome funnel), which also
+    // increments the edited count. The clipboard was already written above.
+    if (proposalId != null) {
+      invoke("record_proposal_copied", { proposalId, copyToClipboard: false }).catch((err) => {
         // Silent failure for non-critical tracking - don't block user workflow
+        console.error("Failed to record proposal copy:", err);
+      });
+      return;
+    }
+
+    // getContent is provided for edited proposals, not the initial generation
+    if (getContent) {
+      invoke<number>("increment_proposals_edited").catch((err) => {
         console.error("Failed to increment proposals edited count:", err);
       });
     }
-    prevCopiedRef.current = copied;
-  }, [copied, getContent]);
+  }, [copied, getContent, proposalId]);
 
   const handleCopy = useCallback(async () => {
     // Story 6.6: Use dynamic content if available, otherwise static text
@@ -4,6 +4,7 @@ import "./EncryptionStatusIndicator.css";
 export interface EncryptionStatus {
   databaseEncrypted: boolean;
   apiKeyInKeychain: boolean;
+  apiKeyBackend?: "keychain" | "encrypted_file" | "plaintext_config" | null;
   cipherVersion: string;
 }
 
@@ -2,6 +2,7 @@ import { invoke } from "@tauri-apps/api/core";
 import { useState, useEffect, useRef } from "react";
 
 import { useArrowKeyNavigation } from "../hooks/useArrowKeyNavigation";
+import { getErrorMessage } from "../utils/appError";
 
 import HistoryItem, { type ProposalSummary } from "./HistoryItem";
 
@@ -31,7 +32,7 @@ function HistoryList() {
         const result = await invoke<ProposalSummary[]>("get_proposals");
         setProposals(result ?? []);
       } catch (err) {
-        const message = err instanceof Error ? err.message : String(err);
+        const message = getErrorMessage(err);
         setError(message);
       } finally {
         setLoading(false);
@@ -51,6 +51,7 @@ describe("HookStrategySelector", () => {
       status: "active",
       remote_id: null,
       ab_weight: 0.2,
+      in_ab_rotation: true,
     },
     {
       id: 2,
@@ -62,6 +63,7 @@ describe("HookStrategySelector", () => {
       status: "active",
       remote_id: null,
       ab_weight: 0.2,
+      in_ab_rotation: true,
     },
     {
       id: 3,
@@ -73,6 +75,7 @@ describe("HookStrategySelector", () => {
       status: "active",
       remote_id: null,
       ab_weight: 0.2,
+      in_ab_rotation: true,
     },
     {
       id: 4,
@@ -84,6 +87,7 @@ describe("HookStrategySelector", () => {
       status: "active",
       remote_id: null,
       ab_weight: 0.2,
+      in_ab_rotation: true,
     },
     {
       id: 5,
@@ -95,6 +99,7 @@ describe("HookStrategySelector", () => {
       status: "active",
       remote_id: null,
       ab_weight: 0.2,
+      in_ab_rotation: true,
     },
   ];
 
@@ -381,6 +386,7 @@ describe("HookStrategySelector - New Badge Integration (Story 10.5)", () => {
       status: "active",
       remote_id: "remote-1",
       ab_weight: 0.2,
+      in_ab_rotation: true,
     },
     {
       id: 2,
@@ -392,6 +398,7 @@ describe("HookStrategySelector - New Badge Integration (Story 10.5)", () => {
       status: "active",
       remote_id: "remote-2",
       ab_weight: 0.2,
+      in_ab_rotation: true,
     },
   ];
 
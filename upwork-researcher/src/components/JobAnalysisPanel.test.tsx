@@ -246,6 +246,7 @@ describe("JobAnalysisPanel", () => {
               budgetAlignmentPct: 90,
               budgetDisplay: "$55/hr vs your $50/hr",
               budgetType: "hourly",
+              budgetCurrency: null,
               recommendation: "Proceed with caution.",
             });
           }
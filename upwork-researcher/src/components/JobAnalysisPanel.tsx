@@ -40,6 +40,7 @@ interface ScoringBreakdown {
   budgetAlignmentPct: number | null;
   budgetDisplay: string;
   budgetType: string;
+  budgetCurrency: string | null; // ISO code of the posted budget; null = USD assumed
   recommendation: string;
 }
 
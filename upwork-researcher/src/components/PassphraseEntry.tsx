@@ -1,5 +1,7 @@
 import { invoke } from "@tauri-apps/api/core";
 import { useState, useEffect } from "react";
+
+import { getErrorMessage } from "../utils/appError";
 import "./PassphraseEntry.css";
 
 interface PassphraseStrength {
@@ -127,7 +129,7 @@ function PassphraseEntry({ onComplete, onCancel, showRecoveryOption }: Passphras
       }
       onComplete(passphrase);
     } catch (err) {
-      setError(err as string);
+      setError(getErrorMessage(err));
     }
   };
 
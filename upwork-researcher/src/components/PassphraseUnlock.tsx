@@ -1,5 +1,7 @@
 import { invoke } from "@tauri-apps/api/core";
 import { useState, useRef, useEffect } from "react";
+
+import { getErrorMessage } from "../utils/appError";
 import "./PassphraseUnlock.css";
 
 interface VerifyPassphraseResult {
@@ -95,7 +97,7 @@ function PassphraseUnlock({ onUnlocked }: PassphraseUnlockProps) {
         inputRef.current?.focus();
       }
     } catch (err) {
-      setError(typeof err === "string" ? err : String(err));
+      setError(getErrorMessage(err));
     } finally {
       setLoading(false);
     }
@@ -2,6 +2,7 @@ import { invoke } from "@tauri-apps/api/core";
 import type { Editor } from "@tiptap/react";
 import { useState, useCallback, useEffect } from "react";
 
+import { isAppError } from "../utils/appError";
 import { getPlainTextFromEditor } from "../utils/editorUtils";
 
 import CopyButton from "./CopyButton";
@@ -107,7 +108,9 @@ function ProposalOutput({
         setDeleteError(result.message);
       }
     } catch (err) {
-      setDeleteError(err instanceof Error ? err.message : "Failed to delete proposal");
+      setDeleteError(
+        isAppError(err) || err instanceof Error ? err.message : "Failed to delete proposal",
+      );
     } finally {
       setDeleting(false);
     }
@@ -1,6 +1,7 @@
 import { invoke } from "@tauri-apps/api/core";
-import { writeText } from "@tauri-apps/plugin-clipboard-manager";
 import { useState } from "react";
+
+import { writeAppClipboard } from "../utils/clipboard";
 import "./RecoveryOptions.css";
 
 interface RecoveryOptionsProps {
@@ -49,11 +50,12 @@ function RecoveryOptions({ passphrase, onComplete, onSkip }: RecoveryOptionsProp
     window.print();
   };
 
-  // Copy recovery key to clipboard (L1 fix: use Tauri clipboard plugin)
+  // Copy recovery key to clipboard (L1 fix: Tauri clipboard plugin, marked as our own
+  // write so the clipboard watcher ignores it)
   const handleCopy = async () => {
     if (recoveryKey) {
       try {
-        await writeText(recoveryKey);
+        await writeAppClipboard(recoveryKey);
         setCopied(true);
         setTimeout(() => setCopied(false), 2000);
       } catch {
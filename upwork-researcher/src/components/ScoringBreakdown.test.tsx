@@ -31,6 +31,7 @@ describe("ScoringBreakdownCard", () => {
     budgetAlignmentPct: 90,
     budgetDisplay: "$55/hr vs your $50/hr rate",
     budgetType: "hourly",
+    budgetCurrency: null,
     recommendation: "Proceed with caution. Review client history before applying.",
   };
 
@@ -17,6 +17,7 @@ export interface ThresholdSuggestion {
   successfulOverrideCount: number;
   averageOverrideScore: number;
   direction: "increase" | "decrease" | "at_maximum";
+  triggeringOverrideIds?: number[]; // Overrides behind an "increase" suggestion
 }
 
 interface ThresholdAdjustmentNotificationProps {
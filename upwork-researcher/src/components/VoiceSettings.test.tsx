@@ -2,6 +2,7 @@ import { invoke } from "@tauri-apps/api/core";
 import { render, screen, waitFor, fireEvent } from "@testing-library/react";
 import { describe, it, expect, vi, beforeEach, afterEach } from "vitest";
 
+import { useSettingsStore } from "../stores/useSettingsStore";
 import { DEFAULT_USER_ID, VOICE_SAVE_DEBOUNCE_MS } from "../types/voice";
 
 import { VoiceSettings } from "./VoiceSettings";
@@ -29,6 +30,7 @@ const mockInvoke = invoke as ReturnType<typeof vi.fn>;
 describe("VoiceSettings", () => {
   beforeEach(() => {
     vi.clearAllMocks();
+    useSettingsStore.setState({ settings: {} });
   });
 
   afterEach(() => {
@@ -403,7 +405,7 @@ describe("VoiceSettings", () => {
   });
 
   // [AI-Review] Verify exact get_voice_profile invoke call format
-  it("calls get_voice_profile with correct userId on mount", async () => {
+  it("calls get_voice_profile with the active profile on mount", async () => {
     mockInvoke.mockResolvedValue(null);
 
     render(<VoiceSettings />);
@@ -412,12 +414,40 @@ describe("VoiceSettings", () => {
       expect(screen.queryByText(/loading/i)).not.toBeInTheDocument();
     });
 
-    // Verify exact call format with DEFAULT_USER_ID constant
+    // No active_voice_profile setting: the default profile
     expect(mockInvoke).toHaveBeenCalledWith("get_voice_profile", {
-      userId: DEFAULT_USER_ID,
+      profileName: DEFAULT_USER_ID,
     });
   });
 
+  it("loads and saves the active voice profile", async () => {
+    useSettingsStore.setState({ settings: { active_voice_profile: "Technical" } });
+    mockInvoke.mockResolvedValue(null);
+
+    render(<VoiceSettings />);
+
+    await waitFor(() => {
+      expect(screen.queryByText(/loading/i)).not.toBeInTheDocument();
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("get_voice_profile", {
+      profileName: "Technical",
+    });
+
+    fireEvent.change(screen.getByLabelText(/tone.*formal to casual/i), {
+      target: { value: "4" },
+    });
+
+    await waitFor(
+      () => {
+        expect(mockInvoke).toHaveBeenCalledWith("update_voice_parameters", {
+          userId: "Technical",
+          params: { tone_score: 4 },
+        });
+      },
+      { timeout: 1000 },
+    );
+  });
+
   // [AI-Review] Test slider step="0.5" precision through save/load cycle
   it("preserves half-point precision (step=0.5) through save/load", async () => {
     // First render with half-point values from profile
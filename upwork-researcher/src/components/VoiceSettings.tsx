@@ -8,10 +8,10 @@ import {
   VoiceLearningProgress,
   useProposalsEditedCount,
 } from "../features/voice-learning";
+import { getActiveVoiceProfile, useSettingsStore } from "../stores/useSettingsStore";
 import {
   VoiceProfile,
   VoiceParameterUpdate,
-  DEFAULT_USER_ID,
   VOICE_SAVE_DEBOUNCE_MS,
   DEFAULT_VOICE_PARAMS,
   TONE_LABELS,
@@ -24,6 +24,9 @@ import {
 // Voice parameters user can manually adjust via sliders
 
 export function VoiceSettings() {
+  // Sliders edit the profile generation uses
+  const activeProfile = useSettingsStore(getActiveVoiceProfile);
+
   // State for three manual parameters (use defaults from constants)
   const [toneScore, setToneScore] = useState(DEFAULT_VOICE_PARAMS.tone_score);
   const [lengthPreference, setLengthPreference] = useState(DEFAULT_VOICE_PARAMS.length_preference);
@@ -67,10 +70,10 @@ export function VoiceSettings() {
   // Debounce timer
   const timeoutRef = useRef<number | null>(null);
 
-  // AC: Load current voice profile on mount
+  // AC: Load current voice profile on mount (and when the active profile changes)
   useEffect(() => {
     loadProfile();
-  }, []);
+  }, [activeProfile]);
 
   // Cleanup timeout on unmount
   useEffect(() => {
@@ -84,7 +87,7 @@ export function VoiceSettings() {
   const loadProfile = async () => {
     try {
       const profile = await invoke<VoiceProfile | null>("get_voice_profile", {
-        userId: DEFAULT_USER_ID,
+        profileName: activeProfile,
       });
 
       if (profile) {
@@ -116,7 +119,7 @@ export function VoiceSettings() {
       setSaving(true);
       try {
         await invoke("update_voice_parameters", {
-          userId: DEFAULT_USER_ID,
+          userId: activeProfile,
           params: updates,
         });
         // AC: Show save indicator
@@ -11,6 +11,10 @@ export type SortField = "score" | "date" | "clientName";
 
 export type ScoreFilter = "all" | "greenOnly" | "yellowAndGreen";
 
+export type JobStatus = "active" | "stale" | "applied" | "dismissed";
+
+export type QueueStatus = "active" | "archived" | "snoozed";
+
 export interface JobQueueItem {
   id: number;
   clientName: string;
@@ -20,6 +24,12 @@ export interface JobQueueItem {
   overallScore: number | null;
   scoreColor: ScoreColor;
   createdAt: string | null;
+  /** Stale and dismissed jobs are only returned with includeInactive */
+  jobStatus?: JobStatus;
+  /** Archived and snoozed jobs are only returned with includeArchived / includeSnoozed */
+  queueStatus?: QueueStatus;
+  /** End of the snooze (UTC), while snoozed */
+  snoozedUntil?: string | null;
 }
 
 /**
@@ -17,6 +17,8 @@ interface ArchiveMetadata {
   settingsCount: number;
   voiceProfileCount: number;
   dbSizeBytes: number;
+  goldenProposalCount?: number;
+  voiceDataIncluded?: boolean;
 }
 
 interface ImportPreview {
@@ -25,6 +27,8 @@ interface ImportPreview {
   archiveVersion: number | null;
   currentVersion: number;
   warnings: string[];
+  voiceDataAvailable?: boolean;
+  localVoiceProfileExists?: boolean;
 }
 
 interface ImportProgress {
@@ -42,11 +46,13 @@ interface ImportSummary {
   settingsImported: number;
   settingsSkipped: number;
   voiceProfileImported: boolean;
+  goldenProposalsImported?: number;
   totalRecords: number;
 }
 
 type ImportStep = "select" | "metadata" | "decrypt" | "mode" | "importing" | "complete" | "error";
 type ImportMode = "replace" | "merge";
+type VoiceConflict = "keep" | "replace";
 
 interface ImportArchiveDialogProps {
   onClose: () => void;
@@ -62,6 +68,8 @@ export function ImportArchiveDialog({ onClose, onImportComplete }: ImportArchive
   const [showPassphrase, setShowPassphrase] = useState(false);
   const [mode, setMode] = useState<ImportMode>("merge");
   const [replaceConfirmed, setReplaceConfirmed] = useState(false);
+  const [importVoiceData, setImportVoiceData] = useState(false);
+  const [voiceConflict, setVoiceConflict] = useState<VoiceConflict>("keep");
   const [progress, setProgress] = useState<ImportProgress | null>(null);
   const [summary, setSummary] = useState<ImportSummary | null>(null);
   const [error, setError] = useState<string>("");
@@ -156,6 +164,8 @@ export function ImportArchiveDialog({ onClose, onImportComplete }: ImportArchive
         archivePath,
         passphrase,
         mode,
+        importVoiceData,
+        voiceConflict,
       });
 
       setSummary(result);
@@ -168,7 +178,7 @@ export function ImportArchiveDialog({ onClose, onImportComplete }: ImportArchive
     } finally {
       setIsProcessing(false);
     }
-  }, [archivePath, passphrase, mode, replaceConfirmed]);
+  }, [archivePath, passphrase, mode, replaceConfirmed, importVoiceData, voiceConflict]);
 
   const formatDate = (isoDate: string): string => {
     try {
@@ -412,6 +422,45 @@ export function ImportArchiveDialog({ onClose, onImportComplete }: ImportArchive
                 </div>
               )}
 
+              {/* Voice profile and golden proposals are only restored on request */}
+              {preview.voiceDataAvailable && (
+                <div className="voice-data-option">
+                  <label className="confirmation-checkbox">
+                    <input
+                      type="checkbox"
+                      checked={importVoiceData}
+                      onChange={(e) => setImportVoiceData(e.target.checked)}
+                    />
+                    <span>Also restore voice profile and golden proposals</span>
+                  </label>
+                  {importVoiceData && preview.localVoiceProfileExists && (
+                    <div className="voice-conflict">
+                      <p>You already have a voice profile on this device.</p>
+                      <label>
+                        <input
+                          type="radio"
+                          name="voice-conflict"
+                          value="keep"
+                          checked={voiceConflict === "keep"}
+                          onChange={() => setVoiceConflict("keep")}
+                        />
+                        Keep my current profile
+                      </label>
+                      <label>
+                        <input
+                          type="radio"
+                          name="voice-conflict"
+                          value="replace"
+                          checked={voiceConflict === "replace"}
+                          onChange={() => setVoiceConflict("replace")}
+                        />
+                        Replace it with the archived profile
+                      </label>
+                    </div>
+                  )}
+                </div>
+              )}
+
               {error && <div className="error-message">{error}</div>}
 
               <button
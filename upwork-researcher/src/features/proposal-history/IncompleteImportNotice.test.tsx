@@ -0,0 +1,59 @@
+// Tests for IncompleteImportNotice component (Story 7.7)
+
+import { render, screen, fireEvent, waitFor } from "@testing-library/react";
+import { describe, it, expect, vi, beforeEach } from "vitest";
+
+import { IncompleteImportNotice } from "./IncompleteImportNotice";
+
+const mockInvoke = vi.fn();
+vi.mock("@tauri-apps/api/core", () => ({
+  invoke: (...args: unknown[]) => mockInvoke(...args),
+}));
+
+const interruptedImport = {
+  archivePath: "/home/user/backups/proposals.urb",
+  mode: "merge",
+  startedAt: "2026-10-16T10:00:00Z",
+  stage: "inserting",
+  processed: 200,
+  total: 500,
+};
+
+describe("IncompleteImportNotice", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("fetches interrupted imports on mount and shows them", async () => {
+    mockInvoke.mockResolvedValue([interruptedImport]);
+
+    render(<IncompleteImportNotice />);
+
+    await waitFor(() => {
+      expect(screen.getByRole("alert")).toHaveTextContent("Import interrupted");
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("get_incomplete_imports");
+    expect(screen.getByText(/proposals\.urb \(merge\)/)).toHaveTextContent("(200 of 500)");
+  });
+
+  it("renders nothing when no import was interrupted", async () => {
+    mockInvoke.mockResolvedValue([]);
+
+    const { container } = render(<IncompleteImportNotice />);
+
+    await waitFor(() => expect(mockInvoke).toHaveBeenCalledWith("get_incomplete_imports"));
+    expect(container).toBeEmptyDOMElement();
+  });
+
+  it("dismisses the notice in the backend", async () => {
+    mockInvoke.mockImplementation((command: string) =>
+      Promise.resolve(command === "get_incomplete_imports" ? [interruptedImport] : null),
+    );
+
+    render(<IncompleteImportNotice />);
+    fireEvent.click(await screen.findByRole("button", { name: "Dismiss" }));
+
+    expect(screen.queryByRole("alert")).not.toBeInTheDocument();
+    expect(mockInvoke).toHaveBeenCalledWith("dismiss_incomplete_imports");
+  });
+});
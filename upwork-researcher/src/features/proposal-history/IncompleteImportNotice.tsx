@@ -0,0 +1,64 @@
+// Notice for archive imports interrupted before they committed (Story 7.7)
+// The backend finds them at startup, before any listener exists, so they are
+// fetched on mount rather than received as an event.
+
+import { invoke } from "@tauri-apps/api/core";
+import { useCallback, useEffect, useState } from "react";
+
+import "./IncompleteImportNotice.css";
+
+/** Journal of an interrupted import (matches Rust archive_import::ImportJournal) */
+export interface IncompleteImport {
+  archivePath: string;
+  mode: "replace" | "merge";
+  startedAt: string;
+  stage: string;
+  processed: number;
+  total: number;
+}
+
+function archiveName(path: string): string {
+  return path.split(/[\\/]/).pop() || path;
+}
+
+export function IncompleteImportNotice() {
+  const [imports, setImports] = useState<IncompleteImport[]>([]);
+
+  useEffect(() => {
+    invoke<IncompleteImport[]>("get_incomplete_imports")
+      .then((journals) => setImports(journals ?? []))
+      .catch(() => {
+        // Non-blocking — the notice is informational
+      });
+  }, []);
+
+  const handleDismiss = useCallback(() => {
+    setImports([]);
+    invoke("dismiss_incomplete_imports").catch(() => {});
+  }, []);
+
+  if (imports.length === 0) {
+    return null;
+  }
+
+  return (
+    <div className="incomplete-import-notice" role="alert">
+      <strong>Import interrupted</strong>
+      <ul className="incomplete-import-notice__list">
+        {imports.map((journal) => (
+          <li key={`${journal.archivePath}-${journal.startedAt}`}>
+            {archiveName(journal.archivePath)} ({journal.mode}) stopped at the {journal.stage}{" "}
+            stage{journal.total > 0 && ` (${journal.processed} of ${journal.total})`}.
+          </li>
+        ))}
+      </ul>
+      <p className="incomplete-import-notice__hint">
+        Nothing from {imports.length === 1 ? "this import" : "these imports"} was applied. Run
+        the import again from Settings to restore the archive.
+      </p>
+      <button type="button" className="incomplete-import-notice__dismiss" onClick={handleDismiss}>
+        Dismiss
+      </button>
+    </div>
+  );
+}
@@ -35,6 +35,9 @@ const mockSummary = {
   responseRate: 22.9,
   bestStrategy: "social_proof",
   bestStrategyRate: 40.0,
+  copiedProposals: 30,
+  copiedPositive: 8,
+  copyConversionRate: 26.7,
 };
 
 const emptySummary = {
@@ -45,6 +48,9 @@ const emptySummary = {
   responseRate: 0,
   bestStrategy: null,
   bestStrategyRate: 0,
+  copiedProposals: 0,
+  copiedPositive: 0,
+  copyConversionRate: 0,
 };
 
 describe("ProposalAnalyticsDashboard", () => {
@@ -80,6 +86,8 @@ describe("ProposalAnalyticsDashboard", () => {
     expect(screen.getByText("Social Proof")).toBeTruthy();
     expect(screen.getByText("40.0%")).toBeTruthy();
     expect(screen.getByText("12")).toBeTruthy();
+    expect(screen.getByText("30")).toBeTruthy();
+    expect(screen.getByText("26.7% converted")).toBeTruthy();
   });
 
   it("renders empty state when totalProposals is 0", async () => {
@@ -102,7 +110,7 @@ describe("ProposalAnalyticsDashboard", () => {
     renderDashboard();
 
     const skeletons = document.querySelectorAll(".metric-card-skeleton");
-    expect(skeletons.length).toBe(4);
+    expect(skeletons.length).toBe(5);
   });
 
   it("renders error state on failure", async () => {
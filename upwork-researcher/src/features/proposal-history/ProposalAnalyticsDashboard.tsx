@@ -33,6 +33,7 @@ export function ProposalAnalyticsDashboard({ onBack }: ProposalAnalyticsDashboar
           <div className="metric-card-skeleton" />
           <div className="metric-card-skeleton" />
           <div className="metric-card-skeleton" />
+          <div className="metric-card-skeleton" />
         </div>
         <div className="charts-skeleton">
           <div className="chart-skeleton" />
@@ -89,6 +90,16 @@ export function ProposalAnalyticsDashboard({ onBack }: ProposalAnalyticsDashboar
           <div className="metric-label">This Month</div>
           <div className="metric-value">{summary.proposalsThisMonth}</div>
         </div>
+
+        <div className="metric-card">
+          <div className="metric-label">Copied</div>
+          <div className="metric-value">{summary.copiedProposals}</div>
+          {summary.copiedProposals > 0 && (
+            <div className="metric-subtitle">
+              {summary.copyConversionRate.toFixed(1)}% converted
+            </div>
+          )}
+        </div>
       </div>
 
       {/* Charts Grid */}
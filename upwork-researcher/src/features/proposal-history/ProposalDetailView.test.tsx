@@ -1,4 +1,5 @@
 import { QueryClient, QueryClientProvider } from "@tanstack/react-query";
+import { writeText } from "@tauri-apps/plugin-clipboard-manager";
 import { render, screen, fireEvent, waitFor, act } from "@testing-library/react";
 import { describe, it, expect, vi, beforeEach } from "vitest";
 
@@ -11,6 +12,7 @@ vi.mock("@tauri-apps/api/core", () => ({
 import { invoke } from "@tauri-apps/api/core";
 
 const mockInvoke = vi.mocked(invoke);
+const mockWriteText = vi.mocked(writeText);
 
 const mockProposal = {
   id: 1,
@@ -138,9 +140,6 @@ describe("ProposalDetailView", () => {
   });
 
   it("copies proposal text and shows Copied indicator (AC-3)", async () => {
-    const writeText = vi.fn().mockResolvedValue(undefined);
-    Object.assign(navigator, { clipboard: { writeText } });
-
     render(<ProposalDetailView proposalId={1} onBack={onBack} />, {
       wrapper: createWrapper(),
     });
@@ -155,7 +154,13 @@ describe("ProposalDetailView", () => {
       expect(screen.getByText("Copied!")).toBeInTheDocument();
     });
 
-    expect(writeText).toHaveBeenCalledWith(mockProposal.generatedText);
+    // Marked as the app's own write, then recorded as a copy
+    expect(mockInvoke).toHaveBeenCalledWith("mark_clipboard_write");
+    expect(mockWriteText).toHaveBeenCalledWith(mockProposal.generatedText);
+    expect(mockInvoke).toHaveBeenCalledWith("record_proposal_copied", {
+      proposalId: 1,
+      copyToClipboard: false,
+    });
   });
 
   it("expands original job content on click", async () => {
@@ -6,6 +6,7 @@ import { format } from "date-fns";
 import { useState, useCallback, useEffect, useRef } from "react";
 
 import DeleteConfirmDialog from "../../components/DeleteConfirmDialog";
+import { writeAppClipboard } from "../../utils/clipboard";
 
 import { OutcomeDropdown, formatLabel } from "./OutcomeDropdown";
 import { useProposalDetail } from "./useProposalDetail";
@@ -130,18 +131,24 @@ export function ProposalDetailView({ proposalId, onBack }: ProposalDetailViewPro
   );
 
   // AC-3: Copy proposal text
-  // L-2 CR note: Uses direct clipboard API instead of useSafeCopy — safety checks
-  // already ran at generation time. Detail view is for reviewing past proposals.
+  // L-2 CR note: Skips useSafeCopy — safety checks already ran at generation time.
+  // Detail view is for reviewing past proposals. The copy is recorded like any other
+  // (start of the outcome funnel).
   const handleCopy = useCallback(async () => {
     if (!proposal) return;
     try {
-      await navigator.clipboard.writeText(proposal.generatedText);
+      await writeAppClipboard(proposal.generatedText);
       setCopyLabel("Copied!");
       setTimeout(() => setCopyLabel("Copy Proposal"), 2000);
     } catch {
       setToast({ type: "error", message: "Failed to copy to clipboard" });
+      return;
     }
-  }, [proposal]);
+    invoke("record_proposal_copied", { proposalId, copyToClipboard: false }).catch((err) => {
+      // Non-critical tracking - the text is already on the clipboard
+      console.error("Failed to record proposal copy:", err);
+    });
+  }, [proposal, proposalId]);
 
   // AC-6: Delete proposal
   const handleDeleteConfirm = useCallback(async () => {
@@ -29,6 +29,10 @@ const mockData: StrategyEffectivenessData[] = [
     won: 4,
     responseRate: 0.4,
     avgScore: 1.8,
+    corrected: 0,
+    sampleSize: 10,
+    pValue: null,
+    significance: "baseline",
   },
   {
     hookStrategyId: "Social Proof",
@@ -37,6 +41,10 @@ const mockData: StrategyEffectivenessData[] = [
     won: 2,
     responseRate: 0.4,
     avgScore: 2.0,
+    corrected: 0,
+    sampleSize: 5,
+    pValue: null,
+    significance: "insufficient_data",
   },
   {
     hookStrategyId: "Contrarian",
@@ -45,6 +53,10 @@ const mockData: StrategyEffectivenessData[] = [
     won: 1,
     responseRate: 0.125,
     avgScore: 0.5,
+    corrected: 0,
+    sampleSize: 8,
+    pValue: null,
+    significance: "insufficient_data",
   },
 ];
 
@@ -90,6 +102,14 @@ describe("StrategyEffectivenessTable", () => {
     expect(await screen.findByText("12.5%")).toBeInTheDocument();
   });
 
+  it("shows significance labels and flags small samples", async () => {
+    mockInvoke.mockResolvedValue(mockData);
+    render(<StrategyEffectivenessTable />, { wrapper });
+
+    expect(await screen.findByText("Baseline")).toBeInTheDocument();
+    expect(screen.getAllByText("Insufficient data")).toHaveLength(2);
+  });
+
   // Task 4.12.4: Empty state
   it("shows empty state when no data", async () => {
     mockInvoke.mockResolvedValue([]);
@@ -2,7 +2,7 @@
 import { useQuery } from "@tanstack/react-query";
 import { invoke } from "@tauri-apps/api/core";
 
-import type { StrategyEffectivenessData } from "../../types/analytics";
+import type { StrategyEffectivenessData, StrategySignificance } from "../../types/analytics";
 
 /** React Query hook for strategy effectiveness data (Story 10.4: Task 4.6) */
 export function useStrategyEffectiveness() {
@@ -21,11 +21,19 @@ function responseRateColor(rate: number): string {
   return "var(--color-error, #f87171)";
 }
 
+const SIGNIFICANCE_LABELS: Record<StrategySignificance, string> = {
+  baseline: "Baseline",
+  significant: "Significant",
+  not_significant: "Not significant",
+  insufficient_data: "Insufficient data",
+};
+
 /**
  * Strategy Effectiveness Table (Story 10.4: AC-4)
  *
  * Displays each hook strategy's A/B vs manual proposal outcomes:
- * total sent, won count, response rate %, and average score.
+ * total sent, won count, response rate %, average score, and whether the
+ * response rate differs significantly from the best strategy.
  * Rows are ranked by response rate descending (SQL already sorted).
  */
 export function StrategyEffectivenessTable() {
@@ -72,6 +80,7 @@ export function StrategyEffectivenessTable() {
             <th scope="col">Lost</th>
             <th scope="col">Response Rate</th>
             <th scope="col">Avg Score</th>
+            <th scope="col">Significance</th>
           </tr>
         </thead>
         <tbody>
@@ -96,6 +105,14 @@ export function StrategyEffectivenessTable() {
                   {ratePct}%
                 </td>
                 <td>{row.avgScore.toFixed(2)}</td>
+                <td
+                  className={`significance significance--${row.significance}`}
+                  title={`${row.sampleSize} outcomes recorded${
+                    row.pValue !== null ? `, p = ${row.pValue.toFixed(3)}` : ""
+                  }`}
+                >
+                  {SIGNIFICANCE_LABELS[row.significance]}
+                </td>
               </tr>
             );
           })}
@@ -8,6 +8,7 @@ export { ProposalAnalyticsDashboard } from "./ProposalAnalyticsDashboard";
 export { SearchFilterBar } from "./SearchFilterBar";
 export { OutcomeDropdown, formatLabel } from "./OutcomeDropdown";
 export { DatabaseExportButton } from "./DatabaseExportButton";
+export { IncompleteImportNotice } from "./IncompleteImportNotice";
 export { useProposalHistory } from "./useProposalHistory";
 export { useProposalDetail } from "./useProposalDetail";
 export {
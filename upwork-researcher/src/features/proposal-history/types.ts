@@ -8,12 +8,17 @@ export interface ProposalListItem {
   createdAt: string; // ISO timestamp
   outcomeStatus: OutcomeStatus; // Story 7.1: pending/submitted/hired/etc.
   hookStrategyId: string | null; // Story 7.1: Hook strategy name or null
+  perplexityScore?: number | null; // Cached by analyze_stored_proposal_perplexity; null after edits
+  perplexityFlaggedCount?: number | null;
+  jobPostId?: number | null; // Originating job post; null if unknown or deleted
+  isFavorite?: boolean; // Pinned via toggle_proposal_favorite
 }
 
 export interface ProposalHistoryResponse {
   proposals: ProposalListItem[];
   totalCount: number;
   hasMore: boolean;
+  nextBeforeId?: number | null; // Keyset cursor for get_proposal_history's beforeId
 }
 
 // Story 7.4: Full proposal detail for detail view
@@ -29,9 +34,28 @@ export interface ProposalDetail {
   hookStrategyId: string | null;
   jobPostId: number | null;
   jobTitle: string | null;
+  // Joined from the linked job post (null when there is no link)
+  jobClientName?: string | null;
+  jobOverallScore?: number | null;
+  jobBudgetType?: string | null;
   revisionCount: number;
 }
 
+// How a proposal was generated (get_generation_metadata).
+// recorded=false: saved before metadata was recorded, string fields are "unknown".
+export interface GenerationMetadata {
+  proposalId: number;
+  recorded: boolean;
+  model: string;
+  humanizationIntensity: string;
+  hookStrategyId: string; // strategy key or "none"
+  voiceProfileVersion: string; // voice profile updated_at or "default"
+  voiceProfileName: string | null; // voice profile used; null with the default voice
+  targetWords: number | null;
+  apiKeyProfile: string | null; // API key profile used; null when not recorded
+  recordedAt: string | null;
+}
+
 // =========================================================================
 // Story 7.5: Analytics Dashboard Types
 // =========================================================================
@@ -44,6 +68,9 @@ export interface AnalyticsSummary {
   responseRate: number; // Computed: positive / resolved * 100
   bestStrategy: string | null; // hook_strategy_id with highest rate
   bestStrategyRate: number; // response rate of best strategy
+  copiedProposals: number; // proposals with copied_at set (funnel start)
+  copiedPositive: number; // copied proposals with a positive outcome
+  copyConversionRate: number; // Computed: copiedPositive / copiedProposals * 100
 }
 
 export interface OutcomeCount {
@@ -64,4 +91,40 @@ export interface WeeklyActivity {
   proposalCount: number;
   positiveCount: number;
   responseRate: number; // Computed: positive / total * 100
+  copiedCount: number; // proposals from this week that were copied
+  copyConversionRate: number; // Computed: copied positive / copiedCount * 100
+}
+
+// Weekly digest (generate_weekly_digest); all timestamps are UTC "YYYY-MM-DD HH:MM:SS"
+export interface ScoreDistribution {
+  green: number;
+  yellow: number;
+  red: number;
+  gray: number;
+  unscored: number;
+}
+
+export interface WeeklyDigest {
+  weekLabel: string; // ISO week, e.g. "2026-W41"
+  weekStart: string; // Monday 00:00:00 UTC
+  weekEnd: string; // following Monday, exclusive
+  complete: boolean; // false for the current week
+  generatedAt: string;
+  proposalsGenerated: number;
+  proposalsCopied: number;
+  outcomesRecorded: number;
+  positiveOutcomes: number;
+  responseRate: number;
+  priorWeekResponseRate: number;
+  responseRateChange: number; // percentage points
+  topStrategy: StrategyPerformance | null;
+  jobsImported: number;
+  jobScores: ScoreDistribution;
+  safetyOverrides: number;
+}
+
+export interface DigestResult {
+  digest: WeeklyDigest;
+  markdown: string;
+  cached: boolean; // served from the digests table
 }
@@ -36,6 +36,17 @@ export interface CalibrationResult {
   profile: VoiceProfile;
   elapsed_ms: number;
   proposals_analyzed: number;
+  quality?: CalibrationQuality;
+}
+
+/** Calibration confidence and warnings (low sample count, inconsistent samples) */
+export interface CalibrationQuality {
+  confidence_score: number; // 0-100
+  confidence_level: "low" | "medium" | "high";
+  sample_count: number;
+  recommended_min_samples: number;
+  variance: number; // 0.0-1.0
+  warnings: string[];
 }
 
 export interface MappedMetric {
@@ -51,6 +62,14 @@ export interface VoiceLearningProgress {
   progressPercent: number; // 0-100
 }
 
+/** Result of `should_suggest_recalibration` (threshold is the voice_learning_threshold setting) */
+export interface RecalibrationSuggestion {
+  shouldSuggest: boolean;
+  editedCount: number;
+  threshold: number;
+  editsRemaining: number;
+}
+
 /** Calculate voice learning status based on proposal count */
 export function getVoiceLearningStatus(count: number): VoiceLearningProgress {
   if (count === 0) {
@@ -3,6 +3,7 @@ import { writeText } from "@tauri-apps/plugin-clipboard-manager";
 import { renderHook, act, waitFor } from "@testing-library/react";
 import { describe, it, expect, vi, beforeEach, afterEach } from "vitest";
 
+import { useSettingsStore } from "../../stores/useSettingsStore";
 import { useSafeCopy } from "../useSafeCopy";
 
 vi.mock("@tauri-apps/plugin-clipboard-manager", () => ({
@@ -20,6 +21,7 @@ describe("useSafeCopy", () => {
   beforeEach(() => {
     vi.clearAllMocks();
     vi.useFakeTimers();
+    useSettingsStore.setState({ settings: {} });
 
     // Default: safe perplexity score (below threshold)
     mockInvoke.mockImplementation((command: string) => {
@@ -51,10 +53,11 @@ describe("useSafeCopy", () => {
         await result.current.actions.triggerCopy("Test proposal");
       });
 
-      expect(mockInvoke).toHaveBeenCalledWith("get_safety_threshold");
+      expect(mockInvoke).toHaveBeenCalledWith("get_safety_threshold", { intensity: "medium" });
       expect(mockInvoke).toHaveBeenCalledWith("analyze_perplexity", {
         text: "Test proposal",
         threshold: 180,
+        intensity: "medium",
       });
       expect(mockWriteText).toHaveBeenCalledWith("Test proposal");
       expect(result.current.state.copied).toBe(true);
@@ -164,6 +167,23 @@ describe("useSafeCopy", () => {
       expect(mockInvoke).toHaveBeenCalledWith("analyze_perplexity", {
         text: "Test proposal",
         threshold: 180,
+        intensity: "medium",
+      });
+    });
+
+    it("uses the threshold for the current humanization intensity", async () => {
+      useSettingsStore.setState({ settings: { humanization_intensity: "heavy" } });
+      const { result } = renderHook(() => useSafeCopy());
+
+      await act(async () => {
+        await result.current.actions.triggerCopy("Test proposal");
+      });
+
+      expect(mockInvoke).toHaveBeenCalledWith("get_safety_threshold", { intensity: "heavy" });
+      expect(mockInvoke).toHaveBeenCalledWith("analyze_perplexity", {
+        text: "Test proposal",
+        threshold: 180,
+        intensity: "heavy",
       });
     });
 
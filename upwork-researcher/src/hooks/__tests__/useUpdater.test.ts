@@ -2,29 +2,73 @@
  * Tests for useUpdater hook (Story 9.6 Task 6)
  */
 
-import type { Update } from '@tauri-apps/plugin-updater';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { act, renderHook, waitFor } from '@testing-library/react';
 import { describe, it, expect, vi, beforeAll, beforeEach, afterEach } from 'vitest';
 
-import { useUpdater } from '../useUpdater';
+import { useUpdater, type DownloadProgress } from '../useUpdater';
 
 
-// Mock the Tauri plugins
-vi.mock('@tauri-apps/plugin-updater', () => ({
-  check: vi.fn(),
-}));
-
 vi.mock('@tauri-apps/plugin-process', () => ({
   relaunch: vi.fn(),
 }));
 
 // Import mocked modules for assertions
-import { check } from '@tauri-apps/plugin-updater';
 import { relaunch } from '@tauri-apps/plugin-process';
 
-const mockCheck = vi.mocked(check);
+const mockInvoke = vi.mocked(invoke);
+const mockListen = vi.mocked(listen);
 const mockRelaunch = vi.mocked(relaunch);
 
+/** A release on the update channel's feed; `install` stands in for the download */
+interface FeedRelease {
+  version: string;
+  currentVersion: string;
+  body?: string;
+  date?: string;
+  critical?: boolean;
+  install?: (emit: (progress: DownloadProgress) => void) => unknown;
+}
+
+// The channel feed: resolves to the release it offers, or null when up to date.
+// check_for_update and prepare_and_apply_update are served from it below.
+const mockCheck = vi.fn<() => Promise<FeedRelease | null>>();
+
+let offered: FeedRelease | null = null;
+let progressListener: ((event: { payload: DownloadProgress }) => void) | null = null;
+
+beforeEach(() => {
+  offered = null;
+  progressListener = null;
+  mockInvoke.mockImplementation(async (command: string) => {
+    if (command === 'check_for_update') {
+      offered = await mockCheck();
+      return {
+        channel: 'stable',
+        feed: 'stable',
+        currentVersion: offered?.currentVersion ?? '1.0.0',
+        latestVersion: offered?.version ?? null,
+        status: offered ? 'available' : 'upToDate',
+        message: '',
+        body: offered?.body ?? null,
+        date: offered?.date ?? null,
+        critical: offered?.critical ?? false,
+      };
+    }
+    if (command === 'prepare_and_apply_update') {
+      return offered?.install?.((progress) => progressListener?.({ payload: progress }));
+    }
+    return null;
+  });
+  mockListen.mockImplementation(async (event, handler) => {
+    if (event === 'update:download-progress') {
+      progressListener = handler as typeof progressListener;
+    }
+    return () => {};
+  });
+});
+
 describe('useUpdater', () => {
   beforeEach(() => {
     vi.clearAllMocks();
@@ -38,18 +82,18 @@ describe('useUpdater', () => {
 
   describe('checkForUpdate', () => {
     it('should return update info when update is available', async () => {
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'New features and bug fixes',
         date: '2026-02-15T10:00:00Z',
-        downloadAndInstall: vi.fn(),
+        install: vi.fn(),
       };
 
       // Background check on mount (will be called first)
       mockCheck.mockResolvedValueOnce(null);
       // Manual check call (will be called second)
-      mockCheck.mockResolvedValueOnce(mockUpdate as Update);
+      mockCheck.mockResolvedValueOnce(mockUpdate);
 
       const { result } = renderHook(() => useUpdater());
 
@@ -126,7 +170,38 @@ describe('useUpdater', () => {
       });
 
       expect(updateInfo!).toBeNull();
-      expect(result.current.error).toBe('Failed to check for updates');
+      // Backend commands reject with their error message as a string
+      expect(result.current.error).toBe('String error');
+    });
+
+    it('should not offer an update when ahead of the channel', async () => {
+      mockInvoke.mockImplementation(async (command: string) =>
+        command === 'check_for_update'
+          ? {
+              channel: 'stable',
+              feed: 'stable',
+              currentVersion: '1.3.0-beta.2',
+              latestVersion: '1.2.0',
+              status: 'aheadOfChannel',
+              message: 'You will stay on this version until a newer stable release is out.',
+              body: null,
+              date: null,
+              critical: false,
+            }
+          : null
+      );
+
+      const { result } = renderHook(() => useUpdater({ autoCheckEnabled: false }));
+
+      let updateInfo: Awaited<ReturnType<typeof result.current.checkForUpdate>>;
+      await act(async () => {
+        updateInfo = await result.current.checkForUpdate();
+      });
+
+      // Never offered as a downgrade
+      expect(updateInfo!).toBeNull();
+      expect(result.current.updateAvailable).toBe(false);
+      expect(result.current.error).toBeNull();
     });
   });
 
@@ -145,18 +220,18 @@ describe('useUpdater', () => {
         }
       );
 
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Update',
         date: '2026-02-15',
-        downloadAndInstall: mockDownloadAndInstall,
+        install: mockDownloadAndInstall,
       };
 
       // Background check
       mockCheck.mockResolvedValueOnce(null);
       // Manual check
-      mockCheck.mockResolvedValueOnce(mockUpdate as Update);
+      mockCheck.mockResolvedValueOnce(mockUpdate);
 
       const { result } = renderHook(() => useUpdater());
 
@@ -177,6 +252,8 @@ describe('useUpdater', () => {
       await result.current.downloadAndInstall(onProgress);
 
       expect(mockDownloadAndInstall).toHaveBeenCalledTimes(1);
+      // Installed by the backend from the same channel feed, pinned to the offered version
+      expect(mockInvoke).toHaveBeenCalledWith('prepare_and_apply_update', { version: '1.2.0' });
       expect(progressEvents).toHaveLength(3);
       expect(progressEvents[0]).toEqual({
         event: 'Started',
@@ -211,18 +288,18 @@ describe('useUpdater', () => {
         .fn()
         .mockRejectedValueOnce(new Error('Download failed'));
 
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Update',
         date: '2026-02-15',
-        downloadAndInstall: mockDownloadAndInstall,
+        install: mockDownloadAndInstall,
       };
 
       // Background check
       mockCheck.mockResolvedValueOnce(null);
       // Manual check
-      mockCheck.mockResolvedValueOnce(mockUpdate as Update);
+      mockCheck.mockResolvedValueOnce(mockUpdate);
 
       const { result } = renderHook(() => useUpdater());
 
@@ -326,18 +403,18 @@ describe('useUpdater', () => {
     it('should pass progress callback to downloadAndInstall for resume support', async () => {
       const mockDownloadAndInstall = vi.fn();
 
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Update',
         date: '2026-02-15',
-        downloadAndInstall: mockDownloadAndInstall,
+        install: mockDownloadAndInstall,
       };
 
       // Background check
       mockCheck.mockResolvedValueOnce(null);
       // Manual check
-      mockCheck.mockResolvedValueOnce(mockUpdate as Update);
+      mockCheck.mockResolvedValueOnce(mockUpdate);
 
       const { result } = renderHook(() => useUpdater());
 
@@ -352,7 +429,7 @@ describe('useUpdater', () => {
       const onProgress = vi.fn();
       await result.current.downloadAndInstall(onProgress);
 
-      // Verify callback was passed to plugin (plugin handles resume internally)
+      // Verify progress is wired to the install (updater handles resume internally)
       expect(mockDownloadAndInstall).toHaveBeenCalledWith(expect.any(Function));
     });
   });
@@ -361,15 +438,15 @@ describe('useUpdater', () => {
 describe('Story 9.7: UI notification extensions', () => {
   describe('updateAvailable flag (Task 1.1)', () => {
     it('should expose updateAvailable=true when background check finds update', async () => {
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Update',
         date: '2026-02-15',
-        downloadAndInstall: vi.fn(),
+        install: vi.fn(),
       };
 
-      mockCheck.mockResolvedValueOnce(mockUpdate as Update);
+      mockCheck.mockResolvedValueOnce(mockUpdate);
 
       const { result } = renderHook(() => useUpdater());
 
@@ -391,15 +468,15 @@ describe('Story 9.7: UI notification extensions', () => {
 
   describe('updateInfo exposure (Task 1.2)', () => {
     it('should expose updateInfo when update is available', async () => {
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'New features',
         date: '2026-02-15T10:00:00Z',
-        downloadAndInstall: vi.fn(),
+        install: vi.fn(),
       };
 
-      mockCheck.mockResolvedValueOnce(mockUpdate as Update);
+      mockCheck.mockResolvedValueOnce(mockUpdate);
 
       const { result } = renderHook(() => useUpdater());
 
@@ -453,15 +530,15 @@ describe('Story 9.7: UI notification extensions', () => {
         await new Promise((resolve) => setTimeout(resolve, 5000));
       });
 
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Update',
         date: '2026-02-15',
-        downloadAndInstall: mockDownloadAndInstall,
+        install: mockDownloadAndInstall,
       };
 
-      mockCheck.mockResolvedValue(mockUpdate as Update);
+      mockCheck.mockResolvedValue(mockUpdate);
 
       const { result } = renderHook(() => useUpdater({ autoCheckEnabled: false }));
 
@@ -504,15 +581,15 @@ describe('Story 9.7: UI notification extensions', () => {
         }
       );
 
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Update',
         date: '2026-02-15',
-        downloadAndInstall: mockDownloadAndInstall,
+        install: mockDownloadAndInstall,
       };
 
-      mockCheck.mockResolvedValue(mockUpdate as Update);
+      mockCheck.mockResolvedValue(mockUpdate);
 
       const { result } = renderHook(() => useUpdater({ autoCheckEnabled: false }));
 
@@ -541,15 +618,15 @@ describe('Story 9.7: UI notification extensions', () => {
         }
       );
 
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Update',
         date: '2026-02-15',
-        downloadAndInstall: mockDownloadAndInstall,
+        install: mockDownloadAndInstall,
       };
 
-      mockCheck.mockResolvedValue(mockUpdate as Update);
+      mockCheck.mockResolvedValue(mockUpdate);
 
       const { result } = renderHook(() => useUpdater({ autoCheckEnabled: false }));
 
@@ -587,15 +664,15 @@ describe('Story 9.7: UI notification extensions', () => {
         }
       );
 
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Update',
         date: '2026-02-15',
-        downloadAndInstall: mockDownloadAndInstall,
+        install: mockDownloadAndInstall,
       };
 
-      mockCheck.mockResolvedValue(mockUpdate as Update);
+      mockCheck.mockResolvedValue(mockUpdate);
 
       const { result } = renderHook(() => useUpdater({ autoCheckEnabled: false }));
 
@@ -693,15 +770,15 @@ describe('Story 9.7: UI notification extensions', () => {
 
   describe('skippedVersion param (Task 1.9)', () => {
     it('should suppress updateAvailable when version matches skippedVersion', async () => {
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Update',
         date: '2026-02-15',
-        downloadAndInstall: vi.fn(),
+        install: vi.fn(),
       };
 
-      mockCheck.mockResolvedValue(mockUpdate as Update);
+      mockCheck.mockResolvedValue(mockUpdate);
 
       const { result } = renderHook(() =>
         useUpdater({ skippedVersion: '1.2.0' })
@@ -715,15 +792,15 @@ describe('Story 9.7: UI notification extensions', () => {
     });
 
     it('should not suppress updateAvailable when version differs from skippedVersion', async () => {
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.3.0',
         currentVersion: '1.0.0',
         body: 'Update',
         date: '2026-02-15',
-        downloadAndInstall: vi.fn(),
+        install: vi.fn(),
       };
 
-      mockCheck.mockResolvedValue(mockUpdate as Update);
+      mockCheck.mockResolvedValue(mockUpdate);
 
       const { result } = renderHook(() =>
         useUpdater({ skippedVersion: '1.2.0' })
@@ -742,16 +819,16 @@ describe('Story 9.7: UI notification extensions', () => {
 describe('Story 9.8: Critical update detection (Task 2)', () => {
   describe('isCritical field in UpdateInfo (Task 2.2, 2.3)', () => {
     it('should parse critical: true from update manifest', async () => {
-      const mockUpdate: Partial<Update> & { critical?: boolean } = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'CRITICAL: AI detection fix',
         date: '2026-02-16T12:00:00Z',
-        downloadAndInstall: vi.fn(),
+        install: vi.fn(),
         critical: true,
       };
 
-      mockCheck.mockResolvedValue(mockUpdate as Update);
+      mockCheck.mockResolvedValue(mockUpdate);
 
       const { result } = renderHook(() => useUpdater({ autoCheckEnabled: false }));
 
@@ -764,17 +841,17 @@ describe('Story 9.8: Critical update detection (Task 2)', () => {
     });
 
     it('should parse critical: false from update manifest', async () => {
-      const mockUpdate: Partial<Update> & { critical?: boolean} = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Optional feature update',
         date: '2026-02-16T12:00:00Z',
-        downloadAndInstall: vi.fn(),
+        install: vi.fn(),
         critical: false,
       };
 
       // Background check returns non-critical update
-      mockCheck.mockResolvedValue(mockUpdate as Update);
+      mockCheck.mockResolvedValue(mockUpdate);
 
       const { result } = renderHook(() => useUpdater());
 
@@ -790,17 +867,17 @@ describe('Story 9.8: Critical update detection (Task 2)', () => {
     });
 
     it('should default to false if critical field missing', async () => {
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Regular update',
         date: '2026-02-16T12:00:00Z',
-        downloadAndInstall: vi.fn(),
+        install: vi.fn(),
         // critical field intentionally omitted
       };
 
       // Background check and manual check return update without critical field
-      mockCheck.mockResolvedValue(mockUpdate as Update);
+      mockCheck.mockResolvedValue(mockUpdate);
 
       const { result } = renderHook(() => useUpdater());
 
@@ -818,17 +895,17 @@ describe('Story 9.8: Critical update detection (Task 2)', () => {
 
   describe('pendingCriticalUpdate state (Task 2.4, 2.5)', () => {
     it('should expose pendingCriticalUpdate=true when critical update found', async () => {
-      const mockUpdate: Partial<Update> & { critical?: boolean } = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'CRITICAL: Security fix',
         date: '2026-02-16T12:00:00Z',
-        downloadAndInstall: vi.fn(),
+        install: vi.fn(),
         critical: true,
       };
 
       // Background check finds critical update
-      mockCheck.mockResolvedValue(mockUpdate as Update);
+      mockCheck.mockResolvedValue(mockUpdate);
 
       const { result } = renderHook(() => useUpdater());
 
@@ -838,16 +915,16 @@ describe('Story 9.8: Critical update detection (Task 2)', () => {
     });
 
     it('should expose pendingCriticalUpdate=false when non-critical update found', async () => {
-      const mockUpdate: Partial<Update> & { critical?: boolean } = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Optional update',
         date: '2026-02-16T12:00:00Z',
-        downloadAndInstall: vi.fn(),
+        install: vi.fn(),
         critical: false,
       };
 
-      mockCheck.mockResolvedValueOnce(mockUpdate as Update);
+      mockCheck.mockResolvedValueOnce(mockUpdate);
 
       const { result } = renderHook(() => useUpdater());
 
@@ -874,16 +951,16 @@ describe('Story 9.8: Critical update detection (Task 2)', () => {
         .mockRejectedValueOnce(new Error('Network error'))
         .mockResolvedValueOnce(undefined);
 
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Update',
         date: '2026-02-16',
-        downloadAndInstall: mockDownloadAndInstall,
+        install: mockDownloadAndInstall,
       };
 
       // Background check and manual check return update
-      mockCheck.mockResolvedValue(mockUpdate as Update);
+      mockCheck.mockResolvedValue(mockUpdate);
 
       const { result } = renderHook(() => useUpdater());
 
@@ -918,16 +995,16 @@ describe('Story 9.8: Critical update detection (Task 2)', () => {
         .mockRejectedValueOnce(new Error('First error'))
         .mockResolvedValueOnce(undefined);
 
-      const mockUpdate: Partial<Update> = {
+      const mockUpdate: FeedRelease = {
         version: '1.2.0',
         currentVersion: '1.0.0',
         body: 'Update',
         date: '2026-02-16',
-        downloadAndInstall: mockDownloadAndInstall,
+        install: mockDownloadAndInstall,
       };
 
       // Background check and manual check return update
-      mockCheck.mockResolvedValue(mockUpdate as Update);
+      mockCheck.mockResolvedValue(mockUpdate);
 
       const { result } = renderHook(() => useUpdater());
 
@@ -62,6 +62,7 @@ describe("useRehumanization", () => {
     expect(mockInvoke).toHaveBeenCalledWith("analyze_perplexity", {
       text: "Regenerated proposal",
       threshold: 180,
+      intensity: "heavy",
     });
 
     // Score < 180 → onSuccess called, attempts reset
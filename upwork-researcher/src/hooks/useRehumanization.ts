@@ -15,6 +15,7 @@ import { useState, useCallback, useEffect, useRef } from "react";
 import type { HumanizationIntensity } from "../stores/useSettingsStore";
 import type { PerplexityAnalysis } from "../types/perplexity";
 import { DEFAULT_PERPLEXITY_THRESHOLD } from "../types/perplexity";
+import { getErrorMessage } from "../utils/appError";
 
 interface RegenerationResult {
   generated_text: string;
@@ -99,13 +100,15 @@ export function useRehumanization(
       setEffectiveIntensity(result.new_intensity as HumanizationIntensity);
 
       // Analyze perplexity of regenerated text
+      // Judged against the threshold for the escalated intensity (falls back to the global one)
       const analysis = await invoke<PerplexityAnalysis>("analyze_perplexity", {
         text: result.generated_text,
         threshold: DEFAULT_PERPLEXITY_THRESHOLD,
+        intensity: result.new_intensity,
       });
 
       // Check if passing
-      if (analysis.score < DEFAULT_PERPLEXITY_THRESHOLD) {
+      if (analysis.score < analysis.threshold) {
         // Success! Close modal and show success state
         optionsRef.current.onSuccess?.(result.generated_text, analysis);
         setAttemptCount(0); // Reset for next generation
@@ -115,7 +118,7 @@ export function useRehumanization(
         optionsRef.current.onAnalysisComplete?.(analysis);
       }
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      const errorMessage = getErrorMessage(error);
       optionsRef.current.onFailure?.(errorMessage);
     } finally {
       setIsRegenerating(false);
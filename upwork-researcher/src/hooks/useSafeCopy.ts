@@ -7,10 +7,11 @@
  */
 
 import { invoke } from "@tauri-apps/api/core";
-import { writeText } from "@tauri-apps/plugin-clipboard-manager";
 import { useState, useCallback, useRef, useEffect } from "react";
 
+import { getHumanizationIntensity, useSettingsStore } from "../stores/useSettingsStore";
 import type { PerplexityAnalysis } from "../types/perplexity";
+import { writeAppClipboard } from "../utils/clipboard";
 
 /** Pending override data when proposalId wasn't available at copy time */
 export interface PendingOverride {
@@ -63,6 +64,9 @@ export function useSafeCopy(): UseSafeCopyReturn {
   const [showOverrideConfirm, setShowOverrideConfirm] = useState(false);
   const [analysisResult, setAnalysisResult] = useState<PerplexityAnalysis | null>(null);
 
+  // Copied text is judged against the threshold for the current humanization intensity
+  const humanizationIntensity = useSettingsStore(getHumanizationIntensity);
+
   // Store text for override flow
   const [pendingText, setPendingText] = useState<string>("");
 
@@ -92,7 +96,9 @@ export function useSafeCopy(): UseSafeCopyReturn {
       // Fetch current threshold (Story 3.5)
       let threshold: number;
       try {
-        threshold = await invoke<number>("get_safety_threshold");
+        threshold = await invoke<number>("get_safety_threshold", {
+          intensity: humanizationIntensity,
+        });
       } catch (thresholdErr) {
         console.warn("Failed to get safety threshold, using default 180:", thresholdErr);
         threshold = 180;
@@ -104,11 +110,12 @@ export function useSafeCopy(): UseSafeCopyReturn {
         analysis = await invoke<PerplexityAnalysis>("analyze_perplexity", {
           text,
           threshold,
+          intensity: humanizationIntensity,
         });
       } catch (analysisErr) {
         // On analysis failure, allow copy (graceful degradation)
         console.warn("Perplexity analysis failed, allowing copy:", analysisErr);
-        await writeText(text);
+        await writeAppClipboard(text);
         setCopied(true);
         setAnalyzing(false);
 
@@ -123,7 +130,7 @@ export function useSafeCopy(): UseSafeCopyReturn {
       // Check threshold
       if (analysis.score < analysis.threshold) {
         // Safe to copy
-        await writeText(text);
+        await writeAppClipboard(text);
         setCopied(true);
         setError(null);
 
@@ -140,7 +147,7 @@ export function useSafeCopy(): UseSafeCopyReturn {
       setError("Failed to copy to clipboard");
       console.error("Clipboard write failed:", err);
     }
-  }, []);
+  }, [humanizationIntensity]);
 
   const dismissWarning = useCallback(() => {
     setShowWarningModal(false);
@@ -164,7 +171,7 @@ export function useSafeCopy(): UseSafeCopyReturn {
       setShowOverrideConfirm(false);
 
       try {
-        await writeText(text);
+        await writeAppClipboard(text);
         setCopied(true);
         setError(null);
 
@@ -1,10 +1,12 @@
 /**
  * Hook for auto-updater functionality (Story 9.6)
- * Wraps @tauri-apps/plugin-updater for update checking, downloading, and installation
+ * Checks and installs through the backend (check_for_update, prepare_and_apply_update)
+ * so the configured update channel's feed is used for both, and installs are backed up
  */
 
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { relaunch } from '@tauri-apps/plugin-process';
-import { check, Update } from '@tauri-apps/plugin-updater';
 import { useState, useCallback, useEffect, useRef } from 'react';
 
 export interface UpdateInfo {
@@ -15,6 +17,36 @@ export interface UpdateInfo {
   isCritical: boolean; // Story 9.8 Task 2.2
 }
 
+/** check_for_update result (update_channel.rs) */
+interface UpdateCheckResult {
+  channel: 'stable' | 'beta';
+  feed: 'stable' | 'beta';
+  currentVersion: string;
+  latestVersion: string | null;
+  status: 'upToDate' | 'available' | 'aheadOfChannel';
+  message: string;
+  body: string | null;
+  date: string | null;
+  critical: boolean;
+}
+
+/** Emitted by prepare_and_apply_update while the update downloads */
+const UPDATE_DOWNLOAD_PROGRESS = 'update:download-progress';
+
+/** The offered update, or null when the channel's feed has nothing newer */
+function toUpdateInfo(result: UpdateCheckResult | null | undefined): UpdateInfo | null {
+  if (!result || result.status !== 'available' || !result.latestVersion) {
+    return null;
+  }
+  return {
+    version: result.latestVersion,
+    currentVersion: result.currentVersion,
+    body: result.body ?? null,
+    date: result.date ?? null,
+    isCritical: result.critical ?? false, // Story 9.8 Task 2.3
+  };
+}
+
 export type DownloadEventType = 'Started' | 'Progress' | 'Finished';
 
 export interface DownloadProgress {
@@ -72,7 +104,7 @@ export function useUpdater(options?: UseUpdaterOptions): UseUpdaterReturn {
   const [isChecking, setIsChecking] = useState(false);
   const [isDownloading, setIsDownloading] = useState(false);
   const [error, setError] = useState<string | null>(null);
-  const [pendingUpdate, setPendingUpdate] = useState<Update | null>(null);
+  const [pendingUpdate, setPendingUpdate] = useState<UpdateInfo | null>(null);
   const [downloadProgress, setDownloadProgress] = useState(0);
   const [isDownloaded, setIsDownloaded] = useState(false);
   const [totalBytes, setTotalBytes] = useState(0);
@@ -107,7 +139,7 @@ export function useUpdater(options?: UseUpdaterOptions): UseUpdaterReturn {
     setError(null);
 
     try {
-      const update = await check();
+      const update = toUpdateInfo(await invoke<UpdateCheckResult>('check_for_update'));
 
       if (update) {
         // Story 9.7: Check if manually skipped version
@@ -120,17 +152,10 @@ export function useUpdater(options?: UseUpdaterOptions): UseUpdaterReturn {
 
         setPendingUpdate(update);
 
-        // Story 9.8 Task 2.1-2.3: Parse critical flag from update manifest
-        const isCritical = (update as { critical?: boolean }).critical ?? false;
-        setPendingCriticalUpdate(isCritical);
-
-        return {
-          version: update.version,
-          currentVersion: update.currentVersion,
-          body: update.body ?? null,
-          date: update.date ?? null,
-          isCritical, // Story 9.8 Task 2.3
-        };
+        // Story 9.8 Task 2.1-2.3: Critical flag from the update manifest
+        setPendingCriticalUpdate(update.isCritical);
+
+        return update;
       }
 
       setPendingUpdate(null);
@@ -138,7 +163,11 @@ export function useUpdater(options?: UseUpdaterOptions): UseUpdaterReturn {
       return null;
     } catch (err) {
       const errorMessage =
-        err instanceof Error ? err.message : 'Failed to check for updates';
+        err instanceof Error
+          ? err.message
+          : typeof err === 'string' && err
+            ? err
+            : 'Failed to check for updates';
       setError(errorMessage);
       return null;
     } finally {
@@ -148,9 +177,9 @@ export function useUpdater(options?: UseUpdaterOptions): UseUpdaterReturn {
 
   /**
    * Download and install pending update (AC-6)
-   * Signature verification is handled automatically by the plugin
-   * Supports resume on network interruption
-   * Story 9.7: Extended with progress tracking
+   * prepare_and_apply_update backs up the running version, then installs the same
+   * version from the channel's feed; signature verification is done by the updater
+   * Story 9.7: Extended with progress tracking (update:download-progress events)
    */
   const downloadAndInstall = useCallback(
     async (onProgress?: (progress: DownloadProgress) => void): Promise<void> => {
@@ -170,40 +199,47 @@ export function useUpdater(options?: UseUpdaterOptions): UseUpdaterReturn {
       let contentLength = 0;
       let received = 0;
 
-      try {
-        await pendingUpdate.downloadAndInstall((event) => {
-          // CR R1 H-2: Skip state updates if download was cancelled
-          if (cancelledRef.current) return;
-
-          // Track progress for UI (Story 9.7 Task 1.5)
-          if (event.event === 'Started' && event.data?.contentLength) {
-            contentLength = event.data.contentLength;
-            setTotalBytes(contentLength);
-          } else if (event.event === 'Progress' && event.data?.chunkLength) {
-            received += event.data.chunkLength;
-            setReceivedBytes(received);
-            if (contentLength > 0) {
-              const progress = Math.round((received / contentLength) * 100);
-              setDownloadProgress(progress);
-            }
-          } else if (event.event === 'Finished') {
-            setDownloadProgress(100);
-            setIsDownloaded(true);
+      const unlisten = await listen<DownloadProgress>(UPDATE_DOWNLOAD_PROGRESS, ({ payload }) => {
+        // CR R1 H-2: Skip state updates if download was cancelled
+        if (cancelledRef.current) return;
+
+        // Track progress for UI (Story 9.7 Task 1.5)
+        if (payload.event === 'Started' && payload.data?.contentLength) {
+          contentLength = payload.data.contentLength;
+          setTotalBytes(contentLength);
+        } else if (payload.event === 'Progress' && payload.data?.chunkLength) {
+          received += payload.data.chunkLength;
+          setReceivedBytes(received);
+          if (contentLength > 0) {
+            const progress = Math.round((received / contentLength) * 100);
+            setDownloadProgress(progress);
           }
+        } else if (payload.event === 'Finished') {
+          setDownloadProgress(100);
+          setIsDownloaded(true);
+        }
 
-          if (onProgress) {
-            onProgress({
-              event: event.event,
-              data: event.data,
-            });
-          }
-        });
+        if (onProgress) {
+          onProgress({
+            event: payload.event,
+            data: payload.data ?? undefined,
+          });
+        }
+      });
+
+      try {
+        await invoke('prepare_and_apply_update', { version: pendingUpdate.version });
       } catch (err) {
         const errorMessage =
-          err instanceof Error ? err.message : 'Failed to download update';
+          err instanceof Error
+            ? err.message
+            : typeof err === 'string' && err
+              ? err
+              : 'Failed to download update';
         setError(errorMessage);
         throw err;
       } finally {
+        unlisten();
         setIsDownloading(false);
       }
     },
@@ -257,7 +293,7 @@ export function useUpdater(options?: UseUpdaterOptions): UseUpdaterReturn {
       // Don't use the checkForUpdate function here because we don't want
       // to set error state for background checks
       try {
-        const update = await check();
+        const update = toUpdateInfo(await invoke<UpdateCheckResult>('check_for_update'));
         if (mounted && update) {
           // Story 9.7: Check if manually skipped version in background
           if (skippedVersion && update.version === skippedVersion) {
@@ -272,9 +308,8 @@ export function useUpdater(options?: UseUpdaterOptions): UseUpdaterReturn {
           // Version is not skipped, set as pending
           setPendingUpdate(update);
 
-          // Story 9.8 Task 2.1: Parse critical flag from background check
-          const isCritical = (update as { critical?: boolean }).critical ?? false;
-          setPendingCriticalUpdate(isCritical);
+          // Story 9.8 Task 2.1: Critical flag from background check
+          setPendingCriticalUpdate(update.isCritical);
         } else if (mounted && !update) {
           setPendingUpdate(null);
           setPendingCriticalUpdate(false);
@@ -307,15 +342,8 @@ export function useUpdater(options?: UseUpdaterOptions): UseUpdaterReturn {
 
   // Compute derived state (Task 1.1, 1.2, 1.9, Story 9.8)
   const updateAvailable = Boolean(pendingUpdate && pendingUpdate.version !== skippedVersion);
-  const updateInfo: UpdateInfo | null = pendingUpdate && pendingUpdate.version !== skippedVersion
-    ? {
-        version: pendingUpdate.version,
-        currentVersion: pendingUpdate.currentVersion,
-        body: pendingUpdate.body ?? null,
-        date: pendingUpdate.date ?? null,
-        isCritical: (pendingUpdate as { critical?: boolean }).critical ?? false, // Story 9.8 Task 2.3
-      }
-    : null;
+  const updateInfo: UpdateInfo | null =
+    pendingUpdate && pendingUpdate.version !== skippedVersion ? pendingUpdate : null;
 
   return {
     checkForUpdate,
@@ -119,6 +119,14 @@ export const getHumanizationIntensity = (state: SettingsState): HumanizationInte
   return "medium"; // Default (AC6)
 };
 
+/**
+ * Voice profile used for generation (`active_voice_profile`). Defaults to "default",
+ * matching the backend's voice_profile::active_profile_name.
+ */
+export const getActiveVoiceProfile = (state: SettingsState): string => {
+  return state.settings.active_voice_profile?.trim() || "default";
+};
+
 /** Auto-update enabled flag (Story 9.7 Task 2.1). Defaults to true. */
 export const getAutoUpdateEnabled = (state: SettingsState): boolean => {
   const value = state.settings.auto_update_enabled;
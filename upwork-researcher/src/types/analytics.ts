@@ -18,4 +18,19 @@ export interface StrategyEffectivenessData {
   responseRate: number;
   /** Weighted average outcome score: hired=3, interview=2, response_received=1, else=0 */
   avgScore: number;
+  /** Proposals whose strategy was manually corrected (only ever in manual rows) */
+  corrected: number;
+  /** Proposals with an outcome recorded (not pending or just submitted) */
+  sampleSize: number;
+  /** Two-proportion z-test p-value against the baseline; null for the baseline and insufficient data */
+  pValue: number | null;
+  /** How the response rate compares with the best strategy of the same source */
+  significance: StrategySignificance;
 }
+
+/** Matches Rust `Significance` enum in ab_testing.rs */
+export type StrategySignificance =
+  | "baseline"
+  | "significant"
+  | "not_significant"
+  | "insufficient_data";
@@ -58,6 +58,7 @@ describe("hooks types", () => {
       status: "active",
       remote_id: null,
       ab_weight: 0.2,
+      in_ab_rotation: true,
     };
 
     it("should parse strategy and extract first example", () => {
@@ -20,6 +20,8 @@ export interface HookStrategy {
   remote_id: string | null;
   /** A/B testing weight in [0.0, 1.0]. 0.0 = inactive for A/B (Story 10.4) */
   ab_weight: number;
+  /** Whether automatic A/B selection may pick this strategy */
+  in_ab_rotation: boolean;
 }
 
 /**
@@ -84,6 +86,7 @@ export function parseHookStrategy(strategy: HookStrategy): ParsedHookStrategy {
     status: strategy.status,
     remote_id: strategy.remote_id,
     ab_weight: strategy.ab_weight,
+    in_ab_rotation: strategy.in_ab_rotation,
     firstExample: allExamples[0] || "No example available",
     allExamples,
   };
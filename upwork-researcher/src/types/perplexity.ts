@@ -10,6 +10,19 @@ export interface PerplexityAnalysis {
   score: number;
   threshold: number;
   flaggedSentences: FlaggedSentence[];
+  /** Some chunks of a long text failed; score and flags cover the rest */
+  partial?: boolean;
+  /** Number of requests the text was analyzed in */
+  chunkCount?: number;
+  /** Character ranges that could not be analyzed */
+  failedRanges?: FailedChunkRange[];
+}
+
+/** Text range a perplexity chunk request failed for */
+export interface FailedChunkRange {
+  start: number;
+  end: number;
+  error: string;
 }
 
 /**
@@ -4,7 +4,7 @@
 export interface RevisionSummary {
   id: number;
   proposalId: number;
-  revisionType: "generation" | "edit" | "restore";
+  revisionType: "generation" | "edit" | "restore" | "partial_regen" | "rehumanize";
   restoredFromId: number | null;
   createdAt: string; // ISO timestamp
   contentPreview: string; // First 50 chars
@@ -14,9 +14,17 @@ export interface ProposalRevision {
   id: number;
   proposalId: number;
   content: string;
-  revisionType: "generation" | "edit" | "restore";
+  revisionType: "generation" | "edit" | "restore" | "partial_regen" | "rehumanize";
   restoredFromId: number | null;
   createdAt: string;
+  /** Set when the stored changes were unreadable and `content` is the nearest full copy */
+  warning?: string;
+}
+
+/** Result of `create_revision`; `deduplicated` when the content matched the latest revision */
+export interface CreatedRevision {
+  id: number;
+  deduplicated: boolean;
 }
 
 // Story 6-7: Archive Old Revisions
@@ -24,7 +32,7 @@ export interface ArchivedRevision {
   id: number;
   proposalId: number; // M3 fix: Include for data integrity validation
   content: string;
-  revisionType: "generation" | "edit" | "restore";
+  revisionType: "generation" | "edit" | "restore" | "partial_regen" | "rehumanize";
   restoredFromId: number | null;
   createdAt: string; // ISO timestamp
 }
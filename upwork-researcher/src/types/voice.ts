@@ -34,6 +34,18 @@ export interface VoiceProfile {
   updated_at?: string;
 }
 
+/**
+ * One named voice profile, as returned by list_voice_profiles
+ * Matches Rust VoiceProfileSummary struct
+ */
+export interface VoiceProfileSummary {
+  name: string;
+  calibrationSource: "GoldenSet" | "QuickCalibration" | "Implicit";
+  sampleCount: number;
+  updatedAt: string;
+  isActive: boolean;
+}
+
 /**
  * Partial update for manual voice parameter adjustments
  * Used by update_voice_parameters Tauri command
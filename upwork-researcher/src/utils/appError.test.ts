@@ -0,0 +1,46 @@
+import { describe, it, expect } from "vitest";
+
+import { getErrorMessage, getRateLimitSeconds, isAppError } from "./appError";
+
+describe("isAppError", () => {
+  it("recognizes structured errors", () => {
+    expect(isAppError({ code: "NOT_FOUND", message: "Proposal not found." })).toBe(true);
+  });
+
+  it("rejects strings and plain errors", () => {
+    expect(isAppError("boom")).toBe(false);
+    expect(isAppError(new Error("boom"))).toBe(false);
+    expect(isAppError(null)).toBe(false);
+  });
+});
+
+describe("getErrorMessage", () => {
+  it("reads message from AppError, Error, and string", () => {
+    expect(getErrorMessage({ code: "INTERNAL", message: "from app error" })).toBe(
+      "from app error",
+    );
+    expect(getErrorMessage(new Error("from error"))).toBe("from error");
+    expect(getErrorMessage("from string")).toBe("from string");
+  });
+});
+
+describe("getRateLimitSeconds", () => {
+  it("reads remaining seconds from structured detail", () => {
+    expect(
+      getRateLimitSeconds({
+        code: "RATE_LIMITED",
+        message: "RATE_LIMITED:12",
+        detail: { remainingSeconds: 12 },
+      }),
+    ).toBe(12);
+  });
+
+  it("falls back to the legacy string format", () => {
+    expect(getRateLimitSeconds("RATE_LIMITED:30")).toBe(30);
+  });
+
+  it("returns null for other errors", () => {
+    expect(getRateLimitSeconds({ code: "INTERNAL", message: "boom" })).toBeNull();
+    expect(getRateLimitSeconds("Network error")).toBeNull();
+  });
+});
@@ -0,0 +1,71 @@
+/**
+ * Structured command errors (AppError) returned by migrated Tauri commands.
+ *
+ * Backend `AppError` serializes as `{ code, message, detail? }`. Commands that
+ * have not been migrated still reject with a plain string, so helpers here accept both.
+ */
+
+export type ErrorCode =
+  | "RATE_LIMITED"
+  | "LOCALLY_RATE_LIMITED"
+  | "AB_NO_ACTIVE_WEIGHTS"
+  | "SCORE_BELOW_FLOOR"
+  | "DATABASE_LOCKED"
+  | "DATABASE_NOT_READY"
+  | "INCORRECT_PASSPHRASE"
+  | "API_KEY_MISSING"
+  | "GENERATION_TIMEOUT"
+  | "VALIDATION_FAILED"
+  | "NOT_FOUND"
+  | "DATABASE_ERROR"
+  | "MIGRATION_FAILED"
+  | "BACKUP_FAILED"
+  | "PRESENTATION_MODE_BLOCKED"
+  | "API_OVERLOADED"
+  | "INTEGRITY_MISMATCH"
+  | "INTERNAL";
+
+export interface AppError {
+  code: ErrorCode;
+  message: string;
+  detail?: Record<string, unknown>;
+}
+
+/**
+ * Type guard for structured AppError payloads
+ */
+export function isAppError(err: unknown): err is AppError {
+  return (
+    typeof err === "object" &&
+    err !== null &&
+    typeof (err as AppError).code === "string" &&
+    typeof (err as AppError).message === "string"
+  );
+}
+
+/**
+ * Extract a display message from any invoke rejection (AppError, Error, or string)
+ */
+export function getErrorMessage(err: unknown): string {
+  if (isAppError(err)) return err.message;
+  if (err instanceof Error) return err.message;
+  return String(err);
+}
+
+/**
+ * Remaining cooldown seconds if the error is a rate limit, otherwise null.
+ * Understands both the structured error and the legacy `RATE_LIMITED:<secs>` string.
+ */
+export function getRateLimitSeconds(err: unknown): number | null {
+  if (isAppError(err) && err.code === "RATE_LIMITED") {
+    const remaining = err.detail?.remainingSeconds;
+    if (typeof remaining === "number") return remaining;
+  }
+
+  const message = getErrorMessage(err);
+  if (message.startsWith("RATE_LIMITED:")) {
+    const remaining = parseInt(message.split(":")[1], 10);
+    if (!isNaN(remaining)) return remaining;
+  }
+  return null;
+}
@@ -0,0 +1,19 @@
+/**
+ * App-initiated clipboard writes.
+ *
+ * The opt-in clipboard watcher (`clipboard_watch_enabled`) offers copied Upwork
+ * job posts for import. Marking our own writes first keeps copied proposals
+ * from ever being offered back to the user.
+ */
+
+import { invoke } from "@tauri-apps/api/core";
+import { writeText } from "@tauri-apps/plugin-clipboard-manager";
+
+export async function writeAppClipboard(text: string): Promise<void> {
+  try {
+    await invoke("mark_clipboard_write");
+  } catch {
+    // Watcher bookkeeping is best-effort; never block the copy
+  }
+  await writeText(text);
+}
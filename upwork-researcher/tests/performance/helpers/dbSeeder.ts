@@ -5,8 +5,12 @@ import { invoke } from "@tauri-apps/api/core";
 export interface SeedOptions {
   proposals?: number;
   jobs?: number;
+  /** Same seed, same data (default 42) */
+  seed?: number;
 }
 
+export type SeedProfile = "small" | "medium" | "large";
+
 /**
  * Seeds the database with test data for performance benchmarks
  * Creates realistic data matching production schemas
@@ -14,25 +18,36 @@ export interface SeedOptions {
 export async function seedDatabase(options: SeedOptions = {}): Promise<void> {
   const proposalCount = options.proposals ?? 0;
   const jobCount = options.jobs ?? 0;
+  const seed = options.seed;
 
   console.log(`[PERF] Seeding database: ${proposalCount} proposals, ${jobCount} jobs`);
 
+  // Seed job posts first so proposals can link to them
+  if (jobCount > 0) {
+    await invoke("seed_job_posts", { count: jobCount, seed });
+  }
+
   // Seed proposals
   if (proposalCount > 0) {
-    await invoke("seed_proposals", { count: proposalCount });
+    await invoke("seed_proposals", { count: proposalCount, seed });
   }
 
-  // Seed job posts
-  if (jobCount > 0) {
-    await invoke("seed_job_posts", { count: jobCount });
-  }
+  console.log("[PERF] Database seeding complete");
+}
 
+/**
+ * Seeds a consistent dataset for a size preset: small (100 proposals),
+ * medium (2,000) or large (20,000), with half as many scored job posts
+ */
+export async function seedFullDataset(profile: SeedProfile, seed?: number): Promise<void> {
+  console.log(`[PERF] Seeding ${profile} dataset`);
+  await invoke("seed_full_dataset", { profile, seed });
   console.log("[PERF] Database seeding complete");
 }
 
 /**
- * Clears all test data from the database
- * Use with caution - only for test databases
+ * Clears seeded test data from the database
+ * Only rows created by the seed commands are removed
  */
 export async function clearDatabase(): Promise<void> {
   console.log("[PERF] Clearing database");